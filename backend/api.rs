@@ -0,0 +1,336 @@
+//! # Direct Backend API
+//!
+//! `AllowanceApi` implemented by calling the domain services in-process.
+//!
+//! The egui frontend goes through this instead of reaching into individual
+//! services, so its call sites and DTO mapping match any other frontend
+//! built on the same trait.
+
+use frontend_api::{AllowanceApi, ApiError, ApiResult};
+use shared::{
+    AcknowledgeCelebrationsRequest, AcknowledgeCelebrationsResponse, ActiveChildResponse, AddMoneyRequest, AddMoneyResponse, BalanceChartMarker, BalanceChartPoint, BalanceChartRequest,
+    BalanceChartResponse, Celebration, ChildListResponse,
+    DeleteTransactionsRequest, DeleteTransactionsResponse, DescriptionSuggestion, DescriptionSuggestionsRequest,
+    DescriptionSuggestionsResponse, DuplicateTransactionRequest, DuplicateTransactionResponse, HealthResponse, MilestoneHistoryResponse, PaginationInfo, PendingCelebrationsResponse, ReachedMilestone, SchemaVersionInfo, SetActiveChildRequest,
+    SetActiveChildResponse, SpendMoneyRequest, SpendMoneyResponse, TransactionListRequest,
+    TransactionListResponse, VersionResponse,
+};
+
+use crate::backend::domain::commands::celebrations::{AcknowledgeCelebrationsCommand, MilestoneHistoryQuery, PendingCelebrationsQuery};
+use crate::backend::domain::commands::child::SetActiveChildCommand;
+use crate::backend::domain::commands::quick_picks::DescriptionSuggestionsQuery;
+use crate::backend::domain::commands::reports::{BalanceSeriesQuery, ChartMarkerKind, ChartResolution};
+use crate::backend::domain::commands::transactions::{DeleteTransactionsCommand, DuplicateTransactionCommand, TransactionListQuery};
+use crate::backend::domain::errors::classify;
+use crate::backend::domain::models::actor::Actor;
+use crate::backend::domain::models::celebration::CelebrationKind;
+use crate::backend::domain::models::quick_pick::QuickPickKind;
+use crate::backend::domain::{ChildMapper, MoneyManagementService, TransactionMapper};
+use crate::backend::Backend;
+
+/// `AllowanceApi` over a `Backend` in the same process
+pub struct DirectBackendApi<'a> {
+    backend: &'a Backend,
+    money_service: MoneyManagementService,
+}
+
+impl<'a> DirectBackendApi<'a> {
+    /// Create an API over the given backend
+    pub fn new(backend: &'a Backend) -> Self {
+        Self {
+            backend,
+            money_service: MoneyManagementService::new(),
+        }
+    }
+
+    /// Use a money service with its own limits and currency symbol for add/spend calls
+    pub fn with_money_service(mut self, money_service: MoneyManagementService) -> Self {
+        self.money_service = money_service;
+        self
+    }
+
+    fn require_active_child(&self) -> ApiResult<()> {
+        let active = self.backend.child_service.get_active_child().map_err(backend_error)?;
+        match active.active_child.child {
+            Some(_) => Ok(()),
+            None => Err(ApiError::no_active_child()),
+        }
+    }
+
+    /// Check the child a request names exists, or that there is an active
+    /// child to fall back on when it names none
+    fn require_child(&self, child_id: Option<&str>) -> ApiResult<()> {
+        match child_id {
+            Some(id) => self
+                .backend
+                .transaction_service
+                .resolve_child(Some(id))
+                .map(|_| ())
+                .map_err(backend_error),
+            None => self.require_active_child(),
+        }
+    }
+}
+
+impl AllowanceApi for DirectBackendApi<'_> {
+    fn list_children(&self) -> ApiResult<ChildListResponse> {
+        let result = self.backend.child_service.list_children().map_err(backend_error)?;
+        Ok(ChildListResponse {
+            children: result.children.into_iter().map(ChildMapper::to_dto).collect(),
+        })
+    }
+
+    fn get_active_child(&self) -> ApiResult<ActiveChildResponse> {
+        let result = self.backend.child_service.get_active_child().map_err(backend_error)?;
+        Ok(ActiveChildResponse {
+            active_child: result.active_child.child.map(ChildMapper::to_dto),
+        })
+    }
+
+    fn set_active_child(&self, request: SetActiveChildRequest) -> ApiResult<SetActiveChildResponse> {
+        let result = self
+            .backend
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: request.child_id })
+            .map_err(backend_error)?;
+        Ok(SetActiveChildResponse {
+            success_message: format!("Switched to {}", result.child.name),
+            active_child: ChildMapper::to_dto(result.child),
+        })
+    }
+
+    fn list_transactions(&self, request: TransactionListRequest) -> ApiResult<TransactionListResponse> {
+        self.require_child(request.child_id.as_deref())?;
+        let query = TransactionListQuery {
+            child_id: request.child_id,
+            after: request.after,
+            limit: request.limit,
+            start_date: request.start_date,
+            end_date: request.end_date,
+        };
+        let result = self.backend.transaction_service.list_transactions_domain(query).map_err(backend_error)?;
+        Ok(TransactionListResponse {
+            transactions: result
+                .transactions
+                .into_iter()
+                .map(|transaction| TransactionMapper::to_dto_with_lock(transaction, result.period_lock.as_ref()))
+                .collect(),
+            pagination: PaginationInfo {
+                has_more: result.pagination.has_more,
+                next_cursor: result.pagination.next_cursor,
+            },
+        })
+    }
+
+    fn add_money(&self, request: AddMoneyRequest) -> ApiResult<AddMoneyResponse> {
+        self.require_child(request.child_id.as_deref())?;
+        self.money_service
+            .add_money_complete(
+                request,
+                &self.backend.child_service,
+                &self.backend.transaction_service,
+                &self.backend.goal_service,
+            )
+            .map_err(backend_error)
+    }
+
+    fn spend_money(&self, request: SpendMoneyRequest) -> ApiResult<SpendMoneyResponse> {
+        self.require_child(request.child_id.as_deref())?;
+        self.money_service
+            .spend_money_complete(
+                request,
+                &self.backend.child_service,
+                &self.backend.transaction_service,
+                &self.backend.goal_service,
+            )
+            .map_err(backend_error)
+    }
+
+    fn delete_transactions(&self, request: DeleteTransactionsRequest) -> ApiResult<DeleteTransactionsResponse> {
+        self.require_child(request.child_id.as_deref())?;
+        let result = self
+            .backend
+            .transaction_service
+            .delete_transactions_domain(DeleteTransactionsCommand {
+                child_id: request.child_id,
+                transaction_ids: request.transaction_ids,
+                actor: request.actor.as_deref().and_then(Actor::parse),
+            })
+            .map_err(backend_error)?;
+        Ok(DeleteTransactionsResponse {
+            deleted_count: result.deleted_count,
+            success_message: result.success_message,
+            not_found_ids: result.not_found_ids,
+        })
+    }
+
+    fn duplicate_transaction(&self, request: DuplicateTransactionRequest) -> ApiResult<DuplicateTransactionResponse> {
+        self.require_child(request.child_id.as_deref())?;
+        let result = self
+            .backend
+            .transaction_service
+            .duplicate_transaction(DuplicateTransactionCommand {
+                child_id: request.child_id,
+                transaction_id: request.transaction_id,
+                parent_override: false,
+                actor: request.actor.as_deref().and_then(Actor::parse),
+            })
+            .map_err(backend_error)?;
+        Ok(DuplicateTransactionResponse {
+            success_message: format!("Added another \"{}\"", result.transaction.description),
+            new_balance: result.transaction.balance,
+            transaction: TransactionMapper::to_dto(result.transaction),
+        })
+    }
+
+    fn get_balance_chart(&self, request: BalanceChartRequest) -> ApiResult<BalanceChartResponse> {
+        self.require_child(request.child_id.as_deref())?;
+        let result = self
+            .backend
+            .reports_service
+            .get_balance_series(BalanceSeriesQuery {
+                child_id: request.child_id,
+                start_date: request.start_date,
+                end_date: request.end_date,
+                resolution: match request.resolution {
+                    shared::ChartResolution::Daily => ChartResolution::Daily,
+                    shared::ChartResolution::Weekly => ChartResolution::Weekly,
+                    shared::ChartResolution::Monthly => ChartResolution::Monthly,
+                },
+            })
+            .map_err(backend_error)?;
+        Ok(BalanceChartResponse {
+            resolution: request.resolution,
+            points: result
+                .points
+                .into_iter()
+                .map(|point| BalanceChartPoint { date: point.date, balance: point.balance })
+                .collect(),
+            markers: result
+                .markers
+                .into_iter()
+                .map(|marker| BalanceChartMarker {
+                    date: marker.date,
+                    kind: match marker.kind {
+                        ChartMarkerKind::LargeTransaction => shared::ChartMarkerKind::LargeTransaction,
+                        ChartMarkerKind::GoalCreated => shared::ChartMarkerKind::GoalCreated,
+                        ChartMarkerKind::GoalCompleted => shared::ChartMarkerKind::GoalCompleted,
+                    },
+                    description: marker.description,
+                    amount: marker.amount,
+                })
+                .collect(),
+        })
+    }
+
+    fn get_description_suggestions(&self, request: DescriptionSuggestionsRequest) -> ApiResult<DescriptionSuggestionsResponse> {
+        self.require_active_child()?;
+        let result = self
+            .backend
+            .quick_pick_service
+            .get_description_suggestions(DescriptionSuggestionsQuery {
+                child_id: None,
+                kind: match request.transaction_type {
+                    shared::TransactionType::Expense | shared::TransactionType::Penalty => QuickPickKind::Expense,
+                    _ => QuickPickKind::Income,
+                },
+                prefix: request.prefix,
+                limit: request.limit,
+            })
+            .map_err(backend_error)?;
+        Ok(DescriptionSuggestionsResponse {
+            suggestions: result
+                .suggestions
+                .into_iter()
+                .map(|s| DescriptionSuggestion { description: s.description, amount: s.amount })
+                .collect(),
+        })
+    }
+
+    fn get_pending_celebrations(&self) -> ApiResult<PendingCelebrationsResponse> {
+        self.require_active_child()?;
+        let result = self
+            .backend
+            .celebration_service
+            .get_pending_celebrations(PendingCelebrationsQuery { child_id: None })
+            .map_err(backend_error)?;
+        Ok(PendingCelebrationsResponse {
+            celebrations: result
+                .celebrations
+                .into_iter()
+                .map(|c| Celebration {
+                    id: c.id,
+                    kind: match c.kind {
+                        CelebrationKind::AllowancePosted => shared::CelebrationKind::AllowancePosted,
+                        CelebrationKind::GoalCompleted => shared::CelebrationKind::GoalCompleted,
+                        CelebrationKind::BalanceMilestone => shared::CelebrationKind::BalanceMilestone,
+                    },
+                    created_at: c.created_at,
+                    message: c.message,
+                    amount: c.amount,
+                })
+                .collect(),
+        })
+    }
+
+    fn acknowledge_celebrations(&self, request: AcknowledgeCelebrationsRequest) -> ApiResult<AcknowledgeCelebrationsResponse> {
+        self.require_active_child()?;
+        let result = self
+            .backend
+            .celebration_service
+            .acknowledge_celebrations(AcknowledgeCelebrationsCommand { child_id: None, celebration_ids: request.celebration_ids })
+            .map_err(backend_error)?;
+        Ok(AcknowledgeCelebrationsResponse { acknowledged_count: result.acknowledged_count })
+    }
+
+    fn get_milestone_history(&self) -> ApiResult<MilestoneHistoryResponse> {
+        self.require_active_child()?;
+        let result = self
+            .backend
+            .celebration_service
+            .get_milestone_history(MilestoneHistoryQuery { child_id: None })
+            .map_err(backend_error)?;
+        Ok(MilestoneHistoryResponse {
+            reached: result
+                .reached
+                .into_iter()
+                .map(|m| ReachedMilestone { amount: m.amount, reached_on: m.reached_on, transaction_id: m.transaction_id })
+                .collect(),
+            upcoming: result.upcoming,
+        })
+    }
+
+    fn health(&self) -> ApiResult<HealthResponse> {
+        let report = self.backend.health_service.check_health().map_err(backend_error)?;
+        Ok(HealthResponse {
+            healthy: report.is_healthy(),
+            data_directory: report.data_directory,
+            storage_reachable: report.storage_reachable,
+            writable: report.writable,
+            read_only_reason: report.read_only_reason,
+            schema_versions: report
+                .schema_versions
+                .into_iter()
+                .map(|check| SchemaVersionInfo {
+                    file: check.file,
+                    version: check.version,
+                    current_version: check.current_version,
+                })
+                .collect(),
+            problems: report.problems,
+        })
+    }
+
+    fn version(&self) -> ApiResult<VersionResponse> {
+        let info = self.backend.health_service.version_info();
+        Ok(VersionResponse {
+            app_version: info.app_version,
+            git_commit: info.git_commit,
+            storage_backend: info.storage_backend,
+        })
+    }
+}
+
+fn backend_error(error: anyhow::Error) -> ApiError {
+    classify(&error).into()
+}
@@ -0,0 +1,248 @@
+//! Agreement service domain logic for the allowance tracker.
+//!
+//! Writes a printable "allowance agreement" for a child as a PDF: how much
+//! allowance they get and on which day, the chores they take on, the rules
+//! for their money, and lines for the child and a parent to sign. Families
+//! tend to print one at the start of a school year to set expectations.
+//!
+//! The allowance and the money rules come from the child's current settings,
+//! so the agreement matches what the app will actually do. Chores and any
+//! extra rules are written by the parent when generating it.
+
+use anyhow::Result;
+use chrono::{Datelike, Local};
+use log::info;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::backend::domain::allowance_service::AllowanceService;
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::commands::agreement::{GenerateAgreementCommand, GenerateAgreementResult};
+use crate::backend::domain::commands::allowance::GetAllowanceConfigCommand;
+use crate::backend::domain::commands::child::GetChildCommand;
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::localization::DateStyle;
+use crate::backend::domain::models::child_settings::ChildSettings;
+use crate::backend::storage::csv::{CsvConnection, GlobalConfigRepository};
+use crate::backend::storage::pdf_document::PdfDocumentWriter;
+use crate::backend::storage::GlobalConfigStorage;
+
+/// Blank lines printed for chores or rules when there are none to list
+const BLANK_LINES: usize = 3;
+
+/// Service for writing printable allowance agreements
+#[derive(Clone)]
+pub struct AgreementService {
+    global_config_repository: GlobalConfigRepository,
+    child_service: ChildService,
+    allowance_service: AllowanceService,
+}
+
+impl AgreementService {
+    /// Create a new AgreementService
+    pub fn new(csv_conn: Arc<CsvConnection>, child_service: ChildService, allowance_service: AllowanceService) -> Self {
+        Self {
+            global_config_repository: GlobalConfigRepository::new((*csv_conn).clone()),
+            child_service,
+            allowance_service,
+        }
+    }
+
+    /// Write a child's allowance agreement as a PDF and return where it went
+    pub fn generate_agreement(&self, command: GenerateAgreementCommand) -> Result<GenerateAgreementResult> {
+        let child = match command.child_id {
+            Some(child_id) => self
+                .child_service
+                .get_child(GetChildCommand { child_id: child_id.clone() })?
+                .child
+                .ok_or_else(|| DomainError::child_not_found(&child_id))?,
+            None => self
+                .child_service
+                .get_active_child()?
+                .active_child
+                .child
+                .ok_or(DomainError::NoActiveChild)?,
+        };
+
+        let directory = match command.output_directory.as_deref().map(str::trim).filter(|dir| !dir.is_empty()) {
+            Some(dir) => PathBuf::from(dir),
+            None => dirs::document_dir()
+                .or_else(dirs::home_dir)
+                .ok_or_else(|| DomainError::invalid("Choose a folder to save the agreement in"))?,
+        };
+        if !directory.is_dir() {
+            return Err(DomainError::invalid(format!("Folder does not exist: {}", directory.display())).into());
+        }
+
+        let settings = self.child_service.get_child_settings(&child.id)?.settings;
+        let allowance = self
+            .allowance_service
+            .get_allowance_config(GetAllowanceConfigCommand { child_id: Some(child.id.clone()) })?
+            .allowance_config
+            .filter(|config| config.is_active);
+        let config = self.global_config_repository.get_global_config()?;
+        let locale = settings.locale.unwrap_or(config.locale);
+        let currency = settings.currency.unwrap_or(config.currency);
+        let money = |amount: f64| currency.format(amount, locale);
+        let today = Local::now().date_naive();
+
+        let mut pdf = PdfDocumentWriter::new();
+        pdf.title("Allowance Agreement");
+        pdf.paragraph(&format!(
+            "This agreement is between {} and their parents, starting {}.",
+            child.name,
+            locale.format_date(today.year(), today.month(), today.day(), DateStyle::Long)
+        ));
+
+        pdf.heading("Allowance");
+        match &allowance {
+            Some(allowance) => pdf.paragraph(&format!(
+                "{} gets an allowance of {} {}.",
+                child.name,
+                money(allowance.amount),
+                allowance.schedule_label()
+            )),
+            None => pdf.paragraph(&format!("{} doesn't get a regular allowance yet.", child.name)),
+        }
+
+        pdf.heading("Chores");
+        pdf.paragraph(&format!("{} agrees to:", child.name));
+        write_list(&mut pdf, &non_blank(&command.chores));
+
+        pdf.heading("Rules");
+        let mut rules = settings_rules(&child.name, &settings);
+        rules.extend(non_blank(&command.rules));
+        write_list(&mut pdf, &rules);
+
+        pdf.heading("Signatures");
+        pdf.paragraph("We have read this agreement and will stick to it.");
+        pdf.signature_line(&child.name);
+        pdf.signature_line("Parent");
+
+        let file_name = format!(
+            "{}_allowance_agreement_{}.pdf",
+            CsvConnection::generate_safe_directory_name(&child.name),
+            today.format("%Y-%m-%d")
+        );
+        let path = directory.join(file_name);
+        fs::write(&path, pdf.finish())?;
+
+        info!("📜 AGREEMENT: Wrote allowance agreement for {} to {}", child.name, path.display());
+        Ok(GenerateAgreementResult {
+            child_id: child.id,
+            file_path: path.to_string_lossy().to_string(),
+        })
+    }
+}
+
+/// The money rules a child's settings put in place, in words
+fn settings_rules(child_name: &str, settings: &ChildSettings) -> Vec<String> {
+    let mut rules = Vec::new();
+    if settings.prevent_negative_balance {
+        rules.push(format!("{} can't spend more than they have.", child_name));
+    }
+    if settings.auto_save_percentage > 0.0 {
+        let destination = if settings.auto_save_to_goal { "towards the current goal" } else { "for savings" };
+        rules.push(format!(
+            "{}% of all money {} gets is put aside {}.",
+            settings.auto_save_percentage, child_name, destination
+        ));
+    }
+    if settings.round_up_spends {
+        rules.push("Every purchase is rounded up to the next whole amount, and the change is saved.".to_string());
+    }
+    rules
+}
+
+fn non_blank(items: &[String]) -> Vec<String> {
+    items
+        .iter()
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// A bulleted list, or blank lines to fill in by hand when there is nothing to list
+fn write_list(pdf: &mut PdfDocumentWriter, items: &[String]) {
+    if items.is_empty() {
+        for _ in 0..BLANK_LINES {
+            pdf.blank_bullet();
+        }
+    } else {
+        for item in items {
+            pdf.bullet(item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::allowance::UpdateAllowanceConfigCommand;
+    use crate::backend::domain::commands::child::{CreateChildCommand, UpdateChildSettingsCommand};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_agreement_lists_allowance_chores_and_rules() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path().join("data"))?);
+        let child_service = ChildService::new(connection.clone());
+        let allowance_service = AllowanceService::new(connection.clone());
+        let service = AgreementService::new(connection, child_service.clone(), allowance_service.clone());
+
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })?
+            .child;
+        allowance_service.update_allowance_config(UpdateAllowanceConfigCommand {
+            child_id: Some(child.id.clone()),
+            amount: 5.0,
+            day_of_week: 6,
+            is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
+        })?;
+        child_service.update_child_settings(UpdateChildSettingsCommand {
+            child_id: child.id.clone(),
+            prevent_negative_balance: Some(true),
+            auto_save_percentage: None,
+            auto_save_to_goal: None,
+            round_up_spends: None,
+            locale: None,
+            currency: None,
+            balance_milestones: None,
+            background: None,
+        })?;
+
+        let result = service.generate_agreement(GenerateAgreementCommand {
+            child_id: Some(child.id.clone()),
+            chores: vec!["Feed the cat".to_string(), "  ".to_string()],
+            rules: vec!["Ask before buying games".to_string()],
+            output_directory: Some(temp_dir.path().to_string_lossy().to_string()),
+        })?;
+
+        assert_eq!(result.child_id, child.id);
+        let pdf = fs::read(&result.file_path)?;
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(pdf.starts_with(b"%PDF"));
+        assert!(text.contains("(Ada gets an allowance of $5.00 every Saturday.)"));
+        assert!(text.contains("(Feed the cat)"));
+        assert!(text.contains("(Ada can't spend more than they have.)"));
+        assert!(text.contains("(Ask before buying games)"));
+
+        // The folder has to exist
+        let missing = service.generate_agreement(GenerateAgreementCommand {
+            child_id: Some(child.id),
+            chores: Vec::new(),
+            rules: Vec::new(),
+            output_directory: Some(temp_dir.path().join("nowhere").to_string_lossy().to_string()),
+        });
+        assert!(missing.is_err());
+        Ok(())
+    }
+}
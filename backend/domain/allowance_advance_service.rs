@@ -0,0 +1,258 @@
+//! Allowance advance service domain logic for the allowance tracker.
+//!
+//! Granting an advance records an income transaction now and a repayment
+//! schedule that takes an equal share back on each of the next few allowance
+//! days. Each installment has to fit inside that day's allowance, after any
+//! other advance already scheduled for it, so an allowance is reduced but
+//! never turned into a charge. The deductions themselves are taken when
+//! allowances are issued (see `TransactionService`), and upcoming allowances in
+//! the calendar are shown already reduced.
+
+use anyhow::Result;
+use chrono::{Local, NaiveDate};
+use log::info;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::commands::advances::{
+    AdvanceResult, GrantAdvanceCommand, OutstandingAdvancesQuery, OutstandingAdvancesResult,
+};
+use crate::backend::domain::commands::transactions::CreateTransactionCommand;
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::models::allowance_advance::AllowanceAdvance;
+use crate::backend::domain::models::transaction::TransactionType;
+use crate::backend::domain::{AllowanceService, TransactionService};
+use crate::backend::storage::csv::{AllowanceAdvanceRepository, CsvConnection};
+use crate::backend::storage::traits::AllowanceAdvanceStorage;
+
+/// Most allowances a single advance can be repaid from
+pub const MAX_ADVANCE_INSTALLMENTS: u32 = 12;
+
+/// Service for paying allowance early and repaying it from later allowances
+#[derive(Clone)]
+pub struct AllowanceAdvanceService {
+    advance_repository: AllowanceAdvanceRepository,
+    child_service: ChildService,
+    allowance_service: AllowanceService,
+    transaction_service: Arc<TransactionService>,
+}
+
+impl AllowanceAdvanceService {
+    /// Create a new AllowanceAdvanceService
+    pub fn new(
+        csv_conn: Arc<CsvConnection>,
+        child_service: ChildService,
+        allowance_service: AllowanceService,
+        transaction_service: Arc<TransactionService>,
+    ) -> Self {
+        let advance_repository = AllowanceAdvanceRepository::new((*csv_conn).clone());
+        Self {
+            advance_repository,
+            child_service,
+            allowance_service,
+            transaction_service,
+        }
+    }
+
+    /// Pay the active child an advance, repaid from their next allowances
+    pub fn grant_advance(&self, command: GrantAdvanceCommand) -> Result<AdvanceResult> {
+        if !command.amount.is_finite() || command.amount <= 0.0 {
+            return Err(DomainError::invalid("Advance amount must be greater than 0").into());
+        }
+        if command.installments == 0 || command.installments > MAX_ADVANCE_INSTALLMENTS {
+            return Err(DomainError::invalid(format!(
+                "An advance is repaid over 1 to {} allowances",
+                MAX_ADVANCE_INSTALLMENTS
+            ))
+            .into());
+        }
+
+        let child = self.transaction_service.get_active_child()?;
+        let today = Local::now().date_naive();
+        let upcoming = self
+            .allowance_service
+            .upcoming_allowances(&child.id, today, command.installments as usize)?;
+        if upcoming.is_empty() {
+            return Err(DomainError::invalid(format!("{} needs an active allowance to repay an advance from", child.name)).into());
+        }
+        if upcoming.len() < command.installments as usize {
+            return Err(DomainError::invalid(format!(
+                "{} only has {} allowances coming up in the next year",
+                child.name,
+                upcoming.len()
+            ))
+            .into());
+        }
+
+        let due_dates: Vec<NaiveDate> = upcoming.iter().map(|(date, _)| *date).collect();
+        let installments = AllowanceAdvance::split_installments(command.amount, &due_dates);
+        let scheduled = self.scheduled_deductions(&child.id)?;
+        for (installment, (date, allowance)) in installments.iter().zip(&upcoming) {
+            let available = allowance - scheduled.get(date).copied().unwrap_or(0.0);
+            if installment.amount > available + 0.005 {
+                return Err(DomainError::invalid(format!(
+                    "${:.2} is more than the next {} allowances can repay (only ${:.2} is left on {})",
+                    command.amount,
+                    command.installments,
+                    available.max(0.0),
+                    date.format("%b %-d")
+                ))
+                .into());
+            }
+        }
+
+        // Kept free of "allowance" so it isn't mistaken for the day's allowance
+        let description = match command.installments {
+            1 => "Advance, repaid on the next payday".to_string(),
+            count => format!("Advance, repaid over the next {} paydays", count),
+        };
+        let transaction = self.transaction_service.create_transaction(CreateTransactionCommand {
+            child_id: Some(child.id.clone()),
+            description,
+            amount: command.amount,
+            date: None,
+            parent_override: false,
+            actor: command.actor,
+            icon: None,
+            transaction_type: Some(TransactionType::AllowancePayment),
+        })?;
+
+        let now_millis = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+        let advance = AllowanceAdvance {
+            id: AllowanceAdvance::generate_id(&child.id, now_millis),
+            child_id: child.id.clone(),
+            amount: command.amount,
+            date: transaction.date,
+            transaction_id: transaction.id.clone(),
+            installments,
+        };
+        self.advance_repository.store_advance(&advance)?;
+
+        info!("💸 ADVANCE: {} granted ${:.2} in {}, repaid over {} allowances", child.id, advance.amount, advance.id, command.installments);
+        Ok(AdvanceResult { advance, transaction })
+    }
+
+    /// Advances a child is still repaying
+    pub fn get_outstanding(&self, query: OutstandingAdvancesQuery) -> Result<OutstandingAdvancesResult> {
+        let child_id = match query.child_id {
+            Some(id) => id,
+            None => self
+                .child_service
+                .get_active_child()?
+                .active_child
+                .child
+                .map(|child| child.id)
+                .ok_or(DomainError::NoActiveChild)?,
+        };
+
+        let open_advances: Vec<AllowanceAdvance> = self
+            .advance_repository
+            .list_advances(&child_id)?
+            .into_iter()
+            .filter(|advance| !advance.is_repaid())
+            .collect();
+        let outstanding = open_advances.iter().map(AllowanceAdvance::outstanding).sum::<f64>();
+
+        Ok(OutstandingAdvancesResult {
+            outstanding: (outstanding * 100.0).round() / 100.0,
+            child_id,
+            open_advances,
+        })
+    }
+
+    /// Deductions not yet taken, by allowance day
+    fn scheduled_deductions(&self, child_id: &str) -> Result<HashMap<NaiveDate, f64>> {
+        let mut deductions = HashMap::new();
+        for advance in self.advance_repository.list_advances(child_id)? {
+            for installment in advance.installments.iter().filter(|i| !i.is_paid()) {
+                *deductions.entry(installment.due_date).or_insert(0.0) += installment.amount;
+            }
+        }
+        Ok(deductions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::allowance::UpdateAllowanceConfigCommand;
+    use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand};
+    use crate::backend::domain::models::transaction::TransactionType;
+    use crate::backend::domain::BalanceService;
+    use chrono::Datelike;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_advance_is_scheduled_against_upcoming_allowances() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let child_service = ChildService::new(connection.clone());
+        let allowance_service = AllowanceService::new(connection.clone());
+        let transaction_service = Arc::new(TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            allowance_service.clone(),
+            BalanceService::new(connection.clone()),
+        ));
+        let service = AllowanceAdvanceService::new(
+            connection,
+            child_service.clone(),
+            allowance_service.clone(),
+            transaction_service.clone(),
+        );
+
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })?
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() })?;
+        let grant = |amount: f64, installments: u32| {
+            service.grant_advance(GrantAdvanceCommand { amount, installments, actor: None })
+        };
+
+        let refused = grant(10.0, 2).unwrap_err();
+        assert!(matches!(refused.downcast_ref::<DomainError>(), Some(DomainError::Invalid(_))));
+
+        // Allowance on tomorrow's weekday, so the next two allowances are tomorrow and a week later
+        let today = Local::now().date_naive();
+        allowance_service.update_allowance_config(UpdateAllowanceConfigCommand {
+            child_id: Some(child.id.clone()),
+            amount: 5.0,
+            day_of_week: today.succ_opt().unwrap().weekday().num_days_from_sunday() as u8,
+            is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
+        })?;
+
+        let too_much = grant(12.0, 2).unwrap_err();
+        assert!(matches!(too_much.downcast_ref::<DomainError>(), Some(DomainError::Invalid(_))));
+
+        let result = grant(6.0, 2)?;
+        assert_eq!(result.transaction.amount, 6.0);
+        let due: Vec<NaiveDate> = result.advance.installments.iter().map(|i| i.due_date).collect();
+        assert_eq!(due, vec![today + chrono::Duration::days(1), today + chrono::Duration::days(8)]);
+
+        // Only $2 of the first allowance is left for another advance
+        assert!(grant(5.0, 1).is_err());
+        grant(2.0, 1)?;
+
+        let outstanding = service.get_outstanding(OutstandingAdvancesQuery { child_id: None })?;
+        assert_eq!(outstanding.open_advances.len(), 2);
+        assert_eq!(outstanding.outstanding, 8.0);
+
+        let upcoming = allowance_service.generate_future_allowance_transactions(
+            &child.id,
+            today,
+            today + chrono::Duration::days(8),
+        )?;
+        let amounts: Vec<f64> = upcoming.iter().map(|t| t.amount).collect();
+        assert_eq!(amounts, vec![0.0, 2.0]);
+        assert!(upcoming.iter().all(|t| t.transaction_type == TransactionType::FutureAllowance));
+        Ok(())
+    }
+}
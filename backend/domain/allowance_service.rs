@@ -3,10 +3,13 @@ use chrono::{Utc, NaiveDate, Datelike, Local, FixedOffset};
 use log::{info, warn};
 use std::sync::Arc;
 
-use crate::backend::storage::csv::{CsvConnection, AllowanceRepository, TransactionRepository};
-use crate::backend::storage::traits::{AllowanceStorage, TransactionStorage};
+use crate::backend::storage::csv::{CsvConnection, AllowanceAdvanceRepository, AllowanceRepository, TransactionRepository};
+use crate::backend::storage::traits::{AllowanceAdvanceStorage, AllowanceStorage, TransactionStorage};
 use crate::backend::domain::child_service::ChildService;
-use crate::backend::domain::models::allowance::AllowanceConfig;
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::models::allowance::{
+    AllowanceConfig, AllowanceDayChange, AllowanceFrequency, AllowanceScheduleChange, MAX_INTERVAL_WEEKS, MIN_INTERVAL_WEEKS,
+};
 use crate::backend::domain::models::transaction::{Transaction as DomainTransaction, TransactionType as DomainTransactionType};
 use crate::backend::domain::commands::allowance::{
     GetAllowanceConfigCommand, UpdateAllowanceConfigCommand
@@ -22,6 +25,7 @@ use crate::backend::domain::commands::child::GetChildCommand;
 pub struct AllowanceService {
     allowance_repository: AllowanceRepository,
     transaction_repository: TransactionRepository,
+    advance_repository: AllowanceAdvanceRepository,
     child_service: ChildService,
 }
 
@@ -30,10 +34,12 @@ impl AllowanceService {
     pub fn new(csv_conn: Arc<CsvConnection>) -> Self {
         let allowance_repository = AllowanceRepository::new((*csv_conn).clone());
         let transaction_repository = TransactionRepository::new((*csv_conn).clone());
+        let advance_repository = AllowanceAdvanceRepository::new((*csv_conn).clone());
         let child_service = ChildService::new(csv_conn);
         Self {
             allowance_repository,
             transaction_repository,
+            advance_repository,
             child_service,
         }
     }
@@ -87,19 +93,35 @@ impl AllowanceService {
 
         // Validate day of week
         if !AllowanceConfig::is_valid_day_of_week(command.day_of_week) {
-            return Err(anyhow::anyhow!(
+            return Err(DomainError::invalid(format!(
                 "Invalid day of week: {}. Must be 0-6 (Sunday-Saturday)",
                 command.day_of_week
-            ));
+            )).into());
+        }
+
+        // Validate day of month
+        if let Some(day) = command.day_of_month.filter(|day| !AllowanceConfig::is_valid_day_of_month(*day)) {
+            return Err(DomainError::invalid(format!(
+                "Invalid day of month: {}. Must be 1-31",
+                day
+            )).into());
+        }
+
+        // Validate weeks between every-few-weeks allowances
+        if let Some(weeks) = command.interval_weeks.filter(|weeks| !AllowanceConfig::is_valid_interval_weeks(*weeks)) {
+            return Err(DomainError::invalid(format!(
+                "Invalid interval: {} weeks. Must be {}-{}",
+                weeks, MIN_INTERVAL_WEEKS, MAX_INTERVAL_WEEKS
+            )).into());
         }
 
         // Validate amount
         if command.amount < 0.0 {
-            return Err(anyhow::anyhow!("Allowance amount cannot be negative"));
+            return Err(DomainError::invalid("Allowance amount cannot be negative").into());
         }
 
         if command.amount > 1_000_000.0 {
-            return Err(anyhow::anyhow!("Allowance amount is too large"));
+            return Err(DomainError::invalid("Allowance amount is too large").into());
         }
 
         let child_id = match command.child_id {
@@ -107,7 +129,7 @@ impl AllowanceService {
                 // Verify the child exists
                 let get_child_command = GetChildCommand { child_id: id.clone() };
                 if self.child_service.get_child(get_child_command)?.child.is_none() {
-                    return Err(anyhow::anyhow!("Child not found: {}", id));
+                    return Err(DomainError::child_not_found(&id).into());
                 }
                 id
             }
@@ -116,7 +138,7 @@ impl AllowanceService {
                 let active_child_result = self.child_service.get_active_child()?;
                 let child = match active_child_result.active_child.child {
                     Some(c) => c.id,
-                    None => return Err(anyhow::anyhow!("No active child found to update allowance config")),
+                    None => return Err(DomainError::NoActiveChild.into()),
                 };
                 child
             }
@@ -130,12 +152,48 @@ impl AllowanceService {
         let now = Utc::now();
         let timestamp_rfc3339 = now.to_rfc3339();
 
+        // An every-few-weeks allowance is paid on its first payday's day of the week
+        let frequency = command.frequency.or(existing_domain_config.as_ref().map(|config| config.frequency)).unwrap_or_default();
+        let anchor_date = command.anchor_date.or(existing_domain_config.as_ref().and_then(|config| config.anchor_date));
+        let day_of_week = match (frequency, anchor_date) {
+            (AllowanceFrequency::EveryNWeeks, Some(anchor)) => anchor.weekday().num_days_from_sunday() as u8,
+            (AllowanceFrequency::EveryNWeeks, None) => {
+                return Err(DomainError::invalid("Pick the first payday of an every-few-weeks allowance").into());
+            }
+            _ => command.day_of_week,
+        };
+
         let domain_allowance_config = match existing_domain_config {
             Some(mut config) => {
                 // Update existing config
+                DomainError::check_not_stale("allowance", command.expected_updated_at.as_deref(), &config.updated_at)?;
+                let today = Local::now().date_naive();
+                let day_of_month = command.day_of_month.unwrap_or(config.day_of_month);
+                let interval_weeks = command.interval_weeks.unwrap_or(config.interval_weeks);
+                if config.is_active && command.is_active {
+                    let current = AllowanceScheduleChange::from_config(&config, today);
+                    if !current.matches(frequency, day_of_month, interval_weeks, anchor_date) {
+                        config.schedule_change =
+                            Self::record_schedule_change(&config, frequency, day_of_month, interval_weeks, anchor_date, today);
+                        // A day change still describes the weeks before a switch away
+                        // from weekly; moving back to weekly starts the weeks afresh
+                        if frequency == AllowanceFrequency::Weekly {
+                            config.day_change = None;
+                        }
+                    } else if frequency == AllowanceFrequency::Weekly && config.day_of_week != day_of_week {
+                        config.day_change = Self::record_day_change(&config, day_of_week, today);
+                    }
+                }
                 config.amount = command.amount;
-                config.day_of_week = command.day_of_week;
+                config.day_of_week = day_of_week;
+                config.frequency = frequency;
+                config.day_of_month = day_of_month;
+                config.interval_weeks = interval_weeks;
+                config.anchor_date = anchor_date;
                 config.is_active = command.is_active;
+                if let Some(policy) = command.day_change_policy {
+                    config.day_change_policy = policy;
+                }
                 config.updated_at = timestamp_rfc3339;
                 config
             }
@@ -144,10 +202,17 @@ impl AllowanceService {
                 AllowanceConfig {
                     child_id: child_id.clone(),
                     amount: command.amount,
-                    day_of_week: command.day_of_week,
+                    day_of_week,
                     is_active: command.is_active,
                     created_at: timestamp_rfc3339.clone(),
                     updated_at: timestamp_rfc3339,
+                    day_change_policy: command.day_change_policy.unwrap_or_default(),
+                    day_change: None,
+                    frequency,
+                    day_of_month: command.day_of_month.unwrap_or(1),
+                    interval_weeks: command.interval_weeks.unwrap_or(MIN_INTERVAL_WEEKS),
+                    anchor_date,
+                    schedule_change: None,
                 }
             }
         };
@@ -157,10 +222,10 @@ impl AllowanceService {
             .store_allowance_config(&domain_allowance_config)?;
 
         info!(
-            "Updated allowance config for child {}: ${:.2} on {}s",
+            "Updated allowance config for child {}: ${:.2} {}",
             child_id,
             domain_allowance_config.amount,
-            domain_allowance_config.day_name()
+            domain_allowance_config.schedule_label()
         );
 
         Ok(UpdateAllowanceConfigResult {
@@ -169,6 +234,51 @@ impl AllowanceService {
         })
     }
 
+    /// Work out the day change to record when an active allowance moves to `new_day_of_week` on `today`
+    ///
+    /// Changing the day more than once on the same day keeps the original
+    /// day, so the switch-over is measured from the last allowance actually
+    /// paid; changing it back clears the change altogether.
+    fn record_day_change(config: &AllowanceConfig, new_day_of_week: u8, today: NaiveDate) -> Option<AllowanceDayChange> {
+        let previous_day_of_week = match config.day_change {
+            Some(change) if change.effective_date == today => change.previous_day_of_week,
+            _ => config.day_of_week,
+        };
+        if previous_day_of_week == new_day_of_week {
+            return None;
+        }
+
+        info!("Allowance day for child {} moving from {} to {} on {} ({:?})",
+              config.child_id, previous_day_of_week, new_day_of_week, today, config.day_change_policy);
+        Some(AllowanceDayChange { previous_day_of_week, effective_date: today })
+    }
+
+    /// Work out the schedule change to record when an active allowance moves
+    /// to `frequency` (with its day of month or interval) on `today`
+    ///
+    /// Like day changes, changing more than once on the same day keeps the
+    /// original schedule, and changing back to it clears the change.
+    fn record_schedule_change(
+        config: &AllowanceConfig,
+        frequency: AllowanceFrequency,
+        day_of_month: u8,
+        interval_weeks: u8,
+        anchor_date: Option<NaiveDate>,
+        today: NaiveDate,
+    ) -> Option<AllowanceScheduleChange> {
+        let previous = match config.schedule_change {
+            Some(change) if change.effective_date == today => change,
+            _ => AllowanceScheduleChange::from_config(config, today),
+        };
+        if previous.matches(frequency, day_of_month, interval_weeks, anchor_date) {
+            return None;
+        }
+
+        info!("Allowance for child {} moving from {:?} to {:?} (day of month {}) on {}",
+              config.child_id, previous.previous_frequency, frequency, day_of_month, today);
+        Some(previous)
+    }
+
     /// Delete allowance configuration for a child
     pub fn delete_allowance_config(&self, child_id: &str) -> Result<bool> {
         info!("Deleting allowance config for child: {}", child_id);
@@ -214,8 +324,8 @@ impl AllowanceService {
         
         let config = match allowance_config {
             Some(config) if config.is_active => {
-                info!("🔮 ALLOWANCE DEBUG: Found active config - amount: ${:.2}, day_of_week: {} ({})", 
-                     config.amount, config.day_of_week, config.day_name());
+                info!("🔮 ALLOWANCE DEBUG: Found active config - amount: ${:.2}, {}", 
+                     config.amount, config.schedule_label());
                 config
             },
             Some(_config) => {
@@ -228,6 +338,14 @@ impl AllowanceService {
             }
         };
 
+        // Advance repayments still to come make those allowances smaller
+        let mut deductions: std::collections::HashMap<NaiveDate, f64> = std::collections::HashMap::new();
+        for advance in self.advance_repository.list_advances(child_id)? {
+            for installment in advance.installments.iter().filter(|i| !i.is_paid()) {
+                *deductions.entry(installment.due_date).or_default() += installment.amount;
+            }
+        }
+
         let mut future_allowances = Vec::new();
         let current_date = Local::now().date_naive();
         
@@ -240,14 +358,14 @@ impl AllowanceService {
             checked_days += 1;
             let day_of_week = current.weekday().num_days_from_sunday() as u8;
             let is_future = current > current_date;
-            let matches_allowance_day = day_of_week == config.day_of_week;
+            let amount_due = config.amount_due_on(current);
             
-            info!("🔮 ALLOWANCE DEBUG: Checking date {} - day_of_week: {} ({}), is_future: {}, matches_allowance_day: {}", 
-                 current, day_of_week, current.weekday(), is_future, matches_allowance_day);
+            info!("🔮 ALLOWANCE DEBUG: Checking date {} - day_of_week: {} ({}), is_future: {}, amount_due: {:?}", 
+                 current, day_of_week, current.weekday(), is_future, amount_due);
             
-            // Check if this date is in the future and matches the allowance day of week
+            // Check if this date is in the future and has an allowance due
             if current > current_date {
-                if day_of_week == config.day_of_week {
+                if let Some(amount) = amount_due {
                     info!("🔮 ALLOWANCE DEBUG: ✅ CREATING future allowance for {} on {}", child_id, current);
                     
                     // This is a future allowance day!
@@ -258,21 +376,31 @@ impl AllowanceService {
                         .single()
                         .unwrap();
                     
+                    let (description, amount) = match deductions.get(&current) {
+                        Some(deduction) => (
+                            format!("Upcoming allowance (${:.2} repays advance)", deduction),
+                            ((amount - deduction) * 100.0).round() / 100.0,
+                        ),
+                        None => ("Upcoming allowance".to_string(), amount),
+                    };
                     let allowance_transaction = DomainTransaction {
                         id: format!("future-allowance::{}::{}", child_id, current.format("%Y-%m-%d")),
                         child_id: child_id.to_string(),
                         date: transaction_datetime,
-                        description: "Upcoming allowance".to_string(),
-                        amount: config.amount,
+                        description,
+                        amount,
                         balance: f64::NAN, // Balance calculation delegated to BalanceService
                         transaction_type: DomainTransactionType::FutureAllowance,
+                        actor: None,
+                        category: None,
+                        icon: None,
                     };
                     
                     future_allowances.push(allowance_transaction);
                     info!("🔮 ALLOWANCE DEBUG: Generated future allowance for {} on {} (day_of_week: {}, expected: {}, datetime: {})", 
                           child_id, current, day_of_week, config.day_of_week, transaction_datetime);
                 } else {
-                    info!("🔮 ALLOWANCE DEBUG: ❌ Future date {} doesn't match allowance day (got {}, need {})", 
+                    info!("🔮 ALLOWANCE DEBUG: ❌ Future date {} has no allowance due (day_of_week {}, allowance day {})", 
                          current, day_of_week, config.day_of_week);
                 }
             } else {
@@ -330,14 +458,12 @@ impl AllowanceService {
         // Iterate through each date in the range
         let mut current = from_date;
         while current <= to_date && current <= current_date {
-            let day_of_week = current.weekday().num_days_from_sunday() as u8;
-            
-            if day_of_week == config.day_of_week {
+            if let Some(amount) = config.amount_due_on(current) {
                 // This is an allowance day - check if allowance already exists
                 if !self.has_allowance_for_date(&config.child_id, current)? {
-                    pending_dates.push((current, config.amount));
+                    pending_dates.push((current, amount));
                     info!("🎯 Found pending allowance for {} on {} (${:.2})", 
-                          child_id, current, config.amount);
+                          child_id, current, amount);
                 }
             }
             
@@ -355,6 +481,42 @@ impl AllowanceService {
         Ok(pending_dates)
     }
 
+    /// Find the next allowance due after `after`, looking up to five weeks ahead
+    ///
+    /// Returns the date and amount, or `None` if the child has no active
+    /// allowance (or the switch-over rules skip everything in that window).
+    pub fn next_allowance(&self, child_id: &str, after: NaiveDate) -> Result<Option<(NaiveDate, f64)>> {
+        let config = match self.allowance_repository.get_allowance_config(child_id)? {
+            Some(config) if config.is_active => config,
+            _ => return Ok(None),
+        };
+
+        Ok(after
+            .iter_days()
+            .skip(1)
+            .take(35)
+            .find_map(|date| config.amount_due_on(date).map(|amount| (date, amount))))
+    }
+
+    /// The next `count` allowance days after `after`, looking up to a year ahead
+    ///
+    /// Returns fewer than `count` when the child has no active allowance or the
+    /// year runs out first.
+    pub fn upcoming_allowances(&self, child_id: &str, after: NaiveDate, count: usize) -> Result<Vec<(NaiveDate, f64)>> {
+        let config = match self.allowance_repository.get_allowance_config(child_id)? {
+            Some(config) if config.is_active => config,
+            _ => return Ok(Vec::new()),
+        };
+
+        Ok(after
+            .iter_days()
+            .skip(1)
+            .take(366)
+            .filter_map(|date| config.amount_due_on(date).map(|amount| (date, amount)))
+            .take(count)
+            .collect())
+    }
+
     /// Check if an allowance already exists for a specific date
     /// This is used to prevent duplicate allowances
     fn has_allowance_for_date(&self, child_id: &str, date: NaiveDate) -> Result<bool> {
@@ -463,6 +625,12 @@ mod tests {
             amount: 10.0,
             day_of_week: 1, // Monday
             is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
 
         let update_response = service
@@ -505,6 +673,12 @@ mod tests {
             amount: 5.0,
             day_of_week: 0, // Sunday
             is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
 
         let _initial_response = service
@@ -517,6 +691,12 @@ mod tests {
             amount: 15.0,
             day_of_week: 6, // Saturday
             is_active: false,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
 
         let update_response = service
@@ -531,6 +711,90 @@ mod tests {
         assert_eq!(update_response.allowance_config.day_name(), "Saturday");
     }
 
+    #[test]
+    fn test_day_change_is_recorded() {
+        use crate::backend::domain::models::allowance::DayChangePolicy;
+
+        let service = setup_test();
+        let child = create_test_child(&service);
+        let update = |day_of_week: u8, day_change_policy: Option<DayChangePolicy>| {
+            service
+                .update_allowance_config(UpdateAllowanceConfigCommand {
+                    child_id: Some(child.id.clone()),
+                    amount: 7.0,
+                    day_of_week,
+                    is_active: true,
+                    day_change_policy,
+                    frequency: None,
+                    day_of_month: None,
+                    interval_weeks: None,
+                    anchor_date: None,
+                    expected_updated_at: None,
+                })
+                .unwrap()
+                .allowance_config
+        };
+
+        let config = update(5, Some(DayChangePolicy::Prorate)); // Friday
+        assert_eq!(config.day_change, None);
+        assert_eq!(config.day_change_policy, DayChangePolicy::Prorate);
+
+        // Two changes on the same day are measured from the original Friday
+        update(6, None);
+        let config = update(0, None);
+        let change = config.day_change.expect("day change should be recorded");
+        assert_eq!(change.previous_day_of_week, 5);
+        assert_eq!(change.effective_date, Local::now().date_naive());
+        assert_eq!(config.day_change_policy, DayChangePolicy::Prorate);
+
+        // Changing back to Friday the same day undoes the change
+        let config = update(5, None);
+        assert_eq!(config.day_change, None);
+    }
+
+    #[test]
+    fn test_switch_to_monthly_is_recorded() {
+        let service = setup_test();
+        let child = create_test_child(&service);
+        let update = |frequency: AllowanceFrequency, day_of_month: u8| {
+            service
+                .update_allowance_config(UpdateAllowanceConfigCommand {
+                    child_id: Some(child.id.clone()),
+                    amount: 20.0,
+                    day_of_week: 5,
+                    is_active: true,
+                    day_change_policy: None,
+                    frequency: Some(frequency),
+                    day_of_month: Some(day_of_month),
+                    interval_weeks: None,
+                    anchor_date: None,
+                    expected_updated_at: None,
+                })
+                .map(|result| result.allowance_config)
+        };
+
+        let config = update(AllowanceFrequency::Weekly, 1).unwrap();
+        assert_eq!(config.schedule_change, None);
+
+        // Earlier Fridays are still paid weekly when catching up
+        let config = update(AllowanceFrequency::Monthly, 31).unwrap();
+        assert_eq!(config.schedule_label(), "on the 31st of every month (or its last day)");
+        let change = config.schedule_change.expect("schedule change should be recorded");
+        assert_eq!(change.previous_frequency, AllowanceFrequency::Weekly);
+        assert_eq!(change.previous_day_of_week, 5);
+        assert_eq!(change.effective_date, Local::now().date_naive());
+
+        let next = service.next_allowance(&child.id, NaiveDate::from_ymd_opt(2099, 2, 1).unwrap()).unwrap();
+        assert_eq!(next, Some((NaiveDate::from_ymd_opt(2099, 2, 28).unwrap(), 20.0)));
+
+        // Switching back the same day undoes the change
+        let config = update(AllowanceFrequency::Weekly, 31).unwrap();
+        assert_eq!(config.schedule_change, None);
+
+        assert!(update(AllowanceFrequency::Monthly, 32).is_err());
+        assert!(update(AllowanceFrequency::Monthly, 0).is_err());
+    }
+
     #[test]
     fn test_invalid_day_of_week() {
         let service = setup_test();
@@ -541,6 +805,12 @@ mod tests {
             amount: 10.0,
             day_of_week: 7, // Invalid - should be 0-6
             is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
 
         let result = service.update_allowance_config(command);
@@ -558,6 +828,12 @@ mod tests {
             amount: -5.0,
             day_of_week: 1,
             is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
 
         let result = service.update_allowance_config(command);
@@ -576,6 +852,12 @@ mod tests {
             amount: 10.0,
             day_of_week: 1,
             is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
 
         service
@@ -627,6 +909,12 @@ mod tests {
             amount: 5.0,
             day_of_week: 1,
             is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
 
         let command2 = UpdateAllowanceConfigCommand {
@@ -634,6 +922,12 @@ mod tests {
             amount: 10.0,
             day_of_week: 5,
             is_active: false,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
 
         service
@@ -673,6 +967,13 @@ mod tests {
             is_active: true,
             created_at: chrono::Utc::now().to_rfc3339(),
             updated_at: chrono::Utc::now().to_rfc3339(),
+            day_change_policy: Default::default(),
+            day_change: None,
+            frequency: AllowanceFrequency::Weekly,
+            day_of_month: 1,
+            interval_weeks: 2,
+            anchor_date: None,
+            schedule_change: None,
         };
 
         let days = [
@@ -729,6 +1030,12 @@ mod tests {
             amount: 10.0,
             day_of_week: 1, // Monday
             is_active: false, // Inactive
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
 
         service
@@ -759,6 +1066,12 @@ mod tests {
             amount: 5.0,
             day_of_week: 0, // Sunday
             is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
 
         service
@@ -849,6 +1162,9 @@ mod tests {
             amount: 5.0,
             balance: 5.0,
             transaction_type: DomainTransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
 
         // Store the transaction
@@ -882,6 +1198,9 @@ mod tests {
             amount: -2.0, // Negative amount (expense)
             balance: 3.0,
             transaction_type: DomainTransactionType::Expense,
+            actor: None,
+            category: None,
+            icon: None,
         };
 
         // Store the transaction
@@ -910,6 +1229,12 @@ mod tests {
             amount: 10.0,
             day_of_week: 5, // Friday
             is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
 
         service
@@ -938,6 +1263,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_every_n_weeks_future_allowances_follow_the_anchor() {
+        let service = setup_test();
+        let child = create_test_child(&service);
+        let first_payday = Local::now().date_naive() + chrono::Duration::days(3);
+        let command = |interval_weeks: Option<u8>, anchor_date: Option<NaiveDate>| UpdateAllowanceConfigCommand {
+            child_id: Some(child.id.clone()),
+            amount: 25.0,
+            day_of_week: 1,
+            is_active: true,
+            day_change_policy: None,
+            frequency: Some(AllowanceFrequency::EveryNWeeks),
+            day_of_month: None,
+            interval_weeks,
+            anchor_date,
+            expected_updated_at: None,
+        };
+
+        // The first payday is required, and the interval has to be a few weeks
+        assert!(service.update_allowance_config(command(Some(2), None)).is_err());
+        assert!(service.update_allowance_config(command(Some(1), Some(first_payday))).is_err());
+        let config = service
+            .update_allowance_config(command(Some(2), Some(first_payday)))
+            .unwrap()
+            .allowance_config;
+        assert_eq!(config.day_of_week, first_payday.weekday().num_days_from_sunday() as u8);
+
+        // Two months of calendar chips, whichever months they fall in
+        let today = Local::now().date_naive();
+        let dates: Vec<NaiveDate> = service
+            .generate_future_allowance_transactions(&child.id, today, today + chrono::Duration::days(60))
+            .unwrap()
+            .iter()
+            .map(|allowance| allowance.date.date_naive())
+            .collect();
+        let expected: Vec<NaiveDate> = (0..5).map(|n| first_payday + chrono::Duration::weeks(2 * n)).collect();
+        assert_eq!(dates, expected);
+
+        let upcoming = service.upcoming_allowances(&child.id, today, 3).unwrap();
+        assert_eq!(upcoming.iter().map(|(date, _)| *date).collect::<Vec<_>>(), expected[..3]);
+    }
+
     #[test]
     fn test_duplicate_allowance_prevention() {
         let service = setup_test();
@@ -952,6 +1319,12 @@ mod tests {
             amount: 10.0,
             day_of_week,
             is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
 
         service
@@ -976,6 +1349,9 @@ mod tests {
             amount: 10.0,
             balance: 10.0,
             transaction_type: DomainTransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
 
         service
@@ -1014,6 +1390,9 @@ mod tests {
             amount: 10.0,
             balance: 10.0,
             transaction_type: DomainTransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
 
         service
@@ -1030,6 +1409,9 @@ mod tests {
             amount: 10.0,
             balance: 20.0,
             transaction_type: DomainTransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
 
         service
@@ -1067,6 +1449,12 @@ mod tests {
             amount: 10.0,
             day_of_week,
             is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
 
         service
@@ -1099,6 +1487,9 @@ mod tests {
             amount: 10.0,
             balance: 10.0,
             transaction_type: DomainTransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
 
         service
@@ -1137,6 +1528,9 @@ mod tests {
             amount: 10.0,
             balance: 10.0,
             transaction_type: DomainTransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
 
         service
@@ -1159,6 +1553,9 @@ mod tests {
             amount: 20.0,
             balance: 30.0,
             transaction_type: DomainTransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
 
         service
@@ -1181,6 +1578,9 @@ mod tests {
             amount: 50.0,
             balance: 80.0,
             transaction_type: DomainTransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
 
         service
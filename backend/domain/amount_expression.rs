@@ -0,0 +1,162 @@
+//! Arithmetic in amount fields.
+//!
+//! Lets kids type "3*1.25" or "10-2.50" instead of working the total out
+//! themselves. The evaluator is a small recursive-descent parser over
+//! numbers, `+ - * /` and parentheses; nothing else is accepted, so input
+//! can't do anything but produce a number.
+
+/// Longest expression accepted, to keep parsing trivially cheap
+pub const MAX_EXPRESSION_LENGTH: usize = 64;
+
+/// Deepest parenthesis nesting accepted
+const MAX_NESTING: usize = 8;
+
+/// Whether the input looks like arithmetic rather than a plain number
+pub fn is_expression(input: &str) -> bool {
+    let trimmed = input.trim();
+    // A leading sign alone is still a plain number
+    let body = trimmed.strip_prefix(['-', '+']).unwrap_or(trimmed);
+    body.contains(['+', '-', '*', '/', '(', ')', 'x', '×', '÷'])
+}
+
+/// Evaluate an arithmetic expression, rounding results that are a whole
+/// number of cents up to floating point noise (3 * 1.1 = 3.30)
+pub fn evaluate(input: &str) -> Result<f64, String> {
+    if input.len() > MAX_EXPRESSION_LENGTH {
+        return Err(format!("Expression is too long (maximum {} characters)", MAX_EXPRESSION_LENGTH));
+    }
+
+    // Accept the multiplication and division signs kids are taught
+    let normalized: String = input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match c {
+            'x' | 'X' | '×' => '*',
+            '÷' => '/',
+            other => other,
+        })
+        .collect();
+
+    let mut parser = Parser { chars: normalized.chars().collect(), pos: 0, depth: 0 };
+    let value = parser.expression()?;
+    if parser.pos < parser.chars.len() {
+        return Err(format!("Unexpected '{}' in amount", parser.chars[parser.pos]));
+    }
+    if !value.is_finite() {
+        return Err("Amount is not a valid number".to_string());
+    }
+
+    let cents = (value * 100.0).round() / 100.0;
+    Ok(if (value - cents).abs() < 1e-9 { cents } else { value })
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser {
+    /// expression := term (('+' | '-') term)*
+    fn expression(&mut self) -> Result<f64, String> {
+        let mut value = self.term()?;
+        while let Some(op) = self.peek().filter(|c| *c == '+' || *c == '-') {
+            self.pos += 1;
+            let rhs = self.term()?;
+            value = if op == '+' { value + rhs } else { value - rhs };
+        }
+        Ok(value)
+    }
+
+    /// term := factor (('*' | '/') factor)*
+    fn term(&mut self) -> Result<f64, String> {
+        let mut value = self.factor()?;
+        while let Some(op) = self.peek().filter(|c| *c == '*' || *c == '/') {
+            self.pos += 1;
+            let rhs = self.factor()?;
+            if op == '*' {
+                value *= rhs;
+            } else if rhs == 0.0 {
+                return Err("Can't divide by zero".to_string());
+            } else {
+                value /= rhs;
+            }
+        }
+        Ok(value)
+    }
+
+    /// factor := '-' factor | '(' expression ')' | number
+    fn factor(&mut self) -> Result<f64, String> {
+        match self.peek() {
+            Some('-') => {
+                self.pos += 1;
+                Ok(-self.factor()?)
+            }
+            Some('(') => {
+                self.depth += 1;
+                if self.depth > MAX_NESTING {
+                    return Err("Too many parentheses".to_string());
+                }
+                self.pos += 1;
+                let value = self.expression()?;
+                if self.peek() != Some(')') {
+                    return Err("Missing closing parenthesis".to_string());
+                }
+                self.pos += 1;
+                self.depth -= 1;
+                Ok(value)
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => self.number(),
+            Some(c) => Err(format!("Unexpected '{}' in amount", c)),
+            None => Err("Amount expression is incomplete".to_string()),
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, String> {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit() || c == '.') {
+            self.pos += 1;
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse::<f64>().map_err(|_| format!("'{}' is not a number", text))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluates_basic_arithmetic() {
+        assert_eq!(evaluate("3*1.25"), Ok(3.75));
+        assert_eq!(evaluate("10-2.50"), Ok(7.5));
+        assert_eq!(evaluate("2 + 3 * 4"), Ok(14.0));
+        assert_eq!(evaluate("(2+3)*4"), Ok(20.0));
+        assert_eq!(evaluate("3 x 1.10"), Ok(3.3));
+        assert_eq!(evaluate("9 ÷ 3"), Ok(3.0));
+        assert_eq!(evaluate("-2+5"), Ok(3.0));
+    }
+
+    #[test]
+    fn test_rejects_malformed_input() {
+        assert!(evaluate("3*").is_err());
+        assert!(evaluate("(3+1").is_err());
+        assert!(evaluate("5/0").is_err());
+        assert!(evaluate("1.2.3*2").is_err());
+        assert!(evaluate("abc").is_err());
+        assert!(evaluate(&"1+".repeat(40)).is_err());
+        assert!(evaluate(&format!("{}1{}", "(".repeat(10), ")".repeat(10))).is_err());
+    }
+
+    #[test]
+    fn test_is_expression() {
+        assert!(is_expression("3*1.25"));
+        assert!(is_expression("10 - 2.50"));
+        assert!(!is_expression("12.50"));
+        assert!(!is_expression("-5"));
+    }
+}
@@ -0,0 +1,240 @@
+//! Typed amounts in the form the user's locale writes them.
+//!
+//! People type "1.234,56" in Germany, "1 234,56" in France and "1,234.56" in
+//! the US, often with a currency symbol in front, and number pads produce a
+//! dot whatever the locale. [`normalize_amount`] turns any of these into a
+//! plain dot-decimal string `f64::from_str` (or the arithmetic evaluator in
+//! `amount_expression`) can read, and explains what is wrong when it can't.
+//!
+//! How a separator is read:
+//! - With both `.` and `,` present, whichever comes last is the decimal point.
+//! - A separator used more than once groups thousands ("1,234,567").
+//! - A single separator is a decimal point, unless it is followed by exactly
+//!   three digits and isn't the locale's decimal separator: "1,234" is 1234
+//!   in the US and 1.234 in Germany.
+//! - Spaces and apostrophes only ever group thousands.
+//!
+//! Thousands groups are checked, so "1,23.45" is rejected rather than
+//! guessed at.
+
+use crate::backend::domain::amount_expression;
+use crate::backend::domain::localization::Locale;
+use crate::backend::domain::models::currency::Currency;
+
+/// Characters that only ever group thousands: spaces, no-break spaces and
+/// the apostrophe used in Switzerland
+const GROUPING_ONLY: [char; 4] = [' ', '\u{a0}', '\u{202f}', '\''];
+
+/// Rewrite a typed amount as a dot-decimal number or arithmetic expression,
+/// without currency symbols or thousands separators
+pub fn normalize_amount(input: &str, locale: Locale, currency_symbol: &str) -> Result<String, String> {
+    let stripped = strip_currency_symbols(input, currency_symbol);
+    let stripped = stripped.trim();
+    if stripped.is_empty() {
+        return Err("Empty amount after cleaning".to_string());
+    }
+
+    if amount_expression::is_expression(stripped) {
+        return normalize_expression(stripped, locale);
+    }
+
+    let (sign, number) = match stripped.strip_prefix(['-', '+']) {
+        Some(rest) => (&stripped[..1], rest.trim_start()),
+        None => ("", stripped),
+    };
+    let normalized = normalize_number(number, locale)?;
+    Ok(if sign == "-" { format!("-{}", normalized) } else { normalized })
+}
+
+/// Remove the configured symbol and any known currency's symbol, longest first
+/// so "CA$" isn't left as "CA"
+fn strip_currency_symbols(input: &str, currency_symbol: &str) -> String {
+    let mut symbols: Vec<&str> = Currency::ALL.iter().map(|currency| currency.symbol()).collect();
+    if !currency_symbol.is_empty() {
+        symbols.push(currency_symbol);
+    }
+    symbols.sort_by_key(|symbol| std::cmp::Reverse(symbol.len()));
+
+    let mut stripped = input.to_string();
+    for symbol in symbols {
+        stripped = stripped.replace(symbol, "");
+    }
+    stripped
+}
+
+/// Normalize each number in an arithmetic expression, leaving the operators
+fn normalize_expression(expression: &str, locale: Locale) -> Result<String, String> {
+    let mut normalized = String::with_capacity(expression.len());
+    let mut number = String::new();
+    for c in expression.chars() {
+        if is_number_char(c) {
+            number.push(c);
+            continue;
+        }
+        if !number.trim().is_empty() {
+            normalized.push_str(&normalize_number(number.trim(), locale)?);
+        }
+        number.clear();
+        normalized.push(c);
+    }
+    if !number.trim().is_empty() {
+        normalized.push_str(&normalize_number(number.trim(), locale)?);
+    }
+    Ok(normalized)
+}
+
+fn is_number_char(c: char) -> bool {
+    c.is_ascii_digit() || c == '.' || c == ',' || GROUPING_ONLY.contains(&c)
+}
+
+/// Normalize one unsigned number such as "1.234,56"
+fn normalize_number(number: &str, locale: Locale) -> Result<String, String> {
+    if let Some(bad) = number.chars().find(|c| !is_number_char(*c)) {
+        return Err(format!("Unexpected '{}' in amount", bad));
+    }
+    if !number.chars().any(|c| c.is_ascii_digit()) {
+        return Err(format!("\"{}\" has no digits", number));
+    }
+
+    let decimal = decimal_separator_in(number, locale);
+    if decimal.is_some_and(|separator| number.matches(separator).count() > 1) {
+        return Err(format!("\"{}\" has more than one decimal separator", number));
+    }
+    let (whole, fraction) = match decimal.and_then(|separator| number.rfind(separator)) {
+        Some(position) => (&number[..position], Some(&number[position + 1..])),
+        None => (number, None),
+    };
+
+    if let Some(fraction) = fraction {
+        if !fraction.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("\"{}\" has a separator after the decimal point", number));
+        }
+    }
+
+    let groups: Vec<&str> = whole.split(|c: char| !c.is_ascii_digit()).collect();
+    if groups.len() > 1 {
+        let first_ok = (1..=3).contains(&groups[0].len());
+        let rest_ok = groups[1..].iter().all(|group| group.len() == 3);
+        if !first_ok || !rest_ok {
+            return Err(format!("The thousands separators in \"{}\" are in the wrong places", number));
+        }
+    }
+
+    let whole_digits: String = groups.concat();
+    let whole_digits = if whole_digits.is_empty() { "0".to_string() } else { whole_digits };
+    Ok(match fraction {
+        Some(fraction) if !fraction.is_empty() => format!("{}.{}", whole_digits, fraction),
+        _ => whole_digits,
+    })
+}
+
+/// Which of `.` and `,` is the decimal point in `number`, if either is
+fn decimal_separator_in(number: &str, locale: Locale) -> Option<char> {
+    let last_dot = number.rfind('.');
+    let last_comma = number.rfind(',');
+    match (last_dot, last_comma) {
+        (None, None) => None,
+        (Some(dot), Some(comma)) => Some(if dot > comma { '.' } else { ',' }),
+        (Some(_), None) => single_kind_decimal(number, '.', locale),
+        (None, Some(_)) => single_kind_decimal(number, ',', locale),
+    }
+}
+
+fn single_kind_decimal(number: &str, separator: char, locale: Locale) -> Option<char> {
+    if number.matches(separator).count() > 1 {
+        return None;
+    }
+    let (before, after) = number.split_once(separator)?;
+    let digits_before = before.chars().filter(|c| c.is_ascii_digit()).count();
+    let looks_grouped = after.len() == 3 && after.chars().all(|c| c.is_ascii_digit()) && (1..=3).contains(&digits_before);
+    if looks_grouped && separator != locale.decimal_separator() {
+        None
+    } else {
+        Some(separator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn us(input: &str) -> Result<String, String> {
+        normalize_amount(input, Locale::EnUs, "$")
+    }
+
+    fn de(input: &str) -> Result<String, String> {
+        normalize_amount(input, Locale::DeDe, "€")
+    }
+
+    fn fr(input: &str) -> Result<String, String> {
+        normalize_amount(input, Locale::FrFr, "€")
+    }
+
+    #[test]
+    fn test_plain_and_symbol_prefixed_amounts() {
+        assert_eq!(us("12").unwrap(), "12");
+        assert_eq!(us("$ 12").unwrap(), "12");
+        assert_eq!(us("  $12.50 ").unwrap(), "12.50");
+        assert_eq!(us("12$").unwrap(), "12");
+        assert_eq!(de("12 €").unwrap(), "12");
+        assert_eq!(us("CA$5").unwrap(), "5");
+        assert_eq!(us("£3.20").unwrap(), "3.20");
+        assert_eq!(us(".5").unwrap(), "0.5");
+        assert_eq!(us("5.").unwrap(), "5");
+        assert_eq!(us("-4.25").unwrap(), "-4.25");
+        assert_eq!(us("- $4").unwrap(), "-4");
+    }
+
+    #[test]
+    fn test_locale_grouping_and_decimals() {
+        assert_eq!(us("1,234.56").unwrap(), "1234.56");
+        assert_eq!(de("1.234,56").unwrap(), "1234.56");
+        assert_eq!(fr("1 234,56").unwrap(), "1234.56");
+        assert_eq!(fr("1\u{202f}234,56").unwrap(), "1234.56");
+        assert_eq!(us("1'234.56").unwrap(), "1234.56");
+        assert_eq!(us("1,234,567").unwrap(), "1234567");
+        assert_eq!(de("1.234.567").unwrap(), "1234567");
+        assert_eq!(de("12,50").unwrap(), "12.50");
+    }
+
+    #[test]
+    fn test_ambiguous_single_separators() {
+        // A comma with two digits after it can only be a decimal comma
+        assert_eq!(us("12,50").unwrap(), "12.50");
+        // Number pads type a dot even in comma-decimal locales
+        assert_eq!(de("5.50").unwrap(), "5.50");
+        // Three digits after the locale's grouping separator group thousands...
+        assert_eq!(us("1,234").unwrap(), "1234");
+        assert_eq!(de("1.234").unwrap(), "1234");
+        // ...but after its decimal separator they are decimals
+        assert_eq!(us("1.234").unwrap(), "1.234");
+        assert_eq!(de("1,234").unwrap(), "1.234");
+        // Four leading digits can't be a thousands group
+        assert_eq!(us("1234,567").unwrap(), "1234.567");
+        // Nor can a trailing group that isn't three digits
+        assert_eq!(us("1,2345").unwrap(), "1.2345");
+    }
+
+    #[test]
+    fn test_malformed_amounts_explain_the_problem() {
+        assert_eq!(us("").unwrap_err(), "Empty amount after cleaning");
+        assert_eq!(us("$").unwrap_err(), "Empty amount after cleaning");
+        assert_eq!(us("12abc").unwrap_err(), "Unexpected 'a' in amount");
+        assert_eq!(us(",").unwrap_err(), "\",\" has no digits");
+        assert!(us("1,23.45").unwrap_err().contains("wrong places"));
+        assert!(de("1.23,45").unwrap_err().contains("wrong places"));
+        assert!(us("12 50").unwrap_err().contains("wrong places"));
+        assert!(us("1,234.5.6").unwrap_err().contains("more than one decimal separator"));
+        assert!(de("1.234,5,6").unwrap_err().contains("more than one decimal separator"));
+        assert!(fr("1,5 0").unwrap_err().contains("after the decimal point"));
+    }
+
+    #[test]
+    fn test_expressions_use_the_locale_for_each_number() {
+        assert_eq!(de("3*1,25").unwrap(), "3*1.25");
+        assert_eq!(us("$10 - $2.50").unwrap(), "10-2.50");
+        assert_eq!(de("1.000 + 2,5").unwrap(), "1000+2.5");
+        assert_eq!(us("(2 + 3) x 1.5").unwrap(), "(2+3)x1.5");
+        assert!(us("2 + 1,23.4").is_err());
+    }
+}
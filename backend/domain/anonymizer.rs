@@ -0,0 +1,316 @@
+//! Anonymization of exported data for sharing.
+//!
+//! Turns a family's real data into a dataset that can be attached to a bug
+//! report or shown in a classroom: children get pseudonyms, every date moves
+//! by the same random number of whole weeks, and amounts are rounded. The
+//! shift is in whole weeks so allowances still land on their configured day,
+//! and balances are recalculated from the rounded amounts so they still add
+//! up. Names of children and of parents recorded on transactions are replaced
+//! wherever they appear in descriptions; other free text is kept as written.
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, Utc};
+use std::collections::HashMap;
+
+use crate::backend::domain::models::actor::Actor;
+use crate::backend::domain::models::allowance::AllowanceConfig;
+use crate::backend::domain::models::child::Child;
+use crate::backend::domain::models::goal::DomainGoal;
+use crate::backend::domain::models::transaction::Transaction;
+
+/// Fewest and most weeks dates are moved back by
+const MIN_SHIFT_WEEKS: i64 = 8;
+const MAX_SHIFT_WEEKS: i64 = 104;
+
+/// Amounts are rounded to whole dollars
+const AMOUNT_STEP: f64 = 1.0;
+
+/// Name given to every parent in descriptions
+const PARENT_PSEUDONYM: &str = "Parent";
+
+/// One consistent set of replacements for a whole export
+#[derive(Debug, Clone)]
+pub struct Anonymizer {
+    /// How far every date moves; always a whole number of weeks
+    shift: Duration,
+    /// Real child ID to (pseudonymous ID, pseudonym)
+    children: HashMap<String, (String, String)>,
+    /// Real names to replace in descriptions, longest first, with their pseudonym
+    names: Vec<(String, String)>,
+}
+
+impl Anonymizer {
+    /// Pick a random shift and pseudonyms for `children`
+    ///
+    /// `parent_names` are the names of parents that may appear in
+    /// descriptions, such as those recorded on transactions.
+    pub fn new(children: &[Child], parent_names: &[String]) -> Self {
+        let weeks = MIN_SHIFT_WEEKS + (uuid::Uuid::new_v4().as_u128() % (MAX_SHIFT_WEEKS - MIN_SHIFT_WEEKS + 1) as u128) as i64;
+        Self::with_shift(children, parent_names, weeks)
+    }
+
+    /// Like `new`, with dates moved back by exactly `weeks`
+    pub fn with_shift(children: &[Child], parent_names: &[String], weeks: i64) -> Self {
+        let shift = -Duration::weeks(weeks);
+        let mut pseudonyms: HashMap<String, (String, String)> = HashMap::new();
+        let mut names = Vec::new();
+        for (index, child) in children.iter().enumerate() {
+            let pseudonym = format!("Child {}", Self::letter(index));
+            // Child IDs embed when the child was added, so they are shifted too
+            let mut created_ms = (child.created_at + shift).timestamp_millis().max(0) as u64;
+            while pseudonyms.values().any(|(id, _)| *id == Child::generate_id(created_ms)) {
+                created_ms += 1;
+            }
+            let id = Child::generate_id(created_ms);
+            names.push((child.name.clone(), pseudonym.clone()));
+            pseudonyms.insert(child.id.clone(), (id, pseudonym));
+        }
+        for name in parent_names {
+            if !name.trim().is_empty() {
+                names.push((name.trim().to_string(), PARENT_PSEUDONYM.to_string()));
+            }
+        }
+        // "Anna" must not be replaced inside "Annabelle" first
+        names.sort_by_key(|(name, _)| std::cmp::Reverse(name.chars().count()));
+        Self { shift, children: pseudonyms, names }
+    }
+
+    /// "A" to "Z", then "AA", "AB" and so on
+    fn letter(index: usize) -> String {
+        let mut index = index;
+        let mut letters = Vec::new();
+        loop {
+            letters.push((b'A' + (index % 26) as u8) as char);
+            if index < 26 {
+                break;
+            }
+            index = index / 26 - 1;
+        }
+        letters.iter().rev().collect()
+    }
+
+    /// Pseudonymous ID for a real child ID
+    pub fn child_id(&self, child_id: &str) -> String {
+        self.children
+            .get(child_id)
+            .map(|(id, _)| id.clone())
+            .unwrap_or_else(|| "child::unknown".to_string())
+    }
+
+    pub fn child(&self, child: &Child) -> Child {
+        let name = self
+            .children
+            .get(&child.id)
+            .map(|(_, name)| name.clone())
+            .unwrap_or_else(|| "Child".to_string());
+        Child {
+            id: self.child_id(&child.id),
+            name,
+            birthdate: self.date(child.birthdate),
+            created_at: child.created_at + self.shift,
+            updated_at: child.updated_at + self.shift,
+        }
+    }
+
+    /// A child's transactions, shifted and rounded, with balances recalculated
+    pub fn transactions(&self, transactions: &[Transaction]) -> Vec<Transaction> {
+        let mut sorted: Vec<&Transaction> = transactions.iter().collect();
+        sorted.sort_by_key(|transaction| transaction.date);
+        let mut balance = 0.0;
+        sorted
+            .into_iter()
+            .map(|transaction| {
+                let date = transaction.date + self.shift;
+                let amount = Self::round(transaction.amount);
+                balance += amount;
+                Transaction {
+                    id: Transaction::generate_id(amount, date.timestamp_millis().max(0) as u64),
+                    child_id: self.child_id(&transaction.child_id),
+                    date,
+                    description: self.text(&transaction.description),
+                    amount,
+                    balance,
+                    transaction_type: transaction.transaction_type.clone(),
+                    actor: transaction.actor.as_ref().map(|actor| match actor {
+                        Actor::Kid => Actor::Kid,
+                        Actor::Parent(_) => Actor::Parent(None),
+                    }),
+                    category: transaction.category.clone(),
+                    icon: transaction.icon.clone(),
+                }
+            })
+            .collect()
+    }
+
+    pub fn allowance_config(&self, config: &AllowanceConfig) -> AllowanceConfig {
+        let mut day_change = config.day_change;
+        if let Some(change) = day_change.as_mut() {
+            change.effective_date = self.date(change.effective_date);
+        }
+        let mut schedule_change = config.schedule_change;
+        if let Some(change) = schedule_change.as_mut() {
+            change.effective_date = self.date(change.effective_date);
+            change.previous_anchor_date = change.previous_anchor_date.map(|date| self.date(date));
+        }
+        AllowanceConfig {
+            child_id: self.child_id(&config.child_id),
+            amount: Self::round(config.amount),
+            day_of_week: config.day_of_week,
+            is_active: config.is_active,
+            created_at: self.timestamp(&config.created_at),
+            updated_at: self.timestamp(&config.updated_at),
+            day_change_policy: config.day_change_policy,
+            day_change,
+            frequency: config.frequency,
+            day_of_month: config.day_of_month,
+            interval_weeks: config.interval_weeks,
+            anchor_date: config.anchor_date.map(|date| self.date(date)),
+            schedule_change,
+        }
+    }
+
+    pub fn goal(&self, goal: &DomainGoal) -> DomainGoal {
+        let child_id = self.child_id(&goal.child_id);
+        let created_at = self.timestamp(&goal.created_at);
+        let created_ms = DateTime::parse_from_rfc3339(&created_at).map_or(0, |date| date.timestamp_millis().max(0) as u64);
+        DomainGoal {
+            id: DomainGoal::generate_id(&child_id, created_ms),
+            child_id,
+            description: self.text(&goal.description),
+            target_amount: Self::round(goal.target_amount),
+            state: goal.state.clone(),
+            created_at,
+            updated_at: self.timestamp(&goal.updated_at),
+        }
+    }
+
+    fn date(&self, date: NaiveDate) -> NaiveDate {
+        date + self.shift
+    }
+
+    /// Shift an RFC 3339 timestamp, keeping its offset; an unreadable one becomes the shifted current time
+    fn timestamp(&self, timestamp: &str) -> String {
+        DateTime::<FixedOffset>::parse_from_rfc3339(timestamp)
+            .map(|date| (date + self.shift).to_rfc3339())
+            .unwrap_or_else(|_| (Utc::now() + self.shift).to_rfc3339())
+    }
+
+    /// Round to the step, keeping at least one step so money in stays money in
+    fn round(amount: f64) -> f64 {
+        let rounded = (amount / AMOUNT_STEP).round() * AMOUNT_STEP;
+        if rounded == 0.0 && amount != 0.0 {
+            AMOUNT_STEP.copysign(amount)
+        } else {
+            rounded
+        }
+    }
+
+    /// Replace every known name in `text`
+    fn text(&self, text: &str) -> String {
+        self.names
+            .iter()
+            .fold(text.to_string(), |text, (name, pseudonym)| replace_word(&text, name, pseudonym))
+    }
+}
+
+/// Replace whole-word, case-insensitive occurrences of `word` in `text`
+fn replace_word(text: &str, word: &str, replacement: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let word: Vec<char> = word.chars().collect();
+    if word.is_empty() {
+        return text.to_string();
+    }
+    let same = |a: char, b: char| a.to_lowercase().eq(b.to_lowercase());
+    let mut result = String::with_capacity(text.len());
+    let mut index = 0;
+    while index < chars.len() {
+        let end = index + word.len();
+        let matches = end <= chars.len()
+            && chars[index..end].iter().zip(&word).all(|(a, b)| same(*a, *b))
+            && (index == 0 || !chars[index - 1].is_alphanumeric())
+            && (end == chars.len() || !chars[end].is_alphanumeric());
+        if matches {
+            result.push_str(replacement);
+            index = end;
+        } else {
+            result.push(chars[index]);
+            index += 1;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::models::transaction::TransactionType;
+
+    fn child(id: &str, name: &str) -> Child {
+        let created_at = DateTime::parse_from_rfc3339("2025-01-01T12:00:00Z").unwrap().with_timezone(&Utc);
+        Child {
+            id: id.to_string(),
+            name: name.to_string(),
+            birthdate: NaiveDate::from_ymd_opt(2015, 6, 1).unwrap(),
+            created_at,
+            updated_at: created_at,
+        }
+    }
+
+    fn transaction(date: &str, description: &str, amount: f64, actor: Option<Actor>) -> Transaction {
+        Transaction {
+            id: "in-1".to_string(),
+            child_id: "child::1".to_string(),
+            date: DateTime::parse_from_rfc3339(date).unwrap(),
+            description: description.to_string(),
+            amount,
+            balance: 999.0,
+            transaction_type: if amount >= 0.0 { TransactionType::Income } else { TransactionType::Expense },
+            actor,
+            category: Some("Toys".to_string()),
+            icon: None,
+        }
+    }
+
+    #[test]
+    fn test_anonymize_transactions() {
+        let children = [child("child::1", "Anna"), child("child::2", "Annabelle")];
+        let anonymizer = Anonymizer::with_shift(&children, &["Oma".to_string()], 10);
+
+        let anonymized = anonymizer.transactions(&[
+            transaction("2025-03-08T11:00:00-05:00", "Birthday from oma for Anna", 20.4, Some(Actor::Parent(Some("Oma".to_string())))),
+            transaction("2025-03-01T11:00:00-05:00", "Annabelle's old bike", 7.6, None),
+            transaction("2025-03-09T11:00:00-05:00", "Gum", -0.25, Some(Actor::Kid)),
+        ]);
+
+        let rows: Vec<(String, &str, f64, f64)> = anonymized
+            .iter()
+            .map(|t| (t.date.to_rfc3339(), t.description.as_str(), t.amount, t.balance))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("2024-12-21T11:00:00-05:00".to_string(), "Child B's old bike", 8.0, 8.0),
+                ("2024-12-28T11:00:00-05:00".to_string(), "Birthday from Parent for Child A", 20.0, 28.0),
+                ("2024-12-29T11:00:00-05:00".to_string(), "Gum", -1.0, 27.0),
+            ]
+        );
+        assert_eq!(anonymized[1].actor, Some(Actor::Parent(None)));
+        assert!(anonymized.iter().all(|t| t.child_id == anonymizer.child_id("child::1")));
+        // IDs carry a timestamp, which must be the shifted one
+        assert!(anonymized
+            .iter()
+            .all(|t| Transaction::parse_id(&t.id).unwrap().1 == t.date.timestamp_millis() as u64));
+
+        let pseudonym = anonymizer.child(&children[0]);
+        assert_eq!(pseudonym.name, "Child A");
+        assert_eq!(pseudonym.birthdate, NaiveDate::from_ymd_opt(2015, 3, 23).unwrap());
+        assert_ne!(pseudonym.id, anonymizer.child(&children[1]).id);
+    }
+
+    #[test]
+    fn test_letters_and_word_replacement() {
+        assert_eq!(Anonymizer::letter(0), "A");
+        assert_eq!(Anonymizer::letter(25), "Z");
+        assert_eq!(Anonymizer::letter(26), "AA");
+        assert_eq!(replace_word("Max and MAX, not Maxwell", "max", "Child A"), "Child A and Child A, not Maxwell");
+    }
+}
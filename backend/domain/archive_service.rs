@@ -0,0 +1,445 @@
+//! Archive service domain logic for the allowance tracker.
+//!
+//! Every read and write of a child's transactions goes through one CSV file,
+//! so years of history make the whole app slower. Archiving is opt-in: each
+//! whole month older than a cutoff is collapsed into a single summary
+//! transaction carrying the month's net amount and closing balance, and the
+//! original rows move to `transactions_archive.csv` next to it. Running
+//! balances don't change, so the balance, charts and calendar read the same
+//! before and after, and the originals stay available for auditing.
+//!
+//! Months in a closed period are left as they are, since closing a month
+//! promises its transactions won't change until a parent reopens it. So are
+//! months with a transaction that a savings allocation, loan, advance,
+//! reversal, planned purchase or balance milestone points at, which would
+//! otherwise lose what it refers to.
+
+use anyhow::Result;
+use chrono::{Datelike, Local, NaiveDate};
+use log::info;
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::commands::archive::{ArchiveTransactionsCommand, ArchiveTransactionsResult};
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::models::transaction::{Transaction as DomainTransaction, TransactionType};
+use crate::backend::storage::csv::{AllowanceAdvanceRepository, CelebrationRepository, CsvConnection, LoanRepository, PeriodLockRepository, PlannedPurchaseRepository, ReversalRepository, SavingsAllocationRepository, TransactionRepository};
+use crate::backend::storage::traits::{AllowanceAdvanceStorage, CelebrationStorage, LoanStorage, PeriodLockStorage, PlannedPurchaseStorage, SavingsAllocationStorage, TransactionReversalStorage, TransactionStorage};
+
+/// Service for rolling up old transactions into monthly summaries
+#[derive(Clone)]
+pub struct ArchiveService {
+    transaction_repository: TransactionRepository,
+    period_lock_repository: PeriodLockRepository,
+    savings_allocation_repository: SavingsAllocationRepository,
+    loan_repository: LoanRepository,
+    advance_repository: AllowanceAdvanceRepository,
+    reversal_repository: ReversalRepository,
+    planned_purchase_repository: PlannedPurchaseRepository,
+    celebration_repository: CelebrationRepository,
+    child_service: ChildService,
+}
+
+impl ArchiveService {
+    /// Create a new ArchiveService
+    pub fn new(csv_conn: Arc<CsvConnection>, child_service: ChildService) -> Self {
+        let transaction_repository = TransactionRepository::new((*csv_conn).clone());
+        let period_lock_repository = PeriodLockRepository::new((*csv_conn).clone());
+        Self {
+            transaction_repository,
+            period_lock_repository,
+            savings_allocation_repository: SavingsAllocationRepository::new((*csv_conn).clone()),
+            loan_repository: LoanRepository::new((*csv_conn).clone()),
+            advance_repository: AllowanceAdvanceRepository::new((*csv_conn).clone()),
+            reversal_repository: ReversalRepository::new((*csv_conn).clone()),
+            planned_purchase_repository: PlannedPurchaseRepository::new((*csv_conn).clone()),
+            celebration_repository: CelebrationRepository::new((*csv_conn).clone()),
+            child_service,
+        }
+    }
+
+    /// Roll up every whole month that ended at least `older_than_years` ago
+    pub fn archive_old_transactions(&self, command: ArchiveTransactionsCommand) -> Result<ArchiveTransactionsResult> {
+        self.archive_on(command, Local::now().date_naive())
+    }
+
+    fn archive_on(&self, command: ArchiveTransactionsCommand, today: NaiveDate) -> Result<ArchiveTransactionsResult> {
+        if command.older_than_years == 0 {
+            return Err(DomainError::invalid("Only transactions at least a year old can be archived").into());
+        }
+        let child_id = self.resolve_child_id(command.child_id)?;
+        let cutoff = archive_cutoff(today, command.older_than_years);
+        let period_lock = self.period_lock_repository.get_period_lock(&child_id)?;
+        let linked = self.linked_transaction_ids(&child_id)?;
+
+        let transactions = self.transaction_repository.list_transactions_chronological(&child_id, None, None)?;
+        let (old, recent): (Vec<_>, Vec<_>) = transactions.into_iter().partition(|t| t.date.date_naive() < cutoff);
+
+        let mut by_month: BTreeMap<(i32, u32), Vec<DomainTransaction>> = BTreeMap::new();
+        for transaction in old {
+            let date = transaction.date.date_naive();
+            by_month.entry((date.year(), date.month())).or_default().push(transaction);
+        }
+
+        let mut archived = Vec::new();
+        let mut remaining = Vec::new();
+        let mut summary_count = 0;
+        let mut skipped_closed_months = 0;
+        let mut skipped_linked_months = 0;
+        for month in by_month.into_values() {
+            // A month that is already a single row (often an earlier summary) stays as it is
+            if month.len() == 1 {
+                remaining.extend(month);
+                continue;
+            }
+            if period_lock.as_ref().is_some_and(|lock| month.iter().any(|t| lock.covers(t.date))) {
+                skipped_closed_months += 1;
+                remaining.extend(month);
+                continue;
+            }
+            if month.iter().any(|t| linked.contains(&t.id)) {
+                skipped_linked_months += 1;
+                remaining.extend(month);
+                continue;
+            }
+            remaining.push(monthly_summary(&month));
+            summary_count += 1;
+            archived.extend(month);
+        }
+
+        if !archived.is_empty() {
+            remaining.extend(recent);
+            remaining.sort_by_key(|t| t.date);
+            self.transaction_repository.archive_transactions_by_id(&child_id, &archived, &remaining)?;
+        }
+
+        info!(
+            "🗄️ ARCHIVE: {} rolled {} transactions before {} into {} monthly summaries, skipping {} closed and {} linked months",
+            child_id,
+            archived.len(),
+            cutoff,
+            summary_count,
+            skipped_closed_months,
+            skipped_linked_months
+        );
+
+        Ok(ArchiveTransactionsResult {
+            child_id,
+            cutoff,
+            archived_count: archived.len(),
+            summary_count,
+            skipped_closed_months,
+            skipped_linked_months,
+        })
+    }
+
+    /// Transactions that savings allocations, loans, advances, reversals,
+    /// confirmed planned purchases or reached milestones point at
+    fn linked_transaction_ids(&self, child_id: &str) -> Result<HashSet<String>> {
+        let mut linked = HashSet::new();
+        for allocation in self.savings_allocation_repository.list_allocations(child_id)? {
+            linked.insert(allocation.transaction_id);
+        }
+        for loan in self.loan_repository.list_loans(child_id)? {
+            linked.insert(loan.transaction_id);
+            linked.extend(loan.settlements.into_iter().map(|settlement| settlement.transaction_id));
+        }
+        for advance in self.advance_repository.list_advances(child_id)? {
+            linked.insert(advance.transaction_id);
+            linked.extend(advance.installments.into_iter().filter_map(|installment| installment.transaction_id));
+        }
+        for reversal in self.reversal_repository.list_reversals(child_id)? {
+            linked.insert(reversal.original_id);
+            linked.insert(reversal.reversal_id);
+        }
+        for purchase in self.planned_purchase_repository.list_planned_purchases(child_id)? {
+            linked.extend(purchase.transaction_id);
+        }
+        for milestone in self.celebration_repository.list_reached_milestones(child_id)? {
+            linked.insert(milestone.transaction_id);
+        }
+        Ok(linked)
+    }
+
+    /// The original transactions that were rolled up, oldest first
+    pub fn list_archived_transactions(&self, child_id: Option<String>) -> Result<Vec<DomainTransaction>> {
+        let child_id = self.resolve_child_id(child_id)?;
+        self.transaction_repository.read_archived_transactions_by_id(&child_id)
+    }
+
+    fn resolve_child_id(&self, child_id: Option<String>) -> Result<String> {
+        match child_id {
+            Some(id) => Ok(id),
+            None => self
+                .child_service
+                .get_active_child()?
+                .active_child
+                .child
+                .map(|child| child.id)
+                .ok_or(DomainError::NoActiveChild)
+                .map_err(Into::into),
+        }
+    }
+}
+
+/// First day of the current month, `years` years back; whole months before it are archived
+fn archive_cutoff(today: NaiveDate, years: u32) -> NaiveDate {
+    let year = today.year() - years as i32;
+    NaiveDate::from_ymd_opt(year, today.month(), 1).expect("the first of a month always exists")
+}
+
+/// One transaction standing in for a whole month, dated at its last transaction
+fn monthly_summary(month: &[DomainTransaction]) -> DomainTransaction {
+    let last = month.last().expect("months are never empty");
+    let net = (month.iter().map(|t| t.amount).sum::<f64>() * 100.0).round() / 100.0;
+    // Avoid writing "-0" for a month that evened out
+    let net = if net == 0.0 { 0.0 } else { net };
+
+    DomainTransaction {
+        id: DomainTransaction::generate_id(net, last.date.timestamp_millis() as u64),
+        child_id: last.child_id.clone(),
+        date: last.date,
+        description: format!("Monthly summary: {} ({} transactions)", last.date.format("%B %Y"), month.len()),
+        amount: net,
+        balance: last.balance,
+        transaction_type: if net >= 0.0 { TransactionType::Income } else { TransactionType::Expense },
+        actor: None,
+        category: None,
+        icon: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::child::CreateChildCommand;
+    use chrono::DateTime;
+    use tempfile::TempDir;
+
+    struct Fixture {
+        connection: Arc<CsvConnection>,
+        service: ArchiveService,
+        repository: TransactionRepository,
+        child_id: String,
+    }
+
+    /// An archive service over a fresh data directory with a child "Ada"
+    fn setup(temp_dir: &TempDir) -> Result<Fixture> {
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let child_service = ChildService::new(connection.clone());
+        let service = ArchiveService::new(connection.clone(), child_service.clone());
+        let repository = TransactionRepository::new((*connection).clone());
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })?
+            .child;
+        Ok(Fixture { connection, service, repository, child_id: child.id })
+    }
+
+    /// The child's transactions with running balances, from `(date, description, amount)`
+    fn history(child_id: &str, entries: &[(&str, &str, f64)]) -> Vec<DomainTransaction> {
+        let mut balance = 0.0;
+        entries
+            .iter()
+            .map(|&(date, description, amount)| {
+                balance += amount;
+                DomainTransaction {
+                    id: format!("tx-{}", date),
+                    child_id: child_id.to_string(),
+                    date: DateTime::parse_from_rfc3339(date).unwrap(),
+                    description: description.to_string(),
+                    amount,
+                    balance,
+                    transaction_type: if amount >= 0.0 { TransactionType::Income } else { TransactionType::Expense },
+                    actor: None,
+                    category: None,
+                    icon: None,
+                }
+            })
+            .collect()
+    }
+
+    /// Archive two old months after `link` has pointed something at a March
+    /// transaction: March keeps its rows and only April is rolled up
+    fn assert_linked_month_kept(link: impl FnOnce(&Fixture, &[DomainTransaction]) -> Result<()>) -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let fixture = setup(&temp_dir)?;
+        let history = history(
+            &fixture.child_id,
+            &[
+                ("2020-03-06T12:00:00-05:00", "Allowance", 5.0),
+                ("2020-03-13T12:00:00-05:00", "Toy", -3.0),
+                ("2020-04-03T12:00:00-05:00", "Allowance", 5.0),
+                ("2020-04-10T12:00:00-05:00", "Allowance", 5.0),
+            ],
+        );
+        fixture.repository.write_transactions_by_id(&fixture.child_id, &history)?;
+        link(&fixture, &history)?;
+
+        let command = ArchiveTransactionsCommand { child_id: Some(fixture.child_id.clone()), older_than_years: 2 };
+        let result = fixture.service.archive_on(command, NaiveDate::from_ymd_opt(2023, 6, 15).unwrap())?;
+        assert_eq!((result.archived_count, result.summary_count, result.skipped_linked_months), (2, 1, 1));
+
+        let live = fixture.repository.list_transactions_chronological(&fixture.child_id, None, None)?;
+        assert_eq!(live[..2], history[..2]);
+        assert_eq!(live[2].description, "Monthly summary: April 2020 (2 transactions)");
+        Ok(())
+    }
+
+    #[test]
+    fn test_archive_rolls_up_old_months_and_keeps_originals() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let Fixture { service, repository, child_id, .. } = setup(&temp_dir)?;
+        let history = history(
+            &child_id,
+            &[
+                ("2020-03-06T12:00:00-05:00", "Allowance", 5.0),
+                ("2020-03-13T12:00:00-05:00", "Allowance", 5.0),
+                ("2020-03-20T12:00:00-05:00", "Candy", -2.5),
+                ("2020-04-03T12:00:00-05:00", "Allowance", 5.0),
+                ("2020-05-01T12:00:00-05:00", "Gift", 10.0),
+                ("2020-05-02T12:00:00-05:00", "Book", -10.0),
+                ("2023-06-02T12:00:00-05:00", "Allowance", 5.0),
+            ],
+        );
+        repository.write_transactions_by_id(&child_id, &history)?;
+
+        let command = ArchiveTransactionsCommand { child_id: Some(child_id.clone()), older_than_years: 2 };
+        let result = service.archive_on(command.clone(), NaiveDate::from_ymd_opt(2023, 6, 15).unwrap())?;
+        assert_eq!(result.cutoff, NaiveDate::from_ymd_opt(2021, 6, 1).unwrap());
+        assert_eq!(result.archived_count, 5);
+        assert_eq!(result.summary_count, 2);
+
+        let live = repository.list_transactions_chronological(&child_id, None, None)?;
+        let summary: Vec<(&str, f64, f64)> = live.iter().map(|t| (t.description.as_str(), t.amount, t.balance)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Monthly summary: March 2020 (3 transactions)", 7.5, 7.5),
+                ("Allowance", 5.0, 12.5),
+                ("Monthly summary: May 2020 (2 transactions)", 0.0, 12.5),
+                ("Allowance", 5.0, 17.5),
+            ]
+        );
+        assert_eq!(live[2].transaction_type, TransactionType::Income);
+
+        let archived = service.list_archived_transactions(Some(child_id.clone()))?;
+        assert_eq!(archived.len(), 5);
+        assert_eq!(archived[0], history[0]);
+
+        // Nothing left to roll up on a second run
+        let again = service.archive_on(command, NaiveDate::from_ymd_opt(2023, 6, 15).unwrap())?;
+        assert_eq!((again.archived_count, again.summary_count), (0, 0));
+        assert_eq!(repository.list_transactions_chronological(&child_id, None, None)?, live);
+
+        let zero_years = ArchiveTransactionsCommand { child_id: Some(child_id), older_than_years: 0 };
+        assert!(service.archive_old_transactions(zero_years).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_archive_leaves_closed_months_alone() -> Result<()> {
+        use crate::backend::domain::models::period_lock::PeriodLock;
+
+        let temp_dir = TempDir::new()?;
+        let Fixture { service, repository, child_id, .. } = setup(&temp_dir)?;
+        let history = history(
+            &child_id,
+            &[
+                ("2020-03-06T12:00:00-05:00", "Allowance", 5.0),
+                ("2020-03-13T12:00:00-05:00", "Allowance", 5.0),
+                ("2020-04-03T12:00:00-05:00", "Allowance", 5.0),
+                ("2020-04-10T12:00:00-05:00", "Allowance", 5.0),
+            ],
+        );
+        repository.write_transactions_by_id(&child_id, &history)?;
+        service.period_lock_repository.store_period_lock(&PeriodLock {
+            child_id: child_id.clone(),
+            locked_through: NaiveDate::from_ymd_opt(2020, 3, 31).unwrap(),
+            closed_at: chrono::Utc::now(),
+            closed_by: None,
+        })?;
+
+        let command = ArchiveTransactionsCommand { child_id: Some(child_id.clone()), older_than_years: 2 };
+        let result = service.archive_on(command, NaiveDate::from_ymd_opt(2023, 6, 15).unwrap())?;
+        assert_eq!((result.archived_count, result.summary_count, result.skipped_closed_months), (2, 1, 1));
+
+        let live = repository.list_transactions_chronological(&child_id, None, None)?;
+        assert_eq!(live[..2], history[..2], "March is closed and keeps its rows");
+        assert_eq!(live[2].description, "Monthly summary: April 2020 (2 transactions)");
+        Ok(())
+    }
+
+    #[test]
+    fn test_archive_keeps_months_with_linked_transactions() -> Result<()> {
+        use crate::backend::domain::models::transaction_reversal::TransactionReversal;
+
+        let temp_dir = TempDir::new()?;
+        let Fixture { service, repository, child_id, .. } = setup(&temp_dir)?;
+        let history = history(
+            &child_id,
+            &[
+                ("2020-03-06T12:00:00-05:00", "Allowance", 5.0),
+                ("2020-03-13T12:00:00-05:00", "Toy", -3.0),
+                ("2020-03-14T12:00:00-05:00", "Toy", 3.0),
+                ("2020-04-03T12:00:00-05:00", "Allowance", 5.0),
+                ("2020-04-10T12:00:00-05:00", "Allowance", 5.0),
+            ],
+        );
+        repository.write_transactions_by_id(&child_id, &history)?;
+        // The toy went back to the store
+        service.reversal_repository.store_reversal(&TransactionReversal {
+            child_id: child_id.clone(),
+            original_id: history[1].id.clone(),
+            reversal_id: history[2].id.clone(),
+            date: history[2].date,
+            reason: Some("Returned to the store".to_string()),
+        })?;
+
+        let command = ArchiveTransactionsCommand { child_id: Some(child_id.clone()), older_than_years: 2 };
+        let result = service.archive_on(command, NaiveDate::from_ymd_opt(2023, 6, 15).unwrap())?;
+        assert_eq!((result.archived_count, result.summary_count, result.skipped_linked_months), (2, 1, 1));
+
+        let live = repository.list_transactions_chronological(&child_id, None, None)?;
+        assert_eq!(live[..3], history[..3], "both sides of the reversal are still there");
+        assert_eq!(live[3].description, "Monthly summary: April 2020 (2 transactions)");
+        let reversals = service.reversal_repository.list_reversals(&child_id)?;
+        assert!(reversals.iter().all(|r| live.iter().any(|t| t.id == r.original_id) && live.iter().any(|t| t.id == r.reversal_id)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_archive_keeps_months_with_a_confirmed_planned_purchase() -> Result<()> {
+        use crate::backend::domain::models::planned_purchase::{PlannedPurchase, PlannedPurchaseState};
+
+        assert_linked_month_kept(|fixture, history| {
+            let expense = &history[1];
+            let planned = PlannedPurchaseRepository::new((*fixture.connection).clone());
+            planned.store_planned_purchase(&PlannedPurchase {
+                id: PlannedPurchase::generate_id(&fixture.child_id, 1),
+                child_id: fixture.child_id.clone(),
+                description: "Toy".to_string(),
+                amount: 3.0,
+                planned_date: expense.date.date_naive(),
+                state: PlannedPurchaseState::Confirmed,
+                transaction_id: Some(expense.id.clone()),
+                created_at: "2020-03-01T12:00:00-05:00".to_string(),
+                updated_at: "2020-03-13T12:00:00-05:00".to_string(),
+            })
+        })
+    }
+
+    #[test]
+    fn test_archive_keeps_months_with_a_reached_milestone() -> Result<()> {
+        use crate::backend::domain::models::celebration::ReachedMilestone;
+
+        assert_linked_month_kept(|fixture, history| {
+            let allowance = &history[0];
+            let celebrations = CelebrationRepository::new((*fixture.connection).clone());
+            celebrations.record_reached_milestones(
+                &fixture.child_id,
+                &[ReachedMilestone { amount: 5.0, reached_on: allowance.date.date_naive(), transaction_id: allowance.id.clone() }],
+            )
+        })
+    }
+}
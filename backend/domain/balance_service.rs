@@ -290,6 +290,9 @@ mod tests {
             amount,
             balance,
             transaction_type: if amount >= 0.0 { TransactionType::Income } else { TransactionType::Expense },
+            actor: None,
+            category: None,
+            icon: None,
         };
 
         service.transaction_repository.store_transaction(&transaction).unwrap();
@@ -0,0 +1,276 @@
+//! Budget service domain logic for the allowance tracker.
+//!
+//! Parents can give each of a child's spending categories a monthly target
+//! ("Candy" up to $10). The status for a month adds up what was spent in each
+//! targeted category and flags the ones that went over, for the calendar and
+//! reports to show. Money coming back in a category, such as a refund,
+//! counts against what was spent there. Future allowances aren't real money
+//! yet, so they are left out.
+
+use anyhow::Result;
+use chrono::Datelike;
+use log::info;
+use std::sync::Arc;
+
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::commands::budget::{
+    BudgetStatusQuery, BudgetStatusResult, CategoryBudgetStatus, ListBudgetTargetsQuery, ListBudgetTargetsResult,
+    RemoveBudgetTargetCommand, SetBudgetTargetCommand,
+};
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::models::budget_target::BudgetTarget;
+use crate::backend::domain::models::transaction::TransactionType;
+use crate::backend::storage::csv::{BudgetTargetRepository, CsvConnection, TransactionRepository};
+use crate::backend::storage::traits::{BudgetTargetStorage, TransactionStorage};
+
+/// Most targets a child can have
+pub const MAX_BUDGET_TARGETS: usize = 20;
+
+/// Longest category name a target can hold
+pub const MAX_BUDGET_CATEGORY_LENGTH: usize = 40;
+
+/// Service for a child's monthly spending targets
+#[derive(Clone)]
+pub struct BudgetService {
+    budget_target_repository: BudgetTargetRepository,
+    transaction_repository: TransactionRepository,
+    child_service: ChildService,
+}
+
+impl BudgetService {
+    /// Create a new BudgetService
+    pub fn new(csv_conn: Arc<CsvConnection>, child_service: ChildService) -> Self {
+        let budget_target_repository = BudgetTargetRepository::new((*csv_conn).clone());
+        let transaction_repository = TransactionRepository::new((*csv_conn).clone());
+        Self {
+            budget_target_repository,
+            transaction_repository,
+            child_service,
+        }
+    }
+
+    /// Set the monthly target for a category, replacing any earlier one
+    pub fn set_budget_target(&self, command: SetBudgetTargetCommand) -> Result<BudgetTarget> {
+        let category = command.category.trim().to_string();
+        if category.is_empty() {
+            return Err(DomainError::invalid("Category cannot be empty").into());
+        }
+        if category.chars().count() > MAX_BUDGET_CATEGORY_LENGTH {
+            return Err(DomainError::invalid(format!(
+                "Categories can be at most {} characters",
+                MAX_BUDGET_CATEGORY_LENGTH
+            ))
+            .into());
+        }
+        if !command.monthly_limit.is_finite() || command.monthly_limit <= 0.0 {
+            return Err(DomainError::invalid("Monthly targets must be greater than 0").into());
+        }
+
+        let child_id = self.resolve_child_id(command.child_id)?;
+        let existing = self.budget_target_repository.list_budget_targets(&child_id)?;
+        let replacing = existing.iter().any(|target| target.matches_category(&category));
+        if !replacing && existing.len() >= MAX_BUDGET_TARGETS {
+            return Err(DomainError::invalid(format!(
+                "A child can have at most {} spending targets; remove one first",
+                MAX_BUDGET_TARGETS
+            ))
+            .into());
+        }
+
+        let target = BudgetTarget {
+            child_id: child_id.clone(),
+            category,
+            monthly_limit: round_cents(command.monthly_limit),
+        };
+        self.budget_target_repository.store_budget_target(&target)?;
+
+        info!("🎯 BUDGET: {} set \"{}\" to ${:.2} a month", child_id, target.category, target.monthly_limit);
+        Ok(target)
+    }
+
+    /// Remove the target for a category
+    pub fn remove_budget_target(&self, command: RemoveBudgetTargetCommand) -> Result<()> {
+        let child_id = self.resolve_child_id(command.child_id)?;
+        if !self.budget_target_repository.delete_budget_target(&child_id, &command.category)? {
+            return Err(DomainError::NotFound(format!("No target for category: {}", command.category)).into());
+        }
+        info!("🎯 BUDGET: {} removed \"{}\"", child_id, command.category);
+        Ok(())
+    }
+
+    /// A child's targets in the order they were added
+    pub fn list_budget_targets(&self, query: ListBudgetTargetsQuery) -> Result<ListBudgetTargetsResult> {
+        let child_id = self.resolve_child_id(query.child_id)?;
+        let targets = self.budget_target_repository.list_budget_targets(&child_id)?;
+        Ok(ListBudgetTargetsResult { child_id, targets })
+    }
+
+    /// Compare a month's spending in each category with its target
+    pub fn get_budget_status(&self, query: BudgetStatusQuery) -> Result<BudgetStatusResult> {
+        if !(1..=12).contains(&query.month) {
+            return Err(DomainError::invalid(format!("Invalid month: {}", query.month)).into());
+        }
+        let child_id = self.resolve_child_id(query.child_id)?;
+        let targets = self.budget_target_repository.list_budget_targets(&child_id)?;
+
+        let mut spent_by_target = vec![0.0; targets.len()];
+        let mut untargeted_spent = 0.0;
+        let transactions = self.transaction_repository.list_transactions_chronological(&child_id, None, None)?;
+        for transaction in &transactions {
+            if transaction.transaction_type == TransactionType::FutureAllowance {
+                continue;
+            }
+            let date = transaction.date.date_naive();
+            if date.year() != query.year || date.month() != query.month {
+                continue;
+            }
+
+            let target_index = transaction
+                .category
+                .as_deref()
+                .and_then(|category| targets.iter().position(|target| target.matches_category(category)));
+            match target_index {
+                Some(index) => spent_by_target[index] -= transaction.amount,
+                None if transaction.amount < 0.0 => untargeted_spent -= transaction.amount,
+                None => {}
+            }
+        }
+
+        let categories: Vec<CategoryBudgetStatus> = targets
+            .into_iter()
+            .zip(spent_by_target)
+            .map(|(target, spent)| {
+                let spent = round_cents(spent.max(0.0));
+                CategoryBudgetStatus {
+                    remaining: round_cents(target.monthly_limit - spent),
+                    over_budget: spent > target.monthly_limit,
+                    category: target.category,
+                    target: target.monthly_limit,
+                    spent,
+                }
+            })
+            .collect();
+
+        let result = BudgetStatusResult {
+            child_id,
+            year: query.year,
+            month: query.month,
+            categories,
+            untargeted_spent: round_cents(untargeted_spent),
+        };
+        info!(
+            "🎯 BUDGET: {} {}/{} has {} of {} categories over budget",
+            result.child_id,
+            result.month,
+            result.year,
+            result.over_budget().count(),
+            result.categories.len()
+        );
+        Ok(result)
+    }
+
+    /// Use the given child, or the active child when none is given
+    fn resolve_child_id(&self, child_id: Option<String>) -> Result<String> {
+        match child_id {
+            Some(id) => Ok(id),
+            None => match self.child_service.get_active_child()?.active_child.child {
+                Some(child) => Ok(child.id),
+                None => Err(DomainError::NoActiveChild.into()),
+            },
+        }
+    }
+}
+
+fn round_cents(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand};
+    use crate::backend::domain::models::transaction::Transaction;
+    use tempfile::TempDir;
+
+    fn setup_test() -> (BudgetService, TransactionRepository, String, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let connection = Arc::new(CsvConnection::new(temp_dir.path()).unwrap());
+        let child_service = ChildService::new(connection.clone());
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Spender".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+        let transaction_repository = TransactionRepository::new((*connection).clone());
+        let budget_service = BudgetService::new(connection, child_service);
+        (budget_service, transaction_repository, child.id, temp_dir)
+    }
+
+    fn transaction(child_id: &str, id: &str, date: &str, amount: f64, category: Option<&str>) -> Transaction {
+        Transaction {
+            id: id.to_string(),
+            child_id: child_id.to_string(),
+            date: chrono::DateTime::parse_from_rfc3339(date).unwrap(),
+            description: "Shop".to_string(),
+            amount,
+            balance: 0.0,
+            transaction_type: if amount >= 0.0 { TransactionType::Income } else { TransactionType::Expense },
+            actor: None,
+            category: category.map(str::to_string),
+            icon: None,
+        }
+    }
+
+    #[test]
+    fn test_budget_status_flags_categories_over_target() {
+        let (budget_service, transaction_repository, child_id, _temp_dir) = setup_test();
+        for (category, limit) in [("Candy", 10.0), ("Toys", 20.0)] {
+            budget_service
+                .set_budget_target(SetBudgetTargetCommand { child_id: None, category: category.to_string(), monthly_limit: limit })
+                .unwrap();
+        }
+
+        for transaction in [
+            transaction(&child_id, "ex-1-a", "2025-03-02T10:00:00-05:00", -6.0, Some("candy")),
+            transaction(&child_id, "ex-2-a", "2025-03-09T10:00:00-05:00", -7.5, Some("Candy")),
+            transaction(&child_id, "ex-3-a", "2025-03-10T10:00:00-05:00", -15.0, Some("Toys")),
+            transaction(&child_id, "in-4-a", "2025-03-11T10:00:00-05:00", 5.0, Some("Toys")),
+            transaction(&child_id, "ex-5-a", "2025-03-12T10:00:00-05:00", -3.0, None),
+            transaction(&child_id, "ex-6-a", "2025-04-01T10:00:00-05:00", -50.0, Some("Candy")),
+        ] {
+            transaction_repository.store_transaction(&transaction).unwrap();
+        }
+
+        let status = budget_service
+            .get_budget_status(BudgetStatusQuery { child_id: None, year: 2025, month: 3 })
+            .unwrap();
+        assert_eq!(status.categories.len(), 2);
+        assert_eq!(status.categories[0].spent, 13.5);
+        assert_eq!(status.categories[0].remaining, -3.5);
+        assert!(status.categories[0].over_budget);
+        // The refund comes off what was spent on toys
+        assert_eq!(status.categories[1].spent, 10.0);
+        assert!(!status.categories[1].over_budget);
+        assert_eq!(status.untargeted_spent, 3.0);
+        assert_eq!(status.over_budget().map(|s| s.category.as_str()).collect::<Vec<_>>(), vec!["Candy"]);
+    }
+
+    #[test]
+    fn test_set_budget_target_rejects_bad_input() {
+        let (budget_service, _transaction_repository, _child_id, _temp_dir) = setup_test();
+        let command = |category: &str, monthly_limit: f64| SetBudgetTargetCommand {
+            child_id: None,
+            category: category.to_string(),
+            monthly_limit,
+        };
+        assert!(budget_service.set_budget_target(command("  ", 5.0)).is_err());
+        assert!(budget_service.set_budget_target(command("Candy", 0.0)).is_err());
+        assert!(budget_service.set_budget_target(command("Candy", f64::NAN)).is_err());
+
+        budget_service.set_budget_target(command("Candy", 10.0)).unwrap();
+        budget_service.set_budget_target(command(" candy ", 4.0)).unwrap();
+        let targets = budget_service.list_budget_targets(ListBudgetTargetsQuery { child_id: None }).unwrap().targets;
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].monthly_limit, 4.0);
+    }
+}
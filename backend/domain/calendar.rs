@@ -5,37 +5,21 @@
 //! only handle presentation concerns, while all calendar computations
 //! and business rules are handled here.
 
-use shared::{Transaction, TransactionType, CalendarMonth, CalendarDay, CalendarDayType, CurrentDateResponse, CalendarFocusDate};
+use shared::{Transaction, CalendarMonth, CalendarDay, CalendarDayType, CurrentDateResponse, CalendarFocusDate, CalendarHighlight, CalendarDayDigest, CalendarDaySummary, CalendarMonthSummary, CalendarNavigationWindow, CalendarWeekSummary};
 use std::collections::HashMap;
-use chrono::{Local, Datelike};
+use chrono::{Local, Datelike, NaiveDate};
 use std::sync::{Arc, Mutex};
 use log::{self, info};
 
 // Add imports for the new orchestration method
 use crate::backend::domain::transaction_service::TransactionService;
 use crate::backend::domain::commands::transactions::CalendarTransactionsQuery;
+use crate::backend::domain::mappers::TransactionMapper;
+use crate::backend::domain::models::planned_purchase::PlannedPurchase;
 use anyhow::Result;
 
-// We need to create a TransactionMapper module - for now let's create a simple placeholder
-struct TransactionMapper;
-
-impl TransactionMapper {
-    pub fn to_dto(transaction: crate::backend::domain::models::transaction::Transaction) -> Transaction {
-        Transaction {
-            id: transaction.id,
-            date: transaction.date,
-            amount: transaction.amount,
-            description: transaction.description,
-            transaction_type: match transaction.transaction_type {
-                crate::backend::domain::models::transaction::TransactionType::Income => TransactionType::Income,
-                crate::backend::domain::models::transaction::TransactionType::Expense => TransactionType::Expense,
-                crate::backend::domain::models::transaction::TransactionType::FutureAllowance => TransactionType::FutureAllowance,
-            },
-            balance: transaction.balance,
-            child_id: transaction.child_id,
-        }
-    }
-}
+/// ID of the marker a frontend adds on the day a goal is expected to complete
+pub const GOAL_COMPLETION_MARKER_ID: &str = "goal_completion";
 
 /// Calendar service that handles all calendar-related business logic
 #[derive(Clone)]
@@ -43,6 +27,8 @@ pub struct CalendarService {
     /// Current focus date for calendar navigation (month/year only)
     /// This is kept in memory and not persisted to database
     current_focus_date: Arc<Mutex<CalendarFocusDate>>,
+    /// Day (and transaction) to highlight after jumping to it, until cleared
+    current_highlight: Arc<Mutex<Option<CalendarHighlight>>>,
 }
 
 impl CalendarService {
@@ -50,6 +36,7 @@ impl CalendarService {
     pub fn new() -> Self {
         Self {
             current_focus_date: Arc::new(Mutex::new(CalendarFocusDate::default())),
+            current_highlight: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -63,8 +50,11 @@ impl CalendarService {
     ) -> Result<CalendarMonth> {
         info!("🗓️ CALENDAR: Getting calendar month with transactions for {}/{}", month, year);
 
+        // Resolve the child once so the transactions and balances can't come from different children
+        let active_child = transaction_service.get_active_child()?;
+
         // Step 1: Get transactions for calendar (including future allowances)
-        let query = CalendarTransactionsQuery { month, year };
+        let query = CalendarTransactionsQuery { child_id: Some(active_child.id.clone()), month, year };
         
         let result = transaction_service.list_transactions_for_calendar(query)?;
         
@@ -74,7 +64,7 @@ impl CalendarService {
         let dto_transactions: Vec<Transaction> = result
             .transactions
             .into_iter()
-            .map(TransactionMapper::to_dto)
+            .map(|transaction| TransactionMapper::to_dto_with_lock(transaction, result.period_lock.as_ref()))
             .collect();
         
         info!("🗓️ CALENDAR: Total transactions for calendar: {} transactions", dto_transactions.len());
@@ -84,9 +74,6 @@ impl CalendarService {
         }
 
         // Step 3: Generate calendar month using enhanced method that handles NaN balances
-        // Get the active child for balance service
-        let active_child = transaction_service.get_active_child()?;
-        
         // Create balance service for projected balance calculations
         let balance_service = transaction_service.create_balance_service();
         
@@ -107,7 +94,86 @@ impl CalendarService {
         Ok(calendar_month)
     }
 
+    /// Get a focus month in full plus day summaries for the previous and next
+    /// months, so the frontend can show neighbours without another round trip
+    pub fn get_calendar_navigation_window(
+        &self,
+        month: u32,
+        year: u32,
+        transaction_service: &TransactionService,
+    ) -> Result<CalendarNavigationWindow> {
+        info!("🗓️ CALENDAR: Getting navigation window around {}/{}", month, year);
+
+        let focus = self.get_calendar_month_with_transactions(month, year, transaction_service)?;
+
+        let (prev_month, prev_year) = self.previous_month(month, year);
+        let previous = self.get_calendar_month_with_transactions(prev_month, prev_year, transaction_service)?;
+
+        let (next_month, next_year) = self.next_month(month, year);
+        let next = self.get_calendar_month_with_transactions(next_month, next_year, transaction_service)?;
+
+        Ok(CalendarNavigationWindow {
+            focus,
+            previous: self.summarize_month(&previous),
+            next: self.summarize_month(&next),
+        })
+    }
+
+    /// Reduce a calendar month to per-day transaction counts and balances
+    pub fn summarize_month(&self, calendar_month: &CalendarMonth) -> CalendarMonthSummary {
+        CalendarMonthSummary {
+            month: calendar_month.month,
+            year: calendar_month.year,
+            first_day_of_week: calendar_month.first_day_of_week,
+            days: calendar_month
+                .days
+                .iter()
+                .filter(|day| day.day_type == CalendarDayType::MonthDay)
+                .map(|day| CalendarDaySummary {
+                    day: day.day,
+                    transaction_count: day.transactions.len(),
+                    end_of_day_balance: day.balance,
+                })
+                .collect(),
+        }
+    }
+
+    /// Cut a day's transactions down to the `max_visible` largest, counting the rest
+    ///
+    /// Frontends show `visible` as chips and the overflow as a "+N more"
+    /// indicator. Goal and planned purchase markers can be shown but aren't
+    /// money, so they are left out of both nets.
+    pub fn summarize_day(&self, transactions: &[Transaction], max_visible: usize) -> CalendarDayDigest {
+        let is_money = |transaction: &Transaction| {
+            transaction.id != GOAL_COMPLETION_MARKER_ID && !transaction.id.starts_with(PlannedPurchase::ID_PREFIX)
+        };
 
+        // Largest first; a stable sort keeps equal amounts in the order they happened
+        let mut ranked: Vec<usize> = (0..transactions.len()).collect();
+        ranked.sort_by(|&a, &b| transactions[b].amount.abs().total_cmp(&transactions[a].amount.abs()));
+        let mut shown = vec![false; transactions.len()];
+        for &index in ranked.iter().take(max_visible) {
+            shown[index] = true;
+        }
+
+        let mut digest = CalendarDayDigest { visible: Vec::new(), overflow_count: 0, overflow_net: 0.0, net_total: 0.0 };
+        for (transaction, shown) in transactions.iter().zip(shown) {
+            if is_money(transaction) {
+                digest.net_total += transaction.amount;
+            }
+            if shown {
+                digest.visible.push(transaction.clone());
+            } else {
+                digest.overflow_count += 1;
+                if is_money(transaction) {
+                    digest.overflow_net += transaction.amount;
+                }
+            }
+        }
+        digest.net_total = (digest.net_total * 100.0).round() / 100.0;
+        digest.overflow_net = (digest.overflow_net * 100.0).round() / 100.0;
+        digest
+    }
 
     /// Get the number of days in a given month and year
     pub fn days_in_month(&self, month: u32, year: u32) -> u32 {
@@ -341,14 +407,54 @@ impl CalendarService {
         
         log::debug!("🗓️ CALENDAR DEBUG: Total calendar days created: {}", calendar_days.len());
         
+        let weeks = self.summarize_weeks(&calendar_days);
         CalendarMonth {
             month,
             year,
             days: calendar_days,
             first_day_of_week: first_day,
+            weeks,
         }
     }
 
+    /// Total each row of seven calendar days
+    ///
+    /// Padding days carry no transactions, so a row's totals are for its
+    /// month days only. Projected allowances haven't happened yet and are
+    /// left out of earned and spent, but do show in the ending balance.
+    pub fn summarize_weeks(&self, days: &[CalendarDay]) -> Vec<CalendarWeekSummary> {
+        days.chunks(7)
+            .filter_map(|week| {
+                let month_days: Vec<&CalendarDay> =
+                    week.iter().filter(|day| day.day_type == CalendarDayType::MonthDay).collect();
+                let (first, last) = (month_days.first()?, month_days.last()?);
+
+                let mut total_earned = 0.0;
+                let mut total_spent = 0.0;
+                for transaction in month_days.iter().flat_map(|day| &day.transactions) {
+                    if transaction.transaction_type.is_projected() {
+                        continue;
+                    }
+                    if transaction.amount >= 0.0 {
+                        total_earned += transaction.amount;
+                    } else {
+                        total_spent -= transaction.amount;
+                    }
+                }
+                let round = |amount: f64| (amount * 100.0).round() / 100.0;
+
+                Some(CalendarWeekSummary {
+                    first_day: first.day,
+                    last_day: last.day,
+                    total_earned: round(total_earned),
+                    total_spent: round(total_spent),
+                    net_change: round(total_earned - total_spent),
+                    ending_balance: last.balance,
+                })
+            })
+            .collect()
+    }
+
     /// Calculate the starting balance for a month (end of previous month)
     fn calculate_starting_balance_for_month(
         &self,
@@ -473,8 +579,28 @@ impl CalendarService {
         Ok(new_focus_date)
     }
 
+    /// Focus the calendar on the month of `date` and highlight that day, and
+    /// the given transaction on it if any
+    pub fn focus_on(&self, date: NaiveDate, transaction_id: Option<String>) -> CalendarFocusDate {
+        // A NaiveDate's month is always valid
+        let focus_date = self.set_focus_date(date.month(), date.year() as u32).unwrap();
+        *self.current_highlight.lock().unwrap() = Some(CalendarHighlight { date, transaction_id });
+        focus_date
+    }
+
+    /// The day highlighted by the last `focus_on`, if it hasn't been cleared
+    pub fn get_highlight(&self) -> Option<CalendarHighlight> {
+        self.current_highlight.lock().unwrap().clone()
+    }
+
+    /// Stop highlighting the day from the last `focus_on`
+    pub fn clear_highlight(&self) {
+        *self.current_highlight.lock().unwrap() = None;
+    }
+
     /// Navigate to the previous month
     pub fn navigate_previous_month(&self) -> CalendarFocusDate {
+        self.clear_highlight();
         let current_focus = self.get_focus_date();
         let (prev_month, prev_year) = self.previous_month(current_focus.month, current_focus.year);
         
@@ -484,6 +610,7 @@ impl CalendarService {
 
     /// Navigate to the next month  
     pub fn navigate_next_month(&self) -> CalendarFocusDate {
+        self.clear_highlight();
         let current_focus = self.get_focus_date();
         let (next_month, next_year) = self.next_month(current_focus.month, current_focus.year);
         
@@ -501,6 +628,7 @@ impl Default for CalendarService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use shared::TransactionType;
     use crate::backend::storage::traits::{TransactionStorage, Connection};
 
     fn create_test_transaction(date: &str, amount: f64, balance: f64, description: &str) -> Transaction {
@@ -518,9 +646,38 @@ mod tests {
             amount,
             balance,
             transaction_type: if amount >= 0.0 { TransactionType::Income } else { TransactionType::Expense },
+            actor: None,
+            category: None,
+            icon: None,
+            locked: false,
         }
     }
 
+    #[test]
+    fn test_summarize_day_keeps_largest_and_counts_the_rest() {
+        let service = CalendarService::new();
+        let mut goal = create_test_transaction("2024-03-05", 50.0, 0.0, "Bike");
+        goal.id = GOAL_COMPLETION_MARKER_ID.to_string();
+        let day = vec![
+            create_test_transaction("2024-03-05", 5.0, 5.0, "Allowance"),
+            create_test_transaction("2024-03-05", -1.25, 3.75, "Gum"),
+            goal,
+            create_test_transaction("2024-03-05", -4.0, -0.25, "Comic"),
+            create_test_transaction("2024-03-05", 0.5, 0.25, "Found coin"),
+        ];
+
+        let digest = service.summarize_day(&day, 3);
+        let visible: Vec<&str> = digest.visible.iter().map(|t| t.description.as_str()).collect();
+        assert_eq!(visible, vec!["Allowance", "Bike", "Comic"]);
+        assert_eq!(digest.overflow_count, 2);
+        assert_eq!(digest.overflow_net, -0.75);
+        assert_eq!(digest.net_total, 0.25);
+
+        let everything = service.summarize_day(&day, 10);
+        assert_eq!((everything.visible.len(), everything.overflow_count), (5, 0));
+        assert_eq!(service.summarize_day(&day, 0).overflow_count, 5);
+    }
+
     #[test]
     fn test_days_in_month() {
         let service = CalendarService::new();
@@ -634,6 +791,47 @@ mod tests {
         let day_1 = calendar.days.iter().find(|d| d.day == 1 && d.day_type == CalendarDayType::MonthDay);
         assert!(day_1.is_some());
         assert_eq!(day_1.unwrap().transactions.len(), 1);
+        assert_eq!(calendar.weeks.len(), calendar.days.len() / 7);
+    }
+
+    #[test]
+    fn test_summarize_weeks_totals_each_row() {
+        let service = CalendarService::new();
+        let day = |day: u32, balance: f64, transactions: Vec<Transaction>| CalendarDay {
+            day,
+            balance,
+            transactions,
+            day_type: if day == 0 { CalendarDayType::PaddingBefore } else { CalendarDayType::MonthDay },
+            #[allow(deprecated)]
+            is_empty: day == 0,
+        };
+        let mut projected = create_test_transaction("2025-07-11", 5.0, 0.0, "Allowance");
+        projected.transaction_type = TransactionType::FutureAllowance;
+
+        // July 2025 starts on a Tuesday
+        let mut days = vec![day(0, 0.0, vec![]), day(0, 0.0, vec![])];
+        for n in 1..=12 {
+            let transactions = match n {
+                1 => vec![create_test_transaction("2025-07-01", 10.0, 10.0, "Gift")],
+                3 => vec![
+                    create_test_transaction("2025-07-03", -2.5, 7.5, "Candy"),
+                    create_test_transaction("2025-07-03", 1.0, 8.5, "Chores"),
+                ],
+                11 => vec![projected.clone()],
+                _ => vec![],
+            };
+            let balance = if n < 3 { 10.0 } else if n < 11 { 8.5 } else { 13.5 };
+            days.push(day(n, balance, transactions));
+        }
+
+        let weeks = service.summarize_weeks(&days);
+        assert_eq!(weeks.len(), 2);
+        assert_eq!((weeks[0].first_day, weeks[0].last_day), (1, 5));
+        assert_eq!((weeks[0].total_earned, weeks[0].total_spent, weeks[0].net_change), (11.0, 2.5, 8.5));
+        assert_eq!(weeks[0].ending_balance, 8.5);
+        assert_eq!((weeks[1].first_day, weeks[1].last_day), (6, 12));
+        assert_eq!((weeks[1].total_earned, weeks[1].net_change), (0.0, 0.0));
+        assert_eq!(weeks[1].ending_balance, 13.5);
     }
 
     #[test]
@@ -728,6 +926,27 @@ mod tests {
         assert_eq!(focus_date.year, 2026);
     }
 
+    #[test]
+    fn test_focus_on_highlights_day_until_navigating_away() {
+        let service = CalendarService::new();
+        let date = NaiveDate::from_ymd_opt(2023, 11, 14).unwrap();
+
+        let focus_date = service.focus_on(date, Some("ex-1700000000000-abcd".to_string()));
+        assert_eq!(focus_date, CalendarFocusDate { month: 11, year: 2023 });
+        assert_eq!(service.get_focus_date(), focus_date);
+        assert_eq!(
+            service.get_highlight(),
+            Some(CalendarHighlight { date, transaction_id: Some("ex-1700000000000-abcd".to_string()) })
+        );
+
+        service.navigate_next_month();
+        assert_eq!(service.get_highlight(), None);
+
+        service.focus_on(date, None);
+        service.clear_highlight();
+        assert_eq!(service.get_highlight(), None);
+    }
+
     #[test]
     fn test_cross_month_balance_forwarding_comprehensive() {
         use std::sync::Arc;
@@ -853,6 +1072,9 @@ mod tests {
             amount: historical_tx1.amount,
             balance: historical_tx1.balance,
             transaction_type: crate::backend::domain::models::transaction::TransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
         let historical_domain_tx2 = crate::backend::domain::models::transaction::Transaction {
             id: historical_tx2.id.clone(),
@@ -862,6 +1084,9 @@ mod tests {
             amount: historical_tx2.amount,
             balance: historical_tx2.balance,
             transaction_type: crate::backend::domain::models::transaction::TransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
         transaction_repository.store_transaction(&historical_domain_tx1).unwrap();
         transaction_repository.store_transaction(&historical_domain_tx2).unwrap();
@@ -922,6 +1147,9 @@ mod tests {
             amount: 15.0,
             balance: 15.0,
             transaction_type: crate::backend::domain::models::transaction::TransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
         transaction_repository.store_transaction(&historical_domain_tx).unwrap();
 
@@ -945,6 +1173,61 @@ mod tests {
         assert_eq!(july_18_day.balance, 25.0, "July 18th should show projected balance (15.0 + 10.0 = 25.0)");
     }
 
+    #[test]
+    fn test_navigation_window_summarizes_neighbouring_months() {
+        use std::sync::Arc;
+        use tempfile::tempdir;
+        use crate::backend::storage::csv::CsvConnection;
+        use crate::backend::domain::allowance_service::AllowanceService;
+        use crate::backend::domain::balance_service::BalanceService;
+        use crate::backend::domain::child_service::ChildService;
+        use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand};
+        use crate::backend::domain::commands::transactions::CreateTransactionCommand;
+
+        let temp_dir = tempdir().unwrap();
+        let connection = Arc::new(CsvConnection::new(temp_dir.path()).unwrap());
+        let child_service = ChildService::new(connection.clone());
+        let child = child_service.create_child(CreateChildCommand {
+            name: "Window Child".to_string(),
+            birthdate: "2015-01-01".to_string(),
+        }).unwrap().child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+        let transaction_service = TransactionService::new(
+            connection.clone(),
+            child_service,
+            AllowanceService::new(connection.clone()),
+            BalanceService::new(connection.clone()),
+        );
+
+        for (date, amount) in [("2025-05-31T10:00:00-04:00", 10.0), ("2025-07-02T10:00:00-04:00", -4.0), ("2025-07-02T11:00:00-04:00", -1.0)] {
+            transaction_service.create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Test".to_string(),
+                amount,
+                date: Some(chrono::DateTime::parse_from_rfc3339(date).unwrap()),
+                parent_override: true,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            }).unwrap();
+        }
+
+        let window = CalendarService::new().get_calendar_navigation_window(6, 2025, &transaction_service).unwrap();
+        assert_eq!((window.focus.month, window.focus.year), (6, 2025));
+        assert_eq!((window.previous.month, window.next.month), (5, 7));
+
+        // Summaries only cover real days of the month
+        assert_eq!(window.previous.days.len(), 31);
+        assert_eq!(window.next.days.len(), 31);
+
+        let may_31 = window.previous.days.iter().find(|d| d.day == 31).unwrap();
+        assert_eq!((may_31.transaction_count, may_31.end_of_day_balance), (1, 10.0));
+        let july_2 = window.next.days.iter().find(|d| d.day == 2).unwrap();
+        assert_eq!((july_2.transaction_count, july_2.end_of_day_balance), (2, 5.0));
+        let july_3 = window.next.days.iter().find(|d| d.day == 3).unwrap();
+        assert_eq!((july_3.transaction_count, july_3.end_of_day_balance), (0, 5.0));
+    }
+
     #[test]
     fn test_full_calendar_flow_with_projected_balances_integration() {
         // This test verifies the full integration flow where CalendarService
@@ -998,6 +1281,9 @@ mod tests {
                     } else { 
                         crate::backend::domain::models::transaction::TransactionType::Expense 
                     },
+                    actor: None,
+                    category: None,
+                    icon: None,
                 };
                 transaction_repository.store_transaction(&domain_transaction).unwrap();
             }
@@ -1102,6 +1388,9 @@ mod tests {
                     } else { 
                         crate::backend::domain::models::transaction::TransactionType::Expense 
                     },
+                    actor: None,
+                    category: None,
+                    icon: None,
                 };
                 transaction_repository.store_transaction(&domain_transaction).unwrap();
             }
@@ -1196,6 +1485,9 @@ mod tests {
                     } else { 
                         crate::backend::domain::models::transaction::TransactionType::Expense 
                     },
+                    actor: None,
+                    category: None,
+                    icon: None,
                 };
                 transaction_repository.store_transaction(&domain_transaction).unwrap();
             }
@@ -1279,6 +1571,9 @@ mod tests {
                     } else { 
                         crate::backend::domain::models::transaction::TransactionType::Expense 
                     },
+                    actor: None,
+                    category: None,
+                    icon: None,
                 };
                 transaction_repository.store_transaction(&domain_transaction).unwrap();
             }
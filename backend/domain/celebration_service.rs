@@ -0,0 +1,204 @@
+//! Celebration service domain logic for the allowance tracker.
+//!
+//! Transactions and goals record a `Celebration` when something worth
+//! cheering happens: an allowance paid, a goal reached, or the balance passing
+//! a milestone for the first time. Frontends poll for the pending ones, show
+//! their confetti and acknowledge them, so each is shown once on whichever
+//! screen sees it first.
+//!
+//! Balance milestones are also kept as a history, with the date each was
+//! reached, for a timeline view. Parents choose the thresholds per child in
+//! the child's settings.
+
+use anyhow::Result;
+use log::info;
+use std::sync::Arc;
+
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::commands::celebrations::{
+    AcknowledgeCelebrationsCommand, AcknowledgeCelebrationsResult, MilestoneHistoryQuery, MilestoneHistoryResult,
+    PendingCelebrationsQuery, PendingCelebrationsResult,
+};
+use crate::backend::domain::errors::DomainError;
+use crate::backend::storage::csv::{CelebrationRepository, CsvConnection};
+use crate::backend::storage::CelebrationStorage;
+
+/// Service for a child's pending celebrations
+#[derive(Clone)]
+pub struct CelebrationService {
+    celebration_repository: CelebrationRepository,
+    child_service: ChildService,
+}
+
+impl CelebrationService {
+    /// Create a new CelebrationService
+    pub fn new(csv_conn: Arc<CsvConnection>, child_service: ChildService) -> Self {
+        Self {
+            celebration_repository: CelebrationRepository::new((*csv_conn).clone()),
+            child_service,
+        }
+    }
+
+    /// Celebrations recorded for a child that no frontend has acknowledged yet
+    pub fn get_pending_celebrations(&self, query: PendingCelebrationsQuery) -> Result<PendingCelebrationsResult> {
+        let child_id = self.resolve_child_id(query.child_id)?;
+        let celebrations = self.celebration_repository.list_pending_celebrations(&child_id)?;
+        Ok(PendingCelebrationsResult { child_id, celebrations })
+    }
+
+    /// Mark celebrations as shown; ids that are no longer pending are ignored
+    pub fn acknowledge_celebrations(&self, command: AcknowledgeCelebrationsCommand) -> Result<AcknowledgeCelebrationsResult> {
+        let child_id = self.resolve_child_id(command.child_id)?;
+        let acknowledged_count = self
+            .celebration_repository
+            .acknowledge_celebrations(&child_id, &command.celebration_ids)?;
+        if acknowledged_count > 0 {
+            info!("🎉 {} acknowledged {} celebrations", child_id, acknowledged_count);
+        }
+        Ok(AcknowledgeCelebrationsResult { acknowledged_count })
+    }
+
+    /// The milestones a child has reached and the ones still ahead of them
+    pub fn get_milestone_history(&self, query: MilestoneHistoryQuery) -> Result<MilestoneHistoryResult> {
+        let child_id = self.resolve_child_id(query.child_id)?;
+        let reached = self.celebration_repository.list_reached_milestones(&child_id)?;
+        let upcoming = self
+            .child_service
+            .get_child_settings(&child_id)?
+            .settings
+            .milestone_thresholds()
+            .into_iter()
+            .filter(|threshold| !reached.iter().any(|milestone| milestone.amount == *threshold))
+            .collect();
+        Ok(MilestoneHistoryResult { child_id, reached, upcoming })
+    }
+
+    fn resolve_child_id(&self, child_id: Option<String>) -> Result<String> {
+        match child_id {
+            Some(id) => Ok(id),
+            None => match self.child_service.get_active_child()?.active_child.child {
+                Some(child) => Ok(child.id),
+                None => Err(DomainError::NoActiveChild.into()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand, UpdateChildSettingsCommand};
+    use crate::backend::domain::commands::goal::CreateGoalCommand;
+    use crate::backend::domain::commands::transactions::CreateTransactionCommand;
+    use crate::backend::domain::models::celebration::CelebrationKind;
+    use crate::backend::domain::{AllowanceService, BalanceService, GoalService, TransactionService};
+    use tempfile::TempDir;
+
+    fn income(amount: f64) -> CreateTransactionCommand {
+        CreateTransactionCommand {
+            child_id: None,
+            description: "Chores".to_string(),
+            amount,
+            date: None,
+            parent_override: false,
+            actor: None,
+            icon: None,
+            transaction_type: None,
+        }
+    }
+
+    #[test]
+    fn test_milestones_and_goals_are_celebrated_until_acknowledged() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let child_service = ChildService::new(connection.clone());
+        let allowance_service = AllowanceService::new(connection.clone());
+        let balance_service = BalanceService::new(connection.clone());
+        let transaction_service = Arc::new(TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            allowance_service.clone(),
+            balance_service.clone(),
+        ));
+        let goal_service = GoalService::new(
+            connection.clone(),
+            child_service.clone(),
+            allowance_service,
+            transaction_service.clone(),
+            balance_service,
+        );
+        let service = CelebrationService::new(connection, child_service.clone());
+
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })?
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() })?;
+
+        transaction_service.create_transaction(income(8.0))?;
+        assert!(service.get_pending_celebrations(PendingCelebrationsQuery { child_id: None })?.celebrations.is_empty());
+
+        goal_service.create_goal(CreateGoalCommand {
+            child_id: Some(child.id.clone()),
+            description: "Kite".to_string(),
+            target_amount: 20.0,
+        })?;
+        transaction_service.create_transaction(income(20.0))?;
+        goal_service.check_and_complete_goals(&child.id)?;
+
+        let pending = service.get_pending_celebrations(PendingCelebrationsQuery { child_id: None })?.celebrations;
+        let kinds: Vec<_> = pending.iter().map(|c| (c.kind, c.amount)).collect();
+        assert_eq!(kinds, vec![(CelebrationKind::BalanceMilestone, 25.0), (CelebrationKind::GoalCompleted, 20.0)]);
+
+        let result = service.acknowledge_celebrations(AcknowledgeCelebrationsCommand {
+            child_id: Some(child.id.clone()),
+            celebration_ids: pending.iter().map(|c| c.id.clone()).collect(),
+        })?;
+        assert_eq!(result.acknowledged_count, 2);
+        assert!(service.get_pending_celebrations(PendingCelebrationsQuery { child_id: None })?.celebrations.is_empty());
+
+        let history = service.get_milestone_history(MilestoneHistoryQuery { child_id: None })?;
+        assert_eq!(history.reached.iter().map(|m| m.amount).collect::<Vec<_>>(), vec![10.0, 25.0]);
+        assert_eq!(history.upcoming, vec![50.0, 100.0, 250.0, 500.0, 1000.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parent_chosen_milestones_replace_the_defaults() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let child_service = ChildService::new(connection.clone());
+        let transaction_service = TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            AllowanceService::new(connection.clone()),
+            BalanceService::new(connection.clone()),
+        );
+        let service = CelebrationService::new(connection, child_service.clone());
+
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })?
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() })?;
+        child_service.update_child_settings(UpdateChildSettingsCommand {
+            child_id: child.id.clone(),
+            prevent_negative_balance: None,
+            auto_save_percentage: None,
+            auto_save_to_goal: None,
+            round_up_spends: None,
+            locale: None,
+            currency: None,
+            balance_milestones: Some(Some(vec![15.0, 5.0, 40.0])),
+            background: None,
+        })?;
+
+        let transaction = transaction_service.create_transaction(income(20.0))?;
+
+        let history = service.get_milestone_history(MilestoneHistoryQuery { child_id: None })?;
+        assert_eq!(history.reached.iter().map(|m| m.amount).collect::<Vec<_>>(), vec![5.0, 15.0]);
+        assert!(history.reached.iter().all(|m| m.transaction_id == transaction.id));
+        assert_eq!(history.upcoming, vec![40.0]);
+        let pending = service.get_pending_celebrations(PendingCelebrationsQuery { child_id: None })?.celebrations;
+        assert_eq!(pending.iter().map(|c| c.amount).collect::<Vec<_>>(), vec![15.0]);
+        Ok(())
+    }
+}
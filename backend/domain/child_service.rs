@@ -7,22 +7,28 @@ use crate::backend::domain::models::child::{ActiveChild, Child as DomainChild};
 use crate::backend::domain::commands::child::{
     CreateChildCommand, UpdateChildCommand, GetChildCommand, SetActiveChildCommand, DeleteChildCommand,
     CreateChildResult, UpdateChildResult, GetChildResult, GetActiveChildResult, ListChildrenResult,
-    SetActiveChildResult, DeleteChildResult,
+    SetActiveChildResult, DeleteChildResult, UpdateChildSettingsCommand, ChildSettingsResult,
 };
-use crate::backend::storage::csv::{CsvConnection, ChildRepository};
-use crate::backend::storage::traits::ChildStorage;
+use crate::backend::domain::models::child_settings::ChildSettings;
+use crate::backend::domain::models::background::{is_background_image, ChildBackground};
+use crate::backend::domain::models::celebration::normalize_milestones;
+use crate::backend::domain::errors::DomainError;
+use crate::backend::storage::csv::{CsvConnection, ChildRepository, ChildSettingsRepository};
+use crate::backend::storage::traits::{ChildStorage, ChildSettingsStorage};
 
 /// Service for managing children in the allowance tracking system
 #[derive(Clone)]
 pub struct ChildService {
     child_repository: ChildRepository,
+    child_settings_repository: ChildSettingsRepository,
 }
 
 impl ChildService {
     /// Create a new ChildService
     pub fn new(csv_conn: Arc<CsvConnection>) -> Self {
+        let child_settings_repository = ChildSettingsRepository::new((*csv_conn).clone());
         let child_repository = ChildRepository::new(csv_conn);
-        Self { child_repository }
+        Self { child_repository, child_settings_repository }
     }
 
     /// Create a new child
@@ -91,10 +97,11 @@ impl ChildService {
 
         // Get the existing child
         let mut child = self.child_repository.get_child(&command.child_id)?
-            .ok_or_else(|| anyhow::anyhow!("Child not found: {}", command.child_id))?;
+            .ok_or_else(|| DomainError::child_not_found(&command.child_id))?;
 
         // Validate the update command
         self.validate_update_command(&command)?;
+        DomainError::check_not_stale("child's profile", command.expected_updated_at.as_deref(), &child.updated_at.to_rfc3339())?;
 
         // Update fields if provided
         if let Some(name) = command.name {
@@ -122,7 +129,7 @@ impl ChildService {
 
         // Verify child exists
         let child = self.child_repository.get_child(&command.child_id)?
-            .ok_or_else(|| anyhow::anyhow!("Child not found: {}", command.child_id))?;
+            .ok_or_else(|| DomainError::child_not_found(&command.child_id))?;
 
         // Delete from database
         self.child_repository.delete_child(&command.child_id)?;
@@ -135,6 +142,12 @@ impl ChildService {
     }
 
     /// Get the currently active child
+    ///
+    /// There is one active child per data directory, stored in the global
+    /// config (or in memory while read-only), and every service that takes an
+    /// optional `child_id` falls back to it. Code that needs a child other
+    /// than the selected one, such as a second window, should pass its
+    /// `child_id` explicitly rather than switching the active child.
     pub fn get_active_child(&self) -> Result<GetActiveChildResult> {
         debug!("Getting active child");
 
@@ -167,7 +180,7 @@ impl ChildService {
 
         // Validate that the child exists
         let domain_child = self.child_repository.get_child(&command.child_id)?
-            .ok_or_else(|| anyhow::anyhow!("Child not found: {}", command.child_id))?;
+            .ok_or_else(|| DomainError::child_not_found(&command.child_id))?;
 
         // Set as active child in database
         self.child_repository.set_active_child(&command.child_id)?;
@@ -177,15 +190,81 @@ impl ChildService {
         Ok(SetActiveChildResult { child: domain_child })
     }
 
+    /// Get a child's settings, falling back to defaults if none have been saved
+    pub fn get_child_settings(&self, child_id: &str) -> Result<ChildSettingsResult> {
+        debug!("Getting child settings: {}", child_id);
+
+        let settings = self.child_settings_repository.get_child_settings(child_id)?
+            .unwrap_or_else(|| ChildSettings::default_for_child(child_id));
+
+        Ok(ChildSettingsResult { settings })
+    }
+
+    /// Update a child's settings
+    pub fn update_child_settings(&self, command: UpdateChildSettingsCommand) -> Result<ChildSettingsResult> {
+        info!("Updating child settings: {}", command.child_id);
+
+        // Validate that the child exists
+        self.child_repository.get_child(&command.child_id)?
+            .ok_or_else(|| DomainError::child_not_found(&command.child_id))?;
+
+        let mut settings = self.get_child_settings(&command.child_id)?.settings;
+        if let Some(prevent_negative_balance) = command.prevent_negative_balance {
+            settings.prevent_negative_balance = prevent_negative_balance;
+        }
+        if let Some(percentage) = command.auto_save_percentage {
+            if !(0.0..=100.0).contains(&percentage) {
+                return Err(DomainError::invalid("Auto-save percentage must be between 0 and 100").into());
+            }
+            settings.auto_save_percentage = percentage;
+        }
+        if let Some(auto_save_to_goal) = command.auto_save_to_goal {
+            settings.auto_save_to_goal = auto_save_to_goal;
+        }
+        if let Some(round_up_spends) = command.round_up_spends {
+            settings.round_up_spends = round_up_spends;
+        }
+        if let Some(locale) = command.locale {
+            settings.locale = locale;
+        }
+        if let Some(currency) = command.currency {
+            settings.currency = currency;
+        }
+        if let Some(balance_milestones) = command.balance_milestones {
+            settings.balance_milestones = balance_milestones
+                .map(|thresholds| normalize_milestones(&thresholds))
+                .transpose()?;
+        }
+        if let Some(background) = command.background {
+            if let Some(ChildBackground::CustomImage { path }) = &background {
+                if !is_background_image(path) {
+                    return Err(DomainError::invalid("Background must be a PNG, JPEG, GIF or WebP picture").into());
+                }
+                if !std::path::Path::new(path).is_file() {
+                    return Err(DomainError::invalid(format!("Background picture not found: {}", path)).into());
+                }
+            }
+            settings.background = background;
+        }
+        settings.updated_at = Utc::now().to_rfc3339();
+
+        self.child_settings_repository.store_child_settings(&settings)?;
+
+        info!("Updated child settings for {}: prevent_negative_balance={}, auto_save_percentage={}",
+              settings.child_id, settings.prevent_negative_balance, settings.auto_save_percentage);
+
+        Ok(ChildSettingsResult { settings })
+    }
+
     /// Validate create child command
     fn validate_create_command(&self, command: &CreateChildCommand) -> Result<()> {
         // Validate name
         if command.name.trim().is_empty() {
-            return Err(anyhow::anyhow!("Child name cannot be empty"));
+            return Err(DomainError::invalid("Child name cannot be empty").into());
         }
 
         if command.name.len() > 100 {
-            return Err(anyhow::anyhow!("Child name cannot exceed 100 characters"));
+            return Err(DomainError::invalid("Child name cannot exceed 100 characters").into());
         }
 
         // Validate birthdate format (ISO 8601: YYYY-MM-DD)
@@ -199,11 +278,11 @@ impl ChildService {
         // Validate name if provided
         if let Some(ref name) = command.name {
             if name.trim().is_empty() {
-                return Err(anyhow::anyhow!("Child name cannot be empty"));
+                return Err(DomainError::invalid("Child name cannot be empty").into());
             }
 
             if name.len() > 100 {
-                return Err(anyhow::anyhow!("Child name cannot exceed 100 characters"));
+                return Err(DomainError::invalid("Child name cannot exceed 100 characters").into());
             }
         }
 
@@ -219,24 +298,24 @@ impl ChildService {
     fn validate_birthdate(&self, birthdate: &str) -> Result<()> {
         let date_parts: Vec<&str> = birthdate.split('-').collect();
         if date_parts.len() != 3 {
-            return Err(anyhow::anyhow!("Invalid birthdate format. Use YYYY-MM-DD."));
+            return Err(DomainError::invalid("Invalid birthdate format. Use YYYY-MM-DD.").into());
         }
 
         let year: u32 = date_parts[0].parse()
-            .map_err(|_| anyhow::anyhow!("Invalid year in birthdate"))?;
+            .map_err(|_| DomainError::invalid("Invalid year in birthdate"))?;
         let month: u32 = date_parts[1].parse()
-            .map_err(|_| anyhow::anyhow!("Invalid month in birthdate"))?;
+            .map_err(|_| DomainError::invalid("Invalid month in birthdate"))?;
         let day: u32 = date_parts[2].parse()
-            .map_err(|_| anyhow::anyhow!("Invalid day in birthdate"))?;
+            .map_err(|_| DomainError::invalid("Invalid day in birthdate"))?;
 
         if year < 1900 || year > 2100 {
-            return Err(anyhow::anyhow!("Year must be between 1900 and 2100"));
+            return Err(DomainError::invalid("Year must be between 1900 and 2100").into());
         }
         if !(1..=12).contains(&month) {
-            return Err(anyhow::anyhow!("Month must be between 1 and 12"));
+            return Err(DomainError::invalid("Month must be between 1 and 12").into());
         }
         if !(1..=31).contains(&day) {
-            return Err(anyhow::anyhow!("Day must be between 1 and 31"));
+            return Err(DomainError::invalid("Day must be between 1 and 31").into());
         }
 
         Ok(())
@@ -247,6 +326,7 @@ impl ChildService {
 mod tests {
     use super::*;
     use tempfile::tempdir;
+    use crate::backend::domain::models::background::ThemePack;
 
     use crate::backend::domain::commands::child::{
         CreateChildCommand, UpdateChildCommand, GetChildCommand,
@@ -344,6 +424,7 @@ mod tests {
             child_id: created_child_result.child.id.clone(),
             name: Some("  Updated Name  ".to_string()),
             birthdate: Some("2011-02-02".to_string()),
+            expected_updated_at: None,
         };
 
         let updated_child_result = service.update_child(update_cmd).unwrap();
@@ -352,6 +433,31 @@ mod tests {
         assert!(updated_child_result.child.updated_at > created_child_result.child.created_at);
     }
 
+    #[test]
+    fn test_update_child_from_stale_copy_is_a_conflict() {
+        let service = setup_test();
+        let created = service
+            .create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        let loaded = service.get_child(GetChildCommand { child_id: created.id.clone() }).unwrap().child.unwrap();
+        let rename = |name: &str, expected: &chrono::DateTime<Utc>| UpdateChildCommand {
+            child_id: created.id.clone(),
+            name: Some(name.to_string()),
+            birthdate: None,
+            expected_updated_at: Some(expected.to_rfc3339()),
+        };
+
+        // The first edit saves; a second one from the same copy is refused
+        service.update_child(rename("Ada Lovelace", &loaded.updated_at)).unwrap();
+        let error = service.update_child(rename("Ada B.", &loaded.updated_at)).unwrap_err();
+        assert!(matches!(error.downcast_ref::<DomainError>(), Some(DomainError::Conflict(_))));
+
+        let reloaded = service.get_child(GetChildCommand { child_id: created.id.clone() }).unwrap().child.unwrap();
+        assert_eq!(reloaded.name, "Ada Lovelace");
+        service.update_child(rename("Ada B.", &reloaded.updated_at)).unwrap();
+    }
+
     #[test]
     fn test_update_nonexistent_child() {
         let service = setup_test();
@@ -359,6 +465,7 @@ mod tests {
             child_id: "non-existent-id".to_string(),
             name: Some("New Name".to_string()),
             birthdate: None,
+            expected_updated_at: None,
         };
         let result = service.update_child(update_cmd);
         assert!(result.is_err());
@@ -501,4 +608,88 @@ mod tests {
         let active_child_resp_after_delete = service.get_active_child().unwrap();
         assert!(active_child_resp_after_delete.active_child.child.is_none());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_child_settings_default_and_update() {
+        let service = setup_test();
+
+        let create_cmd = CreateChildCommand { name: "Grace".to_string(), birthdate: "2016-03-03".to_string() };
+        let child_id = service.create_child(create_cmd).unwrap().child.id;
+
+        // Defaults apply until settings are saved
+        let settings = service.get_child_settings(&child_id).unwrap().settings;
+        assert!(!settings.prevent_negative_balance);
+
+        let update_cmd = UpdateChildSettingsCommand {
+            child_id: child_id.clone(),
+            prevent_negative_balance: Some(true),
+            auto_save_percentage: None,
+            auto_save_to_goal: None,
+            round_up_spends: None,
+            locale: None,
+            currency: None,
+            balance_milestones: None,
+            background: None,
+        };
+        service.update_child_settings(update_cmd).unwrap();
+
+        let settings = service.get_child_settings(&child_id).unwrap().settings;
+        assert!(settings.prevent_negative_balance);
+
+        // Auto-save percentage must be a valid percentage
+        let bad_percentage_cmd = UpdateChildSettingsCommand {
+            child_id: child_id.clone(),
+            prevent_negative_balance: None,
+            auto_save_percentage: Some(150.0),
+            auto_save_to_goal: None,
+            round_up_spends: None,
+            locale: None,
+            currency: None,
+            balance_milestones: None,
+            background: None,
+        };
+        assert!(service.update_child_settings(bad_percentage_cmd).is_err());
+
+        // Milestones are stored sorted, and must be positive
+        let mut milestones_cmd = UpdateChildSettingsCommand {
+            child_id: child_id.clone(),
+            prevent_negative_balance: None,
+            auto_save_percentage: None,
+            auto_save_to_goal: None,
+            round_up_spends: None,
+            locale: None,
+            currency: None,
+            balance_milestones: Some(Some(vec![100.0, 25.0, 50.0])),
+            background: None,
+        };
+        let settings = service.update_child_settings(milestones_cmd.clone()).unwrap().settings;
+        assert_eq!(settings.milestone_thresholds(), vec![25.0, 50.0, 100.0]);
+        milestones_cmd.balance_milestones = Some(Some(vec![-5.0]));
+        assert!(service.update_child_settings(milestones_cmd.clone()).is_err());
+
+        // Theme packs are kept, and custom backgrounds must be pictures that exist
+        milestones_cmd.balance_milestones = None;
+        milestones_cmd.background = Some(Some(ChildBackground::Pack { pack: ThemePack::Ocean }));
+        let settings = service.update_child_settings(milestones_cmd.clone()).unwrap().settings;
+        assert_eq!(settings.background(), ChildBackground::Pack { pack: ThemePack::Ocean });
+        milestones_cmd.background = Some(Some(ChildBackground::CustomImage { path: "/no/such/picture.png".to_string() }));
+        assert!(service.update_child_settings(milestones_cmd.clone()).is_err());
+        milestones_cmd.background = Some(None);
+        let settings = service.update_child_settings(milestones_cmd).unwrap().settings;
+        assert_eq!(settings.background(), ChildBackground::default());
+
+        // Unknown children are rejected
+        let bad_cmd = UpdateChildSettingsCommand {
+            child_id: "nobody".to_string(),
+            prevent_negative_balance: Some(true),
+            auto_save_percentage: None,
+            auto_save_to_goal: None,
+            round_up_spends: None,
+            locale: None,
+            currency: None,
+            balance_milestones: None,
+            background: None,
+        };
+        assert!(service.update_child_settings(bad_cmd).is_err());
+    }
+}
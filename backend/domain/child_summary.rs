@@ -0,0 +1,142 @@
+//! Derived facts about a child for overview screens.
+//!
+//! Age, the birthday countdown and this year's totals are worked out here,
+//! from the stored birthdate and transactions, so every frontend shows the
+//! same numbers instead of each doing its own date arithmetic.
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::backend::domain::models::transaction::{Transaction, TransactionType};
+
+/// Derived facts about one child as of a given day
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChildSummary {
+    /// Age in whole years
+    pub age: u32,
+    /// Days until the next birthday; 0 on the birthday itself
+    pub days_until_birthday: i64,
+    /// Age the child turns on their next birthday
+    pub next_birthday_age: u32,
+    /// Money received since January 1st
+    pub earned_this_year: f64,
+    /// Money spent since January 1st
+    pub spent_this_year: f64,
+}
+
+impl ChildSummary {
+    /// Work out the summary for a child born on `birthdate`, as of `today`.
+    ///
+    /// Future allowances and anything dated after `today` are ignored.
+    pub fn compute(birthdate: NaiveDate, transactions: &[Transaction], today: NaiveDate) -> Self {
+        let next_birthday = next_birthday(birthdate, today);
+        let mut earned_this_year = 0.0;
+        let mut spent_this_year = 0.0;
+
+        for transaction in transactions {
+            let date = transaction.date.date_naive();
+            if transaction.transaction_type == TransactionType::FutureAllowance || date.year() != today.year() || date > today {
+                continue;
+            }
+            if transaction.amount >= 0.0 {
+                earned_this_year += transaction.amount;
+            } else {
+                spent_this_year -= transaction.amount;
+            }
+        }
+
+        Self {
+            age: age_on(birthdate, today),
+            days_until_birthday: (next_birthday - today).num_days(),
+            next_birthday_age: (next_birthday.year() - birthdate.year()).max(0) as u32,
+            earned_this_year: (earned_this_year * 100.0).round() / 100.0,
+            spent_this_year: (spent_this_year * 100.0).round() / 100.0,
+        }
+    }
+}
+
+/// Age in whole years on `today`
+pub fn age_on(birthdate: NaiveDate, today: NaiveDate) -> u32 {
+    let mut age = today.year() - birthdate.year();
+    if (today.month(), today.day()) < (birthdate.month(), birthdate.day()) {
+        age -= 1;
+    }
+    age.max(0) as u32
+}
+
+/// The next birthday on or after `today`.
+///
+/// A February 29th birthday is celebrated on February 28th in other years.
+pub fn next_birthday(birthdate: NaiveDate, today: NaiveDate) -> NaiveDate {
+    let this_year = birthday_in_year(birthdate, today.year());
+    if this_year >= today {
+        this_year
+    } else {
+        birthday_in_year(birthdate, today.year() + 1)
+    }
+}
+
+fn birthday_in_year(birthdate: NaiveDate, year: i32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, birthdate.month(), birthdate.day())
+        .or_else(|| NaiveDate::from_ymd_opt(year, 2, 28))
+        .expect("February 28th exists in every year")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+
+    fn date(text: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+    }
+
+    fn transaction(amount: f64, date: &str, transaction_type: TransactionType) -> Transaction {
+        Transaction {
+            id: format!("tx-{}", date),
+            child_id: "child".to_string(),
+            date: DateTime::parse_from_rfc3339(date).unwrap(),
+            description: "Test".to_string(),
+            amount,
+            balance: 0.0,
+            transaction_type,
+            actor: None,
+            category: None,
+            icon: None,
+        }
+    }
+
+    #[test]
+    fn test_age_and_birthday_countdown() {
+        let birthdate = date("2015-06-20");
+        assert_eq!(age_on(birthdate, date("2025-06-19")), 9);
+        assert_eq!(age_on(birthdate, date("2025-06-20")), 10);
+
+        let summary = ChildSummary::compute(birthdate, &[], date("2025-06-18"));
+        assert_eq!(summary.days_until_birthday, 2);
+        assert_eq!(summary.next_birthday_age, 10);
+
+        // On the day itself the countdown is zero; the day after it wraps to next year
+        assert_eq!(ChildSummary::compute(birthdate, &[], date("2025-06-20")).days_until_birthday, 0);
+        let after = ChildSummary::compute(birthdate, &[], date("2025-06-21"));
+        assert_eq!(after.days_until_birthday, 364);
+        assert_eq!(after.next_birthday_age, 11);
+
+        // Leap day birthdays fall on February 28th in other years
+        assert_eq!(next_birthday(date("2016-02-29"), date("2025-01-01")), date("2025-02-28"));
+        assert_eq!(next_birthday(date("2016-02-29"), date("2028-01-01")), date("2028-02-29"));
+    }
+
+    #[test]
+    fn test_totals_cover_this_year_only() {
+        let transactions = vec![
+            transaction(50.0, "2024-12-31T12:00:00-05:00", TransactionType::Income),
+            transaction(10.0, "2025-01-01T09:00:00-05:00", TransactionType::Income),
+            transaction(-3.25, "2025-03-01T09:00:00-05:00", TransactionType::Expense),
+            transaction(5.5, "2025-06-01T09:00:00-05:00", TransactionType::Income),
+            transaction(5.0, "2025-06-27T12:00:00+00:00", TransactionType::FutureAllowance),
+        ];
+        let summary = ChildSummary::compute(date("2015-06-20"), &transactions, date("2025-06-18"));
+        assert_eq!(summary.earned_this_year, 15.5);
+        assert_eq!(summary.spent_this_year, 3.25);
+    }
+}
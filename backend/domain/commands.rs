@@ -7,28 +7,63 @@
 //! types.
 
 pub mod transactions {
-    use super::super::models::transaction::Transaction as DomainTransaction;
+    use super::super::models::actor::Actor;
+    use super::super::models::transaction::{Transaction as DomainTransaction, TransactionType};
+    use super::super::models::period_lock::PeriodLock;
+    use super::super::models::transaction_reversal::TransactionReversal;
 
     /// Input for creating a new transaction.
     #[derive(Debug, Clone)]
     pub struct CreateTransactionCommand {
+        /// Which child; `None` for the active child
+        pub child_id: Option<String>,
         pub description: String,
         pub amount: f64,
         pub date: Option<chrono::DateTime<chrono::FixedOffset>>,
+        /// Parent-mode override for the child's "no negative balance" rule
+        pub parent_override: bool,
+        /// Who entered the transaction; `None` for automatic transactions
+        pub actor: Option<Actor>,
+        /// Emoji or preset icon key to tag the transaction with
+        pub icon: Option<String>,
+        /// What kind of transaction it is, e.g. a bonus; `None` for plain
+        /// income or expense by the amount's sign
+        pub transaction_type: Option<TransactionType>,
     }
 
     /// Query parameters for listing transactions.
     #[derive(Debug, Clone, Default)]
     pub struct TransactionListQuery {
+        pub child_id: Option<String>,
         pub after: Option<String>,
         pub limit: Option<u32>,
         pub start_date: Option<String>,
         pub end_date: Option<String>,
     }
 
+    /// Query for finding a child's transactions by their text.
+    #[derive(Debug, Clone)]
+    pub struct TransactionSearchQuery {
+        pub child_id: Option<String>,
+        /// Matched case-insensitively against the description and category
+        pub text: String,
+        /// Most hits to return, newest first
+        pub limit: usize,
+    }
+
+    /// Result of searching transactions.
+    #[derive(Debug, Clone)]
+    pub struct TransactionSearchResult {
+        /// Matching transactions, newest first
+        pub transactions: Vec<DomainTransaction>,
+        /// Whether more matched than `limit`
+        pub has_more: bool,
+    }
+
     /// Query parameters for getting transactions for calendar display.
     #[derive(Debug, Clone)]
     pub struct CalendarTransactionsQuery {
+        pub child_id: Option<String>,
         pub month: u32,
         pub year: u32,
     }
@@ -36,7 +71,10 @@ pub mod transactions {
     /// Command for deleting multiple transactions.
     #[derive(Debug, Clone)]
     pub struct DeleteTransactionsCommand {
+        pub child_id: Option<String>,
         pub transaction_ids: Vec<String>,
+        /// Who deleted them, recorded in the audit log
+        pub actor: Option<Actor>,
     }
 
     /// Generic pagination info returned by list queries.
@@ -51,12 +89,16 @@ pub mod transactions {
     pub struct TransactionListResult {
         pub transactions: Vec<DomainTransaction>,
         pub pagination: PaginationInfo,
+        /// How far the child's transactions are closed, for marking locked rows
+        pub period_lock: Option<PeriodLock>,
     }
 
     /// Result of getting transactions for calendar display.
     #[derive(Debug, Clone)]
     pub struct CalendarTransactionsResult {
         pub transactions: Vec<DomainTransaction>,
+        /// How far the child's transactions are closed, for marking locked rows
+        pub period_lock: Option<PeriodLock>,
     }
 
     /// Result of deleting transactions.
@@ -66,10 +108,186 @@ pub mod transactions {
         pub not_found_ids: Vec<String>,
         pub success_message: String,
     }
+
+    /// Command for undoing a transaction with an offsetting entry, e.g. a
+    /// refunded purchase or a returned gift.
+    #[derive(Debug, Clone)]
+    pub struct ReverseTransactionCommand {
+        pub child_id: Option<String>,
+        pub transaction_id: String,
+        /// Why it was reversed, added to the new entry's description
+        pub reason: Option<String>,
+        /// When the reversal happened; `None` for now
+        pub date: Option<chrono::DateTime<chrono::FixedOffset>>,
+        /// Parent-mode override for the child's "no negative balance" rule
+        pub parent_override: bool,
+        /// Who entered the reversal
+        pub actor: Option<Actor>,
+    }
+
+    /// Result of reversing a transaction.
+    #[derive(Debug, Clone)]
+    pub struct ReverseTransactionResult {
+        pub original: DomainTransaction,
+        pub reversal: DomainTransaction,
+        pub link: TransactionReversal,
+    }
+
+    /// Command for changing a stored transaction, e.g. moving it to another
+    /// day. Fields left `None` keep their value; the amount never changes.
+    #[derive(Debug, Clone)]
+    pub struct EditTransactionCommand {
+        pub child_id: Option<String>,
+        pub transaction_id: String,
+        pub date: Option<chrono::DateTime<chrono::FixedOffset>>,
+        pub description: Option<String>,
+        /// Parent-mode override for the child's "no negative balance" rule
+        pub parent_override: bool,
+        /// Who made the change, recorded in the audit log
+        pub actor: Option<Actor>,
+    }
+
+    /// Result of editing a transaction.
+    #[derive(Debug, Clone)]
+    pub struct EditTransactionResult {
+        /// The transaction as it was before the edit
+        pub original: DomainTransaction,
+        /// The transaction as stored now, with its recalculated balance
+        pub transaction: DomainTransaction,
+        /// How many balances were rewritten because the date moved
+        pub recalculated_rows: usize,
+    }
+
+    /// Command for copying a transaction as a new one dated now, e.g. this
+    /// week's lunch money. The description, amount, category and icon are
+    /// copied.
+    #[derive(Debug, Clone)]
+    pub struct DuplicateTransactionCommand {
+        pub child_id: Option<String>,
+        pub transaction_id: String,
+        /// Parent-mode override for the child's "no negative balance" rule
+        pub parent_override: bool,
+        /// Who entered the copy
+        pub actor: Option<Actor>,
+    }
+
+    /// Result of duplicating a transaction.
+    #[derive(Debug, Clone)]
+    pub struct DuplicateTransactionResult {
+        pub original: DomainTransaction,
+        /// The new copy
+        pub transaction: DomainTransaction,
+    }
+
+    /// Which of a child's transactions a bulk category change applies to.
+    #[derive(Debug, Clone, Default)]
+    pub struct CategoryFilter {
+        /// Matched case-insensitively against the description; empty matches every description
+        pub description_contains: String,
+        /// Only transactions without a category yet
+        pub uncategorized_only: bool,
+        /// First and last day to include; `None` for no limit
+        pub start_date: Option<chrono::NaiveDate>,
+        pub end_date: Option<chrono::NaiveDate>,
+    }
+
+    /// Command for giving every transaction matching a filter the same
+    /// category, or previewing what that would change.
+    #[derive(Debug, Clone)]
+    pub struct BulkCategorizeCommand {
+        pub child_id: Option<String>,
+        pub filter: CategoryFilter,
+        /// Category to set; `None` clears it
+        pub category: Option<String>,
+        /// Only count what would change, without storing anything
+        pub preview: bool,
+        pub actor: Option<Actor>,
+    }
+
+    /// A transaction's category before a bulk change, for undoing it.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct CategoryChange {
+        pub transaction_id: String,
+        pub previous_category: Option<String>,
+    }
+
+    /// What a bulk category change matched and changed.
+    #[derive(Debug, Clone)]
+    pub struct BulkCategorizeResult {
+        pub child_id: String,
+        /// Transactions the filter matched
+        pub matched: usize,
+        /// Matched transactions in a closed period, which are left alone
+        pub skipped_closed: usize,
+        /// The newest few transactions that get (or got) the category, as
+        /// they were before the change
+        pub sample: Vec<DomainTransaction>,
+        /// Every transaction changed and its earlier category; empty for a
+        /// preview or when nothing needed changing
+        pub changes: Vec<CategoryChange>,
+        /// How many transactions get (or got) a different category
+        pub would_change: usize,
+    }
+
+    /// Command for putting back the categories a bulk change replaced.
+    #[derive(Debug, Clone)]
+    pub struct UndoBulkCategorizeCommand {
+        pub child_id: String,
+        pub changes: Vec<CategoryChange>,
+        pub actor: Option<Actor>,
+    }
+
+    /// Command for closing a child's transactions up to a date after a
+    /// monthly review.
+    #[derive(Debug, Clone)]
+    pub struct ClosePeriodCommand {
+        pub child_id: Option<String>,
+        /// The last day to lock, inclusive
+        pub locked_through: chrono::NaiveDate,
+        /// Who closed it, recorded in the audit log
+        pub actor: Option<Actor>,
+    }
+
+    /// Result of closing a period.
+    #[derive(Debug, Clone)]
+    pub struct ClosePeriodResult {
+        pub lock: PeriodLock,
+        /// How many transactions are now locked
+        pub locked_count: usize,
+    }
+
+    /// Command for reopening a child's closed period.
+    #[derive(Debug, Clone)]
+    pub struct ReopenPeriodCommand {
+        pub child_id: Option<String>,
+        /// Who reopened it, recorded in the audit log
+        pub actor: Option<Actor>,
+    }
+
+    /// Result of reopening a closed period.
+    #[derive(Debug, Clone)]
+    pub struct ReopenPeriodResult {
+        /// The lock that was lifted; `None` if nothing was closed
+        pub previous_lock: Option<PeriodLock>,
+    }
+
+    /// What a transaction dated before others changes further down the
+    /// child's history.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct BackdateImpact {
+        /// Later transactions whose running balance gets rewritten
+        pub recalculated_rows: usize,
+        /// How many of those are allowances
+        pub allowances_after: usize,
+        /// Whether the current goal was set after the backdated date, so its
+        /// starting point and progress shift
+        pub goal_affected: bool,
+    }
 }
 
 pub mod allowance {
-    use crate::backend::domain::models::allowance::AllowanceConfig;
+    use chrono::NaiveDate;
+    use crate::backend::domain::models::allowance::{AllowanceConfig, AllowanceFrequency, DayChangePolicy};
 
     /// Input for getting allowance configuration.
     #[derive(Debug, Clone)]
@@ -84,6 +302,19 @@ pub mod allowance {
         pub amount: f64,
         pub day_of_week: u8,
         pub is_active: bool,
+        /// How to pay the switch-over week if the day changes; `None` keeps the current policy
+        pub day_change_policy: Option<DayChangePolicy>,
+        /// Weekly or monthly; `None` keeps the current frequency (weekly for a new allowance)
+        pub frequency: Option<AllowanceFrequency>,
+        /// Day of the month a monthly allowance is paid on; `None` keeps the current day (the 1st for a new allowance)
+        pub day_of_month: Option<u8>,
+        /// Weeks between every-few-weeks allowances; `None` keeps the current interval (two weeks for a new allowance)
+        pub interval_weeks: Option<u8>,
+        /// First payday of an every-few-weeks allowance; `None` keeps the current one
+        pub anchor_date: Option<NaiveDate>,
+        /// The config's `updated_at` when the caller loaded it; if it has
+        /// changed since, the update is refused with a `Conflict`
+        pub expected_updated_at: Option<String>,
     }
 
     /// Result of getting allowance configuration.
@@ -118,6 +349,8 @@ pub mod goal {
         pub child_id: Option<String>,
         pub description: Option<String>,
         pub target_amount: Option<f64>,
+        /// The goal's `updated_at` when the caller loaded it, to refuse stale edits
+        pub expected_updated_at: Option<String>,
     }
 
     /// Input for getting current goal.
@@ -174,10 +407,58 @@ pub mod goal {
         pub goal: DomainGoal,
         pub success_message: String,
     }
+
+    /// Query for how a child's past goals went.
+    #[derive(Debug, Clone)]
+    pub struct GetGoalStatisticsQuery {
+        pub child_id: Option<String>,
+    }
+
+    /// One goal with what happened while it was open.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GoalHistoryEntry {
+        pub goal: DomainGoal,
+        /// Days from setting the goal until it was reached or abandoned;
+        /// days so far for the active goal
+        pub days_taken: i64,
+        /// Balance when the goal was set
+        pub starting_balance: f64,
+        /// Balance when the goal was reached or abandoned; today's for the active goal
+        pub ending_balance: f64,
+        /// `ending_balance - starting_balance`
+        pub saved: f64,
+        /// How much of the target the ending balance covers, 0 to 100
+        pub percent_of_target: f64,
+    }
+
+    /// Totals across all of a child's goals.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GoalStatisticsSummary {
+        pub total_goals: usize,
+        pub completed_count: usize,
+        pub abandoned_count: usize,
+        /// Completed goals as a percentage of finished (completed or abandoned) ones
+        pub completion_rate: Option<f64>,
+        pub average_days_to_complete: Option<f64>,
+        /// Average `percent_of_target` reached by abandoned goals
+        pub average_percent_when_abandoned: Option<f64>,
+    }
+
+    /// Result of the goal statistics query, newest goal first.
+    #[derive(Debug, Clone)]
+    pub struct GetGoalStatisticsResult {
+        pub child_id: String,
+        pub goals: Vec<GoalHistoryEntry>,
+        pub summary: GoalStatisticsSummary,
+    }
 }
 
 pub mod child {
     use crate::backend::domain::models::child::{ActiveChild, Child as DomainChild};
+    use crate::backend::domain::localization::Locale;
+    use crate::backend::domain::models::currency::Currency;
+    use crate::backend::domain::models::background::ChildBackground;
+    use crate::backend::domain::models::child_settings::ChildSettings;
 
     /// Input for creating a new child.
     #[derive(Debug, Clone)]
@@ -192,6 +473,7 @@ pub mod child {
         pub child_id: String,
         pub name: Option<String>,
         pub birthdate: Option<String>, // Format: YYYY-MM-DD
+        pub expected_updated_at: Option<String>, // RFC 3339, to refuse stale edits
     }
 
     /// Input for getting a child by ID.
@@ -253,13 +535,43 @@ pub mod child {
     pub struct DeleteChildResult {
         pub success_message: String,
     }
+
+    /// Input for updating a child's settings. `None` fields are left unchanged.
+    #[derive(Debug, Clone)]
+    pub struct UpdateChildSettingsCommand {
+        pub child_id: String,
+        pub prevent_negative_balance: Option<bool>,
+        pub auto_save_percentage: Option<f64>,
+        pub auto_save_to_goal: Option<bool>,
+        pub round_up_spends: Option<bool>,
+        /// `Some(None)` clears the child's locale so the global one applies
+        pub locale: Option<Option<Locale>>,
+        /// `Some(None)` clears the child's currency so the family one applies
+        pub currency: Option<Option<Currency>>,
+        /// `Some(None)` goes back to the default milestones
+        pub balance_milestones: Option<Option<Vec<f64>>>,
+        /// `Some(None)` goes back to the classic background
+        pub background: Option<Option<ChildBackground>>,
+    }
+
+    /// Result of getting or updating a child's settings.
+    #[derive(Debug, Clone)]
+    pub struct ChildSettingsResult {
+        pub settings: ChildSettings,
+    }
 }
 
 pub mod parental_control {
+    use crate::backend::domain::models::actor::Actor;
+    use crate::backend::domain::models::parent_user::ParentPermission;
+
     /// Input for validating parental control answer.
     #[derive(Debug, Clone)]
     pub struct ValidateParentalControlCommand {
+        /// The family answer, or a named parent's PIN
         pub answer: String,
+        /// What the parent wants to do; named parents need this permission
+        pub permission: Option<ParentPermission>,
     }
 
     /// Result of validating parental control answer.
@@ -267,5 +579,1277 @@ pub mod parental_control {
     pub struct ValidateParentalControlResult {
         pub success: bool,
         pub message: String,
+        /// Who was let in, for attributing what they do next
+        pub actor: Option<Actor>,
+        /// Seconds until another answer will be checked, after too many wrong ones
+        pub locked_for_seconds: Option<u64>,
+    }
+
+    /// Command to add a named parent with their own PIN.
+    #[derive(Debug, Clone)]
+    pub struct AddParentCommand {
+        pub name: String,
+        /// 4 to 8 digits
+        pub pin: String,
+        pub permissions: Vec<ParentPermission>,
+    }
+
+    /// Command to change a named parent; `None` fields are left as they are.
+    #[derive(Debug, Clone)]
+    pub struct UpdateParentCommand {
+        pub parent_id: String,
+        pub name: Option<String>,
+        pub pin: Option<String>,
+        pub permissions: Option<Vec<ParentPermission>>,
+    }
+
+    /// Command to remove a named parent.
+    #[derive(Debug, Clone)]
+    pub struct RemoveParentCommand {
+        pub parent_id: String,
+    }
+}
+
+pub mod reports {
+    use super::super::models::transaction::TransactionType;
+
+    /// Query for the monthly summary of automatically saved amounts.
+    #[derive(Debug, Clone)]
+    pub struct AutoSavedSummaryQuery {
+        pub child_id: Option<String>,
+    }
+
+    /// Total auto-saved (or rounded up) in a single calendar month.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MonthlyAutoSaved {
+        pub year: i32,
+        pub month: u32,
+        pub total: f64,
+        pub allocation_count: usize,
+    }
+
+    /// Result of the auto-saved summary, oldest month first.
+    #[derive(Debug, Clone)]
+    pub struct AutoSavedSummaryResult {
+        pub child_id: String,
+        pub months: Vec<MonthlyAutoSaved>,
+        pub total_auto_saved: f64,
+    }
+
+    /// Query for the monthly summary of round-up savings.
+    #[derive(Debug, Clone)]
+    pub struct RoundUpSummaryQuery {
+        pub child_id: Option<String>,
+    }
+
+    /// Result of the round-up summary, oldest month first.
+    #[derive(Debug, Clone)]
+    pub struct RoundUpSummaryResult {
+        pub child_id: String,
+        pub months: Vec<MonthlyAutoSaved>,
+        pub total_rounded_up: f64,
+    }
+
+    /// Query for a whole year of activity, day by day.
+    #[derive(Debug, Clone)]
+    pub struct YearSummaryQuery {
+        pub child_id: Option<String>,
+        pub year: i32,
+        /// Leave out reversed transactions and the entries that reversed them
+        pub exclude_reversals: bool,
+    }
+
+    /// Money in and out on a single day.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DailyNet {
+        pub date: chrono::NaiveDate,
+        pub income: f64,
+        pub expenses: f64,
+        pub net: f64,
+        pub transaction_count: usize,
+    }
+
+    /// Money in and out across a single calendar month.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MonthlyTotals {
+        pub month: u32,
+        pub income: f64,
+        pub expenses: f64,
+        pub net: f64,
+        pub transaction_count: usize,
+    }
+
+    /// Result of the year summary.
+    ///
+    /// `days` has an entry for every day of the year, January 1st first, and
+    /// `months` always has twelve entries, so a heatmap can be drawn without
+    /// filling gaps. `max_abs_daily_net` is the largest single-day swing in
+    /// either direction, for scaling colour intensity.
+    #[derive(Debug, Clone)]
+    pub struct YearSummaryResult {
+        pub child_id: String,
+        pub year: i32,
+        pub days: Vec<DailyNet>,
+        pub months: Vec<MonthlyTotals>,
+        pub max_abs_daily_net: f64,
+        pub total_net: f64,
+    }
+
+    /// How far apart the points of a balance chart are.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ChartResolution {
+        Daily,
+        Weekly,
+        Monthly,
+    }
+
+    /// Query for a child's balance over a date range, for charting.
+    #[derive(Debug, Clone)]
+    pub struct BalanceSeriesQuery {
+        pub child_id: Option<String>,
+        pub start_date: chrono::NaiveDate,
+        pub end_date: chrono::NaiveDate,
+        pub resolution: ChartResolution,
+    }
+
+    /// Balance at the end of one bucket (day, week or month).
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct BalancePoint {
+        pub date: chrono::NaiveDate,
+        pub balance: f64,
+    }
+
+    /// What a chart marker points out.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ChartMarkerKind {
+        LargeTransaction,
+        GoalCreated,
+        GoalCompleted,
+    }
+
+    /// Something worth pointing out on the balance chart, on the day it happened.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ChartMarker {
+        pub date: chrono::NaiveDate,
+        pub kind: ChartMarkerKind,
+        /// The transaction's or goal's description
+        pub description: String,
+        /// The transaction's amount, or the goal's target
+        pub amount: f64,
+    }
+
+    /// Result of the balance series, oldest point first.
+    ///
+    /// Each point is dated at the end of its bucket, clipped to `end_date`, so
+    /// the last point is always the balance at the end of the range.
+    #[derive(Debug, Clone)]
+    pub struct BalanceSeriesResult {
+        pub child_id: String,
+        pub resolution: ChartResolution,
+        pub points: Vec<BalancePoint>,
+        /// Large transactions and goal dates within the range, oldest first
+        pub markers: Vec<ChartMarker>,
+    }
+
+    /// Query for money earned and spent in each of the last few months.
+    #[derive(Debug, Clone)]
+    pub struct MonthlyCashFlowQuery {
+        pub child_id: Option<String>,
+        /// Last month to include, usually the current one
+        pub end_year: i32,
+        pub end_month: u32,
+        /// How many months to include, counting back from the end month
+        pub months: u32,
+        /// Leave out reversed transactions and the entries that reversed them
+        pub exclude_reversals: bool,
+    }
+
+    /// Money earned and spent in one calendar month.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MonthlyCashFlow {
+        pub year: i32,
+        pub month: u32,
+        pub earned: f64,
+        /// Money spent, always positive
+        pub spent: f64,
+        pub net: f64,
+    }
+
+    /// Result of the monthly cash flow, oldest month first.
+    ///
+    /// Every month in the range has an entry, even a quiet one, so the bars
+    /// line up without filling gaps.
+    #[derive(Debug, Clone)]
+    pub struct MonthlyCashFlowResult {
+        pub child_id: String,
+        pub months: Vec<MonthlyCashFlow>,
+    }
+
+    /// How a spending breakdown splits up the money.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum BreakdownGrouping {
+        /// By the transaction's category, e.g. "Candy"
+        Category,
+        /// By the kind of transaction, e.g. penalties
+        TransactionType,
+    }
+
+    /// Query for where a child's money went over a date range.
+    #[derive(Debug, Clone)]
+    pub struct SpendingBreakdownQuery {
+        pub child_id: Option<String>,
+        pub start_date: chrono::NaiveDate,
+        pub end_date: chrono::NaiveDate,
+        pub group_by: BreakdownGrouping,
+        /// Leave out reversed transactions and the entries that reversed them
+        pub exclude_reversals: bool,
+    }
+
+    /// Money spent on one category or kind of transaction.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SpendingSlice {
+        pub label: String,
+        /// Money spent, always positive
+        pub amount: f64,
+        /// Fraction of everything spent in the range, between 0 and 1
+        pub share: f64,
+        pub transaction_count: usize,
+    }
+
+    /// Result of the spending breakdown, biggest slice first.
+    #[derive(Debug, Clone)]
+    pub struct SpendingBreakdownResult {
+        pub child_id: String,
+        pub start_date: chrono::NaiveDate,
+        pub end_date: chrono::NaiveDate,
+        pub group_by: BreakdownGrouping,
+        pub slices: Vec<SpendingSlice>,
+        pub total_spent: f64,
+    }
+
+    /// Query for one calendar month's report.
+    #[derive(Debug, Clone)]
+    pub struct MonthlyReportQuery {
+        pub child_id: Option<String>,
+        pub year: i32,
+        pub month: u32,
+        /// Leave out reversed transactions and the entries that reversed them;
+        /// balances still include them
+        pub exclude_reversals: bool,
+    }
+
+    /// One transaction in a monthly report.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MonthlyReportLine {
+        pub date: chrono::NaiveDate,
+        pub description: String,
+        pub category: Option<String>,
+        pub transaction_type: TransactionType,
+        pub amount: f64,
+        pub balance: f64,
+    }
+
+    /// Money in and out over one calendar month, with every transaction in it.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MonthlyReportResult {
+        pub child_id: String,
+        pub year: i32,
+        pub month: u32,
+        /// Balance at the end of the previous month
+        pub opening_balance: f64,
+        pub total_earned: f64,
+        pub total_spent: f64,
+        pub closing_balance: f64,
+        /// Net amount of each kind of transaction in the month, in
+        /// `TransactionType::RECORDED` order, leaving out kinds with none
+        pub totals_by_type: Vec<(TransactionType, f64)>,
+        /// The month's transactions, oldest first
+        pub lines: Vec<MonthlyReportLine>,
+    }
+}
+
+pub mod family {
+    use crate::backend::domain::child_summary::ChildSummary;
+    use crate::backend::domain::models::currency::Currency;
+    use chrono::NaiveDate;
+    use std::collections::BTreeMap;
+
+    /// A child's current goal and how close they are to it.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GoalOverview {
+        pub description: String,
+        pub target_amount: f64,
+        /// Current balance as a percentage of the target, capped at 100
+        pub progress_percent: f64,
+    }
+
+    /// The next allowance a child will receive.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct NextAllowance {
+        pub date: NaiveDate,
+        pub amount: f64,
+    }
+
+    /// One child's row in the family overview. Amounts are in the child's own currency.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ChildOverview {
+        pub child_id: String,
+        pub name: String,
+        pub currency: Currency,
+        pub balance: f64,
+        /// Balance in the family currency; `None` when no exchange rate is configured
+        pub balance_in_family_currency: Option<f64>,
+        pub goal: Option<GoalOverview>,
+        pub next_allowance: Option<NextAllowance>,
+        /// Age, birthday countdown and this year's totals
+        pub summary: ChildSummary,
+    }
+
+    /// Result of the family overview query.
+    #[derive(Debug, Clone)]
+    pub struct FamilyOverviewResult {
+        pub family_currency: Currency,
+        pub children: Vec<ChildOverview>,
+        /// Sum of every balance that could be converted into the family currency
+        pub total_in_family_currency: f64,
+        /// Currencies left out of the total because no exchange rate is configured
+        pub unconverted_currencies: Vec<Currency>,
+    }
+
+    /// Command to change the family currency and exchange rates.
+    #[derive(Debug, Clone)]
+    pub struct UpdateFamilyCurrencyCommand {
+        pub family_currency: Option<Currency>,
+        /// Rates to set, as the value of one unit of the currency in the family currency
+        pub exchange_rates: BTreeMap<Currency, f64>,
+        /// Currencies whose rates should be removed
+        pub remove_rates: Vec<Currency>,
+    }
+
+    /// Result of changing the family currency settings.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct FamilyCurrencySettings {
+        pub family_currency: Currency,
+        pub exchange_rates: BTreeMap<Currency, f64>,
+    }
+
+    /// Query comparing children on how they save rather than how much they have.
+    #[derive(Debug, Clone)]
+    pub struct ChildComparisonQuery {
+        /// Leave balances out, so siblings can compare without seeing who has more
+        pub hide_balances: bool,
+    }
+
+    /// One child's row in the comparison.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ChildComparison {
+        pub child_id: String,
+        pub name: String,
+        /// Share of this year's earnings not spent, as a percentage; negative when
+        /// more was spent than earned, `None` when nothing was earned yet
+        pub savings_rate: Option<f64>,
+        pub goals_completed: usize,
+        /// Progress towards the current goal, 0 to 100
+        pub goal_progress_percent: Option<f64>,
+        /// Balance in the child's own currency; `None` when balances are hidden
+        pub balance: Option<f64>,
+        pub currency: Currency,
+    }
+
+    /// Result of the comparison, best savings rate first.
+    #[derive(Debug, Clone)]
+    pub struct ChildComparisonResult {
+        /// Year the savings rates cover
+        pub year: i32,
+        pub balances_hidden: bool,
+        pub children: Vec<ChildComparison>,
+    }
+}
+
+pub mod encryption {
+    /// Command to encrypt (or decrypt) a child's existing data files.
+    #[derive(Debug, Clone)]
+    pub struct ChangeChildEncryptionCommand {
+        pub child_id: String,
+        pub passphrase: String,
+    }
+
+    /// Result of encrypting or decrypting a child's data.
+    #[derive(Debug, Clone)]
+    pub struct ChangeChildEncryptionResult {
+        pub child_id: String,
+        pub is_encrypted: bool,
+        pub files_changed: usize,
+    }
+
+    /// Command to unlock every encrypted child with the parental passphrase.
+    #[derive(Debug, Clone)]
+    pub struct UnlockEncryptedDataCommand {
+        pub passphrase: String,
+    }
+
+    /// Result of unlocking encrypted children.
+    #[derive(Debug, Clone)]
+    pub struct UnlockEncryptedDataResult {
+        pub unlocked_child_ids: Vec<String>,
+        /// Children whose data could not be unlocked with the passphrase
+        pub failed_child_ids: Vec<String>,
+    }
+
+    /// Encryption state of a single child's data.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ChildEncryptionStatus {
+        pub child_id: String,
+        pub child_name: String,
+        pub is_encrypted: bool,
+        pub is_unlocked: bool,
     }
-} 
\ No newline at end of file
+}
+
+pub mod preferences {
+    use crate::backend::domain::models::display_preferences::{ColorTheme, TextSize};
+
+    /// Command to change app-wide display preferences.
+    #[derive(Debug, Clone)]
+    pub struct UpdateDisplayPreferencesCommand {
+        pub text_size: TextSize,
+        pub show_week_summaries: bool,
+        pub show_goal_strip: bool,
+        pub theme: ColorTheme,
+        pub reduce_motion: bool,
+    }
+
+    /// Current app-wide display preferences.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DisplayPreferencesResult {
+        pub text_size: TextSize,
+        /// Zoom factor the frontend applies for `text_size`
+        pub scale_factor: f32,
+        /// Whether the calendar shows a totals row under each week
+        pub show_week_summaries: bool,
+        /// Whether the active goal's progress is shown above the calendar
+        pub show_goal_strip: bool,
+        /// Light, dark or the operating system's colors
+        pub theme: ColorTheme,
+        /// Whether celebrations skip their confetti and other movement
+        pub reduce_motion: bool,
+    }
+
+    /// Command to mark the guided tour finished, or to have it shown again.
+    #[derive(Debug, Clone)]
+    pub struct SetTutorialCompletedCommand {
+        pub completed: bool,
+    }
+
+    /// Whether the guided tour still has to be shown.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TutorialStatusResult {
+        /// Whether the tour has been finished or skipped
+        pub completed: bool,
+    }
+}
+
+pub mod feature_flags {
+    use crate::backend::domain::models::feature_flag::FeatureFlag;
+
+    /// Command to turn an experimental feature on or off.
+    #[derive(Debug, Clone)]
+    pub struct SetFeatureFlagCommand {
+        pub flag: FeatureFlag,
+        pub enabled: bool,
+    }
+
+    /// Whether one experimental feature is on.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct FeatureFlagStatus {
+        pub flag: FeatureFlag,
+        pub enabled: bool,
+    }
+
+    /// Every experimental feature and whether it is on, in settings order.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct FeatureFlagsResult {
+        pub flags: Vec<FeatureFlagStatus>,
+    }
+
+    impl FeatureFlagsResult {
+        /// Whether a flag is on
+        pub fn is_enabled(&self, flag: FeatureFlag) -> bool {
+            self.flags.iter().any(|status| status.flag == flag && status.enabled)
+        }
+    }
+}
+
+pub mod archive {
+    /// Command to roll up a child's old transactions into monthly summaries.
+    #[derive(Debug, Clone)]
+    pub struct ArchiveTransactionsCommand {
+        /// Child to archive; the active child when `None`
+        pub child_id: Option<String>,
+        /// Whole months that ended at least this many years ago are rolled up
+        pub older_than_years: u32,
+    }
+
+    /// Result of rolling up old transactions.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ArchiveTransactionsResult {
+        pub child_id: String,
+        /// Transactions before this date were rolled up
+        pub cutoff: chrono::NaiveDate,
+        /// Original transactions moved into the archive file
+        pub archived_count: usize,
+        /// Monthly summary transactions that replaced them
+        pub summary_count: usize,
+        /// Months left as they are because they are in a closed period
+        pub skipped_closed_months: usize,
+        /// Months left as they are because a savings allocation, loan, advance or reversal points into them
+        pub skipped_linked_months: usize,
+    }
+}
+
+pub mod reset {
+    /// Command to archive and then erase all data in the data directory.
+    #[derive(Debug, Clone)]
+    pub struct ResetAllDataCommand {
+        /// Must be the reset confirmation phrase, typed out by the parent
+        pub confirmation: String,
+        /// Where to write the archive; next to the data directory when `None`
+        pub archive_directory: Option<String>,
+    }
+
+    /// Result of resetting all data.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ResetAllDataResult {
+        /// Zip file holding everything that was erased
+        pub archive_path: String,
+        /// Files copied into the archive
+        pub archived_file_count: usize,
+    }
+}
+
+pub mod loans {
+    use crate::backend::domain::models::actor::Actor;
+    use crate::backend::domain::models::loan::{Loan, LoanDirection};
+    use crate::backend::domain::models::transaction::Transaction;
+
+    /// Command to record money fronted between the active child and a parent.
+    #[derive(Debug, Clone)]
+    pub struct CreateLoanCommand {
+        pub direction: LoanDirection,
+        pub description: String,
+        /// Amount owed (must be positive)
+        pub amount: f64,
+        /// When `None`, the loan is dated now
+        pub date: Option<chrono::DateTime<chrono::FixedOffset>>,
+        /// Allow the opening spend to overdraw a child with the "no negative balance" rule
+        pub parent_override: bool,
+        /// Who recorded the loan
+        pub actor: Option<Actor>,
+    }
+
+    /// Command to pay back some or all of an open loan for the active child.
+    #[derive(Debug, Clone)]
+    pub struct SettleLoanCommand {
+        pub loan_id: String,
+        /// When `None`, everything still owed is settled
+        pub amount: Option<f64>,
+        /// When `None`, the settlement is dated now
+        pub date: Option<chrono::DateTime<chrono::FixedOffset>>,
+        /// Allow a repayment to overdraw a child with the "no negative balance" rule
+        pub parent_override: bool,
+        /// Who recorded the repayment
+        pub actor: Option<Actor>,
+    }
+
+    /// Result of opening or settling a loan.
+    #[derive(Debug, Clone)]
+    pub struct LoanResult {
+        pub loan: Loan,
+        /// The transaction that was just recorded
+        pub transaction: Transaction,
+    }
+
+    /// Query for what is still owed between a child and their parents.
+    #[derive(Debug, Clone)]
+    pub struct OutstandingLoansQuery {
+        pub child_id: Option<String>,
+    }
+
+    /// Open loans for a child, oldest first, with totals in each direction.
+    #[derive(Debug, Clone)]
+    pub struct OutstandingLoansResult {
+        pub child_id: String,
+        pub open_loans: Vec<Loan>,
+        /// What the child still owes their parents
+        pub owed_by_child: f64,
+        /// What the parents still owe the child
+        pub owed_to_child: f64,
+    }
+}
+
+pub mod advances {
+    use crate::backend::domain::models::actor::Actor;
+    use crate::backend::domain::models::allowance_advance::AllowanceAdvance;
+    use crate::backend::domain::models::transaction::Transaction;
+
+    /// Command to pay the active child part of their upcoming allowance now.
+    #[derive(Debug, Clone)]
+    pub struct GrantAdvanceCommand {
+        /// Amount paid now (must be positive)
+        pub amount: f64,
+        /// How many upcoming allowances repay it
+        pub installments: u32,
+        /// Who granted the advance
+        pub actor: Option<Actor>,
+    }
+
+    /// Result of granting an advance.
+    #[derive(Debug, Clone)]
+    pub struct AdvanceResult {
+        pub advance: AllowanceAdvance,
+        /// The income transaction that paid the advance
+        pub transaction: Transaction,
+    }
+
+    /// Query for advances a child is still repaying.
+    #[derive(Debug, Clone)]
+    pub struct OutstandingAdvancesQuery {
+        pub child_id: Option<String>,
+    }
+
+    /// Advances still being repaid, oldest first, with the total left.
+    #[derive(Debug, Clone)]
+    pub struct OutstandingAdvancesResult {
+        pub child_id: String,
+        pub open_advances: Vec<AllowanceAdvance>,
+        /// What is still to come out of upcoming allowances
+        pub outstanding: f64,
+    }
+}
+
+pub mod quick_picks {
+    use crate::backend::domain::models::quick_pick::{QuickPick, QuickPickKind};
+
+    /// Command to save a quick-pick description for the active child.
+    #[derive(Debug, Clone)]
+    pub struct AddQuickPickCommand {
+        pub description: String,
+        /// Amount filled in along with the description, if any
+        pub amount: Option<f64>,
+        pub kind: QuickPickKind,
+    }
+
+    /// Command to remove one of the active child's quick picks.
+    #[derive(Debug, Clone)]
+    pub struct RemoveQuickPickCommand {
+        pub quick_pick_id: String,
+    }
+
+    /// Query for a child's saved quick picks.
+    #[derive(Debug, Clone)]
+    pub struct ListQuickPicksQuery {
+        pub child_id: Option<String>,
+    }
+
+    /// A child's quick picks in the order they were added.
+    #[derive(Debug, Clone)]
+    pub struct ListQuickPicksResult {
+        pub child_id: String,
+        pub quick_picks: Vec<QuickPick>,
+    }
+
+    /// Query for descriptions to offer while a money form is filled in.
+    #[derive(Debug, Clone)]
+    pub struct DescriptionSuggestionsQuery {
+        pub child_id: Option<String>,
+        pub kind: QuickPickKind,
+        /// What has been typed so far; empty offers everything
+        pub prefix: String,
+        pub limit: usize,
+    }
+
+    /// A description to offer, with the amount to fill in if there is one.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DescriptionSuggestion {
+        pub description: String,
+        pub amount: Option<f64>,
+    }
+
+    /// Suggestions, best first.
+    #[derive(Debug, Clone)]
+    pub struct DescriptionSuggestionsResult {
+        pub suggestions: Vec<DescriptionSuggestion>,
+    }
+}
+
+pub mod templates {
+    use crate::backend::domain::models::actor::Actor;
+    use crate::backend::domain::models::transaction::{Transaction, TransactionType};
+    use crate::backend::domain::models::transaction_template::TransactionTemplate;
+
+    /// Command to save a transaction template for the active child.
+    #[derive(Debug, Clone)]
+    pub struct CreateTemplateCommand {
+        pub name: String,
+        pub description: String,
+        /// Positive amount; the type says whether it is money in or out
+        pub amount: f64,
+        pub category: Option<String>,
+        pub transaction_type: TransactionType,
+    }
+
+    /// Command to change one of the active child's templates.
+    #[derive(Debug, Clone)]
+    pub struct UpdateTemplateCommand {
+        pub template_id: String,
+        pub name: String,
+        pub description: String,
+        pub amount: f64,
+        pub category: Option<String>,
+        pub transaction_type: TransactionType,
+    }
+
+    /// Command to delete one of the active child's templates.
+    #[derive(Debug, Clone)]
+    pub struct DeleteTemplateCommand {
+        pub template_id: String,
+    }
+
+    /// Query for a child's templates.
+    #[derive(Debug, Clone)]
+    pub struct ListTemplatesQuery {
+        pub child_id: Option<String>,
+    }
+
+    /// A child's templates in the order they were added.
+    #[derive(Debug, Clone)]
+    pub struct ListTemplatesResult {
+        pub child_id: String,
+        pub templates: Vec<TransactionTemplate>,
+    }
+
+    /// Command to record a transaction from one of the active child's templates.
+    #[derive(Debug, Clone)]
+    pub struct UseTemplateCommand {
+        pub template_id: String,
+        /// When it happened; `None` for now
+        pub date: Option<chrono::DateTime<chrono::FixedOffset>>,
+        /// Parent-mode override for the child's "no negative balance" rule
+        pub parent_override: bool,
+        pub actor: Option<Actor>,
+    }
+
+    /// The template used and the transaction it recorded.
+    #[derive(Debug, Clone)]
+    pub struct UseTemplateResult {
+        pub template: TransactionTemplate,
+        pub transaction: Transaction,
+    }
+}
+
+pub mod import {
+    use crate::backend::domain::models::actor::Actor;
+    use crate::backend::domain::models::transaction::Transaction;
+    use chrono::NaiveDate;
+    use std::collections::HashMap;
+
+    /// Which way round a statement's amounts are.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum SignConvention {
+        /// Negative amounts are money spent, as in most bank exports
+        NegativeIsSpending,
+        /// Positive amounts are money spent, as in most credit card exports
+        PositiveIsSpending,
+    }
+
+    /// Which columns of a statement CSV hold what, counted from 0.
+    #[derive(Debug, Clone)]
+    pub struct StatementColumnMapping {
+        pub date_column: usize,
+        pub description_column: usize,
+        pub amount_column: usize,
+        pub sign_convention: SignConvention,
+        /// chrono format of the date column, e.g. "%m/%d/%Y"
+        pub date_format: String,
+        /// The first row names the columns and is not imported
+        pub has_header_row: bool,
+        pub delimiter: u8,
+        /// Column naming what each row was for, if the statement has one
+        pub category_column: Option<usize>,
+    }
+
+    /// The kinds of statement file that can be imported.
+    #[derive(Debug, Clone)]
+    pub enum StatementFormat {
+        /// Any CSV, read through a column mapping
+        Csv(StatementColumnMapping),
+        /// Open Financial Exchange (.ofx/.qfx), exported by banks and kids' debit cards
+        Ofx,
+        /// Quicken Interchange Format (.qif); dates are read month first
+        Qif,
+    }
+
+    /// Command to parse a statement without importing anything.
+    #[derive(Debug, Clone)]
+    pub struct PreviewStatementCommand {
+        pub content: String,
+        pub format: StatementFormat,
+    }
+
+    /// One row of a statement as it would be imported.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct StatementRow {
+        /// Line in the file, from 1
+        pub line: usize,
+        pub date: Option<NaiveDate>,
+        pub description: String,
+        /// Signed the way transactions are: negative is money spent
+        pub amount: Option<f64>,
+        /// Category as the statement names it
+        pub category: Option<String>,
+        /// ID of a transaction already in the child's history with the same
+        /// date, amount and description
+        pub duplicate_of: Option<String>,
+        /// Why the row can't be imported; empty if it can
+        pub errors: Vec<String>,
+    }
+
+    impl StatementRow {
+        /// The row can be imported as it is
+        pub fn is_valid(&self) -> bool {
+            self.errors.is_empty()
+        }
+    }
+
+    /// A parsed statement, in file order.
+    #[derive(Debug, Clone)]
+    pub struct StatementPreview {
+        /// Column names, when the mapping says there is a header row
+        pub headers: Option<Vec<String>>,
+        pub rows: Vec<StatementRow>,
+        /// Distinct categories named in the statement, for mapping to the
+        /// family's own
+        pub categories: Vec<String>,
+        /// Categories already used in the child's history
+        pub known_categories: Vec<String>,
+    }
+
+    /// Command to import chosen rows of a statement for the active child.
+    #[derive(Debug, Clone)]
+    pub struct ImportStatementCommand {
+        pub content: String,
+        pub format: StatementFormat,
+        /// `StatementRow::line`s to import
+        pub accepted_lines: Vec<usize>,
+        /// Statement category to the category to record; an empty value
+        /// records none. Unmapped categories are kept as the statement names them.
+        pub category_mapping: HashMap<String, String>,
+        pub actor: Option<Actor>,
+    }
+
+    /// A statement row ready to be recorded.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ImportRow {
+        pub date: NaiveDate,
+        pub description: String,
+        pub amount: f64,
+        pub category: Option<String>,
+    }
+
+    /// Result of importing a statement.
+    #[derive(Debug, Clone)]
+    pub struct ImportStatementResult {
+        /// New transactions, oldest first
+        pub imported: Vec<Transaction>,
+        /// Accepted lines that were not imported, and why
+        pub skipped: Vec<(usize, String)>,
+    }
+}
+
+pub mod planned_purchases {
+    use crate::backend::domain::models::actor::Actor;
+    use crate::backend::domain::models::planned_purchase::PlannedPurchase;
+    use crate::backend::domain::models::transaction::Transaction;
+
+    /// Command to reserve money for something the active child plans to buy.
+    #[derive(Debug, Clone)]
+    pub struct CreatePlannedPurchaseCommand {
+        pub description: String,
+        /// Amount to reserve (must be positive)
+        pub amount: f64,
+        /// Today or a later day
+        pub planned_date: chrono::NaiveDate,
+    }
+
+    /// Command to record a planned purchase as an actual expense.
+    #[derive(Debug, Clone)]
+    pub struct ConfirmPlannedPurchaseCommand {
+        pub purchase_id: String,
+        /// What it actually cost; the planned amount when `None`
+        pub amount: Option<f64>,
+        /// When `None`, the expense is dated now
+        pub date: Option<chrono::DateTime<chrono::FixedOffset>>,
+        /// Who confirmed the purchase
+        pub actor: Option<Actor>,
+    }
+
+    /// Command to drop a planned purchase and release its money.
+    #[derive(Debug, Clone)]
+    pub struct CancelPlannedPurchaseCommand {
+        pub purchase_id: String,
+    }
+
+    /// Result of confirming a planned purchase.
+    #[derive(Debug, Clone)]
+    pub struct ConfirmPlannedPurchaseResult {
+        pub purchase: PlannedPurchase,
+        pub transaction: Transaction,
+    }
+
+    /// Query for a child's planned purchases and available balance.
+    #[derive(Debug, Clone)]
+    pub struct PlannedPurchasesQuery {
+        pub child_id: Option<String>,
+        /// Also return confirmed and cancelled purchases
+        pub include_closed: bool,
+    }
+
+    /// A child's planned purchases, by planned date, and what they leave available.
+    #[derive(Debug, Clone)]
+    pub struct PlannedPurchasesResult {
+        pub child_id: String,
+        pub purchases: Vec<PlannedPurchase>,
+        pub balance: f64,
+        /// Total of purchases still planned
+        pub reserved: f64,
+        /// Balance minus reserved
+        pub available: f64,
+    }
+}
+
+pub mod health {
+    /// Schema version of one config file compared with what this build writes.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SchemaVersionCheck {
+        /// Path relative to the data directory
+        pub file: String,
+        /// `None` if the file could not be read
+        pub version: Option<u32>,
+        pub current_version: u32,
+    }
+
+    /// Result of checking that the backend can read and write its data.
+    #[derive(Debug, Clone)]
+    pub struct HealthReport {
+        pub data_directory: String,
+        /// The data directory exists and can be listed
+        pub storage_reachable: bool,
+        /// A file could be written to the data directory
+        pub writable: bool,
+        /// Why changes are disabled, if they are on purpose
+        pub read_only_reason: Option<String>,
+        pub schema_versions: Vec<SchemaVersionCheck>,
+        /// Everything that keeps the backend from being healthy
+        pub problems: Vec<String>,
+    }
+
+    impl HealthReport {
+        /// No problems were found
+        pub fn is_healthy(&self) -> bool {
+            self.problems.is_empty()
+        }
+    }
+
+    /// Which build of the backend is running.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct VersionInfo {
+        pub app_version: String,
+        /// Set at build time from `ALLOWANCE_TRACKER_GIT_COMMIT`, if it was
+        pub git_commit: Option<String>,
+        pub storage_backend: String,
+    }
+}
+
+pub mod retention {
+    use super::super::models::retention_policy::RetentionRule;
+
+    /// Command to change how long growing records are kept; `None` keeps them forever.
+    #[derive(Debug, Clone, Default)]
+    pub struct UpdateRetentionPolicyCommand {
+        pub audit_log_days: Option<u32>,
+        pub parental_control_attempt_days: Option<u32>,
+        pub archived_transaction_years: Option<u32>,
+    }
+
+    /// Records one rule removes, or would remove, from one place.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RetentionReportItem {
+        pub rule: RetentionRule,
+        /// Child whose files are affected; `None` for the family-wide attempts file
+        pub child_id: Option<String>,
+        /// Child name, or "Family" for the family-wide file
+        pub owner: String,
+        /// Records dated before this day are removed
+        pub cutoff: chrono::NaiveDate,
+        pub removed_count: usize,
+    }
+
+    /// What a retention run removed, or would remove when `dry_run` is set.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RetentionReport {
+        pub dry_run: bool,
+        /// One item for each rule and place with something to remove
+        pub items: Vec<RetentionReportItem>,
+        /// Places that couldn't be read, such as locked encrypted children
+        pub skipped: Vec<String>,
+    }
+
+    impl RetentionReport {
+        /// Records removed, or that would be removed, across every rule
+        pub fn removed_count(&self) -> usize {
+            self.items.iter().map(|item| item.removed_count).sum()
+        }
+    }
+}
+
+pub mod budget {
+    use super::super::models::budget_target::BudgetTarget;
+
+    /// Command to set a monthly spending target for one of a child's categories.
+    #[derive(Debug, Clone)]
+    pub struct SetBudgetTargetCommand {
+        pub child_id: Option<String>,
+        pub category: String,
+        pub monthly_limit: f64,
+    }
+
+    /// Command to remove the target for a category.
+    #[derive(Debug, Clone)]
+    pub struct RemoveBudgetTargetCommand {
+        pub child_id: Option<String>,
+        pub category: String,
+    }
+
+    /// Query for a child's spending targets.
+    #[derive(Debug, Clone)]
+    pub struct ListBudgetTargetsQuery {
+        pub child_id: Option<String>,
+    }
+
+    /// A child's targets in the order they were added.
+    #[derive(Debug, Clone)]
+    pub struct ListBudgetTargetsResult {
+        pub child_id: String,
+        pub targets: Vec<BudgetTarget>,
+    }
+
+    /// Query for how a month's spending compares with the targets.
+    #[derive(Debug, Clone)]
+    pub struct BudgetStatusQuery {
+        pub child_id: Option<String>,
+        pub year: i32,
+        pub month: u32,
+    }
+
+    /// Spending against the target for one category.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct CategoryBudgetStatus {
+        pub category: String,
+        pub target: f64,
+        pub spent: f64,
+        /// Negative once the target is passed
+        pub remaining: f64,
+        pub over_budget: bool,
+    }
+
+    /// How a month's spending compares with each target, in target order.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct BudgetStatusResult {
+        pub child_id: String,
+        pub year: i32,
+        pub month: u32,
+        pub categories: Vec<CategoryBudgetStatus>,
+        /// Spending in the month with no category, or one without a target
+        pub untargeted_spent: f64,
+    }
+
+    impl BudgetStatusResult {
+        /// Categories whose target was passed this month
+        pub fn over_budget(&self) -> impl Iterator<Item = &CategoryBudgetStatus> {
+            self.categories.iter().filter(|status| status.over_budget)
+        }
+    }
+}
+
+pub mod scheduled_reports {
+    use super::super::models::report_schedule::GeneratedReport;
+
+    /// Command to choose the folder monthly reports are written to; `None` stops them.
+    #[derive(Debug, Clone)]
+    pub struct UpdateReportScheduleCommand {
+        pub folder: Option<String>,
+    }
+
+    /// Command to write every child's report for a month right away.
+    #[derive(Debug, Clone)]
+    pub struct GenerateMonthlyReportsCommand {
+        pub year: i32,
+        pub month: u32,
+    }
+
+    /// Report files written for one month.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GeneratedReportsResult {
+        pub year: i32,
+        pub month: u32,
+        pub reports: Vec<GeneratedReport>,
+        /// Children whose data couldn't be read, such as locked encrypted children
+        pub skipped: Vec<String>,
+    }
+}
+
+pub mod celebrations {
+    use super::super::models::celebration::{Celebration, ReachedMilestone};
+
+    /// Query for a child's celebrations that haven't been shown yet.
+    #[derive(Debug, Clone)]
+    pub struct PendingCelebrationsQuery {
+        pub child_id: Option<String>,
+    }
+
+    /// A child's pending celebrations, oldest first.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PendingCelebrationsResult {
+        pub child_id: String,
+        pub celebrations: Vec<Celebration>,
+    }
+
+    /// Command to mark celebrations as shown.
+    #[derive(Debug, Clone)]
+    pub struct AcknowledgeCelebrationsCommand {
+        pub child_id: Option<String>,
+        pub celebration_ids: Vec<String>,
+    }
+
+    /// How many of the celebrations were still pending.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct AcknowledgeCelebrationsResult {
+        pub acknowledged_count: u32,
+    }
+
+    /// Query for a child's milestone timeline.
+    #[derive(Debug, Clone)]
+    pub struct MilestoneHistoryQuery {
+        pub child_id: Option<String>,
+    }
+
+    /// Milestones a child has reached, oldest first, and the ones still ahead.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MilestoneHistoryResult {
+        pub child_id: String,
+        pub reached: Vec<ReachedMilestone>,
+        /// Thresholds from the child's settings not reached yet, lowest first
+        pub upcoming: Vec<f64>,
+    }
+}
+
+pub mod agreement {
+    /// Command to write a printable allowance agreement for a child.
+    #[derive(Debug, Clone)]
+    pub struct GenerateAgreementCommand {
+        pub child_id: Option<String>,
+        /// Chores the child agrees to do; blank lines are printed when empty
+        pub chores: Vec<String>,
+        /// Family rules printed after the ones that come from the child's settings
+        pub rules: Vec<String>,
+        /// Folder to write the PDF to; the Documents folder when `None`
+        pub output_directory: Option<String>,
+    }
+
+    /// Where the agreement was written.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GenerateAgreementResult {
+        pub child_id: String,
+        pub file_path: String,
+    }
+}
+
+pub mod desktop_notifications {
+    /// Command to choose which events show a desktop notification.
+    #[derive(Debug, Clone)]
+    pub struct UpdateDesktopNotificationsCommand {
+        pub allowance_posted: bool,
+        pub goal_completed: bool,
+    }
+}
+
+pub mod updates {
+    use super::super::models::update_settings::{AvailableUpdate, ReleaseChannel};
+
+    /// Command to choose which releases are offered as updates.
+    #[derive(Debug, Clone)]
+    pub struct UpdateReleaseChannelCommand {
+        pub channel: ReleaseChannel,
+    }
+
+    /// Command to turn the daily update check on or off.
+    #[derive(Debug, Clone)]
+    pub struct UpdateAutomaticCheckCommand {
+        pub enabled: bool,
+    }
+
+    /// Command to put off an update until a newer one comes out.
+    #[derive(Debug, Clone)]
+    pub struct PostponeUpdateCommand {
+        pub version: String,
+    }
+
+    /// What an update check found.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct UpdateCheckResult {
+        /// Version of the running build
+        pub current_version: String,
+        pub channel: ReleaseChannel,
+        /// The newest release on the channel, when it is newer than the running build
+        pub available: Option<AvailableUpdate>,
+    }
+}
+
+pub mod data_directory {
+    /// A relocated child's data folder that isn't where its redirect says.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MissingDataFolder {
+        /// The child's folder in the default data directory
+        pub child_directory: String,
+        /// Where the redirect points, e.g. a drive that isn't plugged in
+        pub expected_path: String,
+    }
+
+    /// Command to point a relocated child at where its folder is now.
+    #[derive(Debug, Clone)]
+    pub struct LocateDataFolderCommand {
+        pub child_directory: String,
+        pub path: String,
+    }
+}
+
+pub mod fridge_view {
+    /// Command to choose where the fridge view is written and whether it is kept up to date.
+    #[derive(Debug, Clone)]
+    pub struct UpdateFridgeViewCommand {
+        /// The HTML file to write; `None` forgets it
+        pub path: Option<String>,
+        pub auto_update: bool,
+    }
+
+    /// A fridge view file that was written.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct FridgeViewResult {
+        pub path: String,
+        pub child_count: usize,
+        /// Children whose data couldn't be read, such as locked encrypted children
+        pub skipped: Vec<String>,
+        /// RFC 3339 timestamp
+        pub generated_at: String,
+    }
+}
@@ -5,16 +5,18 @@ use std::sync::Arc;
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
 
-use crate::backend::storage::csv::CsvConnection;
+use crate::backend::storage::csv::{CsvConnection, LockStatus, ReadOnlyError, ReadOnlySource};
 
 use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::commands::data_directory::{LocateDataFolderCommand, MissingDataFolder};
+use crate::backend::domain::errors::DomainError;
 use shared::{
     GetDataDirectoryResponse, RelocateDataDirectoryRequest, RelocateDataDirectoryResponse, 
     RevertDataDirectoryRequest, RevertDataDirectoryResponse,
     CheckDataDirectoryConflictRequest, CheckDataDirectoryConflictResponse,
     RelocateWithConflictResolutionRequest, RelocateWithConflictResolutionResponse,
     ReturnToDefaultLocationRequest, ReturnToDefaultLocationResponse,
-    ConflictResolution
+    ConflictResolution, PlannedFileOperation, RelocationPlan
 };
 
 /// Service for managing data directory operations
@@ -41,17 +43,17 @@ impl DataDirectoryService {
             id.to_string()
         } else {
             let response = self.child_service.get_active_child()?;
-            response.active_child.child.ok_or_else(|| anyhow::anyhow!("No active child found"))?.id
+            response.active_child.child.ok_or(DomainError::NoActiveChild)?.id
         };
 
-        // Get the child's name (CSV connection expects name, not ID)
+        // Get the child's directory name (CSV connection expects it, not the ID)
         let child = self.child_service.get_child(crate::backend::domain::commands::child::GetChildCommand {
             child_id: child_id_to_use.clone(),
         })?;
         
         let child_name = match child.child {
-            Some(child) => child.name,
-            None => return Err(anyhow::anyhow!("Child not found: {}", child_id_to_use)),
+            Some(child) => self.child_directory_name(&child)?,
+            None => return Err(DomainError::child_not_found(&child_id_to_use).into()),
         };
 
         let current_path = self.csv_connection.get_child_directory(&child_name);
@@ -63,11 +65,24 @@ impl DataDirectoryService {
         let redirect_file = default_child_dir.join(".allowance_redirect");
         let is_redirected = redirect_file.exists();
 
-        info!("Current data directory for child '{}' (name: '{}'): {} (redirected: {})", child_id_to_use, child_name, path_str, is_redirected);
+        // Changes are disabled in read-only mode, or when another instance
+        // (e.g. on a second machine sharing a synced folder) holds the lock
+        let read_only_reason = match (self.csv_connection.read_only_source(), self.csv_connection.child_lock_status(&current_path)) {
+            (Some(enabled_by), _) => Some(ReadOnlyError { enabled_by }.to_string()),
+            (None, LockStatus::HeldByOther(holder)) => Some(format!(
+                "Data is in use on '{}' since {}. Changes are disabled until that app is closed.",
+                holder.hostname, holder.acquired_at
+            )),
+            (None, LockStatus::Unlocked | LockStatus::HeldByUs) => None,
+        };
+
+        info!("Current data directory for child '{}' (name: '{}'): {} (redirected: {}, read-only: {})", child_id_to_use, child_name, path_str, is_redirected, read_only_reason.is_some());
 
         Ok(GetDataDirectoryResponse {
             current_path: path_str,
             is_redirected,
+            is_read_only: read_only_reason.is_some(),
+            read_only_reason,
         })
     }
 
@@ -82,19 +97,33 @@ impl DataDirectoryService {
             id.to_string()
         } else {
             let response = self.child_service.get_active_child()?;
-            response.active_child.child.ok_or_else(|| anyhow::anyhow!("No active child found"))?.id
+            response.active_child.child.ok_or(DomainError::NoActiveChild)?.id
         };
 
-        // Get the child's name (CSV connection expects name, not ID)
+        // Get the child's directory name (CSV connection expects it, not the ID)
         let child = self.child_service.get_child(crate::backend::domain::commands::child::GetChildCommand {
             child_id: child_id_to_use.clone(),
         })?;
         
         let child_name = match child.child {
-            Some(child) => child.name,
-            None => return Err(anyhow::anyhow!("Child not found: {}", child_id_to_use)),
+            Some(child) => self.child_directory_name(&child)?,
+            None => return Err(DomainError::child_not_found(&child_id_to_use).into()),
         };
 
+        if request.dry_run {
+            let plan = self.plan_relocation(&child_name, &request.new_path, None)?;
+            let message = Self::describe_plan(&plan);
+            info!("🔍 Dry run relocation for child '{}': {}", child_id_to_use, message);
+            return Ok(RelocateDataDirectoryResponse {
+                success: plan.can_proceed,
+                message,
+                new_path: request.new_path,
+                plan: Some(plan),
+            });
+        }
+
+        self.csv_connection.ensure_writable()?;
+
         info!("🔄 About to call csv_connection.relocate_child_data_directory with child '{}' (name: '{}') and path: {}", child_id_to_use, child_name, request.new_path);
         match self.csv_connection.relocate_child_data_directory(&child_name, &request.new_path) {
             Ok(message) => {
@@ -103,6 +132,7 @@ impl DataDirectoryService {
                     success: true,
                     message,
                     new_path: request.new_path,
+                    plan: None,
                 })
             }
             Err(e) => {
@@ -114,6 +144,7 @@ impl DataDirectoryService {
                     success: false,
                     message: error_message,
                     new_path: request.new_path,
+                    plan: None,
                 })
             }
         }
@@ -125,22 +156,23 @@ impl DataDirectoryService {
         request: RevertDataDirectoryRequest,
     ) -> Result<RevertDataDirectoryResponse> {
         info!("Reverting data directory for child_id: {:?}", request.child_id);
+        self.csv_connection.ensure_writable()?;
 
         let child_id_to_use = if let Some(id) = request.child_id.as_deref() {
             id.to_string()
         } else {
             let response = self.child_service.get_active_child()?;
-            response.active_child.child.ok_or_else(|| anyhow::anyhow!("No active child found"))?.id
+            response.active_child.child.ok_or(DomainError::NoActiveChild)?.id
         };
 
-        // Get the child's name (CSV connection expects name, not ID)
+        // Get the child's directory name (CSV connection expects it, not the ID)
         let child = self.child_service.get_child(crate::backend::domain::commands::child::GetChildCommand {
             child_id: child_id_to_use.clone(),
         })?;
         
         let child_name = match child.child {
-            Some(child) => child.name,
-            None => return Err(anyhow::anyhow!("Child not found: {}", child_id_to_use)),
+            Some(child) => self.child_directory_name(&child)?,
+            None => return Err(DomainError::child_not_found(&child_id_to_use).into()),
         };
 
         // Check if there's actually a redirect file
@@ -243,9 +275,27 @@ impl DataDirectoryService {
             id.to_string()
         } else {
             let response = self.child_service.get_active_child()?;
-            response.active_child.child.ok_or_else(|| anyhow::anyhow!("No active child found"))?.id
+            response.active_child.child.ok_or(DomainError::NoActiveChild)?.id
         };
 
+        if request.dry_run && request.resolution != ConflictResolution::Cancel {
+            let child_name = self.get_child_name(&child_id_to_use)?;
+            let plan = self.plan_relocation(&child_name, &request.new_path, Some(&request.resolution))?;
+            let message = Self::describe_plan(&plan);
+            info!("🔍 Dry run {:?} for child '{}': {}", request.resolution, child_id_to_use, message);
+            return Ok(RelocateWithConflictResolutionResponse {
+                success: plan.can_proceed,
+                message,
+                new_path: request.new_path,
+                archived_to: None,
+                plan: Some(plan),
+            });
+        }
+
+        if request.resolution != ConflictResolution::Cancel {
+            self.csv_connection.ensure_writable()?;
+        }
+
         match request.resolution {
             ConflictResolution::Cancel => {
                 info!("User cancelled relocation");
@@ -254,6 +304,7 @@ impl DataDirectoryService {
                     message: "Operation cancelled by user".to_string(),
                     new_path: request.new_path,
                     archived_to: None,
+                    plan: None,
                 })
             }
             ConflictResolution::OverwriteTarget => {
@@ -267,6 +318,7 @@ impl DataDirectoryService {
                 let relocate_request = RelocateDataDirectoryRequest {
                     child_id: Some(child_id_to_use),
                     new_path: request.new_path.clone(),
+                    dry_run: false,
                 };
                 
                 let result = self.relocate_directory(relocate_request)?;
@@ -276,19 +328,20 @@ impl DataDirectoryService {
                     message: result.message,
                     new_path: result.new_path,
                     archived_to: None,
+                    plan: None,
                 })
             }
             ConflictResolution::UseTargetData => {
                 info!("Using target data and archiving current data");
                 
-                // Get the child's name first (needed for directory operations)
+                // Get the child's directory name first (needed for directory operations)
                 let child = self.child_service.get_child(crate::backend::domain::commands::child::GetChildCommand {
                     child_id: child_id_to_use.clone(),
                 })?;
                 
                 let child_name = match child.child {
-                    Some(child) => child.name,
-                    None => return Err(anyhow::anyhow!("Child not found: {}", child_id_to_use)),
+                    Some(child) => self.child_directory_name(&child)?,
+                    None => return Err(DomainError::child_not_found(&child_id_to_use).into()),
                 };
                 
                 // Archive current data first
@@ -328,11 +381,300 @@ impl DataDirectoryService {
                     message: format!("Now using data from target location. Original data archived to: {}", archive_path),
                     new_path: request.new_path,
                     archived_to: Some(archive_path),
+                    plan: None,
                 })
             }
         }
     }
 
+    /// What enabled read-only mode, if it is on
+    pub fn read_only_source(&self) -> Option<ReadOnlySource> {
+        self.csv_connection.read_only_source()
+    }
+
+    /// Turn read-only mode on or off from settings
+    pub fn set_read_only(&self, enabled: bool) {
+        self.csv_connection.set_read_only(enabled.then_some(ReadOnlySource::Settings));
+    }
+
+    /// Relocated children whose folder can't be found, such as on an unplugged drive
+    ///
+    /// The backend opens read-only while any are missing, so nothing is
+    /// written to the default location in their place.
+    pub fn missing_data_folders(&self) -> Result<Vec<MissingDataFolder>> {
+        Ok(self
+            .csv_connection
+            .missing_redirect_targets()?
+            .into_iter()
+            .map(|(child_directory, path)| MissingDataFolder {
+                child_directory,
+                expected_path: path.to_string_lossy().to_string(),
+            })
+            .collect())
+    }
+
+    /// Look for missing folders again, leaving read-only mode once all are back
+    ///
+    /// Returns the folders that are still missing.
+    pub fn retry_missing_data_folders(&self) -> Result<Vec<MissingDataFolder>> {
+        let missing = self.missing_data_folders()?;
+        if missing.is_empty() && self.csv_connection.read_only_source() == Some(ReadOnlySource::DataDirectoryMissing) {
+            // Startup skipped the config migrations while the folders were missing
+            let report = crate::backend::storage::csv::migrations::run_startup_migrations(&self.csv_connection)?;
+            if report.failed.is_empty() {
+                self.csv_connection.set_read_only(None);
+                info!("📁 All data folders found; changes are enabled again");
+            } else {
+                warn!("📁 {} config file(s) could not be migrated; staying read-only", report.failed.len());
+                self.csv_connection.set_read_only(Some(ReadOnlySource::MigrationFailed));
+            }
+        }
+        Ok(missing)
+    }
+
+    /// Point a relocated child at where its folder is now, e.g. a drive
+    /// that came back under a different letter
+    ///
+    /// Returns the folders that are still missing.
+    pub fn locate_data_folder(&self, command: LocateDataFolderCommand) -> Result<Vec<MissingDataFolder>> {
+        let path = std::path::Path::new(command.path.trim());
+        if !path.is_dir() {
+            return Err(DomainError::invalid(format!("Folder does not exist: {}", path.display())).into());
+        }
+        self.csv_connection
+            .redirect_child_directory(&command.child_directory, path)
+            .map_err(|e| DomainError::invalid(e.to_string()))?;
+        self.retry_missing_data_folders()
+    }
+
+    /// Keep the directory locks held by this app instance fresh while it is open
+    pub fn refresh_locks(&self) {
+        self.csv_connection.refresh_directory_locks();
+    }
+
+    /// Release the directory locks held by this app instance (call on shutdown)
+    pub fn release_locks(&self) {
+        self.csv_connection.release_directory_locks();
+    }
+
+    /// Commit recent changes to git once the app has been idle for a few seconds
+    pub fn commit_idle_changes(&self) {
+        self.csv_connection.commit_idle_changes();
+    }
+
+    /// Commit every outstanding change to git (call on shutdown)
+    pub fn commit_pending_changes(&self) {
+        self.csv_connection.commit_pending_changes();
+    }
+
+    /// Look up a child's directory name (CSV connection expects it, not the ID)
+    fn get_child_name(&self, child_id: &str) -> Result<String> {
+        let child = self.child_service.get_child(crate::backend::domain::commands::child::GetChildCommand {
+            child_id: child_id.to_string(),
+        })?;
+        match child.child {
+            Some(child) => self.child_directory_name(&child),
+            None => Err(DomainError::child_not_found(child_id).into()),
+        }
+    }
+
+    /// The directory a child's data lives under in the base directory
+    ///
+    /// This is the safe directory name ("ada" for "Ada"), not the display
+    /// name, so it is looked up by ID like the repositories do.
+    fn child_directory_name(&self, child: &crate::backend::domain::models::child::Child) -> Result<String> {
+        Ok(self
+            .csv_connection
+            .find_child_directory_by_id(&child.id)?
+            .unwrap_or_else(|| CsvConnection::generate_safe_directory_name(&child.name)))
+    }
+
+    /// Work out what a relocation would do without touching disk.
+    ///
+    /// `resolution` is `None` for a plain relocation, which mirrors
+    /// `CsvConnection::relocate_child_data_directory`; the conflict
+    /// resolutions mirror the branches of `relocate_with_conflict_resolution`.
+    fn plan_relocation(
+        &self,
+        child_name: &str,
+        new_path: &str,
+        resolution: Option<&ConflictResolution>,
+    ) -> Result<RelocationPlan> {
+        let source_dir = self.csv_connection.get_child_directory(child_name);
+        let default_child_dir = self.csv_connection.base_directory().join(child_name);
+        let target_dir = CsvConnection::unescape_relocation_path(new_path);
+
+        let mut plan = RelocationPlan {
+            source_path: source_dir.to_string_lossy().to_string(),
+            target_path: target_dir.to_string_lossy().to_string(),
+            files_to_copy: Vec::new(),
+            files_to_overwrite: Vec::new(),
+            files_to_archive: Vec::new(),
+            files_to_remove: Vec::new(),
+            archive_path: None,
+            directories_to_create: Vec::new(),
+            estimated_bytes: 0,
+            required_permissions: Vec::new(),
+            issues: Vec::new(),
+            can_proceed: false,
+        };
+
+        if !source_dir.exists() {
+            plan.issues.push(format!("Child '{}' directory does not exist: {}", child_name, source_dir.display()));
+            return Ok(plan);
+        }
+        if let Some(enabled_by) = self.csv_connection.read_only_source() {
+            plan.issues.push(ReadOnlyError { enabled_by }.to_string());
+        }
+        if let LockStatus::HeldByOther(holder) = self.csv_connection.child_lock_status(&source_dir) {
+            plan.issues.push(format!("Data is in use by another instance on '{}'", holder.hostname));
+        }
+
+        let mut writable_dirs = Vec::new();
+
+        if let Some(ConflictResolution::UseTargetData) = resolution {
+            // Current data is archived, the default directory is cleared (except .git)
+            // and a redirect file pointing at the target is written
+            if !self.directory_contains_child_data(&target_dir) {
+                plan.issues.push(format!("Target directory has no child data to use: {}", target_dir.display()));
+            }
+
+            plan.files_to_archive = Self::list_files(&source_dir, &[".git", ".allowance_redirect"])?;
+            let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+            let archive_dir = self.csv_connection.base_directory().join("archive").join(format!("{}_{}", child_name, timestamp));
+            plan.directories_to_create = Self::missing_directories(&archive_dir);
+            plan.archive_path = Some(archive_dir.to_string_lossy().to_string());
+            writable_dirs.push(archive_dir);
+
+            plan.estimated_bytes = plan.files_to_archive.iter().map(|f| f.size_bytes).sum();
+        } else {
+            if target_dir == source_dir {
+                plan.can_proceed = true;
+                return Ok(plan);
+            }
+
+            if target_dir.exists() {
+                let is_empty = std::fs::read_dir(&target_dir)
+                    .map_err(|e| anyhow::anyhow!("Cannot read target directory {}: {}", target_dir.display(), e))?
+                    .next()
+                    .is_none();
+                if matches!(resolution, Some(ConflictResolution::OverwriteTarget)) {
+                    // The whole target directory is removed before copying
+                    plan.files_to_overwrite = Self::list_files(&target_dir, &[])?;
+                } else if !is_empty {
+                    plan.issues.push(format!("Target directory is not empty: {}", target_dir.display()));
+                }
+            }
+
+            plan.files_to_copy = Self::list_files(&source_dir, &[])?;
+            plan.directories_to_create = Self::missing_directories(&target_dir);
+            writable_dirs.push(target_dir.clone());
+
+            plan.estimated_bytes = plan.files_to_copy.iter().map(|f| f.size_bytes).sum();
+        }
+
+        // The default location is cleaned on the first move and always receives the redirect file
+        let is_redirected = default_child_dir.join(".allowance_redirect").exists();
+        if !is_redirected || matches!(resolution, Some(ConflictResolution::UseTargetData)) {
+            plan.files_to_remove = Self::list_files(&default_child_dir, &[".git", ".allowance_redirect"])?;
+        }
+        plan.estimated_bytes += plan.target_path.len() as u64;
+        writable_dirs.push(default_child_dir);
+
+        for dir in writable_dirs {
+            let Some(existing) = Self::nearest_existing_ancestor(&dir) else {
+                plan.issues.push(format!("No existing parent directory for {}", dir.display()));
+                continue;
+            };
+            let existing_str = existing.to_string_lossy().to_string();
+            if plan.required_permissions.contains(&existing_str) {
+                continue;
+            }
+            match std::fs::metadata(&existing) {
+                Ok(metadata) if metadata.permissions().readonly() => {
+                    plan.issues.push(format!("Directory is read-only: {}", existing.display()));
+                }
+                Ok(_) => {}
+                Err(e) => plan.issues.push(format!("Cannot access {}: {}", existing.display(), e)),
+            }
+            plan.required_permissions.push(existing_str);
+        }
+
+        plan.can_proceed = plan.issues.is_empty();
+        Ok(plan)
+    }
+
+    /// Build a one-line confirmation summary for a relocation plan
+    fn describe_plan(plan: &RelocationPlan) -> String {
+        if !plan.can_proceed {
+            return format!("Relocation cannot proceed: {}", plan.issues.join("; "));
+        }
+        if plan.files_to_copy.is_empty() && plan.files_to_archive.is_empty() && plan.files_to_remove.is_empty() {
+            return "Data directory is already at the specified location".to_string();
+        }
+
+        let mut parts = Vec::new();
+        if !plan.files_to_copy.is_empty() {
+            parts.push(format!("{} files would be copied to {}", plan.files_to_copy.len(), plan.target_path));
+        }
+        if !plan.files_to_overwrite.is_empty() {
+            parts.push(format!("{} existing files would be overwritten", plan.files_to_overwrite.len()));
+        }
+        if !plan.files_to_archive.is_empty() {
+            parts.push(format!("{} files would be archived", plan.files_to_archive.len()));
+        }
+        if !plan.files_to_remove.is_empty() {
+            parts.push(format!("{} files would be removed from the default location", plan.files_to_remove.len()));
+        }
+        format!("{} ({} bytes)", parts.join(", "), plan.estimated_bytes)
+    }
+
+    /// Recursively list files under `root` with their sizes, skipping top-level entries named in `skip`
+    fn list_files(root: &std::path::Path, skip: &[&str]) -> Result<Vec<PlannedFileOperation>> {
+        fn walk(dir: &std::path::Path, prefix: &str, skip: &[&str], files: &mut Vec<PlannedFileOperation>) -> Result<()> {
+            for entry in std::fs::read_dir(dir)? {
+                let entry = entry?;
+                let name = entry.file_name().to_string_lossy().to_string();
+                if prefix.is_empty() && skip.contains(&name.as_str()) {
+                    continue;
+                }
+                let relative_path = if prefix.is_empty() { name } else { format!("{}/{}", prefix, name) };
+                let metadata = entry.metadata()?;
+                if metadata.is_dir() {
+                    walk(&entry.path(), &relative_path, &[], files)?;
+                } else {
+                    files.push(PlannedFileOperation { relative_path, size_bytes: metadata.len() });
+                }
+            }
+            Ok(())
+        }
+
+        let mut files = Vec::new();
+        if root.exists() {
+            walk(root, "", skip, &mut files)?;
+        }
+        files.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+        Ok(files)
+    }
+
+    /// Directories that would have to be created for `path` to exist, outermost first
+    fn missing_directories(path: &std::path::Path) -> Vec<String> {
+        let mut missing: Vec<String> = path
+            .ancestors()
+            .take_while(|p| !p.as_os_str().is_empty() && !p.exists())
+            .map(|p| p.to_string_lossy().to_string())
+            .collect();
+        missing.reverse();
+        missing
+    }
+
+    /// The closest ancestor of `path` (including itself) that already exists
+    fn nearest_existing_ancestor(path: &std::path::Path) -> Option<std::path::PathBuf> {
+        path.ancestors()
+            .find(|p| !p.as_os_str().is_empty() && p.exists())
+            .map(|p| p.to_path_buf())
+    }
+
     /// Check if a directory contains valid child data
     fn directory_contains_child_data(&self, path: &std::path::Path) -> bool {
         let child_file = path.join("child.yaml");
@@ -346,14 +688,14 @@ impl DataDirectoryService {
     fn archive_current_data(&self, child_id: &str) -> Result<String> {
         info!("Archiving current data for child: {}", child_id);
         
-        // Get the child's name (CSV connection expects name, not ID)
+        // Get the child's directory name (CSV connection expects it, not the ID)
         let child = self.child_service.get_child(crate::backend::domain::commands::child::GetChildCommand {
             child_id: child_id.to_string(),
         })?;
         
         let child_name = match child.child {
-            Some(child) => child.name,
-            None => return Err(anyhow::anyhow!("Child not found: {}", child_id)),
+            Some(child) => self.child_directory_name(&child)?,
+            None => return Err(DomainError::child_not_found(&child_id).into()),
         };
         
         let current_data_dir = self.csv_connection.get_child_directory(&child_name);
@@ -473,22 +815,23 @@ impl DataDirectoryService {
         request: ReturnToDefaultLocationRequest,
     ) -> Result<ReturnToDefaultLocationResponse> {
         info!("Returning data to default location for child_id: {:?}", request.child_id);
+        self.csv_connection.ensure_writable()?;
 
         let child_id_to_use = if let Some(id) = request.child_id.as_deref() {
             id.to_string()
         } else {
             let response = self.child_service.get_active_child()?;
-            response.active_child.child.ok_or_else(|| anyhow::anyhow!("No active child found"))?.id
+            response.active_child.child.ok_or(DomainError::NoActiveChild)?.id
         };
 
-        // Get the child's name (CSV connection expects name, not ID)
+        // Get the child's directory name (CSV connection expects it, not the ID)
         let child = self.child_service.get_child(crate::backend::domain::commands::child::GetChildCommand {
             child_id: child_id_to_use.clone(),
         })?;
         
         let child_name = match child.child {
-            Some(child) => child.name,
-            None => return Err(anyhow::anyhow!("Child not found: {}", child_id_to_use)),
+            Some(child) => self.child_directory_name(&child)?,
+            None => return Err(DomainError::child_not_found(&child_id_to_use).into()),
         };
 
         let base_dir = self.csv_connection.base_directory();
@@ -626,4 +969,142 @@ impl DataDirectoryService {
             default_path,
         })
     }
-} 
\ No newline at end of file
+} 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::child::CreateChildCommand;
+    use tempfile::TempDir;
+
+    fn setup_test() -> (DataDirectoryService, String, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let connection = Arc::new(CsvConnection::new(temp_dir.path().join("data")).unwrap());
+        let child_service = ChildService::new(connection.clone());
+        let child = child_service
+            .create_child(CreateChildCommand { name: "alice".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        connection.ensure_transactions_file_exists(&child.name).unwrap();
+        let service = DataDirectoryService::new(connection, Arc::new(child_service));
+        (service, child.id, temp_dir)
+    }
+
+    #[test]
+    fn test_dry_run_relocation_reports_plan_without_touching_disk() {
+        let (service, child_id, temp_dir) = setup_test();
+        let source_dir = temp_dir.path().join("data").join("alice");
+        let target_dir = temp_dir.path().join("cloud").join("alice");
+
+        let response = service
+            .relocate_directory(RelocateDataDirectoryRequest {
+                child_id: Some(child_id),
+                new_path: target_dir.to_string_lossy().to_string(),
+                dry_run: true,
+            })
+            .unwrap();
+
+        assert!(response.success);
+        let plan = response.plan.expect("dry run should return a plan");
+        assert!(plan.can_proceed);
+        let copied: Vec<&str> = plan.files_to_copy.iter().map(|f| f.relative_path.as_str()).collect();
+        assert!(copied.contains(&"child.yaml"));
+        assert!(copied.contains(&"transactions.csv"));
+        assert_eq!(plan.files_to_copy, plan.files_to_remove);
+        assert!(plan.files_to_overwrite.is_empty());
+        assert_eq!(plan.directories_to_create.len(), 2);
+        assert!(plan.estimated_bytes >= plan.files_to_copy.iter().map(|f| f.size_bytes).sum::<u64>());
+
+        // Nothing moved
+        assert!(!temp_dir.path().join("cloud").exists());
+        assert!(source_dir.join("child.yaml").exists());
+        assert!(!source_dir.join(".allowance_redirect").exists());
+    }
+
+    #[test]
+    fn test_dry_run_reports_non_empty_target_as_issue() {
+        let (service, child_id, temp_dir) = setup_test();
+        let target_dir = temp_dir.path().join("occupied");
+        std::fs::create_dir_all(&target_dir).unwrap();
+        std::fs::write(target_dir.join("notes.txt"), "keep me").unwrap();
+
+        let plain = service
+            .relocate_directory(RelocateDataDirectoryRequest {
+                child_id: Some(child_id.clone()),
+                new_path: target_dir.to_string_lossy().to_string(),
+                dry_run: true,
+            })
+            .unwrap();
+        assert!(!plain.success);
+        assert!(plain.plan.unwrap().issues.iter().any(|i| i.contains("not empty")));
+
+        let overwrite = service
+            .relocate_with_conflict_resolution(RelocateWithConflictResolutionRequest {
+                child_id: Some(child_id),
+                new_path: target_dir.to_string_lossy().to_string(),
+                resolution: ConflictResolution::OverwriteTarget,
+                dry_run: true,
+            })
+            .unwrap();
+        assert!(overwrite.success);
+        let plan = overwrite.plan.unwrap();
+        assert_eq!(plan.files_to_overwrite, vec![PlannedFileOperation { relative_path: "notes.txt".to_string(), size_bytes: 7 }]);
+
+        // Target left untouched
+        assert_eq!(std::fs::read_to_string(target_dir.join("notes.txt")).unwrap(), "keep me");
+    }
+
+    #[test]
+    fn test_dry_run_use_target_data_lists_archive() {
+        let (service, child_id, temp_dir) = setup_test();
+        let target_dir = temp_dir.path().join("existing");
+        std::fs::create_dir_all(&target_dir).unwrap();
+        std::fs::write(target_dir.join("child.yaml"), "name: alice\n").unwrap();
+
+        let response = service
+            .relocate_with_conflict_resolution(RelocateWithConflictResolutionRequest {
+                child_id: Some(child_id),
+                new_path: target_dir.to_string_lossy().to_string(),
+                resolution: ConflictResolution::UseTargetData,
+                dry_run: true,
+            })
+            .unwrap();
+
+        assert!(response.success);
+        assert!(response.archived_to.is_none());
+        let plan = response.plan.unwrap();
+        assert!(plan.files_to_copy.is_empty());
+        assert!(plan.files_to_archive.iter().any(|f| f.relative_path == "child.yaml"));
+        assert!(plan.archive_path.is_some());
+        assert!(!temp_dir.path().join("data").join("archive").exists());
+    }
+
+    #[test]
+    fn test_missing_relocated_folder_keeps_data_read_only_until_located() {
+        let (service, _child_id, temp_dir) = setup_test();
+        let default_dir = temp_dir.path().join("data").join("alice");
+        let unplugged = temp_dir.path().join("usb").join("alice");
+        std::fs::write(default_dir.join(".allowance_redirect"), unplugged.to_string_lossy().as_bytes()).unwrap();
+        service.csv_connection.set_read_only(Some(ReadOnlySource::DataDirectoryMissing));
+
+        let missing = service.retry_missing_data_folders().unwrap();
+        assert_eq!(missing, vec![MissingDataFolder {
+            child_directory: "alice".to_string(),
+            expected_path: unplugged.to_string_lossy().to_string(),
+        }]);
+        assert_eq!(service.read_only_source(), Some(ReadOnlySource::DataDirectoryMissing));
+
+        // The drive came back somewhere else
+        let remounted = temp_dir.path().join("usb2").join("alice");
+        std::fs::create_dir_all(&remounted).unwrap();
+        assert!(service
+            .locate_data_folder(LocateDataFolderCommand { child_directory: "alice".to_string(), path: remounted.to_string_lossy().to_string() })
+            .is_err(), "a folder without the child's data is refused");
+        std::fs::copy(default_dir.join("child.yaml"), remounted.join("child.yaml")).unwrap();
+
+        let missing = service
+            .locate_data_folder(LocateDataFolderCommand { child_directory: "alice".to_string(), path: remounted.to_string_lossy().to_string() })
+            .unwrap();
+        assert!(missing.is_empty());
+        assert_eq!(service.read_only_source(), None);
+    }
+}
@@ -0,0 +1,73 @@
+//! Typed descriptions, tidied before they are stored.
+//!
+//! A description ends up in a CSV row, a table cell and a calendar chip, so a
+//! pasted line break, tab or stray control character would split the row or
+//! push the layout around. [`sanitize_description`] trims it, turns every run
+//! of whitespace into a single space and drops control characters.
+//!
+//! Lengths are counted in graphemes, what a person sees as one character, so
+//! "👨‍👩‍👧" or "é" written with a combining accent count once, however many
+//! bytes or code points they take.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Most graphemes a stored description may have
+pub const MAX_DESCRIPTION_LENGTH: usize = 256;
+
+/// Trim a description, collapse its whitespace (line breaks and tabs
+/// included) to single spaces and remove control characters
+pub fn sanitize_description(input: &str) -> String {
+    let cleaned: String = input
+        .chars()
+        .filter_map(|c| {
+            if c.is_whitespace() {
+                Some(' ')
+            } else if c.is_control() || is_bidi_control(c) {
+                None
+            } else {
+                Some(c)
+            }
+        })
+        .collect();
+    cleaned.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Length of a description in graphemes
+pub fn description_length(description: &str) -> usize {
+    description.graphemes(true).count()
+}
+
+/// Cut a description down to [`MAX_DESCRIPTION_LENGTH`] graphemes
+pub fn truncate_description(description: &str) -> String {
+    description.graphemes(true).take(MAX_DESCRIPTION_LENGTH).collect()
+}
+
+/// Bidirectional embedding, override and isolate marks, which make text
+/// display in a different order than it was typed
+fn is_bidi_control(c: char) -> bool {
+    matches!(c, '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_collapses_whitespace_and_drops_controls() {
+        assert_eq!(sanitize_description("  Lemonade   stand  "), "Lemonade stand");
+        assert_eq!(sanitize_description("Birthday\r\nmoney\tfrom\u{2028}Grandma"), "Birthday money from Grandma");
+        assert_eq!(sanitize_description("Ca\u{7}ndy\u{0}"), "Candy");
+        assert_eq!(sanitize_description("\u{202e}kooB"), "kooB");
+        assert_eq!(sanitize_description("\n\t\u{1b}"), "");
+        // Emoji sequences keep their joiners and variation selectors
+        assert_eq!(sanitize_description("Gift 👨‍👩‍👧 ❤️"), "Gift 👨‍👩‍👧 ❤️");
+    }
+
+    #[test]
+    fn test_length_counts_graphemes() {
+        assert_eq!(description_length("Toy"), 3);
+        assert_eq!(description_length("👨‍👩‍👧"), 1);
+        assert_eq!(description_length("e\u{301}clair"), 6);
+        assert_eq!(description_length(&truncate_description(&"🍦".repeat(300))), MAX_DESCRIPTION_LENGTH);
+    }
+}
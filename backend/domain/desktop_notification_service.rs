@@ -0,0 +1,198 @@
+//! Desktop notification service domain logic for the allowance tracker.
+//!
+//! Allowances and completed goals already record a `Celebration` for the
+//! child. When a parent opts in, the app's periodic refresh also turns new
+//! ones into system notifications, so "Allowance of $10.00 was added for
+//! Maya" shows up even with the window in the background.
+//!
+//! Only celebrations recorded while the app is open are announced; anything
+//! recorded before it started was either announced last time or is old news.
+//! Notifications don't acknowledge celebrations, so the child still gets
+//! their confetti.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use std::sync::{Arc, Mutex};
+
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::commands::desktop_notifications::UpdateDesktopNotificationsCommand;
+use crate::backend::domain::models::celebration::{Celebration, CelebrationKind};
+use crate::backend::domain::models::child::Child;
+use crate::backend::domain::models::desktop_notifications::DesktopNotificationSettings;
+use crate::backend::storage::csv::{CelebrationRepository, CsvConnection, GlobalConfig, GlobalConfigRepository};
+use crate::backend::storage::{CelebrationStorage, GlobalConfigStorage};
+
+/// Something that can show a notification, so tests don't pop up real ones
+pub trait DesktopNotifier: Send + Sync {
+    fn show(&self, title: &str, body: &str) -> Result<()>;
+}
+
+/// Notifications through the operating system's notification center
+pub struct SystemNotifier;
+
+impl DesktopNotifier for SystemNotifier {
+    fn show(&self, title: &str, body: &str) -> Result<()> {
+        notify_rust::Notification::new()
+            .appname("Allowance Tracker")
+            .summary(title)
+            .body(body)
+            .show()?;
+        Ok(())
+    }
+}
+
+/// Service for announcing allowances and completed goals as desktop notifications
+#[derive(Clone)]
+pub struct DesktopNotificationService {
+    global_config_repository: GlobalConfigRepository,
+    celebration_repository: CelebrationRepository,
+    child_service: ChildService,
+    notifier: Arc<dyn DesktopNotifier>,
+    /// Celebrations recorded after this haven't been announced yet
+    announced_through: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl DesktopNotificationService {
+    /// Create a new DesktopNotificationService
+    pub fn new(csv_conn: Arc<CsvConnection>, child_service: ChildService) -> Self {
+        Self {
+            global_config_repository: GlobalConfigRepository::new((*csv_conn).clone()),
+            celebration_repository: CelebrationRepository::new((*csv_conn).clone()),
+            child_service,
+            notifier: Arc::new(SystemNotifier),
+            announced_through: Arc::new(Mutex::new(Utc::now())),
+        }
+    }
+
+    /// Show notifications somewhere other than the system notification center
+    pub fn with_notifier(mut self, notifier: Arc<dyn DesktopNotifier>) -> Self {
+        self.notifier = notifier;
+        self
+    }
+
+    /// Which events show a desktop notification
+    pub fn get_settings(&self) -> Result<DesktopNotificationSettings> {
+        Ok(self.global_config_repository.get_global_config()?.desktop_notifications)
+    }
+
+    /// Turn notifications for each kind of event on or off
+    pub fn update_settings(&self, command: UpdateDesktopNotificationsCommand) -> Result<DesktopNotificationSettings> {
+        let mut config = self.global_config_repository.get_global_config()?;
+        config.desktop_notifications = DesktopNotificationSettings {
+            allowance_posted: command.allowance_posted,
+            goal_completed: command.goal_completed,
+        };
+        config.updated_at = Utc::now().to_rfc3339();
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!("🔔 Desktop notifications set to {:?}", config.desktop_notifications);
+        Ok(config.desktop_notifications)
+    }
+
+    /// Show a notification for each opted-in celebration recorded since the last call
+    ///
+    /// Called from the app's periodic refresh, after allowances are issued.
+    /// Returns how many notifications were shown.
+    pub fn notify_new_celebrations(&self) -> Result<usize> {
+        let now = Utc::now();
+        let since = {
+            let mut announced_through = self.announced_through.lock().unwrap();
+            std::mem::replace(&mut *announced_through, now)
+        };
+
+        let config = self.global_config_repository.get_global_config()?;
+        if config.desktop_notifications.is_empty() {
+            return Ok(0);
+        }
+
+        let mut shown = 0;
+        for child in self.child_service.list_children()?.children {
+            let celebrations = match self.celebration_repository.list_pending_celebrations(&child.id) {
+                Ok(celebrations) => celebrations,
+                Err(e) => {
+                    warn!("🔔 Skipping {}: {}", child.name, e);
+                    continue;
+                }
+            };
+            for celebration in celebrations {
+                if celebration.created_at <= since
+                    || celebration.created_at > now
+                    || !config.desktop_notifications.wants(celebration.kind)
+                {
+                    continue;
+                }
+                let (title, body) = self.describe(&config, &child, &celebration)?;
+                match self.notifier.show(&title, &body) {
+                    Ok(()) => shown += 1,
+                    Err(e) => warn!("🔔 Could not show notification \"{}\": {}", body, e),
+                }
+            }
+        }
+        Ok(shown)
+    }
+
+    fn describe(&self, config: &GlobalConfig, child: &Child, celebration: &Celebration) -> Result<(String, String)> {
+        Ok(match celebration.kind {
+            CelebrationKind::AllowancePosted => {
+                let settings = self.child_service.get_child_settings(&child.id)?.settings;
+                let currency = settings.currency.unwrap_or(config.currency);
+                let locale = settings.locale.unwrap_or(config.locale);
+                (
+                    "Allowance added".to_string(),
+                    format!("Allowance of {} was added for {}", currency.format(celebration.amount, locale), child.name),
+                )
+            }
+            CelebrationKind::GoalCompleted => ("Goal completed!".to_string(), format!("{}: {}", child.name, celebration.message)),
+            CelebrationKind::BalanceMilestone => ("Milestone reached!".to_string(), format!("{}: {}", child.name, celebration.message)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::child::CreateChildCommand;
+    use tempfile::TempDir;
+
+    #[derive(Default)]
+    struct RecordingNotifier {
+        shown: Mutex<Vec<(String, String)>>,
+    }
+
+    impl DesktopNotifier for RecordingNotifier {
+        fn show(&self, title: &str, body: &str) -> Result<()> {
+            self.shown.lock().unwrap().push((title.to_string(), body.to_string()));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_only_opted_in_events_are_announced_once() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let child_service = ChildService::new(connection.clone());
+        let notifier = Arc::new(RecordingNotifier::default());
+        let service = DesktopNotificationService::new(connection.clone(), child_service.clone()).with_notifier(notifier.clone());
+        let celebrations = CelebrationRepository::new((*connection).clone());
+
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Maya".to_string(), birthdate: "2015-01-01".to_string() })?
+            .child;
+        celebrations.record_celebration(&Celebration::allowance_posted(&child.id, 10.0))?;
+        assert_eq!(service.notify_new_celebrations()?, 0, "notifications are off by default");
+
+        service.update_settings(UpdateDesktopNotificationsCommand { allowance_posted: true, goal_completed: false })?;
+        celebrations.record_celebration(&Celebration::allowance_posted(&child.id, 10.0))?;
+        celebrations.record_celebration(&Celebration::goal_completed(&child.id, "Lego set", 40.0))?;
+        assert_eq!(service.notify_new_celebrations()?, 1);
+        assert_eq!(
+            notifier.shown.lock().unwrap().as_slice(),
+            &[("Allowance added".to_string(), "Allowance of $10.00 was added for Maya".to_string())]
+        );
+
+        assert_eq!(service.notify_new_celebrations()?, 0, "each celebration is announced once");
+        assert_eq!(service.get_settings()?, DesktopNotificationSettings { allowance_posted: true, goal_completed: false });
+        Ok(())
+    }
+}
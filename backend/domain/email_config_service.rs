@@ -1,38 +1,55 @@
 use anyhow::{Context, Result};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
 
 use super::email_service::EmailConfig;
+use super::errors::DomainError;
+use super::secrets_service::{SecretsService, SMTP_PASSWORD_SECRET};
 
 pub struct EmailConfigService;
 
 impl EmailConfigService {
-    pub fn load_config(config_path: &Path) -> Result<EmailConfig> {
+    /// Load the email config, taking the SMTP password from the secrets store.
+    /// A password still written in the TOML is moved into the secrets store
+    /// and removed from the file.
+    pub fn load_config(config_path: &Path, secrets: &SecretsService) -> Result<EmailConfig> {
         let config_content = fs::read_to_string(config_path)
             .with_context(|| format!("Failed to read email config file: {:?}", config_path))?;
 
-        let config: EmailConfig = toml::from_str(&config_content)
+        let mut config: EmailConfig = toml::from_str(&config_content)
             .with_context(|| "Failed to parse email config TOML")?;
 
+        if config.password.is_empty() {
+            config.password = secrets.get_secret(SMTP_PASSWORD_SECRET)?.unwrap_or_default();
+        } else {
+            secrets.set_secret(SMTP_PASSWORD_SECRET, &config.password)?;
+            match Self::remove_password_from_file(config_path, &config_content) {
+                Ok(()) => log::info!("🔑 Moved SMTP password from {:?} to the {}", config_path, secrets.backend_name()),
+                Err(e) => log::warn!("🔑 SMTP password stored securely, but it could not be removed from {:?}: {}", config_path, e),
+            }
+        }
+
         // Validate required fields
         if config.username.is_empty() {
-            return Err(anyhow::anyhow!("Email username is required"));
+            return Err(DomainError::invalid("Email username is required").into());
         }
         if config.password.is_empty() {
-            return Err(anyhow::anyhow!("Email password is required"));
+            return Err(DomainError::invalid("Email password is required").into());
         }
         if config.from_email.is_empty() {
-            return Err(anyhow::anyhow!("From email is required"));
+            return Err(DomainError::invalid("From email is required").into());
         }
         if config.to_emails.is_empty() {
-            return Err(anyhow::anyhow!("At least one recipient email is required"));
+            return Err(DomainError::invalid("At least one recipient email is required").into());
         }
 
         Ok(config)
     }
 
-    pub fn load_config_or_default(config_path: &Path) -> EmailConfig {
-        match Self::load_config(config_path) {
+    pub fn load_config_or_default(config_path: &Path, secrets: &SecretsService) -> EmailConfig {
+        match Self::load_config(config_path, secrets) {
             Ok(config) => config,
             Err(e) => {
                 log::warn!("Failed to load email config from {:?}: {}", config_path, e);
@@ -41,4 +58,113 @@ impl EmailConfigService {
             }
         }
     }
-} 
\ No newline at end of file
+
+    /// Rewrite the TOML without its `password` key
+    fn remove_password_from_file(config_path: &Path, config_content: &str) -> Result<()> {
+        let mut table: toml::Table = toml::from_str(config_content)?;
+        table.remove("password");
+
+        // Use atomic write pattern: write to temp file, then rename
+        let temp_path = config_path.with_extension("tmp");
+        fs::write(&temp_path, toml::to_string(&table)?)?;
+        fs::rename(&temp_path, config_path)?;
+        Ok(())
+    }
+}
+
+/// Reloads the email config when its file changes, so new SMTP settings take
+/// effect without restarting the app
+pub struct EmailConfigWatcher {
+    config_path: PathBuf,
+    secrets: SecretsService,
+    /// Modification time of the file when it was last loaded; `None` if it didn't exist
+    loaded_modified: Mutex<Option<SystemTime>>,
+}
+
+impl EmailConfigWatcher {
+    pub fn new(config_path: impl Into<PathBuf>, secrets: SecretsService) -> Self {
+        Self {
+            config_path: config_path.into(),
+            secrets,
+            loaded_modified: Mutex::new(None),
+        }
+    }
+
+    /// Load the config now, falling back to the default when it can't be read
+    pub fn load(&self) -> EmailConfig {
+        // Note the time after loading: loading may rewrite the file to move a
+        // password out of it, and that rewrite shouldn't count as a change
+        let config = EmailConfigService::load_config_or_default(&self.config_path, &self.secrets);
+        *self.loaded_modified.lock().unwrap() = self.modified();
+        config
+    }
+
+    /// The reloaded config if the file was created, edited or removed since it
+    /// was last loaded
+    pub fn reload_if_changed(&self) -> Option<EmailConfig> {
+        if self.modified() == *self.loaded_modified.lock().unwrap() {
+            return None;
+        }
+        log::info!("📧 {:?} changed; reloading email config", self.config_path);
+        Some(self.load())
+    }
+
+    fn modified(&self) -> Option<SystemTime> {
+        fs::metadata(&self.config_path).and_then(|m| m.modified()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::secrets::EncryptedFileSecretStore;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_plaintext_password_is_moved_to_secrets_store() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let secrets = SecretsService::with_store(Arc::new(EncryptedFileSecretStore::new(temp_dir.path().join("secrets"))?));
+        let config_path = temp_dir.path().join("email_config.toml");
+        fs::write(
+            &config_path,
+            "smtp_server = \"smtp.example.com\"\nsmtp_port = 587\nusername = \"parent\"\npassword = \"hunter2\"\nfrom_email = \"parent@example.com\"\nto_emails = [\"kid@example.com\"]\n",
+        )?;
+
+        let config = EmailConfigService::load_config(&config_path, &secrets)?;
+        assert_eq!(config.password, "hunter2");
+        assert_eq!(secrets.get_secret(SMTP_PASSWORD_SECRET)?.as_deref(), Some("hunter2"));
+
+        let rewritten = fs::read_to_string(&config_path)?;
+        assert!(!rewritten.contains("hunter2"));
+        assert!(rewritten.contains("smtp.example.com"));
+
+        // Later loads read the password back from the secrets store
+        let config = EmailConfigService::load_config(&config_path, &secrets)?;
+        assert_eq!(config.password, "hunter2");
+        Ok(())
+    }
+
+    #[test]
+    fn test_watcher_reloads_only_after_the_file_changes() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let secrets = SecretsService::with_store(Arc::new(EncryptedFileSecretStore::new(temp_dir.path().join("secrets"))?));
+        secrets.set_secret(SMTP_PASSWORD_SECRET, "hunter2")?;
+        let config_path = temp_dir.path().join("email_config.toml");
+        let watcher = EmailConfigWatcher::new(&config_path, secrets);
+
+        // No file yet: the default config, and nothing to reload until one appears
+        assert_eq!(watcher.load().smtp_server, EmailConfig::default().smtp_server);
+        assert!(watcher.reload_if_changed().is_none());
+
+        fs::write(
+            &config_path,
+            "smtp_server = \"smtp.example.com\"\nsmtp_port = 587\nusername = \"parent\"\nfrom_email = \"parent@example.com\"\nto_emails = [\"kid@example.com\"]\n",
+        )?;
+        let config = watcher.reload_if_changed().expect("new file should be loaded");
+        assert_eq!(config.smtp_server, "smtp.example.com");
+        assert_eq!(config.password, "hunter2");
+        assert!(watcher.reload_if_changed().is_none());
+        Ok(())
+    }
+}
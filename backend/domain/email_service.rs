@@ -16,7 +16,8 @@ pub struct EmailConfig {
     pub smtp_server: String,
     pub smtp_port: u16,
     pub username: String,
-    pub password: String,
+    #[serde(default)]
+    pub password: String, // Loaded from the secrets store; left blank in the TOML
     pub from_email: String,
     pub to_emails: Vec<String>, // Changed from single to_email to list of emails
 }
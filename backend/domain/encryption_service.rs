@@ -0,0 +1,195 @@
+//! Encryption service domain logic for the allowance tracker.
+//!
+//! Children's data files can optionally be encrypted with a key derived from
+//! the parental passphrase. This service turns encryption on and off for a
+//! child (rewriting the existing files) and drives the unlock-at-startup flow:
+//! the frontend asks [`EncryptionService::children_needing_unlock`] after
+//! launch and, if any are listed, prompts for the passphrase and calls
+//! [`EncryptionService::unlock`]. The actual file handling lives in the CSV
+//! storage layer, so every repository works unchanged once data is unlocked.
+
+use anyhow::Result;
+use log::{info, warn};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::commands::encryption::{
+    ChangeChildEncryptionCommand, ChangeChildEncryptionResult, ChildEncryptionStatus,
+    UnlockEncryptedDataCommand, UnlockEncryptedDataResult,
+};
+use crate::backend::storage::csv::{CsvConnection, EncryptionError};
+
+/// Service for encrypting child data and unlocking it at startup
+#[derive(Clone)]
+pub struct EncryptionService {
+    csv_connection: Arc<CsvConnection>,
+    child_service: ChildService,
+}
+
+impl EncryptionService {
+    /// Create a new EncryptionService
+    pub fn new(csv_conn: Arc<CsvConnection>, child_service: ChildService) -> Self {
+        Self {
+            csv_connection: csv_conn,
+            child_service,
+        }
+    }
+
+    /// Encryption state of every child
+    pub fn get_encryption_status(&self) -> Result<Vec<ChildEncryptionStatus>> {
+        let mut statuses = Vec::new();
+        for child in self.child_service.list_children()?.children {
+            let child_dir = self.child_directory(&child.id)?;
+            statuses.push(ChildEncryptionStatus {
+                is_encrypted: self.csv_connection.is_child_encrypted(&child_dir)?,
+                is_unlocked: self.csv_connection.is_child_unlocked(&child_dir)?,
+                child_id: child.id,
+                child_name: child.name,
+            });
+        }
+        Ok(statuses)
+    }
+
+    /// Encrypted children whose data can't be read until the passphrase is entered
+    pub fn children_needing_unlock(&self) -> Result<Vec<ChildEncryptionStatus>> {
+        Ok(self
+            .get_encryption_status()?
+            .into_iter()
+            .filter(|status| status.is_encrypted && !status.is_unlocked)
+            .collect())
+    }
+
+    /// Try the passphrase on every locked child
+    pub fn unlock(&self, command: UnlockEncryptedDataCommand) -> Result<UnlockEncryptedDataResult> {
+        let mut unlocked_child_ids = Vec::new();
+        let mut failed_child_ids = Vec::new();
+
+        for status in self.children_needing_unlock()? {
+            let child_dir = self.child_directory(&status.child_id)?;
+            match self.csv_connection.unlock_child_directory(&child_dir, &command.passphrase) {
+                Ok(()) => unlocked_child_ids.push(status.child_id),
+                Err(e) if e.downcast_ref::<EncryptionError>().is_some() => {
+                    warn!("🔐 ENCRYPTION: Could not unlock data for '{}': {}", status.child_name, e);
+                    failed_child_ids.push(status.child_id);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        info!(
+            "🔐 ENCRYPTION: Unlocked {} child(ren), {} failed",
+            unlocked_child_ids.len(),
+            failed_child_ids.len()
+        );
+        Ok(UnlockEncryptedDataResult {
+            unlocked_child_ids,
+            failed_child_ids,
+        })
+    }
+
+    /// Encrypt a child's existing data files, startup snapshot included, with the passphrase
+    pub fn enable_encryption(&self, command: ChangeChildEncryptionCommand) -> Result<ChangeChildEncryptionResult> {
+        Self::validate_passphrase(&command.passphrase)?;
+        let child_dir = self.child_directory(&command.child_id)?;
+        let files_changed = self
+            .csv_connection
+            .encrypt_child_directory(&child_dir, &command.passphrase)?;
+        Ok(ChangeChildEncryptionResult {
+            child_id: command.child_id,
+            is_encrypted: true,
+            files_changed,
+        })
+    }
+
+    /// Decrypt a child's data files back to plain text
+    pub fn disable_encryption(&self, command: ChangeChildEncryptionCommand) -> Result<ChangeChildEncryptionResult> {
+        let child_dir = self.child_directory(&command.child_id)?;
+        let files_changed = self
+            .csv_connection
+            .decrypt_child_directory(&child_dir, &command.passphrase)?;
+        Ok(ChangeChildEncryptionResult {
+            child_id: command.child_id,
+            is_encrypted: false,
+            files_changed,
+        })
+    }
+
+    fn child_directory(&self, child_id: &str) -> Result<PathBuf> {
+        self.csv_connection
+            .get_child_directory_by_id(child_id)?
+            .ok_or_else(|| DomainError::child_not_found(child_id))
+            .map_err(Into::into)
+    }
+
+    fn validate_passphrase(passphrase: &str) -> Result<()> {
+        if passphrase.trim().len() < 4 {
+            return Err(DomainError::invalid("Passphrase must be at least 4 characters").into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::models::transaction::{Transaction, TransactionType};
+    use crate::backend::storage::csv::test_utils::TestHelper;
+    use crate::backend::storage::csv::TransactionRepository;
+    use crate::backend::storage::traits::TransactionStorage;
+
+    fn service_for(connection: &CsvConnection) -> EncryptionService {
+        let connection = Arc::new(connection.clone());
+        EncryptionService::new(connection.clone(), ChildService::new(connection))
+    }
+
+    #[test]
+    fn test_encrypt_unlock_and_decrypt_child_data() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        helper.transaction_repo.store_transaction(&Transaction {
+            id: "tx-1".to_string(),
+            child_id: child.id.clone(),
+            date: chrono::DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")?,
+            description: "Allowance".to_string(),
+            amount: 10.0,
+            balance: 10.0,
+            transaction_type: TransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
+        })?;
+
+        let service = service_for(&helper.env.connection);
+        let result = service.enable_encryption(ChangeChildEncryptionCommand {
+            child_id: child.id.clone(),
+            passphrase: "ice cold".to_string(),
+        })?;
+        assert!(result.files_changed >= 1);
+        assert!(service.children_needing_unlock()?.is_empty());
+
+        // A restarted app can't read the transactions until it is unlocked
+        let restarted = CsvConnection::new(&helper.env.base_path)?;
+        let repo = TransactionRepository::new(restarted.clone());
+        let err = repo.list_transactions(&child.id, None, None).unwrap_err();
+        assert!(err.downcast_ref::<EncryptionError>().is_some());
+
+        let restarted_service = service_for(&restarted);
+        assert_eq!(restarted_service.children_needing_unlock()?.len(), 1);
+        let result = restarted_service.unlock(UnlockEncryptedDataCommand { passphrase: "warm".to_string() })?;
+        assert_eq!(result.failed_child_ids, vec![child.id.clone()]);
+
+        let result = restarted_service.unlock(UnlockEncryptedDataCommand { passphrase: "ice cold".to_string() })?;
+        assert_eq!(result.unlocked_child_ids, vec![child.id.clone()]);
+        assert_eq!(repo.list_transactions(&child.id, None, None)?.len(), 1);
+
+        restarted_service.disable_encryption(ChangeChildEncryptionCommand {
+            child_id: child.id.clone(),
+            passphrase: "ice cold".to_string(),
+        })?;
+        let fresh = TransactionRepository::new(CsvConnection::new(&helper.env.base_path)?);
+        assert_eq!(fresh.list_transactions(&child.id, None, None)?.len(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+//! Typed errors for the domain layer.
+//!
+//! Services keep returning `anyhow::Result`, but the errors they raise
+//! themselves are `DomainError`s, and storage raises its own typed errors
+//! (`ReadOnlyError`, `EncryptionError`, ...). `classify` looks through an
+//! error's chain for any of these and turns it into a `shared::ErrorResponse`,
+//! so a frontend can tell "child not found" from "disk full" without
+//! matching on message text.
+
+use shared::{ErrorCode, ErrorResponse};
+use thiserror::Error;
+
+use crate::backend::domain::models::child_settings::InsufficientFundsError;
+use crate::backend::domain::models::goal::GoalValidationError;
+use crate::backend::domain::quick_entry::QuickEntryError;
+use crate::backend::storage::csv::{DirectoryLockError, EncryptionError, ReadOnlyError};
+
+/// Errors raised by the domain services
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum DomainError {
+    /// A child, goal or other record doesn't exist
+    #[error("{0}")]
+    NotFound(String),
+    /// The operation works on the active child and none is selected
+    #[error("No active child found. Please select a child first.")]
+    NoActiveChild,
+    /// Input that breaks a business rule
+    #[error("{0}")]
+    Invalid(String),
+    /// The request clashes with the current state
+    #[error("{0}")]
+    Conflict(String),
+    /// A parental check was required and not passed
+    #[error("{0}")]
+    Unauthorized(String),
+}
+
+impl DomainError {
+    /// The child with this id doesn't exist
+    pub fn child_not_found(child_id: &str) -> Self {
+        DomainError::NotFound(format!("Child not found: {}", child_id))
+    }
+
+    /// Input that breaks a business rule
+    pub fn invalid(message: impl Into<String>) -> Self {
+        DomainError::Invalid(message.into())
+    }
+
+    /// Refuse an update made from a stale copy of a record.
+    ///
+    /// `expected` is the `updated_at` the caller loaded, `current` the one in
+    /// storage. They are compared as instants, so "Z" and "+00:00" match.
+    /// Without an `expected` value the update goes ahead as before.
+    pub fn check_not_stale(what: &str, expected: Option<&str>, current: &str) -> Result<(), DomainError> {
+        let Some(expected) = expected else {
+            return Ok(());
+        };
+        let same = match (
+            chrono::DateTime::parse_from_rfc3339(expected),
+            chrono::DateTime::parse_from_rfc3339(current),
+        ) {
+            (Ok(expected), Ok(current)) => expected == current,
+            _ => expected == current,
+        };
+        if same {
+            Ok(())
+        } else {
+            Err(DomainError::Conflict(format!(
+                "The {} was changed somewhere else since you opened it. Reload it and try again.",
+                what
+            )))
+        }
+    }
+
+    /// Code reported to frontends for this error
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            DomainError::NotFound(_) => ErrorCode::NotFound,
+            DomainError::NoActiveChild => ErrorCode::NoActiveChild,
+            DomainError::Invalid(_) => ErrorCode::InvalidInput,
+            DomainError::Conflict(_) => ErrorCode::Conflict,
+            DomainError::Unauthorized(_) => ErrorCode::Unauthorized,
+        }
+    }
+}
+
+/// Turn any error from a service into a code and the message to show.
+///
+/// The first typed error found in the chain decides the code; the message is
+/// the outermost one, as it is today.
+pub fn classify(error: &anyhow::Error) -> ErrorResponse {
+    let code = error.chain().find_map(code_for).unwrap_or(ErrorCode::Internal);
+    ErrorResponse {
+        code,
+        message: error.to_string(),
+    }
+}
+
+fn code_for(cause: &(dyn std::error::Error + 'static)) -> Option<ErrorCode> {
+    if let Some(error) = cause.downcast_ref::<DomainError>() {
+        return Some(error.code());
+    }
+    if cause.is::<ReadOnlyError>() {
+        return Some(ErrorCode::ReadOnly);
+    }
+    if cause.is::<DirectoryLockError>() {
+        return Some(ErrorCode::Locked);
+    }
+    if let Some(error) = cause.downcast_ref::<EncryptionError>() {
+        return Some(match error {
+            EncryptionError::Locked { .. } => ErrorCode::Locked,
+            EncryptionError::WrongPassphrase { .. } => ErrorCode::Unauthorized,
+            EncryptionError::Corrupt { .. } => ErrorCode::Storage,
+            EncryptionError::AlreadyEncrypted { .. } | EncryptionError::NotEncrypted { .. } => ErrorCode::Conflict,
+        });
+    }
+    if cause.is::<InsufficientFundsError>() {
+        return Some(ErrorCode::InsufficientFunds);
+    }
+    if let Some(error) = cause.downcast_ref::<GoalValidationError>() {
+        return Some(match error {
+            GoalValidationError::ActiveGoalAlreadyExists => ErrorCode::Conflict,
+            _ => ErrorCode::InvalidInput,
+        });
+    }
+    if cause.is::<QuickEntryError>() {
+        return Some(ErrorCode::InvalidInput);
+    }
+    if let Some(error) = cause.downcast_ref::<std::io::Error>() {
+        return Some(match error.kind() {
+            std::io::ErrorKind::StorageFull => ErrorCode::StorageFull,
+            std::io::ErrorKind::NotFound => ErrorCode::NotFound,
+            _ => ErrorCode::Storage,
+        });
+    }
+    if cause.is::<csv::Error>() || cause.is::<serde_yaml::Error>() {
+        return Some(ErrorCode::Storage);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::csv::ReadOnlySource;
+    use anyhow::Context;
+
+    #[test]
+    fn test_classify_finds_typed_errors_in_the_chain() {
+        let error = anyhow::Error::from(DomainError::child_not_found("child::1"));
+        assert_eq!(
+            classify(&error),
+            ErrorResponse { code: ErrorCode::NotFound, message: "Child not found: child::1".to_string() }
+        );
+
+        // Context added on the way up keeps the code but shows the outer message
+        let error = Err::<(), _>(ReadOnlyError { enabled_by: ReadOnlySource::Settings })
+            .context("Failed to save transaction")
+            .unwrap_err();
+        let response = classify(&error);
+        assert_eq!(response.code, ErrorCode::ReadOnly);
+        assert_eq!(response.message, "Failed to save transaction");
+
+        let disk_full = anyhow::Error::from(std::io::Error::from(std::io::ErrorKind::StorageFull));
+        assert_eq!(classify(&disk_full).code, ErrorCode::StorageFull);
+        assert_eq!(classify(&disk_full).code.http_status(), 507);
+
+        assert_eq!(classify(&anyhow::anyhow!("Something odd")).code, ErrorCode::Internal);
+        assert_eq!(serde_yaml::to_string(&ErrorCode::NoActiveChild).unwrap().trim(), "no_active_child");
+    }
+
+    #[test]
+    fn test_stale_updates_are_conflicts() {
+        let saved = "2025-03-01T10:00:00.123456Z";
+        assert!(DomainError::check_not_stale("goal", None, saved).is_ok());
+        assert!(DomainError::check_not_stale("goal", Some("2025-03-01T10:00:00.123456+00:00"), saved).is_ok());
+
+        let error = DomainError::check_not_stale("goal", Some("2025-02-28T09:00:00Z"), saved).unwrap_err();
+        assert_eq!(error.code(), ErrorCode::Conflict);
+        assert!(error.to_string().contains("goal was changed somewhere else"));
+    }
+}
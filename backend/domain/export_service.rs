@@ -1,41 +1,36 @@
 //! Export service domain logic for the allowance tracker.
 //!
 //! This module contains all business logic related to exporting transaction data
-//! as CSV files, including orchestration of child lookup, transaction retrieval,
-//! and file operations. The UI should only handle presentation concerns.
+//! as CSV files, zip archives and Excel workbooks, including orchestration of
+//! child lookup, transaction retrieval, and file operations. The UI should only
+//! handle presentation concerns.
 
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{DateTime, SecondsFormat, Utc};
 use log::{info, error};
 use std::fs;
 
 
-use shared::{ExportDataRequest, ExportDataResponse, ExportToPathRequest, ExportToPathResponse, Transaction, TransactionType};
+use shared::{ExportAllDataRequest, ExportAllDataResponse, ExportDataRequest, ExportDataResponse, ExportToPathRequest, ExportToPathResponse, ExportWorkbookRequest, Transaction};
 use crate::backend::domain::child_service::ChildService;
 use crate::backend::domain::transaction_service::TransactionService;
+use crate::backend::domain::allowance_service::AllowanceService;
+use crate::backend::domain::goal_service::GoalService;
+use crate::backend::domain::family_service::FamilyService;
+use crate::backend::domain::parental_control_service::ParentalControlService;
 use crate::backend::domain::commands::transactions::TransactionListQuery;
 use crate::backend::domain::commands::child::GetChildCommand;
-
-// Create TransactionMapper placeholder
-struct TransactionMapper;
-
-impl TransactionMapper {
-    pub fn to_dto(transaction: crate::backend::domain::models::transaction::Transaction) -> Transaction {
-        Transaction {
-            id: transaction.id,
-            date: transaction.date,
-            amount: transaction.amount,
-            description: transaction.description,
-            transaction_type: match transaction.transaction_type {
-                crate::backend::domain::models::transaction::TransactionType::Income => TransactionType::Income,
-                crate::backend::domain::models::transaction::TransactionType::Expense => TransactionType::Expense,
-                crate::backend::domain::models::transaction::TransactionType::FutureAllowance => TransactionType::FutureAllowance,
-            },
-            balance: transaction.balance,
-            child_id: transaction.child_id,
-        }
-    }
-}
+use crate::backend::domain::commands::allowance::GetAllowanceConfigCommand;
+use crate::backend::domain::commands::goal::GetGoalHistoryCommand;
+use crate::backend::domain::models::export_manifest::{ExportManifest, ExportManifestChild, EXPORT_FORMAT_VERSION, EXPORT_MANIFEST_FILE};
+use crate::backend::storage::csv::transactions_csv;
+use crate::backend::storage::zip_archive::ZipArchiveWriter;
+use crate::backend::storage::xlsx_workbook::{XlsxCell, XlsxSheet, XlsxWorkbookWriter};
+use crate::backend::domain::mappers::TransactionMapper;
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::anonymizer::Anonymizer;
+use crate::backend::domain::models::actor::Actor;
+use crate::backend::domain::models::goal::DomainGoalState;
 
 /// Export service that handles all export-related business logic
 #[derive(Clone)]
@@ -56,11 +51,22 @@ impl ExportService {
         request: ExportDataRequest,
         child_service: &ChildService,
         transaction_service: &TransactionService,
+        parental_control_service: &ParentalControlService,
     ) -> Result<ExportDataResponse> {
         info!("📄 EXPORT: Exporting transactions as CSV for child_id: {:?}", request.child_id);
+        parental_control_service.authorize_export(request.parental_answer.as_deref())?;
+        self.build_transactions_csv(request.child_id, child_service, transaction_service)
+    }
 
+    /// Build the CSV for one child; callers check the export is allowed first
+    fn build_transactions_csv(
+        &self,
+        child_id: Option<String>,
+        child_service: &ChildService,
+        transaction_service: &TransactionService,
+    ) -> Result<ExportDataResponse> {
         // Step 1: Determine which child to export for
-        let child_id_to_use = if let Some(id) = request.child_id {
+        let child_id_to_use = if let Some(id) = child_id {
             id
         } else {
             let active_child_response = child_service.get_active_child()?;
@@ -71,7 +77,7 @@ impl ExportService {
                 }
                 None => {
                     error!("❌ EXPORT: No active child found and no child_id provided");
-                    return Err(anyhow::anyhow!("No active child set and no child_id provided"));
+                    return Err(DomainError::NoActiveChild.into());
                 }
             }
         };
@@ -84,7 +90,7 @@ impl ExportService {
             result if result.child.is_some() => result.child.unwrap(),
             _ => {
                 error!("❌ EXPORT: Child not found: {}", child_id_to_use);
-                return Err(anyhow::anyhow!("Child not found: {}", child_id_to_use));
+                return Err(DomainError::child_not_found(&child_id_to_use).into());
             }
         };
 
@@ -92,6 +98,7 @@ impl ExportService {
 
         // Step 3: Get all transactions for the child (no pagination for export)
         let domain_query = TransactionListQuery {
+            child_id: Some(child.id.clone()),
             after: None,
             limit: Some(10000),
             start_date: None,
@@ -111,9 +118,12 @@ impl ExportService {
 
         info!("✅ EXPORT: Retrieved {} transactions for export", transactions.len());
 
-        // Step 4: Generate CSV content
+        // Step 4: Generate CSV content, watermarked with who it is for and when
+        // it was made so an edited copy is easier to spot
+        let now = Utc::now();
         let mut csv_content = String::new();
-        csv_content.push_str("transaction_id,transaction_date,description,amount\n");
+        csv_content.push_str(&export_watermark(&child.name, now));
+        csv_content.push_str("transaction_id,transaction_date,description,amount,recorded_by\n");
 
         for (index, transaction) in transactions.iter().enumerate() {
             // Format the date as yyyy/mm/dd
@@ -121,17 +131,17 @@ impl ExportService {
 
             // Format the CSV row
             let row = format!(
-                "{},{},\"{}\",{:.2}\n",
+                "{},{},\"{}\",{:.2},\"{}\"\n",
                 index + 1, // Simple incrementing integer as requested
                 formatted_date,
                 transaction.description.replace("\"", "\"\""), // Escape quotes in description
-                transaction.amount
+                transaction.amount,
+                transaction.actor.as_deref().unwrap_or_default().replace("\"", "\"\"")
             );
             csv_content.push_str(&row);
         }
 
         // Step 5: Generate filename with current date
-        let now = Utc::now();
         let filename = format!(
             "{}_transactions_{}.csv",
             child.name.replace(" ", "_").to_lowercase(),
@@ -158,44 +168,26 @@ impl ExportService {
         request: ExportToPathRequest,
         child_service: &ChildService,
         transaction_service: &TransactionService,
+        parental_control_service: &ParentalControlService,
     ) -> Result<ExportToPathResponse> {
         info!("📁 EXPORT: Exporting to path - custom_path: {:?}", request.custom_path);
+        parental_control_service.authorize_export(request.parental_answer.as_deref())?;
 
         // Step 1: First, get the export data using existing logic
-        let export_request = ExportDataRequest {
-            child_id: request.child_id.clone(),
-        };
-
-        let export_response = self.export_transactions_csv(export_request, child_service, transaction_service)?;
+        let export_response = self.build_transactions_csv(request.child_id.clone(), child_service, transaction_service)?;
 
         // Step 2: Determine the export directory
-        let export_dir = match request.custom_path.clone() {
-            Some(custom_path) if !custom_path.trim().is_empty() => {
-                // Basic path sanitization: remove quotes, trim whitespace, handle common issues
-                let cleaned_path = self.sanitize_path(&custom_path);
-                std::path::PathBuf::from(cleaned_path)
-            }
-            _ => {
-                // Use default location: Documents folder
-                match dirs::document_dir() {
-                    Some(docs_dir) => docs_dir,
-                    None => {
-                        // Fallback to home directory if Documents not available
-                        match dirs::home_dir() {
-                            Some(home_dir) => home_dir,
-                            None => {
-                                error!("❌ EXPORT: Could not determine default export directory");
-                                return Ok(ExportToPathResponse {
-                                    success: false,
-                                    message: "Failed to determine export directory".to_string(),
-                                    file_path: String::new(),
-                                    transaction_count: 0,
-                                    child_name: String::new(),
-                                });
-                            }
-                        }
-                    }
-                }
+        let export_dir = match self.resolve_export_directory(request.custom_path.as_deref()) {
+            Some(dir) => dir,
+            None => {
+                error!("❌ EXPORT: Could not determine default export directory");
+                return Ok(ExportToPathResponse {
+                    success: false,
+                    message: "Failed to determine export directory".to_string(),
+                    file_path: String::new(),
+                    transaction_count: 0,
+                    child_name: String::new(),
+                });
             }
         };
 
@@ -244,6 +236,334 @@ impl ExportService {
         }
     }
 
+    /// Build a zip archive containing every child's transactions plus a manifest
+    /// describing the children, their allowance configs and goals.
+    ///
+    /// The archive is export-only: it can't be restored into a data folder,
+    /// since it holds none of the settings a full backup has.
+    ///
+    /// With `anonymize`, everything in the archive goes through one
+    /// `Anonymizer`, so the dataset stays consistent across children.
+    ///
+    /// Archive layout:
+    /// ```text
+    /// manifest.yaml
+    /// children/{child_id}/transactions.csv
+    /// ```
+    pub fn build_all_children_archive(
+        &self,
+        child_service: &ChildService,
+        transaction_service: &TransactionService,
+        allowance_service: &AllowanceService,
+        goal_service: &GoalService,
+        anonymize: bool,
+    ) -> Result<(Vec<u8>, ExportManifest)> {
+        let now = Utc::now();
+        let mut archive = ZipArchiveWriter::new(now.naive_local());
+        let mut manifest = ExportManifest {
+            format_version: EXPORT_FORMAT_VERSION.to_string(),
+            exported_at: now.to_rfc3339(),
+            anonymized: anonymize,
+            children: Vec::new(),
+        };
+
+        let mut children = Vec::new();
+        for child in child_service.list_children()?.children {
+            let transactions = transaction_service.list_all_transactions_for_child(&child.id)?;
+            children.push((child, transactions));
+        }
+        let anonymizer = anonymize.then(|| {
+            let parent_names: Vec<String> = children
+                .iter()
+                .flat_map(|(_, transactions)| transactions.iter())
+                .filter_map(|transaction| match &transaction.actor {
+                    Some(Actor::Parent(Some(name))) => Some(name.clone()),
+                    _ => None,
+                })
+                .collect::<std::collections::BTreeSet<_>>()
+                .into_iter()
+                .collect();
+            let just_children: Vec<_> = children.iter().map(|(child, _)| child.clone()).collect();
+            Anonymizer::new(&just_children, &parent_names)
+        });
+
+        for (child, transactions) in children {
+            info!("📦 EXPORT: Adding child {} to archive", child.id);
+            let transactions = match &anonymizer {
+                Some(anonymizer) => anonymizer.transactions(&transactions),
+                None => transactions,
+            };
+
+            // Transactions are written in the same layout as the on-disk storage
+            // format so they can be imported without conversion
+            let csv_bytes = transactions_csv(&transactions)?;
+
+            let allowance_config = allowance_service
+                .get_allowance_config(GetAllowanceConfigCommand { child_id: Some(child.id.clone()) })?
+                .allowance_config;
+            let goals = goal_service
+                .get_goal_history(GetGoalHistoryCommand { child_id: Some(child.id.clone()), limit: None })?
+                .goals;
+            let (child, allowance_config, goals) = match &anonymizer {
+                Some(anonymizer) => (
+                    anonymizer.child(&child),
+                    allowance_config.map(|config| anonymizer.allowance_config(&config)),
+                    goals.iter().map(|goal| anonymizer.goal(goal)).collect(),
+                ),
+                None => (child, allowance_config, goals),
+            };
+
+            let transactions_file = format!("children/{}/transactions.csv", child.id);
+            archive.add_file(&transactions_file, &csv_bytes)?;
+
+            manifest.children.push(ExportManifestChild {
+                child,
+                transactions_file,
+                transaction_count: transactions.len(),
+                allowance_config,
+                goals,
+            });
+        }
+
+        let manifest_yaml = serde_yaml::to_string(&manifest)?;
+        archive.add_file(EXPORT_MANIFEST_FILE, manifest_yaml.as_bytes())?;
+
+        Ok((archive.finish()?, manifest))
+    }
+
+    /// Export every child's data as a zip archive to a specified path (or default location)
+    pub fn export_all_children(
+        &self,
+        request: ExportAllDataRequest,
+        child_service: &ChildService,
+        transaction_service: &TransactionService,
+        allowance_service: &AllowanceService,
+        goal_service: &GoalService,
+    ) -> Result<ExportAllDataResponse> {
+        info!("📦 EXPORT: Exporting all children - custom_path: {:?}, anonymize: {}", request.custom_path, request.anonymize);
+
+        let (archive_bytes, manifest) = self.build_all_children_archive(
+            child_service,
+            transaction_service,
+            allowance_service,
+            goal_service,
+            request.anonymize,
+        )?;
+        let child_count = manifest.children.len();
+        let transaction_count: usize = manifest.children.iter().map(|c| c.transaction_count).sum();
+
+        let failure = |message: String, file_path: String| ExportAllDataResponse {
+            success: false,
+            message,
+            file_path,
+            child_count: 0,
+            transaction_count: 0,
+        };
+
+        let export_dir = match self.resolve_export_directory(request.custom_path.as_deref()) {
+            Some(dir) => dir,
+            None => {
+                error!("❌ EXPORT: Could not determine default export directory");
+                return Ok(failure("Failed to determine export directory".to_string(), String::new()));
+            }
+        };
+
+        if let Err(e) = fs::create_dir_all(&export_dir) {
+            error!("❌ EXPORT: Failed to create export directory {:?}: {}", export_dir, e);
+            return Ok(failure(
+                format!("Failed to create export directory: {}", e),
+                export_dir.to_string_lossy().to_string(),
+            ));
+        }
+
+        let kind = if request.anonymize { "anonymized" } else { "export" };
+        let filename = format!("allowance_tracker_{}_{}.zip", kind, Utc::now().format("%Y%m%d_%H%M%S"));
+        let file_path = export_dir.join(filename);
+        let file_path_str = file_path.to_string_lossy().to_string();
+
+        match fs::write(&file_path, &archive_bytes) {
+            Ok(_) => {
+                info!("✅ EXPORT: Exported {} children ({} transactions) to: {}",
+                      child_count, transaction_count, file_path_str);
+                Ok(ExportAllDataResponse {
+                    success: true,
+                    message: format!("Exported {} children to: {}", child_count, file_path_str),
+                    file_path: file_path_str,
+                    child_count,
+                    transaction_count,
+                })
+            }
+            Err(e) => {
+                error!("❌ EXPORT: Failed to write export archive to {:?}: {}", file_path, e);
+                Ok(failure(format!("Failed to write export archive: {}", e), file_path_str))
+            }
+        }
+    }
+
+    /// Build an Excel workbook with a summary sheet followed by one sheet per child
+    ///
+    /// The summary lists every child's balance, then every goal. Child sheets
+    /// list transactions oldest first. Amounts use each child's currency, and
+    /// each sheet's header row stays in view while scrolling.
+    pub fn build_workbook(
+        &self,
+        child_service: &ChildService,
+        transaction_service: &TransactionService,
+        goal_service: &GoalService,
+        family_service: &FamilyService,
+    ) -> Result<(Vec<u8>, usize, usize)> {
+        let mut summary = XlsxSheet::new(
+            "Summary",
+            &["Child", "Balance", "Transactions", "Last activity"],
+            &[24.0, 14.0, 14.0, 14.0],
+        );
+        let mut goal_rows = Vec::new();
+        let mut child_sheets = Vec::new();
+        let mut transaction_count = 0;
+
+        let children = child_service.list_children()?.children;
+        for child in &children {
+            info!("📊 EXPORT: Adding child {} to workbook", child.id);
+            let symbol = family_service.get_currency_for_child(&child.id)?.symbol().to_string();
+            let transactions = transaction_service.list_all_transactions_for_child(&child.id)?;
+            transaction_count += transactions.len();
+
+            let mut sheet = XlsxSheet::new(
+                &child.name,
+                &["Date", "Description", "Category", "Type", "Amount", "Balance", "Recorded by"],
+                &[12.0, 36.0, 16.0, 14.0, 12.0, 12.0, 18.0],
+            );
+            for transaction in &transactions {
+                sheet.add_row(vec![
+                    XlsxCell::Date(transaction.date.date_naive()),
+                    XlsxCell::Text(transaction.description.clone()),
+                    XlsxCell::Text(transaction.category.clone().unwrap_or_default()),
+                    XlsxCell::Text(transaction.transaction_type.label().to_string()),
+                    XlsxCell::Money(transaction.amount, symbol.clone()),
+                    XlsxCell::Money(transaction.balance, symbol.clone()),
+                    XlsxCell::Text(transaction.actor.as_ref().map(Actor::label).unwrap_or_default()),
+                ]);
+            }
+            child_sheets.push(sheet);
+
+            summary.add_row(vec![
+                XlsxCell::Text(child.name.clone()),
+                XlsxCell::Money(transactions.last().map(|t| t.balance).unwrap_or(0.0), symbol.clone()),
+                XlsxCell::Number(transactions.len() as f64),
+                transactions.last().map_or(XlsxCell::Empty, |t| XlsxCell::Date(t.date.date_naive())),
+            ]);
+
+            let goals = goal_service
+                .get_goal_history(GetGoalHistoryCommand { child_id: Some(child.id.clone()), limit: None })?
+                .goals;
+            for goal in goals {
+                let created = DateTime::parse_from_rfc3339(&goal.created_at)
+                    .map_or(XlsxCell::Empty, |created| XlsxCell::Date(created.date_naive()));
+                let state = match goal.state {
+                    DomainGoalState::Active => "Active",
+                    DomainGoalState::Completed => "Completed",
+                    DomainGoalState::Cancelled => "Cancelled",
+                };
+                goal_rows.push(vec![
+                    XlsxCell::Text(child.name.clone()),
+                    XlsxCell::Text(goal.description),
+                    XlsxCell::Money(goal.target_amount, symbol.clone()),
+                    XlsxCell::Text(state.to_string()),
+                    created,
+                ]);
+            }
+        }
+
+        if !goal_rows.is_empty() {
+            summary.add_blank_row();
+            summary.add_header_row(&["Child", "Goal", "Target", "State", "Started"]);
+            for row in goal_rows {
+                summary.add_row(row);
+            }
+        }
+
+        let mut workbook = XlsxWorkbookWriter::new();
+        workbook.add_sheet(summary);
+        for sheet in child_sheets {
+            workbook.add_sheet(sheet);
+        }
+        Ok((workbook.finish(Utc::now().naive_local())?, children.len(), transaction_count))
+    }
+
+    /// Export every child as an Excel workbook to a specified path (or default location)
+    pub fn export_workbook(
+        &self,
+        request: ExportWorkbookRequest,
+        child_service: &ChildService,
+        transaction_service: &TransactionService,
+        goal_service: &GoalService,
+        family_service: &FamilyService,
+        parental_control_service: &ParentalControlService,
+    ) -> Result<ExportAllDataResponse> {
+        info!("📊 EXPORT: Exporting workbook - custom_path: {:?}", request.custom_path);
+        parental_control_service.authorize_export(request.parental_answer.as_deref())?;
+
+        let (workbook_bytes, child_count, transaction_count) =
+            self.build_workbook(child_service, transaction_service, goal_service, family_service)?;
+
+        let failure = |message: String, file_path: String| ExportAllDataResponse {
+            success: false,
+            message,
+            file_path,
+            child_count: 0,
+            transaction_count: 0,
+        };
+
+        let export_dir = match self.resolve_export_directory(request.custom_path.as_deref()) {
+            Some(dir) => dir,
+            None => {
+                error!("❌ EXPORT: Could not determine default export directory");
+                return Ok(failure("Failed to determine export directory".to_string(), String::new()));
+            }
+        };
+
+        if let Err(e) = fs::create_dir_all(&export_dir) {
+            error!("❌ EXPORT: Failed to create export directory {:?}: {}", export_dir, e);
+            return Ok(failure(
+                format!("Failed to create export directory: {}", e),
+                export_dir.to_string_lossy().to_string(),
+            ));
+        }
+
+        let file_path = export_dir.join(workbook_filename(Utc::now()));
+        let file_path_str = file_path.to_string_lossy().to_string();
+
+        match fs::write(&file_path, &workbook_bytes) {
+            Ok(_) => {
+                info!("✅ EXPORT: Exported workbook with {} children ({} transactions) to: {}",
+                      child_count, transaction_count, file_path_str);
+                Ok(ExportAllDataResponse {
+                    success: true,
+                    message: format!("Exported {} children to: {}", child_count, file_path_str),
+                    file_path: file_path_str,
+                    child_count,
+                    transaction_count,
+                })
+            }
+            Err(e) => {
+                error!("❌ EXPORT: Failed to write workbook to {:?}: {}", file_path, e);
+                Ok(failure(format!("Failed to write workbook: {}", e), file_path_str))
+            }
+        }
+    }
+
+    /// Resolve the directory to export into: the sanitized custom path if one was
+    /// given, otherwise the Documents folder (falling back to the home directory)
+    fn resolve_export_directory(&self, custom_path: Option<&str>) -> Option<std::path::PathBuf> {
+        match custom_path {
+            Some(custom_path) if !custom_path.trim().is_empty() => {
+                // Basic path sanitization: remove quotes, trim whitespace, handle common issues
+                Some(std::path::PathBuf::from(self.sanitize_path(custom_path)))
+            }
+            _ => dirs::document_dir().or_else(dirs::home_dir),
+        }
+    }
+
     /// Basic path sanitization to handle common user input issues
     fn sanitize_path(&self, path: &str) -> String {
         let mut cleaned = path.trim().to_string();
@@ -280,6 +600,20 @@ impl ExportService {
     }
 }
 
+/// Comment line put at the top of single-child exports
+fn export_watermark(child_name: &str, generated_at: DateTime<Utc>) -> String {
+    format!(
+        "# Allowance Tracker export for {} generated {}\n",
+        child_name.replace(['\r', '\n'], " "),
+        generated_at.to_rfc3339_opts(SecondsFormat::Secs, true)
+    )
+}
+
+/// File name for a workbook export made at `generated_at`
+fn workbook_filename(generated_at: DateTime<Utc>) -> String {
+    format!("allowance_tracker_{}.xlsx", generated_at.format("%Y%m%d_%H%M%S"))
+}
+
 impl Default for ExportService {
     fn default() -> Self {
         Self::new()
@@ -318,4 +652,249 @@ mod tests {
         let _service_default = ExportService::default();
         assert!(true); // Default service created successfully
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_build_all_children_archive() {
+        use crate::backend::domain::{AllowanceService, BalanceService};
+        use crate::backend::domain::commands::allowance::UpdateAllowanceConfigCommand;
+        use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand};
+        use crate::backend::domain::commands::transactions::CreateTransactionCommand;
+        use crate::backend::storage::csv::CsvConnection;
+        use crate::backend::storage::zip_archive::read_zip_entries;
+        use std::sync::Arc;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let connection = Arc::new(CsvConnection::new(temp_dir.path()).unwrap());
+        let child_service = ChildService::new(connection.clone());
+        let allowance_service = AllowanceService::new(connection.clone());
+        let balance_service = BalanceService::new(connection.clone());
+        let transaction_service = Arc::new(TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            allowance_service.clone(),
+            balance_service.clone(),
+        ));
+        let goal_service = GoalService::new(
+            connection.clone(),
+            child_service.clone(),
+            allowance_service.clone(),
+            transaction_service.clone(),
+            balance_service,
+        );
+
+        for name in ["Alice", "Bob"] {
+            let child = child_service
+                .create_child(CreateChildCommand { name: name.to_string(), birthdate: "2015-01-01".to_string() })
+                .unwrap()
+                .child;
+            child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+            transaction_service
+                .create_transaction(CreateTransactionCommand {
+                    child_id: None,
+                    description: format!("Gift for {}, with comma", name),
+                    amount: 5.0,
+                    date: None,
+                    parent_override: false,
+                    actor: Some(Actor::Parent(Some("Mom".to_string()))),
+                    icon: None,
+                    transaction_type: None,
+                })
+                .unwrap();
+        }
+        allowance_service
+            .update_allowance_config(UpdateAllowanceConfigCommand {
+                child_id: Some("alice".to_string()),
+                amount: 3.0,
+                day_of_week: 5,
+                is_active: true,
+                day_change_policy: None,
+                frequency: None,
+                day_of_month: None,
+                interval_weeks: None,
+                anchor_date: None,
+                expected_updated_at: None,
+            })
+            .unwrap();
+
+        let service = ExportService::new();
+        let (bytes, manifest) = service
+            .build_all_children_archive(&child_service, &transaction_service, &allowance_service, &goal_service, false)
+            .unwrap();
+
+        assert_eq!(manifest.format_version, EXPORT_FORMAT_VERSION);
+        assert_eq!(manifest.children.len(), 2);
+        let alice = manifest.children.iter().find(|c| c.child.name == "Alice").unwrap();
+        assert_eq!(alice.transaction_count, 1);
+        assert_eq!(alice.allowance_config.as_ref().unwrap().amount, 3.0);
+        let bob = manifest.children.iter().find(|c| c.child.name == "Bob").unwrap();
+        assert!(bob.allowance_config.is_none());
+
+        let entries = read_zip_entries(&bytes).unwrap();
+        let names: Vec<&str> = entries.iter().map(|(name, _)| name.as_str()).collect();
+        assert!(names.contains(&"manifest.yaml"));
+        assert!(names.contains(&alice.transactions_file.as_str()));
+        assert!(names.contains(&bob.transactions_file.as_str()));
+
+        // The manifest in the archive round-trips to the returned manifest
+        let manifest_bytes = &entries.iter().find(|(name, _)| name == "manifest.yaml").unwrap().1;
+        let parsed: ExportManifest = serde_yaml::from_slice(manifest_bytes).unwrap();
+        assert_eq!(parsed, manifest);
+
+        let alice_csv = String::from_utf8(
+            entries.iter().find(|(name, _)| name == &alice.transactions_file).unwrap().1.clone(),
+        ).unwrap();
+        // Same layout as transactions.csv, version comment included
+        let header = alice_csv.lines().find(|line| !line.starts_with('#')).unwrap();
+        assert_eq!(header, crate::backend::storage::csv::transaction_repository::TRANSACTION_COLUMNS.join(","));
+        assert!(alice_csv.contains("\"Gift for Alice, with comma\""));
+        assert!(alice_csv.contains(&Actor::Parent(Some("Mom".to_string())).to_storage()), "who recorded it is kept: {}", alice_csv);
+
+        // Nothing in an anonymized archive names the children
+        let (bytes, manifest) = service
+            .build_all_children_archive(&child_service, &transaction_service, &allowance_service, &goal_service, true)
+            .unwrap();
+        assert!(manifest.anonymized);
+        let names: Vec<&str> = manifest.children.iter().map(|c| c.child.name.as_str()).collect();
+        assert_eq!(names.len(), 2);
+        assert!(names.iter().all(|name| name.starts_with("Child ")));
+        for (_, content) in read_zip_entries(&bytes).unwrap() {
+            let content = String::from_utf8(content).unwrap();
+            assert!(!content.contains("Alice") && !content.contains("Bob"), "{}", content);
+        }
+    }
+
+    #[test]
+    fn test_export_is_watermarked_and_gated() {
+        use crate::backend::domain::{AllowanceService, BalanceService};
+        use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand};
+        use crate::backend::domain::commands::transactions::CreateTransactionCommand;
+        use crate::backend::storage::csv::CsvConnection;
+        use std::sync::Arc;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let connection = Arc::new(CsvConnection::new(temp_dir.path()).unwrap());
+        let child_service = ChildService::new(connection.clone());
+        let transaction_service = TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            AllowanceService::new(connection.clone()),
+            BalanceService::new(connection.clone()),
+        );
+        let parental_control_service = ParentalControlService::with_answer(connection, "ice cold".to_string());
+
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Alice".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+        transaction_service
+            .create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Gift".to_string(),
+                amount: 5.0,
+                date: None,
+                parent_override: false,
+                actor: Some(Actor::Parent(Some("Mom".to_string()))),
+                icon: None,
+                transaction_type: None,
+            })
+            .unwrap();
+
+        let service = ExportService::new();
+        let export = |answer: Option<&str>| {
+            service.export_transactions_csv(
+                ExportDataRequest { child_id: None, parental_answer: answer.map(str::to_string) },
+                &child_service,
+                &transaction_service,
+                &parental_control_service,
+            )
+        };
+
+        let response = export(None).unwrap();
+        let mut lines = response.csv_content.lines();
+        let watermark = lines.next().unwrap();
+        assert!(watermark.starts_with("# Allowance Tracker export for Alice generated "), "{}", watermark);
+        assert_eq!(lines.next(), Some("transaction_id,transaction_date,description,amount,recorded_by"));
+        let row = lines.next().unwrap();
+        assert!(row.ends_with(",5.00,\"Parent (Mom)\""), "{}", row);
+        assert_eq!(response.transaction_count, 1);
+
+        // Once a parent turns the policy on, exports need the answer
+        parental_control_service.set_export_requires_parental_control(true).unwrap();
+        let refused = export(None).unwrap_err();
+        assert!(matches!(refused.downcast_ref::<DomainError>(), Some(DomainError::Unauthorized(_))));
+        assert!(export(Some("warm milk")).is_err());
+        assert_eq!(export(Some("ice cold")).unwrap().transaction_count, 1);
+    }
+
+    #[test]
+    fn test_build_workbook_has_summary_and_child_sheets() {
+        use crate::backend::domain::{AllowanceService, BalanceService};
+        use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand};
+        use crate::backend::domain::commands::goal::CreateGoalCommand;
+        use crate::backend::domain::models::transaction::TransactionType;
+        use crate::backend::domain::commands::transactions::CreateTransactionCommand;
+        use crate::backend::storage::csv::CsvConnection;
+        use crate::backend::storage::zip_archive::read_zip_entries;
+        use std::sync::Arc;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let connection = Arc::new(CsvConnection::new(temp_dir.path()).unwrap());
+        let child_service = ChildService::new(connection.clone());
+        let allowance_service = AllowanceService::new(connection.clone());
+        let balance_service = BalanceService::new(connection.clone());
+        let transaction_service = Arc::new(TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            allowance_service.clone(),
+            balance_service.clone(),
+        ));
+        let goal_service = GoalService::new(
+            connection.clone(),
+            child_service.clone(),
+            allowance_service.clone(),
+            transaction_service.clone(),
+            balance_service.clone(),
+        );
+        let family_service = FamilyService::new(connection, child_service.clone(), allowance_service, balance_service);
+
+        let alice = child_service
+            .create_child(CreateChildCommand { name: "Alice".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: alice.id.clone() }).unwrap();
+        transaction_service
+            .create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Gift & card".to_string(),
+                amount: 5.0,
+                date: None,
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: Some(TransactionType::Gift),
+            })
+            .unwrap();
+        goal_service
+            .create_goal(CreateGoalCommand { child_id: None, description: "Kite".to_string(), target_amount: 20.0 })
+            .unwrap();
+
+        let (bytes, child_count, transaction_count) = ExportService::new()
+            .build_workbook(&child_service, &transaction_service, &goal_service, &family_service)
+            .unwrap();
+        assert_eq!((child_count, transaction_count), (1, 1));
+
+        let entries = read_zip_entries(&bytes).unwrap();
+        let text = |name: &str| {
+            let (_, contents) = entries.iter().find(|(entry, _)| entry == name).unwrap();
+            String::from_utf8(contents.clone()).unwrap()
+        };
+        let workbook = text("xl/workbook.xml");
+        assert!(workbook.contains(r#"<sheet name="Summary""#) && workbook.contains(r#"<sheet name="Alice""#), "{}", workbook);
+        let summary = text("xl/worksheets/sheet1.xml");
+        assert!(summary.contains("Kite") && summary.contains("Active"), "{}", summary);
+        let sheet = text("xl/worksheets/sheet2.xml");
+        assert!(sheet.contains("Gift &amp; card") && sheet.contains(r#"<t xml:space="preserve">Gift</t>"#), "{}", sheet);
+        assert!(sheet.contains(r#"state="frozen""#));
+    }
+}
@@ -0,0 +1,463 @@
+//! Family service domain logic for the allowance tracker.
+//!
+//! Builds the family overview: every child's balance, goal progress and next
+//! allowance side by side. Children may count their money in different
+//! currencies, so balances are also converted into the family currency when
+//! a parent has entered an exchange rate; balances without a rate are shown
+//! in their own currency and left out of the family total rather than guessed.
+//! Derived facts such as age come from `child_summary`.
+//!
+//! The comparison view puts children side by side on savings rate and goals
+//! completed instead. Those don't depend on how much a child has, so it can
+//! leave balances out and still be fair between siblings of different ages.
+
+use anyhow::Result;
+use chrono::{Datelike, Local, NaiveDate, Utc};
+use log::info;
+use std::sync::Arc;
+
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::child_summary::ChildSummary;
+use crate::backend::domain::commands::family::{
+    ChildComparison, ChildComparisonQuery, ChildComparisonResult, ChildOverview, FamilyCurrencySettings,
+    FamilyOverviewResult, GoalOverview, NextAllowance, UpdateFamilyCurrencyCommand,
+};
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::models::child::Child as DomainChild;
+use crate::backend::domain::models::currency::Currency;
+use crate::backend::domain::models::goal::DomainGoalState;
+use crate::backend::domain::{AllowanceService, BalanceService};
+use crate::backend::storage::csv::{CsvConnection, GlobalConfig, GlobalConfigRepository, GoalRepository, TransactionRepository};
+use crate::backend::storage::traits::TransactionStorage;
+use crate::backend::storage::GlobalConfigStorage;
+
+/// Service for questions about the whole family rather than one child
+#[derive(Clone)]
+pub struct FamilyService {
+    global_config_repository: GlobalConfigRepository,
+    goal_repository: GoalRepository,
+    transaction_repository: TransactionRepository,
+    child_service: ChildService,
+    allowance_service: AllowanceService,
+    balance_service: BalanceService,
+}
+
+impl FamilyService {
+    /// Create a new FamilyService
+    pub fn new(
+        csv_conn: Arc<CsvConnection>,
+        child_service: ChildService,
+        allowance_service: AllowanceService,
+        balance_service: BalanceService,
+    ) -> Self {
+        let global_config_repository = GlobalConfigRepository::new((*csv_conn).clone());
+        let goal_repository = GoalRepository::new((*csv_conn).clone());
+        let transaction_repository = TransactionRepository::new((*csv_conn).clone());
+        Self {
+            global_config_repository,
+            goal_repository,
+            transaction_repository,
+            child_service,
+            allowance_service,
+            balance_service,
+        }
+    }
+
+    /// Every child's balance, goal progress and next allowance in one response
+    pub fn get_family_overview(&self) -> Result<FamilyOverviewResult> {
+        self.family_overview_on(Local::now().date_naive())
+    }
+
+    fn family_overview_on(&self, today: NaiveDate) -> Result<FamilyOverviewResult> {
+        let config = self.global_config_repository.get_global_config()?;
+        let children = self.child_service.list_children()?.children;
+
+        let mut overviews = Vec::with_capacity(children.len());
+        let mut total_in_family_currency = 0.0;
+        let mut unconverted_currencies = Vec::new();
+
+        for child in &children {
+            let overview = self.child_overview(child, &config, today)?;
+            match overview.balance_in_family_currency {
+                Some(converted) => total_in_family_currency += converted,
+                None => {
+                    if !unconverted_currencies.contains(&overview.currency) {
+                        unconverted_currencies.push(overview.currency);
+                    }
+                }
+            }
+            overviews.push(overview);
+        }
+
+        let total_in_family_currency = round_cents(total_in_family_currency);
+        info!("👪 FAMILY: {} children, total {} {:.2}", overviews.len(), config.currency, total_in_family_currency);
+
+        Ok(FamilyOverviewResult {
+            family_currency: config.currency,
+            children: overviews,
+            total_in_family_currency,
+            unconverted_currencies,
+        })
+    }
+
+    fn child_overview(&self, child: &DomainChild, config: &GlobalConfig, today: NaiveDate) -> Result<ChildOverview> {
+        let currency = self.child_currency(&child.id, config)?;
+        let balance = self.balance_service.get_current_balance(&child.id)?;
+
+        let goal = self.goal_repository.get_current_goal(&child.id)?.map(|goal| GoalOverview {
+            progress_percent: goal_progress_percent(balance, goal.target_amount),
+            description: goal.description,
+            target_amount: goal.target_amount,
+        });
+
+        let next_allowance = self
+            .allowance_service
+            .next_allowance(&child.id, today)?
+            .map(|(date, amount)| NextAllowance { date, amount });
+
+        let transactions = self.transaction_repository.list_transactions_chronological(&child.id, None, None)?;
+        let summary = ChildSummary::compute(child.birthdate, &transactions, today);
+
+        Ok(ChildOverview {
+            child_id: child.id.clone(),
+            name: child.name.clone(),
+            currency,
+            balance,
+            balance_in_family_currency: convert(balance, currency, config),
+            goal,
+            next_allowance,
+            summary,
+        })
+    }
+
+    /// Compare every child on savings rate and goals completed this year
+    pub fn get_child_comparison(&self, query: ChildComparisonQuery) -> Result<ChildComparisonResult> {
+        self.child_comparison_on(query, Local::now().date_naive())
+    }
+
+    fn child_comparison_on(&self, query: ChildComparisonQuery, today: NaiveDate) -> Result<ChildComparisonResult> {
+        let config = self.global_config_repository.get_global_config()?;
+        let children = self.child_service.list_children()?.children;
+
+        let mut comparisons = Vec::with_capacity(children.len());
+        for child in &children {
+            let balance = self.balance_service.get_current_balance(&child.id)?;
+            let transactions = self.transaction_repository.list_transactions_chronological(&child.id, None, None)?;
+            let summary = ChildSummary::compute(child.birthdate, &transactions, today);
+            let savings_rate = (summary.earned_this_year > 0.0).then(|| {
+                let rate = (summary.earned_this_year - summary.spent_this_year) / summary.earned_this_year * 100.0;
+                (rate * 10.0).round() / 10.0
+            });
+
+            let goals_completed = self
+                .goal_repository
+                .list_goals(&child.id, None)?
+                .iter()
+                .filter(|goal| goal.state == DomainGoalState::Completed)
+                .count();
+            let goal_progress_percent = self
+                .goal_repository
+                .get_current_goal(&child.id)?
+                .map(|goal| goal_progress_percent(balance, goal.target_amount));
+
+            comparisons.push(ChildComparison {
+                child_id: child.id.clone(),
+                name: child.name.clone(),
+                savings_rate,
+                goals_completed,
+                goal_progress_percent,
+                balance: (!query.hide_balances).then_some(balance),
+                currency: self.child_currency(&child.id, &config)?,
+            });
+        }
+
+        // Best savers first; children who haven't earned anything yet go last
+        comparisons.sort_by(|a, b| match (a.savings_rate, b.savings_rate) {
+            (Some(a_rate), Some(b_rate)) => b_rate.total_cmp(&a_rate),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        });
+
+        info!(
+            "👪 FAMILY: compared {} children{}",
+            comparisons.len(),
+            if query.hide_balances { " with balances hidden" } else { "" }
+        );
+
+        Ok(ChildComparisonResult {
+            year: today.year(),
+            balances_hidden: query.hide_balances,
+            children: comparisons,
+        })
+    }
+
+    /// Currency that applies to a child: their own setting, else the family one
+    pub fn get_currency_for_child(&self, child_id: &str) -> Result<Currency> {
+        let config = self.global_config_repository.get_global_config()?;
+        self.child_currency(child_id, &config)
+    }
+
+    fn child_currency(&self, child_id: &str, config: &GlobalConfig) -> Result<Currency> {
+        let settings = self.child_service.get_child_settings(child_id)?.settings;
+        Ok(settings.currency.unwrap_or(config.currency))
+    }
+
+    /// Get the family currency and configured exchange rates
+    pub fn get_currency_settings(&self) -> Result<FamilyCurrencySettings> {
+        let config = self.global_config_repository.get_global_config()?;
+        Ok(FamilyCurrencySettings {
+            family_currency: config.currency,
+            exchange_rates: config.exchange_rates,
+        })
+    }
+
+    /// Change the family currency and exchange rates
+    pub fn update_currency_settings(&self, command: UpdateFamilyCurrencyCommand) -> Result<FamilyCurrencySettings> {
+        if let Some((currency, rate)) = command.exchange_rates.iter().find(|(_, rate)| !rate.is_finite() || **rate <= 0.0) {
+            return Err(DomainError::invalid(format!("Exchange rate for {} must be greater than 0 (got {})", currency, rate)).into());
+        }
+
+        let mut config = self.global_config_repository.get_global_config()?;
+        if let Some(currency) = command.family_currency {
+            config.currency = currency;
+        }
+        for currency in &command.remove_rates {
+            config.exchange_rates.remove(currency);
+        }
+        config.exchange_rates.extend(command.exchange_rates);
+        // A rate from the family currency to itself is always 1
+        config.exchange_rates.remove(&config.currency);
+        config.updated_at = Utc::now().to_rfc3339();
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!("👪 FAMILY: currency {} with {} exchange rate(s)", config.currency, config.exchange_rates.len());
+
+        Ok(FamilyCurrencySettings {
+            family_currency: config.currency,
+            exchange_rates: config.exchange_rates,
+        })
+    }
+}
+
+/// Convert an amount into the family currency, if a rate is known
+fn convert(amount: f64, currency: Currency, config: &GlobalConfig) -> Option<f64> {
+    if currency == config.currency {
+        return Some(amount);
+    }
+    config.exchange_rates.get(&currency).map(|rate| round_cents(amount * rate))
+}
+
+/// Balance as a percentage of a goal's target, capped at 100
+fn goal_progress_percent(balance: f64, target_amount: f64) -> f64 {
+    if target_amount > 0.0 {
+        ((balance / target_amount * 100.0).clamp(0.0, 100.0) * 10.0).round() / 10.0
+    } else {
+        100.0
+    }
+}
+
+fn round_cents(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::allowance::UpdateAllowanceConfigCommand;
+    use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand, UpdateChildSettingsCommand};
+    use crate::backend::domain::commands::goal::CreateGoalCommand;
+    use crate::backend::domain::commands::transactions::CreateTransactionCommand;
+    use crate::backend::domain::{GoalService, TransactionService};
+    use std::collections::BTreeMap;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_family_overview_converts_where_configured() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let child_service = ChildService::new(connection.clone());
+        let allowance_service = AllowanceService::new(connection.clone());
+        let balance_service = BalanceService::new(connection.clone());
+        let transaction_service = Arc::new(TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            allowance_service.clone(),
+            balance_service.clone(),
+        ));
+        let goal_service = GoalService::new(
+            connection.clone(),
+            child_service.clone(),
+            allowance_service.clone(),
+            transaction_service.clone(),
+            balance_service.clone(),
+        );
+        let service = FamilyService::new(connection, child_service.clone(), allowance_service.clone(), balance_service);
+
+        let create = |name: &str, currency: Option<Currency>| -> Result<String> {
+            let child = child_service
+                .create_child(CreateChildCommand { name: name.to_string(), birthdate: "2015-01-01".to_string() })?
+                .child;
+            child_service.update_child_settings(UpdateChildSettingsCommand {
+                child_id: child.id.clone(),
+                prevent_negative_balance: None,
+                auto_save_percentage: None,
+                auto_save_to_goal: None,
+                round_up_spends: None,
+                locale: None,
+                currency: Some(currency),
+                balance_milestones: None,
+                background: None,
+            })?;
+            child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() })?;
+            transaction_service.create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Birthday".to_string(),
+                amount: 20.0,
+                date: None,
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })?;
+            Ok(child.id)
+        };
+        let home = create("Home", None)?;
+        let abroad = create("Abroad", Some(Currency::Gbp))?;
+        let euro = create("Euro", Some(Currency::Eur))?;
+
+        goal_service.create_goal(CreateGoalCommand {
+            child_id: Some(home.clone()),
+            description: "Bike".to_string(),
+            target_amount: 80.0,
+        })?;
+        allowance_service.update_allowance_config(UpdateAllowanceConfigCommand {
+            child_id: Some(home.clone()),
+            amount: 5.0,
+            day_of_week: 5, // Friday
+            is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
+        })?;
+
+        service.update_currency_settings(UpdateFamilyCurrencyCommand {
+            family_currency: None,
+            exchange_rates: BTreeMap::from([(Currency::Gbp, 1.25)]),
+            remove_rates: Vec::new(),
+        })?;
+
+        // Wednesday
+        let overview = service.family_overview_on(NaiveDate::from_ymd_opt(2025, 6, 18).unwrap())?;
+        assert_eq!(overview.family_currency, Currency::Usd);
+        assert_eq!(overview.children.len(), 3);
+
+        let find = |id: &str| overview.children.iter().find(|c| c.child_id == id).unwrap();
+        let home = find(&home);
+        assert_eq!(home.balance_in_family_currency, Some(20.0));
+        assert_eq!(home.summary.age, 10);
+        assert_eq!(home.summary.days_until_birthday, 197);
+        assert_eq!(home.goal.as_ref().map(|g| g.progress_percent), Some(25.0));
+        assert_eq!(
+            home.next_allowance,
+            Some(NextAllowance { date: NaiveDate::from_ymd_opt(2025, 6, 20).unwrap(), amount: 5.0 })
+        );
+
+        let abroad = find(&abroad);
+        assert_eq!(abroad.currency, Currency::Gbp);
+        assert_eq!(abroad.balance, 20.0);
+        assert_eq!(abroad.balance_in_family_currency, Some(25.0));
+        assert_eq!(abroad.next_allowance, None);
+        assert_eq!(service.get_currency_for_child(&abroad.child_id)?, Currency::Gbp);
+        assert_eq!(service.get_currency_for_child(&home.child_id)?, Currency::Usd);
+
+        // No EUR rate: shown in euros but left out of the total
+        assert_eq!(find(&euro).balance_in_family_currency, None);
+        assert_eq!(overview.total_in_family_currency, 45.0);
+        assert_eq!(overview.unconverted_currencies, vec![Currency::Eur]);
+
+        assert!(service
+            .update_currency_settings(UpdateFamilyCurrencyCommand {
+                family_currency: None,
+                exchange_rates: BTreeMap::from([(Currency::Eur, 0.0)]),
+                remove_rates: Vec::new(),
+            })
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_child_comparison_ranks_by_savings_rate_and_can_hide_balances() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let child_service = ChildService::new(connection.clone());
+        let allowance_service = AllowanceService::new(connection.clone());
+        let balance_service = BalanceService::new(connection.clone());
+        let transaction_service = TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            allowance_service.clone(),
+            balance_service.clone(),
+        );
+        let goal_repository = GoalRepository::new((*connection).clone());
+        let service = FamilyService::new(connection, child_service.clone(), allowance_service, balance_service);
+
+        let money = |description: &str, amount: f64| CreateTransactionCommand {
+            child_id: None,
+            description: description.to_string(),
+            amount,
+            date: Some(chrono::DateTime::parse_from_rfc3339("2025-03-01T12:00:00Z").unwrap()),
+            parent_override: false,
+            actor: None,
+            icon: None,
+            transaction_type: None,
+        };
+        let create = |name: &str| -> Result<String> {
+            let child = child_service
+                .create_child(CreateChildCommand { name: name.to_string(), birthdate: "2015-01-01".to_string() })?
+                .child;
+            child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() })?;
+            Ok(child.id)
+        };
+
+        // Spender earns more but keeps a smaller share of it
+        let spender = create("Spender")?;
+        transaction_service.create_transaction(money("Chores", 100.0))?;
+        transaction_service.create_transaction(money("Games", -60.0))?;
+        let saver = create("Saver")?;
+        transaction_service.create_transaction(money("Chores", 10.0))?;
+        transaction_service.create_transaction(money("Candy", -1.0))?;
+        let newcomer = create("Newcomer")?;
+
+        for (index, state) in [DomainGoalState::Completed, DomainGoalState::Completed, DomainGoalState::Active].into_iter().enumerate() {
+            goal_repository.store_goal(&crate::backend::domain::models::goal::DomainGoal {
+                id: format!("goal::saver_{}", index),
+                child_id: saver.clone(),
+                description: "Kite".to_string(),
+                target_amount: 18.0,
+                state,
+                created_at: "2025-01-01T00:00:00Z".to_string(),
+                updated_at: "2025-01-01T00:00:00Z".to_string(),
+            })?;
+        }
+
+        let today = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        let comparison = service.child_comparison_on(ChildComparisonQuery { hide_balances: true }, today)?;
+        assert!(comparison.balances_hidden);
+        let order: Vec<&str> = comparison.children.iter().map(|c| c.child_id.as_str()).collect();
+        assert_eq!(order, vec![saver.as_str(), spender.as_str(), newcomer.as_str()]);
+        assert_eq!(comparison.children[0].savings_rate, Some(90.0));
+        assert_eq!(comparison.children[0].goals_completed, 2);
+        assert_eq!(comparison.children[0].goal_progress_percent, Some(50.0));
+        assert_eq!(comparison.children[1].savings_rate, Some(40.0));
+        assert_eq!(comparison.children[2].savings_rate, None);
+        assert!(comparison.children.iter().all(|c| c.balance.is_none()));
+
+        let shown = service.child_comparison_on(ChildComparisonQuery { hide_balances: false }, today)?;
+        assert_eq!(shown.children[0].balance, Some(9.0));
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+//! Feature flag service domain logic for the allowance tracker.
+//!
+//! Experimental features (see [`FeatureFlag`]) are off until a parent turns
+//! them on in settings. The flags live in the global config, so they apply to
+//! every child in the data directory and survive restarts. Services behind
+//! an experiment call [`FeatureFlagService::require_enabled`] before doing
+//! anything, so a flag that is off can't be worked around from another
+//! frontend either.
+
+use anyhow::Result;
+use chrono::Utc;
+use log::info;
+use std::sync::Arc;
+
+use crate::backend::domain::commands::feature_flags::{FeatureFlagStatus, FeatureFlagsResult, SetFeatureFlagCommand};
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::models::feature_flag::FeatureFlag;
+use crate::backend::storage::csv::{CsvConnection, GlobalConfigRepository};
+use crate::backend::storage::GlobalConfigStorage;
+
+/// Service for reading and toggling experimental features
+#[derive(Clone)]
+pub struct FeatureFlagService {
+    global_config_repository: GlobalConfigRepository,
+}
+
+impl FeatureFlagService {
+    /// Create a new FeatureFlagService
+    pub fn new(csv_conn: Arc<CsvConnection>) -> Self {
+        let global_config_repository = GlobalConfigRepository::new((*csv_conn).clone());
+        Self { global_config_repository }
+    }
+
+    /// Every flag and whether it is on
+    pub fn get_feature_flags(&self) -> Result<FeatureFlagsResult> {
+        let config = self.global_config_repository.get_global_config()?;
+        Ok(FeatureFlagsResult {
+            flags: FeatureFlag::ALL
+                .iter()
+                .map(|flag| FeatureFlagStatus { flag: *flag, enabled: config.feature_flags.contains(flag) })
+                .collect(),
+        })
+    }
+
+    /// Whether a flag is on
+    pub fn is_enabled(&self, flag: FeatureFlag) -> Result<bool> {
+        Ok(self.global_config_repository.get_global_config()?.feature_flags.contains(&flag))
+    }
+
+    /// Fail with a `Conflict` unless the flag is on
+    pub fn require_enabled(&self, flag: FeatureFlag) -> Result<()> {
+        if self.is_enabled(flag)? {
+            return Ok(());
+        }
+        Err(DomainError::Conflict(format!(
+            "{} is an experimental feature and is turned off. A parent can turn it on in Settings → Experiments.",
+            flag.label()
+        ))
+        .into())
+    }
+
+    /// Turn a flag on or off
+    pub fn set_feature_flag(&self, command: SetFeatureFlagCommand) -> Result<FeatureFlagsResult> {
+        let mut config = self.global_config_repository.get_global_config()?;
+        let changed = if command.enabled {
+            config.feature_flags.insert(command.flag)
+        } else {
+            config.feature_flags.remove(&command.flag)
+        };
+        if changed {
+            config.updated_at = Utc::now().to_rfc3339();
+            self.global_config_repository.update_global_config(&config)?;
+            info!("🧪 Feature flag {:?} turned {}", command.flag, if command.enabled { "on" } else { "off" });
+        }
+        self.get_feature_flags()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::csv::test_utils::TestHelper;
+
+    #[test]
+    fn test_flags_are_off_until_set_and_persisted() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let service = FeatureFlagService::new(Arc::new(helper.env.connection.clone()));
+
+        assert!(service.get_feature_flags()?.flags.iter().all(|status| !status.enabled));
+        let error = service.require_enabled(FeatureFlag::Interest).unwrap_err();
+        assert!(matches!(error.downcast_ref::<DomainError>(), Some(DomainError::Conflict(_))));
+
+        let result = service.set_feature_flag(SetFeatureFlagCommand { flag: FeatureFlag::Interest, enabled: true })?;
+        assert!(result.is_enabled(FeatureFlag::Interest));
+        assert!(!result.is_enabled(FeatureFlag::Chores));
+
+        // A fresh service over the same data directory sees the saved flag
+        let reopened = FeatureFlagService::new(Arc::new(CsvConnection::new(&helper.env.base_path)?));
+        reopened.require_enabled(FeatureFlag::Interest)?;
+
+        reopened.set_feature_flag(SetFeatureFlagCommand { flag: FeatureFlag::Interest, enabled: false })?;
+        assert!(!service.is_enabled(FeatureFlag::Interest)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,425 @@
+//! Fridge view service domain logic for the allowance tracker.
+//!
+//! Writes a single, self-contained HTML page with every child's balance,
+//! recent transactions and current goal, for a kitchen tablet or any other
+//! browser that shouldn't need the app. The page has its styles inline and
+//! no scripts or links, and asks the browser to reload itself every few
+//! minutes so a copy in a synced folder stays current.
+//!
+//! A parent writes the page on demand, or turns on automatic updates and the
+//! app's periodic refresh rewrites it while the app is running. Like exports,
+//! the page lives outside the data directory and is never encrypted.
+
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Local, Utc};
+use log::{info, warn};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::commands::fridge_view::{FridgeViewResult, UpdateFridgeViewCommand};
+use crate::backend::domain::commands::goal::GetCurrentGoalCommand;
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::mappers::TransactionMapper;
+use crate::backend::domain::localization::{DateStyle, Locale};
+use crate::backend::domain::models::currency::Currency;
+use crate::backend::domain::models::fridge_view::FridgeViewSettings;
+use crate::backend::domain::models::transaction::TransactionType;
+use crate::backend::domain::{GoalService, TransactionService};
+use crate::backend::storage::csv::{CsvConnection, GlobalConfigRepository};
+use crate::backend::storage::GlobalConfigStorage;
+
+/// How long an automatically updated page is left before it is rewritten;
+/// the page reloads itself at the same pace
+pub const FRIDGE_VIEW_UPDATE_MINUTES: u64 = 5;
+
+/// Most recent transactions shown for each child
+const RECENT_TRANSACTION_COUNT: usize = 8;
+
+/// Service for writing the read-only fridge view page
+#[derive(Clone)]
+pub struct FridgeViewService {
+    csv_connection: Arc<CsvConnection>,
+    global_config_repository: GlobalConfigRepository,
+    child_service: ChildService,
+    transaction_service: Arc<TransactionService>,
+    goal_service: Arc<GoalService>,
+}
+
+/// One child's card on the page
+struct ChildCard {
+    name: String,
+    balance: String,
+    goal: Option<GoalProgress>,
+    /// Date, description with the kind's emoji, formatted amount and the
+    /// amount's CSS classes for its direction and kind
+    recent: Vec<(String, String, String, String)>,
+}
+
+struct GoalProgress {
+    description: String,
+    saved: String,
+    target: String,
+    /// 0 to 100
+    percent: u32,
+}
+
+impl FridgeViewService {
+    /// Create a new FridgeViewService
+    pub fn new(
+        csv_conn: Arc<CsvConnection>,
+        child_service: ChildService,
+        transaction_service: Arc<TransactionService>,
+        goal_service: Arc<GoalService>,
+    ) -> Self {
+        Self {
+            global_config_repository: GlobalConfigRepository::new((*csv_conn).clone()),
+            csv_connection: csv_conn,
+            child_service,
+            transaction_service,
+            goal_service,
+        }
+    }
+
+    /// Where the page goes and whether it is kept up to date
+    pub fn get_settings(&self) -> Result<FridgeViewSettings> {
+        Ok(self.global_config_repository.get_global_config()?.fridge_view)
+    }
+
+    /// Choose the page's file and whether the periodic refresh rewrites it
+    ///
+    /// The folder the file goes in has to exist already.
+    pub fn update_settings(&self, command: UpdateFridgeViewCommand) -> Result<FridgeViewSettings> {
+        let path = command.path.map(|path| path.trim().to_string()).filter(|path| !path.is_empty());
+        if let Some(path) = &path {
+            let folder = Path::new(path).parent().filter(|folder| !folder.as_os_str().is_empty());
+            if !folder.is_some_and(Path::is_dir) {
+                return Err(DomainError::invalid(format!("Fridge view folder does not exist: {}", path)).into());
+            }
+        }
+        if command.auto_update && path.is_none() {
+            return Err(DomainError::invalid("Choose a file for the fridge view first").into());
+        }
+
+        let mut config = self.global_config_repository.get_global_config()?;
+        config.fridge_view = FridgeViewSettings { path, auto_update: command.auto_update };
+        config.updated_at = Utc::now().to_rfc3339();
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!(
+            "🧲 FRIDGE VIEW: Page set to {:?}, automatic updates {}",
+            config.fridge_view.path,
+            if config.fridge_view.auto_update { "on" } else { "off" }
+        );
+        Ok(config.fridge_view)
+    }
+
+    /// When the page file was last written, if it exists
+    pub fn last_written(&self) -> Result<Option<DateTime<Local>>> {
+        let Some(path) = self.get_settings()?.path else {
+            return Ok(None);
+        };
+        Ok(fs::metadata(path).and_then(|metadata| metadata.modified()).ok().map(DateTime::<Local>::from))
+    }
+
+    /// Write the page now, whatever the schedule says
+    pub fn generate(&self) -> Result<FridgeViewResult> {
+        let path = self
+            .get_settings()?
+            .path
+            .ok_or_else(|| DomainError::invalid("Choose a file for the fridge view first"))?;
+        self.write_page(&path, Utc::now())
+    }
+
+    /// Rewrite the page if automatic updates are on and it is due
+    ///
+    /// Called from the app's periodic refresh. Returns `None` when there is
+    /// nothing to do: updates are off, the page is recent enough, or the data
+    /// is read-only because another instance is keeping the page up to date.
+    pub fn run_scheduled(&self) -> Result<Option<FridgeViewResult>> {
+        let settings = self.get_settings()?;
+        let Some(path) = settings.path.filter(|_| settings.auto_update) else {
+            return Ok(None);
+        };
+        if self.csv_connection.read_only_source().is_some() {
+            return Ok(None);
+        }
+        let age = fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+        if age.is_some_and(|age| age < Duration::from_secs(FRIDGE_VIEW_UPDATE_MINUTES * 60)) {
+            return Ok(None);
+        }
+        self.write_page(&path, Utc::now()).map(Some)
+    }
+
+    fn write_page(&self, path: &str, now: DateTime<Utc>) -> Result<FridgeViewResult> {
+        let (html, child_count, skipped) = self.render_html(now)?;
+
+        // Write beside the page and swap it in, so a browser never loads half a file
+        let target = Path::new(path);
+        let temp_path = target.with_extension("html.tmp");
+        fs::write(&temp_path, html)?;
+        fs::rename(&temp_path, target)?;
+
+        info!("🧲 FRIDGE VIEW: Wrote {} children to {}", child_count, path);
+        Ok(FridgeViewResult {
+            path: path.to_string(),
+            child_count,
+            skipped,
+            generated_at: now.to_rfc3339(),
+        })
+    }
+
+    /// The page as HTML, how many children it shows and which were skipped
+    fn render_html(&self, now: DateTime<Utc>) -> Result<(String, usize, Vec<String>)> {
+        let config = self.global_config_repository.get_global_config()?;
+        let mut cards = Vec::new();
+        let mut skipped = Vec::new();
+        for child in self.child_service.list_children()?.children {
+            match self.child_card(&child.id, &child.name, config.currency, config.locale) {
+                Ok(card) => cards.push(card),
+                Err(e) => {
+                    warn!("🧲 FRIDGE VIEW: Skipping {}: {}", child.name, e);
+                    skipped.push(child.name);
+                }
+            }
+        }
+        let child_count = cards.len();
+        Ok((page_html(&cards, now.with_timezone(&Local), config.locale), child_count, skipped))
+    }
+
+    fn child_card(&self, child_id: &str, name: &str, family_currency: Currency, family_locale: Locale) -> Result<ChildCard> {
+        let settings = self.child_service.get_child_settings(child_id)?.settings;
+        let currency = settings.currency.unwrap_or(family_currency);
+        let locale = settings.locale.unwrap_or(family_locale);
+
+        let transactions: Vec<_> = self
+            .transaction_service
+            .list_all_transactions_for_child(child_id)?
+            .into_iter()
+            .filter(|t| t.transaction_type != TransactionType::FutureAllowance)
+            .collect();
+        let balance = transactions.last().map(|t| t.balance).unwrap_or(0.0);
+        let recent = transactions
+            .iter()
+            .rev()
+            .take(RECENT_TRANSACTION_COUNT)
+            .map(|t| {
+                let date = t.date.date_naive();
+                let description = match TransactionMapper::type_to_dto(&t.transaction_type).emoji() {
+                    Some(emoji) => format!("{} {}", emoji, t.description),
+                    None => t.description.clone(),
+                };
+                let direction = if t.amount >= 0.0 { "in" } else { "out" };
+                (
+                    locale.format_date(date.year(), date.month(), date.day(), DateStyle::Medium),
+                    description,
+                    currency.format(t.amount, locale),
+                    format!("{} {}", direction, t.transaction_type.storage_key()),
+                )
+            })
+            .collect();
+
+        let current = self.goal_service.get_current_goal(GetCurrentGoalCommand { child_id: Some(child_id.to_string()) })?;
+        let goal = current.goal.map(|goal| {
+            let saved = current.calculation.map_or(balance, |calculation| calculation.current_balance).max(0.0);
+            let percent = if goal.target_amount > 0.0 {
+                ((saved / goal.target_amount) * 100.0).clamp(0.0, 100.0).floor() as u32
+            } else {
+                100
+            };
+            GoalProgress {
+                description: goal.description,
+                saved: currency.format(saved.min(goal.target_amount), locale),
+                target: currency.format(goal.target_amount, locale),
+                percent,
+            }
+        });
+
+        Ok(ChildCard {
+            name: name.to_string(),
+            balance: currency.format(balance, locale),
+            goal,
+            recent,
+        })
+    }
+}
+
+const PAGE_STYLE: &str = "\
+body{margin:0;padding:24px;background:#fdf6ec;color:#333;font-family:-apple-system,'Segoe UI',Roboto,Helvetica,Arial,sans-serif}\
+h1{margin:0 0 20px;font-size:28px}\
+.cards{display:flex;flex-wrap:wrap;gap:20px}\
+.card{flex:1 1 320px;background:#fff;border-radius:16px;padding:20px;box-shadow:0 2px 8px rgba(0,0,0,.08)}\
+.card h2{margin:0;font-size:24px}\
+.balance{font-size:40px;font-weight:bold;margin:8px 0 16px}\
+.goal{margin-bottom:16px}\
+.bar{height:18px;background:#eee;border-radius:9px;overflow:hidden;margin:6px 0}\
+.fill{height:100%;background:#6cc04a}\
+.recent{width:100%;border-collapse:collapse;font-size:15px}\
+.recent td{padding:4px 0;border-top:1px solid #f0f0f0}\
+.recent .amount{text-align:right;white-space:nowrap}\
+.in{color:#2e7d32}.out{color:#c62828}\
+.bonus{color:#b8860b}.gift{color:#c71585}.interest{color:#00897b}.transfer{color:#4682b4}.adjustment{color:#707070}\
+.muted{color:#888}\
+footer{margin-top:24px;font-size:13px;color:#888}";
+
+/// The whole page, with each child's card
+fn page_html(cards: &[ChildCard], generated_at: DateTime<Local>, locale: Locale) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"");
+    html.push_str(locale.code());
+    html.push_str("\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    html.push_str(&format!("<meta http-equiv=\"refresh\" content=\"{}\">\n", FRIDGE_VIEW_UPDATE_MINUTES * 60));
+    html.push_str("<title>Allowance Tracker</title>\n<style>");
+    html.push_str(PAGE_STYLE);
+    html.push_str("</style>\n</head>\n<body>\n<h1>💰 Allowance Tracker</h1>\n<div class=\"cards\">\n");
+
+    if cards.is_empty() {
+        html.push_str("<p class=\"muted\">No children to show yet.</p>\n");
+    }
+    for card in cards {
+        html.push_str("<section class=\"card\">\n");
+        html.push_str(&format!("<h2>{}</h2>\n", escape_html(&card.name)));
+        html.push_str(&format!("<div class=\"balance\">{}</div>\n", escape_html(&card.balance)));
+
+        if let Some(goal) = &card.goal {
+            html.push_str(&format!(
+                "<div class=\"goal\">🎯 {}<div class=\"bar\"><div class=\"fill\" style=\"width:{}%\"></div></div>\
+                 <span class=\"muted\">{} of {} ({}%)</span></div>\n",
+                escape_html(&goal.description),
+                goal.percent,
+                escape_html(&goal.saved),
+                escape_html(&goal.target),
+                goal.percent
+            ));
+        }
+
+        if card.recent.is_empty() {
+            html.push_str("<p class=\"muted\">No transactions yet.</p>\n");
+        } else {
+            html.push_str("<table class=\"recent\">\n");
+            for (date, description, amount, classes) in &card.recent {
+                html.push_str(&format!(
+                    "<tr><td class=\"muted\">{}</td><td>{}</td><td class=\"amount {}\">{}</td></tr>\n",
+                    escape_html(date),
+                    escape_html(description),
+                    classes,
+                    escape_html(amount)
+                ));
+            }
+            html.push_str("</table>\n");
+        }
+        html.push_str("</section>\n");
+    }
+
+    let date = generated_at.date_naive();
+    html.push_str(&format!(
+        "</div>\n<footer>Last updated {} at {}</footer>\n</body>\n</html>\n",
+        locale.format_date(date.year(), date.month(), date.day(), DateStyle::Long),
+        generated_at.format("%H:%M")
+    ));
+    html
+}
+
+/// Text made safe to place in HTML
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand};
+    use crate::backend::domain::commands::goal::CreateGoalCommand;
+    use crate::backend::domain::commands::transactions::CreateTransactionCommand;
+    use crate::backend::domain::{AllowanceService, BalanceService};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_generate_writes_balance_goal_and_escaped_transactions() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let page_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let child_service = ChildService::new(connection.clone());
+        let allowance_service = AllowanceService::new(connection.clone());
+        let balance_service = BalanceService::new(connection.clone());
+        let transaction_service = Arc::new(TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            allowance_service.clone(),
+            balance_service.clone(),
+        ));
+        let goal_service = Arc::new(GoalService::new(
+            connection.clone(),
+            child_service.clone(),
+            allowance_service,
+            transaction_service.clone(),
+            balance_service,
+        ));
+        let service = FridgeViewService::new(connection, child_service.clone(), transaction_service.clone(), goal_service.clone());
+
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Alice & Bob".to_string(), birthdate: "2015-01-01".to_string() })?
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() })?;
+        transaction_service.create_transaction(CreateTransactionCommand {
+            child_id: None,
+            description: "<Lemonade> stand".to_string(),
+            amount: 5.0,
+            date: Some(DateTime::parse_from_rfc3339("2025-02-10T12:00:00Z")?),
+            parent_override: false,
+            actor: None,
+            icon: None,
+            transaction_type: None,
+        })?;
+        goal_service.create_goal(CreateGoalCommand {
+            child_id: None,
+            description: "Bike".to_string(),
+            target_amount: 20.0,
+        })?;
+
+        // Nothing to write to yet
+        assert!(service.generate().is_err());
+        assert!(service.run_scheduled()?.is_none());
+        assert!(service
+            .update_settings(UpdateFridgeViewCommand {
+                path: Some(page_dir.path().join("missing").join("fridge.html").to_string_lossy().to_string()),
+                auto_update: false,
+            })
+            .is_err());
+
+        let path = page_dir.path().join("fridge.html").to_string_lossy().to_string();
+        service.update_settings(UpdateFridgeViewCommand { path: Some(path.clone()), auto_update: true })?;
+
+        let result = service.run_scheduled()?.expect("a page that doesn't exist yet is due");
+        assert_eq!(result.child_count, 1);
+        let html = fs::read_to_string(&path)?;
+        assert!(html.contains("<h2>Alice &amp; Bob</h2>"));
+        assert!(html.contains("&lt;Lemonade&gt; stand"));
+        assert!(html.contains("<div class=\"balance\">$5.00</div>"));
+        assert!(html.contains("width:25%"));
+        assert!(!html.contains("<script"));
+
+        // Just written, so the schedule leaves it alone until it is due again
+        assert!(service.run_scheduled()?.is_none());
+        assert!(service.last_written()?.is_some());
+        assert_eq!(service.generate()?.path, path);
+        Ok(())
+    }
+}
@@ -9,6 +9,7 @@
 //! - **Goal CRUD**: Creating, reading, updating, and deleting goals
 //! - **Goal Calculations**: Projecting completion dates using future allowances
 //! - **State Management**: Handling goal lifecycle (active, cancelled, completed)
+//! - **Goal History**: How long past goals took and how close abandoned ones got
 //! - **Business Rules**: Enforcing goal validation and business constraints
 //! - **Integration**: Working with AllowanceService and TransactionService
 //!
@@ -21,25 +22,31 @@
 //! - Proper error handling for edge cases
 
 use anyhow::Result;
-use chrono::{Utc, Duration, Local, Datelike};
+use chrono::{DateTime, FixedOffset, Utc, Duration, Local};
 use log::{info, warn};
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::backend::storage::csv::{CsvConnection, GoalRepository};
+use crate::backend::storage::csv::{CelebrationRepository, CsvConnection, GoalRepository};
+use crate::backend::storage::CelebrationStorage;
 use crate::backend::domain::{child_service::ChildService, AllowanceService, TransactionService, BalanceService};
+use crate::backend::domain::models::celebration::Celebration;
 use crate::backend::domain::models::goal::{DomainGoal, DomainGoalState};
 use crate::backend::domain::commands::goal::{
     CreateGoalCommand, UpdateGoalCommand, GetCurrentGoalCommand, GetGoalHistoryCommand, CancelGoalCommand,
     CreateGoalResult, UpdateGoalResult, GetCurrentGoalResult, GetGoalHistoryResult, CancelGoalResult,
+    GetGoalStatisticsQuery, GetGoalStatisticsResult, GoalHistoryEntry, GoalStatisticsSummary,
 };
+use crate::backend::domain::models::transaction::{Transaction as DomainTransaction, TransactionType};
 use crate::backend::domain::commands::transactions::{TransactionListQuery};
+use crate::backend::domain::errors::DomainError;
 
 use shared::GoalCalculation;
 
 /// Service for managing goals and goal-related calculations
 pub struct GoalService {
     goal_repository: GoalRepository,
+    celebration_repository: CelebrationRepository,
     child_service: ChildService,
     allowance_service: AllowanceService,
     transaction_service: Arc<TransactionService>, // Use Arc for shared ownership
@@ -57,8 +64,10 @@ impl GoalService {
         balance_service: BalanceService,
     ) -> Self {
         let goal_repository = GoalRepository::new((*csv_conn).clone());
+        let celebration_repository = CelebrationRepository::new((*csv_conn).clone());
         Self {
             goal_repository,
+            celebration_repository,
             child_service,
             allowance_service,
             transaction_service, // Store Arc
@@ -72,15 +81,15 @@ impl GoalService {
 
         // Validate description
         if command.description.trim().is_empty() {
-            return Err(anyhow::anyhow!("Goal description cannot be empty"));
+            return Err(DomainError::invalid("Goal description cannot be empty").into());
         }
         if command.description.len() > 256 {
-            return Err(anyhow::anyhow!("Goal description cannot exceed 256 characters"));
+            return Err(DomainError::invalid("Goal description cannot exceed 256 characters").into());
         }
 
         // Validate target amount
         if command.target_amount <= 0.0 {
-            return Err(anyhow::anyhow!("Goal target amount must be positive"));
+            return Err(DomainError::invalid("Goal target amount must be positive").into());
         }
 
         // Get child ID
@@ -90,7 +99,7 @@ impl GoalService {
                 let active_child_response = self.child_service.get_active_child()?;
                 let child = match active_child_response.active_child.child {
                     Some(c) => c.id,
-                    None => return Err(anyhow::anyhow!("No active child found")),
+                    None => return Err(DomainError::NoActiveChild.into()),
                 };
                 child
             }
@@ -98,14 +107,14 @@ impl GoalService {
 
         // Check if child already has an active goal
         if self.goal_repository.has_active_goal(&child_id)? {
-            return Err(anyhow::anyhow!("Child already has an active goal. Cancel or complete the existing goal first."));
+            return Err(DomainError::Conflict("Child already has an active goal. Cancel or complete the existing goal first.".to_string()).into());
         }
 
         // Get current balance to validate goal is achievable
         let current_balance = self.get_current_balance(&child_id)?;
         if current_balance >= command.target_amount {
-            return Err(anyhow::anyhow!("Target amount (${:.2}) must be greater than current balance (${:.2})", 
-                                     command.target_amount, current_balance));
+            return Err(DomainError::invalid(format!("Target amount (${:.2}) must be greater than current balance (${:.2})", 
+                                     command.target_amount, current_balance)).into());
         }
 
         // Generate goal ID
@@ -191,7 +200,7 @@ impl GoalService {
                 let active_child_response = self.child_service.get_active_child()?;
                 let child = match active_child_response.active_child.child {
                     Some(c) => c.id,
-                    None => return Err(anyhow::anyhow!("No active child found to update goal")),
+                    None => return Err(DomainError::NoActiveChild.into()),
                 };
                 child
             }
@@ -200,30 +209,31 @@ impl GoalService {
         // Get current active goal (returns domain Goal)
         let mut current_goal_domain = match self.goal_repository.get_current_goal(&child_id)? {
             Some(goal) => goal,
-            None => return Err(anyhow::anyhow!("No active goal found to update")),
+            None => return Err(DomainError::NotFound("No active goal found to update".to_string()).into()),
         };
+        DomainError::check_not_stale("goal", command.expected_updated_at.as_deref(), &current_goal_domain.updated_at)?;
 
         // Update fields if provided
         if let Some(description) = command.description {
             if description.trim().is_empty() {
-                return Err(anyhow::anyhow!("Goal description cannot be empty"));
+                return Err(DomainError::invalid("Goal description cannot be empty").into());
             }
             if description.len() > 256 {
-                return Err(anyhow::anyhow!("Goal description cannot exceed 256 characters"));
+                return Err(DomainError::invalid("Goal description cannot exceed 256 characters").into());
             }
             current_goal_domain.description = description.trim().to_string();
         }
 
         if let Some(target_amount) = command.target_amount {
             if target_amount <= 0.0 {
-                return Err(anyhow::anyhow!("Goal target amount must be positive"));
+                return Err(DomainError::invalid("Goal target amount must be positive").into());
             }
             
             // Validate target amount is greater than current balance
             let current_balance = self.get_current_balance(&child_id)?;
             if current_balance >= target_amount {
-                return Err(anyhow::anyhow!("Target amount (${:.2}) must be greater than current balance (${:.2})", 
-                                         target_amount, current_balance));
+                return Err(DomainError::invalid(format!("Target amount (${:.2}) must be greater than current balance (${:.2})", 
+                                         target_amount, current_balance)).into());
             }
             
             current_goal_domain.target_amount = target_amount;
@@ -258,7 +268,7 @@ impl GoalService {
                 let active_child_response = self.child_service.get_active_child()?;
                 let child = match active_child_response.active_child.child {
                     Some(c) => c.id,
-                    None => return Err(anyhow::anyhow!("No active child found to cancel goal")),
+                    None => return Err(DomainError::NoActiveChild.into()),
                 };
                 child
             }
@@ -267,7 +277,7 @@ impl GoalService {
         // Cancel the goal (returns domain Goal)
         let cancelled_goal_domain = match self.goal_repository.cancel_current_goal(&child_id)? {
             Some(goal) => goal,
-            None => return Err(anyhow::anyhow!("No active goal found to cancel")),
+            None => return Err(DomainError::NotFound("No active goal found to cancel".to_string()).into()),
         };
 
         info!("Successfully cancelled goal: {}", cancelled_goal_domain.id);
@@ -306,6 +316,27 @@ impl GoalService {
         Ok(GetGoalHistoryResult { goals: goals_domain })
     }
 
+    /// Every goal a child has set, with how it went, plus totals for a goal history page
+    pub fn get_goal_statistics(&self, query: GetGoalStatisticsQuery) -> Result<GetGoalStatisticsResult> {
+        let child_id = match query.child_id {
+            Some(id) => id,
+            None => self
+                .child_service
+                .get_active_child()?
+                .active_child
+                .child
+                .map(|child| child.id)
+                .ok_or(DomainError::NoActiveChild)?,
+        };
+
+        let goals = self.goal_repository.list_goals(&child_id, None)?;
+        let transactions = self.transaction_service.list_all_transactions_for_child(&child_id)?;
+        let (goals, summary) = goal_statistics(goals, &transactions, Utc::now().fixed_offset());
+
+        info!("🎯 Goal statistics for {}: {} goals, completion rate {:?}", child_id, summary.total_goals, summary.completion_rate);
+        Ok(GetGoalStatisticsResult { child_id, goals, summary })
+    }
+
     /// Check if current balance meets any active goal and auto-complete if so
     pub fn check_and_complete_goals(&self, child_id: &str) -> Result<Option<DomainGoal>> {
         info!("Checking for goal completion for child: {}", child_id);
@@ -330,6 +361,13 @@ impl GoalService {
             // Mark goal as completed (returns domain Goal)
             let completed_goal_domain = self.goal_repository.complete_current_goal(child_id)?;
             
+            if let Some(goal) = &completed_goal_domain {
+                let celebration = Celebration::goal_completed(child_id, &goal.description, goal.target_amount);
+                if let Err(e) = self.celebration_repository.record_celebration(&celebration) {
+                    warn!("Failed to record celebration for goal {}: {}", goal.id, e);
+                }
+            }
+            
             return Ok(completed_goal_domain);
         }
 
@@ -352,6 +390,7 @@ impl GoalService {
         
         // Get all transactions since goal creation
         let query = TransactionListQuery {
+            child_id: Some(goal.child_id.clone()),
             after: None,
             limit: Some(1000), // Reasonable limit
             start_date: Some(goal_creation_date.and_hms_opt(0, 0, 0).unwrap().and_utc().to_rfc3339()),
@@ -503,13 +542,11 @@ impl GoalService {
 
         // Find the next allowance dates
         for _ in 0..365 { // Limit to 1 year
-            if projected_date > current_date {
-                let day_of_week = projected_date.weekday().num_days_from_sunday() as u8;
-                if day_of_week == config.day_of_week {
-                    allowances_counted += 1;
-                    if allowances_counted >= allowances_needed {
-                        break;
-                    }
+            // Follows the allowance schedule, weekly or monthly
+            if projected_date > current_date && config.amount_due_on(projected_date).is_some() {
+                allowances_counted += 1;
+                if allowances_counted >= allowances_needed {
+                    break;
                 }
             }
             
@@ -548,9 +585,10 @@ impl GoalService {
     }
 
     /// Get current balance for a child
-    fn get_current_balance(&self, _child_id: &str) -> Result<f64> {
+    fn get_current_balance(&self, child_id: &str) -> Result<f64> {
         // Get the latest transaction to get current balance
         let query = TransactionListQuery {
+            child_id: Some(child_id.to_string()),
             after: None,
             limit: Some(1),
             start_date: None,
@@ -566,6 +604,79 @@ impl GoalService {
     }
 }
 
+/// Work out how each goal went from the transactions around it.
+///
+/// A completed goal ends with the first transaction that brought the balance
+/// up to its target, which also works for goals completed before completion
+/// times were recorded. An abandoned goal ends when it was cancelled, and the
+/// active goal is measured up to `now`.
+fn goal_statistics(
+    goals: Vec<DomainGoal>,
+    transactions: &[DomainTransaction],
+    now: DateTime<FixedOffset>,
+) -> (Vec<GoalHistoryEntry>, GoalStatisticsSummary) {
+    let real: Vec<&DomainTransaction> = transactions
+        .iter()
+        .filter(|t| t.transaction_type != TransactionType::FutureAllowance)
+        .collect();
+    let balance_at = |moment: DateTime<FixedOffset>| {
+        real.iter().take_while(|t| t.date <= moment).last().map_or(0.0, |t| t.balance)
+    };
+    let parse = |value: &str| DateTime::parse_from_rfc3339(value).ok();
+
+    let entries: Vec<GoalHistoryEntry> = goals
+        .into_iter()
+        .map(|goal| {
+            let started = parse(&goal.created_at).unwrap_or(now);
+            let ended = match goal.state {
+                DomainGoalState::Active => now,
+                DomainGoalState::Completed => real
+                    .iter()
+                    .find(|t| t.date >= started && t.balance >= goal.target_amount)
+                    .map(|t| t.date)
+                    .or_else(|| parse(&goal.updated_at))
+                    .unwrap_or(started),
+                DomainGoalState::Cancelled => parse(&goal.updated_at).unwrap_or(started),
+            }
+            .max(started);
+            let starting_balance = balance_at(started);
+            let ending_balance = balance_at(ended);
+            let percent_of_target = if goal.target_amount > 0.0 {
+                round_to(ending_balance / goal.target_amount * 100.0, 1).clamp(0.0, 100.0)
+            } else {
+                0.0
+            };
+            GoalHistoryEntry {
+                days_taken: (ended - started).num_days(),
+                starting_balance,
+                ending_balance,
+                saved: round_to(ending_balance - starting_balance, 2),
+                percent_of_target,
+                goal,
+            }
+        })
+        .collect();
+
+    let average = |values: Vec<f64>| (!values.is_empty()).then(|| round_to(values.iter().sum::<f64>() / values.len() as f64, 1));
+    let completed: Vec<&GoalHistoryEntry> = entries.iter().filter(|e| e.goal.state == DomainGoalState::Completed).collect();
+    let abandoned: Vec<&GoalHistoryEntry> = entries.iter().filter(|e| e.goal.state == DomainGoalState::Cancelled).collect();
+    let finished = completed.len() + abandoned.len();
+    let summary = GoalStatisticsSummary {
+        total_goals: entries.len(),
+        completed_count: completed.len(),
+        abandoned_count: abandoned.len(),
+        completion_rate: (finished > 0).then(|| round_to(completed.len() as f64 / finished as f64 * 100.0, 1)),
+        average_days_to_complete: average(completed.iter().map(|e| e.days_taken as f64).collect()),
+        average_percent_when_abandoned: average(abandoned.iter().map(|e| e.percent_of_target).collect()),
+    };
+    (entries, summary)
+}
+
+fn round_to(value: f64, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    (value * factor).round() / factor
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -613,9 +724,14 @@ mod tests {
 
         // Add initial money transaction to give child starting balance
         let initial_money_cmd = CreateTransactionCommand {
+            child_id: None,
             description: "Starting allowance".to_string(),
             amount: 5.0,
             date: None,
+            parent_override: false,
+            actor: None,
+            icon: None,
+            transaction_type: None,
         };
         service.transaction_service.as_ref().create_transaction_domain(initial_money_cmd)
             .expect("Failed to create initial transaction");
@@ -626,6 +742,12 @@ mod tests {
             amount: 5.0,
             day_of_week: 0, // Sunday
             is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
         service.allowance_service.update_allowance_config(create_allowance_cmd).expect("Failed to create allowance");
 
@@ -660,9 +782,14 @@ mod tests {
         use shared::AddMoneyRequest;
         let money_service = MoneyManagementService::new();
         let add_money_request = AddMoneyRequest {
+            child_id: None,
             description: "Gift money".to_string(),
             amount: amount_to_add,
             date: None,
+            amount_expression: None,
+            actor: None,
+            icon: None,
+            transaction_type: None,
         };
         
         let _response = money_service.add_money_complete(
@@ -806,4 +933,97 @@ mod tests {
         assert!(result.calculation.projected_completion_date.is_some());
         assert!(!result.calculation.exceeds_time_limit);
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_goal_calculation_follows_biweekly_allowance() {
+        use crate::backend::domain::models::allowance::AllowanceFrequency;
+
+        let service = create_test_service();
+        let child_id = create_test_child_and_allowance(&service);
+        let first_payday = Local::now().date_naive() + Duration::days(1);
+        service
+            .allowance_service
+            .update_allowance_config(UpdateAllowanceConfigCommand {
+                child_id: Some(child_id.clone()),
+                amount: 5.0,
+                day_of_week: 0,
+                is_active: true,
+                day_change_policy: None,
+                frequency: Some(AllowanceFrequency::EveryNWeeks),
+                day_of_month: None,
+                interval_weeks: Some(2),
+                anchor_date: Some(first_payday),
+                expected_updated_at: None,
+            })
+            .expect("Failed to switch to a biweekly allowance");
+
+        // Four allowances two weeks apart, the first tomorrow
+        let balance = service.get_current_balance(&child_id).unwrap();
+        let calculation = service.calculate_goal_completion(&child_id, balance + 20.0).unwrap();
+        assert_eq!(calculation.allowances_needed, 4);
+        let expected = (first_payday + Duration::weeks(6)).format("%Y-%m-%d").to_string();
+        assert!(calculation.projected_completion_date.unwrap().starts_with(&expected));
+        assert!(calculation.is_achievable);
+    }
+
+    #[test]
+    fn test_goal_statistics_measure_completed_and_abandoned_goals() {
+        let at = |value: &str| DateTime::parse_from_rfc3339(value).unwrap();
+        let transaction = |date: &str, amount: f64, balance: f64| DomainTransaction {
+            id: format!("t-{}", date),
+            child_id: "child".to_string(),
+            date: at(date),
+            description: "Chores".to_string(),
+            amount,
+            balance,
+            transaction_type: if amount >= 0.0 { TransactionType::Income } else { TransactionType::Expense },
+            actor: None,
+            category: None,
+            icon: None,
+        };
+        let goal = |id: &str, target_amount: f64, state: DomainGoalState, created_at: &str, updated_at: &str| DomainGoal {
+            id: id.to_string(),
+            child_id: "child".to_string(),
+            description: id.to_string(),
+            target_amount,
+            state,
+            created_at: created_at.to_string(),
+            updated_at: updated_at.to_string(),
+        };
+        let transactions = vec![
+            transaction("2024-01-01T12:00:00Z", 5.0, 5.0),
+            transaction("2024-01-08T12:00:00Z", 10.0, 15.0),
+            transaction("2024-01-20T12:00:00Z", 10.0, 25.0),
+            transaction("2024-02-10T12:00:00Z", -20.0, 5.0),
+            transaction("2024-02-20T12:00:00Z", 10.0, 15.0),
+        ];
+        let goals = vec![
+            goal("kite", 30.0, DomainGoalState::Active, "2024-03-01T00:00:00Z", "2024-03-01T00:00:00Z"),
+            goal("game", 40.0, DomainGoalState::Cancelled, "2024-02-11T00:00:00Z", "2024-02-25T00:00:00Z"),
+            // Completed before completion times were recorded, so the Jan 20 balance marks the end
+            goal("bike", 20.0, DomainGoalState::Completed, "2024-01-02T00:00:00Z", "2024-01-02T00:00:00Z"),
+        ];
+
+        let (entries, summary) = goal_statistics(goals, &transactions, at("2024-03-11T00:00:00Z"));
+
+        let kite = &entries[0];
+        assert_eq!((kite.days_taken, kite.saved, kite.percent_of_target), (10, 0.0, 50.0));
+        let game = &entries[1];
+        assert_eq!((game.days_taken, game.saved, game.percent_of_target), (14, 10.0, 37.5));
+        let bike = &entries[2];
+        assert_eq!((bike.days_taken, bike.starting_balance, bike.ending_balance), (18, 5.0, 25.0));
+        assert_eq!((bike.saved, bike.percent_of_target), (20.0, 100.0));
+
+        assert_eq!(summary, GoalStatisticsSummary {
+            total_goals: 3,
+            completed_count: 1,
+            abandoned_count: 1,
+            completion_rate: Some(50.0),
+            average_days_to_complete: Some(18.0),
+            average_percent_when_abandoned: Some(37.5),
+        });
+
+        let (_, empty) = goal_statistics(Vec::new(), &transactions, at("2024-03-11T00:00:00Z"));
+        assert_eq!((empty.completion_rate, empty.average_days_to_complete), (None, None));
+    }
+}
@@ -0,0 +1,162 @@
+//! Health service domain logic for the allowance tracker.
+//!
+//! Answers "is the backend able to do its job?" for frontends and scripts:
+//! the data directory can be read and written, and every config file is at a
+//! schema version this build understands. Also reports which build is
+//! running, so a problem can be matched to a version.
+
+use anyhow::Result;
+use log::{info, warn};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::backend::domain::commands::health::{HealthReport, SchemaVersionCheck, VersionInfo};
+use crate::backend::storage::csv::migrations::schema_versions;
+use crate::backend::storage::csv::{CsvConnection, ReadOnlyError};
+
+/// File written and removed again to prove the data directory is writable
+const WRITE_PROBE_FILE: &str = ".health_check";
+
+/// Service for backend health and version checks
+#[derive(Clone)]
+pub struct HealthService {
+    csv_connection: Arc<CsvConnection>,
+}
+
+impl HealthService {
+    /// Create a new HealthService
+    pub fn new(csv_connection: Arc<CsvConnection>) -> Self {
+        Self { csv_connection }
+    }
+
+    /// Check that storage is reachable, writable and at a known schema
+    pub fn check_health(&self) -> Result<HealthReport> {
+        let base_dir = self.csv_connection.base_directory();
+        let mut problems = Vec::new();
+
+        let storage_reachable = match fs::read_dir(&base_dir) {
+            Ok(_) => true,
+            Err(e) => {
+                problems.push(format!("Data directory {} can't be read: {}", base_dir.display(), e));
+                false
+            }
+        };
+
+        // Read-only mode is a choice, not a fault, so nothing is probed then
+        let read_only_reason = self
+            .csv_connection
+            .read_only_source()
+            .map(|enabled_by| ReadOnlyError { enabled_by }.to_string());
+        let writable = match (&read_only_reason, storage_reachable) {
+            (Some(_), _) | (None, false) => false,
+            (None, true) => match probe_write(&base_dir) {
+                Ok(()) => true,
+                Err(e) => {
+                    problems.push(format!("Data directory {} isn't writable: {}", base_dir.display(), e));
+                    false
+                }
+            },
+        };
+
+        let schema_versions: Vec<SchemaVersionCheck> = if storage_reachable {
+            schema_versions(&self.csv_connection)?
+                .into_iter()
+                .map(|status| SchemaVersionCheck {
+                    file: status
+                        .file
+                        .strip_prefix(&base_dir)
+                        .unwrap_or(&status.file)
+                        .to_string_lossy()
+                        .to_string(),
+                    version: status.version,
+                    current_version: status.current_version,
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        for check in &schema_versions {
+            match check.version {
+                None => problems.push(format!("{} can't be read", check.file)),
+                Some(version) if version != check.current_version => problems.push(format!(
+                    "{} is at schema version {}; this build uses {}",
+                    check.file, version, check.current_version
+                )),
+                Some(_) => {}
+            }
+        }
+
+        if problems.is_empty() {
+            info!("🩺 HEALTH: OK ({} config files checked)", schema_versions.len());
+        } else {
+            warn!("🩺 HEALTH: {} problem(s): {}", problems.len(), problems.join("; "));
+        }
+        Ok(HealthReport {
+            data_directory: base_dir.to_string_lossy().to_string(),
+            storage_reachable,
+            writable,
+            read_only_reason,
+            schema_versions,
+            problems,
+        })
+    }
+
+    /// Which build of the backend is running
+    pub fn version_info(&self) -> VersionInfo {
+        VersionInfo {
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            git_commit: option_env!("ALLOWANCE_TRACKER_GIT_COMMIT").map(str::to_string),
+            storage_backend: "csv".to_string(),
+        }
+    }
+}
+
+/// Write and remove a small file in `dir`
+fn probe_write(dir: &Path) -> std::io::Result<()> {
+    let probe = dir.join(WRITE_PROBE_FILE);
+    fs::write(&probe, b"ok")?;
+    fs::remove_file(&probe)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::child_service::ChildService;
+    use crate::backend::domain::commands::child::CreateChildCommand;
+    use crate::backend::storage::csv::ReadOnlySource;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_health_reports_schema_versions_and_read_only_mode() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        ChildService::new(connection.clone())
+            .create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })?;
+        let service = HealthService::new(connection.clone());
+
+        let report = service.check_health()?;
+        assert!(report.is_healthy(), "{:?}", report.problems);
+        assert!(report.storage_reachable && report.writable);
+        assert!(!temp_dir.path().join(WRITE_PROBE_FILE).exists());
+        let child = report.schema_versions.iter().find(|c| c.file.ends_with("child.yaml")).unwrap();
+        assert_eq!(child.version, Some(child.current_version));
+
+        // A config from a newer build is a problem
+        let child_yaml = temp_dir.path().join(&child.file);
+        let content = fs::read_to_string(&child_yaml)?;
+        fs::write(&child_yaml, content.replace("schema_version: 1", "schema_version: 9"))?;
+        let report = service.check_health()?;
+        assert!(!report.is_healthy());
+        assert!(report.problems[0].contains("schema version 9"));
+
+        // Read-only on purpose isn't a problem, but it isn't writable either
+        fs::write(&child_yaml, content)?;
+        connection.set_read_only(Some(ReadOnlySource::Settings));
+        let report = service.check_health()?;
+        assert!(report.is_healthy());
+        assert!(!report.writable);
+        assert!(report.read_only_reason.is_some());
+        Ok(())
+    }
+}
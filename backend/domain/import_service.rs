@@ -0,0 +1,647 @@
+//! Import service domain logic for the allowance tracker.
+//!
+//! Imports transactions from bank or card statements: CSVs, read through a
+//! column mapping because every bank lays its export out differently, and the
+//! OFX and QIF files that banks and kids' debit cards export. A statement is
+//! previewed first, with each row's problems and any row already in the
+//! child's history flagged, and only the rows the parent accepts are
+//! imported. Categories named in the statement can be mapped to the family's
+//! own on the way in.
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use csv::{ReaderBuilder, StringRecord};
+use log::info;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+use crate::backend::domain::commands::import::{
+    ImportRow, ImportStatementCommand, ImportStatementResult, PreviewStatementCommand, SignConvention,
+    StatementColumnMapping, StatementFormat, StatementPreview, StatementRow,
+};
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::models::transaction::Transaction as DomainTransaction;
+use crate::backend::domain::transaction_service::TransactionService;
+
+/// Longest description a transaction can have
+const MAX_DESCRIPTION_LENGTH: usize = 256;
+
+/// Service for importing statements as transactions
+#[derive(Clone)]
+pub struct ImportService {
+    transaction_service: Arc<TransactionService>,
+}
+
+impl ImportService {
+    /// Create a new ImportService
+    pub fn new(transaction_service: Arc<TransactionService>) -> Self {
+        Self { transaction_service }
+    }
+
+    /// Parse a statement and compare it with the active child's history,
+    /// without importing anything
+    pub fn preview_statement(&self, command: PreviewStatementCommand) -> Result<StatementPreview> {
+        let mut preview = parse_statement(&command.content, &command.format)?;
+        let child = self.transaction_service.get_active_child()?;
+        let history = self.transaction_service.list_all_transactions_for_child(&child.id)?;
+        mark_duplicates(&mut preview.rows, &history);
+        preview.known_categories = known_categories(&history);
+        Ok(preview)
+    }
+
+    /// Import the accepted rows of a statement for the active child
+    ///
+    /// The statement is parsed again, so a row is only imported if it is
+    /// still valid; accepted rows that aren't are reported as skipped.
+    /// Accepted duplicates are imported, since only the parent can tell two
+    /// identical purchases from one seen twice.
+    pub fn import_statement(&self, command: ImportStatementCommand) -> Result<ImportStatementResult> {
+        if command.accepted_lines.is_empty() {
+            return Err(DomainError::invalid("Choose at least one row to import").into());
+        }
+        let preview = parse_statement(&command.content, &command.format)?;
+
+        let mut rows = Vec::new();
+        let mut skipped = Vec::new();
+        for line in &command.accepted_lines {
+            match preview.rows.iter().find(|row| row.line == *line) {
+                None => skipped.push((*line, "No such row in the statement".to_string())),
+                Some(row) => match (row.date, row.amount, row.is_valid()) {
+                    (Some(date), Some(amount), true) => rows.push(ImportRow {
+                        date,
+                        description: row.description.clone(),
+                        amount,
+                        category: map_category(row.category.clone(), &command.category_mapping),
+                    }),
+                    _ => skipped.push((*line, row.errors.join("; "))),
+                },
+            }
+        }
+
+        let imported = self.transaction_service.import_transactions(rows, command.actor)?;
+        info!("📥 IMPORT: {} rows imported, {} skipped", imported.len(), skipped.len());
+        Ok(ImportStatementResult { imported, skipped })
+    }
+}
+
+/// Parse every transaction of a statement in the given format
+fn parse_statement(content: &str, format: &StatementFormat) -> Result<StatementPreview> {
+    // Spreadsheet programs on Windows like to start files with a byte order mark
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let mut preview = match format {
+        StatementFormat::Csv(mapping) => parse_csv_statement(content, mapping)?,
+        StatementFormat::Ofx => parse_ofx_statement(content)?,
+        StatementFormat::Qif => parse_qif_statement(content)?,
+    };
+    let categories: BTreeSet<&String> = preview.rows.iter().filter_map(|row| row.category.as_ref()).collect();
+    preview.categories = categories.into_iter().cloned().collect();
+    Ok(preview)
+}
+
+/// The line, from 1, that `byte` falls on
+fn line_at_byte(content: &str, byte: usize) -> usize {
+    content.as_bytes()[..byte.min(content.len())].iter().filter(|b| **b == b'\n').count() + 1
+}
+
+/// Parse every data row of a statement CSV
+fn parse_csv_statement(content: &str, mapping: &StatementColumnMapping) -> Result<StatementPreview> {
+    if mapping.date_format.trim().is_empty() {
+        return Err(DomainError::invalid("Enter the date format used in the statement").into());
+    }
+    let mut reader = ReaderBuilder::new()
+        .has_headers(mapping.has_header_row)
+        .delimiter(mapping.delimiter)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes());
+
+    let headers = if mapping.has_header_row {
+        let headers = reader
+            .headers()
+            .map_err(|e| DomainError::invalid(format!("The header row can't be read: {}", e)))?;
+        Some(headers.iter().map(str::to_string).collect())
+    } else {
+        None
+    };
+
+    // The reader's own line count skips blank lines, and a record's offset
+    // is where the blank lines before it start, so count past them
+    let line_at = |position: Option<&csv::Position>| {
+        position.map_or(0, |p| {
+            let bytes = content.as_bytes();
+            let mut start = p.byte() as usize;
+            while start < bytes.len() && matches!(bytes[start], b'\r' | b'\n') {
+                start += 1;
+            }
+            line_at_byte(content, start)
+        })
+    };
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let row = match record {
+            Ok(record) => {
+                let line = line_at(record.position());
+                // Spreadsheets often leave empty lines at the end
+                if record.iter().all(str::is_empty) {
+                    continue;
+                }
+                parse_csv_row(line, &record, mapping)
+            }
+            Err(e) => finish_row(
+                line_at(e.position()),
+                None,
+                String::new(),
+                None,
+                None,
+                vec![format!("The row can't be read: {}", e)],
+            ),
+        };
+        rows.push(row);
+    }
+    Ok(StatementPreview { headers, rows, categories: Vec::new(), known_categories: Vec::new() })
+}
+
+fn parse_csv_row(line: usize, record: &StringRecord, mapping: &StatementColumnMapping) -> StatementRow {
+    let mut errors = Vec::new();
+    let mut column = |index: usize, name: &str| -> Option<String> {
+        let value = record.get(index).map(str::to_string);
+        if value.is_none() {
+            errors.push(format!("No {} column (column {} of {})", name, index + 1, record.len()));
+        }
+        value
+    };
+    let date_text = column(mapping.date_column, "date");
+    let description = column(mapping.description_column, "description").unwrap_or_default();
+    let amount_text = column(mapping.amount_column, "amount");
+    let category = mapping
+        .category_column
+        .and_then(|index| column(index, "category"))
+        .filter(|category| !category.is_empty());
+
+    let date = date_text.and_then(|text| match NaiveDate::parse_from_str(&text, &mapping.date_format) {
+        Ok(date) => Some(date),
+        Err(_) => {
+            errors.push(format!("\"{}\" doesn't match the date format {}", text, mapping.date_format));
+            None
+        }
+    });
+    let amount = amount_text.and_then(|text| match parse_statement_amount(&text) {
+        Some(amount) => Some(match mapping.sign_convention {
+            SignConvention::NegativeIsSpending => amount,
+            SignConvention::PositiveIsSpending => -amount,
+        }),
+        None => {
+            errors.push(format!("\"{}\" isn't an amount", text));
+            None
+        }
+    });
+    finish_row(line, date, description, amount, category, errors)
+}
+
+/// Parse the transactions of an OFX file
+///
+/// Handles both the SGML flavour of OFX 1.x, where tags often aren't closed,
+/// and the XML of OFX 2.x. OFX amounts are always negative for money spent,
+/// and OFX has no categories.
+fn parse_ofx_statement(content: &str) -> Result<StatementPreview> {
+    let upper = content.to_ascii_uppercase();
+    if !upper.contains("<OFX>") {
+        return Err(DomainError::invalid("This doesn't look like an OFX file").into());
+    }
+
+    let mut rows = Vec::new();
+    let mut search_from = 0;
+    while let Some(found) = upper[search_from..].find("<STMTTRN>") {
+        let start = search_from + found;
+        let end = upper[start..].find("</STMTTRN>").map_or(content.len(), |end| start + end);
+        let block = &content[start..end];
+        search_from = end.max(start + 1);
+
+        let mut errors = Vec::new();
+        let date = match ofx_field(block, "DTPOSTED") {
+            None => {
+                errors.push("No DTPOSTED date".to_string());
+                None
+            }
+            // YYYYMMDD, usually followed by a time and time zone
+            Some(text) => match text.get(..8).and_then(|day| NaiveDate::parse_from_str(day, "%Y%m%d").ok()) {
+                Some(date) => Some(date),
+                None => {
+                    errors.push(format!("\"{}\" isn't an OFX date", text));
+                    None
+                }
+            },
+        };
+        let amount = match ofx_field(block, "TRNAMT") {
+            None => {
+                errors.push("No TRNAMT amount".to_string());
+                None
+            }
+            Some(text) => parse_statement_amount(&text).or_else(|| {
+                errors.push(format!("\"{}\" isn't an amount", text));
+                None
+            }),
+        };
+        let description = ofx_field(block, "NAME").or_else(|| ofx_field(block, "MEMO")).unwrap_or_default();
+        rows.push(finish_row(line_at_byte(content, start), date, description, amount, None, errors));
+    }
+    Ok(StatementPreview { headers: None, rows, categories: Vec::new(), known_categories: Vec::new() })
+}
+
+/// The value of `<TAG>` inside an OFX block, up to the next tag or line end
+fn ofx_field(block: &str, tag: &str) -> Option<String> {
+    let open = format!("<{}>", tag);
+    let start = block.to_ascii_uppercase().find(&open)? + open.len();
+    let rest = &block[start..];
+    let end = rest.find(['<', '\r', '\n']).unwrap_or(rest.len());
+    let value = rest[..end]
+        .trim()
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&");
+    (!value.is_empty()).then_some(value)
+}
+
+/// Parse the transactions of a QIF file
+///
+/// Each transaction is a run of lines starting with a field code and ends
+/// with `^`. Quicken writes dates month first, with a two-digit year after an
+/// apostrophe for 2000 onwards ("3/14'25"); those forms are all accepted.
+fn parse_qif_statement(content: &str) -> Result<StatementPreview> {
+    let looks_like_qif = content.trim_start().starts_with('!') || content.lines().any(|line| line.trim() == "^");
+    if !looks_like_qif {
+        return Err(DomainError::invalid("This doesn't look like a QIF file").into());
+    }
+
+    let mut rows = Vec::new();
+    let mut fields: Vec<(char, String)> = Vec::new();
+    let mut record_line = 0;
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        let Some(code) = line.chars().next() else {
+            continue;
+        };
+        match code {
+            // Headers such as !Type:Bank or !Account
+            '!' => fields.clear(),
+            '^' => {
+                if !fields.is_empty() {
+                    rows.push(qif_row(record_line, &fields));
+                }
+                fields.clear();
+            }
+            _ => {
+                if fields.is_empty() {
+                    record_line = index + 1;
+                }
+                fields.push((code, line[code.len_utf8()..].trim().to_string()));
+            }
+        }
+    }
+    // The last transaction doesn't always get its ^
+    if !fields.is_empty() {
+        rows.push(qif_row(record_line, &fields));
+    }
+    Ok(StatementPreview { headers: None, rows, categories: Vec::new(), known_categories: Vec::new() })
+}
+
+fn qif_row(line: usize, fields: &[(char, String)]) -> StatementRow {
+    let field = |code: char| fields.iter().find(|(c, value)| *c == code && !value.is_empty()).map(|(_, value)| value.clone());
+    let mut errors = Vec::new();
+
+    let date = match field('D') {
+        None => {
+            errors.push("No D date line".to_string());
+            None
+        }
+        Some(text) => parse_qif_date(&text).or_else(|| {
+            errors.push(format!("\"{}\" isn't a QIF date", text));
+            None
+        }),
+    };
+    // U is the same amount written with more precision by newer Quicken versions
+    let amount = match field('T').or_else(|| field('U')) {
+        None => {
+            errors.push("No T amount line".to_string());
+            None
+        }
+        Some(text) => parse_statement_amount(&text).or_else(|| {
+            errors.push(format!("\"{}\" isn't an amount", text));
+            None
+        }),
+    };
+    let description = field('P').or_else(|| field('M')).unwrap_or_default();
+    finish_row(line, date, description, amount, field('L'), errors)
+}
+
+/// Read a QIF date: "03/14/2025", "3/14/25", "3/14'25", " 3/ 4/25" or "2025-03-14"
+fn parse_qif_date(text: &str) -> Option<NaiveDate> {
+    let normalized: String = text.chars().filter(|c| !c.is_whitespace()).map(|c| if c == '\'' { '/' } else { c }).collect();
+    let parts: Vec<&str> = normalized.split(['/', '-', '.']).collect();
+    let [first, second, third] = parts.as_slice() else {
+        return None;
+    };
+    let (year, month, day) = if first.len() == 4 { (first, second, third) } else { (third, first, second) };
+    let mut year: i32 = year.parse().ok()?;
+    if year < 100 {
+        year += if year < 70 { 2000 } else { 1900 };
+    }
+    NaiveDate::from_ymd_opt(year, month.parse().ok()?, day.parse().ok()?)
+}
+
+/// Build a row from its parsed fields, adding the checks every format shares
+fn finish_row(
+    line: usize,
+    date: Option<NaiveDate>,
+    description: String,
+    amount: Option<f64>,
+    category: Option<String>,
+    mut errors: Vec<String>,
+) -> StatementRow {
+    let amount = match amount {
+        Some(0.0) => {
+            errors.push("Amount is zero".to_string());
+            None
+        }
+        amount => amount,
+    };
+    if description.is_empty() {
+        errors.push("Description is empty".to_string());
+    } else if description.chars().count() > MAX_DESCRIPTION_LENGTH {
+        errors.push(format!("Description is longer than {} characters", MAX_DESCRIPTION_LENGTH));
+    }
+    StatementRow { line, date, description, amount, category, duplicate_of: None, errors }
+}
+
+/// Flag rows matching a transaction already in `history`
+///
+/// A match has the same day, amount and description (ignoring case). Each
+/// existing transaction matches one row at most, so a statement with two
+/// identical purchases against a history with one flags only the first.
+fn mark_duplicates(rows: &mut [StatementRow], history: &[DomainTransaction]) {
+    let key = |date: NaiveDate, amount: f64, description: &str| {
+        (date, (amount * 100.0).round() as i64, description.trim().to_lowercase())
+    };
+    let mut existing: HashMap<_, Vec<&str>> = HashMap::new();
+    for transaction in history {
+        existing
+            .entry(key(transaction.date.date_naive(), transaction.amount, &transaction.description))
+            .or_default()
+            .push(&transaction.id);
+    }
+    for row in rows.iter_mut() {
+        if let (Some(date), Some(amount)) = (row.date, row.amount) {
+            if let Some(ids) = existing.get_mut(&key(date, amount, &row.description)) {
+                row.duplicate_of = ids.pop().map(str::to_string);
+            }
+        }
+    }
+}
+
+/// Distinct categories used in `history`, alphabetically
+fn known_categories(history: &[DomainTransaction]) -> Vec<String> {
+    let mut categories: Vec<String> = history
+        .iter()
+        .filter_map(|transaction| transaction.category.clone())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    categories.sort_by_key(|category| category.to_lowercase());
+    categories
+}
+
+/// The category to record for a statement category
+fn map_category(category: Option<String>, mapping: &HashMap<String, String>) -> Option<String> {
+    let category = category?;
+    let mapped = mapping.get(&category).cloned().unwrap_or(category);
+    let mapped = mapped.trim();
+    (!mapped.is_empty()).then(|| mapped.to_string())
+}
+
+/// Read an amount as banks write them: "$1,234.50", "-12.00" or "(12.00)"
+fn parse_statement_amount(text: &str) -> Option<f64> {
+    let text = text.trim();
+    let (negative, text) = match text.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+        Some(inner) => (true, inner),
+        None => (false, text),
+    };
+    let cleaned: String = text.chars().filter(|c| !matches!(c, '$' | '€' | '£' | ',' | ' ')).collect();
+    let amount: f64 = cleaned.parse().ok().filter(|amount: &f64| amount.is_finite())?;
+    let amount = (amount * 100.0).round() / 100.0;
+    Some(if negative { -amount.abs() } else { amount })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand};
+    use crate::backend::domain::child_service::ChildService;
+    use crate::backend::domain::{AllowanceService, BalanceService};
+    use crate::backend::storage::csv::CsvConnection;
+    use tempfile::TempDir;
+
+    fn mapping() -> StatementColumnMapping {
+        StatementColumnMapping {
+            date_column: 0,
+            description_column: 1,
+            amount_column: 2,
+            sign_convention: SignConvention::NegativeIsSpending,
+            date_format: "%m/%d/%Y".to_string(),
+            has_header_row: true,
+            delimiter: b',',
+            category_column: None,
+        }
+    }
+
+    /// An import service with an active child, and the balance service to check it
+    fn setup() -> Result<(ImportService, BalanceService, String, TempDir)> {
+        let temp_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let child_service = ChildService::new(connection.clone());
+        let balance_service = BalanceService::new(connection.clone());
+        let transaction_service = Arc::new(TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            AllowanceService::new(connection.clone()),
+            balance_service.clone(),
+        ));
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })?
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() })?;
+        Ok((ImportService::new(transaction_service), balance_service, child.id, temp_dir))
+    }
+
+    #[test]
+    fn test_preview_flags_bad_rows_and_import_takes_accepted_ones() -> Result<()> {
+        let (service, balance_service, child_id, _temp_dir) = setup()?;
+
+        let statement = "\
+Date,Description,Amount
+03/02/2025,Birthday check,\"$1,025.00\"
+03/01/2025,Comic book,(4.50)
+2025-03-03,Snacks,-2.00
+03/04/2025,,3.00
+03/05/2025,Nothing,0
+
+03/06/2025,Stickers
+";
+        let csv = |mapping| StatementFormat::Csv(mapping);
+        let preview = service.preview_statement(PreviewStatementCommand {
+            content: statement.to_string(),
+            format: csv(mapping()),
+        })?;
+        assert_eq!(preview.headers, Some(vec!["Date".to_string(), "Description".to_string(), "Amount".to_string()]));
+        let lines: Vec<usize> = preview.rows.iter().map(|row| row.line).collect();
+        assert_eq!(lines, vec![2, 3, 4, 5, 6, 8]);
+        assert_eq!(preview.rows[0].amount, Some(1025.0));
+        assert_eq!(preview.rows[1].amount, Some(-4.5));
+        assert!(preview.rows[2].errors[0].contains("date format"));
+        assert_eq!(preview.rows[3].errors, vec!["Description is empty"]);
+        assert_eq!(preview.rows[4].errors, vec!["Amount is zero"]);
+        assert!(preview.rows[5].errors[0].starts_with("No amount column"));
+
+        let credit_card = service.preview_statement(PreviewStatementCommand {
+            content: statement.to_string(),
+            format: csv(StatementColumnMapping { sign_convention: SignConvention::PositiveIsSpending, ..mapping() }),
+        })?;
+        assert_eq!(credit_card.rows[1].amount, Some(4.5));
+
+        let result = service.import_statement(ImportStatementCommand {
+            content: statement.to_string(),
+            format: csv(mapping()),
+            accepted_lines: vec![2, 3, 4, 99],
+            category_mapping: HashMap::new(),
+            actor: None,
+        })?;
+        // Oldest first, whatever order the statement was in
+        let imported: Vec<(&str, f64)> = result.imported.iter().map(|t| (t.description.as_str(), t.balance)).collect();
+        assert_eq!(imported, vec![("Comic book", -4.5), ("Birthday check", 1020.5)]);
+        assert_eq!(result.skipped.iter().map(|(line, _)| *line).collect::<Vec<_>>(), vec![4, 99]);
+        assert_eq!(balance_service.get_current_balance(&child_id)?, 1020.5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ofx_and_qif_import_with_duplicates_and_category_mapping() -> Result<()> {
+        let (service, balance_service, child_id, _temp_dir) = setup()?;
+
+        // SGML OFX 1.x, tags left open the way most banks write it
+        let ofx = "\
+OFXHEADER:100
+DATA:OFXSGML
+
+<OFX>
+<BANKMSGSRSV1><STMTTRNRS><STMTRS>
+<BANKTRANLIST>
+<STMTTRN>
+<TRNTYPE>DEBIT
+<DTPOSTED>20250314120000.000[-5:EST]
+<TRNAMT>-3.25
+<FITID>1001
+<NAME>Ice cream &amp; sprinkles
+</STMTTRN>
+<STMTTRN>
+<TRNTYPE>CREDIT
+<DTPOSTED>20250315
+<TRNAMT>10.00
+<FITID>1002
+<MEMO>Chores reward
+</STMTTRN>
+</BANKTRANLIST>
+</STMTRS></STMTTRNRS></BANKMSGSRSV1>
+</OFX>
+";
+        let preview = service.preview_statement(PreviewStatementCommand { content: ofx.to_string(), format: StatementFormat::Ofx })?;
+        let rows: Vec<(usize, Option<NaiveDate>, &str, Option<f64>)> =
+            preview.rows.iter().map(|row| (row.line, row.date, row.description.as_str(), row.amount)).collect();
+        assert_eq!(
+            rows,
+            vec![
+                (7, NaiveDate::from_ymd_opt(2025, 3, 14), "Ice cream & sprinkles", Some(-3.25)),
+                (14, NaiveDate::from_ymd_opt(2025, 3, 15), "Chores reward", Some(10.0)),
+            ]
+        );
+        service.import_statement(ImportStatementCommand {
+            content: ofx.to_string(),
+            format: StatementFormat::Ofx,
+            accepted_lines: vec![7, 14],
+            category_mapping: HashMap::new(),
+            actor: None,
+        })?;
+        assert!(service
+            .preview_statement(PreviewStatementCommand { content: ofx.to_string(), format: StatementFormat::Ofx })?
+            .rows
+            .iter()
+            .all(|row| row.duplicate_of.is_some()));
+
+        // The same ice cream again in a QIF export, plus a second identical one
+        let qif = "\
+!Type:Bank
+D3/14'25
+T-3.25
+PIce Cream & Sprinkles
+LFood:Treats
+^
+D03/14/2025
+T-3.25
+PIce cream & sprinkles
+LFood:Treats
+^
+D3/16/25
+T-12.00
+PLego minifigures
+LToys
+^
+";
+        let preview = service.preview_statement(PreviewStatementCommand { content: qif.to_string(), format: StatementFormat::Qif })?;
+        assert!(preview.rows.iter().all(StatementRow::is_valid));
+        assert_eq!(preview.rows.iter().map(|row| row.line).collect::<Vec<_>>(), vec![2, 7, 12]);
+        assert!(preview.rows[0].duplicate_of.is_some());
+        assert_eq!(preview.rows[1].duplicate_of, None);
+        assert_eq!(preview.categories, vec!["Food:Treats".to_string(), "Toys".to_string()]);
+
+        let result = service.import_statement(ImportStatementCommand {
+            content: qif.to_string(),
+            format: StatementFormat::Qif,
+            accepted_lines: vec![7, 12],
+            category_mapping: HashMap::from([("Food:Treats".to_string(), "Candy".to_string())]),
+            actor: None,
+        })?;
+        let categories: Vec<Option<&str>> = result.imported.iter().map(|t| t.category.as_deref()).collect();
+        assert_eq!(categories, vec![Some("Candy"), Some("Toys")]);
+        assert_eq!(balance_service.get_current_balance(&child_id)?, -8.5);
+
+        let known = service
+            .preview_statement(PreviewStatementCommand { content: qif.to_string(), format: StatementFormat::Qif })?
+            .known_categories;
+        assert_eq!(known, vec!["Candy".to_string(), "Toys".to_string()]);
+
+        let err = service
+            .preview_statement(PreviewStatementCommand { content: "a,b,c".to_string(), format: StatementFormat::Ofx })
+            .unwrap_err();
+        assert!(err.to_string().contains("OFX"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_qif_date() {
+        let march_14 = NaiveDate::from_ymd_opt(2025, 3, 14);
+        assert_eq!(parse_qif_date("3/14'25"), march_14);
+        assert_eq!(parse_qif_date(" 3/14/25"), march_14);
+        assert_eq!(parse_qif_date("2025-03-14"), march_14);
+        assert_eq!(parse_qif_date("12/31/99"), NaiveDate::from_ymd_opt(1999, 12, 31));
+        assert_eq!(parse_qif_date("14/3/25"), None);
+    }
+
+    #[test]
+    fn test_parse_statement_amount() {
+        assert_eq!(parse_statement_amount("12"), Some(12.0));
+        assert_eq!(parse_statement_amount(" -3.999 "), Some(-4.0));
+        assert_eq!(parse_statement_amount("(1,200.10)"), Some(-1200.1));
+        assert_eq!(parse_statement_amount("abc"), None);
+        assert_eq!(parse_statement_amount("NaN"), None);
+    }
+}
@@ -0,0 +1,277 @@
+//! Loan service domain logic for the allowance tracker.
+//!
+//! Tracks money fronted between a parent and a child. Opening a loan records
+//! a transaction (money in when a parent fronts it, money out when the child
+//! pays on a parent's behalf) and each repayment records another in the
+//! opposite direction, linked to the loan. The outstanding query answers
+//! "who owes whom, and how much" from those links; deleting a linked
+//! transaction removes its link, so the two can't drift apart.
+
+use anyhow::Result;
+use log::info;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::commands::loans::{
+    CreateLoanCommand, LoanResult, OutstandingLoansQuery, OutstandingLoansResult, SettleLoanCommand,
+};
+use crate::backend::domain::commands::transactions::CreateTransactionCommand;
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::models::loan::{Loan, LoanDirection, LoanSettlement};
+use crate::backend::domain::models::transaction::TransactionType;
+use crate::backend::domain::TransactionService;
+use crate::backend::storage::csv::{CsvConnection, LoanRepository};
+use crate::backend::storage::traits::LoanStorage;
+
+/// Service for money owed between a parent and a child
+#[derive(Clone)]
+pub struct LoanService {
+    loan_repository: LoanRepository,
+    child_service: ChildService,
+    transaction_service: Arc<TransactionService>,
+}
+
+impl LoanService {
+    /// Create a new LoanService
+    pub fn new(
+        csv_conn: Arc<CsvConnection>,
+        child_service: ChildService,
+        transaction_service: Arc<TransactionService>,
+    ) -> Self {
+        let loan_repository = LoanRepository::new((*csv_conn).clone());
+        Self {
+            loan_repository,
+            child_service,
+            transaction_service,
+        }
+    }
+
+    /// Record money fronted between the active child and a parent
+    pub fn create_loan(&self, command: CreateLoanCommand) -> Result<LoanResult> {
+        let description = command.description.trim().to_string();
+        if description.is_empty() {
+            return Err(DomainError::invalid("Loan description cannot be empty").into());
+        }
+        validate_amount(command.amount)?;
+
+        let child = self.transaction_service.get_active_child()?;
+        let transaction = self.transaction_service.create_transaction(CreateTransactionCommand {
+            child_id: Some(child.id.clone()),
+            description: command.direction.opening_description(&description),
+            amount: command.direction.opening_sign() * command.amount,
+            date: command.date,
+            parent_override: command.parent_override,
+            actor: command.actor,
+            icon: None,
+            transaction_type: Some(TransactionType::Transfer),
+        })?;
+
+        let now_millis = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+        let loan = Loan {
+            id: Loan::generate_id(&child.id, now_millis),
+            child_id: child.id.clone(),
+            direction: command.direction,
+            description,
+            amount: command.amount,
+            date: transaction.date,
+            transaction_id: transaction.id.clone(),
+            settlements: Vec::new(),
+        };
+        self.loan_repository.store_loan(&loan)?;
+
+        info!("🤝 LOAN: {} opened {} for ${:.2} ({:?})", child.id, loan.id, loan.amount, loan.direction);
+        Ok(LoanResult { loan, transaction })
+    }
+
+    /// Pay back some or all of an open loan for the active child
+    pub fn settle_loan(&self, command: SettleLoanCommand) -> Result<LoanResult> {
+        let child = self.transaction_service.get_active_child()?;
+        let mut loan = self
+            .loan_repository
+            .list_loans(&child.id)?
+            .into_iter()
+            .find(|loan| loan.id == command.loan_id)
+            .ok_or_else(|| DomainError::NotFound(format!("Loan not found: {}", command.loan_id)))?;
+
+        let outstanding = loan.outstanding();
+        if outstanding <= 0.0 {
+            return Err(DomainError::Conflict(format!("\"{}\" has already been paid back", loan.description)).into());
+        }
+        let amount = command.amount.unwrap_or(outstanding);
+        validate_amount(amount)?;
+        if amount > outstanding + 0.005 {
+            return Err(DomainError::invalid(format!(
+                "Settlement of ${:.2} is more than the ${:.2} still owed",
+                amount, outstanding
+            ))
+            .into());
+        }
+
+        let transaction = self.transaction_service.create_transaction(CreateTransactionCommand {
+            child_id: Some(child.id.clone()),
+            description: loan.direction.settlement_description(&loan.description),
+            amount: -loan.direction.opening_sign() * amount,
+            date: command.date,
+            parent_override: command.parent_override,
+            actor: command.actor,
+            icon: None,
+            transaction_type: Some(TransactionType::Transfer),
+        })?;
+        loan.settlements.push(LoanSettlement {
+            transaction_id: transaction.id.clone(),
+            date: transaction.date,
+            amount,
+        });
+        self.loan_repository.store_loan(&loan)?;
+
+        info!("🤝 LOAN: {} settled ${:.2} of {} (${:.2} left)", child.id, amount, loan.id, loan.outstanding());
+        Ok(LoanResult { loan, transaction })
+    }
+
+    /// Open loans for a child, with what is owed in each direction
+    pub fn get_outstanding(&self, query: OutstandingLoansQuery) -> Result<OutstandingLoansResult> {
+        let child_id = match query.child_id {
+            Some(id) => id,
+            None => self
+                .child_service
+                .get_active_child()?
+                .active_child
+                .child
+                .map(|child| child.id)
+                .ok_or(DomainError::NoActiveChild)?,
+        };
+
+        let open_loans: Vec<Loan> = self
+            .loan_repository
+            .list_loans(&child_id)?
+            .into_iter()
+            .filter(|loan| !loan.is_settled())
+            .collect();
+        let total = |direction: LoanDirection| {
+            let sum: f64 = open_loans.iter().filter(|l| l.direction == direction).map(|l| l.outstanding()).sum();
+            (sum * 100.0).round() / 100.0
+        };
+
+        Ok(OutstandingLoansResult {
+            owed_by_child: total(LoanDirection::ChildOwesParent),
+            owed_to_child: total(LoanDirection::ParentOwesChild),
+            child_id,
+            open_loans,
+        })
+    }
+}
+
+fn validate_amount(amount: f64) -> Result<()> {
+    if !amount.is_finite() || amount <= 0.0 {
+        return Err(DomainError::invalid("Loan amounts must be greater than 0").into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand};
+    use crate::backend::domain::commands::transactions::DeleteTransactionsCommand;
+    use crate::backend::domain::{AllowanceService, BalanceService};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_loans_open_settle_and_unlink() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let child_service = ChildService::new(connection.clone());
+        let balance_service = BalanceService::new(connection.clone());
+        let transaction_service = Arc::new(TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            AllowanceService::new(connection.clone()),
+            balance_service.clone(),
+        ));
+        let service = LoanService::new(connection, child_service.clone(), transaction_service.clone());
+
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })?
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() })?;
+
+        let game = service.create_loan(CreateLoanCommand {
+            direction: LoanDirection::ChildOwesParent,
+            description: "Video game".to_string(),
+            amount: 20.0,
+            date: None,
+            parent_override: false,
+            actor: None,
+        })?;
+        assert_eq!(game.transaction.amount, 20.0);
+        assert_eq!(game.transaction.description, "Loan from parent: Video game");
+
+        let stamps = service.create_loan(CreateLoanCommand {
+            direction: LoanDirection::ParentOwesChild,
+            description: "Stamps".to_string(),
+            amount: 7.5,
+            date: None,
+            parent_override: false,
+            actor: None,
+        })?;
+        assert_eq!(stamps.transaction.amount, -7.5);
+
+        let partial = service.settle_loan(SettleLoanCommand {
+            loan_id: game.loan.id.clone(),
+            amount: Some(5.0),
+            date: None,
+            parent_override: false,
+            actor: None,
+        })?;
+        assert_eq!(partial.transaction.amount, -5.0);
+        assert_eq!(partial.loan.outstanding(), 15.0);
+
+        let outstanding = service.get_outstanding(OutstandingLoansQuery { child_id: None })?;
+        assert_eq!(outstanding.open_loans.len(), 2);
+        assert_eq!(outstanding.owed_by_child, 15.0);
+        assert_eq!(outstanding.owed_to_child, 7.5);
+
+        let too_much = service.settle_loan(SettleLoanCommand {
+            loan_id: game.loan.id.clone(),
+            amount: Some(16.0),
+            date: None,
+            parent_override: false,
+            actor: None,
+        });
+        assert!(matches!(too_much.unwrap_err().downcast_ref::<DomainError>(), Some(DomainError::Invalid(_))));
+
+        // Settling with no amount pays off the rest; a fully settled pair nets to zero
+        let reimbursed = service.settle_loan(SettleLoanCommand {
+            loan_id: stamps.loan.id.clone(),
+            amount: None,
+            date: None,
+            parent_override: false,
+            actor: None,
+        })?;
+        assert_eq!(reimbursed.transaction.amount, 7.5);
+        assert!(reimbursed.loan.is_settled());
+        assert_eq!(balance_service.get_current_balance(&child.id)?, 15.0);
+
+        let again = service.settle_loan(SettleLoanCommand {
+            loan_id: stamps.loan.id.clone(),
+            amount: None,
+            date: None,
+            parent_override: false,
+            actor: None,
+        });
+        assert!(matches!(again.unwrap_err().downcast_ref::<DomainError>(), Some(DomainError::Conflict(_))));
+
+        // Deleting the repayment puts the full amount back on the books
+        transaction_service.delete_transactions(DeleteTransactionsCommand {
+            child_id: None,
+            transaction_ids: vec![partial.transaction.id.clone()],
+            actor: None,
+        })?;
+        let outstanding = service.get_outstanding(OutstandingLoansQuery { child_id: Some(child.id) })?;
+        assert_eq!(outstanding.open_loans.len(), 1);
+        assert_eq!(outstanding.owed_by_child, 20.0);
+        assert_eq!(outstanding.owed_to_child, 0.0);
+        Ok(())
+    }
+}
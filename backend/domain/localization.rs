@@ -0,0 +1,419 @@
+//! Localization domain logic for the allowance tracker.
+//!
+//! A [`Locale`] decides how dates and amounts are written ("June 19, 2025"
+//! vs "19 June 2025", "$5.50" vs "$5,50"). It can be set globally and
+//! overridden per child in their settings. User-facing strings produced by
+//! the domain are looked up in a [`StringCatalog`] by key, so translations
+//! can be added without touching the services that use them; any key
+//! without a translation falls back to English.
+
+use anyhow::Result;
+use chrono::Utc;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::backend::storage::csv::{CsvConnection, ChildSettingsRepository, GlobalConfigRepository};
+use crate::backend::storage::traits::ChildSettingsStorage;
+use crate::backend::storage::GlobalConfigStorage;
+
+/// Languages and regional formats the app knows how to format for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Locale {
+    #[default]
+    EnUs,
+    EnGb,
+    DeDe,
+    FrFr,
+    EsEs,
+}
+
+/// How much detail to show when formatting a date
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateStyle {
+    /// "June 19, 2025" / "19 June 2025"
+    Long,
+    /// "Jun 19, 2025" / "19 Jun 2025"
+    Medium,
+    /// "06/19/2025" / "19/06/2025"
+    Short,
+}
+
+impl Locale {
+    /// Every supported locale, in the order settings should list them
+    pub const ALL: [Locale; 5] = [Locale::EnUs, Locale::EnGb, Locale::DeDe, Locale::FrFr, Locale::EsEs];
+
+    /// BCP 47 code stored in config files ("en-US")
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::EnUs => "en-US",
+            Locale::EnGb => "en-GB",
+            Locale::DeDe => "de-DE",
+            Locale::FrFr => "fr-FR",
+            Locale::EsEs => "es-ES",
+        }
+    }
+
+    /// Parse a locale code, accepting "en_US" and any letter case
+    pub fn from_code(code: &str) -> Option<Self> {
+        let normalized = code.trim().replace('_', "-").to_ascii_lowercase();
+        Self::ALL.into_iter().find(|locale| locale.code().to_ascii_lowercase() == normalized)
+    }
+
+    /// Name of the locale in its own language, for settings pickers
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Locale::EnUs => "English (US)",
+            Locale::EnGb => "English (UK)",
+            Locale::DeDe => "Deutsch",
+            Locale::FrFr => "Français",
+            Locale::EsEs => "Español",
+        }
+    }
+
+    /// Character between whole and fractional parts of an amount
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            Locale::EnUs | Locale::EnGb => '.',
+            Locale::DeDe | Locale::FrFr | Locale::EsEs => ',',
+        }
+    }
+
+    /// Character used to group thousands when parsing typed amounts
+    pub fn grouping_separator(&self) -> char {
+        match self {
+            Locale::EnUs | Locale::EnGb => ',',
+            Locale::DeDe | Locale::EsEs => '.',
+            Locale::FrFr => ' ',
+        }
+    }
+
+    /// Full month name (1 = January)
+    pub fn month_name(&self, month: u32) -> &'static str {
+        const EN: [&str; 12] = ["January", "February", "March", "April", "May", "June",
+            "July", "August", "September", "October", "November", "December"];
+        const DE: [&str; 12] = ["Januar", "Februar", "März", "April", "Mai", "Juni",
+            "Juli", "August", "September", "Oktober", "November", "Dezember"];
+        const FR: [&str; 12] = ["janvier", "février", "mars", "avril", "mai", "juin",
+            "juillet", "août", "septembre", "octobre", "novembre", "décembre"];
+        const ES: [&str; 12] = ["enero", "febrero", "marzo", "abril", "mayo", "junio",
+            "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"];
+
+        let names = match self {
+            Locale::EnUs | Locale::EnGb => &EN,
+            Locale::DeDe => &DE,
+            Locale::FrFr => &FR,
+            Locale::EsEs => &ES,
+        };
+        match month {
+            1..=12 => names[month as usize - 1],
+            _ => "Invalid Month",
+        }
+    }
+
+    /// Abbreviated month name (1 = Jan)
+    pub fn short_month_name(&self, month: u32) -> String {
+        let name = self.month_name(month);
+        match self {
+            // French keeps short names whole and marks the rest with a dot
+            Locale::FrFr if name.chars().count() > 4 => format!("{}.", name.chars().take(4).collect::<String>()),
+            Locale::FrFr => name.to_string(),
+            _ => name.chars().take(3).collect(),
+        }
+    }
+
+    /// Format a calendar date in this locale
+    pub fn format_date(&self, year: i32, month: u32, day: u32, style: DateStyle) -> String {
+        match style {
+            DateStyle::Long => self.format_date_with_month(year, self.month_name(month), day),
+            DateStyle::Medium => self.format_date_with_month(year, &self.short_month_name(month), day),
+            DateStyle::Short => match self {
+                Locale::EnUs => format!("{:02}/{:02}/{}", month, day, year),
+                Locale::DeDe => format!("{:02}.{:02}.{}", day, month, year),
+                Locale::EnGb | Locale::FrFr | Locale::EsEs => format!("{:02}/{:02}/{}", day, month, year),
+            },
+        }
+    }
+
+    fn format_date_with_month(&self, year: i32, month_name: &str, day: u32) -> String {
+        match self {
+            Locale::EnUs => format!("{} {}, {}", month_name, day, year),
+            Locale::EnGb | Locale::FrFr => format!("{} {} {}", day, month_name, year),
+            Locale::DeDe => format!("{}. {} {}", day, month_name, year),
+            Locale::EsEs => format!("{} de {} de {}", day, month_name, year),
+        }
+    }
+
+    /// Format a number with the given decimal places and this locale's decimal separator
+    pub fn format_decimal(&self, value: f64, decimal_places: usize) -> String {
+        let formatted = format!("{:.*}", decimal_places, value);
+        match self.decimal_separator() {
+            '.' => formatted,
+            separator => formatted.replace('.', &separator.to_string()),
+        }
+    }
+
+    /// Format a whole number with this locale's thousands grouping ("1,000,000")
+    pub fn format_whole_number(&self, value: u64) -> String {
+        let digits = value.to_string();
+        let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                grouped.push(self.grouping_separator());
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+
+    /// Rewrite a typed amount into the form `f64::from_str` accepts.
+    ///
+    /// Grouping separators are dropped and the decimal separator becomes a
+    /// dot. In comma-decimal locales a dot is still read as the decimal point
+    /// when the input has no comma, since "5.50" is what most keyboards'
+    /// number pads produce.
+    pub fn normalize_amount_input(&self, input: &str) -> String {
+        let trimmed = input.trim();
+        let decimal = self.decimal_separator();
+        let grouping = self.grouping_separator();
+
+        if decimal == ',' && !trimmed.contains(',') {
+            return trimmed.replace([' ', '\u{a0}'], "");
+        }
+
+        trimmed
+            .chars()
+            .filter(|c| *c != grouping && *c != ' ' && *c != '\u{a0}')
+            .map(|c| if c == decimal { '.' } else { c })
+            .collect()
+    }
+}
+
+impl From<String> for Locale {
+    fn from(code: String) -> Self {
+        Self::from_code(&code).unwrap_or_else(|| {
+            warn!("🌐 LOCALIZATION: Unknown locale '{}', using {}", code, Locale::default().code());
+            Locale::default()
+        })
+    }
+}
+
+impl From<Locale> for String {
+    fn from(locale: Locale) -> Self {
+        locale.code().to_string()
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// Keys for user-facing strings; shared with the frontends so they can
+/// render the same messages themselves
+pub use shared::messages::keys;
+use shared::messages::{english, fill_placeholders};
+use shared::LocalizedMessage;
+
+/// Lookup table of user-facing strings for one locale.
+///
+/// Messages may contain `{name}` placeholders that [`StringCatalog::format`]
+/// fills in. Lookups fall back to English and then to the key itself, so a
+/// partial translation never produces an empty label.
+#[derive(Debug, Clone, Default)]
+pub struct StringCatalog {
+    locale: Locale,
+    translations: Arc<HashMap<String, String>>,
+}
+
+impl StringCatalog {
+    /// Catalog with the built-in English strings only
+    pub fn new(locale: Locale) -> Self {
+        Self {
+            locale,
+            translations: Arc::new(HashMap::new()),
+        }
+    }
+
+    /// Catalog with translations for `locale` layered over English
+    pub fn with_translations(locale: Locale, translations: HashMap<String, String>) -> Self {
+        Self {
+            locale,
+            translations: Arc::new(translations),
+        }
+    }
+
+    /// Parse translations from a flat YAML map of key to message
+    pub fn from_yaml(locale: Locale, yaml: &str) -> Result<Self> {
+        let translations: HashMap<String, String> = serde_yaml::from_str(yaml)?;
+        Ok(Self::with_translations(locale, translations))
+    }
+
+    /// Locale this catalog formats for
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Look up the message for a key
+    pub fn get(&self, key: &str) -> String {
+        self.translations
+            .get(key)
+            .map(String::as_str)
+            .or_else(|| english(key))
+            .unwrap_or(key)
+            .to_string()
+    }
+
+    /// Look up a message and fill in its `{name}` placeholders
+    pub fn format(&self, key: &str, args: &[(&str, &str)]) -> String {
+        fill_placeholders(&self.get(key), args.iter().copied())
+    }
+
+    /// Render a message that carries its own key and placeholder values
+    pub fn render(&self, message: &LocalizedMessage) -> String {
+        fill_placeholders(&self.get(&message.key), message.param_pairs())
+    }
+}
+
+/// Service for reading and changing locale settings
+#[derive(Clone)]
+pub struct LocalizationService {
+    global_config_repository: GlobalConfigRepository,
+    child_settings_repository: ChildSettingsRepository,
+}
+
+impl LocalizationService {
+    pub fn new(csv_conn: Arc<CsvConnection>) -> Self {
+        let global_config_repository = GlobalConfigRepository::new((*csv_conn).clone());
+        let child_settings_repository = ChildSettingsRepository::new((*csv_conn).clone());
+        Self { global_config_repository, child_settings_repository }
+    }
+
+    /// Locale used for children without their own setting
+    pub fn get_global_locale(&self) -> Result<Locale> {
+        Ok(self.global_config_repository.get_global_config()?.locale)
+    }
+
+    /// Change the locale used for children without their own setting
+    pub fn set_global_locale(&self, locale: Locale) -> Result<()> {
+        let mut config = self.global_config_repository.get_global_config()?;
+        config.locale = locale;
+        config.updated_at = Utc::now().to_rfc3339();
+        self.global_config_repository.update_global_config(&config)?;
+        info!("🌐 LOCALIZATION: Global locale set to {}", locale);
+        Ok(())
+    }
+
+    /// Locale that applies to a child: their own setting, else the global one
+    pub fn get_locale_for_child(&self, child_id: &str) -> Result<Locale> {
+        let child_locale = self.child_settings_repository
+            .get_child_settings(child_id)?
+            .and_then(|settings| settings.locale);
+        match child_locale {
+            Some(locale) => Ok(locale),
+            None => self.get_global_locale(),
+        }
+    }
+
+    /// String catalog for the locale that applies to a child
+    pub fn catalog_for_child(&self, child_id: &str) -> Result<StringCatalog> {
+        Ok(StringCatalog::new(self.get_locale_for_child(child_id)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dates_follow_locale() {
+        assert_eq!(Locale::EnUs.format_date(2025, 6, 19, DateStyle::Long), "June 19, 2025");
+        assert_eq!(Locale::EnGb.format_date(2025, 6, 19, DateStyle::Long), "19 June 2025");
+        assert_eq!(Locale::DeDe.format_date(2025, 3, 5, DateStyle::Long), "5. März 2025");
+        assert_eq!(Locale::EsEs.format_date(2025, 6, 19, DateStyle::Long), "19 de junio de 2025");
+        assert_eq!(Locale::EnUs.format_date(2025, 6, 19, DateStyle::Medium), "Jun 19, 2025");
+        assert_eq!(Locale::FrFr.format_date(2025, 9, 1, DateStyle::Medium), "1 sept. 2025");
+        assert_eq!(Locale::EnUs.format_date(2025, 6, 19, DateStyle::Short), "06/19/2025");
+        assert_eq!(Locale::DeDe.format_date(2025, 6, 19, DateStyle::Short), "19.06.2025");
+    }
+
+    #[test]
+    fn test_amounts_follow_locale() {
+        assert_eq!(Locale::EnUs.format_decimal(1234.5, 2), "1234.50");
+        assert_eq!(Locale::DeDe.format_decimal(1234.5, 2), "1234,50");
+        assert_eq!(Locale::EnUs.format_whole_number(1_000_000), "1,000,000");
+        assert_eq!(Locale::DeDe.format_whole_number(12_500), "12.500");
+        assert_eq!(Locale::EnUs.format_whole_number(999), "999");
+
+        assert_eq!(Locale::EnUs.normalize_amount_input("1,234.50"), "1234.50");
+        assert_eq!(Locale::DeDe.normalize_amount_input("1.234,50"), "1234.50");
+        assert_eq!(Locale::FrFr.normalize_amount_input("1 234,50"), "1234.50");
+        // A lone dot is still a decimal point in comma locales
+        assert_eq!(Locale::DeDe.normalize_amount_input("5.50"), "5.50");
+    }
+
+    #[test]
+    fn test_locale_codes_round_trip() {
+        assert_eq!(Locale::from_code("en_gb"), Some(Locale::EnGb));
+        assert_eq!(Locale::from_code("xx-YY"), None);
+
+        let yaml = serde_yaml::to_string(&Locale::FrFr).unwrap();
+        assert_eq!(serde_yaml::from_str::<Locale>(&yaml).unwrap(), Locale::FrFr);
+        // Unknown codes in config files fall back instead of failing to load
+        assert_eq!(serde_yaml::from_str::<Locale>("klingon").unwrap(), Locale::EnUs);
+    }
+
+    #[test]
+    fn test_child_locale_overrides_global() -> Result<()> {
+        use crate::backend::domain::child_service::ChildService;
+        use crate::backend::domain::commands::child::UpdateChildSettingsCommand;
+        use crate::backend::storage::csv::test_utils::TestHelper;
+
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let connection = Arc::new(helper.env.connection.clone());
+        let service = LocalizationService::new(connection.clone());
+
+        assert_eq!(service.get_locale_for_child(&child.id)?, Locale::EnUs);
+        service.set_global_locale(Locale::EnGb)?;
+        assert_eq!(service.get_locale_for_child(&child.id)?, Locale::EnGb);
+
+        let child_service = ChildService::new(connection);
+        let mut command = UpdateChildSettingsCommand {
+            child_id: child.id.clone(),
+            prevent_negative_balance: None,
+            auto_save_percentage: None,
+            auto_save_to_goal: None,
+            round_up_spends: None,
+            locale: Some(Some(Locale::DeDe)),
+            currency: None,
+            balance_milestones: None,
+            background: None,
+        };
+        child_service.update_child_settings(command.clone())?;
+        assert_eq!(service.get_locale_for_child(&child.id)?, Locale::DeDe);
+
+        // Clearing the child's locale falls back to the global one again
+        command.locale = Some(None);
+        child_service.update_child_settings(command)?;
+        assert_eq!(service.catalog_for_child(&child.id)?.locale(), Locale::EnGb);
+        Ok(())
+    }
+
+    #[test]
+    fn test_catalog_falls_back_to_english() {
+        let mut translations = HashMap::new();
+        translations.insert(keys::VALIDATION_AMOUNT_TOO_SMALL.to_string(), "Betrag ist zu klein. Minimum ist {min}".to_string());
+        let catalog = StringCatalog::with_translations(Locale::DeDe, translations);
+
+        assert_eq!(catalog.format(keys::VALIDATION_AMOUNT_TOO_SMALL, &[("min", "0,01 $")]), "Betrag ist zu klein. Minimum ist 0,01 $");
+        assert_eq!(catalog.get(keys::VALIDATION_EMPTY_DESCRIPTION), "Please enter a description");
+        assert_eq!(catalog.get("no.such.key"), "no.such.key");
+
+        let message = LocalizedMessage::new(keys::VALIDATION_AMOUNT_TOO_SMALL).with_param("min", "0,01 $");
+        assert_eq!(catalog.render(&message), "Betrag ist zu klein. Minimum ist 0,01 $");
+    }
+}
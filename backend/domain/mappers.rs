@@ -0,0 +1,123 @@
+//! Mapping from domain models to the `shared` DTOs.
+//!
+//! Services that answer with DTOs and the frontend API both convert through
+//! here, so a field added to a model only has to be mapped once.
+
+use crate::backend::domain::models::child::Child as DomainChild;
+use crate::backend::domain::models::period_lock::PeriodLock;
+use crate::backend::domain::models::transaction::{Transaction as DomainTransaction, TransactionType as DomainTransactionType};
+
+/// Converts domain transactions to DTOs
+pub struct TransactionMapper;
+
+impl TransactionMapper {
+    pub fn to_dto(transaction: DomainTransaction) -> shared::Transaction {
+        Self::to_dto_with_lock(transaction, None)
+    }
+
+    /// Like `to_dto`, marking the transaction locked when it falls in the
+    /// child's closed period
+    pub fn to_dto_with_lock(transaction: DomainTransaction, period_lock: Option<&PeriodLock>) -> shared::Transaction {
+        let locked = period_lock.is_some_and(|lock| lock.covers(transaction.date));
+        shared::Transaction {
+            id: transaction.id,
+            child_id: transaction.child_id,
+            date: transaction.date,
+            description: transaction.description,
+            amount: transaction.amount,
+            balance: transaction.balance,
+            transaction_type: Self::type_to_dto(&transaction.transaction_type),
+            actor: transaction.actor.map(|actor| actor.label()),
+            category: transaction.category,
+            icon: transaction.icon,
+            locked,
+        }
+    }
+
+    pub fn type_to_dto(transaction_type: &DomainTransactionType) -> shared::TransactionType {
+        match transaction_type {
+            DomainTransactionType::Income => shared::TransactionType::Income,
+            DomainTransactionType::Expense => shared::TransactionType::Expense,
+            DomainTransactionType::FutureAllowance => shared::TransactionType::FutureAllowance,
+            DomainTransactionType::AllowancePayment => shared::TransactionType::AllowancePayment,
+            DomainTransactionType::Bonus => shared::TransactionType::Bonus,
+            DomainTransactionType::Penalty => shared::TransactionType::Penalty,
+            DomainTransactionType::Gift => shared::TransactionType::Gift,
+            DomainTransactionType::Transfer => shared::TransactionType::Transfer,
+            DomainTransactionType::Interest => shared::TransactionType::Interest,
+            DomainTransactionType::Adjustment => shared::TransactionType::Adjustment,
+        }
+    }
+
+    pub fn type_from_dto(transaction_type: &shared::TransactionType) -> DomainTransactionType {
+        match transaction_type {
+            shared::TransactionType::Income => DomainTransactionType::Income,
+            shared::TransactionType::Expense => DomainTransactionType::Expense,
+            shared::TransactionType::FutureAllowance => DomainTransactionType::FutureAllowance,
+            shared::TransactionType::AllowancePayment => DomainTransactionType::AllowancePayment,
+            shared::TransactionType::Bonus => DomainTransactionType::Bonus,
+            shared::TransactionType::Penalty => DomainTransactionType::Penalty,
+            shared::TransactionType::Gift => DomainTransactionType::Gift,
+            shared::TransactionType::Transfer => DomainTransactionType::Transfer,
+            shared::TransactionType::Interest => DomainTransactionType::Interest,
+            shared::TransactionType::Adjustment => DomainTransactionType::Adjustment,
+        }
+    }
+}
+
+/// Converts domain children to DTOs
+pub struct ChildMapper;
+
+impl ChildMapper {
+    pub fn to_dto(child: DomainChild) -> shared::Child {
+        shared::Child {
+            id: child.id,
+            name: child.name,
+            birthdate: child.birthdate,
+            created_at: child.created_at,
+            updated_at: child.updated_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::models::actor::Actor;
+    use chrono::DateTime;
+
+    #[test]
+    fn test_transaction_to_dto_keeps_every_field() {
+        let transaction = DomainTransaction {
+            id: "transaction::income::1702516122000".to_string(),
+            child_id: "child::1".to_string(),
+            date: DateTime::parse_from_rfc3339("2025-06-18T12:00:00-04:00").unwrap(),
+            description: "Birthday gift".to_string(),
+            amount: 20.0,
+            balance: 45.5,
+            transaction_type: DomainTransactionType::FutureAllowance,
+            actor: Some(Actor::Parent(Some("Mom".to_string()))),
+            category: Some("Gifts".to_string()),
+            icon: None,
+        };
+        let dto = TransactionMapper::to_dto(transaction.clone());
+        assert_eq!(dto.id, transaction.id);
+        assert_eq!(dto.child_id, transaction.child_id);
+        assert_eq!(dto.date, transaction.date);
+        assert_eq!(dto.description, transaction.description);
+        assert_eq!((dto.amount, dto.balance), (20.0, 45.5));
+        assert_eq!(dto.transaction_type, shared::TransactionType::FutureAllowance);
+        assert_eq!(dto.actor.as_deref(), Some("Parent (Mom)"));
+        assert_eq!(dto.category.as_deref(), Some("Gifts"));
+        assert!(!dto.locked);
+
+        let lock = |through: &str| PeriodLock {
+            child_id: "child::1".to_string(),
+            locked_through: chrono::NaiveDate::parse_from_str(through, "%Y-%m-%d").unwrap(),
+            closed_at: chrono::Utc::now(),
+            closed_by: None,
+        };
+        assert!(TransactionMapper::to_dto_with_lock(transaction.clone(), Some(&lock("2025-06-30"))).locked);
+        assert!(!TransactionMapper::to_dto_with_lock(transaction, Some(&lock("2025-05-31"))).locked);
+    }
+}
@@ -12,6 +12,26 @@
 //! - **transaction_table**: Transaction table formatting and display logic
 //! - **calendar**: Calendar view generation and date-based transaction organization
 //! - **money_management**: Money form handling, validation, and user interactions
+//! - **mappers**: Domain model to `shared` DTO conversion
+//! - **errors**: `DomainError` and classification into `shared::ErrorCode`
+//! - **localization**: Locales, date/amount formatting and the string catalog
+//! - **quick_entry**: One-line keyboard entry parsed into a transaction command
+//! - **archive_service**: Opt-in rollup of old months into summary transactions
+//! - **loan_service**: Money fronted between a parent and a child, and its repayments
+//! - **allowance_advance_service**: Allowance paid early and repaid from later allowances
+//! - **planned_purchase_service**: Money reserved for a future purchase
+//! - **quick_pick_service**: Per-child saved descriptions and money form suggestions
+//! - **transaction_template_service**: Per-child saved transactions ("favorites") added with one tap
+//! - **reset_service**: Archive and erase all data, behind a typed confirmation
+//! - **health_service**: Storage and schema checks, and which build is running
+//! - **import_service**: Bank and card statement CSVs imported through a column mapping
+//! - **retention_service**: How long growing records are kept, applied daily or as a dry run
+//! - **budget_service**: Monthly spending targets per category and how a month compares
+//! - **scheduled_report_service**: Monthly reports written to a folder as each month ends
+//! - **desktop_notification_service**: Opt-in system notifications for allowances and completed goals
+//! - **update_service**: Release channel and the opt-in daily check for a newer build
+//! - **fridge_view_service**: A read-only HTML page of balances and goals for a kitchen tablet
+//! - **anonymizer**: Pseudonyms, shifted dates and rounded amounts for shareable exports
 //!
 //! ## Key Responsibilities
 //!
@@ -56,6 +76,15 @@ pub mod transaction_service;
 pub mod transaction_table;
 pub mod calendar;
 pub mod money_management;
+pub mod mappers;
+pub mod errors;
+pub mod amount_expression;
+pub mod amount_input;
+pub mod description_input;
+pub mod anonymizer;
+pub mod localization;
+pub mod quick_entry;
+pub mod child_summary;
 pub mod child_service;
 pub mod parental_control_service;
 pub mod allowance_service;
@@ -63,6 +92,30 @@ pub mod balance_service;
 pub mod goal_service;
 pub mod data_directory_service;
 pub mod export_service;
+pub mod reports_service;
+pub mod archive_service;
+pub mod loan_service;
+pub mod allowance_advance_service;
+pub mod planned_purchase_service;
+pub mod quick_pick_service;
+pub mod transaction_template_service;
+pub mod reset_service;
+pub mod retention_service;
+pub mod budget_service;
+pub mod scheduled_report_service;
+pub mod desktop_notification_service;
+pub mod update_service;
+pub mod fridge_view_service;
+pub mod health_service;
+pub mod import_service;
+pub mod family_service;
+pub mod encryption_service;
+pub mod secrets_service;
+pub mod preferences_service;
+pub mod snapshot_service;
+pub mod feature_flag_service;
+pub mod celebration_service;
+pub mod agreement_service;
 pub mod commands;
 pub mod models;
 pub mod email_service;
@@ -72,12 +125,41 @@ pub use transaction_service::*;
 pub use transaction_table::*;
 pub use calendar::*;
 pub use money_management::*;
+pub use mappers::{ChildMapper, TransactionMapper};
+pub use errors::DomainError;
+pub use localization::{Locale, DateStyle, StringCatalog, LocalizationService};
+pub use quick_entry::{parse_quick_entry, QuickEntryError};
+pub use child_summary::ChildSummary;
 pub use parental_control_service::*;
 pub use allowance_service::*;
 pub use balance_service::*;
 pub use goal_service::*;
 pub use data_directory_service::*;
 pub use export_service::*;
+pub use reports_service::*;
+pub use archive_service::*;
+pub use loan_service::*;
+pub use allowance_advance_service::*;
+pub use planned_purchase_service::*;
+pub use quick_pick_service::*;
+pub use transaction_template_service::*;
+pub use reset_service::*;
+pub use retention_service::*;
+pub use budget_service::*;
+pub use scheduled_report_service::*;
+pub use desktop_notification_service::*;
+pub use update_service::*;
+pub use fridge_view_service::*;
+pub use health_service::*;
+pub use import_service::*;
+pub use family_service::*;
+pub use encryption_service::*;
+pub use secrets_service::*;
+pub use preferences_service::*;
+pub use snapshot_service::*;
+pub use feature_flag_service::*;
+pub use celebration_service::*;
+pub use agreement_service::*;
 pub use commands::*;
 pub use email_service::*;
 pub use email_config_service::*; 
\ No newline at end of file
@@ -0,0 +1,63 @@
+//! Domain model for who entered a change
+//!
+//! Transactions and audit entries remember whether they were entered in kid
+//! mode or by a parent who passed the parental challenge, so a disputed entry
+//! can be traced back. Records written before this existed have no actor.
+
+use serde::{Deserialize, Serialize};
+
+/// Who entered a transaction or audited action
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Actor {
+    /// Entered in kid mode, without a parental challenge
+    Kid,
+    /// Entered by a parent, named when more than one parent can sign in
+    Parent(Option<String>),
+}
+
+impl Actor {
+    /// Value stored in `actor` columns: `kid`, `parent` or `parent:<name>`
+    pub fn to_storage(&self) -> String {
+        match self {
+            Actor::Kid => "kid".to_string(),
+            Actor::Parent(None) => "parent".to_string(),
+            Actor::Parent(Some(name)) => format!("parent:{}", name),
+        }
+    }
+
+    /// Parse a stored value; an empty or unknown value means no actor was recorded
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "kid" => Some(Actor::Kid),
+            "parent" => Some(Actor::Parent(None)),
+            other => other
+                .strip_prefix("parent:")
+                .map(|name| Actor::Parent(Some(name.to_string()))),
+        }
+    }
+
+    /// Short label for tables and logs, e.g. "Kid" or "Parent (Mom)"
+    pub fn label(&self) -> String {
+        match self {
+            Actor::Kid => "Kid".to_string(),
+            Actor::Parent(None) => "Parent".to_string(),
+            Actor::Parent(Some(name)) => format!("Parent ({})", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_actor_storage_round_trip() {
+        for actor in [Actor::Kid, Actor::Parent(None), Actor::Parent(Some("Grandma Jo".to_string()))] {
+            assert_eq!(Actor::parse(&actor.to_storage()), Some(actor));
+        }
+        assert_eq!(Actor::parse(""), None);
+        assert_eq!(Actor::parse("robot"), None);
+        assert_eq!(Actor::Parent(Some("Mom".to_string())).label(), "Parent (Mom)");
+    }
+}
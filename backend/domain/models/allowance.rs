@@ -1,5 +1,6 @@
 //! Domain model for an allowance configuration.
 use serde::{Deserialize, Serialize};
+use chrono::{Datelike, Duration, NaiveDate};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AllowanceConfig {
@@ -9,25 +10,466 @@ pub struct AllowanceConfig {
     pub is_active: bool,
     pub created_at: String, // RFC 3339 timestamp
     pub updated_at: String, // RFC 3339 timestamp
+    /// What happens to the first allowance after the day of week changes
+    #[serde(default)]
+    pub day_change_policy: DayChangePolicy,
+    /// The most recent change of allowance day, if any
+    #[serde(default)]
+    pub day_change: Option<AllowanceDayChange>,
+    /// Whether the allowance is paid every week or once a month
+    #[serde(default)]
+    pub frequency: AllowanceFrequency,
+    /// Day of the month a monthly allowance is paid on (1-31); months
+    /// shorter than that pay on their last day
+    #[serde(default = "default_day_of_month")]
+    pub day_of_month: u8,
+    /// How many weeks apart an every-few-weeks allowance is paid
+    #[serde(default = "default_interval_weeks")]
+    pub interval_weeks: u8,
+    /// First payday of an every-few-weeks allowance; later ones follow every
+    /// `interval_weeks` weeks, on the same day of the week
+    #[serde(default)]
+    pub anchor_date: Option<NaiveDate>,
+    /// The most recent change of frequency, day of month or interval, if any
+    #[serde(default)]
+    pub schedule_change: Option<AllowanceScheduleChange>,
+}
+
+/// Fewest and most weeks an every-few-weeks allowance can be paid apart
+pub const MIN_INTERVAL_WEEKS: u8 = 2;
+pub const MAX_INTERVAL_WEEKS: u8 = 12;
+
+fn default_day_of_month() -> u8 {
+    1
+}
+
+fn default_interval_weeks() -> u8 {
+    MIN_INTERVAL_WEEKS
+}
+
+/// How often an allowance is paid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AllowanceFrequency {
+    /// Every week on `day_of_week`
+    #[default]
+    Weekly,
+    /// Once a month on `day_of_month`
+    Monthly,
+    /// Every `interval_weeks` weeks from `anchor_date`, e.g. biweekly with a
+    /// parent's payday
+    EveryNWeeks,
+}
+
+impl AllowanceFrequency {
+    /// Every frequency, in the order shown in settings
+    pub const ALL: [AllowanceFrequency; 3] =
+        [AllowanceFrequency::Weekly, AllowanceFrequency::EveryNWeeks, AllowanceFrequency::Monthly];
+
+    /// Label shown in settings
+    pub fn label(&self) -> &'static str {
+        match self {
+            AllowanceFrequency::Weekly => "Weekly",
+            AllowanceFrequency::Monthly => "Monthly",
+            AllowanceFrequency::EveryNWeeks => "Every few weeks",
+        }
+    }
+}
+
+/// How the first allowance on a new day is paid after the allowance day changes.
+///
+/// Moving the day mid-week leaves a gap between the last allowance on the old
+/// day and the first one on the new day that isn't a week long: moving Friday
+/// to Sunday on a Saturday puts two allowances two days apart, while moving it
+/// on a Wednesday leaves nine days without one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DayChangePolicy {
+    /// Drop the first allowance on the new day if it comes less than a week
+    /// after the last one, so no week ever gets two
+    Skip,
+    /// Scale the first allowance on the new day by how many days it covers
+    Prorate,
+    /// Pay every allowance on the schedule in full, even if two land in one week
+    #[default]
+    Double,
+}
+
+impl DayChangePolicy {
+    /// Every policy, in the order shown in settings
+    pub const ALL: [DayChangePolicy; 3] = [DayChangePolicy::Skip, DayChangePolicy::Prorate, DayChangePolicy::Double];
+
+    /// Label shown in settings
+    pub fn label(&self) -> &'static str {
+        match self {
+            DayChangePolicy::Skip => "Skip the extra allowance",
+            DayChangePolicy::Prorate => "Prorate the first allowance",
+            DayChangePolicy::Double => "Pay both in full",
+        }
+    }
+}
+
+/// A change of frequency, day of month or interval, recorded so catching up
+/// on missed allowances pays earlier dates on the old schedule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllowanceScheduleChange {
+    pub previous_frequency: AllowanceFrequency,
+    pub previous_day_of_week: u8,
+    pub previous_day_of_month: u8,
+    #[serde(default = "default_interval_weeks")]
+    pub previous_interval_weeks: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_anchor_date: Option<NaiveDate>,
+    /// First date paid on the new schedule
+    pub effective_date: NaiveDate,
+}
+
+impl AllowanceScheduleChange {
+    /// The schedule `config` pays on now, as the one a change on `effective_date` replaces
+    pub fn from_config(config: &AllowanceConfig, effective_date: NaiveDate) -> Self {
+        Self {
+            previous_frequency: config.frequency,
+            previous_day_of_week: config.day_of_week,
+            previous_day_of_month: config.day_of_month,
+            previous_interval_weeks: config.interval_weeks,
+            previous_anchor_date: config.anchor_date,
+            effective_date,
+        }
+    }
+
+    /// Whether the previous schedule pays on the same dates as the given one.
+    /// Weekly days of week are left to `AllowanceDayChange`.
+    pub fn matches(
+        &self,
+        frequency: AllowanceFrequency,
+        day_of_month: u8,
+        interval_weeks: u8,
+        anchor_date: Option<NaiveDate>,
+    ) -> bool {
+        self.previous_frequency == frequency
+            && match frequency {
+                AllowanceFrequency::Weekly => true,
+                AllowanceFrequency::Monthly => self.previous_day_of_month == day_of_month,
+                AllowanceFrequency::EveryNWeeks => {
+                    self.previous_interval_weeks == interval_weeks && self.previous_anchor_date == anchor_date
+                }
+            }
+    }
+}
+
+/// A change of allowance day, recorded so the switch-over week is paid correctly
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllowanceDayChange {
+    /// Day of week allowances were paid on before the change
+    pub previous_day_of_week: u8,
+    /// First date paid on the new schedule; earlier dates use the previous day
+    pub effective_date: NaiveDate,
 }
 
 impl AllowanceConfig {
     /// Get the day name for the configured day of week
     pub fn day_name(&self) -> &'static str {
-        match self.day_of_week {
-            0 => "Sunday",
-            1 => "Monday",
-            2 => "Tuesday",
-            3 => "Wednesday",
-            4 => "Thursday",
-            5 => "Friday",
-            6 => "Saturday",
-            _ => "Invalid",
-        }
+        weekday_name(self.day_of_week)
     }
     
     /// Validate day of week value
     pub fn is_valid_day_of_week(day: u8) -> bool {
         day <= 6
     }
-} 
\ No newline at end of file
+
+    /// Validate day of month value
+    pub fn is_valid_day_of_month(day: u8) -> bool {
+        (1..=31).contains(&day)
+    }
+
+    /// Validate the weeks between every-few-weeks allowances
+    pub fn is_valid_interval_weeks(weeks: u8) -> bool {
+        (MIN_INTERVAL_WEEKS..=MAX_INTERVAL_WEEKS).contains(&weeks)
+    }
+
+    /// When the allowance is paid, e.g. "every Friday" or "on the 1st of every month"
+    pub fn schedule_label(&self) -> String {
+        match self.frequency {
+            AllowanceFrequency::Weekly => format!("every {}", self.day_name()),
+            AllowanceFrequency::Monthly if self.day_of_month > 28 => {
+                format!("on the {} of every month (or its last day)", ordinal(self.day_of_month))
+            }
+            AllowanceFrequency::Monthly => format!("on the {} of every month", ordinal(self.day_of_month)),
+            AllowanceFrequency::EveryNWeeks => {
+                let Some(anchor) = self.anchor_date else {
+                    return format!("every {} weeks", self.interval_weeks);
+                };
+                let weekday = weekday_name(anchor.weekday().num_days_from_sunday() as u8);
+                let every = match self.interval_weeks {
+                    2 => format!("every other {}", weekday),
+                    weeks => format!("every {} weeks on {}", weeks, weekday),
+                };
+                format!("{} from {}", every, anchor.format("%b %-d, %Y"))
+            }
+        }
+    }
+
+    /// Description given to the allowance transactions this config pays
+    pub fn payment_description(&self) -> String {
+        match self.frequency {
+            AllowanceFrequency::Weekly => "Weekly allowance".to_string(),
+            AllowanceFrequency::Monthly => "Monthly allowance".to_string(),
+            AllowanceFrequency::EveryNWeeks if self.interval_weeks == 2 => "Biweekly allowance".to_string(),
+            AllowanceFrequency::EveryNWeeks => format!("Allowance (every {} weeks)", self.interval_weeks),
+        }
+    }
+
+    /// The date a monthly allowance is paid in the given month, moved back
+    /// to the month's last day when it is shorter than `day_of_month`
+    pub fn monthly_payday(&self, year: i32, month: u32) -> Option<NaiveDate> {
+        (1..=self.day_of_month.max(1) as u32)
+            .rev()
+            .find_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+    }
+
+    /// Amount of allowance due on `date`, or `None` if nothing is due that day.
+    ///
+    /// Dates before the most recent day change follow the previous day of
+    /// week; the first allowance on the new day is adjusted by the
+    /// `day_change_policy`. A monthly allowance is due on its payday in each
+    /// month, and an every-few-weeks one on its anchor date and every
+    /// `interval_weeks` weeks after it. Dates before a change of frequency,
+    /// day of month or interval follow the previous schedule. Only dates are compared, so daylight saving
+    /// changes never shift an allowance onto a different day.
+    pub fn amount_due_on(&self, date: NaiveDate) -> Option<f64> {
+        if let Some(change) = self.schedule_change {
+            if date < change.effective_date {
+                let previous = AllowanceConfig {
+                    frequency: change.previous_frequency,
+                    day_of_week: change.previous_day_of_week,
+                    day_of_month: change.previous_day_of_month,
+                    interval_weeks: change.previous_interval_weeks,
+                    anchor_date: change.previous_anchor_date,
+                    schedule_change: None,
+                    ..self.clone()
+                };
+                return previous.amount_due_on(date);
+            }
+        }
+        match self.frequency {
+            AllowanceFrequency::Monthly => {
+                return (self.monthly_payday(date.year(), date.month()) == Some(date)).then_some(self.amount);
+            }
+            AllowanceFrequency::EveryNWeeks => {
+                let anchor = self.anchor_date?;
+                let days = (date - anchor).num_days();
+                let period = 7 * self.interval_weeks.max(1) as i64;
+                return (days >= 0 && days % period == 0).then_some(self.amount);
+            }
+            AllowanceFrequency::Weekly => {}
+        }
+
+        let day_of_week = date.weekday().num_days_from_sunday() as u8;
+
+        let Some(change) = self.day_change else {
+            return (day_of_week == self.day_of_week).then_some(self.amount);
+        };
+
+        if date < change.effective_date {
+            return (day_of_week == change.previous_day_of_week).then_some(self.amount);
+        }
+        if day_of_week != self.day_of_week {
+            return None;
+        }
+        if date != next_weekday_on_or_after(change.effective_date, self.day_of_week) {
+            return Some(self.amount);
+        }
+
+        // First allowance on the new day: compare against the last one on the old day
+        let last_old = next_weekday_on_or_after(change.effective_date - Duration::days(7), change.previous_day_of_week);
+        let gap_days = (date - last_old).num_days();
+
+        match self.day_change_policy {
+            DayChangePolicy::Double => Some(self.amount),
+            DayChangePolicy::Skip if gap_days < 7 => None,
+            DayChangePolicy::Skip => Some(self.amount),
+            DayChangePolicy::Prorate => Some((self.amount * gap_days as f64 / 7.0 * 100.0).round() / 100.0),
+        }
+    }
+}
+
+/// Name of a day of the week (0 = Sunday)
+fn weekday_name(day_of_week: u8) -> &'static str {
+    match day_of_week {
+        0 => "Sunday",
+        1 => "Monday",
+        2 => "Tuesday",
+        3 => "Wednesday",
+        4 => "Thursday",
+        5 => "Friday",
+        6 => "Saturday",
+        _ => "Invalid",
+    }
+}
+
+/// "1st", "2nd", "23rd" and so on, for days of the month
+fn ordinal(day: u8) -> String {
+    let suffix = match (day % 10, day % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", day, suffix)
+}
+
+/// First date on or after `date` that falls on `day_of_week` (0 = Sunday)
+fn next_weekday_on_or_after(date: NaiveDate, day_of_week: u8) -> NaiveDate {
+    let current = date.weekday().num_days_from_sunday() as i64;
+    date + Duration::days((day_of_week as i64 - current).rem_euclid(7))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(text: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+    }
+
+    /// $7 on Sundays, moved from Fridays with the change effective on `effective`
+    fn friday_to_sunday(policy: DayChangePolicy, effective: &str) -> AllowanceConfig {
+        AllowanceConfig {
+            child_id: "child".to_string(),
+            amount: 7.0,
+            day_of_week: 0,
+            is_active: true,
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+            updated_at: "2025-01-01T00:00:00Z".to_string(),
+            day_change_policy: policy,
+            day_change: Some(AllowanceDayChange { previous_day_of_week: 5, effective_date: date(effective) }),
+            frequency: AllowanceFrequency::Weekly,
+            day_of_month: 1,
+            interval_weeks: 2,
+            anchor_date: None,
+            schedule_change: None,
+        }
+    }
+
+    #[test]
+    fn test_day_change_short_week() {
+        // Changed on Saturday 2025-06-14, the day after Friday's allowance
+        let skip = friday_to_sunday(DayChangePolicy::Skip, "2025-06-14");
+        assert_eq!(skip.amount_due_on(date("2025-06-13")), Some(7.0)); // Old Friday still counts
+        assert_eq!(skip.amount_due_on(date("2025-06-15")), None); // Two days later: skipped
+        assert_eq!(skip.amount_due_on(date("2025-06-22")), Some(7.0));
+        assert_eq!(skip.amount_due_on(date("2025-06-20")), None); // Fridays are over
+
+        let prorate = friday_to_sunday(DayChangePolicy::Prorate, "2025-06-14");
+        assert_eq!(prorate.amount_due_on(date("2025-06-15")), Some(2.0));
+        assert_eq!(prorate.amount_due_on(date("2025-06-22")), Some(7.0));
+
+        let double = friday_to_sunday(DayChangePolicy::Double, "2025-06-14");
+        assert_eq!(double.amount_due_on(date("2025-06-13")), Some(7.0));
+        assert_eq!(double.amount_due_on(date("2025-06-15")), Some(7.0));
+    }
+
+    #[test]
+    fn test_day_change_long_week() {
+        // Changed on Wednesday 2025-06-11, before that week's Friday
+        let skip = friday_to_sunday(DayChangePolicy::Skip, "2025-06-11");
+        assert_eq!(skip.amount_due_on(date("2025-06-06")), Some(7.0));
+        assert_eq!(skip.amount_due_on(date("2025-06-13")), None);
+        assert_eq!(skip.amount_due_on(date("2025-06-15")), Some(7.0)); // Nine days: nothing to skip
+
+        let prorate = friday_to_sunday(DayChangePolicy::Prorate, "2025-06-11");
+        assert_eq!(prorate.amount_due_on(date("2025-06-15")), Some(9.0));
+
+        // Changed on the old day itself: that Friday is already on the new schedule
+        let prorate = friday_to_sunday(DayChangePolicy::Prorate, "2025-06-13");
+        assert_eq!(prorate.amount_due_on(date("2025-06-13")), None);
+        assert_eq!(prorate.amount_due_on(date("2025-06-15")), Some(9.0));
+
+        // Changed on the new day itself: it is paid straight away
+        let prorate = friday_to_sunday(DayChangePolicy::Prorate, "2025-06-15");
+        assert_eq!(prorate.amount_due_on(date("2025-06-15")), Some(2.0));
+    }
+
+    #[test]
+    fn test_day_change_across_daylight_saving() {
+        // US clocks change on Sunday 2025-03-09 and Sunday 2025-11-02
+        let prorate = friday_to_sunday(DayChangePolicy::Prorate, "2025-03-08");
+        assert_eq!(prorate.amount_due_on(date("2025-03-07")), Some(7.0));
+        assert_eq!(prorate.amount_due_on(date("2025-03-09")), Some(2.0));
+        assert_eq!(prorate.amount_due_on(date("2025-03-16")), Some(7.0));
+
+        let skip = friday_to_sunday(DayChangePolicy::Skip, "2025-11-01");
+        assert_eq!(skip.amount_due_on(date("2025-10-31")), Some(7.0));
+        assert_eq!(skip.amount_due_on(date("2025-11-02")), None);
+        assert_eq!(skip.amount_due_on(date("2025-11-09")), Some(7.0));
+    }
+
+    #[test]
+    fn test_no_day_change_uses_configured_day() {
+        let mut config = friday_to_sunday(DayChangePolicy::Skip, "2025-06-14");
+        config.day_change = None;
+        assert_eq!(config.amount_due_on(date("2025-06-15")), Some(7.0));
+        assert_eq!(config.amount_due_on(date("2025-06-13")), None);
+    }
+
+    #[test]
+    fn test_monthly_allowance_clamps_to_month_end() {
+        let mut config = friday_to_sunday(DayChangePolicy::Skip, "2025-06-14");
+        config.day_change = None;
+        config.frequency = AllowanceFrequency::Monthly;
+        config.day_of_month = 31;
+        assert_eq!(config.amount_due_on(date("2025-01-31")), Some(7.0));
+        assert_eq!(config.amount_due_on(date("2025-02-28")), Some(7.0));
+        assert_eq!(config.amount_due_on(date("2024-02-29")), Some(7.0));
+        assert_eq!(config.amount_due_on(date("2024-02-28")), None);
+        assert_eq!(config.amount_due_on(date("2025-04-30")), Some(7.0));
+        assert_eq!(config.amount_due_on(date("2025-06-15")), None); // No longer weekly
+        assert_eq!(config.schedule_label(), "on the 31st of every month (or its last day)");
+
+        config.day_of_month = 1;
+        assert_eq!(config.amount_due_on(date("2025-03-01")), Some(7.0));
+        assert_eq!(config.schedule_label(), "on the 1st of every month");
+    }
+
+    #[test]
+    fn test_schedule_change_keeps_earlier_dates_on_the_old_schedule() {
+        // Weekly on Sundays until the switch to the 1st of the month on 2025-06-10
+        let mut config = friday_to_sunday(DayChangePolicy::Double, "2025-06-14");
+        config.day_change = None;
+        config.frequency = AllowanceFrequency::Monthly;
+        config.schedule_change = Some(AllowanceScheduleChange {
+            previous_frequency: AllowanceFrequency::Weekly,
+            previous_day_of_week: 0,
+            previous_day_of_month: 1,
+            previous_interval_weeks: 2,
+            previous_anchor_date: None,
+            effective_date: date("2025-06-10"),
+        });
+        assert_eq!(config.amount_due_on(date("2025-06-01")), Some(7.0)); // A Sunday
+        assert_eq!(config.amount_due_on(date("2025-06-08")), Some(7.0));
+        assert_eq!(config.amount_due_on(date("2025-06-15")), None);
+        assert_eq!(config.amount_due_on(date("2025-07-01")), Some(7.0));
+    }
+
+    #[test]
+    fn test_every_n_weeks_counts_from_the_anchor_across_months() {
+        let mut config = friday_to_sunday(DayChangePolicy::Skip, "2025-06-14");
+        config.day_change = None;
+        config.frequency = AllowanceFrequency::EveryNWeeks;
+        config.anchor_date = Some(date("2025-01-24")); // A Friday payday
+        assert_eq!(config.amount_due_on(date("2025-01-17")), None); // Before the first payday
+        assert_eq!(config.amount_due_on(date("2025-01-24")), Some(7.0));
+        assert_eq!(config.amount_due_on(date("2025-01-31")), None);
+        assert_eq!(config.amount_due_on(date("2025-02-07")), Some(7.0));
+        assert_eq!(config.amount_due_on(date("2025-02-21")), Some(7.0));
+        assert_eq!(config.amount_due_on(date("2025-03-07")), Some(7.0));
+        assert_eq!(config.amount_due_on(date("2025-12-26")), Some(7.0)); // 48 weeks later
+        assert_eq!(config.schedule_label(), "every other Friday from Jan 24, 2025");
+
+        config.interval_weeks = 3;
+        assert_eq!(config.amount_due_on(date("2025-02-07")), None);
+        assert_eq!(config.amount_due_on(date("2025-02-14")), Some(7.0));
+        assert_eq!(config.payment_description(), "Allowance (every 3 weeks)");
+    }
+}
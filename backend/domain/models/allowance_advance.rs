@@ -0,0 +1,117 @@
+//! Domain model for allowance advances
+//!
+//! An advance pays part of a child's future allowance today. It is recorded as
+//! an income transaction now, plus a repayment schedule: one installment per
+//! upcoming allowance day. When an allowance day arrives its installment is
+//! taken back as an expense, so the child effectively receives a smaller
+//! allowance until the advance is repaid.
+
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// One deduction from a future allowance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdvanceInstallment {
+    /// Allowance day the deduction is taken on
+    pub due_date: NaiveDate,
+    /// Amount deducted (always positive)
+    pub amount: f64,
+    /// The repayment transaction, once the deduction has been taken
+    #[serde(default)]
+    pub transaction_id: Option<String>,
+}
+
+impl AdvanceInstallment {
+    /// Whether the deduction has been taken
+    pub fn is_paid(&self) -> bool {
+        self.transaction_id.is_some()
+    }
+}
+
+/// Part of a child's future allowance paid early
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AllowanceAdvance {
+    pub id: String,
+    pub child_id: String,
+    /// Amount paid early (always positive)
+    pub amount: f64,
+    pub date: DateTime<FixedOffset>,
+    /// The income transaction that paid the advance
+    pub transaction_id: String,
+    /// Deductions from upcoming allowances, earliest first
+    pub installments: Vec<AdvanceInstallment>,
+}
+
+impl AllowanceAdvance {
+    /// Generate an advance ID for a child
+    pub fn generate_id(child_id: &str, now_millis: u64) -> String {
+        format!("advance::{}_{}", child_id, now_millis)
+    }
+
+    /// Split `amount` evenly across allowance days, in cents
+    ///
+    /// Earlier installments take the leftover cents, so the schedule always
+    /// adds up to exactly `amount`.
+    pub fn split_installments(amount: f64, due_dates: &[NaiveDate]) -> Vec<AdvanceInstallment> {
+        if due_dates.is_empty() {
+            return Vec::new();
+        }
+        let total_cents = (amount * 100.0).round() as i64;
+        let count = due_dates.len() as i64;
+        due_dates
+            .iter()
+            .enumerate()
+            .map(|(index, due_date)| {
+                let cents = total_cents / count + i64::from((index as i64) < total_cents % count);
+                AdvanceInstallment {
+                    due_date: *due_date,
+                    amount: cents as f64 / 100.0,
+                    transaction_id: None,
+                }
+            })
+            .collect()
+    }
+
+    /// Amount still to be deducted, rounded to cents
+    pub fn outstanding(&self) -> f64 {
+        let unpaid: f64 = self.installments.iter().filter(|i| !i.is_paid()).map(|i| i.amount).sum();
+        (unpaid * 100.0).round() / 100.0
+    }
+
+    /// Whether every deduction has been taken
+    pub fn is_repaid(&self) -> bool {
+        self.installments.iter().all(AdvanceInstallment::is_paid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_installments_adds_up_to_the_advance() {
+        let dates: Vec<NaiveDate> = ["2024-03-01", "2024-03-08", "2024-03-15"]
+            .iter()
+            .map(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap())
+            .collect();
+        let installments = AllowanceAdvance::split_installments(10.0, &dates);
+        let amounts: Vec<f64> = installments.iter().map(|i| i.amount).collect();
+        assert_eq!(amounts, vec![3.34, 3.33, 3.33]);
+        assert_eq!(installments[2].due_date, dates[2]);
+
+        let mut advance = AllowanceAdvance {
+            id: AllowanceAdvance::generate_id("alice", 1),
+            child_id: "alice".to_string(),
+            amount: 10.0,
+            date: DateTime::parse_from_rfc3339("2024-02-27T12:00:00-05:00").unwrap(),
+            transaction_id: "in-1".to_string(),
+            installments,
+        };
+        assert_eq!(advance.id, "advance::alice_1");
+        assert_eq!(advance.outstanding(), 10.0);
+
+        advance.installments[0].transaction_id = Some("ex-1".to_string());
+        assert_eq!(advance.outstanding(), 6.66);
+        assert!(!advance.is_repaid());
+    }
+}
@@ -0,0 +1,71 @@
+//! Domain model for audit log entries
+//!
+//! The audit log records notable actions taken on a child's data, such as a
+//! parent overriding a money rule. Entries are append-only.
+
+use serde::{Deserialize, Serialize};
+use chrono::Utc;
+
+use super::actor::Actor;
+
+/// Action recorded when a parent overrides the "no negative balance" rule
+pub const AUDIT_ACTION_OVERDRAFT_OVERRIDE: &str = "overdraft_override";
+
+/// Action recorded when a transaction is entered by a known actor
+pub const AUDIT_ACTION_TRANSACTION_ADDED: &str = "transaction_added";
+
+/// Action recorded for each transaction a parent deletes
+pub const AUDIT_ACTION_TRANSACTION_DELETED: &str = "transaction_deleted";
+
+/// Action recorded when a transaction is undone with an offsetting entry
+pub const AUDIT_ACTION_TRANSACTION_REVERSED: &str = "transaction_reversed";
+
+/// Action recorded when a transaction's date or description is changed
+pub const AUDIT_ACTION_TRANSACTION_EDITED: &str = "transaction_edited";
+
+/// Action recorded once for each statement imported
+pub const AUDIT_ACTION_TRANSACTIONS_IMPORTED: &str = "transactions_imported";
+
+/// Action recorded when many transactions get a category at once, or that change is undone
+pub const AUDIT_ACTION_TRANSACTIONS_RECATEGORIZED: &str = "transactions_recategorized";
+
+/// Action recorded when a parent closes the books up to a date
+pub const AUDIT_ACTION_PERIOD_CLOSED: &str = "period_closed";
+
+/// Action recorded when a parent reopens a closed period
+pub const AUDIT_ACTION_PERIOD_REOPENED: &str = "period_reopened";
+
+/// Domain model for a single audit log entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// When the action happened (RFC 3339 timestamp)
+    pub timestamp: String,
+    /// Machine-readable action tag, e.g. `overdraft_override`
+    pub action: String,
+    /// Transaction affected by the action, if any
+    pub transaction_id: Option<String>,
+    /// Human-readable details
+    pub details: String,
+    /// Who took the action, when known
+    #[serde(default)]
+    pub actor: Option<Actor>,
+}
+
+impl AuditEntry {
+    /// Create a new audit entry timestamped now
+    pub fn new(action: &str, transaction_id: Option<String>, details: String) -> Self {
+        Self {
+            timestamp: Utc::now().to_rfc3339(),
+            action: action.to_string(),
+            transaction_id,
+            details,
+            actor: None,
+        }
+    }
+
+    /// Attribute the entry to whoever took the action
+    pub fn with_actor(mut self, actor: Option<Actor>) -> Self {
+        self.actor = actor;
+        self
+    }
+}
@@ -0,0 +1,92 @@
+//! Domain model for a child's background
+//!
+//! Each child can keep the bundled photo, switch to one of the theme packs
+//! that ship with the app, or use a picture of their own. Custom pictures
+//! are referenced by path rather than copied into the data directory, so if
+//! the file is moved or deleted the app falls back to the bundled photo.
+
+use serde::{Deserialize, Serialize};
+
+/// Picture formats the app can draw as a background
+pub const BACKGROUND_IMAGE_EXTENSIONS: [&str; 5] = ["png", "jpg", "jpeg", "gif", "webp"];
+
+/// A look that ships with the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemePack {
+    /// The bundled photo with a blue tint
+    #[default]
+    Classic,
+    Ocean,
+    Meadow,
+    Sunset,
+    StarryNight,
+}
+
+impl ThemePack {
+    /// Every pack, in the order settings lists them
+    pub const ALL: [ThemePack; 5] = [
+        ThemePack::Classic,
+        ThemePack::Ocean,
+        ThemePack::Meadow,
+        ThemePack::Sunset,
+        ThemePack::StarryNight,
+    ];
+
+    /// Label shown in settings
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThemePack::Classic => "Classic",
+            ThemePack::Ocean => "Ocean",
+            ThemePack::Meadow => "Meadow",
+            ThemePack::Sunset => "Sunset",
+            ThemePack::StarryNight => "Starry night",
+        }
+    }
+}
+
+/// What is drawn behind a child's calendar, table and charts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ChildBackground {
+    /// One of the bundled theme packs
+    Pack { pack: ThemePack },
+    /// A picture chosen from the computer
+    CustomImage { path: String },
+}
+
+impl Default for ChildBackground {
+    fn default() -> Self {
+        ChildBackground::Pack { pack: ThemePack::Classic }
+    }
+}
+
+/// Whether a file looks like a picture the app can draw, judging by its extension
+pub fn is_background_image(path: &str) -> bool {
+    std::path::Path::new(path)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| BACKGROUND_IMAGE_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_background_defaults_to_classic_pack() {
+        assert_eq!(ChildBackground::default(), ChildBackground::Pack { pack: ThemePack::Classic });
+        let yaml = serde_yaml::to_string(&ChildBackground::Pack { pack: ThemePack::StarryNight }).unwrap();
+        assert_eq!(serde_yaml::from_str::<ChildBackground>(&yaml).unwrap(), ChildBackground::Pack { pack: ThemePack::StarryNight });
+        assert!(yaml.contains("starry_night"));
+    }
+
+    #[test]
+    fn test_is_background_image_checks_extension() {
+        assert!(is_background_image("/home/kid/Pictures/dino.PNG"));
+        assert!(is_background_image("C:\\Users\\kid\\beach.jpeg"));
+        assert!(!is_background_image("/home/kid/notes.txt"));
+        assert!(!is_background_image("/home/kid/no_extension"));
+    }
+}
@@ -0,0 +1,24 @@
+//! Domain model for a child's monthly spending targets
+//!
+//! A family can set how much a child means to spend on a category each month
+//! ("Candy" up to $10, "Toys" up to $20). Spending is matched to a target by
+//! the transaction's category, ignoring case.
+
+use serde::{Deserialize, Serialize};
+
+/// How much a child means to spend on one category in a calendar month
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BudgetTarget {
+    pub child_id: String,
+    /// Category as the parent typed it, e.g. "Toys"
+    pub category: String,
+    /// Most the child means to spend on the category each month (always positive)
+    pub monthly_limit: f64,
+}
+
+impl BudgetTarget {
+    /// Whether a transaction category counts towards this target
+    pub fn matches_category(&self, category: &str) -> bool {
+        self.category.eq_ignore_ascii_case(category.trim())
+    }
+}
@@ -0,0 +1,137 @@
+//! Domain model for celebrations
+//!
+//! When something worth cheering happens, such as an allowance being paid or a
+//! goal being reached, the backend records a `Celebration` for the child.
+//! Frontends ask for the pending ones, play their animation, and acknowledge
+//! them so they aren't shown twice. Deciding what counts lives here rather
+//! than in each UI, so every frontend celebrates the same things.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::backend::domain::errors::DomainError;
+
+/// Balances worth celebrating the first time a child reaches them, unless a
+/// parent has chosen the child's own thresholds
+pub const BALANCE_MILESTONES: [f64; 7] = [10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0];
+
+/// Most milestone thresholds a parent can set for one child
+pub const MAX_BALANCE_MILESTONES: usize = 20;
+
+/// Most unacknowledged celebrations kept per child; older ones are dropped
+pub const MAX_PENDING_CELEBRATIONS: usize = 20;
+
+/// What is being celebrated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CelebrationKind {
+    AllowancePosted,
+    GoalCompleted,
+    BalanceMilestone,
+}
+
+/// Something that happened for a child and hasn't been celebrated yet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Celebration {
+    pub id: String,
+    pub child_id: String,
+    pub kind: CelebrationKind,
+    pub created_at: DateTime<Utc>,
+    /// Ready to show, e.g. "You reached $100.00!"
+    pub message: String,
+    /// The allowance paid, the goal's target or the milestone reached
+    pub amount: f64,
+}
+
+impl Celebration {
+    fn new(child_id: &str, kind: CelebrationKind, message: String, amount: f64) -> Self {
+        let created_at = Utc::now();
+        let kind_key = match kind {
+            CelebrationKind::AllowancePosted => "allowance",
+            CelebrationKind::GoalCompleted => "goal",
+            CelebrationKind::BalanceMilestone => "milestone",
+        };
+        Self {
+            id: format!("celebration::{}_{}_{}", kind_key, child_id, created_at.timestamp_micros()),
+            child_id: child_id.to_string(),
+            kind,
+            created_at,
+            message,
+            amount,
+        }
+    }
+
+    pub fn allowance_posted(child_id: &str, amount: f64) -> Self {
+        Self::new(child_id, CelebrationKind::AllowancePosted, format!("Your ${:.2} allowance is here!", amount), amount)
+    }
+
+    pub fn goal_completed(child_id: &str, goal_description: &str, target_amount: f64) -> Self {
+        Self::new(child_id, CelebrationKind::GoalCompleted, format!("You reached your goal: {}!", goal_description), target_amount)
+    }
+
+    pub fn balance_milestone(child_id: &str, milestone: f64) -> Self {
+        Self::new(child_id, CelebrationKind::BalanceMilestone, format!("You reached ${:.2}!", milestone), milestone)
+    }
+}
+
+/// A balance milestone a child has reached, for the milestone timeline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReachedMilestone {
+    pub amount: f64,
+    /// Date of the transaction that took the balance past it
+    pub reached_on: NaiveDate,
+    pub transaction_id: String,
+}
+
+/// The thresholds a balance change from `before` to `after` crosses on the
+/// way up, lowest first, leaving out any the child has already reached
+pub fn milestones_crossed(before: f64, after: f64, thresholds: &[f64], already_reached: &[f64]) -> Vec<f64> {
+    let mut crossed: Vec<f64> = thresholds
+        .iter()
+        .copied()
+        .filter(|&milestone| before < milestone && after >= milestone && !already_reached.contains(&milestone))
+        .collect();
+    crossed.sort_by(|a, b| a.total_cmp(b));
+    crossed
+}
+
+/// Check milestone thresholds chosen by a parent, returning them sorted with
+/// duplicates removed
+pub fn normalize_milestones(thresholds: &[f64]) -> Result<Vec<f64>, DomainError> {
+    if thresholds.len() > MAX_BALANCE_MILESTONES {
+        return Err(DomainError::invalid(format!("A child can have at most {} milestones", MAX_BALANCE_MILESTONES)));
+    }
+    if let Some(bad) = thresholds.iter().find(|t| !t.is_finite() || **t <= 0.0) {
+        return Err(DomainError::invalid(format!("Milestone {} must be a positive amount", bad)));
+    }
+    let mut normalized: Vec<f64> = thresholds.iter().map(|t| (t * 100.0).round() / 100.0).collect();
+    normalized.sort_by(|a, b| a.total_cmp(b));
+    normalized.dedup();
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_milestones_are_celebrated_once_on_the_way_up() {
+        let thresholds = BALANCE_MILESTONES;
+        assert_eq!(milestones_crossed(95.0, 105.0, &thresholds, &[10.0, 25.0, 50.0]), vec![100.0]);
+        // Jumping past several reaches them all
+        assert_eq!(milestones_crossed(20.0, 120.0, &thresholds, &[10.0]), vec![25.0, 50.0, 100.0]);
+        // Coming back up to one already reached doesn't count again
+        assert!(milestones_crossed(95.0, 105.0, &thresholds, &[100.0]).is_empty());
+        assert!(milestones_crossed(105.0, 95.0, &thresholds, &[]).is_empty());
+        assert!(milestones_crossed(101.0, 120.0, &thresholds, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_parent_milestones_are_sorted_and_checked() {
+        assert_eq!(normalize_milestones(&[100.0, 25.0, 50.0, 25.0]).unwrap(), vec![25.0, 50.0, 100.0]);
+        assert_eq!(normalize_milestones(&[]).unwrap(), Vec::<f64>::new());
+        assert!(normalize_milestones(&[25.0, 0.0]).is_err());
+        assert!(normalize_milestones(&[f64::NAN]).is_err());
+        assert!(normalize_milestones(&[1.0; MAX_BALANCE_MILESTONES + 1]).is_err());
+    }
+}
@@ -0,0 +1,104 @@
+//! Domain model for per-child settings
+//!
+//! Child settings hold optional rules that apply to a single child's money
+//! (as opposed to the allowance schedule, which lives in `allowance.rs`).
+//! Every field has a sensible default so a child without a settings file
+//! behaves exactly as before.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::backend::domain::localization::Locale;
+use crate::backend::domain::models::background::ChildBackground;
+use crate::backend::domain::models::celebration::BALANCE_MILESTONES;
+use crate::backend::domain::models::currency::Currency;
+
+/// Domain model for a child's optional money rules
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChildSettings {
+    pub child_id: String,
+    /// When true, spends that would take the balance below zero are rejected
+    pub prevent_negative_balance: bool,
+    /// Percentage (0-100) of every income transaction earmarked to savings; 0 disables
+    pub auto_save_percentage: f64,
+    /// When true, auto-saved amounts contribute to the active goal if there is one
+    pub auto_save_to_goal: bool,
+    /// When true, every spend is rounded up to the next dollar and the change
+    /// is earmarked to savings (or the active goal, with `auto_save_to_goal`)
+    pub round_up_spends: bool,
+    /// Date and number formatting for this child; None uses the global locale
+    pub locale: Option<Locale>,
+    /// Currency this child's money is counted in; None uses the family currency
+    pub currency: Option<Currency>,
+    /// Balances to celebrate, lowest first; None uses `BALANCE_MILESTONES`
+    pub balance_milestones: Option<Vec<f64>>,
+    /// Theme pack or picture drawn behind this child's views; None uses the classic look
+    pub background: Option<ChildBackground>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl ChildSettings {
+    /// Default settings for a child that has never been configured
+    pub fn default_for_child(child_id: &str) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            child_id: child_id.to_string(),
+            prevent_negative_balance: false,
+            auto_save_percentage: 0.0,
+            auto_save_to_goal: false,
+            round_up_spends: false,
+            locale: None,
+            currency: None,
+            balance_milestones: None,
+            background: None,
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    /// The balances this child's milestones are celebrated at
+    pub fn milestone_thresholds(&self) -> Vec<f64> {
+        self.balance_milestones.clone().unwrap_or_else(|| BALANCE_MILESTONES.to_vec())
+    }
+
+    /// The background drawn behind this child's views
+    pub fn background(&self) -> ChildBackground {
+        self.background.clone().unwrap_or_default()
+    }
+}
+
+/// Error returned when a spend would overdraw a child who has the
+/// "no negative balance" rule enabled
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error("Insufficient funds: this spend would overdraw the balance by ${shortfall:.2} (available: ${available:.2})")]
+pub struct InsufficientFundsError {
+    /// Amount the child is short by (always positive)
+    pub shortfall: f64,
+    /// Balance available before the spend
+    pub available: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_settings_allow_negative_balance() {
+        let settings = ChildSettings::default_for_child("alice");
+        assert_eq!(settings.child_id, "alice");
+        assert!(!settings.prevent_negative_balance);
+        assert_eq!(settings.auto_save_percentage, 0.0);
+        assert!(!settings.auto_save_to_goal);
+        assert!(!settings.round_up_spends);
+        assert_eq!(settings.locale, None);
+    }
+
+    #[test]
+    fn test_insufficient_funds_message_includes_shortfall() {
+        let error = InsufficientFundsError { shortfall: 2.5, available: 7.5 };
+        let message = error.to_string();
+        assert!(message.contains("$2.50"));
+        assert!(message.contains("$7.50"));
+    }
+}
@@ -0,0 +1,51 @@
+//! Domain model for a child's startup snapshot
+//!
+//! Reading and parsing every transaction before the first frame gets slow as
+//! the files grow. A `ChildSnapshot` keeps the few things the opening screen
+//! needs — the balance, the recent transactions and the active goal — in one
+//! small file, so the app can show them straight away and check them against
+//! the full data in the background.
+
+use chrono::{DateTime, Duration, FixedOffset, Utc};
+use serde::{Deserialize, Serialize};
+use shared::GoalCalculation;
+
+use super::goal::DomainGoal;
+use super::transaction::Transaction;
+
+/// How many days of transactions a snapshot keeps
+pub const SNAPSHOT_RECENT_DAYS: i64 = 60;
+
+/// What the opening screen shows for a child, as of `written_at`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChildSnapshot {
+    pub child_id: String,
+    /// When the snapshot was built from the full data
+    pub written_at: DateTime<Utc>,
+    /// Balance after the latest transaction
+    pub balance: f64,
+    /// Transactions from the last `SNAPSHOT_RECENT_DAYS` days, newest first
+    pub recent_transactions: Vec<Transaction>,
+    /// The goal being saved for, if any
+    #[serde(default)]
+    pub active_goal: Option<DomainGoal>,
+    /// Projection for the active goal
+    #[serde(default)]
+    pub goal_calculation: Option<GoalCalculation>,
+}
+
+impl ChildSnapshot {
+    /// Earliest date a snapshot taken at `now` keeps transactions from
+    pub fn recent_cutoff(now: DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+        now - Duration::days(SNAPSHOT_RECENT_DAYS)
+    }
+
+    /// Whether two snapshots hold the same data, whenever they were written
+    pub fn same_data(&self, other: &ChildSnapshot) -> bool {
+        self.child_id == other.child_id
+            && self.balance == other.balance
+            && self.recent_transactions == other.recent_transactions
+            && self.active_goal == other.active_goal
+            && self.goal_calculation == other.goal_calculation
+    }
+}
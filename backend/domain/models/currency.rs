@@ -0,0 +1,80 @@
+//! Domain model for the currency a child's money is counted in
+//!
+//! Most families use one currency, but a child living or saving abroad can
+//! keep their allowance in another. Each child may set their own currency;
+//! the family view converts balances into the family's display currency
+//! using exchange rates entered by a parent.
+
+use serde::{Deserialize, Serialize};
+
+use crate::backend::domain::localization::Locale;
+
+/// Supported currencies, stored by ISO 4217 code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Currency {
+    #[default]
+    Usd,
+    Cad,
+    Aud,
+    Gbp,
+    Eur,
+}
+
+impl Currency {
+    /// Every supported currency, in the order shown in settings
+    pub const ALL: [Currency; 5] = [Currency::Usd, Currency::Cad, Currency::Aud, Currency::Gbp, Currency::Eur];
+
+    /// ISO 4217 code, e.g. "USD"
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::Usd => "USD",
+            Currency::Cad => "CAD",
+            Currency::Aud => "AUD",
+            Currency::Gbp => "GBP",
+            Currency::Eur => "EUR",
+        }
+    }
+
+    /// Parse an ISO 4217 code, ignoring case
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|currency| currency.code().eq_ignore_ascii_case(code.trim()))
+    }
+
+    /// Symbol written before amounts
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Currency::Usd => "$",
+            Currency::Cad => "CA$",
+            Currency::Aud => "A$",
+            Currency::Gbp => "£",
+            Currency::Eur => "€",
+        }
+    }
+
+    /// Format an amount with this currency's symbol and the locale's decimal separator
+    pub fn format(&self, amount: f64, locale: Locale) -> String {
+        let sign = if amount < 0.0 { "-" } else { "" };
+        format!("{}{}{}", sign, self.symbol(), locale.format_decimal(amount.abs(), 2))
+    }
+}
+
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currency_codes_and_formatting() {
+        assert_eq!(Currency::from_code("gbp"), Some(Currency::Gbp));
+        assert_eq!(Currency::from_code("XYZ"), None);
+        assert_eq!(serde_yaml::to_string(&Currency::Eur).unwrap().trim(), "EUR");
+        assert_eq!(Currency::Eur.format(-3.5, Locale::DeDe), "-€3,50");
+        assert_eq!(Currency::Usd.format(12.0, Locale::EnUs), "$12.00");
+    }
+}
@@ -0,0 +1,37 @@
+//! Domain model for desktop notification settings
+//!
+//! The app can pop up a system notification when an allowance is paid or a
+//! goal is completed, so a parent sees it without the window in front. Each
+//! kind of event is opted into separately, and all are off until a parent
+//! turns them on.
+
+use serde::{Deserialize, Serialize};
+
+use super::celebration::CelebrationKind;
+
+/// Which events show a desktop notification
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DesktopNotificationSettings {
+    /// Notify when a scheduled allowance is added
+    #[serde(default)]
+    pub allowance_posted: bool,
+    /// Notify when a child completes a goal
+    #[serde(default)]
+    pub goal_completed: bool,
+}
+
+impl DesktopNotificationSettings {
+    /// Whether every notification is off, so nothing needs saving
+    pub fn is_empty(&self) -> bool {
+        !self.allowance_posted && !self.goal_completed
+    }
+
+    /// Whether a celebration of this kind should also be a notification
+    pub fn wants(&self, kind: CelebrationKind) -> bool {
+        match kind {
+            CelebrationKind::AllowancePosted => self.allowance_posted,
+            CelebrationKind::GoalCompleted => self.goal_completed,
+            CelebrationKind::BalanceMilestone => false,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Domain model for app-wide display preferences
+//!
+//! These apply to the whole app rather than one child, so they live in the
+//! global config. Text size scales every font and row height together, so
+//! the table and calendar stay readable from across the room. The color
+//! theme picks light or dark colors, or follows the operating system.
+
+use serde::{Deserialize, Serialize};
+
+/// How large the app draws text and the rows around it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TextSize {
+    #[default]
+    Normal,
+    Large,
+    ExtraLarge,
+}
+
+impl TextSize {
+    /// Every text size, smallest first
+    pub const ALL: [TextSize; 3] = [TextSize::Normal, TextSize::Large, TextSize::ExtraLarge];
+
+    /// Factor applied to the whole UI
+    pub fn scale_factor(&self) -> f32 {
+        match self {
+            TextSize::Normal => 1.0,
+            TextSize::Large => 1.25,
+            TextSize::ExtraLarge => 1.5,
+        }
+    }
+
+    /// Label shown in settings
+    pub fn label(&self) -> &'static str {
+        match self {
+            TextSize::Normal => "Normal",
+            TextSize::Large => "Large",
+            TextSize::ExtraLarge => "Extra large",
+        }
+    }
+}
+
+/// Which colors the app draws with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorTheme {
+    #[default]
+    Light,
+    Dark,
+    /// Light or dark, whichever the operating system uses
+    System,
+}
+
+impl ColorTheme {
+    /// Every theme, in the order settings lists them
+    pub const ALL: [ColorTheme; 3] = [ColorTheme::Light, ColorTheme::Dark, ColorTheme::System];
+
+    /// Label shown in settings
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColorTheme::Light => "Light",
+            ColorTheme::Dark => "Dark",
+            ColorTheme::System => "Match my computer",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_sizes_grow() {
+        assert_eq!(TextSize::default(), TextSize::Normal);
+        assert_eq!(TextSize::Normal.scale_factor(), 1.0);
+        assert!(TextSize::ALL.windows(2).all(|pair| pair[0].scale_factor() < pair[1].scale_factor()));
+        assert_eq!(serde_yaml::to_string(&TextSize::ExtraLarge).unwrap().trim(), "extra_large");
+    }
+
+    #[test]
+    fn test_color_theme_defaults_to_light() {
+        assert_eq!(ColorTheme::default(), ColorTheme::Light);
+        assert_eq!(serde_yaml::to_string(&ColorTheme::System).unwrap().trim(), "system");
+        assert_eq!(serde_yaml::from_str::<ColorTheme>("dark").unwrap(), ColorTheme::Dark);
+    }
+}
@@ -0,0 +1,47 @@
+//! Domain model for the manifest included in full-data export archives.
+//!
+//! The manifest (`manifest.yaml` at the root of the archive) describes every
+//! child in the export along with their allowance config and goals, and
+//! points at the per-child transaction CSV inside the archive.
+//!
+//! Export archives are for reading elsewhere, not for restoring: they leave
+//! out settings, parental control and most per-child files. A folder is
+//! restored from the backup zip a reset writes instead (see
+//! `data_location::restore_backup_archive`), which refuses export archives.
+
+use serde::{Deserialize, Serialize};
+
+use super::allowance::AllowanceConfig;
+use super::child::Child;
+use super::goal::DomainGoal;
+
+/// Name of the manifest at the root of an export archive
+pub const EXPORT_MANIFEST_FILE: &str = "manifest.yaml";
+
+/// Version of the export archive layout
+///
+/// 1.1 writes each transactions CSV in the full `transactions.csv` layout,
+/// schema comment and actor, category and icon columns included.
+pub const EXPORT_FORMAT_VERSION: &str = "1.1";
+
+/// Top-level manifest describing an export archive
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportManifest {
+    pub format_version: String,
+    pub exported_at: String, // RFC 3339 timestamp
+    /// Names, dates and amounts were disguised for sharing; see `Anonymizer`
+    #[serde(default)]
+    pub anonymized: bool,
+    pub children: Vec<ExportManifestChild>,
+}
+
+/// Everything exported for a single child
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportManifestChild {
+    pub child: Child,
+    /// Path of the child's transactions CSV inside the archive
+    pub transactions_file: String,
+    pub transaction_count: usize,
+    pub allowance_config: Option<AllowanceConfig>,
+    pub goals: Vec<DomainGoal>,
+}
@@ -0,0 +1,47 @@
+//! Domain model for experimental feature flags
+//!
+//! Features still being tried out stay hidden until a parent turns them on
+//! for this data directory. The flags are kept in the global config, so an
+//! experiment can be tried on one family's machine without a special build.
+
+use serde::{Deserialize, Serialize};
+
+/// An experimental feature that can be turned on at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeatureFlag {
+    Chores,
+    Achievements,
+    Sync,
+    Interest,
+}
+
+impl FeatureFlag {
+    /// Every flag, in the order settings lists them
+    pub const ALL: [FeatureFlag; 4] = [
+        FeatureFlag::Chores,
+        FeatureFlag::Achievements,
+        FeatureFlag::Sync,
+        FeatureFlag::Interest,
+    ];
+
+    /// Name shown in settings and errors
+    pub fn label(&self) -> &'static str {
+        match self {
+            FeatureFlag::Chores => "Chores",
+            FeatureFlag::Achievements => "Achievements",
+            FeatureFlag::Sync => "Sync",
+            FeatureFlag::Interest => "Interest",
+        }
+    }
+
+    /// One line on what turning the flag on does
+    pub fn description(&self) -> &'static str {
+        match self {
+            FeatureFlag::Chores => "Pay for chores the child ticks off",
+            FeatureFlag::Achievements => "Award badges for saving milestones",
+            FeatureFlag::Sync => "Share data with other devices",
+            FeatureFlag::Interest => "Pay interest on savings",
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Domain model for the fridge view page
+//!
+//! The fridge view is a single HTML file with every child's balance, recent
+//! transactions and goal progress, meant to be left open on a tablet or
+//! another computer without running the app there. A parent chooses where
+//! the file goes, and can have the app rewrite it as the data changes.
+
+use serde::{Deserialize, Serialize};
+
+/// Where the fridge view is written and whether the app keeps it up to date
+///
+/// When it was last written isn't stored here: the file's own modification
+/// time says, so keeping it fresh doesn't rewrite the global config.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FridgeViewSettings {
+    /// The HTML file to write; `None` until a parent chooses one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Whether the periodic refresh rewrites the file
+    #[serde(default)]
+    pub auto_update: bool,
+}
+
+impl FridgeViewSettings {
+    /// Whether nothing about the fridge view needs saving
+    pub fn is_empty(&self) -> bool {
+        self.path.is_none() && !self.auto_update
+    }
+}
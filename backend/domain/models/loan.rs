@@ -0,0 +1,128 @@
+//! Domain model for money fronted between a parent and a child
+//!
+//! Sometimes a parent fronts money and the child pays it back later, or the
+//! child pays for something on a parent's behalf and is reimbursed. Each of
+//! these is a `Loan`: an opening transaction that moves the money, plus the
+//! settlement transactions that pay it back, linked by transaction id. Once a
+//! loan is fully settled its transactions net to zero on the child's balance.
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+/// Who owes whom
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoanDirection {
+    /// A parent fronted money; the child pays it back
+    ChildOwesParent,
+    /// The child paid on a parent's behalf; the parent reimburses them
+    ParentOwesChild,
+}
+
+impl LoanDirection {
+    /// Sign of the opening transaction: money in when a parent fronts it,
+    /// money out when the child pays on a parent's behalf
+    pub fn opening_sign(&self) -> f64 {
+        match self {
+            LoanDirection::ChildOwesParent => 1.0,
+            LoanDirection::ParentOwesChild => -1.0,
+        }
+    }
+
+    /// Description of the opening transaction
+    pub fn opening_description(&self, description: &str) -> String {
+        match self {
+            LoanDirection::ChildOwesParent => format!("Loan from parent: {}", description),
+            LoanDirection::ParentOwesChild => format!("Paid for parent: {}", description),
+        }
+    }
+
+    /// Description of a settlement transaction
+    pub fn settlement_description(&self, description: &str) -> String {
+        match self {
+            LoanDirection::ChildOwesParent => format!("Paid back parent: {}", description),
+            LoanDirection::ParentOwesChild => format!("Reimbursed by parent: {}", description),
+        }
+    }
+}
+
+/// A repayment towards a loan, recorded as its own transaction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoanSettlement {
+    /// The transaction that moved the money back
+    pub transaction_id: String,
+    pub date: DateTime<FixedOffset>,
+    /// Amount repaid (always positive)
+    pub amount: f64,
+}
+
+/// Money owed between a parent and a child
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Loan {
+    pub id: String,
+    pub child_id: String,
+    pub direction: LoanDirection,
+    /// What the money was for, e.g. "Video game"
+    pub description: String,
+    /// Amount originally owed (always positive)
+    pub amount: f64,
+    pub date: DateTime<FixedOffset>,
+    /// The transaction that opened the loan
+    pub transaction_id: String,
+    /// Repayments so far, oldest first
+    pub settlements: Vec<LoanSettlement>,
+}
+
+impl Loan {
+    /// Generate a loan ID for a child
+    pub fn generate_id(child_id: &str, now_millis: u64) -> String {
+        format!("loan::{}_{}", child_id, now_millis)
+    }
+
+    /// Total repaid so far, rounded to cents
+    pub fn settled_amount(&self) -> f64 {
+        (self.settlements.iter().map(|s| s.amount).sum::<f64>() * 100.0).round() / 100.0
+    }
+
+    /// Amount still owed, rounded to cents and never negative
+    pub fn outstanding(&self) -> f64 {
+        (((self.amount - self.settled_amount()) * 100.0).round() / 100.0).max(0.0)
+    }
+
+    /// Whether the loan has been paid back in full
+    pub fn is_settled(&self) -> bool {
+        self.outstanding() <= 0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outstanding_tracks_settlements() {
+        let date = DateTime::parse_from_rfc3339("2024-03-01T12:00:00-05:00").unwrap();
+        let mut loan = Loan {
+            id: Loan::generate_id("alice", 1),
+            child_id: "alice".to_string(),
+            direction: LoanDirection::ChildOwesParent,
+            description: "Game".to_string(),
+            amount: 20.0,
+            date,
+            transaction_id: "in-1".to_string(),
+            settlements: Vec::new(),
+        };
+        assert_eq!(loan.id, "loan::alice_1");
+        assert_eq!(loan.outstanding(), 20.0);
+
+        for amount in [0.1, 0.2, 9.7] {
+            loan.settlements.push(LoanSettlement { transaction_id: "ex-1".to_string(), date, amount });
+        }
+        assert_eq!(loan.settled_amount(), 10.0);
+        assert_eq!(loan.outstanding(), 10.0);
+        assert!(!loan.is_settled());
+
+        loan.settlements.push(LoanSettlement { transaction_id: "ex-2".to_string(), date, amount: 10.0 });
+        assert!(loan.is_settled());
+    }
+}
@@ -1,7 +1,33 @@
 //! src-tauri/src/backend/domain/models/mod.rs
 
+pub mod actor;
 pub mod allowance;
+pub mod allowance_advance;
+pub mod audit_entry;
+pub mod background;
+pub mod budget_target;
+pub mod celebration;
 pub mod child;
+pub mod child_settings;
+pub mod child_snapshot;
+pub mod currency;
+pub mod desktop_notifications;
+pub mod display_preferences;
+pub mod export_manifest;
+pub mod feature_flag;
+pub mod fridge_view;
 pub mod goal;
+pub mod loan;
+pub mod parent_user;
 pub mod parental_control_attempt;
-pub mod transaction; 
\ No newline at end of file
+pub mod period_lock;
+pub mod planned_purchase;
+pub mod quick_pick;
+pub mod report_schedule;
+pub mod retention_policy;
+pub mod savings_allocation;
+pub mod transaction;
+pub mod transaction_icon;
+pub mod transaction_template;
+pub mod transaction_reversal;
+pub mod update_settings; 
\ No newline at end of file
@@ -0,0 +1,62 @@
+//! Domain model for named parent accounts
+//!
+//! Besides the shared family answer, a household can add named parents or
+//! guardians, each with their own PIN and a set of permissions. A grandparent
+//! might get into settings without being allowed to delete transactions.
+//! Parents belong to the whole family, so they live in the global config.
+
+use serde::{Deserialize, Serialize};
+
+/// Something a parent can be allowed to do after entering their PIN
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParentPermission {
+    /// Remove transactions from the table
+    DeleteTransactions,
+    /// Open the settings menu (allowance, loans, profiles, ...)
+    ManageSettings,
+    /// Let a spend take a child below the "no negative balance" floor
+    OverrideBalanceFloor,
+}
+
+impl ParentPermission {
+    /// Every permission, in the order shown in settings
+    pub const ALL: [ParentPermission; 3] = [
+        ParentPermission::DeleteTransactions,
+        ParentPermission::ManageSettings,
+        ParentPermission::OverrideBalanceFloor,
+    ];
+
+    /// Label shown in settings
+    pub fn label(&self) -> &'static str {
+        match self {
+            ParentPermission::DeleteTransactions => "Delete transactions",
+            ParentPermission::ManageSettings => "Change settings",
+            ParentPermission::OverrideBalanceFloor => "Overdraw a balance",
+        }
+    }
+}
+
+/// A named parent or guardian who signs in with a PIN
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParentUser {
+    pub id: String,
+    pub name: String,
+    /// Argon2id hash of the PIN in PHC string format
+    pub pin_hash: String,
+    #[serde(default)]
+    pub permissions: Vec<ParentPermission>,
+    pub created_at: String,
+}
+
+impl ParentUser {
+    /// Generate a parent ID
+    pub fn generate_id(now_millis: u64) -> String {
+        format!("parent::{}", now_millis)
+    }
+
+    /// Whether this parent may do something
+    pub fn can(&self, permission: ParentPermission) -> bool {
+        self.permissions.contains(&permission)
+    }
+}
@@ -0,0 +1,75 @@
+//! Domain model for a child's closed period
+//!
+//! After a monthly review a parent can close the books up to a date.
+//! Transactions dated on or before it are locked: they can't be deleted, and
+//! nothing new can be dated into the closed period, until a parent reopens it.
+
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::actor::Actor;
+
+/// How far a child's transactions are closed, and who closed them
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PeriodLock {
+    pub child_id: String,
+    /// The last day of the closed period, inclusive
+    pub locked_through: NaiveDate,
+    /// When the period was closed
+    pub closed_at: DateTime<Utc>,
+    /// Who closed it, when known
+    pub closed_by: Option<Actor>,
+}
+
+impl PeriodLock {
+    /// Whether a transaction dated `date` falls in the closed period
+    pub fn covers(&self, date: DateTime<FixedOffset>) -> bool {
+        date.date_naive() <= self.locked_through
+    }
+
+    /// The last day of a month, for closing a whole month at once
+    pub fn month_end(year: i32, month: u32) -> Option<NaiveDate> {
+        let first_of_next = if month == 12 {
+            NaiveDate::from_ymd_opt(year + 1, 1, 1)
+        } else {
+            NaiveDate::from_ymd_opt(year, month + 1, 1)
+        };
+        NaiveDate::from_ymd_opt(year, month, 1)?;
+        first_of_next?.pred_opt()
+    }
+
+    /// The month that is normally closed next: the one before `today`'s
+    pub fn previous_month_end(today: NaiveDate) -> NaiveDate {
+        today.with_day(1).and_then(|first| first.pred_opt()).unwrap_or(today)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_covers_the_whole_last_day() {
+        let lock = PeriodLock {
+            child_id: "child::1".to_string(),
+            locked_through: NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(),
+            closed_at: Utc::now(),
+            closed_by: None,
+        };
+        let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap();
+        assert!(lock.covers(at("2025-03-31T23:59:00-04:00")));
+        assert!(lock.covers(at("2024-12-01T08:00:00-05:00")));
+        assert!(!lock.covers(at("2025-04-01T00:00:00-04:00")));
+    }
+
+    #[test]
+    fn test_month_ends() {
+        assert_eq!(PeriodLock::month_end(2024, 2), NaiveDate::from_ymd_opt(2024, 2, 29));
+        assert_eq!(PeriodLock::month_end(2025, 12), NaiveDate::from_ymd_opt(2025, 12, 31));
+        assert_eq!(PeriodLock::month_end(2025, 13), None);
+        assert_eq!(
+            PeriodLock::previous_month_end(NaiveDate::from_ymd_opt(2025, 1, 15).unwrap()),
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()
+        );
+    }
+}
@@ -0,0 +1,53 @@
+//! Domain model for planned purchases
+//!
+//! A planned purchase is a child's commitment to buy something for a set
+//! amount on a future date. Until it is confirmed or cancelled the amount is
+//! reserved: it still counts towards the balance, but not towards what is
+//! available to spend. Confirming turns it into an ordinary expense.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Where a planned purchase is in its life
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlannedPurchaseState {
+    /// Money is reserved and the purchase hasn't happened yet
+    Planned,
+    /// The purchase happened and was recorded as an expense
+    Confirmed,
+    /// The plan was dropped and the money released
+    Cancelled,
+}
+
+/// Something a child has committed to buy
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlannedPurchase {
+    pub id: String,
+    pub child_id: String,
+    pub description: String,
+    /// Amount reserved (always positive)
+    pub amount: f64,
+    /// Day the child plans to buy it
+    pub planned_date: NaiveDate,
+    pub state: PlannedPurchaseState,
+    /// The expense recorded on confirmation
+    pub transaction_id: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl PlannedPurchase {
+    /// Prefix shared by every planned purchase ID
+    pub const ID_PREFIX: &'static str = "planned::";
+
+    /// Generate a planned purchase ID for a child
+    pub fn generate_id(child_id: &str, now_millis: u64) -> String {
+        format!("{}{}_{}", Self::ID_PREFIX, child_id, now_millis)
+    }
+
+    /// Whether the amount is still reserved
+    pub fn is_open(&self) -> bool {
+        self.state == PlannedPurchaseState::Planned
+    }
+}
@@ -0,0 +1,35 @@
+//! Domain model for a child's quick-pick descriptions
+//!
+//! Quick picks are the descriptions a family types over and over ("Lunch
+//! money", "Book fair"), saved per child so the money forms can offer them as
+//! one-click choices. A quick pick can also carry the amount it usually is.
+
+use serde::{Deserialize, Serialize};
+
+/// Which money form a quick pick is offered in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuickPickKind {
+    /// Offered when adding money
+    Income,
+    /// Offered when spending money
+    Expense,
+}
+
+/// A saved description for one child
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuickPick {
+    pub id: String,
+    pub child_id: String,
+    pub description: String,
+    /// Amount filled in along with the description, if any (always positive)
+    pub amount: Option<f64>,
+    pub kind: QuickPickKind,
+}
+
+impl QuickPick {
+    /// Generate a quick pick ID for a child
+    pub fn generate_id(child_id: &str, now_millis: u64) -> String {
+        format!("quickpick::{}_{}", child_id, now_millis)
+    }
+}
@@ -0,0 +1,46 @@
+//! Domain model for monthly reports written to a folder
+//!
+//! When a parent picks a folder, each child's report for the month just
+//! ended is written there as a CSV file on the 1st of the next month (or the
+//! first time the app is opened after it). Every file written is recorded so
+//! the settings screen can list them.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Most generated files remembered; older entries are dropped from the list,
+/// not from disk
+pub const MAX_REPORT_HISTORY: usize = 240;
+
+/// Where monthly reports go and which have been written
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReportSchedule {
+    /// Folder reports are written to; `None` turns the schedule off
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder: Option<String>,
+    /// First day of the latest month the scheduled run covered
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_month_generated: Option<NaiveDate>,
+    /// Files written so far, newest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub history: Vec<GeneratedReport>,
+}
+
+impl ReportSchedule {
+    /// Whether nothing about the schedule needs saving
+    pub fn is_empty(&self) -> bool {
+        self.folder.is_none() && self.last_month_generated.is_none() && self.history.is_empty()
+    }
+}
+
+/// One report file that was written
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeneratedReport {
+    pub child_id: String,
+    pub child_name: String,
+    pub year: i32,
+    pub month: u32,
+    pub path: String,
+    /// RFC 3339 timestamp
+    pub generated_at: String,
+}
@@ -0,0 +1,85 @@
+//! Domain model for data retention rules
+//!
+//! Some files only ever grow: the audit log, the parental challenge attempts
+//! and the originals kept when old months are archived. A family can choose
+//! how long each is kept, and anything older is removed once a day while the
+//! app is open. Every rule is off until a parent sets it, so nothing is
+//! removed by default.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// How long each kind of growing record is kept; `None` keeps it forever
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionPolicy {
+    /// Audit log entries older than this many days are removed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audit_log_days: Option<u32>,
+    /// Parental challenge attempts older than this many days are removed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parental_control_attempt_days: Option<u32>,
+    /// Archived original transactions older than this many years are removed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_transaction_years: Option<u32>,
+    /// Day the scheduled run last applied the rules
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_run_on: Option<NaiveDate>,
+}
+
+impl RetentionPolicy {
+    /// Whether no rule is set, so a run would remove nothing
+    pub fn has_no_rules(&self) -> bool {
+        self.audit_log_days.is_none()
+            && self.parental_control_attempt_days.is_none()
+            && self.archived_transaction_years.is_none()
+    }
+
+    /// Whether nothing about the policy needs saving
+    pub fn is_empty(&self) -> bool {
+        self.has_no_rules() && self.last_run_on.is_none()
+    }
+}
+
+/// One kind of record a retention rule removes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetentionRule {
+    AuditLog,
+    ParentalControlAttempts,
+    ArchivedTransactions,
+}
+
+impl RetentionRule {
+    /// Every rule, in the order reports list them
+    pub const ALL: [RetentionRule; 3] = [
+        RetentionRule::AuditLog,
+        RetentionRule::ParentalControlAttempts,
+        RetentionRule::ArchivedTransactions,
+    ];
+
+    /// Label shown in settings and reports
+    pub fn label(&self) -> &'static str {
+        match self {
+            RetentionRule::AuditLog => "Audit log entries",
+            RetentionRule::ParentalControlAttempts => "Parental challenge attempts",
+            RetentionRule::ArchivedTransactions => "Archived transactions",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_policy_is_not_written() {
+        let policy = RetentionPolicy::default();
+        assert!(policy.is_empty());
+        assert_eq!(serde_yaml::to_string(&policy).unwrap().trim(), "{}");
+
+        let policy = RetentionPolicy { audit_log_days: Some(90), ..Default::default() };
+        assert!(!policy.has_no_rules());
+        let yaml = serde_yaml::to_string(&policy).unwrap();
+        assert_eq!(serde_yaml::from_str::<RetentionPolicy>(&yaml).unwrap(), policy);
+    }
+}
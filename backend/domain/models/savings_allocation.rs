@@ -0,0 +1,110 @@
+//! Domain model for automatic savings allocations
+//!
+//! When a child has a "pay yourself first" percentage configured, every income
+//! transaction earmarks a share of its amount to savings. With round-ups
+//! turned on, every spend also earmarks the change up to the next whole
+//! dollar. Each earmark is recorded as a `SavingsAllocation` linked to the
+//! transaction it came from.
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+/// Which rule produced an allocation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SavingsSource {
+    /// A percentage of an income transaction
+    #[default]
+    AutoSave,
+    /// The change from rounding a spend up to the next whole dollar
+    RoundUp,
+}
+
+impl SavingsSource {
+    /// Value stored in the `source` column
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SavingsSource::AutoSave => "auto_save",
+            SavingsSource::RoundUp => "round_up",
+        }
+    }
+
+    /// Parse a stored value; files written before round-ups existed have no
+    /// source column, so anything unrecognised is an auto-save
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "round_up" => SavingsSource::RoundUp,
+            _ => SavingsSource::AutoSave,
+        }
+    }
+}
+
+/// An amount automatically earmarked to savings from a transaction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavingsAllocation {
+    pub id: String,
+    /// The transaction this allocation was taken from
+    pub transaction_id: String,
+    /// Date of the linked transaction
+    pub date: DateTime<FixedOffset>,
+    /// Amount earmarked (always positive, rounded to cents)
+    pub amount: f64,
+    /// Percentage that was in effect when the allocation was made; 0 for round-ups
+    pub percentage: f64,
+    /// Goal the allocation contributes to, or `None` for general savings
+    pub goal_id: Option<String>,
+    /// Rule that produced the allocation
+    pub source: SavingsSource,
+}
+
+impl SavingsAllocation {
+    /// Allocation IDs are derived from the linked transaction so each income
+    /// transaction has at most one allocation
+    pub fn generate_id(transaction_id: &str) -> String {
+        format!("alloc::{}", transaction_id)
+    }
+
+    /// Round-up IDs are derived from the linked spend the same way
+    pub fn generate_round_up_id(transaction_id: &str) -> String {
+        format!("roundup::{}", transaction_id)
+    }
+
+    /// Calculate the amount to earmark for an income amount, rounded to cents
+    pub fn calculate_amount(income_amount: f64, percentage: f64) -> f64 {
+        (income_amount * percentage).round() / 100.0
+    }
+
+    /// The change left over when a spend is rounded up to the next whole
+    /// dollar, in cents precision; 0 for spends that are already whole
+    pub fn calculate_round_up(spend_amount: f64) -> f64 {
+        let cents = (spend_amount.abs() * 100.0).round() as i64;
+        let change = (100 - cents % 100) % 100;
+        change as f64 / 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_amount_rounds_to_cents() {
+        assert_eq!(SavingsAllocation::calculate_amount(10.0, 20.0), 2.0);
+        assert_eq!(SavingsAllocation::calculate_amount(3.33, 10.0), 0.33);
+        assert_eq!(SavingsAllocation::calculate_amount(0.05, 50.0), 0.03);
+    }
+
+    #[test]
+    fn test_generate_id_links_transaction() {
+        assert_eq!(SavingsAllocation::generate_id("in-123-abcd"), "alloc::in-123-abcd");
+        assert_eq!(SavingsAllocation::generate_round_up_id("ex-123-abcd"), "roundup::ex-123-abcd");
+    }
+
+    #[test]
+    fn test_calculate_round_up_to_next_dollar() {
+        assert_eq!(SavingsAllocation::calculate_round_up(-3.25), 0.75);
+        assert_eq!(SavingsAllocation::calculate_round_up(-0.99), 0.01);
+        assert_eq!(SavingsAllocation::calculate_round_up(-4.0), 0.0);
+        assert_eq!(SavingsAllocation::calculate_round_up(-2.1), 0.9);
+    }
+}
@@ -1,13 +1,113 @@
 //! Domain model for a transaction.
 use serde::{Deserialize, Serialize};
 use std::time::{SystemTime, UNIX_EPOCH};
-use chrono::{DateTime, FixedOffset};
+use chrono::{DateTime, FixedOffset, NaiveDate};
 
+use super::actor::Actor;
+
+/// What kind of money movement a transaction is
+///
+/// `Income` and `Expense` are the plain kinds, taken from the amount's sign
+/// when nothing more specific was chosen; files written before kinds were
+/// stored read back that way too. `FutureAllowance` is only ever projected,
+/// never stored.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TransactionType {
     Income,
     Expense,
     FutureAllowance,
+    /// A scheduled allowance that was paid, or one paid early
+    AllowancePayment,
+    Bonus,
+    Penalty,
+    Gift,
+    /// Money moved between a child and a parent, such as a loan or its repayment
+    Transfer,
+    Interest,
+    /// A correction, such as a reversal
+    Adjustment,
+}
+
+impl TransactionType {
+    /// Every kind a stored transaction can have, in the order forms and
+    /// reports list them
+    pub const RECORDED: [TransactionType; 9] = [
+        TransactionType::Income,
+        TransactionType::Expense,
+        TransactionType::AllowancePayment,
+        TransactionType::Bonus,
+        TransactionType::Penalty,
+        TransactionType::Gift,
+        TransactionType::Transfer,
+        TransactionType::Interest,
+        TransactionType::Adjustment,
+    ];
+
+    /// The plain kind for an amount: income unless it is negative
+    pub fn from_amount(amount: f64) -> Self {
+        if amount >= 0.0 {
+            TransactionType::Income
+        } else {
+            TransactionType::Expense
+        }
+    }
+
+    /// Key written to the `type` column of `transactions.csv`
+    pub fn storage_key(&self) -> &'static str {
+        match self {
+            TransactionType::Income => "income",
+            TransactionType::Expense => "expense",
+            TransactionType::FutureAllowance => "future_allowance",
+            TransactionType::AllowancePayment => "allowance",
+            TransactionType::Bonus => "bonus",
+            TransactionType::Penalty => "penalty",
+            TransactionType::Gift => "gift",
+            TransactionType::Transfer => "transfer",
+            TransactionType::Interest => "interest",
+            TransactionType::Adjustment => "adjustment",
+        }
+    }
+
+    /// Parse a `type` column value; `None` for a blank or unknown one
+    pub fn from_storage_key(key: &str) -> Option<Self> {
+        let key = key.trim();
+        Self::RECORDED
+            .into_iter()
+            .chain([TransactionType::FutureAllowance])
+            .find(|kind| kind.storage_key() == key)
+    }
+
+    /// Name shown in reports
+    pub fn label(&self) -> &'static str {
+        match self {
+            TransactionType::Income => "Income",
+            TransactionType::Expense => "Expense",
+            TransactionType::FutureAllowance => "Future allowance",
+            TransactionType::AllowancePayment => "Allowance",
+            TransactionType::Bonus => "Bonus",
+            TransactionType::Penalty => "Penalty",
+            TransactionType::Gift => "Gift",
+            TransactionType::Transfer => "Transfer",
+            TransactionType::Interest => "Interest",
+            TransactionType::Adjustment => "Adjustment",
+        }
+    }
+
+    /// Whether a new transaction of this kind may have `amount`: money in
+    /// for allowance, bonuses, gifts and interest, money out for penalties,
+    /// either way for transfers and adjustments
+    pub fn allows_amount(&self, amount: f64) -> bool {
+        match self {
+            TransactionType::Income
+            | TransactionType::AllowancePayment
+            | TransactionType::Bonus
+            | TransactionType::Gift
+            | TransactionType::Interest => amount >= 0.0,
+            TransactionType::Expense | TransactionType::Penalty => amount < 0.0,
+            TransactionType::Transfer | TransactionType::Adjustment => true,
+            TransactionType::FutureAllowance => false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -19,6 +119,15 @@ pub struct Transaction {
     pub amount: f64,
     pub balance: f64,
     pub transaction_type: TransactionType,
+    /// Who entered the transaction; `None` for automatic or older entries
+    #[serde(default)]
+    pub actor: Option<Actor>,
+    /// What the money was for, e.g. "Toys"; `None` when uncategorized
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Emoji or preset icon key the transaction is tagged with; see `transaction_icon`
+    #[serde(default)]
+    pub icon: Option<String>,
 }
 
 impl Transaction {
@@ -55,4 +164,45 @@ impl Transaction {
             .take(len)
             .collect()
     }
-}
\ No newline at end of file
+}
+
+/// `date` moved to another day, keeping its time of day and offset so a
+/// moved transaction keeps its place among that day's other entries
+pub fn date_on_day(date: DateTime<FixedOffset>, day: NaiveDate) -> DateTime<FixedOffset> {
+    day.and_time(date.time())
+        .and_local_timezone(*date.offset())
+        .single()
+        .unwrap_or(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_storage_keys_round_trip() {
+        for kind in TransactionType::RECORDED {
+            assert_eq!(TransactionType::from_storage_key(kind.storage_key()), Some(kind));
+        }
+        assert_eq!(TransactionType::from_storage_key(""), None);
+        assert_eq!(TransactionType::from_storage_key("lottery"), None);
+    }
+
+    #[test]
+    fn test_kinds_limit_the_amount_sign() {
+        assert!(TransactionType::Bonus.allows_amount(5.0));
+        assert!(!TransactionType::Bonus.allows_amount(-5.0));
+        assert!(TransactionType::Penalty.allows_amount(-1.0));
+        assert!(!TransactionType::Penalty.allows_amount(1.0));
+        assert!(TransactionType::Adjustment.allows_amount(-1.0) && TransactionType::Adjustment.allows_amount(1.0));
+        assert!(!TransactionType::FutureAllowance.allows_amount(5.0));
+        assert_eq!(TransactionType::from_amount(-0.5), TransactionType::Expense);
+    }
+
+    #[test]
+    fn test_date_on_day_keeps_time_and_offset() {
+        let date = DateTime::parse_from_rfc3339("2024-06-10T15:30:00-04:00").unwrap();
+        let moved = date_on_day(date, NaiveDate::from_ymd_opt(2024, 5, 31).unwrap());
+        assert_eq!(moved.to_rfc3339(), "2024-05-31T15:30:00-04:00");
+    }
+}
@@ -0,0 +1,100 @@
+//! Domain model for the icon a transaction can be tagged with
+//!
+//! Kids like to mark what money went on, e.g. 🍦 or 🧸. A transaction's icon
+//! is stored either as one of the preset keys below, or as the emoji itself
+//! when it was typed in. Anything else is rejected so a stray word or a comma
+//! can't end up in the transactions file.
+
+use crate::backend::domain::errors::DomainError;
+
+/// Icons offered by the money forms, as (key, emoji)
+pub const PRESET_ICONS: [(&str, &str); 12] = [
+    ("ice_cream", "🍦"),
+    ("candy", "🍬"),
+    ("toy", "🧸"),
+    ("game", "🎮"),
+    ("book", "📚"),
+    ("art", "🎨"),
+    ("sports", "⚽"),
+    ("clothes", "👕"),
+    ("gift", "🎁"),
+    ("chore", "🧹"),
+    ("savings", "🐷"),
+    ("money", "💵"),
+];
+
+/// Longest emoji sequence accepted, in chars; family emoji joined with
+/// zero-width joiners are the longest common ones
+const MAX_EMOJI_CHARS: usize = 12;
+
+/// Check an icon from a form or command, returning what to store.
+/// A blank icon means none.
+pub fn normalize_icon(icon: Option<&str>) -> Result<Option<String>, DomainError> {
+    let Some(icon) = icon.map(str::trim).filter(|icon| !icon.is_empty()) else {
+        return Ok(None);
+    };
+    if PRESET_ICONS.iter().any(|(key, _)| *key == icon) || is_emoji(icon) {
+        Ok(Some(icon.to_string()))
+    } else {
+        Err(DomainError::invalid(format!("\"{}\" is not an emoji or a known icon", icon)))
+    }
+}
+
+/// The emoji to draw for a stored icon
+pub fn icon_glyph(icon: &str) -> &str {
+    PRESET_ICONS
+        .iter()
+        .find(|(key, _)| *key == icon)
+        .map(|(_, emoji)| *emoji)
+        .unwrap_or(icon)
+}
+
+/// True for a single emoji, including skin tones, flags and joined sequences
+fn is_emoji(text: &str) -> bool {
+    let chars: Vec<char> = text.chars().collect();
+    chars.len() <= MAX_EMOJI_CHARS
+        && chars.iter().any(|c| is_pictograph(*c))
+        && chars.iter().all(|c| is_pictograph(*c) || is_emoji_modifier(*c))
+}
+
+fn is_pictograph(c: char) -> bool {
+    matches!(c as u32,
+        0x1F000..=0x1FAFF // Emoticons, pictographs, transport, flags' regional indicators
+        | 0x2600..=0x27BF // Miscellaneous symbols and dingbats
+        | 0x2B00..=0x2BFF // Arrows, stars and shapes
+        | 0x2190..=0x21FF // Arrows
+        | 0x2300..=0x23FF // Watch, hourglass and other technical symbols
+    )
+}
+
+/// Characters that only change how the emoji before them is drawn
+fn is_emoji_modifier(c: char) -> bool {
+    matches!(c as u32,
+        0x200D // Zero-width joiner
+        | 0xFE0F // Emoji presentation selector
+        | 0x20E3 // Combining keycap
+        | 0xE0020..=0xE007F // Tags, used by subdivision flags
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icons_must_be_an_emoji_or_preset_key() {
+        assert_eq!(normalize_icon(None).unwrap(), None);
+        assert_eq!(normalize_icon(Some("  ")).unwrap(), None);
+        assert_eq!(normalize_icon(Some("toy")).unwrap().as_deref(), Some("toy"));
+        assert_eq!(normalize_icon(Some(" 🍦 ")).unwrap().as_deref(), Some("🍦"));
+        assert_eq!(normalize_icon(Some("👍🏽")).unwrap().as_deref(), Some("👍🏽"));
+        assert_eq!(normalize_icon(Some("👨‍👩‍👧")).unwrap().as_deref(), Some("👨‍👩‍👧"));
+
+        assert!(normalize_icon(Some("ice cream")).is_err());
+        assert!(normalize_icon(Some("🍦,1")).is_err());
+        assert!(normalize_icon(Some("\u{200D}")).is_err());
+
+        assert_eq!(icon_glyph("toy"), "🧸");
+        assert_eq!(icon_glyph("🍦"), "🍦");
+    }
+}
@@ -0,0 +1,41 @@
+//! Domain model for a reversed transaction
+//!
+//! Returning a purchase or handing back a gift doesn't delete the original
+//! entry. Instead an offsetting transaction is recorded and the two are linked
+//! as a `TransactionReversal`, so each can point at the other and reports can
+//! leave the pair out.
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+/// A transaction and the offsetting entry that undid it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionReversal {
+    pub child_id: String,
+    /// The transaction that was reversed
+    pub original_id: String,
+    /// The offsetting transaction
+    pub reversal_id: String,
+    /// When the reversal was recorded
+    pub date: DateTime<FixedOffset>,
+    /// Why, e.g. "Returned to the store"
+    pub reason: Option<String>,
+}
+
+impl TransactionReversal {
+    /// Whether the transaction is either side of this reversal
+    pub fn involves(&self, transaction_id: &str) -> bool {
+        self.original_id == transaction_id || self.reversal_id == transaction_id
+    }
+
+    /// The other side of the pair, if the transaction is part of it
+    pub fn counterpart(&self, transaction_id: &str) -> Option<&str> {
+        if self.original_id == transaction_id {
+            Some(&self.reversal_id)
+        } else if self.reversal_id == transaction_id {
+            Some(&self.original_id)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Domain model for a child's transaction templates ("favorites")
+//!
+//! A template is a whole transaction saved under a short name, such as
+//! "Lunch money" for $4.50 spent on Food, so a repeat purchase or a regular
+//! chore payment can be added with one tap instead of filling in a form.
+
+use serde::{Deserialize, Serialize};
+
+use crate::backend::domain::models::transaction::TransactionType;
+
+/// A saved transaction for one child
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionTemplate {
+    pub id: String,
+    pub child_id: String,
+    /// Short name shown on the template's button
+    pub name: String,
+    /// Description the new transaction gets
+    pub description: String,
+    /// Amount of the new transaction (always positive; the kind says which way it goes)
+    pub amount: f64,
+    /// What the money is for, e.g. "Food"; `None` when uncategorized
+    pub category: Option<String>,
+    pub transaction_type: TransactionType,
+}
+
+impl TransactionTemplate {
+    /// Kinds a template can have: the ones a person enters by hand
+    pub const KINDS: [TransactionType; 5] = [
+        TransactionType::Income,
+        TransactionType::Expense,
+        TransactionType::Bonus,
+        TransactionType::Penalty,
+        TransactionType::Gift,
+    ];
+
+    /// Generate a template ID for a child
+    pub fn generate_id(child_id: &str, now_millis: u64) -> String {
+        format!("template::{}_{}", child_id, now_millis)
+    }
+
+    /// Whether the template takes money out, like a spend or a penalty
+    pub fn is_expense(&self) -> bool {
+        !self.transaction_type.allows_amount(self.amount)
+    }
+
+    /// Amount for the new transaction: negative when money goes out
+    pub fn signed_amount(&self) -> f64 {
+        if self.is_expense() {
+            -self.amount
+        } else {
+            self.amount
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signed_amount_follows_the_kind() {
+        let template = |transaction_type| TransactionTemplate {
+            id: "template::1".to_string(),
+            child_id: "child".to_string(),
+            name: "Lunch".to_string(),
+            description: "Lunch money".to_string(),
+            amount: 4.5,
+            category: None,
+            transaction_type,
+        };
+        assert_eq!(template(TransactionType::Expense).signed_amount(), -4.5);
+        assert_eq!(template(TransactionType::Penalty).signed_amount(), -4.5);
+        assert_eq!(template(TransactionType::Income).signed_amount(), 4.5);
+        assert_eq!(template(TransactionType::Gift).signed_amount(), 4.5);
+    }
+}
@@ -0,0 +1,183 @@
+//! Domain model for app updates
+//!
+//! New builds are published as releases; pre-releases are betas. A family
+//! picks which channel they follow, and once a parent turns it on, the app
+//! checks for a newer release once a day. An update a parent puts off with
+//! "Later" isn't offered again until an even newer one comes out.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Which releases are offered as updates
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReleaseChannel {
+    /// Full releases only
+    #[default]
+    Stable,
+    /// Pre-releases as well, for families who want new features first
+    Beta,
+}
+
+impl ReleaseChannel {
+    /// Every channel, in the order settings list them
+    pub const ALL: [ReleaseChannel; 2] = [ReleaseChannel::Stable, ReleaseChannel::Beta];
+
+    /// Label shown in settings
+    pub fn label(self) -> &'static str {
+        match self {
+            ReleaseChannel::Stable => "Stable",
+            ReleaseChannel::Beta => "Beta (early access)",
+        }
+    }
+
+    /// Whether a release is offered on this channel
+    pub fn includes(self, prerelease: bool) -> bool {
+        match self {
+            ReleaseChannel::Stable => !prerelease,
+            ReleaseChannel::Beta => true,
+        }
+    }
+}
+
+/// The release channel and what the update check last did
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateSettings {
+    #[serde(default)]
+    pub channel: ReleaseChannel,
+    /// Whether the app asks for releases by itself; off until a parent turns it on
+    #[serde(default)]
+    pub check_automatically: bool,
+    /// When the scheduled check last asked for releases
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_checked_at: Option<DateTime<Utc>>,
+    /// Version a parent chose to install later; not offered again by the scheduled check
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postponed_version: Option<String>,
+}
+
+impl UpdateSettings {
+    /// Whether nothing about updates needs saving
+    pub fn is_empty(&self) -> bool {
+        self.channel == ReleaseChannel::Stable
+            && !self.check_automatically
+            && self.last_checked_at.is_none()
+            && self.postponed_version.is_none()
+    }
+}
+
+/// A release newer than the running build
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvailableUpdate {
+    pub version: String,
+    pub prerelease: bool,
+    /// Release notes as written by the maintainers
+    pub changelog: String,
+    /// Page the installers are downloaded from
+    pub download_url: String,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+/// A `major.minor.patch` version with an optional pre-release suffix, e.g. "1.4.0-beta.2"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre: Option<String>,
+}
+
+impl ReleaseVersion {
+    /// Read a version or release tag; a leading "v" is allowed and missing parts are 0
+    pub fn parse(version: &str) -> Option<Self> {
+        let version = version.trim();
+        let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+        let version = version.split('+').next()?;
+        let (numbers, pre) = match version.split_once('-') {
+            Some((numbers, pre)) if !pre.is_empty() => (numbers, Some(pre.to_string())),
+            Some(_) => return None,
+            None => (version, None),
+        };
+
+        let mut parts = numbers.split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next().map(str::parse).transpose().ok()?.unwrap_or(0);
+        let patch = parts.next().map(str::parse).transpose().ok()?.unwrap_or(0);
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self { major, minor, patch, pre })
+    }
+}
+
+impl Ord for ReleaseVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.pre, &other.pre) {
+                (None, None) => Ordering::Equal,
+                // A release comes after its own betas
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => compare_pre(a, b),
+            })
+    }
+}
+
+impl PartialOrd for ReleaseVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compare pre-release suffixes part by part, numerically where both parts are numbers
+fn compare_pre(a: &str, b: &str) -> Ordering {
+    let mut a_parts = a.split('.');
+    let mut b_parts = b.split('.');
+    loop {
+        match (a_parts.next(), b_parts.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) => {
+                let ordering = match (a.parse::<u64>(), b.parse::<u64>()) {
+                    (Ok(a), Ok(b)) => a.cmp(&b),
+                    _ => a.cmp(b),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(version: &str) -> ReleaseVersion {
+        ReleaseVersion::parse(version).unwrap()
+    }
+
+    #[test]
+    fn test_versions_and_tags_parse() {
+        assert_eq!(v("v1.2.3"), ReleaseVersion { major: 1, minor: 2, patch: 3, pre: None });
+        assert_eq!(v("0.2"), ReleaseVersion { major: 0, minor: 2, patch: 0, pre: None });
+        assert_eq!(v("1.4.0-beta.2+build7").pre.as_deref(), Some("beta.2"));
+        assert_eq!(ReleaseVersion::parse("latest"), None);
+        assert_eq!(ReleaseVersion::parse("1.2.3.4"), None);
+        assert_eq!(ReleaseVersion::parse("1.2.3-"), None);
+    }
+
+    #[test]
+    fn test_releases_sort_after_their_betas() {
+        assert!(v("0.2.0") > v("0.1.9"));
+        assert!(v("1.0.0") > v("1.0.0-rc.1"));
+        assert!(v("1.0.0-beta.10") > v("1.0.0-beta.2"));
+        assert!(v("1.0.0-rc.1") > v("1.0.0-beta.5"));
+        assert!(v("1.0.0-beta") < v("1.0.0-beta.1"));
+        assert_eq!(v("v1.0").cmp(&v("1.0.0")), Ordering::Equal);
+    }
+}
@@ -8,50 +8,41 @@
 use anyhow::Result;
 use shared::{
     AddMoneyRequest, AddMoneyResponse, SpendMoneyRequest, SpendMoneyResponse,
-    CreateTransactionRequest, MoneyFormState, MoneyFormValidation,
-    MoneyManagementConfig, MoneyValidationError,
+    BackdateImpact as BackdateImpactDto, CreateTransactionRequest, MoneyFormState, MoneyFormValidation,
+    LocalizedMessage, MoneyManagementConfig, MoneyValidationError,
 };
 use chrono::{DateTime, Utc, Duration, TimeZone};
 use time::OffsetDateTime;
-use crate::backend::domain::commands::transactions::CreateTransactionCommand;
-use log::{info, error};
+use crate::backend::domain::amount_expression;
+use crate::backend::domain::amount_input;
+use crate::backend::domain::description_input;
+use crate::backend::domain::localization::{Locale, StringCatalog};
+use crate::backend::domain::commands::child::GetChildCommand;
+use crate::backend::domain::commands::transactions::{BackdateImpact, CreateTransactionCommand};
+use crate::backend::domain::models::actor::Actor;
+use crate::backend::domain::models::child::Child;
+use log::{info, warn, error};
 
 // Add imports for the new orchestration methods
 use crate::backend::domain::child_service::ChildService;
 use crate::backend::domain::transaction_service::TransactionService;
 use crate::backend::domain::goal_service::GoalService;
-
-// Create TransactionMapper placeholder
-struct TransactionMapper;
-
-impl TransactionMapper {
-    pub fn to_dto(transaction: crate::backend::domain::models::transaction::Transaction) -> shared::Transaction {
-        shared::Transaction {
-            id: transaction.id,
-            date: transaction.date,
-            amount: transaction.amount,
-            description: transaction.description,
-            transaction_type: match transaction.transaction_type {
-                crate::backend::domain::models::transaction::TransactionType::Income => shared::TransactionType::Income,
-                crate::backend::domain::models::transaction::TransactionType::Expense => shared::TransactionType::Expense,
-                crate::backend::domain::models::transaction::TransactionType::FutureAllowance => shared::TransactionType::FutureAllowance,
-            },
-            balance: transaction.balance,
-            child_id: transaction.child_id,
-        }
-    }
-}
+use crate::backend::domain::mappers::TransactionMapper;
+use crate::backend::domain::errors::DomainError;
 
 /// Money management service that handles all money-related business logic
 #[derive(Clone)]
 pub struct MoneyManagementService {
     config: MoneyManagementConfig,
+    /// How typed amounts are read, e.g. "1.234,56" in German
+    locale: Locale,
 }
 
 impl MoneyManagementService {
     pub fn new() -> Self {
         Self {
             config: MoneyManagementConfig::default(),
+            locale: Locale::default(),
         }
     }
 
@@ -69,19 +60,7 @@ impl MoneyManagementService {
 
         // Step 1: Check for active child first
         info!("🔍 MONEY MANAGEMENT: Checking for active child...");
-        let active_child_response = child_service.get_active_child()?;
-        // We need to access the connection somehow to debug further
-        
-        let active_child = match active_child_response.active_child.child {
-            Some(child) => {
-                info!("✅ MONEY MANAGEMENT: Active child found: {}", child.id);
-                child
-            },
-            None => {
-                error!("❌ MONEY MANAGEMENT: No active child found for add money operation");
-                return Err(anyhow::anyhow!("No active child found. Please select a child first."));
-            }
-        };
+        let active_child = self.resolve_child(request.child_id.as_deref(), child_service, "add money")?;
 
         // Step 2: Enhanced validation that includes date validation if provided
         info!("🔍 MONEY MANAGEMENT: Starting validation with date: {:?}", request.date);
@@ -98,7 +77,7 @@ impl MoneyManagementService {
             let error_message = self.get_first_error_message(&validation.errors)
                 .unwrap_or_else(|| "Invalid input".to_string());
             error!("❌ MONEY MANAGEMENT: Validation failed: {}", error_message);
-            return Err(anyhow::anyhow!("Validation failed: {}", error_message));
+            return Err(DomainError::invalid(format!("Validation failed: {}", error_message)).into());
         }
 
         // Step 3: Convert to CreateTransactionRequest
@@ -108,9 +87,20 @@ impl MoneyManagementService {
 
         // Step 4: Convert DTO to domain command and create transaction
         let cmd = CreateTransactionCommand {
+            child_id: Some(active_child.id.clone()),
             description: create_request.description.clone(),
             amount: create_request.amount,
             date: create_request.date.clone(),
+            parent_override: false,
+            actor: request.actor.as_deref().and_then(Actor::parse),
+            icon: request.icon.clone(),
+            transaction_type: request.transaction_type.as_ref().map(TransactionMapper::type_from_dto),
+        };
+
+        // Work out what a backdated entry rewrites before it is stored
+        let backdate_impact = match cmd.date {
+            Some(date) => transaction_service.backdate_impact(&active_child.id, date)?,
+            None => None,
         };
 
         info!("🚀 MONEY MANAGEMENT: Creating transaction via TransactionService...");
@@ -151,6 +141,10 @@ impl MoneyManagementService {
             self.generate_success_message(transaction.amount)
         };
         
+        let success_message = match &backdate_impact {
+            Some(impact) => format!("{} {}", success_message, self.backdate_note(impact)),
+            None => success_message,
+        };
         let formatted_amount = self.format_positive_amount(transaction.amount);
 
         let response = AddMoneyResponse {
@@ -158,6 +152,7 @@ impl MoneyManagementService {
             success_message: success_message.clone(),
             new_balance: transaction.balance,
             formatted_amount,
+            backdate_impact: backdate_impact.map(Self::backdate_impact_dto),
         };
 
         info!("✅ MONEY MANAGEMENT: Sending success response: {:?}", response);
@@ -177,18 +172,7 @@ impl MoneyManagementService {
 
         // Step 1: Check for active child first
         info!("🔍 MONEY MANAGEMENT: Checking for active child...");
-        let active_child_response = child_service.get_active_child()?;
-        
-        let active_child = match active_child_response.active_child.child {
-            Some(child) => {
-                info!("✅ MONEY MANAGEMENT: Active child found: {}", child.id);
-                child
-            },
-            None => {
-                error!("❌ MONEY MANAGEMENT: No active child found for spend money operation");
-                return Err(anyhow::anyhow!("No active child found. Please select a child first."));
-            }
-        };
+        let active_child = self.resolve_child(request.child_id.as_deref(), child_service, "spend money")?;
 
         // Step 2: Enhanced validation that includes date validation if provided
         info!("🔍 MONEY MANAGEMENT: Starting validation with date: {:?}", request.date);
@@ -205,7 +189,7 @@ impl MoneyManagementService {
             let error_message = self.get_first_error_message(&validation.errors)
                 .unwrap_or_else(|| "Invalid input".to_string());
             error!("❌ MONEY MANAGEMENT: Validation failed: {}", error_message);
-            return Err(anyhow::anyhow!("Validation failed: {}", error_message));
+            return Err(DomainError::invalid(format!("Validation failed: {}", error_message)).into());
         }
 
         // Step 3: Convert to CreateTransactionRequest (this will make the amount negative)
@@ -215,9 +199,20 @@ impl MoneyManagementService {
 
         // Step 4: Convert DTO to domain command and create transaction
         let cmd = CreateTransactionCommand {
+            child_id: Some(active_child.id.clone()),
             description: create_request.description.clone(),
             amount: create_request.amount,
             date: create_request.date.clone(),
+            parent_override: request.parent_override,
+            actor: request.actor.as_deref().and_then(Actor::parse),
+            icon: request.icon.clone(),
+            transaction_type: request.transaction_type.as_ref().map(TransactionMapper::type_from_dto),
+        };
+
+        // Work out what a backdated entry rewrites before it is stored
+        let backdate_impact = match cmd.date {
+            Some(date) => transaction_service.backdate_impact(&active_child.id, date)?,
+            None => None,
         };
 
         info!("🚀 MONEY MANAGEMENT: Creating transaction via TransactionService...");
@@ -259,6 +254,10 @@ impl MoneyManagementService {
             self.generate_spend_success_message(request.amount)
         };
         
+        let success_message = match &backdate_impact {
+            Some(impact) => format!("{} {}", success_message, self.backdate_note(impact)),
+            None => success_message,
+        };
         let formatted_amount = self.format_negative_amount(request.amount);
 
         let response = SpendMoneyResponse {
@@ -266,6 +265,7 @@ impl MoneyManagementService {
             success_message: success_message.clone(),
             new_balance: transaction.balance,
             formatted_amount,
+            backdate_impact: backdate_impact.map(Self::backdate_impact_dto),
         };
 
         info!("✅ MONEY MANAGEMENT: Sending success response: {:?}", response);
@@ -273,7 +273,13 @@ impl MoneyManagementService {
     }
 
     pub fn with_config(config: MoneyManagementConfig) -> Self {
-        Self { config }
+        Self { config, locale: Locale::default() }
+    }
+
+    /// Read typed amounts the way `locale` writes them
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
     }
 
     /// Create a new form state for adding money
@@ -293,13 +299,14 @@ impl MoneyManagementService {
         let mut errors = Vec::new();
         let mut suggestions = Vec::new();
 
-        // Validate description
-        let description_trimmed = description.trim();
-        if description_trimmed.is_empty() {
+        // Validate description as it will be stored
+        let description = self.sanitize_description(description);
+        let description_length = description_input::description_length(&description);
+        if description.is_empty() {
             errors.push(MoneyValidationError::EmptyDescription);
             suggestions.push("Try: Gift from grandma, gift from aunt...".to_string());
-        } else if description_trimmed.len() > self.config.max_description_length {
-            errors.push(MoneyValidationError::DescriptionTooLong(description_trimmed.len()));
+        } else if description_length > self.config.max_description_length {
+            errors.push(MoneyValidationError::DescriptionTooLong(description_length));
         }
 
         // Validate and parse amount
@@ -338,45 +345,94 @@ impl MoneyManagementService {
             }
         };
 
+        let amount_expression = self.amount_expression_for(amount_input, cleaned_amount);
         MoneyFormValidation {
             is_valid: errors.is_empty(),
+            messages: errors.iter().map(|e| self.localize_error(e)).collect(),
             errors,
             cleaned_amount,
             suggestions,
+            amount_expression,
         }
     }
 
-    /// Clean and parse amount input string
+    /// Clean and parse amount input string, as written in the service's
+    /// locale ("1,234.56", "1.234,56", "$ 12"). Simple arithmetic such as
+    /// "3*1.25" or "10-2.50" is evaluated to its result.
     pub fn clean_and_parse_amount(&self, amount_input: &str) -> Result<f64, String> {
-        // Clean the input - remove dollar signs, spaces, commas
-        let cleaned = amount_input
-            .trim()
-            .replace(&self.config.currency_symbol, "")
-            .replace(",", "")
-            .replace(" ", "");
-
-        // Handle empty input after cleaning
-        if cleaned.is_empty() {
-            return Err("Empty amount after cleaning".to_string());
+        let cleaned = amount_input::normalize_amount(amount_input, self.locale, &self.config.currency_symbol)?;
+
+        if amount_expression::is_expression(&cleaned) {
+            return amount_expression::evaluate(&cleaned);
         }
 
-        // Try to parse as float
-        cleaned.parse::<f64>()
-            .map_err(|e| format!("Invalid number format: {}", e))
+        let amount = cleaned.parse::<f64>()
+            .map_err(|e| format!("Invalid number format: {}", e))?;
+        // Drop floating point noise so whole cents compare exactly
+        let cents = (amount * 100.0).round() / 100.0;
+        Ok(if (amount - cents).abs() < 1e-9 { cents } else { amount })
+    }
+
+    /// The original input, if it was arithmetic that evaluated successfully
+    fn amount_expression_for(&self, amount_input: &str, cleaned_amount: Option<f64>) -> Option<String> {
+        let trimmed = amount_input.trim();
+        if cleaned_amount.is_some() && amount_expression::is_expression(trimmed) {
+            Some(trimmed.to_string())
+        } else {
+            None
+        }
+    }
+
+    /// Tidy a typed description the way it is stored: trimmed, whitespace
+    /// collapsed to single spaces and control characters removed
+    pub fn sanitize_description(&self, description: &str) -> String {
+        description_input::sanitize_description(description)
+    }
+
+    /// Sanitize the transaction description and note the arithmetic the
+    /// amount came from in it, as long as it still fits
+    fn description_with_expression(&self, description: String, amount_expression: Option<&str>) -> String {
+        let description = self.sanitize_description(&description);
+        match amount_expression {
+            Some(expression) => {
+                let noted = format!("{} ({})", description, expression);
+                if description_input::description_length(&noted) <= self.config.max_description_length {
+                    noted
+                } else {
+                    warn!("💰 MONEY MANAGEMENT: Description too long to note amount expression '{}'", expression);
+                    description
+                }
+            }
+            None => description,
+        }
     }
 
     /// Check if amount has too many decimal places
     fn has_too_many_decimal_places(&self, amount: f64) -> bool {
-        // Convert to string and check decimal places
-        let amount_str = format!("{:.3}", amount);
-        if let Some(decimal_pos) = amount_str.find('.') {
-            let decimal_part = &amount_str[decimal_pos + 1..];
-            // Check if there are more than 2 significant decimal places
-            if decimal_part.len() > 2 && !decimal_part.ends_with("0") {
-                return true;
+        // Anything that moves when rounded to whole cents, e.g. 5.0001 or 5.125
+        ((amount * 100.0).round() / 100.0 - amount).abs() > 1e-9
+    }
+
+    /// The child a request names, or the active child when it names none
+    fn resolve_child(&self, child_id: Option<&str>, child_service: &ChildService, operation: &str) -> Result<Child> {
+        let child = match child_id {
+            Some(id) => child_service.get_child(GetChildCommand { child_id: id.to_string() })?.child,
+            None => child_service.get_active_child()?.active_child.child,
+        };
+        match child {
+            Some(child) => {
+                info!("✅ MONEY MANAGEMENT: Child found: {}", child.id);
+                Ok(child)
+            }
+            None => {
+                error!("❌ MONEY MANAGEMENT: No child found for {} operation", operation);
+                Err(match child_id {
+                    Some(id) => DomainError::child_not_found(id),
+                    None => DomainError::NoActiveChild,
+                }
+                .into())
             }
         }
-        false
     }
 
     /// Format amount for display
@@ -392,16 +448,21 @@ impl MoneyManagementService {
     /// Create a transaction request from validated form data
     pub fn create_add_money_request(&self, description: String, amount: f64, date: Option<chrono::DateTime<chrono::FixedOffset>>) -> AddMoneyRequest {
         AddMoneyRequest {
-            description: description.trim().to_string(),
+            child_id: None,
+            description: self.sanitize_description(&description),
             amount,
             date,
+            amount_expression: None,
+            actor: None,
+            icon: None,
+            transaction_type: None,
         }
     }
 
     /// Convert AddMoneyRequest to CreateTransactionRequest
     pub fn to_create_transaction_request(&self, add_money_request: AddMoneyRequest) -> CreateTransactionRequest {
         CreateTransactionRequest {
-            description: add_money_request.description,
+            description: self.description_with_expression(add_money_request.description, add_money_request.amount_expression.as_deref()),
             amount: add_money_request.amount,
             date: add_money_request.date,
         }
@@ -410,9 +471,39 @@ impl MoneyManagementService {
     /// Convert AddMoneyRequest to CreateTransactionCommand (domain)
     pub fn to_create_transaction_command(&self, add_money_request: AddMoneyRequest) -> CreateTransactionCommand {
         CreateTransactionCommand {
-            description: add_money_request.description,
+            child_id: None,
+            description: self.description_with_expression(add_money_request.description, add_money_request.amount_expression.as_deref()),
             amount: add_money_request.amount,
             date: add_money_request.date,
+            parent_override: false,
+            actor: add_money_request.actor.as_deref().and_then(Actor::parse),
+            icon: add_money_request.icon,
+            transaction_type: add_money_request.transaction_type.as_ref().map(TransactionMapper::type_from_dto),
+        }
+    }
+
+    /// Explain which later rows a backdated transaction rewrote, so a parent
+    /// isn't surprised when earlier allowances or the goal show new numbers
+    pub fn backdate_note(&self, impact: &BackdateImpact) -> String {
+        let rows = if impact.recalculated_rows == 1 { "balance" } else { "balances" };
+        let mut note = format!("Updated {} later {}", impact.recalculated_rows, rows);
+        match impact.allowances_after {
+            0 => {}
+            1 => note.push_str(", including 1 allowance"),
+            n => note.push_str(&format!(", including {} allowances", n)),
+        }
+        note.push('.');
+        if impact.goal_affected {
+            note.push_str(" The current goal's progress was recalculated too.");
+        }
+        note
+    }
+
+    fn backdate_impact_dto(impact: BackdateImpact) -> BackdateImpactDto {
+        BackdateImpactDto {
+            recalculated_rows: impact.recalculated_rows,
+            allowances_after: impact.allowances_after,
+            goal_affected: impact.goal_affected,
         }
     }
 
@@ -421,28 +512,29 @@ impl MoneyManagementService {
         format!("🎉 {} added successfully!", self.format_positive_amount(amount))
     }
 
-    /// Get user-friendly error message for validation error
-    pub fn get_error_message(&self, error: &MoneyValidationError) -> String {
+    /// The error as a catalog message, with the limits from this form's
+    /// settings filled in, for a frontend to render in its own language
+    pub fn localize_error(&self, error: &MoneyValidationError) -> LocalizedMessage {
+        let message = error.to_message();
         match error {
-            MoneyValidationError::EmptyDescription => "Please enter a description".to_string(),
-            MoneyValidationError::DescriptionTooLong(len) => {
-                format!("Description is too long ({} characters). Maximum is {}.", len, self.config.max_description_length)
-            }
-            MoneyValidationError::EmptyAmount => "Please enter an amount".to_string(),
-            MoneyValidationError::InvalidAmountFormat(msg) => {
-                format!("Please enter a valid amount (like 5 or 5.00): {}", msg)
+            MoneyValidationError::DescriptionTooLong(_) => {
+                message.with_param("max", self.config.max_description_length.to_string())
             }
-            MoneyValidationError::AmountNotPositive => "Amount must be greater than 0".to_string(),
             MoneyValidationError::AmountTooSmall(min) => {
-                format!("Amount is too small. Minimum is {}{:.2}", self.config.currency_symbol, min)
+                message.with_param("min", format!("{}{:.2}", self.config.currency_symbol, min))
             }
             MoneyValidationError::AmountTooLarge(max) => {
-                format!("Amount is too large. Maximum is {}{:.2}", self.config.currency_symbol, max)
+                message.with_param("max", format!("{}{:.2}", self.config.currency_symbol, max))
             }
-            MoneyValidationError::AmountPrecisionTooHigh => "Amount has too many decimal places. Use at most 2 decimal places.".to_string(),
+            _ => message,
         }
     }
 
+    /// Get user-friendly error message for validation error
+    pub fn get_error_message(&self, error: &MoneyValidationError) -> String {
+        StringCatalog::new(self.locale).render(&self.localize_error(error))
+    }
+
     /// Get all validation error messages as a list
     pub fn get_error_messages(&self, errors: &[MoneyValidationError]) -> Vec<String> {
         errors.iter().map(|e| self.get_error_message(e)).collect()
@@ -534,13 +626,14 @@ impl MoneyManagementService {
         let mut errors = Vec::new();
         let mut suggestions = Vec::new();
 
-        // Validate description
-        let description_trimmed = description.trim();
-        if description_trimmed.is_empty() {
+        // Validate description as it will be stored
+        let description = self.sanitize_description(description);
+        let description_length = description_input::description_length(&description);
+        if description.is_empty() {
             errors.push(MoneyValidationError::EmptyDescription);
             suggestions.push("Try: Toy, book, game...".to_string());
-        } else if description_trimmed.len() > self.config.max_description_length {
-            errors.push(MoneyValidationError::DescriptionTooLong(description_trimmed.len()));
+        } else if description_length > self.config.max_description_length {
+            errors.push(MoneyValidationError::DescriptionTooLong(description_length));
         }
 
         // Validate and parse amount (user enters positive, we'll convert to negative later)
@@ -579,11 +672,14 @@ impl MoneyManagementService {
             }
         };
 
+        let amount_expression = self.amount_expression_for(amount_input, cleaned_amount);
         MoneyFormValidation {
             is_valid: errors.is_empty(),
+            messages: errors.iter().map(|e| self.localize_error(e)).collect(),
             errors,
             cleaned_amount,
             suggestions,
+            amount_expression,
         }
     }
 
@@ -595,16 +691,22 @@ impl MoneyManagementService {
     /// Create a spend money request from validated form data
     pub fn create_spend_money_request(&self, description: String, amount: f64, date: Option<chrono::DateTime<chrono::FixedOffset>>) -> SpendMoneyRequest {
         SpendMoneyRequest {
-            description: description.trim().to_string(),
+            child_id: None,
+            description: self.sanitize_description(&description),
             amount,  // Keep positive, backend will convert to negative
             date,
+            parent_override: false,
+            amount_expression: None,
+            actor: None,
+            icon: None,
+            transaction_type: None,
         }
     }
 
     /// Convert SpendMoneyRequest to CreateTransactionRequest (converting amount to negative)
     pub fn spend_to_create_transaction_request(&self, spend_money_request: SpendMoneyRequest) -> CreateTransactionRequest {
         CreateTransactionRequest {
-            description: spend_money_request.description,
+            description: self.description_with_expression(spend_money_request.description, spend_money_request.amount_expression.as_deref()),
             amount: -spend_money_request.amount.abs(),  // Ensure negative amount
             date: spend_money_request.date,
         }
@@ -613,9 +715,14 @@ impl MoneyManagementService {
     /// Convert SpendMoneyRequest to CreateTransactionCommand (domain, converting amount to negative)
     pub fn spend_to_create_transaction_command(&self, spend_money_request: SpendMoneyRequest) -> CreateTransactionCommand {
         CreateTransactionCommand {
-            description: spend_money_request.description,
+            child_id: None,
+            description: self.description_with_expression(spend_money_request.description, spend_money_request.amount_expression.as_deref()),
             amount: -spend_money_request.amount.abs(),  // Ensure negative amount
             date: spend_money_request.date,
+            parent_override: spend_money_request.parent_override,
+            actor: spend_money_request.actor.as_deref().and_then(Actor::parse),
+            icon: spend_money_request.icon,
+            transaction_type: spend_money_request.transaction_type.as_ref().map(TransactionMapper::type_from_dto),
         }
     }
 
@@ -704,7 +811,9 @@ impl MoneyManagementService {
         if let Some(date_obj) = date {
             let date_str = date_obj.to_rfc3339();
             if let Err(date_error) = self.validate_transaction_date(&date_str, child_created_at) {
-                validation.errors.push(MoneyValidationError::InvalidAmountFormat(date_error));
+                let error = MoneyValidationError::InvalidAmountFormat(date_error);
+                validation.messages.push(self.localize_error(&error));
+                validation.errors.push(error);
                 validation.is_valid = false;
             }
         }
@@ -720,7 +829,9 @@ impl MoneyManagementService {
         if let Some(date_obj) = date {
             let date_str = date_obj.to_rfc3339();
             if let Err(date_error) = self.validate_transaction_date(&date_str, child_created_at) {
-                validation.errors.push(MoneyValidationError::InvalidAmountFormat(date_error));
+                let error = MoneyValidationError::InvalidAmountFormat(date_error);
+                validation.messages.push(self.localize_error(&error));
+                validation.errors.push(error);
                 validation.is_valid = false;
             }
         }
@@ -816,6 +927,19 @@ mod tests {
         assert!(matches!(validation.errors[0], MoneyValidationError::AmountNotPositive));
     }
 
+    #[test]
+    fn test_validate_add_money_form_precision() {
+        let service = create_test_service();
+        
+        for input in ["5.125", "5.0001", "0.015"] {
+            let validation = service.validate_add_money_form("Valid description", input);
+            assert_eq!(validation.errors, vec![MoneyValidationError::AmountPrecisionTooHigh], "{}", input);
+        }
+        for input in ["5.10", "0.07", "999999.99", "3*1.25"] {
+            assert!(service.validate_add_money_form("Valid description", input).is_valid, "{}", input);
+        }
+    }
+
     #[test]
     fn test_clean_and_parse_amount() {
         let service = create_test_service();
@@ -829,6 +953,68 @@ mod tests {
         assert!(service.clean_and_parse_amount("").is_err());
     }
 
+    #[test]
+    fn test_clean_and_parse_localized_amount() {
+        let german = create_test_service().with_locale(Locale::DeDe);
+        assert_eq!(german.clean_and_parse_amount("1.234,56").unwrap(), 1234.56);
+        assert_eq!(german.clean_and_parse_amount("12,50").unwrap(), 12.5);
+        assert_eq!(german.clean_and_parse_amount("€ 3,5 * 2").unwrap(), 7.0);
+
+        let us = create_test_service();
+        assert_eq!(us.clean_and_parse_amount("$ 12").unwrap(), 12.0);
+        assert_eq!(us.clean_and_parse_amount("12,50").unwrap(), 12.5);
+        assert_eq!(us.clean_and_parse_amount("1,000,000").unwrap(), 1_000_000.0);
+
+        // The form shows why an amount couldn't be read
+        let validation = us.validate_add_money_form("Gift", "1,23.45");
+        match &validation.errors[0] {
+            MoneyValidationError::InvalidAmountFormat(detail) => assert!(detail.contains("wrong places"), "{}", detail),
+            other => panic!("unexpected error {:?}", other),
+        }
+        let validation = german.validate_spend_money_form("Eis", "1,234");
+        assert!(matches!(validation.errors[0], MoneyValidationError::AmountPrecisionTooHigh));
+    }
+
+    #[test]
+    fn test_validate_amount_expression() {
+        let service = create_test_service();
+
+        let validation = service.validate_spend_money_form("Stickers", "3*1.25");
+        assert!(validation.is_valid);
+        assert_eq!(validation.cleaned_amount, Some(3.75));
+        assert_eq!(validation.amount_expression.as_deref(), Some("3*1.25"));
+
+        let validation = service.validate_add_money_form("Change back", "$10 - $2.50");
+        assert_eq!(validation.cleaned_amount, Some(7.5));
+
+        // Plain numbers aren't treated as expressions
+        let validation = service.validate_add_money_form("Gift", "10.50");
+        assert_eq!(validation.amount_expression, None);
+
+        let validation = service.validate_spend_money_form("Candy", "10/3");
+        assert!(matches!(validation.errors[0], MoneyValidationError::AmountPrecisionTooHigh));
+
+        let validation = service.validate_spend_money_form("Candy", "2-5");
+        assert!(matches!(validation.errors[0], MoneyValidationError::AmountNotPositive));
+    }
+
+    #[test]
+    fn test_amount_expression_noted_in_description() {
+        let service = create_test_service();
+
+        let mut request = service.create_spend_money_request("Stickers".to_string(), 3.75, None);
+        request.amount_expression = Some("3*1.25".to_string());
+        let command = service.spend_to_create_transaction_command(request);
+        assert_eq!(command.description, "Stickers (3*1.25)");
+        assert_eq!(command.amount, -3.75);
+
+        // Left off when it would make the description too long
+        let long_description = "x".repeat(service.get_config().max_description_length);
+        let mut request = service.create_add_money_request(long_description.clone(), 3.75, None);
+        request.amount_expression = Some("3*1.25".to_string());
+        assert_eq!(service.to_create_transaction_request(request).description, long_description);
+    }
+
     #[test]
     fn test_format_amount() {
         let service = create_test_service();
@@ -853,9 +1039,14 @@ mod tests {
         let service = create_test_service();
         
         let add_money_request = AddMoneyRequest {
+            child_id: None,
             description: "Test".to_string(),
             amount: 10.50,
             date: None,
+            amount_expression: None,
+            actor: None,
+            icon: None,
+            transaction_type: None,
         };
         
         let create_request = service.to_create_transaction_request(add_money_request);
@@ -865,6 +1056,25 @@ mod tests {
         assert_eq!(create_request.date, None);
     }
 
+    #[test]
+    fn test_descriptions_are_sanitized() {
+        let service = create_test_service();
+
+        let validation = service.validate_add_money_form("Birthday\nmoney\t from\u{7} Gran", "5");
+        assert!(validation.is_valid);
+        let request = service.create_add_money_request("  Birthday\nmoney\t from\u{7} Gran ".to_string(), 5.0, None);
+        assert_eq!(request.description, "Birthday money from Gran");
+        assert_eq!(service.to_create_transaction_request(request).description, "Birthday money from Gran");
+
+        let validation = service.validate_spend_money_form("\r\n\u{1b}", "2");
+        assert_eq!(validation.errors, vec![MoneyValidationError::EmptyDescription]);
+
+        // The limit counts what a person sees as characters, not bytes
+        assert!(service.validate_spend_money_form(&"🍦".repeat(256), "2").is_valid);
+        let validation = service.validate_spend_money_form(&"👨‍👩‍👧".repeat(257), "2");
+        assert_eq!(validation.errors, vec![MoneyValidationError::DescriptionTooLong(257)]);
+    }
+
     #[test]
     fn test_generate_success_message() {
         let service = create_test_service();
@@ -874,6 +1084,17 @@ mod tests {
         assert_eq!(message, "🎉 +$10.50 added successfully!");
     }
 
+    #[test]
+    fn test_backdate_note() {
+        let service = create_test_service();
+
+        let note = service.backdate_note(&BackdateImpact { recalculated_rows: 1, allowances_after: 0, goal_affected: false });
+        assert_eq!(note, "Updated 1 later balance.");
+
+        let note = service.backdate_note(&BackdateImpact { recalculated_rows: 4, allowances_after: 2, goal_affected: true });
+        assert_eq!(note, "Updated 4 later balances, including 2 allowances. The current goal's progress was recalculated too.");
+    }
+
     #[test]
     fn test_form_state_management() {
         let service = create_test_service();
@@ -913,6 +1134,16 @@ mod tests {
         
         let error = MoneyValidationError::DescriptionTooLong(300);
         assert!(service.get_error_message(&error).contains("too long"));
+
+        // The same message travels as a key with every value it needs
+        let message = service.localize_error(&error);
+        assert_eq!(message.key, shared::messages::keys::VALIDATION_DESCRIPTION_TOO_LONG);
+        assert_eq!(message.params.get("max").map(String::as_str), Some("256"));
+        assert_eq!(message.to_english(), service.get_error_message(&error));
+
+        let validation = service.validate_add_money_form("", "abc");
+        let keys: Vec<&str> = validation.messages.iter().map(|m| m.key.as_str()).collect();
+        assert_eq!(keys, validation.errors.iter().map(|e| e.key()).collect::<Vec<_>>());
     }
 
     #[test]
@@ -997,9 +1228,15 @@ mod tests {
         let service = create_test_service();
         
         let spend_request = SpendMoneyRequest {
+            child_id: None,
             description: "Game".to_string(),
             amount: 15.00,
             date: None,
+            parent_override: false,
+            amount_expression: None,
+            actor: None,
+            icon: None,
+            transaction_type: None,
         };
         
         let transaction_request = service.spend_to_create_transaction_request(spend_request);
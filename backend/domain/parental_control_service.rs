@@ -1,53 +1,119 @@
 use anyhow::Result;
-use log::{info, error};
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+use chrono::{DateTime, Duration, Utc};
+use log::{info, warn, error};
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::backend::storage::csv::{CsvConnection, ParentalControlRepository};
+use crate::backend::domain::secrets_service::{SecretsService, PARENTAL_CONTROL_ANSWER_SECRET};
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::models::actor::Actor;
+use crate::backend::domain::models::parent_user::{ParentPermission, ParentUser};
+use crate::backend::storage::csv::{CsvConnection, GlobalConfigRepository, ParentalControlRepository, ReadOnlyError};
 use crate::backend::storage::traits::ParentalControlStorage;
-use crate::backend::domain::commands::parental_control::{ValidateParentalControlCommand, ValidateParentalControlResult};
+use crate::backend::storage::GlobalConfigStorage;
+use crate::backend::domain::commands::parental_control::{
+    AddParentCommand, RemoveParentCommand, UpdateParentCommand, ValidateParentalControlCommand,
+    ValidateParentalControlResult,
+};
 
 
-/// Service for handling parental control validation
+/// Answer used until a parent sets their own
+const DEFAULT_ANSWER: &str = "ice cold";
+
+/// Allowed PIN lengths, in digits
+const PIN_LENGTHS: std::ops::RangeInclusive<usize> = 4..=8;
+
+/// Consecutive wrong answers that lock the challenge, and for how many seconds.
+/// Checked in order, so the longest lockout wins.
+const LOCKOUT_STEPS: [(usize, i64); 2] = [(6, 5 * 60), (3, 30)];
+
+/// Argon2id cost for PIN hashes; kept small in tests so they stay fast
+const PIN_KDF_MEMORY_KIB: u32 = if cfg!(test) { 1024 } else { 19 * 1024 };
+
+/// Service for handling parental control validation and named parents
 #[derive(Clone)]
 pub struct ParentalControlService {
     parental_control_repository: ParentalControlRepository,
+    global_config_repository: GlobalConfigRepository,
     correct_answer: String,
 }
 
 impl ParentalControlService {
-    /// Create a new ParentalControlService with the default correct answer
-    pub fn new(csv_conn: Arc<CsvConnection>) -> Self {
-        let parental_control_repository = ParentalControlRepository::new((*csv_conn).clone());
-        Self {
-            parental_control_repository,
-            correct_answer: "ice cold".to_string(),
-        }
+    /// Create a new ParentalControlService with the answer kept in the secrets store.
+    /// The default answer is stored there the first time so it can be changed later.
+    pub fn new(csv_conn: Arc<CsvConnection>, secrets: &SecretsService) -> Self {
+        let correct_answer = match secrets.get_secret(PARENTAL_CONTROL_ANSWER_SECRET) {
+            Ok(Some(answer)) => answer,
+            Ok(None) => {
+                if let Err(e) = secrets.set_secret(PARENTAL_CONTROL_ANSWER_SECRET, DEFAULT_ANSWER) {
+                    warn!("Failed to store default parental control answer: {}", e);
+                }
+                DEFAULT_ANSWER.to_string()
+            }
+            Err(e) => {
+                warn!("Failed to read parental control answer from {}: {}; using default", secrets.backend_name(), e);
+                DEFAULT_ANSWER.to_string()
+            }
+        };
+        Self::with_answer(csv_conn, correct_answer)
     }
 
-    /// Create a new ParentalControlService with a custom correct answer (for testing)
+    /// Create a new ParentalControlService with a specific correct answer
     pub fn with_answer(csv_conn: Arc<CsvConnection>, answer: String) -> Self {
         let parental_control_repository = ParentalControlRepository::new((*csv_conn).clone());
+        let global_config_repository = GlobalConfigRepository::new((*csv_conn).clone());
         Self {
             parental_control_repository,
+            global_config_repository,
             correct_answer: answer.to_lowercase().trim().to_string(),
         }
     }
 
-    /// Validate a parental control answer
+    /// Validate a parental control answer, or a named parent's PIN
     pub fn validate_answer(&self, command: ValidateParentalControlCommand) -> Result<ValidateParentalControlResult> {
+        self.validate_answer_at(command, Utc::now())
+    }
+
+    fn validate_answer_at(&self, command: ValidateParentalControlCommand, now: DateTime<Utc>) -> Result<ValidateParentalControlResult> {
+        // While locked out nothing is checked or recorded, so guessing can't extend the wait
+        if let Some(seconds) = self.lockout_remaining(now)? {
+            info!("Parental control locked for another {}s", seconds);
+            return Ok(locked_result(seconds));
+        }
+
         let attempted_answer = command.answer.trim();
         info!("Validating parental control answer (length: {})", attempted_answer.len());
 
-        // Perform case-insensitive comparison
-        let is_correct = attempted_answer.to_lowercase() == self.correct_answer;
+        // Perform case-insensitive comparison; the family answer can do everything
+        let is_family_answer = attempted_answer.to_lowercase() == self.correct_answer;
+        let parent = if is_family_answer { None } else { self.find_parent_by_pin(attempted_answer)? };
+        let missing_permission = match (&parent, command.permission) {
+            (Some(parent), Some(permission)) if !parent.can(permission) => Some(permission),
+            _ => None,
+        };
+        let is_correct = is_family_answer || (parent.is_some() && missing_permission.is_none());
+
+        // A matching PIN is never written to disk, only whose it was
+        let recorded_value = match &parent {
+            Some(parent) => format!("PIN for {}", parent.name),
+            None => attempted_answer.to_string(),
+        };
 
         // Store the attempt
         let _attempt_id = match self.parental_control_repository.record_parental_control_attempt(
             "global",
-            attempted_answer,
+            &recorded_value,
             is_correct,
         ) {
             Ok(id) => id,
+            Err(e) if e.downcast_ref::<ReadOnlyError>().is_some() => {
+                // Nothing can be recorded in read-only mode, but validation still works
+                info!("Read-only mode: parental control attempt not recorded");
+                0
+            }
             Err(e) => {
                 error!("Failed to store parental control attempt: {}", e);
                 return Err(anyhow::anyhow!("Failed to store parental control attempt"));
@@ -55,23 +121,202 @@ impl ParentalControlService {
         };
 
         // Generate response
-        let result = if is_correct {
-            info!("Parental control validation successful");
-            ValidateParentalControlResult {
-                success: true,
-                message: "Access granted! Welcome to parental settings.".to_string(),
+        let mut result = match (parent, missing_permission) {
+            (Some(parent), Some(permission)) => {
+                info!("Parental control: {} lacks permission {:?}", parent.name, permission);
+                ValidateParentalControlResult {
+                    success: false,
+                    message: format!("{} isn't allowed to {}.", parent.name, permission.label().to_lowercase()),
+                    actor: None,
+                    locked_for_seconds: None,
+                }
             }
-        } else {
-            info!("Parental control validation failed for answer: '{}'", attempted_answer);
-            ValidateParentalControlResult {
-                success: false,
-                message: "Incorrect answer. Access denied.".to_string(),
+            (Some(parent), None) => {
+                info!("Parental control validation successful for {}", parent.name);
+                ValidateParentalControlResult {
+                    success: true,
+                    message: format!("Access granted! Welcome, {}.", parent.name),
+                    actor: Some(Actor::Parent(Some(parent.name))),
+                    locked_for_seconds: None,
+                }
+            }
+            (None, _) if is_correct => {
+                info!("Parental control validation successful");
+                ValidateParentalControlResult {
+                    success: true,
+                    message: "Access granted! Welcome to parental settings.".to_string(),
+                    actor: Some(Actor::Parent(None)),
+                    locked_for_seconds: None,
+                }
+            }
+            (None, _) => {
+                info!("Parental control validation failed for answer: '{}'", attempted_answer);
+                ValidateParentalControlResult {
+                    success: false,
+                    message: "Incorrect answer. Access denied.".to_string(),
+                    actor: None,
+                    locked_for_seconds: None,
+                }
             }
         };
 
+        if !result.success {
+            if let Some(seconds) = self.lockout_remaining(now)? {
+                warn!("Parental control locked for {}s after repeated failures", seconds);
+                result = locked_result(seconds);
+            }
+        }
+
         Ok(result)
     }
 
+    /// Seconds left on a lockout from recent consecutive failures, if any
+    fn lockout_remaining(&self, now: DateTime<Utc>) -> Result<Option<u64>> {
+        let recent = self
+            .parental_control_repository
+            .get_parental_control_attempts("global", Some(LOCKOUT_STEPS[0].0 as u32))?;
+        let failures = recent.iter().take_while(|attempt| !attempt.success).count();
+        let Some(&(_, seconds)) = LOCKOUT_STEPS.iter().find(|(threshold, _)| failures >= *threshold) else {
+            return Ok(None);
+        };
+        let Some(last_failure) = recent.first().and_then(|attempt| DateTime::parse_from_rfc3339(&attempt.timestamp).ok()) else {
+            return Ok(None);
+        };
+        let remaining = last_failure.with_timezone(&Utc) + Duration::seconds(seconds) - now;
+        let remaining_ms = remaining.num_milliseconds();
+        Ok((remaining_ms > 0).then(|| (remaining_ms as u64).div_ceil(1000)))
+    }
+
+    /// The named parent whose PIN this is, if any
+    fn find_parent_by_pin(&self, pin: &str) -> Result<Option<ParentUser>> {
+        if validate_pin(pin).is_err() {
+            return Ok(None);
+        }
+        Ok(self
+            .list_parents()?
+            .into_iter()
+            .find(|parent| verify_pin(pin, &parent.pin_hash)))
+    }
+
+    /// Named parents, in the order they were added
+    pub fn list_parents(&self) -> Result<Vec<ParentUser>> {
+        Ok(self.global_config_repository.get_global_config()?.parents)
+    }
+
+    /// Add a named parent with their own PIN
+    pub fn add_parent(&self, command: AddParentCommand) -> Result<ParentUser> {
+        let mut config = self.global_config_repository.get_global_config()?;
+        let name = validate_parent_name(&command.name, &config.parents, None)?;
+        validate_pin(&command.pin)?;
+        ensure_pin_unused(&command.pin, &config.parents, None)?;
+
+        let now_millis = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+        let parent = ParentUser {
+            id: ParentUser::generate_id(now_millis),
+            name,
+            pin_hash: hash_pin(&command.pin)?,
+            permissions: normalize_permissions(command.permissions),
+            created_at: Utc::now().to_rfc3339(),
+        };
+        config.parents.push(parent.clone());
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!("👪 PARENTS: Added {} ({}) with {:?}", parent.name, parent.id, parent.permissions);
+        Ok(parent)
+    }
+
+    /// Rename a parent, change their PIN or change what they may do
+    pub fn update_parent(&self, command: UpdateParentCommand) -> Result<ParentUser> {
+        let mut config = self.global_config_repository.get_global_config()?;
+        let index = config
+            .parents
+            .iter()
+            .position(|parent| parent.id == command.parent_id)
+            .ok_or_else(|| DomainError::NotFound(format!("Parent not found: {}", command.parent_id)))?;
+
+        if let Some(name) = &command.name {
+            config.parents[index].name = validate_parent_name(name, &config.parents, Some(&command.parent_id))?;
+        }
+        if let Some(pin) = &command.pin {
+            validate_pin(pin)?;
+            ensure_pin_unused(pin, &config.parents, Some(&command.parent_id))?;
+            config.parents[index].pin_hash = hash_pin(pin)?;
+        }
+        if let Some(permissions) = command.permissions {
+            config.parents[index].permissions = normalize_permissions(permissions);
+        }
+        let parent = config.parents[index].clone();
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!("👪 PARENTS: Updated {} ({})", parent.name, parent.id);
+        Ok(parent)
+    }
+
+    /// Remove a named parent; their past entries keep their name
+    pub fn remove_parent(&self, command: RemoveParentCommand) -> Result<()> {
+        let mut config = self.global_config_repository.get_global_config()?;
+        let before = config.parents.len();
+        config.parents.retain(|parent| parent.id != command.parent_id);
+        if config.parents.len() == before {
+            return Err(DomainError::NotFound(format!("Parent not found: {}", command.parent_id)).into());
+        }
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!("👪 PARENTS: Removed {}", command.parent_id);
+        Ok(())
+    }
+
+    /// Whether exporting transactions needs the parental answer or a parent's PIN
+    pub fn export_requires_parental_control(&self) -> Result<bool> {
+        Ok(self.global_config_repository.get_global_config()?.require_parental_control_for_export)
+    }
+
+    /// Turn the export check on or off
+    pub fn set_export_requires_parental_control(&self, required: bool) -> Result<()> {
+        let mut config = self.global_config_repository.get_global_config()?;
+        config.require_parental_control_for_export = required;
+        config.updated_at = Utc::now().to_rfc3339();
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!("🔐 Export {} parental control", if required { "now requires" } else { "no longer requires" });
+        Ok(())
+    }
+
+    /// Whether moving a transaction to another day needs the parental answer or a parent's PIN
+    pub fn moving_transactions_requires_parental_control(&self) -> Result<bool> {
+        Ok(self.global_config_repository.get_global_config()?.require_parental_control_to_move_transactions)
+    }
+
+    /// Turn the check for moving transactions on or off
+    pub fn set_moving_transactions_requires_parental_control(&self, required: bool) -> Result<()> {
+        let mut config = self.global_config_repository.get_global_config()?;
+        config.require_parental_control_to_move_transactions = required;
+        config.updated_at = Utc::now().to_rfc3339();
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!("🔐 Moving transactions {} parental control", if required { "now requires" } else { "no longer requires" });
+        Ok(())
+    }
+
+    /// Check an export may go ahead. When the policy is on, the answer must be
+    /// the family answer or the PIN of a parent allowed to change settings.
+    pub fn authorize_export(&self, answer: Option<&str>) -> Result<()> {
+        if !self.export_requires_parental_control()? {
+            return Ok(());
+        }
+        let Some(answer) = answer.filter(|answer| !answer.trim().is_empty()) else {
+            return Err(DomainError::Unauthorized("Exporting needs the parental control answer".to_string()).into());
+        };
+        let result = self.validate_answer(ValidateParentalControlCommand {
+            answer: answer.to_string(),
+            permission: Some(ParentPermission::ManageSettings),
+        })?;
+        if !result.success {
+            return Err(DomainError::Unauthorized(result.message).into());
+        }
+        Ok(())
+    }
+
     /// Get the correct answer (for testing purposes)
     #[cfg(test)]
     pub fn get_correct_answer(&self) -> &str {
@@ -122,6 +367,84 @@ impl ParentalControlService {
     }
 }
 
+/// Response while the challenge is locked out
+fn locked_result(seconds: u64) -> ValidateParentalControlResult {
+    let wait = if seconds > 60 {
+        format!("{} minutes", seconds.div_ceil(60))
+    } else {
+        format!("{} seconds", seconds)
+    };
+    ValidateParentalControlResult {
+        success: false,
+        message: format!("Too many wrong answers. Try again in {}.", wait),
+        actor: None,
+        locked_for_seconds: Some(seconds),
+    }
+}
+
+/// Trim a parent's name and check it is usable and not taken by another parent
+fn validate_parent_name(name: &str, parents: &[ParentUser], except_id: Option<&str>) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() || name.len() > 50 {
+        return Err(DomainError::invalid("Parent name must be between 1 and 50 characters").into());
+    }
+    let taken = parents
+        .iter()
+        .any(|parent| Some(parent.id.as_str()) != except_id && parent.name.eq_ignore_ascii_case(name));
+    if taken {
+        return Err(DomainError::Conflict(format!("There is already a parent called {}", name)).into());
+    }
+    Ok(name.to_string())
+}
+
+/// A PIN is 4 to 8 digits
+fn validate_pin(pin: &str) -> Result<()> {
+    if !PIN_LENGTHS.contains(&pin.len()) || !pin.chars().all(|c| c.is_ascii_digit()) {
+        return Err(DomainError::invalid("PIN must be 4 to 8 digits").into());
+    }
+    Ok(())
+}
+
+/// Two parents can't share a PIN, or signing in couldn't tell them apart
+fn ensure_pin_unused(pin: &str, parents: &[ParentUser], except_id: Option<&str>) -> Result<()> {
+    let in_use = parents
+        .iter()
+        .any(|parent| Some(parent.id.as_str()) != except_id && verify_pin(pin, &parent.pin_hash));
+    if in_use {
+        return Err(DomainError::Conflict("That PIN is already used by another parent".to_string()).into());
+    }
+    Ok(())
+}
+
+/// Permissions without duplicates, in the order shown in settings
+fn normalize_permissions(permissions: Vec<ParentPermission>) -> Vec<ParentPermission> {
+    ParentPermission::ALL
+        .into_iter()
+        .filter(|permission| permissions.contains(permission))
+        .collect()
+}
+
+fn pin_hasher() -> Result<Argon2<'static>> {
+    let params = Params::new(PIN_KDF_MEMORY_KIB, 2, 1, None)
+        .map_err(|e| anyhow::anyhow!("Invalid PIN hashing parameters: {}", e))?;
+    Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+}
+
+fn hash_pin(pin: &str) -> Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = pin_hasher()?
+        .hash_password(pin.as_bytes(), &salt)
+        .map_err(|e| anyhow::anyhow!("Failed to hash PIN: {}", e))?;
+    Ok(hash.to_string())
+}
+
+/// The hash records its own parameters, so any Argon2 instance can check it
+fn verify_pin(pin: &str, pin_hash: &str) -> bool {
+    PasswordHash::new(pin_hash)
+        .map(|hash| Argon2::default().verify_password(pin.as_bytes(), &hash).is_ok())
+        .unwrap_or(false)
+}
+
 /// Statistics for parental control validation attempts
 #[derive(Debug, Clone)]
 pub struct ParentalControlStats {
@@ -135,11 +458,16 @@ pub struct ParentalControlStats {
 mod tests {
     use super::*;
     use crate::backend::storage::csv::CsvConnection;
+    use crate::backend::storage::secrets::EncryptedFileSecretStore;
+
+    fn test_secrets(dir: &std::path::Path) -> SecretsService {
+        SecretsService::with_store(Arc::new(EncryptedFileSecretStore::new(dir.join("secrets")).unwrap()))
+    }
 
     fn setup_test() -> ParentalControlService {
         let temp_dir = tempfile::tempdir().unwrap();
         let db = Arc::new(CsvConnection::new(temp_dir.path()).expect("Failed to create test database"));
-        ParentalControlService::new(db)
+        ParentalControlService::new(db, &test_secrets(temp_dir.path()))
     }
 
     #[test]
@@ -148,6 +476,7 @@ mod tests {
         
         let command = ValidateParentalControlCommand {
             answer: "ice cold".to_string(),
+            permission: None,
         };
         
         let response = service.validate_answer(command).unwrap();
@@ -170,6 +499,7 @@ mod tests {
         for answer in test_cases {
             let command = ValidateParentalControlCommand {
                 answer: answer.to_string(),
+                permission: None,
             };
             
             let response = service.validate_answer(command).unwrap();
@@ -191,6 +521,7 @@ mod tests {
         for answer in test_cases {
             let command = ValidateParentalControlCommand {
                 answer: answer.to_string(),
+                permission: None,
             };
             
             let response = service.validate_answer(command).unwrap();
@@ -200,8 +531,6 @@ mod tests {
 
     #[test]
     fn test_incorrect_answer_validation() {
-        let service = setup_test();
-        
         let test_cases = vec![
             "wrong answer",
             "cold ice",
@@ -213,8 +542,11 @@ mod tests {
         ];
         
         for answer in test_cases {
+            // A fresh service each time, so repeated failures don't lock the challenge
+            let service = setup_test();
             let command = ValidateParentalControlCommand {
                 answer: answer.to_string(),
+                permission: None,
             };
             
             let response = service.validate_answer(command).unwrap();
@@ -234,12 +566,14 @@ mod tests {
         // Make a correct attempt
         let correct_command = ValidateParentalControlCommand {
             answer: "ice cold".to_string(),
+            permission: None,
         };
         service.validate_answer(correct_command).unwrap();
         
         // Make an incorrect attempt
         let incorrect_command = ValidateParentalControlCommand {
             answer: "wrong".to_string(),
+            permission: None,
         };
         service.validate_answer(incorrect_command).unwrap();
         
@@ -277,6 +611,7 @@ mod tests {
         for (answer, _expected) in requests {
             let command = ValidateParentalControlCommand {
                 answer: answer.to_string(),
+                permission: None,
             };
             service.validate_answer(command).unwrap();
         }
@@ -298,6 +633,7 @@ mod tests {
         // Test correct custom answer
         let correct_command = ValidateParentalControlCommand {
             answer: "custom answer".to_string(),
+            permission: None,
         };
         let response = service.validate_answer(correct_command).unwrap();
         assert!(response.success);
@@ -305,8 +641,157 @@ mod tests {
         // Test default answer should fail
         let default_command = ValidateParentalControlCommand {
             answer: "ice cold".to_string(),
+            permission: None,
         };
         let response = service.validate_answer(default_command).unwrap();
         assert!(!response.success);
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_named_parents_sign_in_with_pin_and_permissions() {
+        let service = setup_test();
+        let grandma = service
+            .add_parent(AddParentCommand {
+                name: " Grandma ".to_string(),
+                pin: "2468".to_string(),
+                permissions: vec![ParentPermission::ManageSettings, ParentPermission::ManageSettings],
+            })
+            .unwrap();
+        assert_eq!(grandma.name, "Grandma");
+        assert_eq!(grandma.permissions, vec![ParentPermission::ManageSettings]);
+        assert!(!grandma.pin_hash.contains("2468"));
+
+        let validate = |answer: &str, permission: ParentPermission| {
+            service
+                .validate_answer(ValidateParentalControlCommand { answer: answer.to_string(), permission: Some(permission) })
+                .unwrap()
+        };
+        let settings = validate("2468", ParentPermission::ManageSettings);
+        assert!(settings.success);
+        assert_eq!(settings.actor, Some(Actor::Parent(Some("Grandma".to_string()))));
+        let delete = validate("2468", ParentPermission::DeleteTransactions);
+        assert!(!delete.success);
+        assert!(delete.message.contains("isn't allowed to delete transactions"));
+
+        // The family answer still opens everything, and PINs never reach the attempts log
+        assert_eq!(validate("ice cold", ParentPermission::DeleteTransactions).actor, Some(Actor::Parent(None)));
+        assert!(!validate("1357", ParentPermission::ManageSettings).success);
+        let recorded: Vec<String> = service.get_recent_attempts(None).unwrap().into_iter().map(|a| a.attempted_value).collect();
+        assert!(!recorded.iter().any(|value| value == "2468"));
+        assert!(recorded.contains(&"PIN for Grandma".to_string()));
+
+        // Names and PINs must be unique, and PINs must be digits
+        let add = |name: &str, pin: &str| {
+            service.add_parent(AddParentCommand { name: name.to_string(), pin: pin.to_string(), permissions: vec![] })
+        };
+        assert!(matches!(add("grandma", "1111").unwrap_err().downcast_ref::<DomainError>(), Some(DomainError::Conflict(_))));
+        assert!(matches!(add("Dad", "2468").unwrap_err().downcast_ref::<DomainError>(), Some(DomainError::Conflict(_))));
+        assert!(matches!(add("Dad", "12ab").unwrap_err().downcast_ref::<DomainError>(), Some(DomainError::Invalid(_))));
+
+        service
+            .update_parent(UpdateParentCommand {
+                parent_id: grandma.id.clone(),
+                name: None,
+                pin: Some("8642".to_string()),
+                permissions: Some(ParentPermission::ALL.to_vec()),
+            })
+            .unwrap();
+        assert!(!validate("2468", ParentPermission::ManageSettings).success);
+        assert!(validate("8642", ParentPermission::DeleteTransactions).success);
+
+        service.remove_parent(RemoveParentCommand { parent_id: grandma.id.clone() }).unwrap();
+        assert!(service.list_parents().unwrap().is_empty());
+        assert!(service.remove_parent(RemoveParentCommand { parent_id: grandma.id }).is_err());
+    }
+
+    #[test]
+    fn test_repeated_failures_lock_the_challenge() {
+        let service = setup_test();
+        let validate_at = |answer: &str, now: DateTime<Utc>| {
+            service
+                .validate_answer_at(ValidateParentalControlCommand { answer: answer.to_string(), permission: None }, now)
+                .unwrap()
+        };
+        let now = Utc::now();
+
+        assert_eq!(validate_at("wrong", now).locked_for_seconds, None);
+        assert_eq!(validate_at("wrong", now).locked_for_seconds, None);
+        let third = validate_at("wrong", now);
+        assert!(third.locked_for_seconds.is_some_and(|seconds| (29..=31).contains(&seconds)));
+        assert!(third.message.contains("Too many wrong answers"));
+
+        // Even the right answer is refused, and not recorded, until the lockout ends
+        let locked = validate_at("ice cold", now + Duration::seconds(10));
+        assert!(!locked.success);
+        assert!(locked.locked_for_seconds.is_some());
+        assert_eq!(service.get_recent_attempts(None).unwrap().len(), 3);
+
+        // Attempts are stamped with the real time, so later checks are simulated
+        // from there. Three more failures make the next lockout longer.
+        let later = now + Duration::seconds(31);
+        for _ in 0..3 {
+            assert!(!validate_at("wrong", later).success);
+        }
+        assert!(service.lockout_remaining(Utc::now()).unwrap().is_some_and(|seconds| seconds > 250));
+        assert!(validate_at("ice cold", later).message.contains("minutes"));
+
+        // Once it has passed, the right answer works and resets the count
+        let unlocked = validate_at("ice cold", now + Duration::minutes(10));
+        assert!(unlocked.success);
+        assert_eq!(unlocked.locked_for_seconds, None);
+        assert_eq!(validate_at("wrong", now + Duration::minutes(10)).locked_for_seconds, None);
+    }
+
+    #[test]
+    fn test_answer_comes_from_secrets_store() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(CsvConnection::new(temp_dir.path()).expect("Failed to create test database"));
+        let secrets = test_secrets(temp_dir.path());
+
+        // First start seeds the default answer into the secrets store
+        ParentalControlService::new(db.clone(), &secrets);
+        assert_eq!(secrets.get_secret(PARENTAL_CONTROL_ANSWER_SECRET).unwrap().as_deref(), Some(DEFAULT_ANSWER));
+
+        secrets.set_secret(PARENTAL_CONTROL_ANSWER_SECRET, "warm milk").unwrap();
+        let service = ParentalControlService::new(db, &secrets);
+        assert_eq!(service.get_correct_answer(), "warm milk");
+        assert!(!service.validate_answer(ValidateParentalControlCommand { answer: "ice cold".to_string(), permission: None }).unwrap().success);
+    }
+
+    #[test]
+    fn test_export_policy_requires_answer() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(CsvConnection::new(temp_dir.path()).expect("Failed to create test database"));
+        let service = ParentalControlService::with_answer(db, "ice cold".to_string());
+
+        // Off by default, so exports need nothing
+        assert!(!service.export_requires_parental_control().unwrap());
+        service.authorize_export(None).unwrap();
+
+        service.set_export_requires_parental_control(true).unwrap();
+        assert!(service.export_requires_parental_control().unwrap());
+        for answer in [None, Some("  "), Some("wrong")] {
+            let refused = service.authorize_export(answer).unwrap_err();
+            assert!(matches!(refused.downcast_ref::<DomainError>(), Some(DomainError::Unauthorized(_))));
+        }
+        service.authorize_export(Some("Ice Cold")).unwrap();
+
+        service.set_export_requires_parental_control(false).unwrap();
+        service.authorize_export(None).unwrap();
+    }
+
+    #[test]
+    fn test_move_policy_is_off_until_turned_on() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(CsvConnection::new(temp_dir.path()).expect("Failed to create test database"));
+        let service = ParentalControlService::with_answer(db, "ice cold".to_string());
+
+        assert!(!service.moving_transactions_requires_parental_control().unwrap());
+        service.set_moving_transactions_requires_parental_control(true).unwrap();
+        assert!(service.moving_transactions_requires_parental_control().unwrap());
+        // The export policy is separate
+        assert!(!service.export_requires_parental_control().unwrap());
+        service.set_moving_transactions_requires_parental_control(false).unwrap();
+        assert!(!service.moving_transactions_requires_parental_control().unwrap());
+    }
+}
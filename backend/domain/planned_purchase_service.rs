@@ -0,0 +1,268 @@
+//! Planned purchase service domain logic for the allowance tracker.
+//!
+//! A child can commit to buying something on a future date. The amount is
+//! reserved straight away: it stays in the balance, but is taken out of what
+//! is available, so the same money can't be promised twice. Confirming the
+//! purchase records it as an expense; cancelling releases the money.
+
+use anyhow::Result;
+use chrono::{Local, NaiveDate, Utc};
+use log::info;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::commands::planned_purchases::{
+    CancelPlannedPurchaseCommand, ConfirmPlannedPurchaseCommand, ConfirmPlannedPurchaseResult,
+    CreatePlannedPurchaseCommand, PlannedPurchasesQuery, PlannedPurchasesResult,
+};
+use crate::backend::domain::commands::transactions::CreateTransactionCommand;
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::models::planned_purchase::{PlannedPurchase, PlannedPurchaseState};
+use crate::backend::domain::{BalanceService, TransactionService};
+use crate::backend::storage::csv::{CsvConnection, PlannedPurchaseRepository};
+use crate::backend::storage::traits::PlannedPurchaseStorage;
+
+/// Service for reserving money ahead of a purchase
+#[derive(Clone)]
+pub struct PlannedPurchaseService {
+    planned_purchase_repository: PlannedPurchaseRepository,
+    child_service: ChildService,
+    transaction_service: Arc<TransactionService>,
+    balance_service: BalanceService,
+}
+
+impl PlannedPurchaseService {
+    /// Create a new PlannedPurchaseService
+    pub fn new(
+        csv_conn: Arc<CsvConnection>,
+        child_service: ChildService,
+        transaction_service: Arc<TransactionService>,
+        balance_service: BalanceService,
+    ) -> Self {
+        let planned_purchase_repository = PlannedPurchaseRepository::new((*csv_conn).clone());
+        Self {
+            planned_purchase_repository,
+            child_service,
+            transaction_service,
+            balance_service,
+        }
+    }
+
+    /// Reserve money for something the active child plans to buy
+    pub fn create_planned_purchase(&self, command: CreatePlannedPurchaseCommand) -> Result<PlannedPurchase> {
+        self.create_on(command, Local::now().date_naive())
+    }
+
+    fn create_on(&self, command: CreatePlannedPurchaseCommand, today: NaiveDate) -> Result<PlannedPurchase> {
+        let description = command.description.trim().to_string();
+        if description.is_empty() || description.len() > 256 {
+            return Err(DomainError::invalid("Description must be between 1 and 256 characters").into());
+        }
+        if !command.amount.is_finite() || command.amount <= 0.0 {
+            return Err(DomainError::invalid("Planned purchase amount must be greater than 0").into());
+        }
+        if command.planned_date < today {
+            return Err(DomainError::invalid("A planned purchase can't be in the past").into());
+        }
+
+        let child = self.transaction_service.get_active_child()?;
+        let summary = self.summarise(&child.id, false)?;
+        if command.amount > summary.available + 0.005 {
+            return Err(DomainError::invalid(format!(
+                "Only ${:.2} is available (${:.2} is already reserved)",
+                summary.available.max(0.0),
+                summary.reserved
+            ))
+            .into());
+        }
+
+        let now_millis = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+        let now = Utc::now().to_rfc3339();
+        let purchase = PlannedPurchase {
+            id: PlannedPurchase::generate_id(&child.id, now_millis),
+            child_id: child.id.clone(),
+            description,
+            amount: command.amount,
+            planned_date: command.planned_date,
+            state: PlannedPurchaseState::Planned,
+            transaction_id: None,
+            created_at: now.clone(),
+            updated_at: now,
+        };
+        self.planned_purchase_repository.store_planned_purchase(&purchase)?;
+
+        info!("🛍️ PLANNED: {} reserved ${:.2} for {} on {}", child.id, purchase.amount, purchase.description, purchase.planned_date);
+        Ok(purchase)
+    }
+
+    /// Record a planned purchase as an actual expense
+    pub fn confirm_planned_purchase(&self, command: ConfirmPlannedPurchaseCommand) -> Result<ConfirmPlannedPurchaseResult> {
+        let mut purchase = self.open_purchase(&command.purchase_id)?;
+        let amount = command.amount.unwrap_or(purchase.amount);
+        if !amount.is_finite() || amount <= 0.0 {
+            return Err(DomainError::invalid("Purchase amount must be greater than 0").into());
+        }
+
+        // The money was set aside for this, so the overdraft rule doesn't apply
+        let transaction = self.transaction_service.create_transaction(CreateTransactionCommand {
+            child_id: Some(purchase.child_id.clone()),
+            description: purchase.description.clone(),
+            amount: -amount,
+            date: command.date,
+            parent_override: true,
+            actor: command.actor,
+            icon: None,
+            transaction_type: None,
+        })?;
+
+        purchase.state = PlannedPurchaseState::Confirmed;
+        purchase.transaction_id = Some(transaction.id.clone());
+        purchase.updated_at = Utc::now().to_rfc3339();
+        self.planned_purchase_repository.store_planned_purchase(&purchase)?;
+
+        info!("🛍️ PLANNED: {} confirmed as {}", purchase.id, transaction.id);
+        Ok(ConfirmPlannedPurchaseResult { purchase, transaction })
+    }
+
+    /// Drop a planned purchase and release its money
+    pub fn cancel_planned_purchase(&self, command: CancelPlannedPurchaseCommand) -> Result<PlannedPurchase> {
+        let mut purchase = self.open_purchase(&command.purchase_id)?;
+        purchase.state = PlannedPurchaseState::Cancelled;
+        purchase.updated_at = Utc::now().to_rfc3339();
+        self.planned_purchase_repository.store_planned_purchase(&purchase)?;
+
+        info!("🛍️ PLANNED: {} cancelled, ${:.2} released", purchase.id, purchase.amount);
+        Ok(purchase)
+    }
+
+    /// A child's planned purchases with their balance, reserved and available amounts
+    pub fn list_planned_purchases(&self, query: PlannedPurchasesQuery) -> Result<PlannedPurchasesResult> {
+        let child_id = match query.child_id {
+            Some(id) => id,
+            None => self
+                .child_service
+                .get_active_child()?
+                .active_child
+                .child
+                .map(|child| child.id)
+                .ok_or(DomainError::NoActiveChild)?,
+        };
+        self.summarise(&child_id, query.include_closed)
+    }
+
+    fn summarise(&self, child_id: &str, include_closed: bool) -> Result<PlannedPurchasesResult> {
+        let mut purchases = self.planned_purchase_repository.list_planned_purchases(child_id)?;
+        let reserved = round_cents(purchases.iter().filter(|p| p.is_open()).map(|p| p.amount).sum());
+        if !include_closed {
+            purchases.retain(|p| p.is_open());
+        }
+        let balance = self.balance_service.get_current_balance(child_id)?;
+
+        Ok(PlannedPurchasesResult {
+            child_id: child_id.to_string(),
+            purchases,
+            balance,
+            reserved,
+            available: round_cents(balance - reserved),
+        })
+    }
+
+    /// The active child's planned purchase with this id, if it is still planned
+    fn open_purchase(&self, purchase_id: &str) -> Result<PlannedPurchase> {
+        let child = self.transaction_service.get_active_child()?;
+        let purchase = self
+            .planned_purchase_repository
+            .list_planned_purchases(&child.id)?
+            .into_iter()
+            .find(|p| p.id == purchase_id)
+            .ok_or_else(|| DomainError::NotFound(format!("Planned purchase not found: {}", purchase_id)))?;
+        if !purchase.is_open() {
+            return Err(DomainError::Conflict(format!("\"{}\" is no longer planned", purchase.description)).into());
+        }
+        Ok(purchase)
+    }
+}
+
+fn round_cents(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand};
+    use crate::backend::domain::AllowanceService;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_planned_purchase_reserves_then_confirms_or_releases() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let child_service = ChildService::new(connection.clone());
+        let balance_service = BalanceService::new(connection.clone());
+        let transaction_service = Arc::new(TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            AllowanceService::new(connection.clone()),
+            balance_service.clone(),
+        ));
+        let service = PlannedPurchaseService::new(connection, child_service.clone(), transaction_service.clone(), balance_service);
+
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })?
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() })?;
+        transaction_service.create_transaction(CreateTransactionCommand {
+            child_id: None,
+            description: "Birthday".to_string(),
+            amount: 30.0,
+            date: None,
+            parent_override: false,
+            actor: None,
+            icon: None,
+            transaction_type: None,
+        })?;
+
+        let today = NaiveDate::from_ymd_opt(2025, 6, 18).unwrap();
+        let plan = |description: &str, amount: f64, planned_date: NaiveDate| {
+            service.create_on(
+                CreatePlannedPurchaseCommand { description: description.to_string(), amount, planned_date },
+                today,
+            )
+        };
+        let lego = plan("Lego", 20.0, NaiveDate::from_ymd_opt(2025, 7, 1).unwrap())?;
+
+        let summary = service.list_planned_purchases(PlannedPurchasesQuery { child_id: None, include_closed: false })?;
+        assert_eq!((summary.balance, summary.reserved, summary.available), (30.0, 20.0, 10.0));
+
+        // The same money can't be reserved twice, and plans can't be backdated
+        assert!(plan("Kite", 15.0, today).is_err());
+        assert!(plan("Kite", 5.0, NaiveDate::from_ymd_opt(2025, 6, 17).unwrap()).is_err());
+
+        service.cancel_planned_purchase(CancelPlannedPurchaseCommand { purchase_id: lego.id.clone() })?;
+        let summary = service.list_planned_purchases(PlannedPurchasesQuery { child_id: None, include_closed: false })?;
+        assert!(summary.purchases.is_empty());
+        assert_eq!(summary.available, 30.0);
+
+        let kite = plan("Kite", 25.0, today)?;
+        let confirmed = service.confirm_planned_purchase(ConfirmPlannedPurchaseCommand {
+            purchase_id: kite.id.clone(),
+            amount: Some(22.5),
+            date: None,
+            actor: None,
+        })?;
+        assert_eq!(confirmed.transaction.amount, -22.5);
+        assert_eq!(confirmed.transaction.description, "Kite");
+        assert_eq!(confirmed.purchase.transaction_id, Some(confirmed.transaction.id.clone()));
+
+        let summary = service.list_planned_purchases(PlannedPurchasesQuery { child_id: Some(child.id), include_closed: true })?;
+        assert_eq!((summary.balance, summary.reserved, summary.available), (7.5, 0.0, 7.5));
+        let states: Vec<PlannedPurchaseState> = summary.purchases.iter().map(|p| p.state).collect();
+        assert_eq!(states, vec![PlannedPurchaseState::Confirmed, PlannedPurchaseState::Cancelled]);
+
+        let again = service.cancel_planned_purchase(CancelPlannedPurchaseCommand { purchase_id: kite.id });
+        assert!(matches!(again.unwrap_err().downcast_ref::<DomainError>(), Some(DomainError::Conflict(_))));
+        Ok(())
+    }
+}
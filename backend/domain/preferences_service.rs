@@ -0,0 +1,141 @@
+//! Preferences service domain logic for the allowance tracker.
+//!
+//! App-wide display preferences, such as text size, the color theme, the
+//! calendar's weekly totals and goal strip, and reduced motion, are kept in
+//! the global config so every child and every launch of the app sees the same setting.
+//!
+//! Whether the guided tour has been taken is kept there too, so it is only
+//! offered once per data directory unless a parent asks to replay it.
+
+use anyhow::Result;
+use chrono::Utc;
+use log::info;
+use std::sync::Arc;
+
+use crate::backend::domain::commands::preferences::{
+    DisplayPreferencesResult, SetTutorialCompletedCommand, TutorialStatusResult, UpdateDisplayPreferencesCommand,
+};
+use crate::backend::storage::csv::{CsvConnection, GlobalConfigRepository};
+use crate::backend::storage::GlobalConfigStorage;
+
+/// Service for reading and changing app-wide preferences
+#[derive(Clone)]
+pub struct PreferencesService {
+    global_config_repository: GlobalConfigRepository,
+}
+
+impl PreferencesService {
+    /// Create a new PreferencesService
+    pub fn new(csv_conn: Arc<CsvConnection>) -> Self {
+        let global_config_repository = GlobalConfigRepository::new((*csv_conn).clone());
+        Self { global_config_repository }
+    }
+
+    /// Get the current display preferences
+    pub fn get_display_preferences(&self) -> Result<DisplayPreferencesResult> {
+        let config = self.global_config_repository.get_global_config()?;
+        Ok(DisplayPreferencesResult {
+            text_size: config.text_size,
+            scale_factor: config.text_size.scale_factor(),
+            show_week_summaries: config.show_week_summaries,
+            show_goal_strip: config.show_goal_strip,
+            theme: config.theme,
+            reduce_motion: config.reduce_motion,
+        })
+    }
+
+    /// Change the display preferences
+    pub fn update_display_preferences(&self, command: UpdateDisplayPreferencesCommand) -> Result<DisplayPreferencesResult> {
+        let mut config = self.global_config_repository.get_global_config()?;
+        config.text_size = command.text_size;
+        config.show_week_summaries = command.show_week_summaries;
+        config.show_goal_strip = command.show_goal_strip;
+        config.theme = command.theme;
+        config.reduce_motion = command.reduce_motion;
+        config.updated_at = Utc::now().to_rfc3339();
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!(
+            "Updated display preferences: text_size={:?}, show_week_summaries={}, show_goal_strip={}, theme={:?}, reduce_motion={}",
+            command.text_size, command.show_week_summaries, command.show_goal_strip, command.theme, command.reduce_motion
+        );
+
+        Ok(DisplayPreferencesResult {
+            text_size: command.text_size,
+            scale_factor: command.text_size.scale_factor(),
+            show_week_summaries: command.show_week_summaries,
+            show_goal_strip: command.show_goal_strip,
+            theme: command.theme,
+            reduce_motion: command.reduce_motion,
+        })
+    }
+
+    /// Whether the guided tour has been finished or skipped
+    pub fn get_tutorial_status(&self) -> Result<TutorialStatusResult> {
+        let config = self.global_config_repository.get_global_config()?;
+        Ok(TutorialStatusResult { completed: config.tutorial_completed })
+    }
+
+    /// Mark the guided tour finished, or clear the mark so it is shown again
+    pub fn set_tutorial_completed(&self, command: SetTutorialCompletedCommand) -> Result<TutorialStatusResult> {
+        let mut config = self.global_config_repository.get_global_config()?;
+        config.tutorial_completed = command.completed;
+        config.updated_at = Utc::now().to_rfc3339();
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!("Updated tutorial status: completed={}", command.completed);
+        Ok(TutorialStatusResult { completed: command.completed })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::models::display_preferences::{ColorTheme, TextSize};
+    use crate::backend::storage::csv::test_utils::TestHelper;
+
+    #[test]
+    fn test_text_size_is_persisted() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let service = PreferencesService::new(Arc::new(helper.env.connection.clone()));
+
+        assert_eq!(service.get_display_preferences()?.text_size, TextSize::Normal);
+        assert!(!service.get_display_preferences()?.show_week_summaries);
+        assert_eq!(service.get_display_preferences()?.theme, ColorTheme::Light);
+        assert!(!service.get_display_preferences()?.reduce_motion);
+
+        let result = service.update_display_preferences(UpdateDisplayPreferencesCommand {
+            text_size: TextSize::Large,
+            show_week_summaries: true,
+            show_goal_strip: true,
+            theme: ColorTheme::Dark,
+            reduce_motion: true,
+        })?;
+        assert_eq!(result.scale_factor, 1.25);
+
+        // A fresh service over the same data directory sees the saved size
+        let reopened = PreferencesService::new(Arc::new(CsvConnection::new(&helper.env.base_path)?));
+        assert_eq!(reopened.get_display_preferences()?.text_size, TextSize::Large);
+        assert!(reopened.get_display_preferences()?.show_week_summaries);
+        assert!(reopened.get_display_preferences()?.show_goal_strip);
+        assert_eq!(reopened.get_display_preferences()?.theme, ColorTheme::Dark);
+        assert!(reopened.get_display_preferences()?.reduce_motion);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tutorial_status_is_persisted() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let service = PreferencesService::new(Arc::new(helper.env.connection.clone()));
+        assert!(!service.get_tutorial_status()?.completed);
+
+        service.set_tutorial_completed(SetTutorialCompletedCommand { completed: true })?;
+        let reopened = PreferencesService::new(Arc::new(CsvConnection::new(&helper.env.base_path)?));
+        assert!(reopened.get_tutorial_status()?.completed);
+
+        // Replaying clears the mark until the tour is finished again
+        reopened.set_tutorial_completed(SetTutorialCompletedCommand { completed: false })?;
+        assert!(!service.get_tutorial_status()?.completed);
+        Ok(())
+    }
+}
@@ -0,0 +1,302 @@
+//! Quick entry parsing for the allowance tracker.
+//!
+//! Turns a single typed line such as "spent 4.50 ice cream yesterday" or
+//! "+10 birthday from grandma" into a transaction command, so a transaction
+//! can be added from the keyboard without opening the add/spend forms.
+//!
+//! The grammar is deliberately small:
+//!
+//! ```text
+//! [direction word] amount description [date]
+//! ```
+//!
+//! - The direction is a word ("spent", "got", ...) or a sign on the amount.
+//! - The amount may include a `$` and simple arithmetic ("3x1.25").
+//! - The date, if given, comes last: "today", "yesterday", "3 days ago",
+//!   a weekday ("monday", "last monday", "on monday"), "2025-06-13" or "6/13".
+//!
+//! Both the quick entry bar in the header and the tray icon's "Add money…" /
+//! "Spend money…" window parse their line here; the tray puts "got" or
+//! "spent" in front of what was typed, so its lines always carry a direction.
+//! A direction the typed line already starts with is taken off first (see
+//! [`split_leading_direction`]), so "spent 4.50" isn't read as "spent spent 4.50".
+
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, TimeZone, Weekday};
+use thiserror::Error;
+
+use crate::backend::domain::amount_expression;
+use crate::backend::domain::commands::transactions::CreateTransactionCommand;
+
+/// Longest description accepted, matching the transaction service limit
+const MAX_DESCRIPTION_LENGTH: usize = 256;
+
+/// Words that mark money going out
+const EXPENSE_WORDS: &[&str] = &["spent", "spend", "paid", "pay", "bought", "buy", "lost", "minus"];
+
+/// Words that mark money coming in
+const INCOME_WORDS: &[&str] = &["got", "get", "earned", "earn", "received", "receive", "add", "added", "plus"];
+
+/// Why a quick entry line could not be understood
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum QuickEntryError {
+    #[error("Type something like \"spent 4.50 ice cream\" or \"+10 birthday money\"")]
+    Empty,
+    #[error("Couldn't find an amount. Put it right after \"spent\"/\"got\", like \"spent 4.50 ice cream\"")]
+    MissingAmount,
+    #[error("'{0}' isn't a valid amount")]
+    InvalidAmount(String),
+    #[error("Amount must be greater than 0")]
+    AmountNotPositive,
+    #[error("Was {0} spent or received? Start with \"spent\" or \"got\", or write -{0} or +{0}")]
+    MissingDirection(String),
+    #[error("\"{word}\" and the sign on {amount} disagree about whether money was spent or received")]
+    ConflictingDirection { word: String, amount: String },
+    #[error("Add a description, like \"spent 4.50 ice cream\"")]
+    MissingDescription,
+    #[error("Description is too long ({0} characters). Maximum is 256.")]
+    DescriptionTooLong(usize),
+    #[error("{0} is in the future")]
+    FutureDate(NaiveDate),
+}
+
+/// Parse a quick entry line into a transaction command.
+///
+/// `now` is the current time in the user's offset; relative dates are
+/// resolved against it, and backdated entries keep its time of day. The
+/// command's `date` is `None` when no date was typed.
+pub fn parse_quick_entry(input: &str, now: DateTime<FixedOffset>) -> Result<CreateTransactionCommand, QuickEntryError> {
+    let mut tokens: Vec<&str> = input.split_whitespace().collect();
+    if tokens.is_empty() {
+        return Err(QuickEntryError::Empty);
+    }
+
+    // Direction word
+    let word_direction = direction_of_word(tokens[0]);
+    let direction_word = word_direction.map(|_| tokens.remove(0).to_string());
+
+    // Amount, with an optional sign
+    let amount_token = tokens.first().copied().ok_or(QuickEntryError::MissingAmount)?;
+    let (sign_direction, unsigned) = match amount_token.chars().next() {
+        Some('+') => (Some(1.0), &amount_token[1..]),
+        Some('-') => (Some(-1.0), &amount_token[1..]),
+        _ => (None, amount_token),
+    };
+    let unsigned = unsigned.trim_start_matches('$');
+    if !unsigned.starts_with(|c: char| c.is_ascii_digit() || c == '.' || c == '(') {
+        return Err(QuickEntryError::MissingAmount);
+    }
+    let magnitude = parse_amount(unsigned)?;
+    tokens.remove(0);
+
+    let direction = match (word_direction, sign_direction) {
+        (Some(word), Some(sign)) if word != sign => {
+            return Err(QuickEntryError::ConflictingDirection {
+                word: direction_word.unwrap_or_default(),
+                amount: amount_token.to_string(),
+            });
+        }
+        (Some(direction), _) | (None, Some(direction)) => direction,
+        (None, None) => return Err(QuickEntryError::MissingDirection(unsigned.to_string())),
+    };
+
+    // Date at the end of the line
+    let today = now.date_naive();
+    let date = match take_trailing_date(&mut tokens, today) {
+        Some(date) if date > today => return Err(QuickEntryError::FutureDate(date)),
+        Some(date) if date == today => None,
+        Some(date) => {
+            let naive = date.and_time(now.time());
+            Some(now.offset().from_local_datetime(&naive).single().unwrap_or(now))
+        }
+        None => None,
+    };
+
+    // "spent 4.50 on ice cream" reads naturally; the "on" isn't part of the description
+    if matches!(tokens.first().map(|t| t.to_lowercase()).as_deref(), Some("on") | Some("for")) && tokens.len() > 1 {
+        tokens.remove(0);
+    }
+    let description = tokens.join(" ");
+    if description.is_empty() {
+        return Err(QuickEntryError::MissingDescription);
+    }
+    if description.len() > MAX_DESCRIPTION_LENGTH {
+        return Err(QuickEntryError::DescriptionTooLong(description.len()));
+    }
+
+    Ok(CreateTransactionCommand {
+        child_id: None,
+        description,
+        amount: direction * magnitude,
+        date,
+        parent_override: false,
+        actor: None,
+        icon: None,
+        transaction_type: None,
+    })
+}
+
+/// The direction a line starts with, from a direction word or a sign on the
+/// amount (-1.0 for money going out, 1.0 for money coming in), and the rest
+/// of the line without it
+pub fn split_leading_direction(input: &str) -> (Option<f64>, &str) {
+    let input = input.trim();
+    match input.chars().next() {
+        Some('+') => return (Some(1.0), input[1..].trim_start()),
+        Some('-') => return (Some(-1.0), input[1..].trim_start()),
+        _ => {}
+    }
+    let first = input.split_whitespace().next().unwrap_or_default();
+    match direction_of_word(first) {
+        Some(direction) => (Some(direction), input[first.len()..].trim_start()),
+        None => (None, input),
+    }
+}
+
+fn direction_of_word(word: &str) -> Option<f64> {
+    let word = word.to_lowercase();
+    if EXPENSE_WORDS.contains(&word.as_str()) {
+        Some(-1.0)
+    } else if INCOME_WORDS.contains(&word.as_str()) {
+        Some(1.0)
+    } else {
+        None
+    }
+}
+
+fn parse_amount(text: &str) -> Result<f64, QuickEntryError> {
+    let value = if amount_expression::is_expression(text) {
+        amount_expression::evaluate(text).map_err(|_| QuickEntryError::InvalidAmount(text.to_string()))?
+    } else {
+        text.replace(',', "").parse::<f64>().map_err(|_| QuickEntryError::InvalidAmount(text.to_string()))?
+    };
+    if value <= 0.0 || !value.is_finite() {
+        return Err(QuickEntryError::AmountNotPositive);
+    }
+    Ok(value)
+}
+
+/// Remove a date phrase from the end of `tokens` and resolve it
+fn take_trailing_date(tokens: &mut Vec<&str>, today: NaiveDate) -> Option<NaiveDate> {
+    let lower: Vec<String> = tokens.iter().map(|t| t.to_lowercase()).collect();
+    let n = lower.len();
+
+    // "3 days ago"
+    if n >= 3 && lower[n - 1] == "ago" && matches!(lower[n - 2].as_str(), "day" | "days") {
+        if let Ok(days) = lower[n - 3].parse::<i64>() {
+            tokens.truncate(n - 3);
+            strip_trailing_preposition(tokens);
+            return Some(today - Duration::days(days));
+        }
+    }
+
+    let last = lower.last()?;
+    let date = match last.as_str() {
+        "today" => Some(today),
+        "yesterday" => Some(today - Duration::days(1)),
+        word => match parse_weekday(word) {
+            // The most recent such day before today
+            Some(weekday) => {
+                let back = (today.weekday().num_days_from_monday() + 7 - weekday.num_days_from_monday()) % 7;
+                Some(today - Duration::days(if back == 0 { 7 } else { back as i64 }))
+            }
+            None => parse_numeric_date(word, today),
+        },
+    }?;
+
+    tokens.pop();
+    if matches!(tokens.last().map(|t| t.to_lowercase()).as_deref(), Some("last")) && parse_weekday(last).is_some() {
+        tokens.pop();
+    }
+    strip_trailing_preposition(tokens);
+    Some(date)
+}
+
+fn strip_trailing_preposition(tokens: &mut Vec<&str>) {
+    if matches!(tokens.last().map(|t| t.to_lowercase()).as_deref(), Some("on")) {
+        tokens.pop();
+    }
+}
+
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    match word {
+        "monday" | "mon" => Some(Weekday::Mon),
+        "tuesday" | "tue" | "tues" => Some(Weekday::Tue),
+        "wednesday" | "wed" => Some(Weekday::Wed),
+        "thursday" | "thu" | "thurs" => Some(Weekday::Thu),
+        "friday" | "fri" => Some(Weekday::Fri),
+        "saturday" | "sat" => Some(Weekday::Sat),
+        "sunday" | "sun" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// "2025-06-13", "6/13/2025" or "6/13" (this year)
+fn parse_numeric_date(word: &str, today: NaiveDate) -> Option<NaiveDate> {
+    if let Ok(date) = NaiveDate::parse_from_str(word, "%Y-%m-%d") {
+        return Some(date);
+    }
+    let parts: Vec<&str> = word.split('/').collect();
+    let (month, day, year) = match parts.as_slice() {
+        [month, day] => (month.parse().ok()?, day.parse().ok()?, today.year()),
+        [month, day, year] => (month.parse().ok()?, day.parse().ok()?, year.parse().ok()?),
+        _ => return None,
+    };
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<FixedOffset> {
+        // Wednesday
+        DateTime::parse_from_rfc3339("2025-06-18T15:30:00-04:00").unwrap()
+    }
+
+    fn date_of(command: &CreateTransactionCommand) -> Option<String> {
+        command.date.map(|d| d.format("%Y-%m-%d").to_string())
+    }
+
+    #[test]
+    fn test_parses_spends_and_income() {
+        let command = parse_quick_entry("spent 4.50 ice cream yesterday", now()).unwrap();
+        assert_eq!(command.amount, -4.5);
+        assert_eq!(command.description, "ice cream");
+        assert_eq!(date_of(&command).as_deref(), Some("2025-06-17"));
+        assert_eq!(command.date.unwrap().format("%H:%M").to_string(), "15:30");
+
+        let command = parse_quick_entry("+10 birthday from grandma", now()).unwrap();
+        assert_eq!(command.amount, 10.0);
+        assert_eq!(command.description, "birthday from grandma");
+        assert_eq!(command.date, None);
+
+        let command = parse_quick_entry("Got $3x2 for washing the car on monday", now()).unwrap();
+        assert_eq!(command.amount, 6.0);
+        assert_eq!(command.description, "washing the car");
+        assert_eq!(date_of(&command).as_deref(), Some("2025-06-16"));
+
+        let command = parse_quick_entry("-2 stickers 3 days ago", now()).unwrap();
+        assert_eq!(command.amount, -2.0);
+        assert_eq!(date_of(&command).as_deref(), Some("2025-06-15"));
+
+        let command = parse_quick_entry("paid 5 on book 6/1", now()).unwrap();
+        assert_eq!(command.description, "book");
+        assert_eq!(date_of(&command).as_deref(), Some("2025-06-01"));
+
+        // A weekday always means the previous one, never today
+        let command = parse_quick_entry("spent 1 gum last wednesday", now()).unwrap();
+        assert_eq!(date_of(&command).as_deref(), Some("2025-06-11"));
+    }
+
+    #[test]
+    fn test_reports_clear_errors() {
+        assert_eq!(parse_quick_entry("   ", now()).unwrap_err(), QuickEntryError::Empty);
+        assert_eq!(parse_quick_entry("spent ice cream", now()).unwrap_err(), QuickEntryError::MissingAmount);
+        assert_eq!(parse_quick_entry("4.50 ice cream", now()).unwrap_err(), QuickEntryError::MissingDirection("4.50".to_string()));
+        assert!(matches!(parse_quick_entry("spent +4 candy", now()).unwrap_err(), QuickEntryError::ConflictingDirection { .. }));
+        assert_eq!(parse_quick_entry("spent 0 candy", now()).unwrap_err(), QuickEntryError::AmountNotPositive);
+        assert_eq!(parse_quick_entry("spent 4.5.1 candy", now()).unwrap_err(), QuickEntryError::InvalidAmount("4.5.1".to_string()));
+        assert_eq!(parse_quick_entry("spent 4 yesterday", now()).unwrap_err(), QuickEntryError::MissingDescription);
+        assert!(matches!(parse_quick_entry("got 4 gift 2025-07-01", now()).unwrap_err(), QuickEntryError::FutureDate(_)));
+    }
+}
@@ -0,0 +1,384 @@
+//! Quick pick service domain logic for the allowance tracker.
+//!
+//! Each child can have their own list of quick-pick descriptions for the add
+//! and spend money forms. Until a child has any of a kind, the built-in
+//! suggestions from `MoneyManagementService` are offered instead, so the
+//! forms never start empty. Descriptions the child has used before follow the
+//! quick picks, ranked by how often and how recently they were used and
+//! carrying the amount they usually were. Suggestions match what has been
+//! typed so far by case-insensitive prefix.
+
+use anyhow::Result;
+use chrono::{DateTime, FixedOffset, Local};
+use log::info;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::commands::quick_picks::{
+    AddQuickPickCommand, DescriptionSuggestion, DescriptionSuggestionsQuery, DescriptionSuggestionsResult,
+    ListQuickPicksQuery, ListQuickPicksResult, RemoveQuickPickCommand,
+};
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::models::child::Child as DomainChild;
+use crate::backend::domain::models::quick_pick::{QuickPick, QuickPickKind};
+use crate::backend::domain::models::transaction::{Transaction as DomainTransaction, TransactionType};
+use crate::backend::domain::MoneyManagementService;
+use crate::backend::storage::csv::{CsvConnection, QuickPickRepository, TransactionRepository};
+use crate::backend::storage::traits::{QuickPickStorage, TransactionStorage};
+
+/// Most quick picks a child can have of each kind
+pub const MAX_QUICK_PICKS: usize = 20;
+
+/// Longest description a quick pick can hold
+pub const MAX_QUICK_PICK_LENGTH: usize = 60;
+
+/// A past use counts half as much for every this many days since it happened
+const HISTORY_HALF_LIFE_DAYS: f64 = 30.0;
+
+/// Service for a child's saved descriptions and the suggestions built from them
+#[derive(Clone)]
+pub struct QuickPickService {
+    quick_pick_repository: QuickPickRepository,
+    transaction_repository: TransactionRepository,
+    child_service: ChildService,
+}
+
+impl QuickPickService {
+    /// Create a new QuickPickService
+    pub fn new(csv_conn: Arc<CsvConnection>, child_service: ChildService) -> Self {
+        let quick_pick_repository = QuickPickRepository::new((*csv_conn).clone());
+        let transaction_repository = TransactionRepository::new((*csv_conn).clone());
+        Self {
+            quick_pick_repository,
+            transaction_repository,
+            child_service,
+        }
+    }
+
+    /// Save a quick pick for the active child
+    pub fn add_quick_pick(&self, command: AddQuickPickCommand) -> Result<QuickPick> {
+        let description = command.description.trim().to_string();
+        if description.is_empty() {
+            return Err(DomainError::invalid("Quick pick description cannot be empty").into());
+        }
+        if description.chars().count() > MAX_QUICK_PICK_LENGTH {
+            return Err(DomainError::invalid(format!(
+                "Quick picks can be at most {} characters",
+                MAX_QUICK_PICK_LENGTH
+            ))
+            .into());
+        }
+        if let Some(amount) = command.amount {
+            if !amount.is_finite() || amount <= 0.0 {
+                return Err(DomainError::invalid("Quick pick amounts must be greater than 0").into());
+            }
+        }
+
+        let child = self.active_child()?;
+        let all = self.quick_pick_repository.list_quick_picks(&child.id)?;
+        let existing: Vec<&QuickPick> = all.iter().filter(|q| q.kind == command.kind).collect();
+        if existing.iter().any(|q| q.description.eq_ignore_ascii_case(&description)) {
+            return Err(DomainError::Conflict(format!("\"{}\" is already a quick pick", description)).into());
+        }
+        if existing.len() >= MAX_QUICK_PICKS {
+            return Err(DomainError::invalid(format!(
+                "{} already has {} quick picks; remove one first",
+                child.name, MAX_QUICK_PICKS
+            ))
+            .into());
+        }
+
+        // Picks added in quick succession would otherwise share a timestamp
+        let mut now_millis = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+        while all.iter().any(|q| q.id == QuickPick::generate_id(&child.id, now_millis)) {
+            now_millis += 1;
+        }
+        let quick_pick = QuickPick {
+            id: QuickPick::generate_id(&child.id, now_millis),
+            child_id: child.id.clone(),
+            description,
+            amount: command.amount,
+            kind: command.kind,
+        };
+        self.quick_pick_repository.store_quick_pick(&quick_pick)?;
+
+        info!("⚡ QUICK PICK: {} added \"{}\" ({:?})", child.id, quick_pick.description, quick_pick.kind);
+        Ok(quick_pick)
+    }
+
+    /// Remove one of the active child's quick picks
+    pub fn remove_quick_pick(&self, command: RemoveQuickPickCommand) -> Result<()> {
+        let child = self.active_child()?;
+        if !self.quick_pick_repository.delete_quick_pick(&child.id, &command.quick_pick_id)? {
+            return Err(DomainError::NotFound(format!("Quick pick not found: {}", command.quick_pick_id)).into());
+        }
+        info!("⚡ QUICK PICK: {} removed {}", child.id, command.quick_pick_id);
+        Ok(())
+    }
+
+    /// A child's saved quick picks
+    pub fn list_quick_picks(&self, query: ListQuickPicksQuery) -> Result<ListQuickPicksResult> {
+        let child_id = match query.child_id {
+            Some(id) => id,
+            None => self.active_child()?.id,
+        };
+        let quick_picks = self.quick_pick_repository.list_quick_picks(&child_id)?;
+        Ok(ListQuickPicksResult { child_id, quick_picks })
+    }
+
+    /// Descriptions to offer in a money form, matching what has been typed
+    ///
+    /// Quick picks come first, then descriptions from the child's history.
+    /// The built-in suggestions fill in only while the child has no quick
+    /// picks of that kind.
+    pub fn get_description_suggestions(&self, query: DescriptionSuggestionsQuery) -> Result<DescriptionSuggestionsResult> {
+        let child_id = match query.child_id {
+            Some(id) => id,
+            None => self.active_child()?.id,
+        };
+
+        let mut candidates: Vec<DescriptionSuggestion> = self
+            .quick_pick_repository
+            .list_quick_picks(&child_id)?
+            .into_iter()
+            .filter(|q| q.kind == query.kind)
+            .map(|q| DescriptionSuggestion { description: q.description, amount: q.amount })
+            .collect();
+        let has_quick_picks = !candidates.is_empty();
+        candidates.extend(self.history_suggestions(&child_id, query.kind)?);
+        if !has_quick_picks {
+            let money_service = MoneyManagementService::new();
+            let built_in = match query.kind {
+                QuickPickKind::Income => money_service.get_description_suggestions(),
+                QuickPickKind::Expense => money_service.get_spending_suggestions(),
+            };
+            candidates.extend(
+                built_in
+                    .into_iter()
+                    .map(|description| DescriptionSuggestion { description, amount: None }),
+            );
+        }
+
+        // A description saved as a quick pick and also used before is offered once
+        let mut seen = Vec::new();
+        candidates.retain(|s| {
+            let key = s.description.to_lowercase();
+            if seen.contains(&key) {
+                return false;
+            }
+            seen.push(key);
+            true
+        });
+
+        Ok(DescriptionSuggestionsResult { suggestions: matching_prefix(candidates, &query.prefix, query.limit) })
+    }
+
+    /// Descriptions from the child's past transactions only, best first
+    pub fn get_history_suggestions(&self, query: DescriptionSuggestionsQuery) -> Result<DescriptionSuggestionsResult> {
+        let child_id = match query.child_id {
+            Some(id) => id,
+            None => self.active_child()?.id,
+        };
+        let candidates = self.history_suggestions(&child_id, query.kind)?;
+        Ok(DescriptionSuggestionsResult { suggestions: matching_prefix(candidates, &query.prefix, query.limit) })
+    }
+
+    fn history_suggestions(&self, child_id: &str, kind: QuickPickKind) -> Result<Vec<DescriptionSuggestion>> {
+        let transactions = self.transaction_repository.list_transactions(child_id, None, None)?;
+        Ok(rank_history(&transactions, kind, Local::now().fixed_offset()))
+    }
+
+    fn active_child(&self) -> Result<DomainChild> {
+        self.child_service
+            .get_active_child()?
+            .active_child
+            .child
+            .ok_or(DomainError::NoActiveChild)
+            .map_err(Into::into)
+    }
+}
+
+/// Keep suggestions that complete what has been typed, best first
+fn matching_prefix(candidates: Vec<DescriptionSuggestion>, prefix: &str, limit: usize) -> Vec<DescriptionSuggestion> {
+    let prefix = prefix.trim().to_lowercase();
+    candidates
+        .into_iter()
+        .filter(|s| {
+            let description = s.description.to_lowercase();
+            // Nothing left to complete once the whole description is typed
+            description.starts_with(&prefix) && description != prefix
+        })
+        .take(limit)
+        .collect()
+}
+
+/// Rank the descriptions used in past transactions of a kind
+///
+/// Each use scores 1, halving every `HISTORY_HALF_LIFE_DAYS`, so something
+/// bought every week outranks something bought once last year. Descriptions
+/// are grouped case-insensitively and shown as most recently written. The
+/// typical amount is the one used most often, the latest winning ties.
+fn rank_history(transactions: &[DomainTransaction], kind: QuickPickKind, now: DateTime<FixedOffset>) -> Vec<DescriptionSuggestion> {
+    struct Usage {
+        description: String,
+        latest: DateTime<FixedOffset>,
+        score: f64,
+        // Amount in cents -> (times used, latest use)
+        amounts: HashMap<i64, (u32, DateTime<FixedOffset>)>,
+    }
+
+    let mut usages: HashMap<String, Usage> = HashMap::new();
+    for transaction in transactions {
+        let matches_kind = transaction.transaction_type != TransactionType::FutureAllowance
+            && match kind {
+                QuickPickKind::Income => transaction.amount >= 0.0,
+                QuickPickKind::Expense => transaction.amount < 0.0,
+            };
+        let description = transaction.description.trim();
+        if !matches_kind || description.is_empty() {
+            continue;
+        }
+
+        let age_days = (now - transaction.date).num_seconds().max(0) as f64 / 86_400.0;
+        let weight = 0.5_f64.powf(age_days / HISTORY_HALF_LIFE_DAYS);
+        let usage = usages.entry(description.to_lowercase()).or_insert_with(|| Usage {
+            description: description.to_string(),
+            latest: transaction.date,
+            score: 0.0,
+            amounts: HashMap::new(),
+        });
+        usage.score += weight;
+        if transaction.date >= usage.latest {
+            usage.latest = transaction.date;
+            usage.description = description.to_string();
+        }
+        let cents = (transaction.amount.abs() * 100.0).round() as i64;
+        let entry = usage.amounts.entry(cents).or_insert((0, transaction.date));
+        entry.0 += 1;
+        entry.1 = entry.1.max(transaction.date);
+    }
+
+    let mut ranked: Vec<Usage> = usages.into_values().collect();
+    ranked.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(b.latest.cmp(&a.latest))
+    });
+    ranked
+        .into_iter()
+        .map(|usage| {
+            let typical = usage
+                .amounts
+                .iter()
+                .max_by(|a, b| a.1 .0.cmp(&b.1 .0).then(a.1 .1.cmp(&b.1 .1)))
+                .map(|(cents, _)| *cents as f64 / 100.0)
+                .filter(|amount| *amount > 0.0);
+            DescriptionSuggestion { description: usage.description, amount: typical }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_quick_picks_replace_built_in_suggestions() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let child_service = ChildService::new(connection.clone());
+        let service = QuickPickService::new(connection, child_service.clone());
+
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })?
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() })?;
+        let suggest = |kind: QuickPickKind, prefix: &str| -> Result<Vec<String>> {
+            let result = service.get_description_suggestions(DescriptionSuggestionsQuery {
+                child_id: None,
+                kind,
+                prefix: prefix.to_string(),
+                limit: 5,
+            })?;
+            Ok(result.suggestions.into_iter().map(|s| s.description).collect())
+        };
+
+        assert_eq!(suggest(QuickPickKind::Income, "gift")?, vec!["Gift from grandma", "Gift from aunt", "Gift from uncle"]);
+
+        let add = |description: &str, amount: Option<f64>, kind: QuickPickKind| {
+            service.add_quick_pick(AddQuickPickCommand { description: description.to_string(), amount, kind })
+        };
+        let lunch = add(" Lunch money ", Some(5.0), QuickPickKind::Income)?;
+        add("Weekly allowance", None, QuickPickKind::Income)?;
+        add("Book fair", None, QuickPickKind::Expense)?;
+        assert_eq!(lunch.description, "Lunch money");
+
+        let duplicate = add("lunch MONEY", None, QuickPickKind::Income).unwrap_err();
+        assert!(matches!(duplicate.downcast_ref::<DomainError>(), Some(DomainError::Conflict(_))));
+        assert!(add("   ", None, QuickPickKind::Income).is_err());
+        assert!(add("Chores", Some(-1.0), QuickPickKind::Income).is_err());
+
+        assert_eq!(suggest(QuickPickKind::Income, "")?, vec!["Lunch money", "Weekly allowance"]);
+        assert_eq!(suggest(QuickPickKind::Income, "lu")?, vec!["Lunch money"]);
+        assert!(suggest(QuickPickKind::Income, "Lunch money")?.is_empty());
+        assert_eq!(suggest(QuickPickKind::Expense, "")?, vec!["Book fair"]);
+
+        service.remove_quick_pick(RemoveQuickPickCommand { quick_pick_id: lunch.id.clone() })?;
+        let missing = service.remove_quick_pick(RemoveQuickPickCommand { quick_pick_id: lunch.id }).unwrap_err();
+        assert!(matches!(missing.downcast_ref::<DomainError>(), Some(DomainError::NotFound(_))));
+        assert_eq!(service.list_quick_picks(ListQuickPicksQuery { child_id: None })?.quick_picks.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_history_suggestions_rank_by_frequency_and_recency() {
+        let now = DateTime::parse_from_rfc3339("2025-06-30T12:00:00-04:00").unwrap();
+        let transaction = |date: &str, description: &str, amount: f64| DomainTransaction {
+            id: format!("t-{}-{}", date, description),
+            child_id: "child".to_string(),
+            date: DateTime::parse_from_rfc3339(&format!("{}T10:00:00-04:00", date)).unwrap(),
+            description: description.to_string(),
+            amount,
+            balance: 0.0,
+            transaction_type: if amount >= 0.0 { TransactionType::Income } else { TransactionType::Expense },
+            actor: None,
+            category: None,
+            icon: None,
+        };
+        let transactions = vec![
+            // Bought often, though not lately
+            transaction("2025-05-01", "ice cream treat", -2.50),
+            transaction("2025-05-08", "Ice cream treat", -3.00),
+            transaction("2025-05-15", "Ice cream treat", -3.00),
+            transaction("2025-05-22", "Ice Cream Treat", -3.00),
+            // Once, long ago
+            transaction("2024-06-01", "Ice skates", -40.00),
+            // Once, yesterday
+            transaction("2025-06-29", "Comic book", -4.00),
+            transaction("2025-06-28", "Birthday money", 20.00),
+        ];
+
+        let expenses = rank_history(&transactions, QuickPickKind::Expense, now);
+        assert_eq!(
+            expenses,
+            vec![
+                DescriptionSuggestion { description: "Ice Cream Treat".to_string(), amount: Some(3.0) },
+                DescriptionSuggestion { description: "Comic book".to_string(), amount: Some(4.0) },
+                DescriptionSuggestion { description: "Ice skates".to_string(), amount: Some(40.0) },
+            ]
+        );
+        assert_eq!(
+            matching_prefix(expenses, "ice", 1),
+            vec![DescriptionSuggestion { description: "Ice Cream Treat".to_string(), amount: Some(3.0) }]
+        );
+
+        let income = rank_history(&transactions, QuickPickKind::Income, now);
+        assert_eq!(income.len(), 1);
+        assert_eq!(income[0].description, "Birthday money");
+    }
+}
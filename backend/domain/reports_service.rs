@@ -0,0 +1,1053 @@
+//! Reports service domain logic for the allowance tracker.
+//!
+//! This module contains read-only summaries built on top of stored data,
+//! such as how much has been automatically saved or rounded up each month,
+//! how money moved day by day across a whole year, the balance over a date
+//! range bucketed for a chart (with markers for large transactions and goal
+//! dates), money earned and spent month by month, where the money went by
+//! category or kind, or a single month's statement.
+//!
+//! A reversed transaction and the entry that reversed it cancel out, so the
+//! year summary and monthly statement can leave both out when asked.
+
+use anyhow::Result;
+use chrono::{Datelike, Duration, NaiveDate};
+use log::info;
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+
+/// Most points a balance series may have; longer ranges need a coarser resolution
+const MAX_CHART_POINTS: usize = 1000;
+
+/// Most months a cash flow query may cover
+const MAX_CASH_FLOW_MONTHS: u32 = 120;
+
+/// Most large transactions marked on one chart; only the biggest are kept
+const MAX_LARGE_TRANSACTION_MARKERS: usize = 10;
+
+/// Smallest amount, either way, a transaction needs to be marked on the chart
+const LARGE_TRANSACTION_MIN_AMOUNT: f64 = 10.0;
+
+/// Slice label for spending without a category
+pub const UNCATEGORIZED_LABEL: &str = "Uncategorized";
+
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::commands::reports::{
+    AutoSavedSummaryQuery, AutoSavedSummaryResult, BalancePoint, BalanceSeriesQuery, BalanceSeriesResult, BreakdownGrouping,
+    ChartMarker, ChartMarkerKind, ChartResolution, DailyNet, MonthlyAutoSaved, MonthlyReportLine, MonthlyReportQuery, MonthlyReportResult,
+    MonthlyCashFlow, MonthlyCashFlowQuery, MonthlyCashFlowResult, MonthlyTotals, RoundUpSummaryQuery, RoundUpSummaryResult, SpendingBreakdownQuery, SpendingBreakdownResult, SpendingSlice,
+    YearSummaryQuery, YearSummaryResult,
+};
+use crate::backend::domain::models::goal::DomainGoalState;
+use crate::backend::domain::models::savings_allocation::{SavingsAllocation, SavingsSource};
+use crate::backend::domain::models::transaction::{Transaction, TransactionType};
+use crate::backend::domain::errors::DomainError;
+use crate::backend::storage::csv::{CsvConnection, GoalRepository, ReversalRepository, SavingsAllocationRepository, TransactionRepository};
+use crate::backend::storage::traits::{SavingsAllocationStorage, TransactionReversalStorage, TransactionStorage};
+
+/// Service for building summary reports
+#[derive(Clone)]
+pub struct ReportsService {
+    savings_allocation_repository: SavingsAllocationRepository,
+    transaction_repository: TransactionRepository,
+    reversal_repository: ReversalRepository,
+    goal_repository: GoalRepository,
+    child_service: ChildService,
+}
+
+impl ReportsService {
+    /// Create a new ReportsService
+    pub fn new(csv_conn: Arc<CsvConnection>, child_service: ChildService) -> Self {
+        let savings_allocation_repository = SavingsAllocationRepository::new((*csv_conn).clone());
+        let transaction_repository = TransactionRepository::new((*csv_conn).clone());
+        let reversal_repository = ReversalRepository::new((*csv_conn).clone());
+        let goal_repository = GoalRepository::new((*csv_conn).clone());
+        Self {
+            savings_allocation_repository,
+            transaction_repository,
+            reversal_repository,
+            goal_repository,
+            child_service,
+        }
+    }
+
+    /// Summarise automatically saved amounts per calendar month
+    pub fn get_auto_saved_summary(&self, query: AutoSavedSummaryQuery) -> Result<AutoSavedSummaryResult> {
+        let child_id = self.resolve_child_id(query.child_id)?;
+
+        let allocations = self.savings_allocation_repository.list_allocations(&child_id)?;
+        let months = monthly_totals(&allocations, SavingsSource::AutoSave);
+        let total_auto_saved = (months.iter().map(|m| m.total).sum::<f64>() * 100.0).round() / 100.0;
+
+        info!("📊 REPORTS: {} auto-saved ${:.2} across {} months", child_id, total_auto_saved, months.len());
+
+        Ok(AutoSavedSummaryResult {
+            child_id,
+            months,
+            total_auto_saved,
+        })
+    }
+
+    /// Summarise round-up savings per calendar month
+    pub fn get_round_up_summary(&self, query: RoundUpSummaryQuery) -> Result<RoundUpSummaryResult> {
+        let child_id = self.resolve_child_id(query.child_id)?;
+
+        let allocations = self.savings_allocation_repository.list_allocations(&child_id)?;
+        let months = monthly_totals(&allocations, SavingsSource::RoundUp);
+        let total_rounded_up = round_cents(months.iter().map(|m| m.total).sum());
+
+        info!("📊 REPORTS: {} rounded up ${:.2} across {} months", child_id, total_rounded_up, months.len());
+
+        Ok(RoundUpSummaryResult {
+            child_id,
+            months,
+            total_rounded_up,
+        })
+    }
+
+    /// Summarise a whole calendar year day by day, for a heatmap view
+    ///
+    /// Days are grouped by the local date each transaction was recorded on.
+    /// Future allowances aren't real money yet, so they are left out.
+    pub fn get_year_summary(&self, query: YearSummaryQuery) -> Result<YearSummaryResult> {
+        let child_id = self.resolve_child_id(query.child_id)?;
+        let year = query.year;
+        let first_day = NaiveDate::from_ymd_opt(year, 1, 1)
+            .ok_or_else(|| DomainError::invalid(format!("Invalid year: {}", year)))?;
+
+        let mut days: Vec<DailyNet> = first_day
+            .iter_days()
+            .take_while(|date| date.year() == year)
+            .map(|date| DailyNet { date, income: 0.0, expenses: 0.0, net: 0.0, transaction_count: 0 })
+            .collect();
+        let mut months: Vec<MonthlyTotals> = (1..=12)
+            .map(|month| MonthlyTotals { month, income: 0.0, expenses: 0.0, net: 0.0, transaction_count: 0 })
+            .collect();
+
+        let excluded = self.excluded_reversals(&child_id, query.exclude_reversals)?;
+        let transactions = self.transaction_repository.list_transactions_chronological(&child_id, None, None)?;
+        for transaction in &transactions {
+            if transaction.transaction_type == TransactionType::FutureAllowance || excluded.contains(&transaction.id) {
+                continue;
+            }
+            let date = transaction.date.date_naive();
+            if date.year() != year {
+                continue;
+            }
+
+            let day = &mut days[date.ordinal0() as usize];
+            let month = &mut months[date.month0() as usize];
+            if transaction.amount >= 0.0 {
+                day.income += transaction.amount;
+                month.income += transaction.amount;
+            } else {
+                day.expenses += -transaction.amount;
+                month.expenses += -transaction.amount;
+            }
+            day.transaction_count += 1;
+            month.transaction_count += 1;
+        }
+
+        for day in &mut days {
+            day.income = round_cents(day.income);
+            day.expenses = round_cents(day.expenses);
+            day.net = round_cents(day.income - day.expenses);
+        }
+        for month in &mut months {
+            month.income = round_cents(month.income);
+            month.expenses = round_cents(month.expenses);
+            month.net = round_cents(month.income - month.expenses);
+        }
+
+        let max_abs_daily_net = days.iter().map(|d| d.net.abs()).fold(0.0, f64::max);
+        let total_net = round_cents(months.iter().map(|m| m.net).sum());
+
+        info!("📊 REPORTS: {} net ${:.2} across {}", child_id, total_net, year);
+
+        Ok(YearSummaryResult {
+            child_id,
+            year,
+            days,
+            months,
+            max_abs_daily_net,
+            total_net,
+        })
+    }
+
+    /// A child's balance at the end of each day, week or month in a date range
+    ///
+    /// Balances carry over from before the range, so a quiet first week still
+    /// shows what the child had. Future allowances aren't real money yet, so
+    /// they are left out.
+    ///
+    /// The biggest transactions in the range and the days goals were set or
+    /// reached come back as markers to draw on top of the line.
+    pub fn get_balance_series(&self, query: BalanceSeriesQuery) -> Result<BalanceSeriesResult> {
+        if query.start_date > query.end_date {
+            return Err(DomainError::invalid("Chart start date must not be after its end date").into());
+        }
+        let bucket_ends = bucket_ends(query.start_date, query.end_date, query.resolution);
+        if bucket_ends.len() > MAX_CHART_POINTS {
+            return Err(DomainError::invalid(format!(
+                "{} points is too many for one chart; use a coarser resolution",
+                bucket_ends.len()
+            ))
+            .into());
+        }
+        let child_id = self.resolve_child_id(query.child_id)?;
+
+        let transactions = self.transaction_repository.list_transactions_chronological(&child_id, None, None)?;
+        let markers = self.chart_markers(&child_id, &transactions, query.start_date, query.end_date)?;
+        let mut transactions = transactions
+            .iter()
+            .filter(|t| t.transaction_type != TransactionType::FutureAllowance)
+            .peekable();
+        let mut balance = 0.0;
+        let points = bucket_ends
+            .into_iter()
+            .map(|date| {
+                while let Some(transaction) = transactions.next_if(|t| t.date.date_naive() <= date) {
+                    balance = transaction.balance;
+                }
+                BalancePoint { date, balance }
+            })
+            .collect::<Vec<_>>();
+
+        info!("📊 REPORTS: {} balance series of {} points ({} to {})", child_id, points.len(), query.start_date, query.end_date);
+
+        Ok(BalanceSeriesResult {
+            child_id,
+            resolution: query.resolution,
+            points,
+            markers,
+        })
+    }
+
+    /// The biggest transactions and the goal dates between `start` and `end`, oldest first
+    fn chart_markers(
+        &self,
+        child_id: &str,
+        transactions: &[Transaction],
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<ChartMarker>> {
+        let in_range = |date: NaiveDate| date >= start && date <= end;
+
+        let mut large: Vec<_> = transactions
+            .iter()
+            .filter(|t| t.transaction_type != TransactionType::FutureAllowance)
+            .filter(|t| t.amount.abs() >= LARGE_TRANSACTION_MIN_AMOUNT && in_range(t.date.date_naive()))
+            .collect();
+        large.sort_by(|a, b| b.amount.abs().total_cmp(&a.amount.abs()));
+        large.truncate(MAX_LARGE_TRANSACTION_MARKERS);
+        let mut markers: Vec<ChartMarker> = large
+            .into_iter()
+            .map(|t| ChartMarker {
+                date: t.date.date_naive(),
+                kind: ChartMarkerKind::LargeTransaction,
+                description: t.description.clone(),
+                amount: t.amount,
+            })
+            .collect();
+
+        let goal_date = |timestamp: &str| chrono::DateTime::parse_from_rfc3339(timestamp).ok().map(|date| date.date_naive());
+        for goal in self.goal_repository.list_goals(child_id, None)? {
+            if let Some(created) = goal_date(&goal.created_at).filter(|date| in_range(*date)) {
+                markers.push(ChartMarker {
+                    date: created,
+                    kind: ChartMarkerKind::GoalCreated,
+                    description: goal.description.clone(),
+                    amount: goal.target_amount,
+                });
+            }
+            if goal.state == DomainGoalState::Completed {
+                if let Some(completed) = goal_date(&goal.updated_at).filter(|date| in_range(*date)) {
+                    markers.push(ChartMarker {
+                        date: completed,
+                        kind: ChartMarkerKind::GoalCompleted,
+                        description: goal.description.clone(),
+                        amount: goal.target_amount,
+                    });
+                }
+            }
+        }
+
+        markers.sort_by_key(|marker| marker.date);
+        Ok(markers)
+    }
+
+    /// Money earned and spent in each of the `months` months up to the end month
+    ///
+    /// Money in counts as earned and money out as spent, whatever kind of
+    /// transaction it is. Future allowances aren't real money yet, so they
+    /// are left out.
+    pub fn get_monthly_cash_flow(&self, query: MonthlyCashFlowQuery) -> Result<MonthlyCashFlowResult> {
+        if query.months == 0 || query.months > MAX_CASH_FLOW_MONTHS {
+            return Err(DomainError::invalid(format!("Cash flow covers 1 to {} months", MAX_CASH_FLOW_MONTHS)).into());
+        }
+        let end_first_day = NaiveDate::from_ymd_opt(query.end_year, query.end_month, 1)
+            .ok_or_else(|| DomainError::invalid(format!("Invalid month: {}/{}", query.end_month, query.end_year)))?;
+        let child_id = self.resolve_child_id(query.child_id)?;
+
+        let first_day = end_first_day - chrono::Months::new(query.months - 1);
+        let mut months: Vec<MonthlyCashFlow> = (0..query.months)
+            .map(|offset| {
+                let date = first_day + chrono::Months::new(offset);
+                MonthlyCashFlow { year: date.year(), month: date.month(), earned: 0.0, spent: 0.0, net: 0.0 }
+            })
+            .collect();
+        let month_count = months.len();
+        let month_index = |date: NaiveDate| {
+            let index = (date.year() - first_day.year()) * 12 + date.month() as i32 - first_day.month() as i32;
+            usize::try_from(index).ok().filter(|index| *index < month_count)
+        };
+
+        let excluded = self.excluded_reversals(&child_id, query.exclude_reversals)?;
+        let transactions = self.transaction_repository.list_transactions_chronological(&child_id, None, None)?;
+        for transaction in &transactions {
+            if transaction.transaction_type == TransactionType::FutureAllowance || excluded.contains(&transaction.id) {
+                continue;
+            }
+            let Some(index) = month_index(transaction.date.date_naive()) else {
+                continue;
+            };
+            if transaction.amount >= 0.0 {
+                months[index].earned += transaction.amount;
+            } else {
+                months[index].spent -= transaction.amount;
+            }
+        }
+
+        for month in &mut months {
+            month.earned = round_cents(month.earned);
+            month.spent = round_cents(month.spent);
+            month.net = round_cents(month.earned - month.spent);
+        }
+
+        info!("📊 REPORTS: {} cash flow for {} months to {}/{}", child_id, months.len(), query.end_month, query.end_year);
+
+        Ok(MonthlyCashFlowResult { child_id, months })
+    }
+
+    /// Where a child's money went between two dates, by category or by kind
+    ///
+    /// Only money going out counts, except that money coming back in a
+    /// category, such as a refund, comes off what was spent there, the same
+    /// as for budget targets. Categories are matched ignoring case and keep
+    /// the spelling first seen. Future allowances aren't real money yet, so
+    /// they are left out.
+    pub fn get_spending_breakdown(&self, query: SpendingBreakdownQuery) -> Result<SpendingBreakdownResult> {
+        if query.start_date > query.end_date {
+            return Err(DomainError::invalid("Breakdown start date must not be after its end date").into());
+        }
+        let child_id = self.resolve_child_id(query.child_id)?;
+
+        let mut slices: Vec<SpendingSlice> = Vec::new();
+        let excluded = self.excluded_reversals(&child_id, query.exclude_reversals)?;
+        let transactions = self.transaction_repository.list_transactions_chronological(&child_id, None, None)?;
+        for transaction in &transactions {
+            if transaction.transaction_type == TransactionType::FutureAllowance || excluded.contains(&transaction.id) {
+                continue;
+            }
+            let date = transaction.date.date_naive();
+            if date < query.start_date || date > query.end_date {
+                continue;
+            }
+
+            let label = match query.group_by {
+                BreakdownGrouping::Category => {
+                    let category = transaction.category.as_deref().map(str::trim).filter(|category| !category.is_empty());
+                    if transaction.amount >= 0.0 && category.is_none() {
+                        continue;
+                    }
+                    category.unwrap_or(UNCATEGORIZED_LABEL)
+                }
+                BreakdownGrouping::TransactionType => {
+                    if transaction.amount >= 0.0 {
+                        continue;
+                    }
+                    transaction.transaction_type.label()
+                }
+            };
+            let index = match slices.iter().position(|slice| slice.label.eq_ignore_ascii_case(label)) {
+                Some(index) => index,
+                None => {
+                    slices.push(SpendingSlice { label: label.to_string(), amount: 0.0, share: 0.0, transaction_count: 0 });
+                    slices.len() - 1
+                }
+            };
+            slices[index].amount -= transaction.amount;
+            slices[index].transaction_count += 1;
+        }
+
+        // A category refunded in full has nothing left to show
+        slices.retain(|slice| slice.amount > 0.005);
+        let total_spent: f64 = slices.iter().map(|slice| slice.amount).sum();
+        for slice in &mut slices {
+            slice.share = slice.amount / total_spent;
+            slice.amount = round_cents(slice.amount);
+        }
+        slices.sort_by(|a, b| b.amount.total_cmp(&a.amount));
+
+        info!(
+            "📊 REPORTS: {} spent ${:.2} in {} {} between {} and {}",
+            child_id,
+            total_spent,
+            slices.len(),
+            if query.group_by == BreakdownGrouping::Category { "categories" } else { "kinds" },
+            query.start_date,
+            query.end_date
+        );
+
+        Ok(SpendingBreakdownResult {
+            child_id,
+            start_date: query.start_date,
+            end_date: query.end_date,
+            group_by: query.group_by,
+            slices,
+            total_spent: round_cents(total_spent),
+        })
+    }
+
+    /// One calendar month's statement: opening and closing balance, totals
+    /// and every transaction
+    ///
+    /// Future allowances aren't real money yet, so they are left out.
+    pub fn get_monthly_report(&self, query: MonthlyReportQuery) -> Result<MonthlyReportResult> {
+        let first_day = NaiveDate::from_ymd_opt(query.year, query.month, 1)
+            .ok_or_else(|| DomainError::invalid(format!("Invalid month: {}/{}", query.month, query.year)))?;
+        let child_id = self.resolve_child_id(query.child_id)?;
+
+        let mut opening_balance = 0.0;
+        let mut closing_balance = None;
+        let mut total_earned = 0.0;
+        let mut total_spent = 0.0;
+        let mut lines = Vec::new();
+
+        let excluded = self.excluded_reversals(&child_id, query.exclude_reversals)?;
+        let transactions = self.transaction_repository.list_transactions_chronological(&child_id, None, None)?;
+        for transaction in &transactions {
+            if transaction.transaction_type == TransactionType::FutureAllowance {
+                continue;
+            }
+            let date = transaction.date.date_naive();
+            if date < first_day {
+                opening_balance = transaction.balance;
+                continue;
+            }
+            if date.year() != query.year || date.month() != query.month {
+                break;
+            }
+
+            closing_balance = Some(transaction.balance);
+            if excluded.contains(&transaction.id) {
+                continue;
+            }
+
+            if transaction.amount >= 0.0 {
+                total_earned += transaction.amount;
+            } else {
+                total_spent -= transaction.amount;
+            }
+            lines.push(MonthlyReportLine {
+                date,
+                description: transaction.description.clone(),
+                category: transaction.category.clone(),
+                transaction_type: transaction.transaction_type.clone(),
+                amount: transaction.amount,
+                balance: transaction.balance,
+            });
+        }
+        let totals_by_type = TransactionType::RECORDED
+            .into_iter()
+            .filter_map(|kind| {
+                let amounts: Vec<f64> = lines.iter().filter(|line| line.transaction_type == kind).map(|line| line.amount).collect();
+                (!amounts.is_empty()).then(|| (kind, round_cents(amounts.iter().sum())))
+            })
+            .collect();
+
+        info!("📊 REPORTS: {} monthly report for {}/{} with {} transactions", child_id, query.month, query.year, lines.len());
+
+        Ok(MonthlyReportResult {
+            child_id,
+            year: query.year,
+            month: query.month,
+            opening_balance,
+            total_earned: round_cents(total_earned),
+            total_spent: round_cents(total_spent),
+            closing_balance: closing_balance.unwrap_or(opening_balance),
+            totals_by_type,
+            lines,
+        })
+    }
+
+    /// Ids of both sides of every reversal, or none when they are kept
+    fn excluded_reversals(&self, child_id: &str, exclude: bool) -> Result<HashSet<String>> {
+        if !exclude {
+            return Ok(HashSet::new());
+        }
+        Ok(self
+            .reversal_repository
+            .list_reversals(child_id)?
+            .into_iter()
+            .flat_map(|reversal| [reversal.original_id, reversal.reversal_id])
+            .collect())
+    }
+
+    /// Use the given child, or the active child when none is given
+    fn resolve_child_id(&self, child_id: Option<String>) -> Result<String> {
+        match child_id {
+            Some(id) => Ok(id),
+            None => match self.child_service.get_active_child()?.active_child.child {
+                Some(child) => Ok(child.id),
+                None => Err(DomainError::NoActiveChild.into()),
+            },
+        }
+    }
+}
+
+/// Group one kind of allocation by calendar month, oldest first
+fn monthly_totals(allocations: &[SavingsAllocation], source: SavingsSource) -> Vec<MonthlyAutoSaved> {
+    // BTreeMap keeps months in chronological order
+    let mut by_month: BTreeMap<(i32, u32), MonthlyAutoSaved> = BTreeMap::new();
+    for allocation in allocations.iter().filter(|a| a.source == source) {
+        let key = (allocation.date.year(), allocation.date.month());
+        let entry = by_month.entry(key).or_insert(MonthlyAutoSaved {
+            year: key.0,
+            month: key.1,
+            total: 0.0,
+            allocation_count: 0,
+        });
+        entry.total += allocation.amount;
+        entry.allocation_count += 1;
+    }
+
+    by_month
+        .into_values()
+        .map(|mut month| {
+            month.total = round_cents(month.total);
+            month
+        })
+        .collect()
+}
+
+/// Last day of each bucket in the range, clipped to `end`. Weeks count from
+/// `start`; months are calendar months.
+fn bucket_ends(start: NaiveDate, end: NaiveDate, resolution: ChartResolution) -> Vec<NaiveDate> {
+    let mut ends = Vec::new();
+    let mut bucket_start = start;
+    while bucket_start <= end {
+        let bucket_end = match resolution {
+            ChartResolution::Daily => bucket_start,
+            ChartResolution::Weekly => bucket_start + Duration::days(6),
+            ChartResolution::Monthly => {
+                let (year, month) = if bucket_start.month() == 12 {
+                    (bucket_start.year() + 1, 1)
+                } else {
+                    (bucket_start.year(), bucket_start.month() + 1)
+                };
+                NaiveDate::from_ymd_opt(year, month, 1).map_or(end, |next| next - Duration::days(1))
+            }
+        }
+        .min(end);
+        ends.push(bucket_end);
+        bucket_start = bucket_end + Duration::days(1);
+    }
+    ends
+}
+
+fn round_cents(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand, UpdateChildSettingsCommand};
+    use crate::backend::domain::commands::transactions::CreateTransactionCommand;
+    use crate::backend::domain::{AllowanceService, BalanceService, TransactionService};
+    use tempfile::TempDir;
+
+    fn setup_test() -> (ReportsService, TransactionService, ChildService, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let connection = Arc::new(CsvConnection::new(temp_dir.path()).unwrap());
+        let child_service = ChildService::new(connection.clone());
+        let transaction_service = TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            AllowanceService::new(connection.clone()),
+            BalanceService::new(connection.clone()),
+        );
+        let reports_service = ReportsService::new(connection, child_service.clone());
+        (reports_service, transaction_service, child_service, temp_dir)
+    }
+
+    fn income(amount: f64, date: &str) -> CreateTransactionCommand {
+        CreateTransactionCommand {
+            child_id: None,
+            description: "Chores".to_string(),
+            amount,
+            date: Some(chrono::DateTime::parse_from_rfc3339(date).unwrap()),
+            parent_override: false,
+            actor: None,
+            icon: None,
+            transaction_type: None,
+        }
+    }
+
+    #[test]
+    fn test_auto_saved_summary_groups_by_month() {
+        let (reports_service, transaction_service, child_service, _temp_dir) = setup_test();
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Saver".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+        child_service
+            .update_child_settings(UpdateChildSettingsCommand {
+                child_id: child.id.clone(),
+                prevent_negative_balance: None,
+                auto_save_percentage: Some(20.0),
+                auto_save_to_goal: None,
+                round_up_spends: None,
+                locale: None,
+                currency: None,
+                balance_milestones: None,
+                background: None,
+            })
+            .unwrap();
+
+        transaction_service.create_transaction(income(10.0, "2024-01-05T12:00:00-05:00")).unwrap();
+        transaction_service.create_transaction(income(5.0, "2024-01-20T12:00:00-05:00")).unwrap();
+        transaction_service.create_transaction(income(2.5, "2024-02-03T12:00:00-05:00")).unwrap();
+
+        // Spending is never auto-saved
+        let mut spend = income(-3.0, "2024-02-04T12:00:00-05:00");
+        spend.description = "Snack".to_string();
+        transaction_service.create_transaction(spend).unwrap();
+
+        let summary = reports_service
+            .get_auto_saved_summary(AutoSavedSummaryQuery { child_id: None })
+            .unwrap();
+        assert_eq!(summary.child_id, child.id);
+        assert_eq!(summary.months.len(), 2);
+        assert_eq!(summary.months[0], MonthlyAutoSaved { year: 2024, month: 1, total: 3.0, allocation_count: 2 });
+        assert_eq!(summary.months[1], MonthlyAutoSaved { year: 2024, month: 2, total: 0.5, allocation_count: 1 });
+        assert_eq!(summary.total_auto_saved, 3.5);
+    }
+
+    #[test]
+    fn test_auto_saved_summary_empty_when_disabled() {
+        let (reports_service, transaction_service, child_service, _temp_dir) = setup_test();
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Spender".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+
+        transaction_service.create_transaction(income(10.0, "2024-01-05T12:00:00-05:00")).unwrap();
+
+        let summary = reports_service
+            .get_auto_saved_summary(AutoSavedSummaryQuery { child_id: Some(child.id) })
+            .unwrap();
+        assert!(summary.months.is_empty());
+        assert_eq!(summary.total_auto_saved, 0.0);
+    }
+
+    #[test]
+    fn test_round_up_summary_groups_spend_change_by_month() {
+        let (reports_service, transaction_service, child_service, _temp_dir) = setup_test();
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Rounder".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+        child_service
+            .update_child_settings(UpdateChildSettingsCommand {
+                child_id: child.id.clone(),
+                prevent_negative_balance: None,
+                auto_save_percentage: Some(10.0),
+                auto_save_to_goal: None,
+                round_up_spends: Some(true),
+                locale: None,
+                currency: None,
+                balance_milestones: None,
+                background: None,
+            })
+            .unwrap();
+
+        transaction_service.create_transaction(income(20.0, "2024-01-02T12:00:00-05:00")).unwrap();
+        let spend = |amount: f64, date: &str| {
+            let mut command = income(amount, date);
+            command.description = "Snack".to_string();
+            transaction_service.create_transaction(command).unwrap()
+        };
+        let candy = spend(-3.25, "2024-01-05T12:00:00-05:00");
+        spend(-1.9, "2024-01-20T12:00:00-05:00");
+        // Whole-dollar spends have nothing to round up
+        spend(-4.0, "2024-02-03T12:00:00-05:00");
+        spend(-0.99, "2024-03-01T12:00:00-05:00");
+
+        // The spend itself is recorded unchanged
+        assert_eq!(candy.amount, -3.25);
+
+        let summary = reports_service
+            .get_round_up_summary(RoundUpSummaryQuery { child_id: None })
+            .unwrap();
+        assert_eq!(summary.months.len(), 2);
+        assert_eq!(summary.months[0], MonthlyAutoSaved { year: 2024, month: 1, total: 0.85, allocation_count: 2 });
+        assert_eq!(summary.months[1], MonthlyAutoSaved { year: 2024, month: 3, total: 0.01, allocation_count: 1 });
+        assert_eq!(summary.total_rounded_up, 0.86);
+
+        // Round-ups don't count as auto-saved income
+        let auto_saved = reports_service
+            .get_auto_saved_summary(AutoSavedSummaryQuery { child_id: None })
+            .unwrap();
+        assert_eq!(auto_saved.total_auto_saved, 2.0);
+    }
+
+    #[test]
+    fn test_year_summary_covers_every_day() {
+        let (reports_service, transaction_service, child_service, _temp_dir) = setup_test();
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Heatmap".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+
+        transaction_service.create_transaction(income(10.0, "2023-12-31T12:00:00-05:00")).unwrap();
+        transaction_service.create_transaction(income(5.0, "2024-03-02T09:00:00-05:00")).unwrap();
+        let mut spend = income(-1.5, "2024-03-02T15:00:00-05:00");
+        spend.description = "Gum".to_string();
+        transaction_service.create_transaction(spend).unwrap();
+        let mut spend = income(-7.25, "2024-12-31T15:00:00-05:00");
+        spend.description = "Toy".to_string();
+        transaction_service.create_transaction(spend).unwrap();
+
+        let summary = reports_service
+            .get_year_summary(YearSummaryQuery { child_id: None, year: 2024, exclude_reversals: false })
+            .unwrap();
+
+        // 2024 is a leap year
+        assert_eq!(summary.days.len(), 366);
+        assert_eq!(summary.months.len(), 12);
+        assert_eq!(summary.days[0].date, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+
+        let march_2 = &summary.days[NaiveDate::from_ymd_opt(2024, 3, 2).unwrap().ordinal0() as usize];
+        assert_eq!(march_2.income, 5.0);
+        assert_eq!(march_2.expenses, 1.5);
+        assert_eq!(march_2.net, 3.5);
+        assert_eq!(march_2.transaction_count, 2);
+        assert_eq!(summary.days[365].net, -7.25);
+
+        assert_eq!(summary.months[2], MonthlyTotals { month: 3, income: 5.0, expenses: 1.5, net: 3.5, transaction_count: 2 });
+        assert_eq!(summary.months[0].transaction_count, 0);
+        assert_eq!(summary.max_abs_daily_net, 7.25);
+        assert_eq!(summary.total_net, -3.75);
+    }
+
+    #[test]
+    fn test_monthly_report_opens_with_previous_balance() {
+        let (reports_service, transaction_service, child_service, _temp_dir) = setup_test();
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Monthly".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+
+        transaction_service.create_transaction(income(10.0, "2025-02-20T12:00:00Z")).unwrap();
+        transaction_service.create_transaction(income(5.0, "2025-03-03T12:00:00Z")).unwrap();
+        transaction_service.create_transaction(income(-4.0, "2025-03-10T12:00:00Z")).unwrap();
+        transaction_service.create_transaction(income(2.0, "2025-04-01T12:00:00Z")).unwrap();
+
+        let report = reports_service
+            .get_monthly_report(MonthlyReportQuery { child_id: None, year: 2025, month: 3, exclude_reversals: false })
+            .unwrap();
+        assert_eq!(report.opening_balance, 10.0);
+        assert_eq!(report.total_earned, 5.0);
+        assert_eq!(report.total_spent, 4.0);
+        assert_eq!(report.closing_balance, 11.0);
+        assert_eq!(report.lines.len(), 2);
+
+        let quiet = reports_service
+            .get_monthly_report(MonthlyReportQuery { child_id: None, year: 2025, month: 5, exclude_reversals: false })
+            .unwrap();
+        assert!(quiet.lines.is_empty());
+        assert_eq!(quiet.closing_balance, 13.0);
+        assert!(reports_service
+            .get_monthly_report(MonthlyReportQuery { child_id: None, year: 2025, month: 13, exclude_reversals: false })
+            .is_err());
+    }
+
+    #[test]
+    fn test_monthly_report_totals_each_kind() {
+        let (reports_service, transaction_service, child_service, _temp_dir) = setup_test();
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Kinds".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+
+        let of_kind = |amount: f64, date: &str, transaction_type| CreateTransactionCommand {
+            transaction_type: Some(transaction_type),
+            ..income(amount, date)
+        };
+        transaction_service.create_transaction(income(10.0, "2025-03-01T12:00:00Z")).unwrap();
+        transaction_service.create_transaction(of_kind(3.0, "2025-03-02T12:00:00Z", TransactionType::Bonus)).unwrap();
+        transaction_service.create_transaction(of_kind(2.0, "2025-03-03T12:00:00Z", TransactionType::Bonus)).unwrap();
+        transaction_service.create_transaction(of_kind(-1.5, "2025-03-04T12:00:00Z", TransactionType::Penalty)).unwrap();
+        assert!(transaction_service.create_transaction(of_kind(1.5, "2025-03-05T12:00:00Z", TransactionType::Penalty)).is_err());
+
+        let report = reports_service
+            .get_monthly_report(MonthlyReportQuery { child_id: None, year: 2025, month: 3, exclude_reversals: false })
+            .unwrap();
+        assert_eq!(
+            report.totals_by_type,
+            vec![(TransactionType::Income, 10.0), (TransactionType::Bonus, 5.0), (TransactionType::Penalty, -1.5)]
+        );
+        assert_eq!((report.total_earned, report.total_spent), (15.0, 1.5));
+    }
+
+    #[test]
+    fn test_reports_can_leave_out_reversals() {
+        use crate::backend::domain::commands::transactions::ReverseTransactionCommand;
+
+        let (reports_service, transaction_service, child_service, _temp_dir) = setup_test();
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Returner".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+
+        transaction_service.create_transaction(income(10.0, "2025-03-01T12:00:00Z")).unwrap();
+        let toy = transaction_service.create_transaction(income(-4.0, "2025-03-10T12:00:00Z")).unwrap();
+        transaction_service
+            .reverse_transaction(ReverseTransactionCommand {
+                child_id: None,
+                transaction_id: toy.id,
+                reason: None,
+                date: Some(chrono::DateTime::parse_from_rfc3339("2025-03-12T12:00:00Z").unwrap()),
+                parent_override: false,
+                actor: None,
+            })
+            .unwrap();
+
+        let report = |exclude_reversals| {
+            reports_service
+                .get_monthly_report(MonthlyReportQuery { child_id: None, year: 2025, month: 3, exclude_reversals })
+                .unwrap()
+        };
+        let everything = report(false);
+        assert_eq!(everything.lines.len(), 3);
+        assert_eq!((everything.total_earned, everything.total_spent), (14.0, 4.0));
+
+        let without = report(true);
+        assert_eq!(without.lines.len(), 1);
+        assert_eq!((without.total_earned, without.total_spent), (10.0, 0.0));
+        assert_eq!(without.closing_balance, 10.0);
+
+        let year = reports_service
+            .get_year_summary(YearSummaryQuery { child_id: None, year: 2025, exclude_reversals: true })
+            .unwrap();
+        assert_eq!(year.months[2].transaction_count, 1);
+        assert_eq!(year.total_net, 10.0);
+    }
+
+    #[test]
+    fn test_balance_series_buckets_carry_balance_over() {
+        let (reports_service, transaction_service, child_service, _temp_dir) = setup_test();
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Charted".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+
+        transaction_service.create_transaction(income(10.0, "2024-01-05T12:00:00-05:00")).unwrap();
+        transaction_service.create_transaction(income(-3.0, "2024-01-20T12:00:00-05:00")).unwrap();
+        transaction_service.create_transaction(income(5.0, "2024-02-10T12:00:00-05:00")).unwrap();
+
+        let date = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let series = |resolution: ChartResolution| {
+            reports_service
+                .get_balance_series(BalanceSeriesQuery {
+                    child_id: None,
+                    start_date: date("2024-01-10"),
+                    end_date: date("2024-02-15"),
+                    resolution,
+                })
+                .unwrap()
+                .points
+        };
+
+        // The first day already shows the money received before the range
+        let daily = series(ChartResolution::Daily);
+        assert_eq!(daily.len(), 37);
+        assert_eq!(daily[0], BalancePoint { date: date("2024-01-10"), balance: 10.0 });
+
+        let weekly: Vec<(NaiveDate, f64)> = series(ChartResolution::Weekly).into_iter().map(|p| (p.date, p.balance)).collect();
+        assert_eq!(weekly, vec![
+            (date("2024-01-16"), 10.0),
+            (date("2024-01-23"), 7.0),
+            (date("2024-01-30"), 7.0),
+            (date("2024-02-06"), 7.0),
+            (date("2024-02-13"), 12.0),
+            (date("2024-02-15"), 12.0),
+        ]);
+
+        let monthly = series(ChartResolution::Monthly);
+        assert_eq!(monthly, vec![
+            BalancePoint { date: date("2024-01-31"), balance: 7.0 },
+            BalancePoint { date: date("2024-02-15"), balance: 12.0 },
+        ]);
+
+        let backwards = reports_service.get_balance_series(BalanceSeriesQuery {
+            child_id: None,
+            start_date: date("2024-02-15"),
+            end_date: date("2024-01-10"),
+            resolution: ChartResolution::Daily,
+        });
+        assert!(matches!(backwards.unwrap_err().downcast_ref::<DomainError>(), Some(DomainError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_balance_series_marks_large_transactions_and_goals() {
+        let (reports_service, transaction_service, child_service, temp_dir) = setup_test();
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Marked".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+
+        transaction_service.create_transaction(income(50.0, "2024-03-02T12:00:00-05:00")).unwrap();
+        transaction_service.create_transaction(income(2.0, "2024-03-03T12:00:00-05:00")).unwrap();
+        transaction_service.create_transaction(income(-15.0, "2024-03-09T12:00:00-05:00")).unwrap();
+        // Outside the range
+        transaction_service.create_transaction(income(80.0, "2024-05-01T12:00:00-05:00")).unwrap();
+
+        let goals = GoalRepository::new(CsvConnection::new(temp_dir.path()).unwrap());
+        goals
+            .store_goal(&crate::backend::domain::models::goal::DomainGoal {
+                id: "goal::marked_1".to_string(),
+                child_id: child.id.clone(),
+                description: "Kite".to_string(),
+                target_amount: 30.0,
+                state: DomainGoalState::Completed,
+                created_at: "2024-03-01T09:00:00-05:00".to_string(),
+                updated_at: "2024-03-20T09:00:00-05:00".to_string(),
+            })
+            .unwrap();
+
+        let date = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let markers = reports_service
+            .get_balance_series(BalanceSeriesQuery {
+                child_id: None,
+                start_date: date("2024-03-01"),
+                end_date: date("2024-03-31"),
+                resolution: ChartResolution::Weekly,
+            })
+            .unwrap()
+            .markers;
+        let summary: Vec<(NaiveDate, ChartMarkerKind, f64)> = markers.iter().map(|m| (m.date, m.kind, m.amount)).collect();
+        assert_eq!(summary, vec![
+            (date("2024-03-01"), ChartMarkerKind::GoalCreated, 30.0),
+            (date("2024-03-02"), ChartMarkerKind::LargeTransaction, 50.0),
+            (date("2024-03-09"), ChartMarkerKind::LargeTransaction, -15.0),
+            (date("2024-03-20"), ChartMarkerKind::GoalCompleted, 30.0),
+        ]);
+        assert_eq!(markers[0].description, "Kite");
+    }
+
+    #[test]
+    fn test_spending_breakdown_by_category_and_kind() {
+        let (reports_service, _transaction_service, child_service, temp_dir) = setup_test();
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Sweet Tooth".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+
+        let transaction_repository = TransactionRepository::new(CsvConnection::new(temp_dir.path()).unwrap());
+        let transaction = |id: &str, date: &str, amount: f64, category: Option<&str>, transaction_type| Transaction {
+            id: id.to_string(),
+            child_id: child.id.clone(),
+            date: chrono::DateTime::parse_from_rfc3339(date).unwrap(),
+            description: "Shop".to_string(),
+            amount,
+            balance: 0.0,
+            transaction_type,
+            actor: None,
+            category: category.map(str::to_string),
+            icon: None,
+        };
+        for transaction in [
+            transaction("in-1-a", "2025-03-01T10:00:00-05:00", 40.0, None, TransactionType::Income),
+            transaction("ex-2-a", "2025-03-02T10:00:00-05:00", -6.0, Some("Candy"), TransactionType::Expense),
+            transaction("ex-3-a", "2025-03-05T10:00:00-05:00", -9.0, Some("candy"), TransactionType::Expense),
+            transaction("ex-4-a", "2025-03-06T10:00:00-05:00", -10.0, Some("Toys"), TransactionType::Expense),
+            transaction("in-5-a", "2025-03-07T10:00:00-05:00", 4.0, Some("Toys"), TransactionType::Income),
+            transaction("ex-6-a", "2025-03-08T10:00:00-05:00", -2.0, None, TransactionType::Penalty),
+            transaction("ex-7-a", "2025-04-01T10:00:00-05:00", -50.0, Some("Candy"), TransactionType::Expense),
+        ] {
+            transaction_repository.store_transaction(&transaction).unwrap();
+        }
+
+        let breakdown = |group_by| {
+            reports_service
+                .get_spending_breakdown(SpendingBreakdownQuery {
+                    child_id: None,
+                    start_date: NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+                    end_date: NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(),
+                    group_by,
+                    exclude_reversals: false,
+                })
+                .unwrap()
+        };
+
+        // The toy refund comes off what was spent on toys
+        let by_category = breakdown(BreakdownGrouping::Category);
+        let slices: Vec<_> = by_category.slices.iter().map(|s| (s.label.as_str(), s.amount, s.transaction_count)).collect();
+        assert_eq!(slices, vec![("Candy", 15.0, 2), ("Toys", 6.0, 2), (UNCATEGORIZED_LABEL, 2.0, 1)]);
+        assert_eq!(by_category.total_spent, 23.0);
+        assert!((by_category.slices.iter().map(|s| s.share).sum::<f64>() - 1.0).abs() < 1e-9);
+
+        let by_kind = breakdown(BreakdownGrouping::TransactionType);
+        let slices: Vec<_> = by_kind.slices.iter().map(|s| (s.label.as_str(), s.amount)).collect();
+        assert_eq!(slices, vec![("Expense", 25.0), ("Penalty", 2.0)]);
+    }
+
+    #[test]
+    fn test_monthly_cash_flow_covers_every_month() {
+        let (reports_service, transaction_service, child_service, _temp_dir) = setup_test();
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Trends".to_string(), birthdate: "2015-01-01".to_string() })
+            .unwrap()
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+
+        transaction_service.create_transaction(income(10.0, "2024-10-15T12:00:00Z")).unwrap();
+        transaction_service.create_transaction(income(20.0, "2024-12-01T12:00:00Z")).unwrap();
+        transaction_service.create_transaction(income(-12.5, "2024-12-20T12:00:00Z")).unwrap();
+        transaction_service.create_transaction(income(5.0, "2025-02-03T12:00:00Z")).unwrap();
+        transaction_service.create_transaction(income(-1.0, "2025-03-01T12:00:00Z")).unwrap();
+
+        let result = reports_service
+            .get_monthly_cash_flow(MonthlyCashFlowQuery {
+                child_id: None,
+                end_year: 2025,
+                end_month: 2,
+                months: 4,
+                exclude_reversals: false,
+            })
+            .unwrap();
+        let months: Vec<_> = result.months.iter().map(|m| (m.year, m.month, m.earned, m.spent, m.net)).collect();
+        assert_eq!(months, vec![
+            (2024, 11, 0.0, 0.0, 0.0),
+            (2024, 12, 20.0, 12.5, 7.5),
+            (2025, 1, 0.0, 0.0, 0.0),
+            (2025, 2, 5.0, 0.0, 5.0),
+        ]);
+
+        let no_months = MonthlyCashFlowQuery { child_id: None, end_year: 2025, end_month: 2, months: 0, exclude_reversals: false };
+        assert!(reports_service.get_monthly_cash_flow(no_months).is_err());
+    }
+}
@@ -0,0 +1,180 @@
+//! Reset service domain logic for the allowance tracker.
+//!
+//! Resetting erases every child, transaction and setting in the data
+//! directory, for handing the app to another family or starting a school
+//! year fresh. It is guarded twice: the UI only offers it behind the parental
+//! challenge, and the command must carry a confirmation phrase typed out in
+//! full. Before anything is removed, the whole data directory is written to a
+//! timestamped zip next to it and read back to check it is complete.
+//!
+//! Secrets (the parental answer, SMTP password) live outside the data
+//! directory and are kept. Children relocated elsewhere lose their redirect
+//! file, but the data in the other location is left alone.
+
+use anyhow::Result;
+use chrono::Local;
+use log::{info, warn};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::backend::domain::commands::reset::{ResetAllDataCommand, ResetAllDataResult};
+use crate::backend::domain::errors::DomainError;
+use crate::backend::storage::csv::directory_lock::LOCK_FILE_NAME;
+use crate::backend::storage::csv::{CsvConnection, DirectoryLockError, LockStatus};
+use crate::backend::storage::zip_archive::{read_zip_entries, ZipArchiveWriter};
+
+/// Phrase a parent must type to confirm a reset
+pub const RESET_CONFIRMATION_PHRASE: &str = "erase all data";
+
+/// Service for archiving and erasing all data
+#[derive(Clone)]
+pub struct ResetService {
+    csv_connection: Arc<CsvConnection>,
+}
+
+impl ResetService {
+    /// Create a new ResetService
+    pub fn new(csv_conn: Arc<CsvConnection>) -> Self {
+        Self { csv_connection: csv_conn }
+    }
+
+    /// Archive the data directory to a zip, then erase everything in it
+    pub fn reset_all_data(&self, command: ResetAllDataCommand) -> Result<ResetAllDataResult> {
+        if !command.confirmation.trim().eq_ignore_ascii_case(RESET_CONFIRMATION_PHRASE) {
+            return Err(DomainError::invalid(format!("Type \"{}\" to confirm the reset", RESET_CONFIRMATION_PHRASE)).into());
+        }
+        self.csv_connection.ensure_writable()?;
+
+        let base_dir = self.csv_connection.base_directory();
+        let entries = top_level_entries(&base_dir)?;
+
+        // Another instance writing into a folder we are about to remove would lose its data
+        for entry in entries.iter().filter(|path| path.is_dir()) {
+            if let LockStatus::HeldByOther(holder) = self.csv_connection.child_lock_status(entry) {
+                return Err(DirectoryLockError::HeldByOtherInstance {
+                    path: entry.to_string_lossy().to_string(),
+                    hostname: holder.hostname,
+                    acquired_at: holder.acquired_at,
+                }
+                .into());
+            }
+        }
+
+        let mut files = Vec::new();
+        collect_files(&base_dir, &base_dir, &mut files)?;
+
+        let now = Local::now();
+        let mut archive = ZipArchiveWriter::new(now.naive_local());
+        for (name, path) in &files {
+            archive.add_file(name, &fs::read(path)?)?;
+        }
+        let archive_bytes = archive.finish()?;
+
+        let archive_dir = match command.archive_directory.as_deref().map(str::trim).filter(|dir| !dir.is_empty()) {
+            Some(dir) => PathBuf::from(dir),
+            None => base_dir.parent().map(Path::to_path_buf).unwrap_or_else(|| base_dir.clone()),
+        };
+        if archive_dir.starts_with(&base_dir) {
+            return Err(DomainError::invalid("The archive can't be saved inside the data folder being erased").into());
+        }
+        fs::create_dir_all(&archive_dir)?;
+        let archive_path = archive_dir.join(format!("allowance_tracker_reset_{}.zip", now.format("%Y%m%d_%H%M%S")));
+        fs::write(&archive_path, &archive_bytes)?;
+
+        // Only erase once the archive on disk is known to hold every file
+        let written = read_zip_entries(&fs::read(&archive_path)?)?;
+        if written.len() != files.len() {
+            return Err(anyhow::anyhow!(
+                "Reset archive {} holds {} of {} files; nothing was erased",
+                archive_path.display(),
+                written.len(),
+                files.len()
+            ));
+        }
+        info!("🧹 RESET: Archived {} files to {}", files.len(), archive_path.display());
+
+        self.csv_connection.release_directory_locks();
+        for entry in &entries {
+            let removed = if entry.is_dir() { fs::remove_dir_all(entry) } else { fs::remove_file(entry) };
+            if let Err(e) = removed {
+                warn!("🧹 RESET: Failed to remove {}: {}", entry.display(), e);
+                return Err(anyhow::anyhow!(
+                    "Reset stopped while removing {}: {}. Everything is saved in {}",
+                    entry.display(),
+                    e,
+                    archive_path.display()
+                ));
+            }
+        }
+
+        info!("🧹 RESET: Erased {} entries from {}", entries.len(), base_dir.display());
+        Ok(ResetAllDataResult {
+            archive_path: archive_path.to_string_lossy().to_string(),
+            archived_file_count: files.len(),
+        })
+    }
+}
+
+fn top_level_entries(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        entries.push(entry?.path());
+    }
+    entries.sort();
+    Ok(entries)
+}
+
+/// Every file under `dir` as `(zip name, path)`, leaving out lock files
+fn collect_files(base_dir: &Path, dir: &Path, files: &mut Vec<(String, PathBuf)>) -> Result<()> {
+    for path in top_level_entries(dir)? {
+        if path.is_dir() {
+            collect_files(base_dir, &path, files)?;
+        } else if path.file_name().is_some_and(|name| name != LOCK_FILE_NAME) {
+            let relative = path.strip_prefix(base_dir)?;
+            let name = relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            files.push((name, path));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::child_service::ChildService;
+    use crate::backend::domain::commands::child::CreateChildCommand;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_reset_archives_then_erases_everything() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path().join("data"))?);
+        let child_service = ChildService::new(connection.clone());
+        child_service.create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })?;
+        connection.ensure_transactions_file_exists("Ada")?;
+        let service = ResetService::new(connection.clone());
+
+        let reset = |confirmation: &str| {
+            service.reset_all_data(ResetAllDataCommand { confirmation: confirmation.to_string(), archive_directory: None })
+        };
+        let refused = reset("yes").unwrap_err();
+        assert!(matches!(refused.downcast_ref::<DomainError>(), Some(DomainError::Invalid(_))));
+        assert_eq!(child_service.list_children()?.children.len(), 1);
+
+        let result = reset("  Erase All Data ")?;
+        assert!(result.archive_path.starts_with(&temp_dir.path().to_string_lossy().to_string()));
+        let entries = read_zip_entries(&fs::read(&result.archive_path)?)?;
+        assert_eq!(entries.len(), result.archived_file_count);
+        assert!(entries.iter().any(|(name, _)| name.ends_with("/transactions.csv")));
+        assert!(!entries.iter().any(|(name, _)| name.ends_with(LOCK_FILE_NAME)));
+
+        assert_eq!(fs::read_dir(temp_dir.path().join("data"))?.count(), 0);
+        assert!(child_service.list_children()?.children.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,313 @@
+//! Retention service domain logic for the allowance tracker.
+//!
+//! The audit log, the parental challenge attempts and the archive of rolled-up
+//! transactions only ever grow. A family can set how long each is kept in the
+//! global config; the app applies the rules once a day while it is open, and
+//! a dry run reports what a run would remove without touching anything.
+//!
+//! Records whose timestamps can't be read are always kept. Children whose
+//! files can't be read right now (locked encryption, or another instance
+//! writing them) are reported as skipped and picked up on a later run.
+
+use anyhow::Result;
+use chrono::{DateTime, Local, Months, Utc};
+use log::{debug, info};
+use std::sync::Arc;
+
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::commands::retention::{
+    RetentionReport, RetentionReportItem, UpdateRetentionPolicyCommand,
+};
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::models::retention_policy::{RetentionPolicy, RetentionRule};
+use crate::backend::storage::csv::{
+    AuditLogRepository, CsvConnection, DirectoryLockError, EncryptionError, GlobalConfigRepository,
+    ParentalControlRepository, TransactionRepository,
+};
+use crate::backend::storage::{AuditLogStorage, GlobalConfigStorage, ParentalControlStorage};
+
+/// Owner shown in reports for the family-wide attempts file
+const FAMILY_OWNER: &str = "Family";
+
+/// Service for keeping growing records within the family's retention rules
+#[derive(Clone)]
+pub struct RetentionService {
+    csv_connection: Arc<CsvConnection>,
+    global_config_repository: GlobalConfigRepository,
+    audit_log_repository: AuditLogRepository,
+    parental_control_repository: ParentalControlRepository,
+    transaction_repository: TransactionRepository,
+    child_service: ChildService,
+}
+
+impl RetentionService {
+    /// Create a new RetentionService
+    pub fn new(csv_conn: Arc<CsvConnection>, child_service: ChildService) -> Self {
+        Self {
+            global_config_repository: GlobalConfigRepository::new((*csv_conn).clone()),
+            audit_log_repository: AuditLogRepository::new((*csv_conn).clone()),
+            parental_control_repository: ParentalControlRepository::new((*csv_conn).clone()),
+            transaction_repository: TransactionRepository::new((*csv_conn).clone()),
+            csv_connection: csv_conn,
+            child_service,
+        }
+    }
+
+    /// The family's current retention rules
+    pub fn get_retention_policy(&self) -> Result<RetentionPolicy> {
+        Ok(self.global_config_repository.get_global_config()?.retention)
+    }
+
+    /// Change the retention rules; nothing is removed until the next run
+    pub fn update_retention_policy(&self, command: UpdateRetentionPolicyCommand) -> Result<RetentionPolicy> {
+        let periods = [
+            command.audit_log_days,
+            command.parental_control_attempt_days,
+            command.archived_transaction_years,
+        ];
+        if periods.contains(&Some(0)) {
+            return Err(DomainError::invalid("Keep records for at least one day or year, or turn the rule off").into());
+        }
+
+        let mut config = self.global_config_repository.get_global_config()?;
+        config.retention.audit_log_days = command.audit_log_days;
+        config.retention.parental_control_attempt_days = command.parental_control_attempt_days;
+        config.retention.archived_transaction_years = command.archived_transaction_years;
+        config.updated_at = Utc::now().to_rfc3339();
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!("🧽 RETENTION: Updated policy {:?}", config.retention);
+        Ok(config.retention)
+    }
+
+    /// Report what applying the rules now would remove, without removing it
+    pub fn preview_retention(&self) -> Result<RetentionReport> {
+        self.run_at(&self.get_retention_policy()?, Utc::now(), true)
+    }
+
+    /// Remove everything older than the rules allow
+    pub fn apply_retention(&self) -> Result<RetentionReport> {
+        self.csv_connection.ensure_writable()?;
+        self.run_at(&self.get_retention_policy()?, Utc::now(), false)
+    }
+
+    /// Apply the rules if they haven't been applied yet today
+    ///
+    /// Called from the app's periodic refresh. Returns `None` when there is
+    /// nothing to do: no rules, already run today, or the data is read-only.
+    pub fn run_scheduled_retention(&self) -> Result<Option<RetentionReport>> {
+        self.run_scheduled_at(Utc::now())
+    }
+
+    fn run_scheduled_at(&self, now: DateTime<Utc>) -> Result<Option<RetentionReport>> {
+        let policy = self.get_retention_policy()?;
+        let today = now.with_timezone(&Local).date_naive();
+        if policy.has_no_rules() || policy.last_run_on == Some(today) || self.csv_connection.read_only_source().is_some() {
+            return Ok(None);
+        }
+
+        let report = self.run_at(&policy, now, false)?;
+
+        let mut config = self.global_config_repository.get_global_config()?;
+        config.retention.last_run_on = Some(today);
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!("🧽 RETENTION: Scheduled run removed {} records", report.removed_count());
+        Ok(Some(report))
+    }
+
+    fn run_at(&self, policy: &RetentionPolicy, now: DateTime<Utc>, dry_run: bool) -> Result<RetentionReport> {
+        let mut report = RetentionReport { dry_run, items: Vec::new(), skipped: Vec::new() };
+        if policy.has_no_rules() {
+            return Ok(report);
+        }
+
+        let children = self.child_service.list_children()?.children;
+        for rule in RetentionRule::ALL {
+            let Some(cutoff) = rule_cutoff(policy, rule, now) else {
+                continue;
+            };
+
+            let mut owners: Vec<(Option<String>, String)> =
+                children.iter().map(|child| (Some(child.id.clone()), child.name.clone())).collect();
+            if rule == RetentionRule::ParentalControlAttempts {
+                owners.push((None, FAMILY_OWNER.to_string()));
+            }
+
+            for (child_id, owner) in owners {
+                match self.apply_rule(rule, child_id.as_deref(), cutoff, dry_run) {
+                    Ok(0) => {}
+                    Ok(removed_count) => report.items.push(RetentionReportItem {
+                        rule,
+                        child_id,
+                        owner,
+                        cutoff: cutoff.with_timezone(&Local).date_naive(),
+                        removed_count,
+                    }),
+                    Err(e) if is_unavailable(&e) => {
+                        debug!("🧽 RETENTION: Skipping {} for {}: {}", rule.label(), owner, e);
+                        if !report.skipped.contains(&owner) {
+                            report.skipped.push(owner);
+                        }
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        info!(
+            "🧽 RETENTION: {} {} records ({} places skipped)",
+            if dry_run { "Would remove" } else { "Removed" },
+            report.removed_count(),
+            report.skipped.len()
+        );
+        Ok(report)
+    }
+
+    /// Remove one kind of record older than `cutoff` from one place; returns how many
+    fn apply_rule(&self, rule: RetentionRule, child_id: Option<&str>, cutoff: DateTime<Utc>, dry_run: bool) -> Result<usize> {
+        match (rule, child_id) {
+            (RetentionRule::AuditLog, Some(child_id)) => {
+                let entries = self.audit_log_repository.list_audit_entries(child_id, None)?;
+                let (kept, removed): (Vec<_>, Vec<_>) =
+                    entries.into_iter().partition(|entry| !is_before(&entry.timestamp, cutoff));
+                if !dry_run && !removed.is_empty() {
+                    // Listed most recent first; the file is oldest first
+                    let kept: Vec<_> = kept.into_iter().rev().collect();
+                    self.audit_log_repository.replace_audit_entries(child_id, &kept)?;
+                }
+                Ok(removed.len())
+            }
+            (RetentionRule::ParentalControlAttempts, child_id) => {
+                let owner_id = child_id.unwrap_or("global");
+                let attempts = self.parental_control_repository.get_parental_control_attempts(owner_id, None)?;
+                let (kept, removed): (Vec<_>, Vec<_>) =
+                    attempts.into_iter().partition(|attempt| !is_before(&attempt.timestamp, cutoff));
+                if !dry_run && !removed.is_empty() {
+                    self.parental_control_repository.replace_parental_control_attempts(owner_id, &kept)?;
+                }
+                Ok(removed.len())
+            }
+            (RetentionRule::ArchivedTransactions, Some(child_id)) => {
+                let archived = self.transaction_repository.read_archived_transactions_by_id(child_id)?;
+                let (kept, removed): (Vec<_>, Vec<_>) =
+                    archived.into_iter().partition(|transaction| transaction.date >= cutoff);
+                if !dry_run && !removed.is_empty() {
+                    self.transaction_repository.write_archived_transactions_by_id(child_id, &kept)?;
+                }
+                Ok(removed.len())
+            }
+            (_, None) => Ok(0),
+        }
+    }
+}
+
+/// Oldest moment a rule keeps, or `None` if the rule is off
+fn rule_cutoff(policy: &RetentionPolicy, rule: RetentionRule, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    match rule {
+        RetentionRule::AuditLog => policy.audit_log_days.map(|days| now - chrono::Duration::days(days as i64)),
+        RetentionRule::ParentalControlAttempts => {
+            policy.parental_control_attempt_days.map(|days| now - chrono::Duration::days(days as i64))
+        }
+        RetentionRule::ArchivedTransactions => policy
+            .archived_transaction_years
+            .and_then(|years| now.checked_sub_months(Months::new(years * 12))),
+    }
+}
+
+/// Whether an RFC 3339 timestamp is before `cutoff`; unreadable ones are kept
+fn is_before(timestamp: &str, cutoff: DateTime<Utc>) -> bool {
+    DateTime::parse_from_rfc3339(timestamp).is_ok_and(|time| time < cutoff)
+}
+
+/// Errors that mean a child's files can't be touched right now, not that something is broken
+fn is_unavailable(error: &anyhow::Error) -> bool {
+    error.downcast_ref::<EncryptionError>().is_some() || error.downcast_ref::<DirectoryLockError>().is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::models::audit_entry::AuditEntry;
+    use crate::backend::domain::models::parental_control_attempt::ParentalControlAttempt;
+    use crate::backend::domain::models::transaction::{Transaction as DomainTransaction, TransactionType};
+    use crate::backend::storage::csv::test_utils::TestHelper;
+
+    fn audit_entry(timestamp: &str, action: &str) -> AuditEntry {
+        AuditEntry { timestamp: timestamp.to_string(), ..AuditEntry::new(action, None, String::new()) }
+    }
+
+    fn attempt(id: i64, timestamp: &str) -> ParentalControlAttempt {
+        ParentalControlAttempt { id, attempted_value: "guess".to_string(), timestamp: timestamp.to_string(), success: false }
+    }
+
+    #[test]
+    fn test_preview_reports_and_apply_removes_old_records() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let connection = Arc::new(helper.env.connection.clone());
+        let child = helper.create_test_child()?;
+        let service = RetentionService::new(connection.clone(), ChildService::new(connection.clone()));
+
+        let audit = AuditLogRepository::new(helper.env.connection.clone());
+        audit.replace_audit_entries(
+            &child.id,
+            &[
+                audit_entry("2024-01-01T12:00:00+00:00", "old"),
+                audit_entry("not a timestamp", "unreadable"),
+                audit_entry("2024-06-01T12:00:00+00:00", "recent"),
+            ],
+        )?;
+        helper.parental_control_repo.replace_parental_control_attempts(
+            "global",
+            &[attempt(1, "2024-01-01T12:00:00+00:00"), attempt(2, "2024-05-30T12:00:00+00:00")],
+        )?;
+        let archived = DomainTransaction {
+            id: "T0001".to_string(),
+            child_id: child.id.clone(),
+            date: DateTime::parse_from_rfc3339("2020-01-03T12:00:00-05:00")?,
+            description: "Allowance".to_string(),
+            amount: 5.0,
+            balance: 5.0,
+            transaction_type: TransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
+        };
+        helper.transaction_repo.write_archived_transactions_by_id(&child.id, &[archived])?;
+
+        // No rules, nothing to do
+        let now = DateTime::parse_from_rfc3339("2024-06-02T12:00:00+00:00")?.with_timezone(&Utc);
+        assert!(service.run_scheduled_at(now)?.is_none());
+
+        service.update_retention_policy(UpdateRetentionPolicyCommand {
+            audit_log_days: Some(30),
+            parental_control_attempt_days: Some(30),
+            archived_transaction_years: None,
+        })?;
+        assert!(service
+            .update_retention_policy(UpdateRetentionPolicyCommand { audit_log_days: Some(0), ..Default::default() })
+            .is_err());
+
+        let preview = service.run_at(&service.get_retention_policy()?, now, true)?;
+        assert!(preview.dry_run);
+        let summary: Vec<(RetentionRule, &str, usize)> =
+            preview.items.iter().map(|item| (item.rule, item.owner.as_str(), item.removed_count)).collect();
+        assert_eq!(
+            summary,
+            vec![(RetentionRule::AuditLog, child.name.as_str(), 1), (RetentionRule::ParentalControlAttempts, FAMILY_OWNER, 1)]
+        );
+        assert_eq!(audit.list_audit_entries(&child.id, None)?.len(), 3);
+
+        let applied = service.run_scheduled_at(now)?.expect("rules are set and it hasn't run today");
+        assert_eq!(applied.removed_count(), 2);
+        let actions: Vec<String> = audit.list_audit_entries(&child.id, None)?.into_iter().map(|e| e.action).collect();
+        assert_eq!(actions, vec!["recent", "unreadable"]);
+        let attempts = helper.parental_control_repo.get_parental_control_attempts("global", None)?;
+        assert_eq!(attempts.iter().map(|a| a.id).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(helper.transaction_repo.read_archived_transactions_by_id(&child.id)?.len(), 1);
+
+        // Once a day only
+        assert!(service.run_scheduled_at(now)?.is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,283 @@
+//! Scheduled report service domain logic for the allowance tracker.
+//!
+//! Once a parent picks a folder, every child's monthly report from
+//! `ReportsService` is written there as a CSV file when a new month starts.
+//! The app checks from its periodic refresh, so a month that ended while the
+//! app was closed is written the next time it is opened. Each file written is
+//! recorded in the global config, newest first.
+//!
+//! Report files live outside the data directory and are never encrypted,
+//! like exports.
+
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Local, Months, NaiveDate, Utc};
+use log::{info, warn};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::commands::reports::{MonthlyReportQuery, MonthlyReportResult};
+use crate::backend::domain::commands::scheduled_reports::{
+    GenerateMonthlyReportsCommand, GeneratedReportsResult, UpdateReportScheduleCommand,
+};
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::models::report_schedule::{GeneratedReport, ReportSchedule, MAX_REPORT_HISTORY};
+use crate::backend::domain::ReportsService;
+use crate::backend::storage::csv::{CsvConnection, GlobalConfigRepository};
+use crate::backend::storage::GlobalConfigStorage;
+
+/// Service for writing monthly reports to a folder on a schedule
+#[derive(Clone)]
+pub struct ScheduledReportService {
+    csv_connection: Arc<CsvConnection>,
+    global_config_repository: GlobalConfigRepository,
+    child_service: ChildService,
+    reports_service: ReportsService,
+}
+
+impl ScheduledReportService {
+    /// Create a new ScheduledReportService
+    pub fn new(csv_conn: Arc<CsvConnection>, child_service: ChildService, reports_service: ReportsService) -> Self {
+        Self {
+            global_config_repository: GlobalConfigRepository::new((*csv_conn).clone()),
+            csv_connection: csv_conn,
+            child_service,
+            reports_service,
+        }
+    }
+
+    /// The report folder and the files written so far
+    pub fn get_report_schedule(&self) -> Result<ReportSchedule> {
+        Ok(self.global_config_repository.get_global_config()?.report_schedule)
+    }
+
+    /// Choose the report folder, or stop writing reports
+    ///
+    /// The folder has to exist already. Turning the schedule on doesn't write
+    /// anything for months that have already ended.
+    pub fn update_report_schedule(&self, command: UpdateReportScheduleCommand) -> Result<ReportSchedule> {
+        let folder = command.folder.map(|folder| folder.trim().to_string()).filter(|folder| !folder.is_empty());
+        if let Some(folder) = &folder {
+            if !Path::new(folder).is_dir() {
+                return Err(DomainError::invalid(format!("Report folder does not exist: {}", folder)).into());
+            }
+        }
+
+        let mut config = self.global_config_repository.get_global_config()?;
+        if config.report_schedule.folder.is_none() && folder.is_some() {
+            config.report_schedule.last_month_generated = Some(previous_month_start(Local::now().date_naive()));
+        }
+        config.report_schedule.folder = folder;
+        config.updated_at = Utc::now().to_rfc3339();
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!("🗓️ REPORTS: Monthly report folder set to {:?}", config.report_schedule.folder);
+        Ok(config.report_schedule)
+    }
+
+    /// Write every child's report for a month now, whatever the schedule says
+    pub fn generate_monthly_reports(&self, command: GenerateMonthlyReportsCommand) -> Result<GeneratedReportsResult> {
+        let month_start = NaiveDate::from_ymd_opt(command.year, command.month, 1)
+            .ok_or_else(|| DomainError::invalid(format!("Invalid month: {}/{}", command.month, command.year)))?;
+        let folder = self
+            .get_report_schedule()?
+            .folder
+            .ok_or_else(|| DomainError::invalid("Choose a report folder first"))?;
+        self.generate_into(&folder, month_start, Utc::now())
+    }
+
+    /// Write last month's reports if they haven't been written yet
+    ///
+    /// Called from the app's periodic refresh. Returns `None` when there is
+    /// nothing to do: no folder, already written, or the data is read-only.
+    pub fn run_scheduled_reports(&self) -> Result<Option<GeneratedReportsResult>> {
+        self.run_scheduled_at(Utc::now())
+    }
+
+    fn run_scheduled_at(&self, now: DateTime<Utc>) -> Result<Option<GeneratedReportsResult>> {
+        let schedule = self.get_report_schedule()?;
+        let Some(folder) = schedule.folder else {
+            return Ok(None);
+        };
+        let due = previous_month_start(now.with_timezone(&Local).date_naive());
+        if schedule.last_month_generated.is_some_and(|last| last >= due) || self.csv_connection.read_only_source().is_some() {
+            return Ok(None);
+        }
+
+        let result = self.generate_into(&folder, due, now)?;
+
+        let mut config = self.global_config_repository.get_global_config()?;
+        config.report_schedule.last_month_generated = Some(due);
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!("🗓️ REPORTS: Scheduled run wrote {} reports for {}", result.reports.len(), due.format("%Y-%m"));
+        Ok(Some(result))
+    }
+
+    fn generate_into(&self, folder: &str, month_start: NaiveDate, now: DateTime<Utc>) -> Result<GeneratedReportsResult> {
+        self.csv_connection.ensure_writable()?;
+        let folder = Path::new(folder);
+        if !folder.is_dir() {
+            return Err(DomainError::invalid(format!("Report folder does not exist: {}", folder.display())).into());
+        }
+
+        let mut result = GeneratedReportsResult {
+            year: month_start.year(),
+            month: month_start.month(),
+            reports: Vec::new(),
+            skipped: Vec::new(),
+        };
+        for child in self.child_service.list_children()?.children {
+            let report = match self.reports_service.get_monthly_report(MonthlyReportQuery {
+                child_id: Some(child.id.clone()),
+                year: result.year,
+                month: result.month,
+                exclude_reversals: false,
+            }) {
+                Ok(report) => report,
+                Err(e) => {
+                    warn!("🗓️ REPORTS: Skipping {}: {}", child.name, e);
+                    result.skipped.push(child.name.clone());
+                    continue;
+                }
+            };
+
+            let file_name = format!(
+                "{}_{}_report.csv",
+                CsvConnection::generate_safe_directory_name(&child.name),
+                month_start.format("%Y-%m")
+            );
+            let path = folder.join(file_name);
+            fs::write(&path, report_csv(&report)?)?;
+
+            result.reports.push(GeneratedReport {
+                child_id: child.id,
+                child_name: child.name,
+                year: result.year,
+                month: result.month,
+                path: path.to_string_lossy().to_string(),
+                generated_at: now.to_rfc3339(),
+            });
+        }
+
+        let mut config = self.global_config_repository.get_global_config()?;
+        for report in &result.reports {
+            config.report_schedule.history.insert(0, report.clone());
+        }
+        config.report_schedule.history.truncate(MAX_REPORT_HISTORY);
+        config.updated_at = now.to_rfc3339();
+        self.global_config_repository.update_global_config(&config)?;
+
+        Ok(result)
+    }
+}
+
+/// First day of the month before the one `today` is in
+fn previous_month_start(today: NaiveDate) -> NaiveDate {
+    let this_month = today.with_day(1).unwrap_or(today);
+    this_month.checked_sub_months(Months::new(1)).unwrap_or(this_month)
+}
+
+/// A monthly report as CSV: the opening balance, each transaction, the
+/// month's totals for each kind and overall, and the closing balance
+fn report_csv(report: &MonthlyReportResult) -> Result<Vec<u8>> {
+    let month_start = NaiveDate::from_ymd_opt(report.year, report.month, 1)
+        .ok_or_else(|| DomainError::invalid(format!("Invalid month: {}/{}", report.month, report.year)))?;
+    let month_end = month_start
+        .checked_add_months(Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .unwrap_or(month_start);
+    let money = |amount: f64| format!("{:.2}", amount);
+    let first_day = month_start.to_string();
+    let last_day = month_end.to_string();
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(["date", "description", "category", "type", "amount", "balance"])?;
+    writer.write_record([first_day.as_str(), "Opening balance", "", "", "", money(report.opening_balance).as_str()])?;
+    for line in &report.lines {
+        writer.write_record([
+            line.date.to_string().as_str(),
+            line.description.as_str(),
+            line.category.as_deref().unwrap_or(""),
+            line.transaction_type.label(),
+            money(line.amount).as_str(),
+            money(line.balance).as_str(),
+        ])?;
+    }
+    for (kind, total) in &report.totals_by_type {
+        let description = format!("{} total", kind.label());
+        writer.write_record([last_day.as_str(), description.as_str(), "", kind.label(), money(*total).as_str(), ""])?;
+    }
+    writer.write_record([last_day.as_str(), "Total earned", "", "", money(report.total_earned).as_str(), ""])?;
+    writer.write_record([last_day.as_str(), "Total spent", "", "", money(-report.total_spent).as_str(), ""])?;
+    writer.write_record([last_day.as_str(), "Closing balance", "", "", "", money(report.closing_balance).as_str()])?;
+    Ok(writer.into_inner()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand};
+    use crate::backend::domain::commands::transactions::CreateTransactionCommand;
+    use crate::backend::domain::{AllowanceService, BalanceService, TransactionService};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_scheduled_run_writes_last_month_once() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let report_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let child_service = ChildService::new(connection.clone());
+        let transaction_service = TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            AllowanceService::new(connection.clone()),
+            BalanceService::new(connection.clone()),
+        );
+        let reports_service = ReportsService::new(connection.clone(), child_service.clone());
+        let service = ScheduledReportService::new(connection, child_service.clone(), reports_service);
+
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Alice".to_string(), birthdate: "2015-01-01".to_string() })?
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() })?;
+        transaction_service.create_transaction(CreateTransactionCommand {
+            child_id: None,
+            description: "Chores, extra".to_string(),
+            amount: 5.0,
+            date: Some(DateTime::parse_from_rfc3339("2025-02-10T12:00:00Z")?),
+            parent_override: false,
+            actor: None,
+            icon: None,
+            transaction_type: None,
+        })?;
+
+        // No folder yet, so nothing is due
+        let now = DateTime::parse_from_rfc3339("2025-03-01T12:00:00Z")?.with_timezone(&Utc);
+        assert!(service.run_scheduled_at(now)?.is_none());
+
+        let folder = report_dir.path().to_string_lossy().to_string();
+        assert!(service
+            .update_report_schedule(UpdateReportScheduleCommand { folder: Some(report_dir.path().join("missing").to_string_lossy().to_string()) })
+            .is_err());
+        service.update_report_schedule(UpdateReportScheduleCommand { folder: Some(folder) })?;
+        let mut config = service.global_config_repository.get_global_config()?;
+        config.report_schedule.last_month_generated = NaiveDate::from_ymd_opt(2025, 1, 1);
+        service.global_config_repository.update_global_config(&config)?;
+
+        let result = service.run_scheduled_at(now)?.expect("February is due");
+        assert_eq!((result.year, result.month), (2025, 2));
+        assert_eq!(result.reports.len(), 1);
+        let content = fs::read_to_string(&result.reports[0].path)?;
+        assert!(result.reports[0].path.ends_with("alice_2025-02_report.csv"));
+        assert!(content.contains("\"Chores, extra\",,Income,5.00,5.00"));
+        assert!(content.contains("Income total,,Income,5.00,"));
+        assert!(content.contains("Closing balance,,,,5.00"));
+
+        // Already written this month
+        assert!(service.run_scheduled_at(now)?.is_none());
+        assert_eq!(service.get_report_schedule()?.history, result.reports);
+        Ok(())
+    }
+}
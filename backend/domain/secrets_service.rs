@@ -0,0 +1,68 @@
+//! Secrets service domain logic for the allowance tracker.
+//!
+//! Keeps passwords and similar values out of plain-text config. Secrets go
+//! to the OS keychain when one is available and to an encrypted file in the
+//! app's config directory otherwise. Secret names used by the app are defined
+//! here so every caller agrees on them.
+
+use anyhow::Result;
+use log::info;
+use std::sync::Arc;
+
+use crate::backend::storage::secrets::{EncryptedFileSecretStore, KeychainSecretStore, SecretStore};
+
+/// SMTP password for email notifications
+pub const SMTP_PASSWORD_SECRET: &str = "smtp_password";
+
+/// Answer to the parental control challenge
+pub const PARENTAL_CONTROL_ANSWER_SECRET: &str = "parental_control_answer";
+
+/// Service for reading and writing secrets
+#[derive(Clone)]
+pub struct SecretsService {
+    store: Arc<dyn SecretStore>,
+}
+
+impl SecretsService {
+    /// Use the OS keychain, falling back to an encrypted file in the config directory
+    pub fn new() -> Result<Self> {
+        let store: Arc<dyn SecretStore> = match KeychainSecretStore::probe() {
+            Some(keychain) => Arc::new(keychain),
+            None => {
+                let config_dir = dirs::config_dir()
+                    .ok_or_else(|| anyhow::anyhow!("Could not determine config directory"))?
+                    .join("allowance-tracker");
+                Arc::new(EncryptedFileSecretStore::new(config_dir)?)
+            }
+        };
+        info!("🔑 SECRETS: Using {}", store.backend_name());
+        Ok(Self { store })
+    }
+
+    /// Use a specific secret store
+    pub fn with_store(store: Arc<dyn SecretStore>) -> Self {
+        Self { store }
+    }
+
+    /// Look up a secret
+    pub fn get_secret(&self, name: &str) -> Result<Option<String>> {
+        self.store.get(name)
+    }
+
+    /// Store or replace a secret
+    pub fn set_secret(&self, name: &str, value: &str) -> Result<()> {
+        self.store.set(name, value)?;
+        info!("🔑 SECRETS: Stored '{}' in {}", name, self.store.backend_name());
+        Ok(())
+    }
+
+    /// Remove a secret
+    pub fn delete_secret(&self, name: &str) -> Result<()> {
+        self.store.delete(name)
+    }
+
+    /// Where secrets are kept
+    pub fn backend_name(&self) -> &'static str {
+        self.store.backend_name()
+    }
+}
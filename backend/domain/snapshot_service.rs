@@ -0,0 +1,201 @@
+//! Snapshot service domain logic for the allowance tracker.
+//!
+//! Keeps each child's startup snapshot (see [`ChildSnapshot`]) in step with
+//! their data. The frontend loads the snapshot before the first frame, then
+//! calls [`SnapshotService::refresh_snapshot`] off the UI thread after startup
+//! and after every change: the refresh reads everything in full, rewrites the
+//! snapshot if anything moved and hands back the fresh copy to reconcile with.
+
+use anyhow::Result;
+use chrono::Utc;
+use log::info;
+use std::sync::Arc;
+
+use crate::backend::domain::commands::goal::GetCurrentGoalCommand;
+use crate::backend::domain::models::child_snapshot::ChildSnapshot;
+use crate::backend::domain::models::transaction::TransactionType;
+use crate::backend::domain::{GoalService, TransactionService};
+use crate::backend::storage::csv::{CsvConnection, SnapshotRepository};
+use crate::backend::storage::ChildSnapshotStorage;
+
+/// Service for building, storing and loading startup snapshots
+#[derive(Clone)]
+pub struct SnapshotService {
+    snapshot_repository: SnapshotRepository,
+    transaction_service: Arc<TransactionService>,
+    goal_service: Arc<GoalService>,
+}
+
+impl SnapshotService {
+    /// Create a new SnapshotService
+    pub fn new(
+        csv_conn: Arc<CsvConnection>,
+        transaction_service: Arc<TransactionService>,
+        goal_service: Arc<GoalService>,
+    ) -> Self {
+        Self {
+            snapshot_repository: SnapshotRepository::new((*csv_conn).clone()),
+            transaction_service,
+            goal_service,
+        }
+    }
+
+    /// The stored snapshot for a child, without touching the rest of their data
+    pub fn load_snapshot(&self, child_id: &str) -> Result<Option<ChildSnapshot>> {
+        self.snapshot_repository.load_snapshot(child_id)
+    }
+
+    /// Build a snapshot from the child's full data
+    pub fn build_snapshot(&self, child_id: &str) -> Result<ChildSnapshot> {
+        let transactions: Vec<_> = self
+            .transaction_service
+            .list_all_transactions_for_child(child_id)?
+            .into_iter()
+            .filter(|t| t.transaction_type != TransactionType::FutureAllowance)
+            .collect();
+        let balance = transactions.last().map(|t| t.balance).unwrap_or(0.0);
+
+        let now = Utc::now();
+        let cutoff = ChildSnapshot::recent_cutoff(now.fixed_offset());
+        let recent_transactions = transactions
+            .into_iter()
+            .rev()
+            .take_while(|t| t.date >= cutoff)
+            .collect();
+
+        let goal = self.goal_service.get_current_goal(GetCurrentGoalCommand {
+            child_id: Some(child_id.to_string()),
+        })?;
+
+        Ok(ChildSnapshot {
+            child_id: child_id.to_string(),
+            written_at: now,
+            balance,
+            recent_transactions,
+            active_goal: goal.goal,
+            goal_calculation: goal.calculation,
+        })
+    }
+
+    /// Rebuild a child's snapshot from their full data, writing it only if it changed
+    pub fn refresh_snapshot(&self, child_id: &str) -> Result<ChildSnapshot> {
+        let fresh = self.build_snapshot(child_id)?;
+        if let Some(stored) = self.snapshot_repository.load_snapshot(child_id)? {
+            if stored.same_data(&fresh) {
+                return Ok(stored);
+            }
+        }
+        self.snapshot_repository.store_snapshot(&fresh)?;
+        info!("📸 Refreshed snapshot for child '{}' (balance ${:.2})", child_id, fresh.balance);
+        Ok(fresh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::child_service::ChildService;
+    use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand};
+    use crate::backend::domain::commands::goal::CreateGoalCommand;
+    use crate::backend::domain::commands::transactions::CreateTransactionCommand;
+    use crate::backend::domain::{AllowanceService, BalanceService};
+    use chrono::Duration;
+    use crate::backend::storage::csv::encryption::MAGIC;
+    use crate::backend::storage::csv::SNAPSHOT_FILE;
+    use tempfile::TempDir;
+
+    fn income(amount: f64, days_ago: i64) -> CreateTransactionCommand {
+        CreateTransactionCommand {
+            child_id: None,
+            description: "Chores".to_string(),
+            amount,
+            date: Some((Utc::now() - Duration::days(days_ago)).fixed_offset()),
+            parent_override: false,
+            actor: None,
+            icon: None,
+            transaction_type: None,
+        }
+    }
+
+    struct Fixture {
+        connection: Arc<CsvConnection>,
+        transaction_service: Arc<TransactionService>,
+        goal_service: Arc<GoalService>,
+        service: SnapshotService,
+        child_id: String,
+    }
+
+    /// Services over a fresh data directory with an active child "Ada"
+    fn setup(temp_dir: &TempDir) -> Result<Fixture> {
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let child_service = ChildService::new(connection.clone());
+        let allowance_service = AllowanceService::new(connection.clone());
+        let balance_service = BalanceService::new(connection.clone());
+        let transaction_service = Arc::new(TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            allowance_service.clone(),
+            balance_service.clone(),
+        ));
+        let goal_service = Arc::new(GoalService::new(
+            connection.clone(),
+            child_service.clone(),
+            allowance_service,
+            transaction_service.clone(),
+            balance_service,
+        ));
+        let service = SnapshotService::new(connection.clone(), transaction_service.clone(), goal_service.clone());
+
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })?
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() })?;
+        Ok(Fixture { connection, transaction_service, goal_service, service, child_id: child.id })
+    }
+
+    #[test]
+    fn test_snapshot_keeps_recent_data_and_refreshes_on_change() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let Fixture { transaction_service, goal_service, service, child_id, .. } = setup(&temp_dir)?;
+        assert!(service.load_snapshot(&child_id)?.is_none());
+
+        transaction_service.create_transaction(income(30.0, 90))?;
+        transaction_service.create_transaction(income(10.0, 1))?;
+        goal_service.create_goal(CreateGoalCommand {
+            child_id: Some(child_id.clone()),
+            description: "Bike".to_string(),
+            target_amount: 100.0,
+        })?;
+
+        let snapshot = service.refresh_snapshot(&child_id)?;
+        assert_eq!(snapshot.balance, 40.0);
+        assert_eq!(snapshot.recent_transactions.len(), 1);
+        assert_eq!(snapshot.active_goal.as_ref().map(|g| g.description.as_str()), Some("Bike"));
+        assert_eq!(service.load_snapshot(&child_id)?, Some(snapshot.clone()));
+
+        // Nothing changed, so the stored snapshot is kept as it was
+        assert_eq!(service.refresh_snapshot(&child_id)?.written_at, snapshot.written_at);
+
+        transaction_service.create_transaction(income(5.0, 0))?;
+        let refreshed = service.refresh_snapshot(&child_id)?;
+        assert_eq!(refreshed.balance, 45.0);
+        assert_eq!(refreshed.recent_transactions.len(), 2);
+        assert_eq!(service.load_snapshot(&child_id)?, Some(refreshed));
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshot_is_sealed_in_an_encrypted_directory() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let Fixture { connection, transaction_service, service, child_id, .. } = setup(&temp_dir)?;
+        transaction_service.create_transaction(income(10.0, 1))?;
+        let child_dir = connection.get_child_directory_by_id(&child_id)?.unwrap();
+        connection.encrypt_child_directory(&child_dir, "ice cold")?;
+
+        service.refresh_snapshot(&child_id)?;
+        let on_disk = std::fs::read(child_dir.join(SNAPSHOT_FILE))?;
+        assert!(on_disk.starts_with(MAGIC), "the snapshot repeats recent transactions, so it must not be plain text");
+        assert_eq!(service.load_snapshot(&child_id)?.map(|snapshot| snapshot.balance), Some(10.0));
+        Ok(())
+    }
+}
@@ -6,28 +6,71 @@ use crate::backend::{
         child_service::ChildService,
         email_service::{EmailServiceWrapper, EmailConfig},
         models::{
+            actor::Actor,
+            audit_entry::{AuditEntry, AUDIT_ACTION_OVERDRAFT_OVERRIDE, AUDIT_ACTION_PERIOD_CLOSED, AUDIT_ACTION_PERIOD_REOPENED, AUDIT_ACTION_TRANSACTIONS_IMPORTED, AUDIT_ACTION_TRANSACTIONS_RECATEGORIZED, AUDIT_ACTION_TRANSACTION_ADDED, AUDIT_ACTION_TRANSACTION_DELETED, AUDIT_ACTION_TRANSACTION_EDITED, AUDIT_ACTION_TRANSACTION_REVERSED},
+            celebration::{milestones_crossed, Celebration, ReachedMilestone},
             child::Child as DomainChild,
+            child_settings::InsufficientFundsError,
+            period_lock::PeriodLock,
+            savings_allocation::{SavingsAllocation, SavingsSource},
             transaction::{Transaction as DomainTransaction, TransactionType as DomainTransactionType},
+            transaction_icon::normalize_icon,
+            transaction_reversal::TransactionReversal,
         },
     },
-    storage::csv::{CsvConnection, TransactionRepository},
-    storage::traits::TransactionStorage,
+    storage::csv::{AllowanceAdvanceRepository, AuditLogRepository, CelebrationRepository, CsvConnection, GlobalConfigRepository, GoalRepository, LoanRepository, PeriodLockRepository, ReversalRepository, SavingsAllocationRepository, TransactionRepository},
+    storage::traits::{AllowanceAdvanceStorage, AuditLogStorage, CelebrationStorage, LoanStorage, PeriodLockStorage, SavingsAllocationStorage, TransactionReversalStorage, TransactionStorage},
 };
-use crate::backend::domain::commands::transactions::{CreateTransactionCommand, TransactionListQuery, TransactionListResult, DeleteTransactionsCommand, DeleteTransactionsResult, PaginationInfo as DomainPagination, CalendarTransactionsQuery, CalendarTransactionsResult};
-use anyhow::{anyhow, Result};
+use crate::backend::storage::GlobalConfigStorage;
+use crate::backend::domain::commands::import::ImportRow;
+use crate::backend::domain::commands::transactions::{CreateTransactionCommand, TransactionListQuery, TransactionListResult, DeleteTransactionsCommand, DeleteTransactionsResult, PaginationInfo as DomainPagination, CalendarTransactionsQuery, CalendarTransactionsResult, ReverseTransactionCommand, ReverseTransactionResult, BackdateImpact, ClosePeriodCommand, ClosePeriodResult, ReopenPeriodCommand, ReopenPeriodResult, TransactionSearchQuery, TransactionSearchResult, EditTransactionCommand, EditTransactionResult, DuplicateTransactionCommand, DuplicateTransactionResult, BulkCategorizeCommand, BulkCategorizeResult, CategoryChange, UndoBulkCategorizeCommand};
+use crate::backend::domain::commands::allowance::GetAllowanceConfigCommand;
+use crate::backend::domain::commands::child::GetChildCommand;
+use anyhow::Result;
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::description_input::{self, MAX_DESCRIPTION_LENGTH};
 use chrono::{Local, NaiveDate};
-use log::{error, info};
-use std::sync::Arc;
+use log::{error, info, warn};
+use std::collections::HashSet;
+use std::sync::{Arc, RwLock};
 
 
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// How many of the transactions a bulk category change applies to are
+/// returned for showing in its preview
+const BULK_CATEGORY_SAMPLE_SIZE: usize = 8;
+
+/// How a new transaction is labelled, beyond its description
+#[derive(Debug, Default)]
+struct TransactionTags {
+    /// ID picked before storing, so an audit entry written first can point
+    /// at it; `None` to generate one
+    id: Option<String>,
+    category: Option<String>,
+    icon: Option<String>,
+    /// `None` to go by the amount's sign
+    transaction_type: Option<DomainTransactionType>,
+}
+
 pub struct TransactionService {
     transaction_repository: TransactionRepository,
+    audit_log_repository: AuditLogRepository,
+    savings_allocation_repository: SavingsAllocationRepository,
+    loan_repository: LoanRepository,
+    advance_repository: AllowanceAdvanceRepository,
+    reversal_repository: ReversalRepository,
+    goal_repository: GoalRepository,
+    celebration_repository: CelebrationRepository,
+    period_lock_repository: PeriodLockRepository,
+    global_config_repository: GlobalConfigRepository,
+    /// Shared by the repositories; bulk changes batch their writes through it
+    connection: CsvConnection,
     child_service: ChildService,
     allowance_service: AllowanceService,
     balance_service: BalanceService,
-    email_service: Option<EmailServiceWrapper>,
+    /// Swapped out when `email_config.toml` is reloaded
+    email_service: RwLock<Option<EmailServiceWrapper>>,
 }
 
 impl TransactionService {
@@ -38,12 +81,31 @@ impl TransactionService {
         balance_service: BalanceService,
     ) -> Self {
         let transaction_repository = TransactionRepository::new((*connection).clone());
+        let audit_log_repository = AuditLogRepository::new((*connection).clone());
+        let savings_allocation_repository = SavingsAllocationRepository::new((*connection).clone());
+        let loan_repository = LoanRepository::new((*connection).clone());
+        let advance_repository = AllowanceAdvanceRepository::new((*connection).clone());
+        let reversal_repository = ReversalRepository::new((*connection).clone());
+        let goal_repository = GoalRepository::new((*connection).clone());
+        let celebration_repository = CelebrationRepository::new((*connection).clone());
+        let period_lock_repository = PeriodLockRepository::new((*connection).clone());
+        let global_config_repository = GlobalConfigRepository::new((*connection).clone());
         Self {
             transaction_repository,
+            audit_log_repository,
+            savings_allocation_repository,
+            loan_repository,
+            advance_repository,
+            reversal_repository,
+            goal_repository,
+            celebration_repository,
+            period_lock_repository,
+            global_config_repository,
+            connection: (*connection).clone(),
             child_service,
             allowance_service,
             balance_service,
-            email_service: None,
+            email_service: RwLock::new(None),
         }
     }
 
@@ -55,26 +117,72 @@ impl TransactionService {
         email_config: EmailConfig,
     ) -> Result<Self> {
         let transaction_repository = TransactionRepository::new((*connection).clone());
+        let audit_log_repository = AuditLogRepository::new((*connection).clone());
+        let savings_allocation_repository = SavingsAllocationRepository::new((*connection).clone());
+        let loan_repository = LoanRepository::new((*connection).clone());
+        let advance_repository = AllowanceAdvanceRepository::new((*connection).clone());
+        let reversal_repository = ReversalRepository::new((*connection).clone());
+        let goal_repository = GoalRepository::new((*connection).clone());
+        let celebration_repository = CelebrationRepository::new((*connection).clone());
+        let period_lock_repository = PeriodLockRepository::new((*connection).clone());
+        let global_config_repository = GlobalConfigRepository::new((*connection).clone());
         let email_service = EmailServiceWrapper::new(email_config)?;
         Ok(Self {
             transaction_repository,
+            audit_log_repository,
+            savings_allocation_repository,
+            loan_repository,
+            advance_repository,
+            reversal_repository,
+            goal_repository,
+            celebration_repository,
+            period_lock_repository,
+            global_config_repository,
+            connection: (*connection).clone(),
             child_service,
             allowance_service,
             balance_service,
-            email_service: Some(email_service),
+            email_service: RwLock::new(Some(email_service)),
         })
     }
 
+    /// Send notifications with a reloaded email config from now on. If the new
+    /// config can't be used, the old one stays in place.
+    pub fn reload_email_config(&self, email_config: EmailConfig) -> Result<()> {
+        let email_service = EmailServiceWrapper::new(email_config)?;
+        *self.email_service.write().unwrap() = Some(email_service);
+        Ok(())
+    }
+
     pub fn create_transaction_domain(
         &self,
         command: CreateTransactionCommand,
+    ) -> Result<DomainTransaction> {
+        self.create_categorized_transaction(command, None)
+    }
+
+    /// Create a transaction already filed under a spending category, as one
+    /// made from a saved template is
+    pub fn create_categorized_transaction(
+        &self,
+        command: CreateTransactionCommand,
+        category: Option<String>,
     ) -> Result<DomainTransaction> {
         // Validate description length here (moving logic from DTO layer)
-        if command.description.is_empty() || command.description.len() > 256 {
-            return Err(anyhow!("Description must be between 1 and 256 characters"));
+        let description = checked_description(&command.description)?;
+        let icon = normalize_icon(command.icon.as_deref())?;
+        if let Some(transaction_type) = &command.transaction_type {
+            if !transaction_type.allows_amount(command.amount) {
+                return Err(DomainError::invalid(format!(
+                    "{} can't have an amount of {:.2}",
+                    transaction_type.label(),
+                    command.amount
+                ))
+                .into());
+            }
         }
 
-        let active_child = self.get_active_child()?;
+        let active_child = self.resolve_child(command.child_id.as_deref())?;
         
         // ✅ FIXED: Use DateTime object directly from command (no parsing needed)
         let transaction_date = command.date.unwrap_or_else(|| {
@@ -82,16 +190,61 @@ impl TransactionService {
             let eastern_offset = chrono::FixedOffset::west_opt(5 * 3600).unwrap(); // EST (UTC-5)
             chrono::Utc::now().with_timezone(&eastern_offset)
         });
+        self.ensure_period_open(&active_child.id, transaction_date)?;
+
+        // Enforce the child's "no negative balance" rule for spends
+        let overdraft = self.check_balance_floor(&active_child.id, transaction_date, command.amount)?;
+        if let Some(error) = &overdraft {
+            if !command.parent_override {
+                warn!("🚫 Rejecting spend of ${:.2} for {}: {}", command.amount.abs(), active_child.id, error);
+                return Err(error.clone().into());
+            }
+            info!("🔓 Parent override: allowing overdraft of ${:.2} for {}", error.shortfall, active_child.id);
+        }
+
+        // An override must leave a trace, so it is logged before the spend is
+        // stored and a failure to log it stops the spend
+        let money = self.money_formatter(&active_child.id)?;
+        let mut id = None;
+        if let Some(error) = overdraft {
+            let transaction_id = new_transaction_id(command.amount)?;
+            let entry = AuditEntry::new(
+                AUDIT_ACTION_OVERDRAFT_OVERRIDE,
+                Some(transaction_id.clone()),
+                format!(
+                    "Parent override: spend of {} overdrew balance by {} (available: {})",
+                    money(command.amount.abs()), money(error.shortfall), money(error.available)
+                ),
+            )
+            .with_actor(command.actor.clone());
+            self.audit_log_repository.append_audit_entry(&active_child.id, &entry)?;
+            id = Some(transaction_id);
+        }
 
         let transaction = self.create_transaction_internal(
             &active_child.id,
             transaction_date,
-            command.description,
+            description,
             command.amount,
+            command.actor,
+            TransactionTags { id, category, icon, transaction_type: command.transaction_type },
         )?;
 
+        // Attributed entries are logged so a disputed transaction can be traced back
+        if let Some(actor) = &transaction.actor {
+            let entry = AuditEntry::new(
+                AUDIT_ACTION_TRANSACTION_ADDED,
+                Some(transaction.id.clone()),
+                format!("{} added \"{}\" ({})", actor.label(), transaction.description, money(transaction.amount)),
+            )
+            .with_actor(Some(actor.clone()));
+            if let Err(e) = self.audit_log_repository.append_audit_entry(&active_child.id, &entry) {
+                error!("Failed to record new transaction in audit log: {}", e);
+            }
+        }
+
         // Send email notification if email service is configured
-        if let Some(email_service) = &self.email_service {
+        if let Some(email_service) = self.email_service.read().unwrap().as_ref() {
             log::info!("📧 Email service is configured, sending notification for transaction: {}", transaction.id);
             let action = if transaction.amount >= 0.0 { "earned" } else { "spent" };
             let current_balance = self.balance_service.get_current_balance(&active_child.id)?;
@@ -108,6 +261,123 @@ impl TransactionService {
         Ok(transaction)
     }
 
+    /// Record already-happened transactions for the active child, such as rows
+    /// of a bank statement, oldest first.
+    ///
+    /// Rows carry a date but no time, so they are recorded at the allowance
+    /// time, a second apart to keep their order within a day. The "no negative
+    /// balance" rule isn't applied to history, and one audit entry covers the
+    /// whole import instead of an email per row.
+    pub fn import_transactions(
+        &self,
+        rows: Vec<ImportRow>,
+        actor: Option<Actor>,
+    ) -> Result<Vec<DomainTransaction>> {
+        // Each row rewrites the transactions file, so write it once at the end
+        let description = format!("Imported {} transactions", rows.len());
+        self.connection.batch_writes(&description, || self.import_rows(rows, actor))
+    }
+
+    fn import_rows(
+        &self,
+        rows: Vec<ImportRow>,
+        actor: Option<Actor>,
+    ) -> Result<Vec<DomainTransaction>> {
+        let active_child = self.get_active_child()?;
+        let mut rows = rows;
+        rows.sort_by_key(|row| row.date);
+        if let Some(first) = rows.first() {
+            self.ensure_period_open(&active_child.id, Self::allowance_datetime(first.date))?;
+        }
+
+        let mut imported = Vec::with_capacity(rows.len());
+        let mut previous_date = None;
+        let mut seconds_into_day = 0;
+        for ImportRow { date, description, amount, category } in rows {
+            let description = checked_description(&description)?;
+            seconds_into_day = if previous_date == Some(date) { seconds_into_day + 1 } else { 0 };
+            previous_date = Some(date);
+            let transaction_date = Self::allowance_datetime(date) + chrono::Duration::seconds(seconds_into_day);
+            imported.push(self.create_transaction_internal(
+                &active_child.id,
+                transaction_date,
+                description,
+                amount,
+                actor.clone(),
+                TransactionTags { category, ..TransactionTags::default() },
+            )?);
+        }
+
+        if !imported.is_empty() {
+            let entry = AuditEntry::new(
+                AUDIT_ACTION_TRANSACTIONS_IMPORTED,
+                None,
+                format!("Imported {} transactions from a statement", imported.len()),
+            )
+            .with_actor(actor);
+            if let Err(e) = self.audit_log_repository.append_audit_entry(&active_child.id, &entry) {
+                error!("Failed to record statement import in audit log: {}", e);
+            }
+        }
+        info!("📥 Imported {} transactions for {}", imported.len(), active_child.id);
+        Ok(imported)
+    }
+
+    /// Formats amounts in the child's currency and locale, for audit entries
+    fn money_formatter(&self, child_id: &str) -> Result<impl Fn(f64) -> String> {
+        let settings = self.child_service.get_child_settings(child_id)?.settings;
+        let config = self.global_config_repository.get_global_config()?;
+        let currency = settings.currency.unwrap_or(config.currency);
+        let locale = settings.locale.unwrap_or(config.locale);
+        Ok(move |amount: f64| currency.format(amount, locale))
+    }
+
+    /// Check whether a spend would break the child's "no negative balance" rule.
+    /// Returns the shortfall if it would, or `None` if the spend is allowed.
+    /// The running balance is checked on the transaction date and on every
+    /// later row up to today, so a backdated spend can't overdraw a day in
+    /// between either.
+    fn check_balance_floor(
+        &self,
+        child_id: &str,
+        date: chrono::DateTime<chrono::FixedOffset>,
+        amount: f64,
+    ) -> Result<Option<InsufficientFundsError>> {
+        if amount >= 0.0 {
+            return Ok(None);
+        }
+
+        let settings = self.child_service.get_child_settings(child_id)?.settings;
+        if !settings.prevent_negative_balance {
+            return Ok(None);
+        }
+
+        // Every row after the spend loses the amount as well
+        let balance_at_date = self
+            .balance_service
+            .calculate_balance_for_new_transaction(child_id, &date.to_rfc3339(), amount)?;
+        let balance_now = self.balance_service.get_current_balance(child_id)? + amount;
+        let lowest_balance = self
+            .transaction_repository
+            .get_transactions_since(child_id, &date.to_rfc3339())?
+            .iter()
+            .filter(|tx| tx.date > date)
+            .filter(|tx| tx.transaction_type != DomainTransactionType::FutureAllowance)
+            .map(|tx| tx.balance + amount)
+            .fold(balance_at_date.min(balance_now), f64::min);
+
+        // Round to cents to avoid floating point noise rejecting exact spends
+        let shortfall = ((-lowest_balance) * 100.0).round() / 100.0;
+        if shortfall <= 0.0 {
+            return Ok(None);
+        }
+
+        Ok(Some(InsufficientFundsError {
+            shortfall,
+            available: ((lowest_balance - amount) * 100.0).round() / 100.0,
+        }))
+    }
+
     /// Private unified function for creating any transaction
     fn create_transaction_internal(
         &self,
@@ -115,9 +385,74 @@ impl TransactionService {
         date: chrono::DateTime<chrono::FixedOffset>,
         description: String,
         amount: f64,
+        actor: Option<Actor>,
+        tags: TransactionTags,
+    ) -> Result<DomainTransaction> {
+        let domain_transaction = self.store_new_transaction(child_id, date, description, amount, actor, tags)?;
+
+        if amount > 0.0 {
+            // Savings allocation is best-effort and must never fail the income itself
+            if let Err(e) = self.allocate_savings(child_id, &domain_transaction) {
+                error!("Failed to record savings allocation for {}: {}", domain_transaction.id, e);
+            }
+            // ...and so are celebrations
+            if let Err(e) = self.record_milestones(child_id, &domain_transaction) {
+                error!("Failed to record balance milestone for {}: {}", domain_transaction.id, e);
+            }
+        } else if amount < 0.0 {
+            // Round-ups are best-effort in the same way and never block the spend
+            if let Err(e) = self.allocate_round_up(child_id, &domain_transaction) {
+                error!("Failed to record round-up for {}: {}", domain_transaction.id, e);
+            }
+        }
+
+        Ok(domain_transaction)
+    }
+
+    /// Add any milestones income takes the child's balance past for the
+    /// first time to their history, celebrating the highest of them
+    fn record_milestones(&self, child_id: &str, transaction: &DomainTransaction) -> Result<()> {
+        let thresholds = self.child_service.get_child_settings(child_id)?.settings.milestone_thresholds();
+        let already_reached: Vec<f64> = self
+            .celebration_repository
+            .list_reached_milestones(child_id)?
+            .iter()
+            .map(|milestone| milestone.amount)
+            .collect();
+        let balance = self.balance_service.get_current_balance(child_id)?;
+        let crossed = milestones_crossed(balance - transaction.amount, balance, &thresholds, &already_reached);
+        let Some(&highest) = crossed.last() else {
+            return Ok(());
+        };
+
+        let reached: Vec<ReachedMilestone> = crossed
+            .iter()
+            .map(|&amount| ReachedMilestone {
+                amount,
+                reached_on: transaction.date.date_naive(),
+                transaction_id: transaction.id.clone(),
+            })
+            .collect();
+        self.celebration_repository.record_reached_milestones(child_id, &reached)?;
+        self.celebration_repository.record_celebration(&Celebration::balance_milestone(child_id, highest))?;
+        Ok(())
+    }
+
+    /// Store a transaction and bring later balances up to date, without
+    /// earmarking any savings from it
+    fn store_new_transaction(
+        &self,
+        child_id: &str,
+        date: chrono::DateTime<chrono::FixedOffset>,
+        description: String,
+        amount: f64,
+        actor: Option<Actor>,
+        tags: TransactionTags,
     ) -> Result<DomainTransaction> {
-        let now_millis = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
-        let transaction_id = DomainTransaction::generate_id(amount, now_millis);
+        let transaction_id = match tags.id {
+            Some(id) => id,
+            None => new_transaction_id(amount)?,
+        };
 
         let transaction_balance = self
             .balance_service
@@ -134,11 +469,10 @@ impl TransactionService {
             description,
             amount,
             balance: transaction_balance,
-            transaction_type: if amount >= 0.0 {
-                DomainTransactionType::Income
-            } else {
-                DomainTransactionType::Expense
-            },
+            transaction_type: tags.transaction_type.unwrap_or_else(|| DomainTransactionType::from_amount(amount)),
+            actor,
+            category: tags.category,
+            icon: tags.icon,
         };
 
         self.transaction_repository
@@ -148,13 +482,593 @@ impl TransactionService {
             .balance_service
             .requires_balance_recalculation(child_id, &date.to_rfc3339())?
         {
-            self.balance_service
+            let recalculated = self
+                .balance_service
                 .recalculate_balances_from_date(child_id, &date.to_rfc3339())?;
+            info!("📅 Backdated {} for {}: recalculated {} balances", domain_transaction.id, child_id, recalculated);
         }
 
         Ok(domain_transaction)
     }
 
+    /// Undo a transaction of the active child with an offsetting entry, such
+    /// as a refunded purchase or a returned gift, and link the two.
+    ///
+    /// The original stays in the history. The new entry has the opposite
+    /// amount and the same category, so category totals net out, and any
+    /// savings earmarked from the original are released. A transaction can
+    /// only be reversed once, and a reversal can't itself be reversed.
+    pub fn reverse_transaction(&self, command: ReverseTransactionCommand) -> Result<ReverseTransactionResult> {
+        let active_child = self.resolve_child(command.child_id.as_deref())?;
+        let original = self
+            .transaction_repository
+            .get_transaction(&active_child.id, &command.transaction_id)?
+            .ok_or_else(|| DomainError::NotFound(format!("Transaction not found: {}", command.transaction_id)))?;
+        if original.transaction_type == DomainTransactionType::FutureAllowance {
+            return Err(DomainError::invalid("A future allowance hasn't been paid yet, so it can't be reversed").into());
+        }
+        if let Some(existing) = self
+            .reversal_repository
+            .list_reversals(&active_child.id)?
+            .into_iter()
+            .find(|reversal| reversal.involves(&original.id))
+        {
+            let problem = if existing.original_id == original.id { "has already been reversed" } else { "is itself a reversal" };
+            return Err(DomainError::Conflict(format!("\"{}\" {}", original.description, problem)).into());
+        }
+
+        let date = command.date.unwrap_or_else(|| {
+            let eastern_offset = chrono::FixedOffset::west_opt(5 * 3600).unwrap(); // EST (UTC-5)
+            chrono::Utc::now().with_timezone(&eastern_offset)
+        });
+        if date < original.date {
+            return Err(DomainError::invalid("A reversal can't be dated before the transaction it reverses").into());
+        }
+        self.ensure_period_open(&active_child.id, date)?;
+        let amount = -original.amount;
+
+        // Giving back income takes money out, so the balance floor still applies
+        let overdraft = self.check_balance_floor(&active_child.id, date, amount)?;
+        if let Some(error) = overdraft {
+            if !command.parent_override {
+                warn!("🚫 Rejecting reversal of {} for {}: {}", original.id, active_child.id, error);
+                return Err(error.into());
+            }
+            info!("🔓 Parent override: allowing reversal to overdraw by ${:.2} for {}", error.shortfall, active_child.id);
+        }
+
+        let reason = command.reason.map(|reason| reason.trim().to_string()).filter(|reason| !reason.is_empty());
+        let reversal = self.store_new_transaction(
+            &active_child.id,
+            date,
+            reversal_description(&original.description, reason.as_deref()),
+            amount,
+            command.actor.clone(),
+            TransactionTags {
+                category: original.category.clone(),
+                icon: original.icon.clone(),
+                transaction_type: Some(DomainTransactionType::Adjustment),
+                ..TransactionTags::default()
+            },
+        )?;
+        let link = TransactionReversal {
+            child_id: active_child.id.clone(),
+            original_id: original.id.clone(),
+            reversal_id: reversal.id.clone(),
+            date,
+            reason,
+        };
+        self.reversal_repository.store_reversal(&link)?;
+        self.savings_allocation_repository
+            .delete_allocations_for_transactions(&active_child.id, std::slice::from_ref(&original.id))?;
+
+        let entry = AuditEntry::new(
+            AUDIT_ACTION_TRANSACTION_REVERSED,
+            Some(original.id.clone()),
+            format!(
+                "{} reversed \"{}\" (${:.2}) dated {}",
+                command.actor.as_ref().map(Actor::label).unwrap_or_else(|| "Someone".to_string()),
+                original.description,
+                original.amount,
+                original.date.format("%Y-%m-%d")
+            ),
+        )
+        .with_actor(command.actor);
+        if let Err(e) = self.audit_log_repository.append_audit_entry(&active_child.id, &entry) {
+            error!("Failed to record reversal in audit log: {}", e);
+        }
+
+        info!("↩️ Reversed {} with {} (${:.2}) for {}", original.id, reversal.id, amount, active_child.id);
+        Ok(ReverseTransactionResult { original, reversal, link })
+    }
+
+    /// Change a stored transaction's date or description, e.g. after it was
+    /// dragged to another day on the calendar. The amount never changes.
+    ///
+    /// Neither the old nor the new date may be in a closed period. Moving a
+    /// transaction rewrites every balance from the earlier of the two dates.
+    pub fn edit_transaction(&self, command: EditTransactionCommand) -> Result<EditTransactionResult> {
+        let child = self.resolve_child(command.child_id.as_deref())?;
+        let original = self
+            .transaction_repository
+            .get_transaction(&child.id, &command.transaction_id)?
+            .ok_or_else(|| DomainError::NotFound(format!("Transaction not found: {}", command.transaction_id)))?;
+        if original.transaction_type == DomainTransactionType::FutureAllowance {
+            return Err(DomainError::invalid("A future allowance hasn't been paid yet, so it can't be edited").into());
+        }
+        self.ensure_period_open(&child.id, original.date)?;
+
+        let mut edited = original.clone();
+        if let Some(description) = &command.description {
+            edited.description = checked_description(description)?;
+        }
+        if let Some(date) = command.date {
+            self.ensure_period_open(&child.id, date)?;
+            if let Some(error) = self.check_balance_floor_for_move(&child.id, &original, date)? {
+                if !command.parent_override {
+                    warn!("🚫 Rejecting move of {} for {}: {}", original.id, child.id, error);
+                    return Err(error.into());
+                }
+                info!("🔓 Parent override: allowing move to overdraw by ${:.2} for {}", error.shortfall, child.id);
+            }
+            edited.date = date;
+        }
+        if edited == original {
+            return Ok(EditTransactionResult { transaction: edited, original, recalculated_rows: 0 });
+        }
+
+        self.transaction_repository.update_transaction(&edited)?;
+        let recalculated_rows = if edited.date != original.date {
+            let from = original.date.min(edited.date);
+            self.balance_service.recalculate_balances_from_date(&child.id, &from.to_rfc3339())?
+        } else {
+            0
+        };
+        let transaction = self
+            .transaction_repository
+            .get_transaction(&child.id, &edited.id)?
+            .unwrap_or(edited);
+
+        let change = if transaction.date != original.date {
+            format!(
+                "moved \"{}\" (${:.2}) from {} to {}",
+                original.description,
+                original.amount,
+                original.date.format("%Y-%m-%d"),
+                transaction.date.format("%Y-%m-%d")
+            )
+        } else {
+            format!("renamed \"{}\" to \"{}\"", original.description, transaction.description)
+        };
+        let entry = AuditEntry::new(
+            AUDIT_ACTION_TRANSACTION_EDITED,
+            Some(original.id.clone()),
+            format!("{} {}", command.actor.as_ref().map(Actor::label).unwrap_or_else(|| "Someone".to_string()), change),
+        )
+        .with_actor(command.actor);
+        if let Err(e) = self.audit_log_repository.append_audit_entry(&child.id, &entry) {
+            error!("Failed to record edit in audit log: {}", e);
+        }
+
+        info!("✏️ Edited {} for {}: recalculated {} balances", transaction.id, child.id, recalculated_rows);
+        Ok(EditTransactionResult { original, transaction, recalculated_rows })
+    }
+
+    /// Copy a transaction as a new one dated now, for repeat purchases like
+    /// the weekly lunch money. The description, amount, category and icon
+    /// are copied; kinds that only the app records, such as an allowance
+    /// payment or a reversal, become plain income or expense.
+    pub fn duplicate_transaction(&self, command: DuplicateTransactionCommand) -> Result<DuplicateTransactionResult> {
+        let child = self.resolve_child(command.child_id.as_deref())?;
+        let original = self
+            .transaction_repository
+            .get_transaction(&child.id, &command.transaction_id)?
+            .ok_or_else(|| DomainError::NotFound(format!("Transaction not found: {}", command.transaction_id)))?;
+        if original.transaction_type == DomainTransactionType::FutureAllowance {
+            return Err(DomainError::invalid("A future allowance hasn't been paid yet, so it can't be duplicated").into());
+        }
+
+        let eastern_offset = chrono::FixedOffset::west_opt(5 * 3600).unwrap(); // EST (UTC-5)
+        let date = chrono::Utc::now().with_timezone(&eastern_offset);
+        self.ensure_period_open(&child.id, date)?;
+        if let Some(error) = self.check_balance_floor(&child.id, date, original.amount)? {
+            if !command.parent_override {
+                warn!("🚫 Rejecting copy of {} for {}: {}", original.id, child.id, error);
+                return Err(error.into());
+            }
+            info!("🔓 Parent override: allowing copy to overdraw by ${:.2} for {}", error.shortfall, child.id);
+        }
+
+        let transaction_type = match original.transaction_type {
+            DomainTransactionType::Bonus | DomainTransactionType::Penalty | DomainTransactionType::Gift => Some(original.transaction_type.clone()),
+            _ => None,
+        };
+        let transaction = self.create_transaction_internal(
+            &child.id,
+            date,
+            original.description.clone(),
+            original.amount,
+            command.actor.clone(),
+            TransactionTags {
+                category: original.category.clone(),
+                icon: original.icon.clone(),
+                transaction_type,
+                ..TransactionTags::default()
+            },
+        )?;
+
+        let entry = AuditEntry::new(
+            AUDIT_ACTION_TRANSACTION_ADDED,
+            Some(transaction.id.clone()),
+            format!(
+                "{} duplicated \"{}\" (${:.2}) from {}",
+                command.actor.as_ref().map(Actor::label).unwrap_or_else(|| "Someone".to_string()),
+                original.description,
+                original.amount,
+                original.date.format("%Y-%m-%d")
+            ),
+        )
+        .with_actor(command.actor);
+        if let Err(e) = self.audit_log_repository.append_audit_entry(&child.id, &entry) {
+            error!("Failed to record duplicated transaction in audit log: {}", e);
+        }
+
+        info!("📄 Duplicated {} as {} for {}", original.id, transaction.id, child.id);
+        Ok(DuplicateTransactionResult { original, transaction })
+    }
+
+    /// Give every transaction matching a filter the same category, e.g. all
+    /// the "allowance" ones after years of uncategorized history. A preview
+    /// stores nothing; its counts say what applying it would change.
+    ///
+    /// Transactions in a closed period are left alone and counted apart.
+    /// The result lists each changed transaction's earlier category, which
+    /// `undo_bulk_categorize` puts back.
+    pub fn bulk_categorize(&self, command: BulkCategorizeCommand) -> Result<BulkCategorizeResult> {
+        let child = self.resolve_child(command.child_id.as_deref())?;
+        let filter = &command.filter;
+        let needle = filter.description_contains.trim().to_lowercase();
+        if needle.is_empty() && !filter.uncategorized_only && filter.start_date.is_none() && filter.end_date.is_none() {
+            return Err(DomainError::invalid(
+                "Pick which transactions to change: some description text, a date range or only uncategorized ones",
+            )
+            .into());
+        }
+        let category = command
+            .category
+            .as_deref()
+            .map(str::trim)
+            .filter(|category| !category.is_empty())
+            .map(str::to_string);
+
+        let lock = self.period_lock_repository.get_period_lock(&child.id)?;
+        let matching: Vec<DomainTransaction> = self
+            .transaction_repository
+            .list_transactions_chronological(&child.id, None, None)?
+            .into_iter()
+            .rev()
+            .filter(|transaction| needle.is_empty() || transaction.description.to_lowercase().contains(&needle))
+            .filter(|transaction| !filter.uncategorized_only || transaction.category.is_none())
+            .filter(|transaction| filter.start_date.is_none_or(|start| transaction.date.date_naive() >= start))
+            .filter(|transaction| filter.end_date.is_none_or(|end| transaction.date.date_naive() <= end))
+            .collect();
+        let matched = matching.len();
+        let (closed, open): (Vec<_>, Vec<_>) = matching
+            .into_iter()
+            .partition(|transaction| lock.as_ref().is_some_and(|lock| lock.covers(transaction.date)));
+        let to_change: Vec<DomainTransaction> = open
+            .into_iter()
+            .filter(|transaction| transaction.category != category)
+            .collect();
+
+        let mut result = BulkCategorizeResult {
+            child_id: child.id.clone(),
+            matched,
+            skipped_closed: closed.len(),
+            sample: to_change.iter().take(BULK_CATEGORY_SAMPLE_SIZE).cloned().collect(),
+            changes: Vec::new(),
+            would_change: to_change.len(),
+        };
+        if command.preview || to_change.is_empty() {
+            return Ok(result);
+        }
+
+        let updates: Vec<(String, Option<String>)> = to_change
+            .iter()
+            .map(|transaction| (transaction.id.clone(), category.clone()))
+            .collect();
+        self.transaction_repository.update_transaction_categories(&child.id, &updates)?;
+        result.changes = to_change
+            .into_iter()
+            .map(|transaction| CategoryChange { transaction_id: transaction.id, previous_category: transaction.category })
+            .collect();
+
+        let change = match &category {
+            Some(category) => format!("filed {} transactions under \"{}\"", result.changes.len(), category),
+            None => format!("cleared the category of {} transactions", result.changes.len()),
+        };
+        let entry = AuditEntry::new(
+            AUDIT_ACTION_TRANSACTIONS_RECATEGORIZED,
+            None,
+            format!("{} {}", command.actor.as_ref().map(Actor::label).unwrap_or_else(|| "Someone".to_string()), change),
+        )
+        .with_actor(command.actor);
+        if let Err(e) = self.audit_log_repository.append_audit_entry(&child.id, &entry) {
+            error!("Failed to record category change in audit log: {}", e);
+        }
+
+        info!("🏷️ Recategorized {} of {} matching transactions for {}", result.changes.len(), matched, child.id);
+        Ok(result)
+    }
+
+    /// Put back the categories a bulk change replaced. Transactions deleted
+    /// or closed since then are skipped; returns how many were restored.
+    pub fn undo_bulk_categorize(&self, command: UndoBulkCategorizeCommand) -> Result<u32> {
+        let child = self.resolve_child(Some(&command.child_id))?;
+        let lock = self.period_lock_repository.get_period_lock(&child.id)?;
+        let ids: Vec<String> = command.changes.iter().map(|change| change.transaction_id.clone()).collect();
+        let still_open: HashSet<String> = self
+            .transaction_repository
+            .list_transactions_by_ids(&child.id, &ids)?
+            .into_iter()
+            .filter(|transaction| !lock.as_ref().is_some_and(|lock| lock.covers(transaction.date)))
+            .map(|transaction| transaction.id)
+            .collect();
+        let updates: Vec<(String, Option<String>)> = command
+            .changes
+            .into_iter()
+            .filter(|change| still_open.contains(&change.transaction_id))
+            .map(|change| (change.transaction_id, change.previous_category))
+            .collect();
+        let restored = self.transaction_repository.update_transaction_categories(&child.id, &updates)?;
+
+        let entry = AuditEntry::new(
+            AUDIT_ACTION_TRANSACTIONS_RECATEGORIZED,
+            None,
+            format!(
+                "{} undid a category change on {} transactions",
+                command.actor.as_ref().map(Actor::label).unwrap_or_else(|| "Someone".to_string()),
+                restored
+            ),
+        )
+        .with_actor(command.actor);
+        if let Err(e) = self.audit_log_repository.append_audit_entry(&child.id, &entry) {
+            error!("Failed to record undone category change in audit log: {}", e);
+        }
+
+        info!("🏷️ Restored the categories of {} of {} transactions for {}", restored, ids.len(), child.id);
+        Ok(restored)
+    }
+
+    /// Check whether moving a spend to an earlier `date` would break the
+    /// child's "no negative balance" rule on the days it now counts on.
+    /// Moving income, or moving anything later, can't lower a balance.
+    fn check_balance_floor_for_move(
+        &self,
+        child_id: &str,
+        original: &DomainTransaction,
+        date: chrono::DateTime<chrono::FixedOffset>,
+    ) -> Result<Option<InsufficientFundsError>> {
+        if original.amount >= 0.0 || date >= original.date {
+            return Ok(None);
+        }
+        let settings = self.child_service.get_child_settings(child_id)?.settings;
+        if !settings.prevent_negative_balance {
+            return Ok(None);
+        }
+
+        // Rows between the new and old dates each lose the spend
+        let moved_balance = self
+            .balance_service
+            .calculate_balance_for_new_transaction(child_id, &date.to_rfc3339(), original.amount)?;
+        let lowest_balance = self
+            .transaction_repository
+            .get_transactions_since(child_id, &date.to_rfc3339())?
+            .iter()
+            .filter(|tx| tx.id != original.id && tx.date > date && tx.date < original.date)
+            .filter(|tx| tx.transaction_type != DomainTransactionType::FutureAllowance)
+            .map(|tx| tx.balance + original.amount)
+            .fold(moved_balance, f64::min);
+
+        // Round to cents to avoid floating point noise rejecting exact spends
+        let shortfall = ((-lowest_balance) * 100.0).round() / 100.0;
+        if shortfall <= 0.0 {
+            return Ok(None);
+        }
+        Ok(Some(InsufficientFundsError {
+            shortfall,
+            available: ((lowest_balance - original.amount) * 100.0).round() / 100.0,
+        }))
+    }
+
+    /// Reversal links for a child, oldest first
+    pub fn list_reversals(&self, child_id: &str) -> Result<Vec<TransactionReversal>> {
+        self.reversal_repository.list_reversals(child_id)
+    }
+
+    /// Lock a child's transactions up to and including a date, typically the
+    /// end of a month that has been reviewed. Locked transactions can't be
+    /// deleted and nothing new can be dated into the period until a parent
+    /// reopens it. A closed period can be extended, but not shortened.
+    pub fn close_period(&self, command: ClosePeriodCommand) -> Result<ClosePeriodResult> {
+        let child_id = self.resolve_child_id(command.child_id)?;
+        if command.locked_through > Local::now().date_naive() {
+            return Err(DomainError::invalid("A period can't be closed before it has ended").into());
+        }
+        if let Some(existing) = self.period_lock_repository.get_period_lock(&child_id)? {
+            if command.locked_through < existing.locked_through {
+                return Err(DomainError::Conflict(format!(
+                    "Transactions are already closed through {}. Reopen them first to close an earlier date.",
+                    existing.locked_through.format("%Y-%m-%d")
+                ))
+                .into());
+            }
+        }
+
+        let lock = PeriodLock {
+            child_id: child_id.clone(),
+            locked_through: command.locked_through,
+            closed_at: chrono::Utc::now(),
+            closed_by: command.actor.clone(),
+        };
+        self.period_lock_repository.store_period_lock(&lock)?;
+        let locked_count = self
+            .transaction_repository
+            .list_transactions_chronological(&child_id, None, None)?
+            .iter()
+            .filter(|tx| lock.covers(tx.date))
+            .count();
+
+        let entry = AuditEntry::new(
+            AUDIT_ACTION_PERIOD_CLOSED,
+            None,
+            format!(
+                "{} closed transactions through {} ({} locked)",
+                command.actor.as_ref().map(Actor::label).unwrap_or_else(|| "Someone".to_string()),
+                lock.locked_through.format("%Y-%m-%d"),
+                locked_count
+            ),
+        )
+        .with_actor(command.actor);
+        if let Err(e) = self.audit_log_repository.append_audit_entry(&child_id, &entry) {
+            error!("Failed to record closed period in audit log: {}", e);
+        }
+
+        Ok(ClosePeriodResult { lock, locked_count })
+    }
+
+    /// Unlock a child's closed period so its transactions can be changed again
+    pub fn reopen_period(&self, command: ReopenPeriodCommand) -> Result<ReopenPeriodResult> {
+        let child_id = self.resolve_child_id(command.child_id)?;
+        let previous_lock = self.period_lock_repository.get_period_lock(&child_id)?;
+        let Some(lock) = &previous_lock else {
+            return Ok(ReopenPeriodResult { previous_lock });
+        };
+        self.period_lock_repository.clear_period_lock(&child_id)?;
+
+        let entry = AuditEntry::new(
+            AUDIT_ACTION_PERIOD_REOPENED,
+            None,
+            format!(
+                "{} reopened transactions closed through {}",
+                command.actor.as_ref().map(Actor::label).unwrap_or_else(|| "Someone".to_string()),
+                lock.locked_through.format("%Y-%m-%d")
+            ),
+        )
+        .with_actor(command.actor);
+        if let Err(e) = self.audit_log_repository.append_audit_entry(&child_id, &entry) {
+            error!("Failed to record reopened period in audit log: {}", e);
+        }
+
+        Ok(ReopenPeriodResult { previous_lock })
+    }
+
+    /// How far a child's transactions are closed, if they are
+    pub fn get_period_lock(&self, child_id: &str) -> Result<Option<PeriodLock>> {
+        self.period_lock_repository.get_period_lock(child_id)
+    }
+
+    /// Refuse a change dated in the child's closed period
+    fn ensure_period_open(&self, child_id: &str, date: chrono::DateTime<chrono::FixedOffset>) -> Result<()> {
+        match self.period_lock_repository.get_period_lock(child_id)? {
+            Some(lock) if lock.covers(date) => Err(DomainError::Conflict(format!(
+                "Transactions through {} are closed. Reopen that period to change it.",
+                lock.locked_through.format("%Y-%m-%d")
+            ))
+            .into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn resolve_child_id(&self, child_id: Option<String>) -> Result<String> {
+        match child_id {
+            Some(id) => Ok(id),
+            None => Ok(self.get_active_child()?.id),
+        }
+    }
+
+    /// Earmark the child's configured "pay yourself first" percentage of an
+    /// income transaction to savings (or the active goal), recording a linked
+    /// allocation. Returns `None` when auto-saving is disabled.
+    fn allocate_savings(
+        &self,
+        child_id: &str,
+        transaction: &DomainTransaction,
+    ) -> Result<Option<SavingsAllocation>> {
+        let settings = self.child_service.get_child_settings(child_id)?.settings;
+        if settings.auto_save_percentage <= 0.0 {
+            return Ok(None);
+        }
+
+        let amount = SavingsAllocation::calculate_amount(transaction.amount, settings.auto_save_percentage);
+        if amount <= 0.0 {
+            return Ok(None);
+        }
+
+        let goal_id = if settings.auto_save_to_goal {
+            self.goal_repository.get_current_goal(child_id)?.map(|goal| goal.id)
+        } else {
+            None
+        };
+
+        let allocation = SavingsAllocation {
+            id: SavingsAllocation::generate_id(&transaction.id),
+            transaction_id: transaction.id.clone(),
+            date: transaction.date,
+            amount,
+            percentage: settings.auto_save_percentage,
+            goal_id,
+            source: SavingsSource::AutoSave,
+        };
+        self.savings_allocation_repository.store_allocation(child_id, &allocation)?;
+
+        info!("💰 Auto-saved ${:.2} ({}%) from {} to {}",
+              amount, settings.auto_save_percentage, transaction.id,
+              allocation.goal_id.as_deref().unwrap_or("savings"));
+        Ok(Some(allocation))
+    }
+
+    /// Round a spend up to the next whole dollar and earmark the change to
+    /// savings (or the active goal), recording a linked allocation. Returns
+    /// `None` when round-ups are disabled or the spend is already whole.
+    fn allocate_round_up(
+        &self,
+        child_id: &str,
+        transaction: &DomainTransaction,
+    ) -> Result<Option<SavingsAllocation>> {
+        let settings = self.child_service.get_child_settings(child_id)?.settings;
+        if !settings.round_up_spends {
+            return Ok(None);
+        }
+
+        let amount = SavingsAllocation::calculate_round_up(transaction.amount);
+        if amount <= 0.0 {
+            return Ok(None);
+        }
+
+        let goal_id = if settings.auto_save_to_goal {
+            self.goal_repository.get_current_goal(child_id)?.map(|goal| goal.id)
+        } else {
+            None
+        };
+
+        let allocation = SavingsAllocation {
+            id: SavingsAllocation::generate_round_up_id(&transaction.id),
+            transaction_id: transaction.id.clone(),
+            date: transaction.date,
+            amount,
+            percentage: 0.0,
+            goal_id,
+            source: SavingsSource::RoundUp,
+        };
+        self.savings_allocation_repository.store_allocation(child_id, &allocation)?;
+
+        info!("💰 Rounded up ${:.2} from {} to {}",
+              amount, transaction.id,
+              allocation.goal_id.as_deref().unwrap_or("savings"));
+        Ok(Some(allocation))
+    }
+
     pub fn create_transaction(
         &self,
         cmd: CreateTransactionCommand,
@@ -162,11 +1076,50 @@ impl TransactionService {
         self.create_transaction_domain(cmd)
     }
 
+    /// What a transaction dated `date` changes further down a child's
+    /// history: the later rows whose balances get recalculated, how many of
+    /// those are allowances, and whether the current goal was set after
+    /// `date`. `None` when nothing comes after `date`, so nothing is rewritten.
+    ///
+    /// Call it before storing the transaction; the new row itself isn't counted.
+    pub fn backdate_impact(
+        &self,
+        child_id: &str,
+        date: chrono::DateTime<chrono::FixedOffset>,
+    ) -> Result<Option<BackdateImpact>> {
+        let later: Vec<DomainTransaction> = self
+            .transaction_repository
+            .get_transactions_since(child_id, &date.to_rfc3339())?
+            .into_iter()
+            .filter(|tx| tx.date > date && tx.transaction_type != DomainTransactionType::FutureAllowance)
+            .collect();
+        if later.is_empty() {
+            return Ok(None);
+        }
+
+        // Allowances are recognised the same way the allowance checker does
+        let allowances_after = later
+            .iter()
+            .filter(|tx| tx.amount > 0.0 && tx.description.to_lowercase().contains("allowance"))
+            .count();
+        let goal_affected = self
+            .goal_repository
+            .get_current_goal(child_id)?
+            .and_then(|goal| chrono::DateTime::parse_from_rfc3339(&goal.created_at).ok())
+            .is_some_and(|created_at| created_at > date);
+
+        Ok(Some(BackdateImpact {
+            recalculated_rows: later.len(),
+            allowances_after,
+            goal_affected,
+        }))
+    }
+
     pub fn list_transactions_domain(
         &self,
         query: TransactionListQuery,
     ) -> Result<TransactionListResult> {
-        let active_child = self.get_active_child()?;
+        let active_child = self.resolve_child(query.child_id.as_deref())?;
 
         let limit = query.limit.unwrap_or(20);
         let query_limit = limit + 1;
@@ -211,6 +1164,7 @@ impl TransactionService {
         Ok(TransactionListResult {
             transactions: db_transactions,
             pagination: DomainPagination { has_more, next_cursor },
+            period_lock: self.period_lock_repository.get_period_lock(&active_child.id)?,
         })
     }
 
@@ -221,6 +1175,39 @@ impl TransactionService {
         self.list_transactions_domain(query)
     }
 
+    /// Find a child's transactions whose description or category contains the
+    /// query text, newest first. An empty query finds nothing.
+    pub fn search_transactions(&self, query: TransactionSearchQuery) -> Result<TransactionSearchResult> {
+        let child = self.resolve_child(query.child_id.as_deref())?;
+        let needle = query.text.trim().to_lowercase();
+        if needle.is_empty() {
+            return Ok(TransactionSearchResult { transactions: Vec::new(), has_more: false });
+        }
+
+        let mut transactions: Vec<DomainTransaction> = self
+            .transaction_repository
+            .list_transactions_chronological(&child.id, None, None)?
+            .into_iter()
+            .rev()
+            .filter(|transaction| {
+                transaction.description.to_lowercase().contains(&needle)
+                    || transaction.category.as_deref().is_some_and(|category| category.to_lowercase().contains(&needle))
+            })
+            .take(query.limit + 1)
+            .collect();
+        let has_more = transactions.len() > query.limit;
+        transactions.truncate(query.limit);
+
+        Ok(TransactionSearchResult { transactions, has_more })
+    }
+
+    /// List every stored transaction for a specific child in chronological order.
+    /// Unlike `list_transactions_domain` this does not depend on the active child.
+    pub fn list_all_transactions_for_child(&self, child_id: &str) -> Result<Vec<DomainTransaction>> {
+        self.transaction_repository
+            .list_transactions_chronological(child_id, None, None)
+    }
+
 
 
     /// List transactions for calendar display, including future allowances
@@ -232,8 +1219,7 @@ impl TransactionService {
     ) -> Result<CalendarTransactionsResult> {
         info!("🗓️ Getting transactions for calendar: month={}, year={}", query.month, query.year);
 
-        // Get the active child
-        let active_child = self.get_active_child()?;
+        let active_child = self.resolve_child(query.child_id.as_deref())?;
 
         // Calculate days in month for end date
         let days_in_month = match query.month {
@@ -248,7 +1234,7 @@ impl TransactionService {
                     28
                 }
             }
-            _ => return Err(anyhow!("Invalid month: {}", query.month)),
+            _ => return Err(DomainError::invalid(format!("Invalid month: {}", query.month)).into()),
         };
 
         // Get historical transactions up to the end of the requested month
@@ -256,6 +1242,7 @@ impl TransactionService {
         info!("🗓️ Fetching historical transactions up to: {}", end_date);
 
         let transaction_query = TransactionListQuery {
+            child_id: Some(active_child.id.clone()),
             after: None,
             limit: Some(10000), // Get all transactions for calendar
             start_date: None,
@@ -263,6 +1250,7 @@ impl TransactionService {
         };
 
         let transaction_result = self.list_transactions_domain(transaction_query)?;
+        let period_lock = transaction_result.period_lock;
         let mut all_transactions = transaction_result.transactions;
         
         info!("🗓️ Found {} historical transactions", all_transactions.len());
@@ -270,12 +1258,12 @@ impl TransactionService {
         // Generate future allowances for the requested month
         let start_date = match NaiveDate::from_ymd_opt(query.year as i32, query.month, 1) {
             Some(date) => date,
-            None => return Err(anyhow!("Invalid date: {}/{}", query.month, query.year)),
+            None => return Err(DomainError::invalid(format!("Invalid date: {}/{}", query.month, query.year)).into()),
         };
         
         let end_date = match NaiveDate::from_ymd_opt(query.year as i32, query.month, days_in_month) {
             Some(date) => date,
-            None => return Err(anyhow!("Invalid end date: {}/{}/{}", query.month, days_in_month, query.year)),
+            None => return Err(DomainError::invalid(format!("Invalid end date: {}/{}/{}", query.month, days_in_month, query.year)).into()),
         };
 
         info!("🗓️ Generating future allowances for child {} from {} to {}", 
@@ -303,6 +1291,7 @@ impl TransactionService {
 
         Ok(CalendarTransactionsResult {
             transactions: all_transactions,
+            period_lock,
         })
     }
 
@@ -310,10 +1299,19 @@ impl TransactionService {
         &self,
         cmd: DeleteTransactionsCommand,
     ) -> Result<DeleteTransactionsResult> {
-        let active_child = self.get_active_child()?;
-        let existing_ids = self
-            .transaction_repository
-            .check_transactions_exist(&active_child.id, &cmd.transaction_ids)?;
+        // Deleting rewrites the transactions file twice and every file linking to them
+        let description = format!("Deleted {} transactions", cmd.transaction_ids.len());
+        self.connection.batch_writes(&description, || self.remove_transactions(cmd))
+    }
+
+    fn remove_transactions(
+        &self,
+        cmd: DeleteTransactionsCommand,
+    ) -> Result<DeleteTransactionsResult> {
+        let active_child = self.resolve_child(cmd.child_id.as_deref())?;
+        let existing_ids = self
+            .transaction_repository
+            .check_transactions_exist(&active_child.id, &cmd.transaction_ids)?;
         let not_found_ids: Vec<String> = cmd
             .transaction_ids
             .iter()
@@ -328,6 +1326,17 @@ impl TransactionService {
         } else {
             Vec::new()
         };
+        if let Some(lock) = self.period_lock_repository.get_period_lock(&active_child.id)? {
+            let locked = transactions_to_delete.iter().filter(|tx| lock.covers(tx.date)).count();
+            if locked > 0 {
+                return Err(DomainError::Conflict(format!(
+                    "{} of these transactions are in the period closed through {}. Reopen it to delete them.",
+                    locked,
+                    lock.locked_through.format("%Y-%m-%d")
+                ))
+                .into());
+            }
+        }
 
         let deleted_count = if !existing_ids.is_empty() {
             self.transaction_repository
@@ -339,10 +1348,37 @@ impl TransactionService {
         if deleted_count > 0 {
             self.balance_service
                 .recalculate_balances_from_date(&active_child.id, "1970-01-01T00:00:00Z")?;
+            self.savings_allocation_repository
+                .delete_allocations_for_transactions(&active_child.id, &existing_ids)?;
+            self.loan_repository
+                .delete_loan_links_for_transactions(&active_child.id, &existing_ids)?;
+            self.advance_repository
+                .delete_advance_links_for_transactions(&active_child.id, &existing_ids)?;
+            self.reversal_repository
+                .delete_reversal_links_for_transactions(&active_child.id, &existing_ids)?;
+        }
+
+        // Deletions are always logged, since the rows themselves are gone
+        for transaction in &transactions_to_delete {
+            let entry = AuditEntry::new(
+                AUDIT_ACTION_TRANSACTION_DELETED,
+                Some(transaction.id.clone()),
+                format!(
+                    "{} deleted \"{}\" (${:.2}) dated {}",
+                    cmd.actor.as_ref().map(Actor::label).unwrap_or_else(|| "Someone".to_string()),
+                    transaction.description,
+                    transaction.amount,
+                    transaction.date.format("%Y-%m-%d")
+                ),
+            )
+            .with_actor(cmd.actor.clone());
+            if let Err(e) = self.audit_log_repository.append_audit_entry(&active_child.id, &entry) {
+                error!("Failed to record deleted transaction in audit log: {}", e);
+            }
         }
 
         // Send email notifications for deleted transactions
-        if let Some(email_service) = &self.email_service {
+        if let Some(email_service) = self.email_service.read().unwrap().as_ref() {
             let current_balance = self.balance_service.get_current_balance(&active_child.id)?;
             for transaction in &transactions_to_delete {
                 if let Err(e) = email_service.send_transaction_deleted_notification(transaction, &active_child, current_balance) {
@@ -390,11 +1426,18 @@ impl TransactionService {
             };
             info!("🎯 ALLOWANCE DEBUG: Found {} pending allowances", pending_allowances.len());
             
+            let description = self
+                .allowance_service
+                .get_allowance_config(GetAllowanceConfigCommand { child_id: Some(active_child.id.clone()) })
+                .ok()
+                .and_then(|result| result.allowance_config)
+                .map_or_else(|| "Weekly allowance".to_string(), |config| config.payment_description());
+
             let mut issued_count = 0;
             for (allowance_date, amount) in pending_allowances {
                 info!("🎯 ALLOWANCE DEBUG: About to create allowance for {} (${:.2})", allowance_date, amount);
                 match self
-                    .create_allowance_transaction(&active_child.id, allowance_date, amount)
+                    .create_allowance_transaction(&active_child.id, allowance_date, amount, &description)
                 {
                     Ok(transaction) => {
                         info!(
@@ -412,6 +1455,10 @@ impl TransactionService {
                 }
             }
             info!("🎯 ALLOWANCE DEBUG: Total allowances issued: {}", issued_count);
+
+            if let Err(e) = self.collect_advance_repayments(&active_child.id, current_date) {
+                error!("💸 Failed to collect advance repayments for {}: {}", active_child.id, e);
+            }
             return Ok(issued_count);
         } else {
             info!("🎯 ALLOWANCE DEBUG: No active child found");
@@ -424,38 +1471,99 @@ impl TransactionService {
         child_id: &str,
         date: NaiveDate,
         amount: f64,
+        description: &str,
     ) -> Result<DomainTransaction> {
         info!("🎯 ALLOWANCE DEBUG: create_allowance_transaction() called for child {}, date {}, amount ${:.2}", child_id, date, amount);
         
-        // Convert NaiveDate to DateTime at noon Eastern time
-        let allowance_datetime = date.and_hms_opt(12, 0, 0).unwrap();
-        let utc_datetime = chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(
-            allowance_datetime,
-            chrono::Utc,
-        );
-        let eastern_offset = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
-        let eastern_datetime = utc_datetime.with_timezone(&eastern_offset);
+        let eastern_datetime = Self::allowance_datetime(date);
         info!("🎯 ALLOWANCE DEBUG: Transaction date: {}", eastern_datetime.to_rfc3339());
 
         let result = self.create_transaction_internal(
             child_id,
             eastern_datetime,
-            "Weekly allowance".to_string(),
+            description.to_string(),
             amount,
+            None,
+            TransactionTags { transaction_type: Some(DomainTransactionType::AllowancePayment), ..TransactionTags::default() },
         );
 
         if let Ok(ref transaction) = result {
             info!("🎯 ALLOWANCE DEBUG: create_allowance_transaction() completed for {}", transaction.id);
+            if let Err(e) = self.celebration_repository.record_celebration(&Celebration::allowance_posted(child_id, amount)) {
+                error!("Failed to record allowance celebration for {}: {}", transaction.id, e);
+            }
         }
 
         result
     }
 
+    /// Take every advance installment due on or before `today` as an expense
+    ///
+    /// Repayments are dated a minute after that day's allowance, so the
+    /// transaction list shows the allowance and then what it repaid.
+    fn collect_advance_repayments(&self, child_id: &str, today: NaiveDate) -> Result<u32> {
+        let mut collected = 0;
+        for mut advance in self.advance_repository.list_advances(child_id)? {
+            let count = advance.installments.len();
+            let mut changed = false;
+            for index in 0..count {
+                let installment = &advance.installments[index];
+                if installment.is_paid() || installment.due_date > today {
+                    continue;
+                }
+                let transaction = self.create_transaction_internal(
+                    child_id,
+                    Self::allowance_datetime(installment.due_date) + chrono::Duration::minutes(1),
+                    format!("Advance repayment ({} of {})", index + 1, count),
+                    -installment.amount,
+                    None,
+                    TransactionTags { transaction_type: Some(DomainTransactionType::Transfer), ..TransactionTags::default() },
+                )?;
+                info!("💸 Collected ${:.2} towards {} on {}", installment.amount, advance.id, installment.due_date);
+                advance.installments[index].transaction_id = Some(transaction.id);
+                changed = true;
+                collected += 1;
+            }
+            if changed {
+                self.advance_repository.store_advance(&advance)?;
+            }
+        }
+        Ok(collected)
+    }
+
+    /// Time automatic allowances are recorded at: noon UTC, shown in Eastern time
+    fn allowance_datetime(date: NaiveDate) -> chrono::DateTime<chrono::FixedOffset> {
+        let allowance_datetime = date.and_hms_opt(12, 0, 0).unwrap();
+        let utc_datetime = chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(
+            allowance_datetime,
+            chrono::Utc,
+        );
+        let eastern_offset = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+        utc_datetime.with_timezone(&eastern_offset)
+    }
+
     pub fn get_active_child(&self) -> Result<DomainChild> {
         self.child_service
             .get_active_child()?
             .active_child.child
-            .ok_or_else(|| anyhow!("No active child found."))
+            .ok_or(DomainError::NoActiveChild)
+            .map_err(Into::into)
+    }
+
+    /// The child a command names, or the active child when it names none
+    ///
+    /// Callers that already know the child should pass it, so a switch of the
+    /// active child part-way through a request can't send its reads and
+    /// writes to different children.
+    pub fn resolve_child(&self, child_id: Option<&str>) -> Result<DomainChild> {
+        match child_id {
+            Some(id) => self
+                .child_service
+                .get_child(GetChildCommand { child_id: id.to_string() })?
+                .child
+                .ok_or_else(|| DomainError::child_not_found(id).into()),
+            None => self.get_active_child(),
+        }
     }
 
     /// Create a balance service for projected balance calculations
@@ -464,6 +1572,31 @@ impl TransactionService {
     }
 }
 
+/// ID for a transaction stored now
+fn new_transaction_id(amount: f64) -> Result<String> {
+    let now_millis = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+    Ok(DomainTransaction::generate_id(amount, now_millis))
+}
+
+/// A new transaction's description, sanitized and checked against the length limit
+fn checked_description(description: &str) -> Result<String> {
+    let description = description_input::sanitize_description(description);
+    if description.is_empty() || description_input::description_length(&description) > MAX_DESCRIPTION_LENGTH {
+        return Err(DomainError::invalid(format!("Description must be between 1 and {} characters", MAX_DESCRIPTION_LENGTH)).into());
+    }
+    Ok(description)
+}
+
+/// Description of the entry that reverses a transaction, kept within the
+/// description length limit
+fn reversal_description(original: &str, reason: Option<&str>) -> String {
+    let description = match reason {
+        Some(reason) => format!("Reversed: {} ({})", original, description_input::sanitize_description(reason)),
+        None => format!("Reversed: {}", original),
+    };
+    description_input::truncate_description(&description)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -519,9 +1652,14 @@ mod tests {
             .unwrap();
 
         let cmd = CreateTransactionCommand {
+            child_id: None,
             amount: 10.0,
             description: "Test transaction".to_string(),
             date: None,
+            parent_override: false,
+            actor: None,
+            icon: None,
+            transaction_type: None,
         };
         let transaction = service.create_transaction(cmd).unwrap();
         assert_eq!(transaction.amount, 10.0);
@@ -552,11 +1690,18 @@ mod tests {
             amount: 10.0,
             day_of_week,
             is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
         service.allowance_service.update_allowance_config(allowance_cmd).expect("Failed to create allowance config");
 
         // First call to list_transactions_domain - should create one allowance
         let query1 = TransactionListQuery {
+            child_id: None,
             after: None,
             limit: Some(10),
             start_date: None,
@@ -568,6 +1713,7 @@ mod tests {
 
         // Second call immediately after - should NOT create another allowance
         let query2 = TransactionListQuery {
+            child_id: None,
             after: None,
             limit: Some(10),
             start_date: None,
@@ -611,11 +1757,18 @@ mod tests {
             amount: 10.0,
             day_of_week,
             is_active: true,
+            day_change_policy: None,
+            frequency: None,
+            day_of_month: None,
+            interval_weeks: None,
+            anchor_date: None,
+            expected_updated_at: None,
         };
         service.allowance_service.update_allowance_config(allowance_cmd).expect("Failed to create allowance config");
 
         // First, verify no allowance transactions exist
         let query = TransactionListQuery {
+            child_id: None,
             after: None,
             limit: Some(10),
             start_date: None,
@@ -651,4 +1804,1047 @@ mod tests {
             assert!(allowance.description.to_lowercase().contains("allowance"), "Should be an allowance transaction");
         }
     }
-}
\ No newline at end of file
+
+    fn setup_child_with_balance_floor(service: &TransactionService) -> DomainChild {
+        let child = create_test_child(&service.child_service, "Floor Child").unwrap();
+        service
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: child.id.clone() })
+            .unwrap();
+        service
+            .child_service
+            .update_child_settings(crate::backend::domain::commands::child::UpdateChildSettingsCommand {
+                child_id: child.id.clone(),
+                prevent_negative_balance: Some(true),
+                auto_save_percentage: None,
+                auto_save_to_goal: None,
+                round_up_spends: None,
+                locale: None,
+                currency: None,
+                balance_milestones: None,
+                background: None,
+            })
+            .unwrap();
+        service
+            .create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Birthday money".to_string(),
+                amount: 10.0,
+                date: None,
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })
+            .unwrap();
+        child
+    }
+
+    #[test]
+    fn test_balance_floor_rejects_overdraft_with_shortfall() {
+        let (service, _conn, _temp_dir) = create_test_service();
+        setup_child_with_balance_floor(&service);
+
+        let error = service
+            .create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Big toy".to_string(),
+                amount: -12.5,
+                date: None,
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })
+            .unwrap_err();
+
+        let insufficient = error
+            .downcast_ref::<InsufficientFundsError>()
+            .expect("should be an insufficient funds error");
+        assert_eq!(insufficient.shortfall, 2.5);
+        assert_eq!(insufficient.available, 10.0);
+
+        // Spending exactly the balance is still allowed
+        let tx = service
+            .create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Everything".to_string(),
+                amount: -10.0,
+                date: None,
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })
+            .unwrap();
+        assert_eq!(tx.balance, 0.0);
+    }
+
+    #[test]
+    fn test_balance_floor_checks_every_day_after_a_backdated_spend() {
+        let (service, _conn, _temp_dir) = create_test_service();
+        setup_child_with_balance_floor(&service);
+        let day = |d: u32| chrono::DateTime::parse_from_rfc3339(&format!("2024-03-{:02}T09:00:00-05:00", d)).unwrap();
+        let add = |description: &str, amount: f64, d: u32| {
+            service.create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: description.to_string(),
+                amount,
+                date: Some(day(d)),
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })
+        };
+        add("Gift", 10.0, 1).unwrap();
+        add("Book", -8.0, 5).unwrap();
+        add("Chores", 20.0, 10).unwrap();
+
+        // $5 on the 3rd leaves $5 that day and today, but the 5th would end at -$3
+        let error = add("Stickers", -5.0, 3).unwrap_err();
+        let insufficient = error
+            .downcast_ref::<InsufficientFundsError>()
+            .expect("should be an insufficient funds error");
+        assert_eq!(insufficient.shortfall, 3.0);
+        assert_eq!(insufficient.available, 2.0);
+
+        assert!(add("Stickers", -2.0, 3).is_ok());
+    }
+
+    #[test]
+    fn test_balance_floor_parent_override_is_audited() {
+        let (service, _conn, _temp_dir) = create_test_service();
+        let child = setup_child_with_balance_floor(&service);
+
+        let tx = service
+            .create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Big toy".to_string(),
+                amount: -12.5,
+                date: None,
+                parent_override: true,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })
+            .unwrap();
+        assert_eq!(tx.balance, -2.5);
+
+        let entries = service.audit_log_repository.list_audit_entries(&child.id, None).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].action, AUDIT_ACTION_OVERDRAFT_OVERRIDE);
+        assert_eq!(entries[0].transaction_id.as_deref(), Some(tx.id.as_str()));
+        assert!(entries[0].details.contains("$2.50"));
+    }
+
+    #[test]
+    fn test_balance_floor_override_is_audited_in_the_childs_currency() {
+        use crate::backend::domain::models::currency::Currency;
+
+        let (service, _conn, _temp_dir) = create_test_service();
+        let child = setup_child_with_balance_floor(&service);
+        service
+            .child_service
+            .update_child_settings(crate::backend::domain::commands::child::UpdateChildSettingsCommand {
+                child_id: child.id.clone(),
+                prevent_negative_balance: None,
+                auto_save_percentage: None,
+                auto_save_to_goal: None,
+                round_up_spends: None,
+                locale: None,
+                currency: Some(Some(Currency::Eur)),
+                balance_milestones: None,
+                background: None,
+            })
+            .unwrap();
+
+        let spend = |parent_override| {
+            service.create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Big toy".to_string(),
+                amount: -12.5,
+                date: None,
+                parent_override,
+                actor: Some(Actor::Parent(Some("Mom".to_string()))),
+                icon: None,
+                transaction_type: None,
+            })
+        };
+        spend(true).unwrap();
+        let entries = service.audit_log_repository.list_audit_entries(&child.id, None).unwrap();
+        let details = &entries.iter().find(|entry| entry.action == AUDIT_ACTION_OVERDRAFT_OVERRIDE).unwrap().details;
+        assert!(details.contains(&Currency::Eur.format(12.5, Default::default())), "{}", details);
+        assert!(!details.contains('$'), "{}", details);
+    }
+
+    #[test]
+    fn test_balance_floor_override_is_refused_when_it_cant_be_audited() {
+        let (service, conn, _temp_dir) = create_test_service();
+        let child = setup_child_with_balance_floor(&service);
+        let child_dir = conn.get_child_directory_by_id(&child.id).unwrap().unwrap();
+        // Nothing can be appended to an audit log that is a folder
+        std::fs::remove_file(child_dir.join("audit_log.csv")).ok();
+        std::fs::create_dir(child_dir.join("audit_log.csv")).unwrap();
+        let before = service.balance_service.get_current_balance(&child.id).unwrap();
+
+        let result = service.create_transaction(CreateTransactionCommand {
+            child_id: None,
+            description: "Big toy".to_string(),
+            amount: -12.5,
+            date: None,
+            parent_override: true,
+            actor: None,
+            icon: None,
+            transaction_type: None,
+        });
+        assert!(result.is_err());
+        assert_eq!(service.balance_service.get_current_balance(&child.id).unwrap(), before);
+    }
+
+    #[test]
+    fn test_actor_is_stored_and_audited() {
+        let (service, _conn, _temp_dir) = create_test_service();
+        let child = setup_child_with_balance_floor(&service);
+
+        let mom = Actor::Parent(Some("Mom".to_string()));
+        let tx = service
+            .create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Candy".to_string(),
+                amount: -2.0,
+                date: None,
+                parent_override: false,
+                actor: Some(mom.clone()),
+                icon: None,
+                transaction_type: None,
+            })
+            .unwrap();
+
+        let stored = service.list_all_transactions_for_child(&child.id).unwrap();
+        assert_eq!(stored.iter().find(|t| t.id == tx.id).unwrap().actor, Some(mom.clone()));
+        assert_eq!(stored.iter().find(|t| t.id != tx.id).unwrap().actor, None);
+
+        // Only the attributed transaction is logged
+        let entries = service.audit_log_repository.list_audit_entries(&child.id, None).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].action, AUDIT_ACTION_TRANSACTION_ADDED);
+        assert_eq!(entries[0].transaction_id.as_deref(), Some(tx.id.as_str()));
+        assert_eq!(entries[0].actor, Some(mom.clone()));
+        assert!(entries[0].details.starts_with("Parent (Mom) added \"Candy\""));
+
+        service
+            .delete_transactions_domain(DeleteTransactionsCommand { child_id: None, transaction_ids: vec![tx.id.clone()], actor: Some(mom.clone()) })
+            .unwrap();
+        let entries = service.audit_log_repository.list_audit_entries(&child.id, None).unwrap();
+        assert_eq!(entries[0].action, AUDIT_ACTION_TRANSACTION_DELETED);
+        assert_eq!(entries[0].actor, Some(mom));
+        assert!(entries[0].details.starts_with("Parent (Mom) deleted \"Candy\""));
+    }
+
+    #[test]
+    fn test_overdraft_allowed_without_balance_floor() {
+        let (service, _conn, _temp_dir) = create_test_service();
+        let child = create_test_child(&service.child_service, "Free Child").unwrap();
+        service
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: child.id.clone() })
+            .unwrap();
+
+        let tx = service
+            .create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Candy".to_string(),
+                amount: -3.0,
+                date: None,
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })
+            .unwrap();
+        assert_eq!(tx.balance, -3.0);
+    }
+
+    #[test]
+    fn test_income_auto_saves_to_active_goal_and_cleans_up_on_delete() {
+        use crate::backend::domain::models::goal::{DomainGoal, DomainGoalState};
+
+        let (service, _conn, _temp_dir) = create_test_service();
+        let child = create_test_child(&service.child_service, "Goal Saver").unwrap();
+        service
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: child.id.clone() })
+            .unwrap();
+        service
+            .child_service
+            .update_child_settings(crate::backend::domain::commands::child::UpdateChildSettingsCommand {
+                child_id: child.id.clone(),
+                prevent_negative_balance: None,
+                auto_save_percentage: Some(25.0),
+                auto_save_to_goal: Some(true),
+                round_up_spends: None,
+                locale: None,
+                currency: None,
+                balance_milestones: None,
+                background: None,
+            })
+            .unwrap();
+        let goal = DomainGoal {
+            id: DomainGoal::generate_id(&child.id, 1),
+            child_id: child.id.clone(),
+            description: "Bike".to_string(),
+            target_amount: 100.0,
+            state: DomainGoalState::Active,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        };
+        service.goal_repository.store_goal(&goal).unwrap();
+
+        let tx = service
+            .create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Allowance".to_string(),
+                amount: 8.0,
+                date: None,
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })
+            .unwrap();
+        // The full income still lands in the balance; the allocation only earmarks it
+        assert_eq!(tx.balance, 8.0);
+
+        let allocations = service.savings_allocation_repository.list_allocations(&child.id).unwrap();
+        assert_eq!(allocations.len(), 1);
+        assert_eq!(allocations[0].transaction_id, tx.id);
+        assert_eq!(allocations[0].amount, 2.0);
+        assert_eq!(allocations[0].goal_id.as_deref(), Some(goal.id.as_str()));
+
+        service
+            .delete_transactions_domain(DeleteTransactionsCommand { child_id: None, transaction_ids: vec![tx.id.clone()], actor: None })
+            .unwrap();
+        assert!(service.savings_allocation_repository.list_allocations(&child.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_read_only_mode_rejects_writes_but_allows_reads() {
+        use crate::backend::storage::csv::{ReadOnlyError, ReadOnlySource};
+
+        let (service, conn, _temp_dir) = create_test_service();
+        let child = create_test_child(&service.child_service, "Demo Child").unwrap();
+        service
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: child.id.clone() })
+            .unwrap();
+        service
+            .create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Gift".to_string(),
+                amount: 5.0,
+                date: None,
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })
+            .unwrap();
+
+        conn.set_read_only(Some(ReadOnlySource::EnvironmentVariable));
+
+        let err = service
+            .create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Candy".to_string(),
+                amount: -1.0,
+                date: None,
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ReadOnlyError>(),
+            Some(&ReadOnlyError { enabled_by: ReadOnlySource::EnvironmentVariable })
+        );
+
+        // Reads and in-memory active child switching still work
+        let other = create_test_child(&service.child_service, "Other Child");
+        assert!(other.unwrap_err().downcast_ref::<ReadOnlyError>().is_some());
+        assert_eq!(service.list_all_transactions_for_child(&child.id).unwrap().len(), 1);
+        service
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: child.id.clone() })
+            .unwrap();
+
+        conn.set_read_only(None);
+        assert!(service
+            .create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Candy".to_string(),
+                amount: -1.0,
+                date: None,
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })
+            .is_ok());
+    }
+
+    #[test]
+    fn test_read_only_mode_rejects_moving_a_transaction() {
+        use crate::backend::storage::csv::{ReadOnlyError, ReadOnlySource};
+
+        let (service, conn, _temp_dir) = create_test_service();
+        let child = create_test_child(&service.child_service, "Demo Child").unwrap();
+        service
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: child.id.clone() })
+            .unwrap();
+        let gift = service
+            .create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Gift".to_string(),
+                amount: 5.0,
+                date: Some(chrono::DateTime::parse_from_rfc3339("2024-03-10T09:00:00-05:00").unwrap()),
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })
+            .unwrap();
+        let before = service.list_all_transactions_for_child(&child.id).unwrap();
+
+        conn.set_read_only(Some(ReadOnlySource::EnvironmentVariable));
+        let err = service
+            .edit_transaction(EditTransactionCommand {
+                child_id: None,
+                transaction_id: gift.id.clone(),
+                date: Some(chrono::DateTime::parse_from_rfc3339("2024-03-05T09:00:00-05:00").unwrap()),
+                description: None,
+                parent_override: false,
+                actor: None,
+            })
+            .unwrap_err();
+        assert!(err.downcast_ref::<ReadOnlyError>().is_some());
+
+        // The drag-move must not look saved when nothing was written
+        conn.set_read_only(None);
+        assert_eq!(service.list_all_transactions_for_child(&child.id).unwrap(), before);
+    }
+
+    #[test]
+    fn test_read_only_mode_rejects_deletes() {
+        use crate::backend::storage::csv::{ReadOnlyError, ReadOnlySource};
+
+        let (service, conn, _temp_dir) = create_test_service();
+        let child = create_test_child(&service.child_service, "Demo Child").unwrap();
+        service
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: child.id.clone() })
+            .unwrap();
+        let gift = service
+            .create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Gift".to_string(),
+                amount: 5.0,
+                date: None,
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })
+            .unwrap();
+
+        conn.set_read_only(Some(ReadOnlySource::EnvironmentVariable));
+        let err = service
+            .delete_transactions_domain(DeleteTransactionsCommand {
+                child_id: None,
+                transaction_ids: vec![gift.id.clone()],
+                actor: None,
+            })
+            .unwrap_err();
+        assert!(err.downcast_ref::<ReadOnlyError>().is_some());
+        let err = service.transaction_repository.delete_transaction(&child.id, &gift.id).unwrap_err();
+        assert!(err.downcast_ref::<ReadOnlyError>().is_some());
+
+        conn.set_read_only(None);
+        assert_eq!(service.list_all_transactions_for_child(&child.id).unwrap(), vec![gift]);
+    }
+
+    #[test]
+    fn test_read_only_mode_rejects_balance_recalculation() {
+        use crate::backend::storage::csv::{ReadOnlyError, ReadOnlySource};
+
+        let (service, conn, _temp_dir) = create_test_service();
+        let child = create_test_child(&service.child_service, "Demo Child").unwrap();
+        service
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: child.id.clone() })
+            .unwrap();
+        let gift = service
+            .create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Gift".to_string(),
+                amount: 5.0,
+                date: None,
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })
+            .unwrap();
+
+        conn.set_read_only(Some(ReadOnlySource::EnvironmentVariable));
+        let err = service
+            .balance_service
+            .recalculate_balances_from_date(&child.id, "1970-01-01T00:00:00Z")
+            .unwrap_err();
+        assert!(err.downcast_ref::<ReadOnlyError>().is_some());
+        let err = service
+            .transaction_repository
+            .update_transaction_balances(&[(gift.id.clone(), 99.0)])
+            .unwrap_err();
+        assert!(err.downcast_ref::<ReadOnlyError>().is_some());
+
+        conn.set_read_only(None);
+        assert_eq!(service.list_all_transactions_for_child(&child.id).unwrap()[0].balance, 5.0);
+    }
+
+    #[test]
+    fn test_advance_repayments_are_collected_once_due() {
+        use crate::backend::domain::models::allowance_advance::AllowanceAdvance;
+
+        let (service, _conn, _temp_dir) = create_test_service();
+        let child = create_test_child(&service.child_service, "Saver").unwrap();
+        service.child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() }).unwrap();
+        let advance_tx = service.create_transaction(CreateTransactionCommand {
+            child_id: None,
+            amount: 10.0,
+            description: "Advance, repaid over the next 2 paydays".to_string(),
+            date: Some(chrono::DateTime::parse_from_rfc3339("2024-03-01T09:00:00-05:00").unwrap()),
+            parent_override: false,
+            actor: None,
+            icon: None,
+            transaction_type: None,
+        }).unwrap();
+        let due_dates = [NaiveDate::from_ymd_opt(2024, 3, 2).unwrap(), NaiveDate::from_ymd_opt(2024, 3, 9).unwrap()];
+        service.advance_repository.store_advance(&AllowanceAdvance {
+            id: AllowanceAdvance::generate_id(&child.id, 1),
+            child_id: child.id.clone(),
+            amount: 10.0,
+            date: advance_tx.date,
+            transaction_id: advance_tx.id.clone(),
+            installments: AllowanceAdvance::split_installments(10.0, &due_dates),
+        }).unwrap();
+
+        // Only the first installment is due; running again doesn't take it twice
+        assert_eq!(service.collect_advance_repayments(&child.id, due_dates[0]).unwrap(), 1);
+        assert_eq!(service.collect_advance_repayments(&child.id, due_dates[0]).unwrap(), 0);
+        let transactions = service.list_all_transactions_for_child(&child.id).unwrap();
+        let repayment = transactions.last().unwrap();
+        assert_eq!((repayment.amount, repayment.balance), (-5.0, 5.0));
+        assert_eq!(repayment.description, "Advance repayment (1 of 2)");
+
+        // Deleting a repayment forgives it rather than taking it again
+        service
+            .delete_transactions_domain(DeleteTransactionsCommand { child_id: None, transaction_ids: vec![repayment.id.clone()], actor: None })
+            .unwrap();
+        assert_eq!(service.collect_advance_repayments(&child.id, due_dates[1]).unwrap(), 1);
+        let advance = &service.advance_repository.list_advances(&child.id).unwrap()[0];
+        assert_eq!(advance.installments.len(), 1);
+        assert!(advance.is_repaid());
+    }
+
+    #[test]
+    fn test_reverse_transaction_links_both_entries() {
+        let (service, _conn, _temp_dir) = create_test_service();
+        let child = create_test_child(&service.child_service, "Refunder").unwrap();
+        service
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: child.id.clone() })
+            .unwrap();
+        let create = |description: &str, amount: f64| {
+            service
+                .create_transaction(CreateTransactionCommand {
+                    child_id: None,
+                    description: description.to_string(),
+                    amount,
+                    date: None,
+                    parent_override: false,
+                    actor: None,
+                    icon: None,
+                    transaction_type: None,
+                })
+                .unwrap()
+        };
+        create("Allowance", 10.0);
+        let toy = create("Toy car", -4.0);
+        let reverse = |transaction_id: &str| {
+            service.reverse_transaction(ReverseTransactionCommand {
+                child_id: None,
+                transaction_id: transaction_id.to_string(),
+                reason: Some(" Returned to the store ".to_string()),
+                date: None,
+                parent_override: false,
+                actor: None,
+            })
+        };
+
+        let result = reverse(&toy.id).unwrap();
+        assert_eq!(result.reversal.amount, 4.0);
+        assert_eq!(result.reversal.balance, 10.0);
+        assert_eq!(result.reversal.description, "Reversed: Toy car (Returned to the store)");
+        assert_eq!(result.link.counterpart(&toy.id), Some(result.reversal.id.as_str()));
+        assert_eq!(result.link.counterpart(&result.reversal.id), Some(toy.id.as_str()));
+        assert_eq!(service.list_reversals(&child.id).unwrap(), vec![result.link.clone()]);
+
+        // Neither side can be reversed again
+        for id in [&toy.id, &result.reversal.id] {
+            let conflict = reverse(id).unwrap_err();
+            assert!(matches!(conflict.downcast_ref::<DomainError>(), Some(DomainError::Conflict(_))));
+        }
+        let missing = reverse("ex-1-none").unwrap_err();
+        assert!(matches!(missing.downcast_ref::<DomainError>(), Some(DomainError::NotFound(_))));
+
+        // Deleting the reversal unlinks the original, so it can be reversed afresh
+        service
+            .delete_transactions_domain(DeleteTransactionsCommand { child_id: None, transaction_ids: vec![result.reversal.id.clone()], actor: None })
+            .unwrap();
+        assert!(service.list_reversals(&child.id).unwrap().is_empty());
+        assert!(reverse(&toy.id).is_ok());
+    }
+
+    #[test]
+    fn test_search_transactions_matches_text_newest_first() {
+        let (service, _conn, _temp_dir) = create_test_service();
+        let child = create_test_child(&service.child_service, "Searcher").unwrap();
+        let create = |description: &str, amount: f64, date: &str| {
+            service
+                .create_transaction(CreateTransactionCommand {
+                    child_id: Some(child.id.clone()),
+                    description: description.to_string(),
+                    amount,
+                    date: Some(chrono::DateTime::parse_from_rfc3339(date).unwrap()),
+                    parent_override: false,
+                    actor: None,
+                    icon: None,
+                    transaction_type: None,
+                })
+                .unwrap()
+        };
+        create("Birthday money", 20.0, "2024-03-01T12:00:00-05:00");
+        let first_toy = create("Toy car", -4.0, "2024-03-05T12:00:00-05:00");
+        let second_toy = create("Lego toy", -6.0, "2024-04-10T12:00:00-05:00");
+        let search = |text: &str, limit: usize| {
+            service
+                .search_transactions(TransactionSearchQuery { child_id: Some(child.id.clone()), text: text.to_string(), limit })
+                .unwrap()
+        };
+
+        let hits = search(" TOY ", 10);
+        let ids: Vec<&str> = hits.transactions.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec![second_toy.id.as_str(), first_toy.id.as_str()]);
+        assert!(!hits.has_more);
+
+        let limited = search("toy", 1);
+        assert_eq!(limited.transactions.len(), 1);
+        assert!(limited.has_more);
+
+        assert!(search("   ", 10).transactions.is_empty());
+        assert!(search("bike", 10).transactions.is_empty());
+    }
+
+    #[test]
+    fn test_backdated_transaction_reports_and_recalculates_later_rows() {
+        use crate::backend::domain::models::goal::{DomainGoal, DomainGoalState};
+        use chrono::{TimeZone, Timelike};
+
+        let (service, _conn, _temp_dir) = create_test_service();
+        let child = create_test_child(&service.child_service, "Backdater").unwrap();
+        service
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: child.id.clone() })
+            .unwrap();
+        let offset = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+        let day = |d: u32| offset.with_ymd_and_hms(2025, 3, d, 12, 0, 0).unwrap();
+        let add = |description: &str, amount: f64, date| {
+            service
+                .create_transaction(CreateTransactionCommand {
+                    child_id: None,
+                    description: description.to_string(),
+                    amount,
+                    date: Some(date),
+                    parent_override: false,
+                    actor: None,
+                    icon: None,
+                    transaction_type: None,
+                })
+                .unwrap()
+        };
+
+        add("Weekly allowance", 5.0, day(1));
+        add("Weekly allowance", 5.0, day(8));
+        add("Candy", -2.0, day(10));
+        service
+            .goal_repository
+            .store_goal(&DomainGoal {
+                id: DomainGoal::generate_id(&child.id, 1),
+                child_id: child.id.clone(),
+                description: "Kite".to_string(),
+                target_amount: 30.0,
+                state: DomainGoalState::Active,
+                created_at: day(9).to_rfc3339(),
+                updated_at: day(9).to_rfc3339(),
+            })
+            .unwrap();
+
+        // Nothing comes after the newest entry
+        assert_eq!(service.backdate_impact(&child.id, day(12)).unwrap(), None);
+
+        let impact = service.backdate_impact(&child.id, day(5)).unwrap().unwrap();
+        assert_eq!(impact, BackdateImpact { recalculated_rows: 2, allowances_after: 1, goal_affected: true });
+        let impact = service.backdate_impact(&child.id, day(9).with_hour(18).unwrap()).unwrap().unwrap();
+        assert_eq!(impact, BackdateImpact { recalculated_rows: 1, allowances_after: 0, goal_affected: false });
+
+        add("Birthday", 10.0, day(5));
+        let balances: Vec<f64> = service
+            .transaction_repository
+            .list_transactions_chronological(&child.id, None, None)
+            .unwrap()
+            .iter()
+            .map(|tx| tx.balance)
+            .collect();
+        assert_eq!(balances, vec![5.0, 15.0, 20.0, 18.0]);
+    }
+
+    #[test]
+    fn test_edit_transaction_moves_it_and_recalculates_balances() {
+        use chrono::TimeZone;
+
+        let (service, _conn, _temp_dir) = create_test_service();
+        let child = create_test_child(&service.child_service, "Mover").unwrap();
+        service
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: child.id.clone() })
+            .unwrap();
+        service
+            .child_service
+            .update_child_settings(crate::backend::domain::commands::child::UpdateChildSettingsCommand {
+                child_id: child.id.clone(),
+                prevent_negative_balance: Some(true),
+                auto_save_percentage: None,
+                auto_save_to_goal: None,
+                round_up_spends: None,
+                locale: None,
+                currency: None,
+                balance_milestones: None,
+                background: None,
+            })
+            .unwrap();
+        let offset = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+        let day = |d: u32| offset.with_ymd_and_hms(2025, 3, d, 12, 0, 0).unwrap();
+        let add = |description: &str, amount: f64, date| {
+            service
+                .create_transaction(CreateTransactionCommand {
+                    child_id: None,
+                    description: description.to_string(),
+                    amount,
+                    date: Some(date),
+                    parent_override: false,
+                    actor: None,
+                    icon: None,
+                    transaction_type: None,
+                })
+                .unwrap()
+        };
+        add("Allowance", 5.0, day(1));
+        add("Gift", 10.0, day(10));
+        let toy = add("Toy", -12.0, day(20));
+        let balances = || -> Vec<(String, f64)> {
+            service
+                .list_all_transactions_for_child(&child.id)
+                .unwrap()
+                .into_iter()
+                .map(|tx| (tx.description, tx.balance))
+                .collect()
+        };
+        let move_toy = |d: u32, parent_override: bool| {
+            service.edit_transaction(EditTransactionCommand {
+                child_id: None,
+                transaction_id: toy.id.clone(),
+                date: Some(day(d)),
+                description: None,
+                parent_override,
+                actor: None,
+            })
+        };
+
+        // Moving the toy before the gift would leave the child short
+        let refused = move_toy(5, false).unwrap_err();
+        assert!(refused.downcast_ref::<InsufficientFundsError>().is_some());
+        assert_eq!(balances(), vec![("Allowance".to_string(), 5.0), ("Gift".to_string(), 15.0), ("Toy".to_string(), 3.0)]);
+
+        let moved = move_toy(15, false).unwrap();
+        assert_eq!(moved.original.date, day(20));
+        assert_eq!(moved.transaction.date, day(15));
+        assert_eq!(moved.transaction.balance, 3.0);
+
+        let moved = move_toy(5, true).unwrap();
+        assert_eq!(moved.transaction.balance, -7.0);
+        assert_eq!(balances(), vec![("Allowance".to_string(), 5.0), ("Toy".to_string(), -7.0), ("Gift".to_string(), 3.0)]);
+
+        // Nothing moves out of, or into, a closed period
+        service
+            .close_period(ClosePeriodCommand { child_id: None, locked_through: PeriodLock::month_end(2025, 3).unwrap(), actor: None })
+            .unwrap();
+        let locked = move_toy(20, true).unwrap_err();
+        assert!(matches!(locked.downcast_ref::<DomainError>(), Some(DomainError::Conflict(_))));
+    }
+
+    #[test]
+    fn test_duplicate_transaction_copies_it_dated_today() {
+        let (service, _conn, _temp_dir) = create_test_service();
+        let child = create_test_child(&service.child_service, "Luncher").unwrap();
+        service
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: child.id.clone() })
+            .unwrap();
+        let today = chrono::Local::now().date_naive();
+        let week_ago = today - chrono::Duration::days(7);
+        service
+            .import_transactions(
+                vec![
+                    ImportRow { date: week_ago, description: "Allowance".to_string(), amount: 20.0, category: None },
+                    ImportRow { date: week_ago, description: "Lunch money".to_string(), amount: -4.5, category: Some("Food".to_string()) },
+                ],
+                None,
+            )
+            .unwrap();
+        let lunch = service
+            .list_all_transactions_for_child(&child.id)
+            .unwrap()
+            .into_iter()
+            .find(|tx| tx.description == "Lunch money")
+            .unwrap();
+
+        let result = service
+            .duplicate_transaction(DuplicateTransactionCommand {
+                child_id: None,
+                transaction_id: lunch.id.clone(),
+                parent_override: false,
+                actor: Some(Actor::Kid),
+            })
+            .unwrap();
+        assert_eq!(result.original.id, lunch.id);
+        assert_ne!(result.transaction.id, lunch.id);
+        assert_eq!(result.transaction.description, "Lunch money");
+        assert_eq!(result.transaction.amount, -4.5);
+        assert_eq!(result.transaction.category.as_deref(), Some("Food"));
+        assert_eq!(result.transaction.date.date_naive(), chrono::Utc::now().with_timezone(&result.transaction.date.timezone()).date_naive());
+        assert_eq!(result.transaction.balance, 11.0);
+
+        let missing = service
+            .duplicate_transaction(DuplicateTransactionCommand {
+                child_id: None,
+                transaction_id: "missing".to_string(),
+                parent_override: false,
+                actor: None,
+            })
+            .unwrap_err();
+        assert!(matches!(missing.downcast_ref::<DomainError>(), Some(DomainError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_bulk_categorize_previews_applies_and_undoes() {
+        use crate::backend::domain::commands::transactions::CategoryFilter;
+
+        let (service, _conn, _temp_dir) = create_test_service();
+        let child = create_test_child(&service.child_service, "Sorter").unwrap();
+        service
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: child.id.clone() })
+            .unwrap();
+        let day = chrono::Local::now().date_naive() - chrono::Duration::days(20);
+        service
+            .import_transactions(
+                vec![
+                    ImportRow { date: day, description: "Weekly allowance".to_string(), amount: 5.0, category: None },
+                    ImportRow { date: day + chrono::Duration::days(7), description: "Weekly Allowance".to_string(), amount: 5.0, category: None },
+                    ImportRow { date: day, description: "Allowance bonus".to_string(), amount: 2.0, category: Some("Income".to_string()) },
+                    ImportRow { date: day, description: "Candy".to_string(), amount: -1.0, category: Some("Food".to_string()) },
+                ],
+                None,
+            )
+            .unwrap();
+        let command = |uncategorized_only: bool, preview: bool| BulkCategorizeCommand {
+            child_id: None,
+            filter: CategoryFilter { description_contains: "allowance".to_string(), uncategorized_only, ..Default::default() },
+            category: Some(" Allowance ".to_string()),
+            preview,
+            actor: Some(Actor::Parent(None)),
+        };
+        let categories = || -> Vec<(String, Option<String>)> {
+            service
+                .list_all_transactions_for_child(&child.id)
+                .unwrap()
+                .into_iter()
+                .map(|tx| (tx.description, tx.category))
+                .collect()
+        };
+        let before = categories();
+
+        // A preview counts without storing anything
+        let preview = service.bulk_categorize(command(true, true)).unwrap();
+        assert_eq!((preview.matched, preview.would_change, preview.skipped_closed), (2, 2, 0));
+        assert!(preview.changes.is_empty());
+        let preview = service.bulk_categorize(command(false, true)).unwrap();
+        assert_eq!((preview.matched, preview.would_change), (3, 3));
+        assert_eq!(categories(), before);
+
+        let applied = service.bulk_categorize(command(false, false)).unwrap();
+        assert_eq!(applied.changes.len(), 3);
+        assert!(categories()
+            .iter()
+            .all(|(description, category)| description == "Candy" || category.as_deref() == Some("Allowance")));
+
+        // Running it again has nothing left to change
+        assert_eq!(service.bulk_categorize(command(false, true)).unwrap().would_change, 0);
+
+        let restored = service
+            .undo_bulk_categorize(UndoBulkCategorizeCommand {
+                child_id: applied.child_id,
+                changes: applied.changes,
+                actor: Some(Actor::Parent(None)),
+            })
+            .unwrap();
+        assert_eq!(restored, 3);
+        assert_eq!(categories(), before);
+
+        // Every transaction at once has to be asked for with a filter
+        let everything = BulkCategorizeCommand { filter: CategoryFilter::default(), ..command(false, true) };
+        assert!(service.bulk_categorize(everything).is_err());
+    }
+
+    #[test]
+    fn test_closed_period_locks_transactions_until_reopened() {
+        use chrono::TimeZone;
+
+        let (service, _conn, _temp_dir) = create_test_service();
+        let child = create_test_child(&service.child_service, "Closer").unwrap();
+        service
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: child.id.clone() })
+            .unwrap();
+        let offset = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+        let day = |m: u32, d: u32| offset.with_ymd_and_hms(2025, m, d, 12, 0, 0).unwrap();
+        let add = |amount: f64, date| {
+            service.create_transaction(CreateTransactionCommand {
+                child_id: None,
+                description: "Chores".to_string(),
+                amount,
+                date: Some(date),
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })
+        };
+        let march = add(5.0, day(3, 10)).unwrap();
+        let april = add(3.0, day(4, 2)).unwrap();
+
+        let mom = Some(Actor::Parent(Some("Mom".to_string())));
+        let march_end = PeriodLock::month_end(2025, 3).unwrap();
+        let result = service
+            .close_period(ClosePeriodCommand { child_id: None, locked_through: march_end, actor: mom.clone() })
+            .unwrap();
+        assert_eq!(result.locked_count, 1);
+        assert_eq!(result.lock.closed_by, mom);
+
+        // Nothing can be dated into March or deleted from it...
+        let error = add(1.0, day(3, 20)).unwrap_err();
+        assert!(matches!(error.downcast_ref::<DomainError>(), Some(DomainError::Conflict(_))));
+        let delete = |ids: Vec<String>| service.delete_transactions_domain(DeleteTransactionsCommand { child_id: None, transaction_ids: ids, actor: None });
+        assert!(delete(vec![march.id.clone(), april.id.clone()]).is_err());
+        // ...and the listing says which rows are locked
+        let listed = service.list_transactions_domain(TransactionListQuery::default()).unwrap();
+        assert_eq!(listed.period_lock.as_ref().map(|lock| lock.locked_through), Some(march_end));
+        assert_eq!(listed.transactions.len(), 2);
+
+        // A closed period can't be shortened without reopening it first
+        let earlier = service.close_period(ClosePeriodCommand {
+            child_id: None,
+            locked_through: PeriodLock::month_end(2025, 2).unwrap(),
+            actor: None,
+        });
+        assert!(earlier.is_err());
+
+        // Later months are still open
+        assert_eq!(delete(vec![april.id]).unwrap().deleted_count, 1);
+
+        let reopened = service.reopen_period(ReopenPeriodCommand { child_id: None, actor: mom }).unwrap();
+        assert_eq!(reopened.previous_lock.map(|lock| lock.locked_through), Some(march_end));
+        assert!(add(1.0, day(3, 20)).is_ok());
+        assert_eq!(delete(vec![march.id]).unwrap().deleted_count, 1);
+
+        let actions: Vec<String> = service
+            .audit_log_repository
+            .list_audit_entries(&child.id, None)
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.action)
+            .filter(|action| action.starts_with("period_"))
+            .collect();
+        // Newest first
+        assert_eq!(actions, vec![AUDIT_ACTION_PERIOD_REOPENED, AUDIT_ACTION_PERIOD_CLOSED]);
+    }
+
+    #[test]
+    fn test_commands_naming_a_child_ignore_the_active_child() {
+        let (service, _conn, _temp_dir) = create_test_service();
+        let ada = create_test_child(&service.child_service, "Ada").unwrap();
+        let ben = create_test_child(&service.child_service, "Ben").unwrap();
+        service
+            .child_service
+            .set_active_child(SetActiveChildCommand { child_id: ada.id.clone() })
+            .unwrap();
+
+        let gift = service
+            .create_transaction(CreateTransactionCommand {
+                child_id: Some(ben.id.clone()),
+                description: "Gift".to_string(),
+                amount: 5.0,
+                date: None,
+                parent_override: false,
+                actor: None,
+                icon: None,
+                transaction_type: None,
+            })
+            .unwrap();
+
+        let list = |child_id: Option<String>| {
+            service
+                .list_transactions_domain(TransactionListQuery { child_id, ..Default::default() })
+                .unwrap()
+                .transactions
+        };
+        assert!(list(None).is_empty());
+        assert_eq!(list(Some(ben.id.clone()))[0].id, gift.id);
+
+        // Deleting by id only looks in the named child's history
+        let missed = service
+            .delete_transactions_domain(DeleteTransactionsCommand { child_id: None, transaction_ids: vec![gift.id.clone()], actor: None })
+            .unwrap();
+        assert_eq!(missed.not_found_ids, vec![gift.id.clone()]);
+        service
+            .delete_transactions_domain(DeleteTransactionsCommand { child_id: Some(ben.id.clone()), transaction_ids: vec![gift.id.clone()], actor: None })
+            .unwrap();
+        assert!(list(Some(ben.id.clone())).is_empty());
+
+        let unknown = service.resolve_child(Some("child::missing")).unwrap_err();
+        assert!(matches!(unknown.downcast_ref::<DomainError>(), Some(DomainError::NotFound(_))));
+    }
+}
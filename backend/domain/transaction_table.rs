@@ -8,9 +8,10 @@
 //!
 //! - **Table Formatting**: Converting raw transactions into formatted display data
 //! - **Amount Formatting**: Configurable currency and sign display options
-//! - **Date Formatting**: Multiple date format options (ISO, short, long)
+//! - **Date Formatting**: Multiple date format options (ISO, short, long), written per locale
 //! - **Input Validation**: Validating transaction form inputs before submission
 //! - **CSS Classification**: Providing styling hints for positive/negative amounts
+//! - **Clipboard Export**: Rendering selected rows as TSV/CSV for pasting into spreadsheets
 //! - **Configuration Management**: Flexible display configuration options
 //!
 //! ## Core Components
@@ -31,6 +32,9 @@
 use shared::{Transaction, FormattedTransaction, AmountType, ValidationResult, ValidationError};
 use anyhow::Result;
 use serde::{Serialize, Deserialize};
+use crate::backend::domain::amount_input;
+use crate::backend::domain::localization::{keys, DateStyle, Locale, StringCatalog};
+use crate::backend::domain::models::currency::Currency;
 
 /// Configuration for transaction table display
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -39,13 +43,19 @@ pub struct TransactionTableConfig {
     pub decimal_places: u8,
     pub date_format: DateFormat,
     pub amount_format: AmountFormat,
+    /// Month names, date order and decimal separator
+    #[serde(default)]
+    pub locale: Locale,
+    /// Currency whose symbol is shown before amounts
+    #[serde(default)]
+    pub currency: Currency,
 }
 
 /// Date formatting options
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DateFormat {
-    MonthDayYear,  // "June 13, 2025"
-    ShortDate,     // "06/13/2025"
+    MonthDayYear,  // "June 13, 2025" (long date in the configured locale)
+    ShortDate,     // "06/13/2025" (numeric date in the configured locale)
     ISO,           // "2025-06-13"
 }
 
@@ -57,23 +67,37 @@ pub enum AmountFormat {
     ColorOnly,        // "$10.00" (styled with color)
 }
 
+/// Text layouts for copying table rows to the clipboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClipboardFormat {
+    /// Tab-separated; what spreadsheets expect when pasting
+    Tsv,
+    /// Comma-separated, or semicolon-separated where the comma is the decimal separator
+    Csv,
+}
+
 /// Transaction table service that handles all table-related business logic
 #[derive(Clone)]
 pub struct TransactionTableService {
     config: TransactionTableConfig,
+    catalog: StringCatalog,
 }
 
 impl TransactionTableService {
     /// Create a new TransactionTableService with default configuration
     pub fn new() -> Self {
-        Self {
-            config: TransactionTableConfig::default(),
-        }
+        Self::with_config(TransactionTableConfig::default())
     }
 
     /// Create a new TransactionTableService with custom configuration
     pub fn with_config(config: TransactionTableConfig) -> Self {
-        Self { config }
+        let catalog = StringCatalog::new(config.locale);
+        Self { config, catalog }
+    }
+
+    /// Create a new TransactionTableService whose messages come from a translated catalog
+    pub fn with_catalog(config: TransactionTableConfig, catalog: StringCatalog) -> Self {
+        Self { config, catalog }
     }
 
     /// Format a list of transactions for table display
@@ -105,10 +129,10 @@ impl TransactionTableService {
         if let Some((year, month, day)) = self.parse_date(rfc3339_date) {
             match self.config.date_format {
                 DateFormat::MonthDayYear => {
-                    format!("{} {}, {}", self.month_name(month), day, year)
+                    self.config.locale.format_date(year as i32, month, day, DateStyle::Long)
                 }
                 DateFormat::ShortDate => {
-                    format!("{:02}/{:02}/{}", month, day, year)
+                    self.config.locale.format_date(year as i32, month, day, DateStyle::Short)
                 }
                 DateFormat::ISO => {
                     format!("{}-{:02}-{:02}", year, month, day)
@@ -123,8 +147,8 @@ impl TransactionTableService {
     /// Format an amount for display based on configuration
     pub fn format_amount(&self, amount: f64) -> String {
         let abs_amount = amount.abs();
-        let currency = if self.config.show_currency_symbol { "$" } else { "" };
-        let formatted_value = format!("{}{:.2}", currency, abs_amount);
+        let currency = if self.config.show_currency_symbol { self.config.currency.symbol() } else { "" };
+        let formatted_value = format!("{}{}", currency, self.config.locale.format_decimal(abs_amount, self.config.decimal_places as usize));
 
         match self.config.amount_format {
             AmountFormat::PlusMinusSign => {
@@ -147,8 +171,8 @@ impl TransactionTableService {
 
     /// Format a balance for display
     pub fn format_balance(&self, balance: f64) -> String {
-        let currency = if self.config.show_currency_symbol { "$" } else { "" };
-        format!("{}{:.2}", currency, balance)
+        let currency = if self.config.show_currency_symbol { self.config.currency.symbol() } else { "" };
+        format!("{}{}", currency, self.config.locale.format_decimal(balance, self.config.decimal_places as usize))
     }
 
     /// Classify amount type for styling purposes
@@ -171,6 +195,45 @@ impl TransactionTableService {
         }
     }
 
+    /// Render transactions as text for pasting into a spreadsheet.
+    ///
+    /// Dates follow the configured date format. Amounts are written as plain
+    /// numbers with the locale's decimal separator so spreadsheets treat them
+    /// as numbers; the currency goes in the column header instead.
+    pub fn format_for_clipboard(&self, transactions: &[Transaction], format: ClipboardFormat) -> String {
+        let separator = match format {
+            ClipboardFormat::Tsv => '\t',
+            ClipboardFormat::Csv if self.config.locale.decimal_separator() == ',' => ';',
+            ClipboardFormat::Csv => ',',
+        };
+        let decimals = self.config.decimal_places as usize;
+        let code = self.config.currency.code();
+
+        let mut rows = vec![vec![
+            "Date".to_string(),
+            "Description".to_string(),
+            format!("Amount ({})", code),
+            format!("Balance ({})", code),
+        ]];
+        for transaction in transactions {
+            let date_str = transaction.date.format("%Y-%m-%dT%H:%M:%S%z").to_string();
+            rows.push(vec![
+                self.format_date(&date_str),
+                transaction.description.clone(),
+                self.config.locale.format_decimal(transaction.amount, decimals),
+                self.config.locale.format_decimal(transaction.balance, decimals),
+            ]);
+        }
+
+        let mut output = String::new();
+        for row in rows {
+            let cells: Vec<String> = row.iter().map(|cell| clipboard_cell(cell, format, separator)).collect();
+            output.push_str(&cells.join(&separator.to_string()));
+            output.push('\n');
+        }
+        output
+    }
+
     /// Validate transaction form input
     pub fn validate_transaction_input(&self, description: &str, amount_input: &str) -> ValidationResult {
         let mut errors = Vec::new();
@@ -213,12 +276,9 @@ impl TransactionTableService {
 
     /// Clean and parse amount input string
     pub fn clean_and_parse_amount(&self, amount_input: &str) -> Result<f64> {
-        // Clean the input - remove dollar signs, spaces, commas
-        let cleaned = amount_input
-            .trim()
-            .replace("$", "")
-            .replace(",", "")
-            .replace(" ", "");
+        // Clean the input - remove currency symbols, spaces and thousands separators
+        let cleaned = amount_input::normalize_amount(amount_input, self.config.locale, "$")
+            .map_err(|e| anyhow::anyhow!(e))?;
 
         // Try to parse as float
         cleaned.parse::<f64>()
@@ -243,29 +303,26 @@ impl TransactionTableService {
         None
     }
 
-    /// Get human-readable month name
-    fn month_name(&self, month: u32) -> &'static str {
-        match month {
-            1 => "January", 2 => "February", 3 => "March", 4 => "April",
-            5 => "May", 6 => "June", 7 => "July", 8 => "August",
-            9 => "September", 10 => "October", 11 => "November", 12 => "December",
-            _ => "Invalid Month",
-        }
-    }
-
     /// Get error message for validation error
     pub fn validation_error_message(&self, error: &ValidationError) -> String {
         match error {
-            ValidationError::EmptyDescription => "Please enter a description".to_string(),
-            ValidationError::DescriptionTooLong(len) => {
-                format!("Description is too long ({} characters). Maximum is 256.", len)
-            }
+            ValidationError::EmptyDescription => self.catalog.get(keys::VALIDATION_EMPTY_DESCRIPTION),
+            ValidationError::DescriptionTooLong(len) => self.catalog.format(
+                keys::VALIDATION_DESCRIPTION_TOO_LONG,
+                &[("length", &len.to_string()), ("max", "256")],
+            ),
             ValidationError::InvalidAmount(msg) => {
-                format!("Please enter a valid amount (like 5 or 5.00): {}", msg)
+                self.catalog.format(keys::VALIDATION_INVALID_AMOUNT, &[("reason", msg)])
+            }
+            ValidationError::AmountNotPositive => self.catalog.get(keys::VALIDATION_AMOUNT_NOT_POSITIVE),
+            ValidationError::AmountTooLarge => {
+                let max = format!("${}", self.config.locale.format_whole_number(1_000_000));
+                self.catalog.format(keys::VALIDATION_AMOUNT_TOO_LARGE, &[("max", &max)])
+            }
+            ValidationError::AmountTooSmall => {
+                let min = format!("${}", self.config.locale.format_decimal(0.01, 2));
+                self.catalog.format(keys::VALIDATION_AMOUNT_TOO_SMALL, &[("min", &min)])
             }
-            ValidationError::AmountNotPositive => "Amount must be greater than 0".to_string(),
-            ValidationError::AmountTooLarge => "Amount is too large. Maximum is $1,000,000".to_string(),
-            ValidationError::AmountTooSmall => "Amount is too small. Minimum is $0.01".to_string(),
         }
     }
 
@@ -275,6 +332,21 @@ impl TransactionTableService {
     }
 }
 
+/// Make a cell safe to paste: TSV has no quoting, so tabs and line breaks become
+/// spaces; CSV quotes cells containing the separator, quotes or line breaks.
+fn clipboard_cell(value: &str, format: ClipboardFormat, separator: char) -> String {
+    match format {
+        ClipboardFormat::Tsv => value.replace(['\t', '\r', '\n'], " "),
+        ClipboardFormat::Csv => {
+            if value.contains([separator, '"', '\r', '\n']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value.to_string()
+            }
+        }
+    }
+}
+
 impl Default for TransactionTableService {
     fn default() -> Self {
         Self::new()
@@ -288,6 +360,8 @@ impl Default for TransactionTableConfig {
             decimal_places: 2,
             date_format: DateFormat::MonthDayYear,
             amount_format: AmountFormat::PlusMinusSign,
+            locale: Locale::default(),
+            currency: Currency::default(),
         }
     }
 }
@@ -311,6 +385,10 @@ mod tests {
             amount,
             balance,
             transaction_type: if amount >= 0.0 { shared::TransactionType::Income } else { shared::TransactionType::Expense },
+            actor: None,
+            category: None,
+            icon: None,
+            locked: false,
         }
     }
 
@@ -369,6 +447,58 @@ mod tests {
         assert_eq!(service.format_date("2025-06-13T09:00:00-04:00"), "2025-06-13");
     }
 
+    #[test]
+    fn test_locale_formatting() {
+        let config = TransactionTableConfig { locale: Locale::DeDe, ..TransactionTableConfig::default() };
+        let service = TransactionTableService::with_config(config);
+
+        assert_eq!(service.format_date("2025-06-19T09:00:00-04:00"), "19. Juni 2025");
+        assert_eq!(service.format_amount(-5.25), "-$5,25");
+        assert_eq!(service.format_balance(100.5), "$100,50");
+        assert_eq!(service.clean_and_parse_amount("1.234,56").unwrap(), 1234.56);
+        assert_eq!(
+            service.validation_error_message(&ValidationError::AmountTooLarge),
+            "Amount is too large. Maximum is $1.000.000"
+        );
+
+        let config = TransactionTableConfig { locale: Locale::EnGb, ..TransactionTableConfig::default() };
+        let service = TransactionTableService::with_config(config);
+        assert_eq!(service.format_date("2025-06-19T09:00:00-04:00"), "19 June 2025");
+    }
+
+    #[test]
+    fn test_clipboard_formats() {
+        let transactions = vec![
+            create_test_transaction("a", "2025-06-13T09:00:00-04:00", "Allowance", 5.0, 15.0),
+            create_test_transaction("b", "2025-06-14T09:00:00-04:00", "Toy, \"big\"\tone", -3.5, 11.5),
+        ];
+
+        let config = TransactionTableConfig { date_format: DateFormat::ISO, ..TransactionTableConfig::default() };
+        let service = TransactionTableService::with_config(config);
+        assert_eq!(
+            service.format_for_clipboard(&transactions, ClipboardFormat::Tsv),
+            "Date\tDescription\tAmount (USD)\tBalance (USD)\n\
+             2025-06-13\tAllowance\t5.00\t15.00\n\
+             2025-06-14\tToy, \"big\" one\t-3.50\t11.50\n"
+        );
+        assert_eq!(
+            service.format_for_clipboard(&transactions[1..], ClipboardFormat::Csv).lines().nth(1),
+            Some("2025-06-14,\"Toy, \"\"big\"\"\tone\",-3.50,11.50")
+        );
+
+        // Comma decimals switch CSV to semicolons
+        let config = TransactionTableConfig {
+            date_format: DateFormat::ShortDate,
+            locale: Locale::DeDe,
+            currency: Currency::Eur,
+            ..TransactionTableConfig::default()
+        };
+        let service = TransactionTableService::with_config(config);
+        let csv = service.format_for_clipboard(&transactions[..1], ClipboardFormat::Csv);
+        assert_eq!(csv, "Date;Description;Amount (EUR);Balance (EUR)\n13.06.2025;Allowance;5,00;15,00\n");
+        assert_eq!(service.format_amount(-3.5), "-€3,50");
+    }
+
     #[test]
     fn test_different_amount_formats() {
         let mut config = TransactionTableConfig::default();
@@ -0,0 +1,299 @@
+//! Transaction template service domain logic for the allowance tracker.
+//!
+//! Each child can save whole transactions as templates ("favorites"): a
+//! name for the button, and the description, amount, category and kind the
+//! transaction gets. Using a template records that transaction through
+//! `TransactionService`, so it follows the same rules as one typed into a
+//! form: closed periods, the "no negative balance" rule and the audit log.
+
+use anyhow::Result;
+use log::info;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::backend::domain::commands::templates::{
+    CreateTemplateCommand, DeleteTemplateCommand, ListTemplatesQuery, ListTemplatesResult, UpdateTemplateCommand,
+    UseTemplateCommand, UseTemplateResult,
+};
+use crate::backend::domain::commands::transactions::CreateTransactionCommand;
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::models::transaction::TransactionType;
+use crate::backend::domain::models::transaction_template::TransactionTemplate;
+use crate::backend::domain::TransactionService;
+use crate::backend::storage::csv::{CsvConnection, TransactionTemplateRepository};
+use crate::backend::storage::traits::TransactionTemplateStorage;
+
+/// Most templates a child can have
+pub const MAX_TEMPLATES: usize = 12;
+
+/// Longest name a template's button can show
+pub const MAX_TEMPLATE_NAME_LENGTH: usize = 24;
+
+/// The fields a template is made of, checked and trimmed
+struct TemplateFields {
+    name: String,
+    description: String,
+    amount: f64,
+    category: Option<String>,
+    transaction_type: TransactionType,
+}
+
+/// Service for a child's saved transaction templates
+#[derive(Clone)]
+pub struct TransactionTemplateService {
+    template_repository: TransactionTemplateRepository,
+    transaction_service: Arc<TransactionService>,
+}
+
+impl TransactionTemplateService {
+    /// Create a new TransactionTemplateService
+    pub fn new(csv_conn: Arc<CsvConnection>, transaction_service: Arc<TransactionService>) -> Self {
+        let template_repository = TransactionTemplateRepository::new((*csv_conn).clone());
+        Self {
+            template_repository,
+            transaction_service,
+        }
+    }
+
+    /// Save a template for the active child
+    pub fn create_template(&self, command: CreateTemplateCommand) -> Result<TransactionTemplate> {
+        let fields = checked_fields(
+            &command.name,
+            &command.description,
+            command.amount,
+            command.category,
+            command.transaction_type,
+        )?;
+        let child = self.transaction_service.get_active_child()?;
+        let existing = self.template_repository.list_templates(&child.id)?;
+        ensure_unique_name(&existing, &fields.name, None)?;
+        if existing.len() >= MAX_TEMPLATES {
+            return Err(DomainError::invalid(format!(
+                "{} already has {} templates; delete one first",
+                child.name, MAX_TEMPLATES
+            ))
+            .into());
+        }
+
+        // Templates saved in quick succession would otherwise share a timestamp
+        let mut now_millis = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+        while existing.iter().any(|t| t.id == TransactionTemplate::generate_id(&child.id, now_millis)) {
+            now_millis += 1;
+        }
+        let template = TransactionTemplate {
+            id: TransactionTemplate::generate_id(&child.id, now_millis),
+            child_id: child.id.clone(),
+            name: fields.name,
+            description: fields.description,
+            amount: fields.amount,
+            category: fields.category,
+            transaction_type: fields.transaction_type,
+        };
+        self.template_repository.store_template(&template)?;
+
+        info!("⭐ TEMPLATE: {} saved \"{}\"", child.id, template.name);
+        Ok(template)
+    }
+
+    /// Change one of the active child's templates
+    pub fn update_template(&self, command: UpdateTemplateCommand) -> Result<TransactionTemplate> {
+        let fields = checked_fields(
+            &command.name,
+            &command.description,
+            command.amount,
+            command.category,
+            command.transaction_type,
+        )?;
+        let child = self.transaction_service.get_active_child()?;
+        let existing = self.template_repository.list_templates(&child.id)?;
+        let Some(current) = existing.iter().find(|t| t.id == command.template_id) else {
+            return Err(DomainError::NotFound(format!("Template not found: {}", command.template_id)).into());
+        };
+        ensure_unique_name(&existing, &fields.name, Some(&current.id))?;
+
+        let template = TransactionTemplate {
+            name: fields.name,
+            description: fields.description,
+            amount: fields.amount,
+            category: fields.category,
+            transaction_type: fields.transaction_type,
+            ..current.clone()
+        };
+        self.template_repository.store_template(&template)?;
+
+        info!("⭐ TEMPLATE: {} updated {}", child.id, template.id);
+        Ok(template)
+    }
+
+    /// Delete one of the active child's templates
+    pub fn delete_template(&self, command: DeleteTemplateCommand) -> Result<()> {
+        let child = self.transaction_service.get_active_child()?;
+        if !self.template_repository.delete_template(&child.id, &command.template_id)? {
+            return Err(DomainError::NotFound(format!("Template not found: {}", command.template_id)).into());
+        }
+        info!("⭐ TEMPLATE: {} deleted {}", child.id, command.template_id);
+        Ok(())
+    }
+
+    /// A child's templates
+    pub fn list_templates(&self, query: ListTemplatesQuery) -> Result<ListTemplatesResult> {
+        let child_id = match query.child_id {
+            Some(id) => id,
+            None => self.transaction_service.get_active_child()?.id,
+        };
+        let templates = self.template_repository.list_templates(&child_id)?;
+        Ok(ListTemplatesResult { child_id, templates })
+    }
+
+    /// Record the transaction one of the active child's templates describes
+    pub fn use_template(&self, command: UseTemplateCommand) -> Result<UseTemplateResult> {
+        let child = self.transaction_service.get_active_child()?;
+        let template = self
+            .template_repository
+            .list_templates(&child.id)?
+            .into_iter()
+            .find(|t| t.id == command.template_id)
+            .ok_or_else(|| DomainError::NotFound(format!("Template not found: {}", command.template_id)))?;
+
+        let transaction = self.transaction_service.create_categorized_transaction(
+            CreateTransactionCommand {
+                child_id: Some(child.id.clone()),
+                description: template.description.clone(),
+                amount: template.signed_amount(),
+                date: command.date,
+                parent_override: command.parent_override,
+                actor: command.actor,
+                icon: None,
+                transaction_type: Some(template.transaction_type.clone()),
+            },
+            template.category.clone(),
+        )?;
+
+        info!("⭐ TEMPLATE: {} used \"{}\" for {}", child.id, template.name, transaction.id);
+        Ok(UseTemplateResult { template, transaction })
+    }
+}
+
+/// Trim and check what a template is being saved with
+fn checked_fields(
+    name: &str,
+    description: &str,
+    amount: f64,
+    category: Option<String>,
+    transaction_type: TransactionType,
+) -> Result<TemplateFields> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(DomainError::invalid("Template name cannot be empty").into());
+    }
+    if name.chars().count() > MAX_TEMPLATE_NAME_LENGTH {
+        return Err(DomainError::invalid(format!(
+            "Template names can be at most {} characters",
+            MAX_TEMPLATE_NAME_LENGTH
+        ))
+        .into());
+    }
+    let description = description.trim().to_string();
+    if description.is_empty() {
+        return Err(DomainError::invalid("Template description cannot be empty").into());
+    }
+    if !amount.is_finite() || amount <= 0.0 {
+        return Err(DomainError::invalid("Template amounts must be greater than 0").into());
+    }
+    if !TransactionTemplate::KINDS.contains(&transaction_type) {
+        return Err(DomainError::invalid(format!(
+            "{} transactions can't be saved as a template",
+            transaction_type.label()
+        ))
+        .into());
+    }
+    let category = category.map(|c| c.trim().to_string()).filter(|c| !c.is_empty());
+    Ok(TemplateFields { name, description, amount, category, transaction_type })
+}
+
+/// Names are what tells the buttons apart, so they must differ ignoring case
+fn ensure_unique_name(existing: &[TransactionTemplate], name: &str, except_id: Option<&str>) -> Result<()> {
+    let taken = existing
+        .iter()
+        .any(|t| Some(t.id.as_str()) != except_id && t.name.eq_ignore_ascii_case(name));
+    if taken {
+        return Err(DomainError::Conflict(format!("There is already a template called \"{}\"", name)).into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand};
+    use crate::backend::domain::{child_service::ChildService, AllowanceService, BalanceService};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_templates_are_saved_edited_and_used() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let child_service = ChildService::new(connection.clone());
+        let balance_service = BalanceService::new(connection.clone());
+        let transaction_service = Arc::new(TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            AllowanceService::new(connection.clone()),
+            balance_service,
+        ));
+        let service = TransactionTemplateService::new(connection, transaction_service.clone());
+
+        let child = child_service
+            .create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })?
+            .child;
+        child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() })?;
+
+        let chores = service.create_template(CreateTemplateCommand {
+            name: "Chores".to_string(),
+            description: "Weekly chores".to_string(),
+            amount: 10.0,
+            category: None,
+            transaction_type: TransactionType::Income,
+        })?;
+        let lunch = service.create_template(CreateTemplateCommand {
+            name: " Lunch ".to_string(),
+            description: "Lunch money".to_string(),
+            amount: 4.5,
+            category: Some("Food".to_string()),
+            transaction_type: TransactionType::Expense,
+        })?;
+        assert_eq!(lunch.name, "Lunch");
+
+        // Names must differ, and amounts are entered as positive numbers
+        let duplicate = CreateTemplateCommand {
+            name: "lunch".to_string(),
+            description: "Lunch again".to_string(),
+            amount: 4.5,
+            category: None,
+            transaction_type: TransactionType::Expense,
+        };
+        assert!(service.create_template(duplicate.clone()).is_err());
+        assert!(service.create_template(CreateTemplateCommand { name: "Snack".to_string(), amount: -2.0, ..duplicate }).is_err());
+
+        let lunch = service.update_template(UpdateTemplateCommand {
+            template_id: lunch.id.clone(),
+            name: "School lunch".to_string(),
+            description: "Lunch money".to_string(),
+            amount: 5.0,
+            category: Some("Food".to_string()),
+            transaction_type: TransactionType::Expense,
+        })?;
+
+        service.use_template(UseTemplateCommand { template_id: chores.id.clone(), date: None, parent_override: false, actor: None })?;
+        let used = service.use_template(UseTemplateCommand { template_id: lunch.id.clone(), date: None, parent_override: false, actor: None })?;
+        assert_eq!(used.transaction.amount, -5.0);
+        assert_eq!(used.transaction.description, "Lunch money");
+        assert_eq!(used.transaction.category.as_deref(), Some("Food"));
+        assert_eq!(used.transaction.balance, 5.0);
+
+        service.delete_template(DeleteTemplateCommand { template_id: chores.id })?;
+        let listed = service.list_templates(ListTemplatesQuery { child_id: Some(child.id) })?;
+        assert_eq!(listed.templates, vec![lunch]);
+        Ok(())
+    }
+}
@@ -0,0 +1,312 @@
+//! Update service domain logic for the allowance tracker.
+//!
+//! Builds are published as GitHub releases, with betas marked as
+//! pre-releases. The service reads the release list, picks the newest one on
+//! the family's channel and offers it when it is newer than the running
+//! build, with its release notes as the changelog. A parent can check from
+//! settings; the app's periodic refresh only contacts the release list once
+//! a parent has turned automatic checks on, and then at most once a day.
+//!
+//! Installing is left to the release's installer: the app points the parent
+//! at the download rather than replacing its own files, which signed and
+//! packaged builds don't allow. "Later" postpones that version, so the
+//! scheduled check stays quiet until a newer release comes out.
+//!
+//! The release list URL can be pointed elsewhere, for a fork or a test feed,
+//! with the `ALLOWANCE_TRACKER_RELEASES_URL` environment variable.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use log::info;
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::backend::domain::commands::updates::{PostponeUpdateCommand, UpdateAutomaticCheckCommand, UpdateCheckResult, UpdateReleaseChannelCommand};
+use crate::backend::domain::errors::DomainError;
+use crate::backend::domain::models::update_settings::{AvailableUpdate, ReleaseChannel, ReleaseVersion, UpdateSettings};
+use crate::backend::storage::csv::{CsvConnection, GlobalConfigRepository};
+use crate::backend::storage::GlobalConfigStorage;
+
+/// Where releases are listed unless `ALLOWANCE_TRACKER_RELEASES_URL` says otherwise
+pub const DEFAULT_RELEASES_URL: &str = "https://api.github.com/repos/kerryqhart/allowance-tracker/releases";
+
+/// How long the scheduled check waits between checks
+const CHECK_INTERVAL_HOURS: i64 = 24;
+
+/// How long a release list request may take before the check gives up
+const REQUEST_TIMEOUT_SECONDS: u64 = 10;
+
+/// One published release, as the update check needs it
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PublishedRelease {
+    #[serde(rename = "tag_name")]
+    pub tag: String,
+    #[serde(default)]
+    pub prerelease: bool,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(rename = "body", default)]
+    pub notes: Option<String>,
+    #[serde(rename = "html_url")]
+    pub url: String,
+    #[serde(default)]
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+/// Somewhere releases are listed, so tests don't go to the network
+pub trait ReleaseFeed: Send + Sync {
+    fn releases(&self) -> Result<Vec<PublishedRelease>>;
+}
+
+/// Releases from the GitHub releases API
+pub struct GitHubReleaseFeed {
+    url: String,
+}
+
+impl GitHubReleaseFeed {
+    /// The project's release list, or the one `ALLOWANCE_TRACKER_RELEASES_URL` names
+    pub fn from_env() -> Self {
+        let url = std::env::var("ALLOWANCE_TRACKER_RELEASES_URL")
+            .ok()
+            .filter(|url| !url.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_RELEASES_URL.to_string());
+        Self { url }
+    }
+}
+
+impl ReleaseFeed for GitHubReleaseFeed {
+    fn releases(&self) -> Result<Vec<PublishedRelease>> {
+        let releases = ureq::get(&self.url)
+            .set("Accept", "application/vnd.github+json")
+            .set("User-Agent", concat!("allowance-tracker/", env!("CARGO_PKG_VERSION")))
+            .timeout(std::time::Duration::from_secs(REQUEST_TIMEOUT_SECONDS))
+            .call()?
+            .into_json()?;
+        Ok(releases)
+    }
+}
+
+/// Service for the release channel and update checks
+#[derive(Clone)]
+pub struct UpdateService {
+    csv_connection: Arc<CsvConnection>,
+    global_config_repository: GlobalConfigRepository,
+    feed: Arc<dyn ReleaseFeed>,
+    current_version: String,
+}
+
+impl UpdateService {
+    /// Create a new UpdateService
+    pub fn new(csv_conn: Arc<CsvConnection>) -> Self {
+        Self {
+            global_config_repository: GlobalConfigRepository::new((*csv_conn).clone()),
+            csv_connection: csv_conn,
+            feed: Arc::new(GitHubReleaseFeed::from_env()),
+            current_version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+
+    /// Read releases from somewhere other than the project's release list
+    pub fn with_feed(mut self, feed: Arc<dyn ReleaseFeed>) -> Self {
+        self.feed = feed;
+        self
+    }
+
+    /// The release channel and what the scheduled check last did
+    pub fn get_update_settings(&self) -> Result<UpdateSettings> {
+        Ok(self.global_config_repository.get_global_config()?.updates)
+    }
+
+    /// Choose which releases are offered; a postponed update is forgotten
+    pub fn update_release_channel(&self, command: UpdateReleaseChannelCommand) -> Result<UpdateSettings> {
+        let mut config = self.global_config_repository.get_global_config()?;
+        config.updates.channel = command.channel;
+        config.updates.postponed_version = None;
+        config.updated_at = Utc::now().to_rfc3339();
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!("⬆️ UPDATES: Release channel set to {:?}", command.channel);
+        Ok(config.updates)
+    }
+
+    /// Turn the daily check on or off
+    pub fn update_automatic_check(&self, command: UpdateAutomaticCheckCommand) -> Result<UpdateSettings> {
+        let mut config = self.global_config_repository.get_global_config()?;
+        config.updates.check_automatically = command.enabled;
+        config.updated_at = Utc::now().to_rfc3339();
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!("⬆️ UPDATES: Automatic checks {}", if command.enabled { "on" } else { "off" });
+        Ok(config.updates)
+    }
+
+    /// Look for a newer release now, including one that was postponed
+    pub fn check_for_updates(&self) -> Result<UpdateCheckResult> {
+        let channel = self.get_update_settings()?.channel;
+        let available = self.newest_release(channel)?;
+        Ok(UpdateCheckResult { current_version: self.current_version.clone(), channel, available })
+    }
+
+    /// Keep quiet about an update until a newer one comes out
+    pub fn postpone_update(&self, command: PostponeUpdateCommand) -> Result<UpdateSettings> {
+        if ReleaseVersion::parse(&command.version).is_none() {
+            return Err(DomainError::invalid(format!("Not a version: {}", command.version)).into());
+        }
+        let mut config = self.global_config_repository.get_global_config()?;
+        config.updates.postponed_version = Some(command.version);
+        config.updated_at = Utc::now().to_rfc3339();
+        self.global_config_repository.update_global_config(&config)?;
+
+        info!("⬆️ UPDATES: Postponed {:?}", config.updates.postponed_version);
+        Ok(config.updates)
+    }
+
+    /// Check for an update if the last check was a day ago or more
+    ///
+    /// Called from the app's periodic refresh. Returns `None` when automatic
+    /// checks are off, it isn't time yet, the data is read-only, or there is
+    /// nothing new that hasn't been postponed; otherwise the result's
+    /// `available` is always set.
+    pub fn run_scheduled_check(&self) -> Result<Option<UpdateCheckResult>> {
+        self.run_scheduled_check_at(Utc::now())
+    }
+
+    fn run_scheduled_check_at(&self, now: DateTime<Utc>) -> Result<Option<UpdateCheckResult>> {
+        let settings = self.get_update_settings()?;
+        let checked_recently = settings
+            .last_checked_at
+            .is_some_and(|last| now - last < Duration::hours(CHECK_INTERVAL_HOURS));
+        if !settings.check_automatically || checked_recently || self.csv_connection.read_only_source().is_some() {
+            return Ok(None);
+        }
+
+        // Record the attempt first, so an offline laptop doesn't retry every tick
+        let mut config = self.global_config_repository.get_global_config()?;
+        config.updates.last_checked_at = Some(now);
+        self.global_config_repository.update_global_config(&config)?;
+
+        let available = self.newest_release(settings.channel)?;
+        let postponed = settings.postponed_version.as_deref().and_then(ReleaseVersion::parse);
+        let available = available.filter(|update| match (&postponed, ReleaseVersion::parse(&update.version)) {
+            (Some(postponed), Some(version)) => version > *postponed,
+            _ => true,
+        });
+        Ok(available.map(|update| UpdateCheckResult {
+            current_version: self.current_version.clone(),
+            channel: settings.channel,
+            available: Some(update),
+        }))
+    }
+
+    /// The newest release on the channel, when it is newer than the running build
+    fn newest_release(&self, channel: ReleaseChannel) -> Result<Option<AvailableUpdate>> {
+        let current = ReleaseVersion::parse(&self.current_version)
+            .ok_or_else(|| anyhow::anyhow!("Running version {} can't be compared", self.current_version))?;
+
+        let newest = self
+            .feed
+            .releases()?
+            .into_iter()
+            .filter(|release| !release.draft && channel.includes(release.prerelease))
+            .filter_map(|release| ReleaseVersion::parse(&release.tag).map(|version| (version, release)))
+            .filter(|(version, _)| *version > current)
+            .max_by(|(a, _), (b, _)| a.cmp(b));
+
+        Ok(newest.map(|(_, release)| {
+            info!("⬆️ UPDATES: {} is available (running {})", release.tag, self.current_version);
+            AvailableUpdate {
+                version: release.tag.trim_start_matches(['v', 'V']).to_string(),
+                prerelease: release.prerelease,
+                changelog: release.notes.unwrap_or_default().trim().to_string(),
+                download_url: release.url,
+                published_at: release.published_at,
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    struct FixedFeed(Vec<PublishedRelease>);
+
+    impl ReleaseFeed for FixedFeed {
+        fn releases(&self) -> Result<Vec<PublishedRelease>> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn release(tag: &str, prerelease: bool) -> PublishedRelease {
+        PublishedRelease {
+            tag: tag.to_string(),
+            prerelease,
+            draft: false,
+            notes: Some(format!("What's new in {}\n", tag)),
+            url: format!("https://example.com/releases/{}", tag),
+            published_at: None,
+        }
+    }
+
+    fn service(temp_dir: &TempDir, releases: Vec<PublishedRelease>) -> Result<UpdateService> {
+        let connection = Arc::new(CsvConnection::new(temp_dir.path())?);
+        let mut service = UpdateService::new(connection).with_feed(Arc::new(FixedFeed(releases)));
+        service.current_version = "1.0.0".to_string();
+        Ok(service)
+    }
+
+    #[test]
+    fn test_channel_decides_which_releases_are_offered() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let service = service(&temp_dir, vec![release("v0.9.0", false), release("v1.1.0", false), release("v1.2.0-beta.1", true)])?;
+
+        let stable = service.check_for_updates()?;
+        assert_eq!(stable.current_version, "1.0.0");
+        let update = stable.available.unwrap();
+        assert_eq!(update.version, "1.1.0");
+        assert_eq!(update.changelog, "What's new in v1.1.0");
+
+        service.update_release_channel(UpdateReleaseChannelCommand { channel: ReleaseChannel::Beta })?;
+        assert_eq!(service.check_for_updates()?.available.unwrap().version, "1.2.0-beta.1");
+        Ok(())
+    }
+
+    #[test]
+    fn test_scheduled_check_runs_daily_and_respects_postponed_updates() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let service = service(&temp_dir, vec![release("v1.1.0", false)])?;
+        let now = Utc::now();
+        service.update_automatic_check(UpdateAutomaticCheckCommand { enabled: true })?;
+
+        service.postpone_update(PostponeUpdateCommand { version: "1.1.0".to_string() })?;
+        assert_eq!(service.run_scheduled_check_at(now)?, None);
+        assert_eq!(service.get_update_settings()?.last_checked_at, Some(now));
+        assert!(service.check_for_updates()?.available.is_some(), "a manual check still shows it");
+
+        let newer = UpdateService { feed: Arc::new(FixedFeed(vec![release("v1.2.0", false)])), ..service };
+        assert_eq!(newer.run_scheduled_check_at(now + Duration::hours(1))?, None, "checked an hour ago");
+        let result = newer.run_scheduled_check_at(now + Duration::hours(25))?.unwrap();
+        assert_eq!(result.available.unwrap().version, "1.2.0");
+        Ok(())
+    }
+
+    #[test]
+    fn test_scheduled_check_stays_off_until_a_parent_turns_it_on() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let service = service(&temp_dir, vec![release("v1.1.0", false)])?;
+        let now = Utc::now();
+
+        assert!(!service.get_update_settings()?.check_automatically);
+        assert_eq!(service.run_scheduled_check_at(now)?, None);
+        assert_eq!(service.get_update_settings()?.last_checked_at, None, "nothing was asked for");
+
+        let settings = service.update_automatic_check(UpdateAutomaticCheckCommand { enabled: true })?;
+        assert!(settings.check_automatically);
+        assert_eq!(service.run_scheduled_check_at(now)?.unwrap().available.unwrap().version, "1.1.0");
+
+        service.update_automatic_check(UpdateAutomaticCheckCommand { enabled: false })?;
+        assert_eq!(service.run_scheduled_check_at(now + Duration::hours(25))?, None);
+        Ok(())
+    }
+}
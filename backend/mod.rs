@@ -1,11 +1,17 @@
 //! # Backend Module for egui Frontend
 //!
 //! This backend module provides direct access to domain services and storage
-//! for the egui frontend. Unlike the Tauri version, this backend:
+//! for the egui frontend. It is the only backend tree; the separate Tauri
+//! backend it was forked from is gone, so email, encryption and the other
+//! services only need to be written once. This backend:
 //! - Uses synchronous operations (no async/await)
 //! - Provides direct access to domain services
 //! - Excludes the IO/REST layer entirely
 //! - Is optimized for desktop-only operation
+//!
+//! A host with an async runtime should call it through `frontend_api::AllowanceApi`
+//! from its blocking thread pool rather than growing a second, async copy of the
+//! services.
 
 use anyhow::Result;
 use std::sync::Arc;
@@ -14,6 +20,9 @@ use std::sync::Arc;
 pub mod domain;
 pub mod storage;
 
+// The shared frontend API, served in-process
+pub mod api;
+
 // Re-export commonly used types
 pub use storage::csv::CsvConnection;
 
@@ -23,34 +32,118 @@ pub struct Backend {
     pub transaction_service: Arc<domain::TransactionService>,
     pub calendar_service: domain::CalendarService,
     pub allowance_service: domain::AllowanceService,
-    pub goal_service: domain::GoalService,
+    pub goal_service: Arc<domain::GoalService>,
     pub parental_control_service: domain::ParentalControlService,
     pub balance_service: domain::BalanceService,
     pub data_directory_service: domain::DataDirectoryService,
     pub export_service: domain::ExportService,
+    pub reports_service: domain::ReportsService,
+    pub archive_service: domain::ArchiveService,
+    pub loan_service: domain::LoanService,
+    pub allowance_advance_service: domain::AllowanceAdvanceService,
+    pub planned_purchase_service: domain::PlannedPurchaseService,
+    pub quick_pick_service: domain::QuickPickService,
+    pub transaction_template_service: domain::TransactionTemplateService,
+    pub reset_service: domain::ResetService,
+    pub retention_service: domain::RetentionService,
+    pub budget_service: domain::BudgetService,
+    pub scheduled_report_service: domain::ScheduledReportService,
+    pub desktop_notification_service: domain::DesktopNotificationService,
+    pub update_service: domain::UpdateService,
+    pub fridge_view_service: domain::FridgeViewService,
+    pub health_service: domain::HealthService,
+    pub import_service: domain::ImportService,
+    pub family_service: domain::FamilyService,
+    pub encryption_service: domain::EncryptionService,
+    pub secrets_service: domain::SecretsService,
+    pub localization_service: domain::LocalizationService,
+    pub preferences_service: domain::PreferencesService,
+    pub snapshot_service: domain::SnapshotService,
+    pub feature_flag_service: domain::FeatureFlagService,
+    pub celebration_service: domain::CelebrationService,
+    pub agreement_service: domain::AgreementService,
+    /// Watches `email_config.toml`; `None` when the backend was given a fixed config
+    email_config_watcher: Option<domain::EmailConfigWatcher>,
 }
 
 impl Backend {
     /// Create a new backend instance with all services
     pub fn new() -> Result<Self> {
-        // Use the real data directory in ~/Documents/Allowance Tracker
-        let home_dir = dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
-        let data_path = home_dir.join("Documents").join("Allowance Tracker");
-        
-        // Create the CSV connection with the real data directory
+        // Use ~/Documents/Allowance Tracker, or the portable or chosen folder when there is one
+        let data_path = storage::csv::data_location::resolve_data_directory()?;
         log::info!("🔍 Backend::new() using real data path: {:?}", data_path);
+        Self::with_data_directory(data_path)
+    }
+    
+    /// Create a backend over a data directory, with the real secrets and email config
+    ///
+    /// Used when the usual folder couldn't be opened and a parent picked another.
+    pub fn with_data_directory(data_path: impl AsRef<std::path::Path>) -> Result<Self> {
+        // Passwords live in the OS keychain (or an encrypted file), not in config files
+        let secrets_service = domain::SecretsService::new()?;
+        
+        // Load email config for transaction notifications
+        let email_config_watcher = domain::EmailConfigWatcher::new("email_config.toml", secrets_service.clone());
+        let email_config = email_config_watcher.load();
+        log::info!("📧 Email config loaded: SMTP server = {}", email_config.smtp_server);
+        
+        let mut backend = Self::open(data_path, secrets_service, email_config)?;
+        backend.email_config_watcher = Some(email_config_watcher);
+        Ok(backend)
+    }
+    
+    /// Pick up edits to `email_config.toml` without restarting
+    ///
+    /// Scheduler settings and feature flags live in the global config, which is
+    /// read on every use, so only the email config needs reloading.
+    pub fn reload_config_if_changed(&self) {
+        let Some(watcher) = &self.email_config_watcher else {
+            return;
+        };
+        if let Some(email_config) = watcher.reload_if_changed() {
+            match self.transaction_service.reload_email_config(email_config) {
+                Ok(()) => log::info!("📧 Email config reloaded"),
+                Err(e) => log::warn!("📧 Reloaded email config could not be used; keeping the previous one: {}", e),
+            }
+        }
+    }
+    
+    /// Create a backend over any data directory, with the given secrets and email config
+    ///
+    /// `new()` is this with the real data directory; tests and tools open a
+    /// temp directory with a file-backed secret store instead.
+    pub fn open(
+        data_path: impl AsRef<std::path::Path>,
+        secrets_service: domain::SecretsService,
+        email_config: domain::EmailConfig,
+    ) -> Result<Self> {
         let csv_connection = Arc::new(CsvConnection::new(data_path)?);
+        let missing_folders = csv_connection.missing_redirect_targets()?;
+        if let Some(source) = storage::csv::read_only::read_only_source_from_environment() {
+            csv_connection.set_read_only(Some(source));
+            log::info!("🔧 MIGRATION: Skipping config migrations in read-only mode");
+        } else if !missing_folders.is_empty() {
+            // Writing now would put data in the default location instead of the missing folder
+            for (child_directory, path) in &missing_folders {
+                log::error!("📁 Data folder for '{}' is missing: {:?}", child_directory, path);
+            }
+            csv_connection.set_read_only(Some(storage::csv::ReadOnlySource::DataDirectoryMissing));
+            log::info!("🔧 MIGRATION: Skipping config migrations until the missing folders are back");
+        } else {
+            // Upgrade old config files; if any can't be upgraded, open read-only so
+            // nothing overwrites data this version doesn't understand
+            let report = storage::csv::migrations::run_startup_migrations(&csv_connection)?;
+            if !report.failed.is_empty() {
+                log::error!("🔧 MIGRATION: {} config file(s) could not be migrated; opening data read-only", report.failed.len());
+                csv_connection.set_read_only(Some(storage::csv::ReadOnlySource::MigrationFailed));
+            }
+        }
         
         // Create services using the Arc<CsvConnection> pattern
         let child_service = domain::child_service::ChildService::new(csv_connection.clone());
         let allowance_service = domain::AllowanceService::new(csv_connection.clone());
         let balance_service = domain::BalanceService::new(csv_connection.clone());
         
-        // Load email config and create TransactionService with email support
-        let email_config_path = std::path::Path::new("email_config.toml");
-        let email_config = domain::EmailConfigService::load_config_or_default(email_config_path);
-        log::info!("📧 Email config loaded: SMTP server = {}", email_config.smtp_server);
-        
         let transaction_service = Arc::new(domain::TransactionService::with_email_service(
             csv_connection.clone(),
             child_service.clone(),
@@ -61,15 +154,15 @@ impl Backend {
         
         let calendar_service = domain::CalendarService::new();
         
-        let goal_service = domain::GoalService::new(
+        let goal_service = Arc::new(domain::GoalService::new(
             csv_connection.clone(),
             child_service.clone(),
             allowance_service.clone(),
             transaction_service.clone(), // Pass Arc
             balance_service.clone(),
-        );
+        ));
         
-        let parental_control_service = domain::ParentalControlService::new(csv_connection.clone());
+        let parental_control_service = domain::ParentalControlService::new(csv_connection.clone(), &secrets_service);
         
         let data_directory_service = domain::DataDirectoryService::new(
             csv_connection.clone(),
@@ -78,6 +171,90 @@ impl Backend {
         
         let export_service = domain::ExportService::new();
         
+        let reports_service = domain::ReportsService::new(csv_connection.clone(), child_service.clone());
+        
+        let archive_service = domain::ArchiveService::new(csv_connection.clone(), child_service.clone());
+        
+        let loan_service = domain::LoanService::new(
+            csv_connection.clone(),
+            child_service.clone(),
+            transaction_service.clone(),
+        );
+        
+        let allowance_advance_service = domain::AllowanceAdvanceService::new(
+            csv_connection.clone(),
+            child_service.clone(),
+            allowance_service.clone(),
+            transaction_service.clone(),
+        );
+        
+        let planned_purchase_service = domain::PlannedPurchaseService::new(
+            csv_connection.clone(),
+            child_service.clone(),
+            transaction_service.clone(),
+            balance_service.clone(),
+        );
+        
+        let quick_pick_service = domain::QuickPickService::new(csv_connection.clone(), child_service.clone());
+        
+        let transaction_template_service = domain::TransactionTemplateService::new(csv_connection.clone(), transaction_service.clone());
+        
+        let reset_service = domain::ResetService::new(csv_connection.clone());
+        
+        let retention_service = domain::RetentionService::new(csv_connection.clone(), child_service.clone());
+        
+        let budget_service = domain::BudgetService::new(csv_connection.clone(), child_service.clone());
+        
+        let scheduled_report_service = domain::ScheduledReportService::new(
+            csv_connection.clone(),
+            child_service.clone(),
+            reports_service.clone(),
+        );
+        
+        let desktop_notification_service = domain::DesktopNotificationService::new(csv_connection.clone(), child_service.clone());
+        
+        let update_service = domain::UpdateService::new(csv_connection.clone());
+        
+        let health_service = domain::HealthService::new(csv_connection.clone());
+        
+        let import_service = domain::ImportService::new(transaction_service.clone());
+        
+        let family_service = domain::FamilyService::new(
+            csv_connection.clone(),
+            child_service.clone(),
+            allowance_service.clone(),
+            balance_service.clone(),
+        );
+        
+        let encryption_service = domain::EncryptionService::new(csv_connection.clone(), child_service.clone());
+        
+        let localization_service = domain::LocalizationService::new(csv_connection.clone());
+        
+        let preferences_service = domain::PreferencesService::new(csv_connection.clone());
+        
+        let fridge_view_service = domain::FridgeViewService::new(
+            csv_connection.clone(),
+            child_service.clone(),
+            transaction_service.clone(),
+            goal_service.clone(),
+        );
+        
+        let snapshot_service = domain::SnapshotService::new(
+            csv_connection.clone(),
+            transaction_service.clone(),
+            goal_service.clone(),
+        );
+        
+        let feature_flag_service = domain::FeatureFlagService::new(csv_connection.clone());
+        
+        let celebration_service = domain::CelebrationService::new(csv_connection.clone(), child_service.clone());
+        
+        let agreement_service = domain::AgreementService::new(
+            csv_connection.clone(),
+            child_service.clone(),
+            allowance_service.clone(),
+        );
+        
         Ok(Backend {
             child_service,
             transaction_service,
@@ -88,6 +265,40 @@ impl Backend {
             balance_service,
             data_directory_service,
             export_service,
+            reports_service,
+            archive_service,
+            loan_service,
+            allowance_advance_service,
+            planned_purchase_service,
+            quick_pick_service,
+            transaction_template_service,
+            reset_service,
+            retention_service,
+            budget_service,
+            scheduled_report_service,
+            desktop_notification_service,
+            update_service,
+            fridge_view_service,
+            health_service,
+            import_service,
+            family_service,
+            encryption_service,
+            secrets_service,
+            localization_service,
+            preferences_service,
+            snapshot_service,
+            feature_flag_service,
+            celebration_service,
+            agreement_service,
+            email_config_watcher: None,
         })
     }
+}
+
+impl Drop for Backend {
+    fn drop(&mut self) {
+        // Commit what is left before other instances sharing the data directory take over writing
+        self.data_directory_service.commit_pending_changes();
+        self.data_directory_service.release_locks();
+    }
 } 
\ No newline at end of file
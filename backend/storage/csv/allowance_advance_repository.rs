@@ -0,0 +1,215 @@
+//! # CSV Allowance Advance Repository
+//!
+//! This module stores allowance advances and their repayment schedules in a
+//! YAML file in each child's directory: `{child_directory}/advances.yaml`.
+//!
+//! ## File Structure
+//!
+//! ```yaml
+//! advances:
+//! - id: advance::alice_1705314600000
+//!   amount: 10.0
+//!   date: 2024-01-15T10:30:00-05:00
+//!   transaction_id: in-1705314600000-a1b2
+//!   installments:
+//!   - due_date: 2024-01-19
+//!     amount: 5.0
+//!     transaction_id: ex-1705658400000-c3d4
+//!   - due_date: 2024-01-26
+//!     amount: 5.0
+//! ```
+//!
+//! The child_id is implicit from the directory, so it is not written to disk.
+
+use anyhow::Result;
+use chrono::{DateTime, FixedOffset};
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::backend::domain::models::allowance_advance::{AdvanceInstallment, AllowanceAdvance as DomainAllowanceAdvance};
+use super::connection::CsvConnection;
+
+/// YAML representation of a child's advances file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct YamlAdvanceFile {
+    #[serde(default)]
+    advances: Vec<YamlAdvance>,
+}
+
+/// YAML representation of a single advance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct YamlAdvance {
+    id: String,
+    amount: f64,
+    date: DateTime<FixedOffset>,
+    transaction_id: String,
+    #[serde(default)]
+    installments: Vec<AdvanceInstallment>,
+}
+
+/// YAML-based allowance advance repository using per-child files
+#[derive(Clone)]
+pub struct AllowanceAdvanceRepository {
+    connection: CsvConnection,
+}
+
+impl AllowanceAdvanceRepository {
+    /// Create a new allowance advance repository
+    pub fn new(connection: CsvConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Get the advances file path for a child
+    fn get_advances_path(&self, child_id: &str) -> Result<PathBuf> {
+        let child_directory = self
+            .connection
+            .find_child_directory_by_id(child_id)?
+            .ok_or_else(|| anyhow::anyhow!("Child with ID '{}' not found", child_id))?;
+        Ok(self.connection.get_child_directory(&child_directory).join("advances.yaml"))
+    }
+
+    /// Read all advances for a child in file order
+    fn read_advances(&self, child_id: &str) -> Result<Vec<DomainAllowanceAdvance>> {
+        let yaml_path = self.get_advances_path(child_id)?;
+        let content = match self.connection.read_child_file(&yaml_path)? {
+            Some(content) => content,
+            None => return Ok(Vec::new()),
+        };
+
+        let file: YamlAdvanceFile = serde_yaml::from_str(&content)?;
+        Ok(file
+            .advances
+            .into_iter()
+            .map(|advance| DomainAllowanceAdvance {
+                id: advance.id,
+                child_id: child_id.to_string(),
+                amount: advance.amount,
+                date: advance.date,
+                transaction_id: advance.transaction_id,
+                installments: advance.installments,
+            })
+            .collect())
+    }
+
+    /// Write all advances for a child, replacing the file
+    fn write_advances(&self, child_id: &str, advances: &[DomainAllowanceAdvance]) -> Result<()> {
+        let yaml_path = self.get_advances_path(child_id)?;
+        if let Some(child_dir) = yaml_path.parent() {
+            self.connection.ensure_child_writable(child_dir)?;
+        }
+
+        let file = YamlAdvanceFile {
+            advances: advances
+                .iter()
+                .map(|advance| YamlAdvance {
+                    id: advance.id.clone(),
+                    amount: advance.amount,
+                    date: advance.date,
+                    transaction_id: advance.transaction_id.clone(),
+                    installments: advance.installments.clone(),
+                })
+                .collect(),
+        };
+        let content = serde_yaml::to_string(&file)?;
+        self.connection.write_child_file(&yaml_path, content.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl crate::backend::storage::AllowanceAdvanceStorage for AllowanceAdvanceRepository {
+    fn store_advance(&self, advance: &DomainAllowanceAdvance) -> Result<()> {
+        let mut advances = self.read_advances(&advance.child_id)?;
+        if let Some(pos) = advances.iter().position(|a| a.id == advance.id) {
+            advances[pos] = advance.clone();
+        } else {
+            advances.push(advance.clone());
+        }
+        advances.sort_by_key(|a| a.date);
+        self.write_advances(&advance.child_id, &advances)?;
+
+        info!("💸 Stored advance {} (${:.2} outstanding) for child '{}'", advance.id, advance.outstanding(), advance.child_id);
+        Ok(())
+    }
+
+    fn list_advances(&self, child_id: &str) -> Result<Vec<DomainAllowanceAdvance>> {
+        self.read_advances(child_id)
+    }
+
+    fn delete_advance_links_for_transactions(&self, child_id: &str, transaction_ids: &[String]) -> Result<u32> {
+        let mut advances = self.read_advances(child_id)?;
+        let mut removed = 0;
+
+        advances.retain(|advance| {
+            let keep = !transaction_ids.contains(&advance.transaction_id);
+            if !keep {
+                removed += 1;
+            }
+            keep
+        });
+        for advance in &mut advances {
+            let before = advance.installments.len();
+            advance
+                .installments
+                .retain(|i| !i.transaction_id.as_ref().is_some_and(|id| transaction_ids.contains(id)));
+            removed += (before - advance.installments.len()) as u32;
+        }
+
+        if removed > 0 {
+            self.write_advances(child_id, &advances)?;
+            debug!("Removed {} advance links for child '{}'", removed, child_id);
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::csv::test_utils::TestHelper;
+    use crate::backend::storage::traits::AllowanceAdvanceStorage;
+    use chrono::NaiveDate;
+
+    fn advance(child_id: &str, transaction_id: &str, date: &str) -> DomainAllowanceAdvance {
+        let due_dates = [
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 8).unwrap(),
+        ];
+        DomainAllowanceAdvance {
+            id: format!("advance::{}", transaction_id),
+            child_id: child_id.to_string(),
+            amount: 10.0,
+            date: DateTime::parse_from_rfc3339(date).unwrap(),
+            transaction_id: transaction_id.to_string(),
+            installments: DomainAllowanceAdvance::split_installments(10.0, &due_dates),
+        }
+    }
+
+    #[test]
+    fn test_store_list_and_unlink_advances() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let repo = AllowanceAdvanceRepository::new(helper.env.connection.clone());
+
+        assert!(repo.list_advances(&child.id)?.is_empty());
+
+        let mut bike = advance(&child.id, "in-2", "2024-02-20T12:00:00-05:00");
+        bike.installments[0].transaction_id = Some("ex-3".to_string());
+        repo.store_advance(&bike)?;
+        repo.store_advance(&advance(&child.id, "in-1", "2024-02-10T12:00:00-05:00"))?;
+
+        let advances = repo.list_advances(&child.id)?;
+        assert_eq!(advances.len(), 2);
+        assert_eq!(advances[0].transaction_id, "in-1");
+        assert_eq!(advances[1], bike);
+
+        // Deleting a repayment forgives that installment; deleting the advance itself removes it
+        let removed = repo.delete_advance_links_for_transactions(&child.id, &["ex-3".to_string(), "in-1".to_string()])?;
+        assert_eq!(removed, 2);
+        let advances = repo.list_advances(&child.id)?;
+        assert_eq!(advances.len(), 1);
+        assert_eq!(advances[0].installments.len(), 1);
+        assert_eq!(advances[0].outstanding(), 5.0);
+        Ok(())
+    }
+}
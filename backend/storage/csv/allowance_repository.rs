@@ -28,7 +28,11 @@ use log::{info, warn, debug};
 
 use std::path::PathBuf;
 
-use crate::backend::domain::models::allowance::AllowanceConfig as DomainAllowanceConfig;
+use crate::backend::domain::models::allowance::{
+    AllowanceConfig as DomainAllowanceConfig, AllowanceDayChange, AllowanceFrequency, AllowanceScheduleChange, DayChangePolicy,
+    MIN_INTERVAL_WEEKS,
+};
+use chrono::NaiveDate;
 use super::connection::CsvConnection;
 use crate::backend::storage::GitManager;
 use serde_yaml;
@@ -45,6 +49,30 @@ struct YamlAllowanceConfig {
     is_active: bool,
     created_at: String,
     updated_at: String,
+    #[serde(default)]
+    day_change_policy: DayChangePolicy,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    day_change: Option<AllowanceDayChange>,
+    #[serde(default)]
+    frequency: AllowanceFrequency,
+    #[serde(default = "first_of_month")]
+    day_of_month: u8,
+    #[serde(default = "two_weeks")]
+    interval_weeks: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    anchor_date: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    schedule_change: Option<AllowanceScheduleChange>,
+}
+
+/// Files written before monthly allowances existed pay on the 1st if switched to monthly
+fn first_of_month() -> u8 {
+    1
+}
+
+/// Files written before every-few-weeks allowances existed
+fn two_weeks() -> u8 {
+    MIN_INTERVAL_WEEKS
 }
 
 /// CSV-based allowance config repository using per-child YAML files
@@ -74,6 +102,7 @@ impl AllowanceRepository {
     /// Save allowance config to a specific child directory
     fn save_allowance_config_to_directory(&self, config: &DomainAllowanceConfig, child_directory: &str) -> Result<()> {
         let child_dir = self.connection.get_child_directory(child_directory);
+        self.connection.ensure_child_writable(&child_dir)?;
         
         // Ensure the child directory exists
         if !child_dir.exists() {
@@ -90,6 +119,13 @@ impl AllowanceRepository {
             is_active: config.is_active,
             created_at: config.created_at.clone(),
             updated_at: config.updated_at.clone(),
+            day_change_policy: config.day_change_policy,
+            day_change: config.day_change,
+            frequency: config.frequency,
+            day_of_month: config.day_of_month,
+            interval_weeks: config.interval_weeks,
+            anchor_date: config.anchor_date,
+            schedule_change: config.schedule_change,
         };
 
         let yaml_content = serde_yaml::to_string(&yaml_model)?;
@@ -124,6 +160,13 @@ impl AllowanceRepository {
             is_active: yaml_model.is_active,
             created_at: yaml_model.created_at,
             updated_at: yaml_model.updated_at,
+            day_change_policy: yaml_model.day_change_policy,
+            day_change: yaml_model.day_change,
+            frequency: yaml_model.frequency,
+            day_of_month: yaml_model.day_of_month,
+            interval_weeks: yaml_model.interval_weeks,
+            anchor_date: yaml_model.anchor_date,
+            schedule_change: yaml_model.schedule_change,
         };
 
         debug!("Loaded allowance config for child directory '{}' from {:?}", child_directory, yaml_path);
@@ -215,6 +258,7 @@ impl crate::backend::storage::AllowanceStorage for AllowanceRepository {
         let yaml_path = self.get_allowance_config_path(&child_directory);
         
         if yaml_path.exists() {
+            self.connection.ensure_child_writable(&self.connection.get_child_directory(&child_directory))?;
             std::fs::remove_file(&yaml_path)?;
             info!("Deleted allowance config for child ID '{}' from {:?}", child_id, yaml_path);
             Ok(true)
@@ -247,6 +291,7 @@ impl crate::backend::storage::AllowanceStorage for AllowanceRepository {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::backend::storage::traits::AllowanceStorage;
     use tempfile::TempDir;
     use chrono::Utc;
     use crate::backend::domain::models::child::Child as DomainChild;
@@ -285,6 +330,13 @@ mod tests {
             is_active: true,
             created_at: Utc::now().to_rfc3339(),
             updated_at: Utc::now().to_rfc3339(),
+            day_change_policy: DayChangePolicy::default(),
+            day_change: None,
+            frequency: AllowanceFrequency::Weekly,
+            day_of_month: 1,
+            interval_weeks: 2,
+            anchor_date: None,
+            schedule_change: None,
         };
         
         // Store the config
@@ -312,6 +364,13 @@ mod tests {
             is_active: true,
             created_at: Utc::now().to_rfc3339(),
             updated_at: Utc::now().to_rfc3339(),
+            day_change_policy: DayChangePolicy::default(),
+            day_change: None,
+            frequency: AllowanceFrequency::Weekly,
+            day_of_month: 1,
+            interval_weeks: 2,
+            anchor_date: None,
+            schedule_change: None,
         };
         
         // Store the initial config
@@ -341,6 +400,13 @@ mod tests {
             is_active: true,
             created_at: Utc::now().to_rfc3339(),
             updated_at: Utc::now().to_rfc3339(),
+            day_change_policy: DayChangePolicy::default(),
+            day_change: None,
+            frequency: AllowanceFrequency::Weekly,
+            day_of_month: 1,
+            interval_weeks: 2,
+            anchor_date: None,
+            schedule_change: None,
         };
         
         // Store the config
@@ -383,6 +449,13 @@ mod tests {
             is_active: true,
             created_at: Utc::now().to_rfc3339(),
             updated_at: Utc::now().to_rfc3339(),
+            day_change_policy: DayChangePolicy::default(),
+            day_change: None,
+            frequency: AllowanceFrequency::Weekly,
+            day_of_month: 1,
+            interval_weeks: 2,
+            anchor_date: None,
+            schedule_change: None,
         };
         
         let config2 = DomainAllowanceConfig {
@@ -392,6 +465,13 @@ mod tests {
             is_active: false,
             created_at: Utc::now().to_rfc3339(),
             updated_at: Utc::now().to_rfc3339(),
+            day_change_policy: DayChangePolicy::default(),
+            day_change: None,
+            frequency: AllowanceFrequency::Weekly,
+            day_of_month: 1,
+            interval_weeks: 2,
+            anchor_date: None,
+            schedule_change: None,
         };
         
         repo.store_allowance_config(&config1).unwrap();
@@ -418,6 +498,13 @@ mod tests {
             is_active: true,
             created_at: Utc::now().to_rfc3339(),
             updated_at: Utc::now().to_rfc3339(),
+            day_change_policy: DayChangePolicy::default(),
+            day_change: None,
+            frequency: AllowanceFrequency::Weekly,
+            day_of_month: 1,
+            interval_weeks: 2,
+            anchor_date: None,
+            schedule_change: None,
         };
         
         // Storing config for nonexistent child should fail
@@ -0,0 +1,220 @@
+//! # CSV Audit Log Repository
+//!
+//! This module provides an append-only audit log stored per-child in
+//! `{child_directory}/audit_log.csv`.
+//!
+//! ## CSV Format
+//!
+//! ```csv
+//! # schema_version: 1
+//! timestamp,action,transaction_id,details,actor
+//! 2024-01-15T10:30:00+00:00,overdraft_override,ex-1705314600000-a1b2,"Parent override: ...",parent
+//! ```
+//!
+//! Columns are found by header name (see `csv_schema`). Logs started before
+//! actors were recorded have a four-column header, and may already have rows
+//! with the extra column appended. A log in any older layout is rewritten in
+//! the current one the next time an entry is appended, so appended rows
+//! always line up with the header.
+
+use anyhow::Result;
+use csv::Writer;
+use log::{debug, info};
+use std::path::{Path, PathBuf};
+
+use crate::backend::domain::models::actor::Actor;
+use crate::backend::domain::models::audit_entry::AuditEntry as DomainAuditEntry;
+use super::connection::CsvConnection;
+use super::csv_schema::{self, ColumnIndex};
+
+/// Schema version written at the top of `audit_log.csv`
+const SCHEMA_VERSION: u32 = 1;
+
+/// Columns of `audit_log.csv`, in the order they are written
+const COLUMNS: &[&str] = &["timestamp", "action", "transaction_id", "details", "actor"];
+
+/// CSV-based audit log repository using per-child CSV files
+#[derive(Clone)]
+pub struct AuditLogRepository {
+    connection: CsvConnection,
+}
+
+impl AuditLogRepository {
+    /// Create a new audit log repository
+    pub fn new(connection: CsvConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Get the audit log file path for a child
+    fn get_audit_log_path(&self, child_id: &str) -> Result<PathBuf> {
+        let child_directory = self
+            .connection
+            .find_child_directory_by_id(child_id)?
+            .ok_or_else(|| anyhow::anyhow!("Child with ID '{}' not found", child_id))?;
+        Ok(self
+            .connection
+            .get_child_directory(&child_directory)
+            .join("audit_log.csv"))
+    }
+
+    /// Replace a child's whole audit log with `entries`, given oldest first
+    ///
+    /// Only the retention cleanup rewrites the log; everything else appends.
+    pub fn replace_audit_entries(&self, child_id: &str, entries: &[DomainAuditEntry]) -> Result<()> {
+        let csv_path = self.get_audit_log_path(child_id)?;
+        self.write_entries(&csv_path, entries)?;
+
+        info!("📝 AUDIT: Rewrote log for child '{}' with {} entries", child_id, entries.len());
+        Ok(())
+    }
+
+    /// Write `entries`, oldest first, as the whole log in the current layout
+    fn write_entries(&self, csv_path: &Path, entries: &[DomainAuditEntry]) -> Result<()> {
+        if let Some(child_dir) = csv_path.parent() {
+            self.connection.ensure_child_writable(child_dir)?;
+        }
+
+        let mut csv_writer = csv_schema::writer(SCHEMA_VERSION, COLUMNS)?;
+        for entry in entries {
+            write_entry(&mut csv_writer, entry)?;
+        }
+        let content = csv_writer.into_inner()?;
+        self.connection.write_child_file(csv_path, &content)?;
+        Ok(())
+    }
+}
+
+/// Write one entry as a row in `COLUMNS` order
+fn write_entry(csv_writer: &mut Writer<Vec<u8>>, entry: &DomainAuditEntry) -> Result<()> {
+    csv_writer.write_record([
+        entry.timestamp.as_str(),
+        entry.action.as_str(),
+        entry.transaction_id.as_deref().unwrap_or(""),
+        entry.details.as_str(),
+        entry.actor.as_ref().map(Actor::to_storage).unwrap_or_default().as_str(),
+    ])?;
+    Ok(())
+}
+
+/// Parse a whole log, oldest first
+fn parse_entries(content: &str) -> Result<Vec<DomainAuditEntry>> {
+    let mut csv_reader = csv_schema::reader(content);
+    let columns = ColumnIndex::new(csv_reader.headers()?, COLUMNS);
+    let mut entries = Vec::new();
+    for result in csv_reader.records() {
+        let record = result?;
+        let transaction_id = columns.value(&record, "transaction_id");
+        entries.push(DomainAuditEntry {
+            timestamp: columns.value(&record, "timestamp").to_string(),
+            action: columns.value(&record, "action").to_string(),
+            transaction_id: if transaction_id.is_empty() {
+                None
+            } else {
+                Some(transaction_id.to_string())
+            },
+            details: columns.value(&record, "details").to_string(),
+            actor: columns.get(&record, "actor").and_then(Actor::parse),
+        });
+    }
+    Ok(entries)
+}
+
+impl crate::backend::storage::AuditLogStorage for AuditLogRepository {
+    fn append_audit_entry(&self, child_id: &str, entry: &DomainAuditEntry) -> Result<()> {
+        let csv_path = self.get_audit_log_path(child_id)?;
+        match self.connection.read_child_file(&csv_path)? {
+            Some(content) if csv_schema::is_current(&content, SCHEMA_VERSION, COLUMNS)? => {
+                if let Some(child_dir) = csv_path.parent() {
+                    self.connection.ensure_child_writable(child_dir)?;
+                }
+                let mut csv_writer = Writer::from_writer(Vec::new());
+                write_entry(&mut csv_writer, entry)?;
+                let content = csv_writer.into_inner()?;
+                self.connection.append_child_file(&csv_path, &content)?;
+            }
+            Some(content) => {
+                // An older layout: bring the whole log up to date with the new entry
+                let mut entries = parse_entries(&content)?;
+                entries.push(entry.clone());
+                self.write_entries(&csv_path, &entries)?;
+                info!("📝 AUDIT: Upgraded log for child '{}' to schema version {}", child_id, SCHEMA_VERSION);
+            }
+            None => self.write_entries(&csv_path, std::slice::from_ref(entry))?,
+        }
+
+        info!("📝 AUDIT: Recorded '{}' for child '{}'", entry.action, child_id);
+        Ok(())
+    }
+
+    fn list_audit_entries(&self, child_id: &str, limit: Option<u32>) -> Result<Vec<DomainAuditEntry>> {
+        let csv_path = self.get_audit_log_path(child_id)?;
+        let content = match self.connection.read_child_file(&csv_path)? {
+            Some(content) => content,
+            None => {
+                debug!("No audit log found for child '{}'", child_id);
+                return Ok(Vec::new());
+            }
+        };
+
+        let mut entries = parse_entries(&content)?;
+
+        // Most recent first
+        entries.reverse();
+        if let Some(limit) = limit {
+            entries.truncate(limit as usize);
+        }
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::csv::test_utils::TestHelper;
+    use crate::backend::storage::traits::AuditLogStorage;
+
+    #[test]
+    fn test_append_and_list_entries_most_recent_first() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let repo = AuditLogRepository::new(helper.env.connection.clone());
+
+        assert!(repo.list_audit_entries(&child.id, None)?.is_empty());
+
+        repo.append_audit_entry(&child.id, &DomainAuditEntry::new("first", None, "one".to_string()))?;
+        repo.append_audit_entry(
+            &child.id,
+            &DomainAuditEntry::new("second", Some("ex-1-abcd".to_string()), "two, with comma".to_string()),
+        )?;
+
+        let entries = repo.list_audit_entries(&child.id, None)?;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].action, "second");
+        assert_eq!(entries[0].transaction_id.as_deref(), Some("ex-1-abcd"));
+        assert_eq!(entries[0].details, "two, with comma");
+        assert_eq!(entries[1].transaction_id, None);
+
+        assert_eq!(repo.list_audit_entries(&child.id, Some(1))?.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_actor_rows_append_to_log_without_actor_column() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let repo = AuditLogRepository::new(helper.env.connection.clone());
+        let path = repo.get_audit_log_path(&child.id)?;
+        std::fs::write(&path, "timestamp,action,transaction_id,details\n2024-01-15T10:30:00+00:00,old,,before actors\n")?;
+
+        let entry = DomainAuditEntry::new("new", None, "after".to_string())
+            .with_actor(Some(Actor::Parent(Some("Dad".to_string()))));
+        repo.append_audit_entry(&child.id, &entry)?;
+
+        let entries = repo.list_audit_entries(&child.id, None)?;
+        assert_eq!(entries[0].actor, Some(Actor::Parent(Some("Dad".to_string()))));
+        assert_eq!(entries[1].action, "old");
+        assert_eq!(entries[1].actor, None);
+        assert!(csv_schema::is_current(&std::fs::read_to_string(&path)?, SCHEMA_VERSION, COLUMNS)?);
+        Ok(())
+    }
+}
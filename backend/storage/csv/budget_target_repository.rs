@@ -0,0 +1,169 @@
+//! # CSV Budget Target Repository
+//!
+//! This module stores a child's monthly spending targets in a YAML file in
+//! their directory: `{child_directory}/budget_targets.yaml`.
+//!
+//! ## File Structure
+//!
+//! ```yaml
+//! budget_targets:
+//! - category: Candy
+//!   monthly_limit: 10.0
+//! - category: Toys
+//!   monthly_limit: 20.0
+//! ```
+//!
+//! The child_id is implicit from the directory, so it is not written to disk.
+
+use anyhow::Result;
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::backend::domain::models::budget_target::BudgetTarget as DomainBudgetTarget;
+use super::connection::CsvConnection;
+
+/// YAML representation of a child's budget targets file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct YamlBudgetTargetFile {
+    #[serde(default)]
+    budget_targets: Vec<YamlBudgetTarget>,
+}
+
+/// YAML representation of a single budget target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct YamlBudgetTarget {
+    category: String,
+    monthly_limit: f64,
+}
+
+/// YAML-based budget target repository using per-child files
+#[derive(Clone)]
+pub struct BudgetTargetRepository {
+    connection: CsvConnection,
+}
+
+impl BudgetTargetRepository {
+    /// Create a new budget target repository
+    pub fn new(connection: CsvConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Get the budget targets file path for a child
+    fn get_budget_targets_path(&self, child_id: &str) -> Result<PathBuf> {
+        let child_directory = self
+            .connection
+            .find_child_directory_by_id(child_id)?
+            .ok_or_else(|| anyhow::anyhow!("Child with ID '{}' not found", child_id))?;
+        Ok(self.connection.get_child_directory(&child_directory).join("budget_targets.yaml"))
+    }
+
+    /// Read all budget targets for a child in file order
+    fn read_budget_targets(&self, child_id: &str) -> Result<Vec<DomainBudgetTarget>> {
+        let yaml_path = self.get_budget_targets_path(child_id)?;
+        let content = match self.connection.read_child_file(&yaml_path)? {
+            Some(content) => content,
+            None => return Ok(Vec::new()),
+        };
+
+        let file: YamlBudgetTargetFile = serde_yaml::from_str(&content)?;
+        Ok(file
+            .budget_targets
+            .into_iter()
+            .map(|target| DomainBudgetTarget {
+                child_id: child_id.to_string(),
+                category: target.category,
+                monthly_limit: target.monthly_limit,
+            })
+            .collect())
+    }
+
+    /// Write all budget targets for a child, replacing the file
+    fn write_budget_targets(&self, child_id: &str, targets: &[DomainBudgetTarget]) -> Result<()> {
+        let yaml_path = self.get_budget_targets_path(child_id)?;
+        if let Some(child_dir) = yaml_path.parent() {
+            self.connection.ensure_child_writable(child_dir)?;
+        }
+
+        let file = YamlBudgetTargetFile {
+            budget_targets: targets
+                .iter()
+                .map(|target| YamlBudgetTarget {
+                    category: target.category.clone(),
+                    monthly_limit: target.monthly_limit,
+                })
+                .collect(),
+        };
+        let content = serde_yaml::to_string(&file)?;
+        self.connection.write_child_file(&yaml_path, content.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl crate::backend::storage::BudgetTargetStorage for BudgetTargetRepository {
+    fn store_budget_target(&self, target: &DomainBudgetTarget) -> Result<()> {
+        let mut targets = self.read_budget_targets(&target.child_id)?;
+        if let Some(pos) = targets.iter().position(|t| t.matches_category(&target.category)) {
+            targets[pos] = target.clone();
+        } else {
+            targets.push(target.clone());
+        }
+        self.write_budget_targets(&target.child_id, &targets)?;
+
+        info!("🎯 Stored budget target \"{}\" for child '{}'", target.category, target.child_id);
+        Ok(())
+    }
+
+    fn list_budget_targets(&self, child_id: &str) -> Result<Vec<DomainBudgetTarget>> {
+        self.read_budget_targets(child_id)
+    }
+
+    fn delete_budget_target(&self, child_id: &str, category: &str) -> Result<bool> {
+        let mut targets = self.read_budget_targets(child_id)?;
+        let before = targets.len();
+        targets.retain(|t| !t.matches_category(category));
+        if targets.len() == before {
+            return Ok(false);
+        }
+        self.write_budget_targets(child_id, &targets)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::csv::test_utils::TestHelper;
+    use crate::backend::storage::traits::BudgetTargetStorage;
+
+    #[test]
+    fn test_store_replaces_target_for_same_category() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let repo = BudgetTargetRepository::new(helper.env.connection.clone());
+
+        assert!(repo.list_budget_targets(&child.id)?.is_empty());
+
+        let candy = DomainBudgetTarget {
+            child_id: child.id.clone(),
+            category: "Candy".to_string(),
+            monthly_limit: 10.0,
+        };
+        let toys = DomainBudgetTarget {
+            category: "Toys".to_string(),
+            monthly_limit: 20.0,
+            ..candy.clone()
+        };
+        repo.store_budget_target(&candy)?;
+        repo.store_budget_target(&toys)?;
+
+        let cheaper_candy = DomainBudgetTarget { category: "candy".to_string(), monthly_limit: 5.0, ..candy.clone() };
+        repo.store_budget_target(&cheaper_candy)?;
+        assert_eq!(repo.list_budget_targets(&child.id)?, vec![cheaper_candy, toys.clone()]);
+
+        assert!(repo.delete_budget_target(&child.id, "CANDY")?);
+        assert!(!repo.delete_budget_target(&child.id, "Candy")?);
+        assert_eq!(repo.list_budget_targets(&child.id)?, vec![toys]);
+        Ok(())
+    }
+}
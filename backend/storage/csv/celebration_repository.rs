@@ -0,0 +1,225 @@
+//! # CSV Celebration Repository
+//!
+//! This module stores a child's celebrations that no frontend has
+//! acknowledged yet, in a YAML file in the child's directory:
+//! `{child_directory}/celebrations.yaml`.
+//!
+//! ## File Structure
+//!
+//! ```yaml
+//! reached_milestones:
+//! - amount: 100.0
+//!   reached_on: 2024-01-15
+//!   transaction_id: transaction::income::1705314600000
+//! pending:
+//! - id: celebration::milestone_alice_1705314600000000
+//!   kind: balance_milestone
+//!   created_at: 2024-01-15T10:30:00Z
+//!   message: You reached $100.00!
+//!   amount: 100.0
+//! ```
+//!
+//! `reached_milestones` is the child's milestone history. It also keeps a
+//! milestone from being celebrated again after the balance dips below it and
+//! climbs back.
+//! The child_id is implicit from the directory, so it is not written to disk.
+
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::backend::domain::models::celebration::{
+    Celebration as DomainCelebration, CelebrationKind, ReachedMilestone, MAX_PENDING_CELEBRATIONS,
+};
+use super::connection::CsvConnection;
+
+/// YAML representation of a child's celebrations file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct YamlCelebrationFile {
+    #[serde(default)]
+    reached_milestones: Vec<YamlReachedMilestone>,
+    #[serde(default)]
+    pending: Vec<YamlCelebration>,
+}
+
+/// YAML representation of a milestone in the child's history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct YamlReachedMilestone {
+    amount: f64,
+    reached_on: NaiveDate,
+    transaction_id: String,
+}
+
+/// YAML representation of a single pending celebration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct YamlCelebration {
+    id: String,
+    kind: CelebrationKind,
+    created_at: DateTime<Utc>,
+    message: String,
+    amount: f64,
+}
+
+/// YAML-based celebration repository using per-child files
+#[derive(Clone)]
+pub struct CelebrationRepository {
+    connection: CsvConnection,
+}
+
+impl CelebrationRepository {
+    /// Create a new celebration repository
+    pub fn new(connection: CsvConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Get the celebrations file path for a child
+    fn get_celebrations_path(&self, child_id: &str) -> Result<PathBuf> {
+        let child_directory = self
+            .connection
+            .find_child_directory_by_id(child_id)?
+            .ok_or_else(|| anyhow::anyhow!("Child with ID '{}' not found", child_id))?;
+        Ok(self.connection.get_child_directory(&child_directory).join("celebrations.yaml"))
+    }
+
+    fn read_file(&self, child_id: &str) -> Result<YamlCelebrationFile> {
+        let yaml_path = self.get_celebrations_path(child_id)?;
+        match self.connection.read_child_file(&yaml_path)? {
+            Some(content) => Ok(serde_yaml::from_str(&content)?),
+            None => Ok(YamlCelebrationFile::default()),
+        }
+    }
+
+    fn write_file(&self, child_id: &str, file: &YamlCelebrationFile) -> Result<()> {
+        let yaml_path = self.get_celebrations_path(child_id)?;
+        if let Some(child_dir) = yaml_path.parent() {
+            self.connection.ensure_child_writable(child_dir)?;
+        }
+        let content = serde_yaml::to_string(file)?;
+        self.connection.write_child_file(&yaml_path, content.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl crate::backend::storage::CelebrationStorage for CelebrationRepository {
+    fn record_celebration(&self, celebration: &DomainCelebration) -> Result<()> {
+        let mut file = self.read_file(&celebration.child_id)?;
+        file.pending.push(YamlCelebration {
+            id: celebration.id.clone(),
+            kind: celebration.kind,
+            created_at: celebration.created_at,
+            message: celebration.message.clone(),
+            amount: celebration.amount,
+        });
+        // A child who never opens the app shouldn't come back to a backlog of confetti
+        if file.pending.len() > MAX_PENDING_CELEBRATIONS {
+            let excess = file.pending.len() - MAX_PENDING_CELEBRATIONS;
+            file.pending.drain(..excess);
+        }
+        self.write_file(&celebration.child_id, &file)?;
+
+        info!("🎉 Recorded celebration {} for child '{}'", celebration.id, celebration.child_id);
+        Ok(())
+    }
+
+    fn list_pending_celebrations(&self, child_id: &str) -> Result<Vec<DomainCelebration>> {
+        Ok(self
+            .read_file(child_id)?
+            .pending
+            .into_iter()
+            .map(|celebration| DomainCelebration {
+                id: celebration.id,
+                child_id: child_id.to_string(),
+                kind: celebration.kind,
+                created_at: celebration.created_at,
+                message: celebration.message,
+                amount: celebration.amount,
+            })
+            .collect())
+    }
+
+    fn acknowledge_celebrations(&self, child_id: &str, celebration_ids: &[String]) -> Result<u32> {
+        let mut file = self.read_file(child_id)?;
+        let before = file.pending.len();
+        file.pending.retain(|celebration| !celebration_ids.contains(&celebration.id));
+        let removed = (before - file.pending.len()) as u32;
+
+        if removed > 0 {
+            self.write_file(child_id, &file)?;
+            debug!("Acknowledged {} celebrations for child '{}'", removed, child_id);
+        }
+        Ok(removed)
+    }
+
+    fn record_reached_milestones(&self, child_id: &str, milestones: &[ReachedMilestone]) -> Result<()> {
+        if milestones.is_empty() {
+            return Ok(());
+        }
+        let mut file = self.read_file(child_id)?;
+        file.reached_milestones.extend(milestones.iter().map(|milestone| YamlReachedMilestone {
+            amount: milestone.amount,
+            reached_on: milestone.reached_on,
+            transaction_id: milestone.transaction_id.clone(),
+        }));
+        self.write_file(child_id, &file)?;
+
+        info!("🏁 Child '{}' reached {} new milestones", child_id, milestones.len());
+        Ok(())
+    }
+
+    fn list_reached_milestones(&self, child_id: &str) -> Result<Vec<ReachedMilestone>> {
+        Ok(self
+            .read_file(child_id)?
+            .reached_milestones
+            .into_iter()
+            .map(|milestone| ReachedMilestone {
+                amount: milestone.amount,
+                reached_on: milestone.reached_on,
+                transaction_id: milestone.transaction_id,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::csv::test_utils::TestHelper;
+    use crate::backend::storage::traits::CelebrationStorage;
+
+    #[test]
+    fn test_record_acknowledge_and_cap_celebrations() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let repo = CelebrationRepository::new(helper.env.connection.clone());
+
+        assert!(repo.list_pending_celebrations(&child.id)?.is_empty());
+        assert!(repo.list_reached_milestones(&child.id)?.is_empty());
+
+        let reached = ReachedMilestone {
+            amount: 50.0,
+            reached_on: NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+            transaction_id: "transaction::income::1705314600000".to_string(),
+        };
+        repo.record_reached_milestones(&child.id, &[reached.clone()])?;
+        let milestone = DomainCelebration::balance_milestone(&child.id, 50.0);
+        repo.record_celebration(&milestone)?;
+        assert_eq!(repo.list_pending_celebrations(&child.id)?, vec![milestone.clone()]);
+
+        // Acknowledging clears the celebration but keeps the milestone history
+        assert_eq!(repo.acknowledge_celebrations(&child.id, &[milestone.id.clone(), "unknown".to_string()])?, 1);
+        assert!(repo.list_pending_celebrations(&child.id)?.is_empty());
+        assert_eq!(repo.list_reached_milestones(&child.id)?, vec![reached]);
+
+        for i in 0..MAX_PENDING_CELEBRATIONS + 3 {
+            let mut allowance = DomainCelebration::allowance_posted(&child.id, 5.0);
+            allowance.id = format!("celebration::allowance_{}", i);
+            repo.record_celebration(&allowance)?;
+        }
+        let pending = repo.list_pending_celebrations(&child.id)?;
+        assert_eq!(pending.len(), MAX_PENDING_CELEBRATIONS);
+        assert_eq!(pending[0].id, "celebration::allowance_3");
+        Ok(())
+    }
+}
@@ -9,6 +9,8 @@ use serde::{Deserialize, Serialize};
 /// Intermediate struct for YAML serialization with string date fields
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct YamlChild {
+    #[serde(default)]
+    schema_version: u32,
     id: String,
     name: String,
     birthdate: String, // String representation for YAML
@@ -16,6 +18,7 @@ struct YamlChild {
     updated_at: String, // String representation for YAML
 }
 use super::connection::CsvConnection;
+use super::migrations::{CHILD_SCHEMA_VERSION, GLOBAL_CONFIG_SCHEMA_VERSION};
 use crate::backend::storage::GitManager;
 use serde_yaml;
 
@@ -142,6 +145,7 @@ impl ChildRepository {
     fn save_child_to_directory(&self, child: &DomainChild, directory_name: &str) -> Result<()> {
         // Ensure the child directory exists
         let child_dir = self.connection.get_child_directory(directory_name);
+        self.connection.ensure_child_writable(&child_dir)?;
         if !child_dir.exists() {
             fs::create_dir_all(&child_dir)?;
             info!("Created child directory: {:?}", child_dir);
@@ -149,6 +153,7 @@ impl ChildRepository {
         
         // Convert domain child to YAML child
         let yaml_child = YamlChild {
+            schema_version: CHILD_SCHEMA_VERSION,
             id: child.id.clone(),
             name: child.name.clone(),
             birthdate: child.birthdate.format("%Y-%m-%d").to_string(),
@@ -172,6 +177,11 @@ impl ChildRepository {
     
     /// Get the currently active child directory name from global config (synchronous version)
     fn get_active_child_directory(&self) -> Result<Option<String>> {
+        // In read-only mode the selection is only kept in memory
+        if let Some(directory_name) = self.connection.read_only_mode().active_child_directory() {
+            return Ok(Some(directory_name));
+        }
+
         let global_config_path = self.get_global_config_path();
         
         if !global_config_path.exists() {
@@ -189,12 +199,33 @@ impl ChildRepository {
     
     /// Set the currently active child directory in global config (synchronous version)
     fn set_active_child_directory(&self, directory_name: &str) -> Result<()> {
+        if self.connection.read_only_source().is_some() {
+            self.connection.read_only_mode().set_active_child_directory(directory_name);
+            info!("Read-only mode: active child '{}' kept in memory only", directory_name);
+            return Ok(());
+        }
+
         let global_config_path = self.get_global_config_path();
         
-        // Create minimal global config
-        let mut config = serde_yaml::Value::Mapping(serde_yaml::Mapping::new());
+        // Update the existing global config, keeping any other fields
+        let mut config = if global_config_path.exists() {
+            serde_yaml::from_str(&fs::read_to_string(&global_config_path)?)?
+        } else {
+            serde_yaml::Value::Mapping(serde_yaml::Mapping::new())
+        };
+        if !config.is_mapping() {
+            config = serde_yaml::Value::Mapping(serde_yaml::Mapping::new());
+        }
+        let now = chrono::Utc::now().to_rfc3339();
+        config["schema_version"] = serde_yaml::Value::from(GLOBAL_CONFIG_SCHEMA_VERSION);
         config["active_child_directory"] = serde_yaml::Value::String(directory_name.to_string());
-        config["data_format_version"] = serde_yaml::Value::String("1.0".to_string());
+        if config.get("data_format_version").is_none() {
+            config["data_format_version"] = serde_yaml::Value::String("1.0".to_string());
+        }
+        if config.get("created_at").is_none() {
+            config["created_at"] = serde_yaml::Value::String(now.clone());
+        }
+        config["updated_at"] = serde_yaml::Value::String(now);
         
         let yaml_content = serde_yaml::to_string(&config)?;
         
@@ -245,6 +276,7 @@ impl crate::backend::storage::ChildStorage for ChildRepository {
         if let Some(dir_name) = self.connection.find_child_directory_by_id(child_id)? {
             let child_dir = self.connection.get_child_directory(&dir_name);
             if child_dir.exists() {
+                self.connection.ensure_child_writable(&child_dir)?;
                 fs::remove_dir_all(&child_dir)?;
                 info!("Deleted child directory: {:?}", child_dir);
             }
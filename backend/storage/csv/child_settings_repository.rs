@@ -0,0 +1,180 @@
+//! # CSV Child Settings Repository
+//!
+//! This module provides file-based storage for per-child settings using a YAML
+//! file in each child's directory: `{child_directory}/child_settings.yaml`.
+//!
+//! ## File Structure
+//!
+//! ```text
+//! data/
+//! ├── global_config.yaml
+//! └── {child_name}/
+//!     ├── child.yaml
+//!     ├── allowance_config.yaml
+//!     ├── child_settings.yaml    ← This module manages these files
+//!     └── transactions.csv
+//! ```
+//!
+//! Every field in the file is optional on read so that settings added later
+//! don't break files written by older versions.
+
+use anyhow::Result;
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::backend::domain::models::child_settings::ChildSettings as DomainChildSettings;
+use crate::backend::domain::localization::Locale;
+use crate::backend::domain::models::background::ChildBackground;
+use crate::backend::domain::models::currency::Currency;
+use super::connection::CsvConnection;
+
+/// YAML representation of child settings. The child_id is implicit from the
+/// directory name, so it is not written to disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct YamlChildSettings {
+    #[serde(default)]
+    prevent_negative_balance: bool,
+    #[serde(default)]
+    auto_save_percentage: f64,
+    #[serde(default)]
+    auto_save_to_goal: bool,
+    #[serde(default)]
+    round_up_spends: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    locale: Option<Locale>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    currency: Option<Currency>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    balance_milestones: Option<Vec<f64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    background: Option<ChildBackground>,
+    #[serde(default)]
+    created_at: String,
+    #[serde(default)]
+    updated_at: String,
+}
+
+/// CSV-based child settings repository using per-child YAML files
+#[derive(Clone)]
+pub struct ChildSettingsRepository {
+    connection: CsvConnection,
+}
+
+impl ChildSettingsRepository {
+    /// Create a new child settings repository
+    pub fn new(connection: CsvConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Get the settings file path for a specific child directory
+    fn get_settings_path(&self, child_directory: &str) -> PathBuf {
+        self.connection
+            .get_child_directory(child_directory)
+            .join("child_settings.yaml")
+    }
+}
+
+impl crate::backend::storage::ChildSettingsStorage for ChildSettingsRepository {
+    fn get_child_settings(&self, child_id: &str) -> Result<Option<DomainChildSettings>> {
+        let child_directory = match self.connection.find_child_directory_by_id(child_id)? {
+            Some(dir) => dir,
+            None => {
+                debug!("Child with ID '{}' not found when getting child settings", child_id);
+                return Ok(None);
+            }
+        };
+
+        let yaml_path = self.get_settings_path(&child_directory);
+        if !yaml_path.exists() {
+            return Ok(None);
+        }
+
+        let yaml_content = std::fs::read_to_string(&yaml_path)?;
+        let yaml_model: YamlChildSettings = serde_yaml::from_str(&yaml_content)?;
+
+        Ok(Some(DomainChildSettings {
+            child_id: child_id.to_string(),
+            prevent_negative_balance: yaml_model.prevent_negative_balance,
+            auto_save_percentage: yaml_model.auto_save_percentage,
+            auto_save_to_goal: yaml_model.auto_save_to_goal,
+            round_up_spends: yaml_model.round_up_spends,
+            locale: yaml_model.locale,
+            currency: yaml_model.currency,
+            balance_milestones: yaml_model.balance_milestones,
+            background: yaml_model.background,
+            created_at: yaml_model.created_at,
+            updated_at: yaml_model.updated_at,
+        }))
+    }
+
+    fn store_child_settings(&self, settings: &DomainChildSettings) -> Result<()> {
+        let child_directory = match self.connection.find_child_directory_by_id(&settings.child_id)? {
+            Some(dir) => dir,
+            None => {
+                return Err(anyhow::anyhow!(
+                    "Cannot store child settings: child with ID '{}' not found. Create the child first.",
+                    settings.child_id
+                ));
+            }
+        };
+
+        let yaml_model = YamlChildSettings {
+            prevent_negative_balance: settings.prevent_negative_balance,
+            auto_save_percentage: settings.auto_save_percentage,
+            auto_save_to_goal: settings.auto_save_to_goal,
+            round_up_spends: settings.round_up_spends,
+            locale: settings.locale,
+            currency: settings.currency,
+            balance_milestones: settings.balance_milestones.clone(),
+            background: settings.background.clone(),
+            created_at: settings.created_at.clone(),
+            updated_at: settings.updated_at.clone(),
+        };
+        let yaml_content = serde_yaml::to_string(&yaml_model)?;
+
+        // Use atomic write pattern: write to temp file, then rename
+        let yaml_path = self.get_settings_path(&child_directory);
+        self.connection.ensure_child_writable(&self.connection.get_child_directory(&child_directory))?;
+        let temp_path = yaml_path.with_extension("tmp");
+        std::fs::write(&temp_path, yaml_content)?;
+        std::fs::rename(&temp_path, &yaml_path)?;
+
+        info!("Stored child settings for child ID '{}'", settings.child_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::csv::test_utils::TestHelper;
+    use crate::backend::storage::traits::ChildSettingsStorage;
+
+    #[test]
+    fn test_missing_settings_returns_none() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let repo = ChildSettingsRepository::new(helper.env.connection.clone());
+
+        assert!(repo.get_child_settings(&child.id)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_store_and_load_settings() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let repo = ChildSettingsRepository::new(helper.env.connection.clone());
+
+        let mut settings = DomainChildSettings::default_for_child(&child.id);
+        settings.prevent_negative_balance = true;
+        settings.auto_save_percentage = 25.0;
+        settings.round_up_spends = true;
+        repo.store_child_settings(&settings)?;
+
+        let loaded = repo.get_child_settings(&child.id)?.expect("settings should exist");
+        assert_eq!(loaded, settings);
+        Ok(())
+    }
+}
@@ -3,6 +3,13 @@ use std::path::{Path, PathBuf};
 use std::fs;
 use std::io;
 use crate::backend::storage::traits::Connection;
+use super::directory_lock::{DirectoryLockManager, LockStatus};
+use super::encryption::EncryptionKeys;
+use super::csv_schema;
+use super::read_only::{ReadOnlyMode, ReadOnlySource};
+use super::transaction_repository::{TRANSACTIONS_SCHEMA_VERSION, TRANSACTION_COLUMNS};
+use super::write_coordinator::{WriteCoordinator, COMMIT_IDLE_SECONDS};
+use crate::backend::storage::GitManager;
 use log::{info, warn, error, debug};
 
 use std::sync::{Arc, Mutex};
@@ -11,6 +18,10 @@ use std::sync::{Arc, Mutex};
 #[derive(Clone, Debug)]
 pub struct CsvConnection {
     base_directory: Arc<Mutex<PathBuf>>,
+    locks: DirectoryLockManager,
+    read_only: ReadOnlyMode,
+    encryption: EncryptionKeys,
+    writes: WriteCoordinator,
 }
 
 impl CsvConnection {
@@ -25,6 +36,10 @@ impl CsvConnection {
         
         Ok(Self {
             base_directory: Arc::new(Mutex::new(base_path)),
+            locks: DirectoryLockManager::new(),
+            read_only: ReadOnlyMode::default(),
+            encryption: EncryptionKeys::default(),
+            writes: WriteCoordinator::default(),
         })
     }
     
@@ -130,19 +145,21 @@ impl CsvConnection {
     /// Ensure a CSV file exists with proper header for the child using the child name
     pub fn ensure_transactions_file_exists(&self, child_name: &str) -> Result<()> {
         let child_dir = self.get_child_directory(child_name);
+        let file_path = child_dir.join("transactions.csv");
+        
+        if file_path.exists() {
+            return Ok(());
+        }
+        self.ensure_child_writable(&child_dir)?;
         
         // Create the child directory if it doesn't exist
         if !child_dir.exists() {
             fs::create_dir_all(&child_dir)?;
         }
         
-        let file_path = child_dir.join("transactions.csv");
-        
-        if !file_path.exists() {
-            // Create the file with CSV header
-            let header = "id,child_id,date,description,amount,balance\n";
-            fs::write(&file_path, header)?;
-        }
+        // Create the file with the version comment and CSV header
+        let header = format!("{}{}\n", csv_schema::schema_comment(TRANSACTIONS_SCHEMA_VERSION), TRANSACTION_COLUMNS.join(","));
+        self.write_child_file(&file_path, header.as_bytes())?;
         
         Ok(())
     }
@@ -158,6 +175,197 @@ impl CsvConnection {
         let base_dir = self.base_directory.lock().unwrap();
         base_dir.clone()
     }
+
+    /// Take or refresh the advisory lock on a child directory before writing to it.
+    /// Fails with `ReadOnlyError` in read-only mode, or `DirectoryLockError` if
+    /// another app instance holds the lock.
+    pub fn ensure_child_writable(&self, child_dir: &Path) -> Result<()> {
+        self.ensure_writable()?;
+        self.locks.ensure_writable(child_dir)
+    }
+
+    /// Fail with `ReadOnlyError` if read-only mode is on
+    pub fn ensure_writable(&self) -> Result<()> {
+        self.read_only.check()?;
+        Ok(())
+    }
+
+    /// Turn read-only mode on (with what enabled it) or off
+    pub fn set_read_only(&self, enabled_by: Option<ReadOnlySource>) {
+        match enabled_by {
+            Some(source) => info!("🔒 Read-only mode enabled ({})", source),
+            None => info!("🔓 Read-only mode disabled"),
+        }
+        self.read_only.set(enabled_by);
+    }
+
+    /// What enabled read-only mode, if it is on
+    pub fn read_only_source(&self) -> Option<ReadOnlySource> {
+        self.read_only.enabled_by()
+    }
+
+    /// Read-only state shared by all clones of this connection
+    pub fn read_only_mode(&self) -> &ReadOnlyMode {
+        &self.read_only
+    }
+
+    /// Report whether a child directory is locked by this or another instance
+    pub fn child_lock_status(&self, child_dir: &Path) -> LockStatus {
+        self.locks.status(child_dir)
+    }
+
+    /// Refresh the directory locks held by this connection (call periodically)
+    pub fn refresh_directory_locks(&self) {
+        self.locks.refresh_held();
+    }
+
+    /// Release all directory locks held by this connection (call on shutdown)
+    pub fn release_directory_locks(&self) {
+        self.locks.release_all();
+    }
+
+    /// Read a file in a child directory, decrypting it if it was sealed.
+    /// Returns `None` if the file doesn't exist.
+    pub fn read_child_file(&self, path: &Path) -> Result<Option<String>> {
+        if let Some(held) = self.writes.held_contents(path) {
+            return Ok(Some(String::from_utf8(held)?));
+        }
+        match self.encryption.read(path)? {
+            Some(bytes) => Ok(Some(String::from_utf8(bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Replace a data file in a child directory, encrypting it if the directory is encrypted.
+    /// While a batch is open the write is held until the batch ends.
+    pub fn write_child_file(&self, path: &Path, contents: &[u8]) -> Result<()> {
+        if self.writes.hold(path, contents) {
+            return Ok(());
+        }
+        self.encryption.write(path, contents)?;
+        self.writes.record_change(path, &Self::change_description(path));
+        Ok(())
+    }
+
+    /// Append rows to a CSV file in a child directory, creating it if needed
+    pub fn append_child_file(&self, path: &Path, contents: &[u8]) -> Result<()> {
+        if self.writes.append_held(path, contents) {
+            return Ok(());
+        }
+        self.encryption.append(path, contents)?;
+        self.writes.record_change(path, &Self::change_description(path));
+        Ok(())
+    }
+
+    /// Run `operation` with child file writes held, then write each changed file once.
+    ///
+    /// The changes are committed to git together under `description`. Held
+    /// files are written even if `operation` fails, as they would have been
+    /// without the batch.
+    pub fn batch_writes<T>(&self, description: &str, operation: impl FnOnce() -> Result<T>) -> Result<T> {
+        self.writes.begin_batch(description);
+        let result = operation();
+        let flushed = match self.writes.end_batch() {
+            Some((description, files)) => self.write_held_files(&description, files),
+            None => Ok(()),
+        };
+        let value = result?;
+        flushed?;
+        Ok(value)
+    }
+
+    /// Write the files held by a finished batch
+    fn write_held_files(&self, description: &str, files: Vec<(PathBuf, Vec<u8>)>) -> Result<()> {
+        let count = files.len();
+        let mut first_error = None;
+        for (path, contents) in files {
+            // Keep going so one bad file doesn't lose the others
+            match self.encryption.write(&path, &contents) {
+                Ok(()) => self.writes.record_change(&path, description),
+                Err(e) => {
+                    error!("Failed to write {} after batch '{}': {}", path.display(), description, e);
+                    if first_error.is_none() {
+                        first_error = Some(e);
+                    }
+                }
+            }
+        }
+        debug!("Batch '{}' wrote {} file(s)", description, count);
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Commit changes to git once no change has been made for a few seconds
+    pub fn commit_idle_changes(&self) {
+        let changes = self.writes.take_idle_changes(std::time::Duration::from_secs(COMMIT_IDLE_SECONDS));
+        self.commit_changes(changes);
+    }
+
+    /// Commit every outstanding change to git now (call on shutdown)
+    pub fn commit_pending_changes(&self) {
+        let changes = self.writes.take_all_changes();
+        self.commit_changes(changes);
+    }
+
+    /// Commit each child directory's changes, in directories versioned with git
+    fn commit_changes(&self, changes: Vec<(PathBuf, String)>) {
+        let git_manager = GitManager::new();
+        for (child_dir, message) in changes {
+            if !git_manager.is_git_repository(&child_dir) {
+                continue;
+            }
+            // Versioning never fails the change itself
+            let committed = git_manager
+                .add_all_sync(&child_dir)
+                .and_then(|_| git_manager.commit_sync(&child_dir, &message));
+            if let Err(e) = committed {
+                warn!("Failed to commit changes in {}: {}", child_dir.display(), e);
+            }
+        }
+    }
+
+    /// Commit message for a write made outside a batch
+    fn change_description(path: &Path) -> String {
+        let file_name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+        format!("Updated {}", file_name)
+    }
+
+    /// Whether a child directory's data files are encrypted
+    pub fn is_child_encrypted(&self, child_dir: &Path) -> Result<bool> {
+        Ok(EncryptionKeys::marker(child_dir)?.is_some())
+    }
+
+    /// Whether an encrypted child directory has been unlocked this session
+    pub fn is_child_unlocked(&self, child_dir: &Path) -> Result<bool> {
+        self.encryption.is_unlocked(child_dir)
+    }
+
+    /// Unlock an encrypted child directory with the parental passphrase
+    pub fn unlock_child_directory(&self, child_dir: &Path, passphrase: &str) -> Result<()> {
+        self.encryption.unlock(child_dir, passphrase)
+    }
+
+    /// Encrypt the existing data files of a child directory
+    pub fn encrypt_child_directory(&self, child_dir: &Path, passphrase: &str) -> Result<usize> {
+        self.ensure_child_writable(child_dir)?;
+        self.encryption.encrypt_directory(child_dir, passphrase)
+    }
+
+    /// Decrypt the data files of a child directory back to plain text
+    pub fn decrypt_child_directory(&self, child_dir: &Path, passphrase: &str) -> Result<usize> {
+        self.ensure_child_writable(child_dir)?;
+        self.encryption.decrypt_directory(child_dir, passphrase)
+    }
+
+    /// Unescape common shell escape sequences in a user-supplied relocation path
+    pub fn unescape_relocation_path(path: &str) -> PathBuf {
+        let unescaped_path = path
+            .replace("\\ ", " ")              // Escaped spaces
+            .replace("\\~", "~")              // Escaped tildes
+            .replace("\\'", "'")              // Escaped single quotes
+            .replace("\\\"", "\"")            // Escaped double quotes
+            .replace("\\\\", "\\");           // Escaped backslashes (do this last)
+        PathBuf::from(unescaped_path)
+    }
     
     /// Relocate a child's data directory to a new location
     pub fn relocate_child_data_directory<P: AsRef<Path>>(&self, child_name: &str, new_path: P) -> Result<String> {
@@ -167,16 +375,8 @@ impl CsvConnection {
         let path_str = new_path.as_ref().to_string_lossy().to_string();
         info!("🔍 Path as string: {}", path_str);
         
-        // Unescape shell escape sequences
-        let unescaped_path = path_str
-            .replace("\\ ", " ")              // Escaped spaces
-            .replace("\\~", "~")              // Escaped tildes
-            .replace("\\'", "'")              // Escaped single quotes
-            .replace("\\\"", "\"")            // Escaped double quotes
-            .replace("\\\\", "\\");           // Escaped backslashes (do this last)
-        
-        info!("🔍 Unescaped path: {}", unescaped_path);
-        let new_path = PathBuf::from(unescaped_path);
+        let new_path = Self::unescape_relocation_path(&path_str);
+        info!("🔍 Unescaped path: {}", new_path.display());
         info!("🔄 Starting child data directory relocation for '{}' to: {}", child_name, new_path.display());
         info!("🔍 Final path components: {:?}", new_path.components().collect::<Vec<_>>());
         
@@ -206,6 +406,9 @@ impl CsvConnection {
         }
         info!("✅ Source directory exists");
         
+        // Don't move data out from under another instance that is writing to it
+        self.ensure_child_writable(&current_actual_dir)?;
+        
         // Create parent directories for the target path if needed
         if let Some(parent) = new_path.parent() {
             info!("🔍 Checking parent directory: {}", parent.display());
@@ -558,6 +761,7 @@ impl CsvConnection {
     /// Revert a child's data directory back to the default location
     pub fn revert_child_data_directory(&self, child_name: &str) -> Result<String> {
         info!("Starting child data directory revert for '{}'", child_name);
+        self.ensure_writable()?;
         
         let base_dir = self.base_directory.lock().unwrap();
         let default_child_dir = base_dir.join(child_name);
@@ -665,6 +869,49 @@ impl CsvConnection {
             .to_string()
     }
     
+    /// Child directories whose `.allowance_redirect` points at a folder that
+    /// isn't there, such as a USB drive that isn't plugged in
+    ///
+    /// `get_child_directory` falls back to the default location for these,
+    /// so anything written before the folder comes back would go to the wrong place.
+    pub fn missing_redirect_targets(&self) -> Result<Vec<(String, PathBuf)>> {
+        let base_dir = self.base_directory();
+        if !base_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut missing = Vec::new();
+        for entry in fs::read_dir(base_dir)? {
+            let path = entry?.path();
+            let Some(dir_name) = path.file_name().and_then(|n| n.to_str()).map(str::to_string) else {
+                continue;
+            };
+            let Ok(target) = fs::read_to_string(path.join(".allowance_redirect")) else {
+                continue;
+            };
+            let target = PathBuf::from(target.trim());
+            if !target.exists() {
+                missing.push((dir_name, target));
+            }
+        }
+        missing.sort();
+        Ok(missing)
+    }
+
+    /// Point a child directory's redirect at a folder found somewhere else
+    ///
+    /// Only the redirect file in the default location is written, so this
+    /// works in read-only mode; the folder must already hold the child's data.
+    pub fn redirect_child_directory(&self, child_directory: &str, target: &Path) -> Result<()> {
+        if !target.join("child.yaml").exists() {
+            return Err(anyhow::anyhow!("{} doesn't contain a child's data (no child.yaml)", target.display()));
+        }
+        let redirect_file = self.base_directory().join(child_directory).join(".allowance_redirect");
+        fs::write(&redirect_file, target.to_string_lossy().as_bytes())?;
+        info!("Child {} data redirected to: {}", child_directory, target.display());
+        Ok(())
+    }
+
     /// Find the child directory name that contains a child with the given child_id
     /// This is the authoritative method for mapping child IDs to directory names
     /// Returns the directory name (not full path) that can be used with get_child_directory()
@@ -984,6 +1231,28 @@ mod tests {
         println!("✅ Error handling test with invalid escaped paths passed");
         Ok(())
     }
+    
+    #[test]
+    fn test_batched_writes_are_held_until_the_batch_ends() -> Result<()> {
+        let (connection, _temp_dir) = create_test_connection()?;
+        let child_dir = connection.get_child_directory("test_child");
+        fs::create_dir_all(&child_dir)?;
+        let path = child_dir.join("loans.yaml");
+        
+        let result: Result<()> = connection.batch_writes("Imported 2 transactions", || {
+            connection.write_child_file(&path, b"first")?;
+            connection.write_child_file(&path, b"second")?;
+            // Reads see the held contents before anything reaches the disk
+            assert_eq!(connection.read_child_file(&path)?, Some("second".to_string()));
+            assert!(!path.exists());
+            Err(anyhow::anyhow!("row 3 is invalid"))
+        });
+        
+        // A failed batch still writes what it changed
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&path)?, "second");
+        Ok(())
+    }
 }
 
 impl Connection for CsvConnection {
@@ -0,0 +1,165 @@
+//! # CSV Schema
+//!
+//! Shared reading and writing rules for the per-child CSV files, so columns
+//! can be added without breaking older files or tools that add their own:
+//!
+//! - Every file starts with a `# schema_version: N` comment line, then the
+//!   header row. The comment is skipped when reading.
+//! - Columns are found by header name, never by position. Unknown columns
+//!   are ignored, and a column the file doesn't have reads as empty.
+//! - A file whose header is an older, shorter version of today's columns may
+//!   have rows appended with the newer columns at the end, as the audit log
+//!   did before this module existed. Values past the end of such a header
+//!   are still read at their usual position.
+//!
+//! Files are always rewritten in the current layout, so columns added by
+//! another tool are kept only until the app next rewrites the file.
+//!
+//! ```csv
+//! # schema_version: 1
+//! id,transaction_id,date,amount,percentage,goal_id,source
+//! ```
+
+use anyhow::Result;
+use csv::{Reader, ReaderBuilder, StringRecord, Writer};
+use std::collections::HashMap;
+
+const SCHEMA_VERSION_PREFIX: &str = "# schema_version:";
+
+/// Reader for a schema-versioned CSV file: skips the version comment and
+/// allows rows of any width
+pub fn reader(content: &str) -> Reader<&[u8]> {
+    ReaderBuilder::new()
+        .flexible(true)
+        .comment(Some(b'#'))
+        .from_reader(content.as_bytes())
+}
+
+/// Writer for a schema-versioned CSV file, with the version comment and the
+/// header already written
+pub fn writer(version: u32, columns: &[&str]) -> Result<Writer<Vec<u8>>> {
+    let mut csv_writer = Writer::from_writer(schema_comment(version).into_bytes());
+    csv_writer.write_record(columns)?;
+    Ok(csv_writer)
+}
+
+/// The comment line that starts a file at `version`
+pub fn schema_comment(version: u32) -> String {
+    format!("{} {}\n", SCHEMA_VERSION_PREFIX, version)
+}
+
+/// The schema version a file declares, if it starts with the version comment
+pub fn schema_version(content: &str) -> Option<u32> {
+    content
+        .lines()
+        .next()?
+        .strip_prefix(SCHEMA_VERSION_PREFIX)?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Whether a file can be appended to as it is: the current version and
+/// exactly `columns`, in order
+pub fn is_current(content: &str, version: u32, columns: &[&str]) -> Result<bool> {
+    if schema_version(content) != Some(version) {
+        return Ok(false);
+    }
+    let headers = reader(content).headers()?.clone();
+    Ok(headers.iter().eq(columns.iter().copied()))
+}
+
+/// Where each known column is in one file
+pub struct ColumnIndex {
+    positions: HashMap<&'static str, usize>,
+}
+
+impl ColumnIndex {
+    /// Match a file's header row against the columns the app knows, in their
+    /// current order
+    pub fn new(headers: &StringRecord, columns: &[&'static str]) -> Self {
+        let mut positions = HashMap::new();
+        for (position, name) in headers.iter().enumerate() {
+            if let Some(column) = columns.iter().find(|column| **column == name.trim()) {
+                positions.entry(*column).or_insert(position);
+            }
+        }
+
+        // An older header, a prefix of today's columns, may have newer rows
+        // appended with the added columns after it
+        let is_prefix = headers.len() < columns.len()
+            && headers.iter().zip(columns.iter()).all(|(name, column)| name.trim() == *column);
+        if is_prefix {
+            for (position, column) in columns.iter().enumerate().skip(headers.len()) {
+                positions.entry(*column).or_insert(position);
+            }
+        }
+
+        Self { positions }
+    }
+
+    /// A column's value in `record`, or `None` if the file or row doesn't have it
+    pub fn get<'r>(&self, record: &'r StringRecord, column: &str) -> Option<&'r str> {
+        self.positions.get(column).and_then(|position| record.get(*position))
+    }
+
+    /// A column's value in `record`, empty if it is missing
+    pub fn value<'r>(&self, record: &'r StringRecord, column: &str) -> &'r str {
+        self.get(record, column).unwrap_or("")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLUMNS: &[&str] = &["id", "amount", "actor"];
+
+    fn rows(content: &str) -> Vec<(String, String, Option<String>)> {
+        let mut csv_reader = reader(content);
+        let columns = ColumnIndex::new(&csv_reader.headers().unwrap().clone(), COLUMNS);
+        csv_reader
+            .records()
+            .map(|record| {
+                let record = record.unwrap();
+                (
+                    columns.value(&record, "id").to_string(),
+                    columns.value(&record, "amount").to_string(),
+                    columns.get(&record, "actor").map(str::to_string),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_columns_are_read_by_name() {
+        let content = "# schema_version: 1\nnote,actor,amount,id\nhello,kid,5,a\n";
+        assert_eq!(rows(content), vec![("a".to_string(), "5".to_string(), Some("kid".to_string()))]);
+    }
+
+    #[test]
+    fn test_older_header_reads_appended_columns_by_position() {
+        let content = "id,amount\na,5\nb,6,parent\n";
+        assert_eq!(
+            rows(content),
+            vec![
+                ("a".to_string(), "5".to_string(), None),
+                ("b".to_string(), "6".to_string(), Some("parent".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_written_files_declare_their_version() -> Result<()> {
+        let mut csv_writer = writer(3, COLUMNS)?;
+        csv_writer.write_record(["a", "5", ""])?;
+        let content = String::from_utf8(csv_writer.into_inner()?)?;
+
+        assert_eq!(content, "# schema_version: 3\nid,amount,actor\na,5,\n");
+        assert_eq!(schema_version(&content), Some(3));
+        assert!(is_current(&content, 3, COLUMNS)?);
+        assert!(!is_current(&content, 2, COLUMNS)?);
+        assert!(!is_current("id,amount,actor\n", 3, COLUMNS)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+//! # Data Location
+//!
+//! Works out which folder the app opens before any backend exists, in this
+//! order:
+//!
+//! 1. The portable folder next to the app (see the `portable` module)
+//! 2. A folder a parent chose when the usual one couldn't be opened
+//! 3. `~/Documents/Allowance Tracker`
+//!
+//! The chosen folder is remembered in `data_directory.txt` in the OS config
+//! directory (e.g. `~/.config/Allowance Tracker` on Linux), outside any data
+//! folder, so it still works when the usual folder is the one that's broken.
+//!
+//! A folder can also be filled from a backup zip, such as the archive a
+//! reset writes, when the data itself was lost. "Export all" archives can't
+//! be restored this way; they hold only part of the data.
+
+use anyhow::Result;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::backend::domain::models::export_manifest::EXPORT_MANIFEST_FILE;
+use crate::backend::storage::zip_archive::read_zip_entries;
+use super::portable;
+
+/// File in the OS config directory holding the folder a parent chose
+const CHOSEN_DIRECTORY_FILE: &str = "data_directory.txt";
+
+/// Where the data lives when nothing else is set up
+pub fn default_data_directory() -> Result<PathBuf> {
+    let home_dir = dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
+    Ok(home_dir.join("Documents").join("Allowance Tracker"))
+}
+
+/// The folder to open at startup: portable, then chosen, then the default
+pub fn resolve_data_directory() -> Result<PathBuf> {
+    if let Some(portable) = portable::find_portable_data_directory() {
+        log::info!("🧳 Portable mode: using data next to the app at {:?}", portable);
+        return Ok(portable);
+    }
+    if let Some(chosen) = chosen_data_directory() {
+        log::info!("📁 Using the data folder chosen at startup: {:?}", chosen);
+        return Ok(chosen);
+    }
+    default_data_directory()
+}
+
+/// The folder a parent chose when the usual one couldn't be opened, if any
+pub fn chosen_data_directory() -> Option<PathBuf> {
+    let contents = fs::read_to_string(chosen_directory_file()?).ok()?;
+    let path = contents.trim();
+    (!path.is_empty()).then(|| PathBuf::from(path))
+}
+
+/// Open this folder from now on, instead of the default one
+pub fn remember_data_directory(path: &Path) -> Result<()> {
+    let file = chosen_directory_file().ok_or_else(|| anyhow::anyhow!("Could not find the config directory"))?;
+    if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&file, path.to_string_lossy().as_bytes())?;
+    log::info!("📁 Remembered data folder {:?}", path);
+    Ok(())
+}
+
+fn chosen_directory_file() -> Option<PathBuf> {
+    Some(dirs::config_dir()?.join("Allowance Tracker").join(CHOSEN_DIRECTORY_FILE))
+}
+
+/// Unpack a backup zip into an empty folder, returning how many files were written
+pub fn restore_backup_archive(archive: &Path, target: &Path) -> Result<usize> {
+    if target.exists() && fs::read_dir(target)?.next().is_some() {
+        return Err(anyhow::anyhow!("Restore into an empty folder; {} already has files in it", target.display()));
+    }
+    let entries = read_zip_entries(&fs::read(archive)?)?;
+    if entries.is_empty() {
+        return Err(anyhow::anyhow!("{} has no files in it", archive.display()));
+    }
+    if entries.iter().any(|(name, _)| name == EXPORT_MANIFEST_FILE) {
+        return Err(anyhow::anyhow!("{} is an export, not a backup, and can't be restored", archive.display()));
+    }
+
+    // Check every name before writing anything, so a bad archive leaves the folder empty
+    let mut files = Vec::with_capacity(entries.len());
+    for (name, contents) in entries {
+        let relative = Path::new(&name);
+        if !relative.components().all(|component| matches!(component, Component::Normal(_))) {
+            return Err(anyhow::anyhow!("{} contains an unsafe path: {}", archive.display(), name));
+        }
+        files.push((target.join(relative), contents));
+    }
+
+    for (path, contents) in &files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+    }
+    log::info!("📦 Restored {} files from {:?} into {:?}", files.len(), archive, target);
+    Ok(files.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::zip_archive::ZipArchiveWriter;
+    use tempfile::TempDir;
+
+    fn archive_with(temp_dir: &TempDir, entries: &[(&str, &str)]) -> PathBuf {
+        let mut writer = ZipArchiveWriter::new(chrono::Local::now().naive_local());
+        for (name, contents) in entries {
+            writer.add_file(name, contents.as_bytes()).unwrap();
+        }
+        let path = temp_dir.path().join("backup.zip");
+        fs::write(&path, writer.finish().unwrap()).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_backup_restores_into_an_empty_folder_only() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive = archive_with(&temp_dir, &[("global_config.yaml", "schema_version: 1\n"), ("alice/child.yaml", "name: alice\n")]);
+        let target = temp_dir.path().join("restored");
+
+        assert_eq!(restore_backup_archive(&archive, &target).unwrap(), 2);
+        assert_eq!(fs::read_to_string(target.join("alice").join("child.yaml")).unwrap(), "name: alice\n");
+        assert!(restore_backup_archive(&archive, &target).unwrap_err().to_string().contains("empty folder"));
+    }
+
+    #[test]
+    fn test_backup_with_unsafe_paths_writes_nothing() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive = archive_with(&temp_dir, &[("ok.yaml", "a"), ("../escape.yaml", "b")]);
+        let target = temp_dir.path().join("restored");
+
+        assert!(restore_backup_archive(&archive, &target).is_err());
+        assert!(!target.exists());
+        assert!(!temp_dir.path().join("escape.yaml").exists());
+    }
+
+    #[test]
+    fn test_export_archive_is_not_restored() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive = archive_with(&temp_dir, &[(EXPORT_MANIFEST_FILE, "children: []\n"), ("children/alice/transactions.csv", "id\n")]);
+        let target = temp_dir.path().join("restored");
+
+        let error = restore_backup_archive(&archive, &target).unwrap_err();
+        assert!(error.to_string().contains("is an export, not a backup"), "{}", error);
+        assert!(!target.exists());
+    }
+}
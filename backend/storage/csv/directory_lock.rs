@@ -0,0 +1,378 @@
+//! # Child Directory Locks
+//!
+//! Advisory lock files that stop two app instances (for example on two
+//! machines sharing a Dropbox or iCloud folder) from writing the same child's
+//! CSV files at the same time.
+//!
+//! Each child directory gets a `.allowance_lock` YAML file naming the instance
+//! that holds it. The lock is taken on the first write and refreshed by later
+//! writes and by the app's periodic tick (see
+//! [`DirectoryLockManager::refresh_held`]), so an instance left open without
+//! writing keeps it. A lock that hasn't been refreshed for
+//! [`STALE_LOCK_SECONDS`] is treated as abandoned (crashed app, sleeping
+//! laptop) and taken over.
+//!
+//! When another instance holds a fresh lock, writes fail with
+//! [`DirectoryLockError`] while reads keep working, so the app falls back to
+//! read-only access until the other instance lets go.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Name of the lock file inside each child directory
+pub const LOCK_FILE_NAME: &str = ".allowance_lock";
+
+/// Locks not refreshed for this long are considered abandoned
+pub const STALE_LOCK_SECONDS: i64 = 10 * 60;
+
+/// How often a held lock's timestamp is rewritten
+const REFRESH_INTERVAL_SECONDS: i64 = 60;
+
+/// Contents of a lock file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockHolder {
+    pub instance_id: String,
+    pub hostname: String,
+    pub pid: u32,
+    pub acquired_at: String,  // RFC 3339
+    pub refreshed_at: String, // RFC 3339
+}
+
+impl LockHolder {
+    /// Whether the holder has stopped refreshing the lock
+    pub fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        match DateTime::parse_from_rfc3339(&self.refreshed_at) {
+            Ok(refreshed_at) => (now - refreshed_at.with_timezone(&Utc)).num_seconds() >= STALE_LOCK_SECONDS,
+            Err(_) => true,
+        }
+    }
+}
+
+/// Error returned when writing to a directory locked by another instance
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum DirectoryLockError {
+    #[error("Data directory {path} is in use by another instance on '{hostname}' since {acquired_at}; it is read-only until that instance closes")]
+    HeldByOtherInstance {
+        path: String,
+        hostname: String,
+        acquired_at: String,
+    },
+}
+
+/// Lock state of a directory as seen by this instance
+#[derive(Debug, Clone, PartialEq)]
+pub enum LockStatus {
+    /// No live lock; the next write will take it
+    Unlocked,
+    /// This instance holds the lock
+    HeldByUs,
+    /// Another instance holds a fresh lock, so the directory is read-only
+    HeldByOther(LockHolder),
+}
+
+/// Tracks the directory locks held by this app instance
+#[derive(Debug, Clone)]
+pub struct DirectoryLockManager {
+    instance_id: String,
+    hostname: String,
+    held: Arc<Mutex<HashSet<PathBuf>>>,
+}
+
+impl Default for DirectoryLockManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DirectoryLockManager {
+    /// Create a lock manager with a fresh instance id
+    pub fn new() -> Self {
+        Self {
+            instance_id: uuid::Uuid::new_v4().to_string(),
+            hostname: current_hostname(),
+            held: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    /// Take or refresh the lock on `dir`, failing if another instance holds it
+    pub fn ensure_writable(&self, dir: &Path) -> Result<()> {
+        if !dir.exists() {
+            // Nothing to protect yet; the lock is taken on the next write
+            return Ok(());
+        }
+
+        let now = Utc::now();
+        match self.read_lock(dir) {
+            Some(holder) if self.is_ours(&holder) => {
+                self.refresh_if_due(dir, &holder, now)?;
+                self.held.lock().unwrap().insert(dir.to_path_buf());
+                Ok(())
+            }
+            Some(holder) if !holder.is_stale(now) => {
+                self.held.lock().unwrap().remove(dir);
+                warn!("🔒 LOCK: {} is held by {} (pid {}); writes disabled", dir.display(), holder.hostname, holder.pid);
+                Err(DirectoryLockError::HeldByOtherInstance {
+                    path: dir.to_string_lossy().to_string(),
+                    hostname: holder.hostname,
+                    acquired_at: holder.acquired_at,
+                }
+                .into())
+            }
+            stale => {
+                if let Some(holder) = stale {
+                    warn!("🔒 LOCK: Taking over stale lock on {} from {} (last refreshed {})", dir.display(), holder.hostname, holder.refreshed_at);
+                }
+                self.write_lock(dir, &now.to_rfc3339(), now)?;
+                self.held.lock().unwrap().insert(dir.to_path_buf());
+                info!("🔒 LOCK: Acquired lock on {}", dir.display());
+                Ok(())
+            }
+        }
+    }
+
+    /// Report the lock state of `dir` without changing it
+    pub fn status(&self, dir: &Path) -> LockStatus {
+        match self.read_lock(dir) {
+            Some(holder) if self.is_ours(&holder) => LockStatus::HeldByUs,
+            Some(holder) if !holder.is_stale(Utc::now()) => LockStatus::HeldByOther(holder),
+            _ => LockStatus::Unlocked,
+        }
+    }
+
+    /// Refresh every lock this instance holds, so one that sees no writes
+    /// isn't taken for abandoned. A lock another instance has taken over
+    /// since is no longer ours and is dropped.
+    pub fn refresh_held(&self) {
+        let now = Utc::now();
+        self.held.lock().unwrap().retain(|dir| match self.read_lock(dir) {
+            Some(holder) if self.is_ours(&holder) => {
+                if let Err(e) = self.refresh_if_due(dir, &holder, now) {
+                    warn!("🔒 LOCK: Failed to refresh lock on {}: {}", dir.display(), e);
+                }
+                true
+            }
+            _ => {
+                warn!("🔒 LOCK: Lost lock on {}", dir.display());
+                false
+            }
+        });
+    }
+
+    /// Release every lock this instance holds
+    pub fn release_all(&self) {
+        let dirs: Vec<PathBuf> = self.held.lock().unwrap().drain().collect();
+        for dir in dirs {
+            // Only remove the file if it is still ours
+            if matches!(self.read_lock(&dir), Some(holder) if self.is_ours(&holder)) {
+                match fs::remove_file(dir.join(LOCK_FILE_NAME)) {
+                    Ok(()) => info!("🔒 LOCK: Released lock on {}", dir.display()),
+                    Err(e) => warn!("🔒 LOCK: Failed to release lock on {}: {}", dir.display(), e),
+                }
+            }
+        }
+    }
+
+    /// Locks written by this process count as ours, even from another connection
+    fn is_ours(&self, holder: &LockHolder) -> bool {
+        holder.instance_id == self.instance_id
+            || (holder.hostname == self.hostname && holder.pid == std::process::id())
+    }
+
+    fn refresh_if_due(&self, dir: &Path, holder: &LockHolder, now: DateTime<Utc>) -> Result<()> {
+        let refreshed_at = DateTime::parse_from_rfc3339(&holder.refreshed_at)
+            .map(|t| t.with_timezone(&Utc))
+            .unwrap_or(now);
+        if (now - refreshed_at).num_seconds() >= REFRESH_INTERVAL_SECONDS {
+            self.write_lock(dir, &holder.acquired_at, now)?;
+        }
+        Ok(())
+    }
+
+    fn read_lock(&self, dir: &Path) -> Option<LockHolder> {
+        let content = fs::read_to_string(dir.join(LOCK_FILE_NAME)).ok()?;
+        match serde_yaml::from_str(&content) {
+            Ok(holder) => Some(holder),
+            Err(e) => {
+                // A half-synced or corrupt lock file protects nothing; treat it as stale
+                warn!("🔒 LOCK: Ignoring unreadable lock file in {}: {}", dir.display(), e);
+                None
+            }
+        }
+    }
+
+    fn write_lock(&self, dir: &Path, acquired_at: &str, now: DateTime<Utc>) -> Result<()> {
+        let holder = LockHolder {
+            instance_id: self.instance_id.clone(),
+            hostname: self.hostname.clone(),
+            pid: std::process::id(),
+            acquired_at: acquired_at.to_string(),
+            refreshed_at: now.to_rfc3339(),
+        };
+        let lock_path = dir.join(LOCK_FILE_NAME);
+
+        // Use atomic write pattern: write to temp file, then rename
+        let temp_path = lock_path.with_extension("tmp");
+        fs::write(&temp_path, serde_yaml::to_string(&holder)?)?;
+        fs::rename(&temp_path, &lock_path)?;
+        Ok(())
+    }
+}
+
+/// Best-effort machine name for lock files
+fn current_hostname() -> String {
+    std::env::var("HOSTNAME")
+        .or_else(|_| std::env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "unknown-host".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn foreign_lock(dir: &Path, refreshed_at: DateTime<Utc>) {
+        let holder = LockHolder {
+            instance_id: "other-instance".to_string(),
+            hostname: "other-laptop".to_string(),
+            pid: 1,
+            acquired_at: refreshed_at.to_rfc3339(),
+            refreshed_at: refreshed_at.to_rfc3339(),
+        };
+        fs::write(dir.join(LOCK_FILE_NAME), serde_yaml::to_string(&holder).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_first_write_acquires_and_release_removes_lock() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let manager = DirectoryLockManager::new();
+
+        assert_eq!(manager.status(temp_dir.path()), LockStatus::Unlocked);
+        manager.ensure_writable(temp_dir.path())?;
+        assert!(temp_dir.path().join(LOCK_FILE_NAME).exists());
+        assert_eq!(manager.status(temp_dir.path()), LockStatus::HeldByUs);
+
+        // Re-entrant for the same instance
+        manager.ensure_writable(temp_dir.path())?;
+
+        manager.release_all();
+        assert!(!temp_dir.path().join(LOCK_FILE_NAME).exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fresh_foreign_lock_makes_directory_read_only() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        foreign_lock(temp_dir.path(), Utc::now());
+        let manager = DirectoryLockManager::new();
+
+        let err = manager.ensure_writable(temp_dir.path()).unwrap_err();
+        match err.downcast_ref::<DirectoryLockError>() {
+            Some(DirectoryLockError::HeldByOtherInstance { hostname, .. }) => assert_eq!(hostname, "other-laptop"),
+            None => panic!("expected DirectoryLockError, got {}", err),
+        }
+        assert!(matches!(manager.status(temp_dir.path()), LockStatus::HeldByOther(_)));
+
+        // Releasing must not delete someone else's lock
+        manager.release_all();
+        assert!(temp_dir.path().join(LOCK_FILE_NAME).exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_stale_foreign_lock_is_taken_over() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        foreign_lock(temp_dir.path(), Utc::now() - chrono::Duration::seconds(STALE_LOCK_SECONDS + 5));
+        let manager = DirectoryLockManager::new();
+
+        assert_eq!(manager.status(temp_dir.path()), LockStatus::Unlocked);
+        manager.ensure_writable(temp_dir.path())?;
+        assert_eq!(manager.status(temp_dir.path()), LockStatus::HeldByUs);
+        Ok(())
+    }
+
+    #[test]
+    fn test_held_locks_are_refreshed_without_writes() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let manager = DirectoryLockManager::new();
+        manager.ensure_writable(temp_dir.path())?;
+
+        // Backdate our lock as if the app had sat idle
+        let long_ago = Utc::now() - chrono::Duration::seconds(STALE_LOCK_SECONDS - 5);
+        manager.write_lock(temp_dir.path(), &long_ago.to_rfc3339(), long_ago)?;
+        manager.refresh_held();
+        let holder = manager.read_lock(temp_dir.path()).unwrap();
+        assert!(!holder.is_stale(Utc::now() + chrono::Duration::seconds(STALE_LOCK_SECONDS - 60)));
+        assert_eq!(holder.acquired_at, long_ago.to_rfc3339());
+
+        // Another instance took it over; it isn't ours to refresh or release any more
+        foreign_lock(temp_dir.path(), Utc::now());
+        manager.refresh_held();
+        manager.release_all();
+        assert!(matches!(manager.status(temp_dir.path()), LockStatus::HeldByOther(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_repository_writes_fail_but_reads_work_when_locked_elsewhere() -> Result<()> {
+        use crate::backend::domain::models::child_settings::ChildSettings;
+        use crate::backend::storage::csv::test_utils::TestHelper;
+        use crate::backend::storage::csv::ChildSettingsRepository;
+        use crate::backend::storage::traits::ChildSettingsStorage;
+
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let repo = ChildSettingsRepository::new(helper.env.connection.clone());
+        let child_directory = helper.env.connection.find_child_directory_by_id(&child.id)?.unwrap();
+        foreign_lock(&helper.env.connection.get_child_directory(&child_directory), Utc::now());
+
+        let err = repo.store_child_settings(&ChildSettings::default_for_child(&child.id)).unwrap_err();
+        assert!(err.downcast_ref::<DirectoryLockError>().is_some());
+        assert!(repo.get_child_settings(&child.id)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_deletes_fail_when_locked_elsewhere() -> Result<()> {
+        use crate::backend::domain::models::transaction::{Transaction, TransactionType};
+        use crate::backend::storage::csv::test_utils::TestHelper;
+        use crate::backend::storage::traits::TransactionStorage;
+
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let transaction = Transaction {
+            id: "transaction::income::1735732800000".to_string(),
+            child_id: child.id.clone(),
+            date: chrono::DateTime::parse_from_rfc3339("2025-01-01T12:00:00Z").unwrap(),
+            description: "Gift".to_string(),
+            amount: 10.0,
+            balance: 10.0,
+            transaction_type: TransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
+        };
+        helper.transaction_repo.store_transaction(&transaction)?;
+        let child_directory = helper.env.connection.find_child_directory_by_id(&child.id)?.unwrap();
+        foreign_lock(&helper.env.connection.get_child_directory(&child_directory), Utc::now());
+
+        let err = helper.transaction_repo.delete_transaction(&child.id, &transaction.id).unwrap_err();
+        assert!(err.downcast_ref::<DirectoryLockError>().is_some());
+        let err = helper
+            .transaction_repo
+            .delete_transactions(&child.id, &[transaction.id.clone()])
+            .unwrap_err();
+        assert!(err.downcast_ref::<DirectoryLockError>().is_some());
+        assert!(helper.transaction_repo.get_transaction(&child.id, &transaction.id)?.is_some());
+        Ok(())
+    }
+}
@@ -0,0 +1,479 @@
+//! # Encrypted Child Data
+//!
+//! Optional at-rest encryption of a child's data files: the CSV files
+//! (transactions, goals, audit log, savings allocations, parental control
+//! attempts), the YAML data files (loans, templates, reversals and the like)
+//! and the startup snapshot.
+//!
+//! An encrypted child directory contains an `encryption.yaml` marker holding a
+//! random salt, the key derivation parameters and a small verifier. The key is
+//! derived from the parental passphrase with Argon2id and each file is sealed
+//! with XChaCha20-Poly1305:
+//!
+//! ```text
+//! ATENC1\n | 24-byte nonce | ciphertext + 16-byte tag
+//! ```
+//!
+//! Keys are only kept in memory, indexed by the salt, so they still apply
+//! after a child's data directory is relocated. Until a directory is unlocked,
+//! reads and writes of its data files fail with [`EncryptionError::Locked`].
+//! The files needed to list children before unlocking (`child.yaml`, child
+//! settings, allowance config) stay in plain text, as do the marker itself and
+//! dot files such as the directory lock and a relocation redirect.
+
+use anyhow::Result;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use super::directory_lock::LOCK_FILE_NAME;
+
+/// Name of the marker file inside an encrypted child directory
+pub const ENCRYPTION_MARKER_FILE: &str = "encryption.yaml";
+
+/// Files in a child directory that are never sealed
+const PLAIN_TEXT_FILES: &[&str] = &[
+    ENCRYPTION_MARKER_FILE,
+    LOCK_FILE_NAME,
+    "child.yaml",
+    "child_settings.yaml",
+    "allowance_config.yaml",
+];
+
+/// Header written at the start of every encrypted file
+pub(crate) const MAGIC: &[u8] = b"ATENC1\n";
+
+const NONCE_LEN: usize = 24;
+const SALT_LEN: usize = 16;
+pub(crate) const KEY_LEN: usize = 32;
+
+/// Known plaintext sealed into the marker to check a passphrase
+const VERIFIER_PLAINTEXT: &[u8] = b"allowance-tracker";
+
+/// Argon2id cost; kept small in tests so they stay fast
+const KDF_MEMORY_KIB: u32 = if cfg!(test) { 1024 } else { 19 * 1024 };
+const KDF_ITERATIONS: u32 = 2;
+const KDF_PARALLELISM: u32 = 1;
+
+/// Contents of `encryption.yaml`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncryptionMarker {
+    pub format_version: u32,
+    pub cipher: String,
+    pub kdf: String,
+    pub salt: String, // hex
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+    pub verifier: String, // hex: nonce + sealed VERIFIER_PLAINTEXT
+    pub encrypted_at: String, // RFC 3339
+}
+
+/// Errors specific to encrypted child directories
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum EncryptionError {
+    #[error("Data in {path} is encrypted; unlock it with the parental passphrase first")]
+    Locked { path: String },
+    #[error("Incorrect passphrase for the encrypted data in {path}")]
+    WrongPassphrase { path: String },
+    #[error("Encrypted file {path} is damaged or was sealed with a different key")]
+    Corrupt { path: String },
+    #[error("Data in {path} is already encrypted")]
+    AlreadyEncrypted { path: String },
+    #[error("Data in {path} is not encrypted")]
+    NotEncrypted { path: String },
+}
+
+/// Unlocked keys shared by every clone of a connection
+#[derive(Clone, Default)]
+pub struct EncryptionKeys {
+    /// Derived keys by marker salt
+    keys: Arc<Mutex<HashMap<String, [u8; KEY_LEN]>>>,
+}
+
+impl std::fmt::Debug for EncryptionKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print key material
+        f.debug_struct("EncryptionKeys")
+            .field("unlocked", &self.keys.lock().unwrap().len())
+            .finish()
+    }
+}
+
+impl EncryptionKeys {
+    /// Read the encryption marker of a child directory, if it is encrypted
+    pub fn marker(dir: &Path) -> Result<Option<EncryptionMarker>> {
+        let marker_path = dir.join(ENCRYPTION_MARKER_FILE);
+        if !marker_path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&marker_path)?;
+        Ok(Some(serde_yaml::from_str(&content)?))
+    }
+
+    /// Whether the directory is encrypted and its key is loaded
+    pub fn is_unlocked(&self, dir: &Path) -> Result<bool> {
+        Ok(match Self::marker(dir)? {
+            Some(marker) => self.keys.lock().unwrap().contains_key(&marker.salt),
+            None => false,
+        })
+    }
+
+    /// Derive and remember the key for an encrypted directory
+    pub fn unlock(&self, dir: &Path, passphrase: &str) -> Result<()> {
+        let marker = Self::marker(dir)?.ok_or_else(|| EncryptionError::NotEncrypted {
+            path: dir.to_string_lossy().to_string(),
+        })?;
+        let key = derive_key(passphrase, &marker)?;
+        let verifier = decode_hex(&marker.verifier)?;
+        match open(&key, &verifier) {
+            Some(plaintext) if plaintext == VERIFIER_PLAINTEXT => {}
+            _ => {
+                return Err(EncryptionError::WrongPassphrase {
+                    path: dir.to_string_lossy().to_string(),
+                }
+                .into())
+            }
+        }
+        self.keys.lock().unwrap().insert(marker.salt, key);
+        info!("🔐 ENCRYPTION: Unlocked {}", dir.display());
+        Ok(())
+    }
+
+    /// Forget every unlocked key
+    pub fn lock_all(&self) {
+        let mut keys = self.keys.lock().unwrap();
+        for key in keys.values_mut() {
+            key.fill(0);
+        }
+        keys.clear();
+    }
+
+    /// Read a data file, decrypting it if needed. Returns `None` if it doesn't exist.
+    pub fn read(&self, path: &Path) -> Result<Option<Vec<u8>>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let bytes = fs::read(path)?;
+        if !bytes.starts_with(MAGIC) {
+            return Ok(Some(bytes));
+        }
+        let key = self.key_for(path)?;
+        let plaintext = open(&key, &bytes[MAGIC.len()..]).ok_or_else(|| EncryptionError::Corrupt {
+            path: path.to_string_lossy().to_string(),
+        })?;
+        Ok(Some(plaintext))
+    }
+
+    /// Write a data file, encrypting it if its directory is encrypted
+    pub fn write(&self, path: &Path, contents: &[u8]) -> Result<()> {
+        if !is_sealed_file(path) {
+            return write_atomic(path, contents);
+        }
+        let dir = path.parent().unwrap_or(Path::new("."));
+        match Self::marker(dir)? {
+            Some(marker) => {
+                let key = self.key_for_marker(dir, &marker)?;
+                let mut sealed = MAGIC.to_vec();
+                sealed.extend(seal(&key, contents)?);
+                write_atomic(path, &sealed)
+            }
+            None => write_atomic(path, contents),
+        }
+    }
+
+    /// Append to a data file. Encrypted files are rewritten as a whole.
+    pub fn append(&self, path: &Path, contents: &[u8]) -> Result<()> {
+        let dir = path.parent().unwrap_or(Path::new("."));
+        if !is_sealed_file(path) || Self::marker(dir)?.is_none() {
+            let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+            file.write_all(contents)?;
+            return Ok(());
+        }
+        let mut existing = self.read(path)?.unwrap_or_default();
+        existing.extend_from_slice(contents);
+        self.write(path, &existing)
+    }
+
+    /// Encrypt every data file in `dir` and write its marker. Returns the number of files encrypted.
+    pub fn encrypt_directory(&self, dir: &Path, passphrase: &str) -> Result<usize> {
+        if Self::marker(dir)?.is_some() {
+            return Err(EncryptionError::AlreadyEncrypted {
+                path: dir.to_string_lossy().to_string(),
+            }
+            .into());
+        }
+
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let mut marker = EncryptionMarker {
+            format_version: 1,
+            cipher: "xchacha20poly1305".to_string(),
+            kdf: "argon2id".to_string(),
+            salt: encode_hex(&salt),
+            memory_kib: KDF_MEMORY_KIB,
+            iterations: KDF_ITERATIONS,
+            parallelism: KDF_PARALLELISM,
+            verifier: String::new(),
+            encrypted_at: chrono::Utc::now().to_rfc3339(),
+        };
+        let key = derive_key(passphrase, &marker)?;
+        marker.verifier = encode_hex(&seal(&key, VERIFIER_PLAINTEXT)?);
+
+        // Read everything before the marker exists so plaintext files are picked up as-is
+        let files = data_files(dir)?;
+        let mut contents = Vec::new();
+        for path in &files {
+            contents.push(fs::read(path)?);
+        }
+
+        write_atomic(&dir.join(ENCRYPTION_MARKER_FILE), serde_yaml::to_string(&marker)?.as_bytes())?;
+        self.keys.lock().unwrap().insert(marker.salt.clone(), key);
+        for (path, plaintext) in files.iter().zip(contents) {
+            if plaintext.starts_with(MAGIC) {
+                continue;
+            }
+            self.write(path, &plaintext)?;
+        }
+        info!("🔐 ENCRYPTION: Encrypted {} file(s) in {}", files.len(), dir.display());
+        Ok(files.len())
+    }
+
+    /// Decrypt every sealed file in `dir` and remove its marker. Returns the number of files decrypted.
+    pub fn decrypt_directory(&self, dir: &Path, passphrase: &str) -> Result<usize> {
+        self.unlock(dir, passphrase)?;
+        let marker = Self::marker(dir)?.expect("unlocked directory has a marker");
+
+        let mut files = Vec::new();
+        for path in data_files(dir)? {
+            if fs::read(&path)?.starts_with(MAGIC) {
+                files.push(path);
+            }
+        }
+        let mut contents = Vec::new();
+        for path in &files {
+            contents.push(self.read(path)?.unwrap_or_default());
+        }
+        // Write plaintext before dropping the marker so a failure leaves every file readable
+        for (path, plaintext) in files.iter().zip(&contents) {
+            write_atomic(path, plaintext)?;
+        }
+        fs::remove_file(dir.join(ENCRYPTION_MARKER_FILE))?;
+        if let Some(mut key) = self.keys.lock().unwrap().remove(&marker.salt) {
+            key.fill(0);
+        }
+        info!("🔐 ENCRYPTION: Decrypted {} file(s) in {}", files.len(), dir.display());
+        Ok(files.len())
+    }
+
+    fn key_for(&self, path: &Path) -> Result<[u8; KEY_LEN]> {
+        let dir = path.parent().unwrap_or(Path::new("."));
+        let marker = Self::marker(dir)?.ok_or_else(|| EncryptionError::Corrupt {
+            path: path.to_string_lossy().to_string(),
+        })?;
+        self.key_for_marker(dir, &marker)
+    }
+
+    fn key_for_marker(&self, dir: &Path, marker: &EncryptionMarker) -> Result<[u8; KEY_LEN]> {
+        self.keys
+            .lock()
+            .unwrap()
+            .get(&marker.salt)
+            .copied()
+            .ok_or_else(|| {
+                EncryptionError::Locked {
+                    path: dir.to_string_lossy().to_string(),
+                }
+                .into()
+            })
+    }
+}
+
+fn derive_key(passphrase: &str, marker: &EncryptionMarker) -> Result<[u8; KEY_LEN]> {
+    let params = argon2::Params::new(marker.memory_kib, marker.iterations, marker.parallelism, Some(KEY_LEN))
+        .map_err(|e| anyhow::anyhow!("Invalid key derivation parameters: {}", e))?;
+    let argon2 = argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params);
+    let salt = decode_hex(&marker.salt)?;
+    let mut key = [0u8; KEY_LEN];
+    argon2
+        .hash_password_into(passphrase.as_bytes(), &salt, &mut key)
+        .map_err(|e| anyhow::anyhow!("Key derivation failed: {}", e))?;
+    Ok(key)
+}
+
+/// Encrypt with a fresh random nonce, returning `nonce || ciphertext`
+pub(crate) fn seal(key: &[u8; KEY_LEN], plaintext: &[u8]) -> Result<Vec<u8>> {
+    let cipher = XChaCha20Poly1305::new(Key::from_slice(key));
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|_| anyhow::anyhow!("Encryption failed"))?;
+    let mut sealed = nonce.to_vec();
+    sealed.extend(ciphertext);
+    Ok(sealed)
+}
+
+/// Decrypt `nonce || ciphertext`; `None` if the key is wrong or the data was altered
+pub(crate) fn open(key: &[u8; KEY_LEN], sealed: &[u8]) -> Option<Vec<u8>> {
+    if sealed.len() < NONCE_LEN {
+        return None;
+    }
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    XChaCha20Poly1305::new(Key::from_slice(key))
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .ok()
+}
+
+/// Whether a file is one that gets sealed in an encrypted directory
+fn is_sealed_file(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    !name.starts_with('.') && !PLAIN_TEXT_FILES.contains(&name) && path.extension().is_none_or(|ext| ext != "tmp")
+}
+
+/// Data files directly inside a child directory, sorted by name
+fn data_files(dir: &Path) -> Result<Vec<std::path::PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && is_sealed_file(&path) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    // Use atomic write pattern: write to temp file, then rename
+    let temp_path = path.with_extension("tmp");
+    fs::write(&temp_path, contents)?;
+    fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>> {
+    if !hex.is_ascii() || !hex.len().is_multiple_of(2) {
+        return Err(anyhow::anyhow!("Invalid hex string in encryption marker"));
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|e| anyhow::anyhow!("Invalid hex in encryption marker: {}", e)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_encrypt_directory_round_trip() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let csv_path = temp_dir.path().join("transactions.csv");
+        fs::write(&csv_path, "id,amount\ntx-1,5\n")?;
+        fs::write(temp_dir.path().join("child.yaml"), "name: Alice\n")?;
+
+        let keys = EncryptionKeys::default();
+        assert_eq!(keys.encrypt_directory(temp_dir.path(), "ice cold")?, 1);
+        assert!(fs::read(&csv_path)?.starts_with(MAGIC));
+        // YAML stays readable without the key
+        assert_eq!(fs::read_to_string(temp_dir.path().join("child.yaml"))?, "name: Alice\n");
+        assert_eq!(keys.read(&csv_path)?.unwrap(), b"id,amount\ntx-1,5\n");
+
+        keys.append(&csv_path, b"tx-2,3\n")?;
+        assert_eq!(keys.read(&csv_path)?.unwrap(), b"id,amount\ntx-1,5\ntx-2,3\n");
+
+        assert_eq!(keys.decrypt_directory(temp_dir.path(), "ice cold")?, 1);
+        assert_eq!(fs::read_to_string(&csv_path)?, "id,amount\ntx-1,5\ntx-2,3\n");
+        assert!(!temp_dir.path().join(ENCRYPTION_MARKER_FILE).exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_writes_after_encrypting_seal_every_data_file() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let csv_path = temp_dir.path().join("transactions.csv");
+        let yaml_path = temp_dir.path().join("loans.yaml");
+        let snapshot_path = temp_dir.path().join("snapshot.json");
+        let child_path = temp_dir.path().join("child.yaml");
+        let lock_path = temp_dir.path().join(LOCK_FILE_NAME);
+        fs::write(&csv_path, "id,amount\n")?;
+        fs::write(&snapshot_path, "{}")?;
+        fs::write(&child_path, "name: Alice\n")?;
+        let keys = EncryptionKeys::default();
+        assert_eq!(keys.encrypt_directory(temp_dir.path(), "ice cold")?, 2);
+        assert!(fs::read(&snapshot_path)?.starts_with(MAGIC));
+
+        keys.write(&csv_path, b"id,amount\ntx-1,5\n")?;
+        keys.write(&yaml_path, b"loans: []\n")?;
+        keys.write(&lock_path, b"pid: 1\n")?;
+        assert!(fs::read(&csv_path)?.starts_with(MAGIC));
+        assert!(fs::read(&yaml_path)?.starts_with(MAGIC));
+        assert_eq!(fs::read_to_string(&lock_path)?, "pid: 1\n");
+        assert_eq!(fs::read_to_string(&child_path)?, "name: Alice\n");
+        assert_eq!(keys.read(&csv_path)?.unwrap(), b"id,amount\ntx-1,5\n");
+        assert_eq!(keys.read(&yaml_path)?.unwrap(), b"loans: []\n");
+
+        assert_eq!(keys.decrypt_directory(temp_dir.path(), "ice cold")?, 3);
+        assert_eq!(fs::read_to_string(&csv_path)?, "id,amount\ntx-1,5\n");
+        assert_eq!(fs::read_to_string(&yaml_path)?, "loans: []\n");
+        assert_eq!(fs::read_to_string(&snapshot_path)?, "{}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_locked_directory_requires_correct_passphrase() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let csv_path = temp_dir.path().join("goals.csv");
+        fs::write(&csv_path, "id\n")?;
+        EncryptionKeys::default().encrypt_directory(temp_dir.path(), "ice cold")?;
+
+        // A fresh key store (like a restarted app) starts locked
+        let keys = EncryptionKeys::default();
+        let err = keys.read(&csv_path).unwrap_err();
+        assert!(matches!(err.downcast_ref::<EncryptionError>(), Some(EncryptionError::Locked { .. })));
+        assert!(keys.write(&csv_path, b"id\n").is_err());
+
+        let err = keys.unlock(temp_dir.path(), "warm").unwrap_err();
+        assert!(matches!(err.downcast_ref::<EncryptionError>(), Some(EncryptionError::WrongPassphrase { .. })));
+
+        keys.unlock(temp_dir.path(), "ice cold")?;
+        assert!(keys.is_unlocked(temp_dir.path())?);
+        assert_eq!(keys.read(&csv_path)?.unwrap(), b"id\n");
+
+        keys.lock_all();
+        assert!(!keys.is_unlocked(temp_dir.path())?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tampered_file_is_reported_as_corrupt() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let csv_path = temp_dir.path().join("transactions.csv");
+        fs::write(&csv_path, "id,amount\n")?;
+        let keys = EncryptionKeys::default();
+        keys.encrypt_directory(temp_dir.path(), "ice cold")?;
+
+        let mut bytes = fs::read(&csv_path)?;
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+        fs::write(&csv_path, bytes)?;
+
+        let err = keys.read(&csv_path).unwrap_err();
+        assert!(matches!(err.downcast_ref::<EncryptionError>(), Some(EncryptionError::Corrupt { .. })));
+        Ok(())
+    }
+}
@@ -18,7 +18,50 @@
 //! ## YAML Format
 //!
 //! ```yaml
+//! schema_version: 1
 //! active_child_directory: "child_name"
+//! locale: "en-US"
+//! text_size: normal
+//! show_week_summaries: true
+//! show_goal_strip: true
+//! theme: system
+//! reduce_motion: true
+//! tutorial_completed: true
+//! currency: USD
+//! exchange_rates:
+//!   GBP: 1.27
+//! parents:
+//! - id: parent::1737487800000
+//!   name: Grandma
+//!   pin_hash: $argon2id$v=19$...
+//!   permissions: [manage_settings]
+//!   created_at: "2025-01-21T19:30:00Z"
+//! retention:
+//!   audit_log_days: 365
+//!   last_run_on: 2025-01-21
+//! report_schedule:
+//!   folder: /Users/parent/Documents/Allowance reports
+//!   last_month_generated: 2025-01-01
+//!   history:
+//!   - child_id: child::1737487800000
+//!     child_name: Alice
+//!     year: 2025
+//!     month: 1
+//!     path: /Users/parent/Documents/Allowance reports/alice_2025-01_report.csv
+//!     generated_at: "2025-02-01T08:00:00Z"
+//! desktop_notifications:
+//!   allowance_posted: true
+//!   goal_completed: true
+//! updates:
+//!   channel: beta
+//!   check_automatically: true
+//!   last_checked_at: 2025-01-21T19:30:00Z
+//! fridge_view:
+//!   path: /Users/parent/Dropbox/fridge.html
+//!   auto_update: true
+//! require_parental_control_for_export: true
+//! require_parental_control_to_move_transactions: true
+//! feature_flags: [chores, interest]
 //! data_format_version: "1.0"
 //! created_at: "2025-01-21T19:30:00Z"
 //! updated_at: "2025-01-21T19:35:00Z"
@@ -28,7 +71,7 @@
 //!
 //! - Single global configuration file
 //! - Active child directory tracking
-//! - Data format versioning for future migrations
+//! - Schema versioning; older files are upgraded at startup by the `migrations` module
 //! - Atomic file writes with temp files
 
 use anyhow::Result;
@@ -38,13 +81,82 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
+use crate::backend::domain::localization::Locale;
+use crate::backend::domain::models::currency::Currency;
+use crate::backend::domain::models::desktop_notifications::DesktopNotificationSettings;
+use crate::backend::domain::models::display_preferences::{ColorTheme, TextSize};
+use crate::backend::domain::models::feature_flag::FeatureFlag;
+use crate::backend::domain::models::fridge_view::FridgeViewSettings;
+use crate::backend::domain::models::parent_user::ParentUser;
+use crate::backend::domain::models::report_schedule::ReportSchedule;
+use crate::backend::domain::models::retention_policy::RetentionPolicy;
+use crate::backend::domain::models::update_settings::UpdateSettings;
+use std::collections::{BTreeMap, BTreeSet};
 use super::connection::CsvConnection;
+use super::migrations::GLOBAL_CONFIG_SCHEMA_VERSION;
 
 /// Global configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GlobalConfig {
+    /// Schema version of this file (0 for files written before versioning)
+    #[serde(default)]
+    pub schema_version: u32,
     /// Directory name of the currently active child (None if no active child)
     pub active_child_directory: Option<String>,
+    /// Locale for children without their own locale setting
+    #[serde(default)]
+    pub locale: Locale,
+    /// Text size for the whole app
+    #[serde(default)]
+    pub text_size: TextSize,
+    /// Whether the calendar shows a totals row under each week
+    #[serde(default)]
+    pub show_week_summaries: bool,
+    /// Whether the active goal's progress is shown above the calendar
+    #[serde(default)]
+    pub show_goal_strip: bool,
+    /// Light, dark or the operating system's colors
+    #[serde(default)]
+    pub theme: ColorTheme,
+    /// Whether celebrations skip their confetti and other movement
+    #[serde(default)]
+    pub reduce_motion: bool,
+    /// Whether the guided tour has been finished or skipped
+    #[serde(default)]
+    pub tutorial_completed: bool,
+    /// Currency the family view totals are shown in
+    #[serde(default)]
+    pub currency: Currency,
+    /// Value of one unit of each other currency in `currency`, entered by a parent
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub exchange_rates: BTreeMap<Currency, f64>,
+    /// Named parents who sign in with their own PIN
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parents: Vec<ParentUser>,
+    /// How long growing records are kept before the scheduled cleanup removes them
+    #[serde(default, skip_serializing_if = "RetentionPolicy::is_empty")]
+    pub retention: RetentionPolicy,
+    /// Folder monthly reports are written to, and the files written so far
+    #[serde(default, skip_serializing_if = "ReportSchedule::is_empty")]
+    pub report_schedule: ReportSchedule,
+    /// Which events pop up a desktop notification
+    #[serde(default, skip_serializing_if = "DesktopNotificationSettings::is_empty")]
+    pub desktop_notifications: DesktopNotificationSettings,
+    /// Release channel and the state of the daily update check
+    #[serde(default, skip_serializing_if = "UpdateSettings::is_empty")]
+    pub updates: UpdateSettings,
+    /// Where the fridge view page is written and whether it is kept up to date
+    #[serde(default, skip_serializing_if = "FridgeViewSettings::is_empty")]
+    pub fridge_view: FridgeViewSettings,
+    /// Whether exporting transactions needs the parental answer or a parent's PIN
+    #[serde(default)]
+    pub require_parental_control_for_export: bool,
+    /// Whether dragging a transaction to another day needs the parental answer or a parent's PIN
+    #[serde(default)]
+    pub require_parental_control_to_move_transactions: bool,
+    /// Experimental features turned on for this data directory
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub feature_flags: BTreeSet<FeatureFlag>,
     /// Data format version for future migrations
     pub data_format_version: String,
     /// When the global config was first created
@@ -57,7 +169,26 @@ impl Default for GlobalConfig {
     fn default() -> Self {
         let now = Utc::now().to_rfc3339();
         Self {
+            schema_version: GLOBAL_CONFIG_SCHEMA_VERSION,
             active_child_directory: None,
+            locale: Locale::default(),
+            text_size: TextSize::default(),
+            show_week_summaries: false,
+            show_goal_strip: false,
+            theme: ColorTheme::default(),
+            reduce_motion: false,
+            tutorial_completed: false,
+            currency: Currency::default(),
+            exchange_rates: BTreeMap::new(),
+            parents: Vec::new(),
+            retention: RetentionPolicy::default(),
+            report_schedule: ReportSchedule::default(),
+            desktop_notifications: DesktopNotificationSettings::default(),
+            updates: UpdateSettings::default(),
+            fridge_view: FridgeViewSettings::default(),
+            require_parental_control_for_export: false,
+            require_parental_control_to_move_transactions: false,
+            feature_flags: BTreeSet::new(),
             data_format_version: "1.0".to_string(),
             created_at: now.clone(),
             updated_at: now,
@@ -104,8 +235,11 @@ impl GlobalConfigRepository {
             debug!("Loaded global config from {:?}", config_path);
             Ok(config)
         } else {
-            // Create default config
+            // Create default config (in memory only when read-only)
             let config = GlobalConfig::default();
+            if self.connection.read_only_source().is_some() {
+                return Ok(config);
+            }
             self.save_global_config(&config)?;
             info!("Created default global config at {:?}", config_path);
             Ok(config)
@@ -114,6 +248,7 @@ impl GlobalConfigRepository {
     
     /// Save global config to file
     fn save_global_config(&self, config: &GlobalConfig) -> Result<()> {
+        self.connection.ensure_writable()?;
         let config_path = self.get_global_config_path();
         let base_dir = self.connection.base_directory();
         
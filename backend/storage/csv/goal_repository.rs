@@ -2,9 +2,11 @@ use crate::backend::domain::models::goal::{DomainGoal, DomainGoalState};
 use anyhow::Result;
 use log::warn;
 use serde::{Deserialize, Serialize};
-use std::fs::{self};
-use std::io::BufWriter;
 use super::connection::CsvConnection;
+use super::csv_schema;
+
+/// Schema version written at the top of `goals.csv`
+const SCHEMA_VERSION: u32 = 1;
 
 /// CSV record structure for goals
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -65,12 +67,13 @@ impl GoalRepository {
 
     fn read_goals(&self, child_id: &str) -> Result<Vec<DomainGoal>> {
         let file_path = self.connection.get_goals_file_path(child_id);
-        if !file_path.exists() {
-            return Ok(Vec::new());
-        }
+        let content = match self.connection.read_child_file(&file_path)? {
+            Some(content) => content,
+            None => return Ok(Vec::new()),
+        };
 
-        let file = fs::File::open(file_path)?;
-        let mut rdr = csv::Reader::from_reader(file);
+        // Fields are matched to columns by header name, and unknown columns are ignored
+        let mut rdr = csv_schema::reader(&content);
         let mut goals = Vec::new();
         for result in rdr.deserialize() {
             let record: GoalRecord = result?;
@@ -90,16 +93,17 @@ impl GoalRepository {
         
         // Ensure the parent directory exists
         if let Some(parent) = file_path.parent() {
+            self.connection.ensure_child_writable(parent)?;
             std::fs::create_dir_all(parent)?;
         }
         
-        let file = fs::File::create(file_path)?;
-        let mut wtr = csv::Writer::from_writer(BufWriter::new(file));
+        let mut wtr = csv::Writer::from_writer(csv_schema::schema_comment(SCHEMA_VERSION).into_bytes());
         for goal in goals {
             let record = GoalRecord::from(goal.clone());
             wtr.serialize(record)?;
         }
-        wtr.flush()?;
+        let content = wtr.into_inner()?;
+        self.connection.write_child_file(&file_path, &content)?;
         Ok(())
     }
 }
@@ -150,6 +154,7 @@ impl GoalRepository {
         let mut goals = self.read_goals(child_id)?;
         if let Some(goal) = goals.iter_mut().find(|g| g.state == DomainGoalState::Active) {
             goal.state = DomainGoalState::Cancelled;
+            goal.updated_at = chrono::Utc::now().to_rfc3339();
             let cancelled_goal = goal.clone();
             self.write_goals(child_id, &goals)?;
             Ok(Some(cancelled_goal))
@@ -163,6 +168,7 @@ impl GoalRepository {
         let mut goals = self.read_goals(child_id)?;
         if let Some(goal) = goals.iter_mut().find(|g| g.state == DomainGoalState::Active) {
             goal.state = DomainGoalState::Completed;
+            goal.updated_at = chrono::Utc::now().to_rfc3339();
             let completed_goal = goal.clone();
             self.write_goals(child_id, &goals)?;
             Ok(Some(completed_goal))
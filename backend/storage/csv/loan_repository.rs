@@ -0,0 +1,219 @@
+//! # CSV Loan Repository
+//!
+//! This module stores money fronted between a parent and a child in a YAML
+//! file in each child's directory: `{child_directory}/loans.yaml`.
+//!
+//! ## File Structure
+//!
+//! ```yaml
+//! loans:
+//! - id: loan::alice_1705314600000
+//!   direction: child_owes_parent
+//!   description: Video game
+//!   amount: 20.0
+//!   date: 2024-01-15T10:30:00-05:00
+//!   transaction_id: in-1705314600000-a1b2
+//!   settlements:
+//!   - transaction_id: ex-1705401000000-c3d4
+//!     date: 2024-01-16T10:30:00-05:00
+//!     amount: 5.0
+//! ```
+//!
+//! The child_id is implicit from the directory, so it is not written to disk.
+
+use anyhow::Result;
+use chrono::{DateTime, FixedOffset};
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::backend::domain::models::loan::{Loan as DomainLoan, LoanDirection, LoanSettlement};
+use super::connection::CsvConnection;
+
+/// YAML representation of a child's loans file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct YamlLoanFile {
+    #[serde(default)]
+    loans: Vec<YamlLoan>,
+}
+
+/// YAML representation of a single loan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct YamlLoan {
+    id: String,
+    direction: LoanDirection,
+    description: String,
+    amount: f64,
+    date: DateTime<FixedOffset>,
+    transaction_id: String,
+    #[serde(default)]
+    settlements: Vec<LoanSettlement>,
+}
+
+/// YAML-based loan repository using per-child files
+#[derive(Clone)]
+pub struct LoanRepository {
+    connection: CsvConnection,
+}
+
+impl LoanRepository {
+    /// Create a new loan repository
+    pub fn new(connection: CsvConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Get the loans file path for a child
+    fn get_loans_path(&self, child_id: &str) -> Result<PathBuf> {
+        let child_directory = self
+            .connection
+            .find_child_directory_by_id(child_id)?
+            .ok_or_else(|| anyhow::anyhow!("Child with ID '{}' not found", child_id))?;
+        Ok(self.connection.get_child_directory(&child_directory).join("loans.yaml"))
+    }
+
+    /// Read all loans for a child in file order
+    fn read_loans(&self, child_id: &str) -> Result<Vec<DomainLoan>> {
+        let yaml_path = self.get_loans_path(child_id)?;
+        let content = match self.connection.read_child_file(&yaml_path)? {
+            Some(content) => content,
+            None => return Ok(Vec::new()),
+        };
+
+        let file: YamlLoanFile = serde_yaml::from_str(&content)?;
+        Ok(file
+            .loans
+            .into_iter()
+            .map(|loan| DomainLoan {
+                id: loan.id,
+                child_id: child_id.to_string(),
+                direction: loan.direction,
+                description: loan.description,
+                amount: loan.amount,
+                date: loan.date,
+                transaction_id: loan.transaction_id,
+                settlements: loan.settlements,
+            })
+            .collect())
+    }
+
+    /// Write all loans for a child, replacing the file
+    fn write_loans(&self, child_id: &str, loans: &[DomainLoan]) -> Result<()> {
+        let yaml_path = self.get_loans_path(child_id)?;
+        if let Some(child_dir) = yaml_path.parent() {
+            self.connection.ensure_child_writable(child_dir)?;
+        }
+
+        let file = YamlLoanFile {
+            loans: loans
+                .iter()
+                .map(|loan| YamlLoan {
+                    id: loan.id.clone(),
+                    direction: loan.direction,
+                    description: loan.description.clone(),
+                    amount: loan.amount,
+                    date: loan.date,
+                    transaction_id: loan.transaction_id.clone(),
+                    settlements: loan.settlements.clone(),
+                })
+                .collect(),
+        };
+        let content = serde_yaml::to_string(&file)?;
+        self.connection.write_child_file(&yaml_path, content.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl crate::backend::storage::LoanStorage for LoanRepository {
+    fn store_loan(&self, loan: &DomainLoan) -> Result<()> {
+        let mut loans = self.read_loans(&loan.child_id)?;
+        if let Some(pos) = loans.iter().position(|l| l.id == loan.id) {
+            loans[pos] = loan.clone();
+        } else {
+            loans.push(loan.clone());
+        }
+        loans.sort_by_key(|l| l.date);
+        self.write_loans(&loan.child_id, &loans)?;
+
+        info!("🤝 Stored loan {} (${:.2} outstanding) for child '{}'", loan.id, loan.outstanding(), loan.child_id);
+        Ok(())
+    }
+
+    fn list_loans(&self, child_id: &str) -> Result<Vec<DomainLoan>> {
+        self.read_loans(child_id)
+    }
+
+    fn delete_loan_links_for_transactions(&self, child_id: &str, transaction_ids: &[String]) -> Result<u32> {
+        let mut loans = self.read_loans(child_id)?;
+        let mut removed = 0;
+
+        loans.retain(|loan| {
+            let keep = !transaction_ids.contains(&loan.transaction_id);
+            if !keep {
+                removed += 1;
+            }
+            keep
+        });
+        for loan in &mut loans {
+            let before = loan.settlements.len();
+            loan.settlements.retain(|s| !transaction_ids.contains(&s.transaction_id));
+            removed += (before - loan.settlements.len()) as u32;
+        }
+
+        if removed > 0 {
+            self.write_loans(child_id, &loans)?;
+            debug!("Removed {} loan links for child '{}'", removed, child_id);
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::csv::test_utils::TestHelper;
+    use crate::backend::storage::traits::LoanStorage;
+
+    fn loan(child_id: &str, transaction_id: &str, date: &str) -> DomainLoan {
+        DomainLoan {
+            id: format!("loan::{}", transaction_id),
+            child_id: child_id.to_string(),
+            direction: LoanDirection::ChildOwesParent,
+            description: "Game".to_string(),
+            amount: 20.0,
+            date: DateTime::parse_from_rfc3339(date).unwrap(),
+            transaction_id: transaction_id.to_string(),
+            settlements: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_store_list_and_unlink_loans() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let repo = LoanRepository::new(helper.env.connection.clone());
+
+        assert!(repo.list_loans(&child.id)?.is_empty());
+
+        let mut game = loan(&child.id, "in-2", "2024-02-01T12:00:00-05:00");
+        game.settlements.push(LoanSettlement {
+            transaction_id: "ex-3".to_string(),
+            date: DateTime::parse_from_rfc3339("2024-02-08T12:00:00-05:00").unwrap(),
+            amount: 5.0,
+        });
+        repo.store_loan(&game)?;
+        repo.store_loan(&loan(&child.id, "in-1", "2024-01-01T12:00:00-05:00"))?;
+
+        let loans = repo.list_loans(&child.id)?;
+        assert_eq!(loans.len(), 2);
+        assert_eq!(loans[0].transaction_id, "in-1");
+        assert_eq!(loans[1], game);
+
+        // Deleting a settlement's transaction reopens the loan; deleting the opening one removes it
+        let removed = repo.delete_loan_links_for_transactions(&child.id, &["ex-3".to_string(), "in-1".to_string()])?;
+        assert_eq!(removed, 2);
+        let loans = repo.list_loans(&child.id)?;
+        assert_eq!(loans.len(), 1);
+        assert_eq!(loans[0].outstanding(), 20.0);
+        Ok(())
+    }
+}
@@ -0,0 +1,419 @@
+//! # Config File Migrations
+//!
+//! Upgrades `global_config.yaml` and each child's `child.yaml` to the current
+//! schema at startup.
+//!
+//! Each file carries a `schema_version` (missing means version 0). Migrations
+//! are registered per file kind in order, and each one upgrades a file from
+//! `from_version` to `from_version + 1` by editing the raw YAML mapping, so
+//! old files never need to deserialize into today's structs.
+//!
+//! Before a file is changed, a backup copy (`child.yaml.v0.<timestamp>.bak`)
+//! is written next to it. The upgraded file is written atomically only after
+//! every step succeeded, so a failing migration leaves the original untouched.
+//! Files written by a newer version of the app are never modified.
+
+use anyhow::{anyhow, Context, Result};
+use chrono::Utc;
+use log::{error, info};
+use serde_yaml::{Mapping, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::connection::CsvConnection;
+use super::directory_lock::LockStatus;
+
+/// Current schema version of `global_config.yaml`
+pub const GLOBAL_CONFIG_SCHEMA_VERSION: u32 = 1;
+
+/// Current schema version of `child.yaml`
+pub const CHILD_SCHEMA_VERSION: u32 = 1;
+
+const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+/// Information available to a migration step
+pub struct MigrationContext {
+    /// Name of the child directory, for per-child files
+    pub directory_name: Option<String>,
+    /// RFC 3339 timestamp used to fill in missing dates
+    pub now: String,
+}
+
+/// A single upgrade step for one file kind
+pub struct Migration {
+    pub from_version: u32,
+    pub description: &'static str,
+    pub apply: fn(&mut Mapping, &MigrationContext) -> Result<()>,
+}
+
+/// A migration that was applied to a file
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedMigration {
+    pub file: PathBuf,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub description: String,
+}
+
+/// A file that could not be migrated and was left as it was
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationFailure {
+    pub file: PathBuf,
+    pub error: String,
+    pub backup_path: Option<PathBuf>,
+}
+
+/// Outcome of running all startup migrations
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MigrationReport {
+    pub applied: Vec<AppliedMigration>,
+    pub failed: Vec<MigrationFailure>,
+}
+
+/// Schema version found in one config file
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaVersionStatus {
+    pub file: PathBuf,
+    /// `None` if the file could not be read or has an invalid version
+    pub version: Option<u32>,
+    /// Version this build of the app writes
+    pub current_version: u32,
+}
+
+/// Registered migrations for `global_config.yaml`, in order
+pub fn global_config_migrations() -> &'static [Migration] {
+    &[Migration {
+        from_version: 0,
+        description: "Add schema_version and fill in missing global config fields",
+        apply: migrate_global_config_v0_to_v1,
+    }]
+}
+
+/// Registered migrations for `child.yaml`, in order
+pub fn child_migrations() -> &'static [Migration] {
+    &[Migration {
+        from_version: 0,
+        description: "Add schema_version, derive missing id from directory and drop legacy age field",
+        apply: migrate_child_v0_to_v1,
+    }]
+}
+
+/// Migrate the global config and every child's config to the current schema
+pub fn run_startup_migrations(connection: &CsvConnection) -> Result<MigrationReport> {
+    let base_dir = connection.base_directory();
+    let mut report = MigrationReport::default();
+    let now = Utc::now().to_rfc3339();
+
+    let global_config_path = base_dir.join("global_config.yaml");
+    if global_config_path.exists() {
+        let context = MigrationContext { directory_name: None, now: now.clone() };
+        migrate_into_report(&global_config_path, global_config_migrations(), GLOBAL_CONFIG_SCHEMA_VERSION, &context, &mut report);
+    }
+
+    for entry in fs::read_dir(&base_dir).with_context(|| format!("Failed to read data directory {}", base_dir.display()))? {
+        let entry = entry?;
+        if !entry.path().is_dir() {
+            continue;
+        }
+        let directory_name = entry.file_name().to_string_lossy().to_string();
+        let child_dir = connection.get_child_directory(&directory_name);
+        let child_yaml = child_dir.join("child.yaml");
+        if !child_yaml.exists() {
+            continue;
+        }
+        if let LockStatus::HeldByOther(holder) = connection.child_lock_status(&child_dir) {
+            info!("🔧 MIGRATION: Skipping {} while it is in use on '{}'", child_yaml.display(), holder.hostname);
+            continue;
+        }
+        let context = MigrationContext { directory_name: Some(directory_name), now: now.clone() };
+        migrate_into_report(&child_yaml, child_migrations(), CHILD_SCHEMA_VERSION, &context, &mut report);
+    }
+
+    if report.applied.is_empty() && report.failed.is_empty() {
+        info!("🔧 MIGRATION: All config files are up to date");
+    }
+    Ok(report)
+}
+
+fn migrate_into_report(
+    path: &Path,
+    migrations: &[Migration],
+    target_version: u32,
+    context: &MigrationContext,
+    report: &mut MigrationReport,
+) {
+    match migrate_file(path, migrations, target_version, context) {
+        Ok(applied) => report.applied.extend(applied),
+        Err(failure) => {
+            error!("🔧 MIGRATION: Failed to migrate {}: {}", path.display(), failure.error);
+            report.failed.push(failure);
+        }
+    }
+}
+
+/// Upgrade one file to `target_version`. On failure the file is left untouched
+/// and the failure records the backup path, if one was made.
+pub fn migrate_file(
+    path: &Path,
+    migrations: &[Migration],
+    target_version: u32,
+    context: &MigrationContext,
+) -> std::result::Result<Vec<AppliedMigration>, MigrationFailure> {
+    let mut backup_path = None;
+    apply_migrations(path, migrations, target_version, context, &mut backup_path).map_err(|e| MigrationFailure {
+        file: path.to_path_buf(),
+        error: format!("{:#}", e),
+        backup_path,
+    })
+}
+
+fn apply_migrations(
+    path: &Path,
+    migrations: &[Migration],
+    target_version: u32,
+    context: &MigrationContext,
+    backup_path: &mut Option<PathBuf>,
+) -> Result<Vec<AppliedMigration>> {
+    let mut mapping = read_mapping(path)?;
+
+    let current_version = schema_version(&mapping)?;
+    if current_version == target_version {
+        return Ok(Vec::new());
+    }
+    if current_version > target_version {
+        return Err(anyhow!(
+            "{} has schema version {} but this app only understands up to {}; it was probably written by a newer version",
+            path.display(),
+            current_version,
+            target_version
+        ));
+    }
+
+    let backup = backup_file(path, current_version)?;
+    info!("🔧 MIGRATION: Backed up {} to {}", path.display(), backup.display());
+    *backup_path = Some(backup);
+
+    let mut applied = Vec::new();
+    let mut version = current_version;
+    while version < target_version {
+        let migration = migrations
+            .iter()
+            .find(|m| m.from_version == version)
+            .ok_or_else(|| anyhow!("No migration registered from schema version {}", version))?;
+
+        (migration.apply)(&mut mapping, context)
+            .with_context(|| format!("Migration v{} -> v{} ({}) failed", version, version + 1, migration.description))?;
+        mapping.insert(Value::from(SCHEMA_VERSION_KEY), Value::from(version + 1));
+
+        info!("🔧 MIGRATION: {} v{} -> v{}: {}", path.display(), version, version + 1, migration.description);
+        applied.push(AppliedMigration {
+            file: path.to_path_buf(),
+            from_version: version,
+            to_version: version + 1,
+            description: migration.description.to_string(),
+        });
+        version += 1;
+    }
+
+    write_atomically(path, &mapping)?;
+    Ok(applied)
+}
+
+/// Read the schema version of the global config and every child's config
+///
+/// Nothing is changed; this reports what startup migrations would see.
+pub fn schema_versions(connection: &CsvConnection) -> Result<Vec<SchemaVersionStatus>> {
+    let base_dir = connection.base_directory();
+    let mut versions = Vec::new();
+    let status = |file: PathBuf, current_version: u32| SchemaVersionStatus {
+        version: read_mapping(&file).and_then(|mapping| schema_version(&mapping)).ok(),
+        file,
+        current_version,
+    };
+
+    let global_config_path = base_dir.join("global_config.yaml");
+    if global_config_path.exists() {
+        versions.push(status(global_config_path, GLOBAL_CONFIG_SCHEMA_VERSION));
+    }
+    for entry in fs::read_dir(&base_dir).with_context(|| format!("Failed to read data directory {}", base_dir.display()))? {
+        let entry = entry?;
+        if !entry.path().is_dir() {
+            continue;
+        }
+        let directory_name = entry.file_name().to_string_lossy().to_string();
+        let child_yaml = connection.get_child_directory(&directory_name).join("child.yaml");
+        if child_yaml.exists() {
+            versions.push(status(child_yaml, CHILD_SCHEMA_VERSION));
+        }
+    }
+    Ok(versions)
+}
+
+fn read_mapping(path: &Path) -> Result<Mapping> {
+    let content = fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    match serde_yaml::from_str::<Value>(&content) {
+        Ok(Value::Mapping(mapping)) => Ok(mapping),
+        Ok(Value::Null) => Ok(Mapping::new()),
+        Ok(_) => Err(anyhow!("{} is not a YAML mapping", path.display())),
+        Err(e) => Err(anyhow!("{} is not valid YAML: {}", path.display(), e)),
+    }
+}
+
+fn schema_version(mapping: &Mapping) -> Result<u32> {
+    match mapping.get(SCHEMA_VERSION_KEY) {
+        None | Some(Value::Null) => Ok(0),
+        Some(value) => value
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| anyhow!("Invalid schema_version: {:?}", value)),
+    }
+}
+
+fn backup_file(path: &Path, version: u32) -> Result<PathBuf> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow!("Invalid config path: {}", path.display()))?
+        .to_string_lossy();
+    let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
+    let backup_path = path.with_file_name(format!("{}.v{}.{}.bak", file_name, version, timestamp));
+    fs::copy(path, &backup_path).with_context(|| format!("Failed to back up {}", path.display()))?;
+    Ok(backup_path)
+}
+
+fn write_atomically(path: &Path, mapping: &Mapping) -> Result<()> {
+    let yaml_content = serde_yaml::to_string(mapping)?;
+    let temp_path = path.with_extension("tmp");
+    fs::write(&temp_path, yaml_content)?;
+    fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+/// Insert `value` under `key` unless the key already has a non-null value
+fn insert_if_missing(mapping: &mut Mapping, key: &str, value: Value) {
+    if matches!(mapping.get(key), None | Some(Value::Null)) {
+        mapping.insert(Value::from(key), value);
+    }
+}
+
+fn migrate_global_config_v0_to_v1(mapping: &mut Mapping, context: &MigrationContext) -> Result<()> {
+    // Older builds wrote only active_child_directory and data_format_version
+    if !mapping.contains_key("active_child_directory") {
+        mapping.insert(Value::from("active_child_directory"), Value::Null);
+    }
+    insert_if_missing(mapping, "data_format_version", Value::from("1.0"));
+    insert_if_missing(mapping, "created_at", Value::from(context.now.clone()));
+    insert_if_missing(mapping, "updated_at", Value::from(context.now.clone()));
+    Ok(())
+}
+
+fn migrate_child_v0_to_v1(mapping: &mut Mapping, context: &MigrationContext) -> Result<()> {
+    let directory_name = context
+        .directory_name
+        .as_deref()
+        .ok_or_else(|| anyhow!("Child migration requires the child directory name"))?;
+
+    if !matches!(mapping.get("name"), Some(Value::String(_))) {
+        return Err(anyhow!("child.yaml has no name"));
+    }
+    if !matches!(mapping.get("birthdate"), Some(Value::String(_))) {
+        return Err(anyhow!("child.yaml has no birthdate"));
+    }
+
+    // Legacy files predate IDs; the ID must match the containing directory
+    insert_if_missing(mapping, "id", Value::from(directory_name));
+
+    let created_at = mapping
+        .get("created_at")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| context.now.clone());
+    insert_if_missing(mapping, "created_at", Value::from(created_at.clone()));
+    insert_if_missing(mapping, "updated_at", Value::from(created_at));
+
+    // Age is derived from birthdate
+    mapping.remove("age");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn backups_in(dir: &Path) -> Vec<PathBuf> {
+        fs::read_dir(dir)
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .filter(|p| p.to_string_lossy().ends_with(".bak"))
+            .collect()
+    }
+
+    #[test]
+    fn test_startup_migrates_legacy_files_with_backups() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let connection = CsvConnection::new(temp_dir.path())?;
+        fs::write(
+            temp_dir.path().join("global_config.yaml"),
+            "active_child_directory: alice\ndata_format_version: '1.0'\n",
+        )?;
+        let child_dir = temp_dir.path().join("alice");
+        fs::create_dir_all(&child_dir)?;
+        fs::write(child_dir.join("child.yaml"), "name: Alice\nage: 10\nbirthdate: '2014-01-01'\n")?;
+
+        let report = run_startup_migrations(&connection)?;
+        assert!(report.failed.is_empty());
+        assert_eq!(report.applied.len(), 2);
+
+        let child: Mapping = serde_yaml::from_str(&fs::read_to_string(child_dir.join("child.yaml"))?)?;
+        assert_eq!(child.get("id").and_then(|v| v.as_str()), Some("alice"));
+        assert_eq!(child.get(SCHEMA_VERSION_KEY).and_then(|v| v.as_u64()), Some(1));
+        assert!(child.get("age").is_none());
+        assert_eq!(backups_in(&child_dir).len(), 1);
+
+        // The upgraded global config now loads as a full GlobalConfig
+        let config: super::super::GlobalConfig =
+            serde_yaml::from_str(&fs::read_to_string(temp_dir.path().join("global_config.yaml"))?)?;
+        assert_eq!(config.schema_version, GLOBAL_CONFIG_SCHEMA_VERSION);
+        assert_eq!(config.active_child_directory.as_deref(), Some("alice"));
+
+        // Running again is a no-op
+        let report = run_startup_migrations(&connection)?;
+        assert!(report.applied.is_empty());
+        assert_eq!(backups_in(&child_dir).len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_failed_migration_leaves_file_untouched_with_backup() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let connection = CsvConnection::new(temp_dir.path())?;
+        let child_dir = temp_dir.path().join("broken");
+        fs::create_dir_all(&child_dir)?;
+        let original = "name: Broken\n";
+        fs::write(child_dir.join("child.yaml"), original)?;
+
+        let report = run_startup_migrations(&connection)?;
+        assert_eq!(report.failed.len(), 1);
+        assert!(report.failed[0].error.contains("birthdate"));
+        assert_eq!(fs::read_to_string(child_dir.join("child.yaml"))?, original);
+
+        let backup = report.failed[0].backup_path.clone().unwrap();
+        assert_eq!(fs::read_to_string(backup)?, original);
+        Ok(())
+    }
+
+    #[test]
+    fn test_newer_schema_is_not_modified() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join("child.yaml");
+        let original = "schema_version: 99\nid: x\n";
+        fs::write(&path, original)?;
+        let context = MigrationContext { directory_name: Some("x".to_string()), now: Utc::now().to_rfc3339() };
+
+        let failure = migrate_file(&path, child_migrations(), CHILD_SCHEMA_VERSION, &context).unwrap_err();
+        assert!(failure.error.contains("newer version"));
+        assert!(failure.backup_path.is_none());
+        assert_eq!(fs::read_to_string(&path)?, original);
+        Ok(())
+    }
+}
@@ -15,26 +15,73 @@
 //!
 //! CSV files have the following structure:
 //! ```csv
-//! id,child_id,date,description,amount,balance
-//! tx_1234567890,child_abc,2024-01-15T10:30:00Z,"Allowance",10.00,10.00
-//! tx_1234567891,child_abc,2024-01-16T15:45:00Z,"Spent on toy",-5.00,5.00
+//! # schema_version: 2
+//! id,child_id,date,description,amount,balance,actor,category,icon,type
+//! tx_1234567890,child_abc,2024-01-15T10:30:00Z,"Allowance",10.00,10.00,,,,allowance
+//! tx_1234567891,child_abc,2024-01-16T15:45:00Z,"Spent on toy",-5.00,5.00,kid,Toys,,expense
 //! ```
+//!
+//! `actor` is `kid`, `parent` or `parent:<name>`, and empty for automatic
+//! transactions. Files from before it existed have only six columns.
+//! `type` is the transaction's kind, e.g. `bonus` or `penalty`; files from
+//! before it existed read as plain income or expense by the amount's sign.
+//! Columns are read by header name (see `csv_schema`), so older files and
+//! files with extra columns from other tools still load.
 
 pub mod connection;
+pub mod csv_schema;
+pub mod directory_lock;
+pub mod read_only;
+pub mod portable;
+pub mod data_location;
+pub mod encryption;
+pub mod migrations;
 pub mod transaction_repository;
 pub mod child_repository;
 pub mod allowance_repository;
 pub mod parental_control_repository;
 pub mod global_config_repository;
 pub mod goal_repository;
+pub mod child_settings_repository;
+pub mod audit_log_repository;
+pub mod savings_allocation_repository;
+pub mod loan_repository;
+pub mod allowance_advance_repository;
+pub mod planned_purchase_repository;
+pub mod quick_pick_repository;
+pub mod transaction_template_repository;
+pub mod budget_target_repository;
+pub mod reversal_repository;
+pub mod snapshot_repository;
+pub mod celebration_repository;
+pub mod period_lock_repository;
+pub mod write_coordinator;
 
 #[cfg(test)]
 pub mod test_utils;
+#[cfg(test)]
+mod round_trip_tests;
 
 pub use connection::CsvConnection;
-pub use transaction_repository::TransactionRepository;
+pub use directory_lock::{DirectoryLockError, LockHolder, LockStatus};
+pub use encryption::EncryptionError;
+pub use read_only::{ReadOnlyError, ReadOnlySource};
+pub use transaction_repository::{transactions_csv, TransactionRepository};
 pub use child_repository::ChildRepository;
 pub use allowance_repository::AllowanceRepository;
 pub use parental_control_repository::ParentalControlRepository;
 pub use global_config_repository::{GlobalConfigRepository, GlobalConfig, GlobalConfigStorage};
-pub use goal_repository::GoalRepository;
\ No newline at end of file
+pub use goal_repository::GoalRepository;
+pub use child_settings_repository::ChildSettingsRepository;
+pub use audit_log_repository::AuditLogRepository;
+pub use savings_allocation_repository::SavingsAllocationRepository;
+pub use loan_repository::LoanRepository;
+pub use allowance_advance_repository::AllowanceAdvanceRepository;
+pub use planned_purchase_repository::PlannedPurchaseRepository;
+pub use quick_pick_repository::QuickPickRepository;
+pub use transaction_template_repository::TransactionTemplateRepository;
+pub use budget_target_repository::BudgetTargetRepository;
+pub use reversal_repository::ReversalRepository;
+pub use snapshot_repository::{SnapshotRepository, SNAPSHOT_FILE};
+pub use celebration_repository::CelebrationRepository;
+pub use period_lock_repository::PeriodLockRepository;
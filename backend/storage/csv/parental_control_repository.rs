@@ -20,11 +20,15 @@
 //!
 //! CSV files have the following structure:
 //! ```csv
+//! # schema_version: 1
 //! id,attempted_value,timestamp,success
 //! 1,"wrong_answer","2024-01-15T10:30:00Z",false
 //! 2,"correct_answer","2024-01-15T10:31:00Z",true
 //! ```
 //!
+//! Columns are found by header name (see `csv_schema`). A file in an older
+//! layout is rewritten in the current one the next time an attempt is added.
+//!
 //! ## Features
 //!
 //! - Per-child CSV files for parental control attempts
@@ -35,17 +39,23 @@
 use anyhow::Result;
 
 
-use csv::{Reader, Writer};
+use csv::Writer;
 use log::{info, debug};
 use serde::{Deserialize, Serialize};
-use std::fs::{File, OpenOptions};
-use std::io::{BufReader, BufWriter};
 use std::path::PathBuf;
 
 use crate::backend::domain::models::parental_control_attempt::ParentalControlAttempt as DomainParentalControlAttempt;
 use super::connection::CsvConnection;
+use super::csv_schema::{self, ColumnIndex};
+use super::encryption::EncryptionError;
 use crate::backend::storage::GitManager;
 
+/// Schema version written at the top of `parental_control_attempts.csv`
+const SCHEMA_VERSION: u32 = 1;
+
+/// Columns of `parental_control_attempts.csv`, in the order they are written
+const COLUMNS: &[&str] = &["id", "attempted_value", "timestamp", "success"];
+
 /// CSV record structure for parental control attempts
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct ParentalControlAttemptRecord {
@@ -140,22 +150,20 @@ impl ParentalControlRepository {
     fn get_next_id(&self, child_directory: &str) -> Result<i64> {
         let csv_path = self.get_parental_control_file_path(child_directory);
         
-        if !csv_path.exists() {
-            return Ok(1); // First ID
-        }
+        let content = match self.connection.read_child_file(&csv_path)? {
+            Some(content) => content,
+            None => return Ok(1), // First ID
+        };
         
-        let file = File::open(&csv_path)?;
-        let reader = BufReader::new(file);
-        let mut csv_reader = Reader::from_reader(reader);
+        let mut csv_reader = csv_schema::reader(&content);
+        let columns = ColumnIndex::new(csv_reader.headers()?, COLUMNS);
         
         let mut max_id = 0i64;
         for result in csv_reader.records() {
             let record = result?;
-            if record.len() >= 1 {
-                if let Ok(id) = record[0].parse::<i64>() {
-                    if id > max_id {
-                        max_id = id;
-                    }
+            if let Some(Ok(id)) = columns.get(&record, "id").map(str::parse::<i64>) {
+                if id > max_id {
+                    max_id = id;
                 }
             }
         }
@@ -167,6 +175,7 @@ impl ParentalControlRepository {
     #[allow(dead_code)]
     fn append_parental_control_attempt(&self, child_directory: &str, record: &ParentalControlAttemptRecord) -> Result<()> {
         let child_dir = self.connection.get_child_directory(child_directory);
+        self.connection.ensure_child_writable(&child_dir)?;
         
         // Ensure the child directory exists
         if !child_dir.exists() {
@@ -175,31 +184,23 @@ impl ParentalControlRepository {
         }
         
         let csv_path = self.get_parental_control_file_path(child_directory);
-        let file_exists = csv_path.exists();
-        
-        // Open file in append mode
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&csv_path)?;
-        
-        let writer = BufWriter::new(file);
-        let mut csv_writer = Writer::from_writer(writer);
-        
-        // Write header if file is new
-        if !file_exists {
-            csv_writer.write_record(&["id", "attempted_value", "timestamp", "success"])?;
+        let attempt = DomainParentalControlAttempt::from(record.clone());
+        match self.connection.read_child_file(&csv_path)? {
+            Some(content) if csv_schema::is_current(&content, SCHEMA_VERSION, COLUMNS)? => {
+                let mut csv_writer = Writer::from_writer(Vec::new());
+                write_attempt(&mut csv_writer, &attempt)?;
+                let content = csv_writer.into_inner()?;
+                self.connection.append_child_file(&csv_path, &content)?;
+            }
+            Some(content) => {
+                // An older layout: bring the whole file up to date with the new attempt
+                let mut attempts = parse_attempts(&content)?;
+                attempts.push(attempt);
+                self.connection.write_child_file(&csv_path, &attempts_csv(&attempts)?)?;
+            }
+            None => self.connection.write_child_file(&csv_path, &attempts_csv(&[attempt])?)?,
         }
         
-        // Write the record - manually write to handle quoting properly
-        csv_writer.write_record(&[
-            &record.id.to_string(),
-            &record.attempted_value,
-            &record.timestamp,
-            &record.success.to_string(),
-        ])?;
-        csv_writer.flush()?;
-        
         debug!("Appended parental control attempt to {:?}: ID {}", csv_path, record.id);
         
         // Git integration: commit the parental_control_attempts.csv change
@@ -220,28 +221,15 @@ impl ParentalControlRepository {
     fn load_parental_control_attempts_from_directory(&self, child_directory: &str, limit: Option<u32>) -> Result<Vec<DomainParentalControlAttempt>> {
         let csv_path = self.get_parental_control_file_path(child_directory);
         
-        if !csv_path.exists() {
-            debug!("No parental control attempts file found in directory '{}'", child_directory);
-            return Ok(Vec::new());
-        }
-        
-        let file = File::open(&csv_path)?;
-        let reader = BufReader::new(file);
-        let mut csv_reader = Reader::from_reader(reader);
-        
-        let mut attempts = Vec::new();
-        for result in csv_reader.records() {
-            let record = result?;
-            if record.len() >= 4 {
-                let attempt = DomainParentalControlAttempt {
-                    id: record[0].parse::<i64>()?,
-                    attempted_value: record[1].to_string(),
-                    timestamp: record[2].to_string(),
-                    success: record[3].parse::<bool>()?,
-                };
-                attempts.push(attempt);
+        let content = match self.connection.read_child_file(&csv_path)? {
+            Some(content) => content,
+            None => {
+                debug!("No parental control attempts file found in directory '{}'", child_directory);
+                return Ok(Vec::new());
             }
-        }
+        };
+        
+        let mut attempts = parse_attempts(&content)?;
         
         // Sort by ID descending (most recent first, assuming IDs are incremental)
         attempts.sort_by(|a: &DomainParentalControlAttempt, b: &DomainParentalControlAttempt| b.id.cmp(&a.id));
@@ -256,6 +244,74 @@ impl ParentalControlRepository {
     }
 }
 
+impl ParentalControlRepository {
+    /// Replace the attempts file for a child, or the family-wide file for `"global"`
+    ///
+    /// Only the retention cleanup rewrites attempts; recording one appends.
+    pub fn replace_parental_control_attempts(&self, child_id: &str, attempts: &[DomainParentalControlAttempt]) -> Result<()> {
+        let child_directory = if child_id == "global" {
+            "global".to_string()
+        } else {
+            self.connection
+                .find_child_directory_by_id(child_id)?
+                .ok_or_else(|| anyhow::anyhow!("Child not found: {}", child_id))?
+        };
+        let csv_path = self.get_parental_control_file_path(&child_directory);
+        if child_directory == "global" {
+            self.connection.ensure_writable()?;
+        } else {
+            self.connection.ensure_child_writable(&self.connection.get_child_directory(&child_directory))?;
+        }
+
+        let mut sorted = attempts.to_vec();
+        sorted.sort_by_key(|attempt| attempt.id);
+        self.connection.write_child_file(&csv_path, &attempts_csv(&sorted)?)?;
+
+        info!("Rewrote parental control attempts for '{}' with {} attempts", child_id, attempts.len());
+        Ok(())
+    }
+}
+
+/// Write one attempt as a row in `COLUMNS` order
+fn write_attempt(csv_writer: &mut Writer<Vec<u8>>, attempt: &DomainParentalControlAttempt) -> Result<()> {
+    csv_writer.write_record([
+        attempt.id.to_string(),
+        attempt.attempted_value.clone(),
+        attempt.timestamp.clone(),
+        attempt.success.to_string(),
+    ])?;
+    Ok(())
+}
+
+/// A whole attempts file in the current layout, in the given order
+fn attempts_csv(attempts: &[DomainParentalControlAttempt]) -> Result<Vec<u8>> {
+    let mut csv_writer = csv_schema::writer(SCHEMA_VERSION, COLUMNS)?;
+    for attempt in attempts {
+        write_attempt(&mut csv_writer, attempt)?;
+    }
+    Ok(csv_writer.into_inner()?)
+}
+
+/// Parse a whole attempts file in file order, skipping rows without an id or result
+fn parse_attempts(content: &str) -> Result<Vec<DomainParentalControlAttempt>> {
+    let mut csv_reader = csv_schema::reader(content);
+    let columns = ColumnIndex::new(csv_reader.headers()?, COLUMNS);
+    let mut attempts = Vec::new();
+    for result in csv_reader.records() {
+        let record = result?;
+        let (Some(id), Some(success)) = (columns.get(&record, "id"), columns.get(&record, "success")) else {
+            continue;
+        };
+        attempts.push(DomainParentalControlAttempt {
+            id: id.parse::<i64>()?,
+            attempted_value: columns.value(&record, "attempted_value").to_string(),
+            timestamp: columns.value(&record, "timestamp").to_string(),
+            success: success.parse::<bool>()?,
+        });
+    }
+    Ok(attempts)
+}
+
 impl crate::backend::storage::ParentalControlStorage for ParentalControlRepository {
     fn record_parental_control_attempt(&self, child_id: &str, attempted_value: &str, success: bool) -> Result<i64> {
         // Handle global parental control attempts specially
@@ -313,7 +369,15 @@ impl crate::backend::storage::ParentalControlStorage for ParentalControlReposito
         
         // Load attempts from each child directory
         for child_directory in child_directories {
-            let attempts = self.load_parental_control_attempts_from_directory(&child_directory, None)?;
+            let attempts = match self.load_parental_control_attempts_from_directory(&child_directory, None) {
+                Ok(attempts) => attempts,
+                Err(e) if e.downcast_ref::<EncryptionError>().is_some() => {
+                    // Encrypted children that aren't unlocked yet are left out
+                    debug!("Skipping parental control attempts in '{}': {}", child_directory, e);
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
             all_attempts.extend(attempts);
         }
         
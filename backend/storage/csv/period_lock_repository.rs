@@ -0,0 +1,135 @@
+//! # CSV Period Lock Repository
+//!
+//! This module stores how far a child's transactions are closed, in a YAML
+//! file in the child's directory: `{child_directory}/period_lock.yaml`.
+//!
+//! ## File Structure
+//!
+//! ```yaml
+//! locked_through: 2024-01-31
+//! closed_at: 2024-02-02T19:15:00Z
+//! closed_by: parent:Mom
+//! ```
+//!
+//! There is no file while nothing is closed; reopening removes it.
+//! The child_id is implicit from the directory, so it is not written to disk.
+
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::backend::domain::models::actor::Actor;
+use crate::backend::domain::models::period_lock::PeriodLock as DomainPeriodLock;
+use super::connection::CsvConnection;
+
+/// YAML representation of a child's period lock file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct YamlPeriodLock {
+    locked_through: NaiveDate,
+    closed_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    closed_by: Option<String>,
+}
+
+/// YAML-based period lock repository using per-child files
+#[derive(Clone)]
+pub struct PeriodLockRepository {
+    connection: CsvConnection,
+}
+
+impl PeriodLockRepository {
+    /// Create a new period lock repository
+    pub fn new(connection: CsvConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Get the period lock file path for a child
+    fn get_period_lock_path(&self, child_id: &str) -> Result<PathBuf> {
+        let child_directory = self
+            .connection
+            .find_child_directory_by_id(child_id)?
+            .ok_or_else(|| anyhow::anyhow!("Child with ID '{}' not found", child_id))?;
+        Ok(self.connection.get_child_directory(&child_directory).join("period_lock.yaml"))
+    }
+}
+
+impl crate::backend::storage::PeriodLockStorage for PeriodLockRepository {
+    fn get_period_lock(&self, child_id: &str) -> Result<Option<DomainPeriodLock>> {
+        let yaml_path = self.get_period_lock_path(child_id)?;
+        let content = match self.connection.read_child_file(&yaml_path)? {
+            Some(content) => content,
+            None => return Ok(None),
+        };
+
+        let lock: YamlPeriodLock = serde_yaml::from_str(&content)?;
+        Ok(Some(DomainPeriodLock {
+            child_id: child_id.to_string(),
+            locked_through: lock.locked_through,
+            closed_at: lock.closed_at,
+            closed_by: lock.closed_by.as_deref().and_then(Actor::parse),
+        }))
+    }
+
+    fn store_period_lock(&self, lock: &DomainPeriodLock) -> Result<()> {
+        let yaml_path = self.get_period_lock_path(&lock.child_id)?;
+        if let Some(child_dir) = yaml_path.parent() {
+            self.connection.ensure_child_writable(child_dir)?;
+        }
+
+        let content = serde_yaml::to_string(&YamlPeriodLock {
+            locked_through: lock.locked_through,
+            closed_at: lock.closed_at,
+            closed_by: lock.closed_by.as_ref().map(Actor::to_storage),
+        })?;
+        self.connection.write_child_file(&yaml_path, content.as_bytes())?;
+
+        info!("🔒 Closed transactions through {} for child '{}'", lock.locked_through, lock.child_id);
+        Ok(())
+    }
+
+    fn clear_period_lock(&self, child_id: &str) -> Result<bool> {
+        let yaml_path = self.get_period_lock_path(child_id)?;
+        if !yaml_path.exists() {
+            return Ok(false);
+        }
+        if let Some(child_dir) = yaml_path.parent() {
+            self.connection.ensure_child_writable(child_dir)?;
+        }
+        std::fs::remove_file(&yaml_path)?;
+
+        info!("🔓 Reopened closed transactions for child '{}'", child_id);
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::csv::test_utils::TestHelper;
+    use crate::backend::storage::traits::PeriodLockStorage;
+
+    #[test]
+    fn test_store_read_and_clear_period_lock() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let repo = PeriodLockRepository::new(helper.env.connection.clone());
+
+        assert_eq!(repo.get_period_lock(&child.id)?, None);
+        assert!(!repo.clear_period_lock(&child.id)?);
+
+        let lock = DomainPeriodLock {
+            child_id: child.id.clone(),
+            locked_through: NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+            closed_at: DateTime::parse_from_rfc3339("2024-02-02T19:15:00Z")?.with_timezone(&Utc),
+            closed_by: Some(Actor::Parent(Some("Mom".to_string()))),
+        };
+        repo.store_period_lock(&lock)?;
+        assert_eq!(repo.get_period_lock(&child.id)?, Some(lock));
+
+        assert!(repo.clear_period_lock(&child.id)?);
+        assert_eq!(repo.get_period_lock(&child.id)?, None);
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+//! # CSV Planned Purchase Repository
+//!
+//! This module stores a child's planned purchases in a YAML file in their
+//! directory: `{child_directory}/planned_purchases.yaml`.
+//!
+//! ## File Structure
+//!
+//! ```yaml
+//! planned_purchases:
+//! - id: planned::alice_1705314600000
+//!   description: Lego set
+//!   amount: 24.99
+//!   planned_date: 2024-02-10
+//!   state: planned
+//!   created_at: 2024-01-15T10:30:00+00:00
+//!   updated_at: 2024-01-15T10:30:00+00:00
+//! ```
+//!
+//! The child_id is implicit from the directory, so it is not written to disk.
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::backend::domain::models::planned_purchase::{PlannedPurchase as DomainPlannedPurchase, PlannedPurchaseState};
+use super::connection::CsvConnection;
+
+/// YAML representation of a child's planned purchases file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct YamlPlannedPurchaseFile {
+    #[serde(default)]
+    planned_purchases: Vec<YamlPlannedPurchase>,
+}
+
+/// YAML representation of a single planned purchase
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct YamlPlannedPurchase {
+    id: String,
+    description: String,
+    amount: f64,
+    planned_date: NaiveDate,
+    state: PlannedPurchaseState,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    transaction_id: Option<String>,
+    #[serde(default)]
+    created_at: String,
+    #[serde(default)]
+    updated_at: String,
+}
+
+/// YAML-based planned purchase repository using per-child files
+#[derive(Clone)]
+pub struct PlannedPurchaseRepository {
+    connection: CsvConnection,
+}
+
+impl PlannedPurchaseRepository {
+    /// Create a new planned purchase repository
+    pub fn new(connection: CsvConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Get the planned purchases file path for a child
+    fn get_planned_purchases_path(&self, child_id: &str) -> Result<PathBuf> {
+        let child_directory = self
+            .connection
+            .find_child_directory_by_id(child_id)?
+            .ok_or_else(|| anyhow::anyhow!("Child with ID '{}' not found", child_id))?;
+        Ok(self.connection.get_child_directory(&child_directory).join("planned_purchases.yaml"))
+    }
+
+    /// Read all planned purchases for a child in file order
+    fn read_planned_purchases(&self, child_id: &str) -> Result<Vec<DomainPlannedPurchase>> {
+        let yaml_path = self.get_planned_purchases_path(child_id)?;
+        let content = match self.connection.read_child_file(&yaml_path)? {
+            Some(content) => content,
+            None => return Ok(Vec::new()),
+        };
+
+        let file: YamlPlannedPurchaseFile = serde_yaml::from_str(&content)?;
+        Ok(file
+            .planned_purchases
+            .into_iter()
+            .map(|purchase| DomainPlannedPurchase {
+                id: purchase.id,
+                child_id: child_id.to_string(),
+                description: purchase.description,
+                amount: purchase.amount,
+                planned_date: purchase.planned_date,
+                state: purchase.state,
+                transaction_id: purchase.transaction_id,
+                created_at: purchase.created_at,
+                updated_at: purchase.updated_at,
+            })
+            .collect())
+    }
+
+    /// Write all planned purchases for a child, replacing the file
+    fn write_planned_purchases(&self, child_id: &str, purchases: &[DomainPlannedPurchase]) -> Result<()> {
+        let yaml_path = self.get_planned_purchases_path(child_id)?;
+        if let Some(child_dir) = yaml_path.parent() {
+            self.connection.ensure_child_writable(child_dir)?;
+        }
+
+        let file = YamlPlannedPurchaseFile {
+            planned_purchases: purchases
+                .iter()
+                .map(|purchase| YamlPlannedPurchase {
+                    id: purchase.id.clone(),
+                    description: purchase.description.clone(),
+                    amount: purchase.amount,
+                    planned_date: purchase.planned_date,
+                    state: purchase.state,
+                    transaction_id: purchase.transaction_id.clone(),
+                    created_at: purchase.created_at.clone(),
+                    updated_at: purchase.updated_at.clone(),
+                })
+                .collect(),
+        };
+        let content = serde_yaml::to_string(&file)?;
+        self.connection.write_child_file(&yaml_path, content.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl crate::backend::storage::PlannedPurchaseStorage for PlannedPurchaseRepository {
+    fn store_planned_purchase(&self, purchase: &DomainPlannedPurchase) -> Result<()> {
+        let mut purchases = self.read_planned_purchases(&purchase.child_id)?;
+        if let Some(pos) = purchases.iter().position(|p| p.id == purchase.id) {
+            purchases[pos] = purchase.clone();
+        } else {
+            purchases.push(purchase.clone());
+        }
+        purchases.sort_by_key(|p| p.planned_date);
+        self.write_planned_purchases(&purchase.child_id, &purchases)?;
+
+        info!("🛍️ Stored planned purchase {} ({:?}) for child '{}'", purchase.id, purchase.state, purchase.child_id);
+        Ok(())
+    }
+
+    fn list_planned_purchases(&self, child_id: &str) -> Result<Vec<DomainPlannedPurchase>> {
+        self.read_planned_purchases(child_id)
+    }
+}
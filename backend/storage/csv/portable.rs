@@ -0,0 +1,72 @@
+//! # Portable Mode
+//!
+//! Normally the data lives in `~/Documents/Allowance Tracker`. In portable
+//! mode it lives in an `Allowance Tracker Data` folder next to the app
+//! instead, so the app and the family's data can travel together on a USB
+//! stick and be opened on any computer.
+//!
+//! Portable mode is on whenever that folder exists. Starting the app once
+//! with the `--portable` flag creates it. On macOS the folder sits next to
+//! the `.app` bundle rather than inside it.
+//!
+//! Passwords for email and other secrets still go in the computer's keychain,
+//! so they have to be entered again on each computer.
+
+use std::path::{Path, PathBuf};
+
+/// Command line flag that creates the portable data folder and uses it
+pub const PORTABLE_FLAG: &str = "--portable";
+
+/// Name of the data folder next to the app in portable mode
+pub const PORTABLE_DATA_DIR: &str = "Allowance Tracker Data";
+
+/// The portable folder next to the app, when there is one or `--portable` was given
+pub fn find_portable_data_directory() -> Option<PathBuf> {
+    let requested = std::env::args().any(|arg| arg == PORTABLE_FLAG);
+    match app_directory() {
+        Some(app_dir) => portable_data_directory(&app_dir, requested),
+        None => {
+            if requested {
+                log::warn!("🧳 {} was given but the app's folder couldn't be found; using the usual data folder", PORTABLE_FLAG);
+            }
+            None
+        }
+    }
+}
+
+/// The portable data folder in `app_dir`, if it exists or was asked for
+pub fn portable_data_directory(app_dir: &Path, requested: bool) -> Option<PathBuf> {
+    let portable = app_dir.join(PORTABLE_DATA_DIR);
+    (requested || portable.is_dir()).then_some(portable)
+}
+
+/// The folder the app was started from, outside any macOS `.app` bundle
+fn app_directory() -> Option<PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    let exe_dir = exe.parent()?;
+    let bundle = exe_dir
+        .ancestors()
+        .find(|dir| dir.extension().is_some_and(|extension| extension == "app"));
+    match bundle {
+        Some(bundle) => bundle.parent().map(Path::to_path_buf),
+        None => Some(exe_dir.to_path_buf()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_portable_folder_is_used_once_it_exists() {
+        let app_dir = TempDir::new().unwrap();
+        assert_eq!(portable_data_directory(app_dir.path(), false), None);
+
+        let expected = app_dir.path().join(PORTABLE_DATA_DIR);
+        assert_eq!(portable_data_directory(app_dir.path(), true), Some(expected.clone()));
+
+        std::fs::create_dir(&expected).unwrap();
+        assert_eq!(portable_data_directory(app_dir.path(), false), Some(expected));
+    }
+}
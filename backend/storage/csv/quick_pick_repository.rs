@@ -0,0 +1,180 @@
+//! # CSV Quick Pick Repository
+//!
+//! This module stores a child's quick-pick descriptions in a YAML file in
+//! their directory: `{child_directory}/quick_picks.yaml`.
+//!
+//! ## File Structure
+//!
+//! ```yaml
+//! quick_picks:
+//! - id: quickpick::alice_1705314600000
+//!   description: Lunch money
+//!   amount: 5.0
+//!   kind: income
+//! - id: quickpick::alice_1705314700000
+//!   description: Book fair
+//!   kind: expense
+//! ```
+//!
+//! The child_id is implicit from the directory, so it is not written to disk.
+
+use anyhow::Result;
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::backend::domain::models::quick_pick::{QuickPick as DomainQuickPick, QuickPickKind};
+use super::connection::CsvConnection;
+
+/// YAML representation of a child's quick picks file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct YamlQuickPickFile {
+    #[serde(default)]
+    quick_picks: Vec<YamlQuickPick>,
+}
+
+/// YAML representation of a single quick pick
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct YamlQuickPick {
+    id: String,
+    description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    amount: Option<f64>,
+    kind: QuickPickKind,
+}
+
+/// YAML-based quick pick repository using per-child files
+#[derive(Clone)]
+pub struct QuickPickRepository {
+    connection: CsvConnection,
+}
+
+impl QuickPickRepository {
+    /// Create a new quick pick repository
+    pub fn new(connection: CsvConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Get the quick picks file path for a child
+    fn get_quick_picks_path(&self, child_id: &str) -> Result<PathBuf> {
+        let child_directory = self
+            .connection
+            .find_child_directory_by_id(child_id)?
+            .ok_or_else(|| anyhow::anyhow!("Child with ID '{}' not found", child_id))?;
+        Ok(self.connection.get_child_directory(&child_directory).join("quick_picks.yaml"))
+    }
+
+    /// Read all quick picks for a child in file order
+    fn read_quick_picks(&self, child_id: &str) -> Result<Vec<DomainQuickPick>> {
+        let yaml_path = self.get_quick_picks_path(child_id)?;
+        let content = match self.connection.read_child_file(&yaml_path)? {
+            Some(content) => content,
+            None => return Ok(Vec::new()),
+        };
+
+        let file: YamlQuickPickFile = serde_yaml::from_str(&content)?;
+        Ok(file
+            .quick_picks
+            .into_iter()
+            .map(|quick_pick| DomainQuickPick {
+                id: quick_pick.id,
+                child_id: child_id.to_string(),
+                description: quick_pick.description,
+                amount: quick_pick.amount,
+                kind: quick_pick.kind,
+            })
+            .collect())
+    }
+
+    /// Write all quick picks for a child, replacing the file
+    fn write_quick_picks(&self, child_id: &str, quick_picks: &[DomainQuickPick]) -> Result<()> {
+        let yaml_path = self.get_quick_picks_path(child_id)?;
+        if let Some(child_dir) = yaml_path.parent() {
+            self.connection.ensure_child_writable(child_dir)?;
+        }
+
+        let file = YamlQuickPickFile {
+            quick_picks: quick_picks
+                .iter()
+                .map(|quick_pick| YamlQuickPick {
+                    id: quick_pick.id.clone(),
+                    description: quick_pick.description.clone(),
+                    amount: quick_pick.amount,
+                    kind: quick_pick.kind,
+                })
+                .collect(),
+        };
+        let content = serde_yaml::to_string(&file)?;
+        self.connection.write_child_file(&yaml_path, content.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl crate::backend::storage::QuickPickStorage for QuickPickRepository {
+    fn store_quick_pick(&self, quick_pick: &DomainQuickPick) -> Result<()> {
+        let mut quick_picks = self.read_quick_picks(&quick_pick.child_id)?;
+        if let Some(pos) = quick_picks.iter().position(|q| q.id == quick_pick.id) {
+            quick_picks[pos] = quick_pick.clone();
+        } else {
+            quick_picks.push(quick_pick.clone());
+        }
+        self.write_quick_picks(&quick_pick.child_id, &quick_picks)?;
+
+        info!("⚡ Stored quick pick \"{}\" for child '{}'", quick_pick.description, quick_pick.child_id);
+        Ok(())
+    }
+
+    fn list_quick_picks(&self, child_id: &str) -> Result<Vec<DomainQuickPick>> {
+        self.read_quick_picks(child_id)
+    }
+
+    fn delete_quick_pick(&self, child_id: &str, quick_pick_id: &str) -> Result<bool> {
+        let mut quick_picks = self.read_quick_picks(child_id)?;
+        let before = quick_picks.len();
+        quick_picks.retain(|q| q.id != quick_pick_id);
+        if quick_picks.len() == before {
+            return Ok(false);
+        }
+        self.write_quick_picks(child_id, &quick_picks)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::csv::test_utils::TestHelper;
+    use crate::backend::storage::traits::QuickPickStorage;
+
+    #[test]
+    fn test_store_list_and_delete_quick_picks() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let repo = QuickPickRepository::new(helper.env.connection.clone());
+
+        assert!(repo.list_quick_picks(&child.id)?.is_empty());
+
+        let lunch = DomainQuickPick {
+            id: "quickpick::1".to_string(),
+            child_id: child.id.clone(),
+            description: "Lunch money".to_string(),
+            amount: Some(5.0),
+            kind: QuickPickKind::Income,
+        };
+        let book_fair = DomainQuickPick {
+            id: "quickpick::2".to_string(),
+            description: "Book fair".to_string(),
+            amount: None,
+            kind: QuickPickKind::Expense,
+            ..lunch.clone()
+        };
+        repo.store_quick_pick(&lunch)?;
+        repo.store_quick_pick(&book_fair)?;
+        assert_eq!(repo.list_quick_picks(&child.id)?, vec![lunch.clone(), book_fair]);
+
+        assert!(repo.delete_quick_pick(&child.id, "quickpick::2")?);
+        assert!(!repo.delete_quick_pick(&child.id, "quickpick::2")?);
+        assert_eq!(repo.list_quick_picks(&child.id)?, vec![lunch]);
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+//! # Read-only Mode
+//!
+//! A global switch that blocks every write to the data directory, for demoing
+//! the app or browsing a backup copy without risking changes.
+//!
+//! Read-only mode can be turned on with the `--read-only` command line flag,
+//! the `ALLOWANCE_TRACKER_READ_ONLY` environment variable, or at runtime from
+//! settings. It is also turned on when a config file can't be migrated at
+//! startup, or when a relocated child's folder is missing. While it is on, every write fails with [`ReadOnlyError`].
+//! Switching the active child still works, but the choice only lives in memory.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// Command line flag that starts the app in read-only mode
+pub const READ_ONLY_FLAG: &str = "--read-only";
+
+/// Environment variable that starts the app in read-only mode when set to a truthy value
+pub const READ_ONLY_ENV_VAR: &str = "ALLOWANCE_TRACKER_READ_ONLY";
+
+/// What turned read-only mode on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadOnlySource {
+    CommandLineFlag,
+    EnvironmentVariable,
+    Settings,
+    /// A config file could not be upgraded to the current schema
+    MigrationFailed,
+    /// A relocated child's data folder wasn't found, e.g. an unplugged drive
+    DataDirectoryMissing,
+}
+
+impl fmt::Display for ReadOnlySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadOnlySource::CommandLineFlag => write!(f, "started with {}", READ_ONLY_FLAG),
+            ReadOnlySource::EnvironmentVariable => write!(f, "{} is set", READ_ONLY_ENV_VAR),
+            ReadOnlySource::Settings => write!(f, "enabled in settings"),
+            ReadOnlySource::MigrationFailed => write!(f, "a config file could not be upgraded"),
+            ReadOnlySource::DataDirectoryMissing => write!(f, "a data folder is missing"),
+        }
+    }
+}
+
+/// Error returned by any write attempted while read-only mode is on
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("The app is in read-only mode ({enabled_by}); changes are disabled")]
+pub struct ReadOnlyError {
+    pub enabled_by: ReadOnlySource,
+}
+
+/// Shared read-only flag, cloned into every connection
+#[derive(Debug, Clone, Default)]
+pub struct ReadOnlyMode {
+    enabled_by: Arc<Mutex<Option<ReadOnlySource>>>,
+    /// Active child chosen while read-only, kept in memory instead of global config
+    active_child_directory: Arc<Mutex<Option<String>>>,
+}
+
+impl ReadOnlyMode {
+    /// Turn read-only mode on or off
+    pub fn set(&self, enabled_by: Option<ReadOnlySource>) {
+        *self.enabled_by.lock().unwrap() = enabled_by;
+        if enabled_by.is_none() {
+            self.active_child_directory.lock().unwrap().take();
+        }
+    }
+
+    /// What turned read-only mode on, if it is on
+    pub fn enabled_by(&self) -> Option<ReadOnlySource> {
+        *self.enabled_by.lock().unwrap()
+    }
+
+    /// Fail with [`ReadOnlyError`] if read-only mode is on
+    pub fn check(&self) -> Result<(), ReadOnlyError> {
+        match self.enabled_by() {
+            Some(enabled_by) => Err(ReadOnlyError { enabled_by }),
+            None => Ok(()),
+        }
+    }
+
+    /// In-memory active child directory override
+    pub fn active_child_directory(&self) -> Option<String> {
+        self.active_child_directory.lock().unwrap().clone()
+    }
+
+    /// Remember the active child directory in memory
+    pub fn set_active_child_directory(&self, directory_name: &str) {
+        *self.active_child_directory.lock().unwrap() = Some(directory_name.to_string());
+    }
+}
+
+/// Work out whether read-only mode was requested at startup
+pub fn read_only_source_from_environment() -> Option<ReadOnlySource> {
+    if std::env::args().any(|arg| arg == READ_ONLY_FLAG) {
+        return Some(ReadOnlySource::CommandLineFlag);
+    }
+    match std::env::var(READ_ONLY_ENV_VAR) {
+        Ok(value) if is_truthy(&value) => Some(ReadOnlySource::EnvironmentVariable),
+        _ => None,
+    }
+}
+
+fn is_truthy(value: &str) -> bool {
+    matches!(value.trim().to_lowercase().as_str(), "1" | "true" | "yes" | "on")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_reports_source() {
+        let mode = ReadOnlyMode::default();
+        assert!(mode.check().is_ok());
+
+        mode.set(Some(ReadOnlySource::Settings));
+        assert_eq!(mode.check(), Err(ReadOnlyError { enabled_by: ReadOnlySource::Settings }));
+
+        // Clones share the flag
+        let clone = mode.clone();
+        clone.set(None);
+        assert!(mode.check().is_ok());
+    }
+
+    #[test]
+    fn test_active_child_override_cleared_when_disabled() {
+        let mode = ReadOnlyMode::default();
+        mode.set(Some(ReadOnlySource::CommandLineFlag));
+        mode.set_active_child_directory("alice");
+        assert_eq!(mode.active_child_directory().as_deref(), Some("alice"));
+
+        mode.set(None);
+        assert_eq!(mode.active_child_directory(), None);
+    }
+
+    #[test]
+    fn test_truthy_values() {
+        assert!(is_truthy("1"));
+        assert!(is_truthy(" TRUE "));
+        assert!(is_truthy("yes"));
+        assert!(!is_truthy("0"));
+        assert!(!is_truthy(""));
+    }
+}
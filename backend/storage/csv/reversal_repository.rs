@@ -0,0 +1,181 @@
+//! # CSV Reversal Repository
+//!
+//! This module stores the links between reversed transactions and the entries
+//! that offset them in a YAML file in each child's directory:
+//! `{child_directory}/reversals.yaml`.
+//!
+//! ## File Structure
+//!
+//! ```yaml
+//! reversals:
+//! - original_id: ex-1705314600000-a1b2
+//!   reversal_id: in-1705401000000-c3d4
+//!   date: 2024-01-16T10:30:00-05:00
+//!   reason: Returned to the store
+//! ```
+//!
+//! The child_id is implicit from the directory, so it is not written to disk.
+
+use anyhow::Result;
+use chrono::{DateTime, FixedOffset};
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::backend::domain::models::transaction_reversal::TransactionReversal as DomainReversal;
+use super::connection::CsvConnection;
+
+/// YAML representation of a child's reversals file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct YamlReversalFile {
+    #[serde(default)]
+    reversals: Vec<YamlReversal>,
+}
+
+/// YAML representation of a single reversal link
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct YamlReversal {
+    original_id: String,
+    reversal_id: String,
+    date: DateTime<FixedOffset>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+}
+
+/// YAML-based reversal repository using per-child files
+#[derive(Clone)]
+pub struct ReversalRepository {
+    connection: CsvConnection,
+}
+
+impl ReversalRepository {
+    /// Create a new reversal repository
+    pub fn new(connection: CsvConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Get the reversals file path for a child
+    fn get_reversals_path(&self, child_id: &str) -> Result<PathBuf> {
+        let child_directory = self
+            .connection
+            .find_child_directory_by_id(child_id)?
+            .ok_or_else(|| anyhow::anyhow!("Child with ID '{}' not found", child_id))?;
+        Ok(self.connection.get_child_directory(&child_directory).join("reversals.yaml"))
+    }
+
+    /// Read all reversals for a child in file order
+    fn read_reversals(&self, child_id: &str) -> Result<Vec<DomainReversal>> {
+        let yaml_path = self.get_reversals_path(child_id)?;
+        let content = match self.connection.read_child_file(&yaml_path)? {
+            Some(content) => content,
+            None => return Ok(Vec::new()),
+        };
+
+        let file: YamlReversalFile = serde_yaml::from_str(&content)?;
+        Ok(file
+            .reversals
+            .into_iter()
+            .map(|reversal| DomainReversal {
+                child_id: child_id.to_string(),
+                original_id: reversal.original_id,
+                reversal_id: reversal.reversal_id,
+                date: reversal.date,
+                reason: reversal.reason,
+            })
+            .collect())
+    }
+
+    /// Write all reversals for a child, replacing the file
+    fn write_reversals(&self, child_id: &str, reversals: &[DomainReversal]) -> Result<()> {
+        let yaml_path = self.get_reversals_path(child_id)?;
+        if let Some(child_dir) = yaml_path.parent() {
+            self.connection.ensure_child_writable(child_dir)?;
+        }
+
+        let file = YamlReversalFile {
+            reversals: reversals
+                .iter()
+                .map(|reversal| YamlReversal {
+                    original_id: reversal.original_id.clone(),
+                    reversal_id: reversal.reversal_id.clone(),
+                    date: reversal.date,
+                    reason: reversal.reason.clone(),
+                })
+                .collect(),
+        };
+        let content = serde_yaml::to_string(&file)?;
+        self.connection.write_child_file(&yaml_path, content.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl crate::backend::storage::TransactionReversalStorage for ReversalRepository {
+    fn store_reversal(&self, reversal: &DomainReversal) -> Result<()> {
+        let mut reversals = self.read_reversals(&reversal.child_id)?;
+        reversals.push(reversal.clone());
+        reversals.sort_by_key(|r| r.date);
+        self.write_reversals(&reversal.child_id, &reversals)?;
+
+        info!("↩️ Stored reversal of {} by {} for child '{}'", reversal.original_id, reversal.reversal_id, reversal.child_id);
+        Ok(())
+    }
+
+    fn list_reversals(&self, child_id: &str) -> Result<Vec<DomainReversal>> {
+        self.read_reversals(child_id)
+    }
+
+    fn delete_reversal_links_for_transactions(&self, child_id: &str, transaction_ids: &[String]) -> Result<u32> {
+        let mut reversals = self.read_reversals(child_id)?;
+        let before = reversals.len();
+        reversals.retain(|reversal| !transaction_ids.iter().any(|id| reversal.involves(id)));
+        let removed = (before - reversals.len()) as u32;
+
+        if removed > 0 {
+            self.write_reversals(child_id, &reversals)?;
+            debug!("Removed {} reversal links for child '{}'", removed, child_id);
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::csv::test_utils::TestHelper;
+    use crate::backend::storage::traits::TransactionReversalStorage;
+
+    fn reversal(child_id: &str, original_id: &str, reversal_id: &str, date: &str) -> DomainReversal {
+        DomainReversal {
+            child_id: child_id.to_string(),
+            original_id: original_id.to_string(),
+            reversal_id: reversal_id.to_string(),
+            date: DateTime::parse_from_rfc3339(date).unwrap(),
+            reason: None,
+        }
+    }
+
+    #[test]
+    fn test_store_list_and_unlink_reversals() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let repo = ReversalRepository::new(helper.env.connection.clone());
+
+        assert!(repo.list_reversals(&child.id)?.is_empty());
+
+        let mut toy = reversal(&child.id, "ex-2", "in-3", "2024-02-08T12:00:00-05:00");
+        toy.reason = Some("Returned to the store".to_string());
+        repo.store_reversal(&toy)?;
+        repo.store_reversal(&reversal(&child.id, "in-1", "ex-4", "2024-01-01T12:00:00-05:00"))?;
+
+        let reversals = repo.list_reversals(&child.id)?;
+        assert_eq!(reversals.len(), 2);
+        assert_eq!(reversals[0].original_id, "in-1");
+        assert_eq!(reversals[1], toy);
+
+        // Deleting either side of a pair removes its link
+        let removed = repo.delete_reversal_links_for_transactions(&child.id, &["in-3".to_string()])?;
+        assert_eq!(removed, 1);
+        assert_eq!(repo.list_reversals(&child.id)?.len(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,476 @@
+//! Round-trip tests for the on-disk formats
+//!
+//! Two kinds of checks guard against silent schema drift:
+//!
+//! - **Property tests** write randomly generated transactions, goals and
+//!   allowance configs through the repositories and read them back, asserting
+//!   nothing is lost and that writing the same data again gives the same bytes.
+//! - **Golden files** in `testdata/` pin the exact bytes written for a fixed
+//!   set of records, and check that those files still parse. A new field or a
+//!   changed column order fails here first; update the golden file only when
+//!   the format change is intended and old files still load.
+
+use anyhow::Result;
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
+use proptest::prelude::*;
+use std::fs;
+
+use super::connection::CsvConnection;
+use super::csv_schema;
+use super::test_utils::TestHelper;
+use crate::backend::domain::models::actor::Actor;
+use crate::backend::domain::models::allowance::{
+    AllowanceConfig, AllowanceDayChange, AllowanceFrequency, AllowanceScheduleChange, DayChangePolicy,
+};
+use crate::backend::domain::models::child::Child as DomainChild;
+use crate::backend::domain::models::goal::{DomainGoal, DomainGoalState};
+use crate::backend::domain::models::transaction::{Transaction, TransactionType};
+use crate::backend::storage::traits::{AllowanceStorage, TransactionStorage};
+
+/// Every case creates a fresh data directory, so keep the case count modest
+const CASES: u32 = 48;
+
+/// 2020-01-01 to 2030-01-01, in milliseconds
+const TIMESTAMP_RANGE_MS: std::ops::Range<i64> = 1_577_836_800_000..1_893_456_000_000;
+
+fn date_at(timestamp_ms: i64, offset_hours: i32) -> DateTime<FixedOffset> {
+    FixedOffset::east_opt(offset_hours * 3600)
+        .unwrap()
+        .timestamp_millis_opt(timestamp_ms)
+        .unwrap()
+}
+
+fn cents() -> impl Strategy<Value = f64> {
+    (-100_000i64..100_000).prop_map(|cents| cents as f64 / 100.0)
+}
+
+/// Descriptions with the characters most likely to upset a CSV writer
+fn description() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[a-zA-Z0-9 ,.'\"!?-]{0,30}",
+        "[a-z ,\"\n]{0,20}",
+        "\\PC{0,20}",
+    ]
+}
+
+/// No actor, the kid, or a parent with or without an awkward name
+fn actor() -> impl Strategy<Value = Option<Actor>> {
+    prop_oneof![
+        Just(None),
+        Just(Some(Actor::Kid)),
+        proptest::option::of("[a-zA-Z :,\"]{0,12}").prop_map(|name| Some(Actor::Parent(name))),
+    ]
+}
+
+/// No category, or one with characters a CSV writer has to quote
+fn category() -> impl Strategy<Value = Option<String>> {
+    proptest::option::of("[a-zA-Z ,\"]{1,12}")
+}
+
+/// No icon, a preset key, or an emoji sequence several chars long
+fn icon() -> impl Strategy<Value = Option<String>> {
+    proptest::option::of(prop::sample::select(vec!["toy", "🍦", "👍🏽", "👨‍👩‍👧"]).prop_map(str::to_string))
+}
+
+/// Any kind a stored transaction can have
+fn transaction_type() -> impl Strategy<Value = TransactionType> {
+    prop::sample::select(TransactionType::RECORDED.to_vec())
+}
+
+fn transaction(child_id: String, timestamp_ms: i64) -> impl Strategy<Value = Transaction> {
+    (cents(), cents(), description(), -11i32..=14, actor(), category(), icon(), transaction_type()).prop_map(move |(amount, balance, description, offset_hours, actor, category, icon, transaction_type)| {
+        Transaction {
+            id: Transaction::generate_id(amount, timestamp_ms as u64),
+            child_id: child_id.clone(),
+            date: date_at(timestamp_ms, offset_hours),
+            description,
+            amount,
+            balance,
+            transaction_type,
+            actor,
+            category,
+            icon,
+        }
+    })
+}
+
+/// Transactions with distinct timestamps, in a random order
+fn transactions(child_id: &str) -> impl Strategy<Value = Vec<Transaction>> {
+    let child_id = child_id.to_string();
+    prop::collection::btree_set(TIMESTAMP_RANGE_MS, 0..15)
+        .prop_flat_map(move |timestamps| {
+            timestamps
+                .into_iter()
+                .map(|timestamp_ms| transaction(child_id.clone(), timestamp_ms))
+                .collect::<Vec<_>>()
+        })
+        .prop_shuffle()
+}
+
+fn goal_state() -> impl Strategy<Value = DomainGoalState> {
+    prop_oneof![
+        Just(DomainGoalState::Active),
+        Just(DomainGoalState::Cancelled),
+        Just(DomainGoalState::Completed),
+    ]
+}
+
+/// Goals with distinct creation times, oldest first, as the service appends them
+fn goals(child_id: &str) -> impl Strategy<Value = Vec<DomainGoal>> {
+    let child_id = child_id.to_string();
+    prop::collection::btree_set(TIMESTAMP_RANGE_MS, 0..8).prop_flat_map(move |timestamps| {
+        timestamps
+            .into_iter()
+            .map(|timestamp_ms| {
+                let child_id = child_id.clone();
+                (description(), (1i64..1_000_000).prop_map(|cents| cents as f64 / 100.0), goal_state()).prop_map(
+                    move |(description, target_amount, state)| {
+                        let created_at = date_at(timestamp_ms, -5).to_rfc3339();
+                        DomainGoal {
+                            id: DomainGoal::generate_id(&child_id, timestamp_ms as u64),
+                            child_id: child_id.clone(),
+                            description,
+                            target_amount,
+                            state,
+                            updated_at: created_at.clone(),
+                            created_at,
+                        }
+                    },
+                )
+            })
+            .collect::<Vec<_>>()
+    })
+}
+
+fn allowance_config(child_id: &str) -> impl Strategy<Value = AllowanceConfig> {
+    let child_id = child_id.to_string();
+    let day_change = prop::option::of((0u8..7, 0u32..3650).prop_map(|(previous_day_of_week, days)| {
+        AllowanceDayChange {
+            previous_day_of_week,
+            effective_date: NaiveDate::from_ymd_opt(2020, 1, 1).unwrap() + chrono::Duration::days(days as i64),
+        }
+    }));
+    let policy = prop::sample::select(DayChangePolicy::ALL.to_vec());
+    let frequency = prop::sample::select(AllowanceFrequency::ALL.to_vec());
+    let some_date = |days: u32| NaiveDate::from_ymd_opt(2020, 1, 1).unwrap() + chrono::Duration::days(days as i64);
+    let schedule_change = prop::option::of(
+        (
+            prop::sample::select(AllowanceFrequency::ALL.to_vec()),
+            0u8..7,
+            1u8..32,
+            2u8..13,
+            prop::option::of(0u32..3650),
+            0u32..3650,
+        )
+            .prop_map(
+                move |(previous_frequency, previous_day_of_week, previous_day_of_month, previous_interval_weeks, anchor_days, days)| {
+                    AllowanceScheduleChange {
+                        previous_frequency,
+                        previous_day_of_week,
+                        previous_day_of_month,
+                        previous_interval_weeks,
+                        previous_anchor_date: anchor_days.map(some_date),
+                        effective_date: some_date(days),
+                    }
+                },
+            ),
+    );
+    (
+        (0i64..100_000),
+        0u8..7,
+        any::<bool>(),
+        TIMESTAMP_RANGE_MS,
+        policy,
+        day_change,
+        frequency,
+        1u8..32,
+        2u8..13,
+        prop::option::of(0u32..3650),
+        schedule_change,
+    )
+        .prop_map(
+            move |(
+                cents,
+                day_of_week,
+                is_active,
+                timestamp_ms,
+                day_change_policy,
+                day_change,
+                frequency,
+                day_of_month,
+                interval_weeks,
+                anchor_days,
+                schedule_change,
+            )| {
+                AllowanceConfig {
+                    child_id: child_id.clone(),
+                    amount: cents as f64 / 100.0,
+                    day_of_week,
+                    is_active,
+                    created_at: date_at(timestamp_ms, 0).to_rfc3339(),
+                    updated_at: date_at(timestamp_ms, -5).to_rfc3339(),
+                    day_change_policy,
+                    day_change,
+                    frequency,
+                    day_of_month,
+                    interval_weeks,
+                    anchor_date: anchor_days.map(some_date),
+                    schedule_change,
+                }
+            },
+        )
+}
+
+fn transactions_file(helper: &TestHelper, child: &DomainChild) -> std::path::PathBuf {
+    helper
+        .env
+        .connection
+        .get_transactions_file_path(&CsvConnection::generate_safe_directory_name(&child.name))
+}
+
+fn allowance_file(helper: &TestHelper, child: &DomainChild) -> Result<std::path::PathBuf> {
+    let directory = helper.env.connection.get_child_directory_by_id(&child.id)?.expect("test child exists");
+    Ok(directory.join("allowance_config.yaml"))
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(CASES))]
+
+    #[test]
+    fn prop_transactions_round_trip(transactions in transactions("test_child")) {
+        let helper = TestHelper::new().unwrap();
+        let child = helper.create_test_child().unwrap();
+
+        helper.transaction_repo.write_transactions_by_id(&child.id, &transactions).unwrap();
+        let read_back = helper.transaction_repo.read_transactions_by_id(&child.id).unwrap();
+        prop_assert_eq!(&read_back, &transactions);
+
+        // Writing what was read gives the same file
+        let written = fs::read(transactions_file(&helper, &child)).unwrap();
+        helper.transaction_repo.write_transactions_by_id(&child.id, &read_back).unwrap();
+        prop_assert_eq!(fs::read(transactions_file(&helper, &child)).unwrap(), written);
+    }
+
+    #[test]
+    fn prop_stored_transactions_are_ordered_by_date(transactions in transactions("test_child")) {
+        let store_all = |order: &[Transaction]| -> Vec<u8> {
+            let helper = TestHelper::new().unwrap();
+            let child = helper.create_test_child().unwrap();
+            let directory = CsvConnection::generate_safe_directory_name(&child.name);
+            for transaction in order {
+                helper.transaction_repo.store_transaction_with_child_name(transaction, &directory).unwrap();
+            }
+            let stored = helper.transaction_repo.list_transactions_chronological(&child.id, None, None).unwrap();
+            assert!(stored.windows(2).all(|pair| pair[0].date <= pair[1].date));
+            fs::read(transactions_file(&helper, &child)).unwrap()
+        };
+
+        // The file doesn't depend on the order transactions were added in
+        let reversed: Vec<Transaction> = transactions.iter().rev().cloned().collect();
+        prop_assert_eq!(store_all(&transactions), store_all(&reversed));
+    }
+
+    #[test]
+    fn prop_goals_round_trip(goals in goals("test_child")) {
+        let helper = TestHelper::new().unwrap();
+        let child = helper.create_test_child().unwrap();
+
+        for goal in &goals {
+            helper.goal_repo.store_goal(goal).unwrap();
+        }
+        let mut expected = goals.clone();
+        expected.reverse();
+        prop_assert_eq!(helper.goal_repo.list_goals(&child.id, None).unwrap(), expected);
+    }
+
+    #[test]
+    fn prop_allowance_config_round_trips(config in allowance_config("test_child")) {
+        let helper = TestHelper::new().unwrap();
+        let child = helper.create_test_child().unwrap();
+
+        helper.allowance_repo.store_allowance_config(&config).unwrap();
+        let read_back = helper.allowance_repo.get_allowance_config(&child.id).unwrap();
+        prop_assert_eq!(read_back.as_ref(), Some(&config));
+
+        let path = allowance_file(&helper, &child).unwrap();
+        let written = fs::read(&path).unwrap();
+        helper.allowance_repo.store_allowance_config(&read_back.unwrap()).unwrap();
+        prop_assert_eq!(fs::read(&path).unwrap(), written);
+    }
+}
+
+fn golden_transactions(child_id: &str) -> Vec<Transaction> {
+    let entry = |id: &str, timestamp_ms: i64, offset_hours: i32, description: &str, amount: f64, balance: f64, actor: Option<Actor>, category: Option<&str>, icon: Option<&str>, transaction_type: TransactionType| Transaction {
+        id: id.to_string(),
+        child_id: child_id.to_string(),
+        date: date_at(timestamp_ms, offset_hours),
+        description: description.to_string(),
+        amount,
+        balance,
+        transaction_type,
+        actor,
+        category: category.map(str::to_string),
+        icon: icon.map(str::to_string),
+    };
+    vec![
+        entry("in-1735747200000-8a3d", 1_735_747_200_000, -5, "Weekly allowance", 5.0, 5.0, None, None, None, TransactionType::AllowancePayment),
+        entry("ex-1735920000123-bee9", 1_735_920_000_123, -5, "Stickers, \"holographic\"", -1.25, 3.75, Some(Actor::Kid), Some("Toys"), Some("🍬"), TransactionType::Expense),
+        entry("in-1736370000000-c473", 1_736_370_000_000, 1, "Birthday 🎂 from Oma", 20.0, 23.75, Some(Actor::Parent(Some("Oma".to_string()))), Some("Gifts"), Some("gift"), TransactionType::Gift),
+    ]
+}
+
+fn golden_goals(child_id: &str) -> Vec<DomainGoal> {
+    vec![
+        DomainGoal {
+            id: DomainGoal::generate_id(child_id, 1_735_747_200_000),
+            child_id: child_id.to_string(),
+            description: "Lego set".to_string(),
+            target_amount: 49.99,
+            state: DomainGoalState::Cancelled,
+            created_at: "2025-01-01T11:00:00-05:00".to_string(),
+            updated_at: "2025-01-03T09:30:00-05:00".to_string(),
+        },
+        DomainGoal {
+            id: DomainGoal::generate_id(child_id, 1_735_920_000_000),
+            child_id: child_id.to_string(),
+            description: "Bike, with bell".to_string(),
+            target_amount: 120.0,
+            state: DomainGoalState::Active,
+            created_at: "2025-01-03T11:00:00-05:00".to_string(),
+            updated_at: "2025-01-03T11:00:00-05:00".to_string(),
+        },
+    ]
+}
+
+fn golden_allowance_config(child_id: &str) -> AllowanceConfig {
+    AllowanceConfig {
+        child_id: child_id.to_string(),
+        amount: 7.5,
+        day_of_week: 5,
+        is_active: true,
+        created_at: "2025-01-01T16:00:00+00:00".to_string(),
+        updated_at: "2025-03-01T16:00:00+00:00".to_string(),
+        day_change_policy: DayChangePolicy::Prorate,
+        day_change: Some(AllowanceDayChange {
+            previous_day_of_week: 1,
+            effective_date: NaiveDate::from_ymd_opt(2025, 3, 7).unwrap(),
+        }),
+        frequency: AllowanceFrequency::EveryNWeeks,
+        day_of_month: 31,
+        interval_weeks: 2,
+        anchor_date: Some(NaiveDate::from_ymd_opt(2025, 3, 14).unwrap()),
+        schedule_change: Some(AllowanceScheduleChange {
+            previous_frequency: AllowanceFrequency::Monthly,
+            previous_day_of_week: 5,
+            previous_day_of_month: 1,
+            previous_interval_weeks: 2,
+            previous_anchor_date: None,
+            effective_date: NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(),
+        }),
+    }
+}
+
+#[test]
+fn test_golden_transactions_csv() -> Result<()> {
+    let helper = TestHelper::new()?;
+    let child = helper.create_test_child()?;
+    let path = transactions_file(&helper, &child);
+    let golden = include_str!("testdata/transactions.csv");
+
+    helper.transaction_repo.write_transactions_by_id(&child.id, &golden_transactions(&child.id))?;
+    assert_eq!(fs::read_to_string(&path)?, golden, "transactions.csv format changed");
+
+    fs::write(&path, golden)?;
+    assert_eq!(helper.transaction_repo.read_transactions_by_id(&child.id)?, golden_transactions(&child.id));
+    Ok(())
+}
+
+#[test]
+fn test_transactions_csv_without_actor_column_still_loads() -> Result<()> {
+    let helper = TestHelper::new()?;
+    let child = helper.create_test_child()?;
+    let path = transactions_file(&helper, &child);
+    // No version comment, and none of the actor, category, icon and type
+    // columns; no golden value in them holds a comma
+    let legacy: String = include_str!("testdata/transactions.csv")
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| {
+            let mut line = line;
+            for _ in 0..4 {
+                line = &line[..line.rfind(',').unwrap()];
+            }
+            format!("{}\n", line)
+        })
+        .collect();
+
+    fs::write(&path, legacy)?;
+    let expected: Vec<Transaction> = golden_transactions(&child.id)
+        .into_iter()
+        .map(|transaction| Transaction {
+            transaction_type: TransactionType::from_amount(transaction.amount),
+            actor: None,
+            category: None,
+            icon: None,
+            ..transaction
+        })
+        .collect();
+    assert_eq!(helper.transaction_repo.read_transactions_by_id(&child.id)?, expected);
+    Ok(())
+}
+
+#[test]
+fn test_transactions_csv_with_reordered_and_unknown_columns_loads() -> Result<()> {
+    let helper = TestHelper::new()?;
+    let child = helper.create_test_child()?;
+    let path = transactions_file(&helper, &child);
+    // Read by header name: columns reversed, plus one another tool added
+    let mut golden = csv_schema::reader(include_str!("testdata/transactions.csv"));
+    let mut reordered = csv::Writer::from_writer(Vec::new());
+    let mut header: Vec<String> = golden.headers()?.iter().rev().map(str::to_string).collect();
+    header.insert(3, "receipt_photo".to_string());
+    reordered.write_record(&header)?;
+    for record in golden.records() {
+        let mut fields: Vec<String> = record?.iter().rev().map(str::to_string).collect();
+        fields.insert(3, "IMG_0001.jpg".to_string());
+        reordered.write_record(&fields)?;
+    }
+
+    fs::write(&path, reordered.into_inner()?)?;
+    assert_eq!(helper.transaction_repo.read_transactions_by_id(&child.id)?, golden_transactions(&child.id));
+    Ok(())
+}
+
+#[test]
+fn test_golden_goals_csv() -> Result<()> {
+    let helper = TestHelper::new()?;
+    let child = helper.create_test_child()?;
+    let path = helper.env.connection.get_goals_file_path(&child.id);
+    let golden = include_str!("testdata/goals.csv");
+
+    for goal in golden_goals(&child.id) {
+        helper.goal_repo.store_goal(&goal)?;
+    }
+    assert_eq!(fs::read_to_string(&path)?, golden, "goals.csv format changed");
+
+    fs::write(&path, golden)?;
+    let mut expected = golden_goals(&child.id);
+    expected.reverse();
+    assert_eq!(helper.goal_repo.list_goals(&child.id, None)?, expected);
+    Ok(())
+}
+
+#[test]
+fn test_golden_allowance_config_yaml() -> Result<()> {
+    let helper = TestHelper::new()?;
+    let child = helper.create_test_child()?;
+    let path = allowance_file(&helper, &child)?;
+    let golden = include_str!("testdata/allowance_config.yaml");
+
+    helper.allowance_repo.store_allowance_config(&golden_allowance_config(&child.id))?;
+    assert_eq!(fs::read_to_string(&path)?, golden, "allowance_config.yaml format changed");
+
+    fs::write(&path, golden)?;
+    assert_eq!(helper.allowance_repo.get_allowance_config(&child.id)?, Some(golden_allowance_config(&child.id)));
+    Ok(())
+}
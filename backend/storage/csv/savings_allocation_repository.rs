@@ -0,0 +1,204 @@
+//! # CSV Savings Allocation Repository
+//!
+//! This module stores automatic savings allocations per-child in
+//! `{child_directory}/savings_allocations.csv`.
+//!
+//! ## CSV Format
+//!
+//! ```csv
+//! # schema_version: 1
+//! id,transaction_id,date,amount,percentage,goal_id,source
+//! alloc::in-1705314600000-a1b2,in-1705314600000-a1b2,2024-01-15T10:30:00-05:00,2.00,20,,auto_save
+//! roundup::ex-1705401000000-c3d4,ex-1705401000000-c3d4,2024-01-16T10:30:00-05:00,0.75,0,,round_up
+//! ```
+//!
+//! Files written before round-ups existed have no `source` column; their rows
+//! are read as auto-saves. Columns are found by header name (see
+//! `csv_schema`).
+
+use anyhow::Result;
+use log::{debug, info};
+use std::path::PathBuf;
+
+use crate::backend::domain::models::savings_allocation::{SavingsAllocation as DomainSavingsAllocation, SavingsSource};
+use super::connection::CsvConnection;
+use super::csv_schema::{self, ColumnIndex};
+
+/// Schema version written at the top of `savings_allocations.csv`
+const SCHEMA_VERSION: u32 = 1;
+
+/// Columns of `savings_allocations.csv`, in the order they are written
+const COLUMNS: &[&str] = &["id", "transaction_id", "date", "amount", "percentage", "goal_id", "source"];
+
+/// CSV-based savings allocation repository using per-child CSV files
+#[derive(Clone)]
+pub struct SavingsAllocationRepository {
+    connection: CsvConnection,
+}
+
+impl SavingsAllocationRepository {
+    /// Create a new savings allocation repository
+    pub fn new(connection: CsvConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Get the allocations file path for a child
+    fn get_allocations_path(&self, child_id: &str) -> Result<PathBuf> {
+        let child_directory = self
+            .connection
+            .find_child_directory_by_id(child_id)?
+            .ok_or_else(|| anyhow::anyhow!("Child with ID '{}' not found", child_id))?;
+        Ok(self
+            .connection
+            .get_child_directory(&child_directory)
+            .join("savings_allocations.csv"))
+    }
+
+    /// Read all allocations for a child in file order
+    fn read_allocations(&self, child_id: &str) -> Result<Vec<DomainSavingsAllocation>> {
+        let csv_path = self.get_allocations_path(child_id)?;
+        let content = match self.connection.read_child_file(&csv_path)? {
+            Some(content) => content,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut csv_reader = csv_schema::reader(&content);
+        let columns = ColumnIndex::new(csv_reader.headers()?, COLUMNS);
+        let mut allocations = Vec::new();
+        for result in csv_reader.records() {
+            let record = result?;
+            let goal_id = columns.value(&record, "goal_id");
+            allocations.push(DomainSavingsAllocation {
+                id: columns.value(&record, "id").to_string(),
+                transaction_id: columns.value(&record, "transaction_id").to_string(),
+                date: chrono::DateTime::parse_from_rfc3339(columns.value(&record, "date"))?,
+                amount: columns.get(&record, "amount").unwrap_or("0").parse::<f64>().unwrap_or(0.0),
+                percentage: columns.get(&record, "percentage").unwrap_or("0").parse::<f64>().unwrap_or(0.0),
+                goal_id: if goal_id.is_empty() { None } else { Some(goal_id.to_string()) },
+                source: SavingsSource::parse(columns.value(&record, "source")),
+            });
+        }
+        Ok(allocations)
+    }
+
+    /// Write all allocations for a child, replacing the file
+    fn write_allocations(&self, child_id: &str, allocations: &[DomainSavingsAllocation]) -> Result<()> {
+        let csv_path = self.get_allocations_path(child_id)?;
+        if let Some(child_dir) = csv_path.parent() {
+            self.connection.ensure_child_writable(child_dir)?;
+        }
+        let mut csv_writer = csv_schema::writer(SCHEMA_VERSION, COLUMNS)?;
+        for allocation in allocations {
+            csv_writer.write_record([
+                allocation.id.as_str(),
+                allocation.transaction_id.as_str(),
+                &allocation.date.to_rfc3339(),
+                &allocation.amount.to_string(),
+                &allocation.percentage.to_string(),
+                allocation.goal_id.as_deref().unwrap_or(""),
+                allocation.source.as_str(),
+            ])?;
+        }
+        let content = csv_writer.into_inner()?;
+        self.connection.write_child_file(&csv_path, &content)?;
+        Ok(())
+    }
+}
+
+impl crate::backend::storage::SavingsAllocationStorage for SavingsAllocationRepository {
+    fn store_allocation(&self, child_id: &str, allocation: &DomainSavingsAllocation) -> Result<()> {
+        let mut allocations = self.read_allocations(child_id)?;
+        if let Some(pos) = allocations.iter().position(|a| a.id == allocation.id) {
+            allocations[pos] = allocation.clone();
+        } else {
+            allocations.push(allocation.clone());
+        }
+        allocations.sort_by_key(|a| a.date);
+        self.write_allocations(child_id, &allocations)?;
+
+        info!("💰 Stored savings allocation {} (${:.2}) for child '{}'", allocation.id, allocation.amount, child_id);
+        Ok(())
+    }
+
+    fn list_allocations(&self, child_id: &str) -> Result<Vec<DomainSavingsAllocation>> {
+        self.read_allocations(child_id)
+    }
+
+    fn delete_allocations_for_transactions(&self, child_id: &str, transaction_ids: &[String]) -> Result<u32> {
+        let mut allocations = self.read_allocations(child_id)?;
+        let initial_len = allocations.len();
+        allocations.retain(|a| !transaction_ids.contains(&a.transaction_id));
+
+        let removed = (initial_len - allocations.len()) as u32;
+        if removed > 0 {
+            self.write_allocations(child_id, &allocations)?;
+            debug!("Removed {} savings allocations for child '{}'", removed, child_id);
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::csv::test_utils::TestHelper;
+    use crate::backend::storage::traits::SavingsAllocationStorage;
+
+    fn allocation(transaction_id: &str, date: &str, amount: f64, goal_id: Option<&str>) -> DomainSavingsAllocation {
+        DomainSavingsAllocation {
+            id: DomainSavingsAllocation::generate_id(transaction_id),
+            transaction_id: transaction_id.to_string(),
+            date: chrono::DateTime::parse_from_rfc3339(date).unwrap(),
+            amount,
+            percentage: 20.0,
+            goal_id: goal_id.map(|g| g.to_string()),
+            source: SavingsSource::AutoSave,
+        }
+    }
+
+    #[test]
+    fn test_store_list_and_delete_allocations() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let repo = SavingsAllocationRepository::new(helper.env.connection.clone());
+
+        assert!(repo.list_allocations(&child.id)?.is_empty());
+
+        repo.store_allocation(&child.id, &allocation("in-2", "2024-02-01T12:00:00-05:00", 1.0, Some("goal::x")))?;
+        repo.store_allocation(&child.id, &allocation("in-1", "2024-01-01T12:00:00-05:00", 2.0, None))?;
+
+        let allocations = repo.list_allocations(&child.id)?;
+        assert_eq!(allocations.len(), 2);
+        assert_eq!(allocations[0].transaction_id, "in-1");
+        assert_eq!(allocations[0].goal_id, None);
+        assert_eq!(allocations[1].goal_id.as_deref(), Some("goal::x"));
+
+        let removed = repo.delete_allocations_for_transactions(&child.id, &["in-1".to_string()])?;
+        assert_eq!(removed, 1);
+        assert_eq!(repo.list_allocations(&child.id)?.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_files_without_source_column_read_as_auto_save() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let repo = SavingsAllocationRepository::new(helper.env.connection.clone());
+
+        std::fs::write(
+            repo.get_allocations_path(&child.id)?,
+            "id,transaction_id,date,amount,percentage,goal_id\n\
+             alloc::in-1,in-1,2024-01-15T10:30:00-05:00,2,20,\n",
+        )?;
+        assert_eq!(repo.list_allocations(&child.id)?[0].source, SavingsSource::AutoSave);
+
+        let mut round_up = allocation("ex-1", "2024-01-16T10:30:00-05:00", 0.75, None);
+        round_up.id = DomainSavingsAllocation::generate_round_up_id("ex-1");
+        round_up.source = SavingsSource::RoundUp;
+        repo.store_allocation(&child.id, &round_up)?;
+
+        let sources: Vec<SavingsSource> = repo.list_allocations(&child.id)?.iter().map(|a| a.source).collect();
+        assert_eq!(sources, vec![SavingsSource::AutoSave, SavingsSource::RoundUp]);
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+//! # CSV Snapshot Repository
+//!
+//! This module stores each child's startup snapshot as JSON in the child's
+//! directory: `{child_directory}/snapshot.json`.
+//!
+//! The snapshot only ever repeats data kept elsewhere, so a missing or
+//! unreadable file is never an error for the app — it just means the first
+//! screen waits for the full load, as it did before snapshots existed.
+//! Like every other child file it is sealed when the directory is encrypted.
+
+use anyhow::Result;
+use log::{debug, warn};
+use std::path::PathBuf;
+
+use crate::backend::domain::models::child_snapshot::ChildSnapshot;
+use super::connection::CsvConnection;
+
+/// Name of the snapshot file inside a child directory
+pub const SNAPSHOT_FILE: &str = "snapshot.json";
+
+/// JSON-based snapshot repository using per-child files
+#[derive(Clone)]
+pub struct SnapshotRepository {
+    connection: CsvConnection,
+}
+
+impl SnapshotRepository {
+    /// Create a new snapshot repository
+    pub fn new(connection: CsvConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Get the snapshot file path for a child
+    fn get_snapshot_path(&self, child_id: &str) -> Result<PathBuf> {
+        let child_directory = self
+            .connection
+            .find_child_directory_by_id(child_id)?
+            .ok_or_else(|| anyhow::anyhow!("Child with ID '{}' not found", child_id))?;
+        Ok(self.connection.get_child_directory(&child_directory).join(SNAPSHOT_FILE))
+    }
+}
+
+impl crate::backend::storage::ChildSnapshotStorage for SnapshotRepository {
+    fn load_snapshot(&self, child_id: &str) -> Result<Option<ChildSnapshot>> {
+        let path = self.get_snapshot_path(child_id)?;
+        let content = match self.connection.read_child_file(&path)? {
+            Some(content) => content,
+            None => return Ok(None),
+        };
+
+        // A snapshot from an older version is rebuilt rather than reported
+        match serde_json::from_str::<ChildSnapshot>(&content) {
+            Ok(snapshot) if snapshot.child_id == child_id => Ok(Some(snapshot)),
+            Ok(_) => {
+                warn!("📸 Snapshot for child '{}' belongs to another child, ignoring it", child_id);
+                Ok(None)
+            }
+            Err(e) => {
+                warn!("📸 Could not parse snapshot for child '{}', ignoring it: {}", child_id, e);
+                Ok(None)
+            }
+        }
+    }
+
+    fn store_snapshot(&self, snapshot: &ChildSnapshot) -> Result<()> {
+        let path = self.get_snapshot_path(&snapshot.child_id)?;
+        if let Some(child_dir) = path.parent() {
+            self.connection.ensure_child_writable(child_dir)?;
+        }
+
+        let content = serde_json::to_vec(snapshot)?;
+        self.connection.write_child_file(&path, &content)?;
+        debug!(
+            "📸 Stored snapshot for child '{}' with {} recent transactions",
+            snapshot.child_id,
+            snapshot.recent_transactions.len()
+        );
+        Ok(())
+    }
+}
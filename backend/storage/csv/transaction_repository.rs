@@ -1,17 +1,51 @@
 use anyhow::Result;
 // Removed async_trait - no longer needed for synchronous operations
-use csv::{Reader, Writer};
 use log::{info, warn};
-use std::fs::{File, OpenOptions};
-use std::io::{BufReader, BufWriter};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use crate::backend::domain::models::actor::Actor;
 use crate::backend::domain::models::transaction::{
     Transaction as DomainTransaction, TransactionType as DomainTransactionType,
 };
 use super::connection::CsvConnection;
+use super::csv_schema::{self, ColumnIndex};
 use super::child_repository::ChildRepository;
 use crate::backend::storage::ChildStorage;
 
+/// File next to `transactions.csv` holding the originals of rolled-up transactions
+const ARCHIVE_FILE_NAME: &str = "transactions_archive.csv";
+
+/// Schema version written at the top of `transactions.csv` and the archive
+///
+/// 2 added the `type` column.
+pub const TRANSACTIONS_SCHEMA_VERSION: u32 = 2;
+
+/// Columns of `transactions.csv`, in the order they are written
+pub const TRANSACTION_COLUMNS: &[&str] = &["id", "child_id", "date", "description", "amount", "balance", "actor", "category", "icon", "type"];
+
+/// Transactions in the `transactions.csv` layout: version comment, header and one row each
+///
+/// Exports use this too, so an exported file reads back like a stored one.
+pub fn transactions_csv(transactions: &[DomainTransaction]) -> Result<Vec<u8>> {
+    let mut csv_writer = csv_schema::writer(TRANSACTIONS_SCHEMA_VERSION, TRANSACTION_COLUMNS)?;
+    for transaction in transactions {
+        csv_writer.write_record(&[
+            &transaction.id,
+            &transaction.child_id,
+            &transaction.date.to_rfc3339(),  // ✅ Convert DateTime back to string for CSV storage
+            &transaction.description,
+            &transaction.amount.to_string(),
+            &transaction.balance.to_string(),
+            &transaction.actor.as_ref().map(Actor::to_storage).unwrap_or_default(),
+            &transaction.category.clone().unwrap_or_default(),
+            &transaction.icon.clone().unwrap_or_default(),
+            &transaction.transaction_type.storage_key().to_string(),
+        ])?;
+    }
+    Ok(csv_writer.into_inner()?)
+}
+
 /// CSV-based transaction repository
 #[derive(Clone)]
 pub struct TransactionRepository {
@@ -34,10 +68,14 @@ impl TransactionRepository {
         self.connection.ensure_transactions_file_exists(child_name)?;
         
         let file_path = self.connection.get_transactions_file_path(child_name);
-        
-        let file = File::open(&file_path)?;
-        let reader = BufReader::new(file);
-        let mut csv_reader = Reader::from_reader(reader);
+        self.read_transactions_file(&file_path)
+    }
+
+    /// Parse a transactions-format CSV file; a missing file has no transactions
+    fn read_transactions_file(&self, file_path: &Path) -> Result<Vec<DomainTransaction>> {
+        let content = self.connection.read_child_file(file_path)?.unwrap_or_default();
+        let mut csv_reader = csv_schema::reader(&content);
+        let columns = ColumnIndex::new(csv_reader.headers()?, TRANSACTION_COLUMNS);
         
         let mut transactions = Vec::new();
         
@@ -45,22 +83,27 @@ impl TransactionRepository {
             let record = result?;
             
             // ✅ FIXED: Parse date string into DateTime object (CSV layer responsibility)
-            let date_str = record.get(2).unwrap_or("");
+            let date_str = columns.value(&record, "date");
             let parsed_date = self.parse_date_string(date_str)?;
+            let amount = columns.get(&record, "amount").unwrap_or("0").parse::<f64>().unwrap_or(0.0);
             
             // Parse CSV record into Transaction
             let transaction = DomainTransaction {
-                id: record.get(0).unwrap_or("").to_string(),
-                child_id: record.get(1).unwrap_or("").to_string(),
+                id: columns.value(&record, "id").to_string(),
+                child_id: columns.value(&record, "child_id").to_string(),
                 date: parsed_date,  // ✅ Now uses parsed DateTime object
-                description: record.get(3).unwrap_or("").to_string(),
-                amount: record.get(4).unwrap_or("0").parse::<f64>().unwrap_or(0.0),
-                balance: record.get(5).unwrap_or("0").parse::<f64>().unwrap_or(0.0),
-                transaction_type: if record.get(4).unwrap_or("0").parse::<f64>().unwrap_or(0.0) >= 0.0 { 
-                    DomainTransactionType::Income 
-                } else { 
-                    DomainTransactionType::Expense 
-                },
+                description: columns.value(&record, "description").to_string(),
+                amount,
+                balance: columns.get(&record, "balance").unwrap_or("0").parse::<f64>().unwrap_or(0.0),
+                // Files written before kinds were stored get the plain kind for the amount
+                transaction_type: columns
+                    .get(&record, "type")
+                    .and_then(DomainTransactionType::from_storage_key)
+                    .unwrap_or_else(|| DomainTransactionType::from_amount(amount)),
+                // Files written before actors, categories or icons have no such columns
+                actor: columns.get(&record, "actor").and_then(Actor::parse),
+                category: columns.get(&record, "category").filter(|category| !category.is_empty()).map(str::to_string),
+                icon: columns.get(&record, "icon").filter(|icon| !icon.is_empty()).map(str::to_string),
             };
             
             transactions.push(transaction);
@@ -97,32 +140,17 @@ impl TransactionRepository {
     /// Write all transactions for a child to their CSV file
     fn write_transactions(&self, child_name: &str, transactions: &[DomainTransaction]) -> Result<()> {
         let file_path = self.connection.get_transactions_file_path(child_name);
-        
-        let file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(&file_path)?;
-        
-        let writer = BufWriter::new(file);
-        let mut csv_writer = Writer::from_writer(writer);
-        
-        // Write header
-        csv_writer.write_record(&["id", "child_id", "date", "description", "amount", "balance"])?;
-        
-        // Write transactions
-        for transaction in transactions {
-            csv_writer.write_record(&[
-                &transaction.id,
-                &transaction.child_id,
-                &transaction.date.to_rfc3339(),  // ✅ Convert DateTime back to string for CSV storage
-                &transaction.description,
-                &transaction.amount.to_string(),
-                &transaction.balance.to_string(),
-            ])?;
+        self.write_transactions_file(&file_path, transactions)
+    }
+
+    /// Replace a transactions-format CSV file
+    fn write_transactions_file(&self, file_path: &Path, transactions: &[DomainTransaction]) -> Result<()> {
+        if let Some(child_dir) = file_path.parent() {
+            self.connection.ensure_child_writable(child_dir)?;
         }
         
-        csv_writer.flush()?;
+        let content = transactions_csv(transactions)?;
+        self.connection.write_child_file(file_path, &content)?;
         Ok(())
     }
     
@@ -165,6 +193,48 @@ impl TransactionRepository {
         let child_name = self.get_child_directory_name(child_id)?;
         self.write_transactions(&child_name, transactions)
     }
+
+    /// Path of the archive that keeps the originals of rolled-up transactions
+    fn archive_file_path(&self, child_name: &str) -> PathBuf {
+        self.connection.get_child_directory(child_name).join(ARCHIVE_FILE_NAME)
+    }
+
+    /// Archived original transactions for a child, oldest first
+    pub fn read_archived_transactions_by_id(&self, child_id: &str) -> Result<Vec<DomainTransaction>> {
+        let child_name = self.get_child_directory_name(child_id)?;
+        self.read_transactions_file(&self.archive_file_path(&child_name))
+    }
+
+    /// Replace a child's archive file with `archived`, given oldest first
+    pub fn write_archived_transactions_by_id(&self, child_id: &str, archived: &[DomainTransaction]) -> Result<()> {
+        let child_name = self.get_child_directory_name(child_id)?;
+        self.write_transactions_file(&self.archive_file_path(&child_name), archived)
+    }
+
+    /// Move `archived` into the child's archive file and replace their live
+    /// transactions with `remaining`.
+    ///
+    /// The archive is written first and skips rows it already holds, so if
+    /// writing the live file fails the whole call can simply be repeated.
+    pub fn archive_transactions_by_id(
+        &self,
+        child_id: &str,
+        archived: &[DomainTransaction],
+        remaining: &[DomainTransaction],
+    ) -> Result<()> {
+        let child_name = self.get_child_directory_name(child_id)?;
+        let archive_path = self.archive_file_path(&child_name);
+
+        let mut archive = self.read_transactions_file(&archive_path)?;
+        let known: HashSet<String> = archive.iter().map(|t| t.id.clone()).collect();
+        archive.extend(archived.iter().filter(|t| !known.contains(&t.id)).cloned());
+        archive.sort_by_key(|t| t.date);
+        self.write_transactions_file(&archive_path, &archive)?;
+
+        self.write_transactions(&child_name, remaining)?;
+        info!("Archived {} transactions for child '{}'", archived.len(), child_name);
+        Ok(())
+    }
     
     /// Compare two DateTime objects properly handling timezone conversion
     fn compare_dates(&self, date1: &chrono::DateTime<chrono::FixedOffset>, date2: &str) -> i32 {
@@ -250,8 +320,7 @@ impl crate::backend::storage::TransactionStorage for TransactionRepository {
         // Convert child ID to child name for directory lookup
         let child_name = self.get_child_directory_name(child_id)?;
         Ok(self
-            .read_transactions(&child_name)
-            .unwrap_or_default()
+            .read_transactions(&child_name)?
             .into_iter()
             .find(|t| t.id == transaction_id))
     }
@@ -310,27 +379,46 @@ impl crate::backend::storage::TransactionStorage for TransactionRepository {
     fn update_transaction(&self, transaction: &DomainTransaction) -> Result<()> {
         info!("Updating transaction in CSV: {}", transaction.id);
 
-        let mut transactions = self
-            .read_transactions_by_id(&transaction.child_id)
-            .unwrap_or_default();
+        let mut transactions = self.read_transactions_by_id(&transaction.child_id)?;
 
         if let Some(index) = transactions.iter().position(|t| t.id == transaction.id) {
             transactions[index] = transaction.clone();
-            self.write_transactions_by_id(&transaction.child_id, &transactions)
-                .unwrap_or_default();
+            self.write_transactions_by_id(&transaction.child_id, &transactions)?;
         }
 
         Ok(())
     }
 
+    fn update_transaction_categories(&self, child_id: &str, updates: &[(String, Option<String>)]) -> Result<u32> {
+        if updates.is_empty() {
+            return Ok(0);
+        }
+        let categories: std::collections::HashMap<&str, &Option<String>> =
+            updates.iter().map(|(id, category)| (id.as_str(), category)).collect();
+
+        let mut transactions = self.read_transactions_by_id(child_id)?;
+        let mut updated = 0;
+        for transaction in &mut transactions {
+            if let Some(category) = categories.get(transaction.id.as_str()) {
+                transaction.category = (*category).clone();
+                updated += 1;
+            }
+        }
+        if updated > 0 {
+            self.write_transactions_by_id(child_id, &transactions)?;
+        }
+
+        info!("Updated the category of {} transactions for child {}", updated, child_id);
+        Ok(updated)
+    }
+
     fn delete_transaction(&self, child_id: &str, transaction_id: &str) -> Result<bool> {
         let mut transactions = self.read_transactions_by_id(child_id)?;
         let original_len = transactions.len();
         transactions.retain(|t| t.id != transaction_id);
 
         if transactions.len() < original_len {
-            self.write_transactions_by_id(child_id, &transactions)
-                .unwrap_or_default();
+            self.write_transactions_by_id(child_id, &transactions)?;
             Ok(true)
         } else {
             Ok(false)
@@ -343,8 +431,7 @@ impl crate::backend::storage::TransactionStorage for TransactionRepository {
         let mut transactions = self.read_transactions(&child_name)?;
         let initial_len = transactions.len();
         transactions.retain(|t| !transaction_ids.contains(&t.id));
-        self.write_transactions(&child_name, &transactions)
-            .unwrap_or_default();
+        self.write_transactions(&child_name, &transactions)?;
         Ok((initial_len - transactions.len()) as u32)
     }
 
@@ -426,8 +513,7 @@ impl crate::backend::storage::TransactionStorage for TransactionRepository {
             }
 
             if needs_write {
-                self.write_transactions_by_id(&child_id, &transactions)
-                    .unwrap_or_default();
+                self.write_transactions_by_id(&child_id, &transactions)?;
             }
         }
         
@@ -532,6 +618,9 @@ mod tests {
             amount: 25.50,
             balance: 25.50,
             transaction_type: DomainTransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
         
         // Store transaction
@@ -563,6 +652,9 @@ mod tests {
                 amount: i as f64 * 10.0,
                 balance: (i * (i + 1) / 2) as f64 * 10.0, // Cumulative sum
                 transaction_type: DomainTransactionType::Income,
+                actor: None,
+                category: None,
+                icon: None,
             };
             
             repo.store_transaction(&transaction)?;
@@ -594,6 +686,9 @@ mod tests {
             amount: 10.0,
             balance: 10.0,
             transaction_type: DomainTransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
 
         // Store and verify
@@ -607,6 +702,37 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_transaction_reports_read_errors() -> Result<()> {
+        let (repo, temp_dir) = setup_test_repo()?;
+        let child = setup_test_child(&temp_dir)?;
+        let transaction = DomainTransaction {
+            id: "test_transaction_123".to_string(),
+            child_id: child.id.clone(),
+            date: chrono::DateTime::parse_from_rfc3339("2025-01-01T12:00:00Z").unwrap(),
+            description: "Test transaction".to_string(),
+            amount: 10.0,
+            balance: 10.0,
+            transaction_type: DomainTransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
+        };
+        repo.store_transaction(&transaction)?;
+
+        // Encrypted through another connection, so this repository has no key
+        let other = CsvConnection::new(temp_dir.path())?;
+        let child_dir = other.get_child_directory_by_id(&child.id)?.unwrap();
+        other.encrypt_child_directory(&child_dir, "ice cold")?;
+
+        let err = repo.get_transaction(&child.id, &transaction.id).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<crate::backend::storage::csv::EncryptionError>(),
+            Some(crate::backend::storage::csv::EncryptionError::Locked { .. })
+        ), "a locked file is an error, not a missing transaction: {}", err);
+        Ok(())
+    }
     
     // ========================================
     // ARCHITECTURAL INVARIANT TESTS
@@ -651,6 +777,9 @@ mod tests {
             amount: 10.0,
             balance: 10.0,
             transaction_type: DomainTransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
         
         // This test checks that the date field is NOT a string
@@ -684,6 +813,9 @@ mod tests {
             amount: 50.0,
             balance: 50.0,
             transaction_type: DomainTransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
         
         repo.store_transaction(&transaction)?;
@@ -729,6 +861,9 @@ mod tests {
                 amount: 10.0,
                 balance: 10.0,
                 transaction_type: DomainTransactionType::Income,
+                actor: None,
+                category: None,
+                icon: None,
             };
             
             repo.store_transaction(&transaction)?;
@@ -770,6 +905,9 @@ mod tests {
                 amount: 10.0,
                 balance: 10.0,
                 transaction_type: DomainTransactionType::Income,
+                actor: None,
+                category: None,
+                icon: None,
             };
             
             // Store should either succeed with normalized date or fail gracefully
@@ -810,6 +948,9 @@ mod tests {
             amount: 1.00,
             balance: 17.62,
             transaction_type: DomainTransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
         
         let tx2 = DomainTransaction {
@@ -820,6 +961,9 @@ mod tests {
             amount: 2.00,
             balance: 19.62,
             transaction_type: DomainTransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
         };
         
         // Store transactions
@@ -0,0 +1,204 @@
+//! # CSV Transaction Template Repository
+//!
+//! This module stores a child's transaction templates in a YAML file in
+//! their directory: `{child_directory}/templates.yaml`.
+//!
+//! ## File Structure
+//!
+//! ```yaml
+//! templates:
+//! - id: template::alice_1705314600000
+//!   name: Lunch
+//!   description: Lunch money
+//!   amount: 4.5
+//!   category: Food
+//!   type: expense
+//! - id: template::alice_1705314700000
+//!   name: Walk the dog
+//!   description: Walked the dog
+//!   amount: 2.0
+//!   type: income
+//! ```
+//!
+//! `type` uses the same keys as the `type` column of `transactions.csv`.
+//! The child_id is implicit from the directory, so it is not written to disk.
+
+use anyhow::Result;
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::backend::domain::models::transaction::TransactionType;
+use crate::backend::domain::models::transaction_template::TransactionTemplate as DomainTransactionTemplate;
+use super::connection::CsvConnection;
+
+/// YAML representation of a child's templates file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct YamlTemplateFile {
+    #[serde(default)]
+    templates: Vec<YamlTemplate>,
+}
+
+/// YAML representation of a single template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct YamlTemplate {
+    id: String,
+    name: String,
+    description: String,
+    amount: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    category: Option<String>,
+    #[serde(rename = "type")]
+    transaction_type: String,
+}
+
+/// YAML-based transaction template repository using per-child files
+#[derive(Clone)]
+pub struct TransactionTemplateRepository {
+    connection: CsvConnection,
+}
+
+impl TransactionTemplateRepository {
+    /// Create a new transaction template repository
+    pub fn new(connection: CsvConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Get the templates file path for a child
+    fn get_templates_path(&self, child_id: &str) -> Result<PathBuf> {
+        let child_directory = self
+            .connection
+            .find_child_directory_by_id(child_id)?
+            .ok_or_else(|| anyhow::anyhow!("Child with ID '{}' not found", child_id))?;
+        Ok(self.connection.get_child_directory(&child_directory).join("templates.yaml"))
+    }
+
+    /// Read all templates for a child in file order
+    fn read_templates(&self, child_id: &str) -> Result<Vec<DomainTransactionTemplate>> {
+        let yaml_path = self.get_templates_path(child_id)?;
+        let content = match self.connection.read_child_file(&yaml_path)? {
+            Some(content) => content,
+            None => return Ok(Vec::new()),
+        };
+
+        let file: YamlTemplateFile = serde_yaml::from_str(&content)?;
+        file.templates
+            .into_iter()
+            .map(|template| {
+                let transaction_type = TransactionType::from_storage_key(&template.transaction_type)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown type '{}' in template {}", template.transaction_type, template.id))?;
+                Ok(DomainTransactionTemplate {
+                    id: template.id,
+                    child_id: child_id.to_string(),
+                    name: template.name,
+                    description: template.description,
+                    amount: template.amount,
+                    category: template.category,
+                    transaction_type,
+                })
+            })
+            .collect()
+    }
+
+    /// Write all templates for a child, replacing the file
+    fn write_templates(&self, child_id: &str, templates: &[DomainTransactionTemplate]) -> Result<()> {
+        let yaml_path = self.get_templates_path(child_id)?;
+        if let Some(child_dir) = yaml_path.parent() {
+            self.connection.ensure_child_writable(child_dir)?;
+        }
+
+        let file = YamlTemplateFile {
+            templates: templates
+                .iter()
+                .map(|template| YamlTemplate {
+                    id: template.id.clone(),
+                    name: template.name.clone(),
+                    description: template.description.clone(),
+                    amount: template.amount,
+                    category: template.category.clone(),
+                    transaction_type: template.transaction_type.storage_key().to_string(),
+                })
+                .collect(),
+        };
+        let content = serde_yaml::to_string(&file)?;
+        self.connection.write_child_file(&yaml_path, content.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl crate::backend::storage::TransactionTemplateStorage for TransactionTemplateRepository {
+    fn store_template(&self, template: &DomainTransactionTemplate) -> Result<()> {
+        let mut templates = self.read_templates(&template.child_id)?;
+        if let Some(pos) = templates.iter().position(|t| t.id == template.id) {
+            templates[pos] = template.clone();
+        } else {
+            templates.push(template.clone());
+        }
+        self.write_templates(&template.child_id, &templates)?;
+
+        info!("⭐ Stored template \"{}\" for child '{}'", template.name, template.child_id);
+        Ok(())
+    }
+
+    fn list_templates(&self, child_id: &str) -> Result<Vec<DomainTransactionTemplate>> {
+        self.read_templates(child_id)
+    }
+
+    fn delete_template(&self, child_id: &str, template_id: &str) -> Result<bool> {
+        let mut templates = self.read_templates(child_id)?;
+        let before = templates.len();
+        templates.retain(|t| t.id != template_id);
+        if templates.len() == before {
+            return Ok(false);
+        }
+        self.write_templates(child_id, &templates)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::csv::test_utils::TestHelper;
+    use crate::backend::storage::traits::TransactionTemplateStorage;
+
+    #[test]
+    fn test_store_list_and_delete_templates() -> Result<()> {
+        let helper = TestHelper::new()?;
+        let child = helper.create_test_child()?;
+        let repo = TransactionTemplateRepository::new(helper.env.connection.clone());
+
+        assert!(repo.list_templates(&child.id)?.is_empty());
+
+        let lunch = DomainTransactionTemplate {
+            id: "template::1".to_string(),
+            child_id: child.id.clone(),
+            name: "Lunch".to_string(),
+            description: "Lunch money".to_string(),
+            amount: 4.5,
+            category: Some("Food".to_string()),
+            transaction_type: TransactionType::Expense,
+        };
+        let chores = DomainTransactionTemplate {
+            id: "template::2".to_string(),
+            name: "Chores".to_string(),
+            description: "Weekly chores".to_string(),
+            amount: 3.0,
+            category: None,
+            transaction_type: TransactionType::Bonus,
+            ..lunch.clone()
+        };
+        repo.store_template(&lunch)?;
+        repo.store_template(&chores)?;
+        assert_eq!(repo.list_templates(&child.id)?, vec![lunch.clone(), chores.clone()]);
+
+        let renamed = DomainTransactionTemplate { name: "School lunch".to_string(), ..lunch };
+        repo.store_template(&renamed)?;
+        assert_eq!(repo.list_templates(&child.id)?, vec![renamed.clone(), chores]);
+
+        assert!(repo.delete_template(&child.id, "template::2")?);
+        assert!(!repo.delete_template(&child.id, "template::2")?);
+        assert_eq!(repo.list_templates(&child.id)?, vec![renamed]);
+        Ok(())
+    }
+}
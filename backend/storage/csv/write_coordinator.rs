@@ -0,0 +1,172 @@
+//! # Write Coordinator
+//!
+//! Every change rewrites whole child files, so a bulk operation such as a
+//! statement import or deleting many transactions used to rewrite
+//! `transactions.csv` (and its neighbours) once per row. It also left one
+//! change per write for git versioning to commit.
+//!
+//! Bulk operations now run inside [`CsvConnection::batch_writes`]. While a
+//! batch is open, child file writes are held in memory and later reads see the
+//! held contents. When the outermost batch ends, each changed file is written
+//! once. Batches can nest, and files are flushed even when the operation fails
+//! part way. This matches the unbatched behaviour, where earlier rows were
+//! already on disk.
+//!
+//! Changes are committed to git per child directory, with one message per
+//! batch, once no change has been made for [`COMMIT_IDLE_SECONDS`]. Anything
+//! left over is committed when the app closes.
+//!
+//! [`CsvConnection::batch_writes`]: super::connection::CsvConnection::batch_writes
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Changes are committed once nothing has changed for this long
+pub const COMMIT_IDLE_SECONDS: u64 = 5;
+
+/// Batching state shared by all clones of a connection
+#[derive(Debug, Clone, Default)]
+pub struct WriteCoordinator {
+    state: Arc<Mutex<CoordinatorState>>,
+}
+
+#[derive(Debug, Default)]
+struct CoordinatorState {
+    /// Descriptions of the open batches, outermost first
+    batches: Vec<String>,
+    /// Contents waiting to be written when the outermost batch ends
+    held: BTreeMap<PathBuf, Vec<u8>>,
+    /// Change descriptions not yet committed, by child directory
+    uncommitted: BTreeMap<PathBuf, Vec<String>>,
+    /// When the last change was recorded
+    last_change: Option<Instant>,
+}
+
+impl WriteCoordinator {
+    /// Open a batch; writes are held until every open batch has ended
+    pub fn begin_batch(&self, description: &str) {
+        self.state.lock().unwrap().batches.push(description.to_string());
+    }
+
+    /// Close the innermost batch. When that was the outermost one, returns the
+    /// held files to write, with the description the changes are committed under.
+    pub fn end_batch(&self) -> Option<(String, Vec<(PathBuf, Vec<u8>)>)> {
+        let mut state = self.state.lock().unwrap();
+        let description = state.batches.pop()?;
+        if !state.batches.is_empty() {
+            return None;
+        }
+        let held = std::mem::take(&mut state.held);
+        Some((description, held.into_iter().collect()))
+    }
+
+    /// Hold a file's new contents if a batch is open; false means write it now
+    pub fn hold(&self, path: &Path, contents: &[u8]) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if state.batches.is_empty() {
+            return false;
+        }
+        state.held.insert(path.to_path_buf(), contents.to_vec());
+        true
+    }
+
+    /// Append to held contents, so appends stay in order with held writes;
+    /// false means the file isn't held and should be appended to directly
+    pub fn append_held(&self, path: &Path, contents: &[u8]) -> bool {
+        let mut state = self.state.lock().unwrap();
+        match state.held.get_mut(path) {
+            Some(held) => {
+                held.extend_from_slice(contents);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Held contents of a file, if it was written during the open batch
+    pub fn held_contents(&self, path: &Path) -> Option<Vec<u8>> {
+        self.state.lock().unwrap().held.get(path).cloned()
+    }
+
+    /// Note a change to commit for the child directory holding `path`
+    pub fn record_change(&self, path: &Path, description: &str) {
+        let Some(child_dir) = path.parent() else {
+            return;
+        };
+        let mut state = self.state.lock().unwrap();
+        let descriptions = state.uncommitted.entry(child_dir.to_path_buf()).or_default();
+        if !descriptions.iter().any(|d| d == description) {
+            descriptions.push(description.to_string());
+        }
+        state.last_change = Some(Instant::now());
+    }
+
+    /// Commit messages for every child directory with changes, if nothing has
+    /// changed for `idle` and no batch is open
+    pub fn take_idle_changes(&self, idle: Duration) -> Vec<(PathBuf, String)> {
+        let mut state = self.state.lock().unwrap();
+        let is_idle = state.last_change.is_some_and(|at| at.elapsed() >= idle);
+        if !is_idle || !state.batches.is_empty() {
+            return Vec::new();
+        }
+        Self::drain_changes(&mut state)
+    }
+
+    /// Commit messages for every child directory with changes, regardless of timing
+    pub fn take_all_changes(&self) -> Vec<(PathBuf, String)> {
+        Self::drain_changes(&mut self.state.lock().unwrap())
+    }
+
+    fn drain_changes(state: &mut CoordinatorState) -> Vec<(PathBuf, String)> {
+        state.last_change = None;
+        std::mem::take(&mut state.uncommitted)
+            .into_iter()
+            .map(|(dir, descriptions)| (dir, descriptions.join("; ")))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_batches_flush_once_with_outer_description() {
+        let coordinator = WriteCoordinator::default();
+        let path = Path::new("/data/alice/transactions.csv");
+        assert!(!coordinator.hold(path, b"direct"));
+
+        coordinator.begin_batch("Imported 2 transactions");
+        coordinator.begin_batch("Recalculated balances");
+        assert!(coordinator.hold(path, b"first"));
+        assert!(coordinator.hold(path, b"second"));
+        assert!(coordinator.append_held(path, b",third"));
+        assert!(!coordinator.append_held(Path::new("/data/alice/audit_log.csv"), b"row"));
+        assert_eq!(coordinator.held_contents(path), Some(b"second,third".to_vec()));
+
+        assert_eq!(coordinator.end_batch(), None);
+        let (description, files) = coordinator.end_batch().unwrap();
+        assert_eq!(description, "Imported 2 transactions");
+        assert_eq!(files, vec![(path.to_path_buf(), b"second,third".to_vec())]);
+        assert_eq!(coordinator.held_contents(path), None);
+        assert_eq!(coordinator.end_batch(), None);
+    }
+
+    #[test]
+    fn test_changes_are_committed_once_idle() {
+        let coordinator = WriteCoordinator::default();
+        coordinator.record_change(Path::new("/data/alice/transactions.csv"), "Deleted 3 transactions");
+        coordinator.record_change(Path::new("/data/alice/loans.yaml"), "Deleted 3 transactions");
+        coordinator.record_change(Path::new("/data/alice/goals.csv"), "Updated goals.csv");
+
+        assert!(coordinator.take_idle_changes(Duration::from_secs(60)).is_empty());
+        let changes = coordinator.take_idle_changes(Duration::ZERO);
+        assert_eq!(
+            changes,
+            vec![(PathBuf::from("/data/alice"), "Deleted 3 transactions; Updated goals.csv".to_string())]
+        );
+        assert!(coordinator.take_all_changes().is_empty());
+    }
+}
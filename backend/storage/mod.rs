@@ -41,10 +41,14 @@
 pub mod traits;
 pub mod csv;
 pub mod git;
+pub mod zip_archive;
+pub mod pdf_document;
+pub mod xlsx_workbook;
+pub mod secrets;
 
 // Re-export the main types that other modules need
 pub use csv::CsvConnection;
-pub use traits::{Connection, TransactionStorage, ChildStorage, AllowanceStorage, ParentalControlStorage};
+pub use traits::{Connection, TransactionStorage, ChildStorage, AllowanceStorage, ParentalControlStorage, ChildSettingsStorage, AuditLogStorage, SavingsAllocationStorage, LoanStorage, AllowanceAdvanceStorage, PlannedPurchaseStorage, QuickPickStorage, TransactionTemplateStorage, BudgetTargetStorage, TransactionReversalStorage, ChildSnapshotStorage, CelebrationStorage, PeriodLockStorage};
 pub use csv::{GlobalConfig, GlobalConfigStorage};
 pub use git::GitManager;
 
@@ -0,0 +1,348 @@
+//! # PDF Document Module
+//!
+//! Minimal PDF support for printable documents such as the allowance
+//! agreement. Pages are US Letter, text is set in the Helvetica fonts every
+//! PDF reader has built in, and lines wrap using an average character width
+//! rather than real font metrics. That is plenty for a page of prose and keeps
+//! the format simple enough to write without a PDF dependency.
+//!
+//! Text is written in WinAnsi encoding, which covers Latin-1 plus the euro
+//! sign and typographic quotes. Anything outside it, emoji included, prints
+//! as `?`.
+
+const PAGE_WIDTH: f32 = 612.0;
+const PAGE_HEIGHT: f32 = 792.0;
+const MARGIN: f32 = 72.0;
+/// Helvetica's average glyph width as a fraction of the font size, for wrapping
+const AVERAGE_CHAR_WIDTH: f32 = 0.5;
+const LINE_SPACING: f32 = 1.4;
+const BULLET_INDENT: f32 = 18.0;
+const SIGNATURE_LINE_WIDTH: f32 = 260.0;
+const DATE_LINE_WIDTH: f32 = 140.0;
+
+/// Font sizes and weights used by [`PdfDocumentWriter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextStyle {
+    Title,
+    Heading,
+    Body,
+}
+
+impl TextStyle {
+    fn font_size(&self) -> f32 {
+        match self {
+            TextStyle::Title => 22.0,
+            TextStyle::Heading => 15.0,
+            TextStyle::Body => 11.0,
+        }
+    }
+
+    /// Resource name of the font, as registered on every page
+    fn font(&self) -> &'static str {
+        match self {
+            TextStyle::Body => "F1",
+            TextStyle::Title | TextStyle::Heading => "F2",
+        }
+    }
+}
+
+/// Lays out text top to bottom, starting a new page when one fills up
+pub struct PdfDocumentWriter {
+    pages: Vec<Vec<u8>>,
+    current: Vec<u8>,
+    cursor_y: f32,
+}
+
+impl PdfDocumentWriter {
+    /// Start a document with one empty page
+    pub fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            current: Vec::new(),
+            cursor_y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    /// Large bold text, centred
+    pub fn title(&mut self, text: &str) {
+        let style = TextStyle::Title;
+        for line in wrap(text, chars_per_line(style, 0.0)) {
+            let width = line.chars().count() as f32 * style.font_size() * AVERAGE_CHAR_WIDTH;
+            let x = ((PAGE_WIDTH - width) / 2.0).max(MARGIN);
+            self.write_line(style, x, &line);
+        }
+        self.space(8.0);
+    }
+
+    /// Bold section heading
+    pub fn heading(&mut self, text: &str) {
+        self.space(10.0);
+        self.write_wrapped(TextStyle::Heading, 0.0, text);
+        self.space(2.0);
+    }
+
+    /// Body text, wrapped to the page width
+    pub fn paragraph(&mut self, text: &str) {
+        self.write_wrapped(TextStyle::Body, 0.0, text);
+        self.space(4.0);
+    }
+
+    /// An indented list item
+    pub fn bullet(&mut self, text: &str) {
+        let style = TextStyle::Body;
+        let mut lines = wrap(text, chars_per_line(style, BULLET_INDENT)).into_iter();
+        if let Some(first) = lines.next() {
+            self.ensure_room(line_height(style));
+            self.cursor_y -= line_height(style);
+            self.text_at(style, MARGIN + BULLET_INDENT / 2.0 - 4.0, "•");
+            self.text_at(style, MARGIN + BULLET_INDENT, &first);
+        }
+        for line in lines {
+            self.write_line(style, MARGIN + BULLET_INDENT, &line);
+        }
+        self.space(2.0);
+    }
+
+    /// A blank list item, for filling in by hand
+    pub fn blank_bullet(&mut self) {
+        let style = TextStyle::Body;
+        self.ensure_room(line_height(style) + 8.0);
+        self.cursor_y -= line_height(style) + 8.0;
+        self.text_at(style, MARGIN + BULLET_INDENT / 2.0 - 4.0, "•");
+        self.rule(MARGIN + BULLET_INDENT, PAGE_WIDTH - MARGIN);
+    }
+
+    /// A line to sign on with `label` under it, and a shorter one for the date
+    pub fn signature_line(&mut self, label: &str) {
+        let style = TextStyle::Body;
+        let needed = 36.0 + line_height(style);
+        self.ensure_room(needed);
+        self.cursor_y -= 36.0;
+        let date_start = PAGE_WIDTH - MARGIN - DATE_LINE_WIDTH;
+        self.rule(MARGIN, MARGIN + SIGNATURE_LINE_WIDTH);
+        self.rule(date_start, PAGE_WIDTH - MARGIN);
+        self.cursor_y -= line_height(style);
+        self.text_at(style, MARGIN, label);
+        self.text_at(style, date_start, "Date");
+    }
+
+    /// Vertical gap, in points
+    pub fn space(&mut self, points: f32) {
+        self.cursor_y -= points;
+    }
+
+    /// Lay out the pages and return the finished PDF bytes
+    pub fn finish(mut self) -> Vec<u8> {
+        if !self.current.is_empty() || self.pages.is_empty() {
+            self.pages.push(std::mem::take(&mut self.current));
+        }
+
+        // Objects 1-4 are fixed; each page then takes a page and a content object
+        let page_ids: Vec<usize> = (0..self.pages.len()).map(|i| 5 + i * 2).collect();
+        let kids = page_ids.iter().map(|id| format!("{} 0 R", id)).collect::<Vec<_>>().join(" ");
+
+        let mut objects: Vec<Vec<u8>> = vec![
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            format!("<< /Type /Pages /Kids [{}] /Count {} >>", kids, self.pages.len()).into_bytes(),
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>".to_vec(),
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>".to_vec(),
+        ];
+        for (page_id, content) in page_ids.iter().zip(&self.pages) {
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                     /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                    PAGE_WIDTH, PAGE_HEIGHT, page_id + 1
+                )
+                .into_bytes(),
+            );
+            let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+            stream.extend_from_slice(content);
+            stream.extend_from_slice(b"\nendstream");
+            objects.push(stream);
+        }
+
+        let mut buffer = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(buffer.len());
+            buffer.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+            buffer.extend_from_slice(object);
+            buffer.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref_offset = buffer.len();
+        buffer.extend_from_slice(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes());
+        for offset in offsets {
+            buffer.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+        }
+        buffer.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+                objects.len() + 1,
+                xref_offset
+            )
+            .as_bytes(),
+        );
+        buffer
+    }
+
+    fn write_wrapped(&mut self, style: TextStyle, indent: f32, text: &str) {
+        for line in wrap(text, chars_per_line(style, indent)) {
+            self.write_line(style, MARGIN + indent, &line);
+        }
+    }
+
+    /// Move down a line and write `text` on it
+    fn write_line(&mut self, style: TextStyle, x: f32, text: &str) {
+        self.ensure_room(line_height(style));
+        self.cursor_y -= line_height(style);
+        self.text_at(style, x, text);
+    }
+
+    fn text_at(&mut self, style: TextStyle, x: f32, text: &str) {
+        self.current
+            .extend_from_slice(format!("BT /{} {} Tf {:.1} {:.1} Td (", style.font(), style.font_size(), x, self.cursor_y).as_bytes());
+        self.current.extend_from_slice(&encode_text(text));
+        self.current.extend_from_slice(b") Tj ET\n");
+    }
+
+    /// A thin horizontal line at the cursor
+    fn rule(&mut self, from_x: f32, to_x: f32) {
+        self.current
+            .extend_from_slice(format!("0.75 w {:.1} {:.1} m {:.1} {:.1} l S\n", from_x, self.cursor_y, to_x, self.cursor_y).as_bytes());
+    }
+
+    /// Start a new page unless `height` more points fit on this one
+    fn ensure_room(&mut self, height: f32) {
+        if self.cursor_y - height < MARGIN && !self.current.is_empty() {
+            self.pages.push(std::mem::take(&mut self.current));
+            self.cursor_y = PAGE_HEIGHT - MARGIN;
+        }
+    }
+}
+
+impl Default for PdfDocumentWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn line_height(style: TextStyle) -> f32 {
+    style.font_size() * LINE_SPACING
+}
+
+fn chars_per_line(style: TextStyle, indent: f32) -> usize {
+    let width = PAGE_WIDTH - 2.0 * MARGIN - indent;
+    (width / (style.font_size() * AVERAGE_CHAR_WIDTH)).floor().max(1.0) as usize
+}
+
+/// Split text into lines of at most `max_chars`, breaking between words
+/// where possible
+fn wrap(text: &str, max_chars: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        while word.len() > max_chars {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            lines.push(word.drain(..max_chars).collect());
+        }
+        let word: String = word.into_iter().collect();
+        if word.is_empty() {
+            continue;
+        }
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > max_chars {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Encode text as the contents of a PDF string in WinAnsi
+fn encode_text(text: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        let byte = match c {
+            '(' | ')' | '\\' => {
+                bytes.push(b'\\');
+                c as u8
+            }
+            ' '..='~' => c as u8,
+            '\u{A0}'..='\u{FF}' => c as u32 as u8,
+            '€' => 0x80,
+            '‘' => 0x91,
+            '’' => 0x92,
+            '“' => 0x93,
+            '”' => 0x94,
+            '•' => 0x95,
+            '–' => 0x96,
+            '—' => 0x97,
+            c if c.is_control() => continue,
+            _ => b'?',
+        };
+        bytes.push(byte);
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_breaks_between_words() {
+        assert_eq!(wrap("one two three", 7), vec!["one two", "three"]);
+        assert_eq!(wrap("  spaced   out  ", 20), vec!["spaced out"]);
+        assert_eq!(wrap("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert!(wrap("", 10).is_empty());
+    }
+
+    #[test]
+    fn test_text_is_escaped_and_encoded_as_win_ansi() {
+        assert_eq!(encode_text("(a\\b)"), b"\\(a\\\\b\\)".to_vec());
+        assert_eq!(encode_text("€5 · £2"), vec![0x80, b'5', b' ', 0xB7, b' ', 0xA3, b'2']);
+        assert_eq!(encode_text("🍦\n"), b"?".to_vec());
+    }
+
+    #[test]
+    fn test_finished_document_has_valid_cross_references() {
+        let mut writer = PdfDocumentWriter::new();
+        writer.title("Allowance Agreement");
+        for _ in 0..50 {
+            writer.paragraph("A line of text that goes on for a while so the page fills up.");
+        }
+        writer.bullet("A list item");
+        writer.signature_line("Parent");
+        let pdf = writer.finish();
+
+        assert!(pdf.starts_with(b"%PDF-1.4\n"));
+        assert!(pdf.ends_with(b"%%EOF\n"));
+        assert!(String::from_utf8_lossy(&pdf).contains("/Count 2 "), "50 paragraphs should need a second page");
+
+        // Every xref entry points at the start of its object
+        let xref_start = pdf.windows(5).rposition(|window| window == b"xref\n").unwrap();
+        let tail = String::from_utf8(pdf[xref_start..].to_vec()).unwrap();
+        let entries: Vec<usize> = tail
+            .lines()
+            .skip(3)
+            .take_while(|line| line.ends_with(" n "))
+            .map(|line| line[..10].parse().unwrap())
+            .collect();
+        assert_eq!(entries.len(), 4 + 2 * 2);
+        for (i, offset) in entries.iter().enumerate() {
+            assert!(pdf[*offset..].starts_with(format!("{} 0 obj", i + 1).as_bytes()));
+        }
+        let startxref: usize = tail.lines().rev().nth(1).unwrap().parse().unwrap();
+        assert_eq!(startxref, xref_start);
+    }
+}
@@ -0,0 +1,211 @@
+//! # Secret Storage
+//!
+//! Backends for small secrets such as the SMTP password and the parental
+//! control answer, so they don't have to sit in plain-text config files.
+//!
+//! - [`KeychainSecretStore`] uses the OS credential store (macOS Keychain,
+//!   Windows Credential Manager, Secret Service on Linux).
+//! - [`EncryptedFileSecretStore`] is the fallback when no keychain is
+//!   available: secrets are kept in `secrets.enc`, sealed with
+//!   XChaCha20-Poly1305 under a random key in `secrets.key` (readable only by
+//!   the current user). It keeps secrets out of config files and synced data
+//!   folders, but is only as safe as the user's account.
+
+use anyhow::Result;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::OsRng;
+use log::info;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use super::csv::encryption::{open, seal, KEY_LEN};
+
+/// Service name secrets are filed under in the OS keychain
+pub const KEYCHAIN_SERVICE_NAME: &str = "allowance-tracker";
+
+const SECRETS_FILE_NAME: &str = "secrets.enc";
+const KEY_FILE_NAME: &str = "secrets.key";
+
+/// A place to keep named secrets
+pub trait SecretStore: Send + Sync {
+    /// Look up a secret; `None` if it has never been stored
+    fn get(&self, name: &str) -> Result<Option<String>>;
+
+    /// Store or replace a secret
+    fn set(&self, name: &str, value: &str) -> Result<()>;
+
+    /// Remove a secret; removing a missing secret is not an error
+    fn delete(&self, name: &str) -> Result<()>;
+
+    /// Short description of where secrets are kept, for logs and settings
+    fn backend_name(&self) -> &'static str;
+}
+
+/// Secrets kept in the OS keychain
+pub struct KeychainSecretStore;
+
+impl KeychainSecretStore {
+    /// Use the keychain if this machine has a working one
+    pub fn probe() -> Option<Self> {
+        let entry = keyring::Entry::new(KEYCHAIN_SERVICE_NAME, "availability-probe").ok()?;
+        match entry.get_password() {
+            Ok(_) | Err(keyring::Error::NoEntry) => Some(Self),
+            Err(e) => {
+                info!("🔑 SECRETS: OS keychain unavailable: {}", e);
+                None
+            }
+        }
+    }
+
+    fn entry(name: &str) -> Result<keyring::Entry> {
+        Ok(keyring::Entry::new(KEYCHAIN_SERVICE_NAME, name)?)
+    }
+}
+
+impl SecretStore for KeychainSecretStore {
+    fn get(&self, name: &str) -> Result<Option<String>> {
+        match Self::entry(name)?.get_password() {
+            Ok(value) => Ok(Some(value)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn set(&self, name: &str, value: &str) -> Result<()> {
+        Ok(Self::entry(name)?.set_password(value)?)
+    }
+
+    fn delete(&self, name: &str) -> Result<()> {
+        match Self::entry(name)?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn backend_name(&self) -> &'static str {
+        "OS keychain"
+    }
+}
+
+/// Secrets kept in an encrypted file, for machines without a keychain
+pub struct EncryptedFileSecretStore {
+    directory: PathBuf,
+    /// Serialises read-modify-write of the secrets file
+    write_lock: Mutex<()>,
+}
+
+impl EncryptedFileSecretStore {
+    /// Keep secrets in `directory`, creating it if needed
+    pub fn new<P: AsRef<Path>>(directory: P) -> Result<Self> {
+        let directory = directory.as_ref().to_path_buf();
+        fs::create_dir_all(&directory)?;
+        Ok(Self {
+            directory,
+            write_lock: Mutex::new(()),
+        })
+    }
+
+    fn load_key(&self) -> Result<[u8; KEY_LEN]> {
+        let key_path = self.directory.join(KEY_FILE_NAME);
+        if key_path.exists() {
+            let bytes = fs::read(&key_path)?;
+            return bytes
+                .try_into()
+                .map_err(|_| anyhow::anyhow!("Secrets key file {} is damaged", key_path.display()));
+        }
+
+        let mut key = [0u8; KEY_LEN];
+        OsRng.fill_bytes(&mut key);
+        write_private(&key_path, &key)?;
+        info!("🔑 SECRETS: Created secrets key at {}", key_path.display());
+        Ok(key)
+    }
+
+    fn load(&self) -> Result<BTreeMap<String, String>> {
+        let secrets_path = self.directory.join(SECRETS_FILE_NAME);
+        if !secrets_path.exists() {
+            return Ok(BTreeMap::new());
+        }
+        let sealed = fs::read(&secrets_path)?;
+        let plaintext = open(&self.load_key()?, &sealed)
+            .ok_or_else(|| anyhow::anyhow!("Secrets file {} is damaged or its key has changed", secrets_path.display()))?;
+        Ok(serde_yaml::from_slice(&plaintext)?)
+    }
+
+    fn save(&self, secrets: &BTreeMap<String, String>) -> Result<()> {
+        let sealed = seal(&self.load_key()?, serde_yaml::to_string(secrets)?.as_bytes())?;
+        write_private(&self.directory.join(SECRETS_FILE_NAME), &sealed)
+    }
+}
+
+impl SecretStore for EncryptedFileSecretStore {
+    fn get(&self, name: &str) -> Result<Option<String>> {
+        Ok(self.load()?.remove(name))
+    }
+
+    fn set(&self, name: &str, value: &str) -> Result<()> {
+        let _guard = self.write_lock.lock().unwrap();
+        let mut secrets = self.load()?;
+        secrets.insert(name.to_string(), value.to_string());
+        self.save(&secrets)
+    }
+
+    fn delete(&self, name: &str) -> Result<()> {
+        let _guard = self.write_lock.lock().unwrap();
+        let mut secrets = self.load()?;
+        if secrets.remove(name).is_some() {
+            self.save(&secrets)?;
+        }
+        Ok(())
+    }
+
+    fn backend_name(&self) -> &'static str {
+        "encrypted file"
+    }
+}
+
+/// Atomically write a file only the current user can read
+fn write_private(path: &Path, contents: &[u8]) -> Result<()> {
+    // Use atomic write pattern: write to temp file, then rename
+    let temp_path = path.with_extension("tmp");
+    fs::write(&temp_path, contents)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&temp_path, fs::Permissions::from_mode(0o600))?;
+    }
+    fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_encrypted_file_store_round_trip() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let store = EncryptedFileSecretStore::new(temp_dir.path())?;
+
+        assert_eq!(store.get("smtp_password")?, None);
+        store.set("smtp_password", "hunter2")?;
+        store.set("parental_control_answer", "ice cold")?;
+
+        // Nothing is readable on disk
+        let on_disk = fs::read(temp_dir.path().join(SECRETS_FILE_NAME))?;
+        assert!(!String::from_utf8_lossy(&on_disk).contains("hunter2"));
+
+        // A new store over the same directory sees the same secrets
+        let reopened = EncryptedFileSecretStore::new(temp_dir.path())?;
+        assert_eq!(reopened.get("smtp_password")?.as_deref(), Some("hunter2"));
+
+        reopened.delete("smtp_password")?;
+        reopened.delete("smtp_password")?;
+        assert_eq!(store.get("smtp_password")?, None);
+        assert_eq!(store.get("parental_control_answer")?.as_deref(), Some("ice cold"));
+        Ok(())
+    }
+}
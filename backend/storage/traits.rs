@@ -9,6 +9,19 @@ use crate::backend::domain::models::child::Child as DomainChild;
 use crate::backend::domain::models::transaction::Transaction as DomainTransaction;
 use crate::backend::domain::models::allowance::AllowanceConfig as DomainAllowanceConfig;
 use crate::backend::domain::models::parental_control_attempt::ParentalControlAttempt as DomainParentalControlAttempt;
+use crate::backend::domain::models::child_settings::ChildSettings as DomainChildSettings;
+use crate::backend::domain::models::audit_entry::AuditEntry as DomainAuditEntry;
+use crate::backend::domain::models::savings_allocation::SavingsAllocation as DomainSavingsAllocation;
+use crate::backend::domain::models::loan::Loan as DomainLoan;
+use crate::backend::domain::models::allowance_advance::AllowanceAdvance as DomainAllowanceAdvance;
+use crate::backend::domain::models::planned_purchase::PlannedPurchase as DomainPlannedPurchase;
+use crate::backend::domain::models::quick_pick::QuickPick as DomainQuickPick;
+use crate::backend::domain::models::budget_target::BudgetTarget as DomainBudgetTarget;
+use crate::backend::domain::models::transaction_reversal::TransactionReversal as DomainTransactionReversal;
+use crate::backend::domain::models::child_snapshot::ChildSnapshot as DomainChildSnapshot;
+use crate::backend::domain::models::celebration::{Celebration as DomainCelebration, ReachedMilestone};
+use crate::backend::domain::models::period_lock::PeriodLock as DomainPeriodLock;
+use crate::backend::domain::models::transaction_template::TransactionTemplate as DomainTransactionTemplate;
 
 /// Trait defining the interface for transaction storage operations
 /// 
@@ -35,6 +48,10 @@ pub trait TransactionStorage: Send + Sync {
     /// Update an existing transaction
     fn update_transaction(&self, transaction: &DomainTransaction) -> Result<()>;
     
+    /// Set the category of several of a child's transactions in one write;
+    /// `None` clears it. Returns how many were found and updated.
+    fn update_transaction_categories(&self, child_id: &str, updates: &[(String, Option<String>)]) -> Result<u32>;
+    
     /// Delete a single transaction
     /// Returns true if the transaction was found and deleted, false otherwise
     fn delete_transaction(&self, child_id: &str, transaction_id: &str) -> Result<bool>;
@@ -123,6 +140,158 @@ pub trait ParentalControlStorage: Send + Sync {
     fn get_all_parental_control_attempts(&self, limit: Option<u32>) -> Result<Vec<DomainParentalControlAttempt>>;
 }
 
+/// Trait defining the interface for per-child settings storage operations
+pub trait ChildSettingsStorage: Send + Sync {
+    /// Retrieve settings for a specific child, if any have been saved
+    fn get_child_settings(&self, child_id: &str) -> Result<Option<DomainChildSettings>>;
+    
+    /// Store (create or replace) settings for a child
+    fn store_child_settings(&self, settings: &DomainChildSettings) -> Result<()>;
+}
+
+/// Trait defining the interface for audit log storage operations
+pub trait AuditLogStorage: Send + Sync {
+    /// Append an entry to a child's audit log
+    fn append_audit_entry(&self, child_id: &str, entry: &DomainAuditEntry) -> Result<()>;
+    
+    /// List a child's audit entries, most recent first, with optional limit
+    fn list_audit_entries(&self, child_id: &str, limit: Option<u32>) -> Result<Vec<DomainAuditEntry>>;
+}
+
+/// Trait defining the interface for automatic savings allocation storage operations
+pub trait SavingsAllocationStorage: Send + Sync {
+    /// Store (create or replace) an allocation for a child
+    fn store_allocation(&self, child_id: &str, allocation: &DomainSavingsAllocation) -> Result<()>;
+    
+    /// List all allocations for a child in chronological order
+    fn list_allocations(&self, child_id: &str) -> Result<Vec<DomainSavingsAllocation>>;
+    
+    /// Delete allocations linked to the given transactions, returning how many were removed
+    fn delete_allocations_for_transactions(&self, child_id: &str, transaction_ids: &[String]) -> Result<u32>;
+}
+
+/// Trait defining the interface for parent/child loan storage operations
+pub trait LoanStorage: Send + Sync {
+    /// Store (create or replace) a loan
+    fn store_loan(&self, loan: &DomainLoan) -> Result<()>;
+    
+    /// List all loans for a child in chronological order
+    fn list_loans(&self, child_id: &str) -> Result<Vec<DomainLoan>>;
+    
+    /// Remove loans opened by, and settlements recorded by, the given
+    /// transactions, returning how many links were removed
+    fn delete_loan_links_for_transactions(&self, child_id: &str, transaction_ids: &[String]) -> Result<u32>;
+}
+
+/// Trait defining the interface for allowance advance storage operations
+pub trait AllowanceAdvanceStorage: Send + Sync {
+    /// Store (create or replace) an advance
+    fn store_advance(&self, advance: &DomainAllowanceAdvance) -> Result<()>;
+    
+    /// List all advances for a child in chronological order
+    fn list_advances(&self, child_id: &str) -> Result<Vec<DomainAllowanceAdvance>>;
+    
+    /// Remove advances paid by, and installments repaid by, the given
+    /// transactions, returning how many links were removed
+    fn delete_advance_links_for_transactions(&self, child_id: &str, transaction_ids: &[String]) -> Result<u32>;
+}
+
+/// Trait defining the interface for planned purchase storage operations
+pub trait PlannedPurchaseStorage: Send + Sync {
+    /// Store (create or replace) a planned purchase
+    fn store_planned_purchase(&self, purchase: &DomainPlannedPurchase) -> Result<()>;
+    
+    /// List all planned purchases for a child, ordered by planned date
+    fn list_planned_purchases(&self, child_id: &str) -> Result<Vec<DomainPlannedPurchase>>;
+}
+
+/// Trait defining the interface for quick-pick description storage operations
+pub trait QuickPickStorage: Send + Sync {
+    /// Store (create or replace) a quick pick
+    fn store_quick_pick(&self, quick_pick: &DomainQuickPick) -> Result<()>;
+    
+    /// List a child's quick picks in the order they were added
+    fn list_quick_picks(&self, child_id: &str) -> Result<Vec<DomainQuickPick>>;
+    
+    /// Delete a quick pick, returning whether it existed
+    fn delete_quick_pick(&self, child_id: &str, quick_pick_id: &str) -> Result<bool>;
+}
+
+/// Trait defining the interface for a child's saved transaction templates
+pub trait TransactionTemplateStorage: Send + Sync {
+    /// Store (create or replace) a template
+    fn store_template(&self, template: &DomainTransactionTemplate) -> Result<()>;
+    
+    /// List a child's templates in the order they were added
+    fn list_templates(&self, child_id: &str) -> Result<Vec<DomainTransactionTemplate>>;
+    
+    /// Delete a template, returning whether it existed
+    fn delete_template(&self, child_id: &str, template_id: &str) -> Result<bool>;
+}
+
+/// Trait defining the interface for a child's monthly spending targets
+pub trait BudgetTargetStorage: Send + Sync {
+    /// Store a target, replacing any target for the same category
+    fn store_budget_target(&self, target: &DomainBudgetTarget) -> Result<()>;
+    
+    /// List a child's targets in the order they were added
+    fn list_budget_targets(&self, child_id: &str) -> Result<Vec<DomainBudgetTarget>>;
+    
+    /// Delete the target for a category, returning whether it existed
+    fn delete_budget_target(&self, child_id: &str, category: &str) -> Result<bool>;
+}
+
+/// Trait defining the interface for links between reversed transactions and their offsets
+pub trait TransactionReversalStorage: Send + Sync {
+    /// Store a new reversal link
+    fn store_reversal(&self, reversal: &DomainTransactionReversal) -> Result<()>;
+    
+    /// List a child's reversals in chronological order
+    fn list_reversals(&self, child_id: &str) -> Result<Vec<DomainTransactionReversal>>;
+    
+    /// Remove links involving any of the given transactions, returning how many were removed
+    fn delete_reversal_links_for_transactions(&self, child_id: &str, transaction_ids: &[String]) -> Result<u32>;
+}
+
+/// Trait defining the interface for the per-child startup snapshot
+pub trait ChildSnapshotStorage: Send + Sync {
+    /// Load a child's snapshot, or `None` if there isn't a usable one
+    fn load_snapshot(&self, child_id: &str) -> Result<Option<DomainChildSnapshot>>;
+    
+    /// Replace a child's snapshot
+    fn store_snapshot(&self, snapshot: &DomainChildSnapshot) -> Result<()>;
+}
+
+/// Trait defining the interface for celebration storage operations
+pub trait CelebrationStorage: Send + Sync {
+    /// Record a celebration for a child to pick up
+    fn record_celebration(&self, celebration: &DomainCelebration) -> Result<()>;
+    
+    /// A child's unacknowledged celebrations, oldest first
+    fn list_pending_celebrations(&self, child_id: &str) -> Result<Vec<DomainCelebration>>;
+    
+    /// Remove the given celebrations from the pending ones, returning how many were removed
+    fn acknowledge_celebrations(&self, child_id: &str, celebration_ids: &[String]) -> Result<u32>;
+    
+    /// Add balance milestones to a child's milestone history
+    fn record_reached_milestones(&self, child_id: &str, milestones: &[ReachedMilestone]) -> Result<()>;
+    
+    /// Every balance milestone a child has reached, oldest first
+    fn list_reached_milestones(&self, child_id: &str) -> Result<Vec<ReachedMilestone>>;
+}
+
+/// Trait defining the interface for a child's closed period
+pub trait PeriodLockStorage: Send + Sync {
+    /// How far a child's transactions are closed, if they are
+    fn get_period_lock(&self, child_id: &str) -> Result<Option<DomainPeriodLock>>;
+    
+    /// Close a child's transactions through the lock's date, replacing any earlier lock
+    fn store_period_lock(&self, lock: &DomainPeriodLock) -> Result<()>;
+    
+    /// Reopen everything, returning whether anything was closed
+    fn clear_period_lock(&self, child_id: &str) -> Result<bool>;
+}
+
 
 
 /// Trait defining the interface for storage connections
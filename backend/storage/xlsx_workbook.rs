@@ -0,0 +1,429 @@
+//! # Xlsx Workbook Module
+//!
+//! Minimal Excel workbook support for spreadsheet exports. A workbook is a
+//! zip of SpreadsheetML parts, written here with [`ZipArchiveWriter`] so no
+//! spreadsheet dependency is needed.
+//!
+//! Only what the exports use is supported: text, numbers, dates and amounts
+//! with a currency symbol, a bold header row that stays in view while
+//! scrolling, and fixed column widths. Text is written inline in each cell
+//! rather than in a shared string table, which Excel, LibreOffice and
+//! Numbers all read.
+
+use anyhow::{anyhow, Result};
+use chrono::{NaiveDate, NaiveDateTime};
+use std::collections::HashSet;
+
+use super::zip_archive::ZipArchiveWriter;
+
+const SPREADSHEET_NS: &str = "http://schemas.openxmlformats.org/spreadsheetml/2006/main";
+const RELATIONSHIPS_NS: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+const PACKAGE_RELATIONSHIPS_NS: &str = "http://schemas.openxmlformats.org/package/2006/relationships";
+
+/// Excel limits sheet names to 31 characters
+const MAX_SHEET_NAME_LEN: usize = 31;
+/// First id Excel leaves free for custom number formats
+const FIRST_CUSTOM_NUM_FMT: usize = 164;
+/// Built-in number format for dates in the reader's short date style
+const DATE_NUM_FMT: usize = 14;
+
+/// Cell styles, by their index in `styles.xml`
+const STYLE_NORMAL: usize = 0;
+const STYLE_HEADER: usize = 1;
+const STYLE_DATE: usize = 2;
+/// Currency styles follow, one per symbol in first-use order
+const FIRST_CURRENCY_STYLE: usize = 3;
+
+/// One cell's value
+#[derive(Debug, Clone, PartialEq)]
+pub enum XlsxCell {
+    Empty,
+    Text(String),
+    Number(f64),
+    /// An amount shown with a currency symbol such as `$` or `€`
+    Money(f64, String),
+    Date(NaiveDate),
+}
+
+/// A row, and whether it is drawn as a header
+struct XlsxRow {
+    header: bool,
+    cells: Vec<XlsxCell>,
+}
+
+/// One worksheet whose first row is a frozen header
+pub struct XlsxSheet {
+    name: String,
+    column_widths: Vec<f64>,
+    rows: Vec<XlsxRow>,
+}
+
+impl XlsxSheet {
+    /// Start a sheet with a frozen header row; `column_widths` are in characters
+    pub fn new(name: &str, header: &[&str], column_widths: &[f64]) -> Self {
+        let mut sheet = Self {
+            name: name.to_string(),
+            column_widths: column_widths.to_vec(),
+            rows: Vec::new(),
+        };
+        sheet.add_header_row(header);
+        sheet
+    }
+
+    /// Add a row of values
+    pub fn add_row(&mut self, cells: Vec<XlsxCell>) {
+        self.rows.push(XlsxRow { header: false, cells });
+    }
+
+    /// Add a row of bold titles, e.g. to start a second table further down
+    pub fn add_header_row(&mut self, titles: &[&str]) {
+        let cells = titles.iter().map(|title| XlsxCell::Text(title.to_string())).collect();
+        self.rows.push(XlsxRow { header: true, cells });
+    }
+
+    /// Add an empty row
+    pub fn add_blank_row(&mut self) {
+        self.add_row(Vec::new());
+    }
+}
+
+/// Collects sheets and writes them as an `.xlsx` file
+pub struct XlsxWorkbookWriter {
+    sheets: Vec<XlsxSheet>,
+    sheet_names: HashSet<String>,
+    currency_symbols: Vec<String>,
+}
+
+impl XlsxWorkbookWriter {
+    /// Start an empty workbook
+    pub fn new() -> Self {
+        Self {
+            sheets: Vec::new(),
+            sheet_names: HashSet::new(),
+            currency_symbols: Vec::new(),
+        }
+    }
+
+    /// Add a sheet after the others, adjusting its name to one Excel accepts
+    /// and no other sheet in this workbook already has
+    pub fn add_sheet(&mut self, mut sheet: XlsxSheet) {
+        sheet.name = self.unique_sheet_name(&sheet.name);
+        self.sheet_names.insert(sheet.name.to_lowercase());
+        for row in &sheet.rows {
+            for cell in &row.cells {
+                if let XlsxCell::Money(_, symbol) = cell {
+                    if !self.currency_symbols.contains(symbol) {
+                        self.currency_symbols.push(symbol.clone());
+                    }
+                }
+            }
+        }
+        self.sheets.push(sheet);
+    }
+
+    /// Write the workbook, with every part stamped with `modified`
+    pub fn finish(self, modified: NaiveDateTime) -> Result<Vec<u8>> {
+        if self.sheets.is_empty() {
+            return Err(anyhow!("A workbook needs at least one sheet"));
+        }
+
+        let mut archive = ZipArchiveWriter::new(modified);
+        archive.add_file("[Content_Types].xml", self.content_types().as_bytes())?;
+        archive.add_file("_rels/.rels", root_relationships().as_bytes())?;
+        archive.add_file("xl/workbook.xml", self.workbook().as_bytes())?;
+        archive.add_file("xl/_rels/workbook.xml.rels", self.workbook_relationships().as_bytes())?;
+        archive.add_file("xl/styles.xml", self.styles().as_bytes())?;
+        for (index, sheet) in self.sheets.iter().enumerate() {
+            let name = format!("xl/worksheets/sheet{}.xml", index + 1);
+            archive.add_file(&name, self.worksheet(sheet).as_bytes())?;
+        }
+        archive.finish()
+    }
+
+    fn unique_sheet_name(&self, requested: &str) -> String {
+        let cleaned: String = requested
+            .chars()
+            .map(|c| if matches!(c, '[' | ']' | ':' | '*' | '?' | '/' | '\\') || c.is_control() { '_' } else { c })
+            .collect();
+        let cleaned = cleaned.trim().trim_matches('\'');
+        let base: String = if cleaned.is_empty() { "Sheet" } else { cleaned }
+            .chars()
+            .take(MAX_SHEET_NAME_LEN)
+            .collect();
+
+        let mut name = base.clone();
+        let mut counter = 2;
+        while self.sheet_names.contains(&name.to_lowercase()) {
+            let suffix = format!(" ({})", counter);
+            let keep = MAX_SHEET_NAME_LEN - suffix.chars().count();
+            name = format!("{}{}", base.chars().take(keep).collect::<String>(), suffix);
+            counter += 1;
+        }
+        name
+    }
+
+    fn content_types(&self) -> String {
+        let mut xml = String::from(concat!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+            r#"<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">"#,
+            r#"<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>"#,
+            r#"<Default Extension="xml" ContentType="application/xml"/>"#,
+            r#"<Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>"#,
+            r#"<Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/>"#,
+        ));
+        for index in 1..=self.sheets.len() {
+            xml.push_str(&format!(
+                r#"<Override PartName="/xl/worksheets/sheet{}.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#,
+                index
+            ));
+        }
+        xml.push_str("</Types>");
+        xml
+    }
+
+    fn workbook(&self) -> String {
+        let mut xml = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><workbook xmlns="{}" xmlns:r="{}"><sheets>"#,
+            SPREADSHEET_NS, RELATIONSHIPS_NS
+        );
+        for (index, sheet) in self.sheets.iter().enumerate() {
+            xml.push_str(&format!(
+                r#"<sheet name="{}" sheetId="{}" r:id="rId{}"/>"#,
+                escape_xml(&sheet.name),
+                index + 1,
+                index + 1
+            ));
+        }
+        xml.push_str("</sheets></workbook>");
+        xml
+    }
+
+    fn workbook_relationships(&self) -> String {
+        let mut xml = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="{}">"#,
+            PACKAGE_RELATIONSHIPS_NS
+        );
+        for index in 1..=self.sheets.len() {
+            xml.push_str(&format!(
+                r#"<Relationship Id="rId{}" Type="{}/worksheet" Target="worksheets/sheet{}.xml"/>"#,
+                index, RELATIONSHIPS_NS, index
+            ));
+        }
+        xml.push_str(&format!(
+            r#"<Relationship Id="rId{}" Type="{}/styles" Target="styles.xml"/>"#,
+            self.sheets.len() + 1,
+            RELATIONSHIPS_NS
+        ));
+        xml.push_str("</Relationships>");
+        xml
+    }
+
+    fn styles(&self) -> String {
+        let mut xml = format!(r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><styleSheet xmlns="{}">"#, SPREADSHEET_NS);
+        if !self.currency_symbols.is_empty() {
+            xml.push_str(&format!(r#"<numFmts count="{}">"#, self.currency_symbols.len()));
+            for (index, symbol) in self.currency_symbols.iter().enumerate() {
+                let symbol = symbol.replace('"', "");
+                let code = format!("\"{symbol}\"#,##0.00;-\"{symbol}\"#,##0.00");
+                xml.push_str(&format!(
+                    r#"<numFmt numFmtId="{}" formatCode="{}"/>"#,
+                    FIRST_CUSTOM_NUM_FMT + index,
+                    escape_xml(&code)
+                ));
+            }
+            xml.push_str("</numFmts>");
+        }
+        xml.push_str(concat!(
+            r#"<fonts count="2"><font><sz val="11"/><name val="Calibri"/></font><font><b/><sz val="11"/><name val="Calibri"/></font></fonts>"#,
+            r#"<fills count="3"><fill><patternFill patternType="none"/></fill><fill><patternFill patternType="gray125"/></fill>"#,
+            r#"<fill><patternFill patternType="solid"><fgColor rgb="FFDDEBF7"/><bgColor indexed="64"/></patternFill></fill></fills>"#,
+            r#"<borders count="1"><border><left/><right/><top/><bottom/><diagonal/></border></borders>"#,
+            r#"<cellStyleXfs count="1"><xf numFmtId="0" fontId="0" fillId="0" borderId="0"/></cellStyleXfs>"#,
+        ));
+        xml.push_str(&format!(r#"<cellXfs count="{}">"#, FIRST_CURRENCY_STYLE + self.currency_symbols.len()));
+        xml.push_str(r#"<xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/>"#);
+        xml.push_str(r#"<xf numFmtId="0" fontId="1" fillId="2" borderId="0" xfId="0" applyFont="1" applyFill="1"/>"#);
+        xml.push_str(&format!(
+            r#"<xf numFmtId="{}" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/>"#,
+            DATE_NUM_FMT
+        ));
+        for index in 0..self.currency_symbols.len() {
+            xml.push_str(&format!(
+                r#"<xf numFmtId="{}" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/>"#,
+                FIRST_CUSTOM_NUM_FMT + index
+            ));
+        }
+        xml.push_str("</cellXfs>");
+        xml.push_str(r#"<cellStyles count="1"><cellStyle name="Normal" xfId="0" builtinId="0"/></cellStyles></styleSheet>"#);
+        xml
+    }
+
+    fn worksheet(&self, sheet: &XlsxSheet) -> String {
+        let mut xml = format!(
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><worksheet xmlns="{}">"#,
+                r#"<sheetViews><sheetView workbookViewId="0">"#,
+                r#"<pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/>"#,
+                r#"</sheetView></sheetViews><sheetFormatPr defaultRowHeight="15"/>"#,
+            ),
+            SPREADSHEET_NS
+        );
+        if !sheet.column_widths.is_empty() {
+            xml.push_str("<cols>");
+            for (index, width) in sheet.column_widths.iter().enumerate() {
+                xml.push_str(&format!(r#"<col min="{0}" max="{0}" width="{1}" customWidth="1"/>"#, index + 1, width));
+            }
+            xml.push_str("</cols>");
+        }
+
+        xml.push_str("<sheetData>");
+        for (row_index, row) in sheet.rows.iter().enumerate() {
+            let row_number = row_index + 1;
+            xml.push_str(&format!(r#"<row r="{}">"#, row_number));
+            for (column_index, cell) in row.cells.iter().enumerate() {
+                let reference = format!("{}{}", column_name(column_index), row_number);
+                xml.push_str(&self.cell(&reference, cell, row.header));
+            }
+            xml.push_str("</row>");
+        }
+        xml.push_str("</sheetData></worksheet>");
+        xml
+    }
+
+    fn cell(&self, reference: &str, cell: &XlsxCell, header: bool) -> String {
+        let text_style = if header { STYLE_HEADER } else { STYLE_NORMAL };
+        match cell {
+            XlsxCell::Empty => String::new(),
+            XlsxCell::Text(text) => format!(
+                r#"<c r="{}" s="{}" t="inlineStr"><is><t xml:space="preserve">{}</t></is></c>"#,
+                reference,
+                text_style,
+                escape_xml(text)
+            ),
+            XlsxCell::Number(value) => number_cell(reference, text_style, *value),
+            XlsxCell::Money(amount, symbol) => {
+                let position = self.currency_symbols.iter().position(|known| known == symbol).unwrap_or(0);
+                number_cell(reference, FIRST_CURRENCY_STYLE + position, *amount)
+            }
+            XlsxCell::Date(date) => number_cell(reference, STYLE_DATE, excel_date_serial(*date) as f64),
+        }
+    }
+}
+
+impl Default for XlsxWorkbookWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn root_relationships() -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="{}"><Relationship Id="rId1" Type="{}/officeDocument" Target="xl/workbook.xml"/></Relationships>"#,
+        PACKAGE_RELATIONSHIPS_NS, RELATIONSHIPS_NS
+    )
+}
+
+/// A numeric cell; values that aren't finite are left empty
+fn number_cell(reference: &str, style: usize, value: f64) -> String {
+    if value.is_finite() {
+        format!(r#"<c r="{}" s="{}"><v>{}</v></c>"#, reference, style, value)
+    } else {
+        String::new()
+    }
+}
+
+/// Column letters for a zero-based index: A, B, ... Z, AA, AB, ...
+fn column_name(index: usize) -> String {
+    let mut name = Vec::new();
+    let mut remaining = index + 1;
+    while remaining > 0 {
+        let digit = (remaining - 1) % 26;
+        name.push(b'A' + digit as u8);
+        remaining = (remaining - 1) / 26;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap_or_default()
+}
+
+/// Days since Excel's epoch of 1899-12-30
+fn excel_date_serial(date: NaiveDate) -> i64 {
+    let epoch = NaiveDate::from_ymd_opt(1899, 12, 30).expect("valid epoch");
+    (date - epoch).num_days()
+}
+
+/// Escape text for XML, dropping control characters XML can't hold
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::storage::zip_archive::read_zip_entries;
+
+    fn test_time() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 3, 15).unwrap().and_hms_opt(10, 30, 0).unwrap()
+    }
+
+    fn entry(entries: &[(String, Vec<u8>)], name: &str) -> String {
+        let (_, contents) = entries.iter().find(|(entry, _)| entry == name).unwrap();
+        String::from_utf8(contents.clone()).unwrap()
+    }
+
+    #[test]
+    fn test_column_names_and_date_serials() {
+        assert_eq!(column_name(0), "A");
+        assert_eq!(column_name(25), "Z");
+        assert_eq!(column_name(26), "AA");
+        assert_eq!(column_name(27), "AB");
+        assert_eq!(excel_date_serial(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()), 45292);
+    }
+
+    #[test]
+    fn test_workbook_parts_and_cells() {
+        let mut workbook = XlsxWorkbookWriter::new();
+        let mut sheet = XlsxSheet::new("Alice", &["Date", "Description", "Amount"], &[12.0, 30.0, 12.0]);
+        sheet.add_row(vec![
+            XlsxCell::Date(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()),
+            XlsxCell::Text("Stickers & <gum>".to_string()),
+            XlsxCell::Money(-1.25, "€".to_string()),
+        ]);
+        workbook.add_sheet(sheet);
+        workbook.add_sheet(XlsxSheet::new("alice", &["Other"], &[]));
+        workbook.add_sheet(XlsxSheet::new("Why? [Really]: a very long name indeed", &["x"], &[]));
+        let entries = read_zip_entries(&workbook.finish(test_time()).unwrap()).unwrap();
+
+        assert_eq!(entries[0].0, "[Content_Types].xml");
+        let book = entry(&entries, "xl/workbook.xml");
+        assert!(book.contains(r#"<sheet name="Alice" sheetId="1" r:id="rId1"/>"#), "{}", book);
+        assert!(book.contains(r#"<sheet name="alice (2)""#), "{}", book);
+        assert!(book.contains(r#"<sheet name="Why_ _Really__ a very long name""#), "{}", book);
+
+        let styles = entry(&entries, "xl/styles.xml");
+        assert!(styles.contains(r#"formatCode="&quot;€&quot;#,##0.00;-&quot;€&quot;#,##0.00""#), "{}", styles);
+
+        let first = entry(&entries, "xl/worksheets/sheet1.xml");
+        assert!(first.contains(r#"state="frozen""#));
+        assert!(first.contains(r#"<c r="A1" s="1" t="inlineStr"><is><t xml:space="preserve">Date</t></is></c>"#), "{}", first);
+        assert!(first.contains(r#"<c r="A2" s="2"><v>45292</v></c>"#), "{}", first);
+        assert!(first.contains("Stickers &amp; &lt;gum&gt;"), "{}", first);
+        assert!(first.contains(r#"<c r="C2" s="3"><v>-1.25</v></c>"#), "{}", first);
+    }
+
+    #[test]
+    fn test_empty_workbook_rejected() {
+        assert!(XlsxWorkbookWriter::new().finish(test_time()).is_err());
+    }
+}
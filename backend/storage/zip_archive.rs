@@ -0,0 +1,274 @@
+//! # Zip Archive Module
+//!
+//! Minimal zip support for full-data exports. Entries are written with the
+//! "stored" method (no compression): our exports are small text files, and
+//! this keeps the format readable by every unzip tool without pulling in a
+//! compression dependency.
+//!
+//! Only archives that use the stored method can be read back, which covers
+//! everything produced by [`ZipArchiveWriter`].
+
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, NaiveDateTime, Timelike};
+
+const LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+const ZIP_VERSION: u16 = 20;
+const UTF8_NAMES_FLAG: u16 = 0x0800;
+const METHOD_STORED: u16 = 0;
+
+/// Central directory information kept for each written entry
+struct EntryRecord {
+    name: String,
+    crc32: u32,
+    size: u32,
+    offset: u32,
+}
+
+/// Builds a zip archive in memory
+pub struct ZipArchiveWriter {
+    buffer: Vec<u8>,
+    entries: Vec<EntryRecord>,
+    dos_time: u16,
+    dos_date: u16,
+}
+
+impl ZipArchiveWriter {
+    /// Create a new archive whose entries are stamped with `modified`
+    pub fn new(modified: NaiveDateTime) -> Self {
+        let (dos_time, dos_date) = to_dos_datetime(modified);
+        Self {
+            buffer: Vec::new(),
+            entries: Vec::new(),
+            dos_time,
+            dos_date,
+        }
+    }
+
+    /// Add a file to the archive. Use `/` to separate directories in `name`.
+    pub fn add_file(&mut self, name: &str, contents: &[u8]) -> Result<()> {
+        if self.entries.iter().any(|e| e.name == name) {
+            return Err(anyhow!("Duplicate zip entry: {}", name));
+        }
+        if u16::try_from(self.entries.len() + 1).is_err() {
+            return Err(anyhow!("Too many zip entries"));
+        }
+        let name_len = u16::try_from(name.len()).map_err(|_| anyhow!("Zip entry name too long: {}", name))?;
+        let size = u32::try_from(contents.len()).map_err(|_| anyhow!("Zip entry too large: {}", name))?;
+        let offset = u32::try_from(self.buffer.len()).map_err(|_| anyhow!("Zip archive too large"))?;
+        let crc32 = crc32(contents);
+
+        write_u32(&mut self.buffer, LOCAL_FILE_HEADER_SIGNATURE);
+        write_u16(&mut self.buffer, ZIP_VERSION);
+        write_u16(&mut self.buffer, UTF8_NAMES_FLAG);
+        write_u16(&mut self.buffer, METHOD_STORED);
+        write_u16(&mut self.buffer, self.dos_time);
+        write_u16(&mut self.buffer, self.dos_date);
+        write_u32(&mut self.buffer, crc32);
+        write_u32(&mut self.buffer, size); // compressed size
+        write_u32(&mut self.buffer, size); // uncompressed size
+        write_u16(&mut self.buffer, name_len);
+        write_u16(&mut self.buffer, 0); // extra field length
+        self.buffer.extend_from_slice(name.as_bytes());
+        self.buffer.extend_from_slice(contents);
+
+        self.entries.push(EntryRecord {
+            name: name.to_string(),
+            crc32,
+            size,
+            offset,
+        });
+        Ok(())
+    }
+
+    /// Write the central directory and return the finished archive bytes
+    pub fn finish(mut self) -> Result<Vec<u8>> {
+        let central_directory_offset = u32::try_from(self.buffer.len()).map_err(|_| anyhow!("Zip archive too large"))?;
+
+        for entry in &self.entries {
+            write_u32(&mut self.buffer, CENTRAL_DIRECTORY_SIGNATURE);
+            write_u16(&mut self.buffer, ZIP_VERSION); // version made by
+            write_u16(&mut self.buffer, ZIP_VERSION); // version needed
+            write_u16(&mut self.buffer, UTF8_NAMES_FLAG);
+            write_u16(&mut self.buffer, METHOD_STORED);
+            write_u16(&mut self.buffer, self.dos_time);
+            write_u16(&mut self.buffer, self.dos_date);
+            write_u32(&mut self.buffer, entry.crc32);
+            write_u32(&mut self.buffer, entry.size);
+            write_u32(&mut self.buffer, entry.size);
+            let name_len = u16::try_from(entry.name.len()).map_err(|_| anyhow!("Zip entry name too long: {}", entry.name))?;
+            write_u16(&mut self.buffer, name_len);
+            write_u16(&mut self.buffer, 0); // extra field length
+            write_u16(&mut self.buffer, 0); // comment length
+            write_u16(&mut self.buffer, 0); // disk number start
+            write_u16(&mut self.buffer, 0); // internal attributes
+            write_u32(&mut self.buffer, 0); // external attributes
+            write_u32(&mut self.buffer, entry.offset);
+            self.buffer.extend_from_slice(entry.name.as_bytes());
+        }
+
+        let central_directory_size = u32::try_from(self.buffer.len())
+            .map_err(|_| anyhow!("Zip archive too large"))?
+            - central_directory_offset;
+        let entry_count = u16::try_from(self.entries.len()).map_err(|_| anyhow!("Too many zip entries"))?;
+
+        write_u32(&mut self.buffer, END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        write_u16(&mut self.buffer, 0); // this disk
+        write_u16(&mut self.buffer, 0); // disk with central directory
+        write_u16(&mut self.buffer, entry_count);
+        write_u16(&mut self.buffer, entry_count);
+        write_u32(&mut self.buffer, central_directory_size);
+        write_u32(&mut self.buffer, central_directory_offset);
+        write_u16(&mut self.buffer, 0); // comment length
+
+        Ok(self.buffer)
+    }
+}
+
+/// Read all entries from a stored-method zip archive as `(name, contents)` pairs
+pub fn read_zip_entries(bytes: &[u8]) -> Result<Vec<(String, Vec<u8>)>> {
+    let mut entries = Vec::new();
+    let mut pos = 0usize;
+
+    while pos + 4 <= bytes.len() && read_u32(bytes, pos)? == LOCAL_FILE_HEADER_SIGNATURE {
+        let flags = read_u16(bytes, pos + 6)?;
+        let method = read_u16(bytes, pos + 8)?;
+        let expected_crc = read_u32(bytes, pos + 14)?;
+        let size = read_u32(bytes, pos + 18)? as usize;
+        let name_len = read_u16(bytes, pos + 26)? as usize;
+        let extra_len = read_u16(bytes, pos + 28)? as usize;
+
+        if method != METHOD_STORED {
+            return Err(anyhow!("Unsupported zip compression method: {}", method));
+        }
+        if flags & 0x0008 != 0 {
+            return Err(anyhow!("Zip entries with data descriptors are not supported"));
+        }
+
+        let name_start = pos + 30;
+        let data_start = name_start + name_len + extra_len;
+        let data_end = data_start + size;
+        if data_end > bytes.len() {
+            return Err(anyhow!("Truncated zip archive"));
+        }
+
+        let name = String::from_utf8(bytes[name_start..name_start + name_len].to_vec())?;
+        let contents = bytes[data_start..data_end].to_vec();
+        if crc32(&contents) != expected_crc {
+            return Err(anyhow!("CRC mismatch for zip entry: {}", name));
+        }
+
+        entries.push((name, contents));
+        pos = data_end;
+    }
+
+    Ok(entries)
+}
+
+/// Convert a timestamp to MS-DOS time and date fields
+fn to_dos_datetime(dt: NaiveDateTime) -> (u16, u16) {
+    // DOS dates can't represent years before 1980
+    let year = dt.year().clamp(1980, 2107) as u16;
+    let time = ((dt.hour() as u16) << 11) | ((dt.minute() as u16) << 5) | (dt.second() as u16 / 2);
+    let date = ((year - 1980) << 9) | ((dt.month() as u16) << 5) | dt.day() as u16;
+    (time, date)
+}
+
+/// Standard CRC-32 (IEEE 802.3) as used by zip
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn write_u16(buffer: &mut Vec<u8>, value: u16) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_u32(buffer: &mut Vec<u8>, value: u32) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn read_u16(bytes: &[u8], pos: usize) -> Result<u16> {
+    bytes
+        .get(pos..pos + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .ok_or_else(|| anyhow!("Truncated zip archive"))
+}
+
+fn read_u32(bytes: &[u8], pos: usize) -> Result<u32> {
+    bytes
+        .get(pos..pos + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or_else(|| anyhow!("Truncated zip archive"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_time() -> NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(2024, 3, 15)
+            .unwrap()
+            .and_hms_opt(10, 30, 20)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_crc32_known_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn test_round_trip_entries() {
+        let mut writer = ZipArchiveWriter::new(test_time());
+        writer.add_file("manifest.yaml", b"children: []\n").unwrap();
+        writer.add_file("children/alice/transactions.csv", "id,amount\nin-1,5\n".as_bytes()).unwrap();
+        let bytes = writer.finish().unwrap();
+
+        // Archive starts with a local header and ends with the end-of-central-directory record
+        assert_eq!(&bytes[0..4], &LOCAL_FILE_HEADER_SIGNATURE.to_le_bytes());
+        assert_eq!(&bytes[bytes.len() - 22..bytes.len() - 18], &END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+
+        let entries = read_zip_entries(&bytes).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].0, "manifest.yaml");
+        assert_eq!(entries[0].1, b"children: []\n");
+        assert_eq!(entries[1].0, "children/alice/transactions.csv");
+    }
+
+    #[test]
+    fn test_duplicate_entry_rejected() {
+        let mut writer = ZipArchiveWriter::new(test_time());
+        writer.add_file("a.txt", b"a").unwrap();
+        assert!(writer.add_file("a.txt", b"b").is_err());
+    }
+
+    #[test]
+    fn test_overlong_entry_name_rejected() {
+        let mut writer = ZipArchiveWriter::new(test_time());
+        let name = "a".repeat(usize::from(u16::MAX) + 1);
+        let error = writer.add_file(&name, b"a").unwrap_err();
+        assert!(error.to_string().starts_with("Zip entry name too long"), "{}", error);
+        assert!(read_zip_entries(&writer.finish().unwrap()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_dos_datetime_encoding() {
+        let (time, date) = to_dos_datetime(test_time());
+        assert_eq!(time >> 11, 10);
+        assert_eq!((time >> 5) & 0x3F, 30);
+        assert_eq!((time & 0x1F) * 2, 20);
+        assert_eq!((date >> 9) + 1980, 2024);
+        assert_eq!((date >> 5) & 0x0F, 3);
+        assert_eq!(date & 0x1F, 15);
+    }
+}
@@ -0,0 +1,108 @@
+//! Benchmarks for storage and calendar generation
+//!
+//! Each group runs against synthetic histories of increasing size, up to the
+//! 50k rows a child who has used the app for years could reach. The CSV
+//! repositories read and rewrite whole files, so these numbers grow with
+//! history size; a regression shows up as a steeper curve.
+//!
+//! Run with `cargo bench -p allowance-tracker-egui --bench storage`.
+
+mod support;
+
+use std::hint::black_box;
+use std::sync::Arc;
+
+use allowance_tracker_egui::backend::domain::commands::transactions::TransactionListQuery;
+use allowance_tracker_egui::backend::domain::CalendarService;
+use allowance_tracker_egui::backend::storage::csv::CsvConnection;
+use chrono::{Datelike, Duration, NaiveDate};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use tempfile::TempDir;
+
+use support::{generate_history, BenchServices};
+
+const HISTORY_SIZES: [usize; 3] = [1_000, 10_000, 50_000];
+
+/// Largest history the full recalculation runs against. Updating balances
+/// looks up each transaction's child by re-reading the file, so the cost is
+/// quadratic: 10k rows already takes close to a minute per iteration.
+const FULL_RECALCULATION_MAX_ROWS: usize = 1_000;
+
+/// Fixed so runs on different days compare like for like
+fn last_day() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 6, 30).unwrap()
+}
+
+/// A data directory holding one child with `rows` transactions
+fn setup(rows: usize) -> (TempDir, BenchServices, String) {
+    let temp_dir = TempDir::new().unwrap();
+    let services = BenchServices::new(Arc::new(CsvConnection::new(temp_dir.path()).unwrap()));
+    let child_id = generate_history(&services, "Bench Child", rows, last_day()).unwrap();
+    (temp_dir, services, child_id)
+}
+
+fn bench_list_transactions(c: &mut Criterion) {
+    let mut group = c.benchmark_group("list_transactions");
+    group.sample_size(20);
+    for rows in HISTORY_SIZES {
+        let (_temp_dir, services, _) = setup(rows);
+        group.bench_with_input(BenchmarkId::new("first_page", rows), &rows, |b, _| {
+            b.iter(|| {
+                let query = TransactionListQuery { child_id: None, after: None, limit: Some(20), start_date: None, end_date: None };
+                black_box(services.transaction_service.list_transactions_domain(query).unwrap())
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_calendar_month(c: &mut Criterion) {
+    let mut group = c.benchmark_group("calendar_month");
+    group.sample_size(20);
+    let calendar_service = CalendarService::new();
+    let day = last_day();
+    for rows in HISTORY_SIZES {
+        let (_temp_dir, services, _) = setup(rows);
+        group.bench_with_input(BenchmarkId::new("latest_month", rows), &rows, |b, _| {
+            b.iter(|| {
+                black_box(
+                    calendar_service
+                        .get_calendar_month_with_transactions(day.month(), day.year() as u32, &services.transaction_service)
+                        .unwrap(),
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_balance_recalculation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("recalculate_balances");
+    group.sample_size(10);
+    for rows in HISTORY_SIZES {
+        let (_temp_dir, services, child_id) = setup(rows);
+        // A backdated entry last month, the common case
+        let from_date = format!("{}T00:00:00-05:00", last_day() - Duration::days(30));
+        group.bench_with_input(BenchmarkId::new("last_month", rows), &rows, |b, _| {
+            b.iter(|| black_box(services.balance_service.recalculate_balances_from_date(&child_id, &from_date).unwrap()))
+        });
+        // Backdated to before the first transaction: every balance changes
+        if rows > FULL_RECALCULATION_MAX_ROWS {
+            continue;
+        }
+        group.bench_with_input(BenchmarkId::new("full_history", rows), &rows, |b, _| {
+            b.iter(|| {
+                black_box(
+                    services
+                        .balance_service
+                        .recalculate_balances_from_date(&child_id, "1970-01-01T00:00:00-05:00")
+                        .unwrap(),
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_list_transactions, bench_calendar_month, bench_balance_recalculation);
+criterion_main!(benches);
@@ -0,0 +1,96 @@
+//! Synthetic transaction histories for benchmarks
+//!
+//! Shared by the criterion benches and the `make_bench_data` example, so a
+//! slow case seen in a bench can be reproduced in the app against the same
+//! data. Histories are deterministic: the same row count always gives the
+//! same file.
+
+#![allow(dead_code)]
+
+use std::sync::Arc;
+
+use allowance_tracker_egui::backend::domain::child_service::ChildService;
+use allowance_tracker_egui::backend::domain::commands::child::{CreateChildCommand, SetActiveChildCommand};
+use allowance_tracker_egui::backend::domain::models::transaction::{Transaction, TransactionType};
+use allowance_tracker_egui::backend::domain::{AllowanceService, BalanceService, TransactionService};
+use allowance_tracker_egui::backend::storage::csv::{CsvConnection, TransactionRepository};
+use anyhow::Result;
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate};
+
+/// Hours between generated transactions, so 50k rows span about 45 years
+const HOURS_BETWEEN_TRANSACTIONS: i64 = 8;
+
+const PURCHASES: [&str; 6] = ["Candy", "Stickers", "Comic book", "Toy car", "Movie ticket", "Game credits"];
+
+/// Services wired to one data directory, like `Backend` but without keychain or email
+pub struct BenchServices {
+    pub connection: Arc<CsvConnection>,
+    pub child_service: ChildService,
+    pub balance_service: BalanceService,
+    pub transaction_service: TransactionService,
+}
+
+impl BenchServices {
+    pub fn new(connection: Arc<CsvConnection>) -> Self {
+        let child_service = ChildService::new(connection.clone());
+        let allowance_service = AllowanceService::new(connection.clone());
+        let balance_service = BalanceService::new(connection.clone());
+        let transaction_service = TransactionService::new(
+            connection.clone(),
+            child_service.clone(),
+            allowance_service,
+            balance_service.clone(),
+        );
+        Self { connection, child_service, balance_service, transaction_service }
+    }
+}
+
+/// Create an active child with `rows` transactions ending on `last_day`; returns the child id
+pub fn generate_history(services: &BenchServices, child_name: &str, rows: usize, last_day: NaiveDate) -> Result<String> {
+    let child = services
+        .child_service
+        .create_child(CreateChildCommand { name: child_name.to_string(), birthdate: "2015-06-01".to_string() })?
+        .child;
+    services.child_service.set_active_child(SetActiveChildCommand { child_id: child.id.clone() })?;
+
+    let transactions = synthetic_transactions(&child.id, rows, last_day);
+    TransactionRepository::new((*services.connection).clone()).write_transactions_by_id(&child.id, &transactions)?;
+    Ok(child.id)
+}
+
+/// A weekly allowance with a few purchases in between, oldest first, with running balances
+pub fn synthetic_transactions(child_id: &str, rows: usize, last_day: NaiveDate) -> Vec<Transaction> {
+    let eastern = FixedOffset::west_opt(5 * 3600).unwrap();
+    let last: DateTime<FixedOffset> = last_day.and_hms_opt(18, 0, 0).unwrap().and_local_timezone(eastern).unwrap();
+    let first = last - Duration::hours(HOURS_BETWEEN_TRANSACTIONS * rows.saturating_sub(1) as i64);
+
+    let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut balance = 0.0;
+    (0..rows)
+        .map(|index| {
+            let date = first + Duration::hours(HOURS_BETWEEN_TRANSACTIONS * index as i64);
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let (description, amount) = if index % 21 == 0 {
+                ("Weekly allowance".to_string(), 10.0)
+            } else if seed.is_multiple_of(4) || balance < 1.0 {
+                ("Chores".to_string(), ((seed >> 8) % 500 + 100) as f64 / 100.0)
+            } else {
+                let cents = ((seed >> 16) % 400 + 25) as f64;
+                (PURCHASES[(seed >> 32) as usize % PURCHASES.len()].to_string(), -(cents / 100.0).min(balance))
+            };
+            balance = ((balance + amount) * 100.0_f64).round() / 100.0;
+            Transaction {
+                id: Transaction::generate_id(amount, date.timestamp_millis() as u64),
+                child_id: child_id.to_string(),
+                date,
+                description,
+                amount,
+                balance,
+                transaction_type: if amount >= 0.0 { TransactionType::Income } else { TransactionType::Expense },
+                actor: None,
+                category: None,
+                icon: None,
+            }
+        })
+        .collect()
+}
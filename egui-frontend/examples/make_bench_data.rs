@@ -0,0 +1,35 @@
+//! Generate a large synthetic history to try the app or a benchmark against
+//!
+//! Usage: `cargo run -p allowance-tracker-egui --release --example make_bench_data -- <data-dir> [rows] [child name]`
+//!
+//! Creates the child in `<data-dir>` (the folder that holds `global_config.yaml`)
+//! with `rows` transactions ending today, using the same generator as the
+//! benches. Point the app at a copy of a real data directory, never the
+//! original.
+
+#[path = "../benches/support/mod.rs"]
+mod support;
+
+use std::sync::Arc;
+
+use allowance_tracker_egui::backend::storage::csv::CsvConnection;
+use anyhow::{Context, Result};
+
+use support::{generate_history, BenchServices};
+
+const DEFAULT_ROWS: usize = 50_000;
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let data_dir = args.next().context("usage: make_bench_data <data-dir> [rows] [child name]")?;
+    let rows = match args.next() {
+        Some(rows) => rows.parse().context("rows must be a whole number")?,
+        None => DEFAULT_ROWS,
+    };
+    let child_name = args.next().unwrap_or_else(|| "Bench Child".to_string());
+
+    let services = BenchServices::new(Arc::new(CsvConnection::new(&data_dir)?));
+    let child_id = generate_history(&services, &child_name, rows, chrono::Local::now().date_naive())?;
+    println!("Created {} ({}) with {} transactions in {}", child_name, child_id, rows, data_dir);
+    Ok(())
+}
@@ -1,18 +0,0 @@
-//! # App Module
-//!
-//! This module serves as the main entry point for the allowance tracker application,
-//! re-exporting all UI components for easy access throughout the codebase.
-//!
-//! ## Purpose:
-//! This module provides a clean, centralized import point for all UI functionality,
-//! allowing other modules to simply `use crate::ui::*` to access all UI components.
-//!
-//! ## Usage:
-//! Other modules can import everything they need with:
-//! ```rust
-//! use crate::ui::*;
-//! ```
-//!
-//! This promotes code organization and reduces import boilerplate.
-
-// Re-export all UI components for easy access
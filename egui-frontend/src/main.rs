@@ -3,9 +3,7 @@ use env_logger;
 use log::{info, error};
 use image::GenericImageView;
 
-mod app;
-
-use allowance_tracker_egui::ui::AllowanceTrackerApp;
+use allowance_tracker_egui::ui::AppRoot;
 
 fn load_app_icon() -> Result<egui::IconData, Box<dyn std::error::Error>> {
     let icon_bytes = include_bytes!("../assets/app-icon.png");
@@ -67,18 +65,9 @@ fn main() -> Result<(), eframe::Error> {
                 info!("Persistence storage available");
             }
 
-            // Initialize the app
-            match AllowanceTrackerApp::new(cc) {
-                Ok(app) => {
-                    info!("Successfully initialized Allowance Tracker app");
-                    Ok(Box::new(app))
-                }
-                Err(e) => {
-                    error!("Failed to initialize app: {}", e);
-                    // Convert anyhow::Error to eframe::Error
-                    Err(format!("Failed to initialize app: {}", e).into())
-                }
-            }
+            // Initialize the app; if the data can't be opened the window shows
+            // a recovery screen instead of closing
+            Ok(Box::new(AppRoot::new(cc)))
         }),
     )
 } 
\ No newline at end of file
@@ -27,7 +27,8 @@
 
 use eframe::egui;
 use crate::ui::app_state::AllowanceTrackerApp;
-use crate::ui::components::styling::{setup_kid_friendly_style, draw_image_background};
+use crate::ui::components::styling::{setup_kid_friendly_style, draw_image_background, Theme};
+use crate::ui::components::data_loading::DataRequest;
 
 impl eframe::App for AllowanceTrackerApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
@@ -40,6 +41,17 @@ impl eframe::App for AllowanceTrackerApp {
             self.interaction.child_dropdown.is_open = false;
         }
         
+        // Jump to the quick-add box
+        if ctx.input_mut(|i| i.consume_shortcut(&crate::ui::components::header::QUICK_ENTRY_SHORTCUT)) {
+            self.form.quick_entry_focus_requested = true;
+        }
+        
+        // Pick up anything the loader thread finished since the last frame
+        self.poll_background_loads();
+        
+        // Tray balance, menu picks and its quick-add window
+        self.update_tray(ctx);
+        
         // Load initial data on first run
         // Note: Use cached current_child here to avoid infinite backend calls during loading
         if self.ui.loading && self.core.current_child.is_none() {
@@ -50,17 +62,26 @@ impl eframe::App for AllowanceTrackerApp {
         // This allows the app to issue allowances without requiring a restart
         // The refresh is throttled using Instant/Duration timing to prevent checking every frame
         self.refresh_allowances();
+        // Keep the tick going while nobody touches the window
+        ctx.request_repaint_after(self.ui.allowance_refresh_interval);
+        
+        // Changes are committed to git in one go once things go quiet
+        self.backend().data_directory_service.commit_idle_changes();
         
         // Clear messages after a delay
         if self.ui.error_message.is_some() {
             ctx.request_repaint_after(std::time::Duration::from_secs(5));
         }
+        self.ui.expire_success_message();
+        if let Some(expires_at) = self.ui.success_expires_at {
+            ctx.request_repaint_after(expires_at.saturating_duration_since(std::time::Instant::now()));
+        }
         
         // Main UI with image background
         egui::CentralPanel::default().show(ctx, |ui| {
-            // Draw image background with blue overlay first
+            // Draw the child's background first
             let full_rect = ui.available_rect_before_wrap();
-            draw_image_background(ui, full_rect);
+            draw_image_background(ui, full_rect, &self.core.current_background);
             
             if self.ui.loading {
                 self.render_loading_screen(ui);
@@ -144,6 +165,18 @@ impl eframe::App for AllowanceTrackerApp {
         
         // Render modals
         self.render_modals(ctx);
+        
+        // The edit window opened from a table row's menu
+        self.render_transaction_edit_window(ctx);
+        
+        // Offer to undo a transaction just dragged to another day
+        self.render_undo_toast(ctx);
+        
+        // Celebrations go on top of everything, modals included
+        self.render_celebrations(ctx);
+        
+        // The guided tour dims everything else while it runs
+        self.render_tutorial(ctx);
     }
 }
 
@@ -169,110 +202,136 @@ impl AllowanceTrackerApp {
     /// Draw tab-specific controls for the subheader
     fn draw_tab_specific_controls(&mut self, ui: &mut egui::Ui) {
         use crate::ui::app_state::MainTab;
-        use crate::ui::components::chart_renderer::ChartPeriod;
+        use crate::ui::components::chart_renderer::{ChartPeriod, ChartView};
         
         match self.current_tab() {
             MainTab::Calendar => {
                 self.draw_calendar_navigation_controls(ui);
             }
             MainTab::Table => {
-                // Show table title in subheader
-                ui.label(egui::RichText::new("📋 Recent Transactions")
-                    .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
-                    .color(egui::Color32::WHITE)
-                    .strong());
-            }
-            MainTab::Chart => {
+                use crate::backend::domain::transaction_table::ClipboardFormat;
+                
                 ui.horizontal(|ui| {
-                    // Chart title on the left
-                    ui.label(egui::RichText::new("📊 Balance Chart")
+                    // Show table title in subheader
+                    ui.label(egui::RichText::new("📋 Recent Transactions")
                         .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
                         .color(egui::Color32::WHITE)
                         .strong());
                     
-                    ui.add_space(20.0); // Space between title and buttons
+                    ui.add_space(20.0);
                     
-                    // Time period buttons
-                    // 30 Days button
-                    let days_30_button = egui::Button::new(
-                        egui::RichText::new("30 Days")
+                    // Copy actions for rows selected by clicking them
+                    let selected_count = self.table.selected_ids.len();
+                    let has_selection = selected_count > 0;
+                    let copy_label = if has_selection {
+                        format!("📋 Copy selection ({})", selected_count)
+                    } else {
+                        "📋 Copy selection".to_string()
+                    };
+                    let copy_button = egui::Button::new(
+                        egui::RichText::new(copy_label)
                             .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
-                            .color(if self.chart.selected_period == ChartPeriod::Days30 { 
-                                egui::Color32::WHITE 
-                            } else { 
-                                egui::Color32::from_gray(200) 
-                            })
+                            .color(egui::Color32::from_rgb(100, 100, 100))
                     )
-                    .min_size(egui::vec2(60.0, 28.0))
+                    .min_size(egui::vec2(110.0, 28.0))
                     .corner_radius(egui::CornerRadius::same(6))
-                    .fill(if self.chart.selected_period == ChartPeriod::Days30 {
-                        egui::Color32::from_rgb(100, 150, 255) // Active blue
-                    } else {
-                        egui::Color32::from_rgb(240, 240, 240) // Light gray background for inactive
-                    })
+                    .fill(Theme::current(ui.ctx()).layout.subtle_background)
                     .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 200, 200)));
                     
-                    if ui.add(days_30_button).clicked() {
-                        self.chart.selected_period = ChartPeriod::Days30;
-                        self.chart.chart_data.clear(); // Clear data to force reload
-                        self.load_chart_data();
+                    let copy_response = ui.add_enabled(has_selection, copy_button)
+                        .on_hover_text("Copy the selected rows for pasting into a spreadsheet")
+                        .on_disabled_hover_text("Click rows in the table to select them");
+                    if copy_response.clicked() {
+                        self.copy_selected_transactions(ui.ctx(), ClipboardFormat::Tsv);
                     }
                     
-                    ui.add_space(8.0);
-                    
-                    // 90 Days button
-                    let days_90_button = egui::Button::new(
-                        egui::RichText::new("90 Days")
-                            .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
-                            .color(if self.chart.selected_period == ChartPeriod::Days90 { 
-                                egui::Color32::WHITE 
-                            } else { 
-                                egui::Color32::from_gray(200) 
-                            })
-                    )
-                    .min_size(egui::vec2(60.0, 28.0))
-                    .corner_radius(egui::CornerRadius::same(6))
-                    .fill(if self.chart.selected_period == ChartPeriod::Days90 {
-                        egui::Color32::from_rgb(100, 150, 255) // Active blue
-                    } else {
-                        egui::Color32::from_rgb(240, 240, 240) // Light gray background for inactive
-                    })
-                    .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 200, 200)));
-                    
-                    if ui.add(days_90_button).clicked() {
-                        self.chart.selected_period = ChartPeriod::Days90;
-                        self.chart.chart_data.clear(); // Clear data to force reload
-                        self.load_chart_data();
+                    if has_selection {
+                        ui.add_space(8.0);
+                        if ui.small_button("CSV").on_hover_text("Copy the selected rows as CSV").clicked() {
+                            self.copy_selected_transactions(ui.ctx(), ClipboardFormat::Csv);
+                        }
+                        if ui.small_button("Clear").clicked() {
+                            self.table.selected_ids.clear();
+                        }
                     }
+                });
+            }
+            MainTab::Chart => {
+                ui.horizontal(|ui| {
+                    // Chart title on the left
+                    ui.label(egui::RichText::new("📊 Balance Chart")
+                        .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
+                        .color(egui::Color32::WHITE)
+                        .strong());
                     
-                    ui.add_space(8.0);
+                    ui.add_space(20.0); // Space between title and buttons
                     
-                    // All Time button
-                    let all_time_button = egui::Button::new(
-                        egui::RichText::new("All Time")
-                            .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
-                            .color(if self.chart.selected_period == ChartPeriod::AllTime { 
-                                egui::Color32::WHITE 
-                            } else { 
-                                egui::Color32::from_rgb(100, 100, 100) 
-                            })
-                    )
-                    .min_size(egui::vec2(70.0, 28.0))
-                    .corner_radius(egui::CornerRadius::same(6))
-                    .fill(if self.chart.selected_period == ChartPeriod::AllTime {
-                        egui::Color32::from_rgb(100, 150, 255) // Active blue
-                    } else {
-                        egui::Color32::from_rgb(240, 240, 240) // Light gray background for inactive
-                    })
-                    .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 200, 200)));
+                    // Time period buttons
+                    for period in ChartPeriod::ALL {
+                        let active = self.chart.view == ChartView::Balance && self.chart.selected_period == period;
+                        let period_button = egui::Button::new(
+                            egui::RichText::new(period.button_text())
+                                .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
+                                .color(if active { 
+                                    egui::Color32::WHITE 
+                                } else { 
+                                    egui::Color32::from_gray(200) 
+                                })
+                        )
+                        .min_size(egui::vec2(44.0, 28.0))
+                        .corner_radius(egui::CornerRadius::same(6))
+                        .fill(if active {
+                            egui::Color32::from_rgb(100, 150, 255) // Active blue
+                        } else {
+                            Theme::current(ui.ctx()).layout.subtle_background // Light gray background for inactive
+                        })
+                        .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 200, 200)));
+                        
+                        if ui.add(period_button).on_hover_text(period.label()).clicked() {
+                            self.chart.selected_period = period;
+                            self.chart.view = ChartView::Balance;
+                            self.chart.chart_data.clear(); // Clear data to force reload
+                            self.load_chart_data();
+                        }
+                        
+                        ui.add_space(8.0);
+                    }
                     
-                    if ui.add(all_time_button).clicked() {
-                        self.chart.selected_period = ChartPeriod::AllTime;
-                        self.chart.chart_data.clear(); // Clear data to force reload
-                        self.load_chart_data();
+                    // Buttons for the other views
+                    for view in ChartView::OTHER_VIEWS {
+                        let active = self.chart.view == view;
+                        let view_button = egui::Button::new(
+                            egui::RichText::new(view.button_text())
+                                .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
+                                .color(if active { 
+                                    egui::Color32::WHITE 
+                                } else { 
+                                    egui::Color32::from_rgb(100, 100, 100) 
+                                })
+                        )
+                        .min_size(egui::vec2(70.0, 28.0))
+                        .corner_radius(egui::CornerRadius::same(6))
+                        .fill(if active {
+                            egui::Color32::from_rgb(100, 150, 255) // Active blue
+                        } else {
+                            Theme::current(ui.ctx()).layout.subtle_background // Light gray background for inactive
+                        })
+                        .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 200, 200)));
+                        
+                        if ui.add(view_button).on_hover_text(view.hover_text()).clicked() {
+                            self.chart.show_view(view); // Reloads in case transactions changed
+                        }
+                        
+                        ui.add_space(8.0);
                     }
                 });
             }
+            MainTab::Family => {
+                ui.label(egui::RichText::new("👪 Family")
+                    .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
+                    .color(egui::Color32::WHITE)
+                    .strong());
+            }
             MainTab::Goal => {
                 ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
                     // Show goal title in subheader with proper vertical centering
@@ -297,7 +356,7 @@ impl AllowanceTrackerApp {
                                 .font(egui::FontId::new(14.0, egui::FontFamily::Proportional))
                                 .strong()
                                 .color(egui::Color32::from_rgb(100, 100, 100))) // Same gray text as inactive buttons
-                            .fill(egui::Color32::from_rgb(240, 240, 240)) // Same light gray background as inactive buttons
+                            .fill(Theme::current(ui.ctx()).layout.subtle_background) // Same light gray background as inactive buttons
                             .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 200, 200))) // Same light gray border as inactive buttons
                             .corner_radius(egui::CornerRadius::same(8)) // Same rounding as toggle buttons
                             .min_size(egui::vec2(110.0, 35.0)); // Same height as toggle buttons
@@ -379,6 +438,39 @@ impl AllowanceTrackerApp {
             if ui.add(next_button).clicked() {
                 self.navigate_to_next_month();
             }
+            
+            // The month is read in the background; show that it's on its way
+            if self.calendar.calendar_loading {
+                ui.add_space(10.0);
+                ui.add(egui::Spinner::new().color(egui::Color32::WHITE));
+            }
+            
+            // Flag categories that went past their monthly target
+            if let Some(status) = &self.calendar.budget_status {
+                let over: Vec<_> = status.over_budget().collect();
+                if !over.is_empty() {
+                    ui.add_space(15.0);
+                    let names: Vec<&str> = over.iter().map(|category| category.category.as_str()).collect();
+                    let details: Vec<String> = over
+                        .iter()
+                        .map(|category| format!(
+                            "{}: {} of {}",
+                            category.category,
+                            self.format_currency_amount(category.spent),
+                            self.format_currency_amount(category.target)
+                        ))
+                        .collect();
+                    ui.add(egui::Label::new(egui::RichText::new(format!("⚠ Over budget: {}", names.join(", ")))
+                        .color(egui::Color32::from_rgb(255, 220, 120))
+                        .strong())
+                        .selectable(false))
+                        .on_hover_text(details.join("\n"));
+                }
+            }
+            
+            // Jump straight to a date or a transaction instead of paging month by month
+            ui.add_space(20.0);
+            self.draw_calendar_jump_controls(ui);
         });
     }
     
@@ -402,6 +494,12 @@ impl AllowanceTrackerApp {
         if self.ui.should_refresh_allowances() {
             log::info!("🔄 Performing periodic allowance refresh check");
             
+            // Edits to email_config.toml apply from the next tick, without a restart
+            self.core.backend.reload_config_if_changed();
+            
+            // Held directory locks would look abandoned after a while without writes
+            self.core.backend.data_directory_service.refresh_locks();
+            
             // Use the existing backend method to check and issue pending allowances
             match self.core.backend.transaction_service.as_ref().check_and_issue_pending_allowances() {
                 Ok(count) => {
@@ -412,6 +510,8 @@ impl AllowanceTrackerApp {
                         // This ensures the calendar view updates without requiring manual navigation
                         log::info!("🔄 Reloading calendar data to show new allowances");
                         self.load_calendar_data();
+                        self.table.invalidate();
+                        self.check_celebrations();
                         
                         // Optionally show a success message to the user
                         // self.ui.set_success_message(format!("Issued {} allowances!", count));
@@ -425,6 +525,56 @@ impl AllowanceTrackerApp {
                 }
             }
             
+            // Announce allowances just issued (and goals completed since the last tick) if a parent opted in
+            match self.core.backend.desktop_notification_service.notify_new_celebrations() {
+                Ok(shown) if shown > 0 => {
+                    log::info!("🔔 Periodic refresh: Showed {} desktop notifications", shown);
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    log::warn!("🔔 Desktop notification check failed: {}", e);
+                }
+            }
+            
+            // Retention rules piggyback on the same tick; the service runs them at most once a day
+            match self.core.backend.retention_service.run_scheduled_retention() {
+                Ok(Some(report)) if report.removed_count() > 0 => {
+                    log::info!("🧽 Periodic refresh: Removed {} records past retention", report.removed_count());
+                    self.table.invalidate();
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    log::warn!("🧽 Periodic retention run failed: {}", e);
+                }
+            }
+
+            // Last month's reports are written once, on the first tick of a new month
+            match self.core.backend.scheduled_report_service.run_scheduled_reports() {
+                Ok(Some(result)) if !result.reports.is_empty() => {
+                    log::info!("🗓️ Periodic refresh: Wrote {} monthly reports", result.reports.len());
+                    self.ui.set_success(format!("Saved {} monthly report(s) for {}/{}", result.reports.len(), result.month, result.year));
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    log::warn!("🗓️ Scheduled report run failed: {}", e);
+                }
+            }
+
+            // The fridge view page is rewritten every few minutes when a parent turned that on
+            match self.core.backend.fridge_view_service.run_scheduled() {
+                Ok(Some(result)) => {
+                    log::debug!("🧲 Periodic refresh: Rewrote the fridge view at {}", result.path);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    log::warn!("🧲 Scheduled fridge view update failed: {}", e);
+                }
+            }
+
+            // The update check waits on the network, so it runs on the loader thread;
+            // it only asks for releases when a parent turned it on, at most once a day
+            self.loader.request(DataRequest::UpdateCheck { scheduled: true });
+
             // Mark that we just performed a refresh (updates the timestamp)
             self.ui.mark_allowance_refresh();
         }
@@ -24,10 +24,19 @@
 //! - FormState: Form inputs and validation
 //! - InteractionState: User selections, dropdowns
 
+use eframe::egui;
 use log::{info, warn};
 use chrono::{Datelike, TimeZone};
 use shared::*;
 use crate::backend::Backend;
+use crate::backend::api::DirectBackendApi;
+use crate::backend::domain::models::actor::Actor;
+use crate::backend::domain::models::parent_user::ParentPermission;
+use crate::backend::domain::StringCatalog;
+use frontend_api::AllowanceApi;
+use crate::ui::components::data_loading::DataLoader;
+use crate::ui::tray::AppTray;
+use crate::ui::components::styling::apply_color_theme;
 
 // Import all state modules
 use crate::ui::state::*;
@@ -50,21 +59,57 @@ pub struct AllowanceTrackerApp {
     pub table: TableState,            // Transaction table pagination
     pub chart: ChartState,            // Chart visualization and time periods
     pub goal: GoalUiState,            // Goal management and progress tracking
+    pub family: FamilyState,          // Family overview across all children
+    pub celebration: CelebrationState, // Celebration banner, confetti and reduced motion
+    pub tutorial: TutorialState,      // Guided tour for new family members
     pub settings: crate::ui::components::settings::SettingsState, // Settings modals and forms
+    pub loader: DataLoader,           // Background reads off the UI thread
+    pub tray: Option<AppTray>,        // Menu bar / system tray icon, if the desktop has one
 }
 
 impl AllowanceTrackerApp {
     /// Create a new AllowanceTrackerApp with modular architecture
     pub fn new(cc: &eframe::CreationContext<'_>) -> Result<Self, anyhow::Error> {
-        info!("🚀 Initializing AllowanceTrackerApp with modular architecture");
-        
+        Self::prepare_context(&cc.egui_ctx);
+        let backend = crate::backend::Backend::new()?;
+        Ok(Self::with_backend(&cc.egui_ctx, backend))
+    }
+    
+    /// Fonts and image loaders, needed before the first frame of any screen
+    pub fn prepare_context(ctx: &egui::Context) {
         // Setup custom fonts including Chalkboard
-        crate::ui::setup_custom_fonts(&cc.egui_ctx);
+        crate::ui::setup_custom_fonts(ctx);
         
         // Install image loaders for background support
-        egui_extras::install_image_loaders(&cc.egui_ctx);
+        egui_extras::install_image_loaders(ctx);
+    }
+    
+    /// Create the app over a backend that is already open
+    pub fn with_backend(ctx: &egui::Context, backend: Backend) -> Self {
+        info!("🚀 Initializing AllowanceTrackerApp with modular architecture");
         
-        let backend = crate::backend::Backend::new()?;
+        // Apply the saved text size and colors before the first frame so nothing jumps
+        let (show_week_summaries, show_goal_strip, reduce_motion) = match backend.preferences_service.get_display_preferences() {
+            Ok(preferences) => {
+                info!("🔠 Applying text size {:?} (x{}), theme {:?}", preferences.text_size, preferences.scale_factor, preferences.theme);
+                ctx.set_zoom_factor(preferences.scale_factor);
+                apply_color_theme(ctx, preferences.theme);
+                (preferences.show_week_summaries, preferences.show_goal_strip, preferences.reduce_motion)
+            }
+            Err(e) => {
+                warn!("🔠 Failed to load display preferences: {}", e);
+                (false, false, false)
+            }
+        };
+        
+        // The guided tour runs on first launch until it is finished or skipped
+        let show_tutorial = match backend.preferences_service.get_tutorial_status() {
+            Ok(status) => !status.completed,
+            Err(e) => {
+                warn!("🧭 Failed to load tutorial status: {}", e);
+                false
+            }
+        };
         
         // Check for pending allowances on app startup
         match backend.transaction_service.as_ref().check_and_issue_pending_allowances() {
@@ -80,23 +125,59 @@ impl AllowanceTrackerApp {
             }
         }
         
+        // Encrypted child data can't be read until the parental passphrase is entered
+        let needs_unlock = match backend.encryption_service.children_needing_unlock() {
+            Ok(children) => {
+                if !children.is_empty() {
+                    info!("🔐 {} child(ren) have encrypted data; prompting for passphrase", children.len());
+                }
+                !children.is_empty()
+            }
+            Err(e) => {
+                warn!("🔐 Failed to check encrypted child data: {}", e);
+                false
+            }
+        };
+        
+        // A relocated folder on an unplugged drive leaves the backend read-only until it's back
+        let missing_data_folders = match backend.data_directory_service.missing_data_folders() {
+            Ok(missing) => missing,
+            Err(e) => {
+                warn!("📁 Failed to check relocated data folders: {}", e);
+                Vec::new()
+            }
+        };
+        
         let now = chrono::Local::now();
         let _current_month = now.month();
         let _current_year = now.year();
         
         // Initialize modular state components
+        let loader = DataLoader::new(&backend, ctx.clone());
+        let tray = AppTray::new(ctx.clone());
         let core = CoreAppState::new(backend);
         let ui = UIState::new();
-        let calendar = CalendarState::new(); // Uses current date
-        let modal = ModalState::new();
+        let mut calendar = CalendarState::new(); // Uses current date
+        calendar.show_week_summaries = show_week_summaries;
+        calendar.show_goal_strip = show_goal_strip;
+        let mut modal = ModalState::new();
+        modal.show_unlock_data_modal = needs_unlock;
+        modal.missing_data_folders = missing_data_folders;
         let form = FormState::new();
         let interaction = InteractionState::new();
         let table = TableState::new();
         let chart = ChartState::new();
         let goal = GoalUiState::new();
+        let family = FamilyState::new();
+        let mut celebration = CelebrationState::new();
+        celebration.set_reduce_motion(reduce_motion);
+        let mut tutorial = TutorialState::new();
+        if show_tutorial {
+            tutorial.start();
+        }
         let settings = crate::ui::components::settings::SettingsState::new();
         
-        Ok(Self {
+        Self {
             // Modular state
             core,
             ui,
@@ -107,8 +188,13 @@ impl AllowanceTrackerApp {
             table,
             chart,
             goal,
+            family,
+            celebration,
+            tutorial,
             settings,
-        })
+            loader,
+            tray,
+        }
     }
 
     // TEMPORARY: Getter methods for backward compatibility
@@ -116,19 +202,16 @@ impl AllowanceTrackerApp {
         &self.core.backend
     }
     
+    /// The shared frontend API, served directly by the backend
+    pub fn api(&self) -> DirectBackendApi<'_> {
+        DirectBackendApi::new(&self.core.backend)
+    }
+    
     /// Get current child directly from backend service (the source of truth)
     /// This replaces the cached current_child() method to avoid inconsistencies
     pub fn get_current_child_from_backend(&self) -> Option<shared::Child> {
-        match self.backend().child_service.get_active_child() {
-            Ok(result) => {
-                // Only log once per actual change, not every frame (commented out to reduce noise)
-                // log::info!("🔍 GET_CURRENT_CHILD_BACKEND: Raw result: {:?}", 
-                //     result.active_child.child.as_ref().map(|c| (&c.id, &c.name)));
-                
-                result.active_child.child.map(|domain_child| {
-                    crate::ui::mappers::to_dto(domain_child)
-                })
-            },
+        match self.api().get_active_child() {
+            Ok(response) => response.active_child,
             Err(e) => {
                 log::warn!("❌ GET_CURRENT_CHILD_BACKEND: Failed to get current child from backend: {}", e);
                 None
@@ -147,6 +230,12 @@ impl AllowanceTrackerApp {
         self.core.current_balance
     }
     
+    /// Part of the balance set aside for planned purchases
+    pub fn reserved_amount(&self) -> f64 {
+        let reserved: f64 = self.calendar.planned_purchases.iter().map(|p| p.amount).sum();
+        (reserved * 100.0).round() / 100.0
+    }
+    
     pub fn current_tab(&self) -> MainTab {
         self.core.current_tab
     }
@@ -170,6 +259,7 @@ impl AllowanceTrackerApp {
         self.modal.parental_control_input.clear();
         self.modal.parental_control_error = None;
         self.modal.parental_control_loading = false;
+        self.modal.parental_control_actor = None;
         self.modal.show_parental_control_modal = true;
     }
 
@@ -178,6 +268,9 @@ impl AllowanceTrackerApp {
         info!("🔒 Cancelling parental control challenge");
         self.modal.show_parental_control_modal = false;
         self.modal.pending_protected_action = None;
+        self.interaction.pending_move = None;
+        self.interaction.pending_edit = None;
+        self.interaction.pending_delete_id = None;
         self.modal.parental_control_stage = ParentalControlStage::Question1;
         self.modal.parental_control_input.clear();
         self.modal.parental_control_error = None;
@@ -219,6 +312,7 @@ impl AllowanceTrackerApp {
     /// Navigate to the next month
     pub fn navigate_to_next_month(&mut self) {
         self.calendar.navigate_to_next_month();
+        self.backend().calendar_service.clear_highlight();
         
         // Sync compatibility fields
         // self.selected_month = self.calendar.selected_month;
@@ -256,9 +350,17 @@ impl AllowanceTrackerApp {
         self.modal.parental_control_loading = true;
         self.modal.parental_control_error = None;
         
-        // Create command for backend validation
+        // Named parents need the permission for what they are trying to do
+        let permission = self.modal.pending_protected_action.map(|action| match action {
+            ProtectedAction::DeleteTransactions => ParentPermission::DeleteTransactions,
+            ProtectedAction::AccessSettings => ParentPermission::ManageSettings,
+            // Moving or editing rewrites history the way deleting does
+            ProtectedAction::MoveTransaction | ProtectedAction::EditTransaction => ParentPermission::DeleteTransactions,
+            ProtectedAction::OverrideOverdraft => ParentPermission::OverrideBalanceFloor,
+        });
         let command = crate::backend::domain::commands::parental_control::ValidateParentalControlCommand {
             answer: self.modal.parental_control_input.clone(),
+            permission,
         };
         
         // Call backend service
@@ -269,6 +371,7 @@ impl AllowanceTrackerApp {
                 if result.success {
                     info!("✅ Parental control authentication successful");
                     self.modal.parental_control_stage = ParentalControlStage::Authenticated;
+                    self.modal.parental_control_actor = result.actor;
                     
                     // Execute the pending action
                     info!("🔒 PARENTAL_CONTROL_SUCCESS: Checking for pending actions...");
@@ -287,6 +390,8 @@ impl AllowanceTrackerApp {
                     // Access granted feedback removed
                 } else {
                     info!("❌ Parental control validation failed");
+                    self.modal.parental_control_locked_until = result.locked_for_seconds
+                        .map(|seconds| std::time::Instant::now() + std::time::Duration::from_secs(seconds));
                     self.modal.parental_control_error = Some(result.message);
                     self.modal.parental_control_input.clear();
                 }
@@ -299,6 +404,11 @@ impl AllowanceTrackerApp {
         }
     }
     
+    /// The parent who passed the last challenge, for actions behind it
+    pub(crate) fn parent_actor(&self) -> Option<Actor> {
+        Some(self.modal.parental_control_actor.clone().unwrap_or(Actor::Parent(None)))
+    }
+    
     /// Execute the action after successful authentication
     fn execute_protected_action(&mut self, action: crate::ui::state::modal_state::ProtectedAction) {
         use crate::ui::state::modal_state::ProtectedAction;
@@ -309,6 +419,28 @@ impl AllowanceTrackerApp {
             ProtectedAction::DeleteTransactions => {
                 info!("🗑️ Executing delete transactions action");
                 self.enter_transaction_selection_mode();
+                // Delete from a row's menu starts with that row picked
+                if let Some(transaction_id) = self.interaction.pending_delete_id.take() {
+                    self.interaction.selected_transaction_ids.insert(transaction_id);
+                }
+            }
+            ProtectedAction::MoveTransaction => {
+                if let Some(pending) = self.interaction.pending_move.take() {
+                    self.move_transaction(pending, self.parent_actor());
+                }
+            }
+            ProtectedAction::EditTransaction => {
+                if let Some(edit) = self.interaction.pending_edit.take() {
+                    self.save_transaction_edit(edit, self.parent_actor());
+                }
+            }
+            ProtectedAction::OverrideOverdraft => {
+                info!("🔓 Recording the refused spend with a parent override");
+                if self.submit_expense(self.parent_actor()) {
+                    self.form.expense_form_state.clear();
+                    self.calendar.active_overlay = None;
+                    self.calendar.selected_day = None;
+                }
             }
             ProtectedAction::AccessSettings => {
                 info!("🔒 EXECUTING SETTINGS ACCESS ACTION!");
@@ -392,6 +524,10 @@ impl AllowanceTrackerApp {
                 info!("📤 Export data action - opening modal");
                 self.settings.show_export_modal = true;
                 self.settings.export_form.clear(); // Reset form state
+                match self.backend().parental_control_service.export_requires_parental_control() {
+                    Ok(protected) => self.settings.export_form.protected = protected,
+                    Err(e) => warn!("📤 Failed to read export protection setting: {}", e),
+                }
                 
                 // Update preview immediately
                 let child_name = self.get_current_child_from_backend().as_ref().map(|c| c.name.clone());
@@ -405,6 +541,185 @@ impl AllowanceTrackerApp {
                 // Clear form state when opening modal
                 self.settings.data_directory_form.clear();
             }
+            SettingsAction::TextSize => {
+                info!("🔠 Text size action - opening modal");
+                self.settings.show_text_size_modal = true;
+                self.settings.text_size_error = None;
+            }
+            SettingsAction::ArchiveHistory => {
+                info!("🗄️ Archive history action - opening modal");
+                self.settings.show_archive_modal = true;
+                self.settings.archive_error = None;
+            }
+            SettingsAction::Loans => {
+                info!("🤝 Loans action - opening modal");
+                self.settings.show_loans_modal = true;
+                self.settings.loans = None;
+                self.settings.loan_form.clear();
+            }
+            SettingsAction::PlannedPurchases => {
+                info!("🛍️ Planned purchases action - opening modal");
+                self.settings.show_planned_purchases_modal = true;
+                self.settings.planned_purchase_form.clear();
+            }
+            SettingsAction::Parents => {
+                info!("👪 Parents action - opening modal");
+                self.settings.show_parents_modal = true;
+                self.settings.parents = None;
+                self.settings.parent_form.clear();
+            }
+            SettingsAction::ResetData => {
+                info!("🧹 Reset data action - opening modal");
+                self.settings.show_reset_modal = true;
+                self.settings.reset_confirmation.clear();
+                self.settings.reset_error = None;
+            }
+            SettingsAction::GoalHistory => {
+                info!("🏁 Goal history action - opening modal");
+                self.settings.show_goal_history_modal = true;
+                self.settings.goal_statistics = None;
+                self.settings.goal_history_error = None;
+            }
+            SettingsAction::AllowanceAdvance => {
+                info!("💸 Allowance advance action - opening modal");
+                self.settings.show_advance_modal = true;
+                self.settings.advances = None;
+                self.settings.advance_form.clear();
+            }
+            SettingsAction::QuickPicks => {
+                info!("⚡ Quick picks action - opening modal");
+                self.settings.show_quick_picks_modal = true;
+                self.settings.quick_picks = None;
+                self.settings.quick_pick_form.clear();
+            }
+            SettingsAction::Templates => {
+                info!("⭐ Templates action - opening modal");
+                self.settings.show_templates_modal = true;
+                self.settings.templates = None;
+                self.settings.template_form.clear();
+            }
+            SettingsAction::ImportStatement => {
+                info!("📥 Import statement action - opening modal");
+                self.settings.show_import_modal = true;
+                self.settings.import_form.clear();
+            }
+            SettingsAction::Categorize => {
+                info!("🏷️ Categorize history action - opening modal");
+                self.settings.show_categorize_modal = true;
+                self.settings.categorize_form.clear();
+            }
+            SettingsAction::DataRetention => {
+                info!("🧽 Data retention action - opening modal");
+                self.settings.show_retention_modal = true;
+                match self.backend().retention_service.get_retention_policy() {
+                    Ok(policy) => self.settings.retention_form.load(&policy),
+                    Err(e) => {
+                        self.settings.retention_form = crate::ui::components::settings::RetentionFormState::new();
+                        self.settings.retention_form.error = Some(format!("Could not load retention rules: {}", e));
+                    }
+                }
+            }
+            SettingsAction::SpendingTargets => {
+                info!("🎯 Spending targets action - opening modal");
+                self.settings.show_budget_modal = true;
+                self.settings.budget_targets = None;
+                self.settings.budget_form.clear();
+            }
+            SettingsAction::MonthlyReports => {
+                info!("🗓️ Monthly reports action - opening modal");
+                self.settings.show_report_schedule_modal = true;
+                match self.backend().scheduled_report_service.get_report_schedule() {
+                    Ok(schedule) => self.settings.report_schedule_form.load(schedule),
+                    Err(e) => {
+                        self.settings.report_schedule_form = crate::ui::components::settings::ReportScheduleFormState::new();
+                        self.settings.report_schedule_form.error = Some(format!("Could not load report settings: {}", e));
+                    }
+                }
+            }
+            SettingsAction::Experiments => {
+                info!("🧪 Experiments action - opening modal");
+                self.settings.show_feature_flags_modal = true;
+                match self.backend().feature_flag_service.get_feature_flags() {
+                    Ok(flags) => {
+                        self.settings.feature_flags = Some(flags);
+                        self.settings.feature_flags_error = None;
+                    }
+                    Err(e) => {
+                        self.settings.feature_flags = None;
+                        self.settings.feature_flags_error = Some(format!("Could not load experiments: {}", e));
+                    }
+                }
+            }
+            SettingsAction::AllowanceAgreement => {
+                info!("📜 Allowance agreement action - opening modal");
+                self.settings.show_agreement_modal = true;
+                self.settings.agreement_form.clear();
+            }
+            SettingsAction::CloseMonth => {
+                info!("🔒 Close month action - opening modal");
+                self.settings.close_period_form.clear();
+                if let Some(child) = self.get_current_child_from_backend() {
+                    match self.backend().transaction_service.get_period_lock(&child.id) {
+                        Ok(lock) => self.settings.close_period_form.current_lock = lock,
+                        Err(e) => self.settings.close_period_form.error = Some(format!("Could not read the closed period: {}", e)),
+                    }
+                }
+                self.settings.show_close_period_modal = true;
+            }
+            SettingsAction::Updates => {
+                info!("⬆️ Updates action - opening modal");
+                let check = self.settings.updates_form.check.take();
+                self.settings.updates_form.clear();
+                self.settings.updates_form.check = check;
+                match self.backend().update_service.get_update_settings() {
+                    Ok(settings) => {
+                        self.settings.updates_form.channel = settings.channel;
+                        self.settings.updates_form.check_automatically = settings.check_automatically;
+                    }
+                    Err(e) => self.settings.updates_form.error = Some(format!("Could not load update settings: {}", e)),
+                }
+                self.settings.show_updates_modal = true;
+            }
+            SettingsAction::FridgeView => {
+                info!("🧲 Fridge view action - opening modal");
+                self.settings.show_fridge_view_modal = true;
+                let service = &self.backend().fridge_view_service;
+                match service.get_settings().and_then(|settings| Ok((settings, service.last_written()?))) {
+                    Ok((settings, last_written)) => self.settings.fridge_view_form.load(settings, last_written),
+                    Err(e) => {
+                        self.settings.fridge_view_form = crate::ui::components::settings::FridgeViewFormState::new();
+                        self.settings.fridge_view_form.error = Some(format!("Could not load fridge view settings: {}", e));
+                    }
+                }
+            }
+            SettingsAction::Background => {
+                info!("🖼️ Background action - opening modal");
+                let background = self.core.current_background.clone();
+                self.settings.background_form.load(&background);
+                self.settings.show_background_modal = true;
+            }
+            SettingsAction::SpendingRules => {
+                info!("🚦 Spending rules action - opening modal");
+                self.settings.spending_rules_error = None;
+                self.settings.prevent_negative_balance = false;
+                if let Some(child) = self.get_current_child_from_backend() {
+                    match self.backend().child_service.get_child_settings(&child.id) {
+                        Ok(result) => self.settings.prevent_negative_balance = result.settings.prevent_negative_balance,
+                        Err(e) => self.settings.spending_rules_error = Some(format!("Could not load the spending rules: {}", e)),
+                    }
+                }
+                self.settings.show_spending_rules_modal = true;
+            }
+            SettingsAction::Encryption => {
+                info!("🔐 Encryption action - opening modal");
+                self.settings.encryption_form.clear();
+                self.load_encryption_status();
+                self.settings.show_encryption_modal = true;
+            }
+            SettingsAction::Tutorial => {
+                info!("🧭 Tutorial action - replaying the guided tour");
+                self.start_tutorial();
+            }
         }
     }
     
@@ -520,7 +835,7 @@ impl AllowanceTrackerApp {
     }
     
     /// Clean and parse amount input string (similar to MoneyManagementService)
-    fn clean_and_parse_amount(&self, amount_input: &str) -> Result<f64, String> {
+    pub(crate) fn clean_and_parse_amount(&self, amount_input: &str) -> Result<f64, String> {
         // Clean the input - remove dollar signs, spaces, commas
         let cleaned = amount_input
             .trim()
@@ -533,15 +848,24 @@ impl AllowanceTrackerApp {
             return Err("Amount cannot be empty".to_string());
         }
 
+        // Arithmetic like "3*1.25" is evaluated to its result
+        if crate::backend::domain::amount_expression::is_expression(&cleaned) {
+            return crate::backend::domain::amount_expression::evaluate(&cleaned);
+        }
+
         // Try to parse as float
         cleaned.parse::<f64>()
             .map_err(|_| "Invalid number format".to_string())
     }
     
     /// Check if amount has too many decimal places
-    fn has_too_many_decimal_places(&self, _amount: f64) -> bool {
+    fn has_too_many_decimal_places(&self, amount: f64) -> bool {
         // Check the original input string instead of the parsed float
         let input = self.form.add_money_amount.trim();
+        if crate::backend::domain::amount_expression::is_expression(input) {
+            // Expressions are judged by their result
+            return (amount * 100.0).round() / 100.0 != amount;
+        }
         if let Some(decimal_pos) = input.find('.') {
             let decimal_part = &input[decimal_pos + 1..];
             // Reject if more than 2 decimal places
@@ -570,6 +894,11 @@ impl AllowanceTrackerApp {
     pub fn auto_format_amount_field(&mut self) {
         let input = self.form.add_money_amount.clone();
         
+        // Leave arithmetic as typed so it can be noted on the transaction
+        if crate::backend::domain::amount_expression::is_expression(&input) {
+            return;
+        }
+        
         // Only auto-format if the input looks like a valid number
         if let Ok(amount) = self.clean_and_parse_amount(&input) {
             // Only format if the amount is reasonable and has <= 2 decimal places
@@ -586,60 +915,67 @@ impl AllowanceTrackerApp {
     // GENERIC MONEY TRANSACTION FORM VALIDATION METHODS
     // ====================
     
+    /// Money service using the modal's description limit and the child's currency symbol
+    /// and locale, so the egui forms apply the same rules as every other frontend
+    fn money_service_for(&self, config: &MoneyTransactionModalConfig) -> crate::backend::domain::money_management::MoneyManagementService {
+        crate::backend::domain::money_management::MoneyManagementService::with_config(MoneyManagementConfig {
+            max_description_length: config.max_description_length,
+            currency_symbol: self.core.current_currency.symbol().to_string(),
+            ..MoneyManagementConfig::default()
+        })
+        .with_locale(self.core.current_locale)
+    }
+    
     /// Validate a generic money transaction form and update its validation state
     pub fn validate_money_transaction_form(&self, form_state: &mut MoneyTransactionFormState, config: &MoneyTransactionModalConfig) {
+        let money_service = self.money_service_for(config);
+        let validation = match config.transaction_type {
+            TransactionType::Income => money_service.validate_add_money_form(&form_state.description, &form_state.amount),
+            TransactionType::Expense => money_service.validate_spend_money_form(&form_state.description, &form_state.amount),
+        };
+        
         form_state.description_error = None;
         form_state.amount_error = None;
-        
-        // Validate description
-        let description = form_state.description.trim();
-        if description.is_empty() {
-            form_state.description_error = Some("Description is required".to_string());
-        } else if description.len() > config.max_description_length {
-            form_state.description_error = Some(format!("Description too long ({}/{} characters)", description.len(), config.max_description_length));
-        }
-        
-        // Validate amount
-        let amount_input = form_state.amount.trim();
-        if amount_input.is_empty() {
-            // Don't show "Amount is required" error immediately - let the grayed button be sufficient
-            form_state.amount_error = None;
-        } else {
-            // Clean and parse amount
-            match self.clean_and_parse_amount(amount_input) {
-                Ok(amount) => {
-                    if amount <= 0.0 {
-                        form_state.amount_error = Some("Amount must be positive".to_string());
-                    } else if amount > 1_000_000.0 {
-                        form_state.amount_error = Some("Amount too large (max $1,000,000)".to_string());
-                    } else if amount < 0.01 {
-                        form_state.amount_error = Some("Amount too small (min $0.01)".to_string());
-                    } else if self.has_too_many_decimal_places_generic(amount, &form_state.amount) {
-                        form_state.amount_error = Some("Maximum 2 decimal places allowed".to_string());
-                    }
-                }
-                Err(error) => {
-                    form_state.amount_error = Some(error);
-                }
+        form_state.overdraft_error = None;
+        // Rendered from the catalog keys, as any frontend would in the child's language
+        let catalog = StringCatalog::new(self.core.current_locale);
+        for (error, message) in validation.errors.iter().zip(&validation.messages) {
+            let field = match error {
+                MoneyValidationError::EmptyDescription | MoneyValidationError::DescriptionTooLong(_) => &mut form_state.description_error,
+                // Don't show "Amount is required" error immediately - let the grayed button be sufficient
+                MoneyValidationError::EmptyAmount => continue,
+                _ => &mut form_state.amount_error,
+            };
+            if field.is_none() {
+                *field = Some(catalog.render(message));
             }
         }
         
+        form_state.date_error = form_state.date.and_then(|date| {
+            money_service.validate_transaction_date(&date.format("%Y-%m-%d").to_string(), None).err()
+        });
+        form_state.suggestions = validation.suggestions;
+        
         // Update overall validation state
-        form_state.is_valid = form_state.description_error.is_none() && form_state.amount_error.is_none();
+        form_state.is_valid = form_state.description_error.is_none()
+            && form_state.amount_error.is_none()
+            && form_state.date_error.is_none();
     }
     
-    /// Check if amount has too many decimal places for generic form (takes amount input string)
-    fn has_too_many_decimal_places_generic(&self, _amount: f64, amount_input: &str) -> bool {
-        // Check the original input string instead of the parsed float
-        let input = amount_input.trim();
-        if let Some(decimal_pos) = input.find('.') {
-            let decimal_part = &input[decimal_pos + 1..];
-            // Reject if more than 2 decimal places
-            if decimal_part.len() > 2 {
-                return true;
-            }
-        }
-        false
+    /// Timestamp for a transaction entered on `date`: the current time of day
+    /// for today, noon for earlier days, in the app's Eastern offset
+    pub(crate) fn transaction_timestamp(date: Option<chrono::NaiveDate>) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        use chrono::Timelike;
+        date.map(|date| {
+            let now = chrono::Local::now();
+            let naive_datetime = if date == now.date_naive() {
+                date.and_hms_opt(now.hour(), now.minute(), now.second()).unwrap()
+            } else {
+                date.and_hms_opt(12, 0, 0).unwrap()
+            };
+            let eastern_offset = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+            eastern_offset.from_local_datetime(&naive_datetime).single().unwrap()
+        })
     }
     
     // ====================
@@ -647,40 +983,35 @@ impl AllowanceTrackerApp {
     // ====================
     
     pub fn submit_income_transaction(&mut self) -> bool {
-        use crate::backend::domain::money_management::MoneyManagementService;
-        use chrono::Timelike;
         info!("💰 Submitting income transaction - Description: '{}', Amount: '{}'", 
                   self.form.income_form_state.description, self.form.income_form_state.amount);
-        let amount = match self.clean_and_parse_amount(&self.form.income_form_state.amount) {
-            Ok(amount) => amount,
-            Err(error) => {
-                log::error!("❌ Failed to parse amount: {}", error);
-                self.ui.error_message = Some(format!("Invalid amount: {}", error));
-                return false;
-            }
+        let money_service = self.money_service_for(&MoneyTransactionModalConfig::income_config());
+        let form_state = &self.form.income_form_state;
+        let date = Self::transaction_timestamp(form_state.date.or(self.calendar.selected_day));
+        let validation = money_service.validate_add_money_form_with_date(&form_state.description, &form_state.amount, date.as_ref(), None);
+        let Some(amount) = validation.cleaned_amount.filter(|_| validation.is_valid) else {
+            let error = money_service.get_first_error_message(&validation.errors).unwrap_or_default();
+            log::error!("❌ Income form failed validation: {}", error);
+            self.ui.error_message = Some(format!("Invalid transaction: {}", error));
+            return false;
         };
-        let date_time = self.calendar.selected_day.map(|date| {
-            let now = chrono::Local::now();
-            let naive_datetime = date.and_hms_opt(now.hour(), now.minute(), now.second()).unwrap();
-            let eastern_offset = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
-            eastern_offset.from_local_datetime(&naive_datetime).single().unwrap()
-        });
         let request = shared::AddMoneyRequest {
-            description: self.form.income_form_state.description.trim().to_string(),
+            child_id: None,
+            description: form_state.description.trim().to_string(),
             amount,
-            date: date_time,
+            date,
+            amount_expression: validation.amount_expression,
+            actor: Some(Actor::Kid.to_storage()),
+            icon: form_state.icon.clone(),
+            transaction_type: form_state.transaction_type.clone(),
         };
-        let money_service = MoneyManagementService::new();
-        match money_service.add_money_complete(
-            request,
-            &self.backend().child_service,
-            &self.backend().transaction_service,
-            &self.backend().goal_service,
-        ) {
+        match self.api().with_money_service(money_service).add_money(request) {
             Ok(response) => {
                 info!("✅ Income transaction successful: {}", response.success_message);
                 self.core.current_balance = response.new_balance;
+                self.ui.set_success(response.success_message);
                 self.load_calendar_data();
+                self.table.invalidate();
                 true
             }
             Err(error) => {
@@ -692,42 +1023,50 @@ impl AllowanceTrackerApp {
     }
 
     pub fn submit_expense_transaction(&mut self) -> bool {
-        use crate::backend::domain::money_management::MoneyManagementService;
-        use chrono::Timelike;
+        self.submit_expense(None)
+    }
+
+    /// Record the expense form's spend; `parent_override` is the parent who
+    /// allowed it past the child's no-negative-balance rule, if any
+    fn submit_expense(&mut self, parent_override: Option<Actor>) -> bool {
         info!("💸 Submitting expense transaction - Description: '{}', Amount: '{}'", 
                   self.form.expense_form_state.description, self.form.expense_form_state.amount);
-        let amount = match self.clean_and_parse_amount(&self.form.expense_form_state.amount) {
-            Ok(amount) => amount,
-            Err(error) => {
-                log::error!("❌ Failed to parse amount: {}", error);
-                self.ui.error_message = Some(format!("Invalid amount: {}", error));
-                return false;
-            }
+        let money_service = self.money_service_for(&MoneyTransactionModalConfig::expense_config());
+        let form_state = &self.form.expense_form_state;
+        let date = Self::transaction_timestamp(form_state.date.or(self.calendar.selected_day));
+        let validation = money_service.validate_spend_money_form_with_date(&form_state.description, &form_state.amount, date.as_ref(), None);
+        let Some(amount) = validation.cleaned_amount.filter(|_| validation.is_valid) else {
+            let error = money_service.get_first_error_message(&validation.errors).unwrap_or_default();
+            log::error!("❌ Expense form failed validation: {}", error);
+            self.ui.error_message = Some(format!("Invalid transaction: {}", error));
+            return false;
         };
-        let date_time = self.calendar.selected_day.map(|date| {
-            let now = chrono::Local::now();
-            let naive_datetime = date.and_hms_opt(now.hour(), now.minute(), now.second()).unwrap();
-            let eastern_offset = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
-            eastern_offset.from_local_datetime(&naive_datetime).single().unwrap()
-        });
         let request = shared::SpendMoneyRequest {
-            description: self.form.expense_form_state.description.trim().to_string(),
+            child_id: None,
+            description: form_state.description.trim().to_string(),
             amount,
-            date: date_time,
+            date,
+            parent_override: parent_override.is_some(),
+            amount_expression: validation.amount_expression,
+            actor: Some(parent_override.as_ref().unwrap_or(&Actor::Kid).to_storage()),
+            icon: form_state.icon.clone(),
+            transaction_type: form_state.transaction_type.clone(),
         };
-        let money_service = MoneyManagementService::new();
-        match money_service.spend_money_complete(
-            request,
-            &self.backend().child_service,
-            &self.backend().transaction_service,
-            &self.backend().goal_service,
-        ) {
+        match self.api().with_money_service(money_service).spend_money(request) {
             Ok(response) => {
                 info!("✅ Expense transaction successful: {}", response.success_message);
                 self.core.current_balance = response.new_balance;
+                self.ui.set_success(response.success_message);
                 self.load_calendar_data();
+                self.table.invalidate();
                 true
             }
+            // Shown in the form, next to the parent override
+            Err(error) if error.code == shared::ErrorCode::InsufficientFunds => {
+                info!("🚫 Expense refused by the no-negative-balance rule: {}", error);
+                self.form.expense_form_state.overdraft_error = Some(error.message);
+                false
+            }
             Err(error) => {
                 log::error!("❌ Expense transaction failed: {}", error);
                 self.ui.error_message = Some(format!("Failed to spend money: {}", error));
@@ -735,4 +1074,77 @@ impl AllowanceTrackerApp {
             }
         }
     }
-} 
\ No newline at end of file
+
+    /// Add a transaction typed into the header quick-add box
+    pub fn submit_quick_entry(&mut self) -> bool {
+        let line = self.form.quick_entry_input.clone();
+        match self.record_quick_entry(&line) {
+            Ok(_) => {
+                self.form.quick_entry_input.clear();
+                self.form.quick_entry_error = None;
+                true
+            }
+            Err(error) => {
+                self.form.quick_entry_error = Some(error);
+                false
+            }
+        }
+    }
+
+    /// Add the transaction a quick entry line describes for the active child,
+    /// returning the success message or why it couldn't be added
+    pub(crate) fn record_quick_entry(&mut self, line: &str) -> Result<String, String> {
+        use crate::backend::domain::quick_entry::parse_quick_entry;
+        info!("⚡ Submitting quick entry: '{}'", line);
+        
+        // Same local-time-in-Eastern convention as the add/spend forms
+        let eastern_offset = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+        let now = eastern_offset
+            .from_local_datetime(&chrono::Local::now().naive_local())
+            .single()
+            .unwrap();
+        let command = parse_quick_entry(line, now).map_err(|error| error.to_string())?;
+        
+        let api = self.api();
+        let result = if command.amount >= 0.0 {
+            let request = shared::AddMoneyRequest {
+                child_id: None,
+                description: command.description,
+                amount: command.amount,
+                date: command.date,
+                amount_expression: None,
+                actor: Some(Actor::Kid.to_storage()),
+                icon: None,
+                transaction_type: None,
+            };
+            api.add_money(request).map(|response| (response.success_message, response.new_balance))
+        } else {
+            let request = shared::SpendMoneyRequest {
+                child_id: None,
+                description: command.description,
+                amount: command.amount.abs(),
+                date: command.date,
+                parent_override: false,
+                amount_expression: None,
+                actor: Some(Actor::Kid.to_storage()),
+                icon: None,
+                transaction_type: None,
+            };
+            api.spend_money(request).map(|response| (response.success_message, response.new_balance))
+        };
+        
+        match result {
+            Ok((message, new_balance)) => {
+                info!("✅ Quick entry successful: {}", message);
+                self.core.current_balance = new_balance;
+                self.load_calendar_data();
+                self.table.invalidate();
+                Ok(message)
+            }
+            Err(error) => {
+                log::error!("❌ Quick entry failed: {}", error);
+                Err(error.to_string())
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use eframe::egui;
 use chrono::NaiveDate;
 use crate::ui::app_state::AllowanceTrackerApp;
-use super::types::DayMenuGlyph;
+use super::types::{CalendarDay, DayMenuGlyph};
 use super::styling::action_icons;
 
 impl AllowanceTrackerApp {
@@ -25,6 +25,52 @@ impl AllowanceTrackerApp {
         log::info!("📅 Selected day: {}", clicked_date);
     }
 
+    /// Move the focused day with the arrow keys while a day cell has keyboard focus.
+    ///
+    /// Tab reaches the day cells like any other widget, Left/Right move a day and
+    /// Up/Down a week, switching months at the edges. Enter or Space on the focused
+    /// cell arrives as an ordinary click, which selects the day and shows its actions.
+    pub fn handle_calendar_keyboard(&mut self, ui: &mut egui::Ui) {
+        let Some(focused_day) = self.calendar.focused_day else {
+            return;
+        };
+        let (has_focus, nothing_focused) = ui.memory(|memory| {
+            (memory.has_focus(CalendarDay::focus_id(focused_day)), memory.focused().is_none())
+        });
+        if !has_focus {
+            // While a new month loads its cell may not be drawn yet; the grid hands the
+            // focus back once it is. Otherwise focus went elsewhere or Escape dropped it.
+            if !(self.calendar.calendar_loading && nothing_focused) {
+                self.calendar.focused_day = None;
+            }
+            return;
+        }
+        
+        let step = ui.input_mut(|input| {
+            [
+                (egui::Key::ArrowLeft, -1),
+                (egui::Key::ArrowRight, 1),
+                (egui::Key::ArrowUp, -7),
+                (egui::Key::ArrowDown, 7),
+            ]
+            .into_iter()
+            .find(|(key, _)| input.consume_key(egui::Modifiers::NONE, *key))
+            .map(|(_, days)| days)
+        });
+        let Some(step) = step else {
+            return;
+        };
+        
+        match self.calendar.move_focus(step) {
+            -1 => self.navigate_to_previous_month(),
+            1 => self.navigate_to_next_month(),
+            _ => {}
+        }
+        if let Some(day) = self.calendar.focused_day {
+            ui.memory_mut(|memory| memory.request_focus(CalendarDay::focus_id(day)));
+        }
+    }
+
     /// Render action icons above the selected day
     pub fn render_day_action_icons(&mut self, ui: &mut egui::Ui, day_cell_rect: egui::Rect, selected_date: NaiveDate) {
         // Get glyphs that should be shown for this specific date
@@ -41,7 +87,7 @@ impl AllowanceTrackerApp {
         
         // Shared colors
         let outline_color = action_icons::outline_color();
-        let background_color = action_icons::background_color();
+        let background_color = action_icons::background_color(ui.visuals().dark_mode);
         let text_color = action_icons::text_color();
         
         // Calculate the actual width of the glyphs by measuring them
@@ -1,5 +1,6 @@
 use chrono::{NaiveDate, Datelike};
 use crate::ui::app_state::AllowanceTrackerApp;
+use crate::backend::domain::calendar::GOAL_COMPLETION_MARKER_ID;
 use super::types::{CalendarDay, CalendarDayType};
 
 impl AllowanceTrackerApp {
@@ -29,6 +30,9 @@ impl AllowanceTrackerApp {
         println!("🗓️  Navigation complete: {}/{} → {}/{}", 
                   old_month, old_year, self.calendar.selected_month, self.calendar.selected_year);
         
+        // A day jumped to stays highlighted only until the calendar moves on
+        self.backend().calendar_service.clear_highlight();
+        
         if self.calendar.selected_month == 6 {
             println!("🗓️  🎯 Navigated to June {} - about to load calendar data", self.calendar.selected_year);
         }
@@ -124,13 +128,17 @@ impl AllowanceTrackerApp {
                     if completion_naive_date == date {
                         // Create a goal "transaction" for the chip
                         let goal_transaction = shared::Transaction {
-                            id: "goal_completion".to_string(),
+                            id: GOAL_COMPLETION_MARKER_ID.to_string(),
                             child_id: goal.child_id.clone(),
                             amount: goal.target_amount, // Store the goal amount for display
                             description: goal.description.clone(), // Just the description for tooltip
                             date: completion_date,
                             balance: backend_day.balance, // Use the day's balance
                             transaction_type: shared::TransactionType::Income, // Dummy type for goal chip
+                            actor: None,
+                            category: None,
+                            icon: None,
+                            locked: false,
                         };
                         all_transactions.push(goal_transaction);
                     }
@@ -138,6 +146,28 @@ impl AllowanceTrackerApp {
             }
         }
         
+        // Add a chip for each purchase planned for this day
+        for purchase in self.calendar.planned_purchases.iter().filter(|p| p.planned_date == date) {
+            all_transactions.push(shared::Transaction {
+                id: purchase.id.clone(),
+                child_id: purchase.child_id.clone(),
+                amount: -purchase.amount,
+                description: format!("Planned: {}", purchase.description),
+                date: date
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(chrono::Local)
+                    .unwrap()
+                    .fixed_offset(),
+                balance: backend_day.balance,
+                transaction_type: shared::TransactionType::Expense, // Dummy type for planned purchase chip
+                actor: None,
+                category: None,
+                icon: None,
+                locked: false,
+            });
+        }
+        
         // DEBUG: Log July 21st conversion specifically (commented out - too verbose)
         // if date.month() == 7 && date.year() == 2025 && backend_day.day == 21 {
         //     log::info!("🔍 FRONTEND CONVERT DEBUG: July 21st - backend balance: ${:.2}, frontend balance: ${:.2}, transactions: {}", 
@@ -151,6 +181,7 @@ impl AllowanceTrackerApp {
             day_type,
             transactions: all_transactions,
             balance: Some(backend_day.balance),
+            digest: None,
         }
     }
 
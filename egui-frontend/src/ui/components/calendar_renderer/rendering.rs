@@ -21,7 +21,8 @@
 //!
 //! ## Features:
 //! - Interactive month navigation
-//! - Transaction chips displayed on calendar days
+//! - Transaction chips displayed on calendar days, with "+N more" when a day overflows
+//! - Right-click a day to add or spend money on it, or to open it up
 //! - Responsive grid layout
 //! - Kid-friendly visual design with gradients
 //! - Proper date handling using chrono library
@@ -30,6 +31,11 @@ use eframe::egui;
 use chrono::NaiveDate;
 use shared::Transaction;
 use crate::ui::app_state::AllowanceTrackerApp;
+use crate::ui::components::context_menus::day_context_menu;
+use crate::ui::components::goal_strip::GOAL_STRIP_HEIGHT;
+use crate::ui::components::styling::Theme;
+use crate::ui::state::{PendingMove, TutorialStep};
+use crate::ui::components::ui_components::{measure_text_width, truncate_to_width};
 
 // Import types, styling, and layout from the same module
 use super::types::*;
@@ -63,6 +69,9 @@ impl CalendarDay {
             transaction_selection_mode: false,
             selected_transaction_ids: std::collections::HashSet::new(),
             expanded_day: None,
+            is_highlighted: false,
+            highlighted_transaction_id: None,
+            allow_moving: false,
         });
         response
     }
@@ -73,12 +82,25 @@ impl CalendarDay {
         // Initialize variable to collect checkbox clicks
         let mut clicked_transaction_ids = Vec::new();
         
-        // Allocate space for this day cell and get hover/click detection - same approach as chips
-        let (cell_rect, response) = ui.allocate_exact_size(egui::vec2(width, height), egui::Sense::hover().union(egui::Sense::click()));
+        // Allocate space for this day cell and get hover/click detection - same approach as chips.
+        // The id comes from the date so the keyboard focus can follow a day (see `handle_calendar_keyboard`),
+        // and Enter or Space on the focused cell counts as a click.
+        let (cell_rect, _) = ui.allocate_exact_size(egui::vec2(width, height), egui::Sense::hover());
+        let response = ui.interact(cell_rect, CalendarDay::focus_id(self.date), egui::Sense::hover().union(egui::Sense::click()));
+        response.widget_info(|| egui::WidgetInfo::selected(egui::WidgetType::Button, true, config.is_selected, self.accessibility_label()));
+        if response.has_focus() {
+            // Arrow keys move between days (by date, not by position) instead of to the nearest widget
+            ui.memory_mut(|memory| memory.set_focus_lock_filter(response.id, egui::EventFilter {
+                horizontal_arrows: true,
+                vertical_arrows: true,
+                ..Default::default()
+            }));
+        }
         let is_hovered = response.hovered();
         
         // Draw shadow first (behind everything else) for today's date
         if self.is_today {
+            TutorialStep::AddMoney.record_target(ui.ctx(), cell_rect);
             let shadow_rect = egui::Rect::from_min_size(
                 cell_rect.min + egui::vec2(2.0, 2.0),
                 cell_rect.size()
@@ -91,29 +113,19 @@ impl CalendarDay {
         }
         
         // Draw background for the day cell using centralized color scheme with hover effect
-        let base_bg_color = self.day_type.background_color(self.is_today);
+        let dark_mode = ui.visuals().dark_mode;
         let bg_color = if config.is_selected {
             // Selected day gets a purple-pink tint matching the Create Goal button
-            egui::Color32::from_rgba_unmultiplied(230, 190, 235, 140) // Purple-pink for selection
-        } else if is_hovered {
-            // Make more opaque when hovered - same approach as chips
-            if self.is_today {
-                // For today, make the yellow background more solid
-                egui::Color32::from_rgba_unmultiplied(255, 248, 220, 180) // More opaque yellow
+            if dark_mode {
+                egui::Color32::from_rgba_unmultiplied(110, 60, 130, 170)
             } else {
-                match self.day_type {
-                    CalendarDayType::CurrentMonth => {
-                        // Make current month days more opaque white
-                        egui::Color32::from_rgba_unmultiplied(255, 255, 255, 120) // More opaque white
-                    }
-                    CalendarDayType::FillerDay => {
-                        // Make filler days more opaque gray
-                        egui::Color32::from_rgba_unmultiplied(120, 120, 120, 160) // More opaque gray
-                    }
-                }
+                egui::Color32::from_rgba_unmultiplied(230, 190, 235, 140) // Purple-pink for selection
             }
+        } else if is_hovered {
+            // Make more opaque when hovered - same approach as chips
+            self.day_type.hover_background_color(self.is_today, dark_mode)
         } else {
-            base_bg_color
+            self.day_type.background_color(self.is_today, dark_mode)
         };
         
         ui.painter().rect_filled(
@@ -149,12 +161,12 @@ impl CalendarDay {
             ui.painter().rect_stroke(
                 outer_rect,
                 egui::CornerRadius::same(2),
-                egui::Stroke::new(2.0, self.day_type.border_color(self.is_today)),
+                egui::Stroke::new(2.0, self.day_type.border_color(self.is_today, dark_mode)),
                 egui::StrokeKind::Outside
             );
         } else {
             // Normal single outline for other days
-            let border_color = self.day_type.border_color(self.is_today);
+            let border_color = self.day_type.border_color(self.is_today, dark_mode);
             ui.painter().rect_stroke(
                 cell_rect,
                 egui::CornerRadius::same(2),
@@ -163,6 +175,40 @@ impl CalendarDay {
             );
         }
         
+        // The day a search hit or "go to date" jumped to gets a gold ring over its other outlines
+        if config.is_highlighted {
+            ui.painter().rect_stroke(
+                cell_rect,
+                egui::CornerRadius::same(2),
+                egui::Stroke::new(jump_highlight::DAY_STROKE_WIDTH, jump_highlight::COLOR),
+                egui::StrokeKind::Outside
+            );
+        }
+        
+        // A chip dragged over another day outlines that day as where it will land
+        if config.allow_moving {
+            if let Some(dragged) = response.dnd_hover_payload::<DraggedChip>() {
+                if dragged.date.date_naive() != self.date {
+                    ui.painter().rect_stroke(
+                        cell_rect.shrink(1.0),
+                        egui::CornerRadius::same(2),
+                        egui::Stroke::new(2.5, Theme::current(ui.ctx()).interactive.hover_border),
+                        egui::StrokeKind::Inside
+                    );
+                }
+            }
+        }
+        
+        // Keyboard focus ring, drawn outside the selection and today outlines so both stay visible
+        if response.has_focus() {
+            ui.painter().rect_stroke(
+                cell_rect.expand(3.0),
+                egui::CornerRadius::same(3),
+                egui::Stroke::new(2.0, ui.visuals().selection.stroke.color),
+                egui::StrokeKind::Outside
+            );
+        }
+        
         // Draw the content within the allocated cell rectangle
         let ui_result = ui.allocate_new_ui(egui::UiBuilder::new().max_rect(cell_rect), |ui| {
             ui.vertical(|ui| {
@@ -184,7 +230,7 @@ impl CalendarDay {
                         let day_font_size = get_day_number_font_size(config.is_grid_layout, width);
                         
                         // Day number text color using centralized color scheme
-                        let day_text_color = self.day_type.day_text_color();
+                        let day_text_color = self.day_type.day_text_color(dark_mode);
                         
                         // Create the rich text with emphasis for today
                         let rich_text = egui::RichText::new(self.day_number.to_string())
@@ -207,7 +253,7 @@ impl CalendarDay {
                             let underline_y = text_rect.bottom() + 1.0; // 1px below text
                             let left_padding = 3.0; // More padding on left side
                             let right_padding = 2.0; // Less padding on right side
-                            let underline_color = egui::Color32::from_rgb(80, 80, 80); // Dark gray
+                            let underline_color = if dark_mode { egui::Color32::from_rgb(200, 200, 200) } else { egui::Color32::from_rgb(80, 80, 80) }; // Dark gray
                             ui.painter().line_segment(
                                 [
                                     egui::pos2(text_rect.left() + left_padding, underline_y),
@@ -229,7 +275,7 @@ impl CalendarDay {
                                 let balance_font_size = get_balance_font_size(config.is_grid_layout, width);
                                 
                                 // Balance text color using centralized color scheme
-                                let balance_color = self.day_type.balance_text_color();
+                                let balance_color = self.day_type.balance_text_color(dark_mode);
                                 
                                 ui.add(egui::Label::new(
                                     egui::RichText::new(format!("${:.2}", balance))
@@ -246,12 +292,20 @@ impl CalendarDay {
                 
                 // Transaction chips below - vertically stacked
                 // Convert transactions to calendar chips
-                let chips = CalendarChip::from_transactions(self.transactions.clone(), config.is_grid_layout);
+                let is_expanded = config.expanded_day == Some(self.date);
+                // A summarized day shows the backend's pick of its largest transactions
+                let digest = self.digest.as_ref().filter(|digest| !is_expanded && digest.overflow_count > 0);
+                let chips = match digest {
+                    Some(digest) => CalendarChip::from_transactions(digest.visible.clone(), config.is_grid_layout),
+                    None => CalendarChip::from_transactions(self.transactions.clone(), config.is_grid_layout),
+                };
                 
                 // Calculate how many chips can fit dynamically based on available space
-                let (chips_to_show_count, needs_ellipsis) = if config.expanded_day == Some(self.date) {
+                let (chips_to_show_count, needs_ellipsis) = if is_expanded {
                     // Day is expanded - show all transaction chips, no ellipsis needed
                     (chips.len(), false)
+                } else if digest.is_some() {
+                    (chips.len(), true)
                 } else {
                     // Normal calculation based on available space
                     calculate_transaction_display_limit(height, chips.len())
@@ -267,9 +321,12 @@ impl CalendarDay {
                     ui.add_space(1.0); // Smaller spacing between chips due to padding
                 }
                 
-                // Show ellipsis chip for normal state (not expanded)
-                if !config.expanded_day.map_or(false, |expanded_date| expanded_date == self.date) && needs_ellipsis {
-                    let ellipsis_chip = CalendarChip::create_ellipsis();
+                // Show "+N more" chip for normal state (not expanded); clicking it opens the whole day
+                if !is_expanded && needs_ellipsis {
+                    let ellipsis_chip = match digest {
+                        Some(digest) => CalendarChip::create_overflow(digest.overflow_count, Some(digest.overflow_net)),
+                        None => CalendarChip::create_overflow(chips.len() - chips_to_show_count, None),
+                    };
                     if let Some(result) = self.render_calendar_chip(ui, &ellipsis_chip, width - 8.0, height, config) {
                         if result == "ELLIPSIS_CLICKED" {
                             local_clicked_ids.push("ELLIPSIS_CLICKED".to_string());
@@ -292,11 +349,12 @@ impl CalendarDay {
             // Check for hover and click
             let collapse_response = ui.allocate_rect(collapse_rect, egui::Sense::hover().union(egui::Sense::click()));
             
-            // Style as solid white bar (no border)
+            // Style as solid bar (no border), white like the chips
+            let theme = Theme::current(ui.ctx());
             let collapse_bg_color = if collapse_response.hovered() {
-                egui::Color32::from_rgba_unmultiplied(245, 245, 245, 255) // Very light gray on hover
+                theme.calendar.chip_hover_background // Very light gray on hover
             } else {
-                egui::Color32::WHITE // Solid white
+                theme.calendar.chip_background // Solid white
             };
             
             // Draw collapse button background - no rounding for perfect border alignment
@@ -346,9 +404,9 @@ impl CalendarDay {
         // Get the font family for calendar rendering
         let font_family = get_calendar_font_family(ui.ctx());
         
-        // Get chip styling from the chip type
-        let chip_color = chip.chip_type.primary_color();
-        let text_color = chip.chip_type.text_color();
+        // Get chip styling from the chip and its transaction kind
+        let chip_color = chip.color();
+        let text_color = chip.text_color();
         let uses_dotted_border = chip.chip_type.uses_dotted_border();
         
         // Calculate chip dimensions based on layout
@@ -356,7 +414,7 @@ impl CalendarDay {
         
         // Check if we should show checkbox (only for deletable transactions in selection mode)
         let show_checkbox = config.transaction_selection_mode && 
-                            !matches!(chip.chip_type, CalendarChipType::FutureAllowance | CalendarChipType::Goal);
+                            !matches!(chip.chip_type, CalendarChipType::FutureAllowance | CalendarChipType::Goal | CalendarChipType::PlannedPurchase);
         let checkbox_width = if show_checkbox { 16.0 } else { 0.0 };
         let checkbox_spacing = if show_checkbox { 4.0 } else { 0.0 };
         
@@ -368,9 +426,12 @@ impl CalendarDay {
         };
         
         // Opaque white background for all transaction chips
-        let chip_background = egui::Color32::from_rgba_unmultiplied(255, 255, 255, 255);
+        let theme = Theme::current(ui.ctx());
+        let chip_background = theme.calendar.chip_background;
         
         let mut checkbox_clicked = None;
+        let is_highlighted = config.highlighted_transaction_id.as_deref() == Some(chip.transaction.id.as_str());
+        let highlight_stroke = egui::Stroke::new(jump_highlight::CHIP_STROKE_WIDTH, jump_highlight::COLOR);
         
         ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
             if show_checkbox {
@@ -397,7 +458,7 @@ impl CalendarDay {
                     
                     // Background color - slightly darker when hovered
                     let background_color = if is_hovered {
-                        egui::Color32::from_rgba_unmultiplied(245, 245, 245, 255) // Light gray on hover
+                        theme.calendar.chip_hover_background // Light gray on hover
                     } else {
                         chip_background
                     };
@@ -421,13 +482,17 @@ impl CalendarDay {
                             egui::StrokeKind::Outside
             );
                     }
+                    if is_highlighted {
+                        ui.painter().rect_stroke(rect, egui::CornerRadius::same(4), highlight_stroke, egui::StrokeKind::Outside);
+                    }
                     
-                    // Draw text
+                    // Draw text, shortened if an emoji or long amount doesn't fit
+                    let chip_font = egui::FontId::new(chip_font_size, font_family.clone());
                     ui.painter().text(
                         rect.center(),
                         egui::Align2::CENTER_CENTER,
-                        &chip.display_amount,
-                        egui::FontId::new(chip_font_size, font_family.clone()),
+                        truncate_to_width(ui, &chip.display_amount, chip_font.clone(), rect.width() - 4.0),
+                        chip_font,
                         text_color,
                     );
                     
@@ -438,8 +503,11 @@ impl CalendarDay {
                 });
             } else {
                 // Original chip rendering without checkbox
+                let movable = config.allow_moving && chip.is_movable();
                 let sense = if matches!(chip.chip_type, CalendarChipType::Ellipsis) {
                     egui::Sense::hover().union(egui::Sense::click()) // Ellipsis chips are clickable
+                } else if movable {
+                    egui::Sense::hover().union(egui::Sense::drag()) // Stored transactions can be dragged to another day
                 } else {
                     egui::Sense::hover() // Regular chips only hover
                 };
@@ -455,7 +523,7 @@ impl CalendarDay {
                 
                 // Background color - slightly darker when hovered
                 let background_color = if is_hovered {
-                    egui::Color32::from_rgba_unmultiplied(245, 245, 245, 255) // Light gray on hover
+                    theme.calendar.chip_hover_background // Light gray on hover
                 } else {
                     chip_background
                 };
@@ -479,13 +547,17 @@ impl CalendarDay {
                         egui::StrokeKind::Outside
             );
                 }
+                if is_highlighted {
+                    ui.painter().rect_stroke(rect, egui::CornerRadius::same(4), highlight_stroke, egui::StrokeKind::Outside);
+                }
                 
-                // Draw text
+                // Draw text, shortened if an emoji or long amount doesn't fit
+                let chip_font = egui::FontId::new(chip_font_size, font_family.clone());
                 ui.painter().text(
                     rect.center(),
                     egui::Align2::CENTER_CENTER,
-                    &chip.display_amount,
-                    egui::FontId::new(chip_font_size, font_family.clone()),
+                    truncate_to_width(ui, &chip.display_amount, chip_font.clone(), rect.width() - 4.0),
+                    chip_font,
                     text_color,
                 );
                 
@@ -493,6 +565,35 @@ impl CalendarDay {
                 if is_hovered && !chip.transaction.description.is_empty() {
                     self.show_transaction_tooltip(ui, &chip.transaction.description, rect);
                 }
+                
+                if movable {
+                    if response.drag_started() {
+                        egui::DragAndDrop::set_payload(ui.ctx(), DraggedChip {
+                            transaction_id: chip.transaction.id.clone(),
+                            description: chip.transaction.description.clone(),
+                            date: chip.transaction.date,
+                        });
+                    }
+                    if response.dragged() {
+                        // A copy of the chip follows the pointer until it is dropped
+                        ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+                        if let Some(pointer) = ui.ctx().pointer_interact_pos() {
+                            let painter = ui.ctx().layer_painter(egui::LayerId::new(egui::Order::Tooltip, egui::Id::new("dragged_calendar_chip")));
+                            let ghost = egui::Rect::from_center_size(pointer, rect.size());
+                            painter.rect_filled(ghost, egui::CornerRadius::same(4), chip_background);
+                            painter.rect_stroke(ghost, egui::CornerRadius::same(4), egui::Stroke::new(1.5, chip_color), egui::StrokeKind::Outside);
+                            painter.text(
+                                ghost.center(),
+                                egui::Align2::CENTER_CENTER,
+                                &chip.display_amount,
+                                egui::FontId::new(chip_font_size, font_family.clone()),
+                                text_color,
+                            );
+                        }
+                    } else if is_hovered {
+                        ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
+                    }
+                }
             }
         });
         
@@ -507,14 +608,14 @@ impl CalendarDay {
         // Get cursor position for tooltip positioning
         let cursor_pos = ui.ctx().pointer_interact_pos().unwrap_or(chip_rect.center());
         
-        // Calculate tooltip dimensions (estimate based on text length)
+        // Calculate tooltip dimensions
         let tooltip_font_size = tooltip::FONT_SIZE;
         let tooltip_padding = tooltip::PADDING;
         let max_tooltip_width = tooltip::MAX_WIDTH;
         
-        // Estimate tooltip size (rough approximation)
-        let char_width = tooltip_font_size * 0.6; // Approximate character width
-        let text_width = (description.len() as f32 * char_width).min(max_tooltip_width);
+        // Measure the description as it will render, emoji and other scripts included
+        let text_width = measure_text_width(ui, description, egui::FontId::new(tooltip_font_size, font_family.clone()))
+            .min(max_tooltip_width);
         let text_height = tooltip_font_size * 1.2; // Line height
         let tooltip_size = egui::vec2(text_width + tooltip_padding.x * 2.0, text_height + tooltip_padding.y * 2.0);
         
@@ -735,6 +836,18 @@ impl AllowanceTrackerApp {
         ui.add_space(15.0);
         // Add top spacing for visual separation
         
+        TutorialStep::Calendar.record_target(ui.ctx(), available_rect.shrink(20.0));
+        
+        // The active goal's progress, when turned on, sits above the calendar
+        if self.calendar.show_goal_strip && self.goal.has_active_goal() {
+            let strip_rect = egui::Rect::from_min_size(
+                egui::pos2(available_rect.left() + 20.0, ui.available_rect_before_wrap().top() + 20.0),
+                egui::vec2(available_rect.width() - 40.0, GOAL_STRIP_HEIGHT),
+            );
+            self.draw_goal_strip(ui, strip_rect);
+            ui.add_space(GOAL_STRIP_HEIGHT);
+        }
+        
         // Calculate responsive dimensions - same as original
         let content_width = available_rect.width() - 40.0;
         
@@ -768,7 +881,13 @@ impl AllowanceTrackerApp {
         
         let rows_needed = (calendar_days_count as f32 / 7.0).ceil();
         let vertical_spacing = CALENDAR_CARD_SPACING * (rows_needed - 1.0);
-        let available_height_for_cells = final_card_height - calendar_container_padding - header_height - vertical_spacing;
+        // Each weekly totals row takes its own height plus one more gap
+        let week_summary_space = if self.calendar.show_week_summaries {
+            rows_needed * (week_summary::ROW_HEIGHT + CALENDAR_CARD_SPACING)
+        } else {
+            0.0
+        };
+        let available_height_for_cells = final_card_height - calendar_container_padding - header_height - vertical_spacing - week_summary_space;
         let dynamic_cell_height = (available_height_for_cells / rows_needed).max(40.0).min(200.0);
         
         // Dynamic cell height calculation complete
@@ -809,7 +928,7 @@ impl AllowanceTrackerApp {
                                                 let header_rect = ui.available_rect_before_wrap();
                                                 
                                                 // Draw card-like background
-                                                let bg_color = header::background_color();
+                                                let bg_color = header::background_color(ui.visuals().dark_mode);
                                                 ui.painter().rect_filled(
                                                     header_rect,
                                                     egui::CornerRadius::same(2),
@@ -817,7 +936,7 @@ impl AllowanceTrackerApp {
                                                 );
                                                 
                                                 // Draw border
-                                                let border_color = header::border_color();
+                                                let border_color = header::border_color(ui.visuals().dark_mode);
                                                 ui.painter().rect_stroke(
                                                     header_rect,
                                                     egui::CornerRadius::same(2),
@@ -829,7 +948,7 @@ impl AllowanceTrackerApp {
                                                 ui.add(egui::Label::new(egui::RichText::new(*day_name)
                                                     .font(egui::FontId::new(header::HEADER_FONT_SIZE, font_family.clone()))
                                                     .strong()
-                                                    .color(egui::Color32::DARK_GRAY))
+                                                    .color(header::text_color(ui.visuals().dark_mode)))
                                                     .selectable(false));
                                             },
                                         );
@@ -857,8 +976,10 @@ impl AllowanceTrackerApp {
     /// Draw calendar days with responsive sizing using CalendarDay components
     pub fn draw_calendar_days_responsive(&mut self, ui: &mut egui::Ui, _transactions: &[Transaction], cell_width: f32, cell_height: f32) {
         ui.spacing_mut().item_spacing.y = CALENDAR_CARD_SPACING; // Vertical spacing between week rows
+        // Arrow keys first, so a move into another month loads it before the grid is built
+        self.handle_calendar_keyboard(ui);
         // Use calendar month data from backend (which includes balance data)
-        let all_days: Vec<CalendarDay> = if let Some(ref calendar_month) = self.calendar.calendar_month {
+        let mut all_days: Vec<CalendarDay> = if let Some(ref calendar_month) = self.calendar.calendar_month {
             // Convert backend calendar days to frontend calendar days
             calendar_month.days.iter()
                 .enumerate()
@@ -878,7 +999,15 @@ impl AllowanceTrackerApp {
         // Process days in chunks of 7 (one week per row)
         let mut selected_day_rect: Option<egui::Rect> = None;
         let mut selected_day_date: Option<NaiveDate> = None;
-        for (_week_index, week_days) in all_days.chunks(7).enumerate() {
+        let week_summaries: Vec<shared::CalendarWeekSummary> = if self.calendar.show_week_summaries {
+            self.calendar.calendar_month.as_ref().map(|month| month.weeks.clone()).unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        let font_family = get_calendar_font_family(ui.ctx());
+        let highlight = self.core.backend.calendar_service.get_highlight();
+        let mut dropped: Option<(DraggedChip, NaiveDate)> = None;
+        for (week_index, week_days) in all_days.chunks_mut(7).enumerate() {
             // Calculate row height - use expanded height if any day in this row is expanded
             let row_height = if let Some(expanded_day) = week_days.iter().find(|day| self.calendar.expanded_day == Some(day.date)) {
                 // Calculate exact height needed for all chips in the expanded day
@@ -902,6 +1031,14 @@ impl AllowanceTrackerApp {
                 cell_height // Normal height
             };
             
+            // Days with more transactions than fit are summarized by the backend
+            for calendar_day in week_days.iter_mut() {
+                let (visible_count, overflows) = calculate_transaction_display_limit(row_height, calendar_day.transactions.len());
+                calendar_day.digest = overflows.then(|| {
+                    self.core.backend.calendar_service.summarize_day(&calendar_day.transactions, visible_count)
+                });
+            }
+            
             let _week_response = ui.horizontal(|ui| {
                 ui.spacing_mut().item_spacing.x = CALENDAR_CARD_SPACING; // Horizontal spacing between day cards
                 for calendar_day in week_days.iter() {
@@ -911,6 +1048,7 @@ impl AllowanceTrackerApp {
                         |ui| {
                             // Check if this day is selected
                             let is_selected = self.calendar.selected_day == Some(calendar_day.date);
+                            let highlight = highlight.as_ref().filter(|highlight| highlight.date == calendar_day.date);
                             
                             let (response, clicked_transaction_ids) = calendar_day.render_with_config(ui, cell_width, row_height, &RenderConfig {
                                 is_grid_layout: true,
@@ -919,8 +1057,18 @@ impl AllowanceTrackerApp {
                                 transaction_selection_mode: self.interaction.transaction_selection_mode,
                                 selected_transaction_ids: self.interaction.selected_transaction_ids.clone(),
                                 expanded_day: self.calendar.expanded_day,
+                                is_highlighted: highlight.is_some(),
+                                highlighted_transaction_id: highlight.and_then(|highlight| highlight.transaction_id.clone()),
+                                allow_moving: !self.interaction.transaction_selection_mode,
                             });
                             
+                            // A chip dropped here from another day moves its transaction to this day
+                            if let Some(dragged) = response.dnd_release_payload::<DraggedChip>() {
+                                if dragged.date.date_naive() != calendar_day.date {
+                                    dropped = Some(((*dragged).clone(), calendar_day.date));
+                                }
+                            }
+                            
                             // Handle checkbox clicks on transactions, ellipsis clicks, and collapse clicks
                             for transaction_id in clicked_transaction_ids {
                                 if transaction_id == "ELLIPSIS_CLICKED" {
@@ -938,11 +1086,26 @@ impl AllowanceTrackerApp {
                                 }
                             }
                             
+                            // Remember the day a Tab press landed on so the arrow keys start from it,
+                            // and give the focus back to a day whose month just finished loading
+                            if response.has_focus() {
+                                self.calendar.focused_day = Some(calendar_day.date);
+                            } else if self.calendar.focused_day == Some(calendar_day.date) && ui.memory(|memory| memory.focused().is_none()) {
+                                response.request_focus();
+                            }
+                            
                             // Handle click detection for current month days only
                             if response.clicked() && matches!(calendar_day.day_type, CalendarDayType::CurrentMonth) {
                                 self.handle_calendar_day_click(calendar_day.date);
                             }
                             
+                            // Right-clicking a day of this month offers what can be done on it
+                            if matches!(calendar_day.day_type, CalendarDayType::CurrentMonth) {
+                                if let Some(action) = day_context_menu(&response, calendar_day.date) {
+                                    self.handle_day_menu_action(calendar_day.date, action);
+                                }
+                            }
+                            
                             // Return whether this day is selected for later rect capture
                             is_selected && matches!(calendar_day.day_type, CalendarDayType::CurrentMonth)
                         },
@@ -958,6 +1121,10 @@ impl AllowanceTrackerApp {
                 }
             });
             
+            if let Some(summary) = week_summaries.get(week_index) {
+                draw_week_summary_row(ui, summary, cell_width * 7.0 + CALENDAR_CARD_SPACING * 6.0, &font_family);
+            }
+            
             // No vertical spacing between week rows
         }
         
@@ -967,6 +1134,15 @@ impl AllowanceTrackerApp {
         if let (Some(day_rect), Some(day_date)) = (selected_day_rect, selected_day_date) {
             self.render_day_action_icons(ui, day_rect, day_date);
         }
+        
+        if let Some((dragged, day)) = dropped {
+            self.request_transaction_move(PendingMove {
+                transaction_id: dragged.transaction_id,
+                description: dragged.description,
+                from: dragged.date,
+                to: day,
+            });
+        }
     }
     
 
@@ -976,4 +1152,29 @@ impl AllowanceTrackerApp {
 
     
 
-} 
\ No newline at end of file
+}
+
+/// Draw the totals row under one calendar week
+fn draw_week_summary_row(ui: &mut egui::Ui, summary: &shared::CalendarWeekSummary, width: f32, font_family: &egui::FontFamily) {
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(width, week_summary::ROW_HEIGHT), egui::Sense::hover());
+    ui.painter().rect_filled(rect, egui::CornerRadius::same(2), week_summary::background_color(ui.visuals().dark_mode));
+    
+    let sign = if summary.net_change < 0.0 { "-" } else { "+" };
+    let text = format!(
+        "Earned ${:.2}   Spent ${:.2}   Net {}${:.2}   Ending balance ${:.2}",
+        summary.total_earned,
+        summary.total_spent,
+        sign,
+        summary.net_change.abs(),
+        summary.ending_balance
+    );
+    ui.painter().text(
+        rect.center(),
+        egui::Align2::CENTER_CENTER,
+        text,
+        egui::FontId::new(week_summary::FONT_SIZE, font_family.clone()),
+        week_summary::net_color(summary.net_change),
+    );
+    
+    response.on_hover_text(format!("Days {} to {} of the month", summary.first_day, summary.last_day));
+}
@@ -66,13 +66,30 @@ pub mod header {
     pub const HEADER_FONT_SIZE: f32 = 12.0;
     
     /// Background color for day headers - TESTING: More opaque to compensate for egui 0.32 transparency changes
-    pub fn background_color() -> eframe::egui::Color32 {
-    eframe::egui::Color32::from_rgba_unmultiplied(255, 255, 255, 180)
-}
+    pub fn background_color(dark_mode: bool) -> eframe::egui::Color32 {
+        if dark_mode {
+            eframe::egui::Color32::from_rgba_unmultiplied(30, 32, 42, 200)
+        } else {
+            eframe::egui::Color32::from_rgba_unmultiplied(255, 255, 255, 180)
+        }
+    }
     
     /// Border color for day headers
-    pub fn border_color() -> eframe::egui::Color32 {
-        eframe::egui::Color32::from_rgba_unmultiplied(150, 150, 150, 200)
+    pub fn border_color(dark_mode: bool) -> eframe::egui::Color32 {
+        if dark_mode {
+            eframe::egui::Color32::from_rgba_unmultiplied(90, 92, 106, 200)
+        } else {
+            eframe::egui::Color32::from_rgba_unmultiplied(150, 150, 150, 200)
+        }
+    }
+    
+    /// Text color for the day names
+    pub fn text_color(dark_mode: bool) -> eframe::egui::Color32 {
+        if dark_mode {
+            eframe::egui::Color32::LIGHT_GRAY
+        } else {
+            eframe::egui::Color32::DARK_GRAY
+        }
     }
 }
 
@@ -132,12 +149,54 @@ pub mod action_icons {
     }
     
     /// Background color for action icons
-    pub fn background_color() -> egui::Color32 {
-        egui::Color32::WHITE
+    pub fn background_color(dark_mode: bool) -> egui::Color32 {
+        if dark_mode {
+            egui::Color32::from_rgb(46, 48, 60)
+        } else {
+            egui::Color32::WHITE
+        }
     }
     
     /// Text color for action icons
     pub fn text_color() -> egui::Color32 {
         outline_color() // Same pink as outline
     }
-} 
\ No newline at end of file
+} 
+/// Weekly totals row styling constants
+pub mod week_summary {
+    use eframe::egui;
+    
+    /// Height of the totals row under each week
+    pub const ROW_HEIGHT: f32 = 20.0;
+    
+    /// Font size for the totals
+    pub const FONT_SIZE: f32 = 11.0;
+    
+    /// Background color, matching the day headers
+    pub fn background_color(dark_mode: bool) -> egui::Color32 {
+        super::header::background_color(dark_mode)
+    }
+    
+    /// Text color for a week that ended up or down
+    pub fn net_color(net_change: f64) -> egui::Color32 {
+        if net_change < 0.0 {
+            egui::Color32::from_rgb(128, 128, 128) // Gray like expense chips
+        } else {
+            egui::Color32::from_rgb(46, 160, 67) // Green like income chips
+        }
+    }
+}
+
+/// Styling for the day and chip a search hit or "go to date" jumped to
+pub mod jump_highlight {
+    use eframe::egui;
+    
+    /// Ring around the highlighted day and chip, gold so it stands out from selection and focus
+    pub const COLOR: egui::Color32 = egui::Color32::from_rgb(255, 190, 40);
+    
+    /// Width of the ring around the highlighted day
+    pub const DAY_STROKE_WIDTH: f32 = 3.0;
+    
+    /// Width of the ring around the highlighted chip
+    pub const CHIP_STROKE_WIDTH: f32 = 2.0;
+}
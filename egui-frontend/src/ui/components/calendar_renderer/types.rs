@@ -1,7 +1,11 @@
 use eframe::egui;
 use chrono::NaiveDate;
 use shared::Transaction;
+use crate::backend::domain::calendar::GOAL_COMPLETION_MARKER_ID;
+use crate::backend::domain::models::planned_purchase::PlannedPurchase;
+use crate::backend::domain::models::transaction_icon::icon_glyph;
 use crate::ui::app_state::OverlayType;
+use crate::ui::components::styling::{colors, Theme};
 
 /// Represents the different types of day menu glyphs that can be displayed above a selected day
 #[derive(Debug, Clone, PartialEq)]
@@ -66,69 +70,110 @@ pub enum CalendarDayType {
 
 impl CalendarDayType {
     /// Get the background color for this day type
-    pub fn background_color(&self, is_today: bool) -> egui::Color32 {
+    pub fn background_color(&self, is_today: bool, dark_mode: bool) -> egui::Color32 {
         if is_today {
-            // Light yellow tint for today - original value  
-            egui::Color32::from_rgba_unmultiplied(255, 248, 220, 110)
+            if dark_mode {
+                // Dim amber for today, so it glows without glaring
+                egui::Color32::from_rgba_unmultiplied(90, 80, 40, 150)
+            } else {
+                // Light yellow tint for today - original value  
+                egui::Color32::from_rgba_unmultiplied(255, 248, 220, 110)
+            }
         } else {
-            match self {
-                CalendarDayType::CurrentMonth => {
+            match (self, dark_mode) {
+                (CalendarDayType::CurrentMonth, false) => {
                     // Semi-transparent white background - original value
                     egui::Color32::from_rgba_unmultiplied(255, 255, 255, 55)
                 }
-                CalendarDayType::FillerDay => {
+                (CalendarDayType::CurrentMonth, true) => {
+                    // Semi-transparent slate over the darkened background
+                    egui::Color32::from_rgba_unmultiplied(40, 42, 54, 150)
+                }
+                (CalendarDayType::FillerDay, false) => {
                     // Darker gray for filler days (increased opacity for better visibility)
                     egui::Color32::from_rgba_unmultiplied(120, 120, 120, 120)
                 }
+                (CalendarDayType::FillerDay, true) => {
+                    // Nearly black for filler days so the current month stands out
+                    egui::Color32::from_rgba_unmultiplied(15, 15, 20, 170)
+                }
+            }
+        }
+    }
+
+    /// Get the background color for this day type while the pointer is over it
+    pub fn hover_background_color(&self, is_today: bool, dark_mode: bool) -> egui::Color32 {
+        if is_today {
+            if dark_mode {
+                egui::Color32::from_rgba_unmultiplied(110, 98, 50, 200)
+            } else {
+                // For today, make the yellow background more solid
+                egui::Color32::from_rgba_unmultiplied(255, 248, 220, 180) // More opaque yellow
+            }
+        } else {
+            match (self, dark_mode) {
+                // Make current month days more opaque white
+                (CalendarDayType::CurrentMonth, false) => egui::Color32::from_rgba_unmultiplied(255, 255, 255, 120),
+                (CalendarDayType::CurrentMonth, true) => egui::Color32::from_rgba_unmultiplied(62, 64, 80, 200),
+                // Make filler days more opaque gray
+                (CalendarDayType::FillerDay, false) => egui::Color32::from_rgba_unmultiplied(120, 120, 120, 160),
+                (CalendarDayType::FillerDay, true) => egui::Color32::from_rgba_unmultiplied(25, 25, 32, 210),
             }
         }
     }
 
     /// Get the border color for this day type
-    pub fn border_color(&self, is_today: bool) -> egui::Color32 {
+    pub fn border_color(&self, is_today: bool, dark_mode: bool) -> egui::Color32 {
         if is_today {
             // Pink outline for better visibility against gradient background
             egui::Color32::from_rgb(232, 150, 199)
         } else {
-            match self {
-                CalendarDayType::CurrentMonth => {
+            match (self, dark_mode) {
+                (CalendarDayType::CurrentMonth, false) => {
                     // Normal border
                     egui::Color32::from_rgba_unmultiplied(200, 200, 200, 100)
                 }
-                CalendarDayType::FillerDay => {
+                (CalendarDayType::CurrentMonth, true) => {
+                    egui::Color32::from_rgba_unmultiplied(100, 102, 118, 140)
+                }
+                (CalendarDayType::FillerDay, false) => {
                     // Lighter border for filler days (increased opacity for better visibility)
                     egui::Color32::from_rgba_unmultiplied(150, 150, 150, 140)
                 }
+                (CalendarDayType::FillerDay, true) => {
+                    egui::Color32::from_rgba_unmultiplied(60, 60, 70, 160)
+                }
             }
         }
     }
 
     /// Get the day number text color for this day type
-    pub fn day_text_color(&self) -> egui::Color32 {
-        // Use normal colors for all days
+    pub fn day_text_color(&self, dark_mode: bool) -> egui::Color32 {
         match self {
             CalendarDayType::CurrentMonth => {
-                // Bold black for current month days (including today)
-                egui::Color32::BLACK
+                // Bold black (or white in the dark theme) for current month days, including today
+                Theme::for_dark_mode(dark_mode).typography.body
             }
             CalendarDayType::FillerDay => {
                 // Gray for filler days
-                egui::Color32::from_rgb(150, 150, 150)
+                if dark_mode {
+                    egui::Color32::from_rgb(110, 110, 120)
+                } else {
+                    egui::Color32::from_rgb(150, 150, 150)
+                }
             }
         }
     }
 
     /// Get the balance text color for this day type
-    pub fn balance_text_color(&self) -> egui::Color32 {
-        match self {
-            CalendarDayType::CurrentMonth => {
-                // Normal gray
-                egui::Color32::GRAY
-            }
-            CalendarDayType::FillerDay => {
-                // More subdued gray for filler day balance
-                egui::Color32::from_rgb(120, 120, 120)
-            }
+    pub fn balance_text_color(&self, dark_mode: bool) -> egui::Color32 {
+        match (self, dark_mode) {
+            // Normal gray
+            (CalendarDayType::CurrentMonth, false) => egui::Color32::GRAY,
+            (CalendarDayType::CurrentMonth, true) => egui::Color32::from_rgb(175, 175, 185),
+            // More subdued gray for filler day balance
+            (CalendarDayType::FillerDay, false) => egui::Color32::from_rgb(120, 120, 120),
+            (CalendarDayType::FillerDay, true) => egui::Color32::from_rgb(100, 100, 110),
         }
     }
 }
@@ -144,7 +189,9 @@ pub enum CalendarChipType {
     FutureAllowance,
     /// Goal completion indicator
     Goal,
-    /// Ellipsis indicator for overflow transactions
+    /// Purchase the child has planned, with its money reserved
+    PlannedPurchase,
+    /// "+N more" indicator for transactions that don't fit in the cell
     Ellipsis,
 }
 
@@ -156,6 +203,7 @@ impl CalendarChipType {
             CalendarChipType::Income => egui::Color32::from_rgb(46, 160, 67), // Green for income
             CalendarChipType::FutureAllowance => egui::Color32::from_rgb(46, 160, 67), // Green for future allowances
             CalendarChipType::Goal => egui::Color32::from_rgb(199, 112, 221), // Purple for goals
+            CalendarChipType::PlannedPurchase => egui::Color32::from_rgb(214, 137, 16), // Amber for planned purchases
             CalendarChipType::Ellipsis => egui::Color32::from_rgb(120, 120, 120), // Medium gray for ellipsis
         }
     }
@@ -170,10 +218,19 @@ impl CalendarChipType {
     
     /// Whether this chip type should use a dotted border
     pub fn uses_dotted_border(&self) -> bool {
-        matches!(self, CalendarChipType::FutureAllowance)
+        matches!(self, CalendarChipType::FutureAllowance | CalendarChipType::PlannedPurchase)
     }
 }
 
+/// What is carried while a transaction chip is dragged to another day
+#[derive(Debug, Clone)]
+pub struct DraggedChip {
+    pub transaction_id: String,
+    pub description: String,
+    /// Where the transaction is before the move
+    pub date: chrono::DateTime<chrono::FixedOffset>,
+}
+
 /// Represents a transaction chip displayed on the calendar
 #[derive(Debug, Clone)]
 pub struct CalendarChip {
@@ -186,10 +243,15 @@ pub struct CalendarChip {
 }
 
 impl CalendarChip {
+    /// Whether this chip is a stored transaction that can be dragged to another day
+    pub fn is_movable(&self) -> bool {
+        matches!(self.chip_type, CalendarChipType::Expense | CalendarChipType::Income) && !self.transaction.locked
+    }
+    
     /// Create a new CalendarChip from a transaction
     pub fn from_transaction(transaction: Transaction, is_grid_layout: bool) -> Self {
         // Check if this is a goal transaction (special ID indicates goal chip)
-        if transaction.id == "goal_completion" {
+        if transaction.id == GOAL_COMPLETION_MARKER_ID {
             let display_amount = if is_grid_layout {
                 format!("Goal: ${:.2}", transaction.amount)
             } else {
@@ -202,11 +264,27 @@ impl CalendarChip {
             };
         }
         
-        // Determine chip type based on transaction type for regular transactions
-        let chip_type = match transaction.transaction_type {
-            shared::TransactionType::Income => CalendarChipType::Income,
-            shared::TransactionType::Expense => CalendarChipType::Expense,
-            shared::TransactionType::FutureAllowance => CalendarChipType::FutureAllowance,
+        // Planned purchase "transactions" carry the planned purchase's ID
+        if transaction.id.starts_with(PlannedPurchase::ID_PREFIX) {
+            let display_amount = if is_grid_layout {
+                format!("Plan: -${:.2}", transaction.amount.abs())
+            } else {
+                format!("Plan: -${:.0}", transaction.amount.abs())
+            };
+            return Self {
+                chip_type: CalendarChipType::PlannedPurchase,
+                transaction,
+                display_amount,
+            };
+        }
+        
+        // Projections get their own chip; everything else goes by the amount's sign
+        let chip_type = if transaction.transaction_type.is_projected() {
+            CalendarChipType::FutureAllowance
+        } else if transaction.amount >= 0.0 {
+            CalendarChipType::Income
+        } else {
+            CalendarChipType::Expense
         };
         
         // Format display amount based on type and layout
@@ -223,6 +301,11 @@ impl CalendarChip {
                 format!("-${:.0}", transaction.amount.abs())
             }
         };
+        let display_amount = match (&transaction.icon, transaction.transaction_type.emoji()) {
+            (Some(icon), _) => format!("{} {}", icon_glyph(icon), display_amount),
+            (None, Some(emoji)) if chip_type != CalendarChipType::FutureAllowance => format!("{} {}", emoji, display_amount),
+            _ => display_amount,
+        };
         
         Self {
             chip_type,
@@ -231,6 +314,26 @@ impl CalendarChip {
         }
     }
     
+    /// Border and fill color: the transaction kind's color for income and
+    /// expense chips, otherwise the chip type's
+    pub fn color(&self) -> egui::Color32 {
+        match self.chip_type {
+            CalendarChipType::Income | CalendarChipType::Expense => {
+                colors::transaction_kind_color(&self.transaction.transaction_type)
+                    .unwrap_or_else(|| self.chip_type.primary_color())
+            }
+            _ => self.chip_type.primary_color(),
+        }
+    }
+
+    /// Text color, following `color` like `CalendarChipType::text_color` does
+    pub fn text_color(&self) -> egui::Color32 {
+        match self.chip_type {
+            CalendarChipType::Income | CalendarChipType::Expense => self.color(),
+            _ => self.chip_type.text_color(),
+        }
+    }
+
     /// Convert a vector of transactions to calendar chips
     pub fn from_transactions(transactions: Vec<Transaction>, is_grid_layout: bool) -> Vec<Self> {
         transactions.into_iter()
@@ -238,23 +341,39 @@ impl CalendarChip {
             .collect()
     }
     
-    /// Create an ellipsis chip to indicate overflow transactions
-    pub fn create_ellipsis() -> Self {
-        // Create a dummy transaction for the ellipsis chip (only the display_amount matters)
+    /// Create the "+N more" chip that stands in for transactions that don't fit
+    ///
+    /// `hidden_net` is the net of the hidden transactions when the backend
+    /// summarized the day; the tooltip shows it so the cell still adds up.
+    pub fn create_overflow(hidden_count: usize, hidden_net: Option<f64>) -> Self {
+        let description = match hidden_net {
+            Some(net) => format!(
+                "{} more ({}${:.2}). Click to see the whole day",
+                hidden_count,
+                if net < 0.0 { "-" } else { "+" },
+                net.abs()
+            ),
+            None => format!("{} more. Click to see the whole day", hidden_count),
+        };
+        // Create a dummy transaction for the overflow chip (only the display_amount matters)
         let dummy_transaction = Transaction {
             id: "ellipsis".to_string(),
             child_id: "ellipsis".to_string(),
-            amount: 0.0,
-            description: "Click to see more transactions".to_string(),
+            amount: hidden_net.unwrap_or(0.0),
+            description,
             date: chrono::Utc::now().with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
             balance: 0.0,
             transaction_type: shared::TransactionType::Income, // Dummy type
+            actor: None,
+            category: None,
+            icon: None,
+            locked: false,
         };
         
         Self {
             chip_type: CalendarChipType::Ellipsis,
             transaction: dummy_transaction,
-            display_amount: "...".to_string(),
+            display_amount: format!("+{} more", hidden_count),
         }
     }
 
@@ -274,6 +393,8 @@ pub struct CalendarDay {
     pub transactions: Vec<Transaction>,
     /// The balance at the end of this day (for current month days only)
     pub balance: Option<f64>,
+    /// What fits in the cell when not every transaction does, from `CalendarService::summarize_day`
+    pub digest: Option<shared::CalendarDayDigest>,
 }
 
 /// Configuration for calendar day rendering
@@ -285,6 +406,12 @@ pub struct RenderConfig {
     pub transaction_selection_mode: bool,
     pub selected_transaction_ids: std::collections::HashSet<String>,
     pub expanded_day: Option<NaiveDate>,
+    /// Whether this day is the one a search hit or "go to date" jumped to
+    pub is_highlighted: bool,
+    /// Chip on the highlighted day to ring, if the jump was to a transaction
+    pub highlighted_transaction_id: Option<String>,
+    /// Whether transaction chips can be dragged to another day and dropped on this one
+    pub allow_moving: bool,
 }
 
 impl Default for RenderConfig {
@@ -296,6 +423,9 @@ impl Default for RenderConfig {
             transaction_selection_mode: false,
             selected_transaction_ids: std::collections::HashSet::new(),
             expanded_day: None,
+            is_highlighted: false,
+            highlighted_transaction_id: None,
+            allow_moving: false,
         }
     }
 }
@@ -310,6 +440,7 @@ impl CalendarDay {
             day_type,
             transactions: Vec::new(),
             balance: None,
+            digest: None,
         }
     }
 
@@ -319,4 +450,41 @@ impl CalendarDay {
         self.balance = Some(transaction.balance);
         self.transactions.push(transaction);
     }
-} 
\ No newline at end of file
+
+    /// Widget id of the cell for a date, so keyboard focus can be moved to a
+    /// day before it is drawn
+    pub fn focus_id(date: NaiveDate) -> egui::Id {
+        egui::Id::new(("calendar_day", date))
+    }
+
+    /// What a screen reader announces for this cell, e.g.
+    /// "June 19, balance $55.00, 2 transactions"
+    pub fn accessibility_label(&self) -> String {
+        let mut label = self.date.format("%B %-d").to_string();
+        if !matches!(self.day_type, CalendarDayType::CurrentMonth) {
+            return label;
+        }
+        if let Some(balance) = self.balance {
+            label.push_str(&format!(", balance ${:.2}", balance));
+        }
+        // Goal and planned purchase markers are announced on their own, not counted as money
+        let count = self.transactions.iter()
+            .filter(|transaction| transaction.id != GOAL_COMPLETION_MARKER_ID && !transaction.id.starts_with(PlannedPurchase::ID_PREFIX))
+            .count();
+        match count {
+            0 => label.push_str(", no transactions"),
+            1 => label.push_str(", 1 transaction"),
+            _ => label.push_str(&format!(", {} transactions", count)),
+        }
+        if self.transactions.iter().any(|transaction| transaction.id == GOAL_COMPLETION_MARKER_ID) {
+            label.push_str(", goal expected to complete");
+        }
+        if self.transactions.iter().any(|transaction| transaction.id.starts_with(PlannedPurchase::ID_PREFIX)) {
+            label.push_str(", planned purchase");
+        }
+        if self.is_today {
+            label.push_str(", today");
+        }
+        label
+    }
+}
@@ -0,0 +1,157 @@
+//! # Calendar Search
+//!
+//! This module contains the calendar's "go to date" picker and its search
+//! box, and the jump from either of them to a day on the calendar.
+//!
+//! ## Responsibilities:
+//! - Search the current child's transactions as the search text changes
+//! - List the hits under the search box, newest first
+//! - Show the month of a picked date or search hit, with that day (and the
+//!   hit's chip) highlighted
+//!
+//! ## Purpose:
+//! Finding last spring's birthday money shouldn't take a dozen clicks on the
+//! previous-month button. The highlight lives in the backend's
+//! `CalendarService` next to the focus month, so whichever view asks for the
+//! focus also knows which day to point at.
+
+use eframe::egui;
+use chrono::NaiveDate;
+use log::{info, warn};
+use crate::backend::domain::commands::transactions::TransactionSearchQuery;
+use crate::ui::app_state::{AllowanceTrackerApp, MainTab};
+use crate::ui::components::ui_components::truncate_to_width;
+
+/// Most search hits listed under the search box
+const MAX_SEARCH_RESULTS: usize = 8;
+
+/// Width of the search box and its results list
+const SEARCH_WIDTH: f32 = 260.0;
+
+impl AllowanceTrackerApp {
+    /// Draw the "go to date" picker and the search box with its results
+    pub fn draw_calendar_jump_controls(&mut self, ui: &mut egui::Ui) {
+        let picker = ui.add(
+            egui_extras::DatePickerButton::new(&mut self.calendar.jump_date)
+                .id_salt("calendar_jump_date")
+                .calendar_week(false)
+        ).on_hover_text("Go to a date");
+        if picker.changed() {
+            self.jump_to_calendar_date(self.calendar.jump_date, None);
+        }
+
+        ui.add_space(10.0);
+
+        if !self.calendar.search_text.is_empty() && ui.small_button("✖").on_hover_text("Clear search").clicked() {
+            self.calendar.clear_search();
+        }
+        let search = ui.add(
+            egui::TextEdit::singleline(&mut self.calendar.search_text)
+                .hint_text("🔍 Search transactions")
+                .desired_width(SEARCH_WIDTH)
+        );
+        if search.changed() {
+            self.search_calendar_transactions();
+        }
+        if search.gained_focus() && !self.calendar.search_text.trim().is_empty() {
+            self.calendar.search_open = true;
+        }
+        if ui.input(|input| input.key_pressed(egui::Key::Escape)) {
+            self.calendar.search_open = false;
+        }
+
+        if self.calendar.search_open {
+            self.draw_search_results(ui.ctx(), search.rect);
+        }
+    }
+
+    /// Show the month holding `date` with that day highlighted, and the
+    /// transaction's chip too when one is given
+    pub fn jump_to_calendar_date(&mut self, date: NaiveDate, transaction_id: Option<String>) {
+        info!("🔍 Jumping to {} (transaction {:?})", date, transaction_id);
+        let focus = self.backend().calendar_service.focus_on(date, transaction_id.clone());
+        self.calendar.selected_month = focus.month;
+        self.calendar.selected_year = focus.year as i32;
+        self.calendar.jump_date = date;
+        // A chip can be hidden behind the day's "more" chip, so open the day up
+        self.calendar.expanded_day = transaction_id.is_some().then_some(date);
+        self.set_current_tab(MainTab::Calendar);
+        self.load_calendar_data();
+    }
+
+    /// Find the current child's transactions matching the search text
+    fn search_calendar_transactions(&mut self) {
+        let text = self.calendar.search_text.trim().to_string();
+        if text.is_empty() {
+            self.calendar.search_results.clear();
+            self.calendar.search_has_more = false;
+            self.calendar.search_open = false;
+            return;
+        }
+        let child_id = self.core.current_child.as_ref().map(|child| child.id.clone());
+        let query = TransactionSearchQuery { child_id, text, limit: MAX_SEARCH_RESULTS };
+        match self.backend().transaction_service.search_transactions(query) {
+            Ok(result) => {
+                self.calendar.search_results = result.transactions;
+                self.calendar.search_has_more = result.has_more;
+                self.calendar.search_open = true;
+            }
+            Err(e) => {
+                warn!("🔍 Failed to search transactions: {}", e);
+                self.calendar.search_results.clear();
+                self.calendar.search_has_more = false;
+            }
+        }
+    }
+
+    /// List the search hits under the search box; clicking one jumps to it
+    fn draw_search_results(&mut self, ctx: &egui::Context, search_rect: egui::Rect) {
+        let currency = self.core.current_currency;
+        let locale = self.core.current_locale;
+        let mut picked = None;
+        let area = egui::Area::new(egui::Id::new("calendar_search_results"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(search_rect.left_bottom() + egui::vec2(0.0, 4.0))
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.set_width(SEARCH_WIDTH);
+                    if self.calendar.search_results.is_empty() {
+                        ui.label(egui::RichText::new("No matching transactions").weak());
+                        return;
+                    }
+                    for transaction in &self.calendar.search_results {
+                        let amount = currency.format(transaction.amount, locale);
+                        let date = transaction.date.format("%b %-d, %Y").to_string();
+                        let font = egui::TextStyle::Button.resolve(ui.style());
+                        let description_width = SEARCH_WIDTH - ui.fonts(|fonts| {
+                            fonts.layout_no_wrap(format!("{}  {}", date, amount), font.clone(), egui::Color32::WHITE).size().x
+                        }) - 24.0;
+                        let description = truncate_to_width(ui, &transaction.description, font, description_width.max(40.0));
+                        let hit = ui.add(egui::Button::new(format!("{}  {}  {}", date, description, amount))
+                            .frame(false)
+                            .min_size(egui::vec2(SEARCH_WIDTH, 0.0)));
+                        if hit.on_hover_text(transaction.description.as_str()).clicked() {
+                            picked = Some((transaction.date.date_naive(), transaction.id.clone()));
+                        }
+                    }
+                    if self.calendar.search_has_more {
+                        ui.label(egui::RichText::new("Showing the newest matches; type more to narrow them down").small().weak());
+                    }
+                });
+            });
+
+        // Clicking anywhere else closes the list, but the search text stays
+        let clicked_elsewhere = ctx.input(|input| {
+            input.pointer.any_click()
+                && input.pointer.interact_pos().is_some_and(|pos| !area.response.rect.contains(pos) && !search_rect.contains(pos))
+        });
+        if clicked_elsewhere {
+            self.calendar.search_open = false;
+        }
+
+        if let Some((date, transaction_id)) = picked {
+            self.calendar.search_open = false;
+            self.jump_to_calendar_date(date, Some(transaction_id));
+        }
+    }
+}
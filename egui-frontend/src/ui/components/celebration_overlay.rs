@@ -0,0 +1,131 @@
+//! # Celebration Overlay
+//!
+//! This module shows the current child's celebrations over the app: a banner
+//! with the message and, unless motion is reduced, a burst of confetti.
+//!
+//! ## Responsibilities:
+//! - Ask the backend for pending celebrations every few seconds
+//! - Show them one at a time and acknowledge each once it is on screen
+//! - Step and paint the confetti each frame, repainting while it falls
+//!
+//! ## Purpose:
+//! The backend decides what is worth celebrating (an allowance paid, a goal
+//! reached, a balance milestone); this is where the kid actually sees it.
+
+use eframe::egui;
+use log::{info, warn};
+use std::time::Instant;
+use crate::backend::domain::commands::celebrations::{AcknowledgeCelebrationsCommand, PendingCelebrationsQuery};
+use crate::backend::domain::models::celebration::CelebrationKind;
+use crate::ui::app_state::AllowanceTrackerApp;
+use crate::ui::components::styling::Theme;
+use crate::ui::state::CELEBRATION_DURATION;
+
+/// Pieces of confetti thrown for each celebration
+const CONFETTI_COUNT: usize = 140;
+
+impl AllowanceTrackerApp {
+    /// Queue the current child's celebrations that haven't been shown yet
+    pub fn check_celebrations(&mut self) {
+        self.celebration.last_check = Some(Instant::now());
+        let Some(child_id) = self.core.current_child.as_ref().map(|child| child.id.clone()) else {
+            return;
+        };
+        match self.backend().celebration_service.get_pending_celebrations(PendingCelebrationsQuery { child_id: Some(child_id) }) {
+            Ok(result) => self.celebration.enqueue(result.celebrations),
+            Err(e) => warn!("🎉 Failed to load celebrations: {}", e),
+        }
+    }
+
+    /// Show the current celebration and its confetti over everything else
+    pub fn render_celebrations(&mut self, ctx: &egui::Context) {
+        if self.celebration.should_check() {
+            self.check_celebrations();
+        }
+
+        if self.celebration.current.is_some() && self.celebration.current_expired() {
+            self.celebration.dismiss();
+        }
+        if self.celebration.current.is_none() {
+            self.show_next_celebration(ctx);
+        }
+
+        let dt = ctx.input(|input| input.stable_dt);
+        self.celebration.confetti.step(dt);
+        if self.celebration.confetti.is_active() {
+            let painter = ctx.layer_painter(egui::LayerId::new(egui::Order::Foreground, egui::Id::new("celebration_confetti")));
+            self.celebration.confetti.paint(&painter);
+            ctx.request_repaint();
+        }
+
+        let Some(celebration) = self.celebration.current.clone() else {
+            return;
+        };
+        let theme = Theme::current(ctx);
+        let icon = match celebration.kind {
+            CelebrationKind::AllowancePosted => "💰",
+            CelebrationKind::GoalCompleted => "🏆",
+            CelebrationKind::BalanceMilestone => "🎉",
+        };
+        let mut dismissed = false;
+        egui::Area::new(egui::Id::new("celebration_banner"))
+            .order(egui::Order::Foreground)
+            .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 90.0))
+            .show(ctx, |ui| {
+                let response = egui::Frame::new()
+                    .fill(theme.layout.modal_background)
+                    .stroke(egui::Stroke::new(2.0, theme.interactive.hover_border))
+                    .corner_radius(egui::CornerRadius::same(16))
+                    .inner_margin(egui::Margin::symmetric(24, 14))
+                    .show(ui, |ui| {
+                        ui.label(egui::RichText::new(format!("{} {}", icon, celebration.message))
+                            .size(24.0)
+                            .strong()
+                            .color(theme.typography.body));
+                    })
+                    .response
+                    .interact(egui::Sense::click());
+                if response.on_hover_text("Click to close").clicked() {
+                    dismissed = true;
+                }
+            });
+        if dismissed {
+            self.celebration.dismiss();
+        } else if let Some(shown_at) = self.celebration.shown_at {
+            ctx.request_repaint_after(CELEBRATION_DURATION.saturating_sub(shown_at.elapsed()));
+        }
+    }
+
+    /// Put the next queued celebration for the current child on screen
+    fn show_next_celebration(&mut self, ctx: &egui::Context) {
+        let child_id = self.core.current_child.as_ref().map(|child| child.id.clone());
+        while let Some(celebration) = self.celebration.queue.pop_front() {
+            // Another child's celebrations stay pending until they are selected
+            if Some(&celebration.child_id) != child_id.as_ref() {
+                continue;
+            }
+            let command = AcknowledgeCelebrationsCommand {
+                child_id: Some(celebration.child_id.clone()),
+                celebration_ids: vec![celebration.id.clone()],
+            };
+            if let Err(e) = self.backend().celebration_service.acknowledge_celebrations(command) {
+                warn!("🎉 Failed to acknowledge celebration {}: {}", celebration.id, e);
+            }
+            info!("🎉 Celebrating: {}", celebration.message);
+            if !self.celebration.reduce_motion {
+                let theme = Theme::current(ctx);
+                let palette = [
+                    theme.calendar.income_chip,
+                    theme.calendar.header_start,
+                    theme.calendar.header_end,
+                    egui::Color32::from_rgb(255, 215, 0),
+                    egui::Color32::from_rgb(64, 196, 255),
+                ];
+                self.celebration.confetti.burst(ctx.screen_rect(), CONFETTI_COUNT, &palette);
+            }
+            self.celebration.current = Some(celebration);
+            self.celebration.shown_at = Some(Instant::now());
+            return;
+        }
+    }
+}
@@ -1,51 +1,168 @@
 //! # Chart Renderer Module
 //!
 //! This module handles the balance chart visualization for the allowance tracker app.
-//! It provides a line chart showing balance over time with tooltips and responsive design.
+//! It provides a line chart showing balance over time with tooltips and responsive design,
+//! plus markers for large transactions and the days goals were set or reached.
 //!
 //! ## Key Functions:
 //! - `draw_chart_section()` - Main chart view with data loading and error handling
 //! - `render_balance_chart()` - Render the actual plot using egui::plot
-//! - `prepare_chart_data()` - Transform transaction data into chart points
-//! - `get_date_range_for_period()` - Calculate date ranges for different time periods
+//! - `load_chart_data()` - Fetch pre-bucketed balance points for the selected period
+//! - `get_date_range_for_period()` - Calculate date ranges for the 1M/3M/6M/1Y/All presets
+//! - `render_spending_breakdown()` - Donut chart of where a month's or quarter's money went
+//! - `render_monthly_bars()` - Earned vs spent bars for each of the last 12 months
 //!
 //! ## Purpose:
 //! This module provides a visual representation of balance changes over time,
-//! helping kids understand their spending and saving patterns through an intuitive graph.
+//! helping kids understand their spending and saving patterns through an intuitive graph,
+//! a breakdown that shows how much of their money went to candy, and monthly bars
+//! that show trends like spending that always spikes in December.
 
 use eframe::egui;
-use chrono::{NaiveDate, Duration};
-use shared::Transaction;
+use chrono::{Datelike, Months, NaiveDate, Duration};
+use frontend_api::AllowanceApi;
+use shared::{BalanceChartRequest, ChartMarkerKind, ChartResolution};
 use crate::ui::app_state::AllowanceTrackerApp;
+use crate::backend::domain::commands::reports::{BreakdownGrouping, MonthlyCashFlow, MonthlyCashFlowQuery, SpendingBreakdownQuery, SpendingSlice};
 use crate::backend::domain::commands::transactions::TransactionListQuery;
 use log::{info, warn};
 
 /// Time period options for the chart
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ChartPeriod {
-    Days30,
-    Days90,
+    Month1,
+    Months3,
+    Months6,
+    Year1,
     AllTime,
 }
 
 impl ChartPeriod {
+    /// Every preset, in the order the buttons are shown
+    pub const ALL: [ChartPeriod; 5] = [
+        ChartPeriod::Month1,
+        ChartPeriod::Months3,
+        ChartPeriod::Months6,
+        ChartPeriod::Year1,
+        ChartPeriod::AllTime,
+    ];
+    
     pub fn label(&self) -> &'static str {
         match self {
-            ChartPeriod::Days30 => "30 Days",
-            ChartPeriod::Days90 => "90 Days", 
-            ChartPeriod::AllTime => "1 Year",
+            ChartPeriod::Month1 => "1 Month",
+            ChartPeriod::Months3 => "3 Months",
+            ChartPeriod::Months6 => "6 Months",
+            ChartPeriod::Year1 => "1 Year",
+            ChartPeriod::AllTime => "All Time",
+        }
+    }
+    
+    /// Daily points for a month, weekly up to a year, monthly beyond that
+    pub fn resolution(&self) -> ChartResolution {
+        match self {
+            ChartPeriod::Month1 => ChartResolution::Daily,
+            ChartPeriod::Months3 | ChartPeriod::Months6 | ChartPeriod::Year1 => ChartResolution::Weekly,
+            ChartPeriod::AllTime => ChartResolution::Monthly,
         }
     }
     
     pub fn button_text(&self) -> &'static str {
         match self {
-            ChartPeriod::Days30 => "30 Days",
-            ChartPeriod::Days90 => "90 Days",
-            ChartPeriod::AllTime => "All Time",
+            ChartPeriod::Month1 => "1M",
+            ChartPeriod::Months3 => "3M",
+            ChartPeriod::Months6 => "6M",
+            ChartPeriod::Year1 => "1Y",
+            ChartPeriod::AllTime => "All",
+        }
+    }
+    
+    /// Months covered by the preset, or `None` for all time
+    fn months(&self) -> Option<u32> {
+        match self {
+            ChartPeriod::Month1 => Some(1),
+            ChartPeriod::Months3 => Some(3),
+            ChartPeriod::Months6 => Some(6),
+            ChartPeriod::Year1 => Some(12),
+            ChartPeriod::AllTime => None,
         }
     }
 }
 
+/// Months shown in the monthly bars view, the current one included
+const MONTHLY_BARS_MONTHS: u32 = 12;
+
+/// Which chart the chart tab shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartView {
+    /// Balance line for the selected period
+    Balance,
+    YearHeatmap,
+    SpendingBreakdown,
+    MonthlyBars,
+}
+
+impl ChartView {
+    /// Views with their own button after the balance period buttons
+    pub const OTHER_VIEWS: [ChartView; 3] = [ChartView::YearHeatmap, ChartView::SpendingBreakdown, ChartView::MonthlyBars];
+    
+    pub fn button_text(&self) -> &'static str {
+        match self {
+            ChartView::Balance => "Balance",
+            ChartView::YearHeatmap => "Year Heatmap",
+            ChartView::SpendingBreakdown => "Spending",
+            ChartView::MonthlyBars => "Monthly",
+        }
+    }
+    
+    pub fn hover_text(&self) -> &'static str {
+        match self {
+            ChartView::Balance => "Balance over time",
+            ChartView::YearHeatmap => "Every day of the year at a glance",
+            ChartView::SpendingBreakdown => "Where the money went",
+            ChartView::MonthlyBars => "Earned and spent in each of the last 12 months",
+        }
+    }
+}
+
+/// How much time the spending breakdown covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakdownPeriod {
+    Month,
+    Quarter,
+}
+
+impl BreakdownPeriod {
+    fn months(&self) -> u32 {
+        match self {
+            BreakdownPeriod::Month => 1,
+            BreakdownPeriod::Quarter => 3,
+        }
+    }
+    
+    /// First and last day of the period `periods_back` before the one holding `today`;
+    /// the current period ends today
+    pub fn date_range(&self, periods_back: u32, today: NaiveDate) -> (NaiveDate, NaiveDate) {
+        let months = self.months();
+        let first_of_month = today.with_day(1).unwrap_or(today);
+        let current_start = first_of_month - Months::new(today.month0() % months);
+        let start = current_start - Months::new(months * periods_back);
+        let end = (start + Months::new(months) - Duration::days(1)).min(today);
+        (start, end)
+    }
+}
+
+/// Slice colors, reused in order when there are more slices than colors
+const SLICE_COLORS: [egui::Color32; 8] = [
+    egui::Color32::from_rgb(100, 150, 255),
+    egui::Color32::from_rgb(255, 140, 90),
+    egui::Color32::from_rgb(90, 190, 120),
+    egui::Color32::from_rgb(200, 120, 200),
+    egui::Color32::from_rgb(240, 200, 60),
+    egui::Color32::from_rgb(80, 200, 210),
+    egui::Color32::from_rgb(230, 90, 120),
+    egui::Color32::from_rgb(150, 150, 170),
+];
+
 /// Data point for the balance chart
 #[derive(Debug, Clone)]
 pub struct ChartDataPoint {
@@ -54,6 +171,42 @@ pub struct ChartDataPoint {
     pub timestamp: f64, // Unix timestamp for plotting
 }
 
+/// A large transaction or goal date drawn on top of the balance line
+#[derive(Debug, Clone)]
+pub struct ChartMarkerPoint {
+    pub date: NaiveDate,
+    pub kind: ChartMarkerKind,
+    pub description: String,
+    pub amount: f64,
+    pub balance: f64,   // Where the marker sits on the line
+    pub timestamp: f64, // Unix timestamp for plotting
+}
+
+impl ChartMarkerPoint {
+    /// Series name, which is also what the tooltip is looked up by
+    fn series_name(&self) -> &'static str {
+        match self.kind {
+            ChartMarkerKind::LargeTransaction if self.amount >= 0.0 => "Big deposit",
+            ChartMarkerKind::LargeTransaction => "Big spend",
+            ChartMarkerKind::GoalCreated => "Goal set",
+            ChartMarkerKind::GoalCompleted => "Goal reached",
+        }
+    }
+}
+
+/// Marker series in drawing order, with their shape and color
+const MARKER_SERIES: [(&str, egui_plot::MarkerShape, egui::Color32); 4] = [
+    ("Big deposit", egui_plot::MarkerShape::Up, egui::Color32::from_rgb(60, 170, 90)),
+    ("Big spend", egui_plot::MarkerShape::Down, egui::Color32::from_rgb(220, 80, 80)),
+    ("Goal set", egui_plot::MarkerShape::Square, egui::Color32::from_rgb(200, 120, 200)),
+    ("Goal reached", egui_plot::MarkerShape::Diamond, egui::Color32::from_rgb(230, 170, 20)),
+];
+
+/// Noon on `date` as a Unix timestamp, so points sit in the middle of their day
+fn plot_timestamp(date: NaiveDate) -> f64 {
+    date.and_hms_opt(12, 0, 0).unwrap().and_utc().timestamp() as f64
+}
+
 impl AllowanceTrackerApp {
     /// Draw the chart section with header and chart content
     pub fn draw_chart_section(&mut self, ui: &mut egui::Ui, available_rect: egui::Rect) {
@@ -77,7 +230,13 @@ impl AllowanceTrackerApp {
         
         ui.allocate_new_ui(egui::UiBuilder::new().max_rect(chart_rect), |ui| {
             if let Some(ref _child) = self.get_current_child_from_backend() {
-                if self.chart.chart_data.is_empty() {
+                if self.chart.view == ChartView::YearHeatmap {
+                    self.render_year_heatmap(ui);
+                } else if self.chart.view == ChartView::SpendingBreakdown {
+                    self.render_spending_breakdown(ui);
+                } else if self.chart.view == ChartView::MonthlyBars {
+                    self.render_monthly_bars(ui);
+                } else if self.chart.chart_data.is_empty() {
                     // Show loading state
                     ui.vertical_centered(|ui| {
                         ui.add_space(chart_rect.height() / 3.0);
@@ -132,6 +291,31 @@ impl AllowanceTrackerApp {
             .radius(6.0) // Increased radius for easier hover detection
             .shape(MarkerShape::Circle);
         
+        // Markers get their own series per kind so each has its own shape and color
+        let marker_series: Vec<Points> = MARKER_SERIES
+            .iter()
+            .filter_map(|(name, shape, color)| {
+                let points: Vec<[f64; 2]> = self.chart.chart_markers
+                    .iter()
+                    .filter(|marker| marker.series_name() == *name)
+                    .map(|marker| [marker.timestamp, marker.balance])
+                    .collect();
+                if points.is_empty() {
+                    return None;
+                }
+                Some(Points::new(*name, PlotPoints::from(points))
+                    .color(*color)
+                    .filled(true)
+                    .radius(8.0)
+                    .shape(*shape))
+            })
+            .collect();
+        
+        // Tooltips list every marker on the hovered day, whichever series is under the pointer
+        let markers = self.chart.chart_markers.clone();
+        let currency = self.core.current_currency;
+        let locale = self.core.current_locale;
+        
         // Find the maximum balance for setting upper bound
         let max_balance = self.chart.chart_data
             .iter()
@@ -207,18 +391,27 @@ impl AllowanceTrackerApp {
                 
                 marks
             })
-            .label_formatter(|name, value| {
-                // Only show tooltips for Balance data points
-                if name == "Balance" {
+            .label_formatter(move |name, value| {
+                // Only show tooltips for Balance data points and markers
+                if name == "Balance" || MARKER_SERIES.iter().any(|(series, _, _)| *series == name) {
                     // Format tooltip with date and balance
                     let timestamp = value.x as i64;
                     let balance = value.y;
-                    if let Some(datetime) = chrono::DateTime::from_timestamp(timestamp, 0) {
+                    let mut text = if let Some(datetime) = chrono::DateTime::from_timestamp(timestamp, 0) {
                         let date = datetime.format("%m/%d").to_string();
                         format!("{}: ${:.2}", date, balance)
                     } else {
                         format!("${:.2}", balance)
+                    };
+                    for marker in markers.iter().filter(|marker| (marker.timestamp - value.x).abs() < 1.0) {
+                        let amount = currency.format(marker.amount, locale);
+                        text.push_str(&match marker.kind {
+                            ChartMarkerKind::LargeTransaction => format!("\n{} ({})", marker.description, amount),
+                            ChartMarkerKind::GoalCreated => format!("\n🎯 Goal set: {} ({})", marker.description, amount),
+                            ChartMarkerKind::GoalCompleted => format!("\n🏆 Goal reached: {} ({})", marker.description, amount),
+                        });
                     }
+                    text
                 } else {
                     // Return empty string for all other elements to prevent stray text
                     String::new()
@@ -245,6 +438,9 @@ impl AllowanceTrackerApp {
             .show(ui, |plot_ui| {
                 plot_ui.line(line);
                 plot_ui.points(data_points);
+                for series in marker_series {
+                    plot_ui.points(series);
+                }
             });
         
         // For now, let's see if the built-in coordinate display works better
@@ -259,206 +455,368 @@ impl AllowanceTrackerApp {
         
         info!("📊 Loading chart data for child: {} (period: {:?})", child.name, self.chart.selected_period);
         
+        // The heatmap reloads itself for the current child on its next render
+        self.chart.year_summary = None;
+        
         // Calculate date range based on selected period
         let (start_date, end_date) = self.get_date_range_for_period(self.chart.selected_period);
         
         info!("📊 Chart date range: {} to {} ({} days)", start_date, end_date, (end_date - start_date).num_days());
         
-        // Fetch transactions directly from backend for the specified date range
-        // Convert dates to RFC3339 format for backend query
-        let start_date_str = start_date.and_hms_opt(0, 0, 0).unwrap().and_utc().to_rfc3339();
-        let end_date_str = end_date.and_hms_opt(23, 59, 59).unwrap().and_utc().to_rfc3339();
-        
-        let query = TransactionListQuery {
-            after: None,
-            limit: Some(10000), // Get all transactions in the date range
-            start_date: Some(start_date_str.clone()),
-            end_date: Some(end_date_str.clone()),
+        // The backend buckets the balances, so no dates are parsed here
+        let request = BalanceChartRequest {
+            child_id: None,
+            start_date,
+            end_date,
+            resolution: self.chart.selected_period.resolution(),
         };
         
-        info!("📊 Fetching transactions from backend with query: start_date={}, end_date={}, limit=10000", start_date_str, end_date_str);
-        
-        match self.backend().transaction_service.as_ref().list_transactions_domain(query) {
-            Ok(result) => {
-                info!("📊 Successfully loaded {} transactions from backend for chart", result.transactions.len());
-                
-                // Convert domain transactions to DTO format for chart processing
-                let dto_transactions: Vec<shared::Transaction> = result.transactions
+        match self.api().get_balance_chart(request) {
+            Ok(response) => {
+                let data: Vec<ChartDataPoint> = response.points
                     .into_iter()
-                    .map(|tx| shared::Transaction {
-                        id: tx.id,
-                        child_id: tx.child_id,
-                        amount: tx.amount,
-                        balance: tx.balance,
-                        transaction_type: match tx.transaction_type {
-                            crate::backend::domain::models::transaction::TransactionType::Income => shared::TransactionType::Income,
-                            crate::backend::domain::models::transaction::TransactionType::Expense => shared::TransactionType::Expense,
-                            crate::backend::domain::models::transaction::TransactionType::FutureAllowance => shared::TransactionType::FutureAllowance,
-                        },
-                        description: tx.description,
-                        date: tx.date,
+                    .map(|point| ChartDataPoint {
+                        date: point.date,
+                        balance: point.balance,
+                        timestamp: plot_timestamp(point.date),
                     })
                     .collect();
                 
-                info!("📊 Converted to {} DTO transactions for chart", dto_transactions.len());
-                
-                // Convert to references for prepare_chart_data compatibility
-                let transaction_refs: Vec<&shared::Transaction> = dto_transactions.iter().collect();
-                
-                // Prepare chart data points
-                self.chart.chart_data = self.prepare_chart_data(&transaction_refs, start_date, end_date);
+                // A marker sits on the line at the end of the bucket holding its day
+                let markers = response.markers
+                    .into_iter()
+                    .map(|marker| {
+                        let balance = data
+                            .iter()
+                            .find(|point| point.date >= marker.date)
+                            .or(data.last())
+                            .map_or(0.0, |point| point.balance);
+                        ChartMarkerPoint {
+                            date: marker.date,
+                            kind: marker.kind,
+                            description: marker.description,
+                            amount: marker.amount,
+                            balance,
+                            timestamp: plot_timestamp(marker.date),
+                        }
+                    })
+                    .collect();
+                self.chart.set_data(data, markers);
                 
-                info!("📊 Generated {} chart data points", self.chart.chart_data.len());
+                info!("📊 Generated {} chart data points and {} markers", self.chart.chart_data.len(), self.chart.chart_markers.len());
             }
             Err(e) => {
                 warn!("❌ Failed to load chart data from backend: {}", e);
+                self.chart.set_error(e.message);
             }
         }
     }
     
-    /// Prepare chart data points from transactions
-    pub fn prepare_chart_data(&self, transactions: &[&Transaction], start_date: NaiveDate, end_date: NaiveDate) -> Vec<ChartDataPoint> {
-        let mut data_points = Vec::new();
-        
-        // Sort transactions by date
-        let mut sorted_transactions: Vec<&Transaction> = transactions.iter().copied().collect();
-        sorted_transactions.sort_by(|a, b| a.date.cmp(&b.date));
-        
-        // For 30-day view: show daily balances (last balance of each day)
-        // For 90-day view: show weekly balances (last balance of each week)  
-        // For all-time view: show monthly balances (last balance of each month)
-        
-        match self.chart.selected_period {
-            ChartPeriod::Days30 => {
-                // Daily balances
-                let mut current_date = start_date;
-                let mut running_balance = 0.0;
-                let mut tx_index = 0;
-                
-                while current_date <= end_date {
-                    // Find all transactions for this day
-                    let mut day_final_balance = running_balance;
-                    
-                    while tx_index < sorted_transactions.len() {
-                        let tx = sorted_transactions[tx_index];
-                        let tx_date = tx.date.date_naive();
-                        
-                        if tx_date == current_date {
-                            day_final_balance = tx.balance;
-                            tx_index += 1;
-                        } else if tx_date > current_date {
-                            break;
-                        } else {
-                            tx_index += 1;
-                        }
-                    }
-                    
-                    // Add data point for this day
-                    let timestamp = current_date.and_hms_opt(12, 0, 0).unwrap().and_utc().timestamp() as f64;
-                    data_points.push(ChartDataPoint {
-                        date: current_date,
-                        balance: day_final_balance,
-                        timestamp,
-                    });
-                    
-                    running_balance = day_final_balance;
-                    current_date += Duration::days(1);
+    /// Get date range for the specified chart period
+    pub fn get_date_range_for_period(&self, period: ChartPeriod) -> (NaiveDate, NaiveDate) {
+        let today = chrono::Local::now().date_naive();
+        
+        let start_date = match period.months() {
+            Some(months) => today
+                .checked_sub_months(Months::new(months))
+                .unwrap_or(today - Duration::days(30 * months as i64)),
+            None => {
+                // For true "All Time", get the earliest transaction date from backend
+                match self.get_earliest_transaction_date() {
+                    Some(earliest_date) => earliest_date,
+                    None => today - Duration::days(365), // Fallback if no transactions
                 }
             }
-            ChartPeriod::Days90 => {
-                // Weekly balances with intelligent sampling
-                let mut sample_dates = Vec::new();
-                let mut current_date = start_date;
-                
-                // Generate weekly sample dates
-                while current_date <= end_date {
-                    sample_dates.push(current_date);
-                    current_date += Duration::days(7);
+        };
+        
+        (start_date, today)
+    }
+    
+    /// Load the spending breakdown for the selected month or quarter
+    pub fn load_spending_breakdown(&mut self) {
+        let Some(ref child) = self.core.current_child else {
+            warn!("📊 No child selected for spending breakdown");
+            return;
+        };
+        
+        let today = chrono::Local::now().date_naive();
+        let (start_date, end_date) = self.chart.breakdown_period.date_range(self.chart.breakdown_periods_back, today);
+        let query = SpendingBreakdownQuery {
+            child_id: Some(child.id.clone()),
+            start_date,
+            end_date,
+            group_by: self.chart.breakdown_grouping,
+            exclude_reversals: self.chart.hide_reversals,
+        };
+        
+        match self.backend().reports_service.get_spending_breakdown(query) {
+            Ok(breakdown) => {
+                info!("📊 Loaded spending breakdown for {} to {} ({} slices)", start_date, end_date, breakdown.slices.len());
+                self.chart.spending_breakdown = Some(breakdown);
+            }
+            Err(e) => {
+                warn!("❌ Failed to load spending breakdown: {}", e);
+                self.chart.set_error(format!("Failed to load spending breakdown: {}", e));
+            }
+        }
+    }
+    
+    /// Render the period picker, donut chart and legend of where the money went
+    pub fn render_spending_breakdown(&mut self, ui: &mut egui::Ui) {
+        let locale = self.core.current_locale;
+        let currency = self.core.current_currency;
+        let today = chrono::Local::now().date_naive();
+        let (start_date, _) = self.chart.breakdown_period.date_range(self.chart.breakdown_periods_back, today);
+        let period_title = match self.chart.breakdown_period {
+            BreakdownPeriod::Month => format!("{} {}", locale.month_name(start_date.month()), start_date.year()),
+            BreakdownPeriod::Quarter => format!("Q{} {}", start_date.month0() / 3 + 1, start_date.year()),
+        };
+        
+        // Period and grouping pickers; any change reloads on the next frame
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            if ui.button("◀").clicked() {
+                self.chart.breakdown_periods_back += 1;
+                changed = true;
+            }
+            ui.label(egui::RichText::new(&period_title)
+                .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
+                .strong());
+            if ui.add_enabled(self.chart.breakdown_periods_back > 0, egui::Button::new("▶")).clicked() {
+                self.chart.breakdown_periods_back -= 1;
+                changed = true;
+            }
+            ui.add_space(12.0);
+            for (period, label) in [(BreakdownPeriod::Month, "Month"), (BreakdownPeriod::Quarter, "Quarter")] {
+                if ui.selectable_label(self.chart.breakdown_period == period, label).clicked() && self.chart.breakdown_period != period {
+                    self.chart.breakdown_period = period;
+                    self.chart.breakdown_periods_back = 0;
+                    changed = true;
                 }
-                
-                // CRITICAL: Always include the end date as final sample if not already included
-                if let Some(&last_sample) = sample_dates.last() {
-                    if last_sample < end_date {
-                        sample_dates.push(end_date);
-                    }
+            }
+            ui.add_space(12.0);
+            for (grouping, label) in [(BreakdownGrouping::Category, "By category"), (BreakdownGrouping::TransactionType, "By kind")] {
+                if ui.selectable_label(self.chart.breakdown_grouping == grouping, label).clicked() && self.chart.breakdown_grouping != grouping {
+                    self.chart.breakdown_grouping = grouping;
+                    changed = true;
                 }
-                
-                for &sample_date in &sample_dates {
-                    // Find the last transaction on or before this date
-                    let mut latest_balance = 0.0;
-                    
-                    for tx in &sorted_transactions {
-                        let tx_date = tx.date.date_naive();
-                        if tx_date <= sample_date {
-                            latest_balance = tx.balance;
-                        } else {
-                            break; // Since transactions are sorted by date
-                        }
+            }
+        });
+        if changed {
+            self.chart.spending_breakdown = None;
+            self.chart.error_message = None;
+        }
+        ui.add_space(10.0);
+        
+        // Don't retry every frame after a failure; changing period clears the error
+        if self.chart.spending_breakdown.is_none() && self.chart.error_message.is_none() {
+            self.load_spending_breakdown();
+        }
+        let Some(breakdown) = self.chart.spending_breakdown.clone() else {
+            if let Some(ref error) = self.chart.error_message {
+                ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+            }
+            return;
+        };
+        
+        if breakdown.slices.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(40.0);
+                ui.label(egui::RichText::new(format!("Nothing spent in {}", period_title))
+                    .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
+                    .color(egui::Color32::from_rgb(120, 120, 120)));
+            });
+            return;
+        }
+        
+        let legend_width = 260.0;
+        let diameter = (ui.available_width() - legend_width - 40.0).min(ui.available_height() - 10.0).clamp(120.0, 360.0);
+        ui.horizontal_top(|ui| {
+            let (rect, response) = ui.allocate_exact_size(egui::vec2(diameter, diameter), egui::Sense::hover());
+            let center = rect.center();
+            let outer = diameter / 2.0;
+            let inner = outer * 0.55;
+            let painter = ui.painter_at(rect);
+            
+            // Slices start at twelve o'clock and go clockwise
+            let mut start_angle = -std::f32::consts::FRAC_PI_2;
+            let mut hovered: Option<&SpendingSlice> = None;
+            let hover_pos = response.hover_pos();
+            for (index, slice) in breakdown.slices.iter().enumerate() {
+                let sweep = slice.share as f32 * std::f32::consts::TAU;
+                paint_donut_slice(&painter, center, inner, outer, start_angle, sweep, SLICE_COLORS[index % SLICE_COLORS.len()]);
+                if let Some(pos) = hover_pos {
+                    let offset = pos - center;
+                    let distance = offset.length();
+                    let angle = (offset.y.atan2(offset.x) - start_angle).rem_euclid(std::f32::consts::TAU);
+                    if distance >= inner && distance <= outer && angle < sweep {
+                        hovered = Some(slice);
                     }
-                    
-                    let timestamp = sample_date.and_hms_opt(12, 0, 0).unwrap().and_utc().timestamp() as f64;
-                    data_points.push(ChartDataPoint {
-                        date: sample_date,
-                        balance: latest_balance,
-                        timestamp,
-                    });
                 }
+                start_angle += sweep;
             }
-            ChartPeriod::AllTime => {
-                // Monthly balances (simplified - just sample every 30 days)
-                let mut current_date = start_date;
-                
-                while current_date <= end_date {
-                    // Find the last transaction on or before this date
-                    let mut latest_balance = 0.0;
-                    
-                    for tx in &sorted_transactions {
-                        let tx_date = tx.date.date_naive();
-                        if tx_date <= current_date {
-                            latest_balance = tx.balance;
-                        } else {
-                            break;
+            
+            painter.text(
+                center - egui::vec2(0.0, 9.0),
+                egui::Align2::CENTER_CENTER,
+                currency.format(breakdown.total_spent, locale),
+                egui::FontId::proportional(20.0),
+                ui.visuals().strong_text_color(),
+            );
+            painter.text(
+                center + egui::vec2(0.0, 13.0),
+                egui::Align2::CENTER_CENTER,
+                "spent",
+                egui::FontId::proportional(13.0),
+                egui::Color32::from_rgb(120, 120, 120),
+            );
+            
+            if let Some(slice) = hovered {
+                response.on_hover_text(format!(
+                    "{}\n{} ({:.0}%)\n{} transaction{}",
+                    slice.label,
+                    currency.format(slice.amount, locale),
+                    slice.share * 100.0,
+                    slice.transaction_count,
+                    if slice.transaction_count == 1 { "" } else { "s" },
+                ));
+            }
+            
+            ui.add_space(24.0);
+            
+            // Legend, scrolled when there are many slices
+            egui::ScrollArea::vertical().id_salt("spending_breakdown_legend").max_height(diameter).show(ui, |ui| {
+                egui::Grid::new("spending_breakdown_legend_grid")
+                    .num_columns(4)
+                    .spacing(egui::vec2(12.0, 6.0))
+                    .show(ui, |ui| {
+                        for (index, slice) in breakdown.slices.iter().enumerate() {
+                            let (swatch, _) = ui.allocate_exact_size(egui::vec2(14.0, 14.0), egui::Sense::hover());
+                            ui.painter().rect_filled(swatch, egui::CornerRadius::same(3), SLICE_COLORS[index % SLICE_COLORS.len()]);
+                            ui.label(&slice.label);
+                            ui.label(currency.format(slice.amount, locale));
+                            ui.label(egui::RichText::new(format!("{:.0}%", slice.share * 100.0)).color(egui::Color32::from_rgb(120, 120, 120)));
+                            ui.end_row();
                         }
-                    }
-                    
-                    let timestamp = current_date.and_hms_opt(12, 0, 0).unwrap().and_utc().timestamp() as f64;
-                    data_points.push(ChartDataPoint {
-                        date: current_date,
-                        balance: latest_balance,
-                        timestamp,
                     });
-                    
-                    current_date += Duration::days(30);
-                }
+            });
+        });
+    }
+    
+    /// Load money earned and spent in each of the last 12 months
+    pub fn load_monthly_cash_flow(&mut self) {
+        let Some(ref child) = self.core.current_child else {
+            warn!("📊 No child selected for monthly cash flow");
+            return;
+        };
+        
+        let today = chrono::Local::now().date_naive();
+        let query = MonthlyCashFlowQuery {
+            child_id: Some(child.id.clone()),
+            end_year: today.year(),
+            end_month: today.month(),
+            months: MONTHLY_BARS_MONTHS,
+            exclude_reversals: self.chart.hide_reversals,
+        };
+        
+        match self.backend().reports_service.get_monthly_cash_flow(query) {
+            Ok(cash_flow) => {
+                info!("📊 Loaded monthly cash flow ({} months)", cash_flow.months.len());
+                self.chart.monthly_cash_flow = Some(cash_flow);
+            }
+            Err(e) => {
+                warn!("❌ Failed to load monthly cash flow: {}", e);
+                self.chart.set_error(format!("Failed to load monthly cash flow: {}", e));
             }
         }
-        
-        data_points
     }
     
-    /// Get date range for the specified chart period
-    pub fn get_date_range_for_period(&self, period: ChartPeriod) -> (NaiveDate, NaiveDate) {
-        let today = chrono::Local::now().date_naive();
+    /// Render earned and spent bars side by side for each of the last 12 months
+    pub fn render_monthly_bars(&mut self, ui: &mut egui::Ui) {
+        use egui_plot::{Bar, BarChart, Legend, Plot};
         
-        let start_date = match period {
-            ChartPeriod::Days30 => today - Duration::days(30),
-            ChartPeriod::Days90 => today - Duration::days(90),
-            ChartPeriod::AllTime => {
-                // For true "All Time", get the earliest transaction date from backend
-                match self.get_earliest_transaction_date() {
-                    Some(earliest_date) => earliest_date,
-                    None => today - Duration::days(365), // Fallback if no transactions
-                }
+        if ui.checkbox(&mut self.chart.hide_reversals, "Leave out reversed entries").changed() {
+            self.chart.monthly_cash_flow = None;
+            self.chart.error_message = None;
+        }
+        ui.add_space(6.0);
+        
+        // Don't retry every frame after a failure; switching views clears the error
+        if self.chart.monthly_cash_flow.is_none() && self.chart.error_message.is_none() {
+            self.load_monthly_cash_flow();
+        }
+        let Some(cash_flow) = self.chart.monthly_cash_flow.clone() else {
+            if let Some(ref error) = self.chart.error_message {
+                ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
             }
+            return;
         };
         
-        (start_date, today)
+        let locale = self.core.current_locale;
+        let currency = self.core.current_currency;
+        let month_label = |index: usize| {
+            cash_flow.months.get(index).map(|month| {
+                let name = locale.short_month_name(month.month);
+                // The year only where it changes, so the axis stays short
+                if index == 0 || month.month == 1 {
+                    format!("{} {}", name, month.year)
+                } else {
+                    name
+                }
+            })
+        };
+        
+        let earned_color = egui::Color32::from_rgb(90, 190, 120);
+        let spent_color = egui::Color32::from_rgb(230, 90, 100);
+        let bars = |offset: f64, value: fn(&MonthlyCashFlow) -> f64| -> Vec<Bar> {
+            cash_flow.months
+                .iter()
+                .enumerate()
+                .map(|(index, month)| {
+                    Bar::new(index as f64 + offset, value(month))
+                        .width(0.38)
+                        .name(month_label(index).unwrap_or_default())
+                })
+                .collect()
+        };
+        let earned = BarChart::new("Earned", bars(-0.2, |month| month.earned))
+            .color(earned_color)
+            .element_formatter(Box::new(move |bar, _chart| format!("{}\nEarned {}", bar.name, currency.format(bar.value, locale))));
+        let spent = BarChart::new("Spent", bars(0.2, |month| month.spent))
+            .color(spent_color)
+            .element_formatter(Box::new(move |bar, _chart| format!("{}\nSpent {}", bar.name, currency.format(bar.value, locale))));
+        
+        let axis_labels: Vec<String> = (0..cash_flow.months.len()).filter_map(&month_label).collect();
+        Plot::new("monthly_bars_chart")
+            .legend(Legend::default().position(egui_plot::Corner::LeftTop))
+            .show_background(false)
+            .allow_boxed_zoom(false)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .include_y(0.0)
+            .show_x(false)
+            .show_y(false)
+            .x_grid_spacer(egui_plot::uniform_grid_spacer(|_| [1.0, 1.0, 1.0]))
+            .x_axis_formatter(move |mark, _range| {
+                // Only whole numbers are months
+                if mark.value.fract() != 0.0 || mark.value < 0.0 {
+                    return String::new();
+                }
+                axis_labels.get(mark.value as usize).cloned().unwrap_or_default()
+            })
+            .y_axis_formatter(move |mark, _range| currency.format(mark.value, locale))
+            .show(ui, |plot_ui| {
+                plot_ui.bar_chart(earned);
+                plot_ui.bar_chart(spent);
+            });
     }
     
     /// Get the earliest transaction date from the backend
     fn get_earliest_transaction_date(&self) -> Option<NaiveDate> {
         // Query backend for ALL transactions (no date filter) to find the earliest
         let query = TransactionListQuery {
+            child_id: None,
             after: None,
             limit: Some(10000), // Get all transactions
             start_date: None, // No start date filter
@@ -485,4 +843,26 @@ impl AllowanceTrackerApp {
             }
         }
     }
-} 
\ No newline at end of file
+}
+
+/// Fill one slice of a donut as a triangle strip between the inner and outer radius
+fn paint_donut_slice(painter: &egui::Painter, center: egui::Pos2, inner: f32, outer: f32, start: f32, sweep: f32, color: egui::Color32) {
+    if sweep <= 0.0 {
+        return;
+    }
+    // About one segment every three degrees keeps the edge smooth
+    let segments = ((sweep / std::f32::consts::TAU) * 120.0).ceil().max(1.0) as u32;
+    let mut mesh = egui::Mesh::default();
+    for step in 0..=segments {
+        let angle = start + sweep * step as f32 / segments as f32;
+        let direction = egui::vec2(angle.cos(), angle.sin());
+        mesh.colored_vertex(center + direction * inner, color);
+        mesh.colored_vertex(center + direction * outer, color);
+    }
+    for step in 0..segments {
+        let base = step * 2;
+        mesh.add_triangle(base, base + 1, base + 2);
+        mesh.add_triangle(base + 1, base + 3, base + 2);
+    }
+    painter.add(egui::Shape::mesh(mesh));
+}
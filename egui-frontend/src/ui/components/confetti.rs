@@ -0,0 +1,173 @@
+//! # Confetti
+//!
+//! This module contains a small frame-driven particle system used for
+//! celebrations.
+//!
+//! ## Responsibilities:
+//! - Spawn a burst of confetti pieces across the top of an area
+//! - Move every piece forward by the frame's time step (gravity, drag, spin)
+//! - Paint the pieces, fading them out at the end of their life
+//!
+//! ## Purpose:
+//! egui has no animation system of its own beyond easing single values, so
+//! the celebration overlay advances this system once per frame and asks for
+//! repaints while any confetti is still falling. Randomness comes from a tiny
+//! xorshift generator, which is plenty for where a piece of paper lands.
+
+use eframe::egui;
+
+/// Downward acceleration, in points per second squared
+const GRAVITY: f32 = 380.0;
+
+/// Fraction of horizontal speed lost per second, so pieces drift rather than fly
+const DRAG: f32 = 0.8;
+
+/// Longest time step taken at once, so a stalled frame doesn't teleport the confetti
+const MAX_STEP: f32 = 1.0 / 20.0;
+
+/// Fraction of a piece's life spent fading out
+const FADE_FRACTION: f32 = 0.3;
+
+/// One piece of confetti
+#[derive(Debug, Clone)]
+struct Particle {
+    pos: egui::Pos2,
+    velocity: egui::Vec2,
+    color: egui::Color32,
+    size: egui::Vec2,
+    /// Rotation in radians, and how fast it changes per second
+    angle: f32,
+    spin: f32,
+    /// Seconds since the piece was spawned, and how long it lives
+    age: f32,
+    lifetime: f32,
+}
+
+/// Confetti currently on screen
+#[derive(Debug, Clone)]
+pub struct Confetti {
+    particles: Vec<Particle>,
+    seed: u32,
+}
+
+impl Confetti {
+    /// Create an empty system
+    pub fn new() -> Self {
+        Self { particles: Vec::new(), seed: 0x9e37_79b9 }
+    }
+
+    /// Throw `count` pieces from just above `area`, spread across its width
+    pub fn burst(&mut self, area: egui::Rect, count: usize, palette: &[egui::Color32]) {
+        if palette.is_empty() {
+            return;
+        }
+        for _ in 0..count {
+            let x = area.left() + self.next_random() * area.width();
+            let y = area.top() - self.next_random() * area.height() * 0.25;
+            let color = palette[(self.next_random() * palette.len() as f32) as usize % palette.len()];
+            let particle = Particle {
+                pos: egui::pos2(x, y),
+                velocity: egui::vec2(self.random_between(-160.0, 160.0), self.random_between(-60.0, 120.0)),
+                color,
+                size: egui::vec2(self.random_between(6.0, 11.0), self.random_between(3.0, 6.0)),
+                angle: self.random_between(0.0, std::f32::consts::TAU),
+                spin: self.random_between(-8.0, 8.0),
+                age: 0.0,
+                lifetime: self.random_between(2.2, 3.6),
+            };
+            self.particles.push(particle);
+        }
+    }
+
+    /// Advance every piece by `dt` seconds and drop the ones that have expired
+    pub fn step(&mut self, dt: f32) {
+        let dt = dt.clamp(0.0, MAX_STEP);
+        for particle in &mut self.particles {
+            particle.velocity.y += GRAVITY * dt;
+            particle.velocity.x *= 1.0 - DRAG * dt;
+            particle.pos += particle.velocity * dt;
+            particle.angle += particle.spin * dt;
+            particle.age += dt;
+        }
+        self.particles.retain(|particle| particle.age < particle.lifetime);
+    }
+
+    /// Paint every piece as a small rotated rectangle
+    pub fn paint(&self, painter: &egui::Painter) {
+        for particle in &self.particles {
+            let remaining = 1.0 - particle.age / particle.lifetime;
+            let opacity = (remaining / FADE_FRACTION).min(1.0);
+            let (sin, cos) = particle.angle.sin_cos();
+            let half = particle.size / 2.0;
+            let corners = [(-half.x, -half.y), (half.x, -half.y), (half.x, half.y), (-half.x, half.y)]
+                .map(|(x, y)| particle.pos + egui::vec2(x * cos - y * sin, x * sin + y * cos));
+            painter.add(egui::Shape::convex_polygon(
+                corners.to_vec(),
+                particle.color.gamma_multiply(opacity),
+                egui::Stroke::NONE,
+            ));
+        }
+    }
+
+    /// Whether any confetti is still falling
+    pub fn is_active(&self) -> bool {
+        !self.particles.is_empty()
+    }
+
+    /// Remove all confetti at once
+    pub fn clear(&mut self) {
+        self.particles.clear();
+    }
+
+    /// Next pseudo-random number in `0.0..1.0`
+    fn next_random(&mut self) -> f32 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 17;
+        self.seed ^= self.seed << 5;
+        (self.seed >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    fn random_between(&mut self, low: f32, high: f32) -> f32 {
+        low + self.next_random() * (high - low)
+    }
+}
+
+impl Default for Confetti {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confetti_falls_and_expires() {
+        let mut confetti = Confetti::new();
+        let area = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(400.0, 300.0));
+        confetti.burst(area, 50, &[egui::Color32::RED, egui::Color32::GOLD]);
+        assert_eq!(confetti.particles.len(), 50);
+        assert!(confetti.particles.iter().all(|p| p.pos.x >= 0.0 && p.pos.x <= 400.0));
+
+        let start_y: f32 = confetti.particles.iter().map(|p| p.pos.y).sum();
+        for _ in 0..20 {
+            confetti.step(1.0 / 60.0);
+        }
+        let later_y: f32 = confetti.particles.iter().map(|p| p.pos.y).sum();
+        assert!(later_y > start_y);
+
+        // A long stall is taken in small steps, and every piece is gone within its lifetime
+        for _ in 0..100 {
+            confetti.step(1.0);
+        }
+        assert!(!confetti.is_active());
+    }
+
+    #[test]
+    fn test_burst_without_colors_spawns_nothing() {
+        let mut confetti = Confetti::new();
+        confetti.burst(egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(10.0, 10.0)), 10, &[]);
+        assert!(!confetti.is_active());
+    }
+}
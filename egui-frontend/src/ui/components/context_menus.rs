@@ -0,0 +1,228 @@
+//! # Context Menus
+//!
+//! This module contains the right-click menus on calendar days and table
+//! rows, and the edit window that the row menu opens.
+//!
+//! ## Responsibilities:
+//! - Offer to add or spend money on a day, or to open it up to see all of it
+//! - Offer to edit, delete or duplicate a table row
+//! - Edit a transaction's description and day with the backend's edit command
+//! - Duplicate a transaction as a new one dated today, for repeat purchases
+//! - Send deleting through the parental check into delete mode, with the row
+//!   already picked, so it is confirmed like any other deletion
+//!
+//! ## Purpose:
+//! Fixing one entry shouldn't mean finding the right button for it. Each
+//! menu entry opens the same modal or runs the same command that the
+//! buttons elsewhere in the app do, so it follows the same rules.
+
+use eframe::egui;
+use chrono::NaiveDate;
+use frontend_api::AllowanceApi;
+use log::{info, warn};
+use shared::Transaction;
+use crate::backend::domain::commands::transactions::EditTransactionCommand;
+use crate::backend::domain::models::actor::Actor;
+use crate::backend::domain::models::transaction::date_on_day;
+use crate::ui::app_state::{AllowanceTrackerApp, OverlayType};
+use crate::ui::components::calendar_renderer::DayMenuGlyph;
+use crate::ui::components::transaction_table::TableRowAction;
+use crate::ui::state::modal_state::ProtectedAction;
+use crate::ui::state::RowEdit;
+
+/// Something picked from a calendar day's right-click menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayMenuAction {
+    AddMoney,
+    SpendMoney,
+    ViewDay,
+}
+
+/// Show a day's right-click menu and return the entry picked from it.
+/// Money can only be added on the days that get the 💰 buttons.
+pub fn day_context_menu(response: &egui::Response, date: NaiveDate) -> Option<DayMenuAction> {
+    let can_add = !DayMenuGlyph::for_date(date).is_empty();
+    let mut picked = None;
+    response.context_menu(|ui| {
+        ui.label(egui::RichText::new(date.format("%A, %B %-d").to_string()).strong());
+        ui.separator();
+        let entries = [
+            (DayMenuAction::AddMoney, "💰 Add money here", can_add),
+            (DayMenuAction::SpendMoney, "💸 Spend here", can_add),
+            (DayMenuAction::ViewDay, "🔍 View day", true),
+        ];
+        for (action, label, enabled) in entries {
+            let item = ui.add_enabled(enabled, egui::Button::new(label))
+                .on_disabled_hover_text("Money can only be added for today and the 45 days before it");
+            if item.clicked() {
+                picked = Some(action);
+                ui.close_menu();
+            }
+        }
+    });
+    picked
+}
+
+impl AllowanceTrackerApp {
+    /// Run an entry picked from a calendar day's menu
+    pub fn handle_day_menu_action(&mut self, date: NaiveDate, action: DayMenuAction) {
+        info!("📅 Day menu: {:?} on {}", action, date);
+        self.calendar.selected_day = Some(date);
+        match action {
+            DayMenuAction::AddMoney => {
+                self.form.income_form_state.date = Some(date);
+                self.open_day_overlay(OverlayType::AddMoney);
+            }
+            DayMenuAction::SpendMoney => {
+                self.form.expense_form_state.date = Some(date);
+                self.open_day_overlay(OverlayType::SpendMoney);
+            }
+            DayMenuAction::ViewDay => {
+                // Open the day up so none of its chips hide behind the "more" chip
+                self.calendar.expanded_day = Some(date);
+            }
+        }
+    }
+
+    /// Run an entry picked from a table row's menu
+    pub fn handle_table_row_action(&mut self, action: TableRowAction, transaction: Transaction) {
+        info!("📋 Row menu: {:?} on {}", action, transaction.id);
+        match action {
+            TableRowAction::Edit => {
+                self.interaction.row_edit = Some(RowEdit {
+                    transaction_id: transaction.id,
+                    original_description: transaction.description.clone(),
+                    original_date: transaction.date,
+                    description: transaction.description,
+                    date: transaction.date.date_naive(),
+                });
+            }
+            TableRowAction::Delete => {
+                if self.interaction.transaction_selection_mode {
+                    self.interaction.selected_transaction_ids.insert(transaction.id);
+                } else {
+                    self.interaction.pending_delete_id = Some(transaction.id);
+                    self.start_parental_control_challenge(ProtectedAction::DeleteTransactions);
+                }
+            }
+            TableRowAction::Duplicate => self.duplicate_transaction(&transaction),
+        }
+    }
+
+    /// Add a copy of `transaction` dated today, e.g. this week's lunch money
+    fn duplicate_transaction(&mut self, transaction: &Transaction) {
+        let request = shared::DuplicateTransactionRequest {
+            transaction_id: transaction.id.clone(),
+            actor: Some(Actor::Kid.to_storage()),
+            child_id: None,
+        };
+        match self.api().duplicate_transaction(request) {
+            Ok(response) => {
+                info!("📋 Duplicated {} as {}", transaction.id, response.transaction.id);
+                self.core.current_balance = response.new_balance;
+                self.ui.set_success(response.success_message);
+                self.load_calendar_data();
+                self.table.invalidate();
+                self.load_chart_data();
+            }
+            Err(e) => {
+                warn!("📋 Failed to duplicate {}: {}", transaction.id, e);
+                self.ui.error_message = Some(format!("Couldn't duplicate \"{}\": {}", transaction.description, e));
+            }
+        }
+    }
+
+    fn open_day_overlay(&mut self, overlay: OverlayType) {
+        self.calendar.active_overlay = Some(overlay);
+        self.calendar.modal_just_opened = true; // Prevent backdrop click detection this frame
+    }
+
+    /// Draw the window editing a transaction's description and day, if it is open
+    pub fn render_transaction_edit_window(&mut self, ctx: &egui::Context) {
+        let Some(edit) = self.interaction.row_edit.as_mut() else {
+            return;
+        };
+        let today = chrono::Local::now().date_naive();
+        let (mut save, mut cancel) = (false, false);
+        egui::Window::new("✏ Edit transaction")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.label("Description:");
+                ui.add(egui::TextEdit::singleline(&mut edit.description).desired_width(280.0));
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    ui.label("Day:");
+                    ui.add(egui_extras::DatePickerButton::new(&mut edit.date)
+                        .id_salt("transaction_edit_date")
+                        .calendar_week(false));
+                });
+                if edit.date > today {
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), "The day can't be in the future");
+                }
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    let valid = !edit.description.trim().is_empty() && edit.date <= today;
+                    if ui.add_enabled(valid, egui::Button::new("Save")).clicked() {
+                        save = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        cancel = true;
+                    }
+                });
+            });
+
+        if cancel || ctx.input(|input| input.key_pressed(egui::Key::Escape)) {
+            self.interaction.row_edit = None;
+        } else if save {
+            if let Some(edit) = self.interaction.row_edit.clone() {
+                self.request_transaction_edit(edit);
+            }
+        }
+    }
+
+    /// Save an edit, behind the parental check when the policy for moving transactions asks for it
+    fn request_transaction_edit(&mut self, edit: RowEdit) {
+        match self.backend().parental_control_service.moving_transactions_requires_parental_control() {
+            Ok(true) => {
+                self.interaction.row_edit = None;
+                self.interaction.pending_edit = Some(edit);
+                self.start_parental_control_challenge(ProtectedAction::EditTransaction);
+            }
+            Ok(false) => self.save_transaction_edit(edit, None),
+            Err(e) => {
+                warn!("📋 Failed to read the policy for editing transactions: {}", e);
+                self.ui.error_message = Some(format!("Couldn't save \"{}\": {}", edit.original_description, e));
+            }
+        }
+    }
+
+    /// Store the changed description and day; the window stays open if that fails
+    pub(crate) fn save_transaction_edit(&mut self, edit: RowEdit, actor: Option<Actor>) {
+        let description = edit.description.trim().to_string();
+        let command = EditTransactionCommand {
+            child_id: None,
+            transaction_id: edit.transaction_id.clone(),
+            date: (edit.date != edit.original_date.date_naive()).then(|| date_on_day(edit.original_date, edit.date)),
+            description: (description != edit.original_description).then_some(description),
+            parent_override: false,
+            actor,
+        };
+        match self.backend().transaction_service.edit_transaction(command) {
+            Ok(result) => {
+                info!("📋 Edited {} ({} balances recalculated)", result.transaction.id, result.recalculated_rows);
+                self.interaction.row_edit = None;
+                self.ui.set_success(format!("Saved \"{}\"", result.transaction.description));
+                self.load_calendar_data();
+                self.table.invalidate();
+                self.load_balance();
+                self.load_chart_data();
+            }
+            Err(e) => {
+                warn!("📋 Failed to edit {}: {}", edit.transaction_id, e);
+                self.ui.error_message = Some(format!("Couldn't save \"{}\": {}", edit.original_description, e));
+            }
+        }
+    }
+}
@@ -6,7 +6,11 @@
 //! ## Key Functions:
 //! - `load_initial_data()` - Load all required data on app startup
 //! - `load_balance()` - Fetch current balance for selected child
-//! - `load_calendar_data()` - Load transaction data for calendar view
+//! - `load_calendar_data()` - Load transaction data for calendar view (on the loader thread)
+//! - `load_planned_purchases()` - Load purchases that reserve part of the balance
+//! - `load_budget_status()` - Load the selected month's spending against its targets
+//! - `poll_background_loads()` - Apply results from the loader thread each frame
+//! - `request_snapshot_refresh()` - Bring the child's startup snapshot up to date
 //!
 //! ## Purpose:
 //! This module centralizes all data loading logic, ensuring consistent error handling
@@ -24,14 +28,164 @@
 //! 4. Updates application state with loaded data
 //! 5. Handles any errors and provides user feedback
 //!
+//! Calendar months and the children list are read by `DataLoader` on worker
+//! threads, so large data files don't stall frames; results come back over a
+//! channel and are applied at the start of the next frame. The update check
+//! runs there too, since it waits on the network.
+//!
+//! On startup the balance, goal and recent transactions come from the child's
+//! snapshot file when there is one, so the first frame doesn't wait on the
+//! full CSVs. The loader then rebuilds the snapshot from the full data and
+//! the rest of the views load once it arrives.
+//!
 //! This module ensures the UI always has the most current data available.
 
 use log::{info, warn};
 use chrono::Datelike;
+use eframe::egui;
+use std::collections::HashMap;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Arc;
 use crate::ui::app_state::AllowanceTrackerApp;
 use crate::ui::mappers::to_dto;
+use crate::backend::Backend;
+use crate::backend::domain::child_service::ChildService;
+use crate::backend::domain::commands::budget::BudgetStatusQuery;
+use crate::backend::domain::commands::planned_purchases::PlannedPurchasesQuery;
 use crate::backend::domain::commands::transactions::TransactionListQuery;
-use shared::{Transaction, TransactionType};
+use crate::backend::domain::commands::updates::UpdateCheckResult;
+use crate::backend::domain::models::child::Child as DomainChild;
+use crate::backend::domain::models::child_snapshot::ChildSnapshot;
+use crate::backend::domain::{CalendarService, SnapshotService, TransactionService, UpdateService};
+use shared::{CalendarNavigationWindow, Transaction, TransactionType};
+
+/// Backend reads that run on the loader thread instead of inside a frame
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DataRequest {
+    /// A calendar month plus neighbouring month summaries for the active child
+    CalendarMonth { child_id: Option<String>, month: u32, year: i32 },
+    /// Every child, for the child pickers
+    Children,
+    /// A child's startup snapshot, rebuilt from their full data
+    Snapshot { child_id: String },
+    /// Newer releases; `scheduled` is the daily check, otherwise a parent asked
+    UpdateCheck { scheduled: bool },
+}
+
+/// Data returned by a finished request
+pub enum DataPayload {
+    CalendarMonth(Box<CalendarNavigationWindow>),
+    Children(Vec<DomainChild>),
+    Snapshot(Box<ChildSnapshot>),
+    /// `None` when the scheduled check had nothing to report
+    UpdateCheck(Option<Box<UpdateCheckResult>>),
+}
+
+/// Message sent from the loader thread back to the UI thread
+pub struct DataResponse {
+    pub request: DataRequest,
+    pub result: Result<DataPayload, String>,
+}
+
+/// Runs slow backend reads on worker threads and hands results back by channel.
+///
+/// Each request is tracked while it runs, so asking again for something that
+/// is already loading doesn't start a second read; instead the request is
+/// re-run once the first finishes, in case the data changed in between.
+pub struct DataLoader {
+    child_service: ChildService,
+    calendar_service: CalendarService,
+    transaction_service: Arc<TransactionService>,
+    snapshot_service: SnapshotService,
+    update_service: UpdateService,
+    sender: Sender<DataResponse>,
+    receiver: Receiver<DataResponse>,
+    /// Requests currently running, and whether each must run again when it finishes
+    in_flight: HashMap<DataRequest, bool>,
+    /// Used to wake the UI when a result arrives
+    repaint: egui::Context,
+}
+
+impl DataLoader {
+    /// Create a loader that reads through the given backend's services
+    pub fn new(backend: &Backend, repaint: egui::Context) -> Self {
+        let (sender, receiver) = channel();
+        Self {
+            child_service: backend.child_service.clone(),
+            calendar_service: backend.calendar_service.clone(),
+            transaction_service: backend.transaction_service.clone(),
+            snapshot_service: backend.snapshot_service.clone(),
+            update_service: backend.update_service.clone(),
+            sender,
+            receiver,
+            in_flight: HashMap::new(),
+            repaint,
+        }
+    }
+
+    /// Start a request, or mark it to run again if it is already loading
+    pub fn request(&mut self, request: DataRequest) {
+        if let Some(rerun) = self.in_flight.get_mut(&request) {
+            *rerun = true;
+            return;
+        }
+        self.in_flight.insert(request.clone(), false);
+        self.spawn(request);
+    }
+
+    /// Whether a request is still running
+    pub fn is_loading(&self, request: &DataRequest) -> bool {
+        self.in_flight.contains_key(request)
+    }
+
+    /// Results finished since the last call; re-runs anything asked for again meanwhile
+    pub fn take_finished(&mut self) -> Vec<DataResponse> {
+        let finished: Vec<DataResponse> = self.receiver.try_iter().collect();
+        for response in &finished {
+            if self.in_flight.remove(&response.request) == Some(true) {
+                self.in_flight.insert(response.request.clone(), false);
+                self.spawn(response.request.clone());
+            }
+        }
+        finished
+    }
+
+    fn spawn(&self, request: DataRequest) {
+        let child_service = self.child_service.clone();
+        let calendar_service = self.calendar_service.clone();
+        let transaction_service = self.transaction_service.clone();
+        let snapshot_service = self.snapshot_service.clone();
+        let update_service = self.update_service.clone();
+        let sender = self.sender.clone();
+        let repaint = self.repaint.clone();
+
+        std::thread::spawn(move || {
+            let result = match &request {
+                // Use calendar service instead of transaction service directly
+                // This ensures proper cross-month balance forwarding. Neighbouring months
+                // come back as summaries so month switches can draw balances straight away.
+                DataRequest::CalendarMonth { month, year, .. } => calendar_service
+                    .get_calendar_navigation_window(*month, *year as u32, &transaction_service)
+                    .map(|window| DataPayload::CalendarMonth(Box::new(window))),
+                DataRequest::Children => child_service
+                    .list_children()
+                    .map(|result| DataPayload::Children(result.children)),
+                DataRequest::Snapshot { child_id } => snapshot_service
+                    .refresh_snapshot(child_id)
+                    .map(|snapshot| DataPayload::Snapshot(Box::new(snapshot))),
+                DataRequest::UpdateCheck { scheduled: true } => update_service
+                    .run_scheduled_check()
+                    .map(|result| DataPayload::UpdateCheck(result.map(Box::new))),
+                DataRequest::UpdateCheck { scheduled: false } => update_service
+                    .check_for_updates()
+                    .map(|result| DataPayload::UpdateCheck(Some(Box::new(result)))),
+            };
+            // The receiver only goes away when the app is closing
+            let _ = sender.send(DataResponse { request, result: result.map_err(|e| e.to_string()) });
+            repaint.request_repaint();
+        });
+    }
+}
 
 impl AllowanceTrackerApp {
     /// Refresh all data for current child - common method used when switching children
@@ -39,8 +193,17 @@ impl AllowanceTrackerApp {
     pub fn refresh_all_data_for_current_child(&mut self) {
         info!("🔄 Refreshing all data for current child");
         
+        // Everything is read in full below, so a pending startup snapshot no longer matters
+        self.core.showing_snapshot = false;
+        // Search hits and highlights belong to the previous child
+        self.calendar.clear_search();
+        self.backend().calendar_service.clear_highlight();
+        self.request_children();
+        self.load_locale();
+        self.load_background();
         self.load_balance();
         self.load_calendar_data();
+        self.load_planned_purchases();
         self.reset_table_for_new_child();
         self.load_chart_data();
         self.load_goal_data();
@@ -48,20 +211,51 @@ impl AllowanceTrackerApp {
         info!("✅ All data refreshed for current child");
     }
 
-    /// Load initial data
+    /// Load the locale and currency that apply to the current child
+    pub fn load_locale(&mut self) {
+        let child_id = if let Some(ref child) = self.core.current_child { child.id.clone() } else { return; };
+        match self.backend().localization_service.get_locale_for_child(&child_id) {
+            Ok(locale) => self.core.current_locale = locale,
+            Err(e) => warn!("🌐 Failed to load locale, keeping {}: {}", self.core.current_locale, e),
+        }
+        match self.backend().family_service.get_currency_for_child(&child_id) {
+            Ok(currency) => self.core.current_currency = currency,
+            Err(e) => warn!("🌐 Failed to load currency, keeping {}: {}", self.core.current_currency, e),
+        }
+    }
+
+    /// Load the background the current child picked
+    pub fn load_background(&mut self) {
+        let child_id = if let Some(ref child) = self.core.current_child { child.id.clone() } else { return; };
+        match self.backend().child_service.get_child_settings(&child_id) {
+            Ok(result) => self.core.current_background = result.settings.background(),
+            Err(e) => warn!("🖼️ Failed to load background, keeping the current one: {}", e),
+        }
+    }
+
     pub fn load_initial_data(&mut self) {
         info!("📊 Loading initial data");
+        self.request_children();
         
         // Load active child
         match self.backend().child_service.get_active_child() {
             Ok(response) => {
                 if let Some(child) = response.active_child.child {
+                    let child_id = child.id.clone();
                     self.core.current_child = Some(to_dto(child));
-                    self.load_balance();
-                    self.load_calendar_data();
-                    self.reset_table_for_new_child(); // Reset table state for initial load
-                    self.load_chart_data(); // Refresh chart for initial load
-                    self.load_goal_data(); // Load goal data for initial load
+                    self.load_locale();
+                    self.load_background();
+                    if self.apply_startup_snapshot(&child_id) {
+                        // The rest loads once the snapshot has been checked against the full data
+                        self.load_calendar_data();
+                    } else {
+                        self.load_balance();
+                        self.load_calendar_data();
+                        self.load_planned_purchases();
+                        self.reset_table_for_new_child(); // Reset table state for initial load
+                        self.load_chart_data(); // Refresh chart for initial load
+                        self.load_goal_data(); // Load goal data for initial load
+                    }
                 }
                 self.ui.loading = false;
             }
@@ -72,6 +266,98 @@ impl AllowanceTrackerApp {
         }
     }
     
+    /// Show the child's stored snapshot in place of a full load; false if there isn't one
+    fn apply_startup_snapshot(&mut self, child_id: &str) -> bool {
+        let snapshot = match self.backend().snapshot_service.load_snapshot(child_id) {
+            Ok(Some(snapshot)) => snapshot,
+            Ok(None) => return false,
+            Err(e) => {
+                warn!("📸 Failed to read snapshot, loading in full: {}", e);
+                return false;
+            }
+        };
+        info!("📸 Showing snapshot from {} while the full data loads", snapshot.written_at);
+
+        self.core.current_balance = snapshot.balance;
+        self.goal.set_goal_data(snapshot.active_goal, snapshot.goal_calculation);
+
+        // Older pages follow on from the last snapshot transaction as the table scrolls
+        let recent: Vec<Transaction> = snapshot
+            .recent_transactions
+            .into_iter()
+            .map(crate::ui::mappers::TransactionMapper::to_dto)
+            .collect();
+        let next_cursor = recent.last().map(|t| t.id.clone());
+        self.table.reset();
+        self.table.append_transactions(recent, true, next_cursor);
+
+        self.core.showing_snapshot = true;
+        true
+    }
+    
+    /// Replace the startup snapshot with the full data and load the remaining views
+    fn finish_snapshot_startup(&mut self, snapshot: Option<ChildSnapshot>) {
+        self.core.showing_snapshot = false;
+        match snapshot {
+            Some(snapshot) => {
+                info!("📸 Full load finished, balance ${:.2}", snapshot.balance);
+                self.core.current_balance = snapshot.balance;
+                self.goal.set_goal_data(snapshot.active_goal, snapshot.goal_calculation);
+            }
+            None => {
+                self.load_balance();
+                self.load_goal_data();
+            }
+        }
+        self.load_planned_purchases();
+        self.reset_table_for_new_child();
+        self.load_chart_data();
+    }
+    
+    /// Rebuild the current child's startup snapshot on the loader thread
+    pub fn request_snapshot_refresh(&mut self) {
+        if let Some(child) = &self.core.current_child {
+            let child_id = child.id.clone();
+            self.loader.request(DataRequest::Snapshot { child_id });
+        }
+    }
+    
+    /// Load the current child's open planned purchases
+    pub fn load_planned_purchases(&mut self) {
+        if self.core.current_child.is_none() {
+            self.calendar.planned_purchases.clear();
+            return;
+        }
+        let query = PlannedPurchasesQuery { child_id: None, include_closed: false };
+        match self.backend().planned_purchase_service.list_planned_purchases(query) {
+            Ok(result) => self.calendar.planned_purchases = result.purchases,
+            Err(e) => {
+                warn!("🛍️ Failed to load planned purchases: {}", e);
+                self.calendar.planned_purchases.clear();
+            }
+        }
+    }
+    
+    /// Load the current child's spending against their targets for the selected month
+    pub fn load_budget_status(&mut self) {
+        if self.core.current_child.is_none() {
+            self.calendar.budget_status = None;
+            return;
+        }
+        let query = BudgetStatusQuery {
+            child_id: None,
+            year: self.calendar.selected_year,
+            month: self.calendar.selected_month,
+        };
+        match self.backend().budget_service.get_budget_status(query) {
+            Ok(status) => self.calendar.budget_status = Some(status),
+            Err(e) => {
+                warn!("🎯 Failed to load budget status: {}", e);
+                self.calendar.budget_status = None;
+            }
+        }
+    }
+    
     /// Reset table state when switching to a new child
     pub fn reset_table_for_new_child(&mut self) {
         log::info!("📋 Resetting table state for new child");
@@ -91,6 +377,7 @@ impl AllowanceTrackerApp {
             
             // Get the most recent transaction to get the current balance
             let query = TransactionListQuery {
+                child_id: None,
                 after: None,
                 limit: Some(1), // Just get the most recent transaction
                 start_date: None,
@@ -128,103 +415,185 @@ impl AllowanceTrackerApp {
         log::info!("📊 Balance update complete - Final balance: ${:.2}", self.core.current_balance);
     }
     
-    /// Load calendar data for the selected month/year
+    /// Load calendar data for the selected month/year.
+    ///
+    /// The month is read on the loader thread; `apply_calendar_window` stores it
+    /// when it arrives. Until then the previous month stays on screen.
+    ///
+    /// Every change to a child's money reloads the calendar, so this also
+    /// brings the startup snapshot up to date.
     pub fn load_calendar_data(&mut self) {
         log::info!("📅 Loading calendar data for {}/{}", self.calendar.selected_month, self.calendar.selected_year);
         
-        // Calculate the start and end dates for the selected month
-        let start_date = match chrono::NaiveDate::from_ymd_opt(self.calendar.selected_year, self.calendar.selected_month, 1) {
-            Some(date) => date,
-            None => {
-                log::error!("❌ Failed to create start date for {}/{}", self.calendar.selected_month, self.calendar.selected_year);
-                self.ui.error_message = Some("Invalid date".to_string());
-                return;
+        if chrono::NaiveDate::from_ymd_opt(self.calendar.selected_year, self.calendar.selected_month, 1).is_none() {
+            log::error!("❌ Failed to create start date for {}/{}", self.calendar.selected_month, self.calendar.selected_year);
+            self.ui.error_message = Some("Invalid date".to_string());
+            return;
+        }
+        
+        self.calendar.calendar_loading = true;
+        self.loader.request(DataRequest::CalendarMonth {
+            child_id: self.core.current_child.as_ref().map(|child| child.id.clone()),
+            month: self.calendar.selected_month,
+            year: self.calendar.selected_year,
+        });
+        self.request_snapshot_refresh();
+    }
+    
+    /// Store a calendar month read by the loader thread
+    fn apply_calendar_window(&mut self, window: CalendarNavigationWindow) {
+        let calendar_month = window.focus;
+        self.calendar.adjacent_month_summaries = vec![window.previous, window.next];
+
+        log::info!("📊 Successfully loaded calendar month with {} days for {}/{}", 
+                  calendar_month.days.len(), self.calendar.selected_month, self.calendar.selected_year);
+        
+        // DEBUG: Log July 21st specifically
+        if self.calendar.selected_month == 7 && self.calendar.selected_year == 2025 {
+            if let Some(july_21) = calendar_month.days.iter().find(|d| d.day == 21) {
+                log::info!("🔍 FRONTEND DEBUG: July 21st from backend - balance: ${:.2}, transactions: {}", 
+                          july_21.balance, july_21.transactions.len());
+                for (i, tx) in july_21.transactions.iter().enumerate() {
+                    log::info!("🔍 FRONTEND DEBUG: July 21st transaction {}: {} at {} = balance ${:.2}", 
+                              i + 1, tx.description, tx.date.format("%H:%M:%S"), tx.balance);
+                }
             }
-        };
+        }
         
-        let end_date = if self.calendar.selected_month == 12 {
-            chrono::NaiveDate::from_ymd_opt(self.calendar.selected_year + 1, 1, 1).unwrap()
-        } else {
-            chrono::NaiveDate::from_ymd_opt(self.calendar.selected_year, self.calendar.selected_month + 1, 1).unwrap()
-        } - chrono::Duration::days(1);
+        // Extract transactions from all calendar days (for backward compatibility)
+        let mut all_transactions = Vec::new();
+        for day in &calendar_month.days {
+            all_transactions.extend(day.transactions.clone());
+        }
         
-        log::info!("🗓️  Querying transactions from {} to {}", start_date, end_date);
+        // Store converted transactions in modular calendar state
+        self.calendar.calendar_transactions = all_transactions.clone();
         
-        // Use calendar service instead of transaction service directly
-        // This ensures proper cross-month balance forwarding
-        match self.backend().calendar_service.get_calendar_month_with_transactions(
-            self.calendar.selected_month,
-            self.calendar.selected_year as u32,
-            &self.backend().transaction_service,
-        ) {
-            Ok(calendar_month) => {
-                log::info!("📊 Successfully loaded calendar month with {} days for {}/{}", 
-                          calendar_month.days.len(), self.calendar.selected_month, self.calendar.selected_year);
-                
-                // DEBUG: Log July 21st specifically
-                if self.calendar.selected_month == 7 && self.calendar.selected_year == 2025 {
-                    if let Some(july_21) = calendar_month.days.iter().find(|d| d.day == 21) {
-                        log::info!("🔍 FRONTEND DEBUG: July 21st from backend - balance: ${:.2}, transactions: {}", 
-                                  july_21.balance, july_21.transactions.len());
-                        for (i, tx) in july_21.transactions.iter().enumerate() {
-                            log::info!("🔍 FRONTEND DEBUG: July 21st transaction {}: {} at {} = balance ${:.2}", 
-                                      i + 1, tx.description, tx.date.format("%H:%M:%S"), tx.balance);
-                        }
+        // Store the calendar month data in modular state
+        self.calendar.calendar_month = Some(calendar_month.clone());
+        
+        // Spending may have changed along with the month, so recompute the targets
+        self.load_budget_status();
+        
+        // TEMPORARY: Sync compatibility field
+        // self.calendar_transactions = all_transactions; // Removed
+        
+        log::info!("🔄 Converted to {} DTO transactions", self.calendar.calendar_transactions.len());
+        
+        // Debug first few transactions to verify conversion
+        for (i, transaction) in self.calendar.calendar_transactions.iter().enumerate() {
+            log::debug!("📝 Transaction {}: {} on {} (amount: ${})", 
+                       i + 1, transaction.description, transaction.date, transaction.amount);
+        }
+        
+        // Specifically check for June transactions
+        let june_transactions: Vec<_> = self.calendar.calendar_transactions.iter()
+            .filter(|t| {
+                let transaction_date = t.date.naive_local().date();
+                transaction_date.month() == 6 && transaction_date.year() == self.calendar.selected_year
+            })
+            .collect();
+        
+        log::info!("🗓️  Found {} June {} transactions", june_transactions.len(), self.calendar.selected_year);
+        for transaction in june_transactions {
+            log::info!("  - June transaction: {} on {} (amount: ${})", 
+                      transaction.description, transaction.date, transaction.amount);
+        }
+    }
+    
+    /// Clear calendar data after a failed load
+    fn apply_calendar_error(&mut self, error: String) {
+        log::error!("❌ Failed to load transactions: {}", error);
+        self.ui.error_message = Some(format!("Failed to load transactions: {}", error));
+        
+        // Clear modular fields
+        self.calendar.calendar_transactions = Vec::new();
+        self.calendar.calendar_month = None;
+        self.calendar.adjacent_month_summaries.clear();
+        self.calendar.budget_status = None;
+    }
+    
+    /// Ask the loader thread for a fresh list of children
+    pub fn request_children(&mut self) {
+        self.loader.request(DataRequest::Children);
+    }
+    
+    /// Start loading the children if nothing has been loaded or tried yet
+    pub fn ensure_children_loaded(&mut self) {
+        if self.core.children.is_none() && self.core.children_error.is_none() && !self.loader.is_loading(&DataRequest::Children) {
+            self.request_children();
+        }
+    }
+    
+    /// Apply results that the loader thread has finished since the last frame
+    pub fn poll_background_loads(&mut self) {
+        for response in self.loader.take_finished() {
+            match (response.request, response.result) {
+                (DataRequest::CalendarMonth { child_id, month, year }, result) => {
+                    // Ignore months the user has already navigated away from
+                    let current_child_id = self.core.current_child.as_ref().map(|child| child.id.clone());
+                    if child_id != current_child_id || month != self.calendar.selected_month || year != self.calendar.selected_year {
+                        log::info!("📅 Dropping stale calendar load for {}/{}", month, year);
+                        continue;
+                    }
+                    self.calendar.calendar_loading = self.loader.is_loading(&DataRequest::CalendarMonth { child_id, month, year });
+                    match result {
+                        Ok(DataPayload::CalendarMonth(window)) => self.apply_calendar_window(*window),
+                        Ok(_) => log::warn!("📅 Calendar request returned the wrong payload"),
+                        Err(e) => self.apply_calendar_error(e),
                     }
                 }
-                
-                // Extract transactions from all calendar days (for backward compatibility)
-                let mut all_transactions = Vec::new();
-                for day in &calendar_month.days {
-                    all_transactions.extend(day.transactions.clone());
-                }
-                
-                // Store converted transactions in modular calendar state
-                self.calendar.calendar_transactions = all_transactions.clone();
-                
-                // Store the calendar month data in modular state
-                self.calendar.calendar_month = Some(calendar_month.clone());
-                
-                // TEMPORARY: Sync compatibility field
-                // self.calendar_transactions = all_transactions; // Removed
-                
-                log::info!("🔄 Converted to {} DTO transactions", self.calendar.calendar_transactions.len());
-                
-                // Debug first few transactions to verify conversion
-                for (i, transaction) in self.calendar.calendar_transactions.iter().enumerate() {
-                    log::debug!("📝 Transaction {}: {} on {} (amount: ${})", 
-                               i + 1, transaction.description, transaction.date, transaction.amount);
-                }
-                
-                // Specifically check for June transactions
-                let june_transactions: Vec<_> = self.calendar.calendar_transactions.iter()
-                    .filter(|t| {
-                        let transaction_date = t.date.naive_local().date();
-                        transaction_date.month() == 6 && transaction_date.year() == self.calendar.selected_year
-                    })
-                    .collect();
-                
-                log::info!("🗓️  Found {} June {} transactions", june_transactions.len(), self.calendar.selected_year);
-                for transaction in june_transactions {
-                    log::info!("  - June transaction: {} on {} (amount: ${})", 
-                              transaction.description, transaction.date, transaction.amount);
+                (DataRequest::Children, result) => match result {
+                    Ok(DataPayload::Children(children)) => {
+                        info!("👥 Loaded {} children", children.len());
+                        self.core.children = Some(children);
+                        self.core.children_error = None;
+                    }
+                    Ok(_) => log::warn!("👥 Children request returned the wrong payload"),
+                    Err(e) => {
+                        warn!("👥 Failed to load children: {}", e);
+                        self.core.children_error = Some(e);
+                    }
+                },
+                (DataRequest::Snapshot { child_id }, result) => {
+                    if self.core.current_child.as_ref().map(|child| child.id.as_str()) != Some(child_id.as_str()) {
+                        continue;
+                    }
+                    let snapshot = match result {
+                        Ok(DataPayload::Snapshot(snapshot)) => Some(*snapshot),
+                        Ok(_) => {
+                            log::warn!("📸 Snapshot request returned the wrong payload");
+                            None
+                        }
+                        Err(e) => {
+                            warn!("📸 Failed to refresh snapshot: {}", e);
+                            None
+                        }
+                    };
+                    if self.core.showing_snapshot {
+                        self.finish_snapshot_startup(snapshot);
+                    }
                 }
-            }
-            Err(e) => {
-                log::error!("❌ Failed to load transactions: {}", e);
-                self.ui.error_message = Some(format!("Failed to load transactions: {}", e));
-                
-                // Clear modular fields
-                self.calendar.calendar_transactions = Vec::new();
-                self.calendar.calendar_month = None;
-                
-                // TEMPORARY: Also clear compatibility fields
-                // self.calendar_transactions = Vec::new(); // Removed
-                self.calendar.calendar_month = None;
+                (DataRequest::UpdateCheck { scheduled }, result) => match result {
+                    Ok(DataPayload::UpdateCheck(Some(result))) => {
+                        if let Some(update) = result.available.as_ref().filter(|_| scheduled) {
+                            info!("⬆️ Periodic refresh: Version {} is available", update.version);
+                            self.ui.set_success(format!("Version {} is available. See Settings → Updates.", update.version));
+                        }
+                        self.settings.updates_form.check = Some(*result);
+                        self.settings.updates_form.error = None;
+                    }
+                    Ok(DataPayload::UpdateCheck(None)) => {}
+                    Ok(_) => log::warn!("⬆️ Update check returned the wrong payload"),
+                    Err(e) if scheduled => warn!("⬆️ Scheduled update check failed: {}", e),
+                    Err(e) => {
+                        warn!("⬆️ Update check failed: {}", e);
+                        self.settings.updates_form.error = Some(format!("Could not check for updates: {}", e));
+                    }
+                },
             }
         }
     }
-    
     /// Load initial transactions for the table view
     pub fn load_initial_table_transactions(&mut self) {
         log::info!("📋 Loading initial transactions for table view");
@@ -232,6 +601,14 @@ impl AllowanceTrackerApp {
         // Reset table state for fresh load
         self.table.reset();
         
+        // Reversal links are few, so they are loaded whole rather than per page
+        if let Some(child) = self.get_current_child_from_backend() {
+            match self.backend().transaction_service.list_reversals(&child.id) {
+                Ok(reversals) => self.table.set_reversals(&reversals),
+                Err(e) => log::warn!("📋 Failed to load reversal links: {}", e),
+            }
+        }
+        
         // Start loading first batch
         self.load_more_table_transactions();
     }
@@ -253,6 +630,7 @@ impl AllowanceTrackerApp {
             self.table.start_loading();
             
             let query = TransactionListQuery {
+                child_id: None,
                 after: self.table.next_cursor.clone(),
                 limit: Some(self.table.page_size),
                 start_date: None,
@@ -267,10 +645,11 @@ impl AllowanceTrackerApp {
                               result.transactions.len(), result.pagination.has_more);
                     
                     // Convert domain transactions to DTOs
+                    let period_lock = result.period_lock;
                     let dto_transactions: Vec<Transaction> = result
                         .transactions
                         .into_iter()
-                        .map(|domain_tx| crate::ui::mappers::TransactionMapper::to_dto(domain_tx))
+                        .map(|domain_tx| crate::ui::mappers::TransactionMapper::to_dto_with_lock(domain_tx, period_lock.as_ref()))
                         .filter(|t| t.transaction_type != TransactionType::FutureAllowance) // Filter out future allowances
                         .collect();
                     
@@ -11,6 +11,8 @@
 //! - Consistent styling across different dropdown types
 
 use eframe::egui;
+use crate::ui::components::styling::Theme;
+use crate::ui::components::ui_components::{measure_text_width, truncate_to_width};
 
 /// Represents a single menu item in a dropdown
 #[derive(Clone)]
@@ -28,6 +30,7 @@ pub struct DropdownButtonConfig {
     pub text_color: egui::Color32,
     pub hover_bg_color: egui::Color32,
     pub hover_border_color: egui::Color32,
+    pub max_text_width: Option<f32>,  // Longer text is shortened with an ellipsis and shown in full on hover
 }
 
 /// Configuration for dropdown menu appearance  
@@ -60,14 +63,16 @@ impl DropdownMenu {
         ui: &mut egui::Ui,
         config: &DropdownButtonConfig,
     ) -> (egui::Response, bool) {
+        let font = egui::FontId::new(config.font_size, egui::FontFamily::Proportional);
+        let text = match config.max_text_width {
+            Some(max_width) => truncate_to_width(ui, &config.text, font.clone(), max_width),
+            None => config.text.clone(),
+        };
+
         // Create a clickable area (no selectable text)
         let button_response = ui.allocate_response(
             egui::vec2(
-                ui.fonts(|f| f.layout_no_wrap(
-                    config.text.clone(), 
-                    egui::FontId::new(config.font_size, egui::FontFamily::Proportional), 
-                    config.text_color
-                )).size().x + 16.0, // Add padding
+                measure_text_width(ui, &text, font.clone()) + 16.0, // Add padding
                 config.font_size + 8.0 // Add vertical padding
             ),
             egui::Sense::click()
@@ -77,10 +82,15 @@ impl DropdownMenu {
         ui.painter().text(
             button_response.rect.center(),
             egui::Align2::CENTER_CENTER,
-            &config.text,
-            egui::FontId::new(config.font_size, egui::FontFamily::Proportional),
+            &text,
+            font,
             config.text_color
         );
+        let button_response = if text != config.text {
+            button_response.on_hover_text(&config.text)
+        } else {
+            button_response
+        };
 
         // Add hover and click effects
         if button_response.hovered() || button_response.is_pointer_button_down_on() || self.is_open {
@@ -153,9 +163,25 @@ impl DropdownMenu {
         
         let mut clicked_item = None;
         
+        // Size the menu to its widest item as it will render, emoji included,
+        // with room for padding
+        let item_font = egui::FontId::new(config.item_font_size, egui::FontFamily::Proportional);
+        let display_texts: Vec<String> = items
+            .iter()
+            .map(|item| match &item.icon {
+                Some(icon) => format!("{} {}", icon, item.label),
+                None => item.label.clone(),
+            })
+            .collect();
+        let max_width = display_texts
+            .iter()
+            .map(|text| measure_text_width(ui, text, item_font.clone()))
+            .fold(0.0, f32::max);
+        let dropdown_width = (max_width.max(config.min_width) + 40.0).max(120.0);
+
         // Calculate safe dropdown position that won't need screen boundary adjustment
         let screen_rect = ui.ctx().screen_rect();
-        let estimated_dropdown_width = config.min_width + 60.0; // Estimate with padding
+        let estimated_dropdown_width = dropdown_width + 16.0; // Frame margins
         
         // If dropdown would go off-screen, position it to the left of the button instead
         let safe_x = if button_rect.left() + estimated_dropdown_width > screen_rect.max.x {
@@ -176,7 +202,7 @@ impl DropdownMenu {
             .interactable(true)  // Ensure this area can receive interactions
             .show(ui.ctx(), |ui| {
                 let frame = egui::Frame::popup(&ui.style())
-                    .fill(egui::Color32::WHITE)  // Pure white background
+                    .fill(Theme::current(ui.ctx()).layout.modal_background)  // Pure white background (dark gray in the dark theme)
                     .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(200, 200, 200)))  // Normal border
                     .corner_radius(egui::CornerRadius::same(6))
                     .inner_margin(egui::Margin::same(8));
@@ -188,35 +214,9 @@ impl DropdownMenu {
                             ui.set_min_width(config.min_width);
                             ui.label("No items available");
                         } else {
-                            // Calculate the width needed based on actual item labels
-                            let mut max_width: f32 = 0.0;
-                            
-                            for item in items {
-                                let display_text = if let Some(icon) = &item.icon {
-                                    format!("{} {}", icon, item.label)
-                                } else {
-                                    item.label.clone()
-                                };
-                                
-                                let text_width = ui.fonts(|f| f.layout_no_wrap(display_text.clone(), 
-                                    egui::FontId::new(config.item_font_size, egui::FontFamily::Proportional), 
-                                    egui::Color32::BLACK)).size().x;
-                                
-                                // Add extra breathing room - multiply by 1.3 for more generous sizing
-                                let generous_width = text_width * 1.3;
-                                max_width = max_width.max(generous_width);
-                            }
-                            
-                            // Use the larger of calculated width or minimum width, plus buffer
-                            let dropdown_width = (max_width.max(config.min_width) + 40.0).max(120.0);
                             ui.set_min_width(dropdown_width);
                             
-                            for (index, item) in items.iter().enumerate() {
-                                let button_text = if let Some(icon) = &item.icon {
-                                    format!("{} {}", icon, item.label)
-                                } else {
-                                    item.label.clone()
-                                };
+                            for (index, (item, button_text)) in items.iter().zip(&display_texts).enumerate() {
                                 
                                 log::info!("🚀 RENDERING DROPDOWN ITEM: {}", item.label);
                                 
@@ -230,12 +230,12 @@ impl DropdownMenu {
                                 let text_color = if item.is_current { 
                                     egui::Color32::from_rgb(79, 109, 245) 
                                 } else { 
-                                    egui::Color32::from_rgb(60, 60, 60) 
+                                    Theme::current(ui.ctx()).typography.primary 
                                 };
                                 ui.painter().text(
                                     button_response.rect.center(),
                                     egui::Align2::CENTER_CENTER,
-                                    &button_text,
+                                    button_text,
                                     egui::FontId::new(config.item_font_size, egui::FontFamily::Proportional),
                                     text_color
                                 );
@@ -260,7 +260,7 @@ impl DropdownMenu {
                                         if item.is_current { 
                                             egui::Color32::from_rgb(79, 109, 245) 
                                         } else { 
-                                            egui::Color32::from_rgb(60, 60, 60) 
+                                            Theme::current(ui.ctx()).typography.primary 
                                         }
                                     );
                                 }
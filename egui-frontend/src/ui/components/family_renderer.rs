@@ -0,0 +1,306 @@
+//! # Family Renderer Module
+//!
+//! This module renders the Family tab: one card per child with their balance,
+//! goal progress, next allowance, birthday countdown and this year's totals,
+//! plus a family total. A second view compares the children on savings rate
+//! and goals completed, with balances hidden unless a parent turns them on.
+//!
+//! ## Key Functions:
+//! - `draw_family_section()` - Family tab content with loading and error handling
+//! - `load_family_overview()` - Fetch the overview from the family service
+//! - `load_child_comparison()` - Fetch the comparison from the family service
+//!
+//! ## Purpose:
+//! Lets a parent see every child at a glance without switching between them.
+//! Children can keep their money in different currencies; each balance is shown
+//! in the child's own currency, with the family-currency value alongside when
+//! an exchange rate has been set.
+
+use eframe::egui;
+use chrono::Datelike;
+use frontend_api::AllowanceApi;
+use log::{info, warn};
+use shared::SetActiveChildRequest;
+use crate::backend::domain::commands::family::{ChildComparisonQuery, ChildComparisonResult, ChildOverview};
+use crate::backend::domain::localization::Locale;
+use crate::backend::domain::models::currency::Currency;
+use crate::backend::domain::DateStyle;
+use crate::ui::app_state::{AllowanceTrackerApp, MainTab};
+
+/// Width of a single child card
+const CHILD_CARD_WIDTH: f32 = 260.0;
+
+impl AllowanceTrackerApp {
+    /// Load the family overview from the backend
+    pub fn load_family_overview(&mut self) {
+        match self.backend().family_service.get_family_overview() {
+            Ok(overview) => {
+                info!("👪 Loaded family overview for {} children", overview.children.len());
+                self.family.overview = Some(overview);
+                self.family.error_message = None;
+            }
+            Err(e) => {
+                warn!("👪 Failed to load family overview: {}", e);
+                self.family.error_message = Some(format!("Failed to load family overview: {}", e));
+            }
+        }
+    }
+
+    /// Load the comparison of every child from the backend
+    pub fn load_child_comparison(&mut self) {
+        let query = ChildComparisonQuery { hide_balances: self.family.hide_balances };
+        match self.backend().family_service.get_child_comparison(query) {
+            Ok(comparison) => {
+                info!("👪 Loaded comparison of {} children", comparison.children.len());
+                self.family.comparison = Some(comparison);
+                self.family.error_message = None;
+            }
+            Err(e) => {
+                warn!("👪 Failed to load child comparison: {}", e);
+                self.family.error_message = Some(format!("Failed to load child comparison: {}", e));
+            }
+        }
+    }
+
+    /// Draw the Family tab content
+    pub fn draw_family_section(&mut self, ui: &mut egui::Ui, available_rect: egui::Rect) {
+        // Calculate content area (accounting for card margins)
+        let content_margin = 20.0;
+        let content_rect = egui::Rect::from_min_size(
+            available_rect.min + egui::vec2(content_margin, content_margin),
+            available_rect.size() - egui::vec2(content_margin * 2.0, content_margin * 2.0)
+        );
+        self.draw_card_background(ui, content_rect);
+
+        if self.family.overview.is_none() && self.family.error_message.is_none() {
+            self.load_family_overview();
+        }
+        if self.family.show_comparison && self.family.comparison.is_none() && self.family.error_message.is_none() {
+            self.load_child_comparison();
+        }
+
+        let inner_rect = content_rect.shrink(20.0);
+        let locale = self.core.current_locale;
+        let mut switch_to_child: Option<String> = None;
+
+        ui.allocate_new_ui(egui::UiBuilder::new().max_rect(inner_rect), |ui| {
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut self.family.show_comparison, false, "👪 Overview");
+                ui.selectable_value(&mut self.family.show_comparison, true, "🏆 Compare");
+                if self.family.show_comparison {
+                    ui.add_space(15.0);
+                    if ui.checkbox(&mut self.family.hide_balances, "Hide balances").changed() {
+                        self.family.comparison = None;
+                    }
+                }
+            });
+            ui.add_space(10.0);
+
+            if let Some(error) = &self.family.error_message {
+                ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                return;
+            }
+            if self.family.show_comparison {
+                if let Some(comparison) = &self.family.comparison {
+                    draw_comparison(ui, comparison, locale);
+                }
+                return;
+            }
+            let Some(overview) = &self.family.overview else {
+                return;
+            };
+
+            if overview.children.is_empty() {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(inner_rect.height() / 3.0);
+                    ui.label(egui::RichText::new("Add a child to see the family overview")
+                        .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
+                        .color(egui::Color32::from_rgb(120, 120, 120)));
+                });
+                return;
+            }
+
+            // Family total
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new("Family total")
+                    .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
+                    .strong());
+                ui.label(egui::RichText::new(overview.family_currency.format(overview.total_in_family_currency, locale))
+                    .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
+                    .color(egui::Color32::from_rgb(33, 130, 60))
+                    .strong());
+            });
+            if !overview.unconverted_currencies.is_empty() {
+                let codes: Vec<&str> = overview.unconverted_currencies.iter().map(|c| c.code()).collect();
+                ui.label(egui::RichText::new(format!(
+                    "Not included: balances in {} (no exchange rate to {} set)",
+                    codes.join(", "),
+                    overview.family_currency.code(),
+                ))
+                .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
+                .color(egui::Color32::from_rgb(120, 120, 120)));
+            }
+            ui.add_space(15.0);
+
+            // One card per child
+            egui::ScrollArea::vertical().id_salt("family_overview_scroll").show(ui, |ui| {
+                ui.horizontal_wrapped(|ui| {
+                    ui.spacing_mut().item_spacing = egui::vec2(15.0, 15.0);
+                    for child in &overview.children {
+                        if draw_child_card(ui, child, overview.family_currency, locale) {
+                            switch_to_child = Some(child.child_id.clone());
+                        }
+                    }
+                });
+            });
+        });
+
+        if let Some(child_id) = switch_to_child {
+            self.open_child_from_family(child_id);
+        }
+    }
+
+    /// Make a child active and jump to their calendar
+    fn open_child_from_family(&mut self, child_id: String) {
+        info!("👪 Opening child {} from family overview", child_id);
+        match self.api().set_active_child(SetActiveChildRequest { child_id }) {
+            Ok(response) => {
+                self.core.current_child = Some(response.active_child);
+                self.refresh_all_data_for_current_child();
+                self.set_current_tab(MainTab::Calendar);
+            }
+            Err(e) => {
+                self.ui.error_message = Some(format!("Failed to select child: {}", e));
+            }
+        }
+    }
+}
+
+/// Draw one child's card; returns true when "Open" was clicked
+fn draw_child_card(ui: &mut egui::Ui, child: &ChildOverview, family_currency: Currency, locale: Locale) -> bool {
+    let mut open_clicked = false;
+
+    egui::Frame::new()
+        .fill(egui::Color32::from_rgb(248, 249, 252))
+        .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(220, 220, 220)))
+        .corner_radius(egui::CornerRadius::same(10))
+        .inner_margin(egui::Margin::same(12))
+        .show(ui, |ui| {
+            ui.set_width(CHILD_CARD_WIDTH);
+            ui.vertical(|ui| {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(&child.name)
+                        .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
+                        .strong());
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.small_button("Open").clicked() {
+                            open_clicked = true;
+                        }
+                    });
+                });
+
+                let birthday = match child.summary.days_until_birthday {
+                    0 => format!("🎂 Turns {} today!", child.summary.next_birthday_age),
+                    1 => format!("🎂 Age {} · turns {} tomorrow", child.summary.age, child.summary.next_birthday_age),
+                    days => format!("🎂 Age {} · turns {} in {} days", child.summary.age, child.summary.next_birthday_age, days),
+                };
+                ui.label(egui::RichText::new(birthday)
+                    .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
+                    .color(egui::Color32::from_rgb(120, 120, 120)));
+
+                ui.label(egui::RichText::new(child.currency.format(child.balance, locale))
+                    .font(egui::FontId::new(22.0, egui::FontFamily::Proportional))
+                    .strong());
+                if child.currency != family_currency {
+                    let converted = match child.balance_in_family_currency {
+                        Some(amount) => format!("≈ {}", family_currency.format(amount, locale)),
+                        None => format!("No {} → {} rate set", child.currency.code(), family_currency.code()),
+                    };
+                    ui.label(egui::RichText::new(converted)
+                        .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
+                        .color(egui::Color32::from_rgb(120, 120, 120)));
+                }
+
+                ui.add_space(8.0);
+                match &child.goal {
+                    Some(goal) => {
+                        ui.label(format!("🎯 {} ({})", goal.description, child.currency.format(goal.target_amount, locale)));
+                        ui.add(egui::ProgressBar::new((goal.progress_percent / 100.0) as f32)
+                            .text(format!("{:.0}%", goal.progress_percent)));
+                    }
+                    None => {
+                        ui.label(egui::RichText::new("🎯 No goal yet")
+                            .color(egui::Color32::from_rgb(120, 120, 120)));
+                    }
+                }
+
+                ui.add_space(8.0);
+                ui.label(format!(
+                    "📅 This year: +{} earned · -{} spent",
+                    child.currency.format(child.summary.earned_this_year, locale),
+                    child.currency.format(child.summary.spent_this_year, locale),
+                ));
+
+                ui.add_space(8.0);
+                match &child.next_allowance {
+                    Some(next) => {
+                        let date = locale.format_date(next.date.year(), next.date.month(), next.date.day(), DateStyle::Medium);
+                        ui.label(format!("💰 {} on {}", child.currency.format(next.amount, locale), date));
+                    }
+                    None => {
+                        ui.label(egui::RichText::new("💰 No allowance scheduled")
+                            .color(egui::Color32::from_rgb(120, 120, 120)));
+                    }
+                }
+            });
+        });
+
+    open_clicked
+}
+
+/// Draw the children side by side, best savings rate first
+fn draw_comparison(ui: &mut egui::Ui, comparison: &ChildComparisonResult, locale: Locale) {
+    ui.label(egui::RichText::new(format!(
+        "Savings rate is the share of money earned in {} that hasn't been spent.",
+        comparison.year
+    ))
+    .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
+    .color(egui::Color32::from_rgb(120, 120, 120)));
+    ui.add_space(10.0);
+
+    egui::Grid::new("family_comparison_grid").striped(true).spacing([24.0, 8.0]).show(ui, |ui| {
+        ui.label(egui::RichText::new("Child").strong());
+        ui.label(egui::RichText::new("Savings rate").strong());
+        ui.label(egui::RichText::new("Goals reached").strong());
+        ui.label(egui::RichText::new("Current goal").strong());
+        if !comparison.balances_hidden {
+            ui.label(egui::RichText::new("Balance").strong());
+        }
+        ui.end_row();
+
+        for (position, child) in comparison.children.iter().enumerate() {
+            let medal = match (position, child.savings_rate) {
+                (0, Some(_)) => "🥇 ",
+                (1, Some(_)) => "🥈 ",
+                (2, Some(_)) => "🥉 ",
+                _ => "",
+            };
+            ui.label(egui::RichText::new(format!("{}{}", medal, child.name)).strong());
+            match child.savings_rate {
+                Some(rate) => ui.label(format!("{:.0}%", rate)),
+                None => ui.label(egui::RichText::new("Nothing earned yet").color(egui::Color32::from_rgb(120, 120, 120))),
+            };
+            ui.label(child.goals_completed.to_string());
+            match child.goal_progress_percent {
+                Some(percent) => ui.add(egui::ProgressBar::new((percent / 100.0) as f32)
+                    .desired_width(120.0)
+                    .text(format!("{:.0}%", percent))),
+                None => ui.label(egui::RichText::new("No goal").color(egui::Color32::from_rgb(120, 120, 120))),
+            };
+            if let Some(balance) = child.balance {
+                ui.label(child.currency.format(balance, locale));
+            }
+            ui.end_row();
+        }
+    });
+}
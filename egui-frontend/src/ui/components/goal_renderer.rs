@@ -59,6 +59,7 @@ impl AllowanceTrackerApp {
     /// Draw the main goal section using the centralized layout system
     pub fn draw_goal_section(&mut self, ui: &mut egui::Ui, available_rect: egui::Rect) {
         log::info!("🎯 GOAL_SECTION: Received {}w x {}h from tab manager", available_rect.width(), available_rect.height());
+        crate::ui::state::TutorialStep::GoalCard.record_target(ui.ctx(), available_rect.shrink(20.0));
         
         let layout = GoalLayout::new();
         
@@ -0,0 +1,103 @@
+//! # Goal Strip
+//!
+//! This module contains a compact, single-row view of the active goal that
+//! can be shown above the calendar.
+//!
+//! ## Responsibilities:
+//! - Show the goal's name, a progress bar and the amount saved so far
+//! - Show the days left, from the same calculation as the goal tab's circle
+//! - Open the goal tab when clicked
+//!
+//! ## Purpose:
+//! Keeps the goal in sight while a kid looks at their calendar. The strip
+//! reads the goal and its calculation that `load_goal_data()` already fetched
+//! for the goal tab in one backend call, so showing it adds no extra reads.
+
+use eframe::egui;
+use log::warn;
+use crate::ui::app_state::{AllowanceTrackerApp, MainTab};
+use crate::ui::components::circular_days_progress::calculate_days_progress;
+use crate::ui::components::styling::{draw_card_container, Theme};
+use crate::ui::components::ui_components::truncate_to_width;
+
+/// Height of the goal strip, including its card
+pub const GOAL_STRIP_HEIGHT: f32 = 56.0;
+
+/// Fill of the progress bar, the same pink as the goal tab's bar
+const PROGRESS_COLOR: egui::Color32 = egui::Color32::from_rgb(200, 120, 200);
+
+impl AllowanceTrackerApp {
+    /// Draw the active goal's progress in `rect`; draws nothing without an active goal
+    pub fn draw_goal_strip(&mut self, ui: &mut egui::Ui, rect: egui::Rect) {
+        let (Some(goal), Some(calculation)) = (&self.goal.current_goal, &self.goal.goal_calculation) else {
+            return;
+        };
+        let theme = Theme::current(ui.ctx());
+        let currency = self.core.current_currency;
+        let locale = self.core.current_locale;
+
+        let saved = (goal.target_amount - calculation.amount_needed).clamp(0.0, goal.target_amount);
+        let progress = if goal.target_amount > 0.0 { (saved / goal.target_amount) as f32 } else { 1.0 };
+        let days_text = if calculation.amount_needed <= 0.0 {
+            "Goal reached!".to_string()
+        } else {
+            match calculate_days_progress(goal, Some(calculation)) {
+                Ok(days) => match days.days_remaining {
+                    Some(1) => "1 day to go".to_string(),
+                    Some(remaining) if remaining > 0 => format!("{} days to go", remaining),
+                    _ => "Keep going!".to_string(),
+                },
+                Err(e) => {
+                    warn!("🎯 Could not work out the goal's days: {}", e);
+                    String::new()
+                }
+            }
+        };
+        let amounts_text = format!("{} of {}", currency.format(saved, locale), currency.format(goal.target_amount, locale));
+        let description = goal.description.clone();
+
+        draw_card_container(ui, rect, 10);
+        let inner = rect.shrink2(egui::vec2(16.0, 8.0));
+        let label_width = (inner.width() * 0.28).min(260.0);
+        let amounts_width = 170.0;
+        let label_rect = egui::Rect::from_min_size(inner.min, egui::vec2(label_width, inner.height()));
+        let amounts_rect = egui::Rect::from_min_max(egui::pos2(inner.right() - amounts_width, inner.top()), inner.max);
+        let bar_rect = egui::Rect::from_center_size(
+            egui::pos2((label_rect.right() + amounts_rect.left()) / 2.0, inner.center().y),
+            egui::vec2((amounts_rect.left() - label_rect.right() - 24.0).max(0.0), 14.0),
+        );
+
+        let label_font = egui::FontId::proportional(16.0);
+        let label = truncate_to_width(ui, &format!("🎯 {}", description), label_font.clone(), label_rect.width());
+        let painter = ui.painter();
+        painter.text(label_rect.left_center(), egui::Align2::LEFT_CENTER, label, label_font, theme.typography.body);
+
+        painter.rect_filled(bar_rect, egui::CornerRadius::same(7), theme.layout.subtle_background);
+        if progress > 0.0 {
+            let filled = egui::Rect::from_min_size(bar_rect.min, egui::vec2(bar_rect.width() * progress.clamp(0.0, 1.0), bar_rect.height()));
+            painter.rect_filled(filled, egui::CornerRadius::same(7), PROGRESS_COLOR);
+        }
+
+        painter.text(
+            amounts_rect.right_top() + egui::vec2(0.0, 2.0),
+            egui::Align2::RIGHT_TOP,
+            &amounts_text,
+            egui::FontId::proportional(15.0),
+            theme.typography.body,
+        );
+        painter.text(
+            amounts_rect.right_bottom() - egui::vec2(0.0, 2.0),
+            egui::Align2::RIGHT_BOTTOM,
+            &days_text,
+            egui::FontId::proportional(13.0),
+            theme.typography.muted,
+        );
+
+        let response = ui.interact(rect, ui.id().with("goal_strip"), egui::Sense::click());
+        let summary = format!("Goal {}: {} saved, {}", description, amounts_text, days_text);
+        response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::ProgressIndicator, true, &summary));
+        if response.on_hover_text("Open the goal tab").on_hover_cursor(egui::CursorIcon::PointingHand).clicked() {
+            self.set_current_tab(MainTab::Goal);
+        }
+    }
+}
@@ -6,6 +6,7 @@
 //! ## Key Functions:
 //! - `render_header()` - Main header rendering with child selector and balance
 //! - `render_child_dropdown()` - Child selection dropdown menu
+//! - `render_quick_entry_box()` - One-line keyboard quick-add, focused with Ctrl/Cmd+K, and the ⭐ templates menu
 //! - `render_messages()` - Success/error message display
 //!
 //! ## Purpose:
@@ -22,9 +23,19 @@
 //! - Visual feedback for user actions
 
 use eframe::egui;
+use frontend_api::AllowanceApi;
 use log::info;
 use crate::ui::app_state::AllowanceTrackerApp;
+use crate::ui::components::styling::Theme;
 use crate::ui::components::dropdown_menu::{DropdownMenuItem, DropdownButtonConfig, DropdownMenuConfig};
+use crate::ui::state::modal_state::SettingsAction;
+use crate::ui::state::TutorialStep;
+
+/// Jumps to the quick-add box from anywhere in the app: Ctrl+K, or Cmd+K on macOS
+pub const QUICK_ENTRY_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::K);
+
+/// Widest the child's name may be in the header before it is shortened
+const CHILD_NAME_MAX_WIDTH: f32 = 220.0;
 
 impl AllowanceTrackerApp {
     /// Render the header
@@ -38,7 +49,7 @@ impl AllowanceTrackerApp {
             .fill(egui::Color32::from_rgba_unmultiplied(255, 255, 255, 30)) // Truly translucent white
             .inner_margin(egui::Margin::symmetric(10, 10));
         
-        frame.show(ui, |ui| {
+        let header_response = frame.show(ui, |ui| {
             ui.allocate_ui_with_layout(
                 egui::vec2(ui.available_width(), header_height - 20.0), // Account for margin
                 egui::Layout::top_down(egui::Align::LEFT),
@@ -48,7 +59,7 @@ impl AllowanceTrackerApp {
                         ui.add(egui::Label::new(egui::RichText::new("Allowance Tracker")
                             .font(egui::FontId::new(28.0, egui::FontFamily::Proportional))
                             .strong()
-                            .color(egui::Color32::from_rgb(60, 60, 60))) // Dark gray for readability
+                            .color(Theme::current(ui.ctx()).typography.primary)) // Dark gray for readability
                             .selectable(false)); // Disable text selection
                         
                         // Flexible space to push right content to the right
@@ -70,9 +81,20 @@ impl AllowanceTrackerApp {
                                 ui.add(egui::Label::new(egui::RichText::new(format!("${:.2}", self.current_balance()))
                                     .font(egui::FontId::new(24.0, egui::FontFamily::Proportional))
                                     .strong()
-                                    .color(egui::Color32::from_rgb(60, 60, 60))) // Same dark gray as title
+                                    .color(Theme::current(ui.ctx()).typography.primary)) // Same dark gray as title
                                     .selectable(false)); // Disable text selection
                                 
+                                // Money promised to planned purchases isn't free to spend
+                                let reserved = self.reserved_amount();
+                                if reserved > 0.0 {
+                                    ui.add_space(6.0);
+                                    ui.add(egui::Label::new(egui::RichText::new(format!("${:.2} available", self.current_balance() - reserved))
+                                        .font(egui::FontId::new(13.0, egui::FontFamily::Proportional))
+                                        .color(egui::Color32::from_rgb(120, 120, 120)))
+                                        .selectable(false))
+                                        .on_hover_text(format!("${:.2} is reserved for planned purchases", reserved));
+                                }
+                                
                                 // Add spacing between balance and name
                                 ui.add_space(15.0);
                                 
@@ -83,6 +105,7 @@ impl AllowanceTrackerApp {
                                     text_color: egui::Color32::from_rgb(80, 80, 80),
                                     hover_bg_color: egui::Color32::from_rgba_unmultiplied(255, 255, 255, 20),
                                     hover_border_color: egui::Color32::from_rgb(126, 120, 229),
+                                    max_text_width: Some(CHILD_NAME_MAX_WIDTH),
                                 };
                                 
                                 let (child_button_response, should_show_dropdown) = self.interaction.child_dropdown.render_button(ui, &button_config);
@@ -91,6 +114,10 @@ impl AllowanceTrackerApp {
                                 if should_show_dropdown {
                                     self.render_child_dropdown_with_generalized_component(ui, child_button_response.rect);
                                 }
+                                
+                                // Quick-add box to the left of the child selector
+                                ui.add_space(15.0);
+                                self.render_quick_entry_box(ui);
                             } else {
                                 // No child selected - render select child button using generalized dropdown
                                 let button_config = DropdownButtonConfig {
@@ -99,6 +126,7 @@ impl AllowanceTrackerApp {
                                     text_color: egui::Color32::GRAY,
                                     hover_bg_color: egui::Color32::from_rgba_unmultiplied(255, 255, 255, 20),
                                     hover_border_color: egui::Color32::from_rgb(232, 150, 199),
+                                    max_text_width: None,
                                 };
                                 
                                 let (select_button_response, should_show_dropdown) = self.interaction.child_dropdown.render_button(ui, &button_config);
@@ -113,15 +141,61 @@ impl AllowanceTrackerApp {
                 }
             );
         });
+        TutorialStep::Header.record_target(ui.ctx(), header_response.response.rect);
+    }
+    
+    /// Render the one-line quick-add box ("spent 4.50 ice cream yesterday")
+    pub fn render_quick_entry_box(&mut self, ui: &mut egui::Ui) {
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut self.form.quick_entry_input)
+                .hint_text("⚡ spent 4.50 ice cream yesterday")
+                .desired_width(260.0)
+                .font(egui::FontId::new(14.0, egui::FontFamily::Proportional)),
+        );
+        if std::mem::take(&mut self.form.quick_entry_focus_requested) {
+            response.request_focus();
+        }
+        let response = response.on_hover_text(format!(
+            "Quick add ({})",
+            ui.ctx().format_shortcut(&QUICK_ENTRY_SHORTCUT)
+        ));
+        
+        if response.changed() {
+            self.form.quick_entry_error = None;
+        }
+        if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) && !self.form.quick_entry_input.trim().is_empty() {
+            self.submit_quick_entry();
+        }
+        
+        if let Some(error) = &self.form.quick_entry_error {
+            response.on_hover_text(error.clone());
+            ui.colored_label(egui::Color32::from_rgb(220, 50, 50), "⚠");
+        }
+        
+        // Saved templates, added today with one click
+        let templates = self.current_templates();
+        if !templates.is_empty() {
+            let mut picked = None;
+            ui.menu_button("⭐", |ui| {
+                for template in &templates {
+                    let label = format!("{}  {}", template.name, self.format_currency_amount(template.signed_amount()));
+                    if ui.button(label).on_hover_text(template.description.as_str()).clicked() {
+                        picked = Some(template.clone());
+                        ui.close_menu();
+                    }
+                }
+            }).response.on_hover_text("Add a saved template");
+            if let Some(template) = picked {
+                self.record_from_template(&template, None);
+            }
+        }
     }
     
     /// Render child selector dropdown using generalized component
     pub fn render_child_dropdown_with_generalized_component(&mut self, ui: &mut egui::Ui, button_rect: egui::Rect) {
-        // Load children from backend and build menu items
-        let children_list = match self.backend().child_service.list_children() {
-            Ok(children_result) => children_result.children,
-            Err(_) => vec![],
-        };
+        // Children are read on the loader thread; use what has arrived so far
+        self.ensure_children_loaded();
+        let children_list = self.core.children.clone().unwrap_or_default();
         
         // 🔍 SURGICAL DEBUG: Available children and current selection
         let current_child_from_backend = self.get_current_child_from_backend();
@@ -132,7 +206,7 @@ impl AllowanceTrackerApp {
 
         let menu_items: Vec<DropdownMenuItem> = if children_list.is_empty() {
             vec![DropdownMenuItem {
-                label: "No children available".to_string(),
+                label: if self.core.children.is_none() { "Loading children..." } else { "No children available" }.to_string(),
                 icon: None,
                 is_current: false,
                 is_enabled: false,
@@ -236,6 +310,7 @@ impl AllowanceTrackerApp {
             text_color: egui::Color32::from_rgb(80, 80, 80),
             hover_bg_color: egui::Color32::from_rgba_unmultiplied(255, 255, 255, 20),
             hover_border_color: egui::Color32::from_rgb(126, 120, 229),
+            max_text_width: None,
         };
         
         let (settings_button_response, should_show_dropdown) = self.interaction.settings_dropdown.render_button(ui, &button_config);
@@ -248,45 +323,18 @@ impl AllowanceTrackerApp {
     
     /// Render settings dropdown menu items
     pub fn render_settings_dropdown_menu(&mut self, ui: &mut egui::Ui, button_rect: egui::Rect) {
-        // Define settings menu items based on the screenshot
-        let menu_items = vec![
-            DropdownMenuItem {
-                label: "Profile".to_string(),
-                icon: Some("👤".to_string()),
-                is_current: false,
-                is_enabled: true,
-            },
-            DropdownMenuItem {
-                label: "Create child".to_string(),
-                icon: Some("👶".to_string()),
-                is_current: false,
-                is_enabled: true,
-            },
-            DropdownMenuItem {
-                label: "Configure allowance".to_string(),
-                icon: Some("⚙️".to_string()),
-                is_current: false,
-                is_enabled: true,
-            },
-            DropdownMenuItem {
-                label: "Delete transactions".to_string(),
-                icon: Some("🗑️".to_string()),
-                is_current: false,
-                is_enabled: true,
-            },
-            DropdownMenuItem {
-                label: "Export data".to_string(),
-                icon: Some("📤".to_string()),
-                is_current: false,
-                is_enabled: true,
-            },
-            DropdownMenuItem {
-                label: "Data directory".to_string(),
-                icon: Some("📁".to_string()),
-                is_current: false,
-                is_enabled: true,
-            },
-        ];
+        let menu_items: Vec<DropdownMenuItem> = SettingsAction::ALL
+            .iter()
+            .map(|action| {
+                let (label, icon) = action.menu_entry();
+                DropdownMenuItem {
+                    label: label.to_string(),
+                    icon: Some(icon.to_string()),
+                    is_current: false,
+                    is_enabled: true,
+                }
+            })
+            .collect();
         
         let menu_config = DropdownMenuConfig {
             min_width: 180.0, // Wider for settings menu
@@ -310,17 +358,9 @@ impl AllowanceTrackerApp {
         // Handle settings menu item selection
         if let Some(index) = selected_index {
             // Map index to settings action
-            let settings_action = match index {
-                0 => crate::ui::state::modal_state::SettingsAction::ShowProfile,
-                1 => crate::ui::state::modal_state::SettingsAction::CreateChild,
-                2 => crate::ui::state::modal_state::SettingsAction::ConfigureAllowance,
-                3 => crate::ui::state::modal_state::SettingsAction::DeleteTransactions,
-                4 => crate::ui::state::modal_state::SettingsAction::ExportData,
-                5 => crate::ui::state::modal_state::SettingsAction::DataDirectory,
-                _ => {
-                    log::warn!("🚨 Unknown settings menu item clicked: {}", index);
-                    return;
-                }
+            let Some(&settings_action) = SettingsAction::ALL.get(index) else {
+                log::warn!("🚨 Unknown settings menu item clicked: {}", index);
+                return;
             };
             
             info!("🔒 Settings menu item selected: {:?} - triggering parental control", settings_action);
@@ -333,11 +373,23 @@ impl AllowanceTrackerApp {
         }
     }
 
-    /// Render error messages
+    /// Render error and success messages
     pub fn render_messages(&self, ui: &mut egui::Ui) {
         if let Some(error) = &self.ui.error_message {
             ui.colored_label(egui::Color32::RED, format!("❌ {}", error));
         }
+        if let Some(success) = &self.ui.success_message {
+            egui::Frame::new()
+                .fill(egui::Color32::from_rgb(230, 245, 233))
+                .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(34, 139, 34)))
+                .corner_radius(egui::CornerRadius::same(8))
+                .inner_margin(egui::Margin::symmetric(12, 6))
+                .show(ui, |ui| {
+                    ui.label(egui::RichText::new(success)
+                        .font(egui::FontId::new(14.0, egui::FontFamily::Proportional))
+                        .color(egui::Color32::from_rgb(20, 100, 20)));
+                });
+        }
     }
     
     /// Render transaction selection controls bar (appears when in selection mode)
@@ -375,6 +427,16 @@ impl AllowanceTrackerApp {
                         .color(egui::Color32::from_rgb(100, 100, 100))
                 ).selectable(false));
                 
+                // The table can pick every loaded row at once
+                if self.current_tab() == crate::ui::app_state::MainTab::Table {
+                    ui.add_space(10.0);
+                    if ui.small_button("Select all shown").clicked() {
+                        let ids: Vec<String> = self.table.displayed_transactions.iter().map(|t| t.id.clone()).collect();
+                        info!("✅ Selecting all {} shown table transactions", ids.len());
+                        self.interaction.selected_transaction_ids.extend(ids);
+                    }
+                }
+                
                 // Push right-side controls to the right
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                     // Exit selection mode button
@@ -421,6 +483,24 @@ impl AllowanceTrackerApp {
                         info!("🗑️ Delete selected transactions button clicked");
                         self.delete_selected_transactions();
                     }
+                    
+                    ui.add_space(10.0);
+                    
+                    // Reversing keeps the original and records an offsetting entry, one row at a time
+                    let reverse_enabled = count == 1;
+                    let reverse_button = ui.add_enabled(
+                        reverse_enabled,
+                        egui::Button::new(
+                            egui::RichText::new("↩ Reverse")
+                                .font(egui::FontId::new(13.0, egui::FontFamily::Proportional))
+                        )
+                        .corner_radius(egui::CornerRadius::same(4))
+                    ).on_hover_text("Undo a refunded purchase or returned gift with a linked entry");
+                    
+                    if reverse_button.clicked() && reverse_enabled {
+                        info!("↩️ Reverse selected transaction button clicked");
+                        self.reverse_selected_transaction();
+                    }
                 });
             });
         });
@@ -436,14 +516,17 @@ impl AllowanceTrackerApp {
         }
         let transaction_ids: Vec<String> = self.interaction.selected_transaction_ids.iter().cloned().collect();
         info!("🗑️ Attempting to delete {} transactions: {:?}", transaction_ids.len(), transaction_ids);
-        let command = crate::backend::domain::commands::transactions::DeleteTransactionsCommand {
+        let request = shared::DeleteTransactionsRequest {
+            child_id: None,
             transaction_ids: transaction_ids.clone(),
+            actor: self.parent_actor().map(|actor| actor.to_storage()),
         };
-        match self.backend().transaction_service.as_ref().delete_transactions_domain(command) {
+        match self.api().delete_transactions(request) {
             Ok(result) => {
                 info!("✅ Successfully deleted {} transactions", result.deleted_count);
                 self.exit_transaction_selection_mode();
                 self.load_calendar_data();
+                self.table.invalidate();
                 self.load_balance();
             }
             Err(e) => {
@@ -452,4 +535,34 @@ impl AllowanceTrackerApp {
             }
         }
     }
-} 
\ No newline at end of file
+    
+    /// Undo the one selected transaction with a linked offsetting entry
+    fn reverse_selected_transaction(&mut self) {
+        let Some(transaction_id) = self.interaction.selected_transaction_ids.iter().next().cloned() else {
+            log::warn!("⚠️ No transaction selected for reversal");
+            return;
+        };
+        let command = crate::backend::domain::commands::transactions::ReverseTransactionCommand {
+            child_id: None,
+            transaction_id,
+            reason: None,
+            date: None,
+            parent_override: false,
+            actor: self.parent_actor(),
+        };
+        match self.backend().transaction_service.reverse_transaction(command) {
+            Ok(result) => {
+                info!("✅ Reversed {} with {}", result.original.id, result.reversal.id);
+                self.ui.set_success(format!("Reversed \"{}\"", result.original.description));
+                self.exit_transaction_selection_mode();
+                self.load_calendar_data();
+                self.table.invalidate();
+                self.load_balance();
+            }
+            Err(e) => {
+                log::error!("❌ Failed to reverse transaction: {}", e);
+                self.ui.error_message = Some(format!("Failed to reverse transaction: {}", e));
+            }
+        }
+    }
+}
@@ -14,19 +14,34 @@
 //! - `tab_manager` - Tab navigation and content routing
 //! - `table_renderer` - Table view rendering with responsive design
 //! - `calendar_renderer` - Calendar view rendering with transaction display
+//! - `calendar_search` - "Go to date" picker and transaction search that jump the calendar to a day
 //! - `goal_progress_graph` - Goal-specific balance progression graph component
 //! - `circular_days_progress` - Donut-style circular progress tracker for goal timeline
+//! - `goal_strip` - One-row goal progress shown above the calendar
+//! - `family_renderer` - Family tab with every child's balance, goal and next allowance
+//! - `year_heatmap` - Whole-year heatmap of daily net amounts with monthly totals
+//! - `confetti` - Frame-driven particle effect for celebrations
+//! - `celebration_overlay` - Celebration banner and confetti shown over the app
+//! - `tutorial_overlay` - Guided tour that dims the app around each step's target
+//! - `transaction_move` - Moving a transaction chip dragged to another day, with an undo toast
+//! - `context_menus` - Right-click menus on calendar days and table rows, and the edit window
 //!
 //! ## Architecture:
 //! The components are organized to promote reusability and maintainability.
 //! Each module has a clear responsibility and minimal dependencies on others.
 
 pub mod calendar_renderer;
+pub mod calendar_search;
+pub mod celebration_overlay;
 pub mod chart_renderer;
 pub mod circular_days_progress;
+pub mod confetti;
+pub mod context_menus;
 pub mod data_loading;
 pub mod dropdown_menu;
+pub mod family_renderer;
 pub mod goal_renderer;
+pub mod goal_strip;
 pub mod goal_progress_bar;
 pub mod goal_progress_graph;
 pub mod header;
@@ -34,9 +49,12 @@ pub mod modals;
 pub mod settings;
 pub mod styling;
 pub mod tab_manager;
+pub mod transaction_move;
+pub mod tutorial_overlay;
 pub mod table_renderer;
 pub mod transaction_table;
 pub mod ui_components;
+pub mod year_heatmap;
 
 // Re-export the unified styling system
 pub use styling::*;
@@ -14,8 +14,9 @@
 
 use eframe::egui;
 use crate::ui::app_state::AllowanceTrackerApp;
-use crate::ui::mappers::to_dto;
-use crate::backend::domain::commands::child::SetActiveChildCommand;
+use crate::ui::components::styling::Theme;
+use frontend_api::AllowanceApi;
+use shared::SetActiveChildRequest;
 
 impl AllowanceTrackerApp {
     /// Render the child selector modal
@@ -25,6 +26,11 @@ impl AllowanceTrackerApp {
         }
 
         log::info!("🚀 RENDERING CHILD SELECTOR MODAL");
+        
+        // The list is read on the loader thread; draw whatever has arrived so far
+        self.ensure_children_loaded();
+        let children = self.core.children.clone();
+        let children_error = self.core.children_error.clone();
 
         egui::Window::new("Select Child")
             .collapsible(false)
@@ -35,13 +41,13 @@ impl AllowanceTrackerApp {
                     .strong());
                 
                 // List all children
-                match self.backend().child_service.list_children() {
-                    Ok(children_result) => {
-                        if children_result.children.is_empty() {
+                match (children, children_error) {
+                    (Some(children), _) => {
+                        if children.is_empty() {
                             ui.label("No children found!");
                             ui.label("Debug: Check if test_data directory exists");
                         } else {
-                            for child in children_result.children {
+                            for child in children {
                                 ui.horizontal(|ui| {
                                     // Show if this is the current active child
                                     let is_active = self.get_current_child_from_backend().as_ref()
@@ -93,7 +99,7 @@ impl AllowanceTrackerApp {
                                     let text_color = if is_active { 
                                         egui::Color32::from_rgb(0, 120, 215) // Active child in blue
                                     } else { 
-                                        egui::Color32::from_rgb(60, 60, 60) // Default dark gray
+                                        Theme::current(ui.ctx()).typography.primary // Default dark gray
                                     };
                                     
                                     ui.painter().text(
@@ -113,12 +119,12 @@ impl AllowanceTrackerApp {
                                     
                                     if child_button_response.clicked() {
                                         // Set this child as active
-                                        let command = SetActiveChildCommand {
+                                        let request = SetActiveChildRequest {
                                             child_id: child.id.clone(),
                                         };
-                                        match self.backend().child_service.set_active_child(command) {
-                                            Ok(_) => {
-                                                self.core.current_child = Some(to_dto(child.clone()));
+                                        match self.api().set_active_child(request) {
+                                            Ok(response) => {
+                                                self.core.current_child = Some(response.active_child);
                                                 self.refresh_all_data_for_current_child();
                                                 self.modal.show_child_selector = false;
                                                 // Child selection feedback removed
@@ -134,10 +140,16 @@ impl AllowanceTrackerApp {
                             }
                         }
                     }
-                    Err(e) => {
+                    (None, Some(e)) => {
                         ui.label(format!("Error loading children: {}", e));
                         ui.label("Debug: Check backend initialization");
                     }
+                    (None, None) => {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label("Loading children...");
+                        });
+                    }
                 }
                 
                 ui.separator();
@@ -149,8 +161,10 @@ impl AllowanceTrackerApp {
                     
                     if ui.button(egui::RichText::new("🔄 Refresh")
                         .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))).clicked() {
-                        // Try to reload the active child
+                        // Try to reload the active child and the list
                         self.load_initial_data();
+                        self.core.children_error = None;
+                        self.request_children();
                     }
                 });
             });
@@ -14,6 +14,7 @@
 
 use eframe::egui;
 use crate::ui::app_state::AllowanceTrackerApp;
+use crate::ui::components::styling::Theme;
 
 impl AllowanceTrackerApp {
     /// Render day action overlay based on active overlay type
@@ -116,7 +117,7 @@ impl AllowanceTrackerApp {
                     ui.centered_and_justified(|ui| {
                         // Modal card with proper styling
                         egui::Frame::window(&ui.style())
-                            .fill(egui::Color32::WHITE)
+                            .fill(Theme::current(ui.ctx()).layout.modal_background)
                             .stroke(egui::Stroke::new(3.0, overlay_color))
                                                             .corner_radius(egui::CornerRadius::same(16))
                             .inner_margin(egui::Margin::same(25))
@@ -162,7 +163,7 @@ impl AllowanceTrackerApp {
                                             ui.horizontal(|ui| {
                                                 ui.label(egui::RichText::new("Description:")
                                                     .font(egui::FontId::new(14.0, egui::FontFamily::Proportional))
-                                                    .color(egui::Color32::from_rgb(60, 60, 60)));
+                                                    .color(Theme::current(ui.ctx()).typography.primary));
                                                 
                                                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                                                     let char_count = self.form.add_money_description.len();
@@ -203,7 +204,7 @@ impl AllowanceTrackerApp {
                                             ui.horizontal(|ui| {
                                                 ui.label(egui::RichText::new("Amount:")
                                                     .font(egui::FontId::new(14.0, egui::FontFamily::Proportional))
-                                                    .color(egui::Color32::from_rgb(60, 60, 60)));
+                                                    .color(Theme::current(ui.ctx()).typography.primary));
                                             });
                                             ui.add_space(5.0);
                                             
@@ -212,7 +213,7 @@ impl AllowanceTrackerApp {
                                                 // Static dollar sign
                                                 ui.label(egui::RichText::new("$")
                                                     .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
-                                                    .color(egui::Color32::from_rgb(60, 60, 60)));
+                                                    .color(Theme::current(ui.ctx()).typography.primary));
                                                 
                                                 ui.add_space(2.0);
                                                 
@@ -287,7 +288,7 @@ impl AllowanceTrackerApp {
                                                 let cancel_button = egui::Button::new(egui::RichText::new("Cancel")
                                                     .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
                                                     .color(egui::Color32::from_rgb(100, 100, 100)))
-                                                    .fill(egui::Color32::from_rgb(245, 245, 245))
+                                                    .fill(Theme::current(ui.ctx()).layout.subtle_background)
                                                     .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 200, 200)))
                                                     .corner_radius(egui::CornerRadius::same(10))
                                                     .min_size(egui::vec2(90.0, 40.0));
@@ -397,7 +398,7 @@ impl AllowanceTrackerApp {
                                         let cancel_button = egui::Button::new(egui::RichText::new("Cancel")
                                             .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
                                             .color(egui::Color32::from_rgb(100, 100, 100)))
-                                            .fill(egui::Color32::from_rgb(245, 245, 245))
+                                            .fill(Theme::current(ui.ctx()).layout.subtle_background)
                                             .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 200, 200)))
                                             .corner_radius(egui::CornerRadius::same(10))
                                             .min_size(egui::vec2(90.0, 40.0));
@@ -14,7 +14,7 @@
 
 use eframe::egui;
 use crate::ui::app_state::AllowanceTrackerApp;
-use crate::ui::components::styling::colors;
+use crate::ui::components::styling::{colors, Theme};
 
 impl AllowanceTrackerApp {
     /// Render the goal creation modal with simple opacity effects
@@ -45,7 +45,11 @@ impl AllowanceTrackerApp {
                         ui.set_opacity(0.95); // Slight transparency for frosted effect
                         
                         egui::Frame::window(&ui.style())
-                            .fill(egui::Color32::from_rgba_unmultiplied(255, 255, 255, 230)) // Slightly transparent white
+                            .fill(if ui.visuals().dark_mode {
+                                egui::Color32::from_rgba_unmultiplied(44, 46, 58, 235)
+                            } else {
+                                egui::Color32::from_rgba_unmultiplied(255, 255, 255, 230) // Slightly transparent white
+                            })
                             .stroke(egui::Stroke::new(2.0, egui::Color32::from_rgba_unmultiplied(100, 150, 255, 180)))
                             .corner_radius(egui::CornerRadius::same(15))
                             .inner_margin(egui::Margin::same(20))
@@ -140,7 +144,7 @@ impl AllowanceTrackerApp {
                         // Cancel button with subtle opacity
                         ui.set_opacity(0.85); // Subtle transparency to de-emphasize cancel
                         let cancel_button = egui::Button::new("Cancel")
-                            .fill(egui::Color32::from_rgb(240, 240, 240))
+                            .fill(Theme::current(ui.ctx()).layout.subtle_background)
                             .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 200, 200)))
                             .corner_radius(egui::CornerRadius::same(6))
                             .min_size(egui::vec2(80.0, 35.0));
@@ -0,0 +1,121 @@
+//! # Missing Data Folder Modal
+//!
+//! Startup prompt shown when a child's data was moved to a folder that isn't
+//! there now, usually a USB or network drive that isn't connected.
+//!
+//! ## Responsibilities:
+//! - List the folders that weren't found
+//! - Look again once the drive is plugged in
+//! - Locate a folder that came back somewhere else
+//! - Carry on read-only with what is in the default location
+//!
+//! ## Purpose:
+//! Without the folder the app would read and write the default location
+//! instead, splitting the child's history in two. The backend opens
+//! read-only until every folder is found, and this prompt explains why.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::data_directory::{LocateDataFolderCommand, MissingDataFolder};
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the prompt for relocated data folders that weren't found
+    pub fn render_missing_data_modal(&mut self, ctx: &egui::Context) {
+        if self.modal.missing_data_folders.is_empty() {
+            return;
+        }
+
+        let mut retry_clicked = false;
+        let mut locate: Option<String> = None;
+        let mut continue_read_only = false;
+
+        egui::Window::new("📁 Data Folder Not Found")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.label(egui::RichText::new("Some data isn't where it was saved.")
+                    .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
+                    .strong());
+                ui.add_space(6.0);
+                ui.label("If it's on a USB or network drive, connect it and try again.");
+                ui.add_space(10.0);
+
+                egui::Grid::new("missing_data_folders_grid").spacing([12.0, 6.0]).show(ui, |ui| {
+                    for folder in &self.modal.missing_data_folders {
+                        ui.label(egui::RichText::new(&folder.child_directory).strong());
+                        ui.label(egui::RichText::new(&folder.expected_path).small());
+                        if ui.button("Locate…").clicked() {
+                            locate = Some(folder.child_directory.clone());
+                        }
+                        ui.end_row();
+                    }
+                });
+
+                if let Some(error) = &self.modal.missing_data_error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Try again").clicked() {
+                        retry_clicked = true;
+                    }
+                    if ui.button("Continue read-only")
+                        .on_hover_text("Browse what is in the usual folder; nothing is saved until the drive is back")
+                        .clicked()
+                    {
+                        continue_read_only = true;
+                    }
+                });
+            });
+
+        if retry_clicked {
+            let result = self.backend().data_directory_service.retry_missing_data_folders();
+            self.apply_missing_data_result(result);
+        }
+        if let Some(child_directory) = locate {
+            if let Some(path) = rfd::FileDialog::new().set_title("Locate Data Folder").pick_folder() {
+                let command = LocateDataFolderCommand {
+                    child_directory,
+                    path: path.to_string_lossy().to_string(),
+                };
+                let result = self.backend().data_directory_service.locate_data_folder(command);
+                self.apply_missing_data_result(result);
+            }
+        }
+        if continue_read_only {
+            info!("📁 Continuing read-only with {} data folder(s) missing", self.modal.missing_data_folders.len());
+            self.modal.missing_data_folders.clear();
+            self.modal.missing_data_error = None;
+            self.ui.set_success("Read-only until the data folder is back. Restart the app to try again.".to_string());
+        }
+    }
+
+    /// Show what is still missing, or reload everything once all folders are found
+    fn apply_missing_data_result(&mut self, result: anyhow::Result<Vec<MissingDataFolder>>) {
+        match result {
+            Ok(missing) if missing.is_empty() => {
+                info!("📁 All data folders found");
+                self.modal.missing_data_folders.clear();
+                self.modal.missing_data_error = None;
+
+                // Allowances couldn't be issued at startup while the data was read-only
+                if let Err(e) = self.backend().transaction_service.check_and_issue_pending_allowances() {
+                    warn!("🎯 Failed to check pending allowances after finding data folders: {}", e);
+                }
+                self.refresh_all_data_for_current_child();
+            }
+            Ok(missing) => {
+                self.modal.missing_data_error = Some(format!("{} folder(s) still not found", missing.len()));
+                self.modal.missing_data_folders = missing;
+            }
+            Err(e) => {
+                warn!("📁 Failed to check data folders: {}", e);
+                self.modal.missing_data_error = Some(format!("Could not use that folder: {}", e));
+            }
+        }
+    }
+}
@@ -9,6 +9,8 @@
 //! - `money_transaction` - Add/spend money modals  
 //! - `day_action_overlay` - Calendar day action overlays
 //! - `goal_creation` - Goal creation modal
+//! - `unlock_data` - Startup prompt to unlock encrypted child data
+//! - `missing_data` - Startup prompt when a relocated data folder isn't found
 //! - `profile` - Child profile editing modal
 //! - `shared` - Common modal functionality and styling
 //!
@@ -21,6 +23,8 @@ pub mod parental_control;
 pub mod money_transaction;
 pub mod day_action_overlay;
 pub mod goal_creation;
+pub mod unlock_data;
+pub mod missing_data;
 pub mod shared;
 
 // Re-export modal functions for easy access
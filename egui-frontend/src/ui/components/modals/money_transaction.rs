@@ -4,8 +4,12 @@
 //!
 //! ## Responsibilities:
 //! - Generic modal for income and expense transactions
-//! - Form validation and user input handling
-//! - Visual feedback and error display
+//! - Form validation and user input handling (rules come from `MoneyManagementService`)
+//! - Visual feedback, error display and description suggestions
+//! - One-tap buttons for the child's saved templates, recorded on the form's date
+//! - Date picker for backdating a transaction
+//! - A parent override when a spend is refused by the no-negative-balance rule
+//! - Icon picker for tagging a transaction with an emoji
 //! - Configuration-based modal rendering
 //!
 //! ## Purpose:
@@ -13,7 +17,20 @@
 //! and spending money (expense) transactions with consistent validation and UX.
 
 use eframe::egui;
-use crate::ui::app_state::AllowanceTrackerApp;
+use frontend_api::AllowanceApi;
+use log::warn;
+use shared::{DescriptionSuggestion, DescriptionSuggestionsRequest};
+use crate::backend::domain::models::transaction_icon::PRESET_ICONS;
+use crate::backend::domain::models::transaction_template::TransactionTemplate;
+use crate::ui::app_state::{AllowanceTrackerApp, TransactionType};
+use crate::ui::components::styling::Theme;
+use crate::ui::state::modal_state::ProtectedAction;
+
+/// Size of the modal card, also used to detect backdrop clicks
+const MODAL_SIZE: egui::Vec2 = egui::vec2(450.0, 560.0);
+
+/// How many description suggestions to offer as chips
+const SUGGESTION_CHIP_COUNT: usize = 5;
 
 impl AllowanceTrackerApp {
     /// Generic money transaction modal renderer (income/expense)
@@ -31,6 +48,22 @@ impl AllowanceTrackerApp {
         }
 
         let mut form_submitted = false;
+        let mut override_clicked = false;
+        let today = chrono::Local::now().date_naive();
+        let currency_symbol = self.core.current_currency.symbol();
+        // The date starts on the calendar day that was clicked, or today
+        if form_state.date.is_none() {
+            form_state.date = Some(self.calendar.selected_day.unwrap_or(today));
+        }
+        let mut date_picker_id: Option<egui::Id> = None;
+        // Templates going the same way as this form: money in or money out
+        let wants_expense = matches!(config.transaction_type, TransactionType::Expense);
+        let templates: Vec<TransactionTemplate> = self
+            .current_templates()
+            .into_iter()
+            .filter(|template| template.is_expense() == wants_expense)
+            .collect();
+        let mut picked_template: Option<TransactionTemplate> = None;
 
         // Use Area with Foreground order to ensure it appears above everything
         egui::Area::new(egui::Id::new("money_transaction_modal_overlay"))
@@ -49,14 +82,14 @@ impl AllowanceTrackerApp {
                 ui.allocate_new_ui(egui::UiBuilder::new().max_rect(screen_rect), |ui| {
                     ui.centered_and_justified(|ui| {
                         egui::Frame::window(&ui.style())
-                            .fill(egui::Color32::WHITE)
+                            .fill(Theme::current(ui.ctx()).layout.modal_background)
                             .stroke(egui::Stroke::new(3.0, config.color))
                                                             .corner_radius(egui::CornerRadius::same(15))
                             .inner_margin(egui::Margin::same(20))
                             .show(ui, |ui| {
                                 // Set modal size
-                                ui.set_min_size(egui::vec2(450.0, 350.0));
-                                ui.set_max_size(egui::vec2(450.0, 350.0));
+                                ui.set_min_size(MODAL_SIZE);
+                                ui.set_max_size(MODAL_SIZE);
                                 
                                 ui.vertical_centered(|ui| {
                                     ui.add_space(15.0);
@@ -74,13 +107,63 @@ impl AllowanceTrackerApp {
                                         .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
                                         .color(egui::Color32::from_rgb(80, 80, 80)));
                                     
-                                    ui.add_space(20.0);
+                                    ui.add_space(15.0);
+                                    
+                                    // Date picker for backdating
+                                    ui.horizontal(|ui| {
+                                        ui.label(egui::RichText::new("Date:")
+                                            .font(egui::FontId::new(14.0, egui::FontFamily::Proportional))
+                                            .color(Theme::current(ui.ctx()).typography.primary));
+                                        
+                                        let mut date = form_state.date.unwrap_or(today);
+                                        let date_response = ui.add(
+                                            egui_extras::DatePickerButton::new(&mut date)
+                                                .id_salt("money_transaction_date")
+                                                .calendar_week(false)
+                                        );
+                                        date_picker_id = Some(ui.make_persistent_id("money_transaction_date"));
+                                        if date_response.changed() {
+                                            form_state.date = Some(date);
+                                            self.validate_money_transaction_form(form_state, config);
+                                        }
+                                        
+                                        if date < today {
+                                            ui.label(egui::RichText::new("⏪ Backdated")
+                                                .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
+                                                .color(egui::Color32::from_rgb(120, 120, 120)));
+                                        }
+                                    });
+                                    
+                                    // Show date error message
+                                    if let Some(error) = &form_state.date_error {
+                                        ui.add_space(3.0);
+                                        ui.label(egui::RichText::new(error)
+                                            .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
+                                            .color(egui::Color32::from_rgb(220, 50, 50)));
+                                    }
+                                    
+                                    // Saved templates add their whole transaction on this date at once
+                                    if !templates.is_empty() {
+                                        ui.add_space(8.0);
+                                        ui.horizontal_wrapped(|ui| {
+                                            ui.label(egui::RichText::new("⭐")
+                                                .font(egui::FontId::new(14.0, egui::FontFamily::Proportional)));
+                                            for template in &templates {
+                                                let hover = format!("{} — {}", template.description, self.format_currency_amount(template.signed_amount()));
+                                                if ui.small_button(&template.name).on_hover_text(hover).clicked() {
+                                                    picked_template = Some(template.clone());
+                                                }
+                                            }
+                                        });
+                                    }
+                                    
+                                    ui.add_space(15.0);
                                     
                                     // Description field with validation
                                     ui.horizontal(|ui| {
                                         ui.label(egui::RichText::new("Description:")
                                             .font(egui::FontId::new(14.0, egui::FontFamily::Proportional))
-                                            .color(egui::Color32::from_rgb(60, 60, 60)));
+                                            .color(Theme::current(ui.ctx()).typography.primary));
                                         
                                         // Character count
                                         let char_count = form_state.description.len();
@@ -116,22 +199,77 @@ impl AllowanceTrackerApp {
                                             .color(egui::Color32::from_rgb(220, 50, 50)));
                                     }
                                     
+                                    // Quick picks and past descriptions as chips, narrowed down as the description is typed
+                                    let suggestions = self.description_suggestions_for(form_state, config);
+                                    if !suggestions.is_empty() {
+                                        ui.add_space(5.0);
+                                        ui.horizontal_wrapped(|ui| {
+                                            for suggestion in suggestions {
+                                                let label = match suggestion.amount {
+                                                    Some(amount) => format!("{} — {}", suggestion.description, self.format_currency_amount(amount)),
+                                                    None => suggestion.description.clone(),
+                                                };
+                                                if ui.small_button(label).clicked() {
+                                                    form_state.description = suggestion.description;
+                                                    if let (Some(amount), true) = (suggestion.amount, form_state.amount.trim().is_empty()) {
+                                                        form_state.amount = format!("{:.2}", amount);
+                                                    }
+                                                    self.validate_money_transaction_form(form_state, config);
+                                                }
+                                            }
+                                        });
+                                    }
+                                    
+                                    ui.add_space(10.0);
+                                    
+                                    // Optional icon; clicking the picked one again clears it
+                                    ui.horizontal_wrapped(|ui| {
+                                        ui.label(egui::RichText::new("Icon:")
+                                            .font(egui::FontId::new(14.0, egui::FontFamily::Proportional))
+                                            .color(Theme::current(ui.ctx()).typography.primary));
+                                        for (key, emoji) in PRESET_ICONS {
+                                            let selected = form_state.icon.as_deref() == Some(key);
+                                            let chip = egui::RichText::new(emoji).font(egui::FontId::new(18.0, egui::FontFamily::Proportional));
+                                            if ui.selectable_label(selected, chip).clicked() {
+                                                form_state.icon = if selected { None } else { Some(key.to_string()) };
+                                            }
+                                        }
+                                    });
+
+                                    // Optional kind, e.g. a bonus or a penalty; clicking the picked one again clears it
+                                    let kinds: &[shared::TransactionType] = match config.transaction_type {
+                                        TransactionType::Income => &shared::TransactionType::INCOME_KINDS,
+                                        TransactionType::Expense => &shared::TransactionType::EXPENSE_KINDS,
+                                    };
+                                    ui.horizontal_wrapped(|ui| {
+                                        ui.label(egui::RichText::new("Kind:")
+                                            .font(egui::FontId::new(14.0, egui::FontFamily::Proportional))
+                                            .color(Theme::current(ui.ctx()).typography.primary));
+                                        for kind in kinds {
+                                            let selected = form_state.transaction_type.as_ref() == Some(kind);
+                                            let chip = format!("{} {}", kind.emoji().unwrap_or_default(), kind.label());
+                                            if ui.selectable_label(selected, chip).clicked() {
+                                                form_state.transaction_type = if selected { None } else { Some(kind.clone()) };
+                                            }
+                                        }
+                                    });
+                                    
                                     ui.add_space(15.0);
                                     
                                     // Amount field with validation
                                     ui.horizontal(|ui| {
                                         ui.label(egui::RichText::new("Amount:")
                                             .font(egui::FontId::new(14.0, egui::FontFamily::Proportional))
-                                            .color(egui::Color32::from_rgb(60, 60, 60)));
+                                            .color(Theme::current(ui.ctx()).typography.primary));
                                     });
                                     ui.add_space(5.0);
                                     
-                                    // Amount input with static dollar sign
+                                    // Amount input with the child's currency symbol
                                     ui.horizontal(|ui| {
-                                        // Static dollar sign
-                                        ui.label(egui::RichText::new("$")
+                                        // Static currency symbol
+                                        ui.label(egui::RichText::new(currency_symbol)
                                             .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
-                                            .color(egui::Color32::from_rgb(60, 60, 60)));
+                                            .color(Theme::current(ui.ctx()).typography.primary));
                                         
                                         ui.add_space(2.0);
                                         
@@ -149,15 +287,34 @@ impl AllowanceTrackerApp {
                                         }
                                     });
                                     
-                                    // Show amount error message
+                                    // Show amount error message, with the validation hint for fixing it
                                     if let Some(error) = &form_state.amount_error {
                                         ui.add_space(3.0);
                                         ui.label(egui::RichText::new(error)
                                             .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
                                             .color(egui::Color32::from_rgb(220, 50, 50)));
+                                        if let Some(hint) = form_state.suggestions.last() {
+                                            ui.label(egui::RichText::new(format!("💡 {}", hint))
+                                                .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
+                                                .color(egui::Color32::from_rgb(120, 120, 120)));
+                                        }
+                                    }
+                                    
+                                    // A refused spend can still go through when a parent allows it
+                                    if let Some(error) = &form_state.overdraft_error {
+                                        ui.add_space(3.0);
+                                        ui.label(egui::RichText::new(error)
+                                            .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
+                                            .color(egui::Color32::from_rgb(220, 50, 50)));
+                                        if ui.button("🔓 Parent override")
+                                            .on_hover_text("A parent can record this spend anyway")
+                                            .clicked()
+                                        {
+                                            override_clicked = true;
+                                        }
                                     }
                                     
-                                    ui.add_space(30.0);
+                                    ui.add_space(25.0);
                                     
                                     // Buttons
                                     ui.horizontal(|ui| {
@@ -230,10 +387,13 @@ impl AllowanceTrackerApp {
                         if let Some(pointer_pos) = ui.ctx().input(|i| i.pointer.latest_pos()) {
                             let modal_rect = egui::Rect::from_center_size(
                                 ui.ctx().screen_rect().center(),
-                                egui::vec2(450.0, 350.0)
+                                MODAL_SIZE
                             );
+                            // The date picker's calendar pops up in its own layer and may hang below the card
+                            let over_date_picker = ui.ctx().layer_id_at(pointer_pos)
+                                .is_some_and(|layer| Some(layer.id) == date_picker_id);
                             
-                            if !modal_rect.contains(pointer_pos) {
+                            if !modal_rect.contains(pointer_pos) && !over_date_picker {
                                 // Click was outside modal - close it
                                 form_state.clear();
                                 self.calendar.active_overlay = None;
@@ -252,7 +412,50 @@ impl AllowanceTrackerApp {
                     }
                 }
             });
+
+        if override_clicked {
+            self.start_parental_control_challenge(ProtectedAction::OverrideOverdraft);
+        }
+
+        if let Some(template) = picked_template {
+            if self.record_from_template(&template, form_state.date) {
+                form_state.clear();
+                self.calendar.active_overlay = None;
+                self.calendar.selected_day = None;
+            }
+        }
             
         form_submitted
     }
+
+    /// Suggestions for what has been typed so far, looked up again only when it changes
+    fn description_suggestions_for(
+        &self,
+        form_state: &mut crate::ui::app_state::MoneyTransactionFormState,
+        config: &crate::ui::app_state::MoneyTransactionModalConfig,
+    ) -> Vec<DescriptionSuggestion> {
+        if let Some((typed, suggestions)) = &form_state.description_suggestions {
+            if *typed == form_state.description {
+                return suggestions.clone();
+            }
+        }
+
+        let request = DescriptionSuggestionsRequest {
+            transaction_type: match config.transaction_type {
+                TransactionType::Income => shared::TransactionType::Income,
+                TransactionType::Expense => shared::TransactionType::Expense,
+            },
+            prefix: form_state.description.clone(),
+            limit: SUGGESTION_CHIP_COUNT,
+        };
+        let suggestions = match self.api().get_description_suggestions(request) {
+            Ok(response) => response.suggestions,
+            Err(e) => {
+                warn!("⚡ Failed to load description suggestions: {}", e);
+                Vec::new()
+            }
+        };
+        form_state.description_suggestions = Some((form_state.description.clone(), suggestions.clone()));
+        suggestions
+    }
 } 
\ No newline at end of file
@@ -14,6 +14,7 @@
 
 use eframe::egui;
 use crate::ui::app_state::AllowanceTrackerApp;
+use crate::ui::components::styling::Theme;
 
 impl AllowanceTrackerApp {
     /// Render the parental control modal
@@ -41,7 +42,7 @@ impl AllowanceTrackerApp {
                 ui.allocate_new_ui(egui::UiBuilder::new().max_rect(screen_rect), |ui| {
                         ui.centered_and_justified(|ui| {
                             egui::Frame::window(&ui.style())
-                                .fill(egui::Color32::WHITE)
+                                .fill(Theme::current(ui.ctx()).layout.modal_background)
                                 .stroke(egui::Stroke::new(3.0, egui::Color32::from_rgb(220, 50, 50)))
                                 .corner_radius(egui::CornerRadius::same(15))
                                 .inner_margin(egui::Margin::same(20))
@@ -89,7 +90,7 @@ impl AllowanceTrackerApp {
             ui.label(egui::RichText::new("Settings Access")
                 .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
                 .strong()
-                .color(egui::Color32::from_rgb(60, 60, 60)));
+                .color(Theme::current(ui.ctx()).typography.primary));
             
             ui.add_space(8.0);
             
@@ -114,11 +115,11 @@ impl AllowanceTrackerApp {
                 let yes_button = egui::Button::new(
                     egui::RichText::new("Yes")
                         .font(egui::FontId::new(13.0, egui::FontFamily::Proportional))
-                        .color(egui::Color32::BLACK)
+                        .color(Theme::current(ui.ctx()).typography.body)
                 )
                 .min_size(egui::vec2(70.0, 32.0))
                 .corner_radius(egui::CornerRadius::same(6))
-                .fill(egui::Color32::WHITE)
+                .fill(Theme::current(ui.ctx()).layout.card_background)
                 .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(147, 51, 234))); // Purple outline
                 
                 if ui.add(yes_button).clicked() {
@@ -131,11 +132,11 @@ impl AllowanceTrackerApp {
                 let no_button = egui::Button::new(
                     egui::RichText::new("No")
                         .font(egui::FontId::new(13.0, egui::FontFamily::Proportional))
-                        .color(egui::Color32::BLACK)
+                        .color(Theme::current(ui.ctx()).typography.body)
                 )
                 .min_size(egui::vec2(70.0, 32.0))
                 .corner_radius(egui::CornerRadius::same(6))
-                .fill(egui::Color32::WHITE)
+                .fill(Theme::current(ui.ctx()).layout.card_background)
                 .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(147, 51, 234))); // Purple outline
                 
                 if ui.add(no_button).clicked() {
@@ -149,6 +150,14 @@ impl AllowanceTrackerApp {
     
     /// Render second question: "What's cooler than cool?"
     fn render_question2(&mut self, ui: &mut egui::Ui) {
+        // Count down a lockout from too many wrong answers, then let them try again
+        let locked_for = self.modal.parental_control_locked_until
+            .map(|until| until.saturating_duration_since(std::time::Instant::now()))
+            .filter(|remaining| !remaining.is_zero());
+        if locked_for.is_none() && self.modal.parental_control_locked_until.take().is_some() {
+            self.modal.parental_control_error = None;
+        }
+        
         ui.vertical_centered(|ui| {
             ui.add_space(5.0);
             
@@ -162,7 +171,7 @@ impl AllowanceTrackerApp {
             ui.label(egui::RichText::new("Oh yeah?? If so...")
                 .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
                 .strong()
-                .color(egui::Color32::from_rgb(60, 60, 60)));
+                .color(Theme::current(ui.ctx()).typography.primary));
             
             ui.add_space(4.0);
             
@@ -174,6 +183,7 @@ impl AllowanceTrackerApp {
             
             // Text input - more compact
             let text_input = egui::TextEdit::singleline(&mut self.modal.parental_control_input)
+                .hint_text("Answer, or your parent PIN")
                 .desired_width(300.0)
                 .font(egui::FontId::new(16.0, egui::FontFamily::Proportional));
             
@@ -189,8 +199,15 @@ impl AllowanceTrackerApp {
             
             ui.add_space(10.0);
             
-            // Show error message if any
-            if let Some(error) = &self.modal.parental_control_error {
+            // Show the lockout countdown, or an error message if any
+            if let Some(remaining) = locked_for {
+                let seconds = (remaining.as_millis() as u64).div_ceil(1000);
+                ui.label(egui::RichText::new(format!("Too many wrong answers. Try again in {}:{:02}", seconds / 60, seconds % 60))
+                    .font(egui::FontId::new(14.0, egui::FontFamily::Proportional))
+                    .color(egui::Color32::from_rgb(220, 50, 50)));
+                ui.add_space(5.0);
+                ui.ctx().request_repaint_after(std::time::Duration::from_millis(250));
+            } else if let Some(error) = &self.modal.parental_control_error {
                 ui.label(egui::RichText::new(error)
                     .font(egui::FontId::new(14.0, egui::FontFamily::Proportional))
                     .color(egui::Color32::from_rgb(220, 50, 50)));
@@ -226,12 +243,12 @@ impl AllowanceTrackerApp {
                 .corner_radius(egui::CornerRadius::same(10))
                 .min_size(egui::vec2(120.0, 40.0));
                 
-                if ui.add(submit_button).clicked() && !self.modal.parental_control_loading {
+                if ui.add_enabled(locked_for.is_none(), submit_button).clicked() && !self.modal.parental_control_loading {
                     self.submit_parental_control_answer();
                 }
                 
                 // Also submit if Enter was pressed in the text input
-                if enter_pressed && !self.modal.parental_control_loading {
+                if enter_pressed && !self.modal.parental_control_loading && locked_for.is_none() {
                     self.submit_parental_control_answer();
                 }
                 
@@ -241,11 +258,11 @@ impl AllowanceTrackerApp {
                 let cancel_button = egui::Button::new(
                     egui::RichText::new("Cancel")
                         .font(egui::FontId::new(13.0, egui::FontFamily::Proportional))
-                        .color(egui::Color32::BLACK)
+                        .color(Theme::current(ui.ctx()).typography.body)
                 )
                 .min_size(egui::vec2(70.0, 32.0))
                 .corner_radius(egui::CornerRadius::same(6))
-                .fill(egui::Color32::WHITE)
+                .fill(Theme::current(ui.ctx()).layout.card_background)
                 .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(147, 51, 234))); // Purple outline
                 
                 if ui.add(cancel_button).clicked() {
@@ -22,6 +22,8 @@ use crate::ui::app_state::AllowanceTrackerApp;
 impl AllowanceTrackerApp {
     /// Render all modals - main modal coordinator
     pub fn render_modals(&mut self, ctx: &egui::Context) {
+        self.render_missing_data_modal(ctx);
+        self.render_unlock_data_modal(ctx);
         self.render_child_selector_modal(ctx);
         self.render_day_action_overlay(ctx);
         self.render_parental_control_modal(ctx);
@@ -31,5 +33,28 @@ impl AllowanceTrackerApp {
         self.render_export_modal(ctx); // Export data modal from settings
         self.render_data_directory_modal(ctx); // Data directory modal from settings
         self.render_allowance_config_modal(ctx); // Allowance config modal from settings
+        self.render_text_size_modal(ctx); // Text size modal from settings
+        self.render_archive_modal(ctx); // Archive history modal from settings
+        self.render_loans_modal(ctx); // Loans & IOUs modal from settings
+        self.render_planned_purchases_modal(ctx); // Planned purchases modal from settings
+        self.render_parents_modal(ctx); // Parents & PINs modal from settings
+        self.render_reset_modal(ctx); // Reset all data modal from settings
+        self.render_goal_history_modal(ctx); // Goal history modal from settings
+        self.render_advance_modal(ctx); // Allowance advance modal from settings
+        self.render_quick_picks_modal(ctx); // Quick picks modal from settings
+        self.render_templates_modal(ctx); // Transaction templates modal from settings
+        self.render_categorize_modal(ctx); // Bulk categorize modal from settings
+        self.render_import_modal(ctx); // Statement import modal from settings
+        self.render_retention_modal(ctx); // Data retention modal from settings
+        self.render_budget_modal(ctx); // Spending targets modal from settings
+        self.render_report_schedule_modal(ctx); // Monthly reports modal from settings
+        self.render_feature_flags_modal(ctx); // Experiments modal from settings
+        self.render_agreement_modal(ctx); // Allowance agreement modal from settings
+        self.render_close_period_modal(ctx); // Close month modal from settings
+        self.render_updates_modal(ctx); // Updates modal from settings
+        self.render_fridge_view_modal(ctx); // Fridge view modal from settings
+        self.render_background_modal(ctx); // Background modal from settings
+        self.render_spending_rules_modal(ctx); // Spending rules modal from settings
+        self.render_encryption_modal(ctx); // Encryption modal from settings
     }
 } 
\ No newline at end of file
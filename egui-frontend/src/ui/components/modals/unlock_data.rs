@@ -0,0 +1,98 @@
+//! # Unlock Data Modal
+//!
+//! Startup prompt shown when one or more children have encrypted data.
+//!
+//! ## Responsibilities:
+//! - Ask for the parental passphrase once at launch
+//! - Unlock every encrypted child the passphrase opens
+//! - Reload the visible data once it can be read
+//!
+//! ## Purpose:
+//! Encrypted data files can't be read until their key is derived from the
+//! passphrase, so this modal appears before anything else is usable.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::encryption::UnlockEncryptedDataCommand;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the unlock prompt for encrypted child data
+    pub fn render_unlock_data_modal(&mut self, ctx: &egui::Context) {
+        if !self.modal.show_unlock_data_modal {
+            return;
+        }
+
+        egui::Window::new("🔐 Unlock Data")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.label(egui::RichText::new("Some data is encrypted.")
+                    .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
+                    .strong());
+                ui.add_space(6.0);
+                ui.label("Enter the parental passphrase to open it.");
+                ui.add_space(10.0);
+
+                let input_response = ui.add(
+                    egui::TextEdit::singleline(&mut self.modal.unlock_data_passphrase)
+                        .password(true)
+                        .desired_width(300.0)
+                        .font(egui::FontId::new(16.0, egui::FontFamily::Proportional)),
+                );
+                if self.modal.unlock_data_passphrase.is_empty() {
+                    input_response.request_focus();
+                }
+                let enter_pressed = input_response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+                if let Some(error) = &self.modal.unlock_data_error {
+                    ui.add_space(6.0);
+                    ui.label(egui::RichText::new(error)
+                        .font(egui::FontId::new(14.0, egui::FontFamily::Proportional))
+                        .color(egui::Color32::from_rgb(220, 50, 50)));
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Unlock").clicked() || enter_pressed {
+                        self.submit_unlock_data_passphrase();
+                    }
+                    if ui.button("Skip").clicked() {
+                        // Encrypted children stay unreadable until the app is restarted
+                        self.modal.show_unlock_data_modal = false;
+                        self.modal.unlock_data_passphrase.clear();
+                    }
+                });
+            });
+    }
+
+    /// Try the entered passphrase on every encrypted child
+    fn submit_unlock_data_passphrase(&mut self) {
+        let command = UnlockEncryptedDataCommand {
+            passphrase: self.modal.unlock_data_passphrase.clone(),
+        };
+        match self.backend().encryption_service.unlock(command) {
+            Ok(result) if result.failed_child_ids.is_empty() => {
+                info!("🔐 Unlocked encrypted data for {} child(ren)", result.unlocked_child_ids.len());
+                self.modal.show_unlock_data_modal = false;
+                self.modal.unlock_data_passphrase.clear();
+                self.modal.unlock_data_error = None;
+
+                // Allowances couldn't be issued at startup while the data was locked
+                if let Err(e) = self.backend().transaction_service.check_and_issue_pending_allowances() {
+                    warn!("🎯 Failed to check pending allowances after unlock: {}", e);
+                }
+                self.refresh_all_data_for_current_child();
+            }
+            Ok(_) => {
+                self.modal.unlock_data_passphrase.clear();
+                self.modal.unlock_data_error = Some("Incorrect passphrase. Please try again.".to_string());
+            }
+            Err(e) => {
+                warn!("🔐 Failed to unlock encrypted data: {}", e);
+                self.modal.unlock_data_error = Some(format!("Could not unlock data: {}", e));
+            }
+        }
+    }
+}
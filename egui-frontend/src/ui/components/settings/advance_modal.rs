@@ -0,0 +1,157 @@
+//! # Allowance Advance Modal
+//!
+//! This module contains the modal for paying the current child part of their
+//! allowance early.
+//!
+//! ## Responsibilities:
+//! - Show advances still being repaid and the deductions still to come
+//! - Grant a new advance, repaid over a chosen number of allowances
+//!
+//! ## Purpose:
+//! Lets a child buy something today against next week's allowance. The
+//! advance is an ordinary income transaction, and upcoming allowances in the
+//! calendar show up already reduced by what they will repay.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::allowance_advance_service::MAX_ADVANCE_INSTALLMENTS;
+use crate::backend::domain::commands::advances::{GrantAdvanceCommand, OutstandingAdvancesQuery};
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the allowance advance modal
+    pub fn render_advance_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_advance_modal {
+            return;
+        }
+
+        let child_name = self.core.current_child.as_ref().map(|child| child.name.clone());
+        // A failed load leaves an error in the form instead of retrying every frame
+        if child_name.is_some() && self.settings.advances.is_none() && self.settings.advance_form.error.is_none() {
+            self.load_advances();
+        }
+
+        let mut grant_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("💸 Allowance Advance")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let Some(child_name) = &child_name else {
+                    ui.label("Select a child first.");
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                    return;
+                };
+
+                if let Some(advances) = &self.settings.advances {
+                    if advances.open_advances.is_empty() {
+                        ui.label(egui::RichText::new(format!("{} isn't repaying any advances.", child_name))
+                            .color(egui::Color32::from_rgb(120, 120, 120)));
+                    } else {
+                        ui.label(format!(
+                            "{} still has {} to repay from upcoming allowances",
+                            child_name,
+                            self.format_currency_amount(advances.outstanding)
+                        ));
+                        ui.add_space(8.0);
+                        egui::Grid::new("open_advances_grid").striped(true).spacing([12.0, 6.0]).show(ui, |ui| {
+                            for advance in &advances.open_advances {
+                                for installment in advance.installments.iter().filter(|i| !i.is_paid()) {
+                                    ui.label(installment.due_date.format("%b %-d, %Y").to_string());
+                                    ui.label(format!("-{}", self.format_currency_amount(installment.amount)));
+                                    ui.label(format!(
+                                        "of the {} advance on {}",
+                                        self.format_currency_amount(advance.amount),
+                                        advance.date.format("%b %-d")
+                                    ));
+                                    ui.end_row();
+                                }
+                            }
+                        });
+                    }
+                }
+
+                ui.add_space(12.0);
+                ui.separator();
+                ui.label(egui::RichText::new("Give an advance").strong());
+                let form = &mut self.settings.advance_form;
+                ui.horizontal(|ui| {
+                    ui.label("Amount:");
+                    ui.add(egui::TextEdit::singleline(&mut form.amount).hint_text("10.00").desired_width(70.0));
+                    ui.label("repaid over the next");
+                    ui.add(egui::DragValue::new(&mut form.installments).range(1..=MAX_ADVANCE_INSTALLMENTS));
+                    ui.label(if form.installments == 1 { "allowance" } else { "allowances" });
+                });
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Give advance").clicked() {
+                        grant_clicked = true;
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if grant_clicked {
+            self.grant_advance();
+        }
+        if close {
+            self.settings.show_advance_modal = false;
+        }
+    }
+
+    /// Load advances still being repaid by the current child
+    fn load_advances(&mut self) {
+        match self.backend().allowance_advance_service.get_outstanding(OutstandingAdvancesQuery { child_id: None }) {
+            Ok(advances) => self.settings.advances = Some(advances),
+            Err(e) => {
+                warn!("💸 Failed to load advances: {}", e);
+                self.settings.advance_form.error = Some(format!("Could not load advances: {}", e));
+            }
+        }
+    }
+
+    /// Grant the advance entered in the form
+    fn grant_advance(&mut self) {
+        let amount = match self.clean_and_parse_amount(&self.settings.advance_form.amount) {
+            Ok(amount) => amount,
+            Err(e) => {
+                self.settings.advance_form.error = Some(e);
+                return;
+            }
+        };
+        let command = GrantAdvanceCommand {
+            amount,
+            installments: self.settings.advance_form.installments,
+            // The settings menu is behind the parental challenge
+            actor: self.parent_actor(),
+        };
+        match self.backend().allowance_advance_service.grant_advance(command) {
+            Ok(result) => {
+                info!("💸 Granted advance {}", result.advance.id);
+                self.ui.set_success(format!(
+                    "Gave an advance of {}",
+                    self.format_currency_amount(result.advance.amount)
+                ));
+                self.settings.advance_form.clear();
+                self.settings.advances = None;
+                self.refresh_all_data_for_current_child();
+            }
+            Err(e) => {
+                warn!("💸 Failed to grant advance: {}", e);
+                self.settings.advance_form.error = Some(format!("Could not give advance: {}", e));
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! # Allowance Agreement Modal
+//!
+//! This module contains the modal for printing an allowance agreement for the
+//! active child.
+//!
+//! ## Responsibilities:
+//! - Collect the chores and any extra family rules, one per line
+//! - Choose where the PDF is saved
+//! - Write the agreement and show where it went
+//!
+//! ## Purpose:
+//! A signed page on the fridge sets expectations at the start of a school
+//! year. The allowance and the money rules come from the child's settings.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::agreement::GenerateAgreementCommand;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the allowance agreement modal
+    pub fn render_agreement_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_agreement_modal {
+            return;
+        }
+
+        let mut browse_clicked = false;
+        let mut generate_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("📜 Allowance Agreement")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let form = &mut self.settings.agreement_form;
+                ui.label("Print an agreement with the allowance, chores and rules, for your child and you to sign.");
+                ui.label(egui::RichText::new("The allowance and money rules come from this child's settings. Leave a list empty to fill it in by hand.")
+                    .color(egui::Color32::from_rgb(120, 120, 120)));
+                ui.add_space(10.0);
+
+                ui.label("Chores (one per line):");
+                ui.add(egui::TextEdit::multiline(&mut form.chores).desired_rows(4).desired_width(400.0)
+                    .hint_text("Feed the cat\nMake the bed"));
+                ui.add_space(6.0);
+                ui.label("Other rules (one per line):");
+                ui.add(egui::TextEdit::multiline(&mut form.rules).desired_rows(3).desired_width(400.0)
+                    .hint_text("Ask before buying games"));
+                ui.add_space(6.0);
+
+                ui.horizontal(|ui| {
+                    ui.label("Save to:");
+                    ui.add(egui::TextEdit::singleline(&mut form.folder).hint_text("Documents folder").desired_width(280.0));
+                    if ui.button("Browse…").clicked() {
+                        browse_clicked = true;
+                    }
+                });
+
+                if let Some(path) = &form.saved_path {
+                    ui.add_space(6.0);
+                    ui.label(egui::RichText::new(format!("Saved to {}", path)).small());
+                }
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Create PDF").clicked() {
+                        generate_clicked = true;
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if browse_clicked {
+            if let Some(folder) = rfd::FileDialog::new().set_title("Select Folder").pick_folder() {
+                self.settings.agreement_form.folder = folder.to_string_lossy().to_string();
+            }
+        }
+        if generate_clicked {
+            let form = &self.settings.agreement_form;
+            let command = GenerateAgreementCommand {
+                child_id: None,
+                chores: form.chores.lines().map(str::to_string).collect(),
+                rules: form.rules.lines().map(str::to_string).collect(),
+                output_directory: Some(form.folder.clone()),
+            };
+            match self.backend().agreement_service.generate_agreement(command) {
+                Ok(result) => {
+                    info!("📜 Allowance agreement saved to {}", result.file_path);
+                    self.settings.agreement_form.saved_path = Some(result.file_path);
+                    self.settings.agreement_form.error = None;
+                    self.ui.set_success("Allowance agreement saved".to_string());
+                }
+                Err(e) => {
+                    warn!("📜 Failed to write allowance agreement: {}", e);
+                    self.settings.agreement_form.error = Some(format!("Could not create the agreement: {}", e));
+                }
+            }
+        }
+        if close {
+            self.settings.show_agreement_modal = false;
+        }
+    }
+}
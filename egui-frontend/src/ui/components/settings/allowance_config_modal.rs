@@ -3,7 +3,10 @@
 //! This module contains the allowance configuration modal functionality.
 //!
 //! ## Responsibilities:
-//! - Display allowance configuration form with amount and day of week fields
+//! - Display allowance configuration form with amount and schedule fields
+//!   (weekly on a day of the week, every few weeks from a first payday, or
+//!   monthly on a day of the month)
+//! - Ask how to pay the switch-over week when the day of week changes
 //! - Handle form validation and change detection
 //! - Integrate with backend AllowanceService API
 //! - Provide success feedback after configuration
@@ -14,10 +17,12 @@
 
 use eframe::egui;
 use crate::ui::app_state::AllowanceTrackerApp;
+use crate::ui::components::styling::Theme;
 use crate::ui::components::settings::shared::{
     SettingsModalStyle, render_form_field_with_error
 };
 use crate::backend::domain::commands::allowance::{GetAllowanceConfigCommand, UpdateAllowanceConfigCommand};
+use crate::backend::domain::models::allowance::{AllowanceFrequency, DayChangePolicy, MAX_INTERVAL_WEEKS, MIN_INTERVAL_WEEKS};
 
 impl AllowanceTrackerApp {
     /// Render the allowance configuration modal
@@ -52,7 +57,7 @@ impl AllowanceTrackerApp {
                 // Center the modal content
                 ui.allocate_new_ui(egui::UiBuilder::new().max_rect(screen_rect), |ui| {
                     ui.centered_and_justified(|ui| {
-                        let style = SettingsModalStyle::default_style();
+                        let style = SettingsModalStyle::default_style(ui.ctx());
                         style.apply_frame_styling()
                             .show(ui, |ui| {
                                 // Set modal size
@@ -89,7 +94,7 @@ impl AllowanceTrackerApp {
                                         }
                                     } else {
                                         // Normal form state - subtitle/instructions
-                                        ui.label(egui::RichText::new("Set up weekly allowance for your child")
+                                        ui.label(egui::RichText::new("Set up a weekly, biweekly or monthly allowance for your child")
                                             .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
                                             .color(egui::Color32::from_rgb(100, 100, 100)));
 
@@ -133,7 +138,7 @@ impl AllowanceTrackerApp {
             // Amount field
             let amount_response = render_form_field_with_error(
                 ui,
-                "Weekly Allowance Amount",
+                "Allowance Amount",
                 &mut self.settings.allowance_config_form.amount,
                 "Enter dollar amount (e.g., 5.00)",
                 &self.settings.allowance_config_form.amount_error,
@@ -153,11 +158,37 @@ impl AllowanceTrackerApp {
 
             ui.add_space(15.0);
 
+            // Frequency field
+            ui.label(egui::RichText::new("How Often")
+                .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
+                .strong()
+                .color(Theme::current(ui.ctx()).typography.primary));
+            ui.add_space(5.0);
+            ui.horizontal(|ui| {
+                for frequency in AllowanceFrequency::ALL {
+                    ui.radio_value(&mut self.settings.allowance_config_form.frequency, frequency, frequency.label());
+                }
+            });
+
+            ui.add_space(15.0);
+
+            match self.settings.allowance_config_form.frequency {
+                AllowanceFrequency::Monthly => {
+                    self.render_allowance_day_of_month_field(ui);
+                    return;
+                }
+                AllowanceFrequency::EveryNWeeks => {
+                    self.render_allowance_interval_fields(ui);
+                    return;
+                }
+                AllowanceFrequency::Weekly => {}
+            }
+
             // Day of week field
             ui.label(egui::RichText::new("Day of Week")
                 .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
                 .strong()
-                .color(egui::Color32::from_rgb(60, 60, 60)));
+                .color(Theme::current(ui.ctx()).typography.primary));
 
             ui.add_space(5.0);
 
@@ -167,10 +198,11 @@ impl AllowanceTrackerApp {
                 .selected_text(self.settings.allowance_config_form.day_name())
                 .show_ui(ui, |ui| {
                     // Style the dropdown content with solid background
-                    ui.style_mut().visuals.extreme_bg_color = egui::Color32::WHITE;
-                    ui.style_mut().visuals.faint_bg_color = egui::Color32::from_rgb(248, 248, 248);
-                    ui.style_mut().visuals.widgets.noninteractive.bg_fill = egui::Color32::WHITE;
-                    ui.style_mut().visuals.widgets.inactive.bg_fill = egui::Color32::WHITE;
+                    let theme = Theme::current(ui.ctx());
+                    ui.style_mut().visuals.extreme_bg_color = theme.layout.card_background;
+                    ui.style_mut().visuals.faint_bg_color = theme.table.row_odd;
+                    ui.style_mut().visuals.widgets.noninteractive.bg_fill = theme.layout.card_background;
+                    ui.style_mut().visuals.widgets.inactive.bg_fill = theme.layout.card_background;
                     
                     let mut changed = false;
                     let original_day = self.settings.allowance_config_form.day_of_week;
@@ -200,6 +232,22 @@ impl AllowanceTrackerApp {
                 }
             }
 
+            // Switch-over rule, only relevant when moving an existing allowance to a new day
+            if self.settings.allowance_config_form.is_weekly_day_changing() {
+                ui.add_space(15.0);
+                ui.label(egui::RichText::new("This week")
+                    .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
+                    .strong()
+                    .color(Theme::current(ui.ctx()).typography.primary));
+                ui.label(egui::RichText::new("Changing the day can put two allowances close together, or leave a longer gap.")
+                    .font(egui::FontId::new(13.0, egui::FontFamily::Proportional))
+                    .color(egui::Color32::from_rgb(120, 120, 120)));
+                ui.add_space(5.0);
+                for policy in DayChangePolicy::ALL {
+                    ui.radio_value(&mut self.settings.allowance_config_form.day_change_policy, policy, policy.label());
+                }
+            }
+
             ui.add_space(10.0);
 
             // Help text
@@ -209,6 +257,75 @@ impl AllowanceTrackerApp {
         });
     }
 
+    /// Render the day-of-month dropdown for a monthly allowance
+    fn render_allowance_day_of_month_field(&mut self, ui: &mut egui::Ui) {
+        ui.label(egui::RichText::new("Day of Month")
+            .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
+            .strong()
+            .color(Theme::current(ui.ctx()).typography.primary));
+
+        ui.add_space(5.0);
+
+        egui::ComboBox::from_id_salt("allowance_day_of_month")
+            .width(200.0)
+            .selected_text(self.settings.allowance_config_form.day_of_month.to_string())
+            .show_ui(ui, |ui| {
+                let theme = Theme::current(ui.ctx());
+                ui.style_mut().visuals.extreme_bg_color = theme.layout.card_background;
+                ui.style_mut().visuals.widgets.inactive.bg_fill = theme.layout.card_background;
+                for day in 1..=31u8 {
+                    ui.selectable_value(&mut self.settings.allowance_config_form.day_of_month, day, day.to_string());
+                }
+            });
+
+        ui.add_space(10.0);
+
+        let help = if self.settings.allowance_config_form.day_of_month > 28 {
+            "💡 Allowance will be added on this day every month, or on the last day of shorter months"
+        } else {
+            "💡 Allowance will be automatically added every month on this day"
+        };
+        ui.label(egui::RichText::new(help)
+            .font(egui::FontId::new(13.0, egui::FontFamily::Proportional))
+            .color(egui::Color32::from_rgb(120, 120, 120)));
+    }
+
+    /// Render the interval and first payday of an every-few-weeks allowance
+    fn render_allowance_interval_fields(&mut self, ui: &mut egui::Ui) {
+        let form = &mut self.settings.allowance_config_form;
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new("Every")
+                .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
+                .strong()
+                .color(Theme::current(ui.ctx()).typography.primary));
+            ui.add(egui::DragValue::new(&mut form.interval_weeks).range(MIN_INTERVAL_WEEKS..=MAX_INTERVAL_WEEKS));
+            ui.label("weeks");
+        });
+
+        ui.add_space(10.0);
+
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new("First Payday")
+                .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
+                .strong()
+                .color(Theme::current(ui.ctx()).typography.primary));
+            ui.add(egui_extras::DatePickerButton::new(&mut form.anchor_date)
+                .id_salt("allowance_anchor_date")
+                .calendar_week(false));
+        });
+
+        ui.add_space(10.0);
+
+        ui.label(egui::RichText::new(format!(
+            "💡 Allowance will be added every {} weeks on {}s, starting {}",
+            form.interval_weeks,
+            form.anchor_date.format("%A"),
+            form.anchor_date.format("%b %-d")
+        ))
+            .font(egui::FontId::new(13.0, egui::FontFamily::Proportional))
+            .color(egui::Color32::from_rgb(120, 120, 120)));
+    }
+
     /// Render action buttons for allowance configuration modal
     fn render_allowance_config_action_buttons(&mut self, ui: &mut egui::Ui) {
         let form_valid = self.settings.allowance_config_form.is_valid && 
@@ -313,7 +430,7 @@ impl AllowanceTrackerApp {
                     result.allowance_config.as_ref().map(|c| c.day_of_week));
                 
                 if let Some(config) = result.allowance_config {
-                    log::info!("✅ Loaded existing allowance config: ${:.2} on {}", config.amount, config.day_name());
+                    log::info!("✅ Loaded existing allowance config: ${:.2} {}", config.amount, config.schedule_label());
                     self.settings.allowance_config_form.load_from_config(&config);
                 } else {
                     log::info!("ℹ️ No existing allowance config found, using defaults");
@@ -404,6 +521,13 @@ impl AllowanceTrackerApp {
             amount,
             day_of_week: self.settings.allowance_config_form.day_of_week,
             is_active: true, // Always set to active when updating
+            day_change_policy: Some(self.settings.allowance_config_form.day_change_policy),
+            frequency: Some(self.settings.allowance_config_form.frequency),
+            day_of_month: Some(self.settings.allowance_config_form.day_of_month),
+            interval_weeks: Some(self.settings.allowance_config_form.interval_weeks),
+            anchor_date: (self.settings.allowance_config_form.frequency == AllowanceFrequency::EveryNWeeks)
+                .then_some(self.settings.allowance_config_form.anchor_date),
+            expected_updated_at: self.settings.allowance_config_form.loaded_updated_at.clone(),
         };
 
         match self.backend().allowance_service.update_allowance_config(command) {
@@ -416,7 +540,13 @@ impl AllowanceTrackerApp {
                 // Update original values for future change detection
                 self.settings.allowance_config_form.original_amount = Some(amount);
                 self.settings.allowance_config_form.original_day_of_week = Some(self.settings.allowance_config_form.day_of_week);
+                self.settings.allowance_config_form.original_day_change_policy = Some(self.settings.allowance_config_form.day_change_policy);
+                self.settings.allowance_config_form.original_frequency = Some(self.settings.allowance_config_form.frequency);
+                self.settings.allowance_config_form.original_day_of_month = Some(self.settings.allowance_config_form.day_of_month);
+                self.settings.allowance_config_form.original_interval_weeks = Some(result.allowance_config.interval_weeks);
+                self.settings.allowance_config_form.original_anchor_date = result.allowance_config.anchor_date;
                 self.settings.allowance_config_form.has_existing_config = true;
+                self.settings.allowance_config_form.loaded_updated_at = Some(result.allowance_config.updated_at);
             }
             Err(e) => {
                 log::error!("❌ Failed to update allowance config: {}", e);
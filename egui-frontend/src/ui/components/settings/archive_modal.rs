@@ -0,0 +1,112 @@
+//! # Archive History Modal
+//!
+//! This module contains the modal for rolling up a child's old transactions.
+//!
+//! ## Responsibilities:
+//! - Let a parent choose how many years of detailed history to keep
+//! - Collapse older months into one summary transaction each
+//! - Report how many transactions were moved to the archive file
+//!
+//! ## Purpose:
+//! Keeps the app fast for children with many years of history. The originals
+//! stay in `transactions_archive.csv`, so nothing is lost.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::archive::ArchiveTransactionsCommand;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the archive history modal
+    pub fn render_archive_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_archive_modal {
+            return;
+        }
+
+        let child_name = self.core.current_child.as_ref().map(|child| child.name.clone());
+        let mut archive_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("🗄️ Archive Old History")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let Some(child_name) = &child_name else {
+                    ui.label("Select a child first.");
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                    return;
+                };
+
+                ui.label(format!(
+                    "Combine {}'s older transactions into one summary per month. \
+                     Balances stay the same, and the original transactions are kept in an archive file.",
+                    child_name
+                ));
+                ui.add_space(10.0);
+
+                ui.horizontal(|ui| {
+                    ui.label("Keep full detail for the last");
+                    ui.add(egui::DragValue::new(&mut self.settings.archive_years).range(1..=50));
+                    ui.label(if self.settings.archive_years == 1 { "year" } else { "years" });
+                });
+
+                if let Some(error) = &self.settings.archive_error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Archive").clicked() {
+                        archive_clicked = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if archive_clicked {
+            let command = ArchiveTransactionsCommand {
+                child_id: None,
+                older_than_years: self.settings.archive_years,
+            };
+            match self.backend().archive_service.archive_old_transactions(command) {
+                Ok(result) => {
+                    info!("🗄️ Archived {} transactions into {} summaries", result.archived_count, result.summary_count);
+                    let mut message = if result.archived_count == 0 {
+                        format!("Nothing to archive before {}", result.cutoff.format("%B %Y"))
+                    } else {
+                        format!(
+                            "Archived {} transactions into {} monthly summaries",
+                            result.archived_count, result.summary_count
+                        )
+                    };
+                    if result.skipped_closed_months > 0 {
+                        message.push_str(&format!("; {} closed months were left as they are", result.skipped_closed_months));
+                    }
+                    if result.skipped_linked_months > 0 {
+                        message.push_str(&format!(
+                            "; {} months with loans, advances, savings or reversals were kept in full",
+                            result.skipped_linked_months
+                        ));
+                    }
+                    self.ui.set_success(message);
+                    self.refresh_all_data_for_current_child();
+                    close = true;
+                }
+                Err(e) => {
+                    warn!("🗄️ Failed to archive transactions: {}", e);
+                    self.settings.archive_error = Some(format!("Could not archive: {}", e));
+                }
+            }
+        }
+
+        if close {
+            self.settings.show_archive_modal = false;
+        }
+    }
+}
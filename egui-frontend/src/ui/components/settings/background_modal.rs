@@ -0,0 +1,137 @@
+//! # Background Modal
+//!
+//! This module contains the modal for choosing what is drawn behind the
+//! current child's calendar, table and charts.
+//!
+//! ## Responsibilities:
+//! - List the bundled theme packs with a preview of each
+//! - Pick a picture from the computer instead
+//! - Save the choice to the child's settings
+//!
+//! ## Purpose:
+//! Each child keeps their own look, so switching children also switches the
+//! background and everyone can tell at a glance whose profile is open.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::child::UpdateChildSettingsCommand;
+use crate::backend::domain::models::background::{ChildBackground, ThemePack, BACKGROUND_IMAGE_EXTENSIONS};
+use crate::ui::app_state::AllowanceTrackerApp;
+use crate::ui::components::styling::theme_pack_colors;
+
+impl AllowanceTrackerApp {
+    /// Render the background modal
+    pub fn render_background_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_background_modal {
+            return;
+        }
+
+        let child = self.get_current_child_from_backend();
+        let child_name = child.as_ref().map(|child| child.name.clone()).unwrap_or_default();
+        let mut browse_clicked = false;
+        let mut save_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("🖼️ Background")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let dark_mode = ui.visuals().dark_mode;
+                let form = &mut self.settings.background_form;
+                ui.label(format!("Pick what {} sees behind the calendar, table and charts.", child_name));
+                ui.add_space(10.0);
+
+                for pack in ThemePack::ALL {
+                    ui.horizontal(|ui| {
+                        let (top, bottom) = theme_pack_colors(pack, dark_mode);
+                        let (swatch, _) = ui.allocate_exact_size(egui::vec2(36.0, 20.0), egui::Sense::hover());
+                        ui.painter().rect_filled(swatch.with_max_y(swatch.center().y), egui::CornerRadius::ZERO, top);
+                        ui.painter().rect_filled(swatch.with_min_y(swatch.center().y), egui::CornerRadius::ZERO, bottom);
+                        let selected = !form.use_custom_image && form.pack == pack;
+                        if ui.radio(selected, pack.label()).clicked() {
+                            form.pack = pack;
+                            form.use_custom_image = false;
+                        }
+                    });
+                }
+
+                ui.add_space(6.0);
+                ui.radio_value(&mut form.use_custom_image, true, "My own picture");
+                ui.add_enabled_ui(form.use_custom_image, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.add(egui::TextEdit::singleline(&mut form.custom_image_path).hint_text("Choose a picture").desired_width(280.0));
+                        if ui.button("Browse…").clicked() {
+                            browse_clicked = true;
+                        }
+                    });
+                });
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Save").clicked() {
+                        save_clicked = true;
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if browse_clicked {
+            if let Some(path) = rfd::FileDialog::new()
+                .set_title("Choose a Background Picture")
+                .add_filter("Picture", &BACKGROUND_IMAGE_EXTENSIONS)
+                .pick_file()
+            {
+                self.settings.background_form.custom_image_path = path.to_string_lossy().to_string();
+                self.settings.background_form.use_custom_image = true;
+            }
+        }
+        if save_clicked {
+            self.save_background(child.map(|child| child.id));
+        }
+        if close {
+            self.settings.show_background_modal = false;
+        }
+    }
+
+    /// Save the form's background to the child's settings and start drawing it
+    fn save_background(&mut self, child_id: Option<String>) {
+        let Some(child_id) = child_id else {
+            self.settings.background_form.error = Some("Pick a child first".to_string());
+            return;
+        };
+        let background = self.settings.background_form.background();
+        let command = UpdateChildSettingsCommand {
+            child_id,
+            prevent_negative_balance: None,
+            auto_save_percentage: None,
+            auto_save_to_goal: None,
+            round_up_spends: None,
+            locale: None,
+            currency: None,
+            balance_milestones: None,
+            // The classic look is the default, so nothing needs saving for it
+            background: Some(Some(background.clone()).filter(|background| *background != ChildBackground::default())),
+        };
+        match self.backend().child_service.update_child_settings(command) {
+            Ok(result) => {
+                info!("🖼️ Saved background for {}: {:?}", result.settings.child_id, background);
+                self.core.current_background = result.settings.background();
+                self.settings.background_form.error = None;
+                self.settings.show_background_modal = false;
+                self.ui.set_success("Background saved".to_string());
+            }
+            Err(e) => {
+                warn!("🖼️ Failed to save background: {}", e);
+                self.settings.background_form.error = Some(format!("Could not save: {}", e));
+            }
+        }
+    }
+}
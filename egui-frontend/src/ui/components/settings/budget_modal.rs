@@ -0,0 +1,197 @@
+//! # Spending Targets Modal
+//!
+//! This module contains the modal for the current child's monthly spending
+//! targets.
+//!
+//! ## Responsibilities:
+//! - List the child's targets with what was spent in the month on screen
+//! - Set a target for a category, replacing any earlier one
+//! - Remove a target
+//!
+//! ## Purpose:
+//! Gives a child a limit to aim for in each category ("Candy" up to $10).
+//! The calendar header flags the categories that went over.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::budget::{ListBudgetTargetsQuery, RemoveBudgetTargetCommand, SetBudgetTargetCommand};
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the spending targets modal
+    pub fn render_budget_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_budget_modal {
+            return;
+        }
+
+        let child_name = self.core.current_child.as_ref().map(|child| child.name.clone());
+        // A failed load leaves an error in the form instead of retrying every frame
+        if child_name.is_some() && self.settings.budget_targets.is_none() && self.settings.budget_form.error.is_none() {
+            self.load_budget_targets();
+        }
+
+        let mut remove_category: Option<String> = None;
+        let mut edit_category: Option<(String, f64)> = None;
+        let mut set_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("🎯 Spending Targets")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let Some(child_name) = &child_name else {
+                    ui.label("Select a child first.");
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                    return;
+                };
+
+                ui.label(format!("How much {} means to spend on each category in a month.", child_name));
+
+                if let Some(targets) = &self.settings.budget_targets {
+                    ui.add_space(8.0);
+                    if targets.is_empty() {
+                        ui.label(egui::RichText::new("No targets yet.").color(egui::Color32::from_rgb(120, 120, 120)));
+                    } else {
+                        let month_heading = format!("Spent in {}", self.get_current_month_name());
+                        egui::Grid::new("budget_targets_grid").striped(true).spacing([12.0, 4.0]).show(ui, |ui| {
+                            ui.label(egui::RichText::new("Category").strong());
+                            ui.label(egui::RichText::new("Target").strong());
+                            ui.label(egui::RichText::new(month_heading).strong());
+                            ui.end_row();
+
+                            for target in targets {
+                                let status = self.calendar.budget_status.as_ref().and_then(|status| {
+                                    status.categories.iter().find(|category| category.category == target.category)
+                                });
+                                ui.label(&target.category);
+                                ui.label(self.format_currency_amount(target.monthly_limit));
+                                match status {
+                                    Some(status) if status.over_budget => {
+                                        ui.colored_label(
+                                            egui::Color32::from_rgb(220, 50, 50),
+                                            format!("{} (over)", self.format_currency_amount(status.spent)),
+                                        );
+                                    }
+                                    Some(status) => {
+                                        ui.label(self.format_currency_amount(status.spent));
+                                    }
+                                    None => {
+                                        ui.label("—");
+                                    }
+                                }
+                                ui.horizontal(|ui| {
+                                    if ui.small_button("Edit").clicked() {
+                                        edit_category = Some((target.category.clone(), target.monthly_limit));
+                                    }
+                                    if ui.small_button("Remove").clicked() {
+                                        remove_category = Some(target.category.clone());
+                                    }
+                                });
+                                ui.end_row();
+                            }
+                        });
+                    }
+                }
+
+                ui.add_space(12.0);
+                ui.separator();
+                ui.label(egui::RichText::new("Set a target").strong());
+                ui.label(egui::RichText::new("Spending counts when its category matches, ignoring case.")
+                    .color(egui::Color32::from_rgb(120, 120, 120)));
+                let form = &mut self.settings.budget_form;
+                ui.horizontal(|ui| {
+                    ui.label("Category:");
+                    ui.add(egui::TextEdit::singleline(&mut form.category).hint_text("Candy").desired_width(140.0));
+                    ui.label("Per month:");
+                    ui.add(egui::TextEdit::singleline(&mut form.monthly_limit).hint_text("10.00").desired_width(70.0));
+                });
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Set target").clicked() {
+                        set_clicked = true;
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if let Some((category, monthly_limit)) = edit_category {
+            self.settings.budget_form.category = category;
+            self.settings.budget_form.monthly_limit = format!("{:.2}", monthly_limit);
+            self.settings.budget_form.error = None;
+        }
+        if let Some(category) = remove_category {
+            self.remove_budget_target(category);
+        }
+        if set_clicked {
+            self.set_budget_target();
+        }
+        if close {
+            self.settings.show_budget_modal = false;
+        }
+    }
+
+    /// Load the current child's spending targets
+    fn load_budget_targets(&mut self) {
+        match self.backend().budget_service.list_budget_targets(ListBudgetTargetsQuery { child_id: None }) {
+            Ok(result) => self.settings.budget_targets = Some(result.targets),
+            Err(e) => {
+                warn!("🎯 Failed to load spending targets: {}", e);
+                self.settings.budget_form.error = Some(format!("Could not load spending targets: {}", e));
+            }
+        }
+    }
+
+    /// Save the target entered in the form
+    fn set_budget_target(&mut self) {
+        let monthly_limit = match self.clean_and_parse_amount(&self.settings.budget_form.monthly_limit) {
+            Ok(amount) => amount,
+            Err(e) => {
+                self.settings.budget_form.error = Some(e);
+                return;
+            }
+        };
+        let command = SetBudgetTargetCommand {
+            child_id: None,
+            category: self.settings.budget_form.category.clone(),
+            monthly_limit,
+        };
+        match self.backend().budget_service.set_budget_target(command) {
+            Ok(target) => {
+                info!("🎯 Set spending target for {}", target.category);
+                self.settings.budget_form.clear();
+                self.settings.budget_targets = None;
+                self.load_budget_status();
+            }
+            Err(e) => {
+                warn!("🎯 Failed to set spending target: {}", e);
+                self.settings.budget_form.error = Some(format!("Could not set target: {}", e));
+            }
+        }
+    }
+
+    /// Remove the target for one of the current child's categories
+    fn remove_budget_target(&mut self, category: String) {
+        match self.backend().budget_service.remove_budget_target(RemoveBudgetTargetCommand { child_id: None, category }) {
+            Ok(()) => {
+                self.settings.budget_form.error = None;
+                self.settings.budget_targets = None;
+                self.load_budget_status();
+            }
+            Err(e) => {
+                warn!("🎯 Failed to remove spending target: {}", e);
+                self.settings.budget_form.error = Some(format!("Could not remove target: {}", e));
+            }
+        }
+    }
+}
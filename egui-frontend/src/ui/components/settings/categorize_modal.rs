@@ -0,0 +1,220 @@
+//! # Categorize Modal
+//!
+//! This module contains the modal for giving many of the current child's
+//! past transactions a category at once.
+//!
+//! ## Responsibilities:
+//! - Pick the transactions by description text, by date range, and whether
+//!   to only touch the ones without a category yet
+//! - Preview how many match, how many would change and how many sit in a
+//!   closed month, with a few of them listed
+//! - Apply the change, then offer to undo it
+//!
+//! ## Purpose:
+//! Families who start using categories after years of history shouldn't
+//! have to edit each old transaction. Applying is only possible right after
+//! a preview of the same fields, so nothing changes that wasn't counted first.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::transactions::{BulkCategorizeCommand, UndoBulkCategorizeCommand};
+use crate::backend::domain::models::actor::Actor;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the categorize modal
+    pub fn render_categorize_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_categorize_modal {
+            return;
+        }
+
+        let child_name = self.core.current_child.as_ref().map(|child| child.name.clone());
+        let (mut preview_clicked, mut apply_clicked, mut undo_clicked, mut close) = (false, false, false, false);
+
+        egui::Window::new("🏷️ Categorize History")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let Some(child_name) = &child_name else {
+                    ui.label("Select a child first.");
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                    return;
+                };
+
+                ui.label(format!("Give many of {}'s transactions the same category.", child_name));
+                ui.add_space(8.0);
+
+                let form = &mut self.settings.categorize_form;
+                let mut changed = false;
+                egui::Grid::new("categorize_form_grid").spacing([8.0, 6.0]).show(ui, |ui| {
+                    ui.label("Description contains:");
+                    changed |= ui.add(egui::TextEdit::singleline(&mut form.description_contains)
+                        .hint_text("allowance")
+                        .desired_width(200.0)).changed();
+                    ui.end_row();
+                    ui.label("Category:");
+                    changed |= ui.add(egui::TextEdit::singleline(&mut form.category)
+                        .hint_text("empty clears it")
+                        .desired_width(200.0)).changed();
+                    ui.end_row();
+                });
+                changed |= ui.checkbox(&mut form.uncategorized_only, "Only transactions without a category").changed();
+                ui.horizontal(|ui| {
+                    changed |= ui.checkbox(&mut form.limit_dates, "Only from").changed();
+                    ui.add_enabled_ui(form.limit_dates, |ui| {
+                        changed |= ui.add(egui_extras::DatePickerButton::new(&mut form.start_date)
+                            .id_salt("categorize_start_date")
+                            .calendar_week(false)).changed();
+                        ui.label("to");
+                        changed |= ui.add(egui_extras::DatePickerButton::new(&mut form.end_date)
+                            .id_salt("categorize_end_date")
+                            .calendar_week(false)).changed();
+                    });
+                });
+                if changed {
+                    form.preview = None;
+                    form.error = None;
+                }
+
+                if let Some(preview) = &form.preview {
+                    ui.add_space(10.0);
+                    let target = match form.category() {
+                        Some(category) => format!("filed under \"{}\"", category),
+                        None => "left without a category".to_string(),
+                    };
+                    ui.label(egui::RichText::new(format!(
+                        "{} match; {} would be {}.",
+                        preview.matched, preview.would_change, target
+                    )).strong());
+                    if preview.skipped_closed > 0 {
+                        ui.label(format!("{} are in a closed month and stay as they are.", preview.skipped_closed));
+                    }
+                    for transaction in &preview.sample {
+                        ui.label(egui::RichText::new(format!(
+                            "{}  {}  ({})",
+                            transaction.date.format("%b %-d, %Y"),
+                            transaction.description,
+                            transaction.category.as_deref().unwrap_or("no category")
+                        )).small());
+                    }
+                    if preview.would_change > preview.sample.len() {
+                        ui.label(egui::RichText::new(format!("…and {} more", preview.would_change - preview.sample.len())).small().weak());
+                    }
+                }
+
+                if let Some((message, _)) = &form.applied {
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        ui.colored_label(egui::Color32::from_rgb(40, 150, 60), message);
+                        if ui.button("Undo").clicked() {
+                            undo_clicked = true;
+                        }
+                    });
+                }
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Preview").clicked() {
+                        preview_clicked = true;
+                    }
+                    let can_apply = form.preview.as_ref().is_some_and(|preview| preview.would_change > 0);
+                    if ui.add_enabled(can_apply, egui::Button::new("Apply"))
+                        .on_disabled_hover_text("Preview the change first")
+                        .clicked()
+                    {
+                        apply_clicked = true;
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if preview_clicked {
+            self.run_bulk_categorize(true);
+        }
+        if apply_clicked {
+            self.run_bulk_categorize(false);
+        }
+        if undo_clicked {
+            self.undo_bulk_categorize();
+        }
+        if close {
+            self.settings.show_categorize_modal = false;
+        }
+    }
+
+    /// Preview or apply the category change the form describes
+    fn run_bulk_categorize(&mut self, preview: bool) {
+        let form = &self.settings.categorize_form;
+        let command = BulkCategorizeCommand {
+            child_id: None,
+            filter: form.filter(),
+            category: form.category(),
+            preview,
+            actor: Some(Actor::Parent(None)),
+        };
+        let category = form.category();
+        match self.backend().transaction_service.bulk_categorize(command) {
+            Ok(result) if preview => {
+                self.settings.categorize_form.error = None;
+                self.settings.categorize_form.preview = Some(result);
+            }
+            Ok(result) => {
+                info!("🏷️ Recategorized {} transactions", result.changes.len());
+                let message = match category {
+                    Some(category) => format!("Filed {} transactions under \"{}\"", result.changes.len(), category),
+                    None => format!("Cleared the category of {} transactions", result.changes.len()),
+                };
+                let form = &mut self.settings.categorize_form;
+                form.preview = None;
+                form.error = None;
+                form.applied = Some((message, result));
+                self.refresh_after_recategorizing();
+            }
+            Err(e) => {
+                warn!("🏷️ Failed to categorize transactions: {}", e);
+                self.settings.categorize_form.error = Some(format!("Could not categorize transactions: {}", e));
+            }
+        }
+    }
+
+    /// Put back the categories the last applied change replaced
+    fn undo_bulk_categorize(&mut self) {
+        let Some((_, applied)) = self.settings.categorize_form.applied.take() else {
+            return;
+        };
+        let command = UndoBulkCategorizeCommand {
+            child_id: applied.child_id,
+            changes: applied.changes,
+            actor: Some(Actor::Parent(None)),
+        };
+        match self.backend().transaction_service.undo_bulk_categorize(command) {
+            Ok(restored) => {
+                info!("🏷️ Restored the categories of {} transactions", restored);
+                self.settings.categorize_form.preview = None;
+                self.ui.set_success(format!("Put back the categories of {} transactions", restored));
+                self.refresh_after_recategorizing();
+            }
+            Err(e) => {
+                warn!("🏷️ Failed to undo the category change: {}", e);
+                self.settings.categorize_form.error = Some(format!("Could not undo the change: {}", e));
+            }
+        }
+    }
+
+    /// Reload the views that show categories
+    fn refresh_after_recategorizing(&mut self) {
+        self.load_calendar_data();
+        self.table.invalidate();
+        self.load_chart_data();
+    }
+}
@@ -0,0 +1,144 @@
+//! # Close Month Modal
+//!
+//! This module contains the modal for locking the active child's transactions
+//! once a month has been reviewed.
+//!
+//! ## Responsibilities:
+//! - Show how far transactions are closed now
+//! - Pick the month to close through
+//! - Reopen closed transactions
+//!
+//! ## Purpose:
+//! After going through a month with their child, a parent closes it so a stray
+//! delete or backdated entry can't change what they agreed on. The modal sits
+//! behind the parental challenge like every settings entry, so reopening is
+//! the parental unlock.
+
+use chrono::{Datelike, Local};
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::transactions::{ClosePeriodCommand, ReopenPeriodCommand};
+use crate::backend::domain::models::period_lock::PeriodLock;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+
+impl AllowanceTrackerApp {
+    /// Render the close month modal
+    pub fn render_close_period_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_close_period_modal {
+            return;
+        }
+
+        let mut close_clicked = false;
+        let mut reopen_clicked = false;
+        let mut cancel = false;
+        let this_year = Local::now().year();
+
+        egui::Window::new("🔒 Close Month")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let form = &mut self.settings.close_period_form;
+                ui.label("Closing a month locks its transactions: they can't be deleted, and nothing new can be dated into it.");
+                ui.add_space(8.0);
+
+                match &form.current_lock {
+                    Some(lock) => {
+                        ui.label(format!("Transactions are closed through {}.", lock.locked_through.format("%B %-d, %Y")));
+                        if ui.button("🔓 Reopen").on_hover_text("Unlock every closed transaction").clicked() {
+                            reopen_clicked = true;
+                        }
+                    }
+                    None => {
+                        ui.label("No months are closed yet.");
+                    }
+                }
+                ui.add_space(10.0);
+
+                ui.horizontal(|ui| {
+                    ui.label("Close through the end of");
+                    egui::ComboBox::from_id_salt("close_period_month")
+                        .selected_text(MONTH_NAMES[(form.month as usize).saturating_sub(1) % 12])
+                        .show_ui(ui, |ui| {
+                            for (index, name) in MONTH_NAMES.iter().enumerate() {
+                                ui.selectable_value(&mut form.month, index as u32 + 1, *name);
+                            }
+                        });
+                    egui::ComboBox::from_id_salt("close_period_year")
+                        .selected_text(form.year.to_string())
+                        .show_ui(ui, |ui| {
+                            for year in (this_year - 5..=this_year).rev() {
+                                ui.selectable_value(&mut form.year, year, year.to_string());
+                            }
+                        });
+                });
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Close month").clicked() {
+                        close_clicked = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        cancel = true;
+                    }
+                });
+            });
+
+        if close_clicked {
+            let form = &self.settings.close_period_form;
+            match PeriodLock::month_end(form.year, form.month) {
+                Some(locked_through) => {
+                    let command = ClosePeriodCommand { child_id: None, locked_through, actor: self.parent_actor() };
+                    match self.backend().transaction_service.close_period(command) {
+                        Ok(result) => {
+                            info!("🔒 Closed transactions through {} ({} locked)", result.lock.locked_through, result.locked_count);
+                            self.ui.set_success(format!(
+                                "Closed through {}: {} transactions locked",
+                                result.lock.locked_through.format("%B %-d, %Y"),
+                                result.locked_count
+                            ));
+                            self.settings.show_close_period_modal = false;
+                            self.load_calendar_data();
+                            self.table.invalidate();
+                        }
+                        Err(e) => {
+                            warn!("🔒 Failed to close period: {}", e);
+                            self.settings.close_period_form.error = Some(e.to_string());
+                        }
+                    }
+                }
+                None => self.settings.close_period_form.error = Some("Pick a month to close".to_string()),
+            }
+        }
+        if reopen_clicked {
+            let command = ReopenPeriodCommand { child_id: None, actor: self.parent_actor() };
+            match self.backend().transaction_service.reopen_period(command) {
+                Ok(_) => {
+                    info!("🔓 Reopened closed transactions");
+                    self.settings.close_period_form.current_lock = None;
+                    self.settings.close_period_form.error = None;
+                    self.ui.set_success("Closed months reopened".to_string());
+                    self.load_calendar_data();
+                    self.table.invalidate();
+                }
+                Err(e) => {
+                    warn!("🔓 Failed to reopen period: {}", e);
+                    self.settings.close_period_form.error = Some(format!("Could not reopen: {}", e));
+                }
+            }
+        }
+        if cancel {
+            self.settings.show_close_period_modal = false;
+        }
+    }
+}
@@ -45,7 +45,7 @@ impl AllowanceTrackerApp {
                 // Center the modal content
                 ui.allocate_new_ui(egui::UiBuilder::new().max_rect(screen_rect), |ui| {
                     ui.centered_and_justified(|ui| {
-                        let style = SettingsModalStyle::default_style();
+                        let style = SettingsModalStyle::default_style(ui.ctx());
                         style.apply_frame_styling()
                             .show(ui, |ui| {
                                 // Set modal size
@@ -6,6 +6,7 @@
 //! - Display current data directory location
 //! - Allow user to browse for new data directory location
 //! - Handle conflict detection and resolution when target location has existing data
+//! - Show what a move would do (files, sizes, conflicts) and only move once confirmed
 //! - Integrate with backend DataDirectoryService API
 //! - Provide visual feedback and progress indication
 //!
@@ -21,7 +22,7 @@ use crate::ui::components::settings::shared::{
 use shared::{
     CheckDataDirectoryConflictRequest, 
     RelocateWithConflictResolutionRequest, ConflictResolution,
-    ReturnToDefaultLocationRequest
+    ReturnToDefaultLocationRequest, PlannedFileOperation
 };
 
 impl AllowanceTrackerApp {
@@ -54,7 +55,7 @@ impl AllowanceTrackerApp {
                 // Center the modal content
                 ui.allocate_new_ui(egui::UiBuilder::new().max_rect(screen_rect), |ui| {
                     ui.centered_and_justified(|ui| {
-                        let style = SettingsModalStyle::default_style();
+                        let style = SettingsModalStyle::default_style(ui.ctx());
                         style.apply_frame_styling()
                             .show(ui, |ui| {
                                 // Set modal size
@@ -80,7 +81,9 @@ impl AllowanceTrackerApp {
                                     ui.add_space(25.0);
 
                                     // Form content
-                                    if self.settings.data_directory_form.has_conflict {
+                                    if self.settings.data_directory_form.plan.is_some() {
+                                        self.render_relocation_plan_content(ui);
+                                    } else if self.settings.data_directory_form.has_conflict {
                                         self.render_conflict_resolution_content(ui);
                                     } else {
                                         self.render_data_directory_form_content(ui);
@@ -133,6 +136,22 @@ impl AllowanceTrackerApp {
                 ui.label(egui::RichText::new(&self.settings.data_directory_form.current_path)
                     .font(egui::FontId::new(13.0, egui::FontFamily::Monospace))
                     .color(egui::Color32::from_rgb(70, 130, 180)));
+
+                // Warn when changes are disabled (read-only mode or another instance holds the lock)
+                if let Some(reason) = &self.settings.data_directory_form.read_only_reason {
+                    ui.add_space(10.0);
+                    ui.label(egui::RichText::new(format!("🔒 Read-only: {}", reason))
+                        .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
+                        .color(egui::Color32::from_rgb(200, 80, 80)));
+                }
+
+                // Read-only mode toggle, for demos and browsing backups
+                ui.add_space(10.0);
+                let mut read_only = self.backend().data_directory_service.read_only_source().is_some();
+                if ui.checkbox(&mut read_only, "Read-only mode (no changes are saved)").changed() {
+                    self.backend().data_directory_service.set_read_only(read_only);
+                    self.load_current_data_directory();
+                }
                 
                 // Show "Return to Default Location" button if data is redirected
                 if self.settings.data_directory_form.is_redirected {
@@ -276,15 +295,67 @@ impl AllowanceTrackerApp {
         });
     }
 
+    /// Render the dry run of the chosen move for the user to confirm
+    fn render_relocation_plan_content(&mut self, ui: &mut egui::Ui) {
+        let Some((resolution, plan)) = &self.settings.data_directory_form.plan else {
+            return;
+        };
+        ui.vertical(|ui| {
+            ui.label(egui::RichText::new("Confirm the Move")
+                .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
+                .strong());
+            ui.add_space(6.0);
+            let action = match resolution {
+                ConflictResolution::UseTargetData => "Switch to the data already at",
+                _ => "Copy the data to",
+            };
+            ui.label(format!("{} {}", action, plan.target_path));
+            ui.label(egui::RichText::new(format!("About {} will be written.", format_file_size(plan.estimated_bytes)))
+                .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
+                .color(egui::Color32::from_rgb(120, 120, 120)));
+            ui.add_space(8.0);
+
+            egui::ScrollArea::vertical().max_height(220.0).show(ui, |ui| {
+                render_planned_files(ui, "Copied", &plan.files_to_copy);
+                render_planned_files(ui, "Replaced at the new location", &plan.files_to_overwrite);
+                render_planned_files(ui, "Archived", &plan.files_to_archive);
+                render_planned_files(ui, "Removed from the old location", &plan.files_to_remove);
+                if let Some(archive_path) = &plan.archive_path {
+                    ui.label(egui::RichText::new(format!("Archive folder: {}", archive_path))
+                        .font(egui::FontId::new(12.0, egui::FontFamily::Proportional)));
+                }
+            });
+
+            if !plan.issues.is_empty() {
+                ui.add_space(8.0);
+                ui.label(egui::RichText::new("⚠️ This move can't go ahead:")
+                    .strong()
+                    .color(egui::Color32::from_rgb(200, 0, 0)));
+                for issue in &plan.issues {
+                    ui.label(egui::RichText::new(format!("• {}", issue)).color(egui::Color32::from_rgb(200, 0, 0)));
+                }
+            }
+
+            if let Some(ref error_msg) = self.settings.data_directory_form.error_message {
+                ui.add_space(8.0);
+                ui.label(egui::RichText::new(error_msg)
+                    .color(egui::Color32::from_rgb(200, 0, 0)));
+            }
+        });
+    }
+
     /// Render action buttons for data directory modal
     fn render_data_directory_action_buttons(&mut self, ui: &mut egui::Ui) {
         let is_loading = self.settings.data_directory_form.is_loading;
         let has_conflict = self.settings.data_directory_form.has_conflict;
         let has_success = self.settings.data_directory_form.success_message.is_some();
+        let plan_can_proceed = self.settings.data_directory_form.plan.as_ref().map(|(_, plan)| plan.can_proceed);
 
         // Use mutable borrow flags to handle closure conflicts
         let mut should_proceed = false;
         let mut should_move = false;
+        let mut should_confirm = false;
+        let mut should_go_back = false;
         let mut should_close = false;
 
         ui.horizontal(|ui| {
@@ -308,8 +379,21 @@ impl AllowanceTrackerApp {
 
                     ui.add_space(10.0);
 
+                    if plan_can_proceed.is_some() {
+                        if ui.button(egui::RichText::new("Back")
+                            .font(egui::FontId::new(16.0, egui::FontFamily::Proportional)))
+                            .clicked()
+                        {
+                            should_go_back = true;
+                        }
+
+                        ui.add_space(10.0);
+                    }
+
                     // Primary action button
-                    let (button_text, form_ready) = if has_conflict {
+                    let (button_text, form_ready) = if let Some(can_proceed) = plan_can_proceed {
+                        ("Confirm Move", can_proceed)
+                    } else if has_conflict {
                         ("Proceed with Resolution", self.settings.data_directory_form.is_ready_for_resolution())
                     } else if is_loading {
                         ("⏳ Processing...", false)
@@ -327,7 +411,9 @@ impl AllowanceTrackerApp {
                         });
 
                     if ui.add_enabled(form_ready && !is_loading, button).clicked() {
-                        if has_conflict {
+                        if plan_can_proceed.is_some() {
+                            should_confirm = true;
+                        } else if has_conflict {
                             should_proceed = true;
                         } else {
                             should_move = true;
@@ -338,6 +424,13 @@ impl AllowanceTrackerApp {
         });
 
         // Handle actions outside the UI closure to avoid borrowing conflicts
+        if should_confirm {
+            self.confirm_relocation();
+        }
+        if should_go_back {
+            self.settings.data_directory_form.plan = None;
+            self.settings.data_directory_form.clear_messages();
+        }
         if should_proceed {
             self.proceed_with_data_directory_resolution();
         }
@@ -361,6 +454,7 @@ impl AllowanceTrackerApp {
             Ok(response) => {
                 self.settings.data_directory_form.current_path = response.current_path;
                 self.settings.data_directory_form.is_redirected = response.is_redirected;
+                self.settings.data_directory_form.read_only_reason = response.read_only_reason;
                 self.settings.data_directory_form.set_loading(false);
                 log::info!("✅ Loaded current directory: {} (redirected: {})", self.settings.data_directory_form.current_path, response.is_redirected);
             }
@@ -434,58 +528,70 @@ impl AllowanceTrackerApp {
         }
     }
 
-    /// Proceed with simple relocation (no conflicts)
+    /// Plan the move without conflicts, to show before anything changes
     fn proceed_with_simple_relocation(&mut self) {
-        log::info!("📁 Proceeding with simple relocation");
+        log::info!("📁 Planning simple relocation");
+        self.preview_relocation(ConflictResolution::OverwriteTarget); // Safe since no conflicts
+    }
+
+    /// Plan the move with the chosen conflict resolution, to show before anything changes
+    fn proceed_with_data_directory_resolution(&mut self) {
+        log::info!("📁 Proceeding with conflict resolution");
+        
+        let resolution = match self.settings.data_directory_form.user_decision.clone() {
+            Some(resolution) => resolution,
+            None => {
+                log::warn!("No resolution selected");
+                return;
+            }
+        };
+
+        if matches!(resolution, ConflictResolution::Cancel) {
+            log::info!("📁 User chose to cancel");
+            self.close_data_directory_modal();
+            return;
+        }
+
+        self.preview_relocation(resolution);
+    }
+
+    /// Dry-run the move and keep the plan for the user to confirm
+    fn preview_relocation(&mut self, resolution: ConflictResolution) {
         self.settings.data_directory_form.set_loading(true);
 
         let child_id = self.get_current_child_from_backend().as_ref().map(|c| c.id.clone());
         let request = RelocateWithConflictResolutionRequest {
             child_id,
             new_path: self.settings.data_directory_form.new_path.clone(),
-            resolution: ConflictResolution::OverwriteTarget, // Safe since no conflicts
+            resolution: resolution.clone(),
+            dry_run: true,
         };
 
         match self.backend().data_directory_service.relocate_with_conflict_resolution(request) {
             Ok(response) => {
                 self.settings.data_directory_form.set_loading(false);
-                
-                if response.success {
-                    log::info!("✅ Data directory relocated successfully");
-                    self.settings.data_directory_form.set_success(response.message);
-                    // Update current path
-                    self.settings.data_directory_form.current_path = response.new_path;
-                } else {
-                    log::error!("🚨 Relocation failed: {}", response.message);
-                    self.settings.data_directory_form.set_error(response.message);
+                match response.plan {
+                    Some(plan) => {
+                        log::info!("🔍 Relocation plan: {}", response.message);
+                        self.settings.data_directory_form.plan = Some((resolution, plan));
+                    }
+                    None => self.settings.data_directory_form.set_error(response.message),
                 }
             }
             Err(e) => {
-                log::error!("🚨 Relocation service error: {}", e);
+                log::error!("🚨 Failed to plan the relocation: {}", e);
                 self.settings.data_directory_form.set_loading(false);
-                self.settings.data_directory_form.set_error(format!("Relocation failed: {}", e));
+                self.settings.data_directory_form.set_error(format!("Could not plan the move: {}", e));
             }
         }
     }
 
-    /// Proceed with conflict resolution
-    fn proceed_with_data_directory_resolution(&mut self) {
-        log::info!("📁 Proceeding with conflict resolution");
-        
-        let resolution = match self.settings.data_directory_form.user_decision.clone() {
-            Some(resolution) => resolution,
-            None => {
-                log::warn!("No resolution selected");
-                return;
-            }
-        };
-
-        if matches!(resolution, ConflictResolution::Cancel) {
-            log::info!("📁 User chose to cancel");
-            self.close_data_directory_modal();
+    /// Carry out the move the user confirmed
+    fn confirm_relocation(&mut self) {
+        let Some((resolution, _)) = self.settings.data_directory_form.plan.clone() else {
             return;
-        }
-
+        };
+        log::info!("📁 Relocating after confirmation: {:?}", resolution);
         self.settings.data_directory_form.set_loading(true);
 
         let child_id = self.get_current_child_from_backend().as_ref().map(|c| c.id.clone());
@@ -493,6 +599,7 @@ impl AllowanceTrackerApp {
             child_id,
             new_path: self.settings.data_directory_form.new_path.clone(),
             resolution,
+            dry_run: false,
         };
 
         match self.backend().data_directory_service.relocate_with_conflict_resolution(request) {
@@ -500,7 +607,7 @@ impl AllowanceTrackerApp {
                 self.settings.data_directory_form.set_loading(false);
                 
                 if response.success {
-                    log::info!("✅ Data directory conflict resolved successfully");
+                    log::info!("✅ Data directory relocated successfully");
                     let message = if let Some(archived_to) = response.archived_to {
                         format!("{}\n\nArchived to: {}", response.message, archived_to)
                     } else {
@@ -509,17 +616,18 @@ impl AllowanceTrackerApp {
                     self.settings.data_directory_form.set_success(message);
                     // Update current path
                     self.settings.data_directory_form.current_path = response.new_path;
-                    // Clear conflict state
+                    // Clear conflict and plan state
                     self.settings.data_directory_form.set_conflict(false, None);
+                    self.settings.data_directory_form.plan = None;
                 } else {
-                    log::error!("🚨 Conflict resolution failed: {}", response.message);
+                    log::error!("🚨 Relocation failed: {}", response.message);
                     self.settings.data_directory_form.set_error(response.message);
                 }
             }
             Err(e) => {
-                log::error!("🚨 Conflict resolution service error: {}", e);
+                log::error!("🚨 Relocation service error: {}", e);
                 self.settings.data_directory_form.set_loading(false);
-                self.settings.data_directory_form.set_error(format!("Operation failed: {}", e));
+                self.settings.data_directory_form.set_error(format!("Relocation failed: {}", e));
             }
         }
     }
@@ -561,4 +669,31 @@ impl AllowanceTrackerApp {
         self.settings.data_directory_form.clear();
         log::info!("📁 Data directory modal closed and form reset");
     }
-} 
\ No newline at end of file
+}
+
+/// One group of planned file operations, with each file's size
+fn render_planned_files(ui: &mut egui::Ui, heading: &str, files: &[PlannedFileOperation]) {
+    if files.is_empty() {
+        return;
+    }
+    let total: u64 = files.iter().map(|file| file.size_bytes).sum();
+    ui.label(egui::RichText::new(format!("{}: {} file(s), {}", heading, files.len(), format_file_size(total)))
+        .font(egui::FontId::new(13.0, egui::FontFamily::Proportional))
+        .strong());
+    for file in files {
+        ui.label(egui::RichText::new(format!("   {}  ({})", file.relative_path, format_file_size(file.size_bytes)))
+            .font(egui::FontId::new(12.0, egui::FontFamily::Monospace)));
+    }
+    ui.add_space(6.0);
+}
+
+/// A byte count in B, KB or MB
+fn format_file_size(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
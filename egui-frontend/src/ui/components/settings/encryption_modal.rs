@@ -0,0 +1,168 @@
+//! # Encryption Modal
+//!
+//! This module contains the modal for encrypting the current child's data
+//! files with the parental passphrase, or turning that off again.
+//!
+//! ## Responsibilities:
+//! - Show whether the child's data is encrypted
+//! - Encrypt it after the passphrase has been typed twice
+//! - Decrypt it back to plain text with the passphrase
+//!
+//! ## Purpose:
+//! Families sharing a computer may not want a child's history readable by
+//! anyone who opens the data folder. Encrypting rewrites the existing files,
+//! and a forgotten passphrase can't be recovered, so the modal says so before
+//! anything changes.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::encryption::ChangeChildEncryptionCommand;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the encryption modal
+    pub fn render_encryption_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_encryption_modal {
+            return;
+        }
+
+        let (mut encrypt_clicked, mut decrypt_clicked, mut close) = (false, false, false);
+
+        egui::Window::new("🔐 Encryption")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let form = &mut self.settings.encryption_form;
+                let Some(status) = form.status.clone() else {
+                    ui.label("Select a child first.");
+                    if let Some(error) = &form.error {
+                        ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                    return;
+                };
+
+                if status.is_encrypted {
+                    ui.label(format!("{}'s data is encrypted with the parental passphrase.", status.child_name));
+                    ui.label(egui::RichText::new("Decrypting writes every file back as plain text.")
+                        .color(egui::Color32::from_rgb(120, 120, 120)));
+                } else {
+                    ui.label(format!("{}'s data is stored as plain text.", status.child_name));
+                    ui.label(egui::RichText::new("Encrypted data can't be opened without the passphrase. If it's forgotten, the data is lost.")
+                        .color(egui::Color32::from_rgb(120, 120, 120)));
+                }
+                ui.add_space(8.0);
+
+                egui::Grid::new("encryption_form_grid").spacing([8.0, 6.0]).show(ui, |ui| {
+                    ui.label("Passphrase:");
+                    if ui.add(egui::TextEdit::singleline(&mut form.passphrase)
+                        .password(true)
+                        .desired_width(200.0)).changed()
+                    {
+                        form.error = None;
+                    }
+                    ui.end_row();
+                    if !status.is_encrypted {
+                        ui.label("Type it again:");
+                        if ui.add(egui::TextEdit::singleline(&mut form.confirm_passphrase)
+                            .password(true)
+                            .desired_width(200.0)).changed()
+                        {
+                            form.error = None;
+                        }
+                        ui.end_row();
+                    }
+                });
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    let has_passphrase = !form.passphrase.is_empty();
+                    if status.is_encrypted {
+                        if ui.add_enabled(has_passphrase, egui::Button::new("Decrypt")).clicked() {
+                            decrypt_clicked = true;
+                        }
+                    } else if ui.add_enabled(has_passphrase, egui::Button::new("Encrypt")).clicked() {
+                        encrypt_clicked = true;
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if encrypt_clicked {
+            self.change_encryption(true);
+        }
+        if decrypt_clicked {
+            self.change_encryption(false);
+        }
+        if close {
+            self.settings.encryption_form.clear();
+            self.settings.show_encryption_modal = false;
+        }
+    }
+
+    /// Load whether the current child's data is encrypted into the form
+    pub(crate) fn load_encryption_status(&mut self) {
+        let Some(child) = self.get_current_child_from_backend() else {
+            return;
+        };
+        match self.backend().encryption_service.get_encryption_status() {
+            Ok(statuses) => {
+                self.settings.encryption_form.status = statuses.into_iter().find(|status| status.child_id == child.id);
+            }
+            Err(e) => {
+                warn!("🔐 Failed to read the encryption status: {}", e);
+                self.settings.encryption_form.error = Some(format!("Could not read the encryption status: {}", e));
+            }
+        }
+    }
+
+    /// Encrypt or decrypt the current child's data with the entered passphrase
+    fn change_encryption(&mut self, encrypt: bool) {
+        let form = &self.settings.encryption_form;
+        let Some(status) = &form.status else {
+            return;
+        };
+        if encrypt && form.passphrase != form.confirm_passphrase {
+            self.settings.encryption_form.error = Some("The passphrases don't match".to_string());
+            return;
+        }
+        let command = ChangeChildEncryptionCommand {
+            child_id: status.child_id.clone(),
+            passphrase: form.passphrase.clone(),
+        };
+        let service = &self.backend().encryption_service;
+        let result = if encrypt {
+            service.enable_encryption(command)
+        } else {
+            service.disable_encryption(command)
+        };
+        match result {
+            Ok(result) => {
+                info!("🔐 {} {} file(s) for child {}", if encrypt { "Encrypted" } else { "Decrypted" }, result.files_changed, result.child_id);
+                self.settings.encryption_form.clear();
+                self.settings.show_encryption_modal = false;
+                self.ui.set_success(if encrypt {
+                    "Data encrypted. Keep the passphrase somewhere safe.".to_string()
+                } else {
+                    "Data decrypted".to_string()
+                });
+            }
+            Err(e) => {
+                warn!("🔐 Failed to change encryption: {}", e);
+                self.settings.encryption_form.passphrase.clear();
+                self.settings.encryption_form.confirm_passphrase.clear();
+                self.settings.encryption_form.error = Some(format!("Could not {}: {}", if encrypt { "encrypt" } else { "decrypt" }, e));
+            }
+        }
+    }
+}
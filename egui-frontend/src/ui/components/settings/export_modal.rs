@@ -3,7 +3,7 @@
 //! This module contains the export data modal functionality.
 //!
 //! ## Responsibilities:
-//! - Display export data form with default/custom location options
+//! - Display export data form with format and default/custom location options
 //! - Handle form validation and user input
 //! - Integrate with backend ExportService API
 //! - Provide visual feedback and error handling
@@ -11,15 +11,16 @@
 //!
 //! ## Purpose:
 //! This modal provides an intuitive interface for exporting transaction data
-//! as CSV files with proper path validation and backend integration.
+//! as CSV files or an Excel workbook with proper path validation and backend
+//! integration.
 
 use eframe::egui;
 use crate::ui::app_state::AllowanceTrackerApp;
 use crate::ui::components::settings::shared::{
     SettingsModalStyle, render_form_field_with_error
 };
-use crate::ui::components::settings::ExportType;
-use shared::{ExportToPathRequest};
+use crate::ui::components::settings::{ExportFormat, ExportType};
+use shared::{ExportAllDataRequest, ExportToPathRequest, ExportWorkbookRequest};
 
 impl AllowanceTrackerApp {
     /// Render the export data modal
@@ -51,12 +52,12 @@ impl AllowanceTrackerApp {
                 // Center the modal content
                 ui.allocate_new_ui(egui::UiBuilder::new().max_rect(screen_rect), |ui| {
                     ui.centered_and_justified(|ui| {
-                        let style = SettingsModalStyle::default_style();
+                        let style = SettingsModalStyle::default_style(ui.ctx());
                         style.apply_frame_styling()
                             .show(ui, |ui| {
                                 // Set modal size - slightly larger for export content
-                                ui.set_min_size(egui::vec2(500.0, 450.0));
-                                ui.set_max_size(egui::vec2(500.0, 450.0));
+                                ui.set_min_size(egui::vec2(500.0, 650.0));
+                                ui.set_max_size(egui::vec2(500.0, 650.0));
 
                                 ui.vertical_centered(|ui| {
                                     ui.add_space(15.0);
@@ -70,7 +71,7 @@ impl AllowanceTrackerApp {
                                     ui.add_space(20.0);
 
                                     // Subtitle/instructions
-                                    ui.label(egui::RichText::new("Export all transaction data as a CSV file")
+                                    ui.label(egui::RichText::new("Export transaction data as a CSV file or Excel workbook")
                                         .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
                                         .color(egui::Color32::from_rgb(100, 100, 100)));
 
@@ -95,7 +96,7 @@ impl AllowanceTrackerApp {
                     if let Some(pointer_pos) = ui.ctx().input(|i| i.pointer.latest_pos()) {
                         let modal_rect = egui::Rect::from_center_size(
                             ui.ctx().screen_rect().center(),
-                            egui::vec2(500.0, 450.0)
+                            egui::vec2(500.0, 650.0)
                         );
                         
                         if !modal_rect.contains(pointer_pos) {
@@ -110,6 +111,29 @@ impl AllowanceTrackerApp {
     /// Render the form content for export modal
     fn render_export_form_content(&mut self, ui: &mut egui::Ui) {
         ui.vertical(|ui| {
+            // Format options
+            ui.label(egui::RichText::new("Format")
+                .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
+                .strong());
+
+            ui.add_space(10.0);
+
+            let mut format = self.settings.export_form.format;
+            ui.radio_value(&mut format, ExportFormat::Csv, "CSV (this child's transactions)");
+            ui.add_space(8.0);
+            ui.radio_value(&mut format, ExportFormat::Excel, "Excel workbook (every child, with a summary sheet)");
+            if format != self.settings.export_form.format {
+                self.settings.export_form.format = format;
+                // Anonymized exports are CSV archives only
+                self.settings.export_form.anonymize = false;
+                self.settings.export_form.selected_file_path = None;
+                self.settings.export_form.clear_messages();
+                let child_name = self.get_current_child_from_backend().as_ref().map(|c| c.name.clone());
+                self.settings.export_form.update_preview(child_name.as_deref());
+            }
+
+            ui.add_space(15.0);
+
             // Export location options
             ui.label(egui::RichText::new("Export Location")
                 .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
@@ -221,6 +245,43 @@ impl AllowanceTrackerApp {
                 ui.add_space(15.0);
             }
 
+            // Anonymized dataset for bug reports and demos
+            if self.settings.export_form.format == ExportFormat::Csv {
+                if ui.checkbox(&mut self.settings.export_form.anonymize, "Anonymize for sharing").changed() {
+                    self.settings.export_form.clear_messages();
+                    let child_name = self.get_current_child_from_backend().as_ref().map(|c| c.name.clone());
+                    self.settings.export_form.update_preview(child_name.as_deref());
+                }
+                ui.label(egui::RichText::new("Every child in one archive, with pseudonyms, dates shifted by a random number of weeks and amounts rounded")
+                    .font(egui::FontId::new(12.0, egui::FontFamily::Proportional))
+                    .color(egui::Color32::from_rgb(120, 120, 120)));
+
+                ui.add_space(10.0);
+            }
+
+            // Protected exports need the parental answer, so a child can't take
+            // a copy of the history without a parent knowing
+            let mut protected = self.settings.export_form.protected;
+            if ui.checkbox(&mut protected, "Require the parental answer to export").changed() {
+                match self.backend().parental_control_service.set_export_requires_parental_control(protected) {
+                    Ok(()) => self.settings.export_form.protected = protected,
+                    Err(e) => {
+                        log::error!("🚨 Failed to change export protection: {}", e);
+                        self.settings.export_form.set_error(format!("Could not change protection: {}", e));
+                    }
+                }
+            }
+            if self.settings.export_form.protected && !self.settings.export_form.anonymize {
+                ui.horizontal(|ui| {
+                    ui.label("Parental answer or PIN:");
+                    ui.add(egui::TextEdit::singleline(&mut self.settings.export_form.parental_answer)
+                        .password(true)
+                        .desired_width(180.0));
+                });
+            }
+
+            ui.add_space(10.0);
+
             // Preview section
             ui.separator();
             ui.add_space(15.0);
@@ -332,17 +393,24 @@ impl AllowanceTrackerApp {
             .to_lowercase();
         
         let now = chrono::Utc::now();
-        let default_filename = format!(
-            "{}_transactions_{}.csv",
-            child_name_formatted,
-            now.format("%Y%m%d")
-        );
+        let (default_filename, filter_name, extension) = match self.settings.export_form.format {
+            ExportFormat::Csv => (
+                format!("{}_transactions_{}.csv", child_name_formatted, now.format("%Y%m%d")),
+                "CSV Files",
+                "csv",
+            ),
+            ExportFormat::Excel => (
+                format!("allowance_tracker_{}.xlsx", now.format("%Y%m%d_%H%M%S")),
+                "Excel Workbooks",
+                "xlsx",
+            ),
+        };
 
         // Open save file dialog
         let file_dialog = rfd::FileDialog::new()
             .set_title("Export Data As...")
             .set_file_name(&default_filename)
-            .add_filter("CSV Files", &["csv"])
+            .add_filter(filter_name, &[extension])
             .add_filter("All Files", &["*"]);
 
         // Set initial directory to Documents if available
@@ -381,12 +449,22 @@ impl AllowanceTrackerApp {
         self.settings.export_form.is_exporting = true;
         self.settings.export_form.clear_messages();
 
+        if self.settings.export_form.format == ExportFormat::Excel {
+            self.submit_workbook_export();
+            return;
+        }
+        if self.settings.export_form.anonymize {
+            self.submit_anonymized_export();
+            return;
+        }
+
         // Prepare request - use the effective custom path which prioritizes file dialog selection
         let custom_path = self.settings.export_form.get_effective_custom_path();
 
         let request = ExportToPathRequest {
             child_id: self.get_current_child_from_backend().as_ref().map(|c| c.id.clone()),
             custom_path,
+            parental_answer: Some(self.settings.export_form.parental_answer.clone()),
         };
 
         // Execute export command
@@ -394,6 +472,7 @@ impl AllowanceTrackerApp {
             request,
             &self.backend().child_service,
             &self.backend().transaction_service,
+            &self.backend().parental_control_service,
         ) {
             Ok(response) => {
                 self.settings.export_form.is_exporting = false;
@@ -420,6 +499,77 @@ impl AllowanceTrackerApp {
             Err(e) => {
                 log::error!("🚨 Export service error: {}", e);
                 self.settings.export_form.is_exporting = false;
+                self.settings.export_form.parental_answer.clear();
+                self.settings.export_form.set_error(format!("Export failed: {}", e));
+            }
+        }
+    }
+
+    /// Export every child as an Excel workbook
+    fn submit_workbook_export(&mut self) {
+        let request = ExportWorkbookRequest {
+            custom_path: self.settings.export_form.get_effective_directory(),
+            parental_answer: Some(self.settings.export_form.parental_answer.clone()),
+        };
+        let backend = self.backend();
+        let result = backend.export_service.export_workbook(
+            request,
+            &backend.child_service,
+            &backend.transaction_service,
+            &backend.goal_service,
+            &backend.family_service,
+            &backend.parental_control_service,
+        );
+        self.settings.export_form.is_exporting = false;
+        match result {
+            Ok(response) if response.success => {
+                log::info!("✅ Workbook export written to {}", response.file_path);
+                self.settings.export_form.set_success(format!(
+                    "Exported {} children ({} transactions) to:\n{}",
+                    response.child_count, response.transaction_count, response.file_path
+                ));
+            }
+            Ok(response) => {
+                log::error!("🚨 Workbook export failed: {}", response.message);
+                self.settings.export_form.set_error(response.message);
+            }
+            Err(e) => {
+                log::error!("🚨 Workbook export error: {}", e);
+                self.settings.export_form.parental_answer.clear();
+                self.settings.export_form.set_error(format!("Export failed: {}", e));
+            }
+        }
+    }
+
+    /// Export every child as an anonymized archive
+    fn submit_anonymized_export(&mut self) {
+        let request = ExportAllDataRequest {
+            custom_path: self.settings.export_form.get_effective_directory(),
+            anonymize: true,
+        };
+        let backend = self.backend();
+        let result = backend.export_service.export_all_children(
+            request,
+            &backend.child_service,
+            &backend.transaction_service,
+            &backend.allowance_service,
+            &backend.goal_service,
+        );
+        self.settings.export_form.is_exporting = false;
+        match result {
+            Ok(response) if response.success => {
+                log::info!("✅ Anonymized export written to {}", response.file_path);
+                self.settings.export_form.set_success(format!(
+                    "Anonymized {} children ({} transactions) to:\n{}",
+                    response.child_count, response.transaction_count, response.file_path
+                ));
+            }
+            Ok(response) => {
+                log::error!("🚨 Anonymized export failed: {}", response.message);
+                self.settings.export_form.set_error(response.message);
+            }
+            Err(e) => {
+                log::error!("🚨 Anonymized export error: {}", e);
                 self.settings.export_form.set_error(format!("Export failed: {}", e));
             }
         }
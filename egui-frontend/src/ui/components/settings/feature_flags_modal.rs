@@ -0,0 +1,76 @@
+//! # Experiments Modal
+//!
+//! This module contains the modal for turning experimental features on or off.
+//!
+//! ## Responsibilities:
+//! - List every experimental feature with what it does
+//! - Save a change as soon as its checkbox is clicked
+//!
+//! ## Purpose:
+//! Lets a family try a feature that is still being worked on without a
+//! special build. The choice applies to every child in the data directory.
+
+use eframe::egui;
+use log::warn;
+use crate::backend::domain::commands::feature_flags::SetFeatureFlagCommand;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the experiments modal
+    pub fn render_feature_flags_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_feature_flags_modal {
+            return;
+        }
+
+        let mut toggled = None;
+        let mut close = false;
+
+        egui::Window::new("🧪 Experiments")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.label("Features that are still being tried out. They may change or go away.");
+                ui.add_space(10.0);
+
+                if let Some(flags) = &self.settings.feature_flags {
+                    for status in &flags.flags {
+                        let mut enabled = status.enabled;
+                        if ui.checkbox(&mut enabled, status.flag.label()).changed() {
+                            toggled = Some(SetFeatureFlagCommand { flag: status.flag, enabled });
+                        }
+                        ui.label(egui::RichText::new(status.flag.description())
+                            .color(egui::Color32::from_rgb(120, 120, 120)));
+                        ui.add_space(4.0);
+                    }
+                }
+
+                if let Some(error) = &self.settings.feature_flags_error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+
+        if let Some(command) = toggled {
+            match self.backend().feature_flag_service.set_feature_flag(command) {
+                Ok(flags) => {
+                    self.settings.feature_flags = Some(flags);
+                    self.settings.feature_flags_error = None;
+                }
+                Err(e) => {
+                    warn!("🧪 Failed to change experiment: {}", e);
+                    self.settings.feature_flags_error = Some(format!("Could not save: {}", e));
+                }
+            }
+        }
+
+        if close {
+            self.settings.show_feature_flags_modal = false;
+        }
+    }
+}
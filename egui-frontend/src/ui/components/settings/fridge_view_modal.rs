@@ -0,0 +1,133 @@
+//! # Fridge View Modal
+//!
+//! This module contains the modal for the fridge view: a read-only HTML page
+//! of every child's balance, recent transactions and goal progress.
+//!
+//! ## Responsibilities:
+//! - Choose the file the page is written to
+//! - Turn automatic updates on or off
+//! - Write the page right away
+//!
+//! ## Purpose:
+//! Lets the family check balances on a kitchen tablet or another computer
+//! without running the app there, for example by saving the page to a synced
+//! folder and opening it in the tablet's browser.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::fridge_view::UpdateFridgeViewCommand;
+use crate::backend::domain::FRIDGE_VIEW_UPDATE_MINUTES;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the fridge view modal
+    pub fn render_fridge_view_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_fridge_view_modal {
+            return;
+        }
+
+        let mut browse_clicked = false;
+        let mut save_clicked = false;
+        let mut generate_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("🧲 Fridge View")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let form = &mut self.settings.fridge_view_form;
+                ui.label("Save a page with each child's balance, recent transactions and goal progress that opens in any browser, such as a kitchen tablet.");
+                ui.add_space(10.0);
+
+                ui.horizontal(|ui| {
+                    ui.label("File:");
+                    ui.add(egui::TextEdit::singleline(&mut form.path).hint_text("Choose where to save the page").desired_width(280.0));
+                    if ui.button("Browse…").clicked() {
+                        browse_clicked = true;
+                    }
+                });
+                ui.checkbox(&mut form.auto_update, format!("Keep it up to date while the app is open (every {} minutes)", FRIDGE_VIEW_UPDATE_MINUTES));
+
+                ui.label(egui::RichText::new(match form.last_written {
+                    Some(written) => format!("Last saved {}", written.format("%Y-%m-%d %H:%M")),
+                    None => "Not saved yet.".to_string(),
+                })
+                .color(egui::Color32::from_rgb(120, 120, 120)));
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                let saved_path = form.settings.as_ref().and_then(|settings| settings.path.clone());
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Save").clicked() {
+                        save_clicked = true;
+                    }
+                    if saved_path.is_some() && ui.button("Write page now").clicked() {
+                        generate_clicked = true;
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if browse_clicked {
+            if let Some(path) = rfd::FileDialog::new()
+                .set_title("Save Fridge View")
+                .set_file_name("allowance_fridge_view.html")
+                .add_filter("Web page", &["html"])
+                .save_file()
+            {
+                self.settings.fridge_view_form.path = path.to_string_lossy().to_string();
+            }
+        }
+        if save_clicked {
+            let command = UpdateFridgeViewCommand {
+                path: Some(self.settings.fridge_view_form.path.clone()),
+                auto_update: self.settings.fridge_view_form.auto_update,
+            };
+            match self.backend().fridge_view_service.update_settings(command) {
+                Ok(settings) => {
+                    info!("🧲 Fridge view saved: {:?}", settings.path);
+                    let last_written = self.backend().fridge_view_service.last_written().unwrap_or(None);
+                    self.settings.fridge_view_form.load(settings, last_written);
+                    self.ui.set_success("Fridge view settings saved".to_string());
+                }
+                Err(e) => {
+                    warn!("🧲 Failed to save fridge view settings: {}", e);
+                    self.settings.fridge_view_form.error = Some(format!("Could not save: {}", e));
+                }
+            }
+        }
+        if generate_clicked {
+            self.write_fridge_view();
+        }
+        if close {
+            self.settings.show_fridge_view_modal = false;
+        }
+    }
+
+    /// Write the fridge view page to the saved file
+    fn write_fridge_view(&mut self) {
+        match self.backend().fridge_view_service.generate() {
+            Ok(result) => {
+                info!("🧲 Wrote fridge view with {} children", result.child_count);
+                self.settings.fridge_view_form.last_written = self.backend().fridge_view_service.last_written().unwrap_or(None);
+                self.settings.fridge_view_form.error = if result.skipped.is_empty() {
+                    None
+                } else {
+                    Some(format!("Left out for now (locked or in use): {}", result.skipped.join(", ")))
+                };
+                self.ui.set_success(format!("Saved the fridge view to {}", result.path));
+            }
+            Err(e) => {
+                warn!("🧲 Failed to write fridge view: {}", e);
+                self.settings.fridge_view_form.error = Some(format!("Could not write the page: {}", e));
+            }
+        }
+    }
+}
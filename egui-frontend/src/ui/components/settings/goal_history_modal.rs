@@ -0,0 +1,125 @@
+//! # Goal History Modal
+//!
+//! This module contains the modal showing how the current child's past goals
+//! went.
+//!
+//! ## Responsibilities:
+//! - Summarize completion rate, average time to a goal and how far abandoned
+//!   goals got
+//! - List every goal with how long it was open and how much was saved
+//!
+//! ## Purpose:
+//! Helps parents see whether goals are too big or too far off, so the next
+//! one can be set at a size the child actually reaches.
+
+use eframe::egui;
+use log::warn;
+use crate::backend::domain::commands::goal::GetGoalStatisticsQuery;
+use crate::backend::domain::models::goal::DomainGoalState;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the goal history modal
+    pub fn render_goal_history_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_goal_history_modal {
+            return;
+        }
+
+        let child_name = self.core.current_child.as_ref().map(|child| child.name.clone());
+        // A failed load leaves an error instead of retrying every frame
+        if child_name.is_some() && self.settings.goal_statistics.is_none() && self.settings.goal_history_error.is_none() {
+            self.load_goal_statistics();
+        }
+
+        let mut close = false;
+
+        egui::Window::new("🏁 Goal History")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let Some(child_name) = &child_name else {
+                    ui.label("Select a child first.");
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                    return;
+                };
+
+                if let Some(error) = &self.settings.goal_history_error {
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                if let Some(statistics) = &self.settings.goal_statistics {
+                    let summary = &statistics.summary;
+                    if summary.total_goals == 0 {
+                        ui.label(egui::RichText::new(format!("{} hasn't set any goals yet.", child_name))
+                            .color(egui::Color32::from_rgb(120, 120, 120)));
+                    } else {
+                        ui.label(format!(
+                            "{} goals: {} reached, {} given up",
+                            summary.total_goals, summary.completed_count, summary.abandoned_count
+                        ));
+                        if let Some(rate) = summary.completion_rate {
+                            ui.label(format!("Completion rate: {:.0}%", rate));
+                        }
+                        if let Some(days) = summary.average_days_to_complete {
+                            ui.label(format!("Average time to reach a goal: {:.1} days", days));
+                        }
+                        if let Some(percent) = summary.average_percent_when_abandoned {
+                            ui.label(format!("Given-up goals got {:.0}% of the way on average", percent));
+                        }
+
+                        ui.add_space(8.0);
+                        egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                            egui::Grid::new("goal_history_grid").striped(true).spacing([12.0, 6.0]).show(ui, |ui| {
+                                ui.label(egui::RichText::new("Goal").strong());
+                                ui.label(egui::RichText::new("Status").strong());
+                                ui.label(egui::RichText::new("Days").strong());
+                                ui.label(egui::RichText::new("Saved").strong());
+                                ui.label(egui::RichText::new("Of target").strong());
+                                ui.end_row();
+
+                                for entry in &statistics.goals {
+                                    ui.label(format!(
+                                        "{} ({})",
+                                        entry.goal.description,
+                                        self.format_currency_amount(entry.goal.target_amount)
+                                    ));
+                                    ui.label(match entry.goal.state {
+                                        DomainGoalState::Active => "in progress",
+                                        DomainGoalState::Completed => "reached",
+                                        DomainGoalState::Cancelled => "given up",
+                                    });
+                                    ui.label(entry.days_taken.to_string());
+                                    ui.label(self.format_currency_amount(entry.saved));
+                                    ui.label(format!("{:.0}%", entry.percent_of_target));
+                                    ui.end_row();
+                                }
+                            });
+                        });
+                    }
+                }
+
+                ui.add_space(12.0);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+
+        if close {
+            self.settings.show_goal_history_modal = false;
+        }
+    }
+
+    /// Load goal statistics for the current child
+    fn load_goal_statistics(&mut self) {
+        match self.backend().goal_service.get_goal_statistics(GetGoalStatisticsQuery { child_id: None }) {
+            Ok(statistics) => self.settings.goal_statistics = Some(statistics),
+            Err(e) => {
+                warn!("🏁 Failed to load goal statistics: {}", e);
+                self.settings.goal_history_error = Some(format!("Could not load goal history: {}", e));
+            }
+        }
+    }
+}
@@ -0,0 +1,371 @@
+//! # Import Statement Modal
+//!
+//! This module contains the modal for importing transactions from a bank or
+//! card statement: a CSV, or an OFX or QIF export.
+//!
+//! ## Responsibilities:
+//! - Pick a statement file, guessing its format from the extension
+//! - For CSVs, map columns to date, description, amount and category, with
+//!   the date format and which sign means money spent
+//! - Preview every row with its problems, flagging rows already recorded,
+//!   and choose which rows to import
+//! - Map the statement's categories to the family's own
+//! - Import the chosen rows for the current child
+//!
+//! ## Purpose:
+//! Kids with a debit card already have their history in the card's export;
+//! this brings it in without typing each purchase again.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::import::{ImportStatementCommand, PreviewStatementCommand, SignConvention};
+use crate::ui::app_state::AllowanceTrackerApp;
+use crate::ui::components::settings::state::StatementFileKind;
+
+/// Delimiters offered for statements
+const DELIMITERS: [(u8, &str); 3] = [(b',', "Comma"), (b';', "Semicolon"), (b'\t', "Tab")];
+
+impl AllowanceTrackerApp {
+    /// Render the import statement modal
+    pub fn render_import_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_import_modal {
+            return;
+        }
+
+        let child_name = self.core.current_child.as_ref().map(|child| child.name.clone());
+        let mut choose_file = false;
+        let mut preview_clicked = false;
+        let mut import_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("📥 Import Statement")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let Some(child_name) = &child_name else {
+                    ui.label("Select a child first.");
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                    return;
+                };
+
+                ui.label(format!("Bring transactions from a bank or card statement into {}'s history.", child_name));
+                ui.add_space(8.0);
+
+                let amounts: Vec<String> = self.settings.import_form.preview.as_ref().map_or_else(Vec::new, |preview| {
+                    preview
+                        .rows
+                        .iter()
+                        .map(|row| row.amount.map(|amount| self.format_currency_amount(amount)).unwrap_or_default())
+                        .collect()
+                });
+                let form = &mut self.settings.import_form;
+                ui.horizontal(|ui| {
+                    if ui.button("Choose file…").clicked() {
+                        choose_file = true;
+                    }
+                    ui.label(form.file_path.as_deref().unwrap_or("No file chosen"));
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Format:");
+                    egui::ComboBox::from_id_salt("import_kind")
+                        .width(100.0)
+                        .selected_text(form.kind.label())
+                        .show_ui(ui, |ui| {
+                            for kind in StatementFileKind::ALL {
+                                ui.selectable_value(&mut form.kind, kind, kind.label());
+                            }
+                        });
+                });
+
+                if form.kind == StatementFileKind::Csv {
+                    ui.add_space(8.0);
+                    ui.label(egui::RichText::new("Columns").strong());
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut form.has_header_row, "First row is column names");
+                        ui.label("Separated by:");
+                        let selected = DELIMITERS
+                            .iter()
+                            .find(|(delimiter, _)| *delimiter == form.delimiter)
+                            .map_or("Other", |(_, name)| *name);
+                        egui::ComboBox::from_id_salt("import_delimiter")
+                            .width(100.0)
+                            .selected_text(selected)
+                            .show_ui(ui, |ui| {
+                                for (delimiter, name) in DELIMITERS {
+                                    ui.selectable_value(&mut form.delimiter, delimiter, name);
+                                }
+                            });
+                    });
+
+                    // Column names are only known once a preview has read the header row
+                    let headers = form.preview.as_ref().and_then(|preview| preview.headers.clone());
+                    egui::Grid::new("import_columns_grid").spacing([12.0, 4.0]).show(ui, |ui| {
+                        for (label, column) in [
+                            ("Date:", &mut form.date_column),
+                            ("Description:", &mut form.description_column),
+                            ("Amount:", &mut form.amount_column),
+                        ] {
+                            ui.label(label);
+                            match &headers {
+                                Some(headers) if !headers.is_empty() => {
+                                    let selected = headers
+                                        .get(*column)
+                                        .cloned()
+                                        .unwrap_or_else(|| format!("Column {}", *column + 1));
+                                    egui::ComboBox::from_id_salt(("import_column", label))
+                                        .width(160.0)
+                                        .selected_text(selected)
+                                        .show_ui(ui, |ui| {
+                                            for (index, header) in headers.iter().enumerate() {
+                                                ui.selectable_value(column, index, header);
+                                            }
+                                        });
+                                }
+                                _ => {
+                                    // Shown counted from 1, as a spreadsheet would
+                                    let mut number = *column + 1;
+                                    ui.add(egui::DragValue::new(&mut number).range(1..=99).prefix("Column "));
+                                    *column = number - 1;
+                                }
+                            }
+                            ui.end_row();
+                        }
+
+                        ui.label("Category:");
+                        let selected = match (form.category_column, &headers) {
+                            (None, _) => "None".to_string(),
+                            (Some(column), Some(headers)) => {
+                                headers.get(column).cloned().unwrap_or_else(|| format!("Column {}", column + 1))
+                            }
+                            (Some(column), None) => format!("Column {}", column + 1),
+                        };
+                        let choices = headers.clone().unwrap_or_else(|| (1..=10).map(|n| format!("Column {}", n)).collect());
+                        egui::ComboBox::from_id_salt("import_category_column")
+                            .width(160.0)
+                            .selected_text(selected)
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut form.category_column, None, "None");
+                                for (index, choice) in choices.iter().enumerate() {
+                                    ui.selectable_value(&mut form.category_column, Some(index), choice);
+                                }
+                            });
+                        ui.end_row();
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.label("Date format:");
+                        ui.add(egui::TextEdit::singleline(&mut form.date_format).desired_width(90.0));
+                        ui.label(egui::RichText::new("e.g. %m/%d/%Y or %Y-%m-%d").color(egui::Color32::from_rgb(120, 120, 120)));
+                    });
+                    ui.horizontal(|ui| {
+                        ui.radio_value(&mut form.sign_convention, SignConvention::NegativeIsSpending, "Negative amounts are spending");
+                        ui.radio_value(&mut form.sign_convention, SignConvention::PositiveIsSpending, "Positive amounts are spending");
+                    });
+                }
+
+                if let Some(preview) = &form.preview {
+                    ui.add_space(8.0);
+                    ui.separator();
+                    let valid = preview.rows.iter().filter(|row| row.is_valid()).count();
+                    let duplicates = preview.rows.iter().filter(|row| row.duplicate_of.is_some()).count();
+                    ui.label(format!(
+                        "{} rows, {} can be imported, {} already recorded",
+                        preview.rows.len(),
+                        valid,
+                        duplicates
+                    ));
+                    egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                        egui::Grid::new("import_preview_grid").striped(true).spacing([12.0, 4.0]).show(ui, |ui| {
+                            ui.label(egui::RichText::new("Import").strong());
+                            ui.label(egui::RichText::new("Line").strong());
+                            ui.label(egui::RichText::new("Date").strong());
+                            ui.label(egui::RichText::new("Description").strong());
+                            ui.label(egui::RichText::new("Amount").strong());
+                            ui.label(egui::RichText::new("Category").strong());
+                            ui.label("");
+                            ui.end_row();
+
+                            for (row, amount) in preview.rows.iter().zip(&amounts) {
+                                if row.is_valid() {
+                                    let mut accepted = form.accepted_lines.contains(&row.line);
+                                    if ui.checkbox(&mut accepted, "").changed() {
+                                        if accepted {
+                                            form.accepted_lines.insert(row.line);
+                                        } else {
+                                            form.accepted_lines.remove(&row.line);
+                                        }
+                                    }
+                                } else {
+                                    ui.label("");
+                                }
+                                ui.label(row.line.to_string());
+                                ui.label(row.date.map(|date| date.format("%b %d, %Y").to_string()).unwrap_or_default());
+                                ui.label(&row.description);
+                                ui.label(amount);
+                                ui.label(row.category.as_deref().unwrap_or(""));
+                                if row.duplicate_of.is_some() && row.is_valid() {
+                                    ui.colored_label(egui::Color32::from_rgb(200, 130, 0), "Already recorded");
+                                } else if row.is_valid() {
+                                    ui.label("");
+                                } else {
+                                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), row.errors.join("; "));
+                                }
+                                ui.end_row();
+                            }
+                        });
+                    });
+
+                    // Categories the statement names, recorded as typed here;
+                    // leave one empty to record no category
+                    if !preview.categories.is_empty() {
+                        ui.add_space(8.0);
+                        ui.label(egui::RichText::new("Categories").strong());
+                        egui::Grid::new("import_categories_grid").spacing([12.0, 4.0]).show(ui, |ui| {
+                            for category in &preview.categories {
+                                ui.label(format!("{} →", category));
+                                let mapped = form.category_mapping.entry(category.clone()).or_insert_with(|| category.clone());
+                                ui.add(egui::TextEdit::singleline(mapped).desired_width(140.0));
+                                if !preview.known_categories.is_empty() {
+                                    egui::ComboBox::from_id_salt(("import_known_category", category))
+                                        .width(120.0)
+                                        .selected_text("Existing…")
+                                        .show_ui(ui, |ui| {
+                                            for known in &preview.known_categories {
+                                                if ui.selectable_label(*mapped == *known, known).clicked() {
+                                                    *mapped = known.clone();
+                                                }
+                                            }
+                                        });
+                                }
+                                ui.end_row();
+                            }
+                        });
+                    }
+                }
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(form.content.is_some(), egui::Button::new("Preview")).clicked() {
+                        preview_clicked = true;
+                    }
+                    let count = form.accepted_lines.len();
+                    let can_import = form.preview.is_some() && count > 0;
+                    if ui.add_enabled(can_import, egui::Button::new(format!("Import {} rows", count))).clicked() {
+                        import_clicked = true;
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if choose_file {
+            self.choose_statement_file();
+        }
+        if preview_clicked {
+            self.preview_statement();
+        }
+        if import_clicked {
+            self.import_statement();
+        }
+        if close {
+            self.settings.show_import_modal = false;
+        }
+    }
+
+    /// Ask for a statement file and read it
+    fn choose_statement_file(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_title("Choose a Statement")
+            .add_filter("Statements", &["csv", "ofx", "qfx", "qif"])
+            .add_filter("All Files", &["*"])
+            .pick_file()
+        else {
+            return;
+        };
+        let form = &mut self.settings.import_form;
+        match std::fs::read_to_string(&path) {
+            Ok(content) => {
+                form.file_path = Some(path.to_string_lossy().to_string());
+                form.content = Some(content);
+                form.kind = StatementFileKind::from_path(&path);
+                form.preview = None;
+                form.accepted_lines.clear();
+                form.category_mapping.clear();
+                form.error = None;
+            }
+            Err(e) => {
+                warn!("📥 Failed to read statement {}: {}", path.display(), e);
+                form.error = Some(format!("Could not read {}: {}", path.display(), e));
+            }
+        }
+    }
+
+    /// Parse the chosen statement with the current settings
+    fn preview_statement(&mut self) {
+        let Some(content) = self.settings.import_form.content.clone() else {
+            return;
+        };
+        let command = PreviewStatementCommand { content, format: self.settings.import_form.format() };
+        match self.backend().import_service.preview_statement(command) {
+            Ok(preview) => {
+                let form = &mut self.settings.import_form;
+                // Every new row that can be imported starts out chosen
+                form.accepted_lines = preview
+                    .rows
+                    .iter()
+                    .filter(|row| row.is_valid() && row.duplicate_of.is_none())
+                    .map(|row| row.line)
+                    .collect();
+                form.preview = Some(preview);
+                form.error = None;
+            }
+            Err(e) => {
+                warn!("📥 Failed to preview statement: {}", e);
+                self.settings.import_form.preview = None;
+                self.settings.import_form.accepted_lines.clear();
+                self.settings.import_form.error = Some(format!("Could not read the statement: {}", e));
+            }
+        }
+    }
+
+    /// Import the chosen rows for the current child
+    fn import_statement(&mut self) {
+        let Some(content) = self.settings.import_form.content.clone() else {
+            return;
+        };
+        let command = ImportStatementCommand {
+            content,
+            format: self.settings.import_form.format(),
+            accepted_lines: self.settings.import_form.accepted_lines.iter().copied().collect(),
+            category_mapping: self.settings.import_form.category_mapping.clone(),
+            actor: self.parent_actor(),
+        };
+        match self.backend().import_service.import_statement(command) {
+            Ok(result) => {
+                info!("📥 Imported {} rows, skipped {}", result.imported.len(), result.skipped.len());
+                let message = if result.skipped.is_empty() {
+                    format!("Imported {} transactions", result.imported.len())
+                } else {
+                    format!("Imported {} transactions, skipped {}", result.imported.len(), result.skipped.len())
+                };
+                self.ui.set_success(message);
+                self.settings.import_form.clear();
+                self.settings.show_import_modal = false;
+                self.refresh_all_data_for_current_child();
+            }
+            Err(e) => {
+                warn!("📥 Failed to import statement: {}", e);
+                self.settings.import_form.error = Some(format!("Could not import: {}", e));
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! # Loans Modal
+//!
+//! This module contains the modal for money fronted between a parent and the
+//! current child.
+//!
+//! ## Responsibilities:
+//! - Show what the child owes their parents, and what the parents owe them
+//! - List open loans with how much of each is still owed
+//! - Settle a loan in full, recording the repayment as a transaction
+//! - Record a new loan in either direction
+//!
+//! ## Purpose:
+//! Keeps "I'll pay you back later" visible until it is actually paid back.
+//! Every loan and repayment is an ordinary transaction, so the balance,
+//! calendar and charts stay correct.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::loans::{CreateLoanCommand, OutstandingLoansQuery, SettleLoanCommand};
+use crate::backend::domain::models::loan::LoanDirection;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the loans modal
+    pub fn render_loans_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_loans_modal {
+            return;
+        }
+
+        let child_name = self.core.current_child.as_ref().map(|child| child.name.clone());
+        // A failed load leaves an error in the form instead of retrying every frame
+        if child_name.is_some() && self.settings.loans.is_none() && self.settings.loan_form.error.is_none() {
+            self.load_loans();
+        }
+
+        let mut settle_loan_id: Option<String> = None;
+        let mut add_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("🤝 Loans & IOUs")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let Some(child_name) = &child_name else {
+                    ui.label("Select a child first.");
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                    return;
+                };
+
+                if let Some(loans) = &self.settings.loans {
+                    if loans.owed_by_child > 0.0 {
+                        ui.label(format!("{} owes you {}", child_name, self.format_currency_amount(loans.owed_by_child)));
+                    }
+                    if loans.owed_to_child > 0.0 {
+                        ui.label(format!("You owe {} {}", child_name, self.format_currency_amount(loans.owed_to_child)));
+                    }
+                    if loans.open_loans.is_empty() {
+                        ui.label(egui::RichText::new("Nothing owed either way.")
+                            .color(egui::Color32::from_rgb(120, 120, 120)));
+                    } else {
+                        ui.add_space(8.0);
+                        egui::Grid::new("open_loans_grid").striped(true).spacing([12.0, 6.0]).show(ui, |ui| {
+                            for loan in &loans.open_loans {
+                                ui.label(loan.date.format("%b %-d, %Y").to_string());
+                                ui.label(&loan.description);
+                                ui.label(match loan.direction {
+                                    LoanDirection::ChildOwesParent => "owes you",
+                                    LoanDirection::ParentOwesChild => "you owe",
+                                });
+                                ui.label(format!(
+                                    "{} of {}",
+                                    self.format_currency_amount(loan.outstanding()),
+                                    self.format_currency_amount(loan.amount)
+                                ));
+                                if ui.small_button("Settle").clicked() {
+                                    settle_loan_id = Some(loan.id.clone());
+                                }
+                                ui.end_row();
+                            }
+                        });
+                    }
+                }
+
+                ui.add_space(12.0);
+                ui.separator();
+                ui.label(egui::RichText::new("Record a new loan").strong());
+                let form = &mut self.settings.loan_form;
+                ui.radio_value(&mut form.direction, LoanDirection::ChildOwesParent, format!("I paid for {}", child_name));
+                ui.radio_value(&mut form.direction, LoanDirection::ParentOwesChild, format!("{} paid for me", child_name));
+                ui.horizontal(|ui| {
+                    ui.label("For:");
+                    ui.add(egui::TextEdit::singleline(&mut form.description).hint_text("Video game").desired_width(160.0));
+                    ui.label("Amount:");
+                    ui.add(egui::TextEdit::singleline(&mut form.amount).hint_text("20.00").desired_width(70.0));
+                });
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Add loan").clicked() {
+                        add_clicked = true;
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if let Some(loan_id) = settle_loan_id {
+            self.settle_loan(loan_id);
+        }
+        if add_clicked {
+            self.create_loan();
+        }
+        if close {
+            self.settings.show_loans_modal = false;
+        }
+    }
+
+    /// Load open loans for the current child
+    fn load_loans(&mut self) {
+        match self.backend().loan_service.get_outstanding(OutstandingLoansQuery { child_id: None }) {
+            Ok(loans) => self.settings.loans = Some(loans),
+            Err(e) => {
+                warn!("🤝 Failed to load loans: {}", e);
+                self.settings.loan_form.error = Some(format!("Could not load loans: {}", e));
+            }
+        }
+    }
+
+    /// Pay back everything still owed on a loan
+    fn settle_loan(&mut self, loan_id: String) {
+        let command = SettleLoanCommand {
+            loan_id,
+            amount: None,
+            date: None,
+            parent_override: false,
+            // The settings menu is behind the parental challenge
+            actor: self.parent_actor(),
+        };
+        match self.backend().loan_service.settle_loan(command) {
+            Ok(result) => {
+                info!("🤝 Settled loan {}", result.loan.id);
+                self.ui.set_success(format!("Settled \"{}\"", result.loan.description));
+                self.after_loan_change();
+            }
+            Err(e) => {
+                warn!("🤝 Failed to settle loan: {}", e);
+                self.settings.loan_form.error = Some(format!("Could not settle: {}", e));
+            }
+        }
+    }
+
+    /// Record the loan entered in the form
+    fn create_loan(&mut self) {
+        let amount = match self.clean_and_parse_amount(&self.settings.loan_form.amount) {
+            Ok(amount) => amount,
+            Err(e) => {
+                self.settings.loan_form.error = Some(e);
+                return;
+            }
+        };
+        let command = CreateLoanCommand {
+            direction: self.settings.loan_form.direction,
+            description: self.settings.loan_form.description.clone(),
+            amount,
+            date: None,
+            parent_override: false,
+            // The settings menu is behind the parental challenge
+            actor: self.parent_actor(),
+        };
+        match self.backend().loan_service.create_loan(command) {
+            Ok(result) => {
+                info!("🤝 Recorded loan {}", result.loan.id);
+                self.ui.set_success(format!("Recorded \"{}\"", result.loan.description));
+                self.settings.loan_form.clear();
+                self.after_loan_change();
+            }
+            Err(e) => {
+                warn!("🤝 Failed to record loan: {}", e);
+                self.settings.loan_form.error = Some(format!("Could not record loan: {}", e));
+            }
+        }
+    }
+
+    /// Reload loans and the transactions they created
+    fn after_loan_change(&mut self) {
+        self.settings.loan_form.error = None;
+        self.settings.loans = None;
+        self.refresh_all_data_for_current_child();
+    }
+}
@@ -14,6 +14,27 @@
 //! - `create_child_modal.rs` - Complete create child flow with backend integration
 //! - `profile_modal.rs` - Profile editing functionality (moved from modals/)
 //! - `export_modal.rs` - Data export functionality with default/custom location options
+//! - `text_size_modal.rs` - App-wide text size and the calendar's weekly totals
+//! - `archive_modal.rs` - Roll up old months into summary transactions
+//! - `loans_modal.rs` - Open loans between a parent and the child, and repayments
+//! - `planned_purchases_modal.rs` - Reserve money for a purchase, then confirm or cancel it
+//! - `parents_modal.rs` - Named parents with their own PINs and permissions
+//! - `reset_modal.rs` - Archive and erase all data after a typed confirmation
+//! - `goal_history_modal.rs` - How past goals went, with completion statistics
+//! - `advance_modal.rs` - Pay allowance early and repay it from upcoming allowances
+//! - `quick_picks_modal.rs` - Per-child descriptions offered in the money forms
+//! - `templates_modal.rs` - Per-child saved transactions added with one tap
+//! - `import_modal.rs` - Bank or card statement CSV import with a column mapping
+//! - `categorize_modal.rs` - Give many past transactions a category at once, with a preview and undo
+//! - `retention_modal.rs` - How long logs and archives are kept, with a dry-run preview
+//! - `budget_modal.rs` - Monthly spending targets per category and this month's spending
+//! - `report_schedule_modal.rs` - Folder monthly reports are written to, and the files written
+//! - `feature_flags_modal.rs` - Turn experimental features on or off for this data directory
+//! - `agreement_modal.rs` - Printable allowance agreement with chores, rules and signature lines
+//! - `close_period_modal.rs` - Lock a reviewed month's transactions, or reopen them
+//! - `fridge_view_modal.rs` - Read-only HTML page of balances and goals for a kitchen tablet
+//! - `spending_rules_modal.rs` - The child's "no negative balance" rule
+//! - `encryption_modal.rs` - Encrypt the child's data files with the parental passphrase, or decrypt them
 //! - `shared.rs` - Common styling, validation helpers, and modal utilities
 //!
 //! ## Design Principles:
@@ -29,6 +50,29 @@ pub mod profile_modal; // Added in Phase 3
 pub mod export_modal; // Added in Phase 2 - Export data functionality
 pub mod data_directory_modal; // Data directory management functionality
 pub mod allowance_config_modal; // Allowance configuration functionality
+pub mod text_size_modal; // Large-text accessibility setting
+pub mod archive_modal; // Roll up old history into monthly summaries
+pub mod loans_modal; // Money fronted between parent and child
+pub mod planned_purchases_modal; // Money reserved for future purchases
+pub mod parents_modal; // Named parents who sign in with a PIN
+pub mod reset_modal; // Start over with an empty data folder
+pub mod goal_history_modal; // Completion rate and time to goal
+pub mod advance_modal; // Allowance paid early
+pub mod quick_picks_modal; // Saved descriptions for the money forms
+pub mod templates_modal; // Saved transactions ("favorites")
+pub mod import_modal; // Statement import
+pub mod categorize_modal; // Bulk category changes
+pub mod retention_modal; // Cleanup of old logs and archives
+pub mod budget_modal; // Monthly spending targets
+pub mod report_schedule_modal; // Monthly reports written to a folder
+pub mod feature_flags_modal; // Experimental features
+pub mod agreement_modal; // Printable allowance agreement
+pub mod close_period_modal; // Locking reviewed months
+pub mod updates_modal; // Release channel and update check
+pub mod fridge_view_modal; // Read-only HTML page for a kitchen tablet
+pub mod background_modal; // Theme pack or picture behind each child's views
+pub mod spending_rules_modal; // No negative balance rule
+pub mod encryption_modal; // At-rest encryption of the child's data
 pub mod shared;
 
 pub use state::*;
@@ -0,0 +1,165 @@
+//! # Parents & PINs Modal
+//!
+//! This module contains the modal for named parents who can pass the parental
+//! challenge with their own PIN.
+//!
+//! ## Responsibilities:
+//! - List named parents with what each of them is allowed to do
+//! - Add a parent with a name, a PIN and a set of permissions
+//! - Remove a parent
+//!
+//! ## Purpose:
+//! Lets each parent or guardian sign in as themselves, so changes they make
+//! are attributed to them, and lets a family limit what some of them can do.
+//! The family answer keeps working and still allows everything.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::parental_control::{AddParentCommand, RemoveParentCommand};
+use crate::backend::domain::models::parent_user::ParentPermission;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the parents & PINs modal
+    pub fn render_parents_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_parents_modal {
+            return;
+        }
+
+        // A failed load leaves an error in the form instead of retrying every frame
+        if self.settings.parents.is_none() && self.settings.parent_form.error.is_none() {
+            self.load_parents();
+        }
+
+        let mut remove_parent_id: Option<String> = None;
+        let mut add_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("👪 Parents & PINs")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.label("Each parent can answer the challenge with their own PIN.");
+
+                match &self.settings.parents {
+                    Some(parents) if !parents.is_empty() => {
+                        ui.add_space(8.0);
+                        egui::Grid::new("parents_grid").striped(true).spacing([12.0, 6.0]).show(ui, |ui| {
+                            for parent in parents {
+                                ui.label(egui::RichText::new(&parent.name).strong());
+                                let allowed: Vec<&str> = parent.permissions.iter().map(|p| p.label()).collect();
+                                ui.label(if allowed.is_empty() { "Nothing yet".to_string() } else { allowed.join(", ") });
+                                if ui.small_button("Remove").clicked() {
+                                    remove_parent_id = Some(parent.id.clone());
+                                }
+                                ui.end_row();
+                            }
+                        });
+                    }
+                    _ => {
+                        ui.label(egui::RichText::new("No named parents yet.")
+                            .color(egui::Color32::from_rgb(120, 120, 120)));
+                    }
+                }
+
+                ui.add_space(12.0);
+                ui.separator();
+                ui.label(egui::RichText::new("Add a parent").strong());
+                let form = &mut self.settings.parent_form;
+                ui.horizontal(|ui| {
+                    ui.label("Name:");
+                    ui.add(egui::TextEdit::singleline(&mut form.name).hint_text("Grandma").desired_width(120.0));
+                    ui.label("PIN:");
+                    ui.add(egui::TextEdit::singleline(&mut form.pin).password(true).hint_text("4-8 digits").desired_width(80.0));
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Can:");
+                    for permission in ParentPermission::ALL {
+                        let mut allowed = form.permissions.contains(&permission);
+                        if ui.checkbox(&mut allowed, permission.label()).changed() {
+                            if allowed {
+                                form.permissions.push(permission);
+                            } else {
+                                form.permissions.retain(|p| *p != permission);
+                            }
+                        }
+                    }
+                });
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Add parent").clicked() {
+                        add_clicked = true;
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if let Some(parent_id) = remove_parent_id {
+            self.remove_parent(parent_id);
+        }
+        if add_clicked {
+            self.add_parent();
+        }
+        if close {
+            self.settings.show_parents_modal = false;
+            // Don't keep a typed PIN around after the modal closes
+            self.settings.parent_form.clear();
+        }
+    }
+
+    /// Load named parents into settings state
+    fn load_parents(&mut self) {
+        match self.backend().parental_control_service.list_parents() {
+            Ok(parents) => self.settings.parents = Some(parents),
+            Err(e) => {
+                warn!("👪 Failed to load parents: {}", e);
+                self.settings.parent_form.error = Some(format!("Could not load parents: {}", e));
+            }
+        }
+    }
+
+    /// Add the parent entered in the form
+    fn add_parent(&mut self) {
+        let form = &self.settings.parent_form;
+        let command = AddParentCommand {
+            name: form.name.clone(),
+            pin: form.pin.clone(),
+            permissions: form.permissions.clone(),
+        };
+        match self.backend().parental_control_service.add_parent(command) {
+            Ok(parent) => {
+                info!("👪 Added parent {}", parent.id);
+                self.ui.set_success(format!("{} can now use their PIN", parent.name));
+                self.settings.parent_form.clear();
+                self.settings.parents = None;
+            }
+            Err(e) => {
+                warn!("👪 Failed to add parent: {}", e);
+                self.settings.parent_form.error = Some(format!("Could not add parent: {}", e));
+            }
+        }
+    }
+
+    /// Remove a named parent and their PIN
+    fn remove_parent(&mut self, parent_id: String) {
+        match self.backend().parental_control_service.remove_parent(RemoveParentCommand { parent_id }) {
+            Ok(()) => {
+                self.settings.parent_form.error = None;
+                self.settings.parents = None;
+            }
+            Err(e) => {
+                warn!("👪 Failed to remove parent: {}", e);
+                self.settings.parent_form.error = Some(format!("Could not remove parent: {}", e));
+            }
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! # Planned Purchases Modal
+//!
+//! This module contains the modal for purchases the current child plans to make.
+//!
+//! ## Responsibilities:
+//! - List planned purchases with the money reserved for each
+//! - Confirm a purchase, recording it as an expense
+//! - Cancel a purchase, releasing its money
+//! - Plan a new purchase for today or a later day
+//!
+//! ## Purpose:
+//! Lets a child set money aside for something specific. Reserved money stays
+//! in the balance but is shown as unavailable in the header, and each plan
+//! appears on the calendar on its day.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::planned_purchases::{
+    CancelPlannedPurchaseCommand, ConfirmPlannedPurchaseCommand, CreatePlannedPurchaseCommand,
+};
+use crate::ui::app_state::AllowanceTrackerApp;
+
+/// What a button in the purchase list asked for
+enum PurchaseAction {
+    Confirm(String),
+    Cancel(String),
+}
+
+impl AllowanceTrackerApp {
+    /// Render the planned purchases modal
+    pub fn render_planned_purchases_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_planned_purchases_modal {
+            return;
+        }
+
+        let child_name = self.core.current_child.as_ref().map(|child| child.name.clone());
+        let mut action: Option<PurchaseAction> = None;
+        let mut plan_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("🛍️ Planned Purchases")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let Some(child_name) = &child_name else {
+                    ui.label("Select a child first.");
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                    return;
+                };
+
+                let reserved = self.reserved_amount();
+                ui.label(format!(
+                    "{} has {} available ({} reserved).",
+                    child_name,
+                    self.format_currency_amount(self.current_balance() - reserved),
+                    self.format_currency_amount(reserved)
+                ));
+
+                if self.calendar.planned_purchases.is_empty() {
+                    ui.label(egui::RichText::new("Nothing planned yet.")
+                        .color(egui::Color32::from_rgb(120, 120, 120)));
+                } else {
+                    ui.add_space(8.0);
+                    egui::Grid::new("planned_purchases_grid").striped(true).spacing([12.0, 6.0]).show(ui, |ui| {
+                        for purchase in &self.calendar.planned_purchases {
+                            ui.label(purchase.planned_date.format("%b %-d, %Y").to_string());
+                            ui.label(&purchase.description);
+                            ui.label(self.format_currency_amount(purchase.amount));
+                            if ui.small_button("Bought it").clicked() {
+                                action = Some(PurchaseAction::Confirm(purchase.id.clone()));
+                            }
+                            if ui.small_button("Cancel").clicked() {
+                                action = Some(PurchaseAction::Cancel(purchase.id.clone()));
+                            }
+                            ui.end_row();
+                        }
+                    });
+                }
+
+                ui.add_space(12.0);
+                ui.separator();
+                ui.label(egui::RichText::new("Plan a purchase").strong());
+                let form = &mut self.settings.planned_purchase_form;
+                ui.horizontal(|ui| {
+                    ui.label("What:");
+                    ui.add(egui::TextEdit::singleline(&mut form.description).hint_text("Lego set").desired_width(140.0));
+                    ui.label("Amount:");
+                    ui.add(egui::TextEdit::singleline(&mut form.amount).hint_text("24.99").desired_width(60.0));
+                    ui.label("On:");
+                    ui.add(egui::TextEdit::singleline(&mut form.planned_date).hint_text("YYYY-MM-DD").desired_width(90.0));
+                });
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Reserve money").clicked() {
+                        plan_clicked = true;
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        match action {
+            Some(PurchaseAction::Confirm(purchase_id)) => self.confirm_planned_purchase(purchase_id),
+            Some(PurchaseAction::Cancel(purchase_id)) => self.cancel_planned_purchase(purchase_id),
+            None => {}
+        }
+        if plan_clicked {
+            self.create_planned_purchase();
+        }
+        if close {
+            self.settings.show_planned_purchases_modal = false;
+        }
+    }
+
+    /// Reserve money for the purchase entered in the form
+    fn create_planned_purchase(&mut self) {
+        let amount = match self.clean_and_parse_amount(&self.settings.planned_purchase_form.amount) {
+            Ok(amount) => amount,
+            Err(e) => {
+                self.settings.planned_purchase_form.error = Some(e);
+                return;
+            }
+        };
+        let planned_date = match chrono::NaiveDate::parse_from_str(self.settings.planned_purchase_form.planned_date.trim(), "%Y-%m-%d") {
+            Ok(date) => date,
+            Err(_) => {
+                self.settings.planned_purchase_form.error = Some("Date must be in YYYY-MM-DD format".to_string());
+                return;
+            }
+        };
+        let command = CreatePlannedPurchaseCommand {
+            description: self.settings.planned_purchase_form.description.clone(),
+            amount,
+            planned_date,
+        };
+        match self.backend().planned_purchase_service.create_planned_purchase(command) {
+            Ok(purchase) => {
+                info!("🛍️ Planned {}", purchase.id);
+                self.ui.set_success(format!("Reserved {} for \"{}\"", self.format_currency_amount(purchase.amount), purchase.description));
+                self.settings.planned_purchase_form.clear();
+                self.load_planned_purchases();
+            }
+            Err(e) => {
+                warn!("🛍️ Failed to plan purchase: {}", e);
+                self.settings.planned_purchase_form.error = Some(format!("Could not plan purchase: {}", e));
+            }
+        }
+    }
+
+    /// Record a planned purchase as an expense
+    fn confirm_planned_purchase(&mut self, purchase_id: String) {
+        let command = ConfirmPlannedPurchaseCommand {
+            purchase_id,
+            amount: None,
+            date: None,
+            actor: self.parent_actor(),
+        };
+        match self.backend().planned_purchase_service.confirm_planned_purchase(command) {
+            Ok(result) => {
+                info!("🛍️ Confirmed {} as {}", result.purchase.id, result.transaction.id);
+                self.ui.set_success(format!("Recorded \"{}\"", result.purchase.description));
+                self.settings.planned_purchase_form.error = None;
+                self.refresh_all_data_for_current_child();
+            }
+            Err(e) => {
+                warn!("🛍️ Failed to confirm planned purchase: {}", e);
+                self.settings.planned_purchase_form.error = Some(format!("Could not record purchase: {}", e));
+            }
+        }
+    }
+
+    /// Drop a planned purchase and release its money
+    fn cancel_planned_purchase(&mut self, purchase_id: String) {
+        match self.backend().planned_purchase_service.cancel_planned_purchase(CancelPlannedPurchaseCommand { purchase_id }) {
+            Ok(purchase) => {
+                info!("🛍️ Cancelled {}", purchase.id);
+                self.ui.set_success(format!("Released {} from \"{}\"", self.format_currency_amount(purchase.amount), purchase.description));
+                self.settings.planned_purchase_form.error = None;
+                self.load_planned_purchases();
+            }
+            Err(e) => {
+                warn!("🛍️ Failed to cancel planned purchase: {}", e);
+                self.settings.planned_purchase_form.error = Some(format!("Could not cancel: {}", e));
+            }
+        }
+    }
+}
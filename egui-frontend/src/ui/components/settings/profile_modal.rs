@@ -17,6 +17,7 @@
 use eframe::egui;
 use chrono::{NaiveDate, Datelike, Local};
 use crate::ui::app_state::AllowanceTrackerApp;
+use crate::ui::components::styling::Theme;
 
 impl AllowanceTrackerApp {
     /// Render the profile modal
@@ -44,7 +45,7 @@ impl AllowanceTrackerApp {
                 ui.allocate_new_ui(egui::UiBuilder::new().max_rect(screen_rect), |ui| {
                     ui.centered_and_justified(|ui| {
                         egui::Frame::window(&ui.style())
-                            .fill(egui::Color32::WHITE)
+                            .fill(Theme::current(ui.ctx()).layout.modal_background)
                             .stroke(egui::Stroke::new(3.0, egui::Color32::from_rgb(70, 130, 180))) // Steel blue for profile
                                                             .corner_radius(egui::CornerRadius::same(15))
                             .inner_margin(egui::Margin::same(25))
@@ -109,7 +110,7 @@ impl AllowanceTrackerApp {
             ui.label(egui::RichText::new("Name")
                 .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
                 .strong()
-                .color(egui::Color32::from_rgb(60, 60, 60)));
+                .color(Theme::current(ui.ctx()).typography.primary));
             
             ui.add_space(5.0);
             
@@ -137,7 +138,7 @@ impl AllowanceTrackerApp {
             ui.label(egui::RichText::new("Birthdate")
                 .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
                 .strong()
-                .color(egui::Color32::from_rgb(60, 60, 60)));
+                .color(Theme::current(ui.ctx()).typography.primary));
             
             ui.add_space(5.0);
             
@@ -251,7 +252,7 @@ impl AllowanceTrackerApp {
             let cancel_button = egui::Button::new(egui::RichText::new("Cancel")
                     .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
                     .color(egui::Color32::from_rgb(100, 100, 100)))
-                .fill(egui::Color32::from_rgb(245, 245, 245))
+                .fill(Theme::current(ui.ctx()).layout.subtle_background)
                 .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 200, 200)))
                 .corner_radius(egui::CornerRadius::same(10))
                 .min_size(egui::vec2(button_width, 40.0));
@@ -390,6 +391,7 @@ impl AllowanceTrackerApp {
             child_id,
             name: Some(self.settings.profile_form.name.trim().to_string()),
             birthdate: Some(self.settings.profile_form.birthdate.clone()),
+            expected_updated_at: self.settings.profile_form.loaded_updated_at.clone(),
         };
         
         // Call backend service
@@ -399,6 +401,7 @@ impl AllowanceTrackerApp {
                 
                 // Update current child data
                 self.core.current_child = Some(crate::ui::mappers::to_dto(result.child));
+                self.request_children();
                 
                 // Success feedback
                 self.close_profile_modal();
@@ -0,0 +1,176 @@
+//! # Quick Picks Modal
+//!
+//! This module contains the modal for the current child's quick-pick
+//! descriptions.
+//!
+//! ## Responsibilities:
+//! - List the child's quick picks for adding and for spending money
+//! - Add a quick pick, optionally with the amount it usually is
+//! - Remove a quick pick
+//!
+//! ## Purpose:
+//! Saves typing the same descriptions every week. The money forms offer these
+//! as chips; until a child has any, the built-in suggestions are shown.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::quick_picks::{AddQuickPickCommand, ListQuickPicksQuery, RemoveQuickPickCommand};
+use crate::backend::domain::models::quick_pick::QuickPickKind;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the quick picks modal
+    pub fn render_quick_picks_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_quick_picks_modal {
+            return;
+        }
+
+        let child_name = self.core.current_child.as_ref().map(|child| child.name.clone());
+        // A failed load leaves an error in the form instead of retrying every frame
+        if child_name.is_some() && self.settings.quick_picks.is_none() && self.settings.quick_pick_form.error.is_none() {
+            self.load_quick_picks();
+        }
+
+        let mut remove_id: Option<String> = None;
+        let mut add_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("⚡ Quick Picks")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let Some(child_name) = &child_name else {
+                    ui.label("Select a child first.");
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                    return;
+                };
+
+                ui.label(format!("Descriptions offered when adding or spending {}'s money.", child_name));
+
+                if let Some(quick_picks) = &self.settings.quick_picks {
+                    for (kind, heading) in [(QuickPickKind::Income, "Adding money"), (QuickPickKind::Expense, "Spending money")] {
+                        ui.add_space(8.0);
+                        ui.label(egui::RichText::new(heading).strong());
+                        let of_kind: Vec<_> = quick_picks.iter().filter(|q| q.kind == kind).collect();
+                        if of_kind.is_empty() {
+                            ui.label(egui::RichText::new("None yet, so the built-in suggestions are shown.")
+                                .color(egui::Color32::from_rgb(120, 120, 120)));
+                            continue;
+                        }
+                        egui::Grid::new(("quick_picks_grid", heading)).striped(true).spacing([12.0, 4.0]).show(ui, |ui| {
+                            for quick_pick in of_kind {
+                                ui.label(&quick_pick.description);
+                                ui.label(quick_pick.amount.map(|amount| self.format_currency_amount(amount)).unwrap_or_default());
+                                if ui.small_button("Remove").clicked() {
+                                    remove_id = Some(quick_pick.id.clone());
+                                }
+                                ui.end_row();
+                            }
+                        });
+                    }
+                }
+
+                ui.add_space(12.0);
+                ui.separator();
+                ui.label(egui::RichText::new("Add a quick pick").strong());
+                let form = &mut self.settings.quick_pick_form;
+                ui.horizontal(|ui| {
+                    ui.radio_value(&mut form.kind, QuickPickKind::Income, "Adding money");
+                    ui.radio_value(&mut form.kind, QuickPickKind::Expense, "Spending money");
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Description:");
+                    ui.add(egui::TextEdit::singleline(&mut form.description).hint_text("Lunch money").desired_width(160.0));
+                    ui.label("Amount:");
+                    ui.add(egui::TextEdit::singleline(&mut form.amount).hint_text("optional").desired_width(70.0));
+                });
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Add quick pick").clicked() {
+                        add_clicked = true;
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if let Some(quick_pick_id) = remove_id {
+            self.remove_quick_pick(quick_pick_id);
+        }
+        if add_clicked {
+            self.add_quick_pick();
+        }
+        if close {
+            self.settings.show_quick_picks_modal = false;
+        }
+    }
+
+    /// Load the current child's quick picks
+    fn load_quick_picks(&mut self) {
+        match self.backend().quick_pick_service.list_quick_picks(ListQuickPicksQuery { child_id: None }) {
+            Ok(result) => self.settings.quick_picks = Some(result.quick_picks),
+            Err(e) => {
+                warn!("⚡ Failed to load quick picks: {}", e);
+                self.settings.quick_pick_form.error = Some(format!("Could not load quick picks: {}", e));
+            }
+        }
+    }
+
+    /// Save the quick pick entered in the form
+    fn add_quick_pick(&mut self) {
+        let amount = if self.settings.quick_pick_form.amount.trim().is_empty() {
+            None
+        } else {
+            match self.clean_and_parse_amount(&self.settings.quick_pick_form.amount) {
+                Ok(amount) => Some(amount),
+                Err(e) => {
+                    self.settings.quick_pick_form.error = Some(e);
+                    return;
+                }
+            }
+        };
+        let command = AddQuickPickCommand {
+            description: self.settings.quick_pick_form.description.clone(),
+            amount,
+            kind: self.settings.quick_pick_form.kind,
+        };
+        match self.backend().quick_pick_service.add_quick_pick(command) {
+            Ok(quick_pick) => {
+                info!("⚡ Added quick pick {}", quick_pick.id);
+                // Keep the kind selected for adding several in a row
+                let kind = quick_pick.kind;
+                self.settings.quick_pick_form.clear();
+                self.settings.quick_pick_form.kind = kind;
+                self.settings.quick_picks = None;
+            }
+            Err(e) => {
+                warn!("⚡ Failed to add quick pick: {}", e);
+                self.settings.quick_pick_form.error = Some(format!("Could not add quick pick: {}", e));
+            }
+        }
+    }
+
+    /// Remove one of the current child's quick picks
+    fn remove_quick_pick(&mut self, quick_pick_id: String) {
+        match self.backend().quick_pick_service.remove_quick_pick(RemoveQuickPickCommand { quick_pick_id }) {
+            Ok(()) => {
+                self.settings.quick_pick_form.error = None;
+                self.settings.quick_picks = None;
+            }
+            Err(e) => {
+                warn!("⚡ Failed to remove quick pick: {}", e);
+                self.settings.quick_pick_form.error = Some(format!("Could not remove quick pick: {}", e));
+            }
+        }
+    }
+}
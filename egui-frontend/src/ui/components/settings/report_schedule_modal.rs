@@ -0,0 +1,152 @@
+//! # Monthly Reports Modal
+//!
+//! This module contains the modal for writing each child's monthly report to
+//! a folder.
+//!
+//! ## Responsibilities:
+//! - Choose the folder reports are written to, or stop writing them
+//! - Write last month's reports right away
+//! - List the report files written so far
+//!
+//! ## Purpose:
+//! Keeps a statement per child per month outside the app, for a parent's
+//! records. Reports are written when a new month starts while the app is open.
+
+use eframe::egui;
+use chrono::{Datelike, Local, Months};
+use log::{info, warn};
+use crate::backend::domain::commands::scheduled_reports::{GenerateMonthlyReportsCommand, UpdateReportScheduleCommand};
+use crate::ui::app_state::AllowanceTrackerApp;
+
+/// Most history entries listed in the modal
+const HISTORY_ROWS_SHOWN: usize = 24;
+
+impl AllowanceTrackerApp {
+    /// Render the monthly reports modal
+    pub fn render_report_schedule_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_report_schedule_modal {
+            return;
+        }
+
+        let mut browse_clicked = false;
+        let mut save_clicked = false;
+        let mut turn_off_clicked = false;
+        let mut generate_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("🗓️ Monthly Reports")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let form = &mut self.settings.report_schedule_form;
+                ui.label("Write each child's report for the month to a folder as a CSV file when a new month starts.");
+                ui.add_space(10.0);
+
+                ui.horizontal(|ui| {
+                    ui.label("Folder:");
+                    ui.add(egui::TextEdit::singleline(&mut form.folder).hint_text("Choose a folder").desired_width(280.0));
+                    if ui.button("Browse…").clicked() {
+                        browse_clicked = true;
+                    }
+                });
+
+                let saved_folder = form.schedule.as_ref().and_then(|schedule| schedule.folder.clone());
+                ui.label(egui::RichText::new(match &saved_folder {
+                    Some(_) => "Reports are on.",
+                    None => "Reports are off.",
+                })
+                .color(egui::Color32::from_rgb(120, 120, 120)));
+
+                if let Some(schedule) = &form.schedule {
+                    if !schedule.history.is_empty() {
+                        ui.add_space(10.0);
+                        ui.separator();
+                        ui.label(egui::RichText::new("Reports written").strong());
+                        egui::ScrollArea::vertical().max_height(180.0).show(ui, |ui| {
+                            egui::Grid::new("report_history_grid").striped(true).spacing([12.0, 4.0]).show(ui, |ui| {
+                                for report in schedule.history.iter().take(HISTORY_ROWS_SHOWN) {
+                                    ui.label(&report.child_name);
+                                    ui.label(format!("{}-{:02}", report.year, report.month));
+                                    ui.label(egui::RichText::new(&report.path).small()).on_hover_text(&report.generated_at);
+                                    ui.end_row();
+                                }
+                            });
+                        });
+                    }
+                }
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Save").clicked() {
+                        save_clicked = true;
+                    }
+                    if saved_folder.is_some() {
+                        if ui.button("Write last month now").clicked() {
+                            generate_clicked = true;
+                        }
+                        if ui.button("Turn off").clicked() {
+                            turn_off_clicked = true;
+                        }
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if browse_clicked {
+            if let Some(folder) = rfd::FileDialog::new().set_title("Select Report Folder").pick_folder() {
+                self.settings.report_schedule_form.folder = folder.to_string_lossy().to_string();
+            }
+        }
+        if save_clicked || turn_off_clicked {
+            let folder = if turn_off_clicked { None } else { Some(self.settings.report_schedule_form.folder.clone()) };
+            match self.backend().scheduled_report_service.update_report_schedule(UpdateReportScheduleCommand { folder }) {
+                Ok(schedule) => {
+                    info!("🗓️ Report folder saved: {:?}", schedule.folder);
+                    self.settings.report_schedule_form.load(schedule);
+                    self.ui.set_success("Monthly report settings saved".to_string());
+                }
+                Err(e) => {
+                    warn!("🗓️ Failed to save report folder: {}", e);
+                    self.settings.report_schedule_form.error = Some(format!("Could not save: {}", e));
+                }
+            }
+        }
+        if generate_clicked {
+            self.write_last_month_reports();
+        }
+        if close {
+            self.settings.show_report_schedule_modal = false;
+        }
+    }
+
+    /// Write every child's report for the month before this one
+    fn write_last_month_reports(&mut self) {
+        let today = Local::now().date_naive();
+        let last_month = today.with_day(1).and_then(|first| first.checked_sub_months(Months::new(1))).unwrap_or(today);
+        let command = GenerateMonthlyReportsCommand { year: last_month.year(), month: last_month.month() };
+        let result = self.backend().scheduled_report_service.generate_monthly_reports(command);
+        match result.and_then(|result| Ok((result, self.backend().scheduled_report_service.get_report_schedule()?))) {
+            Ok((result, schedule)) => {
+                info!("🗓️ Wrote {} reports for {}/{}", result.reports.len(), result.month, result.year);
+                self.settings.report_schedule_form.load(schedule);
+                if !result.skipped.is_empty() {
+                    self.settings.report_schedule_form.error =
+                        Some(format!("Skipped for now (locked or in use): {}", result.skipped.join(", ")));
+                }
+                self.ui.set_success(format!("Saved {} monthly report(s)", result.reports.len()));
+            }
+            Err(e) => {
+                warn!("🗓️ Failed to write reports: {}", e);
+                self.settings.report_schedule_form.error = Some(format!("Could not write reports: {}", e));
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! # Reset All Data Modal
+//!
+//! This module contains the modal for erasing every child and setting.
+//!
+//! ## Responsibilities:
+//! - Explain what a reset removes and where the backup goes
+//! - Require the confirmation phrase to be typed before anything happens
+//! - Report where the archive of the erased data was saved
+//!
+//! ## Purpose:
+//! Lets a family start over, e.g. for a new school year or when passing the
+//! computer on, without losing the old data for good.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::reset::ResetAllDataCommand;
+use crate::backend::domain::reset_service::RESET_CONFIRMATION_PHRASE;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the reset all data modal
+    pub fn render_reset_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_reset_modal {
+            return;
+        }
+
+        let mut reset_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("🧹 Reset All Data")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.label("This erases every child, transaction, goal and setting. \
+                          A zip of everything is saved next to the data folder first.");
+                ui.add_space(10.0);
+
+                ui.label(format!("Type \"{}\" to confirm:", RESET_CONFIRMATION_PHRASE));
+                ui.add(egui::TextEdit::singleline(&mut self.settings.reset_confirmation).desired_width(220.0));
+
+                if let Some(error) = &self.settings.reset_error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                let confirmed = self.settings.reset_confirmation.trim().eq_ignore_ascii_case(RESET_CONFIRMATION_PHRASE);
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    let erase_button = egui::Button::new(egui::RichText::new("Erase everything").color(egui::Color32::WHITE))
+                        .fill(egui::Color32::from_rgb(220, 50, 50));
+                    if ui.add_enabled(confirmed, erase_button).clicked() {
+                        reset_clicked = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if reset_clicked {
+            let command = ResetAllDataCommand {
+                confirmation: self.settings.reset_confirmation.clone(),
+                archive_directory: None,
+            };
+            match self.backend().reset_service.reset_all_data(command) {
+                Ok(result) => {
+                    info!("🧹 Reset all data, archived {} files to {}", result.archived_file_count, result.archive_path);
+                    self.ui.set_success(format!("All data erased. A backup was saved to {}", result.archive_path));
+                    self.core.current_child = None;
+                    self.refresh_all_data_for_current_child();
+                    close = true;
+                }
+                Err(e) => {
+                    warn!("🧹 Failed to reset data: {}", e);
+                    self.settings.reset_error = Some(format!("Could not reset: {}", e));
+                }
+            }
+        }
+
+        if close {
+            self.settings.show_reset_modal = false;
+            self.settings.reset_confirmation.clear();
+        }
+    }
+}
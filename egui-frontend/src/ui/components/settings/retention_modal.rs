@@ -0,0 +1,144 @@
+//! # Data Retention Modal
+//!
+//! This module contains the modal for choosing how long growing records are kept.
+//!
+//! ## Responsibilities:
+//! - Turn each retention rule on or off and set its period
+//! - Preview what a cleanup would remove without removing anything
+//! - Run the cleanup now instead of waiting for the daily run
+//!
+//! ## Purpose:
+//! The audit log, parental challenge attempts and transaction archive only
+//! ever grow. The rules are applied once a day while the app is open.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::retention::RetentionReport;
+use crate::backend::domain::models::retention_policy::RetentionRule;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the data retention modal
+    pub fn render_retention_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_retention_modal {
+            return;
+        }
+
+        let mut save_clicked = false;
+        let mut preview_clicked = false;
+        let mut apply_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("🧽 Data Retention")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let form = &mut self.settings.retention_form;
+                ui.label("Remove old records automatically once a day. Rules that are off keep everything.");
+                ui.add_space(10.0);
+
+                egui::Grid::new("retention_rules").num_columns(3).spacing([8.0, 6.0]).show(ui, |ui| {
+                    ui.checkbox(&mut form.audit_log_enabled, RetentionRule::AuditLog.label());
+                    ui.add_enabled(form.audit_log_enabled, egui::DragValue::new(&mut form.audit_log_days).range(1..=3650));
+                    ui.label("days");
+                    ui.end_row();
+
+                    ui.checkbox(&mut form.attempts_enabled, RetentionRule::ParentalControlAttempts.label());
+                    ui.add_enabled(form.attempts_enabled, egui::DragValue::new(&mut form.attempt_days).range(1..=3650));
+                    ui.label("days");
+                    ui.end_row();
+
+                    ui.checkbox(&mut form.archive_enabled, RetentionRule::ArchivedTransactions.label());
+                    ui.add_enabled(form.archive_enabled, egui::DragValue::new(&mut form.archive_years).range(1..=50));
+                    ui.label("years");
+                    ui.end_row();
+                });
+
+                if let Some(report) = &form.report {
+                    ui.add_space(10.0);
+                    ui.separator();
+                    render_report(ui, report);
+                }
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Save").clicked() {
+                        save_clicked = true;
+                    }
+                    if ui.button("Preview cleanup").clicked() {
+                        preview_clicked = true;
+                    }
+                    if ui.button("Clean up now").clicked() {
+                        apply_clicked = true;
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        // Previewing or cleaning up uses the rules on screen, so save them first
+        if save_clicked || preview_clicked || apply_clicked {
+            let command = self.settings.retention_form.to_command();
+            if let Err(e) = self.backend().retention_service.update_retention_policy(command) {
+                warn!("🧽 Failed to save retention rules: {}", e);
+                self.settings.retention_form.error = Some(format!("Could not save: {}", e));
+                return;
+            }
+            self.settings.retention_form.error = None;
+        }
+
+        if save_clicked {
+            self.ui.set_success("Retention rules saved".to_string());
+            close = true;
+        } else if preview_clicked || apply_clicked {
+            let result = if apply_clicked {
+                self.backend().retention_service.apply_retention()
+            } else {
+                self.backend().retention_service.preview_retention()
+            };
+            match result {
+                Ok(report) => {
+                    info!("🧽 Retention {}: {} records", if report.dry_run { "preview" } else { "cleanup" }, report.removed_count());
+                    self.settings.retention_form.report = Some(report);
+                }
+                Err(e) => {
+                    warn!("🧽 Retention run failed: {}", e);
+                    self.settings.retention_form.error = Some(format!("Could not clean up: {}", e));
+                }
+            }
+        }
+
+        if close {
+            self.settings.show_retention_modal = false;
+        }
+    }
+}
+
+/// List what a preview would remove, or what a cleanup removed
+fn render_report(ui: &mut egui::Ui, report: &RetentionReport) {
+    let verb = if report.dry_run { "Would remove" } else { "Removed" };
+    if report.items.is_empty() {
+        ui.label(if report.dry_run { "Nothing would be removed." } else { "Nothing needed removing." });
+    } else {
+        ui.strong(format!("{} {} records:", verb, report.removed_count()));
+        for item in &report.items {
+            ui.label(format!(
+                "• {}: {} {} from before {}",
+                item.owner,
+                item.removed_count,
+                item.rule.label().to_lowercase(),
+                item.cutoff.format("%b %-d, %Y")
+            ));
+        }
+    }
+    if !report.skipped.is_empty() {
+        ui.label(format!("Skipped for now (locked or in use): {}", report.skipped.join(", ")));
+    }
+}
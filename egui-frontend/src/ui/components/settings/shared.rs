@@ -10,6 +10,7 @@
 //! - Reduce code duplication across settings modals
 
 use eframe::egui;
+use crate::ui::components::styling::Theme;
 
 /// Common styling configuration for settings modals
 pub struct SettingsModalStyle {
@@ -23,14 +24,14 @@ pub struct SettingsModalStyle {
 }
 
 impl SettingsModalStyle {
-    /// Default styling for settings modals
-    pub fn default_style() -> Self {
+    /// Default styling for settings modals, over the light or dark theme's modal background
+    pub fn default_style(ctx: &egui::Context) -> Self {
         Self {
             modal_size: egui::vec2(450.0, 400.0),
             title_font_size: 28.0,
             title_color: egui::Color32::from_rgb(70, 130, 180), // Steel blue
             border_color: egui::Color32::from_rgb(70, 130, 180),
-            background_color: egui::Color32::WHITE,
+            background_color: Theme::current(ctx).layout.modal_background,
             rounding: 15,
             margin: 25,
         }
@@ -66,7 +67,7 @@ pub fn render_form_field_with_error(
         ui.label(egui::RichText::new(label)
             .font(egui::FontId::new(16.0, egui::FontFamily::Proportional))
             .strong()
-            .color(egui::Color32::from_rgb(60, 60, 60)));
+            .color(Theme::current(ui.ctx()).typography.primary));
 
         // Text input with character limit
         let mut text_edit = egui::TextEdit::singleline(value)
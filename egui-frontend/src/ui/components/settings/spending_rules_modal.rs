@@ -0,0 +1,98 @@
+//! # Spending Rules Modal
+//!
+//! This module contains the modal for the rules the current child's spends
+//! are checked against.
+//!
+//! ## Responsibilities:
+//! - Turn the "no negative balance" rule on or off
+//! - Save the choice to the child's settings
+//!
+//! ## Purpose:
+//! Some families want spends refused once the money is gone, others are
+//! happy to let a child go briefly into the red. With the rule on, a refused
+//! spend can still be recorded through the parent override in the spend form.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::child::UpdateChildSettingsCommand;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+impl AllowanceTrackerApp {
+    /// Render the spending rules modal
+    pub fn render_spending_rules_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_spending_rules_modal {
+            return;
+        }
+
+        let child = self.get_current_child_from_backend();
+        let child_name = child.as_ref().map(|child| child.name.clone()).unwrap_or_default();
+        let mut save_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("🚦 Spending Rules")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.checkbox(
+                    &mut self.settings.prevent_negative_balance,
+                    format!("Don't let {} spend more than they have", child_name),
+                );
+                ui.label(egui::RichText::new("Spends that would take the balance below zero on any day are refused; a parent can still allow one.")
+                    .color(egui::Color32::from_rgb(120, 120, 120)));
+
+                if let Some(error) = &self.settings.spending_rules_error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Save").clicked() {
+                        save_clicked = true;
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if save_clicked {
+            self.save_spending_rules(child.map(|child| child.id));
+        }
+        if close {
+            self.settings.show_spending_rules_modal = false;
+        }
+    }
+
+    /// Save the rule to the child's settings
+    fn save_spending_rules(&mut self, child_id: Option<String>) {
+        let Some(child_id) = child_id else {
+            self.settings.spending_rules_error = Some("Pick a child first".to_string());
+            return;
+        };
+        let command = UpdateChildSettingsCommand {
+            child_id,
+            prevent_negative_balance: Some(self.settings.prevent_negative_balance),
+            auto_save_percentage: None,
+            auto_save_to_goal: None,
+            round_up_spends: None,
+            locale: None,
+            currency: None,
+            balance_milestones: None,
+            background: None,
+        };
+        match self.backend().child_service.update_child_settings(command) {
+            Ok(result) => {
+                info!("🚦 Saved spending rules for {}: no negative balance = {}", result.settings.child_id, result.settings.prevent_negative_balance);
+                self.settings.spending_rules_error = None;
+                self.settings.show_spending_rules_modal = false;
+                self.ui.set_success("Spending rules saved".to_string());
+            }
+            Err(e) => {
+                warn!("🚦 Failed to save spending rules: {}", e);
+                self.settings.spending_rules_error = Some(format!("Could not save: {}", e));
+            }
+        }
+    }
+}
@@ -13,6 +13,33 @@
 //! This centralizes all settings-related state management, making it easier to
 //! maintain consistent form behavior and validation across settings features.
 
+use crate::backend::domain::commands::advances::OutstandingAdvancesResult;
+use crate::backend::domain::commands::goal::GetGoalStatisticsResult;
+use crate::backend::domain::commands::loans::OutstandingLoansResult;
+use crate::backend::domain::commands::transactions::{BulkCategorizeResult, CategoryFilter};
+use crate::backend::domain::models::allowance::{AllowanceFrequency, DayChangePolicy};
+use crate::backend::domain::models::loan::LoanDirection;
+use crate::backend::domain::models::parent_user::{ParentPermission, ParentUser};
+use crate::backend::domain::models::quick_pick::{QuickPick, QuickPickKind};
+use crate::backend::domain::models::budget_target::BudgetTarget;
+use crate::backend::domain::models::transaction::TransactionType;
+use crate::backend::domain::models::transaction_template::TransactionTemplate;
+use crate::backend::domain::commands::import::{SignConvention, StatementColumnMapping, StatementFormat, StatementPreview};
+use crate::backend::domain::commands::retention::{RetentionReport, UpdateRetentionPolicyCommand};
+use crate::backend::domain::commands::feature_flags::FeatureFlagsResult;
+use crate::backend::domain::commands::updates::UpdateCheckResult;
+use crate::backend::domain::commands::encryption::ChildEncryptionStatus;
+use crate::backend::domain::models::retention_policy::RetentionPolicy;
+use crate::backend::domain::models::report_schedule::ReportSchedule;
+use crate::backend::domain::models::fridge_view::FridgeViewSettings;
+use crate::backend::domain::models::background::{ChildBackground, ThemePack};
+use crate::backend::domain::models::update_settings::ReleaseChannel;
+use crate::backend::domain::models::period_lock::PeriodLock;
+use chrono::{DateTime, Datelike, Local, NaiveDate};
+
+/// Years of full detail the archive modal suggests keeping
+pub const DEFAULT_ARCHIVE_YEARS: u32 = 3;
+
 /// Export type selection for export modal
 #[derive(Debug, Clone, PartialEq)]
 pub enum ExportType {
@@ -28,10 +55,21 @@ impl Default for ExportType {
     }
 }
 
+/// File format chosen in the export modal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    /// The current child's transactions as CSV
+    #[default]
+    Csv,
+    /// Every child in one Excel workbook, with a summary sheet
+    Excel,
+}
+
 /// Form state for exporting transaction data
 #[derive(Debug, Clone)]
 pub struct ExportFormState {
     pub export_type: ExportType,
+    pub format: ExportFormat,
     pub custom_path: String,
     pub selected_file_path: Option<String>, // Path selected via native file dialog
     pub is_exporting: bool,
@@ -39,6 +77,12 @@ pub struct ExportFormState {
     pub error_message: Option<String>,
     pub preview_filename: String,
     pub preview_location: String,
+    /// Export every child as a shareable archive with names, dates and amounts disguised
+    pub anonymize: bool,
+    /// Whether exports need the parental answer or a parent's PIN
+    pub protected: bool,
+    /// Answer or PIN typed in for a protected export
+    pub parental_answer: String,
 }
 
 impl ExportFormState {
@@ -46,6 +90,7 @@ impl ExportFormState {
     pub fn new() -> Self {
         Self {
             export_type: ExportType::Default,
+            format: ExportFormat::Csv,
             custom_path: String::new(),
             selected_file_path: None,
             is_exporting: false,
@@ -53,12 +98,16 @@ impl ExportFormState {
             error_message: None,
             preview_filename: String::new(),
             preview_location: String::new(),
+            anonymize: false,
+            protected: false,
+            parental_answer: String::new(),
         }
     }
 
     /// Clear form fields and messages
     pub fn clear(&mut self) {
         self.export_type = ExportType::Default;
+        self.format = ExportFormat::Csv;
         self.custom_path.clear();
         self.selected_file_path = None;
         self.is_exporting = false;
@@ -66,6 +115,8 @@ impl ExportFormState {
         self.error_message = None;
         self.preview_filename.clear();
         self.preview_location.clear();
+        self.anonymize = false;
+        self.parental_answer.clear();
     }
 
     /// Update preview based on current settings
@@ -129,6 +180,14 @@ impl ExportFormState {
                 }
             }
         };
+
+        // A workbook or an anonymized export covers every child in one file,
+        // saved next to the chosen file
+        if self.format == ExportFormat::Excel {
+            self.preview_filename = format!("allowance_tracker_{}.xlsx", chrono::Utc::now().format("%Y%m%d_%H%M%S"));
+        } else if self.anonymize {
+            self.preview_filename = format!("allowance_tracker_anonymized_{}.zip", chrono::Utc::now().format("%Y%m%d_%H%M%S"));
+        }
     }
 
     /// Clear any previous messages
@@ -160,6 +219,16 @@ impl ExportFormState {
         }
     }
 
+    /// Directory for an anonymized archive: the chosen file's folder, or the custom path
+    pub fn get_effective_directory(&self) -> Option<String> {
+        match (&self.export_type, &self.selected_file_path) {
+            (ExportType::Custom, Some(selected_path)) => std::path::Path::new(selected_path)
+                .parent()
+                .map(|parent| parent.to_string_lossy().to_string()),
+            _ => self.get_effective_custom_path(),
+        }
+    }
+
     /// Get the effective custom path for export
     /// This prioritizes the selected file path from the dialog over the manual custom path
     pub fn get_effective_custom_path(&self) -> Option<String> {
@@ -267,6 +336,7 @@ pub struct ProfileFormState {
     pub birthdate_error: Option<String>,
     pub is_valid: bool,
     pub is_saving: bool,
+    pub loaded_updated_at: Option<String>, // When the child was last saved, to refuse stale edits
 }
 
 impl ProfileFormState {
@@ -278,6 +348,7 @@ impl ProfileFormState {
             birthdate_error: None,
             is_valid: true,
             is_saving: false,
+            loaded_updated_at: None,
         }
     }
     
@@ -288,6 +359,7 @@ impl ProfileFormState {
         self.birthdate_error = None;
         self.is_valid = true;
         self.is_saving = false;
+        self.loaded_updated_at = None;
     }
     
     pub fn populate_from_child(&mut self, child: &crate::backend::domain::models::child::Child) {
@@ -297,6 +369,617 @@ impl ProfileFormState {
         self.birthdate_error = None;
         self.is_valid = true;
         self.is_saving = false;
+        self.loaded_updated_at = Some(child.updated_at.to_rfc3339());
+    }
+}
+
+/// Form state for recording a new loan
+#[derive(Debug, Clone)]
+pub struct LoanFormState {
+    pub direction: LoanDirection,
+    pub description: String,
+    pub amount: String,
+    pub error: Option<String>,
+}
+
+impl LoanFormState {
+    pub fn new() -> Self {
+        Self {
+            direction: LoanDirection::ChildOwesParent,
+            description: String::new(),
+            amount: String::new(),
+            error: None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.direction = LoanDirection::ChildOwesParent;
+        self.description.clear();
+        self.amount.clear();
+        self.error = None;
+    }
+}
+
+/// Form state for the data retention rules
+///
+/// Each rule keeps its period while switched off, so turning it back on
+/// restores what was there.
+#[derive(Debug, Clone)]
+pub struct RetentionFormState {
+    pub audit_log_enabled: bool,
+    pub audit_log_days: u32,
+    pub attempts_enabled: bool,
+    pub attempt_days: u32,
+    pub archive_enabled: bool,
+    pub archive_years: u32,
+    /// Result of the last preview or cleanup
+    pub report: Option<RetentionReport>,
+    pub error: Option<String>,
+}
+
+impl RetentionFormState {
+    pub fn new() -> Self {
+        Self {
+            audit_log_enabled: false,
+            audit_log_days: 365,
+            attempts_enabled: false,
+            attempt_days: 90,
+            archive_enabled: false,
+            archive_years: 7,
+            report: None,
+            error: None,
+        }
+    }
+
+    /// Fill the form from the saved policy
+    pub fn load(&mut self, policy: &RetentionPolicy) {
+        *self = Self::new();
+        if let Some(days) = policy.audit_log_days {
+            self.audit_log_enabled = true;
+            self.audit_log_days = days;
+        }
+        if let Some(days) = policy.parental_control_attempt_days {
+            self.attempts_enabled = true;
+            self.attempt_days = days;
+        }
+        if let Some(years) = policy.archived_transaction_years {
+            self.archive_enabled = true;
+            self.archive_years = years;
+        }
+    }
+
+    pub fn to_command(&self) -> UpdateRetentionPolicyCommand {
+        UpdateRetentionPolicyCommand {
+            audit_log_days: self.audit_log_enabled.then_some(self.audit_log_days),
+            parental_control_attempt_days: self.attempts_enabled.then_some(self.attempt_days),
+            archived_transaction_years: self.archive_enabled.then_some(self.archive_years),
+        }
+    }
+}
+
+/// Form state for the monthly reports folder
+#[derive(Debug, Clone)]
+pub struct ReportScheduleFormState {
+    pub folder: String,
+    /// Saved schedule, with the files written so far
+    pub schedule: Option<ReportSchedule>,
+    pub error: Option<String>,
+}
+
+impl ReportScheduleFormState {
+    pub fn new() -> Self {
+        Self {
+            folder: String::new(),
+            schedule: None,
+            error: None,
+        }
+    }
+
+    /// Fill the form from the saved schedule
+    pub fn load(&mut self, schedule: ReportSchedule) {
+        *self = Self::new();
+        self.folder = schedule.folder.clone().unwrap_or_default();
+        self.schedule = Some(schedule);
+    }
+}
+
+/// Form state for the fridge view page
+#[derive(Debug, Clone)]
+pub struct FridgeViewFormState {
+    pub path: String,
+    pub auto_update: bool,
+    /// Saved settings, to tell whether there is a page to write
+    pub settings: Option<FridgeViewSettings>,
+    /// When the page file was last written
+    pub last_written: Option<DateTime<Local>>,
+    pub error: Option<String>,
+}
+
+impl FridgeViewFormState {
+    pub fn new() -> Self {
+        Self {
+            path: String::new(),
+            auto_update: false,
+            settings: None,
+            last_written: None,
+            error: None,
+        }
+    }
+
+    /// Fill the form from the saved settings
+    pub fn load(&mut self, settings: FridgeViewSettings, last_written: Option<DateTime<Local>>) {
+        *self = Self::new();
+        self.path = settings.path.clone().unwrap_or_default();
+        self.auto_update = settings.auto_update;
+        self.settings = Some(settings);
+        self.last_written = last_written;
+    }
+}
+
+/// Form state for the child's background
+#[derive(Debug, Clone)]
+pub struct BackgroundFormState {
+    /// Theme pack picked, used unless a custom picture is chosen
+    pub pack: ThemePack,
+    /// Whether the child's own picture is picked instead of a pack
+    pub use_custom_image: bool,
+    pub custom_image_path: String,
+    pub error: Option<String>,
+}
+
+impl BackgroundFormState {
+    pub fn new() -> Self {
+        Self {
+            pack: ThemePack::default(),
+            use_custom_image: false,
+            custom_image_path: String::new(),
+            error: None,
+        }
+    }
+
+    /// Fill the form from the child's saved background
+    pub fn load(&mut self, background: &ChildBackground) {
+        *self = Self::new();
+        match background {
+            ChildBackground::Pack { pack } => self.pack = *pack,
+            ChildBackground::CustomImage { path } => {
+                self.use_custom_image = true;
+                self.custom_image_path = path.clone();
+            }
+        }
+    }
+
+    /// The background the form describes
+    pub fn background(&self) -> ChildBackground {
+        if self.use_custom_image {
+            ChildBackground::CustomImage { path: self.custom_image_path.trim().to_string() }
+        } else {
+            ChildBackground::Pack { pack: self.pack }
+        }
+    }
+}
+
+/// Form state for the printable allowance agreement
+#[derive(Debug, Clone)]
+pub struct AgreementFormState {
+    /// One chore per line
+    pub chores: String,
+    /// One family rule per line, printed after the rules from the child's settings
+    pub rules: String,
+    /// Folder to save to; empty uses the Documents folder
+    pub folder: String,
+    /// Where the last agreement was saved
+    pub saved_path: Option<String>,
+    pub error: Option<String>,
+}
+
+impl AgreementFormState {
+    pub fn new() -> Self {
+        Self {
+            chores: String::new(),
+            rules: String::new(),
+            folder: String::new(),
+            saved_path: None,
+            error: None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Form state for closing a reviewed month
+#[derive(Debug, Clone)]
+pub struct ClosePeriodFormState {
+    /// Month to close through, normally last month
+    pub year: i32,
+    pub month: u32,
+    /// What is closed now; reloaded when the modal opens
+    pub current_lock: Option<PeriodLock>,
+    pub error: Option<String>,
+}
+
+impl ClosePeriodFormState {
+    pub fn new() -> Self {
+        let last_month = PeriodLock::previous_month_end(chrono::Local::now().date_naive());
+        Self {
+            year: last_month.year(),
+            month: last_month.month(),
+            current_lock: None,
+            error: None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Form state for giving many past transactions a category at once
+#[derive(Debug, Clone)]
+pub struct CategorizeFormState {
+    /// Text the descriptions must contain
+    pub description_contains: String,
+    pub uncategorized_only: bool,
+    /// Whether the date range below applies
+    pub limit_dates: bool,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    /// Category to set; empty clears it
+    pub category: String,
+    /// Preview for the fields as they are now; cleared when one changes
+    pub preview: Option<BulkCategorizeResult>,
+    /// The last change applied, kept for undoing it
+    pub applied: Option<(String, BulkCategorizeResult)>,
+    pub error: Option<String>,
+}
+
+impl CategorizeFormState {
+    pub fn new() -> Self {
+        let today = chrono::Local::now().date_naive();
+        Self {
+            description_contains: String::new(),
+            uncategorized_only: true,
+            limit_dates: false,
+            start_date: today - chrono::Duration::days(365),
+            end_date: today,
+            category: String::new(),
+            preview: None,
+            applied: None,
+            error: None,
+        }
+    }
+
+    /// The filter the fields describe
+    pub fn filter(&self) -> CategoryFilter {
+        CategoryFilter {
+            description_contains: self.description_contains.clone(),
+            uncategorized_only: self.uncategorized_only,
+            start_date: self.limit_dates.then_some(self.start_date),
+            end_date: self.limit_dates.then_some(self.end_date),
+        }
+    }
+
+    /// The category the fields ask for; `None` clears it
+    pub fn category(&self) -> Option<String> {
+        Some(self.category.trim().to_string()).filter(|category| !category.is_empty())
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Form state for the release channel and update check
+#[derive(Debug, Clone)]
+pub struct UpdatesFormState {
+    pub channel: ReleaseChannel,
+    /// Whether the app checks once a day by itself
+    pub check_automatically: bool,
+    /// Result of the last check, from the modal or the scheduled check
+    pub check: Option<UpdateCheckResult>,
+    pub error: Option<String>,
+}
+
+impl UpdatesFormState {
+    pub fn new() -> Self {
+        Self {
+            channel: ReleaseChannel::default(),
+            check_automatically: false,
+            check: None,
+            error: None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Form state for turning encryption of the child's data on or off
+#[derive(Debug, Clone)]
+pub struct EncryptionFormState {
+    /// Whether the child's data is encrypted now, once loaded
+    pub status: Option<ChildEncryptionStatus>,
+    pub passphrase: String,
+    /// The passphrase typed again; only asked for when turning encryption on
+    pub confirm_passphrase: String,
+    pub error: Option<String>,
+}
+
+impl EncryptionFormState {
+    pub fn new() -> Self {
+        Self {
+            status: None,
+            passphrase: String::new(),
+            confirm_passphrase: String::new(),
+            error: None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Form state for granting an allowance advance
+#[derive(Debug, Clone)]
+pub struct AdvanceFormState {
+    pub amount: String,
+    /// How many upcoming allowances repay it
+    pub installments: u32,
+    pub error: Option<String>,
+}
+
+impl AdvanceFormState {
+    pub fn new() -> Self {
+        Self {
+            amount: String::new(),
+            installments: 2,
+            error: None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// The kinds of statement file the import modal reads
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatementFileKind {
+    Csv,
+    Ofx,
+    Qif,
+}
+
+impl StatementFileKind {
+    pub const ALL: [StatementFileKind; 3] = [StatementFileKind::Csv, StatementFileKind::Ofx, StatementFileKind::Qif];
+
+    /// Guess the kind from a file name, defaulting to CSV
+    pub fn from_path(path: &std::path::Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()).map(str::to_ascii_lowercase).as_deref() {
+            Some("ofx") | Some("qfx") => StatementFileKind::Ofx,
+            Some("qif") => StatementFileKind::Qif,
+            _ => StatementFileKind::Csv,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StatementFileKind::Csv => "CSV",
+            StatementFileKind::Ofx => "OFX / QFX",
+            StatementFileKind::Qif => "QIF",
+        }
+    }
+}
+
+/// Form state for importing a bank or card statement
+#[derive(Debug, Clone)]
+pub struct StatementImportFormState {
+    pub file_path: Option<String>,
+    pub content: Option<String>,
+    pub kind: StatementFileKind,
+    /// CSV columns counted from 0
+    pub date_column: usize,
+    pub description_column: usize,
+    pub amount_column: usize,
+    pub category_column: Option<usize>,
+    pub sign_convention: SignConvention,
+    pub date_format: String,
+    pub has_header_row: bool,
+    pub delimiter: u8,
+    /// Parsed with the settings above when "Preview" was last clicked
+    pub preview: Option<StatementPreview>,
+    /// Lines of the preview to import
+    pub accepted_lines: std::collections::BTreeSet<usize>,
+    /// Statement category to the category recorded for it
+    pub category_mapping: std::collections::HashMap<String, String>,
+    pub error: Option<String>,
+}
+
+impl StatementImportFormState {
+    pub fn new() -> Self {
+        Self {
+            file_path: None,
+            content: None,
+            kind: StatementFileKind::Csv,
+            date_column: 0,
+            description_column: 1,
+            amount_column: 2,
+            category_column: None,
+            sign_convention: SignConvention::NegativeIsSpending,
+            date_format: "%m/%d/%Y".to_string(),
+            has_header_row: true,
+            delimiter: b',',
+            preview: None,
+            accepted_lines: std::collections::BTreeSet::new(),
+            category_mapping: std::collections::HashMap::new(),
+            error: None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// The format as entered; only CSV needs a mapping
+    pub fn format(&self) -> StatementFormat {
+        match self.kind {
+            StatementFileKind::Csv => StatementFormat::Csv(StatementColumnMapping {
+                date_column: self.date_column,
+                description_column: self.description_column,
+                amount_column: self.amount_column,
+                sign_convention: self.sign_convention,
+                date_format: self.date_format.clone(),
+                has_header_row: self.has_header_row,
+                delimiter: self.delimiter,
+                category_column: self.category_column,
+            }),
+            StatementFileKind::Ofx => StatementFormat::Ofx,
+            StatementFileKind::Qif => StatementFormat::Qif,
+        }
+    }
+}
+
+/// Form state for adding a quick-pick description
+#[derive(Debug, Clone)]
+pub struct QuickPickFormState {
+    pub description: String,
+    /// Optional amount filled in with the description
+    pub amount: String,
+    pub kind: QuickPickKind,
+    pub error: Option<String>,
+}
+
+impl QuickPickFormState {
+    pub fn new() -> Self {
+        Self {
+            description: String::new(),
+            amount: String::new(),
+            kind: QuickPickKind::Income,
+            error: None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Form state for adding or editing a transaction template
+#[derive(Debug, Clone)]
+pub struct TemplateFormState {
+    /// The template being edited; `None` when adding a new one
+    pub editing_id: Option<String>,
+    pub name: String,
+    pub description: String,
+    pub amount: String,
+    /// Optional spending category, e.g. "Food"
+    pub category: String,
+    pub transaction_type: TransactionType,
+    pub error: Option<String>,
+}
+
+impl TemplateFormState {
+    pub fn new() -> Self {
+        Self {
+            editing_id: None,
+            name: String::new(),
+            description: String::new(),
+            amount: String::new(),
+            category: String::new(),
+            transaction_type: TransactionType::Expense,
+            error: None,
+        }
+    }
+
+    /// Fill the form in with a template to edit it
+    pub fn edit(template: &TransactionTemplate) -> Self {
+        Self {
+            editing_id: Some(template.id.clone()),
+            name: template.name.clone(),
+            description: template.description.clone(),
+            amount: format!("{:.2}", template.amount),
+            category: template.category.clone().unwrap_or_default(),
+            transaction_type: template.transaction_type.clone(),
+            error: None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Form state for setting a monthly spending target
+#[derive(Debug, Clone)]
+pub struct BudgetTargetFormState {
+    pub category: String,
+    pub monthly_limit: String,
+    pub error: Option<String>,
+}
+
+impl BudgetTargetFormState {
+    pub fn new() -> Self {
+        Self {
+            category: String::new(),
+            monthly_limit: String::new(),
+            error: None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Form state for planning a purchase
+#[derive(Debug, Clone)]
+pub struct PlannedPurchaseFormState {
+    pub description: String,
+    pub amount: String,
+    pub planned_date: String, // YYYY-MM-DD format
+    pub error: Option<String>,
+}
+
+impl PlannedPurchaseFormState {
+    pub fn new() -> Self {
+        Self {
+            description: String::new(),
+            amount: String::new(),
+            planned_date: chrono::Local::now().date_naive().to_string(),
+            error: None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Form state for adding a named parent
+#[derive(Debug, Clone)]
+pub struct ParentFormState {
+    pub name: String,
+    pub pin: String,
+    pub permissions: Vec<ParentPermission>,
+    pub error: Option<String>,
+}
+
+impl ParentFormState {
+    pub fn new() -> Self {
+        Self {
+            name: String::new(),
+            pin: String::new(),
+            permissions: ParentPermission::ALL.to_vec(),
+            error: None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
     }
 }
 
@@ -305,6 +988,12 @@ impl ProfileFormState {
 pub struct AllowanceConfigFormState {
     pub amount: String,
     pub day_of_week: u8, // 0 = Sunday, 1 = Monday, ..., 6 = Saturday
+    pub day_change_policy: DayChangePolicy,
+    pub frequency: AllowanceFrequency,
+    pub day_of_month: u8, // 1-31; shorter months pay on their last day
+    pub interval_weeks: u8,
+    /// First payday of an every-few-weeks allowance
+    pub anchor_date: NaiveDate,
     pub amount_error: Option<String>,
     pub is_valid: bool,
     pub is_saving: bool,
@@ -314,7 +1003,14 @@ pub struct AllowanceConfigFormState {
     // Original values for change detection
     pub original_amount: Option<f64>,
     pub original_day_of_week: Option<u8>,
+    pub original_day_change_policy: Option<DayChangePolicy>,
+    pub original_frequency: Option<AllowanceFrequency>,
+    pub original_day_of_month: Option<u8>,
+    pub original_interval_weeks: Option<u8>,
+    pub original_anchor_date: Option<NaiveDate>,
     pub has_existing_config: bool,
+    /// When the loaded config was last saved, so a change made elsewhere isn't overwritten
+    pub loaded_updated_at: Option<String>,
 }
 
 impl AllowanceConfigFormState {
@@ -323,6 +1019,11 @@ impl AllowanceConfigFormState {
         Self {
             amount: "5.00".to_string(), // Default $5
             day_of_week: 5, // Default Friday
+            day_change_policy: DayChangePolicy::default(),
+            frequency: AllowanceFrequency::Weekly,
+            day_of_month: 1,
+            interval_weeks: 2,
+            anchor_date: Local::now().date_naive(),
             amount_error: None,
             is_valid: true,
             is_saving: false,
@@ -330,7 +1031,13 @@ impl AllowanceConfigFormState {
             error_message: None,
             original_amount: None,
             original_day_of_week: None,
+            original_day_change_policy: None,
+            original_frequency: None,
+            original_day_of_month: None,
+            original_interval_weeks: None,
+            original_anchor_date: None,
             has_existing_config: false,
+            loaded_updated_at: None,
         }
     }
     
@@ -338,6 +1045,11 @@ impl AllowanceConfigFormState {
     pub fn clear(&mut self) {
         self.amount = "5.00".to_string();
         self.day_of_week = 5;
+        self.day_change_policy = DayChangePolicy::default();
+        self.frequency = AllowanceFrequency::Weekly;
+        self.day_of_month = 1;
+        self.interval_weeks = 2;
+        self.anchor_date = Local::now().date_naive();
         self.amount_error = None;
         self.is_valid = true;
         self.is_saving = false;
@@ -345,16 +1057,33 @@ impl AllowanceConfigFormState {
         self.error_message = None;
         self.original_amount = None;
         self.original_day_of_week = None;
+        self.original_day_change_policy = None;
+        self.original_frequency = None;
+        self.original_day_of_month = None;
+        self.original_interval_weeks = None;
+        self.original_anchor_date = None;
         self.has_existing_config = false;
+        self.loaded_updated_at = None;
     }
     
     /// Load existing allowance config into form
     pub fn load_from_config(&mut self, config: &crate::backend::domain::models::allowance::AllowanceConfig) {
         self.amount = format!("{:.2}", config.amount);
         self.day_of_week = config.day_of_week;
+        self.day_change_policy = config.day_change_policy;
+        self.frequency = config.frequency;
+        self.day_of_month = config.day_of_month;
+        self.interval_weeks = config.interval_weeks;
+        self.anchor_date = config.anchor_date.unwrap_or_else(|| Local::now().date_naive());
         self.original_amount = Some(config.amount);
         self.original_day_of_week = Some(config.day_of_week);
+        self.original_day_change_policy = Some(config.day_change_policy);
+        self.original_frequency = Some(config.frequency);
+        self.original_day_of_month = Some(config.day_of_month);
+        self.original_interval_weeks = Some(config.interval_weeks);
+        self.original_anchor_date = config.anchor_date;
         self.has_existing_config = true;
+        self.loaded_updated_at = Some(config.updated_at.clone());
         self.amount_error = None;
         self.is_valid = true;
         self.success_message = None;
@@ -393,7 +1122,17 @@ impl AllowanceConfigFormState {
             changed
         }).unwrap_or(true);
         
-        let has_changes = amount_changed || day_changed;
+        let policy_changed = self.original_day_change_policy
+            .map(|orig| orig != self.day_change_policy)
+            .unwrap_or(true);
+        
+        let schedule_changed = self.original_frequency.is_none_or(|orig| orig != self.frequency)
+            || self.original_day_of_month.is_none_or(|orig| orig != self.day_of_month)
+            || (self.frequency == AllowanceFrequency::EveryNWeeks
+                && (self.original_interval_weeks != Some(self.interval_weeks)
+                    || self.original_anchor_date != Some(self.anchor_date)));
+        
+        let has_changes = amount_changed || day_changed || policy_changed || schedule_changed;
         if has_changes {
             log::info!("⚙️ HAS_CHANGES: amount_changed={}, day_changed={}, result={}", 
                 amount_changed, day_changed, has_changes);
@@ -416,11 +1155,27 @@ impl AllowanceConfigFormState {
         }
     }
     
+    /// Whether an existing weekly allowance is moving to another day of the week
+    pub fn is_weekly_day_changing(&self) -> bool {
+        self.frequency == AllowanceFrequency::Weekly
+            && self.original_frequency == Some(AllowanceFrequency::Weekly)
+            && self.original_day_of_week.is_some_and(|original| original != self.day_of_week)
+    }
+    
     /// Get success message based on form state
     pub fn get_success_message(&self) -> String {
-        format!("New allowance: ${:.2} every {}", 
+        let schedule = match self.frequency {
+            AllowanceFrequency::Weekly => format!("every {}", self.day_name()),
+            AllowanceFrequency::Monthly => format!("on day {} of every month", self.day_of_month),
+            AllowanceFrequency::EveryNWeeks => format!(
+                "every {} weeks from {}",
+                self.interval_weeks,
+                self.anchor_date.format("%b %-d, %Y")
+            ),
+        };
+        format!("New allowance: ${:.2} {}", 
             self.amount.parse::<f64>().unwrap_or(0.0), 
-            self.day_name())
+            schedule)
     }
 }
 
@@ -456,6 +1211,178 @@ pub struct SettingsState {
 
     /// Allowance config form state
     pub allowance_config_form: AllowanceConfigFormState,
+
+    /// Whether the text size modal is visible
+    pub show_text_size_modal: bool,
+
+    /// Error from the last attempt to save the text size
+    pub text_size_error: Option<String>,
+
+    /// Whether the archive history modal is visible
+    pub show_archive_modal: bool,
+
+    /// Years of full detail to keep when archiving
+    pub archive_years: u32,
+
+    /// Error from the last archive attempt
+    pub archive_error: Option<String>,
+
+    /// Whether the loans modal is visible
+    pub show_loans_modal: bool,
+
+    /// Open loans for the current child; reloaded when the modal opens
+    pub loans: Option<OutstandingLoansResult>,
+
+    /// New loan form state
+    pub loan_form: LoanFormState,
+
+    /// Whether the planned purchases modal is visible
+    pub show_planned_purchases_modal: bool,
+
+    /// New planned purchase form state
+    pub planned_purchase_form: PlannedPurchaseFormState,
+
+    /// Whether the parents & PINs modal is visible
+    pub show_parents_modal: bool,
+
+    /// Named parents; reloaded when the modal opens
+    pub parents: Option<Vec<ParentUser>>,
+
+    /// New parent form state
+    pub parent_form: ParentFormState,
+
+    /// Whether the reset all data modal is visible
+    pub show_reset_modal: bool,
+
+    /// Confirmation phrase as typed so far
+    pub reset_confirmation: String,
+
+    /// Error from the last reset attempt
+    pub reset_error: Option<String>,
+
+    /// Whether the goal history modal is visible
+    pub show_goal_history_modal: bool,
+
+    /// Goal statistics for the current child; reloaded when the modal opens
+    pub goal_statistics: Option<GetGoalStatisticsResult>,
+
+    /// Error from loading goal statistics
+    pub goal_history_error: Option<String>,
+
+    /// Whether the allowance advance modal is visible
+    pub show_advance_modal: bool,
+
+    /// Advances still being repaid; reloaded when the modal opens
+    pub advances: Option<OutstandingAdvancesResult>,
+
+    /// New advance form state
+    pub advance_form: AdvanceFormState,
+
+    /// Whether the quick picks modal is visible
+    pub show_quick_picks_modal: bool,
+
+    /// The current child's quick picks; reloaded when the modal opens
+    pub quick_picks: Option<Vec<QuickPick>>,
+
+    /// New quick pick form state
+    pub quick_pick_form: QuickPickFormState,
+
+    /// Whether the bulk categorize modal is visible
+    pub show_categorize_modal: bool,
+
+    /// Bulk categorize form state
+    pub categorize_form: CategorizeFormState,
+
+    /// Whether the transaction templates modal is visible
+    pub show_templates_modal: bool,
+
+    /// The child the cached templates belong to, and their templates; also
+    /// used by the money forms and the quick-add menu
+    pub templates: Option<(String, Vec<TransactionTemplate>)>,
+
+    /// Template add/edit form state
+    pub template_form: TemplateFormState,
+
+    /// Whether the statement import modal is visible
+    pub show_import_modal: bool,
+
+    /// Statement import form state
+    pub import_form: StatementImportFormState,
+
+    /// Whether the data retention modal is visible
+    pub show_retention_modal: bool,
+
+    /// Data retention form state
+    pub retention_form: RetentionFormState,
+
+    /// Whether the spending targets modal is visible
+    pub show_budget_modal: bool,
+
+    /// The current child's spending targets; reloaded when the modal opens
+    pub budget_targets: Option<Vec<BudgetTarget>>,
+
+    /// New spending target form state
+    pub budget_form: BudgetTargetFormState,
+
+    /// Whether the monthly reports modal is visible
+    pub show_report_schedule_modal: bool,
+
+    /// Monthly reports form state
+    pub report_schedule_form: ReportScheduleFormState,
+
+    /// Whether the experiments modal is visible
+    pub show_feature_flags_modal: bool,
+
+    /// Every experimental feature and whether it is on; reloaded when the modal opens
+    pub feature_flags: Option<FeatureFlagsResult>,
+
+    /// Error from loading or changing the experiments
+    pub feature_flags_error: Option<String>,
+
+    /// Whether the allowance agreement modal is visible
+    pub show_agreement_modal: bool,
+
+    /// Allowance agreement form state
+    pub agreement_form: AgreementFormState,
+
+    /// Whether the close month modal is visible
+    pub show_close_period_modal: bool,
+
+    /// Close month form state
+    pub close_period_form: ClosePeriodFormState,
+
+    /// Whether the updates modal is visible
+    pub show_updates_modal: bool,
+
+    /// Release channel and update check state
+    pub updates_form: UpdatesFormState,
+
+    /// Whether the fridge view modal is visible
+    pub show_fridge_view_modal: bool,
+
+    /// Fridge view form state
+    pub fridge_view_form: FridgeViewFormState,
+
+    /// Whether the background modal is visible
+    pub show_background_modal: bool,
+
+    /// Background form state
+    pub background_form: BackgroundFormState,
+
+    /// Whether the spending rules modal is visible
+    pub show_spending_rules_modal: bool,
+
+    /// The child's "no negative balance" rule, as shown in the spending rules modal
+    pub prevent_negative_balance: bool,
+
+    /// Error from loading or saving the spending rules
+    pub spending_rules_error: Option<String>,
+
+    /// Whether the encryption modal is visible
+    pub show_encryption_modal: bool,
+
+    /// Encryption form state
+    pub encryption_form: EncryptionFormState,
 }
 
 impl SettingsState {
@@ -472,6 +1399,63 @@ impl SettingsState {
             data_directory_form: DataDirectoryFormState::new(),
             show_allowance_config_modal: false,
             allowance_config_form: AllowanceConfigFormState::new(),
+            show_text_size_modal: false,
+            text_size_error: None,
+            show_archive_modal: false,
+            archive_years: DEFAULT_ARCHIVE_YEARS,
+            archive_error: None,
+            show_loans_modal: false,
+            loans: None,
+            loan_form: LoanFormState::new(),
+            show_planned_purchases_modal: false,
+            planned_purchase_form: PlannedPurchaseFormState::new(),
+            show_parents_modal: false,
+            parents: None,
+            parent_form: ParentFormState::new(),
+            show_reset_modal: false,
+            reset_confirmation: String::new(),
+            reset_error: None,
+            show_goal_history_modal: false,
+            goal_statistics: None,
+            goal_history_error: None,
+            show_advance_modal: false,
+            advances: None,
+            advance_form: AdvanceFormState::new(),
+            show_quick_picks_modal: false,
+            quick_picks: None,
+            quick_pick_form: QuickPickFormState::new(),
+            show_categorize_modal: false,
+            categorize_form: CategorizeFormState::new(),
+            show_templates_modal: false,
+            templates: None,
+            template_form: TemplateFormState::new(),
+            show_import_modal: false,
+            import_form: StatementImportFormState::new(),
+            show_retention_modal: false,
+            retention_form: RetentionFormState::new(),
+            show_budget_modal: false,
+            budget_targets: None,
+            budget_form: BudgetTargetFormState::new(),
+            show_report_schedule_modal: false,
+            report_schedule_form: ReportScheduleFormState::new(),
+            show_feature_flags_modal: false,
+            feature_flags: None,
+            feature_flags_error: None,
+            show_agreement_modal: false,
+            agreement_form: AgreementFormState::new(),
+            show_close_period_modal: false,
+            close_period_form: ClosePeriodFormState::new(),
+            show_updates_modal: false,
+            updates_form: UpdatesFormState::new(),
+            show_fridge_view_modal: false,
+            fridge_view_form: FridgeViewFormState::new(),
+            show_background_modal: false,
+            background_form: BackgroundFormState::new(),
+            show_spending_rules_modal: false,
+            prevent_negative_balance: false,
+            spending_rules_error: None,
+            show_encryption_modal: false,
+            encryption_form: EncryptionFormState::new(),
         }
     }
 
@@ -482,6 +1466,29 @@ impl SettingsState {
         self.show_export_modal = false;
         self.show_data_directory_modal = false;
         self.show_allowance_config_modal = false;
+        self.show_text_size_modal = false;
+        self.show_archive_modal = false;
+        self.show_loans_modal = false;
+        self.show_planned_purchases_modal = false;
+        self.show_parents_modal = false;
+        self.show_reset_modal = false;
+        self.show_goal_history_modal = false;
+        self.show_advance_modal = false;
+        self.show_quick_picks_modal = false;
+        self.show_templates_modal = false;
+        self.show_categorize_modal = false;
+        self.show_import_modal = false;
+        self.show_retention_modal = false;
+        self.show_budget_modal = false;
+        self.show_report_schedule_modal = false;
+        self.show_feature_flags_modal = false;
+        self.show_agreement_modal = false;
+        self.show_close_period_modal = false;
+        self.show_updates_modal = false;
+        self.show_fridge_view_modal = false;
+        self.show_background_modal = false;
+        self.show_spending_rules_modal = false;
+        self.show_encryption_modal = false;
     }
 
     /// Reset all form states
@@ -530,6 +1537,8 @@ pub struct DataDirectoryFormState {
     pub success_message: Option<String>,
     pub error_message: Option<String>,
     pub is_redirected: bool, // True if current location is a redirect
+    pub read_only_reason: Option<String>, // Set when read-only mode is on or another instance holds the directory lock
+    pub plan: Option<(shared::ConflictResolution, shared::RelocationPlan)>, // Dry run shown for confirmation before the move
 }
 
 impl DataDirectoryFormState {
@@ -545,6 +1554,8 @@ impl DataDirectoryFormState {
             success_message: None,
             error_message: None,
             is_redirected: false,
+            read_only_reason: None,
+            plan: None,
         }
     }
 
@@ -559,6 +1570,8 @@ impl DataDirectoryFormState {
         self.success_message = None;
         self.error_message = None;
         self.is_redirected = false;
+        self.read_only_reason = None;
+        self.plan = None;
     }
 
     /// Set loading state
@@ -0,0 +1,253 @@
+//! # Templates Modal
+//!
+//! This module contains the modal for the current child's transaction
+//! templates ("favorites"), and the one-tap recording they are saved for.
+//!
+//! ## Responsibilities:
+//! - List the child's templates with what each one records
+//! - Add a template, or edit or delete one
+//! - Record the transaction a template describes, for the money forms and
+//!   the quick-add menu in the header
+//!
+//! ## Purpose:
+//! Lunch money on school days or the weekly chore payment is the same
+//! transaction every time. A template keeps all of it, category and kind
+//! included, so it can be added with one tap instead of a filled-in form.
+
+use eframe::egui;
+use chrono::NaiveDate;
+use log::{info, warn};
+use crate::backend::domain::commands::templates::{
+    CreateTemplateCommand, DeleteTemplateCommand, ListTemplatesQuery, UpdateTemplateCommand, UseTemplateCommand,
+};
+use crate::backend::domain::models::actor::Actor;
+use crate::backend::domain::models::transaction_template::TransactionTemplate;
+use crate::ui::app_state::AllowanceTrackerApp;
+use crate::ui::components::settings::state::TemplateFormState;
+
+impl AllowanceTrackerApp {
+    /// Render the templates modal
+    pub fn render_templates_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_templates_modal {
+            return;
+        }
+
+        let child_name = self.core.current_child.as_ref().map(|child| child.name.clone());
+        let templates = self.current_templates();
+
+        let mut edit: Option<TransactionTemplate> = None;
+        let mut delete_id: Option<String> = None;
+        let mut save_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("⭐ Templates")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let Some(child_name) = &child_name else {
+                    ui.label("Select a child first.");
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                    return;
+                };
+
+                ui.label(format!("Transactions {} adds often, ready to add with one tap.", child_name));
+                ui.add_space(8.0);
+                if templates.is_empty() {
+                    ui.label(egui::RichText::new("No templates yet.").color(egui::Color32::from_rgb(120, 120, 120)));
+                } else {
+                    egui::Grid::new("templates_grid").striped(true).spacing([12.0, 4.0]).show(ui, |ui| {
+                        for template in &templates {
+                            ui.label(egui::RichText::new(&template.name).strong());
+                            ui.label(&template.description);
+                            ui.label(self.format_currency_amount(template.signed_amount()));
+                            ui.label(template.category.as_deref().unwrap_or(""));
+                            ui.label(template.transaction_type.label());
+                            if ui.small_button("Edit").clicked() {
+                                edit = Some(template.clone());
+                            }
+                            if ui.small_button("Delete").clicked() {
+                                delete_id = Some(template.id.clone());
+                            }
+                            ui.end_row();
+                        }
+                    });
+                }
+
+                ui.add_space(12.0);
+                ui.separator();
+                let form = &mut self.settings.template_form;
+                let heading = if form.editing_id.is_some() { "Edit template" } else { "Add a template" };
+                ui.label(egui::RichText::new(heading).strong());
+                egui::Grid::new("template_form_grid").spacing([8.0, 6.0]).show(ui, |ui| {
+                    ui.label("Name:");
+                    ui.add(egui::TextEdit::singleline(&mut form.name).hint_text("Lunch").desired_width(200.0));
+                    ui.end_row();
+                    ui.label("Description:");
+                    ui.add(egui::TextEdit::singleline(&mut form.description).hint_text("Lunch money").desired_width(200.0));
+                    ui.end_row();
+                    ui.label("Amount:");
+                    ui.add(egui::TextEdit::singleline(&mut form.amount).hint_text("4.50").desired_width(80.0));
+                    ui.end_row();
+                    ui.label("Category:");
+                    ui.add(egui::TextEdit::singleline(&mut form.category).hint_text("optional").desired_width(120.0));
+                    ui.end_row();
+                });
+                ui.horizontal_wrapped(|ui| {
+                    ui.label("Kind:");
+                    for kind in TransactionTemplate::KINDS {
+                        let label = kind.label();
+                        ui.radio_value(&mut form.transaction_type, kind, label);
+                    }
+                });
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    let save_label = if form.editing_id.is_some() { "Save changes" } else { "Add template" };
+                    if ui.button(save_label).clicked() {
+                        save_clicked = true;
+                    }
+                    if form.editing_id.is_some() && ui.button("Stop editing").clicked() {
+                        form.clear();
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if let Some(template) = edit {
+            self.settings.template_form = TemplateFormState::edit(&template);
+        }
+        if let Some(template_id) = delete_id {
+            self.delete_template(template_id);
+        }
+        if save_clicked {
+            self.save_template();
+        }
+        if close {
+            self.settings.show_templates_modal = false;
+        }
+    }
+
+    /// The current child's templates, loaded again after switching children
+    pub(crate) fn current_templates(&mut self) -> Vec<TransactionTemplate> {
+        let Some(child_id) = self.core.current_child.as_ref().map(|child| child.id.clone()) else {
+            return Vec::new();
+        };
+        if let Some((cached_for, templates)) = &self.settings.templates {
+            if *cached_for == child_id {
+                return templates.clone();
+            }
+        }
+        // A failed load is cached as empty so it isn't retried every frame
+        let templates = match self.backend().transaction_template_service.list_templates(ListTemplatesQuery { child_id: Some(child_id.clone()) }) {
+            Ok(result) => result.templates,
+            Err(e) => {
+                warn!("⭐ Failed to load templates: {}", e);
+                self.settings.template_form.error = Some(format!("Could not load templates: {}", e));
+                Vec::new()
+            }
+        };
+        self.settings.templates = Some((child_id, templates.clone()));
+        templates
+    }
+
+    /// Record the transaction a template describes on `date` (today when
+    /// `None`); returns whether it was added
+    pub(crate) fn record_from_template(&mut self, template: &TransactionTemplate, date: Option<NaiveDate>) -> bool {
+        let command = UseTemplateCommand {
+            template_id: template.id.clone(),
+            date: Self::transaction_timestamp(date),
+            parent_override: false,
+            actor: Some(Actor::Kid),
+        };
+        match self.backend().transaction_template_service.use_template(command) {
+            Ok(result) => {
+                info!("⭐ Added {} from template {}", result.transaction.id, template.id);
+                self.ui.set_success(format!(
+                    "Added \"{}\" ({})",
+                    result.transaction.description,
+                    self.format_currency_amount(result.transaction.amount)
+                ));
+                self.load_calendar_data();
+                self.table.invalidate();
+                self.load_balance();
+                self.load_chart_data();
+                true
+            }
+            Err(e) => {
+                warn!("⭐ Failed to add from template {}: {}", template.id, e);
+                self.ui.error_message = Some(format!("Couldn't add \"{}\": {}", template.name, e));
+                false
+            }
+        }
+    }
+
+    /// Save the template in the form, as a new one or over the one being edited
+    fn save_template(&mut self) {
+        let form = self.settings.template_form.clone();
+        let amount = match self.clean_and_parse_amount(&form.amount) {
+            Ok(amount) => amount,
+            Err(e) => {
+                self.settings.template_form.error = Some(e);
+                return;
+            }
+        };
+        let category = Some(form.category).filter(|category| !category.trim().is_empty());
+        let service = &self.backend().transaction_template_service;
+        let result = match form.editing_id {
+            Some(template_id) => service.update_template(UpdateTemplateCommand {
+                template_id,
+                name: form.name,
+                description: form.description,
+                amount,
+                category,
+                transaction_type: form.transaction_type,
+            }),
+            None => service.create_template(CreateTemplateCommand {
+                name: form.name,
+                description: form.description,
+                amount,
+                category,
+                transaction_type: form.transaction_type,
+            }),
+        };
+        match result {
+            Ok(template) => {
+                info!("⭐ Saved template {}", template.id);
+                self.settings.template_form.clear();
+                self.settings.templates = None;
+            }
+            Err(e) => {
+                warn!("⭐ Failed to save template: {}", e);
+                self.settings.template_form.error = Some(format!("Could not save template: {}", e));
+            }
+        }
+    }
+
+    /// Delete one of the current child's templates
+    fn delete_template(&mut self, template_id: String) {
+        let editing = self.settings.template_form.editing_id.as_deref() == Some(template_id.as_str());
+        match self.backend().transaction_template_service.delete_template(DeleteTemplateCommand { template_id }) {
+            Ok(()) => {
+                if editing {
+                    self.settings.template_form.clear();
+                }
+                self.settings.template_form.error = None;
+                self.settings.templates = None;
+            }
+            Err(e) => {
+                warn!("⭐ Failed to delete template: {}", e);
+                self.settings.template_form.error = Some(format!("Could not delete template: {}", e));
+            }
+        }
+    }
+}
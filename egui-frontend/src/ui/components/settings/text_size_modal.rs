@@ -0,0 +1,158 @@
+//! # Text Size Modal
+//!
+//! This module contains the display settings modal: text size, color
+//! theme, the calendar's weekly totals and goal strip, reduced motion and
+//! desktop notifications.
+//!
+//! ## Responsibilities:
+//! - Show the available text sizes with the current one selected
+//! - Apply a new size or theme immediately so it can be previewed
+//! - Turn the totals row under each calendar week on or off
+//! - Show the active goal's progress above the calendar
+//! - Turn off the confetti for anyone sensitive to animation
+//! - Opt into desktop notifications for allowances and completed goals
+//! - Save the choices to the backend so they are used on every launch
+//!
+//! ## Purpose:
+//! Makes the whole app larger for anyone who finds the default text hard
+//! to read, without changing any individual layout, and lets the calendar
+//! double as a weekly budget review.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::desktop_notifications::UpdateDesktopNotificationsCommand;
+use crate::backend::domain::commands::preferences::UpdateDisplayPreferencesCommand;
+use crate::backend::domain::models::display_preferences::{ColorTheme, TextSize};
+use crate::ui::app_state::AllowanceTrackerApp;
+use crate::ui::components::styling::apply_color_theme;
+
+impl AllowanceTrackerApp {
+    /// Render the display settings modal
+    pub fn render_text_size_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_text_size_modal {
+            return;
+        }
+
+        let (current, current_week_summaries, current_goal_strip, current_theme, current_reduce_motion) = match self.backend().preferences_service.get_display_preferences() {
+            Ok(preferences) => (preferences.text_size, preferences.show_week_summaries, preferences.show_goal_strip, preferences.theme, preferences.reduce_motion),
+            Err(e) => {
+                warn!("🔠 Failed to load display preferences: {}", e);
+                (TextSize::default(), self.calendar.show_week_summaries, self.calendar.show_goal_strip, ColorTheme::default(), self.celebration.reduce_motion)
+            }
+        };
+
+        let current_notifications = match self.backend().desktop_notification_service.get_settings() {
+            Ok(settings) => settings,
+            Err(e) => {
+                warn!("🔔 Failed to load desktop notification settings: {}", e);
+                Default::default()
+            }
+        };
+
+        let mut selected = current;
+        let mut show_week_summaries = current_week_summaries;
+        let mut show_goal_strip = current_goal_strip;
+        let mut theme = current_theme;
+        let mut reduce_motion = current_reduce_motion;
+        let mut notify_allowance = current_notifications.allowance_posted;
+        let mut notify_goal = current_notifications.goal_completed;
+        let mut close = false;
+
+        egui::Window::new("🔠 Display")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.label("Make everything in the app bigger and easier to read.");
+                ui.add_space(10.0);
+
+                for size in TextSize::ALL {
+                    ui.radio_value(&mut selected, size, egui::RichText::new(size.label())
+                        .font(egui::FontId::new(14.0 * size.scale_factor(), egui::FontFamily::Proportional)));
+                }
+
+                ui.add_space(10.0);
+                ui.label("Colors:");
+                ui.horizontal(|ui| {
+                    for option in ColorTheme::ALL {
+                        ui.radio_value(&mut theme, option, option.label());
+                    }
+                });
+
+                ui.add_space(10.0);
+                ui.checkbox(&mut show_week_summaries, "Show weekly totals in the calendar")
+                    .on_hover_text("Earned, spent, net change and ending balance under each week");
+                ui.checkbox(&mut show_goal_strip, "Show goal progress above the calendar")
+                    .on_hover_text("How much of the goal is saved and how many days are left");
+                ui.checkbox(&mut reduce_motion, "Reduce motion")
+                    .on_hover_text("Celebrations show their message without confetti");
+
+                ui.add_space(10.0);
+                ui.label("Desktop notifications:");
+                ui.checkbox(&mut notify_allowance, "When an allowance is added")
+                    .on_hover_text("e.g. \"Allowance of $10.00 was added for Maya\"");
+                ui.checkbox(&mut notify_goal, "When a goal is completed");
+
+                if let Some(error) = &self.settings.text_size_error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                if ui.button("Done").clicked() {
+                    close = true;
+                }
+            });
+
+        if selected != current
+            || show_week_summaries != current_week_summaries
+            || show_goal_strip != current_goal_strip
+            || theme != current_theme
+            || reduce_motion != current_reduce_motion
+        {
+            let command = UpdateDisplayPreferencesCommand {
+                text_size: selected,
+                show_week_summaries,
+                show_goal_strip,
+                theme,
+                reduce_motion,
+            };
+            match self.backend().preferences_service.update_display_preferences(command) {
+                Ok(preferences) => {
+                    info!(
+                        "🔠 Display changed: text size {:?}, weekly totals {}, theme {:?}, reduce motion {}",
+                        preferences.text_size, preferences.show_week_summaries, preferences.theme, preferences.reduce_motion
+                    );
+                    ctx.set_zoom_factor(preferences.scale_factor);
+                    apply_color_theme(ctx, preferences.theme);
+                    self.calendar.show_week_summaries = preferences.show_week_summaries;
+                    self.calendar.show_goal_strip = preferences.show_goal_strip;
+                    self.celebration.set_reduce_motion(preferences.reduce_motion);
+                    self.settings.text_size_error = None;
+                }
+                Err(e) => {
+                    warn!("🔠 Failed to save display settings: {}", e);
+                    self.settings.text_size_error = Some(format!("Could not save display settings: {}", e));
+                }
+            }
+        }
+
+        if notify_allowance != current_notifications.allowance_posted || notify_goal != current_notifications.goal_completed {
+            let command = UpdateDesktopNotificationsCommand { allowance_posted: notify_allowance, goal_completed: notify_goal };
+            match self.backend().desktop_notification_service.update_settings(command) {
+                Ok(settings) => {
+                    info!("🔔 Desktop notifications changed: {:?}", settings);
+                    self.settings.text_size_error = None;
+                }
+                Err(e) => {
+                    warn!("🔔 Failed to save desktop notification settings: {}", e);
+                    self.settings.text_size_error = Some(format!("Could not save notification settings: {}", e));
+                }
+            }
+        }
+
+        if close {
+            self.settings.show_text_size_modal = false;
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! # Updates Modal
+//!
+//! This module contains the modal for the release channel and the update
+//! check.
+//!
+//! ## Responsibilities:
+//! - Choose between stable releases and betas
+//! - Turn the daily check on or off; it is off until a parent allows it
+//! - Check for a newer release and show its changelog
+//! - Open the download page, or postpone the update
+//!
+//! ## Purpose:
+//! Lets a family stay current without watching for new builds. The app
+//! only contacts the release list when a parent checks here or turns on the
+//! daily check; this is where a parent reads what changed and decides when
+//! to install. Checks run on the loader thread, so a slow network doesn't
+//! freeze the window.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::updates::{PostponeUpdateCommand, UpdateAutomaticCheckCommand, UpdateReleaseChannelCommand};
+use crate::backend::domain::models::update_settings::ReleaseChannel;
+use crate::ui::app_state::AllowanceTrackerApp;
+use crate::ui::components::data_loading::DataRequest;
+
+impl AllowanceTrackerApp {
+    /// Render the updates modal
+    pub fn render_updates_modal(&mut self, ctx: &egui::Context) {
+        if !self.settings.show_updates_modal {
+            return;
+        }
+
+        let saved_channel = self.settings.updates_form.channel;
+        let mut channel = saved_channel;
+        let saved_check_automatically = self.settings.updates_form.check_automatically;
+        let mut check_automatically = saved_check_automatically;
+        let checking = self.loader.is_loading(&DataRequest::UpdateCheck { scheduled: false });
+        let mut check_clicked = false;
+        let mut download_clicked = false;
+        let mut later_clicked = false;
+        let mut close = false;
+
+        egui::Window::new("⬆️ Updates")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let form = &self.settings.updates_form;
+                ui.label(format!("You are running version {}.", env!("CARGO_PKG_VERSION")));
+                ui.add_space(8.0);
+
+                ui.horizontal(|ui| {
+                    ui.label("Release channel:");
+                    for option in ReleaseChannel::ALL {
+                        ui.radio_value(&mut channel, option, option.label());
+                    }
+                });
+                ui.label(egui::RichText::new("Betas get new features first, and may have rough edges.")
+                    .color(egui::Color32::from_rgb(120, 120, 120)));
+                ui.add_space(6.0);
+                ui.checkbox(&mut check_automatically, "Check for updates once a day")
+                    .on_hover_text("Asks GitHub for the list of releases; nothing about your family is sent");
+                ui.add_space(10.0);
+
+                match form.check.as_ref().map(|check| &check.available) {
+                    Some(Some(update)) => {
+                        ui.label(egui::RichText::new(format!(
+                            "Version {}{} is available",
+                            update.version,
+                            if update.prerelease { " (beta)" } else { "" }
+                        )).strong());
+                        if let Some(published_at) = update.published_at {
+                            ui.label(egui::RichText::new(format!("Released {}", published_at.format("%B %-d, %Y"))).small());
+                        }
+                        if !update.changelog.is_empty() {
+                            ui.add_space(4.0);
+                            egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                                ui.label(&update.changelog);
+                            });
+                        }
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            if ui.button("Download").on_hover_text("Open the download page; run the installer when it suits you").clicked() {
+                                download_clicked = true;
+                            }
+                            if ui.button("Later").on_hover_text("Don't remind me about this version").clicked() {
+                                later_clicked = true;
+                            }
+                        });
+                    }
+                    Some(None) => {
+                        ui.label("You have the latest version.");
+                    }
+                    None => {}
+                }
+
+                if let Some(error) = &form.error {
+                    ui.add_space(6.0);
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                }
+
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(!checking, egui::Button::new("Check now")).clicked() {
+                        check_clicked = true;
+                    }
+                    if checking {
+                        ui.spinner();
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if channel != saved_channel {
+            match self.backend().update_service.update_release_channel(UpdateReleaseChannelCommand { channel }) {
+                Ok(settings) => {
+                    self.settings.updates_form.channel = settings.channel;
+                    self.settings.updates_form.check = None;
+                    self.settings.updates_form.error = None;
+                    check_clicked = true;
+                }
+                Err(e) => {
+                    warn!("⬆️ Failed to change release channel: {}", e);
+                    self.settings.updates_form.error = Some(format!("Could not change the release channel: {}", e));
+                }
+            }
+        }
+        if check_automatically != saved_check_automatically {
+            match self.backend().update_service.update_automatic_check(UpdateAutomaticCheckCommand { enabled: check_automatically }) {
+                Ok(settings) => {
+                    self.settings.updates_form.check_automatically = settings.check_automatically;
+                    self.settings.updates_form.error = None;
+                }
+                Err(e) => {
+                    warn!("⬆️ Failed to change automatic update checks: {}", e);
+                    self.settings.updates_form.error = Some(format!("Could not save the setting: {}", e));
+                }
+            }
+        }
+        if check_clicked {
+            // The result is applied by poll_background_loads
+            self.loader.request(DataRequest::UpdateCheck { scheduled: false });
+        }
+        let available = self.settings.updates_form.check.as_ref().and_then(|check| check.available.clone());
+        if let Some(update) = available {
+            if download_clicked {
+                info!("⬆️ Opening download page for {}", update.version);
+                ctx.open_url(egui::OpenUrl::new_tab(&update.download_url));
+            }
+            if later_clicked {
+                match self.backend().update_service.postpone_update(PostponeUpdateCommand { version: update.version.clone() }) {
+                    Ok(_) => {
+                        self.ui.set_success(format!("We won't remind you about version {} again", update.version));
+                        self.settings.show_updates_modal = false;
+                    }
+                    Err(e) => {
+                        warn!("⬆️ Failed to postpone update: {}", e);
+                        self.settings.updates_form.error = Some(format!("Could not postpone the update: {}", e));
+                    }
+                }
+            }
+        }
+        if close {
+            self.settings.show_updates_modal = false;
+        }
+    }
+}
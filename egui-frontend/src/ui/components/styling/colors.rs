@@ -9,7 +9,7 @@
 //! - Interactive colors (buttons, hover states)
 //! - Layout colors (backgrounds, cards)
 //! - Typography colors (text)
-//! - Calendar colors (day headers, chips)
+//! - Calendar colors (day headers, chips, transaction kinds)
 //! - Legacy colors (for backward compatibility)
 //!
 //! ## Usage:
@@ -120,6 +120,23 @@ pub const CALENDAR_CURRENT_MONTH_BG: Color32 = CURRENT_THEME.calendar.current_mo
 /// Filler day background (grayed out)
 pub const CALENDAR_FILLER_DAY_BG: Color32 = CURRENT_THEME.calendar.filler_day_bg;
 
+/// Color for a kind of transaction, such as a bonus, in chips and tables;
+/// `None` for plain income, expense and future allowance, which keep their usual colors
+pub fn transaction_kind_color(kind: &shared::TransactionType) -> Option<Color32> {
+    match kind {
+        shared::TransactionType::Income
+        | shared::TransactionType::Expense
+        | shared::TransactionType::FutureAllowance => None,
+        shared::TransactionType::AllowancePayment => Some(Color32::from_rgb(46, 139, 87)),
+        shared::TransactionType::Bonus => Some(Color32::from_rgb(218, 165, 32)),
+        shared::TransactionType::Penalty => Some(Color32::from_rgb(205, 72, 72)),
+        shared::TransactionType::Gift => Some(Color32::from_rgb(199, 21, 133)),
+        shared::TransactionType::Transfer => Some(Color32::from_rgb(70, 130, 180)),
+        shared::TransactionType::Interest => Some(Color32::from_rgb(0, 150, 136)),
+        shared::TransactionType::Adjustment => Some(Color32::from_rgb(112, 112, 112)),
+    }
+}
+
 // ============================================================================
 // Table Colors
 // ============================================================================
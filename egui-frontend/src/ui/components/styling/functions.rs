@@ -5,8 +5,9 @@
 //!
 //! ## Key Functions:
 //! - `setup_kid_friendly_style()` - Configure global egui styling
+//! - `apply_color_theme()` - Switch between the light, dark and system themes
 //! - `draw_solid_purple_background()` - Draw solid background color
-//! - `draw_image_background()` - Draw gradient background with image
+//! - `draw_image_background()` - Draw the child's background: the tinted photo, a theme pack or their own picture
 //! - `draw_card_container()` - Draw card-style containers with shadows
 //! - `draw_day_header_gradient()` - Draw gradient headers for calendar days
 //! - `get_table_header_color()` - Get colors for table headers
@@ -16,13 +17,28 @@
 //! for common styling patterns used throughout the app.
 
 use eframe::egui;
-use super::{theme::CURRENT_THEME, colors};
+use crate::backend::domain::models::background::{ChildBackground, ThemePack};
+use crate::backend::domain::models::display_preferences::ColorTheme;
+use super::theme::Theme;
+
+/// Use the light or dark theme, or follow the operating system's
+///
+/// egui keeps a style for each theme and picks one every frame, so this only
+/// has to be called when the preference changes.
+pub fn apply_color_theme(ctx: &egui::Context, theme: ColorTheme) {
+    ctx.set_theme(match theme {
+        ColorTheme::Light => egui::ThemePreference::Light,
+        ColorTheme::Dark => egui::ThemePreference::Dark,
+        ColorTheme::System => egui::ThemePreference::System,
+    });
+}
 
 /// Setup kid-friendly UI styling for the entire application
 /// 
 /// This function configures the global egui style to create a welcoming,
 /// kid-friendly interface with appropriate fonts, colors, and spacing.
 pub fn setup_kid_friendly_style(ctx: &egui::Context) {
+    let theme = Theme::current(ctx);
     ctx.set_style({
         let mut style = (*ctx.style()).clone();
         
@@ -33,7 +49,7 @@ pub fn setup_kid_friendly_style(ctx: &egui::Context) {
         
         // CRITICAL: Set text edit background color so text fields are visible
         // In egui 0.28, text edits use extreme_bg_color (not text_edit_bg_color which was added later)
-        style.visuals.extreme_bg_color = CURRENT_THEME.interactive.inactive_background;
+        style.visuals.extreme_bg_color = theme.interactive.inactive_background;
         
         // Use Chalkboard font family if available, otherwise fall back to Proportional
         let font_family = if ctx.fonts(|fonts| fonts.families().contains(&egui::FontFamily::Name("Chalkboard".into()))) {
@@ -74,22 +90,46 @@ pub fn setup_kid_friendly_style(ctx: &egui::Context) {
 /// a consistent purple color matching the theme.
 pub fn draw_solid_purple_background(ui: &mut egui::Ui, rect: egui::Rect) {
     // Use the nice purple color from the theme
-    let purple_color = CURRENT_THEME.calendar.header_mid;
+    let purple_color = Theme::current(ui.ctx()).calendar.header_mid;
     
     // Draw solid purple background for this column
     ui.painter().rect_filled(rect, egui::CornerRadius::ZERO, purple_color);
 }
 
-/// Draw image background with blue overlay (replacing gradient)
-/// 
-/// This function draws the main application background using the background image
-/// with a blue tint. Falls back to a solid color if the image fails to load.
-pub fn draw_image_background(ui: &mut egui::Ui, rect: egui::Rect) {
+/// Draw the current child's background
+///
+/// The classic pack is the bundled photo with a blue tint, the other packs are
+/// gradients, and a custom picture is loaded from disk. Until a custom picture
+/// has loaded, or if it can't be read, the classic background is drawn instead.
+pub fn draw_image_background(ui: &mut egui::Ui, rect: egui::Rect, background: &ChildBackground) {
+    match background {
+        ChildBackground::Pack { pack: ThemePack::Classic } => draw_classic_background(ui, rect),
+        ChildBackground::Pack { pack } => {
+            let (top, bottom) = theme_pack_colors(*pack, ui.visuals().dark_mode);
+            paint_vertical_gradient(ui.painter(), rect, top, bottom);
+        }
+        ChildBackground::CustomImage { path } => {
+            let image = egui::Image::new(format!("file://{}", path))
+                .fit_to_exact_size(rect.size())
+                .tint(Theme::current(ui.ctx()).layout.photo_tint);
+            match image.load_for_size(ui.ctx(), rect.size()) {
+                Ok(egui::load::TexturePoll::Ready { .. }) => image.paint_at(ui, rect),
+                _ => draw_classic_background(ui, rect),
+            }
+        }
+    }
+}
+
+/// Draw the bundled photo with blue overlay
+///
+/// Falls back to a solid color if the image fails to load.
+fn draw_classic_background(ui: &mut egui::Ui, rect: egui::Rect) {
     let painter = ui.painter();
+    let theme = Theme::current(ui.ctx());
     
-    // Load and paint the background image with blue tint
+    // Load and paint the background image with blue tint (a darker blue in the dark theme)
     let image_source = egui::include_image!("../../../../assets/background.jpg");
-    let blue_tint = egui::Color32::from_rgba_premultiplied(173, 216, 230, 180); // Light blue tint
+    let blue_tint = theme.layout.background_tint;
     let image = egui::Image::new(image_source)
         .fit_to_exact_size(rect.size())
         .tint(blue_tint); // Apply blue tint directly to the image
@@ -101,12 +141,41 @@ pub fn draw_image_background(ui: &mut egui::Ui, rect: egui::Rect) {
         }
         Err(_e) => {
             // Fallback to a solid color if image fails to load
-            let fallback_color = CURRENT_THEME.layout.gradient_bottom; // Light blue
+            let fallback_color = theme.layout.gradient_bottom; // Light blue, or night blue
             painter.rect_filled(rect, egui::CornerRadius::ZERO, fallback_color);
         }
     }
 }
 
+/// Top and bottom colors of a gradient theme pack, deeper in the dark theme
+pub fn theme_pack_colors(pack: ThemePack, dark_mode: bool) -> (egui::Color32, egui::Color32) {
+    use egui::Color32;
+    match (pack, dark_mode) {
+        (ThemePack::Classic, false) => (Color32::from_rgb(255, 182, 193), Color32::from_rgb(173, 216, 230)),
+        (ThemePack::Classic, true) => (Color32::from_rgb(74, 44, 82), Color32::from_rgb(30, 42, 66)),
+        (ThemePack::Ocean, false) => (Color32::from_rgb(178, 235, 242), Color32::from_rgb(77, 160, 210)),
+        (ThemePack::Ocean, true) => (Color32::from_rgb(20, 70, 100), Color32::from_rgb(8, 28, 52)),
+        (ThemePack::Meadow, false) => (Color32::from_rgb(220, 245, 200), Color32::from_rgb(130, 200, 120)),
+        (ThemePack::Meadow, true) => (Color32::from_rgb(40, 70, 45), Color32::from_rgb(18, 38, 24)),
+        (ThemePack::Sunset, false) => (Color32::from_rgb(255, 204, 128), Color32::from_rgb(240, 128, 150)),
+        (ThemePack::Sunset, true) => (Color32::from_rgb(110, 50, 60), Color32::from_rgb(45, 25, 55)),
+        (ThemePack::StarryNight, false) => (Color32::from_rgb(120, 110, 190), Color32::from_rgb(50, 50, 110)),
+        (ThemePack::StarryNight, true) => (Color32::from_rgb(40, 35, 80), Color32::from_rgb(10, 10, 30)),
+    }
+}
+
+/// Fill a rectangle with a gradient from `top` to `bottom`
+fn paint_vertical_gradient(painter: &egui::Painter, rect: egui::Rect, top: egui::Color32, bottom: egui::Color32) {
+    let mut mesh = egui::Mesh::default();
+    mesh.colored_vertex(rect.left_top(), top);
+    mesh.colored_vertex(rect.right_top(), top);
+    mesh.colored_vertex(rect.left_bottom(), bottom);
+    mesh.colored_vertex(rect.right_bottom(), bottom);
+    mesh.add_triangle(0, 1, 2);
+    mesh.add_triangle(1, 3, 2);
+    painter.add(egui::Shape::mesh(mesh));
+}
+
 /// Draw a modern card container with white background and shadow
 /// 
 /// This function creates the standard card appearance used throughout the app,
@@ -129,6 +198,7 @@ pub fn draw_card_container_frosted(ui: &mut egui::Ui, rect: egui::Rect, rounding
 /// using egui 0.31's new opacity features for enhanced visual appeal.
 fn draw_card_container_with_options(ui: &mut egui::Ui, rect: egui::Rect, rounding: u8, frosted: bool) {
     let painter = ui.painter();
+    let theme = Theme::current(ui.ctx());
     
     if frosted {
         // FROSTED GLASS EFFECT
@@ -155,11 +225,16 @@ fn draw_card_container_with_options(ui: &mut egui::Ui, rect: egui::Rect, roundin
             egui::Color32::from_rgba_unmultiplied(0, 0, 0, 25)
         );
         
-        // Frosted glass background - semi-transparent white with subtle tint
+        // Frosted glass background - semi-transparent white with subtle tint (smoked glass in the dark theme)
+        let glass_color = if ui.visuals().dark_mode {
+            egui::Color32::from_rgba_unmultiplied(30, 32, 42, 170)
+        } else {
+            egui::Color32::from_rgba_unmultiplied(255, 255, 255, 150) // Much more transparent for noticeable effect
+        };
         painter.rect_filled(
             rect, 
             egui::CornerRadius::same(rounding), 
-            egui::Color32::from_rgba_unmultiplied(255, 255, 255, 150) // Much more transparent for noticeable effect
+            glass_color
         );
         
         // Add a subtle border for the glass effect
@@ -190,10 +265,10 @@ fn draw_card_container_with_options(ui: &mut egui::Ui, rect: egui::Rect, roundin
             rect.min + egui::vec2(2.0, 2.0),
             rect.size(),
         );
-        painter.rect_filled(shadow_rect, egui::CornerRadius::same(rounding), colors::CARD_SHADOW);
+        painter.rect_filled(shadow_rect, egui::CornerRadius::same(rounding), theme.layout.card_shadow);
         
         // Draw solid white background
-        painter.rect_filled(rect, egui::CornerRadius::same(rounding), colors::CARD_BACKGROUND);
+        painter.rect_filled(rect, egui::CornerRadius::same(rounding), theme.layout.card_background);
     }
 }
 
@@ -202,25 +277,16 @@ fn draw_card_container_with_options(ui: &mut egui::Ui, rect: egui::Rect, roundin
 /// Creates a smooth pink-to-purple gradient across calendar day headers
 /// based on the day index (0-6 for Monday-Sunday).
 pub fn draw_day_header_gradient(ui: &mut egui::Ui, rect: egui::Rect, day_index: usize) {
-    let painter = ui.painter();
-    
-    // Calculate color based on day index (0-6 for Mon-Sun)
-    let t = day_index as f32 / 6.0; // 0.0 to 1.0
-    
     // Smooth pink-to-purple gradient across all 7 days (no blue transition)
-    let color = egui::Color32::from_rgb(
-        (colors::CALENDAR_HEADER_START.r() as f32 * (1.0 - t) + colors::CALENDAR_HEADER_MID.r() as f32 * t) as u8,
-        (colors::CALENDAR_HEADER_START.g() as f32 * (1.0 - t) + colors::CALENDAR_HEADER_MID.g() as f32 * t) as u8,
-        (colors::CALENDAR_HEADER_START.b() as f32 * (1.0 - t) + colors::CALENDAR_HEADER_MID.b() as f32 * t) as u8,
-    );
+    let color = Theme::current(ui.ctx()).calendar_header_color(day_index);
     
-    painter.rect_filled(rect, egui::CornerRadius::same(5), color);
+    ui.painter().rect_filled(rect, egui::CornerRadius::same(5), color);
 }
 
 /// Get table header color that matches calendar day header style
 /// 
 /// Returns a color from the pink-to-purple gradient for table headers,
 /// ensuring visual consistency between calendar and table styling.
-pub fn get_table_header_color(header_index: usize) -> egui::Color32 {
-    CURRENT_THEME.table_header_color(header_index)
+pub fn get_table_header_color(ctx: &egui::Context, header_index: usize) -> egui::Color32 {
+    Theme::current(ctx).table_header_color(header_index)
 } 
\ No newline at end of file
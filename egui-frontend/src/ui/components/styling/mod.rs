@@ -42,6 +42,6 @@ pub mod colors;
 pub mod functions;
 
 // Re-export the most commonly used items for convenience
-pub use theme::{Theme, CURRENT_THEME};
+pub use theme::{Theme, CURRENT_THEME, DARK_THEME};
 pub use colors::*;
 pub use functions::*; 
\ No newline at end of file
@@ -11,11 +11,12 @@
 //! - `CalendarColors` - Calendar-specific styling
 //! - `TableColors` - Table headers, rows, borders
 //!
-//! ## Future Theming Support:
-//! This structure is designed to support multiple themes in the future while maintaining
-//! a consistent API for components.
+//! ## Light and Dark:
+//! `CURRENT_THEME` is the light theme and `DARK_THEME` its dark counterpart. Components
+//! look up the one in use with `Theme::current(ctx)`, which follows egui's active
+//! theme, so a "match my computer" preference switches along with the system.
 
-use eframe::egui::Color32;
+use eframe::egui::{self, Color32};
 
 /// Main theme configuration structure
 /// 
@@ -63,6 +64,15 @@ pub struct LayoutColors {
     pub card_background: Color32,
     pub card_shadow: Color32,
     pub card_border: Color32,
+    /// Background of modal dialogs
+    pub modal_background: Color32,
+    /// Quiet fill for secondary buttons and inset areas
+    pub subtle_background: Color32,
+    /// Tint multiplied into the app's background image
+    pub background_tint: Color32,
+    /// Tint multiplied into a child's own background picture, dimming it
+    /// just enough for the cards to stand out
+    pub photo_tint: Color32,
 }
 
 /// Text and typography colors
@@ -78,6 +88,10 @@ pub struct TypographyColors {
     pub active: Color32,
     /// White text (for dark backgrounds)
     pub white: Color32,
+    /// Strongest text, for day numbers and table descriptions
+    pub body: Color32,
+    /// Muted text for hints and reversed or locked rows
+    pub muted: Color32,
 }
 
 /// Calendar-specific colors
@@ -98,6 +112,9 @@ pub struct CalendarColors {
     /// Day type backgrounds
     pub current_month_bg: Color32,
     pub filler_day_bg: Color32,
+    /// Transaction chip backgrounds
+    pub chip_background: Color32,
+    pub chip_hover_background: Color32,
 }
 
 /// Table-specific colors
@@ -112,7 +129,7 @@ pub struct TableColors {
     pub border: Color32,
 }
 
-/// The current active theme - Kid-Friendly theme with purple accents
+/// The light theme - Kid-Friendly theme with purple accents, and the default
 pub const CURRENT_THEME: Theme = Theme {
     interactive: InteractiveColors {
         // PRIMARY: Purple-blue color for consistent hover outlines across all buttons
@@ -137,6 +154,11 @@ pub const CURRENT_THEME: Theme = Theme {
         card_background: Color32::WHITE,
         card_shadow: Color32::from_rgba_premultiplied(0, 0, 0, 20),
         card_border: Color32::from_rgb(220, 220, 220),
+        modal_background: Color32::WHITE,
+        subtle_background: Color32::from_rgb(240, 240, 240),
+        // Light blue tint over the background image
+        background_tint: Color32::from_rgba_premultiplied(173, 216, 230, 180),
+        photo_tint: Color32::from_gray(225),
     },
     typography: TypographyColors {
         // Text colors
@@ -145,6 +167,8 @@ pub const CURRENT_THEME: Theme = Theme {
         heading: Color32::from_rgb(70, 70, 70),
         active: Color32::from_rgb(79, 109, 245),
         white: Color32::WHITE,
+        body: Color32::BLACK,
+        muted: Color32::from_rgb(120, 120, 120),
     },
     calendar: CalendarColors {
         // Today's date gets the pink outline for visibility
@@ -162,6 +186,9 @@ pub const CURRENT_THEME: Theme = Theme {
         // Day backgrounds
         current_month_bg: Color32::from_rgba_premultiplied(255, 255, 255, 55),
         filler_day_bg: Color32::from_rgba_premultiplied(120, 120, 120, 120),
+        // Chips are solid white so their text reads over any day background
+        chip_background: Color32::WHITE,
+        chip_hover_background: Color32::from_rgb(245, 245, 245),
     },
     table: TableColors {
         // Header gradient colors (pink to purple across 4 columns)
@@ -178,8 +205,83 @@ pub const CURRENT_THEME: Theme = Theme {
     },
 };
 
+/// The dark theme - the same purple accents over deep blue-gray surfaces
+pub const DARK_THEME: Theme = Theme {
+    interactive: InteractiveColors {
+        // Lighter purple-blue so outlines stand out on dark cards
+        hover_border: Color32::from_rgb(150, 145, 240),
+        hover_border_secondary: Color32::from_rgb(232, 150, 199),
+        hover_background: Color32::from_rgba_premultiplied(20, 20, 20, 20),
+        active_background: Color32::from_rgb(92, 120, 245),
+        inactive_background: Color32::from_rgb(52, 54, 66),
+        button_border_normal: Color32::from_rgb(80, 82, 96),
+        button_border_active: Color32::from_rgb(110, 112, 128),
+    },
+    layout: LayoutColors {
+        gradient_top: Color32::from_rgb(74, 44, 82),
+        gradient_bottom: Color32::from_rgb(30, 42, 66),
+        card_background: Color32::from_rgb(38, 40, 50),
+        card_shadow: Color32::from_rgba_premultiplied(0, 0, 0, 60),
+        card_border: Color32::from_rgb(70, 72, 86),
+        modal_background: Color32::from_rgb(44, 46, 58),
+        subtle_background: Color32::from_rgb(60, 62, 76),
+        // Darkens the background image to a night-time blue
+        background_tint: Color32::from_rgb(70, 80, 115),
+        photo_tint: Color32::from_gray(110),
+    },
+    typography: TypographyColors {
+        primary: Color32::from_rgb(225, 225, 232),
+        secondary: Color32::from_rgb(190, 190, 200),
+        heading: Color32::from_rgb(235, 235, 240),
+        active: Color32::from_rgb(140, 160, 255),
+        white: Color32::WHITE,
+        body: Color32::from_rgb(240, 240, 245),
+        muted: Color32::from_rgb(150, 150, 160),
+    },
+    calendar: CalendarColors {
+        today_border: Color32::from_rgb(232, 150, 199),
+        selected_background: Color32::from_rgba_premultiplied(90, 60, 100, 140),
+        selected_border: Color32::from_rgb(199, 112, 221),
+        // Deeper versions of the light headers so white text keeps its contrast
+        header_start: Color32::from_rgb(170, 90, 120),
+        header_mid: Color32::from_rgb(120, 50, 150),
+        header_end: Color32::from_rgb(60, 110, 150),
+        income_chip: Color32::from_rgb(80, 190, 100),
+        expense_chip: Color32::from_rgb(240, 90, 110),
+        current_month_bg: Color32::from_rgba_premultiplied(20, 20, 26, 140),
+        filler_day_bg: Color32::from_rgba_premultiplied(10, 10, 12, 160),
+        chip_background: Color32::from_rgb(46, 48, 60),
+        chip_hover_background: Color32::from_rgb(60, 62, 76),
+    },
+    table: TableColors {
+        header_colors: [
+            Color32::from_rgb(170, 90, 120),
+            Color32::from_rgb(155, 75, 130),
+            Color32::from_rgb(138, 62, 140),
+            Color32::from_rgb(120, 50, 150),
+        ],
+        row_even: Color32::from_rgb(38, 40, 50),
+        row_odd: Color32::from_rgb(44, 46, 58),
+        border: Color32::from_rgb(70, 72, 86),
+    },
+};
+
 /// Helper functions for common styling patterns
 impl Theme {
+    /// The light or dark theme
+    pub fn for_dark_mode(dark_mode: bool) -> &'static Theme {
+        if dark_mode {
+            &DARK_THEME
+        } else {
+            &CURRENT_THEME
+        }
+    }
+    
+    /// The theme egui is drawing with right now
+    pub fn current(ctx: &egui::Context) -> &'static Theme {
+        Self::for_dark_mode(ctx.theme() == egui::Theme::Dark)
+    }
+    
     /// Get hover border color for interactive elements
     pub fn hover_border(&self) -> Color32 {
         self.interactive.hover_border
@@ -13,6 +13,7 @@
 //! ## Tab Flow:
 //! - MainTab::Calendar -> Renders calendar view with transactions
 //! - MainTab::Table -> Renders transaction table view
+//! - MainTab::Family -> Renders the overview of every child
 //! - Future tabs can be easily added by extending the MainTab enum
 
 use eframe::egui;
@@ -70,6 +71,14 @@ impl AllowanceTrackerApp {
                     
                     self.draw_goal_section(ui, available_rect);
                     
+                    // Small bottom spacing to prevent edge contact
+                    ui.add_space(10.0);
+                }
+                MainTab::Family => {
+                    // Use full available space - let the family view manage its own margins
+                    let available_rect = ui.available_rect_before_wrap();
+                    self.draw_family_section(ui, available_rect);
+                    
                     // Small bottom spacing to prevent edge contact
                     ui.add_space(10.0);
                 }
@@ -5,7 +5,8 @@
 //!
 //! ## Key Functions:
 //! - `draw_transactions_section_with_toggle()` - Renders table with collapsible header
-//! - `draw_table_content()` - Renders the actual transaction table content
+//! - `draw_infinite_scroll_table_content()` - Renders the rows and fetches the next page near the end
+//! - `copy_selected_transactions()` - Copies selected rows to the clipboard for spreadsheets
 //!
 //! ## Purpose:
 //! This module provides the table view functionality for viewing transaction history.
@@ -15,10 +16,15 @@
 //! ## Features:
 //! - Responsive design that adapts to different screen sizes
 //! - Toggle header integration for consistent UI
+//! - Pages of 50 transactions fetched as the user scrolls, with a "Load more" fallback
 //! - Reuses the existing transaction table component for consistency
+//! - Click rows to select them, then copy them as TSV or CSV
+//! - In delete mode, rows get check boxes and feed the same selection as calendar chips
+//! - Right-click a row to edit, delete or duplicate it
 
 use eframe::egui;
 use shared::Transaction;
+use crate::backend::domain::transaction_table::{ClipboardFormat, DateFormat, TransactionTableConfig, TransactionTableService};
 use crate::ui::app_state::AllowanceTrackerApp;
 
 impl AllowanceTrackerApp {
@@ -55,84 +61,83 @@ impl AllowanceTrackerApp {
     
     /// Draw infinite scroll table content with loading detection
     pub fn draw_infinite_scroll_table_content(&mut self, ui: &mut egui::Ui, content_rect: egui::Rect, transactions: &[Transaction]) {
-        let mut content_ui = ui.new_child(egui::UiBuilder::new().max_rect(content_rect).layout(egui::Layout::top_down(egui::Align::Min)));
+        let locale = self.core.current_locale;
         
-        // Calculate actual content height to determine if we need scrolling
-        let row_height = 25.0; // Match the transaction table row height
-        let header_height = 40.0;
-        let estimated_content_height = header_height + (transactions.len() as f32 * row_height) + 100.0; // 100px buffer for loading indicators
+        // The table scrolls its own rows; keep a strip underneath for paging status
+        let footer_height = 36.0;
+        let table_rect = egui::Rect::from_min_size(
+            content_rect.min,
+            egui::vec2(content_rect.width(), (content_rect.height() - footer_height).max(0.0))
+        );
+        let footer_rect = egui::Rect::from_min_max(
+            egui::pos2(content_rect.left() + 40.0, table_rect.bottom()),
+            egui::pos2(content_rect.right() - 40.0, content_rect.bottom())
+        );
         
-        let needs_scrolling = estimated_content_height > content_rect.height() || self.table.can_load_more();
+        let mut table_ui = ui.new_child(egui::UiBuilder::new().max_rect(table_rect).layout(egui::Layout::top_down(egui::Align::Min)));
+        use crate::ui::components::transaction_table::render_responsive_transaction_table;
+        // In delete mode, clicks pick rows for deletion instead of for copying
+        let delete_mode = self.interaction.transaction_selection_mode;
+        let selected_ids = if delete_mode {
+            &mut self.interaction.selected_transaction_ids
+        } else {
+            &mut self.table.selected_ids
+        };
+        let output = render_responsive_transaction_table(&mut table_ui, table_rect, transactions, locale, selected_ids, &self.table.reversal_roles, delete_mode);
+        if let Some((action, transaction)) = output.row_action {
+            self.handle_table_row_action(action, transaction);
+        }
         
-        if needs_scrolling {
-            // Use ScrollArea with infinite scroll when content doesn't fit or there's more to load
-            let scroll_area_response = egui::ScrollArea::vertical()
-                .auto_shrink([false; 2])
-                .max_height(content_rect.height())
-                .show(&mut content_ui, |ui| {
-                    // Use the existing beautiful table implementation
-                    use crate::ui::components::transaction_table::render_responsive_transaction_table;
-                    render_responsive_transaction_table(ui, content_rect, transactions);
-                    
-                    // Loading indicator when fetching more
-                    if self.table.is_loading_more {
-                        ui.add_space(10.0);
-                        ui.horizontal(|ui| {
-                            ui.spinner();
-                            ui.label("Loading more transactions...");
-                        });
-                    }
-                    
-                    // Pagination error display
-                    if let Some(error) = &self.table.pagination_error {
-                        ui.add_space(10.0);
-                        ui.colored_label(egui::Color32::RED, format!("Error: {}", error));
-                    }
-                    
-                    // "No more transactions" indicator
-                    if !self.table.has_more_transactions && self.table.initial_load_complete && !self.table.is_loading_more && self.table.transaction_count() > 0 {
-                        ui.add_space(10.0);
-                        ui.colored_label(egui::Color32::GRAY, "No more transactions to load");
-                    }
-                    
-                    // ONLY add trigger area if there are actually more transactions to load
-                    if self.table.can_load_more() {
-                        let trigger_response = ui.allocate_response(
-                            egui::vec2(ui.available_width(), 50.0),
-                            egui::Sense::hover()
-                        );
-                        
-                        // Trigger loading when this area becomes visible
-                        if trigger_response.rect.intersects(ui.clip_rect()) {
-                            log::info!("📋 Infinite scroll trigger area visible - loading more transactions");
-                            self.load_more_table_transactions();
-                        }
-                    }
-                });
-            
-            // Alternative scroll detection: trigger when scrolled near bottom (only if there's more to load)
-            if self.table.can_load_more() {
-                let scroll_offset = scroll_area_response.state.offset.y;
-                let content_height = scroll_area_response.inner_rect.height();
-                let visible_height = content_rect.height();
-                
-                // If scrolled to within 200px of bottom, trigger loading
-                if content_height > visible_height && scroll_offset + visible_height + 200.0 >= content_height {
-                    log::info!("📋 Near bottom of scroll - loading more transactions (offset: {:.0}, content: {:.0}, visible: {:.0})", 
-                              scroll_offset, content_height, visible_height);
+        // Fetch the next page once the user has scrolled close to the last loaded row
+        if output.near_end && self.table.initial_load_complete && self.table.can_load_more() && self.table.pagination_error.is_none() {
+            log::info!("📋 Near end of table ({} rows) - loading more transactions", self.table.transaction_count());
+            self.load_more_table_transactions();
+        }
+        
+        let mut footer_ui = ui.new_child(egui::UiBuilder::new().max_rect(footer_rect).layout(egui::Layout::left_to_right(egui::Align::Center)));
+        self.draw_table_paging_status(&mut footer_ui);
+    }
+    
+    /// Loading spinner, error with retry, or a "Load more" button under the table
+    fn draw_table_paging_status(&mut self, ui: &mut egui::Ui) {
+        if self.table.is_loading_more {
+            ui.spinner();
+            ui.label("Loading more transactions...");
+        } else if let Some(error) = self.table.pagination_error.clone() {
+            ui.colored_label(egui::Color32::RED, format!("Error: {}", error));
+            if ui.small_button("Retry").clicked() {
+                if self.table.initial_load_complete {
                     self.load_more_table_transactions();
+                } else {
+                    self.load_initial_table_transactions();
                 }
             }
-        } else {
-            // Content fits perfectly - no ScrollArea needed, just render directly
-            use crate::ui::components::transaction_table::render_responsive_transaction_table;
-            render_responsive_transaction_table(&mut content_ui, content_rect, transactions);
-            
-            // Show any error messages even without scrolling
-            if let Some(error) = &self.table.pagination_error {
-                content_ui.add_space(10.0);
-                content_ui.colored_label(egui::Color32::RED, format!("Error: {}", error));
+        } else if self.table.can_load_more() && self.table.initial_load_complete {
+            ui.colored_label(egui::Color32::GRAY, format!("Showing {} transactions", self.table.transaction_count()));
+            if ui.small_button("Load more").clicked() {
+                self.load_more_table_transactions();
             }
+        } else if self.table.initial_load_complete && self.table.transaction_count() > 0 {
+            ui.colored_label(egui::Color32::GRAY, format!("All {} transactions loaded", self.table.transaction_count()));
         }
     }
-} 
\ No newline at end of file
+    
+    /// Copy the selected rows to the clipboard, ready to paste into a spreadsheet
+    pub fn copy_selected_transactions(&mut self, ctx: &egui::Context, format: ClipboardFormat) {
+        let selected = self.table.selected_transactions();
+        if selected.is_empty() {
+            return;
+        }
+        
+        let config = TransactionTableConfig {
+            date_format: DateFormat::ShortDate,
+            locale: self.core.current_locale,
+            currency: self.core.current_currency,
+            ..TransactionTableConfig::default()
+        };
+        let text = TransactionTableService::with_config(config).format_for_clipboard(&selected, format);
+        ctx.copy_text(text);
+        
+        log::info!("📋 Copied {} transactions to the clipboard as {:?}", selected.len(), format);
+    }
+}
@@ -0,0 +1,155 @@
+//! # Transaction Move
+//!
+//! This module moves a transaction to the day its chip was dropped on in
+//! the calendar, and offers to undo the move for a few seconds afterwards.
+//!
+//! ## Responsibilities:
+//! - Ask for the parental answer first when the family's policy says so
+//! - Move the transaction with the backend's edit command, which brings
+//!   every later balance up to date
+//! - Show an undo toast after a move, and move the transaction back from it
+//!
+//! ## Purpose:
+//! A purchase entered on the wrong day is easiest to fix where it is seen,
+//! by dragging it to the right one, without deleting and re-entering it.
+
+use eframe::egui;
+use log::{info, warn};
+use std::time::Instant;
+use crate::backend::domain::commands::transactions::EditTransactionCommand;
+use crate::backend::domain::models::actor::Actor;
+use crate::backend::domain::models::transaction::date_on_day;
+use crate::ui::app_state::AllowanceTrackerApp;
+use crate::ui::components::styling::Theme;
+use crate::ui::state::modal_state::ProtectedAction;
+use crate::ui::state::{MoveUndo, PendingMove, UNDO_TOAST_DURATION};
+
+impl AllowanceTrackerApp {
+    /// Move a dropped transaction, behind the parental check when the policy asks for it
+    pub fn request_transaction_move(&mut self, pending: PendingMove) {
+        match self.backend().parental_control_service.moving_transactions_requires_parental_control() {
+            Ok(true) => {
+                self.interaction.pending_move = Some(pending);
+                self.start_parental_control_challenge(ProtectedAction::MoveTransaction);
+            }
+            Ok(false) => self.move_transaction(pending, None),
+            Err(e) => {
+                warn!("📅 Failed to read the policy for moving transactions: {}", e);
+                self.ui.error_message = Some(format!("Couldn't move \"{}\": {}", pending.description, e));
+            }
+        }
+    }
+
+    /// Move a transaction to the day it was dropped on and offer to undo it
+    pub(crate) fn move_transaction(&mut self, pending: PendingMove, actor: Option<Actor>) {
+        let Some(child_id) = self.core.current_child.as_ref().map(|child| child.id.clone()) else {
+            return;
+        };
+        let command = EditTransactionCommand {
+            child_id: Some(child_id.clone()),
+            transaction_id: pending.transaction_id.clone(),
+            date: Some(date_on_day(pending.from, pending.to)),
+            description: None,
+            parent_override: false,
+            actor,
+        };
+        match self.backend().transaction_service.edit_transaction(command) {
+            Ok(result) => {
+                info!("📅 Moved {} from {} to {}", pending.transaction_id, pending.from.date_naive(), pending.to);
+                self.interaction.move_undo = Some(MoveUndo {
+                    child_id,
+                    transaction_id: pending.transaction_id,
+                    description: result.transaction.description,
+                    previous_date: result.original.date,
+                    moved_to: pending.to,
+                    shown_at: Instant::now(),
+                });
+                self.refresh_after_move();
+            }
+            Err(e) => {
+                warn!("📅 Failed to move {}: {}", pending.transaction_id, e);
+                self.ui.error_message = Some(format!("Couldn't move \"{}\": {}", pending.description, e));
+            }
+        }
+    }
+
+    /// Show the toast for the last move, with its undo button, until it expires
+    pub fn render_undo_toast(&mut self, ctx: &egui::Context) {
+        let Some(undo) = &self.interaction.move_undo else {
+            return;
+        };
+        if undo.expired() {
+            self.interaction.move_undo = None;
+            return;
+        }
+        let message = format!("Moved \"{}\" to {}", undo.description, undo.moved_to.format("%b %-d"));
+        let remaining = UNDO_TOAST_DURATION.saturating_sub(undo.shown_at.elapsed());
+        let theme = Theme::current(ctx);
+
+        let mut undo_clicked = false;
+        let mut dismissed = false;
+        egui::Area::new(egui::Id::new("move_undo_toast"))
+            .order(egui::Order::Foreground)
+            .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -30.0))
+            .show(ctx, |ui| {
+                egui::Frame::new()
+                    .fill(theme.layout.modal_background)
+                    .stroke(egui::Stroke::new(1.5, theme.interactive.hover_border))
+                    .corner_radius(egui::CornerRadius::same(10))
+                    .inner_margin(egui::Margin::symmetric(16, 10))
+                    .show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(message).color(theme.typography.body));
+                            ui.add_space(12.0);
+                            if ui.button("Undo").clicked() {
+                                undo_clicked = true;
+                            }
+                            if ui.small_button("✖").on_hover_text("Close").clicked() {
+                                dismissed = true;
+                            }
+                        });
+                    });
+            });
+
+        if undo_clicked {
+            self.undo_transaction_move();
+        } else if dismissed {
+            self.interaction.move_undo = None;
+        } else {
+            ctx.request_repaint_after(remaining);
+        }
+    }
+
+    /// Put the last moved transaction back on the day it came from
+    fn undo_transaction_move(&mut self) {
+        let Some(undo) = self.interaction.move_undo.take() else {
+            return;
+        };
+        let command = EditTransactionCommand {
+            child_id: Some(undo.child_id),
+            transaction_id: undo.transaction_id.clone(),
+            date: Some(undo.previous_date),
+            description: None,
+            parent_override: false,
+            actor: None,
+        };
+        match self.backend().transaction_service.edit_transaction(command) {
+            Ok(_) => {
+                info!("📅 Moved {} back to {}", undo.transaction_id, undo.previous_date.date_naive());
+                self.refresh_after_move();
+            }
+            Err(e) => {
+                warn!("📅 Failed to undo moving {}: {}", undo.transaction_id, e);
+                self.ui.error_message = Some(format!("Couldn't move \"{}\" back: {}", undo.description, e));
+            }
+        }
+    }
+
+    /// Reload everything that shows the moved transaction or its balances
+    fn refresh_after_move(&mut self) {
+        self.load_calendar_data();
+        self.table.invalidate();
+        self.load_balance();
+        self.load_chart_data();
+    }
+}
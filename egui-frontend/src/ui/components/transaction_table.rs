@@ -1,18 +1,51 @@
+use chrono::Datelike;
 use eframe::egui;
 use shared::*;
+use std::collections::{HashMap, HashSet};
+use crate::backend::domain::localization::{DateStyle, Locale};
+use crate::backend::domain::models::transaction_icon::icon_glyph;
+use crate::ui::components::styling::{colors, Theme};
+use crate::ui::state::ReversalRole;
+
+/// Distance from the end of the rows at which the table reports it is nearly scrolled through
+const NEAR_END_THRESHOLD: f32 = 200.0;
+
+/// Something picked from a row's right-click menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableRowAction {
+    Edit,
+    Delete,
+    Duplicate,
+}
+
+/// What happened in the table this frame
+#[derive(Debug, Default)]
+pub struct TransactionTableOutput {
+    /// The rows are scrolled to near their end, so the next page can be loaded
+    pub near_end: bool,
+    /// A row's menu entry that was picked, with the row's transaction
+    pub row_action: Option<(TableRowAction, Transaction)>,
+}
 
 /// Render the transaction table (simplified version)
-pub fn render_transaction_table(ui: &mut egui::Ui, transactions: &[Transaction]) {
+pub fn render_transaction_table(ui: &mut egui::Ui, transactions: &[Transaction], locale: Locale, selected_ids: &mut HashSet<String>) -> bool {
     // Use the responsive version with a default rectangle
     let available_rect = ui.available_rect_before_wrap();
-    render_responsive_transaction_table(ui, available_rect, transactions);
+    render_responsive_transaction_table(ui, available_rect, transactions, locale, selected_ids, &HashMap::new(), false).near_end
 }
 
-/// Render responsive transaction table with calendar-style transparent styling
-pub fn render_responsive_transaction_table(ui: &mut egui::Ui, available_rect: egui::Rect, transactions: &[Transaction]) {
+/// Render responsive transaction table with calendar-style transparent styling.
+///
+/// Clicking a row toggles it in `selected_ids`; `show_checkboxes` draws a check box
+/// in each row for delete mode. Rows in `reversal_roles` are labelled as reversed
+/// or as the reversal. Right-clicking a row offers to edit, delete or duplicate it.
+/// The output says whether the rows are scrolled to near their end, so the caller
+/// can load the next page, and which menu entry was picked.
+pub fn render_responsive_transaction_table(ui: &mut egui::Ui, available_rect: egui::Rect, transactions: &[Transaction], locale: Locale, selected_ids: &mut HashSet<String>, reversal_roles: &HashMap<String, ReversalRole>, show_checkboxes: bool) -> TransactionTableOutput {
+    let mut output = TransactionTableOutput::default();
     if transactions.is_empty() {
         ui.label("No transactions yet!");
-        return;
+        return output;
     }
 
     // Responsive approach: size everything as percentages of available space
@@ -89,7 +122,7 @@ pub fn render_responsive_transaction_table(ui: &mut egui::Ui, available_rect: eg
                                             let header_rect = ui.available_rect_before_wrap();
                                             
                                             // Draw opaque header background using single color (Amount column color)
-                                            let header_bg_color = crate::ui::components::styling::theme::CURRENT_THEME.table_header_color(2); // Use Amount column color (index 2)
+                                            let header_bg_color = Theme::current(ui.ctx()).table_header_color(2); // Use Amount column color (index 2)
                                             ui.painter().rect_filled(
                                                 header_rect,
                                                 egui::CornerRadius::same(0), // No rounding
@@ -119,7 +152,7 @@ pub fn render_responsive_transaction_table(ui: &mut egui::Ui, available_rect: eg
                             ui.add_space(row_spacing); // Space after headers
                             
                             // Wrap transaction rows in a ScrollArea for infinite scroll
-                            egui::ScrollArea::vertical()
+                            let scroll_output = egui::ScrollArea::vertical()
                                 .auto_shrink([false; 2])
                                 .show(ui, |ui| {
                                     // Transaction data with hover detection
@@ -140,6 +173,18 @@ pub fn render_responsive_transaction_table(ui: &mut egui::Ui, available_rect: eg
                                         // Check if button is hovered for styling
                                         let is_hovered = button_response.hovered();
                                         
+                                        // Clicking a row selects it (for copying, or for deleting in delete mode);
+                                        // rows in a closed period can't be picked for deletion
+                                        let selectable = !(show_checkboxes && transaction.locked);
+                                        if button_response.clicked() && selectable && !selected_ids.remove(&transaction.id) {
+                                            selected_ids.insert(transaction.id.clone());
+                                        }
+                                        let is_selected = selected_ids.contains(&transaction.id);
+                                        
+                                        if let Some(action) = row_context_menu(&button_response, transaction) {
+                                            output.row_action = Some((action, transaction.clone()));
+                                        }
+                                        
                                         // Draw the row content ON TOP of the button
                                         let button_rect = button_response.rect;
                                         ui.allocate_new_ui(egui::UiBuilder::new().max_rect(button_rect), |ui| {
@@ -158,10 +203,18 @@ pub fn render_responsive_transaction_table(ui: &mut egui::Ui, available_rect: eg
                                                     content_width_minus_scrollbar * 0.17, // balance (reduced from 0.20)
                                                 ];
                                                 
-                                                let cell_bg_color = if is_hovered {
-                                                    egui::Color32::from_rgba_unmultiplied(255, 255, 255, 132) // More opaque when hovered (increased by 10%)
-                                                } else {
-                                                    egui::Color32::from_rgba_unmultiplied(255, 255, 255, 72) // Normal transparency (increased to 72)
+                                                let dark_mode = ui.visuals().dark_mode;
+                                                let theme = Theme::for_dark_mode(dark_mode);
+                                                let cell_bg_color = match (dark_mode, is_selected && show_checkboxes, is_selected, is_hovered) {
+                                                    (false, true, _, _) => egui::Color32::from_rgba_unmultiplied(255, 200, 200, 170), // Light red for rows marked for deletion
+                                                    (false, _, true, _) => egui::Color32::from_rgba_unmultiplied(173, 216, 230, 170), // Light blue for selected rows
+                                                    (false, _, _, true) => egui::Color32::from_rgba_unmultiplied(255, 255, 255, 132), // More opaque when hovered (increased by 10%)
+                                                    (false, _, _, false) => egui::Color32::from_rgba_unmultiplied(255, 255, 255, 72), // Normal transparency (increased to 72)
+                                                    // The same states as deeper tints over the darkened background
+                                                    (true, true, _, _) => egui::Color32::from_rgba_unmultiplied(120, 40, 50, 190),
+                                                    (true, _, true, _) => egui::Color32::from_rgba_unmultiplied(40, 80, 110, 190),
+                                                    (true, _, _, true) => egui::Color32::from_rgba_unmultiplied(62, 64, 80, 200),
+                                                    (true, _, _, false) => egui::Color32::from_rgba_unmultiplied(38, 40, 50, 160),
                                                 };
                                                 let cell_border_color = egui::Color32::from_rgba_unmultiplied(200, 200, 200, 100);
                                                 
@@ -191,11 +244,32 @@ pub fn render_responsive_transaction_table(ui: &mut egui::Ui, available_rect: eg
                                                                         egui::StrokeKind::Outside
                                                                     );
                                                                     
-                                                                    let date_display = transaction.date.format("%b %d, %Y").to_string();
+                                                                    // Delete mode check box, painted so the whole row stays one click target
+                                                                    if show_checkboxes && !transaction.locked {
+                                                                        let box_rect = egui::Rect::from_center_size(
+                                                                            egui::pos2(cell_rect.left() + 12.0, cell_rect.center().y),
+                                                                            egui::vec2(12.0, 12.0)
+                                                                        );
+                                                                        ui.painter().rect_stroke(
+                                                                            box_rect,
+                                                                            egui::CornerRadius::same(2),
+                                                                            egui::Stroke::new(1.5, theme.typography.muted),
+                                                                            egui::StrokeKind::Inside
+                                                                        );
+                                                                        if is_selected {
+                                                                            ui.painter().rect_filled(
+                                                                                box_rect.shrink(3.0),
+                                                                                egui::CornerRadius::same(1),
+                                                                                egui::Color32::from_rgb(220, 53, 69)
+                                                                            );
+                                                                        }
+                                                                    }
+                                                                    
+                                                                    let date_display = locale.format_date(transaction.date.year(), transaction.date.month(), transaction.date.day(), DateStyle::Medium);
                                                                     ui.add(egui::Label::new(egui::RichText::new(date_display)
                                                                         .font(egui::FontId::new(content_font_size, font_family.clone()))
                                                                         .strong()
-                                                                        .color(egui::Color32::BLACK))
+                                                                        .color(theme.typography.body))
                                                                         .selectable(false)); // Non-interactive
                                                                 },
                                                             );
@@ -223,9 +297,32 @@ pub fn render_responsive_transaction_table(ui: &mut egui::Ui, available_rect: eg
                                                                         egui::StrokeKind::Outside
                                                                     );
                                                                     
-                                                                    ui.add(egui::Label::new(egui::RichText::new(&transaction.description)
+                                                                    // Delete mode is only reachable through the parental challenge,
+                                                                    // so it is where parents can see who entered each row
+                                                                    let description = match (&transaction.actor, show_checkboxes) {
+                                                                        (Some(actor), true) => format!("{}  · {}", transaction.description, actor),
+                                                                        _ => transaction.description.clone(),
+                                                                    };
+                                                                    let description = match (&transaction.icon, transaction.transaction_type.emoji()) {
+                                                                        (Some(icon), _) => format!("{} {}", icon_glyph(icon), description),
+                                                                        (None, Some(emoji)) => format!("{} {}", emoji, description),
+                                                                        (None, None) => description,
+                                                                    };
+                                                                    // Reversed rows stay in the history but are greyed out
+                                                                    let (description, description_color) = match reversal_roles.get(&transaction.id) {
+                                                                        Some(ReversalRole::Reversed) => (format!("{}  ↩ reversed", description), theme.typography.muted),
+                                                                        Some(ReversalRole::Reversal) => (format!("↩ {}", description), theme.typography.body),
+                                                                        None => (description, theme.typography.body),
+                                                                    };
+                                                                    // So are rows in a month a parent has closed
+                                                                    let (description, description_color) = if transaction.locked {
+                                                                        (format!("🔒 {}", description), theme.typography.muted)
+                                                                    } else {
+                                                                        (description, description_color)
+                                                                    };
+                                                                    ui.add(egui::Label::new(egui::RichText::new(description)
                                                                         .font(egui::FontId::new(content_font_size, font_family.clone()))
-                                                                        .color(egui::Color32::BLACK))
+                                                                        .color(description_color))
                                                                         .selectable(false)); // Non-interactive
                                                                 },
                                                             );
@@ -253,12 +350,12 @@ pub fn render_responsive_transaction_table(ui: &mut egui::Ui, available_rect: eg
                                                                          egui::StrokeKind::Outside
                                                                     );
                                                                     
-                                                                    // Color-code based on amount (keeping existing logic)
-                                                                    let amount_color = if transaction.amount > 0.0 {
-                                                                        egui::Color32::from_rgb(34, 139, 34) // Forest green for positive
+                                                                    // Color-code by kind, falling back to the amount's sign
+                                                                    let amount_color = colors::transaction_kind_color(&transaction.transaction_type).unwrap_or(if transaction.amount > 0.0 {
+                                                                        theme.calendar.income_chip // Forest green for positive
                                                                     } else {
-                                                                        egui::Color32::from_rgb(220, 20, 60) // Crimson for negative
-                                                                    };
+                                                                        theme.calendar.expense_chip // Crimson for negative
+                                                                    });
                                                                     
                                                                     ui.add(egui::Label::new(egui::RichText::new(format!("${:.2}", transaction.amount))
                                                                         .font(egui::FontId::new(content_font_size, font_family.clone()))
@@ -294,7 +391,7 @@ pub fn render_responsive_transaction_table(ui: &mut egui::Ui, available_rect: eg
                                                                     ui.add(egui::Label::new(egui::RichText::new(format!("${:.2}", transaction.balance))
                                                                             .font(egui::FontId::new(content_font_size, font_family.clone()))
                                                                             .strong()
-                                                                            .color(egui::Color32::BLACK))
+                                                                            .color(theme.typography.body))
                                                                             .selectable(false)); // Non-interactive
                                                                 },
                                                             );
@@ -311,10 +408,42 @@ pub fn render_responsive_transaction_table(ui: &mut egui::Ui, available_rect: eg
                                     }
                                 }
                             );
+                            
+                            // Short lists never scroll, so they count as scrolled through
+                            let scrolled_to = scroll_output.state.offset.y + scroll_output.inner_rect.height();
+                            output.near_end = scrolled_to + NEAR_END_THRESHOLD >= scroll_output.content_size.y;
                         }
                     );
                 }
             );
         });
     });
+    
+    output
+}
+
+/// Show a row's right-click menu and return the entry picked from it.
+/// Rows in a closed period can't be changed, and an allowance that hasn't
+/// been paid yet isn't stored to change or copy.
+fn row_context_menu(response: &egui::Response, transaction: &Transaction) -> Option<TableRowAction> {
+    let stored = transaction.transaction_type != TransactionType::FutureAllowance;
+    let changeable = stored && !transaction.locked;
+    let mut picked = None;
+    response.context_menu(|ui| {
+        let entries = [
+            (TableRowAction::Edit, "✏ Edit…", changeable),
+            (TableRowAction::Delete, "🗑 Delete…", changeable),
+            (TableRowAction::Duplicate, "📄 Duplicate", stored),
+        ];
+        for (action, label, enabled) in entries {
+            if ui.add_enabled(enabled, egui::Button::new(label)).clicked() {
+                picked = Some(action);
+                ui.close_menu();
+            }
+        }
+        if transaction.locked {
+            ui.label(egui::RichText::new("This month is closed").small().weak());
+        }
+    });
+    picked
 } 
\ No newline at end of file
@@ -0,0 +1,174 @@
+//! # Tutorial Overlay
+//!
+//! This module draws the guided tour over the app: everything but the
+//! current step's target is dimmed, and a callout next to it explains it.
+//!
+//! ## Responsibilities:
+//! - Start the tour on first launch, or again from the settings menu
+//! - Switch to the tab each step points at
+//! - Dim the app around the target and block clicks while the tour runs
+//! - Save that the tour was taken once it is finished or skipped
+//!
+//! ## Purpose:
+//! A new family member gets a short walk through the header, the calendar,
+//! adding money and the goal card instead of having to find them alone.
+
+use eframe::egui;
+use log::{info, warn};
+use crate::backend::domain::commands::preferences::SetTutorialCompletedCommand;
+use crate::ui::app_state::{AllowanceTrackerApp, MainTab};
+use crate::ui::components::styling::Theme;
+use crate::ui::state::TutorialStep;
+
+/// How dark the app gets around the highlighted target
+const DIM_ALPHA: u8 = 150;
+
+/// Space between the target and its highlight ring
+const HIGHLIGHT_PADDING: f32 = 6.0;
+
+/// Width of the callout explaining each step
+const CALLOUT_WIDTH: f32 = 320.0;
+
+impl AllowanceTrackerApp {
+    /// Start the tour from its first step
+    pub fn start_tutorial(&mut self) {
+        info!("🧭 Starting the guided tour");
+        self.tutorial.start();
+        self.set_current_tab(TutorialStep::ALL[0].tab());
+    }
+
+    /// Draw the current step of the tour, if it is running
+    pub fn render_tutorial(&mut self, ctx: &egui::Context) {
+        let Some(step) = self.tutorial.step else {
+            return;
+        };
+        // Wait until there is a child to show, e.g. while the first one is created
+        if self.core.current_child.is_none() {
+            return;
+        }
+
+        // Adding money points at today, or at the whole calendar when today isn't on screen
+        let target = step.target(ctx).or_else(|| match step {
+            TutorialStep::AddMoney => TutorialStep::Calendar.target(ctx),
+            _ => None,
+        });
+        let screen = ctx.screen_rect();
+        let highlight = target.map(|rect| rect.expand(HIGHLIGHT_PADDING).intersect(screen));
+        let theme = Theme::current(ctx);
+
+        // Dim everything around the target, and swallow clicks so the app waits for the tour
+        egui::Area::new(egui::Id::new("tutorial_backdrop"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(screen.min)
+            .show(ctx, |ui| {
+                let (rect, _) = ui.allocate_exact_size(screen.size(), egui::Sense::click_and_drag());
+                let painter = ui.painter_at(rect);
+                let dim = egui::Color32::from_black_alpha(DIM_ALPHA);
+                match highlight {
+                    Some(hole) => {
+                        painter.rect_filled(egui::Rect::from_min_max(screen.min, egui::pos2(screen.max.x, hole.top())), egui::CornerRadius::ZERO, dim);
+                        painter.rect_filled(egui::Rect::from_min_max(egui::pos2(screen.min.x, hole.bottom()), screen.max), egui::CornerRadius::ZERO, dim);
+                        painter.rect_filled(egui::Rect::from_min_max(egui::pos2(screen.min.x, hole.top()), egui::pos2(hole.left(), hole.bottom())), egui::CornerRadius::ZERO, dim);
+                        painter.rect_filled(egui::Rect::from_min_max(egui::pos2(hole.right(), hole.top()), egui::pos2(screen.max.x, hole.bottom())), egui::CornerRadius::ZERO, dim);
+                        painter.rect_stroke(hole, egui::CornerRadius::same(8), egui::Stroke::new(3.0, theme.interactive.hover_border), egui::StrokeKind::Outside);
+                    }
+                    None => {
+                        painter.rect_filled(screen, egui::CornerRadius::ZERO, dim);
+                    }
+                }
+            });
+
+        // The callout goes under the target when it fits, otherwise above it
+        let callout_height_guess = 170.0;
+        let callout_pos = match highlight {
+            Some(hole) => {
+                let x = (hole.center().x - CALLOUT_WIDTH / 2.0).clamp(screen.left() + 12.0, (screen.right() - CALLOUT_WIDTH - 12.0).max(screen.left()));
+                let y = if hole.bottom() + 12.0 + callout_height_guess <= screen.bottom() {
+                    hole.bottom() + 12.0
+                } else if hole.top() - 12.0 - callout_height_guess >= screen.top() {
+                    hole.top() - 12.0 - callout_height_guess
+                } else {
+                    // A target that fills the screen gets the callout on top of it
+                    hole.center().y - callout_height_guess / 2.0
+                };
+                egui::pos2(x, y)
+            }
+            None => screen.center() - egui::vec2(CALLOUT_WIDTH / 2.0, callout_height_guess / 2.0),
+        };
+
+        let number = TutorialStep::ALL.iter().position(|s| *s == step).unwrap_or(0) + 1;
+        let is_last = step.next().is_none();
+        let (mut back, mut next, mut skip) = (false, false, false);
+        egui::Area::new(egui::Id::new("tutorial_callout"))
+            .order(egui::Order::Tooltip)
+            .fixed_pos(callout_pos)
+            .show(ctx, |ui| {
+                egui::Frame::new()
+                    .fill(theme.layout.modal_background)
+                    .stroke(egui::Stroke::new(2.0, theme.interactive.hover_border))
+                    .corner_radius(egui::CornerRadius::same(12))
+                    .inner_margin(egui::Margin::symmetric(18, 14))
+                    .show(ui, |ui| {
+                        ui.set_width(CALLOUT_WIDTH - 36.0);
+                        ui.label(egui::RichText::new(format!("{} of {}", number, TutorialStep::ALL.len()))
+                            .size(12.0)
+                            .color(theme.typography.muted));
+                        ui.label(egui::RichText::new(step.title()).size(18.0).strong().color(theme.typography.body));
+                        ui.add_space(4.0);
+                        ui.label(egui::RichText::new(step.text()).color(theme.typography.body));
+                        ui.add_space(10.0);
+                        ui.horizontal(|ui| {
+                            if ui.button("Skip tour").clicked() {
+                                skip = true;
+                            }
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                if ui.button(if is_last { "Done" } else { "Next" }).clicked() {
+                                    next = true;
+                                }
+                                if step.previous().is_some() && ui.button("Back").clicked() {
+                                    back = true;
+                                }
+                            });
+                        });
+                    });
+            });
+
+        if ctx.input(|input| input.key_pressed(egui::Key::Escape)) {
+            skip = true;
+        }
+        if skip {
+            info!("🧭 Guided tour skipped at {:?}", step);
+            self.tutorial.skip();
+            self.save_tutorial_completed();
+        } else if next {
+            if self.tutorial.advance() {
+                info!("🧭 Guided tour finished");
+                self.set_current_tab(MainTab::Calendar);
+                self.save_tutorial_completed();
+            } else {
+                self.show_tutorial_step(ctx);
+            }
+        } else if back {
+            self.tutorial.back();
+            self.show_tutorial_step(ctx);
+        }
+    }
+
+    /// Switch to the tab the current step points at and drop its old target
+    fn show_tutorial_step(&mut self, ctx: &egui::Context) {
+        let Some(step) = self.tutorial.step else {
+            return;
+        };
+        self.set_current_tab(step.tab());
+        step.forget_target(ctx);
+        ctx.request_repaint();
+    }
+
+    /// Remember that the tour was taken, so it isn't offered again on launch
+    fn save_tutorial_completed(&mut self) {
+        let command = SetTutorialCompletedCommand { completed: true };
+        if let Err(e) = self.backend().preferences_service.set_tutorial_completed(command) {
+            warn!("🧭 Failed to save that the tour was taken: {}", e);
+        }
+    }
+}
@@ -9,6 +9,7 @@
 //! - `draw_card_header_with_toggles()` - Draws complex headers with multiple toggle buttons
 //! - `draw_card_with_flat_top()` - Draws cards with flat top edges for integration
 //! - `draw_integrated_tabs()` - Draws tab navigation integrated with card styling
+//! - `measure_text_width()` / `truncate_to_width()` - Measure and shorten text as it will render
 //!
 //! ## Purpose:
 //! These functions provide consistent styling and behavior across different parts of the app,
@@ -16,7 +17,12 @@
 //! and hover effects that make the interface feel modern and kid-friendly.
 
 use eframe::egui;
+use unicode_segmentation::UnicodeSegmentation;
 use crate::ui::app_state::{AllowanceTrackerApp, MainTab};
+use crate::ui::components::styling::Theme;
+
+/// Appended to text that was shortened to fit
+const ELLIPSIS: &str = "…";
 
 impl AllowanceTrackerApp {
     /// Draw card background with proper styling
@@ -55,7 +61,32 @@ impl AllowanceTrackerApp {
         // Removed unused import: use crate::ui::components::styling::colors;
         
         ui.horizontal(|ui| {
-            // Goal button (appears rightmost due to right-to-left layout)
+            // Family button (appears rightmost due to right-to-left layout)
+            let family_button = egui::Button::new(egui::RichText::new("👪 Family")
+                .font(egui::FontId::new(14.0, egui::FontFamily::Proportional))
+                .strong()
+                .color(if self.current_tab() == MainTab::Family { 
+                    egui::Color32::WHITE 
+                } else { 
+                    egui::Color32::from_rgb(100, 100, 100) 
+                }))
+            .fill(if self.current_tab() == MainTab::Family {
+                egui::Color32::from_rgb(100, 150, 255) // Active blue
+            } else {
+                Theme::current(ui.ctx()).layout.subtle_background // Light gray background for inactive
+            })
+            .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 200, 200)))
+            .corner_radius(egui::CornerRadius::same(8))
+            .min_size(egui::vec2(95.0, 35.0));
+        
+        if ui.add(family_button).clicked() {
+            self.family.invalidate(); // Balances may have changed since last visit
+            self.set_current_tab(MainTab::Family);
+        }
+        
+        ui.add_space(8.0);
+        
+            // Goal button (appears second rightmost due to right-to-left layout)
             let goal_button = egui::Button::new(egui::RichText::new("🎯 Goal")
                 .font(egui::FontId::new(14.0, egui::FontFamily::Proportional))
                 .strong()
@@ -67,7 +98,7 @@ impl AllowanceTrackerApp {
             .fill(if self.current_tab() == MainTab::Goal {
                 egui::Color32::from_rgb(100, 150, 255) // Active blue
             } else {
-                egui::Color32::from_rgb(240, 240, 240) // Light gray background for inactive
+                Theme::current(ui.ctx()).layout.subtle_background // Light gray background for inactive
             })
             .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 200, 200)))
             .corner_radius(egui::CornerRadius::same(8))
@@ -79,7 +110,7 @@ impl AllowanceTrackerApp {
         
         ui.add_space(8.0);
         
-            // Chart button (appears second rightmost due to right-to-left layout)
+            // Chart button (appears third rightmost due to right-to-left layout)
             let chart_button = egui::Button::new(egui::RichText::new("📊 Chart")
                 .font(egui::FontId::new(14.0, egui::FontFamily::Proportional))
                 .strong()
@@ -91,7 +122,7 @@ impl AllowanceTrackerApp {
             .fill(if self.current_tab() == MainTab::Chart {
                 egui::Color32::from_rgb(100, 150, 255) // Active blue
             } else {
-                egui::Color32::from_rgb(240, 240, 240) // Light gray background for inactive
+                Theme::current(ui.ctx()).layout.subtle_background // Light gray background for inactive
             })
             .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 200, 200)))
             .corner_radius(egui::CornerRadius::same(8))
@@ -115,7 +146,7 @@ impl AllowanceTrackerApp {
             .fill(if self.current_tab() == MainTab::Table {
                 egui::Color32::from_rgb(100, 150, 255) // Active blue
             } else {
-                egui::Color32::from_rgb(240, 240, 240) // Light gray background for inactive
+                Theme::current(ui.ctx()).layout.subtle_background // Light gray background for inactive
             })
             .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 200, 200)))
             .corner_radius(egui::CornerRadius::same(8))
@@ -139,7 +170,7 @@ impl AllowanceTrackerApp {
             .fill(if self.current_tab() == MainTab::Calendar {
                 egui::Color32::from_rgb(100, 150, 255) // Active blue
             } else {
-                egui::Color32::from_rgb(240, 240, 240) // Light gray background for inactive
+                Theme::current(ui.ctx()).layout.subtle_background // Light gray background for inactive
             })
             .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 200, 200)))
             .corner_radius(egui::CornerRadius::same(8))
@@ -165,7 +196,7 @@ impl AllowanceTrackerApp {
         header_ui.label(egui::RichText::new(title)
             .font(egui::FontId::new(20.0, egui::FontFamily::Proportional))
             .strong()
-            .color(egui::Color32::from_rgb(70, 70, 70)));
+            .color(Theme::current(ui.ctx()).typography.heading));
     }
     
     /// Draw card header with title and toggle buttons
@@ -182,7 +213,7 @@ impl AllowanceTrackerApp {
         header_ui.label(egui::RichText::new("Recent Transactions")
             .font(egui::FontId::new(20.0, egui::FontFamily::Proportional))
             .strong()
-            .color(egui::Color32::from_rgb(70, 70, 70)));
+            .color(Theme::current(ui.ctx()).typography.heading));
         
         // Push toggle buttons to the right
         header_ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
@@ -298,14 +329,14 @@ impl AllowanceTrackerApp {
                     egui::Button::new(egui::RichText::new("📅 Calendar")
                         .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
                         .color(egui::Color32::WHITE))
-                        .fill(egui::Color32::WHITE) // Same white as calendar card
+                        .fill(Theme::current(ui.ctx()).layout.card_background) // Same white as calendar card
                         .corner_radius(calendar_rounding)
                         .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(100, 150, 255))) // Purple border
                 } else {
                     egui::Button::new(egui::RichText::new("📅 Calendar")
                         .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
                         .color(egui::Color32::from_rgb(100, 100, 100)))
-                        .fill(egui::Color32::from_rgb(240, 240, 240)) // Theme inactive color
+                        .fill(Theme::current(ui.ctx()).layout.subtle_background) // Theme inactive color
                         .corner_radius(calendar_rounding)
                         .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(100, 150, 255))) // Purple border
                 };
@@ -334,14 +365,14 @@ impl AllowanceTrackerApp {
                     egui::Button::new(egui::RichText::new("📋 Table")
                         .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
                         .color(egui::Color32::WHITE))
-                        .fill(egui::Color32::WHITE) // Same white as calendar card
+                        .fill(Theme::current(ui.ctx()).layout.card_background) // Same white as calendar card
                         .corner_radius(table_rounding)
                         .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(100, 150, 255))) // Purple border
                 } else {
                     egui::Button::new(egui::RichText::new("📋 Table")
                         .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
                         .color(egui::Color32::from_rgb(100, 100, 100)))
-                        .fill(egui::Color32::from_rgb(240, 240, 240)) // Theme inactive color
+                        .fill(Theme::current(ui.ctx()).layout.subtle_background) // Theme inactive color
                         .corner_radius(table_rounding)
                         .stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(100, 150, 255))) // Purple border
                 };
@@ -352,4 +383,71 @@ impl AllowanceTrackerApp {
             });
         });
     }
-} 
\ No newline at end of file
+} 
+
+/// Width `text` takes on one line in `font`
+///
+/// The text is laid out with the fonts egui renders with, so emoji and
+/// scripts that come from a fallback font (Arabic, Hebrew, CJK) are measured
+/// at their real width rather than guessed from the number of bytes.
+pub fn measure_text_width(ui: &egui::Ui, text: &str, font: egui::FontId) -> f32 {
+    ui.fonts(|fonts| fonts.layout_no_wrap(text.to_string(), font, egui::Color32::PLACEHOLDER).size().x)
+}
+
+/// `text` as it fits in `max_width` in `font`, shortened with an ellipsis
+/// if it doesn't
+///
+/// Text is cut between graphemes, so an emoji sequence or a letter with a
+/// combining mark is never split, and the ellipsis goes at the logical end,
+/// which is the left edge of right-to-left text.
+pub fn truncate_to_width(ui: &egui::Ui, text: &str, font: egui::FontId, max_width: f32) -> String {
+    truncate_with(text, max_width, |candidate| measure_text_width(ui, candidate, font.clone()))
+}
+
+/// `truncate_to_width` with the measuring passed in
+fn truncate_with(text: &str, max_width: f32, measure: impl Fn(&str) -> f32) -> String {
+    if measure(text) <= max_width {
+        return text.to_string();
+    }
+    let graphemes: Vec<&str> = text.graphemes(true).collect();
+    let shortened = |count: usize| format!("{}{}", graphemes[..count].concat().trim_end(), ELLIPSIS);
+
+    // Longest prefix that still fits with the ellipsis, by binary search
+    let (mut fits, mut too_long) = (0, graphemes.len());
+    while too_long - fits > 1 {
+        let middle = (fits + too_long) / 2;
+        if measure(&shortened(middle)) <= max_width {
+            fits = middle;
+        } else {
+            too_long = middle;
+        }
+    }
+    if fits == 0 && measure(ELLIPSIS) > max_width {
+        return String::new();
+    }
+    shortened(fits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pretend every grapheme is 10 points wide
+    fn width(text: &str) -> f32 {
+        text.graphemes(true).count() as f32 * 10.0
+    }
+
+    #[test]
+    fn test_truncate_keeps_text_that_fits() {
+        assert_eq!(truncate_with("Alice", 50.0, width), "Alice");
+    }
+
+    #[test]
+    fn test_truncate_cuts_between_graphemes() {
+        assert_eq!(truncate_with("Alexandra", 50.0, width), "Alex…");
+        assert_eq!(truncate_with("Ann Marie", 50.0, width), "Ann…");
+        assert_eq!(truncate_with("👨‍👩‍👧👨‍👩‍👧👨‍👩‍👧", 25.0, width), "👨‍👩‍👧…");
+        assert_eq!(truncate_with("שלום עולם", 40.0, width), "שלו…");
+        assert_eq!(truncate_with("Alexandra", 5.0, width), "");
+    }
+}
@@ -0,0 +1,249 @@
+//! # Year Heatmap Module
+//!
+//! This module renders a GitHub-style heatmap of a whole year of money activity.
+//!
+//! ## Key Functions:
+//! - `render_year_heatmap()` - Year picker, day grid and monthly totals
+//! - `load_year_summary()` - Fetch the day-by-day summary from the reports service
+//!
+//! ## Layout:
+//! - One column per week, one row per weekday (Sunday at the top)
+//! - Green squares for days that ended up positive, red for days that ended up negative
+//! - Colour intensity scales with the size of the day's net compared to the biggest day
+//! - Monthly totals listed under the grid
+//!
+//! ## Purpose:
+//! Shows spending and saving patterns across the whole year at a glance,
+//! like busy birthday months or a steady weekly allowance.
+
+use eframe::egui;
+use chrono::Datelike;
+use log::{info, warn};
+use crate::backend::domain::commands::reports::{DailyNet, YearSummaryQuery};
+use crate::backend::domain::DateStyle;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+/// Size of a single day square
+const CELL_SIZE: f32 = 13.0;
+
+/// Gap between day squares
+const CELL_GAP: f32 = 3.0;
+
+/// Width reserved for the weekday labels on the left
+const LABEL_WIDTH: f32 = 32.0;
+
+/// Colour of days without any transactions
+const EMPTY_DAY_COLOR: egui::Color32 = egui::Color32::from_rgb(235, 237, 240);
+
+/// Colour of days without any transactions in the dark theme
+const EMPTY_DAY_COLOR_DARK: egui::Color32 = egui::Color32::from_rgb(52, 54, 66);
+
+impl AllowanceTrackerApp {
+    /// Load the year summary for the heatmap's current year
+    pub fn load_year_summary(&mut self) {
+        let Some(ref child) = self.core.current_child else {
+            warn!("📊 No child selected for year summary");
+            return;
+        };
+
+        let query = YearSummaryQuery {
+            child_id: Some(child.id.clone()),
+            year: self.chart.heatmap_year,
+            exclude_reversals: self.chart.hide_reversals,
+        };
+
+        match self.backend().reports_service.get_year_summary(query) {
+            Ok(summary) => {
+                info!("📊 Loaded year summary for {} ({} days)", summary.year, summary.days.len());
+                self.chart.year_summary = Some(summary);
+            }
+            Err(e) => {
+                warn!("❌ Failed to load year summary: {}", e);
+                self.chart.set_error(format!("Failed to load year summary: {}", e));
+            }
+        }
+    }
+
+    /// Render the year picker, heatmap grid and monthly totals
+    pub fn render_year_heatmap(&mut self, ui: &mut egui::Ui) {
+        // Year picker
+        ui.horizontal(|ui| {
+            if ui.button("◀").clicked() {
+                self.chart.heatmap_year -= 1;
+                self.chart.year_summary = None;
+                self.chart.error_message = None;
+            }
+            ui.label(egui::RichText::new(self.chart.heatmap_year.to_string())
+                .font(egui::FontId::new(18.0, egui::FontFamily::Proportional))
+                .strong());
+            let next_year_allowed = self.chart.heatmap_year < chrono::Local::now().year();
+            if ui.add_enabled(next_year_allowed, egui::Button::new("▶")).clicked() {
+                self.chart.heatmap_year += 1;
+                self.chart.year_summary = None;
+                self.chart.error_message = None;
+            }
+            ui.add_space(12.0);
+            if ui.checkbox(&mut self.chart.hide_reversals, "Leave out reversed entries").changed() {
+                self.chart.year_summary = None;
+                self.chart.error_message = None;
+            }
+        });
+        ui.add_space(10.0);
+
+        // Don't retry every frame after a failure; changing year clears the error
+        if self.chart.year_summary.is_none() && self.chart.error_message.is_none() {
+            self.load_year_summary();
+        }
+        let Some(summary) = self.chart.year_summary.clone() else {
+            if let Some(ref error) = self.chart.error_message {
+                ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+            }
+            return;
+        };
+
+        let locale = self.core.current_locale;
+        let format_amount = |value: f64| {
+            let sign = if value < 0.0 { "-" } else { "" };
+            format!("{}${}", sign, locale.format_decimal(value.abs(), 2))
+        };
+
+        // Day grid
+        let first_weekday = summary.days.first()
+            .map(|day| day.date.weekday().num_days_from_sunday() as usize)
+            .unwrap_or(0);
+        let week_count = (first_weekday + summary.days.len()).div_ceil(7);
+        let grid_size = egui::vec2(
+            LABEL_WIDTH + week_count as f32 * (CELL_SIZE + CELL_GAP),
+            16.0 + 7.0 * (CELL_SIZE + CELL_GAP),
+        );
+
+        egui::ScrollArea::horizontal().id_salt("year_heatmap_scroll").show(ui, |ui| {
+            let (rect, response) = ui.allocate_exact_size(grid_size, egui::Sense::hover());
+            let painter = ui.painter_at(rect);
+            let grid_origin = rect.min + egui::vec2(LABEL_WIDTH, 16.0);
+            let label_font = egui::FontId::new(10.0, egui::FontFamily::Proportional);
+            let label_color = egui::Color32::from_rgb(120, 120, 120);
+
+            for (row, name) in [(1, "Mon"), (3, "Wed"), (5, "Fri")] {
+                painter.text(
+                    egui::pos2(rect.min.x, grid_origin.y + row as f32 * (CELL_SIZE + CELL_GAP) + CELL_SIZE / 2.0),
+                    egui::Align2::LEFT_CENTER,
+                    name,
+                    label_font.clone(),
+                    label_color,
+                );
+            }
+
+            let mut hovered: Option<&DailyNet> = None;
+            for (index, day) in summary.days.iter().enumerate() {
+                let slot = first_weekday + index;
+                let (week, weekday) = (slot / 7, slot % 7);
+                let cell = egui::Rect::from_min_size(
+                    grid_origin + egui::vec2(week as f32 * (CELL_SIZE + CELL_GAP), weekday as f32 * (CELL_SIZE + CELL_GAP)),
+                    egui::vec2(CELL_SIZE, CELL_SIZE),
+                );
+
+                // Month label above the week that holds the 1st
+                if day.date.day() == 1 {
+                    painter.text(
+                        egui::pos2(cell.min.x, rect.min.y),
+                        egui::Align2::LEFT_TOP,
+                        locale.short_month_name(day.date.month()),
+                        label_font.clone(),
+                        label_color,
+                    );
+                }
+
+                painter.rect_filled(cell, egui::CornerRadius::same(2), heatmap_color(day, summary.max_abs_daily_net, ui.visuals().dark_mode));
+
+                if response.hovered() && response.hover_pos().is_some_and(|pos| cell.contains(pos)) {
+                    hovered = Some(day);
+                }
+            }
+
+            if let Some(day) = hovered {
+                let date = locale.format_date(day.date.year(), day.date.month(), day.date.day(), DateStyle::Medium);
+                let text = if day.transaction_count == 0 {
+                    format!("{}\nNo transactions", date)
+                } else {
+                    format!(
+                        "{}\nNet {}\nIn {} · Out {}\n{} transaction{}",
+                        date,
+                        format_amount(day.net),
+                        format_amount(day.income),
+                        format_amount(day.expenses),
+                        day.transaction_count,
+                        if day.transaction_count == 1 { "" } else { "s" },
+                    )
+                };
+                response.on_hover_text(text);
+            }
+        });
+
+        ui.add_space(16.0);
+
+        // Monthly totals, scrolled when the window is short
+        egui::ScrollArea::vertical().id_salt("year_heatmap_months_scroll").show(ui, |ui| {
+            egui::Grid::new("year_heatmap_monthly_totals")
+                .num_columns(4)
+                .spacing(egui::vec2(24.0, 4.0))
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label(egui::RichText::new("Month").strong());
+                    ui.label(egui::RichText::new("In").strong());
+                    ui.label(egui::RichText::new("Out").strong());
+                    ui.label(egui::RichText::new("Net").strong());
+                    ui.end_row();
+
+                    for month in &summary.months {
+                        ui.label(locale.month_name(month.month));
+                        ui.label(format_amount(month.income));
+                        ui.label(format_amount(month.expenses));
+                        ui.label(egui::RichText::new(format_amount(month.net)).color(net_text_color(month.net)));
+                        ui.end_row();
+                    }
+
+                    ui.label(egui::RichText::new("Year").strong());
+                    ui.label("");
+                    ui.label("");
+                    ui.label(egui::RichText::new(format_amount(summary.total_net)).strong().color(net_text_color(summary.total_net)));
+                    ui.end_row();
+                });
+        });
+    }
+}
+
+/// Pick a day's colour: green for saving, red for spending, stronger for bigger days
+fn heatmap_color(day: &DailyNet, max_abs_net: f64, dark_mode: bool) -> egui::Color32 {
+    let empty = if dark_mode { EMPTY_DAY_COLOR_DARK } else { EMPTY_DAY_COLOR };
+    if day.transaction_count == 0 || max_abs_net <= 0.0 {
+        return empty;
+    }
+
+    // Never fully faded, so a tiny day still shows up next to an empty one
+    let intensity = (0.25 + 0.75 * (day.net.abs() / max_abs_net)).min(1.0) as f32;
+    let target = if day.net > 0.0 {
+        egui::Color32::from_rgb(33, 150, 60)
+    } else if day.net < 0.0 {
+        egui::Color32::from_rgb(210, 50, 50)
+    } else {
+        // Money moved but it evened out
+        egui::Color32::from_rgb(150, 150, 150)
+    };
+    lerp_color(empty, target, intensity)
+}
+
+fn lerp_color(from: egui::Color32, to: egui::Color32, t: f32) -> egui::Color32 {
+    let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    egui::Color32::from_rgb(channel(from.r(), to.r()), channel(from.g(), to.g()), channel(from.b(), to.b()))
+}
+
+fn net_text_color(net: f64) -> egui::Color32 {
+    if net > 0.0 {
+        egui::Color32::from_rgb(33, 130, 60)
+    } else if net < 0.0 {
+        egui::Color32::from_rgb(200, 50, 50)
+    } else {
+        egui::Color32::from_rgb(100, 100, 100)
+    }
+}
@@ -3,6 +3,8 @@ pub mod mappers;
 pub mod components;
 pub mod app_state;
 pub mod app_coordinator;
+pub mod startup_recovery; // Shown when the data can't be opened at launch
+pub mod tray; // Menu bar / system tray icon with the balance and quick add
 pub mod state;  // NEW: Organized state management
 
 pub use fonts::*;
@@ -10,4 +12,5 @@ pub use mappers::*;
 pub use components::*;
 // pub use app_state::*;  // Temporarily disabled to avoid ambiguous exports
 pub use app_state::AllowanceTrackerApp;  // Keep the main app struct available
+pub use startup_recovery::AppRoot;
 pub use state::*;  // NEW: Re-export organized state 
\ No newline at end of file
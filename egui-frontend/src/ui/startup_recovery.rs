@@ -0,0 +1,194 @@
+//! # Startup Recovery
+//!
+//! What the window shows when the backend can't be opened at launch, such as
+//! a data folder that can't be created or a config file that can't be read.
+//!
+//! ## Responsibilities:
+//! - Show what went wrong and which folder was tried, with details to copy
+//! - Try the same folder again
+//! - Open a different folder, remembered for next time
+//! - Restore a backup zip into an empty folder and open that
+//!
+//! ## Purpose:
+//! Before this, a failed startup closed the window with nothing but a log
+//! line. Now the app always opens, and a parent can get back to their data
+//! without editing files by hand.
+
+use eframe::egui;
+use log::{error, info};
+use std::path::PathBuf;
+use crate::backend::storage::csv::data_location;
+use crate::backend::Backend;
+use crate::ui::app_state::AllowanceTrackerApp;
+use crate::ui::components::styling::setup_kid_friendly_style;
+
+/// The top-level app: the tracker, or the recovery screen until a backend opens
+pub enum AppRoot {
+    Running(Box<AllowanceTrackerApp>),
+    Recovery(StartupRecovery),
+}
+
+impl AppRoot {
+    /// Open the usual data folder, falling back to the recovery screen if that fails
+    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        AllowanceTrackerApp::prepare_context(&cc.egui_ctx);
+        match Backend::new() {
+            Ok(backend) => {
+                info!("Successfully initialized Allowance Tracker app");
+                AppRoot::Running(Box::new(AllowanceTrackerApp::with_backend(&cc.egui_ctx, backend)))
+            }
+            Err(e) => {
+                error!("Failed to initialize app: {:#}", e);
+                AppRoot::Recovery(StartupRecovery::new(&e))
+            }
+        }
+    }
+}
+
+impl eframe::App for AppRoot {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        match self {
+            AppRoot::Running(app) => app.update(ctx, frame),
+            AppRoot::Recovery(recovery) => {
+                if let Some(backend) = recovery.show(ctx) {
+                    *self = AppRoot::Running(Box::new(AllowanceTrackerApp::with_backend(ctx, backend)));
+                    ctx.request_repaint();
+                }
+            }
+        }
+    }
+}
+
+/// State of the recovery screen
+pub struct StartupRecovery {
+    /// The folder that was tried, when it could be worked out
+    attempted_directory: Option<PathBuf>,
+    /// The error and its causes, one per line
+    details: String,
+    /// Result of the last action, shown under the buttons
+    status: Option<String>,
+}
+
+impl StartupRecovery {
+    fn new(error: &anyhow::Error) -> Self {
+        Self {
+            attempted_directory: data_location::resolve_data_directory().ok(),
+            details: error.chain().map(|cause| cause.to_string()).collect::<Vec<_>>().join("\n"),
+            status: None,
+        }
+    }
+
+    /// Draw the screen; returns a backend once one opens
+    fn show(&mut self, ctx: &egui::Context) -> Option<Backend> {
+        setup_kid_friendly_style(ctx);
+
+        let mut retry_clicked = false;
+        let mut choose_clicked = false;
+        let mut restore_clicked = false;
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.add_space(40.0);
+                ui.label(egui::RichText::new("😟 The allowance data couldn't be opened")
+                    .font(egui::FontId::new(26.0, egui::FontFamily::Proportional))
+                    .strong());
+                ui.add_space(10.0);
+                ui.label("Nothing has been changed. You can try again, open the data from another folder, or restore a backup.");
+            });
+            ui.add_space(20.0);
+
+            egui::Grid::new("startup_recovery_grid").spacing([12.0, 6.0]).show(ui, |ui| {
+                ui.label(egui::RichText::new("Folder:").strong());
+                ui.label(match &self.attempted_directory {
+                    Some(path) => path.display().to_string(),
+                    None => "Unknown".to_string(),
+                });
+                ui.end_row();
+                ui.label(egui::RichText::new("Version:").strong());
+                ui.label(env!("CARGO_PKG_VERSION"));
+                ui.end_row();
+            });
+
+            ui.add_space(10.0);
+            ui.label(egui::RichText::new("What went wrong:").strong());
+            egui::ScrollArea::vertical().max_height(160.0).show(ui, |ui| {
+                ui.add(egui::Label::new(egui::RichText::new(&self.details).monospace()).wrap());
+            });
+            if ui.small_button("📋 Copy details").clicked() {
+                ctx.copy_text(format!(
+                    "Folder: {}\nVersion: {}\n{}",
+                    self.attempted_directory.as_ref().map(|path| path.display().to_string()).unwrap_or_default(),
+                    env!("CARGO_PKG_VERSION"),
+                    self.details
+                ));
+            }
+
+            ui.add_space(20.0);
+            ui.horizontal(|ui| {
+                if ui.button("🔄 Try again").clicked() {
+                    retry_clicked = true;
+                }
+                if ui.button("📁 Choose another folder…").clicked() {
+                    choose_clicked = true;
+                }
+                if ui.button("📦 Restore a backup…").on_hover_text("Unpack a backup zip, such as the one a reset saves, into an empty folder").clicked() {
+                    restore_clicked = true;
+                }
+            });
+
+            if let Some(status) = &self.status {
+                ui.add_space(10.0);
+                ui.colored_label(egui::Color32::from_rgb(220, 50, 50), status);
+            }
+        });
+
+        if retry_clicked {
+            return self.open(Backend::new(), None);
+        }
+        if choose_clicked {
+            if let Some(folder) = rfd::FileDialog::new().set_title("Choose Data Folder").pick_folder() {
+                return self.open(Backend::with_data_directory(&folder), Some(folder));
+            }
+        }
+        if restore_clicked {
+            let Some(archive) = rfd::FileDialog::new().set_title("Choose Backup").add_filter("Backup", &["zip"]).pick_file() else {
+                return None;
+            };
+            let Some(folder) = rfd::FileDialog::new().set_title("Choose an Empty Folder to Restore Into").pick_folder() else {
+                return None;
+            };
+            match data_location::restore_backup_archive(&archive, &folder) {
+                Ok(count) => {
+                    info!("📦 Restored {} files; opening {:?}", count, folder);
+                    return self.open(Backend::with_data_directory(&folder), Some(folder));
+                }
+                Err(e) => self.status = Some(format!("Could not restore the backup: {}", e)),
+            }
+        }
+        None
+    }
+
+    /// Keep a backend that opened, remembering a newly chosen folder; otherwise show why not
+    fn open(&mut self, result: anyhow::Result<Backend>, chosen: Option<PathBuf>) -> Option<Backend> {
+        match result {
+            Ok(backend) => {
+                if let Some(folder) = chosen {
+                    if let Err(e) = data_location::remember_data_directory(&folder) {
+                        // Still open it; the parent will just be asked again next time
+                        error!("📁 Could not remember the chosen data folder: {}", e);
+                    }
+                }
+                Some(backend)
+            }
+            Err(e) => {
+                error!("Failed to open data: {:#}", e);
+                if let Some(folder) = chosen {
+                    self.attempted_directory = Some(folder);
+                }
+                self.details = e.chain().map(|cause| cause.to_string()).collect::<Vec<_>>().join("\n");
+                self.status = Some("That didn't work either. See what went wrong above.".to_string());
+                None
+            }
+        }
+    }
+}
@@ -5,9 +5,10 @@
 //!
 //! ## Responsibilities:
 //! - Backend connection management
-//! - Current child selection
+//! - Current child selection and the list of children
 //! - Current balance tracking
 //! - Main tab navigation state
+//! - Locale, currency and background of the selected child
 //!
 //! ## Purpose:
 //! This represents the core "business state" of the application - the fundamental
@@ -15,6 +16,10 @@
 
 use shared::*;
 use crate::backend::Backend;
+use crate::backend::domain::localization::Locale;
+use crate::backend::domain::models::background::ChildBackground;
+use crate::backend::domain::models::child::Child as DomainChild;
+use crate::backend::domain::models::currency::Currency;
 
 /// Tabs available in the main interface
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -23,6 +28,7 @@ pub enum MainTab {
     Table,
     Chart,
     Goal,
+    Family,
 }
 
 /// Core application state containing essential app data
@@ -33,11 +39,29 @@ pub struct CoreAppState {
     /// Currently selected child
     pub current_child: Option<Child>,
     
+    /// All children, for the child pickers; `None` until first loaded
+    pub children: Option<Vec<DomainChild>>,
+    
+    /// Error from the last attempt to load the children
+    pub children_error: Option<String>,
+    
     /// Current balance for the selected child
     pub current_balance: f64,
     
     /// Currently active main tab (Calendar or Table)
     pub current_tab: MainTab,
+    
+    /// Locale for the selected child's dates and amounts
+    pub current_locale: Locale,
+    
+    /// Currency the selected child's money is counted in
+    pub current_currency: Currency,
+    
+    /// Theme pack or picture drawn behind the selected child's views
+    pub current_background: ChildBackground,
+    
+    /// Whether the balance, goal and table still come from the startup snapshot
+    pub showing_snapshot: bool,
 }
 
 impl CoreAppState {
@@ -46,8 +70,14 @@ impl CoreAppState {
         Self {
             backend,
             current_child: None,
+            children: None,
+            children_error: None,
             current_balance: 0.0,
             current_tab: MainTab::Calendar, // Default to calendar view
+            current_locale: Locale::default(),
+            current_currency: Currency::default(),
+            current_background: ChildBackground::default(),
+            showing_snapshot: false,
         }
     }
 } 
\ No newline at end of file
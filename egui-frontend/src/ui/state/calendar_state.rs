@@ -14,6 +14,9 @@
 
 use chrono::Datelike;
 use shared::*;
+use crate::backend::domain::commands::budget::BudgetStatusResult;
+use crate::backend::domain::models::planned_purchase::PlannedPurchase;
+use crate::backend::domain::models::transaction::Transaction as DomainTransaction;
 
 /// Types of overlays that can be shown for calendar day interaction
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -36,6 +39,9 @@ pub struct CalendarState {
     /// Calendar month data from backend
     pub calendar_month: Option<shared::CalendarMonth>,
     
+    /// Day summaries for the months either side of the selected one
+    pub adjacent_month_summaries: Vec<shared::CalendarMonthSummary>,
+    
     /// Currently selected month (1-12)
     pub selected_month: u32,
     
@@ -48,6 +54,36 @@ pub struct CalendarState {
     /// Day that is expanded to show all transaction chips
     pub expanded_day: Option<chrono::NaiveDate>,
     
+    /// Day cell the keyboard focus is on, moved with the arrow keys
+    pub focused_day: Option<chrono::NaiveDate>,
+    
+    /// Purchases the current child has planned and not yet made
+    pub planned_purchases: Vec<PlannedPurchase>,
+    
+    /// Spending against the current child's targets for the selected month
+    pub budget_status: Option<BudgetStatusResult>,
+    
+    /// Whether a totals row is drawn under each week (a saved display preference)
+    pub show_week_summaries: bool,
+    
+    /// Whether the active goal's progress is drawn above the calendar (a saved display preference)
+    pub show_goal_strip: bool,
+    
+    /// Day picked in the "go to date" control
+    pub jump_date: chrono::NaiveDate,
+    
+    /// Text in the calendar's search box
+    pub search_text: String,
+    
+    /// Transactions matching the search text, newest first
+    pub search_results: Vec<DomainTransaction>,
+    
+    /// Whether more transactions matched than are listed
+    pub search_has_more: bool,
+    
+    /// Whether the search results list is open under the search box
+    pub search_open: bool,
+    
     /// Active overlay for day interaction
     pub active_overlay: Option<OverlayType>,
     
@@ -66,10 +102,21 @@ impl CalendarState {
             calendar_loading: false,
             calendar_transactions: Vec::new(),
             calendar_month: None,
+            adjacent_month_summaries: Vec::new(),
             selected_month: current_month,
             selected_year: current_year,
             selected_day: None,
             expanded_day: None,
+            focused_day: None,
+            planned_purchases: Vec::new(),
+            budget_status: None,
+            show_week_summaries: false,
+            show_goal_strip: false,
+            jump_date: now.date_naive(),
+            search_text: String::new(),
+            search_results: Vec::new(),
+            search_has_more: false,
+            search_open: false,
             active_overlay: None,
             modal_just_opened: false,
         }
@@ -103,6 +150,42 @@ impl CalendarState {
         log::info!("📅 Navigated to next month: {}/{}", self.selected_month, self.selected_year);
     }
 
+    /// Move the keyboard focus by a number of days (1 for the next day, 7 for
+    /// the same day next week), starting from the selected day or the 1st of
+    /// the month when no day in the shown month has focus yet.
+    ///
+    /// Returns how many months the calendar has to move (-1, 0 or 1) to show
+    /// the newly focused day.
+    pub fn move_focus(&mut self, days: i64) -> i32 {
+        let shown_month = (self.selected_year, self.selected_month);
+        let in_shown_month = |date: &chrono::NaiveDate| (date.year(), date.month()) == shown_month;
+        let start = self.focused_day.filter(in_shown_month)
+            .or(self.selected_day.filter(in_shown_month))
+            .or_else(|| chrono::NaiveDate::from_ymd_opt(self.selected_year, self.selected_month, 1));
+        let Some(start) = start else {
+            return 0;
+        };
+        
+        let target = start + chrono::Duration::days(days);
+        self.focused_day = Some(target);
+        (target.year(), target.month()).cmp(&shown_month) as i32
+    }
+
+    /// Empty the search box and close its results
+    pub fn clear_search(&mut self) {
+        self.search_text.clear();
+        self.search_results.clear();
+        self.search_has_more = false;
+        self.search_open = false;
+    }
+
+    /// Preloaded summary for a neighbouring month, if it was fetched with the current one
+    pub fn preloaded_month_summary(&self, month: u32, year: i32) -> Option<&shared::CalendarMonthSummary> {
+        self.adjacent_month_summaries
+            .iter()
+            .find(|summary| summary.month == month && summary.year as i32 == year)
+    }
+
     /// Get the current month name as a string
     pub fn get_current_month_name(&self) -> String {
         match self.selected_month {
@@ -121,4 +204,40 @@ impl CalendarState {
             _ => "Unknown"
         }.to_string()
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn state_for(year: i32, month: u32) -> CalendarState {
+        let mut state = CalendarState::new();
+        state.selected_year = year;
+        state.selected_month = month;
+        state
+    }
+
+    #[test]
+    fn test_move_focus_starts_from_selected_day() {
+        let mut state = state_for(2025, 6);
+        state.selected_day = NaiveDate::from_ymd_opt(2025, 6, 19);
+
+        assert_eq!(state.move_focus(1), 0);
+        assert_eq!(state.focused_day, NaiveDate::from_ymd_opt(2025, 6, 20));
+        assert_eq!(state.move_focus(-7), 0);
+        assert_eq!(state.focused_day, NaiveDate::from_ymd_opt(2025, 6, 13));
+    }
+
+    #[test]
+    fn test_move_focus_across_month_and_year() {
+        let mut state = state_for(2025, 12);
+        state.focused_day = NaiveDate::from_ymd_opt(2025, 12, 29);
+        assert_eq!(state.move_focus(7), 1);
+        assert_eq!(state.focused_day, NaiveDate::from_ymd_opt(2026, 1, 5));
+
+        let mut state = state_for(2025, 3);
+        assert_eq!(state.move_focus(-1), -1);
+        assert_eq!(state.focused_day, NaiveDate::from_ymd_opt(2025, 2, 28));
+    }
+}
@@ -0,0 +1,108 @@
+//! # Celebration State Module
+//!
+//! This module contains the state for celebrations shown over the app.
+//!
+//! ## Responsibilities:
+//! - Queue of celebrations fetched from the backend, oldest first
+//! - The celebration currently on screen and when it appeared
+//! - Confetti still falling
+//! - Whether motion is reduced
+//!
+//! ## Purpose:
+//! Several celebrations can arrive at once, e.g. an allowance that also
+//! passes a balance milestone, so they are queued and shown one after the
+//! other instead of on top of each other.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use crate::backend::domain::models::celebration::Celebration;
+use crate::ui::components::confetti::Confetti;
+
+/// How long each celebration stays on screen
+pub const CELEBRATION_DURATION: Duration = Duration::from_secs(4);
+
+/// How often the backend is asked for new celebrations while the app is in use
+pub const CELEBRATION_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// State for celebrations and their confetti
+#[derive(Debug)]
+pub struct CelebrationState {
+    /// Celebrations waiting their turn
+    pub queue: VecDeque<Celebration>,
+
+    /// Celebration on screen, if any
+    pub current: Option<Celebration>,
+
+    /// When the current celebration appeared
+    pub shown_at: Option<Instant>,
+
+    /// Confetti for the current celebration
+    pub confetti: Confetti,
+
+    /// Whether celebrations show only their message, without confetti
+    pub reduce_motion: bool,
+
+    /// Last time the backend was asked for new celebrations
+    pub last_check: Option<Instant>,
+}
+
+impl CelebrationState {
+    /// Create new celebration state with nothing to show
+    pub fn new() -> Self {
+        Self {
+            queue: VecDeque::new(),
+            current: None,
+            shown_at: None,
+            confetti: Confetti::new(),
+            reduce_motion: false,
+            last_check: None,
+        }
+    }
+
+    /// Queue celebrations that aren't already showing or waiting
+    pub fn enqueue(&mut self, celebrations: Vec<Celebration>) {
+        for celebration in celebrations {
+            let known = self.current.iter().chain(self.queue.iter()).any(|queued| queued.id == celebration.id);
+            if !known {
+                self.queue.push_back(celebration);
+            }
+        }
+    }
+
+    /// Whether the backend is due to be asked for new celebrations
+    pub fn should_check(&self) -> bool {
+        self.last_check.map_or(true, |last| last.elapsed() >= CELEBRATION_CHECK_INTERVAL)
+    }
+
+    /// Whether the current celebration has been on screen long enough
+    pub fn current_expired(&self) -> bool {
+        self.shown_at.map_or(true, |shown_at| shown_at.elapsed() >= CELEBRATION_DURATION)
+    }
+
+    /// Take the current celebration off screen
+    pub fn dismiss(&mut self) {
+        self.current = None;
+        self.shown_at = None;
+    }
+
+    /// Drop everything queued, e.g. when another child is selected
+    pub fn clear(&mut self) {
+        self.queue.clear();
+        self.dismiss();
+        self.confetti.clear();
+    }
+
+    /// Turn reduced motion on or off; confetti already falling is removed
+    pub fn set_reduce_motion(&mut self, reduce_motion: bool) {
+        self.reduce_motion = reduce_motion;
+        if reduce_motion {
+            self.confetti.clear();
+        }
+    }
+}
+
+impl Default for CelebrationState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -3,7 +3,9 @@
 //! This module manages all chart-related state for the allowance tracker app.
 //! It handles chart data, period selection, and chart configuration.
 
-use crate::ui::components::chart_renderer::{ChartPeriod, ChartDataPoint};
+use chrono::Datelike;
+use crate::backend::domain::commands::reports::{BreakdownGrouping, MonthlyCashFlowResult, SpendingBreakdownResult, YearSummaryResult};
+use crate::ui::components::chart_renderer::{BreakdownPeriod, ChartPeriod, ChartDataPoint, ChartMarkerPoint, ChartView};
 
 /// Chart-specific state for balance visualization
 #[derive(Debug)]
@@ -14,31 +16,87 @@ pub struct ChartState {
     /// Chart data points for the current period
     pub chart_data: Vec<ChartDataPoint>,
     
+    /// Large transactions and goal dates within the current period
+    pub chart_markers: Vec<ChartMarkerPoint>,
+    
     /// Whether chart data is currently loading
     pub is_loading: bool,
     
     /// Error message if chart loading failed
     pub error_message: Option<String>,
+    
+    /// Which chart is shown
+    pub view: ChartView,
+    
+    /// Year shown in the heatmap
+    pub heatmap_year: i32,
+    
+    /// Day-by-day summary for `heatmap_year`, loaded on demand
+    pub year_summary: Option<YearSummaryResult>,
+    
+    /// Whether the heatmap leaves out reversed transactions and their reversals
+    pub hide_reversals: bool,
+    
+    /// Whether the breakdown covers a month or a quarter
+    pub breakdown_period: BreakdownPeriod,
+    
+    /// How many periods before the current one the breakdown shows
+    pub breakdown_periods_back: u32,
+    
+    /// Whether the breakdown splits spending by category or by kind
+    pub breakdown_grouping: BreakdownGrouping,
+    
+    /// Spending for the breakdown's period, loaded on demand
+    pub spending_breakdown: Option<SpendingBreakdownResult>,
+    
+    /// Money earned and spent in each of the last months, loaded on demand
+    pub monthly_cash_flow: Option<MonthlyCashFlowResult>,
 }
 
 impl ChartState {
     /// Create new chart state with default values
     pub fn new() -> Self {
         Self {
-            selected_period: ChartPeriod::Days30, // Default to 30-day view
+            selected_period: ChartPeriod::Month1, // Default to one-month view
             chart_data: Vec::new(),
+            chart_markers: Vec::new(),
             is_loading: false,
             error_message: None,
+            view: ChartView::Balance,
+            heatmap_year: chrono::Local::now().year(),
+            year_summary: None,
+            hide_reversals: false,
+            breakdown_period: BreakdownPeriod::Month,
+            breakdown_periods_back: 0,
+            breakdown_grouping: BreakdownGrouping::Category,
+            spending_breakdown: None,
+            monthly_cash_flow: None,
         }
     }
     
     /// Clear chart data and reset loading state
     pub fn clear_data(&mut self) {
         self.chart_data.clear();
+        self.chart_markers.clear();
+        self.year_summary = None;
+        self.spending_breakdown = None;
+        self.monthly_cash_flow = None;
         self.is_loading = false;
         self.error_message = None;
     }
     
+    /// Switch to another view, dropping what it showed last time so it reloads
+    pub fn show_view(&mut self, view: ChartView) {
+        self.view = view;
+        self.error_message = None;
+        match view {
+            ChartView::Balance => self.chart_data.clear(),
+            ChartView::YearHeatmap => self.year_summary = None,
+            ChartView::SpendingBreakdown => self.spending_breakdown = None,
+            ChartView::MonthlyBars => self.monthly_cash_flow = None,
+        }
+    }
+    
     /// Set loading state
     pub fn set_loading(&mut self, loading: bool) {
         self.is_loading = loading;
@@ -53,9 +111,10 @@ impl ChartState {
         self.is_loading = false;
     }
     
-    /// Set chart data and clear error/loading states
-    pub fn set_data(&mut self, data: Vec<ChartDataPoint>) {
+    /// Set chart data and markers and clear error/loading states
+    pub fn set_data(&mut self, data: Vec<ChartDataPoint>, markers: Vec<ChartMarkerPoint>) {
         self.chart_data = data;
+        self.chart_markers = markers;
         self.is_loading = false;
         self.error_message = None;
     }
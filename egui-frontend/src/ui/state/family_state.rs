@@ -0,0 +1,59 @@
+//! # Family State Module
+//!
+//! This module contains the state for the Family tab.
+//!
+//! ## Responsibilities:
+//! - Family overview loaded from the backend
+//! - Side-by-side comparison of the children, with balances optionally hidden
+//! - Loading error for the overview
+//!
+//! ## Purpose:
+//! The Family tab shows every child at once, so its data is kept apart from
+//! the per-child calendar, table, chart and goal state.
+
+use crate::backend::domain::commands::family::{ChildComparisonResult, FamilyOverviewResult};
+
+/// State for the Family tab
+#[derive(Debug)]
+pub struct FamilyState {
+    /// Overview of every child; `None` until loaded
+    pub overview: Option<FamilyOverviewResult>,
+    
+    /// Children compared on savings rate and goals; `None` until loaded
+    pub comparison: Option<ChildComparisonResult>,
+    
+    /// Whether the tab shows the comparison instead of the child cards
+    pub show_comparison: bool,
+    
+    /// Whether the comparison leaves balances out (on by default)
+    pub hide_balances: bool,
+    
+    /// Error message if the overview failed to load
+    pub error_message: Option<String>,
+}
+
+impl FamilyState {
+    /// Create new empty family state
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Forget the loaded overview so it is fetched again on next render
+    pub fn invalidate(&mut self) {
+        self.overview = None;
+        self.comparison = None;
+        self.error_message = None;
+    }
+}
+
+impl Default for FamilyState {
+    fn default() -> Self {
+        Self {
+            overview: None,
+            comparison: None,
+            show_comparison: false,
+            hide_balances: true,
+            error_message: None,
+        }
+    }
+}
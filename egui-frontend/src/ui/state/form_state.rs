@@ -11,6 +11,8 @@
 //! This centralizes all form-related state management, making it easier to
 //! maintain consistent form behavior and validation across the application.
 
+use chrono::NaiveDate;
+
 /// Transaction type for money modal configuration
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TransactionType {
@@ -43,6 +45,19 @@ pub struct MoneyTransactionFormState {
     pub amount: String,
     pub description_error: Option<String>,
     pub amount_error: Option<String>,
+    /// Day the transaction happened; filled from the clicked calendar day (or today) when the modal opens
+    pub date: Option<NaiveDate>,
+    pub date_error: Option<String>,
+    /// Hints from the shared validation rules, e.g. "Use at most 2 decimal places"
+    pub suggestions: Vec<String>,
+    /// Descriptions offered as chips, and the text they were looked up for
+    pub description_suggestions: Option<(String, Vec<shared::DescriptionSuggestion>)>,
+    /// Preset icon key picked for the transaction, if any
+    pub icon: Option<String>,
+    /// Kind picked for the transaction, e.g. a bonus; `None` for plain income or expense
+    pub transaction_type: Option<shared::TransactionType>,
+    /// Why the spend was refused by the child's no-negative-balance rule; a parent can override it
+    pub overdraft_error: Option<String>,
     pub is_valid: bool,
 }
 
@@ -53,6 +68,13 @@ impl MoneyTransactionFormState {
             amount: String::new(),
             description_error: None,
             amount_error: None,
+            date: None,
+            date_error: None,
+            suggestions: Vec::new(),
+            description_suggestions: None,
+            icon: None,
+            transaction_type: None,
+            overdraft_error: None,
             is_valid: true,
         }
     }
@@ -62,6 +84,13 @@ impl MoneyTransactionFormState {
         self.amount.clear();
         self.description_error = None;
         self.amount_error = None;
+        self.date = None;
+        self.date_error = None;
+        self.suggestions.clear();
+        self.description_suggestions = None;
+        self.icon = None;
+        self.transaction_type = None;
+        self.overdraft_error = None;
         self.is_valid = true;
     }
 }
@@ -113,6 +142,12 @@ pub struct FormState {
     /// Generic money transaction form states
     pub income_form_state: MoneyTransactionFormState,
     pub expense_form_state: MoneyTransactionFormState,
+    
+    /// Quick-add box in the header ("spent 4.50 ice cream yesterday")
+    pub quick_entry_input: String,
+    pub quick_entry_error: Option<String>,
+    /// Set by the quick-add shortcut; the box takes focus on its next render
+    pub quick_entry_focus_requested: bool,
 }
 
 impl FormState {
@@ -126,6 +161,9 @@ impl FormState {
             add_money_is_valid: true,
             income_form_state: MoneyTransactionFormState::new(),
             expense_form_state: MoneyTransactionFormState::new(),
+            quick_entry_input: String::new(),
+            quick_entry_error: None,
+            quick_entry_focus_requested: false,
         }
     }
     
@@ -138,5 +176,7 @@ impl FormState {
         self.add_money_is_valid = true;
         self.income_form_state.clear();
         self.expense_form_state.clear();
+        self.quick_entry_input.clear();
+        self.quick_entry_error = None;
     }
 } 
\ No newline at end of file
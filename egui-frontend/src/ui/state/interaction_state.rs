@@ -4,6 +4,8 @@
 //!
 //! ## Responsibilities:
 //! - Transaction selection state (for deletion)
+//! - A transaction dragged to another day, and the undo offered after moving it
+//! - A transaction being edited from the table's right-click menu
 //! - Dropdown menu states
 //! - User interaction modes
 //!
@@ -12,8 +14,55 @@
 //! selection modes and interactive UI elements consistently.
 
 use std::collections::HashSet;
+use std::time::{Duration, Instant};
+use chrono::{DateTime, FixedOffset, NaiveDate};
 use crate::ui::components::dropdown_menu::DropdownMenu;
 
+/// How long the undo toast stays up after a transaction is moved
+pub const UNDO_TOAST_DURATION: Duration = Duration::from_secs(8);
+
+/// A transaction chip dropped on another day, waiting to be moved
+#[derive(Debug, Clone)]
+pub struct PendingMove {
+    pub transaction_id: String,
+    pub description: String,
+    /// Where the transaction is now
+    pub from: DateTime<FixedOffset>,
+    /// Day it was dropped on
+    pub to: NaiveDate,
+}
+
+/// A move that the toast can still undo
+#[derive(Debug, Clone)]
+pub struct MoveUndo {
+    pub child_id: String,
+    pub transaction_id: String,
+    pub description: String,
+    /// Where the transaction was before the move
+    pub previous_date: DateTime<FixedOffset>,
+    /// Day it was moved to
+    pub moved_to: NaiveDate,
+    pub shown_at: Instant,
+}
+
+impl MoveUndo {
+    /// Whether the toast has been up long enough to go away
+    pub fn expired(&self) -> bool {
+        self.shown_at.elapsed() >= UNDO_TOAST_DURATION
+    }
+}
+
+/// A transaction open in the edit window from the table's right-click menu
+#[derive(Debug, Clone)]
+pub struct RowEdit {
+    pub transaction_id: String,
+    /// Description and date as stored, to tell what was changed
+    pub original_description: String,
+    pub original_date: DateTime<FixedOffset>,
+    pub description: String,
+    pub date: NaiveDate,
+}
+
 /// User interaction state for selections and UI modes
 pub struct InteractionState {
     /// Whether we're in transaction selection mode (for deletion)
@@ -27,6 +76,21 @@ pub struct InteractionState {
     
     /// Settings dropdown menu state
     pub settings_dropdown: DropdownMenu,
+    
+    /// Transaction dropped on another day while the parental check runs
+    pub pending_move: Option<PendingMove>,
+    
+    /// Last move, while its undo toast is showing
+    pub move_undo: Option<MoveUndo>,
+    
+    /// Transaction in the edit window, if it is open
+    pub row_edit: Option<RowEdit>,
+    
+    /// Saved edit waiting for the parental check
+    pub pending_edit: Option<RowEdit>,
+    
+    /// Row to pick for deletion once the parental check lets delete mode open
+    pub pending_delete_id: Option<String>,
 }
 
 impl InteractionState {
@@ -37,6 +101,11 @@ impl InteractionState {
             selected_transaction_ids: HashSet::new(),
             child_dropdown: DropdownMenu::new("child_dropdown".to_string()),
             settings_dropdown: DropdownMenu::new("settings_dropdown".to_string()),
+            pending_move: None,
+            move_undo: None,
+            row_edit: None,
+            pending_edit: None,
+            pending_delete_id: None,
         }
     }
     
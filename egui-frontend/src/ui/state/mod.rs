@@ -12,6 +12,9 @@
 //! - `interaction_state` - User interaction state (selection, dropdowns)
 //! - `table_state` - Transaction table pagination and display state
 //! - `chart_state` - Chart visualization and time period state
+//! - `family_state` - Family overview across all children
+//! - `celebration_state` - Queued celebrations, confetti and reduced motion
+//! - `tutorial_state` - Steps of the guided tour and where their targets are
 //!
 //! ## Architecture:
 //! Each state module is focused and has minimal dependencies on others.
@@ -26,6 +29,9 @@ pub mod interaction_state;
 pub mod table_state;
 pub mod chart_state;
 pub mod goal_state;
+pub mod family_state;
+pub mod celebration_state;
+pub mod tutorial_state;
 
 // Re-export all state components for easy access
 pub use app_state::*;
@@ -36,4 +42,7 @@ pub use form_state::*;
 pub use interaction_state::*;
 pub use table_state::*;
 pub use chart_state::*;
-pub use goal_state::GoalUiState; 
\ No newline at end of file
+pub use goal_state::GoalUiState;
+pub use family_state::FamilyState;
+pub use celebration_state::{CelebrationState, CELEBRATION_DURATION};
+pub use tutorial_state::{TutorialState, TutorialStep}; 
\ No newline at end of file
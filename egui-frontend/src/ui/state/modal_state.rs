@@ -11,6 +11,9 @@
 //! This centralizes all modal-related state management, making it easier to
 //! coordinate modal behavior and prevent conflicts between different modals.
 
+use crate::backend::domain::commands::data_directory::MissingDataFolder;
+use crate::backend::domain::models::actor::Actor;
+
 /// Stages of the parental control challenge flow
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ParentalControlStage {
@@ -24,6 +27,12 @@ pub enum ParentalControlStage {
 pub enum ProtectedAction {
     DeleteTransactions,
     AccessSettings, // NEW: Universal protection for all settings menu items
+    /// Move the dragged transaction in `InteractionState::pending_move`
+    MoveTransaction,
+    /// Save the edit in `InteractionState::pending_edit`
+    EditTransaction,
+    /// Record the spend form's spend despite the child's no-negative-balance rule
+    OverrideOverdraft,
 }
 
 /// Specific settings menu actions that can be executed after parental control
@@ -35,6 +44,105 @@ pub enum SettingsAction {
     DeleteTransactions,
     ExportData,
     DataDirectory,
+    TextSize,
+    ArchiveHistory,
+    Loans,
+    PlannedPurchases,
+    Parents,
+    ResetData,
+    GoalHistory,
+    AllowanceAdvance,
+    QuickPicks,
+    Templates,
+    ImportStatement,
+    Categorize,
+    DataRetention,
+    SpendingTargets,
+    MonthlyReports,
+    Experiments,
+    AllowanceAgreement,
+    CloseMonth,
+    Updates,
+    FridgeView,
+    Background,
+    SpendingRules,
+    Encryption,
+    Tutorial,
+}
+
+impl SettingsAction {
+    /// Every settings action, in the order the settings menu lists them
+    ///
+    /// The menu is built from this list and maps clicks back through it, so a
+    /// new action is registered here and in `menu_entry` only.
+    pub const ALL: [SettingsAction; 30] = [
+        SettingsAction::ShowProfile,
+        SettingsAction::CreateChild,
+        SettingsAction::ConfigureAllowance,
+        SettingsAction::DeleteTransactions,
+        SettingsAction::ExportData,
+        SettingsAction::DataDirectory,
+        SettingsAction::TextSize,
+        SettingsAction::ArchiveHistory,
+        SettingsAction::Loans,
+        SettingsAction::PlannedPurchases,
+        SettingsAction::Parents,
+        SettingsAction::ResetData,
+        SettingsAction::GoalHistory,
+        SettingsAction::AllowanceAdvance,
+        SettingsAction::QuickPicks,
+        SettingsAction::Templates,
+        SettingsAction::ImportStatement,
+        SettingsAction::Categorize,
+        SettingsAction::DataRetention,
+        SettingsAction::SpendingTargets,
+        SettingsAction::MonthlyReports,
+        SettingsAction::Experiments,
+        SettingsAction::AllowanceAgreement,
+        SettingsAction::CloseMonth,
+        SettingsAction::Updates,
+        SettingsAction::FridgeView,
+        SettingsAction::Background,
+        SettingsAction::SpendingRules,
+        SettingsAction::Encryption,
+        SettingsAction::Tutorial,
+    ];
+
+    /// Label and icon for the settings menu
+    pub fn menu_entry(self) -> (&'static str, &'static str) {
+        match self {
+            SettingsAction::ShowProfile => ("Profile", "👤"),
+            SettingsAction::CreateChild => ("Create child", "👶"),
+            SettingsAction::ConfigureAllowance => ("Configure allowance", "⚙️"),
+            SettingsAction::DeleteTransactions => ("Delete transactions", "🗑️"),
+            SettingsAction::ExportData => ("Export data", "📤"),
+            SettingsAction::DataDirectory => ("Data directory", "📁"),
+            SettingsAction::TextSize => ("Display", "🔠"),
+            SettingsAction::ArchiveHistory => ("Archive old history", "🗄️"),
+            SettingsAction::Loans => ("Loans & IOUs", "🤝"),
+            SettingsAction::PlannedPurchases => ("Planned purchases", "🛍️"),
+            SettingsAction::Parents => ("Parents & PINs", "👪"),
+            SettingsAction::ResetData => ("Reset all data", "🧹"),
+            SettingsAction::GoalHistory => ("Goal history", "🏁"),
+            SettingsAction::AllowanceAdvance => ("Allowance advance", "💸"),
+            SettingsAction::QuickPicks => ("Quick picks", "⚡"),
+            SettingsAction::Templates => ("Templates", "⭐"),
+            SettingsAction::ImportStatement => ("Import statement", "📥"),
+            SettingsAction::Categorize => ("Categorize history", "🏷️"),
+            SettingsAction::DataRetention => ("Data retention", "🧽"),
+            SettingsAction::SpendingTargets => ("Spending targets", "🎯"),
+            SettingsAction::MonthlyReports => ("Monthly reports", "🗓️"),
+            SettingsAction::Experiments => ("Experiments", "🧪"),
+            SettingsAction::AllowanceAgreement => ("Allowance agreement", "📜"),
+            SettingsAction::CloseMonth => ("Close month", "🔒"),
+            SettingsAction::Updates => ("Updates", "⬆️"),
+            SettingsAction::FridgeView => ("Fridge view", "🧲"),
+            SettingsAction::Background => ("Background", "🖼️"),
+            SettingsAction::SpendingRules => ("Spending rules", "🚦"),
+            SettingsAction::Encryption => ("Encryption", "🔐"),
+            SettingsAction::Tutorial => ("Take the tour", "🧭"),
+        }
+    }
 }
 
 
@@ -71,6 +179,27 @@ pub struct ModalState {
     /// Whether parental control is currently processing
     pub parental_control_loading: bool,
     
+    /// Parent who passed the most recent challenge, for attributing what they do next
+    pub parental_control_actor: Option<Actor>,
+    
+    /// When the backend will check answers again, after too many wrong ones
+    pub parental_control_locked_until: Option<std::time::Instant>,
+    
+    /// Whether the startup prompt to unlock encrypted child data is visible
+    pub show_unlock_data_modal: bool,
+    
+    /// Passphrase typed into the unlock prompt
+    pub unlock_data_passphrase: String,
+    
+    /// Error message for the unlock prompt
+    pub unlock_data_error: Option<String>,
+    
+    /// Relocated data folders that weren't found at startup; the prompt shows while any remain
+    pub missing_data_folders: Vec<MissingDataFolder>,
+    
+    /// Error message for the missing data folder prompt
+    pub missing_data_error: Option<String>,
+    
 
 }
 
@@ -87,6 +216,13 @@ impl ModalState {
             parental_control_input: String::new(),
             parental_control_error: None,
             parental_control_loading: false,
+            parental_control_actor: None,
+            parental_control_locked_until: None,
+            show_unlock_data_modal: false,
+            unlock_data_passphrase: String::new(),
+            unlock_data_error: None,
+            missing_data_folders: Vec::new(),
+            missing_data_error: None,
         }
     }
     
@@ -106,4 +242,22 @@ impl ModalState {
         self.parental_control_error = None;
         self.parental_control_loading = false;
     }
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_menu_lists_every_action_once() {
+        // Declaration order matches menu order, so each action's discriminant
+        // is its menu position; a variant missing from ALL leaves a gap
+        for (position, action) in SettingsAction::ALL.iter().enumerate() {
+            assert_eq!(*action as usize, position, "{:?} is out of place in SettingsAction::ALL", action);
+        }
+        let mut labels: Vec<&str> = SettingsAction::ALL.iter().map(|action| action.menu_entry().0).collect();
+        labels.sort();
+        labels.dedup();
+        assert_eq!(labels.len(), SettingsAction::ALL.len());
+    }
+}
@@ -6,13 +6,24 @@
 //! - Transaction table pagination state
 //! - Infinite scroll loading state
 //! - Table filtering and sorting (future)
-//! - Table interaction state
+//! - Table interaction state (row selection)
 //!
 //! ## Purpose:
 //! This isolates all table-specific state management, making it easier to
 //! maintain and test table functionality independently.
 
 use shared::*;
+use std::collections::{HashMap, HashSet};
+use crate::backend::domain::models::transaction_reversal::TransactionReversal;
+
+/// Which side of a reversal a table row is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReversalRole {
+    /// The transaction was undone by a later entry
+    Reversed,
+    /// The entry that undid another transaction
+    Reversal,
+}
 
 /// Transaction table-specific state for pagination and display
 #[derive(Debug)]
@@ -40,6 +51,12 @@ pub struct TableState {
     
     /// Page size for pagination requests
     pub page_size: u32,
+    
+    /// IDs of rows the user has selected (for copying)
+    pub selected_ids: HashSet<String>,
+    
+    /// Rows that are part of a reversal, and which side they are
+    pub reversal_roles: HashMap<String, ReversalRole>,
 }
 
 impl TableState {
@@ -54,6 +71,8 @@ impl TableState {
             initial_load_complete: false,
             pagination_error: None,
             page_size: 50, // Load 50 transactions at a time
+            selected_ids: HashSet::new(),
+            reversal_roles: HashMap::new(),
         }
     }
     
@@ -66,6 +85,13 @@ impl TableState {
         self.total_loaded = 0;
         self.initial_load_complete = false;
         self.pagination_error = None;
+        self.selected_ids.clear();
+        self.reversal_roles.clear();
+    }
+    
+    /// Drop loaded pages after transactions change; the table reloads the next time it is drawn
+    pub fn invalidate(&mut self) {
+        self.reset();
     }
     
     /// Add new transactions from a pagination response
@@ -109,4 +135,31 @@ impl TableState {
     pub fn transaction_count(&self) -> usize {
         self.displayed_transactions.len()
     }
-} 
\ No newline at end of file
+    
+    /// Select a row, or deselect it if it was already selected
+    pub fn toggle_selection(&mut self, transaction_id: &str) {
+        if !self.selected_ids.remove(transaction_id) {
+            self.selected_ids.insert(transaction_id.to_string());
+        }
+    }
+    
+    /// Selected transactions in the order they are displayed
+    pub fn selected_transactions(&self) -> Vec<Transaction> {
+        self.displayed_transactions
+            .iter()
+            .filter(|t| self.selected_ids.contains(&t.id))
+            .cloned()
+            .collect()
+    }
+    
+    /// Mark both sides of each reversal so the table can label them
+    pub fn set_reversals(&mut self, reversals: &[TransactionReversal]) {
+        self.reversal_roles = reversals
+            .iter()
+            .flat_map(|reversal| [
+                (reversal.original_id.clone(), ReversalRole::Reversed),
+                (reversal.reversal_id.clone(), ReversalRole::Reversal),
+            ])
+            .collect();
+    }
+}
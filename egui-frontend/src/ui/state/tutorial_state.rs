@@ -0,0 +1,171 @@
+//! # Tutorial State Module
+//!
+//! This module contains the state machine behind the guided tour.
+//!
+//! ## Responsibilities:
+//! - The tour's steps, in order: header → calendar → add money → goal card
+//! - Which step is showing, and moving forwards, backwards or out of the tour
+//! - Where each step's target was drawn on the last frame
+//!
+//! ## Purpose:
+//! The tour points at parts of the app that are drawn by different modules,
+//! so each of them records its own rectangle in egui's frame data and the
+//! overlay looks it up, instead of threading every rectangle through the app.
+
+use eframe::egui;
+use crate::ui::state::MainTab;
+
+/// One stop on the guided tour
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TutorialStep {
+    Header,
+    Calendar,
+    AddMoney,
+    GoalCard,
+}
+
+impl TutorialStep {
+    /// Every step, in the order the tour shows them
+    pub const ALL: [TutorialStep; 4] = [
+        TutorialStep::Header,
+        TutorialStep::Calendar,
+        TutorialStep::AddMoney,
+        TutorialStep::GoalCard,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            TutorialStep::Header => "Who's who",
+            TutorialStep::Calendar => "Your calendar",
+            TutorialStep::AddMoney => "Adding money",
+            TutorialStep::GoalCard => "Saving for something",
+        }
+    }
+
+    pub fn text(self) -> &'static str {
+        match self {
+            TutorialStep::Header => "Up here is whose allowance you're looking at and how much they have. Switch children and open the settings from the right.",
+            TutorialStep::Calendar => "Every allowance and every bit of money in or out shows up on the day it happened.",
+            TutorialStep::AddMoney => "Click a day, then the 💰 button above it to add money, or the other button to spend some.",
+            TutorialStep::GoalCard => "Set a goal here and watch how close you are to buying it, and how many days are left.",
+        }
+    }
+
+    /// Tab the step's target is on
+    pub fn tab(self) -> MainTab {
+        match self {
+            TutorialStep::Header | TutorialStep::Calendar | TutorialStep::AddMoney => MainTab::Calendar,
+            TutorialStep::GoalCard => MainTab::Goal,
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|step| *step == self).unwrap_or(0)
+    }
+
+    /// The step after this one, or `None` at the end of the tour
+    pub fn next(self) -> Option<TutorialStep> {
+        Self::ALL.get(self.index() + 1).copied()
+    }
+
+    /// The step before this one, or `None` at the start of the tour
+    pub fn previous(self) -> Option<TutorialStep> {
+        self.index().checked_sub(1).map(|index| Self::ALL[index])
+    }
+
+    /// Record where this step's target was drawn this frame
+    pub fn record_target(self, ctx: &egui::Context, rect: egui::Rect) {
+        ctx.data_mut(|data| data.insert_temp(self.target_id(), rect));
+    }
+
+    /// Where this step's target was last drawn, if it has been
+    pub fn target(self, ctx: &egui::Context) -> Option<egui::Rect> {
+        ctx.data(|data| data.get_temp(self.target_id()))
+    }
+
+    /// Drop the recorded target, e.g. one left over from an earlier visit to its tab
+    pub fn forget_target(self, ctx: &egui::Context) {
+        ctx.data_mut(|data| data.remove::<egui::Rect>(self.target_id()));
+    }
+
+    fn target_id(self) -> egui::Id {
+        egui::Id::new(("tutorial_target", self))
+    }
+}
+
+/// State of the guided tour
+#[derive(Debug, Default)]
+pub struct TutorialState {
+    /// Step on screen, or `None` when the tour isn't running
+    pub step: Option<TutorialStep>,
+}
+
+impl TutorialState {
+    /// Create tutorial state with the tour not running
+    pub fn new() -> Self {
+        Self { step: None }
+    }
+
+    /// Start the tour from its first step
+    pub fn start(&mut self) {
+        self.step = Some(TutorialStep::ALL[0]);
+    }
+
+    /// Whether the tour is running
+    pub fn is_active(&self) -> bool {
+        self.step.is_some()
+    }
+
+    /// Go to the next step; returns true when that finished the tour
+    pub fn advance(&mut self) -> bool {
+        self.step = self.step.and_then(TutorialStep::next);
+        self.step.is_none()
+    }
+
+    /// Go back a step; the first step stays where it is
+    pub fn back(&mut self) {
+        if let Some(previous) = self.step.and_then(TutorialStep::previous) {
+            self.step = Some(previous);
+        }
+    }
+
+    /// Leave the tour at whatever step it is on
+    pub fn skip(&mut self) {
+        self.step = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tour_walks_every_step_in_order() {
+        let mut tutorial = TutorialState::new();
+        assert!(!tutorial.is_active());
+
+        tutorial.start();
+        let mut seen = vec![tutorial.step.unwrap()];
+        while !tutorial.advance() {
+            seen.push(tutorial.step.unwrap());
+        }
+        assert_eq!(seen, TutorialStep::ALL);
+        assert!(!tutorial.is_active());
+    }
+
+    #[test]
+    fn test_back_stops_at_first_step() {
+        let mut tutorial = TutorialState::new();
+        tutorial.start();
+        tutorial.back();
+        assert_eq!(tutorial.step, Some(TutorialStep::Header));
+
+        tutorial.advance();
+        tutorial.advance();
+        tutorial.back();
+        assert_eq!(tutorial.step, Some(TutorialStep::Calendar));
+
+        tutorial.skip();
+        assert!(!tutorial.is_active());
+    }
+}
@@ -5,7 +5,7 @@
 //!
 //! ## Responsibilities:
 //! - Loading states
-//! - User feedback messages (error only)
+//! - User feedback messages (errors and short-lived success banners)
 //! - General UI status indicators
 //! - Refresh timing for periodic operations
 //!
@@ -15,6 +15,9 @@
 
 use std::time::{Duration, Instant};
 
+/// How long a success banner stays on screen
+pub const SUCCESS_MESSAGE_DURATION: Duration = Duration::from_secs(4);
+
 /// General UI state for loading indicators and user feedback
 #[derive(Debug, Default)]
 pub struct UIState {
@@ -24,6 +27,12 @@ pub struct UIState {
     /// Error message to display to the user
     pub error_message: Option<String>,
     
+    /// Success banner to display to the user, e.g. after adding money
+    pub success_message: Option<String>,
+    
+    /// When the success banner should disappear
+    pub success_expires_at: Option<Instant>,
+    
     /// Last time allowance refresh was performed
     pub last_allowance_refresh: Option<Instant>,
     
@@ -37,6 +46,8 @@ impl UIState {
         Self {
             loading: true, // Start with loading=true during app initialization
             error_message: None,
+            success_message: None,
+            success_expires_at: None,
             last_allowance_refresh: None,
             allowance_refresh_interval: Duration::from_secs(60), // 1 minute (temporarily for testing)
         }
@@ -45,6 +56,8 @@ impl UIState {
     /// Clear any error messages
     pub fn clear_messages(&mut self) {
         self.error_message = None;
+        self.success_message = None;
+        self.success_expires_at = None;
     }
     
     /// Set an error message
@@ -52,6 +65,21 @@ impl UIState {
         self.error_message = Some(message);
     }
     
+    /// Show a success banner for a few seconds; replaces any error
+    pub fn set_success(&mut self, message: String) {
+        self.error_message = None;
+        self.success_message = Some(message);
+        self.success_expires_at = Some(Instant::now() + SUCCESS_MESSAGE_DURATION);
+    }
+    
+    /// Drop the success banner once its time is up
+    pub fn expire_success_message(&mut self) {
+        if self.success_expires_at.is_some_and(|expires_at| Instant::now() >= expires_at) {
+            self.success_message = None;
+            self.success_expires_at = None;
+        }
+    }
+    
     /// Check if it's time to refresh allowances
     pub fn should_refresh_allowances(&self) -> bool {
         match self.last_allowance_refresh {
@@ -115,4 +143,19 @@ mod tests {
         // Should refresh after interval has passed
         assert!(ui_state.should_refresh_allowances());
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn test_success_message_expires() {
+        let mut ui_state = UIState::new();
+        ui_state.set_error("Oops".to_string());
+        ui_state.set_success("🎉 $5.00 added successfully!".to_string());
+        assert!(ui_state.error_message.is_none());
+        
+        ui_state.expire_success_message();
+        assert!(ui_state.success_message.is_some());
+        
+        ui_state.success_expires_at = Some(Instant::now());
+        ui_state.expire_success_message();
+        assert!(ui_state.success_message.is_none());
+    }
+}
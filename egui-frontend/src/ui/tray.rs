@@ -0,0 +1,357 @@
+//! # System Tray
+//!
+//! This module puts an icon in the menu bar (macOS) or notification area
+//! (Windows, Linux) while the app runs.
+//!
+//! ## Responsibilities:
+//! - Show the active child's balance in the icon's tooltip and at the top of its menu
+//! - "Add money…" and "Spend money…" open a small window that takes one
+//!   line, read with the same quick-entry rules as the header's quick-add box
+//! - "Open app" brings the main window back to the front
+//!
+//! ## Purpose:
+//! Recording the ice cream bought on the way home shouldn't mean finding the
+//! main window and a calendar day. Tray icons on Linux need a GTK main loop,
+//! which winit doesn't run, so there the icon lives on its own GTK thread and
+//! balance changes are handed to it over a channel.
+
+use eframe::egui;
+use log::{info, warn};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
+
+use crate::backend::domain::quick_entry::split_leading_direction;
+use crate::ui::app_state::AllowanceTrackerApp;
+
+const ADD_MONEY_ID: &str = "tray_add_money";
+const SPEND_MONEY_ID: &str = "tray_spend_money";
+const OPEN_APP_ID: &str = "tray_open_app";
+
+/// Shown when no child is selected
+const DEFAULT_STATUS: &str = "My Allowance Tracker";
+
+/// A tray menu entry that was picked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayCommand {
+    AddMoney,
+    SpendMoney,
+    OpenApp,
+}
+
+impl TrayCommand {
+    fn from_menu_id(id: &str) -> Option<Self> {
+        match id {
+            ADD_MONEY_ID => Some(TrayCommand::AddMoney),
+            SPEND_MONEY_ID => Some(TrayCommand::SpendMoney),
+            OPEN_APP_ID => Some(TrayCommand::OpenApp),
+            _ => None,
+        }
+    }
+}
+
+/// The one-line window opened from the tray menu
+#[derive(Debug, Clone)]
+pub struct TrayQuickEntry {
+    /// Spend money rather than add it
+    pub spending: bool,
+    pub input: String,
+    pub error: Option<String>,
+    /// Whether the text box has been given focus yet
+    focused: bool,
+}
+
+impl TrayQuickEntry {
+    fn new(spending: bool) -> Self {
+        Self { spending, input: String::new(), error: None, focused: false }
+    }
+
+    /// The typed line with the direction the menu entry implies, in place of
+    /// any direction word or sign it starts with
+    fn line(&self) -> Result<String, String> {
+        let (typed, rest) = split_leading_direction(&self.input);
+        let spending = typed.map(|direction| direction < 0.0).unwrap_or(self.spending);
+        if spending != self.spending {
+            return Err(if self.spending {
+                "That's money coming in; use \"Add money…\" for it".to_string()
+            } else {
+                "That's money going out; use \"Spend money…\" for it".to_string()
+            });
+        }
+        Ok(format!("{} {}", if self.spending { "spent" } else { "got" }, rest))
+    }
+}
+
+/// The tray icon and what was picked from its menu
+pub struct AppTray {
+    commands: Receiver<TrayCommand>,
+    status: StatusDisplay,
+    shown_status: String,
+    /// Open quick-add window, if any
+    pub quick_entry: Option<TrayQuickEntry>,
+}
+
+/// Where the balance line is shown
+#[cfg(not(target_os = "linux"))]
+struct StatusDisplay {
+    tray: TrayIcon,
+    item: MenuItem,
+}
+
+/// Where the balance line is shown; the icon itself lives on the GTK thread
+#[cfg(target_os = "linux")]
+struct StatusDisplay {
+    updates: Sender<String>,
+}
+
+impl AppTray {
+    /// Put the icon in the tray; `None` if the desktop has no tray to put it in
+    pub fn new(repaint: egui::Context) -> Option<Self> {
+        let (sender, commands) = channel();
+        MenuEvent::set_event_handler(Some(move |event: MenuEvent| {
+            if let Some(command) = TrayCommand::from_menu_id(event.id.as_ref()) {
+                // The receiver only goes away when the app is closing
+                let _ = sender.send(command);
+                repaint.request_repaint();
+            }
+        }));
+
+        match StatusDisplay::new() {
+            Ok(status) => {
+                info!("🛎️ Tray icon added");
+                Some(Self { commands, status, shown_status: DEFAULT_STATUS.to_string(), quick_entry: None })
+            }
+            Err(e) => {
+                warn!("🛎️ No tray icon: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Menu entries picked since the last call
+    pub fn take_commands(&self) -> Vec<TrayCommand> {
+        self.commands.try_iter().collect()
+    }
+
+    /// Show a new balance line, if it changed
+    pub fn set_status(&mut self, status: String) {
+        if status != self.shown_status {
+            self.status.show(&status);
+            self.shown_status = status;
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+impl StatusDisplay {
+    fn new() -> anyhow::Result<Self> {
+        let (tray, item) = build_tray()?;
+        Ok(Self { tray, item })
+    }
+
+    fn show(&self, status: &str) {
+        self.item.set_text(status);
+        if let Err(e) = self.tray.set_tooltip(Some(status)) {
+            warn!("🛎️ Failed to update the tray tooltip: {}", e);
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl StatusDisplay {
+    fn new() -> anyhow::Result<Self> {
+        let (updates, received) = channel::<String>();
+        let (ready_sender, ready) = channel();
+        std::thread::spawn(move || {
+            if let Err(e) = gtk::init() {
+                let _ = ready_sender.send(Err(anyhow::anyhow!("GTK couldn't start: {}", e)));
+                return;
+            }
+            let (tray, item) = match build_tray() {
+                Ok(built) => built,
+                Err(e) => {
+                    let _ = ready_sender.send(Err(e));
+                    return;
+                }
+            };
+            let _ = ready_sender.send(Ok(()));
+            gtk::glib::timeout_add_local(std::time::Duration::from_millis(500), move || {
+                for status in received.try_iter() {
+                    item.set_text(&status);
+                    let _ = tray.set_tooltip(Some(&status));
+                }
+                gtk::glib::ControlFlow::Continue
+            });
+            gtk::main();
+        });
+        ready.recv()??;
+        Ok(Self { updates })
+    }
+
+    fn show(&self, status: &str) {
+        let _ = self.updates.send(status.to_string());
+    }
+}
+
+/// The icon with its menu; the first menu line is the balance
+fn build_tray() -> anyhow::Result<(TrayIcon, MenuItem)> {
+    let status = MenuItem::new(DEFAULT_STATUS, false, None);
+    let menu = Menu::with_items(&[
+        &status,
+        &PredefinedMenuItem::separator(),
+        &MenuItem::with_id(ADD_MONEY_ID, "Add money…", true, None),
+        &MenuItem::with_id(SPEND_MONEY_ID, "Spend money…", true, None),
+        &PredefinedMenuItem::separator(),
+        &MenuItem::with_id(OPEN_APP_ID, "Open app", true, None),
+    ])?;
+    let tray = TrayIconBuilder::new()
+        .with_menu(Box::new(menu))
+        .with_tooltip(DEFAULT_STATUS)
+        .with_icon(load_tray_icon()?)
+        .build()?;
+    Ok((tray, status))
+}
+
+fn load_tray_icon() -> anyhow::Result<Icon> {
+    let image = image::load_from_memory(include_bytes!("../../assets/app-icon.png"))?
+        .resize(64, 64, image::imageops::FilterType::Triangle)
+        .to_rgba8();
+    let (width, height) = image.dimensions();
+    Ok(Icon::from_rgba(image.into_raw(), width, height)?)
+}
+
+impl AllowanceTrackerApp {
+    /// Keep the tray's balance current, act on its menu and draw its quick-add window
+    pub fn update_tray(&mut self, ctx: &egui::Context) {
+        let status = match &self.core.current_child {
+            Some(child) => format!("{}: {}", child.name, self.format_currency_amount(self.core.current_balance)),
+            None => DEFAULT_STATUS.to_string(),
+        };
+        let Some(tray) = &mut self.tray else {
+            return;
+        };
+        tray.set_status(status);
+
+        for command in tray.take_commands() {
+            info!("🛎️ Tray: {:?}", command);
+            match command {
+                TrayCommand::AddMoney => tray.quick_entry = Some(TrayQuickEntry::new(false)),
+                TrayCommand::SpendMoney => tray.quick_entry = Some(TrayQuickEntry::new(true)),
+                TrayCommand::OpenApp => {
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Visible(true));
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+                }
+            }
+        }
+
+        self.render_tray_quick_entry(ctx);
+    }
+
+    /// The small window opened from the tray, separate from the main one
+    fn render_tray_quick_entry(&mut self, ctx: &egui::Context) {
+        let Some(mut entry) = self.tray.as_mut().and_then(|tray| tray.quick_entry.take()) else {
+            return;
+        };
+        let title = if entry.spending { "Spend money" } else { "Add money" };
+        let hint = if entry.spending { "4.50 ice cream yesterday" } else { "10 birthday from grandma" };
+        let child_name = self.core.current_child.as_ref().map(|child| child.name.clone());
+        let (mut submit, mut close) = (false, false);
+
+        ctx.show_viewport_immediate(
+            egui::ViewportId::from_hash_of("tray_quick_entry"),
+            egui::ViewportBuilder::default()
+                .with_title(title)
+                .with_inner_size([380.0, 140.0])
+                .with_resizable(false)
+                .with_always_on_top(),
+            |ctx, _class| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let Some(child_name) = &child_name else {
+                        ui.label("Select a child in the app first.");
+                        if ui.button("Close").clicked() {
+                            close = true;
+                        }
+                        return;
+                    };
+                    ui.label(format!("{} for {}:", title, child_name));
+                    let response = ui.add(egui::TextEdit::singleline(&mut entry.input)
+                        .hint_text(hint)
+                        .desired_width(f32::INFINITY));
+                    if !entry.focused {
+                        response.request_focus();
+                        entry.focused = true;
+                    }
+                    if response.changed() {
+                        entry.error = None;
+                    }
+                    if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                        submit = true;
+                    }
+                    if let Some(error) = &entry.error {
+                        ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error);
+                    }
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(!entry.input.trim().is_empty(), egui::Button::new(title)).clicked() {
+                            submit = true;
+                        }
+                        if ui.button("Cancel").clicked() {
+                            close = true;
+                        }
+                    });
+                });
+                if ctx.input(|i| i.viewport().close_requested()) {
+                    close = true;
+                }
+            },
+        );
+
+        if submit && !close {
+            match entry.line().and_then(|line| self.record_quick_entry(&line)) {
+                Ok(message) => {
+                    self.ui.set_success(message);
+                    close = true;
+                }
+                Err(error) => entry.error = Some(error),
+            }
+        }
+        if !close {
+            if let Some(tray) = &mut self.tray {
+                tray.quick_entry = Some(entry);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(spending: bool, input: &str) -> Result<String, String> {
+        let mut entry = TrayQuickEntry::new(spending);
+        entry.input = input.to_string();
+        entry.line()
+    }
+
+    #[test]
+    fn test_line_adds_the_menu_direction() {
+        assert_eq!(line(true, "4.50 ice cream").unwrap(), "spent 4.50 ice cream");
+        assert_eq!(line(false, "  10 birthday from grandma ").unwrap(), "got 10 birthday from grandma");
+    }
+
+    #[test]
+    fn test_line_drops_a_direction_already_typed() {
+        assert_eq!(line(true, "spent 4.50 candy").unwrap(), "spent 4.50 candy");
+        assert_eq!(line(true, "Paid $3 for stickers").unwrap(), "spent $3 for stickers");
+        assert_eq!(line(true, "-4.50 candy").unwrap(), "spent 4.50 candy");
+        assert_eq!(line(false, "got 10 gift").unwrap(), "got 10 gift");
+        assert_eq!(line(false, "+ 10 gift").unwrap(), "got 10 gift");
+    }
+
+    #[test]
+    fn test_line_refuses_the_opposite_direction() {
+        assert!(line(true, "got 10 gift").unwrap_err().contains("Add money"));
+        assert!(line(true, "+10 gift").unwrap_err().contains("Add money"));
+        assert!(line(false, "spent 4 candy").unwrap_err().contains("Spend money"));
+    }
+}
@@ -0,0 +1,236 @@
+//! End-to-end smoke test of the calls the UI makes
+//!
+//! Opens a real `Backend` on a temp directory and drives it the way the egui
+//! app does: `AllowanceApi` through `DirectBackendApi` where the UI uses it,
+//! and the domain services directly where it still does (allowance config,
+//! goals, exports, data directory relocation). This catches wiring mistakes
+//! that unit tests of a single service can't see, such as a service built
+//! over the wrong connection.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use allowance_tracker_egui::backend::api::DirectBackendApi;
+use allowance_tracker_egui::backend::domain::commands::allowance::{GetAllowanceConfigCommand, UpdateAllowanceConfigCommand};
+use allowance_tracker_egui::backend::domain::commands::child::CreateChildCommand;
+use allowance_tracker_egui::backend::domain::commands::goal::{CancelGoalCommand, CreateGoalCommand, GetCurrentGoalCommand};
+use allowance_tracker_egui::backend::domain::{EmailConfig, SecretsService};
+use allowance_tracker_egui::backend::storage::secrets::EncryptedFileSecretStore;
+use allowance_tracker_egui::backend::Backend;
+use anyhow::Result;
+use chrono::{Duration, Local};
+use frontend_api::AllowanceApi;
+use shared::{
+    AcknowledgeCelebrationsRequest, AddMoneyRequest, BalanceChartRequest, CelebrationKind, ChartResolution, DeleteTransactionsRequest, DescriptionSuggestionsRequest,
+    ErrorCode, ExportToPathRequest, RelocateDataDirectoryRequest, SetActiveChildRequest, SpendMoneyRequest,
+    TransactionListRequest, TransactionType,
+};
+use tempfile::TempDir;
+
+/// A backend over `data_dir` that keeps secrets next to it and never sends email
+fn open_backend(data_dir: &Path, secrets_dir: &Path) -> Result<Backend> {
+    let secrets = SecretsService::with_store(Arc::new(EncryptedFileSecretStore::new(secrets_dir)?));
+    Backend::open(data_dir, secrets, EmailConfig::default())
+}
+
+fn list_all() -> TransactionListRequest {
+    TransactionListRequest { child_id: None, after: None, limit: None, start_date: None, end_date: None }
+}
+
+#[test]
+fn test_ui_command_surface_end_to_end() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let backend = open_backend(&temp_dir.path().join("data"), &temp_dir.path().join("secrets"))?;
+    let api = DirectBackendApi::new(&backend);
+
+    let health = api.health()?;
+    assert!(health.healthy, "{:?}", health.problems);
+    assert!(health.writable);
+    assert_eq!(api.version()?.storage_backend, "csv");
+
+    // Nothing works on money until a child is picked
+    assert!(api.list_children()?.children.is_empty());
+    let err = api
+        .add_money(AddMoneyRequest {
+            child_id: None,
+            description: "Chores".to_string(),
+            amount: 5.0,
+            date: None,
+            amount_expression: None,
+            actor: None,
+            icon: None,
+            transaction_type: None,
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::NoActiveChild);
+
+    // Children
+    let child = backend
+        .child_service
+        .create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })?
+        .child;
+    api.set_active_child(SetActiveChildRequest { child_id: child.id.clone() })?;
+    assert_eq!(api.get_active_child()?.active_child.map(|c| c.id), Some(child.id.clone()));
+    assert_eq!(api.list_children()?.children.len(), 1);
+
+    // Allowance config
+    backend.allowance_service.update_allowance_config(UpdateAllowanceConfigCommand {
+        child_id: None,
+        amount: 5.0,
+        day_of_week: 6,
+        is_active: true,
+        day_change_policy: None,
+        frequency: None,
+        day_of_month: None,
+        interval_weeks: None,
+        anchor_date: None,
+        expected_updated_at: None,
+    })?;
+    let config = backend
+        .allowance_service
+        .get_allowance_config(GetAllowanceConfigCommand { child_id: Some(child.id.clone()) })?
+        .allowance_config
+        .expect("allowance config was saved");
+    assert_eq!(config.amount, 5.0);
+
+    // Money in and out
+    let added = api.add_money(AddMoneyRequest {
+        child_id: None,
+        description: "Birthday money".to_string(),
+        amount: 20.0,
+        date: None,
+        amount_expression: None,
+        actor: Some("parent".to_string()),
+        icon: None,
+        transaction_type: None,
+    })?;
+    assert_eq!(added.new_balance, 20.0);
+    assert_eq!(added.backdate_impact, None);
+    let spent = api.spend_money(SpendMoneyRequest {
+        child_id: None,
+        description: "Comic book".to_string(),
+        amount: 4.0,
+        date: None,
+        parent_override: false,
+        amount_expression: None,
+        actor: None,
+        icon: Some("📚".to_string()),
+        transaction_type: None,
+    })?;
+    assert_eq!(spent.new_balance, 16.0);
+    let transactions = api.list_transactions(list_all())?.transactions;
+    assert_eq!(transactions.len(), 2);
+    assert!(transactions.iter().any(|t| t.description == "Comic book" && t.icon.as_deref() == Some("📚")));
+
+    // Passing $10 is celebrated once, until a screen acknowledges it
+    let pending = api.get_pending_celebrations()?.celebrations;
+    assert_eq!(pending.iter().map(|c| (c.kind, c.amount)).collect::<Vec<_>>(), vec![(CelebrationKind::BalanceMilestone, 10.0)]);
+    let acknowledged = api.acknowledge_celebrations(AcknowledgeCelebrationsRequest {
+        celebration_ids: pending.into_iter().map(|c| c.id).collect(),
+    })?;
+    assert_eq!(acknowledged.acknowledged_count, 1);
+    assert!(api.get_pending_celebrations()?.celebrations.is_empty());
+    let milestones = api.get_milestone_history()?;
+    assert_eq!(milestones.reached.iter().map(|m| m.amount).collect::<Vec<_>>(), vec![10.0]);
+    assert_eq!(milestones.upcoming.first(), Some(&25.0));
+
+    let today = Local::now().date_naive();
+    let chart = api.get_balance_chart(BalanceChartRequest {
+        child_id: None,
+        start_date: today - Duration::days(7),
+        end_date: today,
+        resolution: ChartResolution::Daily,
+    })?;
+    assert_eq!(chart.points.last().map(|p| p.balance), Some(16.0));
+
+    let suggestions = api.get_description_suggestions(DescriptionSuggestionsRequest {
+        transaction_type: TransactionType::Expense,
+        prefix: "com".to_string(),
+        limit: 5,
+    })?;
+    assert_eq!(suggestions.suggestions.first().map(|s| s.description.as_str()), Some("Comic book"));
+
+    // Goals
+    backend.goal_service.create_goal(CreateGoalCommand {
+        child_id: None,
+        description: "Lego set".to_string(),
+        target_amount: 30.0,
+    })?;
+    let current = backend.goal_service.get_current_goal(GetCurrentGoalCommand { child_id: None })?;
+    assert_eq!(current.goal.map(|g| g.description), Some("Lego set".to_string()));
+    backend.goal_service.cancel_goal(CancelGoalCommand { child_id: None })?;
+    assert!(backend.goal_service.get_current_goal(GetCurrentGoalCommand { child_id: None })?.goal.is_none());
+
+    // Exports
+    let export_dir = temp_dir.path().join("exports");
+    std::fs::create_dir_all(&export_dir)?;
+    let export = backend.export_service.export_to_path(
+        ExportToPathRequest {
+            child_id: None,
+            custom_path: Some(export_dir.to_string_lossy().to_string()),
+            parental_answer: None,
+        },
+        &backend.child_service,
+        &backend.transaction_service,
+        &backend.parental_control_service,
+    )?;
+    assert!(export.success, "{}", export.message);
+    assert_eq!(export.transaction_count, 2);
+    assert!(Path::new(&export.file_path).exists());
+
+    // Data directory relocation keeps everything reachable
+    let new_home = temp_dir.path().join("relocated");
+    let relocated = backend.data_directory_service.relocate_directory(RelocateDataDirectoryRequest {
+        child_id: None,
+        new_path: new_home.to_string_lossy().to_string(),
+        dry_run: false,
+    })?;
+    assert!(relocated.success, "{}", relocated.message);
+    let current_dir = backend.data_directory_service.get_current_directory(None)?;
+    assert!(current_dir.is_redirected);
+    assert_eq!(api.list_transactions(list_all())?.transactions.len(), 2);
+
+    // Deleting goes through the same path as the transaction table
+    let deleted = api.delete_transactions(DeleteTransactionsRequest {
+        child_id: None,
+        transaction_ids: vec![spent.transaction_id],
+        actor: Some("parent".to_string()),
+    })?;
+    assert_eq!(deleted.deleted_count, 1);
+    assert_eq!(api.list_transactions(list_all())?.transactions.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_backend_reopens_existing_data() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let data_dir = temp_dir.path().join("data");
+    let secrets_dir = temp_dir.path().join("secrets");
+
+    {
+        let backend = open_backend(&data_dir, &secrets_dir)?;
+        let child = backend
+            .child_service
+            .create_child(CreateChildCommand { name: "Ada".to_string(), birthdate: "2015-01-01".to_string() })?
+            .child;
+        let api = DirectBackendApi::new(&backend);
+        api.set_active_child(SetActiveChildRequest { child_id: child.id })?;
+        api.add_money(AddMoneyRequest {
+            child_id: None,
+            description: "Chores".to_string(),
+            amount: 3.0,
+            date: None,
+            amount_expression: None,
+            actor: None,
+            icon: None,
+            transaction_type: None,
+        })?;
+    }
+
+    // Dropping the first backend releases the directory for the next one
+    let backend = open_backend(&data_dir, &secrets_dir)?;
+    let api = DirectBackendApi::new(&backend);
+    assert_eq!(api.list_transactions(list_all())?.transactions.len(), 1);
+    assert!(!backend.data_directory_service.get_current_directory(None)?.is_read_only);
+    Ok(())
+}
@@ -0,0 +1,113 @@
+//! # Frontend API
+//!
+//! The calls a frontend makes against the allowance tracker, as one trait.
+//!
+//! Every UI talks to the tracker through `AllowanceApi`, using the request and
+//! response DTOs from the `shared` crate. How a call reaches the domain
+//! services is up to the implementation:
+//!
+//! - `DirectBackendApi` (egui frontend) calls the services in-process and owns
+//!   the single domain-to-DTO mapping
+//! - A client for an HTTP server would implement the same trait, so a web UI
+//!   keeps the same call sites
+//!
+//! Calls are synchronous, like the backend services behind them. An async
+//! host (a web server, say) runs them on its blocking thread pool instead of
+//! needing an async variant of every call. Failures carry a
+//! `shared::ErrorCode`, the same code a REST server would send alongside its
+//! HTTP status.
+//!
+//! Requests that act on a child's transactions take an optional `child_id`.
+//! Without one they use the active child, which another caller can switch at
+//! any time, so a server handling several clients should always pass it.
+
+use shared::{
+    AcknowledgeCelebrationsRequest, AcknowledgeCelebrationsResponse, ActiveChildResponse, AddMoneyRequest, BalanceChartRequest, BalanceChartResponse, AddMoneyResponse, ChildListResponse,
+    DeleteTransactionsRequest, DeleteTransactionsResponse, DescriptionSuggestionsRequest,
+    DescriptionSuggestionsResponse, DuplicateTransactionRequest, DuplicateTransactionResponse, ErrorCode, ErrorResponse, HealthResponse, MilestoneHistoryResponse, PendingCelebrationsResponse, SetActiveChildRequest,
+    SetActiveChildResponse, SpendMoneyRequest, SpendMoneyResponse, TransactionListRequest,
+    TransactionListResponse, VersionResponse,
+};
+use thiserror::Error;
+
+/// Why an API call failed: a code to branch on and a message to show
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error("{message}")]
+pub struct ApiError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl ApiError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self { code, message: message.into() }
+    }
+
+    /// The call needs an active child and none is selected
+    pub fn no_active_child() -> Self {
+        Self::new(ErrorCode::NoActiveChild, "No active child selected")
+    }
+}
+
+impl From<ErrorResponse> for ApiError {
+    fn from(response: ErrorResponse) -> Self {
+        Self::new(response.code, response.message)
+    }
+}
+
+impl From<ApiError> for ErrorResponse {
+    fn from(error: ApiError) -> Self {
+        ErrorResponse { code: error.code, message: error.message }
+    }
+}
+
+/// Result of an API call
+pub type ApiResult<T> = Result<T, ApiError>;
+
+/// Everything a frontend asks of the allowance tracker
+pub trait AllowanceApi {
+    /// All children, in storage order
+    fn list_children(&self) -> ApiResult<ChildListResponse>;
+
+    /// The child currently being viewed, if any
+    fn get_active_child(&self) -> ApiResult<ActiveChildResponse>;
+
+    /// Switch to another child
+    fn set_active_child(&self, request: SetActiveChildRequest) -> ApiResult<SetActiveChildResponse>;
+
+    /// A page of a child's transactions, newest first
+    fn list_transactions(&self, request: TransactionListRequest) -> ApiResult<TransactionListResponse>;
+
+    /// Record money a child received
+    fn add_money(&self, request: AddMoneyRequest) -> ApiResult<AddMoneyResponse>;
+
+    /// Record money a child spent
+    fn spend_money(&self, request: SpendMoneyRequest) -> ApiResult<SpendMoneyResponse>;
+
+    /// Delete a child's transactions by id
+    fn delete_transactions(&self, request: DeleteTransactionsRequest) -> ApiResult<DeleteTransactionsResponse>;
+
+    /// Copy one of a child's transactions as a new one dated now
+    fn duplicate_transaction(&self, request: DuplicateTransactionRequest) -> ApiResult<DuplicateTransactionResponse>;
+
+    /// A child's balance over a date range, bucketed for a chart
+    fn get_balance_chart(&self, request: BalanceChartRequest) -> ApiResult<BalanceChartResponse>;
+
+    /// Descriptions to offer in the active child's add or spend money form
+    fn get_description_suggestions(&self, request: DescriptionSuggestionsRequest) -> ApiResult<DescriptionSuggestionsResponse>;
+
+    /// The active child's celebrations that no frontend has shown yet
+    fn get_pending_celebrations(&self) -> ApiResult<PendingCelebrationsResponse>;
+
+    /// Mark the active child's celebrations as shown, so no frontend shows them again
+    fn acknowledge_celebrations(&self, request: AcknowledgeCelebrationsRequest) -> ApiResult<AcknowledgeCelebrationsResponse>;
+
+    /// The balance milestones the active child has reached, and the ones ahead
+    fn get_milestone_history(&self) -> ApiResult<MilestoneHistoryResponse>;
+
+    /// Whether storage is reachable, writable and at a known schema; needs no active child
+    fn health(&self) -> ApiResult<HealthResponse>;
+
+    /// Which build of the backend is running
+    fn version(&self) -> ApiResult<VersionResponse>;
+}
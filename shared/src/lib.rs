@@ -2,6 +2,9 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use chrono::{Datelike, DateTime, FixedOffset, NaiveDate, Utc};
 
+pub mod messages;
+pub use messages::LocalizedMessage;
+
 /// Transaction ID in format: "transaction::<income|expense>::epoch_millis"
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Transaction {
@@ -18,6 +21,18 @@ pub struct Transaction {
     pub balance: f64,
     /// Type of transaction for rendering purposes
     pub transaction_type: TransactionType,
+    /// Who entered the transaction, e.g. "Kid" or "Parent (Mom)"
+    #[serde(default)]
+    pub actor: Option<String>,
+    /// What the money was for, e.g. "Toys"
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Emoji or preset icon key, e.g. "🍦" or "toy"
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// In a period a parent has closed, so it can't be deleted
+    #[serde(default)]
+    pub locked: bool,
 }
 
 /// Type of transaction for rendering and business logic
@@ -29,6 +44,71 @@ pub enum TransactionType {
     Expense,
     /// Future allowance transaction (not yet received)
     FutureAllowance,
+    /// A scheduled allowance that was paid
+    AllowancePayment,
+    /// Extra money for something special
+    Bonus,
+    /// Money taken away
+    Penalty,
+    /// Money given by family or friends
+    Gift,
+    /// Money moved between a child and a parent, such as a loan
+    Transfer,
+    /// Interest earned on savings
+    Interest,
+    /// A correction, such as a reversal
+    Adjustment,
+}
+
+impl TransactionType {
+    /// Kinds a money form offers on top of plain income and expense
+    pub const INCOME_KINDS: [TransactionType; 5] = [
+        TransactionType::Bonus,
+        TransactionType::Gift,
+        TransactionType::Interest,
+        TransactionType::Transfer,
+        TransactionType::Adjustment,
+    ];
+    pub const EXPENSE_KINDS: [TransactionType; 3] = [
+        TransactionType::Penalty,
+        TransactionType::Transfer,
+        TransactionType::Adjustment,
+    ];
+
+    /// Name for the kind, e.g. in a picker or tooltip
+    pub fn label(&self) -> &'static str {
+        match self {
+            TransactionType::Income => "Income",
+            TransactionType::Expense => "Expense",
+            TransactionType::FutureAllowance => "Future allowance",
+            TransactionType::AllowancePayment => "Allowance",
+            TransactionType::Bonus => "Bonus",
+            TransactionType::Penalty => "Penalty",
+            TransactionType::Gift => "Gift",
+            TransactionType::Transfer => "Transfer",
+            TransactionType::Interest => "Interest",
+            TransactionType::Adjustment => "Adjustment",
+        }
+    }
+
+    /// Emoji a frontend can show for the kind; `None` for plain income and expense
+    pub fn emoji(&self) -> Option<&'static str> {
+        match self {
+            TransactionType::Income | TransactionType::Expense => None,
+            TransactionType::FutureAllowance | TransactionType::AllowancePayment => Some("📅"),
+            TransactionType::Bonus => Some("⭐"),
+            TransactionType::Penalty => Some("⚠️"),
+            TransactionType::Gift => Some("🎁"),
+            TransactionType::Transfer => Some("🔁"),
+            TransactionType::Interest => Some("📈"),
+            TransactionType::Adjustment => Some("🔧"),
+        }
+    }
+
+    /// Whether this is a projection rather than money that moved
+    pub fn is_projected(&self) -> bool {
+        matches!(self, TransactionType::FutureAllowance)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -41,6 +121,9 @@ pub struct TransactionListRequest {
     pub start_date: Option<String>,
     /// End date for filtering (RFC 3339)
     pub end_date: Option<String>,
+    /// Child to list for - uses the active child if not provided
+    #[serde(default)]
+    pub child_id: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -49,6 +132,173 @@ pub struct TransactionListResponse {
     pub pagination: PaginationInfo,
 }
 
+/// How far apart the points of a balance chart are
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChartResolution {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// Request for the active child's balance over a date range, ready to plot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BalanceChartRequest {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub resolution: ChartResolution,
+    #[serde(default)]
+    pub child_id: Option<String>,  // None for the active child
+}
+
+/// Balance at the end of one day, week or month
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BalanceChartPoint {
+    pub date: NaiveDate,
+    pub balance: f64,
+}
+
+/// What a balance chart marker points out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChartMarkerKind {
+    LargeTransaction,
+    GoalCreated,
+    GoalCompleted,
+}
+
+/// A large transaction, or a goal set or reached, on the day it happened
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BalanceChartMarker {
+    pub date: NaiveDate,
+    pub kind: ChartMarkerKind,
+    pub description: String,
+    pub amount: f64, // Transaction amount, or the goal's target
+}
+
+/// Balance chart points, oldest first; the last one is the balance on `end_date`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BalanceChartResponse {
+    pub resolution: ChartResolution,
+    pub points: Vec<BalanceChartPoint>,
+    #[serde(default)]
+    pub markers: Vec<BalanceChartMarker>,  // Oldest first
+}
+
+/// Request for descriptions to offer while a money form is filled in
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DescriptionSuggestionsRequest {
+    /// `Income` for the add money form, `Expense` for the spend money form
+    pub transaction_type: TransactionType,
+    /// What has been typed so far; empty offers everything
+    #[serde(default)]
+    pub prefix: String,
+    pub limit: usize,
+}
+
+/// A description to offer, with the amount to fill in if there is one
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DescriptionSuggestion {
+    pub description: String,
+    pub amount: Option<f64>,
+}
+
+/// Suggestions for a money form, best first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DescriptionSuggestionsResponse {
+    pub suggestions: Vec<DescriptionSuggestion>,
+}
+
+/// What a celebration is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CelebrationKind {
+    AllowancePosted,
+    GoalCompleted,
+    BalanceMilestone,
+}
+
+/// Something to cheer with an animation, such as confetti
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Celebration {
+    pub id: String,
+    pub kind: CelebrationKind,
+    pub created_at: DateTime<Utc>,
+    /// Ready to show, e.g. "You reached $100.00!"
+    pub message: String,
+    /// The allowance paid, the goal's target or the milestone reached
+    pub amount: f64,
+}
+
+/// The active child's celebrations not yet shown, oldest first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingCelebrationsResponse {
+    pub celebrations: Vec<Celebration>,
+}
+
+/// Celebrations that have been shown, by id
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AcknowledgeCelebrationsRequest {
+    pub celebration_ids: Vec<String>,
+}
+
+/// How many of the celebrations were still pending
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AcknowledgeCelebrationsResponse {
+    pub acknowledged_count: u32,
+}
+
+/// A balance milestone the child has reached
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReachedMilestone {
+    pub amount: f64,
+    pub reached_on: NaiveDate,
+    /// The transaction that took the balance past it
+    pub transaction_id: String,
+}
+
+/// The active child's milestone timeline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MilestoneHistoryResponse {
+    /// Oldest first
+    pub reached: Vec<ReachedMilestone>,
+    /// Thresholds not reached yet, lowest first
+    pub upcoming: Vec<f64>,
+}
+
+/// Schema version of one config file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaVersionInfo {
+    /// Path relative to the data directory
+    pub file: String,
+    /// `None` if the file could not be read
+    pub version: Option<u32>,
+    /// Version this build writes
+    pub current_version: u32,
+}
+
+/// Whether the backend can read and write its data
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthResponse {
+    pub healthy: bool,
+    pub data_directory: String,
+    pub storage_reachable: bool,
+    pub writable: bool,
+    /// Why changes are disabled, when read-only mode is on
+    pub read_only_reason: Option<String>,
+    pub schema_versions: Vec<SchemaVersionInfo>,
+    /// Empty when healthy
+    pub problems: Vec<String>,
+}
+
+/// Which build of the backend is running
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VersionResponse {
+    pub app_version: String,
+    pub git_commit: Option<String>,
+    /// e.g. "csv"
+    pub storage_backend: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CreateTransactionRequest {
     /// Description of the transaction (max 256 characters)
@@ -83,6 +333,25 @@ pub struct CalendarMonth {
     pub year: u32,
     pub days: Vec<CalendarDay>,
     pub first_day_of_week: u32, // 0 = Sunday, 1 = Monday, etc.
+    /// One summary per row of seven `days`, in order
+    #[serde(default)]
+    pub weeks: Vec<CalendarWeekSummary>,
+}
+
+/// Totals for one calendar row, for reviewing the month week by week
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CalendarWeekSummary {
+    /// First and last day of the month in this row
+    pub first_day: u32,
+    pub last_day: u32,
+    /// Money in, not counting projected allowances
+    pub total_earned: f64,
+    /// Money out as a positive amount
+    pub total_spent: f64,
+    /// `total_earned - total_spent`
+    pub net_change: f64,
+    /// Balance at the end of the row's last month day
+    pub ending_balance: f64,
 }
 
 /// Represents a single day in the calendar
@@ -96,6 +365,44 @@ pub struct CalendarDay {
     pub is_empty: bool, // For padding days before/after month
 }
 
+/// Lightweight view of one day: just enough to draw a month before its transactions load
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CalendarDaySummary {
+    pub day: u32,
+    pub transaction_count: usize,
+    pub end_of_day_balance: f64,
+}
+
+/// One day's transactions cut down to what fits in a calendar cell
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CalendarDayDigest {
+    /// The largest transactions by amount, in the order they happened
+    pub visible: Vec<Transaction>,
+    /// How many transactions were left out of `visible`
+    pub overflow_count: usize,
+    /// Net amount of the money transactions left out
+    pub overflow_net: f64,
+    /// Net amount of every money transaction on the day
+    pub net_total: f64,
+}
+
+/// Day summaries for a whole month (month days only, no padding)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CalendarMonthSummary {
+    pub month: u32,
+    pub year: u32,
+    pub first_day_of_week: u32, // 0 = Sunday, 1 = Monday, etc.
+    pub days: Vec<CalendarDaySummary>,
+}
+
+/// A focus month in full plus summaries of the months either side, for preloading
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CalendarNavigationWindow {
+    pub focus: CalendarMonth,
+    pub previous: CalendarMonthSummary,
+    pub next: CalendarMonthSummary,
+}
+
 /// Request for calendar month data
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CalendarMonthRequest {
@@ -120,6 +427,14 @@ impl Default for CalendarFocusDate {
     }
 }
 
+/// A day the calendar should draw attention to, e.g. after jumping to a search hit
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CalendarHighlight {
+    pub date: NaiveDate,
+    /// Transaction to highlight on that day; `None` to highlight just the day
+    pub transaction_id: Option<String>,
+}
+
 /// Request to update the calendar focus date
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct UpdateCalendarFocusRequest {
@@ -145,7 +460,7 @@ pub struct FormattedTransaction {
     pub formatted_balance: String,
     pub raw_amount: f64,
     pub raw_balance: f64,
-    pub raw_date: String, // Original RFC 3339 date for chart parsing
+    pub raw_date: String, // Original RFC 3339 date; charts use `BalanceChartRequest` instead
 }
 
 /// Type of transaction amount for styling and display
@@ -209,6 +524,9 @@ pub struct ParentalControlRequest {
 pub struct ParentalControlResponse {
     pub success: bool,
     pub message: String,
+    /// Seconds until another answer will be checked, after too many wrong ones
+    #[serde(default)]
+    pub locked_for_seconds: Option<u64>,
 }
 
 /// Request for spending money (creating a negative transaction)
@@ -217,6 +535,26 @@ pub struct SpendMoneyRequest {
     pub description: String,
     pub amount: f64,  // User provides positive amount, backend converts to negative
     pub date: Option<DateTime<FixedOffset>>,
+    #[serde(default)]
+    pub parent_override: bool,  // Parent-mode override for the "no negative balance" rule
+    #[serde(default)]
+    pub amount_expression: Option<String>,  // e.g. "3*1.25", noted in the description
+    #[serde(default)]
+    pub actor: Option<String>,  // Who entered it: "kid", "parent" or "parent:<name>"
+    #[serde(default)]
+    pub icon: Option<String>,  // Emoji or preset icon key, e.g. "🍦" or "toy"
+    #[serde(default)]
+    pub transaction_type: Option<TransactionType>,  // e.g. Penalty; None for a plain expense
+    #[serde(default)]
+    pub child_id: Option<String>,  // None for the active child
+}
+
+/// What a backdated transaction changed further down the history
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BackdateImpact {
+    pub recalculated_rows: usize,  // Later transactions whose running balance was rewritten
+    pub allowances_after: usize,  // How many of those were allowances
+    pub goal_affected: bool,  // The current goal was set after the backdated date
 }
 
 /// Response after spending money
@@ -226,6 +564,8 @@ pub struct SpendMoneyResponse {
     pub success_message: String,
     pub new_balance: f64,
     pub formatted_amount: String,
+    #[serde(default)]
+    pub backdate_impact: Option<BackdateImpact>,  // Set when later transactions had to be recalculated
 }
 
 /// Request for adding money (creating a positive transaction)
@@ -234,6 +574,16 @@ pub struct AddMoneyRequest {
     pub description: String,
     pub amount: f64,
     pub date: Option<DateTime<FixedOffset>>,
+    #[serde(default)]
+    pub amount_expression: Option<String>,  // e.g. "3*1.25", noted in the description
+    #[serde(default)]
+    pub actor: Option<String>,  // Who entered it: "kid", "parent" or "parent:<name>"
+    #[serde(default)]
+    pub icon: Option<String>,  // Emoji or preset icon key, e.g. "🍦" or "toy"
+    #[serde(default)]
+    pub transaction_type: Option<TransactionType>,  // e.g. Bonus or Gift; None for plain income
+    #[serde(default)]
+    pub child_id: Option<String>,  // None for the active child
 }
 
 /// Response after adding money
@@ -243,12 +593,18 @@ pub struct AddMoneyResponse {
     pub success_message: String,
     pub new_balance: f64,
     pub formatted_amount: String,
+    #[serde(default)]
+    pub backdate_impact: Option<BackdateImpact>,  // Set when later transactions had to be recalculated
 }
 
 /// Request for deleting multiple transactions
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DeleteTransactionsRequest {
     pub transaction_ids: Vec<String>,
+    #[serde(default)]
+    pub actor: Option<String>,  // Who deleted them: "parent" or "parent:<name>"
+    #[serde(default)]
+    pub child_id: Option<String>,  // None for the active child
 }
 
 /// Response after deleting transactions
@@ -259,6 +615,24 @@ pub struct DeleteTransactionsResponse {
     pub not_found_ids: Vec<String>,
 }
 
+/// Request for copying a transaction as a new one dated now
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DuplicateTransactionRequest {
+    pub transaction_id: String,
+    #[serde(default)]
+    pub actor: Option<String>,  // Who entered the copy, e.g. "kid" or "parent:<name>"
+    #[serde(default)]
+    pub child_id: Option<String>,  // None for the active child
+}
+
+/// Response after copying a transaction
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DuplicateTransactionResponse {
+    pub transaction: Transaction,
+    pub success_message: String,
+    pub new_balance: f64,
+}
+
 /// Form validation result specific to money management
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MoneyFormValidation {
@@ -266,6 +640,12 @@ pub struct MoneyFormValidation {
     pub errors: Vec<MoneyValidationError>,
     pub cleaned_amount: Option<f64>,
     pub suggestions: Vec<String>,
+    #[serde(default)]
+    pub amount_expression: Option<String>,  // Original input when the amount was typed as arithmetic
+    /// `errors` as catalog keys with every value their messages need, for
+    /// frontends that render them in their own language
+    #[serde(default)]
+    pub messages: Vec<LocalizedMessage>,
 }
 
 /// Specific validation errors for money forms
@@ -281,6 +661,36 @@ pub enum MoneyValidationError {
     AmountPrecisionTooHigh,
 }
 
+impl MoneyValidationError {
+    /// Stable key for this error in the message catalog
+    pub fn key(&self) -> &'static str {
+        match self {
+            MoneyValidationError::EmptyDescription => messages::keys::VALIDATION_EMPTY_DESCRIPTION,
+            MoneyValidationError::DescriptionTooLong(_) => messages::keys::VALIDATION_DESCRIPTION_TOO_LONG,
+            MoneyValidationError::EmptyAmount => messages::keys::VALIDATION_EMPTY_AMOUNT,
+            MoneyValidationError::InvalidAmountFormat(_) => messages::keys::VALIDATION_INVALID_AMOUNT,
+            MoneyValidationError::AmountNotPositive => messages::keys::VALIDATION_AMOUNT_NOT_POSITIVE,
+            MoneyValidationError::AmountTooSmall(_) => messages::keys::VALIDATION_AMOUNT_TOO_SMALL,
+            MoneyValidationError::AmountTooLarge(_) => messages::keys::VALIDATION_AMOUNT_TOO_LARGE,
+            MoneyValidationError::AmountPrecisionTooHigh => messages::keys::VALIDATION_AMOUNT_PRECISION_TOO_HIGH,
+        }
+    }
+
+    /// The error as a catalog message with the values it carries. Limits
+    /// that come from the form's settings, like the longest description,
+    /// are added by whoever knows them.
+    pub fn to_message(&self) -> LocalizedMessage {
+        let message = LocalizedMessage::new(self.key());
+        match self {
+            MoneyValidationError::DescriptionTooLong(length) => message.with_param("length", length.to_string()),
+            MoneyValidationError::InvalidAmountFormat(reason) => message.with_param("reason", reason.as_str()),
+            MoneyValidationError::AmountTooSmall(min) => message.with_param("min", format!("{:.2}", min)),
+            MoneyValidationError::AmountTooLarge(max) => message.with_param("max", format!("{:.2}", max)),
+            _ => message,
+        }
+    }
+}
+
 /// State for managing money input forms
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MoneyFormState {
@@ -353,6 +763,10 @@ pub struct ActiveChildResponse {
 pub struct GetDataDirectoryResponse {
     pub current_path: String,
     pub is_redirected: bool, // True if current location is via redirect file
+    #[serde(default)]
+    pub is_read_only: bool, // True in read-only mode or if another app instance holds the directory lock
+    #[serde(default)]
+    pub read_only_reason: Option<String>, // Why changes are disabled, when read-only
 }
 
 /// Request to relocate data directory
@@ -360,6 +774,8 @@ pub struct GetDataDirectoryResponse {
 pub struct RelocateDataDirectoryRequest {
     pub child_id: Option<String>, // If None, uses active child
     pub new_path: String,
+    #[serde(default)]
+    pub dry_run: bool, // If true, only report what would happen without touching disk
 }
 
 /// Response after relocating data directory
@@ -368,6 +784,32 @@ pub struct RelocateDataDirectoryResponse {
     pub success: bool,
     pub message: String,
     pub new_path: String,
+    #[serde(default)]
+    pub plan: Option<RelocationPlan>, // Populated for dry runs
+}
+
+/// A single file affected by a planned relocation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PlannedFileOperation {
+    pub relative_path: String, // Relative to the directory it lives in, using '/' separators
+    pub size_bytes: u64,
+}
+
+/// Summary of everything a relocation would do, produced by a dry run
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RelocationPlan {
+    pub source_path: String,
+    pub target_path: String,
+    pub files_to_copy: Vec<PlannedFileOperation>, // Relative to source_path
+    pub files_to_overwrite: Vec<PlannedFileOperation>, // Existing target files that would be replaced, relative to target_path
+    pub files_to_archive: Vec<PlannedFileOperation>, // Relative to source_path
+    pub files_to_remove: Vec<PlannedFileOperation>, // Files cleaned out of the default location, relative to it
+    pub archive_path: Option<String>, // Approximate archive folder; the real one is timestamped when run
+    pub directories_to_create: Vec<String>,
+    pub estimated_bytes: u64, // Total bytes that would be written
+    pub required_permissions: Vec<String>, // Directories that need write access
+    pub issues: Vec<String>, // Problems that would make the relocation fail
+    pub can_proceed: bool,
 }
 
 /// Request to revert data directory
@@ -416,6 +858,8 @@ pub struct RelocateWithConflictResolutionRequest {
     pub child_id: Option<String>, // If None, uses active child
     pub new_path: String,
     pub resolution: ConflictResolution,
+    #[serde(default)]
+    pub dry_run: bool, // If true, only report what would happen without touching disk
 }
 
 /// Response after relocating with conflict resolution
@@ -425,6 +869,8 @@ pub struct RelocateWithConflictResolutionResponse {
     pub message: String,
     pub new_path: String,
     pub archived_to: Option<String>, // Path where original data was archived, if applicable
+    #[serde(default)]
+    pub plan: Option<RelocationPlan>, // Populated for dry runs
 }
 
 /// Request to return data to default location
@@ -697,11 +1143,65 @@ impl fmt::Display for TransactionIdError {
 
 impl std::error::Error for TransactionIdError {}
 
+/// Machine-readable reason a request failed, shared by every transport
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// The child, goal or other record doesn't exist
+    NotFound,
+    /// The request needs an active child and none is selected
+    NoActiveChild,
+    /// The input broke a business rule, e.g. an empty description
+    InvalidInput,
+    /// The spend would overdraw a child with the no-negative-balance rule
+    InsufficientFunds,
+    /// The request clashes with the current state, e.g. a second active goal
+    Conflict,
+    /// The app is in read-only mode
+    ReadOnly,
+    /// The data is locked by another instance or still encrypted
+    Locked,
+    /// A passphrase or PIN was wrong
+    Unauthorized,
+    /// The disk is full
+    StorageFull,
+    /// Reading or writing the data files failed
+    Storage,
+    /// Anything else
+    Internal,
+}
+
+impl ErrorCode {
+    /// HTTP status a REST server should answer with
+    pub fn http_status(&self) -> u16 {
+        match self {
+            ErrorCode::NotFound => 404,
+            ErrorCode::NoActiveChild | ErrorCode::Conflict => 409,
+            ErrorCode::InvalidInput | ErrorCode::InsufficientFunds => 422,
+            ErrorCode::ReadOnly => 403,
+            ErrorCode::Locked => 423,
+            ErrorCode::Unauthorized => 401,
+            ErrorCode::StorageFull => 507,
+            ErrorCode::Storage | ErrorCode::Internal => 500,
+        }
+    }
+}
+
+/// Error body sent to a frontend: a code to branch on and a message to show
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
 /// Request to export transaction data as CSV
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ExportDataRequest {
     /// Optional child ID - if None, uses active child
     pub child_id: Option<String>,
+    /// Parental answer or PIN, needed when exports are protected
+    #[serde(default)]
+    pub parental_answer: Option<String>,
 }
 
 /// Response containing CSV data for export
@@ -717,12 +1217,50 @@ pub struct ExportDataResponse {
     pub child_name: String,
 }
 
+/// Request to export every child's data as a single zip archive
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExportAllDataRequest {
+    /// Optional custom directory path - if None, uses Documents folder
+    pub custom_path: Option<String>,
+    /// Replace names, shift dates and round amounts so the archive can be shared
+    #[serde(default)]
+    pub anonymize: bool,
+}
+
+/// Request to export every child as an Excel workbook
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExportWorkbookRequest {
+    /// Optional custom directory path - if None, uses Documents folder
+    pub custom_path: Option<String>,
+    /// Parental answer or PIN, needed when exports are protected
+    #[serde(default)]
+    pub parental_answer: Option<String>,
+}
+
+/// Response after exporting every child's data
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExportAllDataResponse {
+    /// Whether the export was successful
+    pub success: bool,
+    /// Success or error message
+    pub message: String,
+    /// Full path where the zip archive was written
+    pub file_path: String,
+    /// Number of children included in the archive
+    pub child_count: usize,
+    /// Total number of transactions across all children
+    pub transaction_count: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ExportToPathRequest {
     /// Optional child ID - if None, uses active child
     pub child_id: Option<String>,
     /// Optional custom directory path - if None, uses Documents folder
     pub custom_path: Option<String>,
+    /// Parental answer or PIN, needed when exports are protected
+    #[serde(default)]
+    pub parental_answer: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -810,6 +1348,32 @@ impl std::error::Error for ChildIdError {}
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_money_validation_errors_carry_their_key_and_values() {
+        let message = MoneyValidationError::DescriptionTooLong(300).to_message();
+        assert_eq!(message.key, "validation.description_too_long");
+        assert_eq!(message.params.get("length").map(String::as_str), Some("300"));
+        assert_eq!(
+            message.with_param("max", "256").to_english(),
+            "Description is too long (300 characters). Maximum is 256."
+        );
+
+        // Every error has English text, so no frontend falls back to showing a key
+        let errors = [
+            MoneyValidationError::EmptyDescription,
+            MoneyValidationError::EmptyAmount,
+            MoneyValidationError::InvalidAmountFormat("letters".to_string()),
+            MoneyValidationError::AmountNotPositive,
+            MoneyValidationError::AmountTooSmall(0.01),
+            MoneyValidationError::AmountTooLarge(1_000_000.0),
+            MoneyValidationError::AmountPrecisionTooHigh,
+        ];
+        for error in errors {
+            assert!(messages::english(error.key()).is_some(), "{:?}", error);
+            assert!(!error.to_message().to_english().contains('{'), "{:?}", error);
+        }
+    }
+
     #[test]
     fn test_generate_transaction_id() {
         // Test income transaction
@@ -859,6 +1423,10 @@ mod tests {
             amount: 10.0,
             balance: 100.0,
             transaction_type: TransactionType::Income,
+            actor: None,
+            category: None,
+            icon: None,
+            locked: false,
         };
 
         assert_eq!(transaction.extract_timestamp().unwrap(), 1702516122000);
@@ -0,0 +1,78 @@
+//! Message catalog shared by every frontend.
+//!
+//! Validation errors travel as a [`LocalizedMessage`]: a stable key from
+//! [`keys`] plus the values its text needs, such as the longest description
+//! allowed. A frontend renders one with its own translations, or with
+//! [`LocalizedMessage::to_english`], so every frontend words the same error
+//! the same way and a translation only has to supply the text for each key.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Stable keys for user-facing messages; never renamed once released
+pub mod keys {
+    pub const VALIDATION_EMPTY_DESCRIPTION: &str = "validation.empty_description";
+    pub const VALIDATION_DESCRIPTION_TOO_LONG: &str = "validation.description_too_long";
+    pub const VALIDATION_EMPTY_AMOUNT: &str = "validation.empty_amount";
+    pub const VALIDATION_INVALID_AMOUNT: &str = "validation.invalid_amount";
+    pub const VALIDATION_AMOUNT_NOT_POSITIVE: &str = "validation.amount_not_positive";
+    pub const VALIDATION_AMOUNT_TOO_LARGE: &str = "validation.amount_too_large";
+    pub const VALIDATION_AMOUNT_TOO_SMALL: &str = "validation.amount_too_small";
+    pub const VALIDATION_AMOUNT_PRECISION_TOO_HIGH: &str = "validation.amount_precision_too_high";
+}
+
+/// English text for every key, with `{name}` placeholders for its parameters;
+/// the fallback for missing translations
+pub fn english(key: &str) -> Option<&'static str> {
+    Some(match key {
+        keys::VALIDATION_EMPTY_DESCRIPTION => "Please enter a description",
+        keys::VALIDATION_DESCRIPTION_TOO_LONG => "Description is too long ({length} characters). Maximum is {max}.",
+        keys::VALIDATION_EMPTY_AMOUNT => "Please enter an amount",
+        keys::VALIDATION_INVALID_AMOUNT => "Please enter a valid amount (like 5 or 5.00): {reason}",
+        keys::VALIDATION_AMOUNT_NOT_POSITIVE => "Amount must be greater than 0",
+        keys::VALIDATION_AMOUNT_TOO_LARGE => "Amount is too large. Maximum is {max}",
+        keys::VALIDATION_AMOUNT_TOO_SMALL => "Amount is too small. Minimum is {min}",
+        keys::VALIDATION_AMOUNT_PRECISION_TOO_HIGH => "Amount has too many decimal places. Use at most 2 decimal places.",
+        _ => return None,
+    })
+}
+
+/// Fill a message's `{name}` placeholders with the given values
+pub fn fill_placeholders<'a>(text: &str, params: impl IntoIterator<Item = (&'a str, &'a str)>) -> String {
+    params.into_iter().fold(text.to_string(), |message, (name, value)| {
+        message.replace(&format!("{{{}}}", name), value)
+    })
+}
+
+/// A message as a catalog key and the values for its placeholders
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocalizedMessage {
+    pub key: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+}
+
+impl LocalizedMessage {
+    pub fn new(key: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            params: BTreeMap::new(),
+        }
+    }
+
+    /// Set a placeholder's value, replacing any earlier one
+    pub fn with_param(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.params.insert(name.to_string(), value.into());
+        self
+    }
+
+    /// The placeholder values as name/value pairs
+    pub fn param_pairs(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.params.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// Render the message in English; an unknown key is shown as itself
+    pub fn to_english(&self) -> String {
+        fill_placeholders(english(&self.key).unwrap_or(self.key.as_str()), self.param_pairs())
+    }
+}